@@ -0,0 +1,520 @@
+//! A minimal resolver of the [browserslist](https://github.com/browserslist/browserslist) queries.
+//!
+//! Biome doesn't embed the usage statistics of the browsers.
+//! The queries that select the browsers by version, such as `chrome >= 100` or `safari 15.4`,
+//! are resolved exactly.
+//! The other queries, such as `defaults`, `> 0.5%` or `last 2 versions`, are approximated with
+//! the recent versions of the major browsers.
+//!
+//! The rules use the [BrowserTargets] service to know whether a [Feature] is available in all the
+//! targeted browsers, before suggesting it.
+//!
+//! Node.js is targeted by the `node` queries, such as `node >= 18`, and by the `engines.node`
+//! field of the `package.json`.
+
+use biome_string_case::StrLikeExtension;
+use rustc_hash::FxHashMap;
+use std::fmt;
+
+/// A browser that can be targeted by a project
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Browser {
+    Chrome,
+    Edge,
+    Firefox,
+    Ie,
+    IosSafari,
+    Opera,
+    Safari,
+    Samsung,
+    /// Node.js comes last, so that the browsers keep their index in the support tables
+    Node,
+}
+
+impl Browser {
+    /// Returns the browser of a browserslist name, such as `chrome` or `ios_saf`.
+    /// The mobile variants of Chrome and Firefox are treated as their desktop version.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase_cow().as_ref() {
+            "and_chr" | "chrome" | "chromeandroid" | "chromeforandroid" => Some(Self::Chrome),
+            "edge" => Some(Self::Edge),
+            "and_ff" | "ff" | "firefox" | "firefoxandroid" | "firefoxforandroid" => {
+                Some(Self::Firefox)
+            }
+            "explorer" | "ie" => Some(Self::Ie),
+            "ios" | "ios_saf" => Some(Self::IosSafari),
+            "node" => Some(Self::Node),
+            "opera" => Some(Self::Opera),
+            "safari" => Some(Self::Safari),
+            "samsung" => Some(Self::Samsung),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `version` of this browser supports the properties and the values
+    /// prefixed with `prefix`, such as `-webkit-`
+    pub fn uses_prefix(self, prefix: &str, version: BrowserVersion) -> bool {
+        match prefix {
+            "-webkit-" => match self {
+                Self::Chrome | Self::Edge | Self::IosSafari | Self::Safari | Self::Samsung => true,
+                // Opera switched from Presto to Blink in its version 15
+                Self::Opera => version >= BrowserVersion::new(15, 0),
+                Self::Firefox | Self::Ie | Self::Node => false,
+            },
+            "-moz-" => self == Self::Firefox,
+            // Edge switched from EdgeHTML to Blink in its version 79
+            "-ms-" => {
+                self == Self::Ie || (self == Self::Edge && version < BrowserVersion::new(79, 0))
+            }
+            "-o-" => self == Self::Opera && version < BrowserVersion::new(15, 0),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Browser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Chrome => "Chrome",
+            Self::Edge => "Edge",
+            Self::Firefox => "Firefox",
+            Self::Ie => "Internet Explorer",
+            Self::IosSafari => "Safari on iOS",
+            Self::Node => "Node.js",
+            Self::Opera => "Opera",
+            Self::Safari => "Safari",
+            Self::Samsung => "Samsung Internet",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A feature of the web platform that some of the targeted browsers may not support.
+///
+/// The versions that support the features come from
+/// [MDN browser-compat-data](https://github.com/mdn/browser-compat-data).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Feature {
+    /// `Array.prototype.at()` and `String.prototype.at()`
+    ArrayAt,
+    /// `Array.fromAsync()`
+    ArrayFromAsync,
+    /// `Array.prototype.findLast()` and `Array.prototype.findLastIndex()`
+    ArrayFindLast,
+    /// The methods of `Array.prototype` that return a changed copy of the array, such as
+    /// `toSorted()` and `toReversed()`
+    ArrayChangeByCopy,
+    /// The methods of `Iterator.prototype`, such as `map()` and `filter()`
+    IteratorHelpers,
+    /// `Object.groupBy()` and `Map.groupBy()`
+    ObjectGroupBy,
+    /// `Object.hasOwn()`
+    ObjectHasOwn,
+    /// `Promise.any()`
+    PromiseAny,
+    /// `Promise.withResolvers()`
+    PromiseWithResolvers,
+    /// `String.prototype.replaceAll()`
+    StringReplaceAll,
+    /// The global `structuredClone()`
+    StructuredClone,
+}
+
+impl Feature {
+    /// Returns the first version of `browser` that supports this feature,
+    /// or `None` if no version supports it
+    const fn first_version(self, browser: Browser) -> Option<BrowserVersion> {
+        let (major, minor) = match (self, browser) {
+            (_, Browser::Ie) => return None,
+            (Self::ArrayAt, Browser::Node) => (16, 6),
+            (Self::ArrayFromAsync, Browser::Chrome | Browser::Edge) => (121, 0),
+            (Self::ArrayFromAsync, Browser::Firefox) => (115, 0),
+            (Self::ArrayFromAsync, Browser::IosSafari | Browser::Safari) => (16, 4),
+            (Self::ArrayFromAsync, Browser::Node) => (22, 0),
+            (Self::ArrayFromAsync, Browser::Opera) => (107, 0),
+            (Self::ArrayFromAsync, Browser::Samsung) => (25, 0),
+            (Self::ArrayFindLast, Browser::Chrome | Browser::Edge) => (97, 0),
+            (Self::ArrayFindLast, Browser::Firefox) => (104, 0),
+            (Self::ArrayFindLast, Browser::IosSafari | Browser::Safari) => (15, 4),
+            (Self::ArrayFindLast, Browser::Node) => (18, 0),
+            (Self::ArrayFindLast, Browser::Opera) => (83, 0),
+            (Self::ArrayFindLast, Browser::Samsung) => (18, 0),
+            (Self::ArrayChangeByCopy, Browser::Chrome | Browser::Edge) => (110, 0),
+            (Self::ArrayChangeByCopy, Browser::Firefox) => (115, 0),
+            (Self::ArrayChangeByCopy, Browser::IosSafari | Browser::Safari) => (16, 0),
+            (Self::ArrayChangeByCopy, Browser::Node) => (20, 0),
+            (Self::ArrayChangeByCopy, Browser::Opera) => (96, 0),
+            (Self::ArrayChangeByCopy, Browser::Samsung) => (21, 0),
+            (Self::IteratorHelpers, Browser::Node) => (22, 0),
+            (Self::ObjectGroupBy, Browser::Chrome | Browser::Edge) => (117, 0),
+            (Self::ObjectGroupBy, Browser::Firefox) => (119, 0),
+            (Self::ObjectGroupBy, Browser::IosSafari | Browser::Safari) => (17, 4),
+            (Self::ObjectGroupBy, Browser::Node) => (21, 0),
+            (Self::ObjectGroupBy, Browser::Opera) => (103, 0),
+            (Self::ObjectGroupBy, Browser::Samsung) => (24, 0),
+            (Self::ObjectHasOwn, Browser::Chrome | Browser::Edge) => (93, 0),
+            (Self::ObjectHasOwn, Browser::Firefox) => (92, 0),
+            (Self::ObjectHasOwn, Browser::IosSafari | Browser::Safari) => (15, 4),
+            (Self::ObjectHasOwn, Browser::Node) => (16, 9),
+            (Self::ObjectHasOwn, Browser::Opera) => (79, 0),
+            (Self::ObjectHasOwn, Browser::Samsung) => (17, 0),
+            (Self::PromiseAny, Browser::Chrome | Browser::Edge) => (85, 0),
+            (Self::PromiseAny, Browser::Firefox) => (79, 0),
+            (Self::PromiseAny, Browser::IosSafari | Browser::Safari) => (14, 0),
+            (Self::PromiseAny, Browser::Node) => (15, 0),
+            (Self::PromiseAny, Browser::Opera) => (71, 0),
+            (Self::PromiseAny, Browser::Samsung) => (14, 0),
+            (Self::PromiseWithResolvers, Browser::Chrome | Browser::Edge) => (119, 0),
+            (Self::PromiseWithResolvers, Browser::Firefox) => (121, 0),
+            (Self::PromiseWithResolvers, Browser::IosSafari | Browser::Safari) => (17, 4),
+            (Self::PromiseWithResolvers, Browser::Node) => (22, 0),
+            (Self::PromiseWithResolvers, Browser::Opera) => (105, 0),
+            (Self::PromiseWithResolvers, Browser::Samsung) => (25, 0),
+            (Self::StringReplaceAll, Browser::Chrome | Browser::Edge) => (85, 0),
+            (Self::StringReplaceAll, Browser::Firefox) => (77, 0),
+            (Self::StringReplaceAll, Browser::IosSafari | Browser::Safari) => (13, 1),
+            (Self::StringReplaceAll, Browser::Node) => (15, 0),
+            (Self::StringReplaceAll, Browser::Opera) => (71, 0),
+            (Self::StringReplaceAll, Browser::Samsung) => (14, 0),
+            (Self::StructuredClone, Browser::Node) => (17, 0),
+            (Self::ArrayAt, Browser::Chrome | Browser::Edge) => (92, 0),
+            (Self::ArrayAt, Browser::Firefox) => (90, 0),
+            (Self::ArrayAt, Browser::IosSafari | Browser::Safari) => (15, 4),
+            (Self::ArrayAt, Browser::Opera) => (78, 0),
+            (Self::ArrayAt, Browser::Samsung) => (16, 0),
+            (Self::StructuredClone, Browser::Chrome | Browser::Edge) => (98, 0),
+            (Self::StructuredClone, Browser::Firefox) => (94, 0),
+            (Self::StructuredClone, Browser::IosSafari | Browser::Safari) => (15, 4),
+            (Self::StructuredClone, Browser::Opera) => (84, 0),
+            (Self::StructuredClone, Browser::Samsung) => (18, 0),
+            (Self::IteratorHelpers, Browser::Chrome | Browser::Edge) => (122, 0),
+            (Self::IteratorHelpers, Browser::Firefox) => (131, 0),
+            (Self::IteratorHelpers, Browser::IosSafari | Browser::Safari) => (18, 4),
+            (Self::IteratorHelpers, Browser::Opera) => (108, 0),
+            (Self::IteratorHelpers, Browser::Samsung) => (26, 0),
+        };
+        Some(BrowserVersion::new(major, minor))
+    }
+}
+
+/// A version of a browser, such as `15.4`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BrowserVersion {
+    major: u16,
+    minor: u16,
+}
+
+impl BrowserVersion {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parses a version, such as `109` or `15.4`.
+    /// The patch number, such as the `1` of `15.4.1`, is ignored.
+    fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = match parts.next() {
+            Some(minor) => minor.parse().ok()?,
+            None => 0,
+        };
+        Some(Self::new(major, minor))
+    }
+
+    /// Returns the oldest version allowed by a semver range, such as `>=18`, `^16.14.0` or
+    /// `16.x || >=18`.
+    ///
+    /// The upper bounds of the range are ignored.
+    fn parse_oldest_in_range(range: &str) -> Option<Self> {
+        let mut oldest: Option<Self> = None;
+        for range in range.split("||") {
+            let bound = range.split_whitespace().next().unwrap_or_default();
+            let version = if bound.starts_with('<') {
+                Self::new(0, 0)
+            } else {
+                let mut parts = bound
+                    .trim_start_matches(['>', '=', '^', '~', 'v'])
+                    .split('.');
+                let major = match parts.next()? {
+                    "" | "*" | "x" | "X" => 0,
+                    major => major.parse().ok()?,
+                };
+                // The wildcards, such as the `x` of `16.x`, allow the version `.0`
+                let minor = parts
+                    .next()
+                    .and_then(|minor| minor.parse().ok())
+                    .unwrap_or(0);
+                Self::new(major, minor)
+            };
+            oldest = Some(oldest.map_or(version, |oldest| oldest.min(version)));
+        }
+        oldest
+    }
+}
+
+impl fmt::Display for BrowserVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.minor == 0 {
+            write!(f, "{}", self.major)
+        } else {
+            write!(f, "{}.{}", self.major, self.minor)
+        }
+    }
+}
+
+/// The browsers selected by `defaults`, and by the queries that depend on the usage statistics
+const DEFAULT_TARGETS: [(Browser, BrowserVersion); 7] = [
+    (Browser::Chrome, BrowserVersion::new(109, 0)),
+    (Browser::Edge, BrowserVersion::new(120, 0)),
+    (Browser::Firefox, BrowserVersion::new(115, 0)),
+    (Browser::IosSafari, BrowserVersion::new(16, 0)),
+    (Browser::Opera, BrowserVersion::new(100, 0)),
+    (Browser::Safari, BrowserVersion::new(16, 0)),
+    (Browser::Samsung, BrowserVersion::new(23, 0)),
+];
+
+/// The browsers targeted by a project, with the oldest targeted version of each of them.
+///
+/// The browsers that aren't targeted by the project are absent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BrowserTargets {
+    versions: FxHashMap<Browser, BrowserVersion>,
+}
+
+impl Default for BrowserTargets {
+    fn default() -> Self {
+        Self {
+            versions: DEFAULT_TARGETS.into_iter().collect(),
+        }
+    }
+}
+
+impl BrowserTargets {
+    /// Resolves a list of browserslist queries, such as `["chrome >= 100", "not dead"]`.
+    ///
+    /// The queries that exclude browsers, such as `not ie 11`, are ignored.
+    /// When no query selects a browser, the default browsers are targeted.
+    pub fn from_queries<'a>(queries: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut versions = FxHashMap::default();
+        let mut includes_defaults = false;
+        for query in queries
+            .into_iter()
+            .flat_map(|queries| queries.split(','))
+            .flat_map(|queries| queries.split(" or "))
+        {
+            let query = query.trim();
+            if query.is_empty() || query.to_ascii_lowercase_cow().starts_with("not ") {
+                continue;
+            }
+            match parse_browser_query(query) {
+                Some((browser, version)) => include(&mut versions, browser, version),
+                None => includes_defaults = true,
+            }
+        }
+        if includes_defaults || versions.is_empty() {
+            for (browser, version) in DEFAULT_TARGETS {
+                include(&mut versions, browser, version);
+            }
+        }
+        Self { versions }
+    }
+
+    /// Resolves the queries of a `.browserslistrc` file.
+    ///
+    /// The queries of the `production` environment are used in addition to the queries that
+    /// don't belong to any environment.
+    pub fn from_browserslistrc(content: &str) -> Self {
+        let mut in_production = true;
+        let queries = content.lines().filter_map(|line| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let environments = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'));
+            if let Some(environments) = environments {
+                in_production = environments
+                    .split_whitespace()
+                    .any(|environment| environment == "production");
+                return None;
+            }
+            in_production.then_some(line)
+        });
+        Self::from_queries(queries)
+    }
+
+    /// Targets Node.js from the `engines.node` field of a `package.json`, such as `>=18`.
+    ///
+    /// Returns `None` if the range can't be parsed.
+    pub fn from_node_engine(range: &str) -> Option<Self> {
+        let mut targets = Self {
+            versions: FxHashMap::default(),
+        };
+        targets.include_node_engine(range)?;
+        Some(targets)
+    }
+
+    /// Adds Node.js to the targets, with the oldest version allowed by `range`,
+    /// the `engines.node` field of a `package.json`.
+    ///
+    /// Returns `None`, and leaves the targets unchanged, if the range can't be parsed.
+    pub fn include_node_engine(&mut self, range: &str) -> Option<()> {
+        let version = BrowserVersion::parse_oldest_in_range(range)?;
+        include(&mut self.versions, Browser::Node, version);
+        Some(())
+    }
+
+    /// Returns the targeted browsers, with their oldest targeted version
+    pub fn iter(&self) -> impl Iterator<Item = (Browser, BrowserVersion)> + '_ {
+        self.versions
+            .iter()
+            .map(|(browser, version)| (*browser, *version))
+    }
+
+    /// Returns `true` if `feature` is available in the oldest targeted version of every
+    /// targeted browser
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.first_unsupported(feature).is_none()
+    }
+
+    /// Returns a targeted browser, with its oldest targeted version, that doesn't support
+    /// `feature`.
+    ///
+    /// When several targeted browsers don't support the feature, the first one in the order of
+    /// [Browser] is returned.
+    pub fn first_unsupported(&self, feature: Feature) -> Option<(Browser, BrowserVersion)> {
+        self.iter()
+            .filter(|(browser, version)| {
+                feature
+                    .first_version(*browser)
+                    .is_none_or(|first_version| *version < first_version)
+            })
+            .min()
+    }
+}
+
+/// Targets `version` of `browser`, unless an older version is already targeted
+fn include(
+    versions: &mut FxHashMap<Browser, BrowserVersion>,
+    browser: Browser,
+    version: BrowserVersion,
+) {
+    versions
+        .entry(browser)
+        .and_modify(|oldest| *oldest = (*oldest).min(version))
+        .or_insert(version);
+}
+
+/// Parses a query that selects the versions of a browser, such as `chrome >= 100`, `safari 15.4`
+/// or `firefox 100-110`, and returns the oldest selected version
+fn parse_browser_query(query: &str) -> Option<(Browser, BrowserVersion)> {
+    let mut parts = query.split_whitespace();
+    let browser = Browser::from_name(parts.next()?)?;
+    let version = match (parts.next()?, parts.next()) {
+        (">=" | ">", Some(version)) => BrowserVersion::parse(version)?,
+        // All the versions older than the given one, including the oldest ones
+        ("<=" | "<", Some(_)) => BrowserVersion::new(0, 0),
+        (range, None) => BrowserVersion::parse(range.split('-').next()?)?,
+        _ => return None,
+    };
+    parts.next().is_none().then_some((browser, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(targets: &BrowserTargets, browser: Browser) -> Option<BrowserVersion> {
+        targets
+            .iter()
+            .find_map(|(target, version)| (target == browser).then_some(version))
+    }
+
+    #[test]
+    fn resolves_browser_queries() {
+        let targets = BrowserTargets::from_queries(["chrome >= 100, safari 15.4-16", "ie <= 11"]);
+        assert_eq!(targets.iter().count(), 3);
+        assert_eq!(
+            version(&targets, Browser::Chrome),
+            Some(BrowserVersion::new(100, 0))
+        );
+        assert_eq!(
+            version(&targets, Browser::Safari),
+            Some(BrowserVersion::new(15, 4))
+        );
+        assert_eq!(
+            version(&targets, Browser::Ie),
+            Some(BrowserVersion::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn approximates_other_queries() {
+        let targets = BrowserTargets::from_queries(["> 0.5%", "ios_saf 12", "not dead"]);
+        assert_eq!(
+            version(&targets, Browser::IosSafari),
+            Some(BrowserVersion::new(12, 0))
+        );
+        assert_eq!(
+            version(&targets, Browser::Chrome),
+            Some(BrowserVersion::new(109, 0))
+        );
+        assert_eq!(BrowserTargets::from_queries([]), BrowserTargets::default());
+    }
+
+    #[test]
+    fn checks_the_features_of_the_targets() {
+        let targets = BrowserTargets::default();
+        assert!(targets.supports(Feature::ArrayAt));
+        assert!(targets.supports(Feature::StructuredClone));
+        assert!(!targets.supports(Feature::IteratorHelpers));
+
+        let targets = BrowserTargets::from_queries(["chrome >= 100", "safari 15"]);
+        assert!(!targets.supports(Feature::ArrayAt));
+
+        let targets = BrowserTargets::from_queries(["firefox >= 131", "ie 11"]);
+        assert!(!targets.supports(Feature::IteratorHelpers));
+    }
+
+    #[test]
+    fn targets_node() {
+        let mut targets = BrowserTargets::from_queries(["node >= 16", "chrome 120"]);
+        assert!(!targets.supports(Feature::StructuredClone));
+        assert_eq!(
+            targets.first_unsupported(Feature::StructuredClone),
+            Some((Browser::Node, BrowserVersion::new(16, 0)))
+        );
+
+        targets.include_node_engine("^14.17.0 || >=16").unwrap();
+        assert_eq!(
+            version(&targets, Browser::Node),
+            Some(BrowserVersion::new(14, 17))
+        );
+        assert!(!targets.supports(Feature::StringReplaceAll));
+
+        let targets = BrowserTargets::from_node_engine(">=18.0.0").unwrap();
+        assert_eq!(targets.iter().count(), 1);
+        assert!(targets.supports(Feature::ArrayFindLast));
+        assert!(!targets.supports(Feature::ArrayChangeByCopy));
+        assert!(BrowserTargets::from_node_engine("latest").is_none());
+        assert_eq!(
+            BrowserTargets::from_node_engine("16.x")
+                .and_then(|targets| version(&targets, Browser::Node)),
+            Some(BrowserVersion::new(16, 0))
+        );
+    }
+
+    #[test]
+    fn reads_browserslistrc() {
+        let targets = BrowserTargets::from_browserslistrc(
+            "# Comment\nfirefox 90\n\n[development]\nchrome 120\n\n[production staging]\nedge 18\n",
+        );
+        assert_eq!(targets.iter().count(), 2);
+        assert_eq!(
+            version(&targets, Browser::Firefox),
+            Some(BrowserVersion::new(90, 0))
+        );
+        assert_eq!(
+            version(&targets, Browser::Edge),
+            Some(BrowserVersion::new(18, 0))
+        );
+    }
+}
@@ -65,7 +65,9 @@ impl Diagnostic for AnalyzerDiagnostic {
 
     fn severity(&self) -> Severity {
         match &self.kind {
-            DiagnosticKind::Rule { .. } => Severity::Error,
+            DiagnosticKind::Rule(rule_diagnostic) => {
+                rule_diagnostic.severity.unwrap_or(Severity::Error)
+            }
             DiagnosticKind::Raw(error) => error.severity(),
         }
     }
@@ -134,6 +136,16 @@ impl AnalyzerDiagnostic {
         self
     }
 
+    /// Returns the severity chosen by the rule that emitted this diagnostic, if any.
+    ///
+    /// When the rule doesn't choose a severity, the severity configured for the rule applies.
+    pub fn rule_severity(&self) -> Option<Severity> {
+        match &self.kind {
+            DiagnosticKind::Rule(rule_diagnostic) => rule_diagnostic.severity,
+            DiagnosticKind::Raw(_) => None,
+        }
+    }
+
     pub const fn is_raw(&self) -> bool {
         matches!(self.kind, DiagnosticKind::Raw(_))
     }
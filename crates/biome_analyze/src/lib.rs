@@ -6,6 +6,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::ops;
 use tracing::trace;
 
+pub mod browserslist;
 mod categories;
 pub mod context;
 mod diagnostics;
@@ -339,12 +340,19 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, true, index, comment.text(), piece.text_range())?;
+                let trivia = Some((true, index));
+                self.handle_comment(&token, trivia, comment.text(), piece.text_range())?;
             }
         }
 
         self.bump_line_index(token.text_trimmed(), token.text_trimmed_range());
 
+        // The comments of some languages are nodes rather than trivia
+        if let Some(comment) = self.suppression_action.comment_ending_with(&token) {
+            let text = comment.text_trimmed().to_string();
+            self.handle_comment(&token, None, &text, comment.text_trimmed_range())?;
+        }
+
         for (index, piece) in token.trailing_trivia().pieces().enumerate() {
             if matches!(
                 piece.kind(),
@@ -356,7 +364,8 @@ where
             }
 
             if let Some(comment) = piece.as_comments() {
-                self.handle_comment(&token, false, index, comment.text(), piece.text_range())?;
+                let trivia = Some((false, index));
+                self.handle_comment(&token, trivia, comment.text(), piece.text_range())?;
             }
         }
 
@@ -443,13 +452,15 @@ where
         ControlFlow::Continue(())
     }
 
-    /// Parse the text content of a comment trivia piece for suppression
-    /// comments, and create line suppression entries accordingly
+    /// Parse the text content of a comment for suppression comments, and
+    /// create line suppression entries accordingly.
+    ///
+    /// `trivia` holds whether the comment is a leading trivia of `token`, and
+    /// the index of its trivia piece. It's `None` if the comment is a node.
     fn handle_comment(
         &mut self,
         token: &SyntaxToken<L>,
-        is_leading: bool,
-        index: usize,
+        trivia: Option<(bool, usize)>,
         text: &str,
         range: TextRange,
     ) -> ControlFlow<Break> {
@@ -510,6 +521,9 @@ where
                 match (key, instance) {
                     (Some(key), Some(value)) => suppressed_instances.push((key, value.to_owned())),
                     (Some(key), None) => {
+                        if group_rule.is_none() {
+                            self.report_broad_suppression(range, Some(rule))?;
+                        }
                         suppressed_rules.push(key);
                         has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
                     }
@@ -538,6 +552,9 @@ where
                     _ => {}
                 }
             } else {
+                if !matches!(kind, SuppressionKind::Deprecated) {
+                    self.report_broad_suppression(range, None)?;
+                }
                 suppressed_rules.clear();
                 suppress_all = true;
                 // If this if a "suppress all lints" comment, no need to
@@ -557,8 +574,10 @@ where
                 .with_tags(DiagnosticTags::DEPRECATED_CODE)
             });
 
-            let signal = signal
-                .with_action(|| update_suppression(self.root, token, is_leading, index, text));
+            let signal = signal.with_action(|| {
+                let (is_leading, index) = trivia?;
+                update_suppression(self.root, token, is_leading, index, text)
+            });
 
             (self.emit_signal)(&signal)?;
         }
@@ -607,6 +626,33 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Emit a diagnostic for a suppression comment that disables all the lint
+    /// rules, or all the rules of the `group`, when the analyzer options
+    /// request it
+    fn report_broad_suppression(
+        &mut self,
+        range: TextRange,
+        group: Option<&str>,
+    ) -> ControlFlow<Break> {
+        if !self.options.configuration.report_broad_suppressions || !range_match(self.range, range)
+        {
+            return ControlFlow::Continue(());
+        }
+
+        let message = match group {
+            Some(group) => format!(
+                "This suppression comment disables all the rules of the group {group}. Suppress only the rules that report a diagnostic, such as `lint/{group}/ruleName`."
+            ),
+            None => "This suppression comment disables all the lint rules. Suppress only the rules that report a diagnostic, such as `lint/group/ruleName`.".to_string(),
+        };
+        let signal = DiagnosticSignal::new(move || {
+            SuppressionDiagnostic::new(category!("suppressions/tooBroad"), range, &message)
+                .with_severity(Severity::Warning)
+        });
+
+        (self.emit_signal)(&signal)
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
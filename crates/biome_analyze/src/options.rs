@@ -67,6 +67,10 @@ pub struct AnalyzerConfiguration {
 
     /// Indicates the type of runtime or transformation used for interpreting JSX.
     pub jsx_runtime: Option<JsxRuntime>,
+
+    /// Whether the suppression comments that disable all the rules, or all the rules of a group,
+    /// are reported
+    pub report_broad_suppressions: bool,
 }
 
 /// A set of information useful to the analyzer infrastructure
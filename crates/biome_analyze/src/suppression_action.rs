@@ -1,5 +1,5 @@
 use crate::SuppressionCommentEmitterPayload;
-use biome_rowan::{BatchMutation, Language, SyntaxToken, TextRange, TokenAtOffset};
+use biome_rowan::{BatchMutation, Language, SyntaxNode, SyntaxToken, TextRange, TokenAtOffset};
 
 pub trait SuppressionAction {
     type Language: Language;
@@ -73,6 +73,17 @@ pub trait SuppressionAction {
         apply_suppression: ApplySuppression<Self::Language>,
         suppression_text: &str,
     );
+
+    /// Returns the comment that ends with `token`, for the languages whose comments are nodes of
+    /// the syntax tree instead of trivia, such as `<!-- comment -->` in HTML.
+    ///
+    /// The analyzer parses the suppressions of these comments like the ones of the comment trivia.
+    fn comment_ending_with(
+        &self,
+        _token: &SyntaxToken<Self::Language>,
+    ) -> Option<SyntaxNode<Self::Language>> {
+        None
+    }
 }
 
 /// Convenient type to store useful information
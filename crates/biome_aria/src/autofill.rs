@@ -0,0 +1,137 @@
+use biome_aria_metadata::{
+    AUTOFILL_ADDRESS_TYPES, AUTOFILL_CONTACT_FIELD_NAMES, AUTOFILL_CONTACT_TYPES,
+    AUTOFILL_FIELD_NAMES,
+};
+use biome_string_case::StrLikeExtension;
+use std::borrow::Cow;
+
+/// Returns `true` if `tokens` are a valid value of the `autocomplete` attribute of HTML, according
+/// to the [grammar of the autofill detail tokens](https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill-detail-tokens).
+///
+/// The value is either `on` or `off` alone, or the following tokens, in this order:
+///
+/// 1. optionally, a token that starts with `section-`;
+/// 2. optionally, `shipping` or `billing`;
+/// 3. a field name such as `street-address`, or a contact field name such as `email`, optionally
+///    preceded by a contact type such as `home`;
+/// 4. optionally, `webauthn`.
+///
+/// The tokens are matched case-insensitively.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_aria::autofill::is_valid_autofill;
+///
+/// assert!(is_valid_autofill(&["off"]));
+/// assert!(is_valid_autofill(&["section-blue", "shipping", "street-address"]));
+/// assert!(is_valid_autofill(&["work", "email", "webauthn"]));
+/// assert!(!is_valid_autofill(&["home", "url"]));
+/// assert!(!is_valid_autofill(&["name", "email"]));
+/// ```
+pub fn is_valid_autofill<S: AsRef<str>>(tokens: &[S]) -> bool {
+    let tokens: Vec<_> = tokens
+        .iter()
+        .map(|token| token.as_ref().to_ascii_lowercase_cow())
+        .collect();
+    let mut tokens: &[Cow<str>] = &tokens;
+    if let [token] = tokens {
+        if token == "on" || token == "off" {
+            return true;
+        }
+    }
+    if let [rest @ .., last] = tokens {
+        if last == "webauthn" {
+            tokens = rest;
+        }
+    }
+    if let [first, rest @ ..] = tokens {
+        if first.starts_with("section-") {
+            tokens = rest;
+        }
+    }
+    if let [first, rest @ ..] = tokens {
+        if is_autofill_address_type(first) {
+            tokens = rest;
+        }
+    }
+    match tokens {
+        [field_name] => {
+            is_autofill_field_name(field_name) || is_autofill_contact_field_name(field_name)
+        }
+        [contact_type, field_name] => {
+            is_autofill_contact_type(contact_type) && is_autofill_contact_field_name(field_name)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `token` is an autofill field name that can't be preceded by a contact type,
+/// such as `street-address`
+pub fn is_autofill_field_name(token: &str) -> bool {
+    AUTOFILL_FIELD_NAMES.binary_search(&token).is_ok()
+}
+
+/// Returns `true` if `token` is an autofill field name that can be preceded by a contact type,
+/// such as `email`
+pub fn is_autofill_contact_field_name(token: &str) -> bool {
+    AUTOFILL_CONTACT_FIELD_NAMES.binary_search(&token).is_ok()
+}
+
+/// Returns `true` if `token` is a contact type, such as `home`
+pub fn is_autofill_contact_type(token: &str) -> bool {
+    AUTOFILL_CONTACT_TYPES.binary_search(&token).is_ok()
+}
+
+/// Returns `true` if `token` is an address type, such as `shipping`
+pub fn is_autofill_address_type(token: &str) -> bool {
+    AUTOFILL_ADDRESS_TYPES.binary_search(&token).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_autofill;
+
+    #[test]
+    fn valid_autofill() {
+        for value in [
+            "on",
+            "OFF",
+            "name",
+            "section-blue name",
+            "billing family-name",
+            "section-blue shipping street-address",
+            "section-somewhere shipping work email",
+            "mobile tel",
+            "tel-local-prefix",
+            "username webauthn",
+            "section-login current-password webauthn",
+        ] {
+            let tokens: Vec<_> = value.split_ascii_whitespace().collect();
+            assert!(is_valid_autofill(&tokens), "{value} should be valid");
+        }
+    }
+
+    #[test]
+    fn invalid_autofill() {
+        for value in [
+            "",
+            "foo",
+            "on name",
+            "name off",
+            "name invalid",
+            "home url",
+            "email home",
+            "shipping section-blue name",
+            "shipping billing name",
+            "name email",
+            "webauthn",
+            "webauthn name",
+            "section-a section-b name",
+            "section-",
+        ] {
+            let tokens: Vec<_> = value.split_ascii_whitespace().collect();
+            assert!(!is_valid_autofill(&tokens), "{value} should be invalid");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+pub mod autofill;
 pub mod iso;
 mod macros;
 pub mod properties;
@@ -1,4 +1,5 @@
-//! Generate ARAI metadata from `./aria-data.json`
+//! Generate ARAI metadata from `./aria-data.json`, and autofill metadata from
+//! `./autofill-data.json`
 
 use biome_string_case::Case;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
@@ -38,6 +39,16 @@ const ISO_LANGUAGES: &[&str] = &[
     "wa", "cy", "wo", "xh", "yi", "ji", "yo", "zu",
 ];
 
+/// The tokens of the `autocomplete` attribute of HTML
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Autofill {
+    field_names: BTreeSet<String>,
+    contact_field_names: BTreeSet<String>,
+    contact_types: BTreeSet<String>,
+    address_types: BTreeSet<String>,
+}
+
 #[derive(Debug, Default, serde::Deserialize)]
 struct Aria {
     roles: BTreeMap<String, AriaRole>,
@@ -229,6 +240,7 @@ fn main() -> io::Result<()> {
     // CARGO instructions: rern if one of these files change
     println!("cargo::rerun-if-changed=build.rs");
     println!("cargo::rerun-if-changed=aria-data.json");
+    println!("cargo::rerun-if-changed=autofill-data.json");
 
     let text = std::fs::read_to_string("aria-data.json")?;
     let data: Aria = serde_json::from_str(&text)?;
@@ -266,6 +278,29 @@ fn main() -> io::Result<()> {
     let iso_countries = generate_enums(ISO_COUNTRIES, "IsoCountries");
     let iso_languages = generate_enums(ISO_LANGUAGES, "IsoLanguages");
 
+    let text = std::fs::read_to_string("autofill-data.json")?;
+    let autofill: Autofill = serde_json::from_str(&text)?;
+    let autofill_field_names = generate_sorted_array(
+        &autofill.field_names,
+        "AUTOFILL_FIELD_NAMES",
+        "The autofill field names, except the contact field names",
+    );
+    let autofill_contact_field_names = generate_sorted_array(
+        &autofill.contact_field_names,
+        "AUTOFILL_CONTACT_FIELD_NAMES",
+        "The autofill field names that can be preceded by a contact type, such as `email`",
+    );
+    let autofill_contact_types = generate_sorted_array(
+        &autofill.contact_types,
+        "AUTOFILL_CONTACT_TYPES",
+        "The contact types, such as `home`",
+    );
+    let autofill_address_types = generate_sorted_array(
+        &autofill.address_types,
+        "AUTOFILL_ADDRESS_TYPES",
+        "The address types, such as `shipping`",
+    );
+
     let tokens = quote! {
         #aria_properties
         #abstract_roles
@@ -273,6 +308,10 @@ fn main() -> io::Result<()> {
         #widget_roles
         #iso_countries
         #iso_languages
+        #autofill_field_names
+        #autofill_contact_field_names
+        #autofill_contact_types
+        #autofill_address_types
     };
     let ast = tokens.to_string();
 
@@ -286,6 +325,18 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
+/// Generates a constant array of the sorted `values`, that can be searched with a binary search
+fn generate_sorted_array(values: &BTreeSet<String>, name: &str, doc: &str) -> TokenStream {
+    let name = Ident::new(name, Span::call_site());
+    let len = values.len();
+    let doc = format!(" {doc}, sorted for binary search");
+    let values = values.iter().map(|value| Literal::string(value));
+    quote! {
+        #[doc = #doc]
+        pub const #name: [&str; #len] = [#( #values ),*];
+    }
+}
+
 fn generate_enums(array: &[&str], enum_name: &str) -> TokenStream {
     let enum_name = Ident::new(enum_name, Span::call_site());
     let mut enum_metadata = Vec::with_capacity(array.len());
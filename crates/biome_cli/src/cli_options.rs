@@ -1,5 +1,5 @@
-use crate::logging::LoggingKind;
-use crate::LoggingLevel;
+use crate::logging::{EventLog, LogFileFormat, LoggingKind};
+use crate::{CliDiagnostic, LoggingLevel};
 use biome_configuration::ConfigurationPathHint;
 use biome_diagnostics::Severity;
 use bpaf::Bpaf;
@@ -27,7 +27,7 @@ pub struct CliOptions {
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
-    /// Cap the amount of diagnostics displayed. When `none` is provided, the limit is lifted.
+    /// Cap the amount of diagnostics displayed for each file, or for the whole run when `--max-diagnostics-scope=global` is provided. When the cap applies to each file, the whole run still displays at most ten times this amount. When `none` is provided, the limit is lifted.
     #[bpaf(
         long("max-diagnostics"),
         argument("none|<NUMBER>"),
@@ -36,6 +36,15 @@ pub struct CliOptions {
     )]
     pub max_diagnostics: MaxDiagnostics,
 
+    /// Set what `--max-diagnostics` caps: "file" caps the diagnostics of each file, and notes how many diagnostics of the file weren't displayed; "global" caps the diagnostics of the whole run.
+    #[bpaf(
+        long("max-diagnostics-scope"),
+        argument("file|global"),
+        fallback(MaxDiagnosticsScope::default()),
+        display_fallback
+    )]
+    pub max_diagnostics_scope: MaxDiagnosticsScope,
+
     /// Skip over files containing syntax errors instead of emitting an error diagnostic.
     #[bpaf(long("skip-errors"), switch)]
     pub skip_errors: bool,
@@ -51,7 +60,7 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|github|junit|summary|gitlab|porcelain"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -76,6 +85,19 @@ pub struct CliOptions {
     )]
     pub log_kind: LoggingKind,
 
+    /// Write the event log of the run to the file at PATH. The events report the files that start and finish processing, the diagnostics emitted, the fixes applied and the panics caught.
+    #[bpaf(long("log-file"), argument("PATH"), optional)]
+    pub log_file: Option<PathBuf>,
+
+    /// The format of the event log written with `--log-file`: "jsonl" writes each event as a JSON object on its own line.
+    #[bpaf(
+        long("log-format"),
+        argument("jsonl"),
+        fallback(LogFileFormat::default()),
+        display_fallback
+    )]
+    pub log_format: LogFileFormat,
+
     #[bpaf(
         long("diagnostic-level"),
         argument("info|warn|error"),
@@ -94,6 +116,15 @@ impl CliOptions {
             Some(path) => ConfigurationPathHint::FromUser(PathBuf::from(path)),
         }
     }
+
+    /// Creates the event log of `--log-file`, when the option is passed
+    pub(crate) fn event_log(&self) -> Result<Option<EventLog>, CliDiagnostic> {
+        self.log_file
+            .as_deref()
+            .map(|path| EventLog::create(path, self.log_format))
+            .transpose()
+            .map_err(CliDiagnostic::io_error)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -133,6 +164,8 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports the status of each file and the summary using a stable, line-oriented format meant for scripts.
+    Porcelain,
 }
 
 impl CliReporter {
@@ -152,6 +185,7 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "porcelain" => Ok(Self::Porcelain),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -169,6 +203,7 @@ impl Display for CliReporter {
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Porcelain => f.write_str("porcelain"),
         }
     }
 }
@@ -223,6 +258,43 @@ impl FromStr for MaxDiagnostics {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum MaxDiagnosticsScope {
+    /// `--max-diagnostics` caps the diagnostics of each file
+    #[default]
+    File,
+    /// `--max-diagnostics` caps the diagnostics of the whole run
+    Global,
+}
+
+impl MaxDiagnosticsScope {
+    pub const fn is_file(&self) -> bool {
+        matches!(self, Self::File)
+    }
+}
+
+impl Display for MaxDiagnosticsScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxDiagnosticsScope::File => f.write_str("file"),
+            MaxDiagnosticsScope::Global => f.write_str("global"),
+        }
+    }
+}
+
+impl FromStr for MaxDiagnosticsScope {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Self::File),
+            "global" => Ok(Self::Global),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --max-diagnostics-scope argument"
+            )),
+        }
+    }
+}
+
 impl From<MaxDiagnostics> for u64 {
     fn from(value: MaxDiagnostics) -> Self {
         match value {
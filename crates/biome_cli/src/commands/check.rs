@@ -1,14 +1,17 @@
 use super::{determine_fix_file_mode, FixFileModeOptions, LoadEditorConfig};
 use crate::cli_options::CliOptions;
 use crate::commands::{get_files_to_process_with_cli_options, CommandRunner};
-use crate::{CliDiagnostic, Execution, TraversalMode};
+use crate::diagnostics::ReportDiagnostic;
+use crate::{CliDiagnostic, CliSession, Execution, TraversalMode};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
+use biome_configuration::schema::configuration_schema;
 use biome_configuration::{
     organize_imports::PartialOrganizeImports, PartialConfiguration, PartialFormatterConfiguration,
     PartialLinterConfiguration,
 };
-use biome_console::Console;
+use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::Merge;
+use biome_diagnostics::adapters::SerdeJsonError;
 use biome_fs::FileSystem;
 use biome_service::{configuration::LoadedConfiguration, DynRef, Workspace, WorkspaceError};
 use std::ffi::OsString;
@@ -31,6 +34,18 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) since: Option<String>,
 }
 
+/// Handler of `biome check --json-schema`: prints the JSON schema of the configuration file
+/// supported by this version of Biome.
+pub(crate) fn print_json_schema(session: CliSession) -> Result<(), CliDiagnostic> {
+    let content = serde_json::to_string_pretty(&configuration_schema()).map_err(|error| {
+        CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+    })?;
+    session.app.console.log(markup! {
+        {content}
+    });
+    Ok(())
+}
+
 impl LoadEditorConfig for CheckCommandPayload {
     fn should_load_editor_config(&self, fs_configuration: &PartialConfiguration) -> bool {
         self.configuration
@@ -1,11 +1,17 @@
 use crate::{
+    diagnostics::ReportDiagnostic,
+    logging::{EventLog, EventLogFilter, EventSource, LogFileFormat},
     open_transport,
     service::{self, ensure_daemon, open_socket, run_daemon},
     CliDiagnostic, CliSession,
 };
 use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::adapters::SerdeJsonError;
 use biome_lsp::ServerFactory;
-use biome_service::{workspace::WorkspaceClient, TransportError, WorkspaceError};
+use biome_service::{
+    workspace::{MetricsParams, WorkspaceClient},
+    TransportError, Workspace, WorkspaceError,
+};
 use std::{env, fs, path::PathBuf};
 use tokio::io;
 use tokio::runtime::Runtime;
@@ -70,6 +76,34 @@ pub(crate) fn stop(session: CliSession) -> Result<(), CliDiagnostic> {
     Ok(())
 }
 
+pub(crate) fn status(session: CliSession, metrics: bool) -> Result<(), CliDiagnostic> {
+    let rt = Runtime::new()?;
+
+    let Some(transport) = open_transport(rt)? else {
+        session.app.console.log(markup! {
+            "The Biome server is not running"
+        });
+        return Ok(());
+    };
+
+    if metrics {
+        let client = WorkspaceClient::new(transport)?;
+        let result = client.metrics(MetricsParams {})?;
+        let content = serde_json::to_string_pretty(&result).map_err(|error| {
+            CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+        })?;
+        session.app.console.log(markup! {
+            {content}
+        });
+    } else {
+        session.app.console.log(markup! {
+            "The Biome server is running"
+        });
+    }
+
+    Ok(())
+}
+
 pub(crate) fn run_server(
     stop_on_disconnect: bool,
     config_path: Option<PathBuf>,
@@ -212,8 +246,15 @@ fn setup_tracing_subscriber(log_path: Option<PathBuf>, log_file_name_prefix: Opt
         .filename_prefix(log_file_name_prefix.unwrap_or(String::from("server.log")))
         .max_log_files(7)
         .rotation(Rotation::HOURLY)
-        .build(biome_log_path)
+        .build(&biome_log_path)
         .expect("Failed to start the logger for the daemon.");
+    let events_appender = tracing_appender::rolling::RollingFileAppender::builder()
+        .filename_prefix("server.events")
+        .filename_suffix("jsonl")
+        .max_log_files(7)
+        .rotation(Rotation::HOURLY)
+        .build(biome_log_path)
+        .expect("Failed to start the event log for the daemon.");
 
     registry()
         .with(
@@ -226,6 +267,10 @@ fn setup_tracing_subscriber(log_path: Option<PathBuf>, log_file_name_prefix: Opt
                 .with_writer(file_appender)
                 .with_filter(LoggingFilter),
         )
+        .with(
+            EventLog::new(EventSource::Daemon, LogFileFormat::Jsonl, events_appender)
+                .with_filter(EventLogFilter),
+        )
         .init();
 }
 
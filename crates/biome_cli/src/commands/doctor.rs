@@ -0,0 +1,321 @@
+use biome_configuration::{ConfigurationPathHint, PartialConfiguration, VERSION};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, ConsoleExt, KeyValuePair};
+use biome_deserialize::StringSet;
+use biome_diagnostics::{PrintDescription, Severity};
+use biome_fs::FileSystem;
+use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_service::DynRef;
+use std::io;
+
+use crate::commands::rage::Section;
+use crate::execute::migrate::{find_eslint_config_file, find_prettier_config_file};
+use crate::service::enumerate_pipes;
+use crate::{CliDiagnostic, CliSession};
+
+/// Handler for the `doctor` command
+pub(crate) fn doctor(session: CliSession) -> Result<(), CliDiagnostic> {
+    let fs = &session.app.fs;
+    let mut findings = Vec::new();
+
+    let loaded_configuration = match load_configuration(fs, ConfigurationPathHint::default()) {
+        Ok(loaded_configuration) => {
+            check_configuration(fs, &loaded_configuration, &mut findings);
+            Some(loaded_configuration)
+        }
+        Err(err) => {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!("The configuration file can't be loaded: {err}"),
+                hint: "Fix the configuration file, or remove it to use the default configuration."
+                    .to_string(),
+            });
+            None
+        }
+    };
+
+    check_daemon_versions(&session, &mut findings);
+
+    session.app.console.log(markup!("Configuration:\n"
+    {ConfigurationStatus(loaded_configuration.as_ref())}
+
+    {Section("Findings")}
+    {Findings(&findings)}
+    ));
+
+    Ok(())
+}
+
+/// A problem of the environment, along with the action that solves it
+struct Finding {
+    severity: Severity,
+    message: String,
+    hint: String,
+}
+
+impl Display for Finding {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let message = self.message.as_str();
+        match self.severity {
+            Severity::Error | Severity::Fatal => {
+                fmt.write_markup(markup!("  "<Error>"\u{2716} "{message}</Error>))?
+            }
+            Severity::Warning => {
+                fmt.write_markup(markup!("  "<Warn>"\u{26a0} "{message}</Warn>))?
+            }
+            Severity::Hint | Severity::Information => {
+                fmt.write_markup(markup!("  "<Info>"\u{2139} "{message}</Info>))?
+            }
+        }
+        let hint = self.hint.as_str();
+        fmt.write_markup(markup!("\n    "<Dim>{hint}</Dim>"\n"))
+    }
+}
+
+struct Findings<'a>(&'a [Finding]);
+
+impl Display for Findings<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        if self.0.is_empty() {
+            return fmt.write_markup(markup!("  "<Success>"\u{2714} No problems found."</Success>));
+        }
+
+        for finding in self.0 {
+            finding.fmt(fmt)?;
+        }
+
+        let count = self.0.len();
+        fmt.write_markup(markup!("\n"<Emphasis>"Found "{count}" problem(s)."</Emphasis>))
+    }
+}
+
+struct ConfigurationStatus<'a>(Option<&'a LoadedConfiguration>);
+
+impl Display for ConfigurationStatus<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Some(loaded_configuration) = self.0 else {
+            return KeyValuePair("Status", markup!(<Error>"Failed to load"</Error>)).fmt(fmt);
+        };
+
+        match loaded_configuration.file_path() {
+            Some(file_path) => {
+                let file_path = file_path.display().to_string();
+                markup!(
+                    {KeyValuePair("Status", markup!(<Dim>"Loaded"</Dim>))}
+                    {KeyValuePair("Path", markup!({file_path}))}
+                )
+                .fmt(fmt)
+            }
+            None => KeyValuePair("Status", markup!(<Dim>"unset"</Dim>)).fmt(fmt),
+        }
+    }
+}
+
+/// Checks the configuration file and the files of the other tools next to it.
+fn check_configuration(
+    fs: &DynRef<'_, dyn FileSystem>,
+    loaded_configuration: &LoadedConfiguration,
+    findings: &mut Vec<Finding>,
+) {
+    for diagnostic in &loaded_configuration.diagnostics {
+        findings.push(Finding {
+            severity: diagnostic.severity(),
+            message: format!(
+                "The configuration file has a problem: {}",
+                PrintDescription(diagnostic)
+            ),
+            hint: "Fix the configuration file. Biome ignores the invalid options.".to_string(),
+        });
+    }
+
+    let configuration = &loaded_configuration.configuration;
+    let Some(directory_path) = loaded_configuration.directory_path() else {
+        findings.push(Finding {
+            severity: Severity::Information,
+            message: "No configuration file was found, Biome uses its default configuration."
+                .to_string(),
+            hint: "Run `biome init` to create a configuration file.".to_string(),
+        });
+        check_other_tools(fs, configuration, findings);
+        return;
+    };
+
+    if let Some(working_directory) = fs.working_directory() {
+        if working_directory != directory_path {
+            findings.push(Finding {
+                severity: Severity::Information,
+                message: format!(
+                    "The configuration file of the parent directory {} is used, because the working directory doesn't have one.",
+                    directory_path.display()
+                ),
+                hint: "Biome only uses a single configuration file. The configuration files of the nested directories are ignored, so run Biome from the root of the project, or use `extends` to share the configuration.".to_string(),
+            });
+        }
+    }
+
+    if let Some(schema) = configuration.schema.as_deref() {
+        check_schema_version(schema, findings);
+    }
+    check_ignored_includes(configuration, findings);
+    check_other_tools(fs, configuration, findings);
+}
+
+/// Checks that the `$schema` of the configuration matches the version of the CLI.
+fn check_schema_version(schema: &str, findings: &mut Vec<Finding>) {
+    let Some(schema_version) = schema
+        .split_once("/schemas/")
+        .and_then(|(_, rest)| rest.split_once('/'))
+        .map(|(version, _)| version)
+    else {
+        return;
+    };
+    if schema_version != VERSION {
+        findings.push(Finding {
+            severity: Severity::Warning,
+            message: format!(
+                "The configuration file uses the schema of Biome {schema_version}, but the CLI is Biome {VERSION}."
+            ),
+            hint: "Run `biome migrate --write` to update the configuration file.".to_string(),
+        });
+    }
+}
+
+/// Checks for the patterns that are included by a tool, but ignored by the same tool or by
+/// `files.ignore`, so the matching files are never processed.
+fn check_ignored_includes(configuration: &PartialConfiguration, findings: &mut Vec<Finding>) {
+    let files_ignore = configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.ignore.as_ref());
+    let sections: [(&str, Option<&StringSet>, Option<&StringSet>); 4] = [
+        (
+            "files",
+            configuration
+                .files
+                .as_ref()
+                .and_then(|files| files.include.as_ref()),
+            None,
+        ),
+        (
+            "formatter",
+            configuration
+                .formatter
+                .as_ref()
+                .and_then(|formatter| formatter.include.as_ref()),
+            configuration
+                .formatter
+                .as_ref()
+                .and_then(|formatter| formatter.ignore.as_ref()),
+        ),
+        (
+            "linter",
+            configuration
+                .linter
+                .as_ref()
+                .and_then(|linter| linter.include.as_ref()),
+            configuration
+                .linter
+                .as_ref()
+                .and_then(|linter| linter.ignore.as_ref()),
+        ),
+        (
+            "organizeImports",
+            configuration
+                .organize_imports
+                .as_ref()
+                .and_then(|organize_imports| organize_imports.include.as_ref()),
+            configuration
+                .organize_imports
+                .as_ref()
+                .and_then(|organize_imports| organize_imports.ignore.as_ref()),
+        ),
+    ];
+    for (section, include, ignore) in sections {
+        for pattern in include.into_iter().flat_map(|include| include.iter()) {
+            let ignored_by = if ignore.is_some_and(|ignore| ignore.contains(pattern)) {
+                format!("{section}.ignore")
+            } else if files_ignore.is_some_and(|ignore| ignore.contains(pattern)) {
+                "files.ignore".to_string()
+            } else {
+                continue;
+            };
+            findings.push(Finding {
+                severity: Severity::Warning,
+                message: format!(
+                    "The pattern `{pattern}` is included by `{section}.include`, but ignored by `{ignored_by}`."
+                ),
+                hint: format!(
+                    "The ignored files are never processed. Remove the pattern from `{section}.include` or from `{ignored_by}`."
+                ),
+            });
+        }
+    }
+}
+
+/// Checks for the configuration files of Prettier and ESLint, which conflict with the formatter
+/// and the linter of Biome when they're enabled.
+fn check_other_tools(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+    findings: &mut Vec<Finding>,
+) {
+    if !configuration.is_formatter_disabled() {
+        if let Some(config_file) = find_prettier_config_file(fs) {
+            findings.push(Finding {
+                severity: Severity::Warning,
+                message: format!(
+                    "The Prettier configuration file {config_file} is present, and the Biome formatter is enabled."
+                ),
+                hint: "Both tools format the same files differently. Run `biome migrate prettier --write` to move the options of Prettier to Biome, then remove Prettier.".to_string(),
+            });
+        }
+    }
+    if !configuration.is_linter_disabled() {
+        if let Some(config_file) = find_eslint_config_file(fs) {
+            let config_file = config_file.strip_prefix("./").unwrap_or(config_file);
+            findings.push(Finding {
+                severity: Severity::Warning,
+                message: format!(
+                    "The ESLint configuration file {config_file} is present, and the Biome linter is enabled."
+                ),
+                hint: "Both tools report the same problems. Run `biome migrate eslint --write` to move the rules of ESLint to Biome, then remove the rules that Biome implements from ESLint.".to_string(),
+            });
+        }
+    }
+}
+
+/// Checks that the running daemon servers have the version of the CLI.
+fn check_daemon_versions(session: &CliSession, findings: &mut Vec<Finding>) {
+    if let Some(server_info) = session.app.workspace.server_info() {
+        let server_version = server_info.version.as_deref().unwrap_or("unknown");
+        if server_version != VERSION {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!(
+                    "The CLI is connected to a daemon server of Biome {server_version}, but the CLI is Biome {VERSION}."
+                ),
+                hint: "Stop the server with `biome stop`, and start it again with the same version as the CLI.".to_string(),
+            });
+        }
+        return;
+    }
+    let Ok(versions) = enumerate_pipes() else {
+        return;
+    };
+    for version in versions {
+        if version != VERSION {
+            let version = if version.is_empty() {
+                "unknown".to_string()
+            } else {
+                version
+            };
+            findings.push(Finding {
+                severity: Severity::Warning,
+                message: format!(
+                    "A daemon server of Biome {version} is running, but the CLI is Biome {VERSION}."
+                ),
+                hint: "The editors may use this server instead of the version of the project. Stop it with the `biome stop` command of its version, and restart the Biome extension of the editor.".to_string(),
+            });
+        }
+    }
+}
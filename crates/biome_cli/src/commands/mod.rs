@@ -28,7 +28,9 @@ use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
 use biome_service::documentation::Doc;
-use biome_service::workspace::{FixFileMode, RegisterProjectFolderParams, UpdateSettingsParams};
+use biome_service::workspace::{
+    FixFileMode, RegisterProjectFolderParams, SetManifestForProjectParams, UpdateSettingsParams,
+};
 use biome_service::{DynRef, Workspace, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
@@ -38,12 +40,14 @@ pub(crate) mod check;
 pub(crate) mod ci;
 pub(crate) mod clean;
 pub(crate) mod daemon;
+pub(crate) mod doctor;
 pub(crate) mod explain;
 pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod rename_token;
 pub(crate) mod search;
 pub(crate) mod version;
 
@@ -69,6 +73,10 @@ pub enum BiomeCommand {
         #[bpaf(long("linter"), switch)]
         bool,
     ),
+
+    #[bpaf(command)]
+    /// Checks the project for common misconfigurations.
+    Doctor(#[bpaf(external(cli_options), hide_usage)] CliOptions),
     /// Starts the Biome daemon server process.
     #[bpaf(command)]
     Start {
@@ -102,6 +110,10 @@ pub enum BiomeCommand {
     #[bpaf(command)]
     Stop,
 
+    /// Inspects the Biome daemon server process.
+    #[bpaf(command)]
+    Daemon(#[bpaf(external(daemon_sub_command))] DaemonSubCommand),
+
     /// Runs formatter, linter and import sorting to the requested files.
     #[bpaf(command)]
     Check {
@@ -176,6 +188,10 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Prints the JSON schema of the configuration file of this version of Biome, and exits.
+        #[bpaf(long("json-schema"), switch)]
+        json_schema: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -468,6 +484,39 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Renames a CSS custom property or a CSS class in the files of the project.
+    ///
+    /// The stylesheets are renamed, and, for a class, the `class` and `className` attributes of
+    /// the JSX elements. Without `--write`, the command only prints the files that would change.
+    ///
+    /// ## Examples
+    ///
+    /// ```shell
+    /// biome rename-token --write -- --color-primary --color-brand
+    /// ```
+    ///
+    /// ```shell
+    /// biome rename-token --write .button .btn
+    /// ```
+    #[bpaf(command("rename-token"))]
+    RenameToken {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Writes the renamed files to disk
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// The name of the custom property, such as `--color-primary`, or of the class, such
+        /// as `.button`.
+        #[bpaf(positional("NAME"))]
+        name: String,
+
+        /// The new name.
+        #[bpaf(positional("NEW_NAME"))]
+        new_name: String,
+    },
+
     /// Shows documentation of various aspects of the CLI.
     ///
     /// ## Examples
@@ -540,6 +589,19 @@ pub enum MigrateSubCommand {
     },
 }
 
+#[derive(Debug, Bpaf, Clone)]
+pub enum DaemonSubCommand {
+    /// Prints whether the Biome daemon server is running.
+    #[bpaf(command)]
+    Status {
+        /// Prints the metrics collected by the daemon server as JSON: the number of requests,
+        /// the hit rate of the cache of syntax trees, the slowest files, and the memory used.
+        /// The metrics never leave the machine.
+        #[bpaf(long("metrics"), switch)]
+        metrics: bool,
+    },
+}
+
 impl MigrateSubCommand {
     pub const fn is_prettier(&self) -> bool {
         matches!(self, MigrateSubCommand::Prettier)
@@ -551,15 +613,18 @@ impl BiomeCommand {
         match self {
             BiomeCommand::Version(cli_options)
             | BiomeCommand::Rage(cli_options, ..)
+            | BiomeCommand::Doctor(cli_options)
             | BiomeCommand::Check { cli_options, .. }
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. }
-            | BiomeCommand::Search { cli_options, .. } => Some(cli_options),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::RenameToken { cli_options, .. } => Some(cli_options),
             BiomeCommand::LspProxy { .. }
             | BiomeCommand::Start { .. }
             | BiomeCommand::Stop
+            | BiomeCommand::Daemon(_)
             | BiomeCommand::Init(_)
             | BiomeCommand::Explain { .. }
             | BiomeCommand::RunServer { .. }
@@ -816,7 +881,11 @@ pub(crate) trait CommandRunner: Sized {
 
     /// The main command to use.
     fn run(&mut self, session: CliSession, cli_options: &CliOptions) -> Result<(), CliDiagnostic> {
-        setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+        setup_cli_subscriber(
+            cli_options.log_level,
+            cli_options.log_kind,
+            cli_options.event_log()?,
+        );
         let fs = &session.app.fs;
         let console = &mut *session.app.console;
         let workspace = &*session.app.workspace;
@@ -854,24 +923,31 @@ pub(crate) trait CommandRunner: Sized {
         let (vcs_base_path, gitignore_matches) =
             configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
         let paths = self.get_files_to_process(fs, &configuration)?;
-        workspace.register_project_folder(RegisterProjectFolderParams {
+        let project_key = workspace.register_project_folder(RegisterProjectFolderParams {
             path: fs.working_directory(),
-            set_as_current_workspace: true,
         })?;
 
         let manifest_data = resolve_manifest(fs)?;
 
-        if let Some(manifest_data) = manifest_data {
-            workspace.set_manifest_for_project(manifest_data.into())?;
+        if let Some((manifest_path, content)) = manifest_data {
+            workspace.set_manifest_for_project(SetManifestForProjectParams {
+                project_key,
+                manifest_path,
+                content,
+                version: 0,
+            })?;
         }
         workspace.update_settings(UpdateSettingsParams {
+            project_key,
             workspace_directory: fs.working_directory(),
             configuration,
             vcs_base_path,
             gitignore_matches,
         })?;
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let execution = self
+            .get_execution(cli_options, console, workspace)?
+            .set_project_key(project_key);
         Ok((execution, paths))
     }
 
@@ -222,6 +222,7 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Indent width", markup!({DebugDisplay(formatter_configuration.indent_width)}))}
                             {KeyValuePair("Line ending", markup!({DebugDisplay(formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplay(formatter_configuration.line_width.value())}))}
+                            {KeyValuePair("Max empty lines", markup!({DebugDisplay(formatter_configuration.max_empty_lines)}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplay(formatter_configuration.attribute_position)}))}
                             {KeyValuePair("Bracket spacing", markup!({DebugDisplay(formatter_configuration.bracket_spacing)}))}
                             {KeyValuePair("Ignore", markup!({DebugDisplay(formatter_configuration.ignore.iter().collect::<Vec<_>>())}))}
@@ -245,7 +246,19 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Indent width", markup!({DebugDisplayOption(javascript_formatter_configuration.indent_width)}))}
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(javascript_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(javascript_formatter_configuration.line_width.map(|lw| lw.value()))}))}
+                            {KeyValuePair("Max empty lines", markup!({DebugDisplayOption(javascript_formatter_configuration.max_empty_lines)}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplayOption(javascript_formatter_configuration.attribute_position)}))}
+                            {KeyValuePair("JSX max inline attributes", markup!({DebugDisplayOption(javascript_formatter_configuration.jsx_max_inline_attributes)}))}
+                            {KeyValuePair("Decorator placement", markup!({DebugDisplay(javascript_formatter_configuration.decorator_placement)}))}
+                            {KeyValuePair("Parameter decorators", markup!({DebugDisplay(javascript_formatter_configuration.parameter_decorators)}))}
+                            {KeyValuePair("Object wrap", markup!({DebugDisplay(javascript_formatter_configuration.object_wrap)}))}
+                            {KeyValuePair("Operator linebreak", markup!({DebugDisplay(javascript_formatter_configuration.operator_linebreak)}))}
+                            {KeyValuePair("Ternary style", markup!({DebugDisplay(javascript_formatter_configuration.ternary_style)}))}
+                            {KeyValuePair("Import export wrap", markup!({DebugDisplay(javascript_formatter_configuration.import_export_wrap)}))}
+                            {KeyValuePair("Max inline specifiers", markup!({DebugDisplayOption(javascript_formatter_configuration.max_inline_specifiers)}))}
+                            {KeyValuePair("Comment reflow", markup!({DebugDisplay(javascript_formatter_configuration.comment_reflow)}))}
+                            {KeyValuePair("JSX children whitespace", markup!({DebugDisplay(javascript_formatter_configuration.jsx_children_whitespace)}))}
+                            {KeyValuePair("Single attribute per line", markup!({DebugDisplay(javascript_formatter_configuration.single_attribute_per_line)}))}
                         )
                         .fmt(fmt)?;
 
@@ -258,6 +271,7 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Indent width", markup!({DebugDisplayOption(json_formatter_configuration.indent_width)}))}
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(json_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(json_formatter_configuration.line_width.map(|lw| lw.value()))}))}
+                            {KeyValuePair("Max empty lines", markup!({DebugDisplayOption(json_formatter_configuration.max_empty_lines)}))}
                             {KeyValuePair("Trailing Commas", markup!({DebugDisplayOption(json_formatter_configuration.trailing_commas)}))}
                         ).fmt(fmt)?;
 
@@ -270,6 +284,7 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Indent width", markup!({DebugDisplayOption(css_formatter_configuration.indent_width)}))}
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(css_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(css_formatter_configuration.line_width)}))}
+                            {KeyValuePair("Max empty lines", markup!({DebugDisplayOption(css_formatter_configuration.max_empty_lines)}))}
                             {KeyValuePair("Quote style", markup!({DebugDisplay(css_formatter_configuration.quote_style)}))}
                         ).fmt(fmt)?;
 
@@ -349,7 +364,7 @@ impl fmt::Display for EnvVarOs {
     }
 }
 
-struct Section<'a>(&'a str);
+pub(crate) struct Section<'a>(pub(crate) &'a str);
 
 impl Display for Section<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
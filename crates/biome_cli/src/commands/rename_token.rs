@@ -0,0 +1,83 @@
+use crate::cli_options::CliOptions;
+use crate::commands::validate_configuration_diagnostics;
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_fs::OpenOptions;
+use biome_service::configuration::{load_configuration, PartialConfigurationExt};
+use biome_service::workspace::{
+    RegisterProjectFolderParams, RenameTokenParams, UpdateSettingsParams,
+};
+
+/// Handler for the `rename-token` command: renames a CSS custom property or a CSS class in the
+/// files of the project
+pub(crate) fn rename_token(
+    session: CliSession,
+    cli_options: &CliOptions,
+    name: String,
+    new_name: String,
+    write: bool,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(
+        cli_options.log_level,
+        cli_options.log_kind,
+        cli_options.event_log()?,
+    );
+    let fs = &session.app.fs;
+    let console = &mut *session.app.console;
+    let workspace = &*session.app.workspace;
+
+    let loaded_configuration = load_configuration(fs, cli_options.as_configuration_path_hint())?;
+    validate_configuration_diagnostics(&loaded_configuration, console, cli_options.verbose)?;
+    let vcs_base_path = loaded_configuration
+        .directory_path
+        .clone()
+        .or(fs.working_directory());
+    let configuration = loaded_configuration.configuration;
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
+    let project_key = workspace.register_project_folder(RegisterProjectFolderParams {
+        path: fs.working_directory(),
+    })?;
+    workspace.update_settings(UpdateSettingsParams {
+        project_key,
+        workspace_directory: fs.working_directory(),
+        configuration,
+        vcs_base_path,
+        gitignore_matches,
+    })?;
+
+    let result = workspace.rename_token(RenameTokenParams {
+        project_key,
+        name: name.clone(),
+        new_name: new_name.clone(),
+    })?;
+
+    for renamed_file in &result.files {
+        let path = renamed_file.path.as_path();
+        let mut file = fs.open_with_options(path, OpenOptions::default().read(true).write(true))?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        if write {
+            file.set_content(renamed_file.indels.new_string(&content).as_bytes())?;
+        }
+        console.log(markup! {
+            {path.display().to_string()}
+        });
+    }
+
+    let count = result.files.len();
+    if count == 0 {
+        console.log(markup! {
+            <Info>"No file uses "<Emphasis>{name}</Emphasis>"."</Info>
+        });
+    } else if write {
+        console.log(markup! {
+            <Info>"Renamed "<Emphasis>{name}</Emphasis>" to "<Emphasis>{new_name}</Emphasis>" in "{count}" file(s)."</Info>
+        });
+    } else {
+        console.log(markup! {
+            <Info>"Renaming "<Emphasis>{name}</Emphasis>" to "<Emphasis>{new_name}</Emphasis>" would change "{count}" file(s). Use the "<Emphasis>"--write"</Emphasis>" flag to write the changes."</Info>
+        });
+    }
+    Ok(())
+}
@@ -131,6 +131,28 @@ pub(crate) struct SkippedDiagnostic;
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "maxDiagnostics", severity = Information)]
+pub(crate) struct TruncatedDiagnostics {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[message]
+    #[description]
+    pub(crate) message: String,
+}
+
+impl TruncatedDiagnostics {
+    pub(crate) fn new(file_name: String, not_printed: u32) -> Self {
+        let issues = if not_printed == 1 { "issue" } else { "issues" };
+        Self {
+            file_name,
+            message: format!(
+                "{not_printed} more {issues} in this file. Use --max-diagnostics to raise the limit of diagnostics displayed for each file."
+            ),
+        }
+    }
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
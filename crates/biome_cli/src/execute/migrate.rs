@@ -14,7 +14,9 @@ use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::{JsonFileSource, JsonRoot};
 use biome_migrate::{migrate_configuration, ControlFlow};
 use biome_rowan::{AstNode, NodeCache};
-use biome_service::workspace::{ChangeFileParams, FixAction, FormatFileParams, OpenFileParams};
+use biome_service::workspace::{
+    ChangeFileParams, FixAction, FormatFileParams, OpenFileParams, ProjectKey,
+};
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::PathBuf;
@@ -30,8 +32,12 @@ mod ignorefile;
 mod node;
 mod prettier;
 
+pub(crate) use eslint::find_config_file as find_eslint_config_file;
+pub(crate) use prettier::find_config_file as find_prettier_config_file;
+
 pub(crate) struct MigratePayload<'a> {
     pub(crate) session: CliSession<'a>,
+    pub(crate) project_key: ProjectKey,
     pub(crate) write: bool,
     pub(crate) configuration_file_path: PathBuf,
     pub(crate) configuration_directory_path: PathBuf,
@@ -42,6 +48,7 @@ pub(crate) struct MigratePayload<'a> {
 pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic> {
     let MigratePayload {
         session,
+        project_key,
         write,
         configuration_file_path,
         configuration_directory_path,
@@ -65,6 +72,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
 
     let biome_path = BiomePath::new(configuration_file_path.as_path());
     workspace.open_file(OpenFileParams {
+        project_key,
         path: biome_path.clone(),
         content: biome_config_content.to_string(),
         version: 0,
@@ -107,9 +115,35 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                         .get_or_insert(Default::default())
                         .extend(ignore_patterns.patterns);
                 }
-                if ignore_patterns.has_negated_patterns {
+                if !ignore_patterns.included_patterns.is_empty() {
+                    biome_config
+                        .formatter
+                        .get_or_insert(Default::default())
+                        .include
+                        .get_or_insert(Default::default())
+                        .extend(ignore_patterns.included_patterns);
+                }
+                if !ignore_patterns.rewritten_patterns.is_empty() {
+                    let rewritten_patterns = ignore_patterns
+                        .rewritten_patterns
+                        .iter()
+                        .map(|(pattern, included)| format!("{pattern} → {included}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
                     console.log(markup! {
-                        <Warn><Emphasis>{prettier::IGNORE_FILE}</Emphasis>" contains negated glob patterns that start with "<Emphasis>"!"</Emphasis>".\nThese patterns cannot be migrated because Biome doesn't support them."</Warn>
+                        <Info><Emphasis>{prettier::IGNORE_FILE}</Emphasis>" contains negated glob patterns that are migrated to "<Emphasis>"formatter.include"</Emphasis>": "<Emphasis>{rewritten_patterns}</Emphasis>"."</Info>
+                    });
+                }
+                if !ignore_patterns.dropped_patterns.is_empty() {
+                    let dropped_patterns = ignore_patterns.dropped_patterns.join(", ");
+                    console.log(markup! {
+                        <Info><Emphasis>{prettier::IGNORE_FILE}</Emphasis>" contains glob patterns that are dropped: "<Emphasis>{dropped_patterns}</Emphasis>".\nThe patterns that ignore all the files are replaced by "<Emphasis>"formatter.include"</Emphasis>", and the negated patterns followed by another pattern that ignores all the files have no effect."</Info>
+                    });
+                }
+                if !ignore_patterns.negated_patterns.is_empty() {
+                    let negated_patterns = ignore_patterns.negated_patterns.join(", ");
+                    console.log(markup! {
+                        <Warn><Emphasis>{prettier::IGNORE_FILE}</Emphasis>" contains negated glob patterns that cannot be migrated: "<Emphasis>{negated_patterns}</Emphasis>".\nBiome only supports the negated patterns that follow a pattern that ignores all the files, such as "<Emphasis>"/*"</Emphasis>"."</Warn>
                     })
                 } else if write && biome_config != old_biome_config {
                     console.log(markup!{
@@ -128,11 +162,15 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     })
                 })?;
                 workspace.change_file(ChangeFileParams {
+                    project_key,
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
                 })?;
-                let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
+                let printed = workspace.format_file(FormatFileParams {
+                    project_key,
+                    path: biome_path,
+                })?;
                 if write {
                     biome_config_file.set_content(printed.as_code().as_bytes())?;
                     console.log(markup!{
@@ -184,9 +222,35 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                         .get_or_insert(Default::default())
                         .extend(ignore_patterns.patterns);
                 }
-                if ignore_patterns.has_negated_patterns {
+                if !ignore_patterns.included_patterns.is_empty() {
+                    biome_config
+                        .linter
+                        .get_or_insert(Default::default())
+                        .include
+                        .get_or_insert(Default::default())
+                        .extend(ignore_patterns.included_patterns);
+                }
+                if !ignore_patterns.rewritten_patterns.is_empty() {
+                    let rewritten_patterns = ignore_patterns
+                        .rewritten_patterns
+                        .iter()
+                        .map(|(pattern, included)| format!("{pattern} → {included}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    console.log(markup! {
+                        <Info><Emphasis>{eslint::IGNORE_FILE}</Emphasis>" contains negated glob patterns that are migrated to "<Emphasis>"linter.include"</Emphasis>": "<Emphasis>{rewritten_patterns}</Emphasis>"."</Info>
+                    });
+                }
+                if !ignore_patterns.dropped_patterns.is_empty() {
+                    let dropped_patterns = ignore_patterns.dropped_patterns.join(", ");
+                    console.log(markup! {
+                        <Info><Emphasis>{eslint::IGNORE_FILE}</Emphasis>" contains glob patterns that are dropped: "<Emphasis>{dropped_patterns}</Emphasis>".\nThe patterns that ignore all the files are replaced by "<Emphasis>"linter.include"</Emphasis>", and the negated patterns followed by another pattern that ignores all the files have no effect."</Info>
+                    });
+                }
+                if !ignore_patterns.negated_patterns.is_empty() {
+                    let negated_patterns = ignore_patterns.negated_patterns.join(", ");
                     console.log(markup! {
-                        <Warn><Emphasis>{eslint::IGNORE_FILE}</Emphasis>" contains negated glob patterns that start with "<Emphasis>"!"</Emphasis>".\nThese patterns cannot be migrated because Biome doesn't support them."</Warn>
+                        <Warn><Emphasis>{eslint::IGNORE_FILE}</Emphasis>" contains negated glob patterns that cannot be migrated: "<Emphasis>{negated_patterns}</Emphasis>".\nBiome only supports the negated patterns that follow a pattern that ignores all the files, such as "<Emphasis>"/*"</Emphasis>"."</Warn>
                     })
                 } else if write && biome_config != old_biome_config {
                     console.log(markup!{
@@ -205,11 +269,15 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
                     })
                 })?;
                 workspace.change_file(ChangeFileParams {
+                    project_key,
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
                 })?;
-                let printed = workspace.format_file(FormatFileParams { path: biome_path })?;
+                let printed = workspace.format_file(FormatFileParams {
+                    project_key,
+                    path: biome_path,
+                })?;
                 if write {
                     biome_config_file.set_content(printed.as_code().as_bytes())?;
                     console.log(markup!{
@@ -59,6 +59,17 @@ const PACKAGE_JSON: &str = "package.json";
 /// ESLint Ignore file. Use the same syntax as gitignore.
 pub(crate) const IGNORE_FILE: &str = ".eslintignore";
 
+/// Returns the ESLint configuration file of the working directory with the highest priority,
+/// flat or legacy.
+///
+/// Unlike [read_eslint_config], it doesn't look for a configuration embedded in `package.json`.
+pub(crate) fn find_config_file(fs: &DynRef<'_, dyn FileSystem>) -> Option<&'static str> {
+    FLAT_CONFIG_FILES
+        .into_iter()
+        .chain(LEGACY_CONFIG_FILES)
+        .find(|config_path_str| fs.path_exists(Path::new(config_path_str)))
+}
+
 /// Returns the ESLint configuration file in the working directory with the highest priority.
 ///
 /// This function respects the priority between ESLint configuration files.
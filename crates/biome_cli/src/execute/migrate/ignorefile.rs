@@ -22,12 +22,31 @@ pub(crate) fn read_ignore_file(
 #[derive(Debug)]
 pub(crate) struct IgnorePatterns {
     pub(crate) patterns: IndexSet<String>,
-    pub(crate) has_negated_patterns: bool,
+    /// Patterns of the files that are included again by negated patterns, such as `!src/`,
+    /// after a pattern that ignores all the files, such as `/*`.
+    pub(crate) included_patterns: IndexSet<String>,
+    /// Negated patterns that cannot be migrated,
+    /// because Biome's `ignore` always takes precedence over `include`.
+    pub(crate) negated_patterns: Vec<String>,
+    /// Negated patterns that are migrated to `include` patterns, with the pattern they are
+    /// migrated to.
+    pub(crate) rewritten_patterns: Vec<(String, String)>,
+    /// Patterns that are dropped: the patterns that ignore all the files, which are replaced by
+    /// the `include` patterns, and the negated patterns followed by another pattern that ignores
+    /// all the files.
+    pub(crate) dropped_patterns: Vec<String>,
 }
 impl IgnorePatterns {
     pub(crate) fn from(content: &str) -> Self {
-        let mut has_negated_patterns = false;
         let mut patterns = IndexSet::new();
+        let mut included_patterns = IndexSet::new();
+        let mut negated_patterns = Vec::new();
+        let mut rewritten_patterns = Vec::new();
+        let mut dropped_patterns = Vec::new();
+        // Set when the last pattern ignores all the files, such as `/*`, with the line of the
+        // pattern. The negated patterns that follow it include the files they match again,
+        // so they are migrated to `include` patterns.
+        let mut all_files_pattern: Option<(String, &str)> = None;
         for line in content.lines() {
             // Trailing spaces are ignored
             let line = line.trim_end();
@@ -35,24 +54,72 @@ impl IgnorePatterns {
             if line.is_empty() || line.starts_with('#') {
                 continue;
             }
+            if let Some(negated_line) = line.strip_prefix('!') {
+                match (convert_included_pattern(negated_line), &all_files_pattern) {
+                    (Ok(pattern), Some((all_files_pattern, all_files_line))) => {
+                        // The files that don't match an `include` pattern are ignored,
+                        // so the pattern that ignores all the files is no longer needed.
+                        if patterns.shift_remove(all_files_pattern) {
+                            dropped_patterns.push(all_files_line.to_string());
+                        }
+                        // A directory pattern must include the files of the directory too.
+                        let pattern = match pattern.strip_suffix('/') {
+                            Some(directory) => format!("{directory}/**"),
+                            None => pattern,
+                        };
+                        rewritten_patterns.push((line.to_string(), pattern.clone()));
+                        included_patterns.insert(pattern);
+                    }
+                    _ => negated_patterns.push(line.to_string()),
+                }
+                continue;
+            }
             match convert_pattern(line) {
                 Ok(pattern) => {
+                    if is_all_files_pattern(line) {
+                        // The files included by the previous negated patterns are ignored again
+                        included_patterns.clear();
+                        dropped_patterns.extend(rewritten_patterns.drain(..).map(|(line, _)| line));
+                        all_files_pattern = Some((pattern.clone(), line));
+                    } else {
+                        all_files_pattern = None;
+                    }
                     patterns.insert(pattern);
                 }
-                Err(_) => {
-                    has_negated_patterns = true;
-                    // Skip negated patterns because we don't support them.
-                    continue;
-                }
+                Err(_) => continue,
             }
         }
         IgnorePatterns {
             patterns,
-            has_negated_patterns,
+            included_patterns,
+            negated_patterns,
+            rewritten_patterns,
+            dropped_patterns,
         }
     }
 }
 
+/// Returns `true` if `line` is a pattern that ignores all the files and directories
+fn is_all_files_pattern(line: &str) -> bool {
+    matches!(
+        line,
+        "*" | "/*" | "**" | "/**" | "**/*" | "/**/*" | "**/" | "**/*/"
+    )
+}
+
+/// Converts a negated pattern that follows a pattern ignoring all the files into an `include`
+/// pattern.
+///
+/// Git cannot include a file again when its parent directory is ignored,
+/// so the pattern only matches the files and directories at the root of the project.
+fn convert_included_pattern(line: &str) -> Result<String, &'static str> {
+    if line.starts_with('!') {
+        return Err("Negated patterns are not supported.");
+    }
+    let line = line.strip_prefix('/').unwrap_or(line);
+    Ok(format!("./{line}"))
+}
+
 pub(crate) fn convert_pattern(line: &str) -> Result<String, &'static str> {
     if line.starts_with('!') {
         // Skip negated patterns because we don't support them.
@@ -82,7 +149,7 @@ mod tests {
         const IGNORE_FILE_CONTENT: &str = r#""#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert!(result.patterns.is_empty());
     }
 
@@ -98,7 +165,7 @@ mod tests {
         "#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert!(result.patterns.is_empty());
     }
 
@@ -114,7 +181,7 @@ dir/
 "#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert_eq!(
             result.patterns,
             [
@@ -141,7 +208,7 @@ dir/subdir/
 "#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert_eq!(
             result.patterns,
             [
@@ -168,7 +235,7 @@ dir/subdir/
 "#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert_eq!(
             result.patterns,
             [
@@ -188,8 +255,130 @@ dir/subdir/
         const IGNORE_FILE_CONTENT: &str = r#"!a"#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(result.has_negated_patterns);
+        assert_eq!(result.negated_patterns, ["!a".to_string()]);
+        assert!(result.patterns.is_empty());
+        assert!(result.included_patterns.is_empty());
+    }
+
+    #[test]
+    fn negated_patterns_after_all_files_pattern() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+dist/
+/*
+!src/
+!/biome.json
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert!(result.negated_patterns.is_empty());
+        assert_eq!(result.patterns, ["**/dist/".to_string()].into());
+        assert_eq!(
+            result.included_patterns,
+            ["./src/**".to_string(), "./biome.json".to_string()].into()
+        );
+        assert_eq!(
+            result.rewritten_patterns,
+            [
+                ("!src/".to_string(), "./src/**".to_string()),
+                ("!/biome.json".to_string(), "./biome.json".to_string()),
+            ]
+        );
+        assert_eq!(result.dropped_patterns, ["/*".to_string()]);
+    }
+
+    #[test]
+    fn negated_patterns_after_all_files_pattern_are_anchored() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+*
+!src/
+!/lib/
+!/scripts
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert!(result.negated_patterns.is_empty());
+        assert!(result.patterns.is_empty());
+        assert_eq!(
+            result.included_patterns,
+            [
+                "./src/**".to_string(),
+                "./lib/**".to_string(),
+                "./scripts".to_string(),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn negated_file_pattern_after_all_files_pattern() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+/*
+!/biome.json
+!package.json
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert!(result.negated_patterns.is_empty());
         assert!(result.patterns.is_empty());
+        assert_eq!(
+            result.included_patterns,
+            ["./biome.json".to_string(), "./package.json".to_string()].into()
+        );
+    }
+
+    #[test]
+    fn patterns_before_all_files_pattern_are_kept() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+dist/
+*.log
+/*
+!src/
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert!(result.negated_patterns.is_empty());
+        assert_eq!(
+            result.patterns,
+            ["**/dist/".to_string(), "**/*.log".to_string()].into()
+        );
+        assert_eq!(result.included_patterns, ["./src/**".to_string()].into());
+    }
+
+    #[test]
+    fn negated_patterns_after_other_patterns() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+/*
+!src/
+src/generated/
+!src/generated/index.js
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert_eq!(
+            result.negated_patterns,
+            ["!src/generated/index.js".to_string()]
+        );
+        assert_eq!(result.patterns, ["src/generated/".to_string()].into());
+        assert_eq!(result.included_patterns, ["./src/**".to_string()].into());
+    }
+
+    #[test]
+    fn all_files_pattern_after_negated_patterns() {
+        const IGNORE_FILE_CONTENT: &str = r#"
+*
+!src/
+**
+"#;
+        let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
+
+        assert!(result.negated_patterns.is_empty());
+        assert_eq!(result.patterns, ["**".to_string()].into());
+        assert!(result.included_patterns.is_empty());
+        assert!(result.rewritten_patterns.is_empty());
+        assert_eq!(
+            result.dropped_patterns,
+            ["*".to_string(), "!src/".to_string()]
+        );
     }
 
     #[test]
@@ -199,7 +388,7 @@ dir/subdir/
         "#;
         let result = IgnorePatterns::from(IGNORE_FILE_CONTENT);
 
-        assert!(!result.has_negated_patterns);
+        assert!(result.negated_patterns.is_empty());
         assert_eq!(
             result.patterns,
             ["**/    # This is not a comment because there is some leading spaces".to_string()]
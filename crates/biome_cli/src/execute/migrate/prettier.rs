@@ -9,7 +9,10 @@ use biome_formatter::{
     QuoteStyle,
 };
 use biome_fs::{FileSystem, OpenOptions};
-use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingCommas};
+use biome_js_analyze::lint::nursery::use_sorted_classes::UtilityClassSortingOptions;
+use biome_js_formatter::context::{
+    ArrowParentheses, ObjectWrap, QuoteProperties, Semicolons, TrailingCommas,
+};
 use biome_json_parser::JsonParserOptions;
 use biome_service::DynRef;
 use std::{ffi::OsStr, path::Path};
@@ -55,10 +58,22 @@ pub(crate) struct PrettierConfiguration {
     jsx_single_quote: bool,
     /// https://prettier.io/docs/en/options#arrow-function-parentheses
     arrow_parens: ArrowParens,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: Option<PrettierObjectWrap>,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: bool,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/options#embedded-language-formatting
+    embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
     /// https://prettier.io/docs/en/configuration.html#configuration-overrides
     overrides: Vec<Override>,
+    /// https://prettier.io/docs/en/plugins
+    plugins: Vec<String>,
+    /// https://github.com/tailwindlabs/prettier-plugin-tailwindcss#sorting-classes-in-function-calls
+    tailwind_functions: Vec<String>,
+    /// https://github.com/tailwindlabs/prettier-plugin-tailwindcss#sorting-non-standard-attributes
+    tailwind_attributes: Vec<String>,
 }
 
 impl Default for PrettierConfiguration {
@@ -75,8 +90,14 @@ impl Default for PrettierConfiguration {
             quote_props: QuoteProps::default(),
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
+            object_wrap: None,
+            single_attribute_per_line: false,
             end_of_line: EndOfLine::default(),
+            embedded_language_formatting: None,
             overrides: vec![],
+            plugins: vec![],
+            tailwind_functions: vec![],
+            tailwind_attributes: vec![],
         }
     }
 }
@@ -112,6 +133,10 @@ pub(crate) struct OverrideOptions {
     jsx_single_quote: Option<bool>,
     /// https://prettier.io/docs/en/options#arrow-function-parentheses
     arrow_parens: Option<ArrowParens>,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: Option<PrettierObjectWrap>,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: Option<bool>,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: Option<EndOfLine>,
 }
@@ -125,6 +150,13 @@ enum EndOfLine {
     Auto,
 }
 
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+enum EmbeddedLanguageFormatting {
+    #[default]
+    Auto,
+    Off,
+}
+
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
 enum ArrowParens {
     #[default]
@@ -132,6 +164,12 @@ enum ArrowParens {
     Avoid,
 }
 
+#[derive(Clone, Debug, Deserializable, Eq, PartialEq)]
+enum PrettierObjectWrap {
+    Preserve,
+    Collapse,
+}
+
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
 enum PrettierTrailingComma {
     #[default]
@@ -158,6 +196,15 @@ impl From<PrettierTrailingComma> for TrailingCommas {
     }
 }
 
+impl From<PrettierObjectWrap> for ObjectWrap {
+    fn from(value: PrettierObjectWrap) -> Self {
+        match value {
+            PrettierObjectWrap::Preserve => Self::Preserve,
+            PrettierObjectWrap::Collapse => Self::Collapse,
+        }
+    }
+}
+
 impl From<ArrowParens> for ArrowParentheses {
     fn from(value: ArrowParens) -> Self {
         match value {
@@ -202,6 +249,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
         let formatter = biome_configuration::PartialFormatterConfiguration {
             indent_width: Some(indent_width),
             line_width: Some(line_width),
+            max_empty_lines: None,
             indent_style: Some(indent_style),
             line_ending: Some(value.end_of_line.into()),
             attribute_position: Some(AttributePosition::default()),
@@ -236,6 +284,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
         let js_formatter = biome_configuration::PartialJavascriptFormatter {
             indent_width: None,
             line_width: None,
+            max_empty_lines: None,
             indent_style: None,
             line_ending: None,
             enabled: None,
@@ -254,12 +303,62 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             bracket_spacing: Some(value.bracket_spacing.into()),
             jsx_quote_style: Some(jsx_quote_style),
             attribute_position: Some(AttributePosition::default()),
+            jsx_max_inline_attributes: None,
+            decorator_placement: None,
+            parameter_decorators: None,
+            object_wrap: value.object_wrap.map(|object_wrap| object_wrap.into()),
+            operator_linebreak: None,
+            ternary_style: None,
+            import_export_wrap: None,
+            max_inline_specifiers: None,
+            comment_reflow: None,
+            jsx_children_whitespace: None,
+            // Biome prints the attributes on one line by default, like Prettier
+            single_attribute_per_line: value.single_attribute_per_line.then_some(true),
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
             formatter: Some(js_formatter),
             ..Default::default()
         };
         result.javascript = Some(js_config);
+        if value
+            .plugins
+            .iter()
+            .any(|plugin| plugin.contains(TAILWIND_PLUGIN))
+        {
+            // The plugin sorts the classes when the code is formatted
+            let options = Box::new(UtilityClassSortingOptions {
+                attributes: (!value.tailwind_attributes.is_empty()).then(|| {
+                    value
+                        .tailwind_attributes
+                        .into_iter()
+                        .map(String::into_boxed_str)
+                        .collect()
+                }),
+                functions: (!value.tailwind_functions.is_empty()).then(|| {
+                    value
+                        .tailwind_functions
+                        .into_iter()
+                        .map(String::into_boxed_str)
+                        .collect::<Vec<_>>()
+                }),
+            });
+            let mut rules = biome_configuration::Rules::default();
+            rules
+                .nursery
+                .get_or_insert_with(Default::default)
+                .use_sorted_classes = Some(biome_configuration::RuleFixConfiguration::WithOptions(
+                biome_configuration::RuleWithFixOptions {
+                    level: biome_configuration::RulePlainConfiguration::Error,
+                    fix: Some(biome_analyze::FixKind::Safe),
+                    options,
+                },
+            ));
+            result.linter = Some(biome_configuration::PartialLinterConfiguration {
+                rules: Some(rules),
+                ..Default::default()
+            });
+        }
         if !value.overrides.is_empty() {
             let mut overrides = biome_configuration::Overrides::default();
             for override_elt in value.overrides {
@@ -316,6 +415,8 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
             && options.jsx_single_quote.is_none()
             && options.bracket_line.is_none()
             && options.arrow_parens.is_none()
+            && options.object_wrap.is_none()
+            && options.single_attribute_per_line.is_none()
             && options.trailing_comma.is_none()
             && options.quote_props.is_none()
             && options.bracket_spacing.is_none()
@@ -348,6 +449,8 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
         let js_formatter = biome_configuration::PartialJavascriptFormatter {
             bracket_same_line: options.bracket_line,
             arrow_parentheses: options.arrow_parens.map(|arrow_parens| arrow_parens.into()),
+            object_wrap: options.object_wrap.map(|object_wrap| object_wrap.into()),
+            single_attribute_per_line: options.single_attribute_per_line,
             semicolons,
             trailing_commas: options
                 .trailing_comma
@@ -382,9 +485,21 @@ const CONFIG_FILES: [&str; 8] = [
     "./prettier.config.cjs",
 ];
 
+/// The Prettier plugin that sorts the Tailwind CSS classes
+const TAILWIND_PLUGIN: &str = "prettier-plugin-tailwindcss";
+
 /// Prettier Ignore file. Use the same syntax as gitignore.
 pub(crate) const IGNORE_FILE: &str = ".prettierignore";
 
+/// Returns the Prettier configuration file of the working directory with the highest priority.
+///
+/// Unlike [read_config_file], it doesn't look for a configuration embedded in `package.json`.
+pub(crate) fn find_config_file(fs: &DynRef<'_, dyn FileSystem>) -> Option<&'static str> {
+    CONFIG_FILES
+        .into_iter()
+        .find(|config_name| fs.path_exists(Path::new(config_name)))
+}
+
 /// This function is in charge of reading prettier files, deserialize its contents
 pub(crate) fn read_config_file(
     fs: &DynRef<'_, dyn FileSystem>,
@@ -487,6 +602,11 @@ fn load_config(
                 <Warn>"Prettier's `\"endOfLine\": \"auto\"` option is not supported in Biome. The default `\"lf\"` option is used instead."</Warn>
             });
         }
+        if result.embedded_language_formatting == Some(EmbeddedLanguageFormatting::Off) {
+            console.log(markup! {
+                <Warn>"Prettier's `\"embeddedLanguageFormatting\": \"off\"` option is not supported in Biome. The embedded code is formatted, as with the `\"auto\"` option: the CSS, GraphQL and SQL code of the tagged templates of JavaScript files, and the JavaScript code blocks of Markdown files. The CSS and GraphQL templates are left as they are when `css.formatter.enabled` or `graphql.formatter.enabled` is `false`."</Warn>
+            });
+        }
         Ok(result)
     } else if path.extension().is_none() {
         // The Prettier config file may be a YAML file.
@@ -502,8 +622,11 @@ fn load_config(
 
 #[cfg(test)]
 mod tests {
-    use crate::execute::migrate::prettier::{PrettierConfiguration, PrettierTrailingComma};
+    use crate::execute::migrate::prettier::{
+        EmbeddedLanguageFormatting, PrettierConfiguration, PrettierTrailingComma,
+    };
     use biome_deserialize::json::deserialize_from_json_str;
+    use biome_formatter::AttributePosition;
     use biome_json_parser::JsonParserOptions;
 
     #[test]
@@ -556,4 +679,106 @@ mod tests {
             }
         ))
     }
+
+    #[test]
+    fn single_attribute_per_line() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"{ "singleAttributePerLine": true }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        let biome_config =
+            biome_configuration::PartialConfiguration::try_from(configuration).unwrap();
+
+        let js_formatter = biome_config
+            .javascript
+            .and_then(|javascript| javascript.formatter)
+            .unwrap();
+        assert_eq!(js_formatter.single_attribute_per_line, Some(true));
+        assert_eq!(
+            js_formatter.attribute_position,
+            Some(AttributePosition::Auto)
+        );
+        assert_eq!(
+            biome_config
+                .formatter
+                .and_then(|formatter| formatter.attribute_position),
+            Some(AttributePosition::Auto)
+        );
+    }
+
+    #[test]
+    fn embedded_language_formatting() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"{ "embeddedLanguageFormatting": "off" }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        assert_eq!(
+            configuration.embedded_language_formatting,
+            Some(EmbeddedLanguageFormatting::Off)
+        );
+    }
+
+    #[test]
+    fn tailwind_plugin() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"
+{
+  "plugins": ["prettier-plugin-tailwindcss"],
+  "tailwindAttributes": ["myClassList"],
+  "tailwindFunctions": ["clsx", "tw"]
+}
+            "#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        let biome_config =
+            biome_configuration::PartialConfiguration::try_from(configuration).unwrap();
+        let use_sorted_classes = biome_config
+            .linter
+            .and_then(|linter| linter.rules)
+            .and_then(|rules| rules.nursery)
+            .and_then(|nursery| nursery.use_sorted_classes)
+            .unwrap();
+
+        let biome_configuration::RuleFixConfiguration::WithOptions(rule) = use_sorted_classes
+        else {
+            panic!("The rule should have options");
+        };
+        assert_eq!(
+            rule.level,
+            biome_configuration::RulePlainConfiguration::Error
+        );
+        assert_eq!(
+            rule.options.attributes,
+            Some(vec!["myClassList".into()].into_boxed_slice())
+        );
+        assert_eq!(
+            rule.options.functions,
+            Some(vec!["clsx".into(), "tw".into()])
+        );
+    }
+
+    #[test]
+    fn no_tailwind_plugin() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"{ "plugins": ["prettier-plugin-organize-imports"] }"#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        let biome_config =
+            biome_configuration::PartialConfiguration::try_from(configuration).unwrap();
+
+        assert!(biome_config.linter.is_none());
+    }
 }
@@ -1,10 +1,10 @@
 mod diagnostics;
-mod migrate;
+pub(crate) mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
 
-use crate::cli_options::{CliOptions, CliReporter};
+use crate::cli_options::{CliOptions, CliReporter, MaxDiagnosticsScope};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
 use crate::execute::migrate::MigratePayload;
@@ -13,6 +13,7 @@ use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::porcelain::{PorcelainReporter, PorcelainReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
@@ -23,6 +24,7 @@ use biome_diagnostics::{category, Category};
 use biome_fs::BiomePath;
 use biome_service::workspace::{
     FeatureName, FeaturesBuilder, FixFileMode, FormatFileParams, OpenFileParams, PatternId,
+    ProjectKey,
 };
 use std::borrow::Borrow;
 use std::ffi::OsString;
@@ -41,6 +43,12 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// Whether [Self::max_diagnostics] caps the diagnostics of each file, or of the whole traversal
+    max_diagnostics_scope: MaxDiagnosticsScope,
+
+    /// The project of the workspace that contains the files to handle
+    project_key: ProjectKey,
 }
 
 impl Execution {
@@ -54,6 +62,8 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            max_diagnostics_scope: MaxDiagnosticsScope::default(),
+            project_key: ProjectKey::default(),
         }
     }
 
@@ -231,6 +241,8 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in a stable, line-oriented format meant for scripts
+    Porcelain,
 }
 
 impl Default for ReportMode {
@@ -253,6 +265,7 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Porcelain => Self::Porcelain,
         }
     }
 }
@@ -263,6 +276,8 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            max_diagnostics_scope: MaxDiagnosticsScope::default(),
+            project_key: ProjectKey::default(),
         }
     }
 
@@ -283,6 +298,8 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            max_diagnostics_scope: MaxDiagnosticsScope::default(),
+            project_key: ProjectKey::default(),
         }
     }
 
@@ -292,6 +309,16 @@ impl Execution {
         self
     }
 
+    /// It sets the project of the workspace that contains the files to handle
+    pub(crate) fn set_project_key(mut self, project_key: ProjectKey) -> Self {
+        self.project_key = project_key;
+        self
+    }
+
+    pub(crate) fn project_key(&self) -> ProjectKey {
+        self.project_key
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -300,6 +327,10 @@ impl Execution {
         self.max_diagnostics
     }
 
+    pub(crate) fn get_max_diagnostics_scope(&self) -> MaxDiagnosticsScope {
+        self.max_diagnostics_scope
+    }
+
     /// `true` only when running the traversal in [TraversalMode::Check] and `should_fix` is `true`
     pub(crate) fn as_fix_file_mode(&self) -> Option<&FixFileMode> {
         match &self.traversal_mode {
@@ -429,6 +460,7 @@ pub fn execute_mode(
         info!("Removing the limit of --max-diagnostics, because of a reporter different from the default one: {}", cli_options.reporter);
         u32::MAX
     };
+    execution.max_diagnostics_scope = cli_options.max_diagnostics_scope;
 
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = execution.as_stdin_file() {
@@ -449,6 +481,7 @@ pub fn execute_mode(
     {
         let payload = MigratePayload {
             session,
+            project_key: execution.project_key,
             write,
             configuration_file_path,
             configuration_directory_path,
@@ -468,6 +501,27 @@ pub fn execute_mode(
         let processed = summary.changed + summary.unchanged;
         let should_exit_on_warnings = summary.warnings > 0 && cli_options.error_on_warnings;
 
+        // Processing emitted error diagnostics, exit with a non-zero code
+        let result =
+            if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
+                Err(CliDiagnostic::no_files_processed())
+            } else if errors > 0 || should_exit_on_warnings {
+                let category = execution.as_diagnostic_category();
+                if should_exit_on_warnings {
+                    if execution.is_check_apply() {
+                        Err(CliDiagnostic::apply_warnings(category))
+                    } else {
+                        Err(CliDiagnostic::check_warnings(category))
+                    }
+                } else if execution.is_check_apply() {
+                    Err(CliDiagnostic::apply_error(category))
+                } else {
+                    Err(CliDiagnostic::check_error(category))
+                }
+            } else {
+                Ok(())
+            };
+
         match execution.report_mode {
             ReportMode::Terminal { with_summary } => {
                 if with_summary {
@@ -518,12 +572,14 @@ pub fn execute_mode(
                     })?;
                     let report_file = BiomePath::new("_report_output.json");
                     session.app.workspace.open_file(OpenFileParams {
+                        project_key: execution.project_key(),
                         content,
                         path: report_file.clone(),
                         version: 0,
                         document_file_source: None,
                     })?;
                     let code = session.app.workspace.format_file(FormatFileParams {
+                        project_key: execution.project_key(),
                         path: report_file.clone(),
                     })?;
                     console.log(markup! {
@@ -572,26 +628,25 @@ pub fn execute_mode(
                 };
                 reporter.write(&mut JunitReporterVisitor::new(console))?;
             }
-        }
-
-        // Processing emitted error diagnostics, exit with a non-zero code
-        if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
-            Err(CliDiagnostic::no_files_processed())
-        } else if errors > 0 || should_exit_on_warnings {
-            let category = execution.as_diagnostic_category();
-            if should_exit_on_warnings {
-                if execution.is_check_apply() {
-                    Err(CliDiagnostic::apply_warnings(category))
-                } else {
-                    Err(CliDiagnostic::check_warnings(category))
-                }
-            } else if execution.is_check_apply() {
-                Err(CliDiagnostic::apply_error(category))
-            } else {
-                Err(CliDiagnostic::check_error(category))
+            ReportMode::Porcelain => {
+                let reporter = PorcelainReporter {
+                    summary,
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                    evaluated_paths,
+                };
+                let mut buffer = PorcelainReporterVisitor::new(result.is_ok());
+                reporter.write(&mut buffer)?;
+                console.log(markup! {
+                    {buffer}
+                });
             }
-        } else {
-            Ok(())
         }
+
+        result
     }
 }
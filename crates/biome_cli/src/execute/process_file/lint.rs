@@ -97,7 +97,6 @@ pub(crate) fn lint_with_guard<'ctx>(
                 let offset = match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
                     Some(b"vue") => VueFileHandler::start(input.as_str()),
                     Some(b"astro") => AstroFileHandler::start(input.as_str()),
-                    Some(b"svelte") => SvelteFileHandler::start(input.as_str()),
                     _ => None,
                 };
 
@@ -37,6 +37,7 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
+                project_key: ctx.execution.project_key(),
                 document_file_source: None,
                 path: biome_path,
                 version: 0,
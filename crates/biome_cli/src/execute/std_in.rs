@@ -28,6 +28,7 @@ pub(crate) fn run<'a>(
 
     if mode.is_format() {
         let file_features = workspace.file_features(SupportsFeatureParams {
+            project_key: mode.project_key(),
             path: biome_path.clone(),
             features: FeaturesBuilder::new().with_formatter().build(),
         })?;
@@ -46,22 +47,18 @@ pub(crate) fn run<'a>(
         };
         if file_features.supports_format() {
             workspace.open_file(OpenFileParams {
+                project_key: mode.project_key(),
                 path: biome_path.clone(),
                 version: 0,
                 content: content.into(),
                 document_file_source: None,
             })?;
             let printed = workspace.format_file(FormatFileParams {
+                project_key: mode.project_key(),
                 path: biome_path.clone(),
             })?;
 
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             console.append(markup! {
                 {output}
             });
@@ -78,6 +75,7 @@ pub(crate) fn run<'a>(
         let mut new_content = Cow::Borrowed(content);
 
         workspace.open_file(OpenFileParams {
+            project_key: mode.project_key(),
             path: biome_path.clone(),
             version: 0,
             content: content.into(),
@@ -85,6 +83,7 @@ pub(crate) fn run<'a>(
         })?;
         // apply fix file of the linter
         let file_features = workspace.file_features(SupportsFeatureParams {
+            project_key: mode.project_key(),
             path: biome_path.clone(),
             features: FeaturesBuilder::new()
                 .with_linter()
@@ -116,6 +115,7 @@ pub(crate) fn run<'a>(
         if let Some(fix_file_mode) = mode.as_fix_file_mode() {
             if file_features.supports_lint() {
                 let fix_file_result = workspace.fix_file(FixFileParams {
+                    project_key: mode.project_key(),
                     fix_file_mode: *fix_file_mode,
                     path: biome_path.clone(),
                     should_format: mode.is_check() && file_features.supports_format(),
@@ -136,6 +136,7 @@ pub(crate) fn run<'a>(
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
+                        project_key: mode.project_key(),
                         content: output.clone(),
                         path: biome_path.clone(),
                         version,
@@ -146,6 +147,7 @@ pub(crate) fn run<'a>(
 
             if file_features.supports_organize_imports() && mode.is_check() {
                 let result = workspace.organize_imports(OrganizeImportsParams {
+                    project_key: mode.project_key(),
                     path: biome_path.clone(),
                 })?;
                 let code = result.code;
@@ -158,6 +160,7 @@ pub(crate) fn run<'a>(
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
+                        project_key: mode.project_key(),
                         content: output.clone(),
                         path: biome_path.clone(),
                         version,
@@ -169,15 +172,10 @@ pub(crate) fn run<'a>(
 
         if file_features.supports_format() && mode.is_check() {
             let printed = workspace.format_file(FormatFileParams {
+                project_key: mode.project_key(),
                 path: biome_path.clone(),
             })?;
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(&new_content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             if (mode.is_check_apply() || mode.is_check_apply_unsafe()) && output != new_content {
                 new_content = Cow::Owned(output);
             }
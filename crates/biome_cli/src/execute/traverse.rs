@@ -4,7 +4,7 @@ use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
-    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic, TruncatedDiagnostics,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
@@ -13,10 +13,11 @@ use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
+use biome_service::events::{self, RunSummary};
 use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::BTreeSet;
 use std::sync::atomic::AtomicU32;
 use std::sync::RwLock;
@@ -92,6 +93,9 @@ pub(crate) fn traverse(
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
 
+    let command = execution.traversal_mode.to_string();
+    events::run_started(&command);
+
     let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
@@ -138,6 +142,17 @@ pub(crate) fn traverse(
     let skipped = skipped.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
     let diagnostics_not_printed = printer.not_printed_diagnostics();
+    events::run_finished(
+        &command,
+        duration,
+        &RunSummary {
+            changed,
+            unchanged,
+            skipped,
+            errors,
+            warnings,
+        },
+    );
     Ok(TraverseResult {
         summary: TraversalSummary {
             changed,
@@ -204,6 +219,10 @@ fn traverse_inputs(
 
 // struct DiagnosticsReporter<'ctx> {}
 
+/// When `--max-diagnostics` caps the diagnostics of each file, the whole run still prints at most
+/// this many times `--max-diagnostics` diagnostics. The help of `--max-diagnostics` documents it.
+const MAX_DIAGNOSTICS_FILE_SCOPE_FACTOR: u32 = 10;
+
 struct DiagnosticsPrinter<'ctx> {
     ///  Execution of the traversal
     execution: &'ctx Execution,
@@ -307,8 +326,37 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         should_print
     }
 
+    /// Count the diagnostic of a file that already printed `printed_in_file` diagnostics, and then
+    /// returns a boolean that tells if it should be printed.
+    ///
+    /// The limit applies to the whole traversal when the scope of `--max-diagnostics` is global.
+    /// Otherwise, the whole traversal is still capped by [MAX_DIAGNOSTICS_FILE_SCOPE_FACTOR].
+    fn should_print_in_file(&self, printed_in_file: u32) -> bool {
+        if !self.execution.get_max_diagnostics_scope().is_file() {
+            return self.should_print();
+        }
+        let printed_diagnostics = self.printed_diagnostics.load(Ordering::Relaxed);
+        let should_print = printed_in_file < self.max_diagnostics
+            && printed_diagnostics
+                < self
+                    .max_diagnostics
+                    .saturating_mul(MAX_DIAGNOSTICS_FILE_SCOPE_FACTOR);
+        if should_print {
+            self.printed_diagnostics.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.not_printed_diagnostics.fetch_add(1, Ordering::Relaxed);
+        }
+
+        should_print
+    }
+
     fn run(&self, receiver: Receiver<Message>, interner: Receiver<PathBuf>) -> Vec<Error> {
         let mut paths: FxHashSet<String> = FxHashSet::default();
+        // The number of diagnostics printed for each file, errors and diffs included
+        let mut printed_by_file: FxHashMap<String, u32> = FxHashMap::default();
+        // The number of diagnostics of each file that weren't printed, because the file or the
+        // whole run reached its limit
+        let mut not_printed_by_file: FxHashMap<String, u32> = FxHashMap::default();
 
         let mut diagnostics_to_print = vec![];
 
@@ -335,7 +383,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         self.warnings.fetch_add(1, Ordering::Relaxed);
                         // self.warnings.set(self.warnings.get() + 1)
                     }
+                    let mut file = None;
                     if let Some(Resource::File(file_path)) = location.resource.as_ref() {
+                        file = Some(file_path.to_string());
                         // Retrieves the file name from the file ID cache, if it's a miss
                         // flush entries from the interner channel until it's found
                         let file_name = match paths.get(*file_path) {
@@ -361,7 +411,20 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         }
                     }
 
-                    let should_print = self.should_print();
+                    // The errors that aren't bound to a file only count against the whole traversal
+                    let should_print = match file {
+                        Some(file) => {
+                            let printed_in_file = printed_by_file.entry(file.clone()).or_default();
+                            let should_print = self.should_print_in_file(*printed_in_file);
+                            if should_print {
+                                *printed_in_file += 1;
+                            } else {
+                                *not_printed_by_file.entry(file).or_default() += 1;
+                            }
+                            should_print
+                        }
+                        None => self.should_print_in_file(0),
+                    };
 
                     if should_print {
                         diagnostics_to_print.push(err);
@@ -374,6 +437,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     diagnostics,
                     skipped_diagnostics,
                 } => {
+                    let (errors, warnings) =
+                        diagnostics.iter().fold((0, 0), |(errors, warnings), diag| {
+                            match diag.severity() {
+                                Severity::Error | Severity::Fatal => (errors + 1, warnings),
+                                Severity::Warning => (errors, warnings + 1),
+                                Severity::Hint | Severity::Information => (errors, warnings),
+                            }
+                        });
+                    events::diagnostics_emitted(&name, errors, warnings, diagnostics.len());
+
                     self.not_printed_diagnostics
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
 
@@ -396,6 +469,8 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             diagnostics_to_print.push(diag);
                         }
                     } else {
+                        let printed_in_file = printed_by_file.entry(name.clone()).or_default();
+                        let mut not_printed_in_file = skipped_diagnostics;
                         for diag in diagnostics {
                             let severity = diag.severity();
                             if self.should_skip_diagnostic(severity, diag.tags()) {
@@ -408,14 +483,21 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                 self.warnings.fetch_add(1, Ordering::Relaxed);
                             }
 
-                            let should_print = self.should_print();
+                            let should_print = self.should_print_in_file(*printed_in_file);
 
                             if should_print {
+                                *printed_in_file += 1;
                                 let diag =
                                     diag.with_file_path(&name).with_file_source_code(&content);
                                 diagnostics_to_print.push(diag)
+                            } else {
+                                not_printed_in_file += 1;
                             }
                         }
+
+                        if not_printed_in_file > 0 {
+                            *not_printed_by_file.entry(name).or_default() += not_printed_in_file;
+                        }
                     }
                 }
                 Message::Diff {
@@ -441,9 +523,13 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         continue;
                     }
 
-                    let should_print = self.should_print();
+                    let printed_in_file = printed_by_file.entry(file_name.clone()).or_default();
+                    let should_print = self.should_print_in_file(*printed_in_file);
 
-                    if should_print {
+                    if !should_print {
+                        *not_printed_by_file.entry(file_name).or_default() += 1;
+                    } else {
+                        *printed_in_file += 1;
                         if self.execution.is_ci() {
                             match diff_kind {
                                 DiffKind::Format => {
@@ -533,6 +619,20 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                 }
             }
         }
+
+        // Tell where the diagnostics that weren't printed are, whether the file or the whole
+        // run reached its limit
+        if self.execution.get_max_diagnostics_scope().is_file() {
+            let mut not_printed_by_file: Vec<_> = not_printed_by_file.into_iter().collect();
+            not_printed_by_file.sort_unstable();
+            for (file_name, not_printed_in_file) in not_printed_by_file {
+                diagnostics_to_print.push(Error::from(TruncatedDiagnostics::new(
+                    file_name,
+                    not_printed_in_file,
+                )));
+            }
+        }
+
         diagnostics_to_print
     }
 }
@@ -626,6 +726,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             let can_handle = !self
                 .workspace
                 .is_path_ignored(IsPathIgnoredParams {
+                    project_key: self.execution.project_key(),
                     biome_path: biome_path.clone(),
                     features: self.execution.to_feature(),
                 })
@@ -642,6 +743,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
         }
 
         let file_features = self.workspace.file_features(SupportsFeatureParams {
+            project_key: self.execution.project_key(),
             path: biome_path.clone(),
             features: self.execution.to_feature(),
         });
@@ -696,31 +798,39 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &BiomePath) {
-    match catch_unwind(move || process_file(ctx, path)) {
+    let start = Instant::now();
+    events::file_started(path);
+    let status = match catch_unwind(move || process_file(ctx, path)) {
         Ok(Ok(FileStatus::Changed)) => {
             ctx.increment_changed(path);
+            "changed"
         }
         Ok(Ok(FileStatus::Unchanged)) => {
             ctx.increment_unchanged();
+            "unchanged"
         }
         Ok(Ok(FileStatus::SearchResult(num_matches, msg))) => {
             ctx.increment_unchanged();
             ctx.increment_matches(num_matches);
             ctx.push_message(msg);
+            "unchanged"
         }
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.increment_unchanged();
             ctx.push_message(msg);
+            "unchanged"
         }
         Ok(Ok(FileStatus::Protected(file_path))) => {
             ctx.increment_unchanged();
             ctx.push_diagnostic(WorkspaceError::protected_file(file_path).into());
+            "protected"
         }
-        Ok(Ok(FileStatus::Ignored)) => {}
+        Ok(Ok(FileStatus::Ignored)) => "ignored",
         Ok(Err(err)) => {
             ctx.increment_unchanged();
             ctx.skipped.fetch_add(1, Ordering::Relaxed);
             ctx.push_message(err);
+            "skipped"
         }
         Err(err) => {
             let message = match err.downcast::<String>() {
@@ -731,9 +841,12 @@ fn handle_file(ctx: &TraversalOptions, path: &BiomePath) {
                 },
             };
 
+            events::panic_caught(path, &message);
             ctx.push_message(
                 PanicDiagnostic { message }.with_file_path(path.display().to_string()),
             );
+            "panicked"
         }
-    }
+    };
+    events::file_finished(path, start.elapsed(), status);
 }
@@ -29,8 +29,8 @@ use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
 use crate::commands::migrate::MigrateCommandPayload;
-use crate::commands::CommandRunner;
 pub use crate::commands::{biome_command, BiomeCommand};
+use crate::commands::{CommandRunner, DaemonSubCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
 pub use execute::{execute_mode, Execution, TraversalMode, VcsTargeted};
@@ -81,6 +81,7 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Rage(_, daemon_logs, formatter, linter) => {
                 commands::rage::rage(self, daemon_logs, formatter, linter)
             }
+            BiomeCommand::Doctor(_) => commands::doctor::doctor(self),
             BiomeCommand::Clean => commands::clean::clean(self),
             BiomeCommand::Start {
                 config_path,
@@ -88,6 +89,12 @@ impl<'app> CliSession<'app> {
                 log_prefix_name,
             } => commands::daemon::start(self, config_path, Some(log_path), Some(log_prefix_name)),
             BiomeCommand::Stop => commands::daemon::stop(self),
+            BiomeCommand::Daemon(DaemonSubCommand::Status { metrics }) => {
+                commands::daemon::status(self, metrics)
+            }
+            BiomeCommand::Check {
+                json_schema: true, ..
+            } => commands::check::print_json_schema(self),
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
@@ -105,6 +112,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                json_schema: _,
             } => run_command(
                 self,
                 &cli_options,
@@ -276,6 +284,12 @@ impl<'app> CliSession<'app> {
                     vcs_configuration,
                 },
             ),
+            BiomeCommand::RenameToken {
+                cli_options,
+                write,
+                name,
+                new_name,
+            } => commands::rename_token::rename_token(self, &cli_options, name, new_name, write),
             BiomeCommand::RunServer {
                 stop_on_disconnect,
                 config_path,
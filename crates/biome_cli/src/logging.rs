@@ -5,39 +5,42 @@ use tracing::Metadata;
 use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::layer::{Context, Filter, SubscriberExt};
 use tracing_subscriber::util::SubscriberInitExt;
-use tracing_subscriber::{registry, Layer};
+use tracing_subscriber::{registry, Layer, Registry};
 
-pub fn setup_cli_subscriber(level: LoggingLevel, kind: LoggingKind) {
-    if level == LoggingLevel::None {
+mod event_log;
+
+pub(crate) use event_log::{EventLog, EventLogFilter, EventSource, LogFileFormat};
+
+pub fn setup_cli_subscriber(level: LoggingLevel, kind: LoggingKind, event_log: Option<EventLog>) {
+    if level == LoggingLevel::None && event_log.is_none() {
         return;
     }
-    let format = tracing_subscriber::fmt::layer()
-        .with_level(true)
-        .with_target(false)
-        .with_thread_names(true)
-        .with_file(true)
-        .with_ansi(true);
-    match kind {
-        LoggingKind::Pretty => {
-            let format = format.pretty();
-            registry()
-                .with(format.with_filter(LoggingFilter { level }))
-                .init()
-        }
-        LoggingKind::Compact => {
-            let format = format.compact();
-            registry()
-                .with(format.with_filter(LoggingFilter { level }))
-                .init()
-        }
-        LoggingKind::Json => {
-            let format = format.json().flatten_event(true);
+    let format: Option<Box<dyn Layer<Registry> + Send + Sync>> = (level != LoggingLevel::None)
+        .then(|| {
+            let format = tracing_subscriber::fmt::layer()
+                .with_level(true)
+                .with_target(false)
+                .with_thread_names(true)
+                .with_file(true)
+                .with_ansi(true);
+            match kind {
+                LoggingKind::Pretty => format.pretty().with_filter(LoggingFilter { level }).boxed(),
+                LoggingKind::Compact => format
+                    .compact()
+                    .with_filter(LoggingFilter { level })
+                    .boxed(),
+                LoggingKind::Json => format
+                    .json()
+                    .flatten_event(true)
+                    .with_filter(LoggingFilter { level })
+                    .boxed(),
+            }
+        });
 
-            registry()
-                .with(format.with_filter(LoggingFilter { level }))
-                .init()
-        }
-    };
+    registry()
+        .with(format)
+        .with(event_log.map(|event_log| event_log.with_filter(EventLogFilter)))
+        .init()
 }
 
 #[derive(Copy, Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq)]
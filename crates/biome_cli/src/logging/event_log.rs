@@ -0,0 +1,256 @@
+use biome_service::events::EVENT_TARGET;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt::{Debug, Display, Formatter};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::field::{Field, Visit};
+use tracing::subscriber::Interest;
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Filter};
+use tracing_subscriber::Layer;
+
+/// Tracing layer that writes the events of [biome_service::events] to a file
+pub struct EventLog {
+    source: EventSource,
+    format: LogFileFormat,
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl EventLog {
+    /// Creates the event log of the CLI, which truncates the file at `path`
+    pub(crate) fn create(path: &Path, format: LogFileFormat) -> io::Result<Self> {
+        Ok(Self::new(EventSource::Cli, format, File::create(path)?))
+    }
+
+    pub(crate) fn new(
+        source: EventSource,
+        format: LogFileFormat,
+        writer: impl Write + Send + 'static,
+    ) -> Self {
+        Self {
+            source,
+            format,
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    fn write_record(&self, record: &EventRecord) -> io::Result<()> {
+        let mut line = match self.format {
+            LogFileFormat::Jsonl => serde_json::to_vec(record)?,
+        };
+        line.push(b'\n');
+
+        // A poisoned lock means that another thread panicked while writing: the event is written
+        // anyway, since the event log is most useful when something went wrong
+        let mut writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        writer.write_all(&line)?;
+        writer.flush()
+    }
+}
+
+impl<S: Subscriber> Layer<S> for EventLog {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+        let Some(name) = visitor.name else {
+            return;
+        };
+
+        let thread = std::thread::current();
+        let record = EventRecord {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| {
+                    duration.as_millis().try_into().unwrap_or(u64::MAX)
+                }),
+            source: self.source,
+            thread: thread.name(),
+            event: &name,
+            fields: visitor.fields,
+        };
+
+        // The event log must never interrupt the run
+        let _ = self.write_record(&record);
+    }
+}
+
+/// Tracing filter enabling only the events of [biome_service::events]
+pub(crate) struct EventLogFilter;
+
+impl EventLogFilter {
+    fn is_enabled(&self, meta: &Metadata<'_>) -> bool {
+        meta.is_event() && meta.target() == EVENT_TARGET
+    }
+}
+
+impl<S> Filter<S> for EventLogFilter {
+    fn enabled(&self, meta: &Metadata<'_>, _cx: &Context<'_, S>) -> bool {
+        self.is_enabled(meta)
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        if self.is_enabled(meta) {
+            Interest::always()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::INFO)
+    }
+}
+
+/// A line of the event log
+#[derive(Serialize)]
+struct EventRecord<'a> {
+    timestamp: u64,
+    source: EventSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thread: Option<&'a str>,
+    event: &'a str,
+    #[serde(flatten)]
+    fields: Map<String, Value>,
+}
+
+/// Collects the name and the fields of an event
+#[derive(Default)]
+struct EventVisitor {
+    name: Option<String>,
+    fields: Map<String, Value>,
+}
+
+impl EventVisitor {
+    fn insert(&mut self, field: &Field, value: Value) {
+        self.fields.insert(field.name().to_string(), value);
+    }
+}
+
+impl Visit for EventVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "event" {
+            self.name = Some(value.to_string());
+        } else {
+            self.insert(field, Value::from(value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record_str(field, &format!("{value:?}"));
+    }
+}
+
+/// The process that wrote an event
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum EventSource {
+    Cli,
+    Daemon,
+}
+
+/// The format of the event log
+#[derive(Copy, Debug, Default, Clone, Eq, PartialEq)]
+pub enum LogFileFormat {
+    /// Each event is a JSON object on its own line
+    #[default]
+    Jsonl,
+}
+
+impl Display for LogFileFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogFileFormat::Jsonl => write!(f, "jsonl"),
+        }
+    }
+}
+
+impl FromStr for LogFileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" => Ok(Self::Jsonl),
+            _ => Err("This log format doesn't exist".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventLog, EventLogFilter, EventSource, LogFileFormat};
+    use biome_service::events;
+    use serde_json::Value;
+    use std::io::{self, Write};
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing_subscriber::prelude::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_the_events_as_json_lines() {
+        let buffer = SharedBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(
+            EventLog::new(EventSource::Cli, LogFileFormat::Jsonl, buffer.clone())
+                .with_filter(EventLogFilter),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            events::file_started(Path::new("src/index.js"));
+            tracing::info!(target: "biome_cli", "not an event");
+            events::file_finished(
+                Path::new("src/index.js"),
+                Duration::from_millis(12),
+                "changed",
+            );
+        });
+
+        let content = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["source"], "cli");
+        assert_eq!(lines[0]["event"], "fileStarted");
+        assert_eq!(lines[0]["path"], "src/index.js");
+        assert_eq!(lines[1]["event"], "fileFinished");
+        assert_eq!(lines[1]["durationMs"], 12);
+        assert_eq!(lines[1]["status"], "changed");
+        assert!(lines[1]["timestamp"].is_u64());
+    }
+}
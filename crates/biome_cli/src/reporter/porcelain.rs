@@ -0,0 +1,168 @@
+//! The porcelain reporter prints a line-oriented report meant to be read by scripts and editor task runners.
+//!
+//! Unlike the other reporters, its format is stable: a change that breaks the existing parsers
+//! requires a new version of the format. The report looks like this:
+//!
+//! ```text
+//! version 1
+//! file error unchanged 2 0 src/index.js
+//! file ok changed 0 0 src/utils.js
+//! summary files 2
+//! summary changed 1
+//! summary unchanged 1
+//! summary skipped 0
+//! summary matches 0
+//! summary errors 2
+//! summary warnings 0
+//! summary suggested-fixes-skipped 0
+//! exit 1
+//! ```
+//!
+//! - The first line is the version of the format.
+//! - There's a `file` line for each processed file and each file with diagnostics, sorted by path.
+//!   It contains the status of the file (`ok`, `warning` or `error`), whether the file was written
+//!   by Biome (`changed` or `unchanged`), the number of errors, the number of warnings, and the path of the file.
+//!   The path is always the last field, so it can contain spaces.
+//! - The `summary` lines contain the counters of the whole run.
+//! - The last line is the exit code of the command: `0` when it succeeds, and `1` when it fails.
+
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::Formatter;
+use biome_diagnostics::{Resource, Severity};
+use biome_fs::BiomePath;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+
+/// The version of the porcelain format
+const PORCELAIN_VERSION: u32 = 1;
+
+pub(crate) struct PorcelainReporter {
+    pub(crate) summary: TraversalSummary,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+    pub(crate) evaluated_paths: BTreeSet<BiomePath>,
+}
+
+impl Reporter for PorcelainReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        visitor.report_handled_paths(self.evaluated_paths)?;
+        visitor.report_summary(&self.execution, self.summary)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct PorcelainFile {
+    errors: u32,
+    warnings: u32,
+    changed: bool,
+}
+
+impl PorcelainFile {
+    fn status(&self) -> &'static str {
+        if self.errors > 0 {
+            "error"
+        } else if self.warnings > 0 {
+            "warning"
+        } else {
+            "ok"
+        }
+    }
+}
+
+pub(crate) struct PorcelainReporterVisitor {
+    files: BTreeMap<String, PorcelainFile>,
+    summary: TraversalSummary,
+    /// Whether the command exits successfully
+    is_success: bool,
+}
+
+impl PorcelainReporterVisitor {
+    pub(crate) fn new(is_success: bool) -> Self {
+        Self {
+            files: BTreeMap::new(),
+            summary: TraversalSummary::default(),
+            is_success,
+        }
+    }
+}
+
+impl biome_console::fmt::Display for PorcelainReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let mut content = format!("version {PORCELAIN_VERSION}\n");
+        for (path, file) in &self.files {
+            let changed = if file.changed { "changed" } else { "unchanged" };
+            content.push_str(&format!(
+                "file {} {changed} {} {} {path}\n",
+                file.status(),
+                file.errors,
+                file.warnings
+            ));
+        }
+        let summary = &self.summary;
+        let counters = [
+            ("files", summary.changed + summary.unchanged),
+            ("changed", summary.changed),
+            ("unchanged", summary.unchanged),
+            ("skipped", summary.skipped),
+            ("matches", summary.matches),
+            ("errors", summary.errors as usize),
+            ("warnings", summary.warnings as usize),
+            (
+                "suggested-fixes-skipped",
+                summary.suggested_fixes_skipped as usize,
+            ),
+        ];
+        for (name, value) in counters {
+            content.push_str(&format!("summary {name} {value}\n"));
+        }
+        content.push_str(if self.is_success { "exit 0" } else { "exit 1" });
+        fmt.write_str(&content)
+    }
+}
+
+impl ReporterVisitor for PorcelainReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        summary: TraversalSummary,
+    ) -> io::Result<()> {
+        self.summary = summary;
+        Ok(())
+    }
+
+    fn report_handled_paths(&mut self, evaluated_paths: BTreeSet<BiomePath>) -> io::Result<()> {
+        for path in evaluated_paths {
+            self.files
+                .entry(path.display().to_string())
+                .or_default()
+                .changed = path.was_written();
+        }
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() < payload.diagnostic_level
+                || (diagnostic.tags().is_verbose() && !payload.verbose)
+            {
+                continue;
+            }
+            let Some(Resource::File(path)) = diagnostic.location().resource else {
+                continue;
+            };
+            let file = self.files.entry(path.to_string()).or_default();
+            match diagnostic.severity() {
+                Severity::Error | Severity::Fatal => file.errors += 1,
+                Severity::Warning => file.warnings += 1,
+                Severity::Hint | Severity::Information => {}
+            }
+        }
+        Ok(())
+    }
+}
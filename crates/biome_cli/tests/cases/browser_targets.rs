@@ -0,0 +1,101 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const HAS_OWN_PROPERTY: &str = r#"foo.hasOwnProperty("bar");"#;
+
+#[test]
+fn javascript_target_disables_unsupported_fixes() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "javascript": {
+    "target": ["safari >= 15"]
+  },
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noPrototypeBuiltins": "warn"
+      }
+    }
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), HAS_OWN_PROPERTY.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", test.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "javascript_target_disables_unsupported_fixes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn javascript_target_is_overridden() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "javascript": {
+    "target": ["safari >= 15"]
+  },
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noPrototypeBuiltins": "warn"
+      }
+    }
+  },
+  "overrides": [
+    {
+      "include": ["modern/**"],
+      "javascript": {
+        "target": ["safari >= 16"]
+      }
+    }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("modern/test.js");
+    fs.insert(test.into(), HAS_OWN_PROPERTY.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", test.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "javascript_target_is_overridden",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -245,3 +245,71 @@ fn max_diagnostics_are_lifted() {
             content.contains(&errors)
         }));
 }
+
+#[test]
+fn max_diagnostics_per_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..2 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, "debugger;\n".repeat(5).as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--max-diagnostics=2"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let truncated_files = console
+        .out_buffer
+        .iter()
+        .filter(|m| {
+            let content = format!("{:?}", m.content);
+            content.contains("3 more issues in this file")
+        })
+        .count();
+
+    assert_eq!(truncated_files, 2);
+}
+
+#[test]
+fn max_diagnostics_global_scope() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..2 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, "debugger;\n".repeat(5).as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--max-diagnostics=2"),
+                ("--max-diagnostics-scope=global"),
+                ("src"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages = &console.out_buffer;
+
+    assert!(!messages.iter().any(|m| {
+        let content = format!("{:?}", m.content);
+        content.contains("more issues in this file")
+    }));
+    assert!(messages.iter().any(|m| {
+        let content = format!("{:?}", m.content);
+        content.contains("Diagnostics not shown")
+    }));
+}
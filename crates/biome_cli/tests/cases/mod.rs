@@ -3,6 +3,7 @@
 
 mod assists;
 mod biome_json_support;
+mod browser_targets;
 mod config_extends;
 mod config_path;
 mod cts_files;
@@ -21,6 +22,7 @@ mod protected_files;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_porcelain;
 mod reporter_summary;
 mod suppressions;
 mod unknown_files;
@@ -0,0 +1,84 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+
+const FIXABLE: &str = "let a = 4;
+debugger;
+console.log(a);
+";
+
+#[test]
+fn reports_files_porcelain_format_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("format.js");
+    fs.insert(file_path1.into(), UNFORMATTED.as_bytes());
+
+    let file_path2 = Path::new("formatted.js");
+    fs.insert(file_path2.into(), "statement();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--reporter=porcelain",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_files_porcelain_format_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_fixed_files_porcelain_check_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIXABLE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write",
+                "--unsafe",
+                "--reporter=porcelain",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_fixed_files_porcelain_check_command",
+        fs,
+        console,
+        result,
+    ));
+}
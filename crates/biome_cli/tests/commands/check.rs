@@ -78,6 +78,38 @@ fn check_help() {
     ));
 }
 
+#[test]
+fn prints_json_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--json-schema"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+    let schema: serde_json::Value =
+        serde_json::from_str(&content).expect("The schema should be valid JSON");
+
+    assert_eq!(schema["title"], "Configuration");
+    assert!(
+        schema["definitions"]["Nursery"]["properties"]
+            .get("useLogicalProperties")
+            .is_some(),
+        "The schema should include the nursery rules"
+    );
+}
+
 #[test]
 fn ok() {
     let mut fs = MemoryFileSystem::default();
@@ -171,7 +203,14 @@ fn maximum_diagnostics() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        Args::from(
+            [
+                ("check"),
+                ("--max-diagnostics-scope=global"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -1270,7 +1309,7 @@ fn max_diagnostics_default() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("check"), ("src")].as_slice()),
+        Args::from([("check"), ("--max-diagnostics-scope=global"), ("src")].as_slice()),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -1319,6 +1358,7 @@ fn max_diagnostics() {
                 ("check"),
                 ("--max-diagnostics"),
                 ("10"),
+                ("--max-diagnostics-scope=global"),
                 Path::new("src").as_os_str().to_str().unwrap(),
             ]
             .as_slice(),
@@ -561,7 +561,7 @@ fn max_diagnostics_default() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("ci"), ("src")].as_slice()),
+        Args::from([("ci"), ("--max-diagnostics-scope=global"), ("src")].as_slice()),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -617,7 +617,16 @@ fn max_diagnostics() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("ci"), ("--max-diagnostics"), ("10"), ("src")].as_slice()),
+        Args::from(
+            [
+                ("ci"),
+                ("--max-diagnostics"),
+                ("10"),
+                ("--max-diagnostics-scope=global"),
+                ("src"),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -659,6 +668,70 @@ fn max_diagnostics() {
     assert_eq!(diagnostic_count, 10);
 }
 
+#[test]
+fn max_diagnostics_file_scope_truncated() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..60 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, UNFORMATTED.as_bytes());
+    }
+
+    // Each file prints its diff, until the whole run prints ten times `--max-diagnostics`.
+    // The other files print a note instead.
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), ("--max-diagnostics"), ("1"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut diagnostic_count = 0;
+    let mut note_count = 0;
+    let mut filtered_messages = Vec::new();
+
+    for msg in console.out_buffer {
+        let MarkupBuf(nodes) = &msg.content;
+        let is_note = nodes
+            .iter()
+            .any(|node| node.content.contains("1 more issue in this file"));
+        let is_diagnostic = nodes.iter().any(|node| {
+            node.content
+                .contains("File content differs from formatting output")
+                || node.content.contains("format")
+                || node.content.contains("ci")
+        });
+
+        if is_note {
+            note_count += 1;
+        } else if is_diagnostic {
+            diagnostic_count += 1;
+        } else {
+            filtered_messages.push(msg);
+        }
+    }
+
+    console.out_buffer = filtered_messages;
+
+    for i in 0..60 {
+        let file_path = format!("src/file_{i}.js");
+        fs.remove(Path::new(&file_path));
+    }
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_diagnostics_file_scope_truncated",
+        fs,
+        console,
+        result,
+    ));
+
+    assert_eq!(diagnostic_count, 10);
+    assert_eq!(note_count, 50);
+}
+
 #[test]
 fn print_verbose() {
     let mut fs = MemoryFileSystem::default();
@@ -0,0 +1,151 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn doctor_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "doctor_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ok() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "formatter": {
+    "enabled": false
+  }
+}"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ok",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn without_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "without_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn with_malformed_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "formatter": {
+    "enabled":
+  }
+}"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_malformed_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn with_problems() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "$schema": "https://biomejs.dev/schemas/1.0.0/schema.json",
+  "files": {
+    "ignore": ["dist/**"]
+  },
+  "formatter": {
+    "include": ["src/**"],
+    "ignore": ["src/**"]
+  },
+  "linter": {
+    "include": ["src/**", "dist/**"]
+  }
+}"#,
+    );
+    fs.insert(Path::new(".prettierrc").into(), r#"{ "semi": false }"#);
+    fs.insert(Path::new(".eslintrc.json").into(), r#"{ "rules": {} }"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_problems",
+        fs,
+        console,
+        result,
+    ));
+}
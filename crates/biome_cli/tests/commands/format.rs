@@ -1798,7 +1798,7 @@ fn max_diagnostics_default() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("format"), ("src")].as_slice()),
+        Args::from([("format"), ("--max-diagnostics-scope=global"), ("src")].as_slice()),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -1851,7 +1851,16 @@ fn max_diagnostics() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("format"), ("--max-diagnostics"), ("10"), ("src")].as_slice()),
+        Args::from(
+            [
+                ("format"),
+                ("--max-diagnostics"),
+                ("10"),
+                ("--max-diagnostics-scope=global"),
+                ("src"),
+            ]
+            .as_slice(),
+        ),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -2306,6 +2315,43 @@ fn format_jsonc_files() {
     ));
 }
 
+#[test]
+fn format_json5_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let code = r#"{unquoted: 'single quotes',   hex: 0xFF,
+  // a comment
+  numbers: [+Infinity, NaN, .5,],}"#;
+    let file_path = Path::new("file.json5");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "{\n\tunquoted: 'single quotes',\n\thex: 0xFF,\n\t// a comment\n\tnumbers: [+Infinity, NaN, 0.5]\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json5_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_json_when_allow_trailing_commas() {
     let mut fs = MemoryFileSystem::default();
@@ -154,7 +154,8 @@ fn maximum_diagnostics() {
             .iter()
             .filter(|m| m.level == LogLevel::Error)
             .count(),
-        20_usize
+        // 20 diagnostics, and the note about the 76 diagnostics not shown
+        21_usize
     );
 
     assert!(messages
@@ -1456,7 +1457,7 @@ fn max_diagnostics_default() {
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         &mut console,
-        Args::from([("lint"), ("src")].as_slice()),
+        Args::from([("lint"), ("--max-diagnostics-scope=global"), ("src")].as_slice()),
     );
 
     assert!(result.is_err(), "run_cli returned {result:?}");
@@ -1503,6 +1504,7 @@ fn max_diagnostics() {
                 ("lint"),
                 ("--max-diagnostics"),
                 ("10"),
+                ("--max-diagnostics-scope=global"),
                 Path::new("src").as_os_str().to_str().unwrap(),
             ]
             .as_slice(),
@@ -68,6 +68,37 @@ fn prettier_migrate_end_of_line() {
     ));
 }
 
+#[test]
+fn prettier_migrate_embedded_language_formatting_off() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{}"#;
+    let prettier = r#"{ "embeddedLanguageFormatting": "off" }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_embedded_language_formatting_off",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn prettier_migrate_with_ignore() {
     let mut fs = MemoryFileSystem::default();
@@ -441,3 +472,85 @@ fn prettier_migrate_overrides() {
         result,
     ));
 }
+
+#[test]
+fn prettier_migrate_with_ignore_negated_patterns() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"{ "useTabs": false, "semi": true, "singleQuote": true }"#;
+    let prettier_ignore = r#"
+/*
+!src/
+src/generated/
+!src/generated/index.js
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let prettier_ignore_path = Path::new(".prettierignore");
+    fs.insert(prettier_ignore_path.into(), prettier_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_with_ignore_negated_patterns",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_with_ignore_rewritten_patterns() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"{ "useTabs": false, "semi": true, "singleQuote": true }"#;
+    let prettier_ignore = r#"
+dist/
+/*
+!docs/
+*
+!src/
+!/biome.json
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let prettier_ignore_path = Path::new(".prettierignore");
+    fs.insert(prettier_ignore_path.into(), prettier_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_with_ignore_rewritten_patterns",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,6 @@
 mod check;
 mod ci;
+mod doctor;
 mod explain;
 mod format;
 mod init;
@@ -1,7 +1,7 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
-use crate::analyzer::RuleAssistConfiguration;
-use biome_analyze::RuleFilter;
+use crate::analyzer::{RuleAssistConfiguration, RuleAssistPlainConfiguration};
+use biome_analyze::{options::RuleOptions, RuleFilter};
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_diagnostics::{Category, Severity};
 use rustc_hash::FxHashSet;
@@ -77,8 +77,8 @@ impl Actions {
                 .source
                 .as_ref()
                 .and_then(|group| group.get_rule_configuration(rule_name))
-                .filter(|conf| !matches!(conf, RuleAssistConfiguration::Off))
-                .map(|conf| conf.into()),
+                .filter(|(level, _)| !matches!(level, RuleAssistPlainConfiguration::Off))
+                .map(|(level, _)| level.into()),
         }
     }
     #[doc = r" It returns the enabled rules by default."]
@@ -101,14 +101,33 @@ impl Actions {
 pub struct Source {
     #[doc = "Enforce props sorting in JSX elements."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_jsx_props: Option<RuleAssistConfiguration>,
+    pub sort_jsx_props: Option<RuleAssistConfiguration<biome_js_analyze::options::SortJsxProps>>,
+    #[doc = "Adds an explicit type annotation to a variable, a parameter or the return of a function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_explicit_type_annotation:
+        Option<RuleAssistConfiguration<biome_js_analyze::options::UseExplicitTypeAnnotation>>,
     #[doc = "Sorts the keys of a JSON object in natural order"]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub use_sorted_keys: Option<RuleAssistConfiguration>,
+    pub use_sorted_keys:
+        Option<RuleAssistConfiguration<biome_json_analyze::options::UseSortedKeys>>,
+    #[doc = "Sorts the keys of a TOML table in natural order"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_table_keys:
+        Option<RuleAssistConfiguration<biome_toml_analyze::options::UseSortedTableKeys>>,
+    #[doc = "Converts a string concatenation to a template literal."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_template_literal:
+        Option<RuleAssistConfiguration<biome_js_analyze::options::UseTemplateLiteral>>,
 }
 impl Source {
     const GROUP_NAME: &'static str = "source";
-    pub(crate) const GROUP_RULES: &'static [&'static str] = &["sortJsxProps", "useSortedKeys"];
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "sortJsxProps",
+        "useExplicitTypeAnnotation",
+        "useSortedKeys",
+        "useSortedTableKeys",
+        "useTemplateLiteral",
+    ];
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
         if let Some(rule) = self.sort_jsx_props.as_ref() {
@@ -116,11 +135,26 @@ impl Source {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.use_sorted_keys.as_ref() {
+        if let Some(rule) = self.use_explicit_type_annotation.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_table_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.use_template_literal.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
@@ -130,11 +164,26 @@ impl Source {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.use_sorted_keys.as_ref() {
+        if let Some(rule) = self.use_explicit_type_annotation.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_table_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.use_template_literal.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -144,10 +193,28 @@ impl Source {
     pub(crate) fn get_rule_configuration(
         &self,
         rule_name: &str,
-    ) -> Option<RuleAssistConfiguration> {
+    ) -> Option<(RuleAssistPlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
-            "sortJsxProps" => self.sort_jsx_props.as_ref().copied(),
-            "useSortedKeys" => self.use_sorted_keys.as_ref().copied(),
+            "sortJsxProps" => self
+                .sort_jsx_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExplicitTypeAnnotation" => self
+                .use_explicit_type_annotation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedKeys" => self
+                .use_sorted_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedTableKeys" => self
+                .use_sorted_table_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useTemplateLiteral" => self
+                .use_template_literal
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
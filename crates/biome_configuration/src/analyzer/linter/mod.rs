@@ -29,6 +29,10 @@ pub struct LinterConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Whether the suppression comments that disable all the lint rules, such as `// biome-ignore lint: <explanation>`, or all the rules of a group, such as `// biome-ignore lint/suspicious: <explanation>`, are reported. `false` by default
+    #[partial(bpaf(hide))]
+    pub report_broad_suppressions: bool,
 }
 
 impl LinterConfiguration {
@@ -44,6 +48,7 @@ impl Default for LinterConfiguration {
             rules: Default::default(),
             ignore: Default::default(),
             include: Default::default(),
+            report_broad_suppressions: false,
         }
     }
 }
@@ -2096,7 +2096,7 @@ pub struct Correctness {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_invalid_constructor_super:
         Option<RuleConfiguration<biome_js_analyze::options::NoInvalidConstructorSuper>>,
-    #[doc = "Disallow non-standard direction values for linear gradient functions."]
+    #[doc = "Disallow non-standard direction values for linear gradient functions, and invalid color stops in gradient functions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_invalid_direction_in_linear_gradient:
         Option<RuleConfiguration<biome_css_analyze::options::NoInvalidDirectionInLinearGradient>>,
@@ -3271,9 +3271,27 @@ pub struct Nursery {
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
+    #[doc = "Disallow the use of {@html} tags in Svelte templates"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_at_html_tags: Option<RuleConfiguration<biome_html_analyze::options::NoAtHtmlTags>>,
+    #[doc = "Disallow await inside loops whose iterations are independent."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_await_in_loops: Option<RuleFixConfiguration<biome_js_analyze::options::NoAwaitInLoops>>,
     #[doc = "Disallow use of CommonJs module system in favor of ESM style imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_common_js: Option<RuleConfiguration<biome_js_analyze::options::NoCommonJs>>,
+    #[doc = "Disallow compiler options of tsconfig.json that contradict each other."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_conflicting_compiler_options:
+        Option<RuleConfiguration<biome_json_analyze::options::NoConflictingCompilerOptions>>,
+    #[doc = "Disallow the deprecated compiler options of tsconfig.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_compiler_options:
+        Option<RuleConfiguration<biome_json_analyze::options::NoDeprecatedCompilerOptions>>,
+    #[doc = "Disallow selecting fields that are deprecated in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_fields:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoDeprecatedFields>>,
     #[doc = "Disallow a lower specificity selector from coming after a higher specificity selector."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_descending_specificity:
@@ -3289,14 +3307,25 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_custom_properties:
         Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateCustomProperties>>,
+    #[doc = "Disallow dependencies that are declared in dependencies and in another dependency field of package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_dependencies:
+        Option<RuleConfiguration<biome_json_analyze::options::NoDuplicateDependencies>>,
     #[doc = "Disallow duplicate conditions in if-else-if chains"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_else_if:
         Option<RuleConfiguration<biome_js_analyze::options::NoDuplicateElseIf>>,
+    #[doc = "Disallow duplicate keys in YAML mappings."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_keys: Option<RuleConfiguration<biome_yaml_analyze::options::NoDuplicateKeys>>,
     #[doc = "Disallow duplicate properties within declaration blocks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_properties:
         Option<RuleConfiguration<biome_css_analyze::options::NoDuplicateProperties>>,
+    #[doc = "Disallow defining the same table more than once."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_tables:
+        Option<RuleConfiguration<biome_toml_analyze::options::NoDuplicateTables>>,
     #[doc = "No duplicated fields in GraphQL operations."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicated_fields:
@@ -3304,7 +3333,7 @@ pub struct Nursery {
     #[doc = "Disallow accessing namespace imports dynamically."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_dynamic_namespace_import_access:
-        Option<RuleConfiguration<biome_js_analyze::options::NoDynamicNamespaceImportAccess>>,
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoDynamicNamespaceImportAccess>>,
     #[doc = "Disallow TypeScript enum."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_enum: Option<RuleConfiguration<biome_js_analyze::options::NoEnum>>,
@@ -3312,6 +3341,13 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_exported_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoExportedImports>>,
+    #[doc = "Disallow the selectors of a CSS module that aren't scoped to the module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_global_leakage: Option<RuleConfiguration<biome_css_analyze::options::NoGlobalLeakage>>,
+    #[doc = "Disallow strings displayed to users that aren't translated."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hardcoded_locale_strings:
+        Option<RuleConfiguration<biome_js_analyze::options::NoHardcodedLocaleStrings>>,
     #[doc = "Prevent usage of \\<head> element in a Next.js project."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_head_element: Option<RuleConfiguration<biome_js_analyze::options::NoHeadElement>>,
@@ -3322,23 +3358,55 @@ pub struct Nursery {
     #[doc = "Prevent usage of \\<img> element in a Next.js project."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_img_element: Option<RuleConfiguration<biome_js_analyze::options::NoImgElement>>,
+    #[doc = "Disallow argument values that don't match the type of their argument in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_argument_values:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoInvalidArgumentValues>>,
+    #[doc = "Disallow invalid version ranges in package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_semver_range:
+        Option<RuleConfiguration<biome_json_analyze::options::NoInvalidSemverRange>>,
     #[doc = "Disallows the use of irregular whitespace characters."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_irregular_whitespace:
         Option<RuleConfiguration<biome_css_analyze::options::NoIrregularWhitespace>>,
+    #[doc = "Disallow text colors that don't contrast enough with the background color."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_low_contrast_colors:
+        Option<RuleConfiguration<biome_css_analyze::options::NoLowContrastColors>>,
     #[doc = "Disallow missing var function for css variables."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_missing_var_function:
         Option<RuleConfiguration<biome_css_analyze::options::NoMissingVarFunction>>,
+    #[doc = "Disallow mutating the properties of function parameters."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_mutation_of_function_parameters_deep:
+        Option<RuleConfiguration<biome_js_analyze::options::NoMutationOfFunctionParametersDeep>>,
     #[doc = "Disallow nested ternary expressions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_nested_ternary: Option<RuleConfiguration<biome_js_analyze::options::NoNestedTernary>>,
     #[doc = "Disallow octal escape sequences in string literals"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_octal_escape: Option<RuleConfiguration<biome_js_analyze::options::NoOctalEscape>>,
+    #[doc = "Disallow the APIs that some of the targeted environments don't support."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_outdated_browser_apis:
+        Option<RuleConfiguration<biome_js_analyze::options::NoOutdatedBrowserApis>>,
+    #[doc = "Prevent the usage of positive integers on tabindex attribute."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_positive_tabindex:
+        Option<RuleFixConfiguration<biome_html_analyze::options::NoPositiveTabindex>>,
     #[doc = "Disallow the use of process.env."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_process_env: Option<RuleConfiguration<biome_js_analyze::options::NoProcessEnv>>,
+    #[doc = "Disallow longhand properties that can be merged into a preceding shorthand property."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_redundant_longhand:
+        Option<RuleFixConfiguration<biome_css_analyze::options::NoRedundantLonghand>>,
+    #[doc = "Disallow type annotations that are identical to the type inferred from the initializer."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_redundant_type_annotations:
+        Option<RuleFixConfiguration<biome_js_analyze::options::NoRedundantTypeAnnotations>>,
     #[doc = "Disallow specified modules when loaded by import or require."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_restricted_imports:
@@ -3350,6 +3418,10 @@ pub struct Nursery {
     #[doc = "Disallow usage of sensitive data such as API keys and tokens."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_secrets: Option<RuleConfiguration<biome_js_analyze::options::NoSecrets>>,
+    #[doc = "Disallow queries of Testing Library that bypass the accessibility tree."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_skipped_accessibility_in_tests:
+        Option<RuleConfiguration<biome_js_analyze::options::NoSkippedAccessibilityInTests>>,
     #[doc = "Enforce that static, visible elements (such as \\<div>) that have click handlers use the valid role attribute."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_static_element_interactions:
@@ -3361,6 +3433,18 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_template_curly_in_string:
         Option<RuleConfiguration<biome_js_analyze::options::NoTemplateCurlyInString>>,
+    #[doc = "Disallow accessing the result of JSON.parse() without validating it."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unchecked_json_parse:
+        Option<RuleConfiguration<biome_js_analyze::options::NoUncheckedJsonParse>>,
+    #[doc = "Disallow composing the classes that aren't defined in a CSS module."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undefined_composes:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUndefinedComposes>>,
+    #[doc = "Disallow selecting fields that don't exist in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_fields:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownFields>>,
     #[doc = "Disallow unknown pseudo-class selectors."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_pseudo_class:
@@ -3373,6 +3457,18 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_type_selector:
         Option<RuleConfiguration<biome_css_analyze::options::NoUnknownTypeSelector>>,
+    #[doc = "Disallow the CSS features that some of the targeted browsers don't support."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsupported_css_features:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnsupportedCssFeatures>>,
+    #[doc = "Disallow custom properties that are never used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_custom_properties:
+        Option<RuleConfiguration<biome_css_analyze::options::NoUnusedCustomProperties>>,
+    #[doc = "Disallow fragments that aren't used by any operation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_fragments:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnusedFragments>>,
     #[doc = "Disallow unnecessary escape sequence in regular expression literals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_escape_in_regex:
@@ -3388,10 +3484,23 @@ pub struct Nursery {
     #[doc = "Disallow use of @value rule in css modules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_value_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoValueAtRule>>,
+    #[doc = "Disallow vendor prefixes that aren't needed by the targeted browsers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vendor_prefix: Option<RuleFixConfiguration<biome_css_analyze::options::NoVendorPrefix>>,
+    #[doc = "Disallow the production code from importing the test files, and the entry points from exporting them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_wildcard_exports_from_tests:
+        Option<RuleConfiguration<biome_js_analyze::options::NoWildcardExportsFromTests>>,
+    #[doc = "Disallow reading properties that only have a setter, and assigning properties that only have a getter."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_accessor_pairs: Option<RuleConfiguration<biome_js_analyze::options::UseAccessorPairs>>,
     #[doc = "Disallow the use of overload signatures that are not next to each other."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_adjacent_overload_signatures:
         Option<RuleConfiguration<biome_js_analyze::options::UseAdjacentOverloadSignatures>>,
+    #[doc = "Enforce that all elements that require alternative text have meaningful information to relay back to the end user."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_alt_text: Option<RuleConfiguration<biome_html_analyze::options::UseAltText>>,
     #[doc = "Enforce that ARIA properties are valid for the roles that are supported by the element."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_aria_props_supported_by_role:
@@ -3399,6 +3508,16 @@ pub struct Nursery {
     #[doc = "Use at() instead of integer index access."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_at_index: Option<RuleFixConfiguration<biome_js_analyze::options::UseAtIndex>>,
+    #[doc = "Enforce that buttons have an accessible name."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_button_name: Option<RuleConfiguration<biome_html_analyze::options::UseButtonName>>,
+    #[doc = "Enforces the usage of the attribute type for the element button"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_button_type: Option<RuleConfiguration<biome_html_analyze::options::UseButtonType>>,
+    #[doc = "Enforce a canonical order of the modifiers of the class members and the parameter properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_canonical_order_of_modifiers:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseCanonicalOrderOfModifiers>>,
     #[doc = "Enforce using single if instead of nested if clauses."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_collapsed_if: Option<RuleFixConfiguration<biome_js_analyze::options::UseCollapsedIf>>,
@@ -3406,6 +3525,10 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_component_export_only_modules:
         Option<RuleConfiguration<biome_js_analyze::options::UseComponentExportOnlyModules>>,
+    #[doc = "Enforce a single notation for the colors."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_color_format:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseConsistentColorFormat>>,
     #[doc = "This rule enforces consistent use of curly braces inside JSX attributes and JSX children."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_curly_braces:
@@ -3414,13 +3537,33 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_member_accessibility:
         Option<RuleConfiguration<biome_js_analyze::options::UseConsistentMemberAccessibility>>,
+    #[doc = "Require checking the status of the responses of fetch() before reading their body."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_response_handling:
+        Option<RuleConfiguration<biome_js_analyze::options::UseConsistentResponseHandling>>,
+    #[doc = "Prefer CSS custom properties over Sass variables for the values that can be themed."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_css_variables_over_sass_variables:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseCssVariablesOverSassVariables>>,
     #[doc = "Require specifying the reason argument when using @deprecated directive"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_deprecated_reason:
         Option<RuleConfiguration<biome_graphql_analyze::options::UseDeprecatedReason>>,
+    #[doc = "Enforce importing the configured modules either with named imports or with a namespace import."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_destructured_imports:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseDestructuredImports>>,
     #[doc = "Require explicit return types on functions and class methods."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_explicit_type: Option<RuleConfiguration<biome_js_analyze::options::UseExplicitType>>,
+    #[doc = "Require the types condition of the exports of package.json to be consistent with the types field."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_exports_types_condition:
+        Option<RuleConfiguration<biome_json_analyze::options::UseExportsTypesCondition>>,
+    #[doc = "Enforce a naming convention for GraphQL fragments."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_fragment_name_convention:
+        Option<RuleConfiguration<biome_graphql_analyze::options::UseFragmentNameConvention>>,
     #[doc = "Enforces the use of a recommended display strategy with Google Fonts."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_google_font_display:
@@ -3432,18 +3575,89 @@ pub struct Nursery {
     #[doc = "Require for-in loops to include an if statement."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_guard_for_in: Option<RuleConfiguration<biome_js_analyze::options::UseGuardForIn>>,
+    #[doc = "Enforce that html element has lang attribute."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_html_lang: Option<RuleConfiguration<biome_html_analyze::options::UseHtmlLang>>,
+    #[doc = "Disallow reassigning exported variables after the module is initialized."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_immutable_exports:
+        Option<RuleConfiguration<biome_js_analyze::options::UseImmutableExports>>,
     #[doc = "Disallows package private imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_restrictions:
         Option<RuleConfiguration<biome_js_analyze::options::UseImportRestrictions>>,
+    #[doc = "Enforce the use of logical properties and values."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_logical_properties:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseLogicalProperties>>,
+    #[doc = "Enforce a maximum nesting depth for the rules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_max_nesting_depth:
+        Option<RuleConfiguration<biome_css_analyze::options::UseMaxNestingDepth>>,
+    #[doc = "Enforce a maximum depth for the selections of operations."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_max_query_depth:
+        Option<RuleConfiguration<biome_graphql_analyze::options::UseMaxQueryDepth>>,
+    #[doc = "Enforce a maximum specificity for the selectors."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_max_specificity:
+        Option<RuleConfiguration<biome_css_analyze::options::UseMaxSpecificity>>,
+    #[doc = "Disallow importing whole heavy libraries when their members can be imported from their own modules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_minimal_bundle_imports:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseMinimalBundleImports>>,
     #[doc = "Enforce specifying the name of GraphQL operations."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_named_operation:
         Option<RuleFixConfiguration<biome_graphql_analyze::options::UseNamedOperation>>,
+    #[doc = "Enforce a naming convention for GraphQL operations."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_operation_naming_convention:
+        Option<RuleFixConfiguration<biome_graphql_analyze::options::UseOperationNamingConvention>>,
+    #[doc = "Enforce the best practices of Playwright for locating elements and waiting for them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_playwright_locator_best_practices:
+        Option<RuleConfiguration<biome_js_analyze::options::UsePlaywrightLocatorBestPractices>>,
+    #[doc = "Enforce the use of the preferred modules over their alternatives."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_preferred_modules:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UsePreferredModules>>,
+    #[doc = "Require the arguments of fields that don't have a default value and can't be null."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_required_arguments:
+        Option<RuleConfiguration<biome_graphql_analyze::options::UseRequiredArguments>>,
+    #[doc = "Disallow comparing and computing integers that exceed Number.MAX_SAFE_INTEGER."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_safe_integer_checks:
+        Option<RuleConfiguration<biome_js_analyze::options::UseSafeIntegerChecks>>,
+    #[doc = "Enforce the use of shorthand properties when all their longhand properties are declared."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_shorthand_properties:
+        Option<RuleFixConfiguration<biome_css_analyze::options::UseShorthandProperties>>,
     #[doc = "Enforce the sorting of CSS utility classes."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_classes:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseSortedClasses>>,
+    #[doc = "Require the dependencies of package.json to be sorted alphabetically."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_dependencies:
+        Option<RuleFixConfiguration<biome_json_analyze::options::UseSortedDependencies>>,
+    #[doc = "Require the fields of package.json to follow the conventional order."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_package_json_fields:
+        Option<RuleFixConfiguration<biome_json_analyze::options::UseSortedPackageJsonFields>>,
+    #[doc = "Enforce sorting the fields of types, the fields of input types, and the values of enums."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_schema_fields:
+        Option<RuleFixConfiguration<biome_graphql_analyze::options::UseSortedSchemaFields>>,
+    #[doc = "Enforce the use of static initialization blocks over static assignments after a class declaration."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_static_class_blocks:
+        Option<RuleFixConfiguration<biome_js_analyze::options::UseStaticClassBlocks>>,
+    #[doc = "Require the compiler options that complement strict in tsconfig.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_strict_companion_options:
+        Option<RuleConfiguration<biome_json_analyze::options::UseStrictCompanionOptions>>,
     #[doc = "Enforce the use of the directive \"use strict\" in script files."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_strict_mode: Option<RuleFixConfiguration<biome_js_analyze::options::UseStrictMode>>,
@@ -3451,10 +3665,17 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_trim_start_end:
         Option<RuleFixConfiguration<biome_js_analyze::options::UseTrimStartEnd>>,
+    #[doc = "Require selecting __typename on interfaces and unions."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_typename_on_abstract_types:
+        Option<RuleConfiguration<biome_graphql_analyze::options::UseTypenameOnAbstractTypes>>,
     #[doc = "Use valid values for the autocomplete attribute on input elements."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_valid_autocomplete:
         Option<RuleConfiguration<biome_js_analyze::options::UseValidAutocomplete>>,
+    #[doc = "Ensure that the attribute passed to the lang attribute is a correct ISO language and/or country."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_lang: Option<RuleConfiguration<biome_html_analyze::options::UseValidLang>>,
 }
 impl DeserializableValidator for Nursery {
     fn validate(
@@ -3473,62 +3694,122 @@ impl DeserializableValidator for Nursery {
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "noAtHtmlTags",
+        "noAwaitInLoops",
         "noCommonJs",
+        "noConflictingCompilerOptions",
+        "noDeprecatedCompilerOptions",
+        "noDeprecatedFields",
         "noDescendingSpecificity",
         "noDocumentCookie",
         "noDocumentImportInPage",
         "noDuplicateCustomProperties",
+        "noDuplicateDependencies",
         "noDuplicateElseIf",
+        "noDuplicateKeys",
         "noDuplicateProperties",
+        "noDuplicateTables",
         "noDuplicatedFields",
         "noDynamicNamespaceImportAccess",
         "noEnum",
         "noExportedImports",
+        "noGlobalLeakage",
+        "noHardcodedLocaleStrings",
         "noHeadElement",
         "noHeadImportInDocument",
         "noImgElement",
+        "noInvalidArgumentValues",
+        "noInvalidSemverRange",
         "noIrregularWhitespace",
+        "noLowContrastColors",
         "noMissingVarFunction",
+        "noMutationOfFunctionParametersDeep",
         "noNestedTernary",
         "noOctalEscape",
+        "noOutdatedBrowserApis",
+        "noPositiveTabindex",
         "noProcessEnv",
+        "noRedundantLonghand",
+        "noRedundantTypeAnnotations",
         "noRestrictedImports",
         "noRestrictedTypes",
         "noSecrets",
+        "noSkippedAccessibilityInTests",
         "noStaticElementInteractions",
         "noSubstr",
         "noTemplateCurlyInString",
+        "noUncheckedJsonParse",
+        "noUndefinedComposes",
+        "noUnknownFields",
         "noUnknownPseudoClass",
         "noUnknownPseudoElement",
         "noUnknownTypeSelector",
+        "noUnsupportedCssFeatures",
+        "noUnusedCustomProperties",
+        "noUnusedFragments",
         "noUselessEscapeInRegex",
         "noUselessStringRaw",
         "noUselessUndefined",
         "noValueAtRule",
+        "noVendorPrefix",
+        "noWildcardExportsFromTests",
+        "useAccessorPairs",
         "useAdjacentOverloadSignatures",
+        "useAltText",
         "useAriaPropsSupportedByRole",
         "useAtIndex",
+        "useButtonName",
+        "useButtonType",
+        "useCanonicalOrderOfModifiers",
         "useCollapsedIf",
         "useComponentExportOnlyModules",
+        "useConsistentColorFormat",
         "useConsistentCurlyBraces",
         "useConsistentMemberAccessibility",
+        "useConsistentResponseHandling",
+        "useCssVariablesOverSassVariables",
         "useDeprecatedReason",
+        "useDestructuredImports",
         "useExplicitType",
+        "useExportsTypesCondition",
+        "useFragmentNameConvention",
         "useGoogleFontDisplay",
         "useGoogleFontPreconnect",
         "useGuardForIn",
+        "useHtmlLang",
+        "useImmutableExports",
         "useImportRestrictions",
+        "useLogicalProperties",
+        "useMaxNestingDepth",
+        "useMaxQueryDepth",
+        "useMaxSpecificity",
+        "useMinimalBundleImports",
         "useNamedOperation",
+        "useOperationNamingConvention",
+        "usePlaywrightLocatorBestPractices",
+        "usePreferredModules",
+        "useRequiredArguments",
+        "useSafeIntegerChecks",
+        "useShorthandProperties",
         "useSortedClasses",
+        "useSortedDependencies",
+        "useSortedPackageJsonFields",
+        "useSortedSchemaFields",
+        "useStaticClassBlocks",
+        "useStrictCompanionOptions",
         "useStrictMode",
         "useTrimStartEnd",
+        "useTypenameOnAbstractTypes",
         "useValidAutocomplete",
+        "useValidLang",
     ];
     const RECOMMENDED_RULES: &'static [&'static str] = &[
         "noDescendingSpecificity",
         "noDuplicateCustomProperties",
         "noDuplicateElseIf",
+        "noDuplicateKeys",
         "noDuplicateProperties",
+        "noDuplicateTables",
         "noDuplicatedFields",
         "noMissingVarFunction",
         "noUnknownPseudoClass",
@@ -3542,21 +3823,23 @@ impl Nursery {
         "useStrictMode",
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -3609,6 +3892,64 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -3625,508 +3966,1088 @@ impl Nursery {
     }
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_common_js.as_ref() {
+        if let Some(rule) = self.no_at_html_tags.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_descending_specificity.as_ref() {
+        if let Some(rule) = self.no_await_in_loops.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_document_cookie.as_ref() {
+        if let Some(rule) = self.no_common_js.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_document_import_in_page.as_ref() {
+        if let Some(rule) = self.no_conflicting_compiler_options.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
+        if let Some(rule) = self.no_deprecated_compiler_options.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_deprecated_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_properties.as_ref() {
+        if let Some(rule) = self.no_descending_specificity.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+        if let Some(rule) = self.no_document_cookie.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+        if let Some(rule) = self.no_document_import_in_page.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_enum.as_ref() {
+        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
+        if let Some(rule) = self.no_duplicate_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_head_element.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_head_import_in_document.as_ref() {
+        if let Some(rule) = self.no_duplicate_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_img_element.as_ref() {
+        if let Some(rule) = self.no_duplicate_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+        if let Some(rule) = self.no_duplicate_tables.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_missing_var_function.as_ref() {
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_nested_ternary.as_ref() {
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_octal_escape.as_ref() {
+        if let Some(rule) = self.no_enum.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_process_env.as_ref() {
+        if let Some(rule) = self.no_exported_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_global_leakage.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_restricted_types.as_ref() {
+        if let Some(rule) = self.no_hardcoded_locale_strings.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_head_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+        if let Some(rule) = self.no_head_import_in_document.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
+        if let Some(rule) = self.no_img_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+        if let Some(rule) = self.no_invalid_argument_values.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+        if let Some(rule) = self.no_invalid_semver_range.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+        if let Some(rule) = self.no_low_contrast_colors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.no_missing_var_function.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+        if let Some(rule) = self.no_mutation_of_function_parameters_deep.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_nested_ternary.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.no_octal_escape.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.no_outdated_browser_apis.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+        if let Some(rule) = self.no_positive_tabindex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_at_index.as_ref() {
+        if let Some(rule) = self.no_process_env.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_collapsed_if.as_ref() {
+        if let Some(rule) = self.no_redundant_longhand.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+        if let Some(rule) = self.no_redundant_type_annotations.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+        if let Some(rule) = self.no_restricted_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_skipped_accessibility_in_tests.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_google_font_display.as_ref() {
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_substr.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_guard_for_in.as_ref() {
+        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_unchecked_json_parse.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.no_undefined_composes.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_strict_mode.as_ref() {
+        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
-        if let Some(rule) = self.use_trim_start_end.as_ref() {
+        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
             }
         }
-        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
             }
         }
-        index_set
-    }
-    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
-        let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.no_common_js.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+        if let Some(rule) = self.no_unsupported_css_features.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
             }
         }
-        if let Some(rule) = self.no_descending_specificity.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+        if let Some(rule) = self.no_unused_custom_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
             }
         }
-        if let Some(rule) = self.no_document_cookie.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+        if let Some(rule) = self.no_unused_fragments.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
             }
         }
-        if let Some(rule) = self.no_document_import_in_page.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
             }
         }
-        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
             }
         }
-        if let Some(rule) = self.no_duplicate_properties.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
             }
         }
-        if let Some(rule) = self.no_duplicated_fields.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+        if let Some(rule) = self.no_vendor_prefix.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
             }
         }
-        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+        if let Some(rule) = self.no_wildcard_exports_from_tests.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
             }
         }
-        if let Some(rule) = self.no_enum.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+        if let Some(rule) = self.use_accessor_pairs.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
             }
         }
-        if let Some(rule) = self.no_exported_imports.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
             }
         }
-        if let Some(rule) = self.no_head_element.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+        if let Some(rule) = self.use_alt_text.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
             }
         }
-        if let Some(rule) = self.no_head_import_in_document.as_ref() {
-            if rule.is_disabled() {
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
+            }
+        }
+        if let Some(rule) = self.use_button_name.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
+            }
+        }
+        if let Some(rule) = self.use_button_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
+            }
+        }
+        if let Some(rule) = self.use_canonical_order_of_modifiers.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
+            }
+        }
+        if let Some(rule) = self.use_collapsed_if.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
+            }
+        }
+        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_color_format.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_response_handling.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
+            }
+        }
+        if let Some(rule) = self.use_css_variables_over_sass_variables.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
+            }
+        }
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
+            }
+        }
+        if let Some(rule) = self.use_destructured_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
+            }
+        }
+        if let Some(rule) = self.use_exports_types_condition.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
+            }
+        }
+        if let Some(rule) = self.use_fragment_name_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
+            }
+        }
+        if let Some(rule) = self.use_google_font_display.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
+            }
+        }
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
+            }
+        }
+        if let Some(rule) = self.use_guard_for_in.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
+            }
+        }
+        if let Some(rule) = self.use_html_lang.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
+            }
+        }
+        if let Some(rule) = self.use_immutable_exports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
+            }
+        }
+        if let Some(rule) = self.use_logical_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
+            }
+        }
+        if let Some(rule) = self.use_max_nesting_depth.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
+            }
+        }
+        if let Some(rule) = self.use_max_query_depth.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_max_specificity.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_minimal_bundle_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_named_operation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_operation_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
+            }
+        }
+        if let Some(rule) = self.use_playwright_locator_best_practices.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_preferred_modules.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_required_arguments.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_safe_integer_checks.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_shorthand_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_dependencies.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_package_json_fields.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_schema_fields.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_static_class_blocks.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_strict_companion_options.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_strict_mode.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
+            }
+        }
+        if let Some(rule) = self.use_trim_start_end.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_typename_on_abstract_types.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_valid_lang.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.no_at_html_tags.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.no_await_in_loops.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.no_common_js.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.no_conflicting_compiler_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_compiler_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.no_descending_specificity.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.no_document_cookie.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.no_document_import_in_page.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_custom_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_keys.as_ref() {
+            if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_img_element.as_ref() {
+        if let Some(rule) = self.no_duplicate_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_tables.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.no_duplicated_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.no_dynamic_namespace_import_access.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_enum.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_exported_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_global_leakage.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_hardcoded_locale_strings.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_head_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_head_import_in_document.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_img_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_argument_values.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_semver_range.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_low_contrast_colors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_missing_var_function.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.no_mutation_of_function_parameters_deep.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.no_nested_ternary.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.no_octal_escape.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.no_outdated_browser_apis.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.no_positive_tabindex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.no_process_env.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.no_redundant_longhand.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
+        if let Some(rule) = self.no_redundant_type_annotations.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_types.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
+            }
+        }
+        if let Some(rule) = self.no_secrets.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
+            }
+        }
+        if let Some(rule) = self.no_skipped_accessibility_in_tests.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
+            }
+        }
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
+            }
+        }
+        if let Some(rule) = self.no_substr.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
+            }
+        }
+        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
+            }
+        }
+        if let Some(rule) = self.no_unchecked_json_parse.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
+            }
+        }
+        if let Some(rule) = self.no_undefined_composes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.no_unsupported_css_features.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.no_unused_custom_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.no_unused_fragments.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.no_value_at_rule.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.no_vendor_prefix.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.no_wildcard_exports_from_tests.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.use_accessor_pairs.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
             }
         }
-        if let Some(rule) = self.no_irregular_whitespace.as_ref() {
+        if let Some(rule) = self.use_alt_text.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
             }
         }
-        if let Some(rule) = self.no_missing_var_function.as_ref() {
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
             }
         }
-        if let Some(rule) = self.no_nested_ternary.as_ref() {
+        if let Some(rule) = self.use_at_index.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
             }
         }
-        if let Some(rule) = self.no_octal_escape.as_ref() {
+        if let Some(rule) = self.use_button_name.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
             }
         }
-        if let Some(rule) = self.no_process_env.as_ref() {
+        if let Some(rule) = self.use_button_type.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.use_canonical_order_of_modifiers.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
             }
         }
-        if let Some(rule) = self.no_restricted_types.as_ref() {
+        if let Some(rule) = self.use_collapsed_if.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
             }
         }
-        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+        if let Some(rule) = self.use_consistent_color_format.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
             }
         }
-        if let Some(rule) = self.no_substr.as_ref() {
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
             }
         }
-        if let Some(rule) = self.no_template_curly_in_string.as_ref() {
+        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_class.as_ref() {
+        if let Some(rule) = self.use_consistent_response_handling.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
             }
         }
-        if let Some(rule) = self.no_unknown_pseudo_element.as_ref() {
+        if let Some(rule) = self.use_css_variables_over_sass_variables.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
             }
         }
-        if let Some(rule) = self.no_unknown_type_selector.as_ref() {
+        if let Some(rule) = self.use_deprecated_reason.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_regex.as_ref() {
+        if let Some(rule) = self.use_destructured_imports.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
             }
         }
-        if let Some(rule) = self.no_useless_string_raw.as_ref() {
+        if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.use_exports_types_condition.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
             }
         }
-        if let Some(rule) = self.no_value_at_rule.as_ref() {
+        if let Some(rule) = self.use_fragment_name_convention.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
             }
         }
-        if let Some(rule) = self.use_adjacent_overload_signatures.as_ref() {
+        if let Some(rule) = self.use_google_font_display.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
             }
         }
-        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
             }
         }
-        if let Some(rule) = self.use_at_index.as_ref() {
+        if let Some(rule) = self.use_guard_for_in.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
             }
         }
-        if let Some(rule) = self.use_collapsed_if.as_ref() {
+        if let Some(rule) = self.use_html_lang.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
             }
         }
-        if let Some(rule) = self.use_component_export_only_modules.as_ref() {
+        if let Some(rule) = self.use_immutable_exports.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
             }
         }
-        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
             }
         }
-        if let Some(rule) = self.use_consistent_member_accessibility.as_ref() {
+        if let Some(rule) = self.use_logical_properties.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
             }
         }
-        if let Some(rule) = self.use_deprecated_reason.as_ref() {
+        if let Some(rule) = self.use_max_nesting_depth.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.use_max_query_depth.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
             }
         }
-        if let Some(rule) = self.use_google_font_display.as_ref() {
+        if let Some(rule) = self.use_max_specificity.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.use_minimal_bundle_imports.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
             }
         }
-        if let Some(rule) = self.use_guard_for_in.as_ref() {
+        if let Some(rule) = self.use_named_operation.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_operation_naming_convention.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.use_playwright_locator_best_practices.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_preferred_modules.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_required_arguments.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_safe_integer_checks.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_shorthand_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
             }
         }
         if let Some(rule) = self.use_sorted_classes.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_package_json_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_schema_fields.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_static_class_blocks.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_strict_companion_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
             }
         }
         if let Some(rule) = self.use_strict_mode.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
             }
         }
         if let Some(rule) = self.use_trim_start_end.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_typename_on_abstract_types.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
             }
         }
         if let Some(rule) = self.use_valid_autocomplete.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_valid_lang.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
             }
         }
         index_set
@@ -4165,10 +5086,30 @@ impl Nursery {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
+            "noAtHtmlTags" => self
+                .no_at_html_tags
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noAwaitInLoops" => self
+                .no_await_in_loops
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noCommonJs" => self
                 .no_common_js
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noConflictingCompilerOptions" => self
+                .no_conflicting_compiler_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedCompilerOptions" => self
+                .no_deprecated_compiler_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedFields" => self
+                .no_deprecated_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDescendingSpecificity" => self
                 .no_descending_specificity
                 .as_ref()
@@ -4185,14 +5126,26 @@ impl Nursery {
                 .no_duplicate_custom_properties
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateDependencies" => self
+                .no_duplicate_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicateElseIf" => self
                 .no_duplicate_else_if
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateKeys" => self
+                .no_duplicate_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicateProperties" => self
                 .no_duplicate_properties
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateTables" => self
+                .no_duplicate_tables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicatedFields" => self
                 .no_duplicated_fields
                 .as_ref()
@@ -4209,6 +5162,14 @@ impl Nursery {
                 .no_exported_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noGlobalLeakage" => self
+                .no_global_leakage
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noHardcodedLocaleStrings" => self
+                .no_hardcoded_locale_strings
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noHeadElement" => self
                 .no_head_element
                 .as_ref()
@@ -4221,14 +5182,30 @@ impl Nursery {
                 .no_img_element
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidArgumentValues" => self
+                .no_invalid_argument_values
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidSemverRange" => self
+                .no_invalid_semver_range
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noIrregularWhitespace" => self
                 .no_irregular_whitespace
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noLowContrastColors" => self
+                .no_low_contrast_colors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMissingVarFunction" => self
                 .no_missing_var_function
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noMutationOfFunctionParametersDeep" => self
+                .no_mutation_of_function_parameters_deep
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noNestedTernary" => self
                 .no_nested_ternary
                 .as_ref()
@@ -4237,10 +5214,26 @@ impl Nursery {
                 .no_octal_escape
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noOutdatedBrowserApis" => self
+                .no_outdated_browser_apis
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noPositiveTabindex" => self
+                .no_positive_tabindex
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noProcessEnv" => self
                 .no_process_env
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noRedundantLonghand" => self
+                .no_redundant_longhand
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noRedundantTypeAnnotations" => self
+                .no_redundant_type_annotations
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noRestrictedImports" => self
                 .no_restricted_imports
                 .as_ref()
@@ -4253,6 +5246,10 @@ impl Nursery {
                 .no_secrets
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noSkippedAccessibilityInTests" => self
+                .no_skipped_accessibility_in_tests
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noStaticElementInteractions" => self
                 .no_static_element_interactions
                 .as_ref()
@@ -4265,6 +5262,18 @@ impl Nursery {
                 .no_template_curly_in_string
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUncheckedJsonParse" => self
+                .no_unchecked_json_parse
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUndefinedComposes" => self
+                .no_undefined_composes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownFields" => self
+                .no_unknown_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownPseudoClass" => self
                 .no_unknown_pseudo_class
                 .as_ref()
@@ -4277,6 +5286,18 @@ impl Nursery {
                 .no_unknown_type_selector
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnsupportedCssFeatures" => self
+                .no_unsupported_css_features
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedCustomProperties" => self
+                .no_unused_custom_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedFragments" => self
+                .no_unused_fragments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUselessEscapeInRegex" => self
                 .no_useless_escape_in_regex
                 .as_ref()
@@ -4293,10 +5314,26 @@ impl Nursery {
                 .no_value_at_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noVendorPrefix" => self
+                .no_vendor_prefix
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noWildcardExportsFromTests" => self
+                .no_wildcard_exports_from_tests
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAccessorPairs" => self
+                .use_accessor_pairs
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useAdjacentOverloadSignatures" => self
                 .use_adjacent_overload_signatures
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useAltText" => self
+                .use_alt_text
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useAriaPropsSupportedByRole" => self
                 .use_aria_props_supported_by_role
                 .as_ref()
@@ -4305,6 +5342,18 @@ impl Nursery {
                 .use_at_index
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useButtonName" => self
+                .use_button_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useButtonType" => self
+                .use_button_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useCanonicalOrderOfModifiers" => self
+                .use_canonical_order_of_modifiers
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useCollapsedIf" => self
                 .use_collapsed_if
                 .as_ref()
@@ -4313,6 +5362,10 @@ impl Nursery {
                 .use_component_export_only_modules
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentColorFormat" => self
+                .use_consistent_color_format
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useConsistentCurlyBraces" => self
                 .use_consistent_curly_braces
                 .as_ref()
@@ -4321,14 +5374,34 @@ impl Nursery {
                 .use_consistent_member_accessibility
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentResponseHandling" => self
+                .use_consistent_response_handling
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useCssVariablesOverSassVariables" => self
+                .use_css_variables_over_sass_variables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useDeprecatedReason" => self
                 .use_deprecated_reason
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useDestructuredImports" => self
+                .use_destructured_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useExplicitType" => self
                 .use_explicit_type
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useExportsTypesCondition" => self
+                .use_exports_types_condition
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useFragmentNameConvention" => self
+                .use_fragment_name_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useGoogleFontDisplay" => self
                 .use_google_font_display
                 .as_ref()
@@ -4341,18 +5414,90 @@ impl Nursery {
                 .use_guard_for_in
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useHtmlLang" => self
+                .use_html_lang
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useImmutableExports" => self
+                .use_immutable_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useImportRestrictions" => self
                 .use_import_restrictions
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useLogicalProperties" => self
+                .use_logical_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMaxNestingDepth" => self
+                .use_max_nesting_depth
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMaxQueryDepth" => self
+                .use_max_query_depth
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMaxSpecificity" => self
+                .use_max_specificity
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useMinimalBundleImports" => self
+                .use_minimal_bundle_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useNamedOperation" => self
                 .use_named_operation
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useOperationNamingConvention" => self
+                .use_operation_naming_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "usePlaywrightLocatorBestPractices" => self
+                .use_playwright_locator_best_practices
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "usePreferredModules" => self
+                .use_preferred_modules
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useRequiredArguments" => self
+                .use_required_arguments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSafeIntegerChecks" => self
+                .use_safe_integer_checks
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useShorthandProperties" => self
+                .use_shorthand_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedClasses" => self
                 .use_sorted_classes
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedDependencies" => self
+                .use_sorted_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedPackageJsonFields" => self
+                .use_sorted_package_json_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedSchemaFields" => self
+                .use_sorted_schema_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useStaticClassBlocks" => self
+                .use_static_class_blocks
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useStrictCompanionOptions" => self
+                .use_strict_companion_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useStrictMode" => self
                 .use_strict_mode
                 .as_ref()
@@ -4361,10 +5506,18 @@ impl Nursery {
                 .use_trim_start_end
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useTypenameOnAbstractTypes" => self
+                .use_typename_on_abstract_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useValidAutocomplete" => self
                 .use_valid_autocomplete
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useValidLang" => self
+                .use_valid_lang
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
@@ -194,11 +194,11 @@ impl From<RulePlainConfiguration> for Severity {
     }
 }
 
-impl From<RuleAssistConfiguration> for Severity {
-    fn from(conf: RuleAssistConfiguration) -> Self {
+impl From<RuleAssistPlainConfiguration> for Severity {
+    fn from(conf: RuleAssistPlainConfiguration) -> Self {
         match conf {
-            RuleAssistConfiguration::On => Severity::Hint,
-            RuleAssistConfiguration::Off => {
+            RuleAssistPlainConfiguration::On => Severity::Hint,
+            RuleAssistPlainConfiguration::Off => {
                 unreachable!("the rule is turned off, it should not step in here")
             }
         }
@@ -216,16 +216,87 @@ pub enum RulePlainConfiguration {
     Off,
 }
 
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
+pub enum RuleAssistConfiguration<T: Default> {
+    Plain(RuleAssistPlainConfiguration),
+    WithOptions(RuleAssistWithOptions<T>),
+}
+
+impl<T: Default + Deserializable> Deserializable for RuleAssistConfiguration<T> {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        rule_name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        if value.visitable_type()? == DeserializableType::Str {
+            Deserializable::deserialize(value, rule_name, diagnostics).map(Self::Plain)
+        } else {
+            Deserializable::deserialize(value, rule_name, diagnostics)
+                .map(|rule| Self::WithOptions(rule))
+        }
+    }
+}
+
+impl<T: Default> RuleAssistConfiguration<T> {
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.level(), RuleAssistPlainConfiguration::Off)
+    }
+    pub fn is_enabled(&self) -> bool {
+        !self.is_disabled()
+    }
+    pub fn level(&self) -> RuleAssistPlainConfiguration {
+        match self {
+            Self::Plain(plain) => *plain,
+            Self::WithOptions(options) => options.level,
+        }
+    }
+}
+
+// Assist configuration has a custom [Merge] implementation so that turning
+// an assist on or off doesn't override the options.
+impl<T: Clone + Default> Merge for RuleAssistConfiguration<T> {
+    fn merge_with(&mut self, other: Self) {
+        match self {
+            Self::Plain(_) => *self = other,
+            Self::WithOptions(this) => match other {
+                Self::Plain(level) => {
+                    this.level = level;
+                }
+                Self::WithOptions(other) => {
+                    this.merge_with(other);
+                }
+            },
+        }
+    }
+}
+
+impl<T: Clone + Default + 'static> RuleAssistConfiguration<T> {
+    pub fn get_options(&self) -> Option<RuleOptions> {
+        match self {
+            Self::Plain(_) => None,
+            Self::WithOptions(options) => Some(RuleOptions::new(options.options.clone(), None)),
+        }
+    }
+}
+
+impl<T: Default> Default for RuleAssistConfiguration<T> {
+    fn default() -> Self {
+        Self::Plain(RuleAssistPlainConfiguration::On)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase")]
-pub enum RuleAssistConfiguration {
+pub enum RuleAssistPlainConfiguration {
     #[default]
     On,
     Off,
 }
 
-impl RuleAssistConfiguration {
+impl RuleAssistPlainConfiguration {
     pub const fn is_enabled(&self) -> bool {
         matches!(self, Self::On)
     }
@@ -235,12 +306,29 @@ impl RuleAssistConfiguration {
     }
 }
 
-impl Merge for RuleAssistConfiguration {
+impl Merge for RuleAssistPlainConfiguration {
     fn merge_with(&mut self, other: Self) {
         *self = other;
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RuleAssistWithOptions<T: Default> {
+    /// Whether the assist is enabled
+    pub level: RuleAssistPlainConfiguration,
+    /// Rule's options
+    pub options: T,
+}
+
+impl<T: Default> Merge for RuleAssistWithOptions<T> {
+    fn merge_with(&mut self, other: Self) {
+        self.level = other.level;
+        self.options = other.options;
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
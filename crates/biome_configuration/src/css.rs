@@ -1,5 +1,5 @@
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
-use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines, QuoteStyle};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -37,6 +37,7 @@ pub struct CssParser {
     pub allow_wrong_line_comments: bool,
 
     /// Enables parsing of CSS Modules specific features.
+    /// When it isn't set, it's enabled in the files whose name ends with `.module.css`.
     #[partial(bpaf(hide))]
     pub css_modules: bool,
 }
@@ -67,8 +68,17 @@ pub struct CssFormatter {
     #[partial(bpaf(long("css-formatter-line-width"), argument("NUMBER"), optional))]
     pub line_width: Option<LineWidth>,
 
-    /// The type of quotes used in CSS code. Defaults to double.
-    #[partial(bpaf(long("css-formatter-quote-style"), argument("double|single"), optional))]
+    /// The maximum number of consecutive empty lines that are kept in CSS (and its super languages) files. Defaults to 1.
+    #[partial(bpaf(long("css-formatter-max-empty-lines"), argument("NUMBER"), optional))]
+    pub max_empty_lines: Option<MaxEmptyLines>,
+
+    /// The type of quotes used in CSS code. Defaults to double. `preserve` keeps the quotes of
+    /// each string.
+    #[partial(bpaf(
+        long("css-formatter-quote-style"),
+        argument("double|single|preserve"),
+        optional
+    ))]
     pub quote_style: QuoteStyle,
 }
 
@@ -80,6 +90,7 @@ impl Default for CssFormatter {
             indent_width: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            max_empty_lines: Default::default(),
             quote_style: QuoteStyle::Double,
         }
     }
@@ -93,6 +104,7 @@ impl PartialCssFormatter {
             indent_width: self.indent_width,
             line_ending: self.line_ending,
             line_width: self.line_width,
+            max_empty_lines: self.max_empty_lines,
             quote_style: self.quote_style.unwrap_or_default(),
         }
     }
@@ -143,5 +155,6 @@ fn default_css() {
     assert_eq!(css_configuration.indent_width, None);
     assert_eq!(css_configuration.line_ending, None);
     assert_eq!(css_configuration.line_width, None);
+    assert_eq!(css_configuration.max_empty_lines, None);
     assert_eq!(css_configuration.quote_style, QuoteStyle::Double);
 }
@@ -2,6 +2,7 @@ use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    MaxEmptyLines,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
@@ -47,6 +48,10 @@ pub struct FormatterConfiguration {
     #[partial(bpaf(long("line-width"), argument("NUMBER"), optional))]
     pub line_width: LineWidth,
 
+    /// The maximum number of consecutive empty lines that are kept between the statements, the class members and the object members. Defaults to 1.
+    #[partial(bpaf(long("max-empty-lines"), argument("NUMBER"), optional))]
+    pub max_empty_lines: MaxEmptyLines,
+
     /// The attribute position style in HTMLish languages. By default auto.
     #[partial(bpaf(long("attribute-position"), argument("multiline|auto"), optional))]
     pub attribute_position: AttributePosition,
@@ -80,6 +85,7 @@ impl PartialFormatterConfiguration {
             indent_width: self.indent_width.unwrap_or_default(),
             line_ending: self.line_ending.unwrap_or_default(),
             line_width: self.line_width.unwrap_or_default(),
+            max_empty_lines: self.max_empty_lines.unwrap_or_default(),
             attribute_position: self.attribute_position.unwrap_or_default(),
             bracket_spacing: self.bracket_spacing.unwrap_or_default(),
             ignore: self.ignore.clone().unwrap_or_default(),
@@ -99,6 +105,7 @@ impl Default for FormatterConfiguration {
             indent_style: IndentStyle::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            max_empty_lines: MaxEmptyLines::default(),
             attribute_position: AttributePosition::default(),
             bracket_spacing: Default::default(),
             ignore: Default::default(),
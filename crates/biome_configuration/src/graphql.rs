@@ -105,12 +105,20 @@ pub struct GraphqlLinter {
     /// Control the formatter for GraphQL files.
     #[partial(bpaf(long("graphql-linter-enabled"), argument("true|false"), optional))]
     pub enabled: bool,
+
+    /// Path to the file that describes the GraphQL schema of the project.
+    ///
+    /// It's either an SDL file, or a JSON file with the result of an introspection query.
+    /// Rules that check documents against the schema are only run when it's set.
+    #[partial(bpaf(hide))]
+    pub schema: Option<String>,
 }
 
 impl PartialGraphqlLinter {
     pub fn get_linter_configuration(&self) -> GraphqlLinter {
         GraphqlLinter {
             enabled: self.enabled.unwrap_or_default(),
+            schema: self.schema.clone(),
         }
     }
 }
@@ -132,4 +140,5 @@ fn default_graphql_linter() {
     let graphql_configuration = GraphqlLinter::default();
 
     assert!(!graphql_configuration.enabled);
+    assert_eq!(graphql_configuration.schema, None);
 }
@@ -0,0 +1,132 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use biome_html_formatter::context::WhitespaceSensitivity;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU8;
+
+/// Options applied to HTML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlConfiguration {
+    /// HTML formatter options
+    #[partial(type, bpaf(external(partial_html_formatter), optional))]
+    pub formatter: HtmlFormatter,
+}
+
+/// Options that changes how the HTML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlFormatter {
+    /// Control the formatter for HTML files.
+    #[partial(bpaf(long("html-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied to HTML files.
+    #[partial(bpaf(long("html-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to HTML files. Default to 2.
+    #[partial(bpaf(long("html-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to HTML files.
+    #[partial(bpaf(long("html-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to HTML files. Defaults to 80.
+    #[partial(bpaf(long("html-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// Whether to hug the closing bracket of the HTML tags whose attributes don't fit in the line width to the end of the last attribute, rather than being alone on the following line. Defaults to false.
+    #[partial(bpaf(
+        long("html-formatter-bracket-same-line"),
+        argument("true|false"),
+        optional
+    ))]
+    pub bracket_same_line: bool,
+
+    /// Whether the HTML elements that have more than one attribute print each attribute on its own line, even if the element fits in the line width. Defaults to false.
+    #[partial(bpaf(
+        long("html-formatter-single-attribute-per-line"),
+        argument("true|false"),
+        optional
+    ))]
+    pub single_attribute_per_line: bool,
+
+    /// Whether the whitespace around and inside the elements is significant: "css" follows the default CSS display of the elements, so only the whitespace around and inside the inline elements, such as `<span>`, is significant; "strict" treats the whitespace around and inside all the elements as significant; "ignore" treats no whitespace as significant. Defaults to "css".
+    #[partial(bpaf(
+        long("html-formatter-whitespace-sensitivity"),
+        argument("css|strict|ignore"),
+        optional
+    ))]
+    pub whitespace_sensitivity: WhitespaceSensitivity,
+
+    /// The maximum number of attributes of an HTML element printed on a single line. The attributes of the elements that have more attributes are each printed on their own line, even if they fit in the line width. Unlimited by default.
+    #[partial(bpaf(
+        long("html-formatter-max-inline-attributes"),
+        argument("NUMBER"),
+        optional
+    ))]
+    pub max_inline_attributes: Option<NonZeroU8>,
+
+    /// Whether the elements that only contain whitespace, like `<div> </div>`, are collapsed. When it's false, the whitespace is kept as a space or as a line break. Defaults to true.
+    #[partial(bpaf(
+        long("html-formatter-collapse-empty-elements"),
+        argument("true|false"),
+        optional
+    ))]
+    pub collapse_empty_elements: bool,
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            bracket_same_line: Default::default(),
+            single_attribute_per_line: Default::default(),
+            whitespace_sensitivity: Default::default(),
+            max_inline_attributes: Default::default(),
+            collapse_empty_elements: true,
+        }
+    }
+}
+
+impl PartialHtmlFormatter {
+    pub fn get_formatter_configuration(&self) -> HtmlFormatter {
+        HtmlFormatter {
+            enabled: self.enabled.unwrap_or_default(),
+            indent_style: self.indent_style,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+            bracket_same_line: self.bracket_same_line.unwrap_or_default(),
+            single_attribute_per_line: self.single_attribute_per_line.unwrap_or_default(),
+            whitespace_sensitivity: self.whitespace_sensitivity.unwrap_or_default(),
+            max_inline_attributes: self.max_inline_attributes,
+            collapse_empty_elements: self.collapse_empty_elements.unwrap_or(true),
+        }
+    }
+}
+
+#[test]
+fn default_html() {
+    let html_configuration = HtmlFormatter::default();
+
+    assert!(html_configuration.enabled);
+    assert!(!html_configuration.bracket_same_line);
+    assert!(!html_configuration.single_attribute_per_line);
+    assert_eq!(
+        html_configuration.whitespace_sensitivity,
+        WhitespaceSensitivity::Css
+    );
+    assert!(html_configuration.collapse_empty_elements);
+}
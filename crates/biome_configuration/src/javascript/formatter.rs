@@ -1,12 +1,16 @@
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{
-    AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle,
+    AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    MaxEmptyLines, QuoteStyle,
 };
 use biome_js_formatter::context::{
-    trailing_commas::TrailingCommas, ArrowParentheses, QuoteProperties, Semicolons,
+    trailing_commas::TrailingCommas, ArrowParentheses, CommentReflow, DecoratorPlacement,
+    ImportExportWrap, JsxChildrenWhitespace, ObjectWrap, OperatorLinebreak, ParameterDecorators,
+    QuoteProperties, Semicolons, TernaryStyle,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU8;
 
 /// Formatting options specific to the JavaScript files
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -14,8 +18,9 @@ use serde::{Deserialize, Serialize};
 #[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
 #[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
 pub struct JavascriptFormatter {
-    /// The type of quotes used in JSX. Defaults to double.
-    #[partial(bpaf(long("jsx-quote-style"), argument("double|single"), optional))]
+    /// The type of quotes used in JSX. Defaults to double. `preserve` keeps the quotes of each
+    /// string.
+    #[partial(bpaf(long("jsx-quote-style"), argument("double|single|preserve"), optional))]
     pub jsx_quote_style: QuoteStyle,
 
     /// When properties in objects are quoted. Defaults to asNeeded.
@@ -82,10 +87,19 @@ pub struct JavascriptFormatter {
     #[partial(bpaf(long("javascript-formatter-line-width"), argument("NUMBER"), optional))]
     pub line_width: Option<LineWidth>,
 
+    /// The maximum number of consecutive empty lines that are kept in JavaScript (and its super languages) files. Defaults to 1.
+    #[partial(bpaf(
+        long("javascript-formatter-max-empty-lines"),
+        argument("NUMBER"),
+        optional
+    ))]
+    pub max_empty_lines: Option<MaxEmptyLines>,
+
     // TODO: Rename the argument to `javascript-formatter-quote-style` once
     // it's also a top-level configurable property.
-    /// The type of quotes used in JavaScript code. Defaults to double.
-    #[partial(bpaf(long("quote-style"), argument("double|single"), optional))]
+    /// The type of quotes used in JavaScript code. Defaults to double. `preserve` keeps the
+    /// quotes of each string.
+    #[partial(bpaf(long("quote-style"), argument("double|single|preserve"), optional))]
     pub quote_style: QuoteStyle,
 
     // it's also a top-level configurable property.
@@ -101,6 +115,54 @@ pub struct JavascriptFormatter {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     #[partial(bpaf(long("bracket-spacing"), argument("true|false"), optional))]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// The maximum number of attributes of a JSX element printed on a single line. The attributes of the elements that have more attributes are each printed on their own line, even if they fit in the line width. Unlimited by default.
+    #[partial(bpaf(long("jsx-max-inline-attributes"), argument("NUMBER"), optional))]
+    pub jsx_max_inline_attributes: Option<NonZeroU8>,
+
+    /// Where the decorators of class members and parameters are printed. Defaults to "preserve".
+    #[partial(bpaf(
+        long("decorator-placement"),
+        argument("preserve|same-line|above"),
+        optional
+    ))]
+    pub decorator_placement: DecoratorPlacement,
+
+    /// Whether the decorators of the parameters break the list of parameters: "auto" follows `decoratorPlacement`, "inline" keeps the decorators on the line of their parameter and only breaks the parameters when they don't fit in the line width, and "break" prints each parameter of a function that has a decorated parameter on its own line. Defaults to "auto".
+    #[partial(bpaf(long("parameter-decorators"), argument("auto|inline|break"), optional))]
+    pub parameter_decorators: ParameterDecorators,
+
+    /// Whether the objects that have a line break between the `{` and their first member stay expanded, even if they fit in the line width. Defaults to "preserve".
+    #[partial(bpaf(long("object-wrap"), argument("preserve|collapse"), optional))]
+    pub object_wrap: ObjectWrap,
+
+    /// Whether the operators of the binary and logical expressions are printed at the end of the line or at the start of the next line, when the expressions don't fit in the line width. Defaults to "after".
+    #[partial(bpaf(long("operator-linebreak"), argument("after|before"), optional))]
+    pub operator_linebreak: OperatorLinebreak,
+
+    /// How the chains of nested conditional expressions are printed when they don't fit on a single line: "indent" indents each nested conditional, and "case" prints each test on the same line as its consequent. Defaults to "indent".
+    #[partial(bpaf(long("ternary-style"), argument("indent|case"), optional))]
+    pub ternary_style: TernaryStyle,
+
+    /// How the lists of named imports and exports are wrapped: "auto" prints them on a single line when they fit in the line width, and "per-line" prints each specifier on its own line. Defaults to "auto".
+    #[partial(bpaf(long("import-export-wrap"), argument("auto|per-line"), optional))]
+    pub import_export_wrap: ImportExportWrap,
+
+    /// The maximum number of named imports or exports printed on a single line. The lists that have more specifiers print each of them on its own line, even if they fit in the line width. Unlimited by default.
+    #[partial(bpaf(long("max-inline-specifiers"), argument("NUMBER"), optional))]
+    pub max_inline_specifiers: Option<NonZeroU8>,
+
+    /// Whether the comments that exceed the line width are wrapped: "preserve" prints the comments as they are in the source, and "wrap" wraps the lines of the `//` comments and of the block comments whose lines start with `*`, when they start on their own line. The leading, trailing and dangling comments are all wrapped, but a comment that follows code on the same line is never wrapped. The directive comments, such as suppression comments, are never wrapped. Defaults to "preserve".
+    #[partial(bpaf(long("comment-reflow"), argument("preserve|wrap"), optional))]
+    pub comment_reflow: CommentReflow,
+
+    /// Whether the lines of JSX children can break at the whitespace between a text and a tag: "break" breaks the line there when it exceeds the line width, and inserts `{" "}` at the end of the line to keep the whitespace, and "glue" keeps the text on the line of the tag, and only breaks the lines between the words of the text. Defaults to "break".
+    #[partial(bpaf(long("jsx-children-whitespace"), argument("break|glue"), optional))]
+    pub jsx_children_whitespace: JsxChildrenWhitespace,
+
+    /// Whether the JSX elements that have more than one attribute print each attribute on its own line, even if the element fits in the line width. Defaults to false.
+    #[partial(bpaf(long("single-attribute-per-line"), argument("true|false"), optional))]
+    pub single_attribute_per_line: bool,
 }
 
 impl PartialJavascriptFormatter {
@@ -120,8 +182,20 @@ impl PartialJavascriptFormatter {
             indent_width: self.indent_width,
             line_ending: self.line_ending,
             line_width: self.line_width,
+            max_empty_lines: self.max_empty_lines,
             quote_style: self.quote_style.unwrap_or_default(),
             attribute_position: self.attribute_position,
+            jsx_max_inline_attributes: self.jsx_max_inline_attributes,
+            decorator_placement: self.decorator_placement.unwrap_or_default(),
+            parameter_decorators: self.parameter_decorators.unwrap_or_default(),
+            object_wrap: self.object_wrap.unwrap_or_default(),
+            operator_linebreak: self.operator_linebreak.unwrap_or_default(),
+            ternary_style: self.ternary_style.unwrap_or_default(),
+            import_export_wrap: self.import_export_wrap.unwrap_or_default(),
+            max_inline_specifiers: self.max_inline_specifiers,
+            comment_reflow: self.comment_reflow.unwrap_or_default(),
+            jsx_children_whitespace: self.jsx_children_whitespace.unwrap_or_default(),
+            single_attribute_per_line: self.single_attribute_per_line.unwrap_or_default(),
         }
     }
 }
@@ -143,8 +217,20 @@ impl Default for JavascriptFormatter {
             indent_width: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            max_empty_lines: Default::default(),
             quote_style: Default::default(),
             attribute_position: Default::default(),
+            jsx_max_inline_attributes: Default::default(),
+            decorator_placement: Default::default(),
+            parameter_decorators: Default::default(),
+            object_wrap: Default::default(),
+            operator_linebreak: Default::default(),
+            ternary_style: Default::default(),
+            import_export_wrap: Default::default(),
+            max_inline_specifiers: Default::default(),
+            comment_reflow: Default::default(),
+            jsx_children_whitespace: Default::default(),
+            single_attribute_per_line: Default::default(),
         }
     }
 }
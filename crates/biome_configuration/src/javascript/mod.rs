@@ -42,6 +42,14 @@ pub struct JavascriptConfiguration {
     #[partial(bpaf(hide))]
     pub jsx_runtime: JsxRuntime,
 
+    /// The environments targeted by the JavaScript files, written as browserslist queries,
+    /// such as `chrome >= 100` or `node >= 18`.
+    ///
+    /// When it's defined, it takes precedence over the `.browserslistrc` files, and over the
+    /// `browserslist` and `engines` fields of the `package.json` manifest.
+    #[partial(bpaf(hide))]
+    pub target: StringSet,
+
     #[partial(type, bpaf(external(partial_javascript_organize_imports), optional))]
     pub organize_imports: JavascriptOrganizeImports,
 }
@@ -1,7 +1,8 @@
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
-use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines};
 use biome_json_formatter::context::TrailingCommas;
 use bpaf::Bpaf;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 /// Options applied to JSON files
@@ -40,6 +41,34 @@ pub struct JsonParser {
     #[partial(bpaf(hide))]
     /// Allow parsing trailing commas in `.json` files
     pub allow_trailing_commas: bool,
+
+    #[partial(bpaf(hide))]
+    /// Allow parsing the JSON5 syntax in `.json` files, such as unquoted keys and single-quoted strings. It also allows comments and trailing commas.
+    pub allow_json5: bool,
+
+    #[partial(bpaf(pure(Default::default()), optional, hide))]
+    /// Additional files that are parsed as JSON, mapped to the parser options they accept, such as `{ ".eslintrc": { "allowComments": true } }`.
+    ///
+    /// The names are matched against the file name, and they take precedence over the built-in list of well-known files, such as `tsconfig.json`.
+    pub well_known_files: JsonWellKnownFiles,
+}
+
+/// The files registered in `json.parser.wellKnownFiles`, mapped to their parser options
+pub type JsonWellKnownFiles = IndexMap<String, JsonFileParser>;
+
+/// The parser options of a file registered in `json.parser.wellKnownFiles`
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonFileParser {
+    /// Allow parsing comments in the file
+    pub allow_comments: bool,
+
+    /// Allow parsing trailing commas in the file
+    pub allow_trailing_commas: bool,
+
+    /// Allow parsing the JSON5 syntax in the file. It also allows comments and trailing commas.
+    pub allow_json5: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -72,6 +101,10 @@ pub struct JsonFormatter {
     #[partial(bpaf(long("json-formatter-line-width"), argument("NUMBER"), optional))]
     pub line_width: Option<LineWidth>,
 
+    /// The maximum number of consecutive empty lines that are kept in JSON (and its super languages) files. Defaults to 1.
+    #[partial(bpaf(long("json-formatter-max-empty-lines"), argument("NUMBER"), optional))]
+    pub max_empty_lines: Option<MaxEmptyLines>,
+
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     #[partial(bpaf(long("json-formatter-trailing-commas"), argument("none|all"), optional))]
     pub trailing_commas: Option<TrailingCommas>,
@@ -86,6 +119,7 @@ impl PartialJsonFormatter {
             indent_size: self.indent_size,
             line_ending: self.line_ending,
             line_width: self.line_width,
+            max_empty_lines: self.max_empty_lines,
             trailing_commas: self.trailing_commas,
         }
     }
@@ -100,6 +134,7 @@ impl Default for JsonFormatter {
             indent_size: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            max_empty_lines: Default::default(),
             trailing_commas: Default::default(),
         }
     }
@@ -9,11 +9,17 @@ pub mod editorconfig;
 pub mod formatter;
 pub mod generated;
 pub mod graphql;
+pub mod html;
 pub mod javascript;
 pub mod json;
+pub mod markdown;
 pub mod organize_imports;
 mod overrides;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod toml;
 pub mod vcs;
+pub mod yaml;
 
 use crate::analyzer::assists::{
     partial_assists_configuration, AssistsConfiguration, PartialAssistsConfiguration,
@@ -46,6 +52,10 @@ pub use graphql::{
     partial_graphql_configuration, GraphqlConfiguration, GraphqlFormatter, GraphqlLinter,
     PartialGraphqlConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
 };
+pub use html::{
+    partial_html_configuration, HtmlConfiguration, HtmlFormatter, PartialHtmlConfiguration,
+    PartialHtmlFormatter,
+};
 pub use javascript::{
     partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
     PartialJavascriptConfiguration, PartialJavascriptFormatter,
@@ -54,6 +64,10 @@ pub use json::{
     partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
     PartialJsonFormatter,
 };
+pub use markdown::{
+    partial_markdown_configuration, MarkdownConfiguration, MarkdownFormatter,
+    PartialMarkdownConfiguration, PartialMarkdownFormatter,
+};
 pub use overrides::{
     OverrideAssistsConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
@@ -62,7 +76,15 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
 use std::path::PathBuf;
+pub use toml::{
+    partial_toml_configuration, PartialTomlConfiguration, PartialTomlFormatter, PartialTomlLinter,
+    TomlConfiguration, TomlFormatter, TomlLinter,
+};
 use vcs::VcsClientKind;
+pub use yaml::{
+    partial_yaml_configuration, PartialYamlConfiguration, PartialYamlFormatter, PartialYamlLinter,
+    YamlConfiguration, YamlFormatter, YamlLinter,
+};
 
 pub const VERSION: &str = match option_env!("BIOME_VERSION") {
     Some(version) => version,
@@ -128,6 +150,22 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_graphql_configuration), optional))]
     pub graphql: GraphqlConfiguration,
 
+    /// Specific configuration for the YAML language
+    #[partial(type, bpaf(external(partial_yaml_configuration), optional, hide))]
+    pub yaml: YamlConfiguration,
+
+    /// Specific configuration for the TOML language
+    #[partial(type, bpaf(external(partial_toml_configuration), optional, hide))]
+    pub toml: TomlConfiguration,
+
+    /// Specific configuration for the Markdown language
+    #[partial(type, bpaf(external(partial_markdown_configuration), optional, hide))]
+    pub markdown: MarkdownConfiguration,
+
+    /// Specific configuration for the HTML language
+    #[partial(type, bpaf(external(partial_html_configuration), optional, hide))]
+    pub html: HtmlConfiguration,
+
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
@@ -287,6 +325,78 @@ impl PartialConfiguration {
             .unwrap_or_default()
     }
 
+    pub fn get_yaml_formatter_configuration(&self) -> YamlFormatter {
+        self.yaml
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_yaml_linter_configuration(&self) -> YamlLinter {
+        self.yaml
+            .as_ref()
+            .map(|f| {
+                f.linter
+                    .as_ref()
+                    .map(|f| f.get_linter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_toml_formatter_configuration(&self) -> TomlFormatter {
+        self.toml
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_toml_linter_configuration(&self) -> TomlLinter {
+        self.toml
+            .as_ref()
+            .map(|f| {
+                f.linter
+                    .as_ref()
+                    .map(|f| f.get_linter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_markdown_formatter_configuration(&self) -> MarkdownFormatter {
+        self.markdown
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_html_formatter_configuration(&self) -> HtmlFormatter {
+        self.html
+            .as_ref()
+            .map(|f| {
+                f.formatter
+                    .as_ref()
+                    .map(|f| f.get_formatter_configuration())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_linter_disabled(&self) -> bool {
         self.linter.as_ref().map_or(false, |f| f.is_disabled())
     }
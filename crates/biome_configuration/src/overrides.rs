@@ -1,14 +1,15 @@
 use super::javascript::PartialJavascriptConfiguration;
 use super::json::PartialJsonConfiguration;
-use super::{PartialCssConfiguration, PartialGraphqlConfiguration};
+use super::{PartialCssConfiguration, PartialGraphqlConfiguration, PartialHtmlConfiguration};
 use crate::{
-    partial_css_configuration, partial_graphql_configuration, partial_javascript_configuration,
-    partial_json_configuration,
+    partial_css_configuration, partial_graphql_configuration, partial_html_configuration,
+    partial_javascript_configuration, partial_json_configuration,
 };
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    MaxEmptyLines,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
@@ -67,6 +68,11 @@ pub struct OverridePattern {
     #[bpaf(external(partial_graphql_configuration), optional, hide)]
     pub graphql: Option<PartialGraphqlConfiguration>,
 
+    /// Specific configuration for the Html language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(partial_html_configuration), optional, hide)]
+    pub html: Option<PartialHtmlConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_formatter_configuration), optional, hide)]
@@ -134,6 +140,11 @@ pub struct OverrideFormatterConfiguration {
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
 
+    /// The maximum number of consecutive empty lines that are kept. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("max-empty-lines"), argument("NUMBER"), optional)]
+    pub max_empty_lines: Option<MaxEmptyLines>,
+
     /// The attribute position style.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("attribute-position"), argument("multiline|auto"), optional)]
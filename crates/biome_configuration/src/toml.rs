@@ -0,0 +1,106 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to TOML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlConfiguration {
+    /// TOML formatter options
+    #[partial(type, bpaf(external(partial_toml_formatter), optional))]
+    pub formatter: TomlFormatter,
+
+    /// TOML linter options
+    #[partial(type, bpaf(external(partial_toml_linter), optional))]
+    pub linter: TomlLinter,
+}
+
+/// Options that changes how the TOML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlFormatter {
+    /// Control the formatter for TOML files.
+    #[partial(bpaf(long("toml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to TOML files. Default to 2.
+    #[partial(bpaf(long("toml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to TOML files. Defaults to 80.
+    #[partial(bpaf(long("toml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+}
+
+impl Default for TomlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+        }
+    }
+}
+
+impl PartialTomlFormatter {
+    pub fn get_formatter_configuration(&self) -> TomlFormatter {
+        TomlFormatter {
+            enabled: self.enabled.unwrap_or_default(),
+            indent_style: self.indent_style,
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+        }
+    }
+}
+
+/// Options that changes how the TOML linter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlLinter {
+    /// Control the linter for TOML files.
+    #[partial(bpaf(long("toml-linter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+}
+
+impl Default for TomlLinter {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl PartialTomlLinter {
+    pub fn get_linter_configuration(&self) -> TomlLinter {
+        TomlLinter {
+            enabled: self.enabled.unwrap_or_default(),
+        }
+    }
+}
+
+#[test]
+fn default_toml() {
+    let toml_configuration = TomlFormatter::default();
+
+    assert!(toml_configuration.enabled);
+    assert_eq!(toml_configuration.indent_style, None);
+    assert_eq!(toml_configuration.indent_width, None);
+    assert_eq!(toml_configuration.line_ending, None);
+    assert_eq!(toml_configuration.line_width, None);
+}
@@ -0,0 +1,101 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlConfiguration {
+    /// YAML formatter options
+    #[partial(type, bpaf(external(partial_yaml_formatter), optional))]
+    pub formatter: YamlFormatter,
+
+    /// YAML linter options
+    #[partial(type, bpaf(external(partial_yaml_linter), optional))]
+    pub linter: YamlLinter,
+}
+
+/// Options that changes how the YAML formatter behaves
+///
+/// There's no option for the indent style: YAML doesn't allow tabs in the indentation.
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlFormatter {
+    /// Control the formatter for YAML files.
+    #[partial(bpaf(long("yaml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The size of the indentation applied to YAML files. Default to 2.
+    #[partial(bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to YAML files.
+    #[partial(bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[partial(bpaf(long("yaml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+}
+
+impl Default for YamlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+        }
+    }
+}
+
+impl PartialYamlFormatter {
+    pub fn get_formatter_configuration(&self) -> YamlFormatter {
+        YamlFormatter {
+            enabled: self.enabled.unwrap_or_default(),
+            indent_width: self.indent_width,
+            line_ending: self.line_ending,
+            line_width: self.line_width,
+        }
+    }
+}
+
+/// Options that changes how the YAML linter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlLinter {
+    /// Control the linter for YAML files.
+    #[partial(bpaf(long("yaml-linter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+}
+
+impl Default for YamlLinter {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl PartialYamlLinter {
+    pub fn get_linter_configuration(&self) -> YamlLinter {
+        YamlLinter {
+            enabled: self.enabled.unwrap_or_default(),
+        }
+    }
+}
+
+#[test]
+fn default_yaml() {
+    let yaml_configuration = YamlFormatter::default();
+
+    assert!(yaml_configuration.enabled);
+    assert_eq!(yaml_configuration.indent_width, None);
+    assert_eq!(yaml_configuration.line_ending, None);
+    assert_eq!(yaml_configuration.line_width, None);
+}
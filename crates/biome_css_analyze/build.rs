@@ -0,0 +1,88 @@
+//! Generate the browser support of the CSS features from `./css-features.json`
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+/// The browsers of the support tables, in the order of the variants of
+/// `biome_analyze::browserslist::Browser`
+const BROWSERS: [&str; 8] = [
+    "chrome", "edge", "firefox", "ie", "ios_saf", "opera", "safari", "samsung",
+];
+
+/// The first version of each browser that supports a feature.
+/// The browsers that don't support the feature are absent.
+type Support = BTreeMap<String, String>;
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct CssFeatures {
+    /// The properties, such as `text-wrap`
+    properties: BTreeMap<String, Support>,
+    /// The values of a property, such as `text-wrap: balance`
+    values: BTreeMap<String, Support>,
+    /// The at-rules without their `@`, such as `container`
+    at_rules: BTreeMap<String, Support>,
+}
+
+fn main() -> io::Result<()> {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=css-features.json");
+
+    let text = fs::read_to_string("css-features.json")?;
+    let features: CssFeatures = serde_json::from_str(&text)?;
+
+    let mut code =
+        String::from("// Generated by `build.rs` from `css-features.json`. Do not edit.\n\n");
+    write_table(
+        &mut code,
+        "The properties that some browsers don't support, sorted by name",
+        "PROPERTIES",
+        &features.properties,
+    );
+    write_table(
+        &mut code,
+        "The values that some browsers don't support, written `property: value` and sorted",
+        "VALUES",
+        &features.values,
+    );
+    write_table(
+        &mut code,
+        "The at-rules that some browsers don't support, without their `@` and sorted by name",
+        "AT_RULES",
+        &features.at_rules,
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    fs::write(PathBuf::from(out_dir).join("css_features.rs"), code)
+}
+
+fn write_table(code: &mut String, doc: &str, name: &str, features: &BTreeMap<String, Support>) {
+    let _ = writeln!(code, "/// {doc}");
+    let _ = writeln!(
+        code,
+        "pub(crate) const {name}: [(&str, Support); {}] = [",
+        features.len()
+    );
+    for (feature, support) in features {
+        if let Some(browser) = support
+            .keys()
+            .find(|browser| !BROWSERS.contains(&browser.as_str()))
+        {
+            panic!("The feature '{feature}' has the unknown browser '{browser}'");
+        }
+        let versions = BROWSERS.map(|browser| match support.get(browser) {
+            Some(version) => {
+                let (major, minor) = version.split_once('.').unwrap_or((version, "0"));
+                let (Ok(major), Ok(minor)) = (major.parse::<u16>(), minor.parse::<u16>()) else {
+                    panic!("The feature '{feature}' has the invalid version '{version}'");
+                };
+                format!("Some(BrowserVersion::new({major}, {minor}))")
+            }
+            None => String::from("None"),
+        });
+        let _ = writeln!(code, "    ({feature:?}, [{}]),", versions.join(", "));
+    }
+    let _ = writeln!(code, "];\n");
+}
@@ -0,0 +1,175 @@
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssDeclarationOrRule, AnyCssDeclarationOrRuleBlock, AnyCssProperty,
+    CssAtRule, CssLayerAtRule, CssLayerDeclaration, CssQualifiedRule, CssRoot, CssRuleBlock,
+    CssRuleList,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// A custom property defined in a `:root` rule of a stylesheet, outside any at-rule other than
+/// `@layer`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RootCustomProperty {
+    /// The name of the cascade layer of the rule, such as `base.tokens`, or `None` when the rule
+    /// isn't in a layer
+    pub layer: Option<String>,
+    /// The name of the property, such as `--color-primary`
+    pub name: String,
+    /// The value of the property, with its whitespace collapsed
+    pub value: String,
+    /// The range of the name of the property
+    pub range: TextRange,
+}
+
+/// Returns the custom properties defined in the `:root` rules of `root`.
+pub fn root_custom_properties(root: &CssRoot) -> Vec<RootCustomProperty> {
+    root.syntax()
+        .descendants()
+        .filter_map(CssQualifiedRule::cast)
+        .flat_map(|rule| rule_custom_properties(&rule))
+        .collect()
+}
+
+/// Returns the custom properties defined by `rule`, if it's a `:root` rule outside any at-rule
+/// other than `@layer`.
+pub fn rule_custom_properties(rule: &CssQualifiedRule) -> Vec<RootCustomProperty> {
+    if rule.prelude().syntax().text_trimmed() != ":root" {
+        return Vec::new();
+    }
+    let Some(layer) = root_rule_layer(rule) else {
+        return Vec::new();
+    };
+    let Ok(AnyCssDeclarationOrRuleBlock::CssDeclarationOrRuleBlock(block)) = rule.block() else {
+        return Vec::new();
+    };
+    block
+        .items()
+        .into_iter()
+        .filter_map(|item| {
+            let AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(declaration) = item else {
+                return None;
+            };
+            let AnyCssProperty::CssGenericProperty(property) =
+                declaration.declaration().ok()?.property().ok()?
+            else {
+                return None;
+            };
+            let AnyCssDeclarationName::CssDashedIdentifier(name) = property.name().ok()? else {
+                return None;
+            };
+            let name = name.value_token().ok()?;
+            let value = property.value().syntax().text_trimmed().to_string();
+            Some(RootCustomProperty {
+                layer: layer.clone(),
+                name: name.text_trimmed().to_string(),
+                value: value.split_whitespace().collect::<Vec<_>>().join(" "),
+                range: name.text_trimmed_range(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the cascade layer of a rule at the root of the stylesheet, or nested in named
+/// `@layer` blocks.
+///
+/// Returns `None` if the rule is nested in another rule, in an anonymous layer or in another
+/// at-rule.
+fn root_rule_layer(rule: &CssQualifiedRule) -> Option<Option<String>> {
+    let mut layers = Vec::new();
+    let mut list = rule.syntax().parent().and_then(CssRuleList::cast)?;
+    loop {
+        let parent = list.syntax().parent()?;
+        if CssRoot::can_cast(parent.kind()) {
+            break;
+        }
+        let block = CssRuleBlock::cast(parent)?;
+        let declaration = block.parent::<CssLayerDeclaration>()?;
+        let at_rule = declaration
+            .parent::<CssLayerAtRule>()?
+            .parent::<CssAtRule>()?;
+        let references = declaration.references();
+        if references.len() != 1 {
+            return None;
+        }
+        let name = references
+            .first()?
+            .ok()?
+            .syntax()
+            .text_trimmed()
+            .to_string();
+        layers.push(name.split_whitespace().collect::<String>());
+        list = at_rule.parent::<CssRuleList>()?;
+    }
+    if layers.is_empty() {
+        Some(None)
+    } else {
+        layers.reverse();
+        Some(Some(layers.join(".")))
+    }
+}
+
+/// The custom properties defined in the `:root` rules of the stylesheets of a project
+#[derive(Debug, Default)]
+pub struct CustomPropertyIndex {
+    /// The folder of the project. The paths of the stylesheets are displayed relative to it.
+    root: PathBuf,
+    /// The custom properties of each stylesheet, by absolute path
+    files: RwLock<BTreeMap<PathBuf, Vec<RootCustomProperty>>>,
+}
+
+impl CustomPropertyIndex {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root: absolute_path(&root),
+            files: RwLock::default(),
+        }
+    }
+
+    /// Indexes the custom properties of the stylesheet `root`, stored at `path`.
+    ///
+    /// The custom properties previously indexed for `path` are replaced.
+    pub fn insert(&self, path: &Path, root: &CssRoot) {
+        let properties = root_custom_properties(root);
+        let path = absolute_path(path);
+        let mut files = self.files.write().unwrap();
+        if properties.is_empty() {
+            files.remove(&path);
+        } else {
+            files.insert(path, properties);
+        }
+    }
+
+    /// Returns the stylesheets, other than `path`, that define `property` with the same value
+    /// in the same cascade layer.
+    ///
+    /// The paths are relative to the folder of the project.
+    pub fn other_definitions(&self, path: &Path, property: &RootCustomProperty) -> Vec<PathBuf> {
+        let path = absolute_path(path);
+        let files = self.files.read().unwrap();
+        files
+            .iter()
+            .filter(|(other_path, properties)| {
+                **other_path != path
+                    && properties.iter().any(|other| {
+                        other.name == property.name
+                            && other.layer == property.layer
+                            && other.value == property.value
+                    })
+            })
+            .map(|(other_path, _)| {
+                other_path
+                    .strip_prefix(&self.root)
+                    .unwrap_or(other_path)
+                    .to_path_buf()
+            })
+            .collect()
+    }
+}
+
+/// Returns the absolute path of `path`, relative to the working directory when `path` is
+/// relative
+fn absolute_path(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
@@ -1,22 +1,28 @@
+pub mod custom_properties;
 mod keywords;
 mod lint;
 pub mod options;
 mod registry;
 mod services;
+mod shorthand;
 mod suppression_action;
 mod utils;
 
+use crate::custom_properties::CustomPropertyIndex;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
+use biome_analyze::browserslist::BrowserTargets;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{category, Error};
-use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax, SuppressionDiagnostic};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
+
+pub(crate) type CssRuleAction = RuleAction<CssLanguage>;
 
 pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     let mut metadata = MetadataRegistry::default();
@@ -26,18 +32,31 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The `browser_targets` are the browsers targeted by the project; the default browsers are
+/// targeted when they are `None`. The `custom_properties` are the custom properties defined by
+/// the stylesheets of the project, when the whole project is analyzed.
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    browser_targets: Option<Arc<BrowserTargets>>,
+    custom_properties: Option<Arc<CustomPropertyIndex>>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        browser_targets,
+        custom_properties,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +70,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    browser_targets: Option<Arc<BrowserTargets>>,
+    custom_properties: Option<Arc<CustomPropertyIndex>>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -63,7 +84,7 @@ where
     ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
-        for comment in parse_suppression_comment(text) {
+        for comment in parse_suppression_comment_with(text, CommentSyntax::C_STYLE) {
             let categories = match comment {
                 Ok(comment) => {
                     if comment.is_legacy {
@@ -99,7 +120,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -118,6 +139,8 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(browser_targets.unwrap_or_default());
+    services.insert_service(custom_properties.unwrap_or_default());
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -186,6 +209,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,17 +2,15 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::{CssFunction, CssParameter};
-use biome_rowan::AstNode;
-use biome_rowan::AstSeparatedList;
-use biome_string_case::StrLikeExtension;
-use regex::Regex;
-use std::sync::LazyLock;
-
-use crate::utils::vendor_prefixed;
+use biome_css_syntax::{AnyCssValue, CssFunction};
+use biome_css_value::dimension::{Dimension, UnitKind};
+use biome_css_value::gradient::{Gradient, GradientArgument, GradientKind, GradientPrelude};
+use biome_css_value::keyword;
+use biome_rowan::{AstNode, TextRange};
 
 declare_lint_rule! {
-    /// Disallow non-standard direction values for linear gradient functions.
+    /// Disallow non-standard direction values for linear gradient functions, and invalid color
+    /// stops in gradient functions.
     ///
     /// A valid and standard direction value is one of the following:
     /// - an angle
@@ -20,6 +18,15 @@ declare_lint_rule! {
     ///
     /// A common mistake (matching outdated non-standard syntax) is to use just a side-or-corner without the preceding to.
     ///
+    /// The rule also checks the color stops of all the gradient functions: `linear-gradient()`,
+    /// `radial-gradient()`, `conic-gradient()` and their `repeating-` variants. It reports:
+    /// - the colors that aren't separated by a comma, such as `red blue`
+    /// - the color hints, such as `40%` in `red, 40%, blue`, that aren't between two color stops
+    /// - the positions that are smaller than a previous position of the same unit, because the
+    ///   browsers move them to the previous position, which makes the color change at once
+    ///
+    /// A color stop can have two positions, such as `red 10% 20%`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -32,6 +39,14 @@ declare_lint_rule! {
     /// .foo { background: linear-gradient(45, #fff, #000); }
     /// ```
     ///
+    /// ```css,expect_diagnostic
+    /// .foo { background: radial-gradient(circle, #fff #000); }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .foo { background: conic-gradient(#fff 50%, #000 25%); }
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```css
@@ -42,6 +57,10 @@ declare_lint_rule! {
     /// .foo { background: linear-gradient(45deg, #fff, #000); }
     /// ```
     ///
+    /// ```css
+    /// .foo { background: radial-gradient(circle at center, #fff 10% 20%, 40%, #000); }
+    /// ```
+    ///
     pub NoInvalidDirectionInLinearGradient {
         version: "1.9.9",
         name: "noInvalidDirectionInLinearGradient",
@@ -51,77 +70,52 @@ declare_lint_rule! {
     }
 }
 
-// It is necessary to find case-insensitive string.
-// Also Check if 'in' is a word boundary.
-// For examples,`to top in srgb` is valid but `to top insrgb` is not valid.
-pub static IN_KEYWORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bin\b").unwrap());
-
-// This regex checks if a string consists of a number immediately followed by a unit, with no space between them.
-// For examples, `45deg`, `45grad` is valid but `45 deg`, `45de` is not valid.
-pub static ANGLE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^[\d.]+(?:deg|grad|rad|turn)$").unwrap());
-
-// This need for capture `side-or-corner` keyword from linear-gradient function.
-// Ensure starts `side-or-corner` keyword `to` and ends with the keyword `side-or-corner`.
-pub static DIRECTION_WITHOUT_TO: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(&format!(r"(?i)^({0})(?: ({0}))?$", "top|left|bottom|right")).unwrap()
-});
+/// An invalid part of a gradient function
+pub enum InvalidGradient {
+    /// The direction of a linear gradient isn't standard, such as `top`
+    NonstandardDirection(TextRange),
+    /// Several colors aren't separated by commas, such as `red blue`
+    MissingComma(TextRange),
+    /// A color hint isn't between two color stops, such as `40%` in `red, 40%`
+    MisplacedHint(TextRange),
+    /// A position is smaller than a previous position of the same unit
+    DecreasingPosition {
+        range: TextRange,
+        previous_range: TextRange,
+    },
+}
 
 impl Rule for NoInvalidDirectionInLinearGradient {
     type Query = Ast<CssFunction>;
-    type State = CssParameter;
-    type Signals = Option<Self::State>;
+    type State = InvalidGradient;
+    type Signals = Box<[Self::State]>;
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
-        let node_name = node.name().ok()?.text();
-        let linear_gradient_property = [
-            "linear-gradient",
-            "-webkit-linear-gradient",
-            "-moz-linear-gradient",
-            "-o-linear-gradient",
-            "-ms-linear-gradient",
-        ];
-        if !linear_gradient_property.contains(&node_name.to_ascii_lowercase_cow().as_ref()) {
-            return None;
-        }
-        let css_parameter = node.items();
+        let Some(gradient) = Gradient::from_function(node) else {
+            return Box::default();
+        };
 
-        let first_css_parameter = css_parameter.first()?.ok()?;
-        let first_css_parameter_text = first_css_parameter.text();
-        if IN_KEYWORD.is_match(&first_css_parameter_text) {
-            return None;
-        }
-        if let Some(first_byte) = first_css_parameter_text.bytes().next() {
-            if first_byte.is_ascii_digit() {
-                if ANGLE.is_match(&first_css_parameter_text) {
-                    return None;
+        let mut signals = Vec::new();
+        if gradient.kind == GradientKind::Linear {
+            if let Some(prelude) = gradient.prelude() {
+                if !is_standard_direction(prelude, gradient.prefixed) {
+                    signals.push(InvalidGradient::NonstandardDirection(
+                        prelude.parameter.range(),
+                    ));
                 }
-                return Some(first_css_parameter);
             }
         }
-        let direction_property = ["top", "left", "bottom", "right"];
-        if !direction_property.iter().any(|&keyword| {
-            first_css_parameter_text
-                .to_ascii_lowercase_cow()
-                .contains(keyword)
-        }) {
-            return None;
-        }
-        let has_prefix = vendor_prefixed(&node_name);
-        if !is_standdard_direction(&first_css_parameter_text, has_prefix) {
-            return Some(first_css_parameter);
-        }
-        None
+        check_color_stops(&gradient, &mut signals);
+        signals.into_boxed_slice()
     }
 
-    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
-        let span = node.range();
-        Some(
-            RuleDiagnostic::new(
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidGradient::NonstandardDirection(range) => RuleDiagnostic::new(
                 rule_category!(),
-                span,
+                range,
                 markup! {
                     "Unexpected nonstandard direction"
                 },
@@ -130,29 +124,151 @@ impl Rule for NoInvalidDirectionInLinearGradient {
             })
             .note(markup! {
                 "See "<Hyperlink href="https://developer.mozilla.org/en-US/docs/Web/CSS/gradient/linear-gradient">"MDN web docs"</Hyperlink>" for more details."
+            }),
+            InvalidGradient::MissingComma(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Expected a comma between the colors of the gradient."
+                },
+            ).note(markup! {
+                "A color stop is a single color followed by up to two positions, such as "<Emphasis>"red 10% 20%"</Emphasis>". The color stops are separated by commas."
+            }),
+            InvalidGradient::MisplacedHint(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Unexpected color hint outside of two color stops."
+                },
+            ).note(markup! {
+                "A color hint is a position between two color stops, such as "<Emphasis>"40%"</Emphasis>" in "<Emphasis>"red, 40%, blue"</Emphasis>", where the two colors are mixed half and half."
+            }),
+            InvalidGradient::DecreasingPosition {
+                range,
+                previous_range,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Unexpected position smaller than a previous position of the gradient."
+                },
+            ).detail(previous_range, markup! {
+                "The previous position is here:"
             })
-        )
+            .note(markup! {
+                "The browsers move this position to the previous one, which makes the color change at once. Sort the positions in increasing order."
+            }),
+        };
+        Some(diagnostic)
     }
 }
 
-fn is_standdard_direction(direction: &str, has_prefix: bool) -> bool {
-    let matches = match (has_prefix, direction.starts_with("to ")) {
-        (true, false) => DIRECTION_WITHOUT_TO.captures(direction),
-        (false, true) => DIRECTION_WITHOUT_TO.captures(&direction[3..]),
+/// Returns `true` if the prelude of a linear gradient is an angle or a standard side-or-corner.
+///
+/// The gradients with a vendor prefix use the legacy syntax, where the side-or-corner isn't
+/// preceded by `to`.
+fn is_standard_direction(prelude: &GradientPrelude, has_prefix: bool) -> bool {
+    match prelude.values.as_slice() {
+        // `in srgb`
+        [] => true,
+        [AnyCssValue::AnyCssDimension(dimension)] => Dimension::from_dimension(dimension)
+            .is_some_and(|dimension| dimension.kind() == Some(UnitKind::Angle)),
+        // `calc(90deg * 2)`
+        [AnyCssValue::AnyCssFunction(_)] => true,
+        values => {
+            let Some(keywords) = values.iter().map(keyword).collect::<Option<Vec<_>>>() else {
+                return false;
+            };
+            let sides = match (has_prefix, keywords.split_first()) {
+                (false, Some((to, sides))) if to == "to" => sides,
+                (true, _) => keywords.as_slice(),
+                _ => return false,
+            };
+            match sides {
+                [side] => side_axis(side).is_some(),
+                [first, second] => match (side_axis(first), side_axis(second)) {
+                    (Some(first), Some(second)) => first != second,
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Returns `true` for the horizontal sides, `false` for the vertical sides, and `None` if
+/// `side` isn't a side
+fn side_axis(side: &str) -> Option<bool> {
+    match side {
+        "left" | "right" => Some(true),
+        "top" | "bottom" => Some(false),
         _ => None,
-    };
-    if let Some(matches) = matches {
-        match (matches.get(1), matches.get(2)) {
-            (Some(_), None) => {
-                return true;
+    }
+}
+
+/// Reports the arguments without commas, the misplaced color hints and the decreasing
+/// positions of `gradient`.
+///
+/// The arguments whose values can't be resolved statically, such as `var(--stops)`, could be
+/// any number of color stops, so the hints around them aren't checked.
+fn check_color_stops(gradient: &Gradient, signals: &mut Vec<InvalidGradient>) {
+    let arguments: Vec<_> = gradient
+        .arguments
+        .iter()
+        .filter(|argument| !matches!(argument, GradientArgument::Prelude(_)))
+        .collect();
+    // The largest position of each unit
+    let mut largest_positions: Vec<(&str, f64, TextRange)> = Vec::new();
+    for (index, argument) in arguments.iter().enumerate() {
+        let positions = match argument {
+            GradientArgument::MissingComma(parameter) => {
+                signals.push(InvalidGradient::MissingComma(parameter.range()));
+                continue;
             }
-            (Some(first_direction), Some(second_direction)) => {
-                if first_direction.as_str() != second_direction.as_str() {
-                    return true;
+            GradientArgument::Hint { position, .. } => {
+                let is_color_stop = |argument: Option<&&GradientArgument>| {
+                    matches!(
+                        argument,
+                        Some(
+                            GradientArgument::ColorStop { .. }
+                                | GradientArgument::MissingComma(_)
+                                | GradientArgument::Unknown(_)
+                        )
+                    )
+                };
+                let previous = index.checked_sub(1).and_then(|index| arguments.get(index));
+                if !is_color_stop(previous) || !is_color_stop(arguments.get(index + 1)) {
+                    signals.push(InvalidGradient::MisplacedHint(argument.range()));
+                }
+                std::slice::from_ref(position)
+            }
+            GradientArgument::ColorStop { positions, .. } => positions.as_slice(),
+            GradientArgument::Prelude(_) | GradientArgument::Unknown(_) => continue,
+        };
+
+        for position in positions {
+            let Some(dimension) = position.dimension else {
+                continue;
+            };
+            let unit = dimension.unit.map_or("", |unit| unit.name());
+            let value = dimension.value;
+            match largest_positions
+                .iter_mut()
+                .find(|(largest_unit, _, _)| *largest_unit == unit)
+            {
+                Some((_, largest_value, largest_range)) => {
+                    if value < *largest_value {
+                        signals.push(InvalidGradient::DecreasingPosition {
+                            range: position.range,
+                            previous_range: *largest_range,
+                        });
+                    } else {
+                        *largest_value = value;
+                        *largest_range = position.range;
+                    }
                 }
+                None => largest_positions.push((unit, value, position.range)),
             }
-            _ => return true,
         }
     }
-    false
 }
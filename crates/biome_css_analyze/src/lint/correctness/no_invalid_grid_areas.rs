@@ -3,7 +3,8 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_css_syntax::CssDeclarationOrRuleList;
-use biome_rowan::{TextRange, TokenText};
+use biome_css_value::grid::{grid_area_rows, GridAreaRow};
+use biome_rowan::TextRange;
 
 use rustc_hash::FxHashSet;
 
@@ -57,9 +58,6 @@ declare_lint_rule! {
     }
 }
 
-type GridAreasProp = (String, TextRange);
-type GridAreasProps = Vec<(TokenText, TextRange)>;
-
 const GRID_AREA_PROPERTIES: [&str; 3] = ["grid", "grid-template", "grid-template-areas"];
 
 #[derive(Debug)]
@@ -84,7 +82,7 @@ impl Rule for NoInvalidGridAreas {
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
         // Extracting the property values of grid-template-areas
-        let plain_grid_areas_props = node
+        let rows = node
             .into_iter()
             .filter_map(|item| {
                 let binding = item
@@ -103,22 +101,11 @@ impl Rule for NoInvalidGridAreas {
                 }
                 None
             })
-            .flat_map(|grid_props| {
-                grid_props
-                    .into_iter()
-                    .filter_map(|x| x.as_any_css_value()?.as_css_string()?.value_token().ok())
-            })
-            // Need to remove `"` with escaping slash from the grid area
-            // Ex: "\"a a a\""
-            .map(|x| {
-                let trimmed_text = x.token_text_trimmed();
-                let text_range = x.text_range();
-                (trimmed_text, text_range)
-            })
-            .collect::<GridAreasProps>();
+            .flat_map(|grid_props| grid_area_rows(&grid_props))
+            .collect::<Vec<_>>();
 
-        if !plain_grid_areas_props.is_empty() {
-            is_consistent_grids(plain_grid_areas_props)
+        if !rows.is_empty() {
+            is_consistent_grids(&rows)
         } else {
             None
         }
@@ -167,29 +154,27 @@ impl Rule for NoInvalidGridAreas {
 }
 
 // Check if the grid areas are consistent
-fn is_consistent_grids(grid_areas_props: GridAreasProps) -> Option<UseConsistentGridAreasState> {
-    let first_prop = inner_string_text(&grid_areas_props[0].0);
-    let first_len = first_prop.len();
-    let mut shortest = &grid_areas_props[0];
+fn is_consistent_grids(rows: &[GridAreaRow]) -> Option<UseConsistentGridAreasState> {
+    let first_len = rows[0].cells.len();
+    let mut shortest = &rows[0];
 
-    for grid_areas_prop in &grid_areas_props {
-        let cleaned_text = inner_string_text(&grid_areas_prop.0);
+    for row in rows {
         // Check if the grid areas are empty
-        if cleaned_text.is_empty() {
+        if row.is_empty() {
             return Some(UseConsistentGridAreasState {
                 text: None,
-                span: grid_areas_prop.1,
+                span: row.range,
                 reason: GridAreaValidationError::EmptyGridArea,
             });
         }
-        // Check if all elements have the same length
-        if cleaned_text.len() != first_len {
-            if cleaned_text.len() < inner_string_text(&shortest.0).len() {
-                shortest = grid_areas_prop;
+        // Check if all rows have the same number of cells
+        if row.cells.len() != first_len {
+            if row.cells.len() < shortest.cells.len() {
+                shortest = row;
             }
             return Some(UseConsistentGridAreasState {
                 text: None,
-                span: shortest.1,
+                span: shortest.range,
                 reason: GridAreaValidationError::InconsistentCellCount,
             });
         }
@@ -200,20 +185,17 @@ fn is_consistent_grids(grid_areas_props: GridAreasProps) -> Option<UseConsistent
     // {"a a a"
     //  "b b b"; }
     //  are the consistent grid properties because it forms a single filled-in rectangle.
-    if grid_areas_props
-        .iter()
-        .all(|prop| is_all_same(prop.0.clone()))
-    {
+    if rows.iter().all(is_all_same) {
         return None;
     }
     //  But in the following grid areas:
     //  {"a a a"
     //   "b b a"; }
     //   are not consistent because `a` breaks a single filled-in rectangle.
-    if let Some(result) = has_partial_match(&grid_areas_props) {
+    if let Some((text, span)) = has_partial_match(rows) {
         return Some(UseConsistentGridAreasState {
-            text: Some(result.0),
-            span: result.1,
+            text: Some(text),
+            span,
             reason: GridAreaValidationError::DuplicateGridToken,
         });
     }
@@ -221,44 +203,26 @@ fn is_consistent_grids(grid_areas_props: GridAreasProps) -> Option<UseConsistent
     None
 }
 
-// Check if all characters in a string are the same
-fn is_all_same(token_text: TokenText) -> bool {
-    let prop = inner_string_text(&token_text);
-    let mut iter = prop.chars().filter(|c| !c.is_whitespace());
+// Check if all the cells of a row are the same
+fn is_all_same(row: &GridAreaRow) -> bool {
+    let mut iter = row.cells.iter();
     let Some(head) = iter.next() else {
         return true;
     };
-    iter.all(|c| c == head)
+    iter.all(|cell| cell == head)
 }
 
-fn has_partial_match(grid_areas_props: &GridAreasProps) -> Option<GridAreasProp> {
-    let mut seen_parts = FxHashSet::default();
+fn has_partial_match(rows: &[GridAreaRow]) -> Option<(String, TextRange)> {
+    let mut seen_cells = FxHashSet::default();
 
-    for (text, range) in grid_areas_props {
-        let prop = inner_string_text(text);
-        let parts: FxHashSet<String> = prop
-            .split_whitespace()
-            .map(|part| part.to_string())
-            .collect();
-        for part in parts {
-            if !seen_parts.insert(part.clone()) {
-                return Some((part, *range));
+    for row in rows {
+        let mut row_cells = FxHashSet::default();
+        for cell in &row.cells {
+            if row_cells.insert(cell.as_str()) && !seen_cells.insert(cell.as_str()) {
+                return Some((cell.clone(), row.range));
             }
         }
     }
 
     None
 }
-
-fn inner_string_text(text: &TokenText) -> &str {
-    let result = text.text();
-    if result.len() >= 2 {
-        debug_assert!(
-            (result.starts_with('"') && result.len() >= 2 && result.ends_with('"'))
-                || (result.starts_with('\'') && result.len() >= 2 && result.ends_with('\''))
-        );
-        result[1..result.len() - 1].trim()
-    } else {
-        result
-    }
-}
@@ -5,12 +5,25 @@ use biome_analyze::declare_lint_group;
 pub mod no_descending_specificity;
 pub mod no_duplicate_custom_properties;
 pub mod no_duplicate_properties;
+pub mod no_global_leakage;
 pub mod no_irregular_whitespace;
+pub mod no_low_contrast_colors;
 pub mod no_missing_var_function;
+pub mod no_redundant_longhand;
+pub mod no_undefined_composes;
 pub mod no_unknown_pseudo_class;
 pub mod no_unknown_pseudo_element;
 pub mod no_unknown_type_selector;
+pub mod no_unsupported_css_features;
+pub mod no_unused_custom_properties;
 pub mod no_value_at_rule;
+pub mod no_vendor_prefix;
+pub mod use_consistent_color_format;
+pub mod use_css_variables_over_sass_variables;
+pub mod use_logical_properties;
+pub mod use_max_nesting_depth;
+pub mod use_max_specificity;
+pub mod use_shorthand_properties;
 
 declare_lint_group! {
     pub Nursery {
@@ -19,12 +32,25 @@ declare_lint_group! {
             self :: no_descending_specificity :: NoDescendingSpecificity ,
             self :: no_duplicate_custom_properties :: NoDuplicateCustomProperties ,
             self :: no_duplicate_properties :: NoDuplicateProperties ,
+            self :: no_global_leakage :: NoGlobalLeakage ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_low_contrast_colors :: NoLowContrastColors ,
             self :: no_missing_var_function :: NoMissingVarFunction ,
+            self :: no_redundant_longhand :: NoRedundantLonghand ,
+            self :: no_undefined_composes :: NoUndefinedComposes ,
             self :: no_unknown_pseudo_class :: NoUnknownPseudoClass ,
             self :: no_unknown_pseudo_element :: NoUnknownPseudoElement ,
             self :: no_unknown_type_selector :: NoUnknownTypeSelector ,
+            self :: no_unsupported_css_features :: NoUnsupportedCssFeatures ,
+            self :: no_unused_custom_properties :: NoUnusedCustomProperties ,
             self :: no_value_at_rule :: NoValueAtRule ,
+            self :: no_vendor_prefix :: NoVendorPrefix ,
+            self :: use_consistent_color_format :: UseConsistentColorFormat ,
+            self :: use_css_variables_over_sass_variables :: UseCssVariablesOverSassVariables ,
+            self :: use_logical_properties :: UseLogicalProperties ,
+            self :: use_max_nesting_depth :: UseMaxNestingDepth ,
+            self :: use_max_specificity :: UseMaxSpecificity ,
+            self :: use_shorthand_properties :: UseShorthandProperties ,
         ]
      }
 }
@@ -1,11 +1,14 @@
 use std::collections::hash_map::Entry;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
-use biome_css_syntax::CssDeclarationOrRuleList;
+use biome_css_syntax::{CssDeclarationOrRuleBlock, CssDeclarationOrRuleList, CssQualifiedRule};
 use biome_rowan::{AstNode, TextRange};
 use rustc_hash::FxHashMap;
 
+use crate::custom_properties::{rule_custom_properties, CustomPropertyIndex};
 use crate::services::semantic::Semantic;
 
 declare_lint_rule! {
@@ -13,6 +16,24 @@ declare_lint_rule! {
     ///
     /// This rule checks the declaration blocks for duplicate custom properties.
     ///
+    /// When the whole project is analyzed, the rule also reports the custom properties of the
+    /// `:root` rules that another stylesheet of the project defines with the same value, in the
+    /// same cascade layer. Such duplicates are usually design tokens copied from one stylesheet
+    /// to another: the copies drift apart when only one of them is updated.
+    ///
+    /// ```css
+    /// /* tokens.css */
+    /// @layer tokens {
+    ///   :root { --color-primary: #0052cc; }
+    /// }
+    ///
+    /// /* button.css */
+    /// @layer tokens {
+    ///   /* --color-primary is already defined in tokens.css */
+    ///   :root { --color-primary: #0052cc; }
+    /// }
+    /// ```
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -44,60 +65,129 @@ declare_lint_rule! {
     }
 }
 
+pub enum DuplicateCustomProperty {
+    /// The property is defined twice in the same declaration block
+    InBlock {
+        first_occurrence: TextRange,
+        duplicate: TextRange,
+        name: String,
+    },
+    /// The property is defined with the same value in the same cascade layer by other
+    /// stylesheets of the project
+    InOtherFiles {
+        range: TextRange,
+        name: String,
+        files: Vec<PathBuf>,
+    },
+}
+
 impl Rule for NoDuplicateCustomProperties {
     type Query = Semantic<CssDeclarationOrRuleList>;
-    type State = (TextRange, (TextRange, String));
-    type Signals = Option<Self::State>;
+    type State = DuplicateCustomProperty;
+    type Signals = Vec<Self::State>;
     type Options = ();
 
-    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
         let model = ctx.model();
 
-        let rule = model.get_rule_by_range(node.range())?;
-
-        let mut seen: FxHashMap<&str, TextRange> = FxHashMap::default();
+        let mut signals = Vec::new();
+        if let Some(rule) = model.get_rule_by_range(node.range()) {
+            let mut seen: FxHashMap<&str, TextRange> = FxHashMap::default();
 
-        for declaration in rule.declarations.iter() {
-            let prop = &declaration.property;
-            let prop_name = prop.name.as_str();
-            let prop_range = prop.range;
+            for declaration in rule.declarations.iter() {
+                let prop = &declaration.property;
+                let prop_name = prop.name.as_str();
+                let prop_range = prop.range;
 
-            let is_custom_property = prop_name.starts_with("--");
+                let is_custom_property = prop_name.starts_with("--");
 
-            if !is_custom_property {
-                continue;
-            }
-
-            match seen.entry(prop_name) {
-                Entry::Occupied(entry) => {
-                    return Some((*entry.get(), (prop_range, prop_name.to_string())));
+                if !is_custom_property {
+                    continue;
                 }
-                Entry::Vacant(_) => {
-                    seen.insert(prop_name, prop_range);
+
+                match seen.entry(prop_name) {
+                    Entry::Occupied(entry) => {
+                        signals.push(DuplicateCustomProperty::InBlock {
+                            first_occurrence: *entry.get(),
+                            duplicate: prop_range,
+                            name: prop_name.to_string(),
+                        });
+                        break;
+                    }
+                    Entry::Vacant(_) => {
+                        seen.insert(prop_name, prop_range);
+                    }
                 }
             }
         }
 
-        None
+        // The index is empty when the project isn't analyzed
+        let Some(index) = ctx.get_service::<Arc<CustomPropertyIndex>>() else {
+            return signals;
+        };
+        let Some(rule) = node
+            .parent::<CssDeclarationOrRuleBlock>()
+            .and_then(|block| block.parent::<CssQualifiedRule>())
+        else {
+            return signals;
+        };
+        for property in rule_custom_properties(&rule) {
+            let files = index.other_definitions(ctx.file_path(), &property);
+            if !files.is_empty() {
+                signals.push(DuplicateCustomProperty::InOtherFiles {
+                    range: property.range,
+                    name: property.name,
+                    files,
+                });
+            }
+        }
+        signals
     }
 
     fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
-        let (first_occurrence_range, (duplicate_range, duplicate_property_name)) = state;
-        Some(
-            RuleDiagnostic::new(
-                rule_category!(),
-                duplicate_range,
-                markup! {
-                    "Duplicate custom properties can lead to unexpected behavior and may override previous declarations unintentionally."
-                },
-            )
-            .detail(first_occurrence_range, markup! {
-                <Emphasis>{duplicate_property_name}</Emphasis> " is already defined here."
-            })
-            .note(markup! {
-                "Remove or rename the duplicate custom property to ensure consistent styling."
-            }),
-        )
+        match state {
+            DuplicateCustomProperty::InBlock {
+                first_occurrence,
+                duplicate,
+                name,
+            } => Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    duplicate,
+                    markup! {
+                        "Duplicate custom properties can lead to unexpected behavior and may override previous declarations unintentionally."
+                    },
+                )
+                .detail(first_occurrence, markup! {
+                    <Emphasis>{name}</Emphasis> " is already defined here."
+                })
+                .note(markup! {
+                    "Remove or rename the duplicate custom property to ensure consistent styling."
+                }),
+            ),
+            DuplicateCustomProperty::InOtherFiles { range, name, files } => {
+                let files = files
+                    .iter()
+                    .map(|file| file.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        range,
+                        markup! {
+                            "The custom property "<Emphasis>{name}</Emphasis>" is already defined with the same value in the same cascade layer."
+                        },
+                    )
+                    .note(markup! {
+                        "It's also defined in "<Emphasis>{files}</Emphasis>"."
+                    })
+                    .note(markup! {
+                        "Define the custom property in a single stylesheet, so that its copies don't drift apart."
+                    }),
+                )
+            }
+        }
     }
 }
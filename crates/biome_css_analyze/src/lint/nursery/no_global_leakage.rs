@@ -0,0 +1,115 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::is_in_global_scope;
+use biome_css_syntax::{
+    AnyCssSelector, CssClassSelector, CssFileSource, CssIdSelector, CssNestedQualifiedRule,
+    CssQualifiedRule,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Disallow the selectors of a CSS module that aren't scoped to the module.
+    ///
+    /// The bundler renames the classes and the identifiers of a
+    /// [CSS module](https://github.com/css-modules/css-modules), so that the styles of the module
+    /// only apply to the elements that use its classes.
+    /// A selector without any local class or identifier, such as `button`, `:global(.title)` or
+    /// `:global .theme a`, isn't renamed: it applies to the whole page, and the styles leak out of
+    /// the module.
+    ///
+    /// The nested rules are scoped by their parent rule, so only the selectors of the top-level
+    /// rules are checked.
+    ///
+    /// This rule only applies to the files whose name ends with `.module.css`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic,css_modules
+    /// button {
+    ///     color: red;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic,css_modules
+    /// :global(.title) {
+    ///     color: red;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,css_modules
+    /// .card button {
+    ///     color: red;
+    /// }
+    /// ```
+    ///
+    /// ```css,css_modules
+    /// :global(.dark) .card {
+    ///     color: white;
+    /// }
+    /// ```
+    ///
+    pub NoGlobalLeakage {
+        version: "next",
+        name: "noGlobalLeakage",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoGlobalLeakage {
+    type Query = Ast<CssQualifiedRule>;
+    type State = AnyCssSelector;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let rule = ctx.query();
+        if !CssFileSource::is_css_module_path(ctx.file_path()) || is_nested(rule) {
+            return Box::default();
+        }
+        rule.prelude()
+            .iter()
+            .filter_map(|selector| selector.ok())
+            .filter(|selector| !is_local_selector(selector))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This selector isn't scoped to the module, so its styles apply to the whole page."
+                },
+            )
+            .note(markup! {
+                "The selectors of a CSS module should contain at least one local class or identifier."
+            })
+            .note(markup! {
+                "Add a local class to the selector, or move the rule to a global stylesheet."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the rule is nested in another rule, whose selector scopes it
+fn is_nested(rule: &CssQualifiedRule) -> bool {
+    rule.syntax().ancestors().skip(1).any(|ancestor| {
+        CssQualifiedRule::can_cast(ancestor.kind())
+            || CssNestedQualifiedRule::can_cast(ancestor.kind())
+    })
+}
+
+/// Returns `true` if the selector contains a class or an identifier that is renamed by the
+/// bundler
+fn is_local_selector(selector: &AnyCssSelector) -> bool {
+    selector.syntax().descendants().any(|node| {
+        (CssClassSelector::can_cast(node.kind()) || CssIdSelector::can_cast(node.kind()))
+            && !is_in_global_scope(&node)
+    })
+}
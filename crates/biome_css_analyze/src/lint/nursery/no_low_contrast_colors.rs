@@ -0,0 +1,217 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssGenericComponentValue, AnyCssValue, CssDeclarationOrRuleList, CssGenericProperty,
+};
+use biome_css_value::{color::Color, dimension::Dimension};
+use biome_rowan::{AstNode, TextRange};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Disallow text colors that don't contrast enough with the background color.
+    ///
+    /// Text with a low contrast is hard to read, especially for people with low vision.
+    /// The [Web Content Accessibility Guidelines](https://www.w3.org/TR/WCAG21/#contrast-minimum)
+    /// (WCAG) require a contrast ratio of at least 4.5:1 between the text and its background, and
+    /// of at least 3:1 for large text.
+    ///
+    /// This rule checks the rule sets that declare both `color` and `background-color` with a
+    /// static color: a hexadecimal color, a named color, or a call to `rgb()` or `hsl()` with
+    /// literal arguments.
+    /// The text is considered large when the rule set declares a `font-size` of at least `24px`
+    /// (`18pt`), or of at least `18.66px` (`14pt`) with a bold `font-weight`.
+    ///
+    /// The rule ignores the colors that can't be resolved statically, such as `var(--color)` or
+    /// `currentcolor`, and the background colors that are transparent.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: #999;
+    ///   background-color: #fff;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: rgb(255 255 255 / 30%);
+    ///   background-color: black;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   color: #333;
+    ///   background-color: #fff;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   color: #949494;
+    ///   background-color: #fff;
+    ///   font-size: 24px;
+    /// }
+    /// ```
+    ///
+    pub NoLowContrastColors {
+        version: "next",
+        name: "noLowContrastColors",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// The minimum contrast ratio of the normal text for the level AA of WCAG
+const MINIMUM_RATIO: f64 = 4.5;
+
+/// The minimum contrast ratio of the large text for the level AA of WCAG
+const MINIMUM_RATIO_LARGE_TEXT: f64 = 3.0;
+
+pub struct LowContrast {
+    /// The range of the value of `color`
+    color_range: TextRange,
+    /// The range of the value of `background-color`
+    background_range: TextRange,
+    ratio: f64,
+    minimum_ratio: f64,
+}
+
+impl Rule for NoLowContrastColors {
+    type Query = Ast<CssDeclarationOrRuleList>;
+    type State = LowContrast;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        // The last declaration of a property overrides the previous ones
+        let mut color = None;
+        let mut background = None;
+        let mut font_size = None;
+        let mut font_weight = None;
+        for item in node {
+            let Some(property) = item
+                .as_css_declaration_with_semicolon()
+                .and_then(|declaration| declaration.declaration().ok())
+                .and_then(|declaration| declaration.property().ok())
+            else {
+                continue;
+            };
+            let Some(property) = property.as_css_generic_property() else {
+                continue;
+            };
+            let Some(name) = property
+                .name()
+                .ok()
+                .and_then(|name| name.as_css_identifier()?.value_token().ok())
+            else {
+                continue;
+            };
+            match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+                "color" => color = Some(property.clone()),
+                "background-color" => background = Some(property.clone()),
+                "font-size" => font_size = Some(property.clone()),
+                "font-weight" => font_weight = Some(property.clone()),
+                _ => {}
+            }
+        }
+
+        let color = color?;
+        let background = background?;
+        let background_color = Color::from_css_value(&single_value(&background)?)?;
+        if !background_color.is_opaque() {
+            return None;
+        }
+        let text_color =
+            Color::from_css_value(&single_value(&color)?)?.blend_over(&background_color);
+
+        let is_large_text = font_size
+            .as_ref()
+            .and_then(font_size_in_pixels)
+            .is_some_and(|size| {
+                size >= 24.0 || (size >= 18.66 && font_weight.as_ref().is_some_and(is_bold))
+            });
+        let minimum_ratio = if is_large_text {
+            MINIMUM_RATIO_LARGE_TEXT
+        } else {
+            MINIMUM_RATIO
+        };
+        let ratio = text_color.contrast_ratio(&background_color);
+        (ratio < minimum_ratio).then(|| LowContrast {
+            color_range: color.value().range(),
+            background_range: background.value().range(),
+            ratio,
+            minimum_ratio,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        // Truncate the ratio, so that a ratio of 4.499 isn't displayed as 4.50
+        let ratio = format!("{:.2}", (state.ratio * 100.0).floor() / 100.0);
+        let minimum_ratio = state.minimum_ratio.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.color_range,
+                markup! {
+                    "The contrast ratio between the text color and the background color is "{ratio}":1, lower than the minimum of "{minimum_ratio}":1."
+                },
+            )
+            .detail(state.background_range, markup! {
+                "The background color is declared here."
+            })
+            .note(markup! {
+                "Text with a low contrast is hard to read, especially for people with low vision. WCAG requires a contrast ratio of at least 4.5:1 for normal text, and 3:1 for large text."
+            })
+            .note(markup! {
+                "Use a darker or a lighter color to increase the contrast."
+            }),
+        )
+    }
+}
+
+/// Returns the value of `property` when it's made of a single value
+fn single_value(property: &CssGenericProperty) -> Option<AnyCssValue> {
+    let mut values = property.value().into_iter();
+    let AnyCssGenericComponentValue::AnyCssValue(value) = values.next()? else {
+        return None;
+    };
+    values.next().is_none().then_some(value)
+}
+
+/// Returns the value of `font-size` in pixels, when it's expressed in an absolute length, such
+/// as `px` or `pt`
+fn font_size_in_pixels(property: &CssGenericProperty) -> Option<f64> {
+    let AnyCssValue::AnyCssDimension(dimension) = single_value(property)? else {
+        return None;
+    };
+    Dimension::from_dimension(&dimension)?.to_pixels()
+}
+
+/// Returns `true` if `font-weight` is `bold`, `bolder`, or a weight of at least `700`
+fn is_bold(property: &CssGenericProperty) -> bool {
+    match single_value(property) {
+        Some(AnyCssValue::CssIdentifier(identifier)) => {
+            identifier.value_token().is_ok_and(|value| {
+                matches!(
+                    value.text_trimmed().to_ascii_lowercase_cow().as_ref(),
+                    "bold" | "bolder"
+                )
+            })
+        }
+        Some(AnyCssValue::CssNumber(number)) => number.value_token().is_ok_and(|value| {
+            value
+                .text_trimmed()
+                .parse::<f64>()
+                .is_ok_and(|weight| weight >= 700.0)
+        }),
+        _ => false,
+    }
+}
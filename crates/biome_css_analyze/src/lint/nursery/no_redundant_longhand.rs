@@ -0,0 +1,269 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDeclarationOrRule, AnyCssFunction, AnyCssGenericComponentValue, AnyCssValue,
+    CssDeclarationOrRuleList, CssDeclarationWithSemicolon, CssGenericProperty, CssSyntaxNode,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, Direction, TriviaPieceKind};
+use biome_string_case::StrLikeExtension;
+
+use crate::shorthand::{
+    collapsed_value_count, component_values, declaration_name, expand_values, is_css_wide_keyword,
+    single_value, Shorthand,
+};
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Disallow longhand properties that can be merged into a preceding shorthand property.
+    ///
+    /// A longhand property declared after its shorthand property in the same block, such as
+    /// `margin-top` after `margin`, overrides one of the values of the shorthand property.
+    /// The value of the longhand property can be written in the shorthand property directly.
+    ///
+    /// This rule checks the shorthand properties `margin`, `padding`, `inset`, `border-width`,
+    /// `border-style`, `border-color` and `border-radius`.
+    /// A longhand property isn't reported when the shorthand property can't be expanded
+    /// statically, such as `margin: var(--spacing)`, when only one of them is `!important`, or
+    /// when another property that sets the same sides, such as `margin-block`, is declared
+    /// between them.
+    ///
+    /// The longhand properties declared before the shorthand property are reported by
+    /// [noShorthandPropertyOverrides](https://biomejs.dev/linter/rules/no-shorthand-property-overrides/).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   margin: 0 auto;
+    ///   margin-top: 1px;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   inset: 0;
+    ///   left: auto;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   margin: 1px auto 0;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   margin: var(--spacing);
+    ///   margin-top: 1px;
+    /// }
+    /// ```
+    ///
+    pub NoRedundantLonghand {
+        version: "next",
+        name: "noRedundantLonghand",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub struct RuleState {
+    shorthand: &'static Shorthand,
+    shorthand_property: CssGenericProperty,
+    /// The values of the four sides set by the shorthand property
+    shorthand_values: [AnyCssValue; 4],
+    longhand: CssDeclarationWithSemicolon,
+    /// The index of the side set by the longhand property
+    index: usize,
+    value: AnyCssValue,
+}
+
+impl Rule for NoRedundantLonghand {
+    type Query = Ast<CssDeclarationOrRuleList>;
+    type State = RuleState;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let list = ctx.query();
+        let declarations: Vec<_> = list
+            .iter()
+            .filter_map(|item| match item {
+                AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(declaration) => {
+                    let name = declaration_name(&declaration)?;
+                    Some((name, declaration))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut signals = Vec::new();
+        for (index, (name, declaration)) in declarations.iter().enumerate() {
+            let Some(shorthand) = Shorthand::from_name(name) else {
+                continue;
+            };
+            find_redundant_longhands(
+                shorthand,
+                declaration,
+                &declarations[index + 1..],
+                &mut signals,
+            );
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let longhand = state.shorthand.longhands[state.index];
+        let shorthand = state.shorthand.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.longhand.declaration().ok()?.range(),
+                markup! {
+                    "The longhand property "<Emphasis>{longhand}</Emphasis>" can be merged into the preceding shorthand property "<Emphasis>{shorthand}</Emphasis>"."
+                },
+            )
+            .detail(
+                state.shorthand_property.range(),
+                markup! {
+                    "The shorthand property is declared here:"
+                },
+            )
+            .note(markup! {
+                "The longhand property overrides one of the values of the shorthand property, which is harder to read than declaring all the values at once."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        // The comments of the values would be lost
+        if has_comments(state.shorthand_property.value().syntax())
+            || has_comments(state.longhand.syntax())
+        {
+            return None;
+        }
+
+        let mut values = state.shorthand_values.clone();
+        values[state.index] = state.value.clone();
+        let texts = values
+            .each_ref()
+            .map(|value| value.syntax().text_trimmed().to_string());
+        let count = collapsed_value_count(&texts);
+        let values = values[..count]
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let leading = if index == 0 { "" } else { " " };
+                let value = value.clone().with_trailing_trivia_pieces([])?;
+                let first_token = value.syntax().first_token()?;
+                let spaced =
+                    first_token.with_leading_trivia([(TriviaPieceKind::Whitespace, leading)]);
+                let value = value
+                    .into_syntax()
+                    .replace_child(first_token.into(), spaced.into())?;
+                AnyCssValue::cast(value).map(AnyCssGenericComponentValue::AnyCssValue)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            state.shorthand_property.value(),
+            make::css_generic_component_value_list(values),
+        );
+        mutation.remove_node(state.longhand.clone());
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Merge the value into the shorthand property "<Emphasis>{state.shorthand.name}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Pushes the longhand properties of `shorthand` declared after `declaration` that can be merged
+/// into it
+fn find_redundant_longhands(
+    shorthand: &'static Shorthand,
+    declaration: &CssDeclarationWithSemicolon,
+    following: &[(String, CssDeclarationWithSemicolon)],
+    signals: &mut Vec<RuleState>,
+) -> Option<()> {
+    let inner = declaration.declaration().ok()?;
+    let is_important = inner.important().is_some();
+    let property = inner.property().ok()?;
+    let property = property.as_css_generic_property()?;
+    let values = component_values(property)?;
+    if values
+        .iter()
+        .any(|value| is_css_wide_keyword(value) || is_substitution(value))
+    {
+        return None;
+    }
+    let shorthand_values = expand_values(&values)?;
+
+    // Only the first declaration of each longhand property overrides the shorthand property
+    let mut seen = [false; 4];
+    for (name, longhand) in following {
+        if !shorthand.is_in_family(name) {
+            continue;
+        }
+        // Another property of the family sets the same sides as the shorthand property
+        let index = shorthand.longhand_index(name)?;
+        if std::mem::replace(&mut seen[index], true) {
+            continue;
+        }
+        let Ok(inner) = longhand.declaration() else {
+            continue;
+        };
+        if inner.important().is_some() != is_important {
+            continue;
+        }
+        let value = inner
+            .property()
+            .ok()
+            .and_then(|property| single_value(property.as_css_generic_property()?))
+            .filter(|value| !is_css_wide_keyword(value));
+        if let Some(value) = value {
+            signals.push(RuleState {
+                shorthand,
+                shorthand_property: property.clone(),
+                shorthand_values: shorthand_values.clone(),
+                longhand: longhand.clone(),
+                index,
+                value,
+            });
+        }
+    }
+    Some(())
+}
+
+/// Returns `true` if `value` is substituted when the styles are computed, like `var(--spacing)`,
+/// so it could stand for several values of the shorthand property
+fn is_substitution(value: &AnyCssValue) -> bool {
+    let AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) = value else {
+        return false;
+    };
+    function
+        .name()
+        .and_then(|name| name.value_token())
+        .is_ok_and(|name| {
+            matches!(
+                name.text_trimmed().to_ascii_lowercase_cow().as_ref(),
+                "var" | "env" | "attr"
+            )
+        })
+}
+
+fn has_comments(node: &CssSyntaxNode) -> bool {
+    node.descendants_tokens(Direction::Next)
+        .any(|token| token.has_leading_comments() || token.has_trailing_comments())
+}
@@ -0,0 +1,102 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_semantic::CssModuleClasses;
+use biome_css_syntax::{CssComposesProperty, CssCustomIdentifier, CssRoot};
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_lint_rule! {
+    /// Disallow composing the classes that aren't defined in a CSS module.
+    ///
+    /// In a [CSS module](https://github.com/css-modules/css-modules), the `composes` property
+    /// includes the styles of other classes.
+    /// Without `from`, the composed classes must be defined in the same module: a class that
+    /// doesn't exist, or that is in the scope of `:global`, can't be composed, and the bundler
+    /// fails to build the module.
+    ///
+    /// The classes composed from another file, such as `composes: button from "./button.css"`,
+    /// and the global classes, such as `composes: container from global`, aren't checked.
+    ///
+    /// The `composes` property is only parsed in CSS modules: the files whose name ends with
+    /// `.module.css`, or any file when the `css.parser.cssModules` option is enabled.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic,css_modules
+    /// .button {
+    ///     composes: base;
+    ///     color: red;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,css_modules
+    /// .base {
+    ///     padding: 4px;
+    /// }
+    ///
+    /// .button {
+    ///     composes: base;
+    ///     color: red;
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedComposes {
+        version: "next",
+        name: "noUndefinedComposes",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndefinedComposes {
+    type Query = Ast<CssRoot>;
+    type State = CssCustomIdentifier;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut properties = root
+            .syntax()
+            .descendants()
+            .filter_map(CssComposesProperty::cast)
+            .peekable();
+        if properties.peek().is_none() {
+            return Box::default();
+        }
+
+        let classes = CssModuleClasses::from_root(root);
+        properties
+            .filter_map(|property| property.value().ok())
+            .filter(|value| value.specifier().is_none())
+            .flat_map(|value| value.classes().iter())
+            .filter(|class| {
+                class
+                    .value_token()
+                    .is_ok_and(|name| !classes.contains(name.text_trimmed()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.value_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The class "<Emphasis>{name.text_trimmed()}</Emphasis>" isn't defined in this module."
+                },
+            )
+            .note(markup! {
+                "Without "<Emphasis>"from"</Emphasis>", "<Emphasis>"composes"</Emphasis>" only accepts the local classes of the same module."
+            })
+            .note(markup! {
+                "Define the class in this module, or compose it from the file that defines it."
+            }),
+        )
+    }
+}
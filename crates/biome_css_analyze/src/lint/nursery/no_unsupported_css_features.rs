@@ -0,0 +1,310 @@
+use std::sync::Arc;
+
+use biome_analyze::browserslist::{Browser, BrowserTargets, BrowserVersion};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssValue, CssAtRule, CssGenericProperty,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Severity;
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow the CSS features that some of the targeted browsers don't support.
+    ///
+    /// The browsers ignore the declarations and the at-rules they don't support.
+    /// A recent feature, such as `text-wrap: balance` or `@container`, silently does nothing
+    /// in the older browsers targeted by the project.
+    ///
+    /// The targeted browsers are read from the closest `.browserslistrc` file, or from the
+    /// `browserslist` field of `package.json`.
+    /// When the project doesn't configure its targets, the browsers selected by `defaults` are
+    /// targeted.
+    ///
+    /// The rule checks the properties, the keyword values and the at-rules of a table generated
+    /// from [MDN browser-compat-data](https://github.com/mdn/browser-compat-data), such as
+    /// `aspect-ratio`, `display: contents`, `grid-template-columns: subgrid`, `@layer` or
+    /// `@starting-style`.
+    /// When a property isn't supported, its values aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// h1 {
+    ///   text-wrap: balance;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @scope (.card) {
+    ///   img {
+    ///     border: none;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .layout {
+    ///   display: grid;
+    ///   aspect-ratio: 16 / 9;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The features are split into three groups: the `properties`, the `values` and the
+    /// `atRules`.
+    /// Each group accepts a severity, `"info"`, `"warn"` or `"error"`, that replaces the severity
+    /// configured for the rule, or `"off"` to stop reporting the features of the group.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "values": "info",
+    ///         "atRules": "error"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnsupportedCssFeatures {
+        version: "next",
+        name: "noUnsupportedCssFeatures",
+        language: "css",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyCssFeature = CssGenericProperty | CssAtRule
+}
+
+/// The first version of each browser that supports a feature, in the order of the variants of
+/// [Browser]: Chrome, Edge, Firefox, Internet Explorer, iOS Safari, Opera, Safari and
+/// Samsung Internet.
+///
+/// The browsers that don't support the feature have no version.
+type Support = [Option<BrowserVersion>; 8];
+
+include!(concat!(env!("OUT_DIR"), "/css_features.rs"));
+
+pub struct UnsupportedFeature {
+    range: TextRange,
+    /// The feature, such as `text-wrap`, `text-wrap: balance` or `@container`
+    name: String,
+    group: FeatureGroup,
+    browser: Browser,
+    version: BrowserVersion,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FeatureGroup {
+    Property,
+    Value,
+    AtRule,
+}
+
+impl FeatureGroup {
+    /// Returns what the diagnostic reports. The browsers ignore the whole declaration when
+    /// they don't support its value.
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Property => "property",
+            Self::Value => "declaration",
+            Self::AtRule => "at-rule",
+        }
+    }
+}
+
+impl Rule for NoUnsupportedCssFeatures {
+    type Query = Ast<AnyCssFeature>;
+    type State = UnsupportedFeature;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUnsupportedCssFeaturesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(targets) = ctx.get_service::<Arc<BrowserTargets>>() else {
+            return Box::default();
+        };
+        let options = ctx.options();
+        let mut signals = Vec::new();
+        match ctx.query() {
+            AnyCssFeature::CssGenericProperty(property) => {
+                let Ok(AnyCssDeclarationName::CssIdentifier(name)) = property.name() else {
+                    return Box::default();
+                };
+                let Ok(name_token) = name.value_token() else {
+                    return Box::default();
+                };
+                let name = name_token.text_trimmed().to_ascii_lowercase_cow();
+                if let Some((browser, version)) = first_unsupported(targets, &PROPERTIES, &name) {
+                    if options.properties != Some(FeatureSeverity::Off) {
+                        signals.push(UnsupportedFeature {
+                            range: name_token.text_trimmed_range(),
+                            name: name.into_owned(),
+                            group: FeatureGroup::Property,
+                            browser,
+                            version,
+                        });
+                    }
+                    return signals.into_boxed_slice();
+                }
+                if options.values == Some(FeatureSeverity::Off) {
+                    return Box::default();
+                }
+                for value in property.value() {
+                    let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) =
+                        value
+                    else {
+                        continue;
+                    };
+                    let Ok(value_token) = value.value_token() else {
+                        continue;
+                    };
+                    let value = value_token.text_trimmed().to_ascii_lowercase_cow();
+                    let feature = format!("{name}: {value}");
+                    if let Some((browser, version)) = first_unsupported(targets, &VALUES, &feature)
+                    {
+                        signals.push(UnsupportedFeature {
+                            range: value_token.text_trimmed_range(),
+                            name: feature,
+                            group: FeatureGroup::Value,
+                            browser,
+                            version,
+                        });
+                    }
+                }
+            }
+            AnyCssFeature::CssAtRule(at_rule) => {
+                if options.at_rules == Some(FeatureSeverity::Off) {
+                    return Box::default();
+                }
+                let (Ok(at_token), Ok(rule)) = (at_rule.at_token(), at_rule.rule()) else {
+                    return Box::default();
+                };
+                let Some(name_token) = rule.syntax().first_token() else {
+                    return Box::default();
+                };
+                let name = name_token.text_trimmed().to_ascii_lowercase_cow();
+                if let Some((browser, version)) = first_unsupported(targets, &AT_RULES, &name) {
+                    signals.push(UnsupportedFeature {
+                        range: at_token
+                            .text_trimmed_range()
+                            .cover(name_token.text_trimmed_range()),
+                        name: format!("@{name}"),
+                        group: FeatureGroup::AtRule,
+                        browser,
+                        version,
+                    });
+                }
+            }
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsupportedFeature {
+            range,
+            name,
+            group,
+            browser,
+            version,
+        } = state;
+        let kind = group.description();
+        let target = format!("{browser} {version}");
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            *range,
+            markup! {
+                "The "{kind}" "<Emphasis>{name}</Emphasis>" isn't supported by "<Emphasis>{target}</Emphasis>", which is targeted by the project."
+            },
+        )
+        .note(markup! {
+            "The browsers that don't support this "{kind}" ignore it."
+        })
+        .note(markup! {
+            "Provide a fallback, or stop targeting the browsers that don't support it. The targeted browsers are read from the "<Emphasis>".browserslistrc"</Emphasis>" file, or from the "<Emphasis>"browserslist"</Emphasis>" field of "<Emphasis>"package.json"</Emphasis>"."
+        });
+        let options = ctx.options();
+        let severity = match group {
+            FeatureGroup::Property => options.properties,
+            FeatureGroup::Value => options.values,
+            FeatureGroup::AtRule => options.at_rules,
+        };
+        Some(match severity.and_then(FeatureSeverity::to_severity) {
+            Some(severity) => diagnostic.severity(severity),
+            None => diagnostic,
+        })
+    }
+}
+
+/// Returns a targeted browser, with its oldest targeted version, that doesn't support `feature`
+/// of `table`.
+///
+/// Returns `None` if `feature` isn't in `table`, or if all the targeted browsers support it.
+fn first_unsupported(
+    targets: &BrowserTargets,
+    table: &[(&str, Support)],
+    feature: &str,
+) -> Option<(Browser, BrowserVersion)> {
+    let index = table
+        .binary_search_by(|(name, _)| (*name).cmp(feature))
+        .ok()?;
+    let (_, support) = table[index];
+    targets
+        .iter()
+        .filter(|(browser, version)| {
+            // Node.js doesn't render CSS
+            *browser != Browser::Node
+                && support[*browser as usize].is_none_or(|first_version| *version < first_version)
+        })
+        .min()
+}
+
+/// Options for the rule `noUnsupportedCssFeatures`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnsupportedCssFeaturesOptions {
+    /// The severity of the unsupported properties, such as `text-wrap`.
+    /// The severity configured for the rule applies by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<FeatureSeverity>,
+    /// The severity of the unsupported values, such as `display: contents`.
+    /// The severity configured for the rule applies by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<FeatureSeverity>,
+    /// The severity of the unsupported at-rules, such as `@container`.
+    /// The severity configured for the rule applies by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at_rules: Option<FeatureSeverity>,
+}
+
+/// The severity of the diagnostics of a group of features
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum FeatureSeverity {
+    /// The features of the group aren't reported.
+    Off,
+    Info,
+    Warn,
+    Error,
+}
+
+impl FeatureSeverity {
+    const fn to_severity(self) -> Option<Severity> {
+        match self {
+            Self::Off => None,
+            Self::Info => Some(Severity::Information),
+            Self::Warn => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssExpression, AnyCssValue, CssDashedIdentifier, CssFunction,
+    CssGenericProperty, CssRoot,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TokenText};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow custom properties that are never used.
+    ///
+    /// A custom property, such as `--primary-color`, is used when it's referenced with the
+    /// `var()` function. The custom properties that are declared but never referenced are dead
+    /// code: they are often design tokens that were left behind after a refactoring.
+    ///
+    /// This rule only looks for the references in the same file.
+    /// The custom properties that are declared in a file, and only used by other files or by
+    /// scripts, should be ignored with a suppression comment.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// :root {
+    ///   --primary-color: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: red;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root {
+    ///   --primary-color: blue;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--primary-color);
+    /// }
+    /// ```
+    ///
+    pub NoUnusedCustomProperties {
+        version: "next",
+        name: "noUnusedCustomProperties",
+        language: "css",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedCustomProperties {
+    type Query = Ast<CssRoot>;
+    type State = CssDashedIdentifier;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        let mut declared = Vec::new();
+        let mut used: FxHashSet<TokenText> = FxHashSet::default();
+        for descendant in node.syntax().descendants() {
+            if let Some(property) = CssGenericProperty::cast_ref(&descendant) {
+                if let Ok(AnyCssDeclarationName::CssDashedIdentifier(name)) = property.name() {
+                    declared.push(name);
+                }
+            } else if let Some(function) = CssFunction::cast_ref(&descendant) {
+                if let Some(name) = referenced_custom_property(&function) {
+                    used.insert(name);
+                }
+            }
+        }
+
+        declared
+            .into_iter()
+            .filter(|name| {
+                name.value_token()
+                    .is_ok_and(|token| !used.contains(&token.token_text_trimmed()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.value_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The custom property "<Emphasis>{name.text_trimmed()}</Emphasis>" is never used."
+                },
+            )
+            .note(markup! {
+                "Unused custom properties make the stylesheet harder to maintain."
+            })
+            .note(markup! {
+                "Remove the declaration, or reference the custom property with "<Emphasis>"var("{name.text_trimmed()}")"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the name of the custom property that `function` references, such as `--color` for
+/// `var(--color, red)`
+fn referenced_custom_property(function: &CssFunction) -> Option<TokenText> {
+    let name = function.name().ok()?.value_token().ok()?;
+    if !name.text_trimmed().eq_ignore_ascii_case("var") {
+        return None;
+    }
+    let AnyCssExpression::CssListOfComponentValuesExpression(expression) =
+        function.items().first()?.ok()?.any_css_expression().ok()?
+    else {
+        return None;
+    };
+    let AnyCssValue::CssDashedIdentifier(property) =
+        expression.css_component_value_list().first()?
+    else {
+        return None;
+    };
+    Some(property.value_token().ok()?.token_text_trimmed())
+}
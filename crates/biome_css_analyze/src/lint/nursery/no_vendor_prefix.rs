@@ -0,0 +1,391 @@
+use std::sync::Arc;
+
+use biome_analyze::browserslist::{BrowserTargets, BrowserVersion};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssValue, CssDeclaration,
+    CssDeclarationWithSemicolon, CssGenericProperty, CssIdentifier, CssSyntaxKind, CssSyntaxToken,
+};
+use biome_rowan::{AstNode, BatchMutationExt, Direction};
+use biome_string_case::StrLikeExtension;
+
+use crate::utils::vender_prefix;
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Disallow vendor prefixes that aren't needed by the targeted browsers.
+    ///
+    /// Properties such as `-webkit-transition`, and values such as `-webkit-sticky`, were needed
+    /// by the browsers that didn't support the standard form yet.
+    /// Once all the browsers targeted by the project support the standard form, the prefixed
+    /// form is dead code.
+    ///
+    /// The targeted browsers are read from the closest `.browserslistrc` file, or from the
+    /// `browserslist` field of `package.json`.
+    /// The queries that select the versions of a browser, such as `safari >= 15`, are resolved
+    /// exactly.
+    /// The other queries, such as `defaults` or `> 0.5%`, are approximated with the recent
+    /// versions of the major browsers, because Biome doesn't embed the usage statistics of the
+    /// browsers.
+    /// When the project doesn't configure its targets, the browsers selected by `defaults` are
+    /// targeted.
+    ///
+    /// The rule only reports the prefixed properties and values that have a standard equivalent,
+    /// such as `-webkit-appearance` or `-moz-max-content`.
+    /// Properties such as `-webkit-tap-highlight-color`, which don't have any standard
+    /// equivalent, are ignored.
+    ///
+    /// The fix replaces the prefixed form with the standard form, or removes the prefixed
+    /// declaration when the standard form is already declared in the same block.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   -webkit-transition: opacity 1s;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   position: -webkit-sticky;
+    ///   position: sticky;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   transition: opacity 1s;
+    ///   position: sticky;
+    /// }
+    /// ```
+    ///
+    /// Safari doesn't support the standard `user-select` property:
+    ///
+    /// ```css
+    /// a {
+    ///   -webkit-user-select: none;
+    ///   user-select: none;
+    /// }
+    /// ```
+    ///
+    pub NoVendorPrefix {
+        version: "next",
+        name: "noVendorPrefix",
+        language: "css",
+        recommended: false,
+        sources: &[
+            RuleSource::Stylelint("property-no-vendor-prefix"),
+            RuleSource::Stylelint("value-no-vendor-prefix"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// A version that no browser has reached yet: the standard form isn't supported
+const NONE: u16 = u16::MAX;
+
+/// The oldest major versions that support the standard form, in the order of the variants of
+/// [biome_analyze::browserslist::Browser]: Chrome, Edge, Firefox, Internet Explorer, iOS Safari, Opera,
+/// Safari and Samsung Internet.
+///
+/// The versions that added the support in a minor release, such as Safari 15.4, are rounded up
+/// to the next major version.
+type Support = [u16; 8];
+
+const ANIMATIONS: Support = [43, 12, 16, 10, 9, 30, 9, 4];
+const APPEARANCE: Support = [84, 84, 80, NONE, 16, 70, 16, 14];
+const BACKDROP_FILTER: Support = [76, 79, 103, NONE, 18, 63, 18, 12];
+const BORDER_RADIUS: Support = [5, 12, 4, 9, 4, 11, 5, 1];
+const BOX_SHADOW: Support = [10, 12, 4, 9, 5, 11, 6, 1];
+const BOX_SIZING: Support = [10, 12, 29, 8, 5, 7, 6, 1];
+const CLIP_PATH: Support = [55, 79, 54, NONE, 14, 42, 14, 6];
+const CURSOR_GRAB: Support = [68, 14, 27, NONE, 11, 55, 11, 10];
+const CURSOR_ZOOM: Support = [37, 12, 24, NONE, 9, 24, 9, 3];
+const FILTER: Support = [53, 12, 35, NONE, 10, 40, 10, 6];
+const FIT_CONTENT: Support = [46, 79, 94, NONE, 11, 33, 11, 5];
+const FLEXBOX: Support = [29, 12, 28, 11, 9, 17, 9, 2];
+const FONT_FEATURE_SETTINGS: Support = [48, 15, 34, 10, 10, 35, 10, 5];
+const HYPHENS: Support = [88, 88, 43, NONE, 17, 74, 17, 15];
+const INTRINSIC_SIZES: Support = [46, 79, 66, NONE, 11, 33, 11, 5];
+const MASKS: Support = [120, 120, 53, NONE, 16, 106, 16, 25];
+const MULTI_COLUMNS: Support = [50, 12, 52, 10, 9, 37, 9, 5];
+const STICKY: Support = [56, 16, 32, NONE, 13, 42, 13, 7];
+const TAB_SIZE: Support = [21, 79, 91, NONE, 7, 15, 7, 2];
+const TEXT_EMPHASIS: Support = [99, 99, 46, NONE, 7, 85, 7, 18];
+const TRANSFORMS: Support = [36, 12, 16, 10, 9, 23, 9, 3];
+const TRANSITIONS: Support = [26, 12, 16, 10, 7, 13, 7, 2];
+const USER_SELECT: Support = [54, 79, 69, NONE, NONE, 41, NONE, 7];
+
+/// The properties that have a prefixed form, sorted by their standard name
+const PREFIXED_PROPERTIES: [(&str, Support); 56] = [
+    ("align-content", FLEXBOX),
+    ("align-items", FLEXBOX),
+    ("align-self", FLEXBOX),
+    ("animation", ANIMATIONS),
+    ("animation-delay", ANIMATIONS),
+    ("animation-direction", ANIMATIONS),
+    ("animation-duration", ANIMATIONS),
+    ("animation-fill-mode", ANIMATIONS),
+    ("animation-iteration-count", ANIMATIONS),
+    ("animation-name", ANIMATIONS),
+    ("animation-play-state", ANIMATIONS),
+    ("animation-timing-function", ANIMATIONS),
+    ("appearance", APPEARANCE),
+    ("backdrop-filter", BACKDROP_FILTER),
+    ("border-radius", BORDER_RADIUS),
+    ("box-shadow", BOX_SHADOW),
+    ("box-sizing", BOX_SIZING),
+    ("clip-path", CLIP_PATH),
+    ("column-count", MULTI_COLUMNS),
+    ("column-gap", MULTI_COLUMNS),
+    ("column-rule", MULTI_COLUMNS),
+    ("column-width", MULTI_COLUMNS),
+    ("columns", MULTI_COLUMNS),
+    ("filter", FILTER),
+    ("flex", FLEXBOX),
+    ("flex-basis", FLEXBOX),
+    ("flex-direction", FLEXBOX),
+    ("flex-flow", FLEXBOX),
+    ("flex-grow", FLEXBOX),
+    ("flex-shrink", FLEXBOX),
+    ("flex-wrap", FLEXBOX),
+    ("font-feature-settings", FONT_FEATURE_SETTINGS),
+    ("hyphens", HYPHENS),
+    ("justify-content", FLEXBOX),
+    ("mask", MASKS),
+    ("mask-clip", MASKS),
+    ("mask-composite", MASKS),
+    ("mask-image", MASKS),
+    ("mask-origin", MASKS),
+    ("mask-position", MASKS),
+    ("mask-repeat", MASKS),
+    ("mask-size", MASKS),
+    ("order", FLEXBOX),
+    ("tab-size", TAB_SIZE),
+    ("text-emphasis", TEXT_EMPHASIS),
+    ("text-emphasis-color", TEXT_EMPHASIS),
+    ("text-emphasis-position", TEXT_EMPHASIS),
+    ("text-emphasis-style", TEXT_EMPHASIS),
+    ("transform", TRANSFORMS),
+    ("transform-origin", TRANSFORMS),
+    ("transition", TRANSITIONS),
+    ("transition-delay", TRANSITIONS),
+    ("transition-duration", TRANSITIONS),
+    ("transition-property", TRANSITIONS),
+    ("transition-timing-function", TRANSITIONS),
+    ("user-select", USER_SELECT),
+];
+
+/// The keywords that have a prefixed form, sorted by their name without the prefix, with their
+/// standard form
+const PREFIXED_VALUES: [(&str, &str, Support); 12] = [
+    ("fit-content", "fit-content", FIT_CONTENT),
+    ("flex", "flex", FLEXBOX),
+    // `-ms-flexbox`
+    ("flexbox", "flex", FLEXBOX),
+    ("grab", "grab", CURSOR_GRAB),
+    ("grabbing", "grabbing", CURSOR_GRAB),
+    ("inline-flex", "inline-flex", FLEXBOX),
+    // `-ms-inline-flexbox`
+    ("inline-flexbox", "inline-flex", FLEXBOX),
+    ("max-content", "max-content", INTRINSIC_SIZES),
+    ("min-content", "min-content", INTRINSIC_SIZES),
+    ("sticky", "sticky", STICKY),
+    ("zoom-in", "zoom-in", CURSOR_ZOOM),
+    ("zoom-out", "zoom-out", CURSOR_ZOOM),
+];
+
+pub struct UnnecessaryPrefix {
+    /// The prefixed property or keyword
+    identifier: CssIdentifier,
+    /// Whether `identifier` is the name of the property, or one of its values
+    is_property: bool,
+    /// The standard form of `identifier`
+    standard: &'static str,
+    /// The declaration of `identifier`, when the standard form is also declared in the same block
+    redundant_declaration: Option<CssDeclarationWithSemicolon>,
+}
+
+impl Rule for NoVendorPrefix {
+    type Query = Ast<CssGenericProperty>;
+    type State = UnnecessaryPrefix;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let property = ctx.query();
+        let Some(targets) = ctx.get_service::<Arc<BrowserTargets>>() else {
+            return Box::default();
+        };
+        let Some(declaration) = property
+            .parent::<CssDeclaration>()
+            .and_then(|declaration| declaration.parent::<CssDeclarationWithSemicolon>())
+        else {
+            return Box::default();
+        };
+        // The other declarations of the same block
+        let siblings: Vec<_> = declaration
+            .syntax()
+            .siblings(Direction::Prev)
+            .skip(1)
+            .chain(declaration.syntax().siblings(Direction::Next).skip(1))
+            .filter_map(|sibling| {
+                let sibling = CssDeclarationWithSemicolon::cast(sibling)?;
+                let property = sibling.declaration().ok()?.property().ok()?;
+                property.as_css_generic_property().cloned()
+            })
+            .collect();
+
+        let mut signals = Vec::new();
+        if let Ok(AnyCssDeclarationName::CssIdentifier(name)) = property.name() {
+            if let Some(standard) = unnecessary_prefix(&name, targets, standard_property) {
+                let is_redundant = siblings
+                    .iter()
+                    .any(|sibling| property_name(sibling).is_some_and(|name| name == standard));
+                signals.push(UnnecessaryPrefix {
+                    identifier: name,
+                    is_property: true,
+                    standard,
+                    redundant_declaration: is_redundant.then(|| declaration.clone()),
+                });
+            }
+        }
+
+        let name = property_name(property);
+        for value in property.value() {
+            let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) = value
+            else {
+                continue;
+            };
+            let Some(standard) = unnecessary_prefix(&value, targets, standard_value) else {
+                continue;
+            };
+            let is_redundant = siblings.iter().any(|sibling| {
+                property_name(sibling) == name
+                    && single_identifier(sibling).is_some_and(|value| value == standard)
+            });
+            signals.push(UnnecessaryPrefix {
+                identifier: value,
+                is_property: false,
+                standard,
+                redundant_declaration: is_redundant.then(|| declaration.clone()),
+            });
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let token = state.identifier.value_token().ok()?;
+        let kind = if state.is_property {
+            "property"
+        } else {
+            "value"
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.identifier.range(),
+                markup! {
+                    "The prefixed "{kind}" "<Emphasis>{token.text_trimmed()}</Emphasis>" isn't needed by the targeted browsers."
+                },
+            )
+            .note(markup! {
+                "All the targeted browsers support the standard "{kind}" "<Emphasis>{state.standard}</Emphasis>"."
+            })
+            .note(markup! {
+                "The targeted browsers are read from the "<Emphasis>".browserslistrc"</Emphasis>" file, or from the "<Emphasis>"browserslist"</Emphasis>" field of "<Emphasis>"package.json"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = if let Some(declaration) = &state.redundant_declaration {
+            mutation.remove_node(declaration.clone());
+            markup! { "Remove the prefixed declaration." }.to_owned()
+        } else {
+            let token = state.identifier.value_token().ok()?;
+            mutation.replace_token_transfer_trivia(
+                token,
+                CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, state.standard, [], []),
+            );
+            markup! { "Use "<Emphasis>{state.standard}</Emphasis>" instead." }.to_owned()
+        };
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns the standard form of `identifier` when its prefix isn't needed by any of the
+/// targeted browsers.
+///
+/// `lookup` returns the standard form of an identifier without its prefix, and its support.
+fn unnecessary_prefix(
+    identifier: &CssIdentifier,
+    targets: &BrowserTargets,
+    lookup: fn(&str) -> Option<(&'static str, Support)>,
+) -> Option<&'static str> {
+    let token = identifier.value_token().ok()?;
+    let text = token.text_trimmed().to_ascii_lowercase_cow();
+    let prefix = vender_prefix(&text);
+    if prefix.is_empty() {
+        return None;
+    }
+    let (standard, support) = lookup(&text[prefix.len()..])?;
+    let is_needed = targets.iter().any(|(browser, version)| {
+        browser.uses_prefix(prefix, version)
+            && version < BrowserVersion::new(support[browser as usize], 0)
+    });
+    (!is_needed).then_some(standard)
+}
+
+fn standard_property(unprefixed: &str) -> Option<(&'static str, Support)> {
+    let index = PREFIXED_PROPERTIES
+        .binary_search_by(|(name, _)| name.cmp(&unprefixed))
+        .ok()?;
+    Some(PREFIXED_PROPERTIES[index])
+}
+
+fn standard_value(unprefixed: &str) -> Option<(&'static str, Support)> {
+    let index = PREFIXED_VALUES
+        .binary_search_by(|(name, _, _)| name.cmp(&unprefixed))
+        .ok()?;
+    let (_, standard, support) = PREFIXED_VALUES[index];
+    Some((standard, support))
+}
+
+/// Returns the lowercase name of `property`
+fn property_name(property: &CssGenericProperty) -> Option<String> {
+    let name = property.name().ok()?;
+    let name = name.as_css_identifier()?.value_token().ok()?;
+    Some(name.text_trimmed().to_ascii_lowercase_cow().into_owned())
+}
+
+/// Returns the lowercase value of `property` when it's a single keyword
+fn single_identifier(property: &CssGenericProperty) -> Option<String> {
+    let mut values = property.value().into_iter();
+    let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) =
+        values.next()?
+    else {
+        return None;
+    };
+    if values.next().is_some() {
+        return None;
+    }
+    let value = value.value_token().ok()?;
+    Some(value.text_trimmed().to_ascii_lowercase_cow().into_owned())
+}
@@ -0,0 +1,343 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssExpression, AnyCssFunction, AnyCssValue, CssColor, CssFunction, CssSyntaxKind,
+    CssSyntaxToken, T,
+};
+use biome_css_value::color::Color;
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, TriviaPiece};
+use biome_string_case::StrLikeExtension;
+use serde::{Deserialize, Serialize};
+
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Enforce a single notation for the colors.
+    ///
+    /// CSS accepts several notations for the same color, such as `#ff0000`, `rgb(255 0 0)`,
+    /// `hsl(0 100% 50%)` or `oklch(62.8% 0.2577 29.23)`.
+    /// Mixing them makes the colors harder to compare and to search.
+    ///
+    /// This rule reports the hexadecimal colors and the calls to `rgb()`, `rgba()`, `hsl()`,
+    /// `hsla()` and `oklch()` that don't use the notation of the project.
+    /// The named colors, such as `red`, and the colors that can't be resolved statically, such
+    /// as `rgb(var(--red) 0 0)`, are ignored.
+    ///
+    /// The fix converts the color to the notation of the project, and keeps its alpha.
+    /// The colors of `oklch()` that are outside of the sRGB color space can't be converted, and
+    /// are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: rgb(255 0 0);
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: hsl(0 100% 50% / 50%);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   color: #ff0000;
+    ///   background-color: #ff000080;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "format": "oklch"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### format
+    ///
+    /// The notation of the colors: `"hex"` (default), `"rgb"`, `"hsl"` or `"oklch"`.
+    ///
+    pub UseConsistentColorFormat {
+        version: "next",
+        name: "useConsistentColorFormat",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyCssColor = CssColor | CssFunction
+}
+
+pub struct ColorState {
+    /// The notation of the color
+    format: ColorFormat,
+    /// The color, converted to the notation of the project
+    replacement: AnyCssValue,
+}
+
+impl Rule for UseConsistentColorFormat {
+    type Query = Ast<AnyCssColor>;
+    type State = ColorState;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentColorFormatOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let expected = ctx.options().format;
+        let format = ColorFormat::of(node)?;
+        if format == expected {
+            return None;
+        }
+        let color = match node {
+            AnyCssColor::CssColor(color) => {
+                Color::from_hex(color.value_token().ok()?.text_trimmed())
+            }
+            AnyCssColor::CssFunction(function) => Color::from_function(function),
+        }?;
+        Some(ColorState {
+            format,
+            replacement: expected.format(&color),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let expected = ctx.options().format;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use the "<Emphasis>{expected.name()}</Emphasis>" notation instead of the "<Emphasis>{state.format.name()}</Emphasis>" notation."
+                },
+            )
+            .note(markup! {
+                "Using a single notation for the colors makes them easier to compare and to search."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let node = ctx.query();
+        let replacement = state.replacement.syntax().text_trimmed().to_string();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(
+            node.syntax().clone().into(),
+            state.replacement.syntax().clone().into(),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{replacement}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Options for the rule `useConsistentColorFormat`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseConsistentColorFormatOptions {
+    /// The notation of the colors.
+    pub format: ColorFormat,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum ColorFormat {
+    /// Hexadecimal colors, such as `#ff0000`.
+    #[default]
+    Hex,
+    /// The `rgb()` function, such as `rgb(255 0 0)`.
+    Rgb,
+    /// The `hsl()` function, such as `hsl(0 100% 50%)`.
+    Hsl,
+    /// The `oklch()` function, such as `oklch(62.8% 0.2577 29.23)`.
+    Oklch,
+}
+
+impl ColorFormat {
+    /// Returns the notation of `node`
+    fn of(node: &AnyCssColor) -> Option<Self> {
+        let function = match node {
+            AnyCssColor::CssColor(_) => return Some(Self::Hex),
+            AnyCssColor::CssFunction(function) => function,
+        };
+        let name = function.name().ok()?.value_token().ok()?;
+        match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+            "rgb" | "rgba" => Some(Self::Rgb),
+            "hsl" | "hsla" => Some(Self::Hsl),
+            "oklch" => Some(Self::Oklch),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Hex => "hexadecimal",
+            Self::Rgb => "rgb()",
+            Self::Hsl => "hsl()",
+            Self::Oklch => "oklch()",
+        }
+    }
+
+    /// Returns `color` written in this notation
+    fn format(self, color: &Color) -> AnyCssValue {
+        let alpha = (color.alpha < 1.0).then_some(color.alpha);
+        match self {
+            Self::Hex => {
+                let channel = |value: f64| format!("{:02x}", (value * 255.0).round() as u8);
+                let mut hex = format!(
+                    "{}{}{}",
+                    channel(color.red),
+                    channel(color.green),
+                    channel(color.blue)
+                );
+                if let Some(alpha) = alpha {
+                    hex.push_str(&channel(alpha));
+                }
+                AnyCssValue::CssColor(make::css_color(
+                    CssSyntaxToken::new_detached(T![#], "#", [], []),
+                    CssSyntaxToken::new_detached(CssSyntaxKind::CSS_COLOR_LITERAL, &hex, [], []),
+                ))
+            }
+            Self::Rgb => color_function(
+                "rgb",
+                [
+                    Component::Number(format_number(color.red * 255.0, 0)),
+                    Component::Number(format_number(color.green * 255.0, 0)),
+                    Component::Number(format_number(color.blue * 255.0, 0)),
+                ],
+                alpha,
+            ),
+            Self::Hsl => {
+                let (hue, saturation, lightness) = color.to_hsl();
+                color_function(
+                    "hsl",
+                    [
+                        Component::Number(format_number(hue, 2)),
+                        Component::Percentage(format_number(saturation * 100.0, 2)),
+                        Component::Percentage(format_number(lightness * 100.0, 2)),
+                    ],
+                    alpha,
+                )
+            }
+            Self::Oklch => {
+                let (lightness, chroma, hue) = color.to_oklch();
+                color_function(
+                    "oklch",
+                    [
+                        Component::Percentage(format_number(lightness * 100.0, 2)),
+                        Component::Number(format_number(chroma, 4)),
+                        Component::Number(format_number(hue, 2)),
+                    ],
+                    alpha,
+                )
+            }
+        }
+    }
+}
+
+/// A numeric argument of a color function
+enum Component {
+    Number(String),
+    Percentage(String),
+}
+
+impl Component {
+    fn into_value(self, trailing_space: bool) -> AnyCssValue {
+        let trailing: &[TriviaPiece] = if trailing_space {
+            &[TriviaPiece::whitespace(1)]
+        } else {
+            &[]
+        };
+        let space = if trailing_space { " " } else { "" };
+        match self {
+            Self::Number(value) => {
+                AnyCssValue::CssNumber(make::css_number(CssSyntaxToken::new_detached(
+                    CssSyntaxKind::CSS_NUMBER_LITERAL,
+                    &format!("{value}{space}"),
+                    [],
+                    trailing.iter().copied(),
+                )))
+            }
+            Self::Percentage(value) => AnyCssValue::AnyCssDimension(
+                make::css_percentage(
+                    CssSyntaxToken::new_detached(CssSyntaxKind::CSS_NUMBER_LITERAL, &value, [], []),
+                    CssSyntaxToken::new_detached(
+                        T![%],
+                        &format!("%{space}"),
+                        [],
+                        trailing.iter().copied(),
+                    ),
+                )
+                .into(),
+            ),
+        }
+    }
+}
+
+/// Creates a call to a color function with the modern syntax, such as `rgb(255 0 0 / 0.5)`
+fn color_function(name: &str, channels: [Component; 3], alpha: Option<f64>) -> AnyCssValue {
+    let [first, second, third] = channels;
+    let channels = make::css_list_of_component_values_expression(make::css_component_value_list([
+        first.into_value(true),
+        second.into_value(true),
+        third.into_value(alpha.is_some()),
+    ]));
+    let expression = match alpha {
+        Some(alpha) => AnyCssExpression::CssBinaryExpression(make::css_binary_expression(
+            channels.into(),
+            CssSyntaxToken::new_detached(T![/], "/ ", [], [TriviaPiece::whitespace(1)]),
+            make::css_list_of_component_values_expression(make::css_component_value_list([
+                Component::Number(format_number(alpha, 3)).into_value(false),
+            ]))
+            .into(),
+        )),
+        None => channels.into(),
+    };
+    AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(make::css_function(
+        make::css_identifier(CssSyntaxToken::new_detached(
+            CssSyntaxKind::IDENT,
+            name,
+            [],
+            [],
+        )),
+        CssSyntaxToken::new_detached(T!['('], "(", [], []),
+        make::css_parameter_list([make::css_parameter(expression)], []),
+        CssSyntaxToken::new_detached(T![')'], ")", [], []),
+    )))
+}
+
+/// Formats `value` with at most `decimals` decimals, without the trailing zeros
+fn format_number(value: f64, decimals: usize) -> String {
+    let text = format!("{value:.decimals$}");
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    // Avoid `-0`, when a negative value is rounded to zero
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
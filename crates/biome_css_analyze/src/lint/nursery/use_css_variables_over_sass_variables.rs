@@ -0,0 +1,411 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssDeclarationOrRule, AnyCssExpression, AnyCssFunction,
+    AnyCssGenericComponentValue, AnyCssProperty, AnyCssRule, AnyCssSelector, AnyCssSubSelector,
+    AnyCssValue, CssGenericProperty, CssRoot, CssSyntaxKind, CssSyntaxToken, ScssDeclaration,
+    ScssIdentifier, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TriviaPiece};
+use biome_string_case::StrLikeExtension;
+use rustc_hash::FxHashMap;
+
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Prefer CSS custom properties over Sass variables for the values that can be themed.
+    ///
+    /// The Sass variables, such as `$primary-color`, are replaced by their values when the
+    /// stylesheet is compiled. The custom properties, such as `--primary-color`, are resolved by
+    /// the browser: they can be changed at runtime, by a theme, a media query or a script, and
+    /// they're inherited by the descendants of the element that declares them.
+    ///
+    /// This rule reports the Sass variables declared at the root of a stylesheet whose value is
+    /// a plain CSS value, such as a color, a length or a font stack.
+    /// The variables computed from other variables, or with a Sass function such as
+    /// `darken()`, aren't reported: they can only become custom properties once the variables
+    /// they depend on are migrated.
+    ///
+    /// Each diagnostic tells how many times the variable is referenced in the file.
+    /// Running this rule on a whole project lists the variables to migrate, and how widely each
+    /// of them is used.
+    ///
+    /// The fix declares the custom property in a `:root` rule, and replaces the references with
+    /// `var()`.
+    /// It's only suggested when the variable has no `!default` or `!global` flag, isn't
+    /// shadowed, and is only referenced directly by the values of the properties: a
+    /// custom property can't be an argument of a Sass function, of a mixin or of an
+    /// interpolation.
+    /// The fix is unsafe, because the stylesheets that load this file with `@use` or `@import`
+    /// can also reference the variable.
+    ///
+    /// This rule only applies to the SCSS files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```scss,expect_diagnostic
+    /// $primary-color: #0d6efd;
+    ///
+    /// a {
+    ///   color: $primary-color;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```scss
+    /// :root {
+    ///   --primary-color: #0d6efd;
+    /// }
+    ///
+    /// a {
+    ///   color: var(--primary-color);
+    /// }
+    /// ```
+    ///
+    /// ```scss
+    /// $base: 8px;
+    /// $gutter: $base * 2;
+    ///
+    /// .sidebar {
+    ///   width: calc(100% - #{$base});
+    /// }
+    /// ```
+    ///
+    pub UseCssVariablesOverSassVariables {
+        version: "next",
+        name: "useCssVariablesOverSassVariables",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct SassVariable {
+    declaration: ScssDeclaration,
+    /// The name without its `$`
+    name: String,
+    /// The references to the variable in the file
+    references: Vec<ScssIdentifier>,
+    /// Whether the variable can be replaced by a custom property
+    is_fixable: bool,
+}
+
+impl Rule for UseCssVariablesOverSassVariables {
+    type Query = Ast<CssRoot>;
+    type State = SassVariable;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+
+        // The references and the bindings of every variable of the file, by name
+        let mut references: FxHashMap<String, Vec<ScssIdentifier>> = FxHashMap::default();
+        let mut bindings: FxHashMap<String, usize> = FxHashMap::default();
+        for identifier in root.syntax().descendants().filter_map(ScssIdentifier::cast) {
+            let Some(name) = variable_name(&identifier) else {
+                continue;
+            };
+            match identifier.syntax().parent().map(|parent| parent.kind()) {
+                Some(
+                    CssSyntaxKind::SCSS_DECLARATION
+                    | CssSyntaxKind::SCSS_PARAMETER
+                    | CssSyntaxKind::SCSS_EACH_BINDING_LIST,
+                ) => *bindings.entry(name).or_default() += 1,
+                // `@include button($size: 2px)`: the name of the parameter of the mixin
+                Some(CssSyntaxKind::SCSS_KEYWORD_ARGUMENT) => {}
+                _ => references.entry(name).or_default().push(identifier),
+            }
+        }
+
+        let mut signals = Vec::new();
+        for rule in root.rules() {
+            let AnyCssRule::ScssDeclaration(declaration) = rule else {
+                continue;
+            };
+            let Ok(value) = declaration.value() else {
+                continue;
+            };
+            if !value.items().iter().all(|item| {
+                item.is_ok_and(|item| {
+                    expression_values(&item).is_some_and(|values| values.iter().all(is_plain_value))
+                })
+            }) {
+                continue;
+            }
+            let Some(name) = declaration
+                .name()
+                .ok()
+                .and_then(|name| variable_name(&name))
+            else {
+                continue;
+            };
+            let references = references.remove(&name).unwrap_or_default();
+            let is_fixable = declaration.modifiers().is_empty()
+                && bindings.get(&name) == Some(&1)
+                && references.iter().all(is_property_value);
+            signals.push(SassVariable {
+                declaration,
+                name,
+                references,
+                is_fixable,
+            });
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = format!("${}", state.name);
+        let references = match state.references.len() {
+            0 => markup! { "This variable isn't referenced in this file." }.to_owned(),
+            1 => markup! { "This variable is referenced once in this file." }.to_owned(),
+            count => {
+                markup! { "This variable is referenced "{count}" times in this file." }.to_owned()
+            }
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.declaration.name().ok()?.range(),
+                markup! {
+                    "The Sass variable "<Emphasis>{name}</Emphasis>" can be a CSS custom property."
+                },
+            )
+            .note(references)
+            .note(markup! {
+                "Unlike a Sass variable, a custom property can be changed at runtime, for example by a theme, and it's inherited by the descendants of the element that declares it."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        if !state.is_fixable {
+            return None;
+        }
+        let custom_property = format!("--{}", state.name);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyCssRule::ScssDeclaration(state.declaration.clone()),
+            AnyCssRule::CssQualifiedRule(root_rule(&custom_property, &state.declaration)?),
+        );
+        for reference in &state.references {
+            mutation.replace_node(
+                AnyCssValue::ScssIdentifier(reference.clone()),
+                var_function(&custom_property),
+            );
+        }
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! {
+                "Declare the custom property "<Emphasis>{custom_property}</Emphasis>" instead."
+            }
+            .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the name of `identifier`, without its `$`
+fn variable_name(identifier: &ScssIdentifier) -> Option<String> {
+    let token = identifier.name().ok()?.value_token().ok()?;
+    Some(token.text_trimmed().to_string())
+}
+
+/// Returns the values of an item of the value of a Sass variable, such as `Helvetica` and
+/// `Arial` in `$font-stack: Helvetica, Arial;`.
+///
+/// Returns `None` if the item is an operation, such as `$base * 2`.
+fn expression_values(expression: &AnyCssExpression) -> Option<Vec<AnyCssValue>> {
+    match expression {
+        AnyCssExpression::CssListOfComponentValuesExpression(list) => {
+            Some(list.css_component_value_list().iter().collect())
+        }
+        AnyCssExpression::CssBinaryExpression(_)
+        | AnyCssExpression::CssParenthesizedExpression(_) => None,
+    }
+}
+
+/// The functions of CSS whose arguments Sass leaves as they are in a custom property
+const CSS_FUNCTIONS: [&str; 16] = [
+    "calc",
+    "clamp",
+    "color",
+    "color-mix",
+    "hsl",
+    "hsla",
+    "hwb",
+    "lab",
+    "lch",
+    "max",
+    "min",
+    "oklab",
+    "oklch",
+    "rgb",
+    "rgba",
+    "var",
+];
+
+/// Returns `true` if `value` is a value of CSS that doesn't depend on Sass.
+fn is_plain_value(value: &AnyCssValue) -> bool {
+    let has_sass_syntax = value.syntax().descendants().any(|node| {
+        matches!(
+            node.kind(),
+            CssSyntaxKind::SCSS_IDENTIFIER | CssSyntaxKind::SCSS_INTERPOLATION
+        )
+    });
+    if has_sass_syntax {
+        return false;
+    }
+    match value {
+        AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => function
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|name| {
+                CSS_FUNCTIONS.contains(&name.text_trimmed().to_ascii_lowercase_cow().as_ref())
+            }),
+        AnyCssValue::AnyCssFunction(AnyCssFunction::CssUrlFunction(_))
+        | AnyCssValue::AnyCssDimension(_)
+        | AnyCssValue::CssColor(_)
+        | AnyCssValue::CssCustomIdentifier(_)
+        | AnyCssValue::CssDashedIdentifier(_)
+        | AnyCssValue::CssIdentifier(_)
+        | AnyCssValue::CssNumber(_)
+        | AnyCssValue::CssRatio(_)
+        | AnyCssValue::CssString(_) => true,
+        AnyCssValue::CssBracketedValue(_)
+        | AnyCssValue::CssMetavariable(_)
+        | AnyCssValue::CssParenthesizedExpression(_)
+        | AnyCssValue::CssUnicodeRange(_)
+        | AnyCssValue::LessIdentifier(_)
+        | AnyCssValue::ScssIdentifier(_)
+        | AnyCssValue::ScssInterpolation(_) => false,
+    }
+}
+
+/// Returns `true` if `reference` is one of the values of a property, such as `$color` in
+/// `color: $color`.
+fn is_property_value(reference: &ScssIdentifier) -> bool {
+    reference
+        .syntax()
+        .parent()
+        .filter(|parent| parent.kind() == CssSyntaxKind::CSS_GENERIC_COMPONENT_VALUE_LIST)
+        .and_then(|list| list.parent())
+        .and_then(CssGenericProperty::cast)
+        .is_some_and(|property| {
+            // Sass doesn't replace the variables in the values of the custom properties
+            matches!(property.name(), Ok(AnyCssDeclarationName::CssIdentifier(_)))
+        })
+}
+
+/// Creates the rule `:root { --name: value; }` that declares the custom property of
+/// `declaration`.
+fn root_rule(
+    custom_property: &str,
+    declaration: &ScssDeclaration,
+) -> Option<biome_css_syntax::CssQualifiedRule> {
+    let value = declaration.value().ok()?;
+    let mut values = Vec::new();
+    for (index, item) in value.items().elements().enumerate() {
+        if index > 0 {
+            values.push(AnyCssGenericComponentValue::CssGenericDelimiter(
+                make::css_generic_delimiter(CssSyntaxToken::new_detached(
+                    T![,],
+                    ", ",
+                    [],
+                    [TriviaPiece::whitespace(1)],
+                )),
+            ));
+        }
+        let item_values = expression_values(&item.into_node().ok()?)?;
+        let count = item_values.len();
+        for (index, value) in item_values.into_iter().enumerate() {
+            let value = if index + 1 == count {
+                value.trim_trailing_trivia()?
+            } else {
+                value
+            };
+            let value = if index == 0 {
+                value.trim_leading_trivia()?
+            } else {
+                value
+            };
+            values.push(AnyCssGenericComponentValue::AnyCssValue(value));
+        }
+    }
+
+    let property = make::css_generic_property(
+        AnyCssDeclarationName::CssDashedIdentifier(make::css_dashed_identifier(
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, custom_property, [], []),
+        )),
+        CssSyntaxToken::new_detached(T![:], ": ", [], [TriviaPiece::whitespace(1)]),
+        make::css_generic_component_value_list(values),
+    );
+    let declaration = make::css_declaration_with_semicolon(
+        make::css_declaration(AnyCssProperty::CssGenericProperty(property)).build(),
+    )
+    .with_semicolon_token(CssSyntaxToken::new_detached(
+        T![;],
+        "; ",
+        [],
+        [TriviaPiece::whitespace(1)],
+    ))
+    .build();
+
+    let selector = make::css_compound_selector(
+        make::css_nested_selector_list([]),
+        make::css_sub_selector_list([AnyCssSubSelector::CssPseudoClassSelector(
+            make::css_pseudo_class_selector(
+                CssSyntaxToken::new_detached(T![:], ":", [], []),
+                make::css_pseudo_class_identifier(make::css_identifier(
+                    CssSyntaxToken::new_detached(
+                        CssSyntaxKind::IDENT,
+                        "root ",
+                        [],
+                        [TriviaPiece::whitespace(1)],
+                    ),
+                ))
+                .into(),
+            ),
+        )]),
+    )
+    .build();
+
+    Some(make::css_qualified_rule(
+        make::css_selector_list([AnyCssSelector::CssCompoundSelector(selector)], []),
+        make::css_declaration_or_rule_block(
+            CssSyntaxToken::new_detached(T!['{'], "{ ", [], [TriviaPiece::whitespace(1)]),
+            make::css_declaration_or_rule_list([
+                AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(declaration),
+            ]),
+            CssSyntaxToken::new_detached(T!['}'], "}", [], []),
+        )
+        .into(),
+    ))
+}
+
+/// Creates the reference `var(--name)` to a custom property
+fn var_function(custom_property: &str) -> AnyCssValue {
+    let argument = make::css_list_of_component_values_expression(make::css_component_value_list([
+        AnyCssValue::CssDashedIdentifier(make::css_dashed_identifier(
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, custom_property, [], []),
+        )),
+    ]));
+    AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(make::css_function(
+        make::css_identifier(CssSyntaxToken::new_detached(
+            CssSyntaxKind::IDENT,
+            "var",
+            [],
+            [],
+        )),
+        CssSyntaxToken::new_detached(T!['('], "(", [], []),
+        make::css_parameter_list([make::css_parameter(argument.into())], []),
+        CssSyntaxToken::new_detached(T![')'], ")", [], []),
+    )))
+}
@@ -0,0 +1,230 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssGenericComponentValue, AnyCssValue, CssGenericProperty, CssSyntaxKind, CssSyntaxToken,
+};
+use biome_rowan::BatchMutationExt;
+use biome_string_case::StrLikeExtension;
+
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of logical properties and values.
+    ///
+    /// Physical properties and values, such as `margin-left` or `text-align: left`, refer to the
+    /// sides of the screen. Their logical equivalents, such as `margin-inline-start` or
+    /// `text-align: start`, refer to the flow of the text instead: they follow the writing mode
+    /// and the direction of the document.
+    /// Stylesheets that use logical properties don't need to be overridden for right-to-left
+    /// languages, such as Arabic or Hebrew.
+    ///
+    /// This rule reports the physical properties that have a logical equivalent, and the
+    /// physical values of `text-align`, `float` and `clear`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   margin-left: 1rem;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   width: 100%;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   text-align: left;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   margin-inline-start: 1rem;
+    ///   inline-size: 100%;
+    ///   text-align: start;
+    /// }
+    /// ```
+    ///
+    pub UseLogicalProperties {
+        version: "next",
+        name: "useLogicalProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The physical properties and their logical equivalent, sorted by physical property
+const LOGICAL_PROPERTIES: [(&str, &str); 52] = [
+    ("border-bottom", "border-block-end"),
+    ("border-bottom-color", "border-block-end-color"),
+    ("border-bottom-left-radius", "border-end-start-radius"),
+    ("border-bottom-right-radius", "border-end-end-radius"),
+    ("border-bottom-style", "border-block-end-style"),
+    ("border-bottom-width", "border-block-end-width"),
+    ("border-left", "border-inline-start"),
+    ("border-left-color", "border-inline-start-color"),
+    ("border-left-style", "border-inline-start-style"),
+    ("border-left-width", "border-inline-start-width"),
+    ("border-right", "border-inline-end"),
+    ("border-right-color", "border-inline-end-color"),
+    ("border-right-style", "border-inline-end-style"),
+    ("border-right-width", "border-inline-end-width"),
+    ("border-top", "border-block-start"),
+    ("border-top-color", "border-block-start-color"),
+    ("border-top-left-radius", "border-start-start-radius"),
+    ("border-top-right-radius", "border-start-end-radius"),
+    ("border-top-style", "border-block-start-style"),
+    ("border-top-width", "border-block-start-width"),
+    ("bottom", "inset-block-end"),
+    ("contain-intrinsic-height", "contain-intrinsic-block-size"),
+    ("contain-intrinsic-width", "contain-intrinsic-inline-size"),
+    ("height", "block-size"),
+    ("left", "inset-inline-start"),
+    ("margin-bottom", "margin-block-end"),
+    ("margin-left", "margin-inline-start"),
+    ("margin-right", "margin-inline-end"),
+    ("margin-top", "margin-block-start"),
+    ("max-height", "max-block-size"),
+    ("max-width", "max-inline-size"),
+    ("min-height", "min-block-size"),
+    ("min-width", "min-inline-size"),
+    ("overflow-x", "overflow-inline"),
+    ("overflow-y", "overflow-block"),
+    ("overscroll-behavior-x", "overscroll-behavior-inline"),
+    ("overscroll-behavior-y", "overscroll-behavior-block"),
+    ("padding-bottom", "padding-block-end"),
+    ("padding-left", "padding-inline-start"),
+    ("padding-right", "padding-inline-end"),
+    ("padding-top", "padding-block-start"),
+    ("right", "inset-inline-end"),
+    ("scroll-margin-bottom", "scroll-margin-block-end"),
+    ("scroll-margin-left", "scroll-margin-inline-start"),
+    ("scroll-margin-right", "scroll-margin-inline-end"),
+    ("scroll-margin-top", "scroll-margin-block-start"),
+    ("scroll-padding-bottom", "scroll-padding-block-end"),
+    ("scroll-padding-left", "scroll-padding-inline-start"),
+    ("scroll-padding-right", "scroll-padding-inline-end"),
+    ("scroll-padding-top", "scroll-padding-block-start"),
+    ("top", "inset-block-start"),
+    ("width", "inline-size"),
+];
+
+/// The properties that accept physical values, along with the physical values and their logical
+/// equivalent
+const LOGICAL_VALUES: [(&str, &[(&str, &str)]); 3] = [
+    (
+        "clear",
+        &[("left", "inline-start"), ("right", "inline-end")],
+    ),
+    (
+        "float",
+        &[("left", "inline-start"), ("right", "inline-end")],
+    ),
+    ("text-align", &[("left", "start"), ("right", "end")]),
+];
+
+pub enum RuleState {
+    /// A physical property, such as `margin-left`
+    Property {
+        name: CssSyntaxToken,
+        logical: &'static str,
+    },
+    /// A physical value, such as the `left` of `text-align: left`
+    Value {
+        value: CssSyntaxToken,
+        logical: &'static str,
+    },
+}
+
+impl Rule for UseLogicalProperties {
+    type Query = Ast<CssGenericProperty>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?;
+        let name = name.as_css_identifier()?.value_token().ok()?;
+        let property = name.text_trimmed().to_ascii_lowercase_cow();
+
+        if let Ok(index) =
+            LOGICAL_PROPERTIES.binary_search_by(|(physical, _)| (*physical).cmp(property.as_ref()))
+        {
+            return Some(RuleState::Property {
+                name,
+                logical: LOGICAL_PROPERTIES[index].1,
+            });
+        }
+
+        let (_, values) = LOGICAL_VALUES
+            .iter()
+            .find(|(value_property, _)| *value_property == property)?;
+        let mut value_list = node.value().into_iter();
+        let Some(AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value))) =
+            value_list.next()
+        else {
+            return None;
+        };
+        if value_list.next().is_some() {
+            return None;
+        }
+        let value = value.value_token().ok()?;
+        let physical_value = value.text_trimmed().to_ascii_lowercase_cow();
+        let &(_, logical) = values
+            .iter()
+            .find(|(physical, _)| *physical == physical_value)?;
+        Some(RuleState::Value { value, logical })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            RuleState::Property { name, logical } => RuleDiagnostic::new(
+                rule_category!(),
+                name.text_trimmed_range(),
+                markup! {
+                    "Use the logical property "<Emphasis>{logical}</Emphasis>" instead of the physical property "<Emphasis>{name.text_trimmed()}</Emphasis>"."
+                },
+            ),
+            RuleState::Value { value, logical } => RuleDiagnostic::new(
+                rule_category!(),
+                value.text_trimmed_range(),
+                markup! {
+                    "Use the logical value "<Emphasis>{logical}</Emphasis>" instead of the physical value "<Emphasis>{value.text_trimmed()}</Emphasis>"."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "Logical properties and values follow the direction of the text, so the styles also work for right-to-left languages."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let (token, logical) = match state {
+            RuleState::Property { name, logical } => (name, logical),
+            RuleState::Value { value, logical } => (value, logical),
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            token.clone(),
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, logical, [], []),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{logical}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
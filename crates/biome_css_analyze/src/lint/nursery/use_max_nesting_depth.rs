@@ -0,0 +1,247 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssAtRule, AnyCssLayer, CssAtRule, CssNestedQualifiedRule, CssQualifiedRule,
+    CssSyntaxKind::*,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a maximum nesting depth for the rules.
+    ///
+    /// Deeply nested rules are hard to read, and produce selectors with a high specificity that
+    /// are hard to override.
+    ///
+    /// The depth of a rule is the number of rules in which it's nested, including the at-rules
+    /// such as `@media` and `@supports`.
+    /// The at-rules at the root of the stylesheet don't count:
+    /// `@media print { a {} }` has the same depth as `a {}`.
+    /// The nesting with `&` counts like any other nesting.
+    ///
+    /// Only the outermost rule that exceeds the maximum depth is reported:
+    /// the rules nested inside it aren't reported again.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .menu {
+    ///   .item {
+    ///     .link {
+    ///       .icon {
+    ///         color: red;
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .menu {
+    ///   & .item {
+    ///     @media (min-width: 600px) {
+    ///       &:hover {
+    ///         color: red;
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .menu {
+    ///   .item {
+    ///     .link {
+    ///       color: red;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// @media (min-width: 600px) {
+    ///   .menu {
+    ///     .item {
+    ///       .link {
+    ///         color: red;
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "max": 1
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### max
+    ///
+    /// The maximum number of rules in which a rule can be nested.
+    /// `0` disallows the nesting.
+    ///
+    /// Default: `2`, which allows `.a { .b { .c {} } }`.
+    ///
+    pub UseMaxNestingDepth {
+        version: "next",
+        name: "useMaxNestingDepth",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("max-nesting-depth")],
+    }
+}
+
+/// Options for the rule `useMaxNestingDepth`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMaxNestingDepthOptions {
+    /// The maximum number of rules in which a rule can be nested.
+    pub max: u8,
+}
+
+impl Default for UseMaxNestingDepthOptions {
+    fn default() -> Self {
+        Self { max: 2 }
+    }
+}
+
+declare_node_union! {
+    /// A rule that can contain other rules
+    pub AnyCssNestingRule = CssQualifiedRule | CssNestedQualifiedRule | CssAtRule
+}
+
+impl AnyCssNestingRule {
+    /// Returns `true` if the rule can contain other rules, and counts in the depth of the rules
+    /// that it contains.
+    fn is_counted(&self) -> bool {
+        match self {
+            Self::CssQualifiedRule(_) | Self::CssNestedQualifiedRule(_) => true,
+            Self::CssAtRule(at_rule) => {
+                // The at-rules at the root of the stylesheet don't count
+                let is_root_level = at_rule
+                    .syntax()
+                    .grand_parent()
+                    .is_some_and(|node| node.kind() == CSS_ROOT);
+                !is_root_level && at_rule.rule().is_ok_and(|rule| contains_rules(&rule))
+            }
+        }
+    }
+
+    /// Returns the range of the selectors of the rule, or of the name and the prelude of the
+    /// at-rule.
+    fn head_range(&self) -> Option<TextRange> {
+        match self {
+            Self::CssQualifiedRule(rule) => Some(rule.prelude().range()),
+            Self::CssNestedQualifiedRule(rule) => Some(rule.prelude().range()),
+            Self::CssAtRule(at_rule) => {
+                let block = at_rule.syntax().descendants().find(|node| {
+                    matches!(
+                        node.kind(),
+                        CSS_RULE_BLOCK | CSS_DECLARATION_OR_RULE_BLOCK | CSS_BOGUS_BLOCK
+                    )
+                })?;
+                let prelude_end = block
+                    .first_token()?
+                    .prev_token()?
+                    .text_trimmed_range()
+                    .end();
+                Some(TextRange::new(at_rule.range().start(), prelude_end))
+            }
+        }
+    }
+
+    /// Returns the text of the head of the rule, with its whitespace collapsed
+    fn head_text(&self) -> Option<String> {
+        let range = self.head_range()?;
+        let text = self.syntax().text_trimmed().to_string();
+        let head = text.get(..usize::from(range.len()))?;
+        Some(head.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Returns `true` if the at-rule has a block that can contain rules
+fn contains_rules(rule: &AnyCssAtRule) -> bool {
+    match rule {
+        AnyCssAtRule::CssContainerAtRule(_)
+        | AnyCssAtRule::CssMediaAtRule(_)
+        | AnyCssAtRule::CssSupportsAtRule(_)
+        | AnyCssAtRule::CssScopeAtRule(_)
+        | AnyCssAtRule::CssStartingStyleAtRule(_)
+        | AnyCssAtRule::CssDocumentAtRule(_)
+        | AnyCssAtRule::CssScreenAtRule(_)
+        | AnyCssAtRule::CssUnknownBlockAtRule(_) => true,
+        AnyCssAtRule::CssLayerAtRule(rule) => {
+            matches!(rule.layer(), Ok(AnyCssLayer::CssLayerDeclaration(_)))
+        }
+        _ => false,
+    }
+}
+
+impl Rule for UseMaxNestingDepth {
+    type Query = Ast<AnyCssNestingRule>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = UseMaxNestingDepthOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.is_counted() {
+            return None;
+        }
+        let depth = nesting_ancestors(node).count();
+        // The rules nested in a reported rule aren't reported again
+        if depth != usize::from(ctx.options().max) + 1 {
+            return None;
+        }
+        node.head_range()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let max = ctx.options().max;
+        let mut chain = nesting_ancestors(node)
+            .map(|rule| rule.head_text())
+            .collect::<Option<Vec<_>>>()?;
+        chain.reverse();
+        chain.push(node.head_text()?);
+        let chain = chain.join(" → ");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This rule is nested deeper than the maximum nesting depth of "{max}"."
+                },
+            )
+            .note(markup! {
+                "The rule is nested as follows: "<Emphasis>{chain}</Emphasis>
+            })
+            .note(markup! {
+                "Deeply nested rules are hard to read, and produce selectors that are hard to override. Consider flattening the nesting."
+            }),
+        )
+    }
+}
+
+/// Returns the rules in which `node` is nested and that count in its depth, from the innermost
+fn nesting_ancestors(node: &AnyCssNestingRule) -> impl Iterator<Item = AnyCssNestingRule> {
+    node.syntax()
+        .ancestors()
+        .skip(1)
+        .filter_map(AnyCssNestingRule::cast)
+        .filter(AnyCssNestingRule::is_counted)
+}
@@ -0,0 +1,180 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_css_semantic::model::Specificity;
+use biome_css_syntax::CssRoot;
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::TextRange;
+use serde::{Deserialize, Serialize};
+
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Enforce a maximum specificity for the selectors.
+    ///
+    /// Selectors with a high specificity, such as `#header .nav > ul li.active a`, are hard to
+    /// override: the selectors that override them need an even higher specificity, or
+    /// `!important`.
+    /// Capping the specificity keeps the selectors flat, and the stylesheets maintainable.
+    ///
+    /// The specificity of a selector is the triple `(a, b, c)`, where `a` counts the ID
+    /// selectors, `b` the class selectors, the attribute selectors and the pseudo-classes, and `c`
+    /// the type selectors and the pseudo-elements.
+    /// Two specificities are compared by `a` first, then by `b`, then by `c`.
+    /// The specificity of a nested selector includes the specificity of its parent selectors.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// #header .nav {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .menu .item .link .icon:hover {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .menu .item {
+    ///   .link .icon:hover {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .menu .item .link:hover {}
+    /// ```
+    ///
+    /// ```css
+    /// nav ul li a {}
+    /// ```
+    ///
+    /// ```css
+    /// :where(#header) .nav {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "max": {
+    ///             "ids": 0,
+    ///             "classes": 2,
+    ///             "types": 2
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### max
+    ///
+    /// The maximum specificity of the selectors, as its number of ID selectors (`ids`), of class
+    /// selectors (`classes`) and of type selectors (`types`).
+    ///
+    /// Default: `{ "ids": 0, "classes": 4, "types": 0 }`, which allows `.a .b .c .d`, and reports
+    /// `#a` and `.a .b .c .d e`.
+    ///
+    pub UseMaxSpecificity {
+        version: "next",
+        name: "useMaxSpecificity",
+        language: "css",
+        recommended: false,
+        sources: &[RuleSource::Stylelint("selector-max-specificity")],
+    }
+}
+
+/// Options for the rule `useMaxSpecificity`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMaxSpecificityOptions {
+    /// The maximum specificity of the selectors.
+    pub max: MaxSpecificity,
+}
+
+/// A specificity, as the number of ID selectors, class selectors and type selectors.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct MaxSpecificity {
+    /// The number of ID selectors.
+    pub ids: u32,
+    /// The number of class selectors, attribute selectors and pseudo-classes.
+    pub classes: u32,
+    /// The number of type selectors and pseudo-elements.
+    pub types: u32,
+}
+
+impl Default for MaxSpecificity {
+    fn default() -> Self {
+        Self {
+            ids: 0,
+            classes: 4,
+            types: 0,
+        }
+    }
+}
+
+impl From<&MaxSpecificity> for Specificity {
+    fn from(max: &MaxSpecificity) -> Self {
+        Self(max.ids, max.classes, max.types)
+    }
+}
+
+pub struct SpecificSelector {
+    range: TextRange,
+    specificity: Specificity,
+}
+
+impl Rule for UseMaxSpecificity {
+    type Query = Semantic<CssRoot>;
+    type State = SpecificSelector;
+    type Signals = Box<[Self::State]>;
+    type Options = UseMaxSpecificityOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let max = Specificity::from(&ctx.options().max);
+        let mut signals = Vec::new();
+        // The model only lists the top-level rules: the nested rules are visited through their
+        // parents, in the order of the document.
+        let mut stack: Vec<_> = model.rules().iter().rev().collect();
+        while let Some(rule) = stack.pop() {
+            signals.extend(
+                rule.selectors
+                    .iter()
+                    .filter(|selector| selector.specificity > max)
+                    .map(|selector| SpecificSelector {
+                        range: selector.range,
+                        specificity: selector.specificity.clone(),
+                    }),
+            );
+            stack.extend(
+                rule.child_ids
+                    .iter()
+                    .rev()
+                    .filter_map(|id| model.get_rule_by_id(*id)),
+            );
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let max = Specificity::from(&ctx.options().max);
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The specificity of this selector, "{state.specificity.to_string()}", is higher than the maximum specificity "{max.to_string()}"."
+                },
+            )
+            .note(markup! {
+                "Selectors with a high specificity are hard to override. Reduce the number of ID selectors, class selectors and type selectors, or wrap some of them in "<Emphasis>":where()"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,354 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssDeclarationOrRule, AnyCssGenericComponentValue, AnyCssValue, CssDeclarationOrRuleList,
+    CssDeclarationWithSemicolon, CssSyntaxKind, CssSyntaxToken,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, Direction, TextRange, TriviaPieceKind};
+
+use crate::shorthand::{
+    collapsed_value_count, declaration_name, is_css_wide_keyword, single_value, Shorthand,
+    SHORTHANDS,
+};
+use crate::CssRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of shorthand properties when all their longhand properties are declared.
+    ///
+    /// A declaration block that declares the four sides of a box, such as `margin-top`,
+    /// `margin-right`, `margin-bottom` and `margin-left`, can declare them at once with the
+    /// shorthand property, such as `margin: 1px 2px`.
+    ///
+    /// This rule reports the groups of longhand properties of `margin`, `padding`, `inset`,
+    /// `border-width`, `border-style`, `border-color` and `border-radius` that are all declared in
+    /// the same block.
+    /// The longhand properties with several values, such as `border-top-left-radius: 4px 8px`,
+    /// and the groups that mix `!important` with normal declarations aren't reported.
+    /// The groups that mix a CSS-wide keyword, such as `inherit`, with other values aren't
+    /// reported either, because the keyword can only be the single value of the shorthand.
+    /// The groups that are interleaved with another declaration of the same family, such as
+    /// `margin-inline` between `margin-top` and `margin-left`, aren't reported either, because
+    /// merging them would change which declaration wins.
+    ///
+    /// The fix replaces the first longhand property with the shorthand property, and removes the
+    /// other ones.
+    /// The comments of the removed declarations are moved before the shorthand property.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   margin-top: 1px;
+    ///   margin-right: 2px;
+    ///   margin-bottom: 1px;
+    ///   margin-left: 2px;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   border-top-color: red;
+    ///   border-right-color: red;
+    ///   border-bottom-color: red;
+    ///   border-left-color: red;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   margin: 1px 2px;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   margin-top: 1px;
+    ///   margin-bottom: 1px;
+    /// }
+    /// ```
+    ///
+    pub UseShorthandProperties {
+        version: "next",
+        name: "useShorthandProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub struct RuleState {
+    shorthand: &'static Shorthand,
+    /// The declarations of the longhand properties, in the order of the values of the shorthand
+    longhands: [Longhand; 4],
+}
+
+struct Longhand {
+    declaration: CssDeclarationWithSemicolon,
+    value: AnyCssValue,
+}
+
+impl RuleState {
+    /// Returns the declarations of the longhand properties, in the order of the block
+    fn declarations(&self) -> Vec<&CssDeclarationWithSemicolon> {
+        let mut declarations: Vec<_> = self
+            .longhands
+            .iter()
+            .map(|longhand| &longhand.declaration)
+            .collect();
+        declarations.sort_by_key(|declaration| declaration.range().start());
+        declarations
+    }
+}
+
+impl Rule for UseShorthandProperties {
+    type Query = Ast<CssDeclarationOrRuleList>;
+    type State = RuleState;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let list = ctx.query();
+        let declarations: Vec<_> = list
+            .iter()
+            .filter_map(|item| match item {
+                AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(declaration) => {
+                    let name = declaration_name(&declaration)?;
+                    Some((name, declaration))
+                }
+                _ => None,
+            })
+            .collect();
+
+        SHORTHANDS
+            .iter()
+            .filter_map(|shorthand| find_longhands(shorthand, &declarations))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let declarations = state.declarations();
+        let range = TextRange::new(
+            declarations.first()?.range().start(),
+            declarations.last()?.declaration().ok()?.range().end(),
+        );
+        let shorthand = state.shorthand.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "These longhand properties can be merged into the shorthand property "<Emphasis>{shorthand}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The shorthand property sets all the sides at once, which makes the declaration block shorter."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let declarations = state.declarations();
+        let (first, removed) = declarations.split_first()?;
+        let property = first
+            .declaration()
+            .ok()?
+            .property()
+            .ok()?
+            .as_css_generic_property()?
+            .clone();
+        let name = property
+            .name()
+            .ok()?
+            .as_css_identifier()?
+            .value_token()
+            .ok()?;
+        let values = shorthand_values(state);
+
+        // The comments of the value of the first longhand property and of the other longhand
+        // properties would be removed, except the ones inside the values that are moved
+        let comments: Vec<_> =
+            property
+                .value()
+                .syntax()
+                .descendants_tokens(Direction::Next)
+                .chain(removed.iter().flat_map(|declaration| {
+                    declaration.syntax().descendants_tokens(Direction::Next)
+                }))
+                .flat_map(|token| {
+                    let value = values.iter().find(|value| {
+                        value
+                            .syntax()
+                            .text_range()
+                            .contains_range(token.text_range())
+                    });
+                    let leading = value.map_or(true, |value| {
+                        value.syntax().first_token().as_ref() == Some(&token)
+                    });
+                    let trailing = value.map_or(true, |value| {
+                        value.syntax().last_token().as_ref() == Some(&token)
+                    });
+                    let leading = leading.then(|| token.leading_trivia().pieces());
+                    let trailing = trailing.then(|| token.trailing_trivia().pieces());
+                    leading
+                        .into_iter()
+                        .flatten()
+                        .chain(trailing.into_iter().flatten())
+                        .filter(|piece| piece.is_comments())
+                        .map(|piece| (piece.kind(), piece.text().to_string()))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+
+        // The comments are placed before the shorthand property, on their own line when the
+        // first longhand property is on its own line
+        let leading_trivia = name.leading_trivia();
+        let indentation: String = leading_trivia
+            .pieces()
+            .rev()
+            .take_while(|piece| !piece.is_newline())
+            .filter(|piece| piece.is_whitespace())
+            .map(|piece| piece.text().to_string())
+            .collect();
+        let has_newline = leading_trivia.pieces().any(|piece| piece.is_newline());
+        let mut trivia: Vec<_> = leading_trivia
+            .pieces()
+            .map(|piece| (piece.kind(), piece.text().to_string()))
+            .collect();
+        for (kind, text) in comments {
+            let is_single_line = kind == TriviaPieceKind::SingleLineComment;
+            trivia.push((kind, text));
+            if has_newline || is_single_line {
+                trivia.push((TriviaPieceKind::Newline, "\n".to_string()));
+                trivia.push((TriviaPieceKind::Whitespace, indentation.clone()));
+            } else {
+                trivia.push((TriviaPieceKind::Whitespace, " ".to_string()));
+            }
+        }
+        let shorthand_name =
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, state.shorthand.name, [], [])
+                .with_leading_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())))
+                .with_trailing_trivia_pieces(name.trailing_trivia().pieces());
+
+        let values = values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let leading = if index == 0 { "" } else { " " };
+                let value = value.with_trailing_trivia_pieces([])?;
+                let first_token = value.syntax().first_token()?;
+                let spaced =
+                    first_token.with_leading_trivia([(TriviaPieceKind::Whitespace, leading)]);
+                let value = value
+                    .into_syntax()
+                    .replace_child(first_token.into(), spaced.into())?;
+                AnyCssValue::cast(value).map(AnyCssGenericComponentValue::AnyCssValue)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(name, shorthand_name);
+        mutation.replace_node(
+            property.value(),
+            make::css_generic_component_value_list(values),
+        );
+        for declaration in removed {
+            mutation.remove_node((*declaration).clone());
+        }
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use the shorthand property "<Emphasis>{state.shorthand.name}</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the declarations of the longhand properties of `shorthand`, if they can be merged
+fn find_longhands(
+    shorthand: &'static Shorthand,
+    declarations: &[(String, CssDeclarationWithSemicolon)],
+) -> Option<RuleState> {
+    let mut positions = [0; 4];
+    for (longhand, position) in shorthand.longhands.iter().zip(positions.iter_mut()) {
+        let mut matches = declarations
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, _))| name == longhand);
+        // A duplicated longhand property is reported by `noDuplicateProperties`
+        let (index, _) = matches.next()?;
+        if matches.next().is_some() {
+            return None;
+        }
+        *position = index;
+    }
+
+    // Another property of the family between the longhand properties sets the same sides
+    let start = *positions.iter().min()?;
+    let end = *positions.iter().max()?;
+    if declarations[start..=end]
+        .iter()
+        .enumerate()
+        .any(|(index, (name, _))| {
+            !positions.contains(&(start + index)) && shorthand.is_in_family(name)
+        })
+    {
+        return None;
+    }
+
+    let mut is_important = None;
+    let mut longhands = Vec::with_capacity(4);
+    for position in positions {
+        let declaration = &declarations[position].1;
+        let inner = declaration.declaration().ok()?;
+        let important = inner.important().is_some();
+        if *is_important.get_or_insert(important) != important {
+            return None;
+        }
+        let property = inner.property().ok()?;
+        let property = property.as_css_generic_property()?;
+        let value = single_value(property)?;
+        longhands.push(Longhand {
+            declaration: declaration.clone(),
+            value,
+        });
+    }
+
+    // A CSS-wide keyword, such as `inherit`, can only be the single value of the shorthand
+    let texts: Vec<_> = longhands
+        .iter()
+        .map(|longhand| longhand.value.syntax().text_trimmed().to_string())
+        .collect();
+    if longhands
+        .iter()
+        .any(|longhand| is_css_wide_keyword(&longhand.value))
+        && texts.iter().any(|text| *text != texts[0])
+    {
+        return None;
+    }
+
+    Some(RuleState {
+        shorthand,
+        longhands: longhands.try_into().ok()?,
+    })
+}
+
+/// Returns the values of the shorthand property, without the values that repeat the value of the
+/// opposite side, such as `1px 2px` for `1px 2px 1px 2px`
+fn shorthand_values(state: &RuleState) -> Vec<AnyCssValue> {
+    let texts = state
+        .longhands
+        .each_ref()
+        .map(|longhand| longhand.value.syntax().text_trimmed().to_string());
+    state.longhands[..collapsed_value_count(&texts)]
+        .iter()
+        .map(|longhand| longhand.value.clone())
+        .collect()
+}
@@ -11,6 +11,8 @@ pub type NoDuplicateProperties =
 pub type NoDuplicateSelectorsKeyframeBlock = < lint :: suspicious :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyBlock =
     <lint::suspicious::no_empty_block::NoEmptyBlock as biome_analyze::Rule>::Options;
+pub type NoGlobalLeakage =
+    <lint::nursery::no_global_leakage::NoGlobalLeakage as biome_analyze::Rule>::Options;
 pub type NoImportantInKeyframe = < lint :: suspicious :: no_important_in_keyframe :: NoImportantInKeyframe as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidDirectionInLinearGradient = < lint :: correctness :: no_invalid_direction_in_linear_gradient :: NoInvalidDirectionInLinearGradient as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidGridAreas =
@@ -18,9 +20,15 @@ pub type NoInvalidGridAreas =
 pub type NoInvalidPositionAtImportRule = < lint :: correctness :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule as biome_analyze :: Rule > :: Options ;
 pub type NoIrregularWhitespace =
     <lint::nursery::no_irregular_whitespace::NoIrregularWhitespace as biome_analyze::Rule>::Options;
+pub type NoLowContrastColors =
+    <lint::nursery::no_low_contrast_colors::NoLowContrastColors as biome_analyze::Rule>::Options;
 pub type NoMissingVarFunction =
     <lint::nursery::no_missing_var_function::NoMissingVarFunction as biome_analyze::Rule>::Options;
+pub type NoRedundantLonghand =
+    <lint::nursery::no_redundant_longhand::NoRedundantLonghand as biome_analyze::Rule>::Options;
 pub type NoShorthandPropertyOverrides = < lint :: suspicious :: no_shorthand_property_overrides :: NoShorthandPropertyOverrides as biome_analyze :: Rule > :: Options ;
+pub type NoUndefinedComposes =
+    <lint::nursery::no_undefined_composes::NoUndefinedComposes as biome_analyze::Rule>::Options;
 pub type NoUnknownFunction =
     <lint::correctness::no_unknown_function::NoUnknownFunction as biome_analyze::Rule>::Options;
 pub type NoUnknownMediaFeatureName = < lint :: correctness :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName as biome_analyze :: Rule > :: Options ;
@@ -33,7 +41,20 @@ pub type NoUnknownTypeSelector = < lint :: nursery :: no_unknown_type_selector :
 pub type NoUnknownUnit =
     <lint::correctness::no_unknown_unit::NoUnknownUnit as biome_analyze::Rule>::Options;
 pub type NoUnmatchableAnbSelector = < lint :: correctness :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector as biome_analyze :: Rule > :: Options ;
+pub type NoUnsupportedCssFeatures = < lint :: nursery :: no_unsupported_css_features :: NoUnsupportedCssFeatures as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedCustomProperties = < lint :: nursery :: no_unused_custom_properties :: NoUnusedCustomProperties as biome_analyze :: Rule > :: Options ;
 pub type NoValueAtRule =
     <lint::nursery::no_value_at_rule::NoValueAtRule as biome_analyze::Rule>::Options;
+pub type NoVendorPrefix =
+    <lint::nursery::no_vendor_prefix::NoVendorPrefix as biome_analyze::Rule>::Options;
+pub type UseConsistentColorFormat = < lint :: nursery :: use_consistent_color_format :: UseConsistentColorFormat as biome_analyze :: Rule > :: Options ;
+pub type UseCssVariablesOverSassVariables = < lint :: nursery :: use_css_variables_over_sass_variables :: UseCssVariablesOverSassVariables as biome_analyze :: Rule > :: Options ;
 pub type UseGenericFontNames =
     <lint::a11y::use_generic_font_names::UseGenericFontNames as biome_analyze::Rule>::Options;
+pub type UseLogicalProperties =
+    <lint::nursery::use_logical_properties::UseLogicalProperties as biome_analyze::Rule>::Options;
+pub type UseMaxNestingDepth =
+    <lint::nursery::use_max_nesting_depth::UseMaxNestingDepth as biome_analyze::Rule>::Options;
+pub type UseMaxSpecificity =
+    <lint::nursery::use_max_specificity::UseMaxSpecificity as biome_analyze::Rule>::Options;
+pub type UseShorthandProperties = < lint :: nursery :: use_shorthand_properties :: UseShorthandProperties as biome_analyze :: Rule > :: Options ;
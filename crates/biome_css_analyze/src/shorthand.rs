@@ -0,0 +1,166 @@
+//! The box shorthand properties of CSS, such as `margin`, whose one to four values set the four
+//! sides of a box, and their longhand properties.
+
+use crate::keywords::BASIC_KEYWORDS;
+use biome_css_syntax::{
+    AnyCssGenericComponentValue, AnyCssValue, CssDeclarationWithSemicolon, CssGenericProperty,
+};
+use biome_css_value::keyword;
+use biome_rowan::AstNodeList;
+use biome_string_case::StrLikeExtension;
+
+/// A shorthand property, and its longhand properties in the order of the values of the shorthand
+pub(crate) struct Shorthand {
+    pub(crate) name: &'static str,
+    pub(crate) longhands: [&'static str; 4],
+    /// The prefix of the other properties that set the same longhand properties, such as
+    /// `margin-inline` for `margin`
+    pub(crate) family: &'static str,
+}
+
+pub(crate) const SHORTHANDS: [Shorthand; 7] = [
+    Shorthand {
+        name: "margin",
+        longhands: ["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        family: "margin",
+    },
+    Shorthand {
+        name: "padding",
+        longhands: [
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        family: "padding",
+    },
+    Shorthand {
+        name: "inset",
+        longhands: ["top", "right", "bottom", "left"],
+        family: "inset",
+    },
+    Shorthand {
+        name: "border-width",
+        longhands: [
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        family: "border",
+    },
+    Shorthand {
+        name: "border-style",
+        longhands: [
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+        family: "border",
+    },
+    Shorthand {
+        name: "border-color",
+        longhands: [
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        family: "border",
+    },
+    Shorthand {
+        name: "border-radius",
+        longhands: [
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+        family: "border",
+    },
+];
+
+impl Shorthand {
+    /// Returns the shorthand property called `name`, which must be lowercase
+    pub(crate) fn from_name(name: &str) -> Option<&'static Self> {
+        SHORTHANDS.iter().find(|shorthand| shorthand.name == name)
+    }
+
+    /// Returns the index of the value of the shorthand set by the longhand property `name`,
+    /// which must be lowercase
+    pub(crate) fn longhand_index(&self, name: &str) -> Option<usize> {
+        self.longhands.iter().position(|longhand| *longhand == name)
+    }
+
+    /// Returns `true` if the property `name`, which must be lowercase, sets some of the sides
+    /// of the shorthand
+    pub(crate) fn is_in_family(&self, name: &str) -> bool {
+        name.starts_with(self.family) || self.longhand_index(name).is_some()
+    }
+}
+
+/// Returns the lowercase name of the property declared by `declaration`
+pub(crate) fn declaration_name(declaration: &CssDeclarationWithSemicolon) -> Option<String> {
+    let property = declaration.declaration().ok()?.property().ok()?;
+    let name = property.as_css_generic_property()?.name().ok()?;
+    let name = name.as_css_identifier()?.value_token().ok()?;
+    Some(name.text_trimmed().to_ascii_lowercase_cow().into_owned())
+}
+
+/// Returns the values of `property` if they're all single components, without delimiters such
+/// as the `/` of `border-radius: 4px / 8px`
+pub(crate) fn component_values(property: &CssGenericProperty) -> Option<Vec<AnyCssValue>> {
+    property
+        .value()
+        .iter()
+        .map(|value| match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => Some(value),
+            AnyCssGenericComponentValue::CssGenericDelimiter(_) => None,
+        })
+        .collect()
+}
+
+/// Returns the value of `property` if it's made of a single component
+pub(crate) fn single_value(property: &CssGenericProperty) -> Option<AnyCssValue> {
+    let mut values = component_values(property)?;
+    if values.len() == 1 {
+        values.pop()
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `value` is a CSS-wide keyword, such as `inherit`, which can't be combined
+/// with other values in a shorthand property
+pub(crate) fn is_css_wide_keyword(value: &AnyCssValue) -> bool {
+    keyword(value).is_some_and(|name| BASIC_KEYWORDS.contains(&name.as_str()))
+}
+
+/// Returns the four values of the sides of a box from the one to four values of its shorthand
+/// property, such as `1px 2px 1px 2px` for `1px 2px`
+pub(crate) fn expand_values<T: Clone>(values: &[T]) -> Option<[T; 4]> {
+    let (top, right, bottom, left) = match values {
+        [all] => (all, all, all, all),
+        [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+        [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => return None,
+    };
+    Some([top.clone(), right.clone(), bottom.clone(), left.clone()])
+}
+
+/// Returns how many of the four values of the sides of a box the shorthand property needs,
+/// without the values that repeat the value of the opposite side, such as `2` for
+/// `1px 2px 1px 2px`
+pub(crate) fn collapsed_value_count(texts: &[String; 4]) -> usize {
+    if texts[3] != texts[1] {
+        4
+    } else if texts[2] != texts[0] {
+        3
+    } else if texts[1] != texts[0] {
+        2
+    } else {
+        1
+    }
+}
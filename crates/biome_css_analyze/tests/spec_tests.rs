@@ -6,13 +6,13 @@ use biome_diagnostics::{DiagnosticExt, Severity};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker, scripts_from_json,
-    write_analyzer_snapshot, CheckActionType,
+    has_bogus_nodes_or_empty_slots, load_browser_targets, parse_test_path, register_leak_checker,
+    scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{css,scss,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{css,json,jsonc}", crate::run_suppression_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
@@ -50,6 +50,8 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             css_modules: true,
             ..CssParserOptions::default()
         }
+    } else if extension == "scss" {
+        CssParserOptions::default().allow_scss()
     } else {
         CssParserOptions::default()
     };
@@ -116,12 +118,24 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
-
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if check_action_type.is_suppression() {
-                    if action.is_suppression() {
+    let browser_targets = load_browser_targets(input_file);
+
+    let (_, errors) =
+        biome_css_analyze::analyze(&root, filter, &options, browser_targets, None, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if check_action_type.is_suppression() {
+                        if action.is_suppression() {
+                            check_code_action(
+                                input_file,
+                                input_code,
+                                source_type,
+                                &action,
+                                parser_options,
+                            );
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(
                             input_file,
                             input_code,
@@ -131,31 +145,33 @@ pub(crate) fn analyze_and_snap(
                         );
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, source_type, &action, parser_options);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            let error = diag.with_severity(Severity::Warning);
-            diagnostics.push(diagnostic_to_string(file_name, input_code, error));
-            return ControlFlow::Continue(());
-        }
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if check_action_type.is_suppression() {
-                if action.category.matches("quickfix.suppressRule") {
+            for action in event.actions() {
+                if check_action_type.is_suppression() {
+                    if action.category.matches("quickfix.suppressRule") {
+                        check_code_action(
+                            input_file,
+                            input_code,
+                            source_type,
+                            &action,
+                            parser_options,
+                        );
+                        code_fixes.push(code_fix_to_string(input_code, action));
+                    }
+                } else if !action.category.matches("quickfix.suppressRule") {
                     check_code_action(input_file, input_code, source_type, &action, parser_options);
                     code_fixes.push(code_fix_to_string(input_code, action));
                 }
-            } else if !action.category.matches("quickfix.suppressRule") {
-                check_code_action(input_file, input_code, source_type, &action, parser_options);
-                code_fixes.push(code_fix_to_string(input_code, action));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
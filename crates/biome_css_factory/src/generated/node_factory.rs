@@ -7,6 +7,42 @@ use biome_css_syntax::{
     *,
 };
 use biome_rowan::AstNode;
+pub fn css_apply_at_rule(
+    apply_token: SyntaxToken,
+    classes: CssApplyClassList,
+    semicolon_token: SyntaxToken,
+) -> CssApplyAtRuleBuilder {
+    CssApplyAtRuleBuilder {
+        apply_token,
+        classes,
+        semicolon_token,
+        important: None,
+    }
+}
+pub struct CssApplyAtRuleBuilder {
+    apply_token: SyntaxToken,
+    classes: CssApplyClassList,
+    semicolon_token: SyntaxToken,
+    important: Option<CssDeclarationImportant>,
+}
+impl CssApplyAtRuleBuilder {
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn build(self) -> CssApplyAtRule {
+        CssApplyAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_APPLY_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.apply_token)),
+                Some(SyntaxElement::Node(self.classes.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
 pub fn css_at_rule(at_token: SyntaxToken, rule: AnyCssAtRule) -> CssAtRule {
     CssAtRule::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_RULE,
@@ -289,6 +325,20 @@ impl CssCompoundSelectorBuilder {
         ))
     }
 }
+pub fn css_config_at_rule(
+    config_token: SyntaxToken,
+    path: CssString,
+    semicolon_token: SyntaxToken,
+) -> CssConfigAtRule {
+    CssConfigAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_CONFIG_AT_RULE,
+        [
+            Some(SyntaxElement::Token(config_token)),
+            Some(SyntaxElement::Node(path.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
 pub fn css_container_and_query(
     left: AnyCssContainerQueryInParens,
     and_token: SyntaxToken,
@@ -1942,6 +1992,20 @@ pub fn css_scope_range_start(start: CssScopeEdge) -> CssScopeRangeStart {
         [Some(SyntaxElement::Node(start.into_syntax()))],
     ))
 }
+pub fn css_screen_at_rule(
+    screen_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssConditionalBlock,
+) -> CssScreenAtRule {
+    CssScreenAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_SCREEN_AT_RULE,
+        [
+            Some(SyntaxElement::Token(screen_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
 pub fn css_starting_style_at_rule(
     starting_style_token: SyntaxToken,
     block: AnyCssStartingStyleBlock,
@@ -2058,6 +2122,20 @@ pub fn css_supports_or_condition(
         ],
     ))
 }
+pub fn css_tailwind_at_rule(
+    tailwind_token: SyntaxToken,
+    name: CssIdentifier,
+    semicolon_token: SyntaxToken,
+) -> CssTailwindAtRule {
+    CssTailwindAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_TAILWIND_AT_RULE,
+        [
+            Some(SyntaxElement::Token(tailwind_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
 pub fn css_type_selector(ident: CssIdentifier) -> CssTypeSelectorBuilder {
     CssTypeSelectorBuilder {
         ident,
@@ -2307,6 +2385,548 @@ pub fn css_value_at_rule_named_import_specifier(
         ],
     ))
 }
+pub fn less_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessDeclarationBuilder {
+    LessDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct LessDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDeclaration {
+        LessDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_guard_binary_condition(
+    left: AnyLessGuardCondition,
+    operator_token: SyntaxToken,
+    right: AnyLessGuardCondition,
+) -> LessGuardBinaryCondition {
+    LessGuardBinaryCondition::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_GUARD_BINARY_CONDITION,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Token(operator_token)),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn less_guard_not_condition(
+    not_token: SyntaxToken,
+    condition: CssParenthesizedExpression,
+) -> LessGuardNotCondition {
+    LessGuardNotCondition::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_GUARD_NOT_CONDITION,
+        [
+            Some(SyntaxElement::Token(not_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+        ],
+    ))
+}
+pub fn less_identifier(at_token: SyntaxToken, name: CssIdentifier) -> LessIdentifier {
+    LessIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_call(selector: CssClassSelector) -> LessMixinCallBuilder {
+    LessMixinCallBuilder {
+        selector,
+        arguments: None,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessMixinCallBuilder {
+    selector: CssClassSelector,
+    arguments: Option<LessMixinParameters>,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessMixinCallBuilder {
+    pub fn with_arguments(mut self, arguments: LessMixinParameters) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessMixinCall {
+        LessMixinCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_CALL,
+            [
+                Some(SyntaxElement::Node(self.selector.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_declaration(
+    selector: CssClassSelector,
+    parameters: LessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessMixinDeclarationBuilder {
+    LessMixinDeclarationBuilder {
+        selector,
+        parameters,
+        block,
+        guard: None,
+    }
+}
+pub struct LessMixinDeclarationBuilder {
+    selector: CssClassSelector,
+    parameters: LessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+    guard: Option<LessMixinGuard>,
+}
+impl LessMixinDeclarationBuilder {
+    pub fn with_guard(mut self, guard: LessMixinGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+    pub fn build(self) -> LessMixinDeclaration {
+        LessMixinDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.selector.into_syntax())),
+                Some(SyntaxElement::Node(self.parameters.into_syntax())),
+                self.guard
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_guard(
+    when_token: SyntaxToken,
+    condition: AnyLessGuardCondition,
+) -> LessMixinGuard {
+    LessMixinGuard::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_GUARD,
+        [
+            Some(SyntaxElement::Token(when_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_parameter(value: AnyLessMixinParameterValue) -> LessMixinParameterBuilder {
+    LessMixinParameterBuilder {
+        value,
+        separator_token: None,
+    }
+}
+pub struct LessMixinParameterBuilder {
+    value: AnyLessMixinParameterValue,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinParameterBuilder {
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinParameter {
+        LessMixinParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinParameters {
+    LessMixinParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_named_parameter(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssExpression,
+) -> LessNamedParameter {
+    LessNamedParameter::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_NAMED_PARAMETER,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_arguments(
+    l_paren_token: SyntaxToken,
+    items: ScssArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssArguments {
+    ScssArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: ScssExpression,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: ScssExpression,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_each_at_rule(
+    each_token: SyntaxToken,
+    bindings: ScssEachBindingList,
+    in_token: SyntaxToken,
+    iterable: ScssExpression,
+    block: AnyCssConditionalBlock,
+) -> ScssEachAtRule {
+    ScssEachAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_AT_RULE,
+        [
+            Some(SyntaxElement::Token(each_token)),
+            Some(SyntaxElement::Node(bindings.into_syntax())),
+            Some(SyntaxElement::Token(in_token)),
+            Some(SyntaxElement::Node(iterable.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_else_clause(
+    at_token: SyntaxToken,
+    else_token: SyntaxToken,
+    body: AnyScssElseClauseBody,
+) -> ScssElseClause {
+    ScssElseClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ELSE_CLAUSE,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Node(body.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_expression(items: ScssExpressionItemList) -> ScssExpression {
+    ScssExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXPRESSION,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_if_at_rule(
+    if_token: SyntaxToken,
+    condition: AnyCssExpression,
+    block: AnyCssConditionalBlock,
+) -> ScssIfAtRuleBuilder {
+    ScssIfAtRuleBuilder {
+        if_token,
+        condition,
+        block,
+        else_clause: None,
+    }
+}
+pub struct ScssIfAtRuleBuilder {
+    if_token: SyntaxToken,
+    condition: AnyCssExpression,
+    block: AnyCssConditionalBlock,
+    else_clause: Option<ScssElseClause>,
+}
+impl ScssIfAtRuleBuilder {
+    pub fn with_else_clause(mut self, else_clause: ScssElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> ScssIfAtRule {
+        ScssIfAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_IF_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.if_token)),
+                Some(SyntaxElement::Node(self.condition.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_include_at_rule(
+    include_token: SyntaxToken,
+    name: CssIdentifier,
+) -> ScssIncludeAtRuleBuilder {
+    ScssIncludeAtRuleBuilder {
+        include_token,
+        name,
+        arguments: None,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssIncludeAtRuleBuilder {
+    include_token: SyntaxToken,
+    name: CssIdentifier,
+    arguments: Option<ScssArguments>,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssIncludeAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssIncludeAtRule {
+        ScssIncludeAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_INCLUDE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.include_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_interpolation(
+    hash_token: SyntaxToken,
+    l_curly_token: SyntaxToken,
+    value: AnyCssExpression,
+    r_curly_token: SyntaxToken,
+) -> ScssInterpolation {
+    ScssInterpolation::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATION,
+        [
+            Some(SyntaxElement::Token(hash_token)),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn scss_keyword_argument(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssExpression,
+) -> ScssKeywordArgument {
+    ScssKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_mixin_at_rule(
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssMixinAtRuleBuilder {
+    ScssMixinAtRuleBuilder {
+        mixin_token,
+        name,
+        block,
+        parameters: None,
+    }
+}
+pub struct ScssMixinAtRuleBuilder {
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+    parameters: Option<ScssParameters>,
+}
+impl ScssMixinAtRuleBuilder {
+    pub fn with_parameters(mut self, parameters: ScssParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> ScssMixinAtRule {
+        ScssMixinAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_MIXIN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.mixin_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter(name: ScssIdentifier) -> ScssParameterBuilder {
+    ScssParameterBuilder {
+        name,
+        default_value: None,
+    }
+}
+pub struct ScssParameterBuilder {
+    name: ScssIdentifier,
+    default_value: Option<ScssParameterDefaultValue>,
+}
+impl ScssParameterBuilder {
+    pub fn with_default_value(mut self, default_value: ScssParameterDefaultValue) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn build(self) -> ScssParameter {
+        ScssParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: AnyCssExpression,
+) -> ScssParameterDefaultValue {
+    ScssParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameters(
+    l_paren_token: SyntaxToken,
+    items: ScssParameterList,
+    r_paren_token: SyntaxToken,
+) -> ScssParameters {
+    ScssParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
+pub fn css_apply_class_list<I>(items: I) -> CssApplyClassList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssApplyClassList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_APPLY_CLASS_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bracketed_value_list<I>(items: I) -> CssBracketedValueList
 where
     I: IntoIterator<Item = AnyCssCustomIdentifier>,
@@ -2796,6 +3416,114 @@ where
         }),
     ))
 }
+pub fn less_mixin_parameter_list<I>(items: I) -> LessMixinParameterList
+where
+    I: IntoIterator<Item = LessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+{
+    LessMixinParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn scss_argument_list<I, S>(items: I, separators: S) -> ScssArgumentList
+where
+    I: IntoIterator<Item = AnyScssArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_each_binding_list<I, S>(items: I, separators: S) -> ScssEachBindingList
+where
+    I: IntoIterator<Item = ScssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssEachBindingList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_BINDING_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_expression_item_list<I, S>(items: I, separators: S) -> ScssExpressionItemList
+where
+    I: IntoIterator<Item = AnyCssExpression>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssExpressionItemList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXPRESSION_ITEM_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_parameter_list<I, S>(items: I, separators: S) -> ScssParameterList
+where
+    I: IntoIterator<Item = ScssParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bogus<I>(slots: I) -> CssBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
@@ -8,7 +8,7 @@ use biome_formatter::comments::{
 use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax};
 
 pub type CssComments = Comments<CssLanguage>;
 
@@ -66,7 +66,7 @@ impl CommentStyle for CssCommentStyle {
     type Language = CssLanguage;
 
     fn is_suppression(text: &str) -> bool {
-        parse_suppression_comment(text)
+        parse_suppression_comment_with(text, CommentSyntax::C_STYLE)
             .filter_map(Result::ok)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
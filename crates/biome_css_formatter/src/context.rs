@@ -2,7 +2,7 @@ use crate::CssCommentStyle;
 use biome_formatter::{prelude::*, AttributePosition, BracketSpacing, IndentWidth, QuoteStyle};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
-    TransformSourceMap,
+    MaxEmptyLines, TransformSourceMap,
 };
 
 use crate::comments::{CssComments, FormatCssLeadingComment};
@@ -61,6 +61,7 @@ pub struct CssFormatOptions {
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
+    max_empty_lines: MaxEmptyLines,
     quote_style: QuoteStyle,
     _file_source: CssFileSource,
 }
@@ -73,6 +74,7 @@ impl CssFormatOptions {
             indent_width: IndentWidth::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            max_empty_lines: MaxEmptyLines::default(),
             quote_style: QuoteStyle::default(),
         }
     }
@@ -97,6 +99,11 @@ impl CssFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
         self.quote_style = quote_style;
         self
@@ -118,6 +125,10 @@ impl CssFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
         self.quote_style = quote_style;
     }
@@ -152,6 +163,10 @@ impl FormatOptions for CssFormatOptions {
         BracketSpacing::default()
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::from(self)
     }
@@ -163,6 +178,7 @@ impl fmt::Display for CssFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Quote style: {}", self.quote_style)
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines.value())
     }
 }
@@ -8,9 +8,11 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
     type Context = CssFormatContext;
     fn fmt(&self, node: &AnyCssAtRule, f: &mut CssFormatter) -> FormatResult<()> {
         match node {
+            AnyCssAtRule::CssApplyAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssBogusAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssCharsetAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssColorProfileAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssConfigAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssContainerAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssCounterStyleAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssDocumentAtRule(node) => node.format().fmt(f),
@@ -25,11 +27,17 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssPageAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssPropertyAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssScopeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssScreenAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssStartingStyleAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssSupportsAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::CssTailwindAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssUnknownBlockAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssUnknownValueAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssValueAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssEachAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIfAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssIncludeAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssMixinAtRule(node) => node.format().fmt(f),
         }
     }
 }
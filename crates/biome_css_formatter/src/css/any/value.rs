@@ -17,9 +17,13 @@ impl FormatRule<AnyCssValue> for FormatAnyCssValue {
             AnyCssValue::CssIdentifier(node) => node.format().fmt(f),
             AnyCssValue::CssMetavariable(node) => node.format().fmt(f),
             AnyCssValue::CssNumber(node) => node.format().fmt(f),
+            AnyCssValue::CssParenthesizedExpression(node) => node.format().fmt(f),
             AnyCssValue::CssRatio(node) => node.format().fmt(f),
             AnyCssValue::CssString(node) => node.format().fmt(f),
             AnyCssValue::CssUnicodeRange(node) => node.format().fmt(f),
+            AnyCssValue::LessIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssInterpolation(node) => node.format().fmt(f),
         }
     }
 }
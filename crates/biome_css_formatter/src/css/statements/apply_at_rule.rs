@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssApplyAtRule, CssApplyAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssApplyAtRule;
+impl FormatNodeRule<CssApplyAtRule> for FormatCssApplyAtRule {
+    fn fmt_fields(&self, node: &CssApplyAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssApplyAtRuleFields {
+            apply_token,
+            classes,
+            important,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [apply_token.format(), space(), classes.format()])?;
+
+        if important.is_some() {
+            write!(f, [space(), important.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
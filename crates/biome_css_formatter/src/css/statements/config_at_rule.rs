@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssConfigAtRule, CssConfigAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssConfigAtRule;
+impl FormatNodeRule<CssConfigAtRule> for FormatCssConfigAtRule {
+    fn fmt_fields(&self, node: &CssConfigAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssConfigAtRuleFields {
+            config_token,
+            path,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                config_token.format(),
+                space(),
+                path.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
@@ -1,8 +1,10 @@
 //! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
 
+pub(crate) mod apply_at_rule;
 pub(crate) mod at_rule;
 pub(crate) mod charset_at_rule;
 pub(crate) mod color_profile_at_rule;
+pub(crate) mod config_at_rule;
 pub(crate) mod container_at_rule;
 pub(crate) mod counter_style_at_rule;
 pub(crate) mod document_at_rule;
@@ -18,8 +20,10 @@ pub(crate) mod namespace_at_rule;
 pub(crate) mod page_at_rule;
 pub(crate) mod property_at_rule;
 pub(crate) mod scope_at_rule;
+pub(crate) mod screen_at_rule;
 pub(crate) mod starting_style_at_rule;
 pub(crate) mod supports_at_rule;
+pub(crate) mod tailwind_at_rule;
 pub(crate) mod unknown_block_at_rule;
 pub(crate) mod unknown_value_at_rule;
 pub(crate) mod value_at_rule;
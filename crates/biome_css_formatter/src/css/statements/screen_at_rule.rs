@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssScreenAtRule, CssScreenAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssScreenAtRule;
+impl FormatNodeRule<CssScreenAtRule> for FormatCssScreenAtRule {
+    fn fmt_fields(&self, node: &CssScreenAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssScreenAtRuleFields {
+            screen_token,
+            name,
+            block,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                screen_token.format(),
+                space(),
+                name.format(),
+                space(),
+                block.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssTailwindAtRule, CssTailwindAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTailwindAtRule;
+impl FormatNodeRule<CssTailwindAtRule> for FormatCssTailwindAtRule {
+    fn fmt_fields(&self, node: &CssTailwindAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTailwindAtRuleFields {
+            tailwind_token,
+            name,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                tailwind_token.format(),
+                space(),
+                name.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
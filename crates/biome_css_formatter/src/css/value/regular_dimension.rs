@@ -1,5 +1,6 @@
 use crate::{prelude::*, utils::string_utils::FormatTokenAsLowercase};
-use biome_css_syntax::{CssRegularDimension, CssRegularDimensionFields};
+use biome_css_syntax::{CssRegularDimension, CssRegularDimensionFields, CssSyntaxToken};
+use biome_css_value::dimension::Unit;
 use biome_formatter::write;
 
 #[derive(Debug, Clone, Default)]
@@ -15,8 +16,30 @@ impl FormatNodeRule<CssRegularDimension> for FormatCssRegularDimension {
             f,
             [
                 FormatTokenAsLowercase::from(value_token?),
-                FormatTokenAsLowercase::from(unit_token?),
+                FormatUnitToken(&unit_token?),
             ]
         )
     }
 }
+
+/// Formats a known unit with its canonical spelling, such as `px`, `Q` or `kHz`
+struct FormatUnitToken<'a>(&'a CssSyntaxToken);
+
+impl Format<CssFormatContext> for FormatUnitToken<'_> {
+    fn fmt(&self, f: &mut CssFormatter) -> FormatResult<()> {
+        let token = self.0;
+        match Unit::from_name(token.text_trimmed()) {
+            Some(unit) if unit.name() != token.text_trimmed() => {
+                write!(
+                    f,
+                    [format_replaced(
+                        token,
+                        &dynamic_text(unit.name(), token.text_trimmed_range().start())
+                    )]
+                )
+            }
+            Some(_) => write!(f, [token.format()]),
+            None => write!(f, [FormatTokenAsLowercase::from(token.clone())]),
+        }
+    }
+}
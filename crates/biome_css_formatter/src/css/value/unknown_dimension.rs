@@ -11,13 +11,13 @@ impl FormatNodeRule<CssUnknownDimension> for FormatCssUnknownDimension {
             unit_token,
         } = node.as_fields();
 
-        let var_name = write!(
+        // The unknown units keep the case as written, because they could be case-sensitive
+        write!(
             f,
             [
                 FormatTokenAsLowercase::from(value_token?),
-                FormatTokenAsLowercase::from(unit_token?),
+                unit_token.format()
             ]
-        );
-        var_name
+        )
     }
 }
@@ -4,6 +4,46 @@ use crate::{
     AsFormat, CssFormatContext, CssFormatter, FormatBogusNodeRule, FormatNodeRule, IntoFormat,
 };
 use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_css_syntax::CssApplyAtRule>
+    for crate::css::statements::apply_at_rule::FormatCssApplyAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssApplyAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssApplyAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssApplyAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssApplyAtRule,
+        crate::css::statements::apply_at_rule::FormatCssApplyAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::apply_at_rule::FormatCssApplyAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssApplyAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssApplyAtRule,
+        crate::css::statements::apply_at_rule::FormatCssApplyAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::apply_at_rule::FormatCssApplyAtRule::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssAtRule> for crate::css::statements::at_rule::FormatCssAtRule {
     type Context = CssFormatContext;
     #[inline(always)]
@@ -619,6 +659,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelector {
         )
     }
 }
+impl FormatRule<biome_css_syntax::CssConfigAtRule>
+    for crate::css::statements::config_at_rule::FormatCssConfigAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssConfigAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssConfigAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssConfigAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssConfigAtRule,
+        crate::css::statements::config_at_rule::FormatCssConfigAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::config_at_rule::FormatCssConfigAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssConfigAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssConfigAtRule,
+        crate::css::statements::config_at_rule::FormatCssConfigAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::config_at_rule::FormatCssConfigAtRule::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssContainerAndQuery>
     for crate::css::auxiliary::container_and_query::FormatCssContainerAndQuery
 {
@@ -4694,6 +4774,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScopeRangeStart {
         )
     }
 }
+impl FormatRule<biome_css_syntax::CssScreenAtRule>
+    for crate::css::statements::screen_at_rule::FormatCssScreenAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssScreenAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssScreenAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssScreenAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssScreenAtRule,
+        crate::css::statements::screen_at_rule::FormatCssScreenAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::screen_at_rule::FormatCssScreenAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssScreenAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssScreenAtRule,
+        crate::css::statements::screen_at_rule::FormatCssScreenAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::screen_at_rule::FormatCssScreenAtRule::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssStartingStyleAtRule>
     for crate::css::statements::starting_style_at_rule::FormatCssStartingStyleAtRule
 {
@@ -5024,6 +5144,46 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSupportsOrCondition {
         )
     }
 }
+impl FormatRule<biome_css_syntax::CssTailwindAtRule>
+    for crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::CssTailwindAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::CssTailwindAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssTailwindAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssTailwindAtRule,
+        crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssTailwindAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssTailwindAtRule,
+        crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::statements::tailwind_at_rule::FormatCssTailwindAtRule::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssTypeSelector>
     for crate::css::selectors::type_selector::FormatCssTypeSelector
 {
@@ -5670,213 +5830,1232 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleNamedImpor
         FormatOwnedWithRule :: new (self , crate :: css :: auxiliary :: value_at_rule_named_import_specifier :: FormatCssValueAtRuleNamedImportSpecifier :: default ())
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
+impl FormatRule<biome_css_syntax::LessDeclaration>
+    for crate::less::auxiliary::declaration::FormatLessDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssBracketedValueList,
-        crate::css::lists::bracketed_value_list::FormatCssBracketedValueList,
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::bracketed_value_list::FormatCssBracketedValueList::default(),
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssBracketedValueList,
-        crate::css::lists::bracketed_value_list::FormatCssBracketedValueList,
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::bracketed_value_list::FormatCssBracketedValueList::default(),
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+impl FormatRule<biome_css_syntax::LessGuardBinaryCondition>
+    for crate::less::auxiliary::guard_binary_condition::FormatLessGuardBinaryCondition
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessGuardBinaryCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessGuardBinaryCondition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessGuardBinaryCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssComponentValueList,
-        crate::css::lists::component_value_list::FormatCssComponentValueList,
+        biome_css_syntax::LessGuardBinaryCondition,
+        crate::less::auxiliary::guard_binary_condition::FormatLessGuardBinaryCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+            crate::less::auxiliary::guard_binary_condition::FormatLessGuardBinaryCondition::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessGuardBinaryCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssComponentValueList,
-        crate::css::lists::component_value_list::FormatCssComponentValueList,
+        biome_css_syntax::LessGuardBinaryCondition,
+        crate::less::auxiliary::guard_binary_condition::FormatLessGuardBinaryCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+            crate::less::auxiliary::guard_binary_condition::FormatLessGuardBinaryCondition::default(
+            ),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+impl FormatRule<biome_css_syntax::LessGuardNotCondition>
+    for crate::less::auxiliary::guard_not_condition::FormatLessGuardNotCondition
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessGuardNotCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessGuardNotCondition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessGuardNotCondition {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssComposesClassList,
-        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+        biome_css_syntax::LessGuardNotCondition,
+        crate::less::auxiliary::guard_not_condition::FormatLessGuardNotCondition,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+            crate::less::auxiliary::guard_not_condition::FormatLessGuardNotCondition::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessGuardNotCondition {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssComposesClassList,
-        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+        biome_css_syntax::LessGuardNotCondition,
+        crate::less::auxiliary::guard_not_condition::FormatLessGuardNotCondition,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+            crate::less::auxiliary::guard_not_condition::FormatLessGuardNotCondition::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl FormatRule<biome_css_syntax::LessIdentifier>
+    for crate::less::value::identifier::FormatLessIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::less::value::identifier::FormatLessIdentifier::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCompoundSelectorList,
-        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+            crate::less::value::identifier::FormatLessIdentifier::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifierList {
+impl FormatRule<biome_css_syntax::LessMixinCall>
+    for crate::less::auxiliary::mixin_call::FormatLessMixinCall
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinCall,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinCall>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssCustomIdentifierList,
-        crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList,
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList::default(),
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifierList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssCustomIdentifierList,
-        crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList,
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList::default(),
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+impl FormatRule<biome_css_syntax::LessMixinDeclaration>
+    for crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationList,
-        crate::css::lists::declaration_list::FormatCssDeclarationList,
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationList,
-        crate::css::lists::declaration_list::FormatCssDeclarationList,
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+impl FormatRule<biome_css_syntax::LessMixinGuard>
+    for crate::less::auxiliary::mixin_guard::FormatLessMixinGuard
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinGuard,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinGuard>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrAtRuleList,
-        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatRefWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrAtRuleList,
-        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+impl FormatRule<biome_css_syntax::LessMixinParameter>
+    for crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssDeclarationOrRuleList,
-        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
     >;
     fn format(&self) -> Self::Format<'_> {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatRefWithRule::new(
             self,
-            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssDeclarationOrRuleList,
-        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
     >;
     fn into_format(self) -> Self::Format {
         #![allow(clippy::default_constructed_unit_structs)]
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameters>
+    for crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessNamedParameter>
+    for crate::less::auxiliary::named_parameter::FormatLessNamedParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessNamedParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessNamedParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessNamedParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessNamedParameter,
+        crate::less::auxiliary::named_parameter::FormatLessNamedParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::named_parameter::FormatLessNamedParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessNamedParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessNamedParameter,
+        crate::less::auxiliary::named_parameter::FormatLessNamedParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::named_parameter::FormatLessNamedParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssArguments>
+    for crate::scss::auxiliary::arguments::FormatScssArguments
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssArguments,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssArguments>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArguments {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArguments,
+        crate::scss::auxiliary::arguments::FormatScssArguments,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::arguments::FormatScssArguments::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArguments {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArguments,
+        crate::scss::auxiliary::arguments::FormatScssArguments,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::arguments::FormatScssArguments::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssDeclaration>
+    for crate::scss::auxiliary::declaration::FormatScssDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssEachAtRule>
+    for crate::scss::statements::each_at_rule::FormatScssEachAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssEachAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssEachAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssElseClause>
+    for crate::scss::auxiliary::else_clause::FormatScssElseClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssElseClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssElseClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssExpression>
+    for crate::scss::auxiliary::expression::FormatScssExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExpression,
+        crate::scss::auxiliary::expression::FormatScssExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::expression::FormatScssExpression::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExpression,
+        crate::scss::auxiliary::expression::FormatScssExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::expression::FormatScssExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIdentifier>
+    for crate::scss::value::identifier::FormatScssIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIfAtRule>
+    for crate::scss::statements::if_at_rule::FormatScssIfAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssIfAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIfAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIncludeAtRule>
+    for crate::scss::statements::include_at_rule::FormatScssIncludeAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIncludeAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIncludeAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssInterpolation>
+    for crate::scss::auxiliary::interpolation::FormatScssInterpolation
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssInterpolation,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssInterpolation>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssKeywordArgument>
+    for crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssKeywordArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssKeywordArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMixinAtRule>
+    for crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMixinAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMixinAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameter>
+    for crate::scss::auxiliary::parameter::FormatScssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameterDefaultValue>
+    for crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameterDefaultValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameterDefaultValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefaultValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameterDefaultValue,
+        crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: parameter_default_value :: FormatScssParameterDefaultValue :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefaultValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameterDefaultValue,
+        crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: parameter_default_value :: FormatScssParameterDefaultValue :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameters>
+    for crate::scss::auxiliary::parameters::FormatScssParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssVariableModifier>
+    for crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssVariableModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssVariableModifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssApplyClassList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssApplyClassList,
+        crate::css::lists::apply_class_list::FormatCssApplyClassList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::apply_class_list::FormatCssApplyClassList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssApplyClassList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssApplyClassList,
+        crate::css::lists::apply_class_list::FormatCssApplyClassList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::apply_class_list::FormatCssApplyClassList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssBracketedValueList,
+        crate::css::lists::bracketed_value_list::FormatCssBracketedValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::bracketed_value_list::FormatCssBracketedValueList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssBracketedValueList,
+        crate::css::lists::bracketed_value_list::FormatCssBracketedValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::bracketed_value_list::FormatCssBracketedValueList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssComposesClassList,
+        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssComposesClassList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssComposesClassList,
+        crate::css::lists::composes_class_list::FormatCssComposesClassList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::composes_class_list::FormatCssComposesClassList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCompoundSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCompoundSelectorList,
+        crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::compound_selector_list::FormatCssCompoundSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifierList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssCustomIdentifierList,
+        crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssCustomIdentifierList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssCustomIdentifierList,
+        crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::custom_identifier_list::FormatCssCustomIdentifierList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationList,
+        crate::css::lists::declaration_list::FormatCssDeclarationList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::declaration_list::FormatCssDeclarationList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationOrAtRuleList,
+        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrAtRuleList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationOrAtRuleList,
+        crate::css::lists::declaration_or_at_rule_list::FormatCssDeclarationOrAtRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: declaration_or_at_rule_list :: FormatCssDeclarationOrAtRuleList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssDeclarationOrRuleList,
+        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssDeclarationOrRuleList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssDeclarationOrRuleList,
+        crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::declaration_or_rule_list::FormatCssDeclarationOrRuleList::default(),
         )
     }
 }
@@ -6445,6 +7624,168 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRulePropertyLi
         FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_property_list :: FormatCssValueAtRulePropertyList :: default ())
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExpressionItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExpressionItemList,
+        crate::scss::lists::expression_item_list::FormatScssExpressionItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::expression_item_list::FormatScssExpressionItemList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExpressionItemList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExpressionItemList,
+        crate::scss::lists::expression_item_list::FormatScssExpressionItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::expression_item_list::FormatScssExpressionItemList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssBogus> for crate::css::bogus::bogus::FormatCssBogus {
     type Context = CssFormatContext;
     #[inline(always)]
@@ -9465,3 +10806,111 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRulePropert
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessGuardCondition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessGuardCondition,
+        crate::less::any::guard_condition::FormatAnyLessGuardCondition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::guard_condition::FormatAnyLessGuardCondition::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessGuardCondition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessGuardCondition,
+        crate::less::any::guard_condition::FormatAnyLessGuardCondition,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::guard_condition::FormatAnyLessGuardCondition::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameterValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinParameterValue,
+        crate::less::any::mixin_parameter_value::FormatAnyLessMixinParameterValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_parameter_value::FormatAnyLessMixinParameterValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameterValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinParameterValue,
+        crate::less::any::mixin_parameter_value::FormatAnyLessMixinParameterValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_parameter_value::FormatAnyLessMixinParameterValue::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssArgument,
+        crate::scss::any::argument::FormatAnyScssArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::argument::FormatAnyScssArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssArgument,
+        crate::scss::any::argument::FormatAnyScssArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::argument::FormatAnyScssArgument::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessGuardCondition;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessGuardCondition;
+impl FormatRule<AnyLessGuardCondition> for FormatAnyLessGuardCondition {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessGuardCondition, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessGuardCondition::CssParenthesizedExpression(node) => node.format().fmt(f),
+            AnyLessGuardCondition::LessGuardBinaryCondition(node) => node.format().fmt(f),
+            AnyLessGuardCondition::LessGuardNotCondition(node) => node.format().fmt(f),
+        }
+    }
+}
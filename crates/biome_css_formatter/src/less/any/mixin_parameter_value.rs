@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinParameterValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinParameterValue;
+impl FormatRule<AnyLessMixinParameterValue> for FormatAnyLessMixinParameterValue {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinParameterValue, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinParameterValue::AnyCssExpression(node) => node.format().fmt(f),
+            AnyLessMixinParameterValue::LessNamedParameter(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod guard_condition;
+pub(crate) mod mixin_parameter_value;
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessDeclaration, LessDeclarationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessDeclaration;
+impl FormatNodeRule<LessDeclaration> for FormatLessDeclaration {
+    fn fmt_fields(&self, node: &LessDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessDeclarationFields {
+            name,
+            colon_token,
+            value,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )?;
+
+        if semicolon_token.is_some() {
+            // if semicolon is present, use the token's format to keep the comments
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [text(";")])
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessGuardBinaryCondition, LessGuardBinaryConditionFields, T};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessGuardBinaryCondition;
+impl FormatNodeRule<LessGuardBinaryCondition> for FormatLessGuardBinaryCondition {
+    fn fmt_fields(
+        &self,
+        node: &LessGuardBinaryCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessGuardBinaryConditionFields {
+            left,
+            operator,
+            right,
+        } = node.as_fields();
+
+        let operator = operator?;
+
+        write!(f, [left.format()])?;
+
+        // `when (@a), (@b)` reads like a list, the comma sticks to the left condition
+        if operator.kind() != T![,] {
+            write!(f, [space()])?;
+        }
+
+        write!(
+            f,
+            [
+                operator.format(),
+                soft_line_break_or_space(),
+                right.format()
+            ]
+        )
+    }
+}
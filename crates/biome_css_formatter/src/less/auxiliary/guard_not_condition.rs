@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessGuardNotCondition, LessGuardNotConditionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessGuardNotCondition;
+impl FormatNodeRule<LessGuardNotCondition> for FormatLessGuardNotCondition {
+    fn fmt_fields(&self, node: &LessGuardNotCondition, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessGuardNotConditionFields {
+            not_token,
+            condition,
+        } = node.as_fields();
+
+        write!(f, [not_token.format(), space(), condition.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinParameter, LessMixinParameterFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameter;
+impl FormatNodeRule<LessMixinParameter> for FormatLessMixinParameter {
+    fn fmt_fields(&self, node: &LessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParameterFields { value, separator } = node.as_fields();
+
+        write!(f, [value.format(), separator.format()])
+    }
+}
@@ -0,0 +1,11 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod declaration;
+pub(crate) mod guard_binary_condition;
+pub(crate) mod guard_not_condition;
+pub(crate) mod mixin_call;
+pub(crate) mod mixin_declaration;
+pub(crate) mod mixin_guard;
+pub(crate) mod mixin_parameter;
+pub(crate) mod mixin_parameters;
+pub(crate) mod named_parameter;
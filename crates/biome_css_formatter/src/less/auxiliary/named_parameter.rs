@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessNamedParameter, LessNamedParameterFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessNamedParameter;
+impl FormatNodeRule<LessNamedParameter> for FormatLessNamedParameter {
+    fn fmt_fields(&self, node: &LessNamedParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessNamedParameterFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
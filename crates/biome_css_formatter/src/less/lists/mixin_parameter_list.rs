@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinParameterList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameterList;
+impl FormatRule<LessMixinParameterList> for FormatLessMixinParameterList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessMixinParameterList, f: &mut CssFormatter) -> FormatResult<()> {
+        // Each parameter keeps its separator, because a list can mix commas and semicolons
+        f.join_with(&soft_line_break_or_space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,4 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument;
+pub(crate) mod else_clause_body;
@@ -0,0 +1,12 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod arguments;
+pub(crate) mod declaration;
+pub(crate) mod else_clause;
+pub(crate) mod expression;
+pub(crate) mod interpolation;
+pub(crate) mod keyword_argument;
+pub(crate) mod parameter;
+pub(crate) mod parameter_default_value;
+pub(crate) mod parameters;
+pub(crate) mod variable_modifier;
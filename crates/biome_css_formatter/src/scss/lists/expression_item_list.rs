@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssExpressionItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssExpressionItemList;
+impl FormatRule<ScssExpressionItemList> for FormatScssExpressionItemList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssExpressionItemList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
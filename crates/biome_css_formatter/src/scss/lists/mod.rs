@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument_list;
+pub(crate) mod each_binding_list;
+pub(crate) mod expression_item_list;
+pub(crate) mod parameter_list;
+pub(crate) mod variable_modifier_list;
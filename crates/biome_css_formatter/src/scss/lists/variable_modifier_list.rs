@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssVariableModifierList;
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssVariableModifierList;
+impl FormatRule<ScssVariableModifierList> for FormatScssVariableModifierList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssVariableModifierList, f: &mut CssFormatter) -> FormatResult<()> {
+        for modifier in node {
+            write!(f, [space(), modifier.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod each_at_rule;
+pub(crate) mod if_at_rule;
+pub(crate) mod include_at_rule;
+pub(crate) mod mixin_at_rule;
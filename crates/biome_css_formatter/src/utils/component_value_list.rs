@@ -31,21 +31,31 @@ where
 
     let values = format_with(|f: &mut Formatter<'_, CssFormatContext>| {
         let mut fill = f.fill();
+        let mut is_previous_comma = false;
+        let mut is_previous_unary_operator = false;
+
+        for (index, (element, formatted)) in node.iter().zip(node.iter().formatted()).enumerate() {
+            let token_kind = CssGenericDelimiter::cast_ref(element.syntax())
+                .and_then(|node| node.value().ok())
+                .map(|token| token.kind());
+
+            let is_comma = matches!(token_kind, Some(CssSyntaxKind::COMMA));
+
+            // A sign at the start of a value stays attached to its operand.
+            // Consider the Less example: `margin: -@gutter, 0;`
+            let is_after_unary_operator = is_previous_unary_operator;
+            is_previous_unary_operator =
+                matches!(token_kind, Some(CssSyntaxKind::PLUS | CssSyntaxKind::MINUS))
+                    && (index == 0 || is_previous_comma);
+            is_previous_comma = is_comma;
 
-        for (element, formatted) in node.iter().zip(node.iter().formatted()) {
             fill.entry(
                 &format_once(|f| {
                     // If the current element is not a comma, insert a soft line break or a space.
                     // Consider the CSS example: `font: first , second;`
                     // The desired format is: `font: first, second;`
                     // A separator should not be added before the comma because the comma acts as a `CssGenericDelimiter`.
-                    let token_kind = CssGenericDelimiter::cast_ref(element.syntax())
-                        .and_then(|node| node.value().ok())
-                        .map(|token| token.kind());
-
-                    let is_comma = matches!(token_kind, Some(CssSyntaxKind::COMMA));
-
-                    if !is_comma {
+                    if !is_comma && !is_after_unary_operator {
                         if matches!(
                             layout,
                             ValueListLayout::PreserveInline | ValueListLayout::OnePerLine
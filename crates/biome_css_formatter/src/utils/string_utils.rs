@@ -144,6 +144,8 @@ impl FormatLiteralStringToken<'_> {
     /// (' content """ dont\'t ')
     /// ```
     /// Like this, we reduced the number of escaped quotes.
+    ///
+    /// With [QuoteStyle::Preserve], the quote of the string is kept, regardless of its content.
     fn compute_string_information(&self, chosen_quote: QuoteStyle) -> StringInformation {
         // For anything other than string literals, the token won't have
         // pre-existing quotes, so we can just immediately, safely use the
@@ -155,6 +157,12 @@ impl FormatLiteralStringToken<'_> {
         }
 
         let literal = self.token().text_trimmed();
+        if chosen_quote.is_preserve() {
+            return StringInformation {
+                preferred_quote: chosen_quote.resolve(literal),
+            };
+        }
+
         let alternate_quote = chosen_quote.other();
         let chosen_quote_byte = chosen_quote.as_byte();
         let alternate_quote_byte = alternate_quote.as_byte();
@@ -12,7 +12,13 @@ use biome_service::{
 
 #[derive(Default)]
 pub struct CssTestFormatLanguage {
-    _source_type: CssFileSource,
+    source_type: CssFileSource,
+}
+
+impl CssTestFormatLanguage {
+    pub fn new(source_type: CssFileSource) -> Self {
+        CssTestFormatLanguage { source_type }
+    }
 }
 
 impl TestFormatLanguage for CssTestFormatLanguage {
@@ -21,10 +27,18 @@ impl TestFormatLanguage for CssTestFormatLanguage {
     type FormatLanguage = CssFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let options = CssParserOptions::default()
+        let mut options = CssParserOptions::default()
             .allow_wrong_line_comments()
             .allow_css_modules();
 
+        if self.source_type.is_scss() {
+            options = options.allow_scss();
+        }
+
+        if self.source_type.is_less() {
+            options = options.allow_less();
+        }
+
         parse_css(text, options).into()
     }
 
@@ -1,7 +1,7 @@
 use biome_configuration::{PartialConfiguration, PartialCssConfiguration, PartialCssFormatter};
 use biome_css_formatter::{context::CssFormatOptions, CssFormatLanguage};
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
-use biome_service::workspace::UpdateSettingsParams;
 use std::path::Path;
 
 mod language {
@@ -17,7 +17,7 @@ mod language {
 /// `cargo insta review` or `cargo insta accept`
 ///
 /// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
-/// the input file name is `{spec_name}.css` and the output file name is `{spec_name}.css.snap`.
+/// the input file name is `{spec_name}.css` (or `{spec_name}.scss`) and the output file name is `{spec_name}.css.snap`.
 ///
 /// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
 ///
@@ -27,29 +27,27 @@ mod language {
 /// * `null` -> input: `tests/specs/null.css`, expected output: `tests/specs/null.css.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
-    let settings = UpdateSettingsParams {
-        configuration: PartialConfiguration {
-            css: Some(PartialCssConfiguration {
-                formatter: Some(PartialCssFormatter {
-                    enabled: Some(true),
-                    ..Default::default()
-                }),
+    let configuration = PartialConfiguration {
+        css: Some(PartialCssConfiguration {
+            formatter: Some(PartialCssFormatter {
+                enabled: Some(true),
                 ..Default::default()
             }),
             ..Default::default()
-        },
-        vcs_base_path: None,
-        gitignore_matches: vec![],
-        workspace_directory: None,
+        }),
+        ..Default::default()
     };
 
-    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
+    let Some(test_file) =
+        SpecTestFile::try_from_file(spec_input_file, root_path, Some(configuration))
     else {
         return;
     };
 
+    let source_type: CssFileSource = test_file.input_file().as_path().try_into().unwrap();
+
     let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(
         test_file,
@@ -5,4 +5,12 @@ mod formatter {
     mod css_module {
         tests_macros::gen_tests! {"tests/specs/css/**/*.css", crate::spec_test::run, ""}
     }
+
+    mod scss {
+        tests_macros::gen_tests! {"tests/specs/scss/**/*.scss", crate::spec_test::run, ""}
+    }
+
+    mod less {
+        tests_macros::gen_tests! {"tests/specs/less/**/*.less", crate::spec_test::run, ""}
+    }
 }
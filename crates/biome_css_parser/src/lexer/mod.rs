@@ -43,6 +43,11 @@ pub enum CssLexContext {
     /// support U+0-9A-F? U+0-9A-F{1,6} U+0-9A-F{1,6}?
     /// https://drafts.csswg.org/css-fonts/#unicode-range-desc
     UnicodeRange,
+
+    /// Applied when lexing the classes of the Tailwind `@apply` at-rule and the path of
+    /// the Tailwind `theme()` function.
+    /// Greedily consume a class such as `hover:bg-blue-500` or `w-1/2` into a single identifier.
+    TailwindUtility,
 }
 
 impl LexContext for CssLexContext {
@@ -132,6 +137,7 @@ impl<'src> Lexer<'src> for CssLexer<'src> {
                 CssLexContext::UrlRawValue => self.consume_url_raw_value_token(current),
                 CssLexContext::Color => self.consume_color_token(current),
                 CssLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                CssLexContext::TailwindUtility => self.consume_tailwind_utility_token(current),
             },
             None => EOF,
         };
@@ -324,6 +330,7 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            DOL if self.options.is_scss_enabled() => self.consume_byte(T![$]),
             UNI if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
                 self.consume_metavariable(GRIT_METAVARIABLE)
             }
@@ -345,7 +352,15 @@ impl<'src> CssLexer<'src> {
             MOR => self.consume_mor(),
             TLD => self.consume_tilde(),
             PIP => self.consume_pipe(),
+            EQL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![==])
+            }
             EQL => self.consume_byte(T![=]),
+            EXL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![!=])
+            }
             EXL => self.consume_byte(T![!]),
             PRC => self.consume_byte(T![%]),
             Dispatch::AMP => self.consume_byte(T![&]),
@@ -492,6 +507,47 @@ impl<'src> CssLexer<'src> {
         CSS_URL_VALUE_RAW_LITERAL
     }
 
+    /// Consumes a Tailwind utility class, or a Tailwind theme path, and returns its syntax kind.
+    ///
+    /// Whitespaces, strings, comments, `!important` and the punctuation ending the at-rule
+    /// or the function are lexed as usual.
+    fn consume_tailwind_utility_token(&mut self, current: u8) -> CssSyntaxKind {
+        match current {
+            b'/' if self.peek_byte() == Some(b'*') => self.consume_token(current),
+            b'!' if self.is_at_important_keyword() => self.consume_token(current),
+            b'\'' | b'"' | b';' | b',' | b'(' | b')' | b'{' | b'}' => self.consume_token(current),
+            _ if matches!(lookup_byte(current), WHS) => self.consume_token(current),
+            _ => self.consume_tailwind_utility(),
+        }
+    }
+
+    /// Consumes a Tailwind utility class until a whitespace or a punctuation ending it.
+    ///
+    /// Brackets and parentheses of arbitrary values are balanced, so `w-[calc(100%-2rem)]`
+    /// is consumed as a whole.
+    fn consume_tailwind_utility(&mut self) -> CssSyntaxKind {
+        let mut depth = 0usize;
+        while let Some(chr) = self.current_byte() {
+            match chr {
+                b'[' | b'(' => depth += 1,
+                b']' | b')' if depth > 0 => depth -= 1,
+                b';' | b',' | b')' | b'{' | b'}' if depth == 0 => break,
+                b'/' if depth == 0 && self.peek_byte() == Some(b'*') => break,
+                _ if matches!(lookup_byte(chr), WHS) => break,
+                _ => {}
+            }
+            self.advance(1);
+        }
+        T![ident]
+    }
+
+    /// Returns `true` if the lexer is at `!important`.
+    fn is_at_important_keyword(&self) -> bool {
+        self.source
+            .get(self.position + 1..self.position + 10)
+            .is_some_and(|keyword| keyword.eq_ignore_ascii_case("important"))
+    }
+
     fn consume_pseudo_nth_selector_token(&mut self, current: u8) -> CssSyntaxKind {
         match current {
             b'-' => self.consume_byte(T![-]),
@@ -922,6 +978,16 @@ impl<'src> CssLexer<'src> {
             b"value" => VALUE_KW,
             b"as" => AS_KW,
             b"composes" => COMPOSES_KW,
+            b"tailwind" => TAILWIND_KW,
+            b"apply" => APPLY_KW,
+            b"screen" => SCREEN_KW,
+            b"config" => CONFIG_KW,
+            b"mixin" => MIXIN_KW,
+            b"include" => INCLUDE_KW,
+            b"if" => IF_KW,
+            b"else" => ELSE_KW,
+            b"each" => EACH_KW,
+            b"when" => WHEN_KW,
             _ => IDENT,
         }
     }
@@ -1093,7 +1159,11 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.options.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.options.allow_wrong_line_comments
+                    || self.options.is_scss_enabled()
+                    || self.options.is_less_enabled() =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
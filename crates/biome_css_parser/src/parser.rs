@@ -33,6 +33,16 @@ pub struct CssParserOptions {
     /// Enables parsing of Grit metavariables.
     /// Defaults to `false`.
     pub grit_metavariables: bool,
+
+    /// Enables parsing of the SCSS syntax: variables, `@mixin`, `@include`, `@if`, `@each`,
+    /// interpolations and `//` comments.
+    /// Defaults to `false`.
+    pub scss: bool,
+
+    /// Enables parsing of the Less syntax: variables, mixins, guards, operations
+    /// and `//` comments.
+    /// Defaults to `false`.
+    pub less: bool,
 }
 
 impl CssParserOptions {
@@ -54,6 +64,18 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of the SCSS syntax.
+    pub fn allow_scss(mut self) -> Self {
+        self.scss = true;
+        self
+    }
+
+    /// Enables parsing of the Less syntax.
+    pub fn allow_less(mut self) -> Self {
+        self.less = true;
+        self
+    }
+
     /// Checks if parsing of CSS Modules features is disabled.
     pub fn is_css_modules_disabled(&self) -> bool {
         !self.css_modules
@@ -63,6 +85,16 @@ impl CssParserOptions {
     pub fn is_metavariable_enabled(&self) -> bool {
         self.grit_metavariables
     }
+
+    /// Checks if parsing of the SCSS syntax is enabled.
+    pub fn is_scss_enabled(&self) -> bool {
+        self.scss
+    }
+
+    /// Checks if parsing of the Less syntax is enabled.
+    pub fn is_less_enabled(&self) -> bool {
+        self.less
+    }
 }
 
 impl<'source> CssParser<'source> {
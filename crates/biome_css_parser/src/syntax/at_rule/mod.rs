@@ -16,8 +16,10 @@ mod page;
 mod parse_error;
 mod property;
 mod scope;
+mod scss;
 mod starting_style;
 mod supports;
+mod tailwind;
 mod unknown;
 mod value;
 
@@ -38,8 +40,15 @@ use crate::syntax::at_rule::namespace::parse_namespace_at_rule;
 use crate::syntax::at_rule::page::parse_page_at_rule;
 use crate::syntax::at_rule::property::parse_property_at_rule;
 use crate::syntax::at_rule::scope::parse_scope_at_rule;
+use crate::syntax::at_rule::scss::{
+    is_at_each_at_rule, is_at_if_at_rule, is_at_include_at_rule, is_at_mixin_at_rule,
+    parse_each_at_rule, parse_if_at_rule, parse_include_at_rule, parse_mixin_at_rule,
+};
 use crate::syntax::at_rule::starting_style::parse_starting_style_at_rule;
 use crate::syntax::at_rule::supports::parse_supports_at_rule;
+use crate::syntax::at_rule::tailwind::{
+    parse_apply_at_rule, parse_config_at_rule, parse_screen_at_rule, parse_tailwind_at_rule,
+};
 use crate::syntax::at_rule::unknown::{is_at_unknown_at_rule, parse_unknown_at_rule};
 use crate::syntax::at_rule::value::parse_value_at_rule;
 use crate::syntax::parse_error::expected_any_at_rule;
@@ -99,6 +108,14 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         T![document] => parse_document_at_rule(p),
         T![property] => parse_property_at_rule(p),
         T![value] => parse_value_at_rule(p),
+        T![tailwind] => parse_tailwind_at_rule(p),
+        T![apply] => parse_apply_at_rule(p),
+        T![screen] => parse_screen_at_rule(p),
+        T![config] => parse_config_at_rule(p),
+        _ if is_at_mixin_at_rule(p) => parse_mixin_at_rule(p),
+        _ if is_at_include_at_rule(p) => parse_include_at_rule(p),
+        _ if is_at_if_at_rule(p) => parse_if_at_rule(p),
+        _ if is_at_each_at_rule(p) => parse_each_at_rule(p),
         _ if is_at_unknown_at_rule(p) => parse_unknown_at_rule(p),
         _ => Absent,
     }
@@ -0,0 +1,389 @@
+use crate::parser::CssParser;
+use crate::syntax::block::{parse_conditional_block, parse_declaration_or_rule_list_block};
+use crate::syntax::parse_error::{expected_identifier, expected_scss_variable};
+use crate::syntax::parse_regular_identifier;
+use crate::syntax::scss::{
+    is_at_scss_identifier, parse_scss_expression, parse_scss_identifier, ScssEachBindingList,
+};
+use crate::syntax::value::function::parse_any_expression;
+use crate::syntax::value::parse_error::expected_expression;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+#[inline]
+pub(crate) fn is_at_mixin_at_rule(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![mixin])
+}
+
+/// Parses the SCSS `@mixin` at-rule, which defines styles that can be reused with `@include`.
+///
+/// Specification: [Sass - @mixin and @include](https://sass-lang.com/documentation/at-rules/mixin/)
+/// # Examples
+///
+/// ```scss
+/// @mixin theme($theme: DarkGray) {
+///     background: $theme;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_mixin_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![mixin]);
+
+    let kind = if parse_regular_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_MIXIN_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+        .is_ok()
+    {
+        SCSS_MIXIN_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT_VALUE);
+    }
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
+
+#[inline]
+pub(crate) fn is_at_include_at_rule(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![include])
+}
+
+/// Parses the SCSS `@include` at-rule, which includes the styles of a mixin.
+///
+/// Specification: [Sass - @mixin and @include](https://sass-lang.com/documentation/at-rules/mixin/)
+/// # Examples
+///
+/// ```scss
+/// .info {
+///     @include theme;
+///     @include theme($theme: DarkRed);
+///     @include hover {
+///         color: red;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_include_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![include]);
+
+    let kind = if parse_regular_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_INCLUDE_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+        .is_ok()
+    {
+        SCSS_INCLUDE_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_scss_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else if !p.at(T!['}']) {
+        // The semicolon is optional for the last item of a block.
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+fn parse_scss_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_ARGUMENTS))
+}
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_any_scss_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_expression,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_any_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_scss_identifier(p) && p.nth_at(2, T![:]) {
+        let m = p.start();
+
+        parse_scss_identifier(p).ok();
+        p.bump(T![:]);
+        parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+
+        Present(m.complete(p, SCSS_KEYWORD_ARGUMENT))
+    } else {
+        parse_any_expression(p)
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_if_at_rule(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![if])
+}
+
+/// Parses the SCSS `@if` at-rule and its `@else if` and `@else` clauses.
+///
+/// Specification: [Sass - @if and @else](https://sass-lang.com/documentation/at-rules/control/if/)
+/// # Examples
+///
+/// ```scss
+/// @mixin theme-colors($light-theme: true) {
+///     @if $light-theme {
+///         background-color: $light-background;
+///     } @else if $high-contrast {
+///         background-color: black;
+///     } @else {
+///         background-color: $dark-background;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_if_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_if_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![if]);
+
+    let kind = if parse_any_expression(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_CONDITION_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_expression,
+        )
+        .is_ok()
+    {
+        SCSS_IF_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_conditional_block(p);
+    parse_scss_else_clause(p).ok();
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+fn is_at_scss_else_clause(p: &mut CssParser) -> bool {
+    p.at(T![@]) && p.nth_at(1, T![else])
+}
+
+#[inline]
+fn parse_scss_else_clause(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_else_clause(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![else]);
+
+    if is_at_if_at_rule(p) {
+        parse_if_at_rule(p).ok();
+    } else {
+        parse_conditional_block(p);
+    }
+
+    Present(m.complete(p, SCSS_ELSE_CLAUSE))
+}
+
+#[inline]
+pub(crate) fn is_at_each_at_rule(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![each])
+}
+
+/// Parses the SCSS `@each` at-rule, which evaluates its block for each element of a list.
+///
+/// Specification: [Sass - @each](https://sass-lang.com/documentation/at-rules/control/each/)
+/// # Examples
+///
+/// ```scss
+/// @each $name, $glyph in $icons {
+///     .icon {
+///         content: $glyph;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_each_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_each_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![each]);
+
+    if !is_at_scss_identifier(p) {
+        p.error(expected_scss_variable(p, p.cur_range()));
+    }
+
+    ScssEachBindingList.parse_list(p);
+    p.expect(T![in]);
+
+    let kind = if parse_scss_expression(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_CONDITION_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_expression,
+        )
+        .is_ok()
+    {
+        SCSS_EACH_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_conditional_block(p);
+
+    Present(m.complete(p, kind))
+}
+
+const SCSS_MIXIN_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['('], T!['{']];
+const SCSS_INCLUDE_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T!['('], T!['{'], T![;], T!['}']];
+const SCSS_ARGUMENT_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![;], T!['{'], T!['}']];
+const SCSS_CONDITION_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['{']];
@@ -0,0 +1,221 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::block::parse_conditional_block;
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::{parse_declaration_important, parse_regular_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+#[inline]
+pub(crate) fn is_at_tailwind_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![tailwind])
+}
+
+/// Parses the Tailwind `@tailwind` at-rule, which injects the styles of a Tailwind layer.
+///
+/// Specification: [Tailwind CSS - @tailwind](https://tailwindcss.com/docs/functions-and-directives#tailwind)
+/// # Examples
+///
+/// ```css
+/// @tailwind base;
+/// @tailwind utilities;
+/// ```
+#[inline]
+pub(crate) fn parse_tailwind_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_tailwind_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![tailwind]);
+
+    let kind = if parse_regular_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, TAILWIND_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+        .is_ok()
+    {
+        CSS_TAILWIND_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+pub(crate) fn is_at_apply_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![apply])
+}
+
+/// Parses the Tailwind `@apply` at-rule, which inlines the styles of utility classes.
+///
+/// The classes are lexed with [CssLexContext::TailwindUtility], so variants and arbitrary
+/// values such as `hover:bg-blue-500`, `w-1/2` or `w-[32px]` are parsed as a single identifier.
+///
+/// Specification: [Tailwind CSS - @apply](https://tailwindcss.com/docs/functions-and-directives#apply)
+/// # Examples
+///
+/// ```css
+/// .btn {
+///     @apply font-bold py-2 px-4 hover:bg-blue-500 !important;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_apply_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_apply_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump_with_context(T![apply], CssLexContext::TailwindUtility);
+
+    if !p.at(T![ident]) {
+        p.error(expected_identifier(p, p.cur_range()));
+    }
+
+    ApplyClassList.parse_list(p);
+    parse_declaration_important(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, CSS_APPLY_AT_RULE))
+}
+
+struct ApplyClassList;
+
+impl ParseNodeList for ApplyClassList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_APPLY_CLASS_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !p.at(T![ident]) {
+            return Absent;
+        }
+
+        let m = p.start();
+        p.bump_with_context(T![ident], CssLexContext::TailwindUtility);
+        Present(m.complete(p, CSS_IDENTIFIER))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(APPLY_RECOVERY_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, APPLY_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_screen_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![screen])
+}
+
+/// Parses the Tailwind `@screen` at-rule, which creates a media query matching a breakpoint.
+///
+/// Specification: [Tailwind CSS - @screen](https://v3.tailwindcss.com/docs/functions-and-directives#screen)
+/// # Examples
+///
+/// ```css
+/// @screen md {
+///     .sidebar {
+///         display: none;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_screen_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_screen_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![screen]);
+
+    let kind = if parse_regular_identifier(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCREEN_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_identifier,
+        )
+        .is_ok()
+    {
+        CSS_SCREEN_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    parse_conditional_block(p);
+
+    Present(m.complete(p, kind))
+}
+
+#[inline]
+pub(crate) fn is_at_config_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![config])
+}
+
+/// Parses the Tailwind `@config` at-rule, which specifies the configuration file to use.
+///
+/// Specification: [Tailwind CSS - @config](https://tailwindcss.com/docs/functions-and-directives#config)
+/// # Examples
+///
+/// ```css
+/// @config "./tailwind.site.config.js";
+/// ```
+#[inline]
+pub(crate) fn parse_config_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_config_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![config]);
+
+    let kind = if parse_string(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, TAILWIND_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_string,
+        )
+        .is_ok()
+    {
+        CSS_CONFIG_AT_RULE
+    } else {
+        CSS_BOGUS_AT_RULE
+    };
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, kind))
+}
+
+const TAILWIND_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T![;]];
+const APPLY_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T![;], T![!], T!['}']];
+const SCREEN_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['{']];
@@ -1,7 +1,11 @@
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_mixin, parse_less_declaration, parse_less_mixin,
+};
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{is_at_scss_declaration, parse_scss_declaration};
 use crate::syntax::{
     is_at_declaration, is_at_declaration_semicolon, is_at_metavariable,
     is_at_nested_qualified_rule, parse_declaration_with_semicolon, parse_empty_declaration,
@@ -40,6 +44,9 @@ fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
         || is_at_nested_qualified_rule(p)
         || is_at_declaration(p)
         || is_at_metavariable(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_declaration(p)
+        || is_at_less_mixin(p)
 }
 
 struct DeclarationOrRuleListParseRecovery;
@@ -60,7 +67,11 @@ impl ParseNodeList for DeclarationOrRuleList {
     const LIST_KIND: Self::Kind = CSS_DECLARATION_OR_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_less_mixin(p) {
+            parse_less_mixin(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
         } else if is_at_declaration(p) {
             // if we are at a declaration,
@@ -128,6 +139,8 @@ impl ParseNodeList for DeclarationOrRuleList {
             parse_nested_qualified_rule(p)
         } else if is_at_metavariable(p) {
             parse_metavariable(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else if is_at_declaration_semicolon(p) {
             parse_empty_declaration(p)
         } else {
@@ -0,0 +1,286 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::{expected_component_value, expected_less_guard_condition};
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::selector::parse_class_selector;
+use crate::syntax::value::function::{
+    is_at_parenthesized, parse_any_expression, parse_parenthesized_expression,
+};
+use crate::syntax::value::parse_error::expected_expression;
+use crate::syntax::{is_nth_at_identifier, parse_declaration_important, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_less_identifier(p: &mut CssParser) -> bool {
+    p.options().is_less_enabled() && p.at(T![@]) && is_nth_at_identifier(p, 1)
+}
+
+/// Parses a Less variable reference.
+///
+/// Specification: [Less - Variables](https://lesscss.org/features/#variables-feature)
+/// # Examples
+///
+/// ```less
+/// .link {
+///     color: @link-color;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_less_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![@]);
+    parse_regular_identifier(p).ok();
+
+    Present(m.complete(p, LESS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_less_declaration(p: &mut CssParser) -> bool {
+    is_at_less_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a Less variable declaration.
+///
+/// Specification: [Less - Variables](https://lesscss.org/features/#variables-feature)
+/// # Examples
+///
+/// ```less
+/// @link-color: #428bca;
+/// @link-color-hover: darken(@link-color, 10%);
+/// ```
+#[inline]
+pub(crate) fn parse_less_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+
+    let value = GenericComponentValueList.parse_list(p);
+    let kind = if value.range(p).is_empty() {
+        p.error(expected_component_value(p, p.cur_range()));
+        CSS_BOGUS_RULE
+    } else {
+        LESS_DECLARATION
+    };
+
+    // The semicolon is optional for the last declaration of a block.
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, kind))
+}
+
+/// Checks if the parser is at a Less value that isn't a CSS value: a variable,
+/// or a parenthesized operation such as `(@a + @b)`.
+#[inline]
+pub(crate) fn is_at_any_less_value(p: &mut CssParser) -> bool {
+    is_at_less_identifier(p) || (p.options().is_less_enabled() && is_at_parenthesized(p))
+}
+
+#[inline]
+pub(crate) fn parse_any_less_value(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_less_identifier(p) {
+        parse_less_identifier(p)
+    } else if is_at_any_less_value(p) {
+        parse_parenthesized_expression(p)
+    } else {
+        Absent
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_less_mixin(p: &mut CssParser) -> bool {
+    p.options().is_less_enabled()
+        && p.at(T![.])
+        && is_nth_at_identifier(p, 1)
+        && p.nth_at_ts(2, LESS_MIXIN_START_SET)
+}
+
+/// Parses a Less mixin declaration with parameters, or a mixin call.
+///
+/// A class selector followed by parameters and a block, optionally guarded by a
+/// `when` condition, declares a mixin. Otherwise, it calls the mixin.
+///
+/// Specification: [Less - Mixins](https://lesscss.org/features/#mixins-feature)
+/// # Examples
+///
+/// ```less
+/// .bordered(@width: 2px) when (@width > 0) {
+///     border: @width solid black;
+/// }
+///
+/// #header {
+///     .bordered(4px);
+///     .rounded;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_less_mixin(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_class_selector(p).ok();
+    let parameters = parse_less_mixin_parameters(p);
+
+    if parameters.is_present() && (p.at(T![when]) || p.at(T!['{'])) {
+        parse_less_mixin_guard(p).ok();
+        parse_declaration_or_rule_list_block(p);
+
+        return Present(m.complete(p, LESS_MIXIN_DECLARATION));
+    }
+
+    parse_declaration_important(p).ok();
+
+    // The semicolon is optional for the last call of a block.
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, LESS_MIXIN_CALL))
+}
+
+#[inline]
+fn parse_less_mixin_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    LessMixinParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_MIXIN_PARAMETERS))
+}
+
+/// The parameters of a mixin are separated by commas or by semicolons.
+/// The separator is kept by each parameter, since both can appear in the same list.
+struct LessMixinParameterList;
+
+impl ParseNodeList for LessMixinParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, LESS_MIXIN_PARAMETER_RECOVERY_SET),
+            expected_expression,
+        )
+    }
+}
+
+#[inline]
+fn parse_less_mixin_parameter(p: &mut CssParser) -> ParsedSyntax {
+    let value = if is_at_less_declaration(p) {
+        let m = p.start();
+
+        parse_less_identifier(p).ok();
+        p.bump(T![:]);
+        parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+
+        Present(m.complete(p, LESS_NAMED_PARAMETER))
+    } else {
+        parse_any_expression(p)
+    };
+
+    let Present(value) = value else {
+        return Absent;
+    };
+
+    let m = value.precede(p);
+    p.eat_ts(LESS_MIXIN_PARAMETER_SEPARATOR_SET);
+
+    Present(m.complete(p, LESS_MIXIN_PARAMETER))
+}
+
+#[inline]
+fn parse_less_mixin_guard(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![when]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![when]);
+    parse_any_less_guard_condition(p).or_add_diagnostic(p, expected_less_guard_condition);
+
+    Present(m.complete(p, LESS_MIXIN_GUARD))
+}
+
+/// Parses the conditions of a guard, which are combined from left to right with
+/// `and`, `or` and `,`.
+#[inline]
+fn parse_any_less_guard_condition(p: &mut CssParser) -> ParsedSyntax {
+    let Present(mut condition) = parse_less_guard_unary_condition(p) else {
+        return Absent;
+    };
+
+    while p.at_ts(LESS_GUARD_OPERATOR_SET) {
+        let m = condition.precede(p);
+
+        p.bump_ts(LESS_GUARD_OPERATOR_SET);
+        parse_less_guard_unary_condition(p).or_add_diagnostic(p, expected_less_guard_condition);
+
+        condition = m.complete(p, LESS_GUARD_BINARY_CONDITION);
+    }
+
+    Present(condition)
+}
+
+#[inline]
+fn parse_less_guard_unary_condition(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(T![not]) {
+        let m = p.start();
+
+        p.bump(T![not]);
+        parse_parenthesized_expression(p).or_add_diagnostic(p, expected_less_guard_condition);
+
+        Present(m.complete(p, LESS_GUARD_NOT_CONDITION))
+    } else {
+        parse_parenthesized_expression(p)
+    }
+}
+
+/// The tokens that follow the name of a mixin when it's declared with parameters or called.
+const LESS_MIXIN_START_SET: TokenSet<CssSyntaxKind> = token_set![T!['('], T![;], T!['}'], T![!]];
+const LESS_MIXIN_PARAMETER_SEPARATOR_SET: TokenSet<CssSyntaxKind> = token_set![T![,], T![;]];
+const LESS_MIXIN_PARAMETER_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![;], T![')'], T!['{'], T!['}']];
+const LESS_GUARD_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![T![and], T![or], T![,]];
+/// The tokens that end a Less expression, such as the argument of a mixin call.
+pub(crate) const LESS_EXPRESSION_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![;], T!['{'], T!['}']];
@@ -1,8 +1,10 @@
 mod at_rule;
 mod block;
 mod css_modules;
+mod less;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
 mod value;
 
@@ -10,14 +12,23 @@ use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::{
+    is_at_any_less_value, is_at_less_declaration, is_at_less_mixin, parse_any_less_value,
+    parse_less_declaration, parse_less_mixin, LESS_EXPRESSION_END_SET,
+};
 use crate::syntax::parse_error::{expected_any_rule, expected_non_css_wide_keyword_identifier};
 use crate::syntax::property::color::{is_at_color, parse_color};
 use crate::syntax::property::unicode_range::{is_at_unicode_range, parse_unicode_range};
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_identifier, is_at_scss_interpolation,
+    parse_scss_declaration, parse_scss_identifier, parse_scss_interpolation,
+    SCSS_EXPRESSION_END_SET,
+};
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{is_at_relative_selector, RelativeSelectorList};
 use crate::syntax::selector::SelectorList;
-use crate::syntax::value::function::BINARY_OPERATION_TOKEN;
+use crate::syntax::value::function::is_at_binary_operator;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
@@ -51,7 +62,10 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_at_rule(p)
+        || is_at_qualified_rule(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_declaration(p)
 }
 
 struct RuleListParseRecovery {
@@ -80,10 +94,16 @@ impl ParseNodeList for RuleList {
     const LIST_KIND: Self::Kind = CSS_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_less_mixin(p) {
+            parse_less_mixin(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else {
             Absent
         }
@@ -254,7 +274,7 @@ pub(crate) fn is_at_declaration_semicolon(p: &mut CssParser) -> bool {
 }
 
 #[inline]
-fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_declaration_important(p) {
         return Absent;
     }
@@ -296,6 +316,9 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
         || is_at_color(p)
         || is_at_bracketed_value(p)
         || is_at_metavariable(p)
+        || is_at_scss_identifier(p)
+        || is_at_scss_interpolation(p)
+        || is_at_any_less_value(p)
 }
 
 #[inline]
@@ -316,12 +339,18 @@ pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
         parse_ratio(p)
     } else if p.at(CSS_NUMBER_LITERAL) {
         parse_regular_number(p)
+    } else if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p)
     } else if is_at_color(p) {
         parse_color(p)
     } else if is_at_bracketed_value(p) {
         parse_bracketed_value(p)
     } else if is_at_metavariable(p) {
         parse_metavariable(p)
+    } else if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_any_less_value(p) {
+        parse_any_less_value(p)
     } else {
         Absent
     }
@@ -338,7 +367,11 @@ impl ParseNodeList for CssComponentValueList {
     }
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(T![,]) || p.at(T![')']) || p.at_ts(BINARY_OPERATION_TOKEN)
+        p.at(T![,])
+            || p.at(T![')'])
+            || is_at_binary_operator(p)
+            || (p.options().is_scss_enabled() && p.at_ts(SCSS_EXPRESSION_END_SET))
+            || (p.options().is_less_enabled() && p.at_ts(LESS_EXPRESSION_END_SET))
     }
 
     fn recover(
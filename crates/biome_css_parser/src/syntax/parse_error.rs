@@ -226,3 +226,11 @@ pub(crate) fn expected_component_value(p: &CssParser, range: TextRange) -> Parse
     )
     .into_diagnostic(p)
 }
+
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("SCSS variable", range, p)
+}
+
+pub(crate) fn expected_less_guard_condition(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("parenthesized guard condition", range, p)
+}
@@ -184,7 +184,7 @@ fn parse_generic_property(p: &mut CssParser) -> ParsedSyntax {
 }
 const END_OF_PROPERTY_VALUE_TOKEN_SET: TokenSet<CssSyntaxKind> = token_set!(T!['}'], T![;]);
 
-struct GenericComponentValueList;
+pub(crate) struct GenericComponentValueList;
 
 impl ParseNodeList for GenericComponentValueList {
     type Kind = CssSyntaxKind;
@@ -231,9 +231,11 @@ fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
 }
 
 const GENERIC_DELIMITER_SET: TokenSet<CssSyntaxKind> = token_set![T![,], T![/]];
+/// The operators of Less operations, such as `width: @base * 2`.
+const LESS_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![T![+], T![-], T![*]];
 #[inline]
 fn is_at_generic_delimiter(p: &mut CssParser) -> bool {
-    p.at_ts(GENERIC_DELIMITER_SET)
+    p.at_ts(GENERIC_DELIMITER_SET) || (p.options().is_less_enabled() && p.at_ts(LESS_OPERATOR_SET))
 }
 
 #[inline]
@@ -243,6 +245,6 @@ fn parse_generic_delimiter(p: &mut CssParser) -> ParsedSyntax {
     }
 
     let m = p.start();
-    p.bump_ts(GENERIC_DELIMITER_SET);
+    p.bump_any();
     Present(m.complete(p, CSS_GENERIC_DELIMITER))
 }
@@ -0,0 +1,260 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_scss_variable};
+use crate::syntax::value::function::{is_at_any_expression, parse_any_expression};
+use crate::syntax::value::parse_error::expected_expression;
+use crate::syntax::{is_nth_at_identifier, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+#[inline]
+fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    // The `$` token is only emitted by the lexer when the SCSS syntax is enabled.
+    p.nth_at(n, T![$]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses an SCSS variable reference.
+///
+/// Specification: [Sass - Variables](https://sass-lang.com/documentation/variables/)
+/// # Examples
+///
+/// ```scss
+/// .alert {
+///     border: 1px solid $border-dark;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![$]);
+    parse_regular_identifier(p).ok();
+
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses an SCSS variable declaration, with its optional `!default` and `!global` flags.
+///
+/// Specification: [Sass - Variables](https://sass-lang.com/documentation/variables/)
+/// # Examples
+///
+/// ```scss
+/// $base-color: #c6538c;
+/// $font-stack: Helvetica, sans-serif !default;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+
+    let kind = if parse_scss_expression(p)
+        .or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_DECLARATION_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_expression,
+        )
+        .is_ok()
+    {
+        SCSS_DECLARATION
+    } else {
+        CSS_BOGUS_RULE
+    };
+
+    ScssVariableModifierList.parse_list(p);
+
+    // The semicolon is optional for the last declaration of a block.
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, kind))
+}
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !is_at_scss_variable_modifier(p) {
+            return Absent;
+        }
+
+        let m = p.start();
+
+        p.bump(T![!]);
+        p.bump_ts(SCSS_VARIABLE_MODIFIER_SET);
+
+        Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !is_at_scss_variable_modifier(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_DECLARATION_RECOVERY_SET),
+            expected_identifier,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_variable_modifier(p: &mut CssParser) -> bool {
+    p.at(T![!]) && p.nth_at_ts(1, SCSS_VARIABLE_MODIFIER_SET)
+}
+
+/// Parses a comma-separated list of expressions, as found in the value of an
+/// SCSS variable declaration or in the iterable of an `@each` rule.
+#[inline]
+pub(crate) fn parse_scss_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_any_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    ScssExpressionItemList.parse_list(p);
+    Present(m.complete(p, SCSS_EXPRESSION))
+}
+
+struct ScssExpressionItemList;
+
+impl ParseSeparatedList for ScssExpressionItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_EXPRESSION_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_any_expression(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(SCSS_EXPRESSION_END_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS,
+                SCSS_EXPRESSION_END_SET.union(token_set![T![,]]),
+            ),
+            expected_expression,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+pub(crate) fn is_at_scss_interpolation(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![#]) && p.nth_at(1, T!['{'])
+}
+
+/// Parses an SCSS interpolation, which injects the result of an expression into a value.
+///
+/// Specification: [Sass - Interpolation](https://sass-lang.com/documentation/interpolation/)
+/// # Examples
+///
+/// ```scss
+/// .sidebar {
+///     width: calc(100% - #{$sidebar-width});
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_interpolation(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![#]);
+    p.bump(T!['{']);
+    parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+    p.expect(T!['}']);
+
+    Present(m.complete(p, SCSS_INTERPOLATION))
+}
+
+/// Parses a comma-separated list of SCSS variables, as declared by an `@each` rule.
+pub(crate) struct ScssEachBindingList;
+
+impl ParseSeparatedList for ScssEachBindingList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_EACH_BINDING_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_identifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![in]) || p.at(T!['{'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![in], T!['{']]),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+/// The tokens that end an SCSS expression list.
+pub(crate) const SCSS_EXPRESSION_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![;], T![!], T!['{'], T!['}'], T![')']];
+const SCSS_DECLARATION_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T![;], T!['}']];
+const SCSS_VARIABLE_MODIFIER_SET: TokenSet<CssSyntaxKind> = token_set![T![default], T![global]];
@@ -1,5 +1,6 @@
 use super::parse_error::expected_expression;
 use super::url::{is_at_url_function, parse_url_function};
+use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::parse_error::expected_declaration_item;
 use crate::syntax::{
@@ -70,14 +71,29 @@ pub(crate) fn parse_function(p: &mut CssParser) -> ParsedSyntax {
 
     let m = p.start();
 
+    let is_theme_function = is_at_theme_function(p);
     parse_regular_identifier(p).ok();
-    p.bump(T!['(']);
+    if is_theme_function {
+        // The path of the Tailwind `theme()` function, such as `colors.blue.500`,
+        // is parsed as a single identifier.
+        p.bump_with_context(T!['('], CssLexContext::TailwindUtility);
+    } else {
+        p.bump(T!['(']);
+    }
     ParameterList.parse_list(p);
     p.expect(T![')']);
 
     Present(m.complete(p, CSS_FUNCTION))
 }
 
+/// Checks if the parser is at the Tailwind `theme()` function.
+///
+/// Specification: [Tailwind CSS - theme()](https://tailwindcss.com/docs/functions-and-directives#theme-function)
+#[inline]
+fn is_at_theme_function(p: &mut CssParser) -> bool {
+    p.cur_text().eq_ignore_ascii_case("theme")
+}
+
 struct ParameterListParseRecovery;
 
 impl ParseRecovery for ParameterListParseRecovery {
@@ -196,7 +212,7 @@ pub(crate) fn parse_any_expression(p: &mut CssParser) -> ParsedSyntax {
     if is_at_binary_operator(p) {
         let binary_expression = param.precede(p);
 
-        p.bump_ts(BINARY_OPERATION_TOKEN);
+        p.bump_any(); // bump the operator
         parse_any_expression(p).or_add_diagnostic(p, expected_expression);
 
         Present(binary_expression.complete(p, CSS_BINARY_EXPRESSION))
@@ -208,6 +224,14 @@ pub(crate) fn parse_any_expression(p: &mut CssParser) -> ParsedSyntax {
 pub(crate) const BINARY_OPERATION_TOKEN: TokenSet<CssSyntaxKind> =
     token_set![T![+], T![-], T![*], T![/]];
 
+/// The additional operators supported in SCSS expressions, such as `@if $a == $b`.
+const SCSS_BINARY_OPERATION_TOKEN: TokenSet<CssSyntaxKind> =
+    token_set![T![%], T![==], T![!=], T![<], T![>], T![<=], T![>=]];
+
+/// The additional operators supported in the guards of Less mixins, such as `when (@a = dark)`.
+const LESS_BINARY_OPERATION_TOKEN: TokenSet<CssSyntaxKind> =
+    token_set![T![=], T![<], T![>], T![<=], T![>=]];
+
 /// Checks if the current position in the CSS parser is at a binary operator.
 ///
 /// This function determines whether the parser's current position is at the start
@@ -216,6 +240,8 @@ pub(crate) const BINARY_OPERATION_TOKEN: TokenSet<CssSyntaxKind> =
 #[inline]
 pub(crate) fn is_at_binary_operator(p: &mut CssParser) -> bool {
     p.at_ts(BINARY_OPERATION_TOKEN)
+        || (p.options().is_scss_enabled() && p.at_ts(SCSS_BINARY_OPERATION_TOKEN))
+        || (p.options().is_less_enabled() && p.at_ts(LESS_BINARY_OPERATION_TOKEN))
 }
 
 /// Determines if the current position in the CSS parser is at the start of a parenthesized expression.
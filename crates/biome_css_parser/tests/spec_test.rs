@@ -45,6 +45,17 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         // TODO: find a way to make it configurable
         .allow_metavariables();
 
+    let language = test_case_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("css");
+
+    match language {
+        "scss" => options = options.allow_scss(),
+        "less" => options = options.allow_less(),
+        _ => {}
+    }
+
     let options_path = Path::new(test_directory).join("options.json");
 
     if options_path.exists() {
@@ -84,7 +95,7 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
@@ -0,0 +1,150 @@
+use biome_css_syntax::{
+    CssClassSelector, CssPseudoClassFunctionSelector, CssPseudoClassIdentifier,
+    CssRelativeSelectorList, CssRoot, CssSelectorList, CssSyntaxNode, T,
+};
+use biome_rowan::{AstNode, TextRange, TokenText};
+use rustc_hash::FxHashMap;
+
+/// The classes exported by a [CSS module](https://github.com/css-modules/css-modules).
+///
+/// A CSS module exports an object that maps the local class names to the class names generated
+/// by the bundler, such as `styles.button` for the selector `.button`.
+/// The classes in the scope of `:global` aren't exported.
+#[derive(Debug, Default, Clone)]
+pub struct CssModuleClasses {
+    /// The exported class names, mapped to the range of their first declaration.
+    classes: FxHashMap<TokenText, TextRange>,
+}
+
+impl CssModuleClasses {
+    pub fn from_root(root: &CssRoot) -> Self {
+        let mut classes = FxHashMap::default();
+        for selector in root
+            .syntax()
+            .descendants()
+            .filter_map(CssClassSelector::cast)
+        {
+            if is_in_global_scope(selector.syntax()) {
+                continue;
+            }
+            let Ok(name) = selector.name().and_then(|name| name.value_token()) else {
+                continue;
+            };
+            classes
+                .entry(name.token_text_trimmed())
+                .or_insert_with(|| name.text_trimmed_range());
+        }
+        Self { classes }
+    }
+
+    /// Returns `true` if the module exports the class `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.classes.contains_key(name)
+    }
+
+    /// Returns the range of the first declaration of the class `name`.
+    pub fn get(&self, name: &str) -> Option<TextRange> {
+        self.classes.get(name).copied()
+    }
+
+    /// Returns the exported class names, along with the range of their first declaration.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, TextRange)> {
+        self.classes
+            .iter()
+            .map(|(name, range)| (name.text(), *range))
+    }
+
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+/// Returns `true` if `node`, which is a part of a selector, is in the scope of `:global`.
+///
+/// The scope is set by the closest `:global(...)` or `:local(...)` that contains the node.
+/// Otherwise, it's set by the last `:global` or `:local` that precedes the node in its
+/// selector, such as for `.b` in `:global .a .b`.
+/// The selectors are local by default.
+pub fn is_in_global_scope(node: &CssSyntaxNode) -> bool {
+    if let Some(function) = node
+        .ancestors()
+        .find_map(CssPseudoClassFunctionSelector::cast)
+    {
+        return function.name().is_ok_and(|name| name.kind() == T![global]);
+    }
+    let Some(selector) = node
+        .ancestors()
+        .take_while(|ancestor| {
+            !CssSelectorList::can_cast(ancestor.kind())
+                && !CssRelativeSelectorList::can_cast(ancestor.kind())
+        })
+        .last()
+    else {
+        return false;
+    };
+    let start = node.text_trimmed_range().start();
+    selector
+        .descendants()
+        .filter_map(CssPseudoClassIdentifier::cast)
+        .take_while(|pseudo_class| pseudo_class.range().end() <= start)
+        .filter_map(|pseudo_class| {
+            let name = pseudo_class.name().ok()?.value_token().ok()?;
+            match name.text_trimmed() {
+                "global" => Some(true),
+                "local" => Some(false),
+                _ => None,
+            }
+        })
+        .last()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CssModuleClasses;
+    use biome_css_parser::{parse_css, CssParserOptions};
+
+    fn classes(source: &str) -> Vec<String> {
+        let parse = parse_css(source, CssParserOptions::default().allow_css_modules());
+        let classes = CssModuleClasses::from_root(&parse.tree());
+        let mut names: Vec<_> = classes.iter().map(|(name, _)| name.to_string()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_local_classes() {
+        assert_eq!(
+            classes(".button { color: red; } .button:hover .icon { color: blue; }"),
+            ["button", "icon"]
+        );
+    }
+
+    #[test]
+    fn test_global_function() {
+        assert_eq!(
+            classes(".menu :global(.open) .item { color: red; }"),
+            ["item", "menu"]
+        );
+    }
+
+    #[test]
+    fn test_global_identifier() {
+        assert_eq!(
+            classes(":global .theme .title :local .label { color: red; } .card { color: blue; }"),
+            ["card", "label"]
+        );
+    }
+
+    #[test]
+    fn test_nested_selectors() {
+        assert_eq!(
+            classes(":global(.theme) { .title { color: red; } }"),
+            ["title"]
+        );
+    }
+}
@@ -1,5 +1,7 @@
+mod css_modules;
 mod events;
 mod semantic_model;
 
+pub use css_modules::*;
 pub use events::*;
 pub use semantic_model::*;
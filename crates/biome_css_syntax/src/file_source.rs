@@ -7,15 +7,13 @@ use std::{ffi::OsStr, path::Path};
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct CssFileSource {
-    // Unused until we potentially support postcss/less/sass
-    #[allow(unused)]
     variant: CssVariant,
 }
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Biome aims to be compatible with the latest Recommendation level standards
+/// of plain CSS, and supports the SCSS syntax of Sass and the Less syntax.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -23,6 +21,10 @@ pub struct CssFileSource {
 enum CssVariant {
     #[default]
     Standard,
+    /// The SCSS syntax of [Sass](https://sass-lang.com/documentation/syntax/)
+    Scss,
+    /// The syntax of [Less](https://lesscss.org/features/)
+    Less,
 }
 
 impl CssFileSource {
@@ -32,6 +34,34 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
+    pub fn less() -> Self {
+        Self {
+            variant: CssVariant::Less,
+        }
+    }
+
+    pub const fn is_less(&self) -> bool {
+        matches!(self.variant, CssVariant::Less)
+    }
+
+    /// Returns `true` if the name of the file follows the convention of
+    /// [CSS Modules](https://github.com/css-modules/css-modules), such as `button.module.css`.
+    pub fn is_css_module_path(path: &Path) -> bool {
+        path.file_stem()
+            .and_then(OsStr::to_str)
+            .is_some_and(|file_stem| file_stem.ends_with(".module"))
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -43,6 +73,8 @@ impl CssFileSource {
         // We assume the file extension is normalized to lowercase
         match extension.as_encoded_bytes() {
             b"css" => Ok(Self::css()),
+            b"scss" => Ok(Self::scss()),
+            b"less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -56,6 +88,8 @@ impl CssFileSource {
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
         match language_id {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
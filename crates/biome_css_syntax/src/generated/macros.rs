@@ -16,6 +16,10 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::CssSyntaxNode::kind(&node) {
+                $crate::CssSyntaxKind::CSS_APPLY_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssApplyAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_AT_RULE => {
                     let $pattern = unsafe { $crate::CssAtRule::new_unchecked(node) };
                     $body
@@ -81,6 +85,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssCompoundSelector::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_CONFIG_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssConfigAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_CONTAINER_AND_QUERY => {
                     let $pattern = unsafe { $crate::CssContainerAndQuery::new_unchecked(node) };
                     $body
@@ -571,6 +579,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssScopeRangeStart::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_SCREEN_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssScreenAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_STARTING_STYLE_AT_RULE => {
                     let $pattern = unsafe { $crate::CssStartingStyleAtRule::new_unchecked(node) };
                     $body
@@ -610,6 +622,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssSupportsOrCondition::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_TAILWIND_AT_RULE => {
+                    let $pattern = unsafe { $crate::CssTailwindAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_TYPE_SELECTOR => {
                     let $pattern = unsafe { $crate::CssTypeSelector::new_unchecked(node) };
                     $body
@@ -688,6 +704,107 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRuleNamedImportSpecifier::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::LESS_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_GUARD_BINARY_CONDITION => {
+                    let $pattern = unsafe { $crate::LessGuardBinaryCondition::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_GUARD_NOT_CONDITION => {
+                    let $pattern = unsafe { $crate::LessGuardNotCondition::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::LessIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_CALL => {
+                    let $pattern = unsafe { $crate::LessMixinCall::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessMixinDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_GUARD => {
+                    let $pattern = unsafe { $crate::LessMixinGuard::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER => {
+                    let $pattern = unsafe { $crate::LessMixinParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETERS => {
+                    let $pattern = unsafe { $crate::LessMixinParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_NAMED_PARAMETER => {
+                    let $pattern = unsafe { $crate::LessNamedParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::ScssArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssEachAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ELSE_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssElseClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXPRESSION => {
+                    let $pattern = unsafe { $crate::ScssExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::ScssIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IF_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIfAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INCLUDE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIncludeAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATION => {
+                    let $pattern = unsafe { $crate::ScssInterpolation::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_KEYWORD_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssKeywordArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MIXIN_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssMixinAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER => {
+                    let $pattern = unsafe { $crate::ScssParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE => {
+                    let $pattern =
+                        unsafe { $crate::ScssParameterDefaultValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETERS => {
+                    let $pattern = unsafe { $crate::ScssParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER => {
+                    let $pattern = unsafe { $crate::ScssVariableModifier::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -797,6 +914,10 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRuleGenericValue::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_APPLY_CLASS_LIST => {
+                    let $pattern = unsafe { $crate::CssApplyClassList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BRACKETED_VALUE_LIST => {
                     let $pattern = unsafe { $crate::CssBracketedValueList::new_unchecked(node) };
                     $body
@@ -923,6 +1044,30 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRulePropertyList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::LessMixinParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::ScssArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_BINDING_LIST => {
+                    let $pattern = unsafe { $crate::ScssEachBindingList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXPRESSION_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::ScssExpressionItemList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::ScssParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST => {
+                    let $pattern = unsafe { $crate::ScssVariableModifierList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
@@ -0,0 +1,534 @@
+//! A series of utilities to parse the colors of CSS, and to compute their contrast
+
+use crate::dimension::{number_value, Dimension};
+use biome_css_syntax::{
+    AnyCssExpression, AnyCssFunction, AnyCssValue, CssComponentValueList, CssFunction, T,
+};
+use biome_string_case::StrLikeExtension;
+
+/// The named colors of CSS and their red, green and blue channels, sorted by name.
+///
+/// See <https://drafts.csswg.org/css-color/#named-colors>
+const NAMED_COLORS: [(&str, [u8; 3]); 148] = [
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("aqua", [0, 255, 255]),
+    ("aquamarine", [127, 255, 212]),
+    ("azure", [240, 255, 255]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("black", [0, 0, 0]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blue", [0, 0, 255]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("burlywood", [222, 184, 135]),
+    ("cadetblue", [95, 158, 160]),
+    ("chartreuse", [127, 255, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("coral", [255, 127, 80]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("crimson", [220, 20, 60]),
+    ("cyan", [0, 255, 255]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deepskyblue", [0, 191, 255]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("firebrick", [178, 34, 34]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("fuchsia", [255, 0, 255]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("gray", [128, 128, 128]),
+    ("green", [0, 128, 0]),
+    ("greenyellow", [173, 255, 47]),
+    ("grey", [128, 128, 128]),
+    ("honeydew", [240, 255, 240]),
+    ("hotpink", [255, 105, 180]),
+    ("indianred", [205, 92, 92]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("khaki", [240, 230, 140]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lightblue", [173, 216, 230]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightyellow", [255, 255, 224]),
+    ("lime", [0, 255, 0]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("magenta", [255, 0, 255]),
+    ("maroon", [128, 0, 0]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("navy", [0, 0, 128]),
+    ("oldlace", [253, 245, 230]),
+    ("olive", [128, 128, 0]),
+    ("olivedrab", [107, 142, 35]),
+    ("orange", [255, 165, 0]),
+    ("orangered", [255, 69, 0]),
+    ("orchid", [218, 112, 214]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("paleturquoise", [175, 238, 238]),
+    ("palevioletred", [219, 112, 147]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("plum", [221, 160, 221]),
+    ("powderblue", [176, 224, 230]),
+    ("purple", [128, 0, 128]),
+    ("rebeccapurple", [102, 51, 153]),
+    ("red", [255, 0, 0]),
+    ("rosybrown", [188, 143, 143]),
+    ("royalblue", [65, 105, 225]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("sienna", [160, 82, 45]),
+    ("silver", [192, 192, 192]),
+    ("skyblue", [135, 206, 235]),
+    ("slateblue", [106, 90, 205]),
+    ("slategray", [112, 128, 144]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("springgreen", [0, 255, 127]),
+    ("steelblue", [70, 130, 180]),
+    ("tan", [210, 180, 140]),
+    ("teal", [0, 128, 128]),
+    ("thistle", [216, 191, 216]),
+    ("tomato", [255, 99, 71]),
+    ("turquoise", [64, 224, 208]),
+    ("violet", [238, 130, 238]),
+    ("wheat", [245, 222, 179]),
+    ("white", [255, 255, 255]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellow", [255, 255, 0]),
+    ("yellowgreen", [154, 205, 50]),
+];
+
+/// A color of the sRGB color space.
+///
+/// The channels and the alpha range from `0.0` to `1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Color {
+    const fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Parses a static color: a hexadecimal color, a named color, or a call to `rgb()`, `rgba()`,
+    /// `hsl()`, `hsla()` or `oklch()` with literal arguments.
+    ///
+    /// Returns `None` for the other values, such as `currentcolor` or `var(--color)`, because
+    /// they can't be resolved statically.
+    pub fn from_css_value(value: &AnyCssValue) -> Option<Self> {
+        match value {
+            AnyCssValue::CssColor(color) => {
+                Self::from_hex(color.value_token().ok()?.text_trimmed())
+            }
+            AnyCssValue::CssIdentifier(identifier) => {
+                Self::from_name(identifier.value_token().ok()?.text_trimmed())
+            }
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+                Self::from_function(function)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses the digits of a hexadecimal color, such as `fff` or `ff000080`
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |digits: &str| {
+            let value = u8::from_str_radix(digits, 16).ok()?;
+            // A single digit is repeated: `f` is `ff`
+            let value = if digits.len() == 1 { value * 17 } else { value };
+            Some(f64::from(value) / 255.0)
+        };
+        let step = match hex.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return None,
+        };
+        let mut channels = (0..hex.len())
+            .step_by(step)
+            .map(|start| channel(&hex[start..start + step]));
+        Some(Self::new(
+            channels.next()??,
+            channels.next()??,
+            channels.next()??,
+            channels.next().unwrap_or(Some(1.0))?,
+        ))
+    }
+
+    /// Parses a named color, such as `red`, or the keyword `transparent`
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase_cow();
+        if name == "transparent" {
+            return Some(Self::new(0.0, 0.0, 0.0, 0.0));
+        }
+        let index = NAMED_COLORS
+            .binary_search_by(|(color, _)| (*color).cmp(name.as_ref()))
+            .ok()?;
+        let [red, green, blue] = NAMED_COLORS[index].1;
+        Some(Self::new(
+            f64::from(red) / 255.0,
+            f64::from(green) / 255.0,
+            f64::from(blue) / 255.0,
+            1.0,
+        ))
+    }
+
+    /// Parses a call to `rgb()`, `rgba()`, `hsl()`, `hsla()` or `oklch()`.
+    ///
+    /// Both the legacy syntax, `rgb(255, 0, 0, 0.5)`, and the modern syntax, `rgb(255 0 0 / 50%)`,
+    /// are supported.
+    /// The colors of `oklch()` that are outside of the sRGB color space aren't supported.
+    pub fn from_function(function: &CssFunction) -> Option<Self> {
+        let name = function.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed().to_ascii_lowercase_cow();
+        if !matches!(name.as_ref(), "rgb" | "rgba" | "hsl" | "hsla" | "oklch") {
+            return None;
+        }
+
+        let mut arguments = Vec::new();
+        let mut alpha = None;
+        for parameter in function.items() {
+            match parameter.ok()?.any_css_expression().ok()? {
+                AnyCssExpression::CssListOfComponentValuesExpression(list) => {
+                    arguments.extend(list.css_component_value_list());
+                }
+                // The alpha of the modern syntax: `rgb(255 0 0 / 50%)`
+                AnyCssExpression::CssBinaryExpression(binary) => {
+                    if binary.operator_token().ok()?.kind() != T![/] {
+                        return None;
+                    }
+                    let (
+                        AnyCssExpression::CssListOfComponentValuesExpression(left),
+                        AnyCssExpression::CssListOfComponentValuesExpression(right),
+                    ) = (binary.left().ok()?, binary.right().ok()?)
+                    else {
+                        return None;
+                    };
+                    arguments.extend(left.css_component_value_list());
+                    alpha = Some(single_value(right.css_component_value_list())?);
+                }
+                AnyCssExpression::CssParenthesizedExpression(_) => return None,
+            }
+        }
+        if alpha.is_none() && arguments.len() == 4 {
+            alpha = arguments.pop();
+        }
+        // The last channel and the alpha are parsed as a ratio when both are numbers, such as
+        // `0 / 0.5` in `rgb(0 0 0 / 0.5)`
+        if let (None, Some(AnyCssValue::CssRatio(ratio))) = (&alpha, arguments.last()) {
+            let (numerator, denominator) = (ratio.numerator().ok()?, ratio.denominator().ok()?);
+            arguments.pop();
+            arguments.push(AnyCssValue::CssNumber(numerator));
+            alpha = Some(AnyCssValue::CssNumber(denominator));
+        }
+        let [first, second, third] = arguments.as_slice() else {
+            return None;
+        };
+        let alpha = match alpha {
+            Some(alpha) => alpha_value(&alpha)?,
+            None => 1.0,
+        };
+
+        match name.as_ref() {
+            "hsl" | "hsla" => {
+                let (red, green, blue) = hsl_to_rgb(
+                    hue_value(first)?,
+                    percentage_value(second)?,
+                    percentage_value(third)?,
+                );
+                Some(Self::new(red, green, blue, alpha))
+            }
+            "oklch" => {
+                // The lightness is a number from `0` to `1`, or a percentage, like the alpha
+                let (red, green, blue) = oklch_to_rgb(
+                    alpha_value(first)?,
+                    chroma_value(second)?,
+                    hue_value(third)?,
+                )?;
+                Some(Self::new(red, green, blue, alpha))
+            }
+            _ => Some(Self::new(
+                rgb_channel_value(first)?,
+                rgb_channel_value(second)?,
+                rgb_channel_value(third)?,
+                alpha,
+            )),
+        }
+    }
+
+    /// Returns the hue in degrees, the saturation and the lightness of the color.
+    ///
+    /// See <https://drafts.csswg.org/css-color/#rgb-to-hsl>
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let max = self.red.max(self.green).max(self.blue);
+        let min = self.red.min(self.green).min(self.blue);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == self.red {
+            ((self.green - self.blue) / delta).rem_euclid(6.0)
+        } else if max == self.green {
+            (self.blue - self.red) / delta + 2.0
+        } else {
+            (self.red - self.green) / delta + 4.0
+        };
+        (hue * 60.0, saturation, lightness)
+    }
+
+    /// Returns the lightness, the chroma and the hue in degrees of the color in the OKLCH color
+    /// space.
+    ///
+    /// See <https://drafts.csswg.org/css-color/#color-conversion-code>
+    pub fn to_oklch(self) -> (f64, f64, f64) {
+        let (red, green, blue) = (
+            to_linear(self.red),
+            to_linear(self.green),
+            to_linear(self.blue),
+        );
+        let l = (0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue).cbrt();
+        let m = (0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue).cbrt();
+        let s = (0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue).cbrt();
+        let lightness = 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s;
+        let a = 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s;
+        let b = 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s;
+        let chroma = a.hypot(b);
+        // The hue of the achromatic colors is powerless
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        (lightness, chroma, hue)
+    }
+
+    /// Returns `true` if the color isn't transparent at all
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    /// Returns the color that results from painting this color over `background`
+    pub fn blend_over(&self, background: &Self) -> Self {
+        let blend =
+            |channel: f64, background: f64| channel * self.alpha + background * (1.0 - self.alpha);
+        Self::new(
+            blend(self.red, background.red),
+            blend(self.green, background.green),
+            blend(self.blue, background.blue),
+            1.0,
+        )
+    }
+
+    /// Returns the relative luminance of the color, as defined by WCAG.
+    ///
+    /// See <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>
+    pub fn relative_luminance(&self) -> f64 {
+        0.2126 * to_linear(self.red)
+            + 0.7152 * to_linear(self.green)
+            + 0.0722 * to_linear(self.blue)
+    }
+
+    /// Returns the contrast ratio between two opaque colors, as defined by WCAG.
+    /// The ratio ranges from `1.0` to `21.0`.
+    ///
+    /// See <https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio>
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let luminance = self.relative_luminance();
+        let other_luminance = other.relative_luminance();
+        let (lighter, darker) = if luminance > other_luminance {
+            (luminance, other_luminance)
+        } else {
+            (other_luminance, luminance)
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+/// Returns the only value of `list`
+fn single_value(list: CssComponentValueList) -> Option<AnyCssValue> {
+    let mut values = list.into_iter();
+    let value = values.next()?;
+    values.next().is_none().then_some(value)
+}
+
+/// Returns the value of a number divided by `number_scale`, or the value of a percentage, such as
+/// `50%`, as a ratio from `0` to `1`
+fn numeric_value(value: &AnyCssValue, number_scale: f64) -> Option<f64> {
+    let value = match value {
+        AnyCssValue::CssNumber(number) => number_value(&number.value_token().ok()?)? / number_scale,
+        AnyCssValue::AnyCssDimension(dimension) => {
+            let percentage = dimension.as_css_percentage()?;
+            number_value(&percentage.value_token().ok()?)? / 100.0
+        }
+        _ => return None,
+    };
+    Some(value.clamp(0.0, 1.0))
+}
+
+/// Returns the value of a channel of `rgb()`: a number from `0` to `255`, or a percentage
+fn rgb_channel_value(value: &AnyCssValue) -> Option<f64> {
+    numeric_value(value, 255.0)
+}
+
+/// Returns the value of the saturation or the lightness of `hsl()`.
+///
+/// The modern syntax accepts numbers from `0` to `100` in place of the percentages.
+fn percentage_value(value: &AnyCssValue) -> Option<f64> {
+    numeric_value(value, 100.0)
+}
+
+/// Returns the value of an alpha: a number from `0` to `1`, or a percentage
+fn alpha_value(value: &AnyCssValue) -> Option<f64> {
+    numeric_value(value, 1.0)
+}
+
+/// Returns the value of the chroma of `oklch()`: a number, or a percentage where `100%` is `0.4`
+fn chroma_value(value: &AnyCssValue) -> Option<f64> {
+    let chroma = match value {
+        AnyCssValue::CssNumber(number) => number_value(&number.value_token().ok()?)?,
+        AnyCssValue::AnyCssDimension(dimension) => {
+            let percentage = dimension.as_css_percentage()?;
+            number_value(&percentage.value_token().ok()?)? * 0.004
+        }
+        _ => return None,
+    };
+    Some(chroma.max(0.0))
+}
+
+/// Returns the value of a hue in degrees, from `0` to `360`
+fn hue_value(value: &AnyCssValue) -> Option<f64> {
+    let degrees = Dimension::from_css_value(value)?.to_degrees()?;
+    Some(degrees.rem_euclid(360.0))
+}
+
+/// Converts a color from HSL to RGB.
+///
+/// See <https://drafts.csswg.org/css-color/#hsl-to-rgb>
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let channel = |offset: f64| {
+        let k = (offset + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (channel(0.0), channel(8.0), channel(4.0))
+}
+
+/// Converts a channel of sRGB to linear light
+fn to_linear(channel: f64) -> f64 {
+    if channel <= 0.040_45 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a channel from linear light to sRGB
+fn from_linear(channel: f64) -> f64 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts a color from OKLCH to RGB.
+///
+/// Returns `None` when the color is outside of the sRGB color space.
+///
+/// See <https://drafts.csswg.org/css-color/#color-conversion-code>
+fn oklch_to_rgb(lightness: f64, chroma: f64, hue: f64) -> Option<(f64, f64, f64)> {
+    let (a, b) = (
+        chroma * hue.to_radians().cos(),
+        chroma * hue.to_radians().sin(),
+    );
+    let l = (lightness + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m = (lightness - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s = (lightness - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+    let channel = |linear: f64| {
+        let channel = from_linear(linear);
+        // Tolerate the rounding errors of the conversion
+        (-1e-4..=1.0 + 1e-4)
+            .contains(&channel)
+            .then_some(channel.clamp(0.0, 1.0))
+    };
+    Some((
+        channel(4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s)?,
+        channel(-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s)?,
+        channel(-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s)?,
+    ))
+}
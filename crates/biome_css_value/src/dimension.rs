@@ -0,0 +1,277 @@
+//! A series of utilities to parse the numeric values of CSS, such as `10px`, `50%` or `1.5`
+
+use biome_css_syntax::{AnyCssDimension, AnyCssValue, CssSyntaxToken};
+
+/// The kind of quantity measured by a unit
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnitKind {
+    /// A length, such as `px`, `em` or `vw`
+    Length,
+    /// An angle, such as `deg` or `turn`
+    Angle,
+    /// A duration, such as `s` or `ms`
+    Time,
+    /// A frequency, such as `Hz`
+    Frequency,
+    /// A resolution, such as `dpi` or `x`
+    Resolution,
+    /// A fraction of the free space of a grid container: `fr`
+    Flex,
+    /// A percentage: `%`
+    Percentage,
+}
+
+/// A known unit of CSS
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Unit {
+    /// The canonical spelling of the unit, such as `px`, `Q` or `kHz`
+    name: &'static str,
+    kind: UnitKind,
+    /// The size of the unit in pixels, for the absolute lengths
+    pixels: Option<f64>,
+}
+
+/// The known units of CSS, with their canonical spelling.
+///
+/// See <https://drafts.csswg.org/css-values/#lengths>
+const UNITS: [Unit; 64] = [
+    // Font-relative lengths
+    Unit::length("em"),
+    Unit::length("rem"),
+    Unit::length("ex"),
+    Unit::length("rex"),
+    Unit::length("cap"),
+    Unit::length("rcap"),
+    Unit::length("ch"),
+    Unit::length("rch"),
+    Unit::length("ic"),
+    Unit::length("ric"),
+    Unit::length("lh"),
+    Unit::length("rlh"),
+    // Viewport-percentage lengths
+    Unit::length("vw"),
+    Unit::length("svw"),
+    Unit::length("lvw"),
+    Unit::length("dvw"),
+    Unit::length("vh"),
+    Unit::length("svh"),
+    Unit::length("lvh"),
+    Unit::length("dvh"),
+    Unit::length("vi"),
+    Unit::length("svi"),
+    Unit::length("lvi"),
+    Unit::length("dvi"),
+    Unit::length("vb"),
+    Unit::length("svb"),
+    Unit::length("lvb"),
+    Unit::length("dvb"),
+    Unit::length("vmin"),
+    Unit::length("svmin"),
+    Unit::length("lvmin"),
+    Unit::length("dvmin"),
+    Unit::length("vmax"),
+    Unit::length("svmax"),
+    Unit::length("lvmax"),
+    Unit::length("dvmax"),
+    // Container query lengths
+    Unit::length("cqw"),
+    Unit::length("cqh"),
+    Unit::length("cqi"),
+    Unit::length("cqb"),
+    Unit::length("cqmin"),
+    Unit::length("cqmax"),
+    // Absolute lengths
+    Unit::absolute_length("px", 1.0),
+    Unit::absolute_length("cm", 96.0 / 2.54),
+    Unit::absolute_length("mm", 96.0 / 25.4),
+    Unit::absolute_length("Q", 96.0 / 101.6),
+    Unit::absolute_length("in", 96.0),
+    Unit::absolute_length("pc", 16.0),
+    Unit::absolute_length("pt", 4.0 / 3.0),
+    Unit::length("mozmm"),
+    // Mini programs
+    Unit::length("rpx"),
+    Unit::new("deg", UnitKind::Angle),
+    Unit::new("grad", UnitKind::Angle),
+    Unit::new("rad", UnitKind::Angle),
+    Unit::new("turn", UnitKind::Angle),
+    Unit::new("s", UnitKind::Time),
+    Unit::new("ms", UnitKind::Time),
+    Unit::new("Hz", UnitKind::Frequency),
+    Unit::new("kHz", UnitKind::Frequency),
+    Unit::new("dpi", UnitKind::Resolution),
+    Unit::new("dpcm", UnitKind::Resolution),
+    Unit::new("dppx", UnitKind::Resolution),
+    Unit::new("x", UnitKind::Resolution),
+    Unit::new("fr", UnitKind::Flex),
+];
+
+const PERCENTAGE: Unit = Unit::new("%", UnitKind::Percentage);
+
+impl Unit {
+    const fn new(name: &'static str, kind: UnitKind) -> Self {
+        Self {
+            name,
+            kind,
+            pixels: None,
+        }
+    }
+
+    const fn length(name: &'static str) -> Self {
+        Self::new(name, UnitKind::Length)
+    }
+
+    const fn absolute_length(name: &'static str, pixels: f64) -> Self {
+        Self {
+            name,
+            kind: UnitKind::Length,
+            pixels: Some(pixels),
+        }
+    }
+
+    /// Returns the unit called `name`, in any case, such as `px`, `PX` or `khz`.
+    ///
+    /// Returns `None` if the unit is unknown.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name == "%" {
+            return Some(PERCENTAGE);
+        }
+        UNITS
+            .iter()
+            .find(|unit| unit.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Returns the canonical spelling of the unit, such as `px`, `Q` or `kHz`
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn kind(&self) -> UnitKind {
+        self.kind
+    }
+
+    /// Returns `true` if the unit is an absolute length, such as `px` or `cm`
+    pub const fn is_absolute_length(&self) -> bool {
+        self.pixels.is_some()
+    }
+}
+
+/// A numeric value: a number, a percentage, or a dimension with a known unit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dimension {
+    pub value: f64,
+    /// The unit of the value, or `None` for a number
+    pub unit: Option<Unit>,
+}
+
+impl Dimension {
+    /// Parses a number, such as `1.5`, a percentage, such as `50%`, or a dimension, such as
+    /// `10px`.
+    ///
+    /// Returns `None` for the other values, and for the dimensions whose unit is unknown.
+    pub fn from_css_value(value: &AnyCssValue) -> Option<Self> {
+        match value {
+            AnyCssValue::CssNumber(number) => Some(Self {
+                value: number_value(&number.value_token().ok()?)?,
+                unit: None,
+            }),
+            AnyCssValue::AnyCssDimension(dimension) => Self::from_dimension(dimension),
+            _ => None,
+        }
+    }
+
+    /// Parses a percentage, such as `50%`, or a dimension, such as `10px`.
+    ///
+    /// Returns `None` if the unit of the dimension is unknown.
+    pub fn from_dimension(dimension: &AnyCssDimension) -> Option<Self> {
+        match dimension {
+            AnyCssDimension::CssPercentage(percentage) => Some(Self {
+                value: number_value(&percentage.value_token().ok()?)?,
+                unit: Some(PERCENTAGE),
+            }),
+            AnyCssDimension::CssRegularDimension(dimension) => Some(Self {
+                value: number_value(&dimension.value_token().ok()?)?,
+                unit: Some(Unit::from_name(
+                    dimension.unit_token().ok()?.text_trimmed(),
+                )?),
+            }),
+            AnyCssDimension::CssUnknownDimension(_) => None,
+        }
+    }
+
+    /// Returns the kind of the unit, or `None` for a number
+    pub fn kind(&self) -> Option<UnitKind> {
+        self.unit.map(|unit| unit.kind)
+    }
+
+    /// Returns `true` if the value is a length, such as `10px`, or the number `0`, which is a
+    /// valid length
+    pub fn is_length(&self) -> bool {
+        match self.unit {
+            Some(unit) => unit.kind == UnitKind::Length,
+            None => self.value == 0.0,
+        }
+    }
+
+    /// Returns the value in pixels, if it's an absolute length, such as `12pt`
+    pub fn to_pixels(&self) -> Option<f64> {
+        Some(self.value * self.unit?.pixels?)
+    }
+
+    /// Returns the value in degrees, if it's an angle, such as `0.5turn`.
+    ///
+    /// A number is considered as a value in degrees, like in the hues of the colors.
+    pub fn to_degrees(&self) -> Option<f64> {
+        let Some(unit) = self.unit else {
+            return Some(self.value);
+        };
+        match unit.name {
+            "deg" => Some(self.value),
+            "grad" => Some(self.value * 0.9),
+            "rad" => Some(self.value.to_degrees()),
+            "turn" => Some(self.value * 360.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the value in milliseconds, if it's a duration, such as `0.3s`
+    pub fn to_milliseconds(&self) -> Option<f64> {
+        match self.unit?.name {
+            "s" => Some(self.value * 1000.0),
+            "ms" => Some(self.value),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the value of a number literal
+pub(crate) fn number_value(token: &CssSyntaxToken) -> Option<f64> {
+    token.text_trimmed().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_units_in_any_case() {
+        assert_eq!(Unit::from_name("PX").map(|unit| unit.name()), Some("px"));
+        assert_eq!(Unit::from_name("khz").map(|unit| unit.name()), Some("kHz"));
+        assert_eq!(Unit::from_name("q").map(|unit| unit.name()), Some("Q"));
+        assert_eq!(Unit::from_name("prototype"), None);
+    }
+
+    #[test]
+    fn converts_absolute_lengths_to_pixels() {
+        let dimension = |value, unit| Dimension {
+            value,
+            unit: Unit::from_name(unit),
+        };
+        assert_eq!(dimension(12.0, "pt").to_pixels(), Some(16.0));
+        assert_eq!(dimension(1.0, "in").to_pixels(), Some(96.0));
+        assert_eq!(dimension(1.0, "em").to_pixels(), None);
+        assert_eq!(dimension(0.5, "turn").to_degrees(), Some(180.0));
+        assert_eq!(dimension(0.3, "s").to_milliseconds(), Some(300.0));
+    }
+}
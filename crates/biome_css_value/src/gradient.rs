@@ -0,0 +1,252 @@
+//! A series of utilities to parse the gradient functions of CSS, such as `linear-gradient()`,
+//! `radial-gradient()` or `conic-gradient()`
+
+use crate::color::Color;
+use crate::dimension::Dimension;
+use crate::keyword;
+use biome_css_syntax::{AnyCssExpression, AnyCssFunction, AnyCssValue, CssFunction, CssParameter};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use biome_string_case::StrLikeExtension;
+
+/// The shape of a gradient
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GradientKind {
+    /// `linear-gradient()` and `repeating-linear-gradient()`
+    Linear,
+    /// `radial-gradient()` and `repeating-radial-gradient()`
+    Radial,
+    /// `conic-gradient()` and `repeating-conic-gradient()`
+    Conic,
+}
+
+/// A call to a gradient function, such as `linear-gradient(to right, red 10%, 40%, blue)`
+#[derive(Debug)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// Whether the name of the function has a vendor prefix, such as `-webkit-linear-gradient`
+    pub prefixed: bool,
+    /// The arguments of the function, in order
+    pub arguments: Vec<GradientArgument>,
+}
+
+/// An argument of a gradient function, separated from the others by a comma
+#[derive(Debug)]
+pub enum GradientArgument {
+    /// The first argument, when it isn't a color stop. It sets the direction of a linear
+    /// gradient, such as `to top` or `45deg`, the shape and the position of a radial gradient,
+    /// such as `circle at center`, or the start angle of a conic gradient, such as `from 90deg`.
+    /// It can also set the color space of the interpolation, such as `in oklch`.
+    Prelude(GradientPrelude),
+    /// A color followed by zero, one or two positions, such as `red`, `red 10%` or
+    /// `red 10% 20%`
+    ColorStop {
+        parameter: CssParameter,
+        positions: Vec<GradientPosition>,
+    },
+    /// A position between two color stops, where the colors are mixed half and half, such as
+    /// `40%`
+    Hint {
+        parameter: CssParameter,
+        position: GradientPosition,
+    },
+    /// Several colors that aren't separated by commas, such as `red blue`
+    MissingComma(CssParameter),
+    /// An argument whose values can't be resolved statically, such as `var(--stops)`
+    Unknown(CssParameter),
+}
+
+/// The first argument of a gradient function, such as `to top in oklch`
+#[derive(Debug)]
+pub struct GradientPrelude {
+    pub parameter: CssParameter,
+    /// The values of the argument, without the `in <color-space>` interpolation method
+    pub values: Vec<AnyCssValue>,
+}
+
+/// A position of a color stop or of a color hint, such as `10%`, `2em` or `90deg`
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientPosition {
+    /// The value of the position, or `None` when it's computed, such as `calc(100% - 2em)`
+    pub dimension: Option<Dimension>,
+    pub range: TextRange,
+}
+
+impl Gradient {
+    /// Parses a call to a gradient function.
+    ///
+    /// Returns `None` if the function isn't a gradient function.
+    pub fn from_function(function: &CssFunction) -> Option<Self> {
+        let name = function.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed().to_ascii_lowercase_cow();
+        let prefixed = ["-webkit-", "-moz-", "-ms-", "-o-"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix));
+        let unprefixed = if prefixed {
+            // `-webkit-linear-gradient` is `linear-gradient`
+            name.splitn(3, '-').nth(2)?
+        } else {
+            name.as_ref()
+        };
+        let kind = match unprefixed.strip_prefix("repeating-").unwrap_or(unprefixed) {
+            "linear-gradient" => GradientKind::Linear,
+            "radial-gradient" => GradientKind::Radial,
+            "conic-gradient" => GradientKind::Conic,
+            _ => return None,
+        };
+
+        let arguments = function
+            .items()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, parameter)| {
+                let parameter = parameter.ok()?;
+                Some(GradientArgument::from_parameter(parameter, index == 0))
+            })
+            .collect();
+        Some(Self {
+            kind,
+            prefixed,
+            arguments,
+        })
+    }
+
+    /// Returns the prelude of the gradient, if it has one
+    pub fn prelude(&self) -> Option<&GradientPrelude> {
+        match self.arguments.first()? {
+            GradientArgument::Prelude(prelude) => Some(prelude),
+            _ => None,
+        }
+    }
+}
+
+/// The role of a value in an argument of a gradient function
+enum GradientValue {
+    Color,
+    Position(GradientPosition),
+    Keyword,
+    Unknown,
+}
+
+impl GradientArgument {
+    pub fn range(&self) -> TextRange {
+        match self {
+            Self::Prelude(prelude) => prelude.parameter.range(),
+            Self::ColorStop { parameter, .. }
+            | Self::Hint { parameter, .. }
+            | Self::MissingComma(parameter)
+            | Self::Unknown(parameter) => parameter.range(),
+        }
+    }
+
+    fn from_parameter(parameter: CssParameter, is_first: bool) -> Self {
+        let Ok(AnyCssExpression::CssListOfComponentValuesExpression(expression)) =
+            parameter.any_css_expression()
+        else {
+            return Self::Unknown(parameter);
+        };
+        let values: Vec<_> = expression.css_component_value_list().into_iter().collect();
+
+        let mut colors = 0;
+        let mut keywords = 0;
+        let mut positions = Vec::new();
+        for value in &values {
+            match GradientValue::from_value(value) {
+                GradientValue::Color => colors += 1,
+                GradientValue::Position(position) => positions.push(position),
+                GradientValue::Keyword => keywords += 1,
+                GradientValue::Unknown => return Self::Unknown(parameter),
+            }
+        }
+
+        match (colors, keywords, positions.len()) {
+            // `to right red, blue`
+            (1.., 1.., _) if is_first => Self::MissingComma(parameter),
+            (0, _, _) if is_first => Self::Prelude(GradientPrelude::new(parameter, values)),
+            (0, 0, 1) => Self::Hint {
+                parameter,
+                position: positions.remove(0),
+            },
+            (1, 0, 0..=2) => Self::ColorStop {
+                parameter,
+                positions,
+            },
+            (2.., _, _) => Self::MissingComma(parameter),
+            _ => Self::Unknown(parameter),
+        }
+    }
+}
+
+impl GradientPrelude {
+    fn new(parameter: CssParameter, values: Vec<AnyCssValue>) -> Self {
+        // `in oklch longer hue` sets the color space of the interpolation, before or after the
+        // other values
+        let interpolation = values
+            .iter()
+            .position(|value| keyword(value).is_some_and(|keyword| keyword == "in"));
+        let values = match interpolation {
+            Some(start) => {
+                let end = values[start..]
+                    .iter()
+                    .position(|value| {
+                        keyword(value)
+                            .is_some_and(|keyword| matches!(keyword.as_str(), "to" | "at" | "from"))
+                    })
+                    .map_or(values.len(), |end| start + end);
+                values[..start]
+                    .iter()
+                    .chain(&values[end..])
+                    .cloned()
+                    .collect()
+            }
+            None => values,
+        };
+        Self { parameter, values }
+    }
+}
+
+impl GradientValue {
+    fn from_value(value: &AnyCssValue) -> Self {
+        match value {
+            AnyCssValue::CssColor(_) => Self::Color,
+            AnyCssValue::CssIdentifier(identifier) => {
+                let Ok(name) = identifier.value_token() else {
+                    return Self::Unknown;
+                };
+                let name = name.text_trimmed();
+                if name.eq_ignore_ascii_case("currentcolor") || Color::from_name(name).is_some() {
+                    Self::Color
+                } else {
+                    Self::Keyword
+                }
+            }
+            AnyCssValue::CssNumber(_) | AnyCssValue::AnyCssDimension(_) => {
+                match Dimension::from_css_value(value) {
+                    Some(dimension) => Self::Position(GradientPosition {
+                        dimension: Some(dimension),
+                        range: value.range(),
+                    }),
+                    None => Self::Unknown,
+                }
+            }
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+                let Some(name) = function
+                    .name()
+                    .ok()
+                    .and_then(|name| name.value_token().ok())
+                else {
+                    return Self::Unknown;
+                };
+                match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+                    "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch"
+                    | "color" | "color-mix" | "light-dark" => Self::Color,
+                    "calc" | "min" | "max" | "clamp" => Self::Position(GradientPosition {
+                        dimension: None,
+                        range: function.range(),
+                    }),
+                    _ => Self::Unknown,
+                }
+            }
+            _ => Self::Unknown,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! A series of utilities to parse the grid templates of CSS, such as the value of
+//! `grid-template-areas`
+
+use biome_css_syntax::{AnyCssGenericComponentValue, CssGenericComponentValueList, CssString};
+use biome_rowan::{AstNode, TextRange};
+
+/// A row of named grid areas, such as `"header header sidebar"`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GridAreaRow {
+    /// The cell tokens of the row, in order, such as `["header", "header", "sidebar"]`.
+    ///
+    /// A null cell token is a sequence of `.`, and any other sequence of characters that can't
+    /// be in a name, such as `,`, is a trash token.
+    pub cells: Vec<String>,
+    /// The range of the string of the row
+    pub range: TextRange,
+}
+
+impl GridAreaRow {
+    /// Splits the content of a string into cell tokens.
+    ///
+    /// See <https://drafts.csswg.org/css-grid/#valdef-grid-template-areas-string>
+    pub fn from_string(string: &CssString) -> Option<Self> {
+        let token = string.value_token().ok()?;
+        let text = token.text_trimmed();
+        let content = text
+            .strip_prefix(['"', '\''])
+            .and_then(|text| text.strip_suffix(['"', '\'']))
+            .unwrap_or(text);
+
+        let mut cells: Vec<String> = Vec::new();
+        let mut previous_kind = None;
+        for char in content.chars() {
+            let kind = CellKind::of(char);
+            match kind {
+                CellKind::Whitespace => {}
+                // A name or a sequence of `.` continues
+                _ if previous_kind == Some(kind) && kind != CellKind::Trash => {
+                    if let Some(cell) = cells.last_mut() {
+                        cell.push(char);
+                    }
+                }
+                _ => cells.push(char.to_string()),
+            }
+            previous_kind = Some(kind);
+        }
+
+        Some(Self {
+            cells,
+            range: string.range(),
+        })
+    }
+
+    /// Returns `true` if the row doesn't have any cell, like `""`
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+/// Returns `true` if `cell` is a null cell token, like `.` or `...`
+pub fn is_null_cell(cell: &str) -> bool {
+    !cell.is_empty() && cell.bytes().all(|byte| byte == b'.')
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CellKind {
+    Name,
+    Null,
+    Whitespace,
+    Trash,
+}
+
+impl CellKind {
+    fn of(char: char) -> Self {
+        match char {
+            '.' => Self::Null,
+            ' ' | '\t' | '\n' | '\r' | '\x0C' => Self::Whitespace,
+            '-' | '_' => Self::Name,
+            char if char.is_ascii_alphanumeric() || !char.is_ascii() => Self::Name,
+            _ => Self::Trash,
+        }
+    }
+}
+
+/// Returns the rows of named grid areas in the value of `grid-template-areas`, `grid-template`
+/// or `grid`, which are its strings.
+pub fn grid_area_rows(value: &CssGenericComponentValueList) -> Vec<GridAreaRow> {
+    value
+        .into_iter()
+        .filter_map(|value| match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => {
+                GridAreaRow::from_string(value.as_css_string()?)
+            }
+            AnyCssGenericComponentValue::CssGenericDelimiter(_) => None,
+        })
+        .collect()
+}
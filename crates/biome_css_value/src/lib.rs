@@ -0,0 +1,21 @@
+//! Typed parsing of the component values of CSS, such as the colors, the dimensions, the
+//! gradients, the grid templates and the transitions.
+//!
+//! The lint rules and the formatter use these utilities instead of matching the text of the
+//! tokens, so that they understand the values in the same way.
+
+use biome_css_syntax::AnyCssValue;
+use biome_string_case::StrLikeExtension;
+
+pub mod color;
+pub mod dimension;
+pub mod gradient;
+pub mod grid;
+pub mod transition;
+
+/// Returns the lowercase name of `value` if it's an identifier
+pub fn keyword(value: &AnyCssValue) -> Option<String> {
+    let identifier = value.as_css_identifier()?;
+    let name = identifier.value_token().ok()?;
+    Some(name.text_trimmed().to_ascii_lowercase_cow().to_string())
+}
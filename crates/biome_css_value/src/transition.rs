@@ -0,0 +1,128 @@
+//! A series of utilities to parse the `transition` shorthand of CSS
+
+use crate::dimension::{Dimension, UnitKind};
+use crate::keyword;
+use biome_css_syntax::{
+    AnyCssFunction, AnyCssGenericComponentValue, AnyCssValue, CssGenericComponentValueList, T,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+use biome_string_case::StrLikeExtension;
+
+/// A single transition of the `transition` shorthand, such as `opacity 0.3s ease-in 100ms`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transition {
+    /// The lowercase name of the transitioned property, or `None` when it's omitted, which
+    /// means `all`
+    pub property: Option<String>,
+    /// The duration, or `None` when it's omitted, which means `0s`
+    pub duration: Option<Dimension>,
+    /// The delay, or `None` when it's omitted, which means `0s`
+    pub delay: Option<Dimension>,
+    /// The easing function, such as `ease-in` or `cubic-bezier(0.1, 0.7, 1, 0.1)`, or `None`
+    /// when it's omitted, which means `ease`
+    pub easing_function: Option<AnyCssValue>,
+    /// Whether the transition applies to the properties that are animated discretely, with
+    /// `allow-discrete`
+    pub allow_discrete: bool,
+    /// The range of the values of the transition
+    pub range: TextRange,
+}
+
+impl Transition {
+    fn from_values(values: &[AnyCssValue]) -> Option<Self> {
+        let first = values.first()?;
+        let last = values.last()?;
+        let mut transition = Self {
+            property: None,
+            duration: None,
+            delay: None,
+            easing_function: None,
+            allow_discrete: false,
+            range: first.range().cover(last.range()),
+        };
+        let mut behavior = None;
+
+        for value in values {
+            match value {
+                AnyCssValue::CssIdentifier(_) => {
+                    let name = keyword(value)?;
+                    match name.as_str() {
+                        "linear" | "ease" | "ease-in" | "ease-out" | "ease-in-out"
+                        | "step-start" | "step-end" => {
+                            set_once(&mut transition.easing_function, value.clone())?
+                        }
+                        "normal" | "allow-discrete" => {
+                            set_once(&mut behavior, name == "allow-discrete")?
+                        }
+                        _ => set_once(&mut transition.property, name)?,
+                    }
+                }
+                AnyCssValue::CssDashedIdentifier(identifier) => {
+                    let name = identifier.value_token().ok()?;
+                    set_once(&mut transition.property, name.text_trimmed().to_string())?;
+                }
+                AnyCssValue::AnyCssDimension(_) => {
+                    let dimension = Dimension::from_css_value(value)?;
+                    if dimension.kind() != Some(UnitKind::Time) {
+                        return None;
+                    }
+                    // The first time is the duration, and the second time is the delay
+                    if transition.duration.is_none() {
+                        transition.duration = Some(dimension);
+                    } else {
+                        set_once(&mut transition.delay, dimension)?;
+                    }
+                }
+                AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+                    let name = function.name().ok()?.value_token().ok()?;
+                    match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+                        "cubic-bezier" | "steps" | "linear" => {
+                            set_once(&mut transition.easing_function, value.clone())?
+                        }
+                        // The other functions, such as `var()`, can't be resolved statically
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            }
+        }
+
+        transition.allow_discrete = behavior.unwrap_or_default();
+        Some(transition)
+    }
+}
+
+/// Sets `slot` to `value`, or returns `None` if `slot` is already set
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Option<()> {
+    if slot.is_some() {
+        return None;
+    }
+    *slot = Some(value);
+    Some(())
+}
+
+/// Parses the value of the `transition` shorthand, such as
+/// `opacity 0.3s ease-in, transform 200ms`, into its transitions.
+///
+/// Returns `None` if the value can't be resolved statically, such as `var(--transition)`, or if
+/// it isn't valid.
+///
+/// See <https://drafts.csswg.org/css-transitions/#transition-shorthand-property>
+pub fn transitions(value: &CssGenericComponentValueList) -> Option<Vec<Transition>> {
+    let mut transitions = Vec::new();
+    let mut values = Vec::new();
+    for value in value.iter() {
+        match value {
+            AnyCssGenericComponentValue::AnyCssValue(value) => values.push(value),
+            AnyCssGenericComponentValue::CssGenericDelimiter(delimiter) => {
+                if delimiter.value().ok()?.kind() != T![,] {
+                    return None;
+                }
+                transitions.push(Transition::from_values(&values)?);
+                values.clear();
+            }
+        }
+    }
+    transitions.push(Transition::from_values(&values)?);
+    Some(transitions)
+}
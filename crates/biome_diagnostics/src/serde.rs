@@ -82,6 +82,13 @@ impl Diagnostic {
             .map(|span| TextRange::new(span.start() + offset, span.end() + offset));
         self
     }
+
+    /// Replaces the span of the diagnostic, such as when the range of an embedded code is
+    /// mapped to its host document
+    pub fn with_span(mut self, span: TextRange) -> Self {
+        self.location.span = Some(span);
+        self
+    }
 }
 
 impl super::Diagnostic for Diagnostic {
@@ -133,43 +133,65 @@ define_categories! {
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
+    "lint/nursery/noAtHtmlTags": "https://biomejs.dev/linter/rules/no-at-html-tags",
+    "lint/nursery/noAwaitInLoops": "https://biomejs.dev/linter/rules/no-await-in-loops",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
     "lint/nursery/noCommonJs": "https://biomejs.dev/linter/rules/no-common-js",
+    "lint/nursery/noConflictingCompilerOptions": "https://biomejs.dev/linter/rules/no-conflicting-compiler-options",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
+    "lint/nursery/noDeprecatedCompilerOptions": "https://biomejs.dev/linter/rules/no-deprecated-compiler-options",
+    "lint/nursery/noDeprecatedFields": "https://biomejs.dev/linter/rules/no-deprecated-fields",
     "lint/nursery/noDescendingSpecificity": "https://biomejs.dev/linter/rules/no-descending-specificity",
     "lint/nursery/noDocumentCookie": "https://biomejs.dev/linter/rules/no-document-cookie",
     "lint/nursery/noDocumentImportInPage": "https://biomejs.dev/linter/rules/no-document-import-in-page",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
     "lint/nursery/noDuplicateAtImportRules": "https://biomejs.dev/linter/rules/no-duplicate-at-import-rules",
     "lint/nursery/noDuplicateCustomProperties": "https://biomejs.dev/linter/rules/no-duplicate-custom-properties",
+    "lint/nursery/noDuplicateDependencies": "https://biomejs.dev/linter/rules/no-duplicate-dependencies",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
+    "lint/nursery/noDuplicateKeys": "https://biomejs.dev/linter/rules/no-duplicate-keys",
     "lint/nursery/noDuplicateProperties": "https://biomejs.dev/linter/rules/no-duplicate-properties",
+    "lint/nursery/noDuplicateTables": "https://biomejs.dev/linter/rules/no-duplicate-tables",
     "lint/nursery/noDuplicatedFields": "https://biomejs.dev/linter/rules/no-duplicated-fields",
     "lint/nursery/noDynamicNamespaceImportAccess": "https://biomejs.dev/linter/rules/no-dynamic-namespace-import-access",
     "lint/nursery/noEnum": "https://biomejs.dev/linter/rules/no-enum",
     "lint/nursery/noExportedImports": "https://biomejs.dev/linter/rules/no-exported-imports",
+    "lint/nursery/noGlobalLeakage": "https://biomejs.dev/linter/rules/no-global-leakage",
+    "lint/nursery/noHardcodedLocaleStrings": "https://biomejs.dev/linter/rules/no-hardcoded-locale-strings",
     "lint/nursery/noHeadElement": "https://biomejs.dev/linter/rules/no-head-element",
     "lint/nursery/noHeadImportInDocument": "https://biomejs.dev/linter/rules/no-head-import-in-document",
     "lint/nursery/noImgElement": "https://biomejs.dev/linter/rules/no-img-element",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
+    "lint/nursery/noInvalidArgumentValues": "https://biomejs.dev/linter/rules/no-invalid-argument-values",
     "lint/nursery/noInvalidDirectionInLinearGradient": "https://biomejs.dev/linter/rules/no-invalid-direction-in-linear-gradient",
     "lint/nursery/noInvalidGridAreas": "https://biomejs.dev/linter/rules/use-consistent-grid-areas",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
+    "lint/nursery/noInvalidSemverRange": "https://biomejs.dev/linter/rules/no-invalid-semver-range",
     "lint/nursery/noIrregularWhitespace": "https://biomejs.dev/linter/rules/no-irregular-whitespace",
+    "lint/nursery/noLowContrastColors": "https://biomejs.dev/linter/rules/no-low-contrast-colors",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
     "lint/nursery/noMissingVarFunction": "https://biomejs.dev/linter/rules/no-missing-var-function",
+    "lint/nursery/noMutationOfFunctionParametersDeep": "https://biomejs.dev/linter/rules/no-mutation-of-function-parameters-deep",
     "lint/nursery/noNestedTernary": "https://biomejs.dev/linter/rules/no-nested-ternary",
     "lint/nursery/noOctalEscape": "https://biomejs.dev/linter/rules/no-octal-escape",
+    "lint/nursery/noOutdatedBrowserApis": "https://biomejs.dev/linter/rules/no-outdated-browser-apis",
+    "lint/nursery/noPositiveTabindex": "https://biomejs.dev/linter/rules/no-positive-tabindex",
     "lint/nursery/noProcessEnv": "https://biomejs.dev/linter/rules/no-process-env",
     "lint/nursery/noReactSpecificProps": "https://biomejs.dev/linter/rules/no-react-specific-props",
+    "lint/nursery/noRedundantLonghand": "https://biomejs.dev/linter/rules/no-redundant-longhand",
+    "lint/nursery/noRedundantTypeAnnotations": "https://biomejs.dev/linter/rules/no-redundant-type-annotations",
     "lint/nursery/noRestrictedImports": "https://biomejs.dev/linter/rules/no-restricted-imports",
     "lint/nursery/noRestrictedTypes": "https://biomejs.dev/linter/rules/no-restricted-types",
     "lint/nursery/noSecrets": "https://biomejs.dev/linter/rules/no-secrets",
     "lint/nursery/noShorthandPropertyOverrides": "https://biomejs.dev/linter/rules/no-shorthand-property-overrides",
+    "lint/nursery/noSkippedAccessibilityInTests": "https://biomejs.dev/linter/rules/no-skipped-accessibility-in-tests",
     "lint/nursery/noStaticElementInteractions": "https://biomejs.dev/linter/rules/no-static-element-interactions",
     "lint/nursery/noSubstr": "https://biomejs.dev/linter/rules/no-substr",
     "lint/nursery/noTemplateCurlyInString": "https://biomejs.dev/linter/rules/no-template-curly-in-string",
+    "lint/nursery/noUncheckedJsonParse": "https://biomejs.dev/linter/rules/no-unchecked-json-parse",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUndefinedComposes": "https://biomejs.dev/linter/rules/no-undefined-composes",
+    "lint/nursery/noUnknownFields": "https://biomejs.dev/linter/rules/no-unknown-fields",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
@@ -180,32 +202,68 @@ define_categories! {
     "lint/nursery/noUnknownTypeSelector": "https://biomejs.dev/linter/rules/no-unknown-type-selector",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnsupportedCssFeatures": "https://biomejs.dev/linter/rules/no-unsupported-css-features",
+    "lint/nursery/noUnusedCustomProperties": "https://biomejs.dev/linter/rules/no-unused-custom-properties",
+    "lint/nursery/noUnusedFragments": "https://biomejs.dev/linter/rules/no-unused-fragments",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
     "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
     "lint/nursery/noUselessStringRaw": "https://biomejs.dev/linter/rules/no-useless-string-raw",
     "lint/nursery/noUselessUndefined": "https://biomejs.dev/linter/rules/no-useless-undefined",
     "lint/nursery/noValueAtRule": "https://biomejs.dev/linter/rules/no-value-at-rule",
+    "lint/nursery/noVendorPrefix": "https://biomejs.dev/linter/rules/no-vendor-prefix",
+    "lint/nursery/noWildcardExportsFromTests": "https://biomejs.dev/linter/rules/no-wildcard-exports-from-tests",
+    "lint/nursery/useAccessorPairs": "https://biomejs.dev/linter/rules/use-accessor-pairs",
     "lint/nursery/useAdjacentOverloadSignatures": "https://biomejs.dev/linter/rules/use-adjacent-overload-signatures",
+    "lint/nursery/useAltText": "https://biomejs.dev/linter/rules/use-alt-text",
     "lint/nursery/useAriaPropsSupportedByRole": "https://biomejs.dev/linter/rules/use-aria-props-supported-by-role",
     "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useButtonName": "https://biomejs.dev/linter/rules/use-button-name",
+    "lint/nursery/useButtonType": "https://biomejs.dev/linter/rules/use-button-type",
+    "lint/nursery/useCanonicalOrderOfModifiers": "https://biomejs.dev/linter/rules/use-canonical-order-of-modifiers",
     "lint/nursery/useCollapsedIf": "https://biomejs.dev/linter/rules/use-collapsed-if",
     "lint/nursery/useComponentExportOnlyModules": "https://biomejs.dev/linter/rules/use-components-only-module",
+    "lint/nursery/useConsistentColorFormat": "https://biomejs.dev/linter/rules/use-consistent-color-format",
     "lint/nursery/useConsistentCurlyBraces": "https://biomejs.dev/linter/rules/use-consistent-curly-braces",
     "lint/nursery/useConsistentMemberAccessibility": "https://biomejs.dev/linter/rules/use-consistent-member-accessibility",
+    "lint/nursery/useConsistentResponseHandling": "https://biomejs.dev/linter/rules/use-consistent-response-handling",
+    "lint/nursery/useCssVariablesOverSassVariables": "https://biomejs.dev/linter/rules/use-css-variables-over-sass-variables",
     "lint/nursery/useDeprecatedReason": "https://biomejs.dev/linter/rules/use-deprecated-reason",
+    "lint/nursery/useDestructuredImports": "https://biomejs.dev/linter/rules/use-destructured-imports",
     "lint/nursery/useExplicitFunctionReturnType": "https://biomejs.dev/linter/rules/use-explicit-function-return-type",
     "lint/nursery/useExplicitType": "https://biomejs.dev/linter/rules/use-explicit-function-return-type",
+    "lint/nursery/useExportsTypesCondition": "https://biomejs.dev/linter/rules/use-exports-types-condition",
+    "lint/nursery/useFragmentNameConvention": "https://biomejs.dev/linter/rules/use-fragment-name-convention",
     "lint/nursery/useGoogleFontDisplay": "https://biomejs.dev/linter/rules/use-google-font-display",
     "lint/nursery/useGoogleFontPreconnect": "https://biomejs.dev/linter/rules/use-google-font-preconnect",
     "lint/nursery/useGuardForIn": "https://biomejs.dev/linter/rules/use-guard-for-in",
+    "lint/nursery/useHtmlLang": "https://biomejs.dev/linter/rules/use-html-lang",
+    "lint/nursery/useImmutableExports": "https://biomejs.dev/linter/rules/use-immutable-exports",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
     "lint/nursery/useJsxCurlyBraceConvention": "https://biomejs.dev/linter/rules/use-jsx-curly-brace-convention",
+    "lint/nursery/useLogicalProperties": "https://biomejs.dev/linter/rules/use-logical-properties",
+    "lint/nursery/useMaxNestingDepth": "https://biomejs.dev/linter/rules/use-max-nesting-depth",
+    "lint/nursery/useMaxQueryDepth": "https://biomejs.dev/linter/rules/use-max-query-depth",
+    "lint/nursery/useMaxSpecificity": "https://biomejs.dev/linter/rules/use-max-specificity",
+    "lint/nursery/useMinimalBundleImports": "https://biomejs.dev/linter/rules/use-minimal-bundle-imports",
     "lint/nursery/useNamedOperation": "https://biomejs.dev/linter/rules/use-named-operation",
+    "lint/nursery/useOperationNamingConvention": "https://biomejs.dev/linter/rules/use-operation-naming-convention",
+    "lint/nursery/usePlaywrightLocatorBestPractices": "https://biomejs.dev/linter/rules/use-playwright-locator-best-practices",
+    "lint/nursery/usePreferredModules": "https://biomejs.dev/linter/rules/use-preferred-modules",
+    "lint/nursery/useRequiredArguments": "https://biomejs.dev/linter/rules/use-required-arguments",
+    "lint/nursery/useSafeIntegerChecks": "https://biomejs.dev/linter/rules/use-safe-integer-checks",
+    "lint/nursery/useShorthandProperties": "https://biomejs.dev/linter/rules/use-shorthand-properties",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedDependencies": "https://biomejs.dev/linter/rules/use-sorted-dependencies",
+    "lint/nursery/useSortedPackageJsonFields": "https://biomejs.dev/linter/rules/use-sorted-package-json-fields",
+    "lint/nursery/useSortedSchemaFields": "https://biomejs.dev/linter/rules/use-sorted-schema-fields",
+    "lint/nursery/useStaticClassBlocks": "https://biomejs.dev/linter/rules/use-static-class-blocks",
+    "lint/nursery/useStrictCompanionOptions": "https://biomejs.dev/linter/rules/use-strict-companion-options",
     "lint/nursery/useStrictMode": "https://biomejs.dev/linter/rules/use-strict-mode",
     "lint/nursery/useTrimStartEnd": "https://biomejs.dev/linter/rules/use-trim-start-end",
+    "lint/nursery/useTypenameOnAbstractTypes": "https://biomejs.dev/linter/rules/use-typename-on-abstract-types",
     "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
+    "lint/nursery/useValidLang": "https://biomejs.dev/linter/rules/use-valid-lang",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
@@ -333,7 +391,10 @@ define_categories! {
     "lint/suspicious/useValidTypeof": "https://biomejs.dev/linter/rules/use-valid-typeof",
     // end lint rules
     // start assists rules
+    "assists/source/useExplicitTypeAnnotation": "https://biomejs.dev/linter/actions/use-explicit-type-annotation",
     "assists/source/useSortedKeys": "https://biomejs.dev/linter/actions/use-sorted-keys",
+    "assists/source/useSortedTableKeys": "https://biomejs.dev/linter/actions/use-sorted-table-keys",
+    "assists/source/useTemplateLiteral": "https://biomejs.dev/linter/actions/use-template-literal",
     // end assists rules
     ; // start syntax rules
     "syntax/correctness/noTypeOnlyImportAttributes",
@@ -355,6 +416,7 @@ define_categories! {
     "deserialize",
     "project",
     "search",
+    "maxDiagnostics",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
@@ -382,6 +444,7 @@ define_categories! {
     "suppressions/unknownRule",
     "suppressions/unused",
     "suppressions/deprecatedSuppressionComment",
+    "suppressions/tooBroad",
 
     // Used in tests and examples
     "args/fileNotFound",
@@ -1,7 +1,10 @@
 use crate::format_element::tag::{Condition, Tag};
 use crate::prelude::tag::{DedentMode, GroupMode, LabelId};
 use crate::prelude::*;
-use crate::{format_element, write, Argument, Arguments, GroupId, TextRange, TextSize};
+use crate::{
+    format_element, write, Argument, Arguments, FormatContext, FormatOptions, GroupId, TextRange,
+    TextSize,
+};
 use crate::{Buffer, VecBuffer};
 use biome_rowan::{Language, SyntaxNode, SyntaxToken, TextLen, TokenText};
 use std::borrow::Cow;
@@ -129,7 +132,43 @@ pub const fn hard_line_break() -> Line {
 /// ```
 #[inline]
 pub const fn empty_line() -> Line {
-    Line::new(LineMode::Empty)
+    Line::new(LineMode::Empty(1))
+}
+
+/// Forced empty lines. Inserts enough line breaks in the output for the previous and next
+/// element to be separated by `count` empty lines. It's a [hard_line_break] if `count` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use biome_formatter::{format, format_args};
+/// use biome_formatter::prelude::*;
+///
+/// fn main() -> FormatResult<()> {
+/// let elements = format!(
+///     SimpleFormatContext::default(), [
+///     text("a,"),
+///     empty_lines(2),
+///     text("b"),
+///     empty_line(),
+///     empty_lines(3),
+///     text("c"),
+/// ])?;
+///
+/// assert_eq!(
+///     "a,\n\n\nb\n\n\n\nc",
+///     elements.print()?.as_code()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub const fn empty_lines(count: u8) -> Line {
+    if count == 0 {
+        hard_line_break()
+    } else {
+        Line::new(LineMode::Empty(count))
+    }
 }
 
 /// A line break if the enclosing `Group` doesn't fit on a single line, a space otherwise.
@@ -2492,6 +2531,7 @@ pub struct JoinNodesBuilder<'fmt, 'buf, Separator, Context> {
 impl<'fmt, 'buf, Separator, Context> JoinNodesBuilder<'fmt, 'buf, Separator, Context>
 where
     Separator: Format<Context>,
+    Context: FormatContext,
 {
     pub(super) fn new(separator: Separator, fmt: &'fmt mut Formatter<'buf, Context>) -> Self {
         Self {
@@ -2504,11 +2544,18 @@ where
 
     /// Adds a new node with the specified formatted content to the output, respecting any new lines
     /// that appear before the node in the input source.
+    ///
+    /// The empty lines before the node are kept up to the
+    /// [max_empty_lines](FormatOptions::max_empty_lines) option.
     pub fn entry<L: Language>(&mut self, node: &SyntaxNode<L>, content: &dyn Format<Context>) {
         self.result = self.result.and_then(|_| {
             if self.has_elements {
-                if get_lines_before(node) > 1 {
-                    write!(self.fmt, [empty_line()])?;
+                let max_empty_lines = self.fmt.options().max_empty_lines().value();
+                let lines = get_lines_before(node)
+                    .saturating_sub(1)
+                    .min(usize::from(max_empty_lines));
+                if lines > 0 {
+                    write!(self.fmt, [empty_lines(lines as u8)])?;
                 } else {
                     self.separator.fmt(self.fmt)?;
                 }
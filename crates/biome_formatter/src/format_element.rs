@@ -100,8 +100,10 @@ pub enum LineMode {
     Soft,
     /// See [crate::builders::hard_line_break] for documentation.
     Hard,
-    /// See [crate::builders::empty_line] for documentation.
-    Empty,
+    /// See [crate::builders::empty_line] and [crate::builders::empty_lines] for documentation.
+    ///
+    /// Holds the number of empty lines, which is at least 1.
+    Empty(u8),
 }
 
 impl LineMode {
@@ -245,7 +247,9 @@ impl FormatElements for FormatElement {
         match self {
             FormatElement::ExpandParent => true,
             FormatElement::Tag(Tag::StartGroup(group)) => !group.mode().is_flat(),
-            FormatElement::Line(line_mode) => matches!(line_mode, LineMode::Hard | LineMode::Empty),
+            FormatElement::Line(line_mode) => {
+                matches!(line_mode, LineMode::Hard | LineMode::Empty(_))
+            }
             FormatElement::StaticText { text } => text.contains('\n'),
             FormatElement::DynamicText { text, .. } => text.contains('\n'),
             FormatElement::LocatedTokenText { slice, .. } => slice.contains('\n'),
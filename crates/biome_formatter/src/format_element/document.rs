@@ -115,7 +115,7 @@ impl Document {
                     FormatElement::DynamicText { text, .. } => text.contains('\n'),
                     FormatElement::LocatedTokenText { slice, .. } => slice.contains('\n'),
                     FormatElement::ExpandParent
-                    | FormatElement::Line(LineMode::Hard | LineMode::Empty) => true,
+                    | FormatElement::Line(LineMode::Hard | LineMode::Empty(_)) => true,
                     _ => false,
                 };
 
@@ -306,9 +306,19 @@ impl Format<IrFormatContext> for &[FormatElement] {
                     LineMode::Hard => {
                         write!(f, [text("hard_line_break")])?;
                     }
-                    LineMode::Empty => {
+                    LineMode::Empty(1) => {
                         write!(f, [text("empty_line")])?;
                     }
+                    LineMode::Empty(count) => {
+                        write!(
+                            f,
+                            [
+                                text("empty_lines("),
+                                dynamic_text(&std::format!("{count}"), TextSize::default()),
+                                text(")")
+                            ]
+                        )?;
+                    }
                 },
                 FormatElement::ExpandParent => {
                     write!(f, [text("expand_parent")])?;
@@ -115,11 +115,12 @@ impl<'buf, Context> Formatter<'buf, Context> {
     /// line break or empty line depending on the input file.
     ///
     /// This function inspects the input source and separates consecutive elements with either
-    /// a [crate::builders::soft_line_break_or_space] or [crate::builders::empty_line] depending on how many line breaks were
+    /// a [crate::builders::soft_line_break_or_space] or [crate::builders::empty_lines] depending on how many line breaks were
     /// separating the elements in the original file.
-    pub fn join_nodes_with_soft_line<'a>(
-        &'a mut self,
-    ) -> JoinNodesBuilder<'a, 'buf, Line, Context> {
+    pub fn join_nodes_with_soft_line<'a>(&'a mut self) -> JoinNodesBuilder<'a, 'buf, Line, Context>
+    where
+        Context: FormatContext,
+    {
         JoinNodesBuilder::new(soft_line_break_or_space(), self)
     }
 
@@ -127,9 +128,12 @@ impl<'buf, Context> Formatter<'buf, Context> {
     /// line breaks depending on the input file.
     ///
     /// This function inspects the input source and separates consecutive elements with either
-    /// a [crate::builders::hard_line_break] or [crate::builders::empty_line] depending on how many line breaks were separating the
+    /// a [crate::builders::hard_line_break] or [crate::builders::empty_lines] depending on how many line breaks were separating the
     /// elements in the original file.
-    pub fn join_nodes_with_hardline<'a>(&'a mut self) -> JoinNodesBuilder<'a, 'buf, Line, Context> {
+    pub fn join_nodes_with_hardline<'a>(&'a mut self) -> JoinNodesBuilder<'a, 'buf, Line, Context>
+    where
+        Context: FormatContext,
+    {
         JoinNodesBuilder::new(hard_line_break(), self)
     }
 
@@ -142,7 +146,10 @@ impl<'buf, Context> Formatter<'buf, Context> {
     /// This function should likely only be used in a `best_fitting!` context, where one variant attempts to
     /// force a list of nodes onto a single line without any possible breaks, then falls back to a broken
     /// out variant if the content does not fit.
-    pub fn join_nodes_with_space<'a>(&'a mut self) -> JoinNodesBuilder<'a, 'buf, Space, Context> {
+    pub fn join_nodes_with_space<'a>(&'a mut self) -> JoinNodesBuilder<'a, 'buf, Space, Context>
+    where
+        Context: FormatContext,
+    {
         JoinNodesBuilder::new(space(), self)
     }
 
@@ -374,6 +374,109 @@ impl Debug for LineWidth {
     }
 }
 
+/// Validated value for the `max_empty_lines` formatter options: the maximum number of
+/// consecutive empty lines that are kept between the statements and the members.
+///
+/// The allowed range of values is 0..=20
+#[derive(Clone, Copy, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct MaxEmptyLines(u8);
+
+impl MaxEmptyLines {
+    /// Minimum allowed value for a valid [MaxEmptyLines]
+    pub const MIN: u8 = 0;
+    /// Maximum allowed value for a valid [MaxEmptyLines]
+    pub const MAX: u8 = 20;
+
+    /// Return the numeric value for this [MaxEmptyLines]
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for MaxEmptyLines {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl Deserializable for MaxEmptyLines {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = TextNumber::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.parse::<Self>() {
+            return Some(value);
+        }
+        diagnostics.push(DeserializationDiagnostic::new_out_of_bound_integer(
+            Self::MIN,
+            Self::MAX,
+            value.range(),
+        ));
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MaxEmptyLines {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value: u8 = serde::Deserialize::deserialize(deserializer)?;
+        let max_empty_lines = MaxEmptyLines::try_from(value).map_err(serde::de::Error::custom)?;
+        Ok(max_empty_lines)
+    }
+}
+
+impl FromStr for MaxEmptyLines {
+    type Err = ParseFormatNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = u8::from_str(s).map_err(ParseFormatNumberError::ParseError)?;
+        let value =
+            Self::try_from(value).map_err(ParseFormatNumberError::TryFromMaxEmptyLinesError)?;
+        Ok(value)
+    }
+}
+
+impl TryFrom<u8> for MaxEmptyLines {
+    type Error = MaxEmptyLinesFromIntError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(MaxEmptyLinesFromIntError(value))
+        }
+    }
+}
+
+impl biome_console::fmt::Display for MaxEmptyLines {
+    fn fmt(&self, fmt: &mut biome_console::fmt::Formatter) -> std::io::Result<()> {
+        fmt.write_markup(markup! {{self.value()}})
+    }
+}
+
+impl Display for MaxEmptyLines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.value();
+        f.write_str(&std::format!("{value}"))
+    }
+}
+
+impl Debug for MaxEmptyLines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
 /// Error type returned when parsing a [LineWidth] or [IndentWidth] from a string fails
 pub enum ParseFormatNumberError {
     /// The string could not be parsed to a number
@@ -382,6 +485,8 @@ pub enum ParseFormatNumberError {
     TryFromU16Error(LineWidthFromIntError),
     /// The `u8 value of the string is not a valid [IndentWidth]
     TryFromU8Error(IndentWidthFromIntError),
+    /// The `u8` value of the string is not a valid [MaxEmptyLines]
+    TryFromMaxEmptyLinesError(MaxEmptyLinesFromIntError),
 }
 
 impl From<IndentWidthFromIntError> for ParseFormatNumberError {
@@ -390,6 +495,12 @@ impl From<IndentWidthFromIntError> for ParseFormatNumberError {
     }
 }
 
+impl From<MaxEmptyLinesFromIntError> for ParseFormatNumberError {
+    fn from(value: MaxEmptyLinesFromIntError) -> Self {
+        Self::TryFromMaxEmptyLinesError(value)
+    }
+}
+
 impl From<LineWidthFromIntError> for ParseFormatNumberError {
     fn from(value: LineWidthFromIntError) -> Self {
         Self::TryFromU16Error(value)
@@ -414,6 +525,9 @@ impl std::fmt::Display for ParseFormatNumberError {
             ParseFormatNumberError::ParseError(err) => std::fmt::Display::fmt(err, fmt),
             ParseFormatNumberError::TryFromU16Error(err) => std::fmt::Display::fmt(err, fmt),
             ParseFormatNumberError::TryFromU8Error(err) => std::fmt::Display::fmt(err, fmt),
+            ParseFormatNumberError::TryFromMaxEmptyLinesError(err) => {
+                std::fmt::Display::fmt(err, fmt)
+            }
         }
     }
 }
@@ -455,6 +569,21 @@ impl std::fmt::Display for IndentWidthFromIntError {
     }
 }
 
+/// Error type returned when converting a u8 to a [MaxEmptyLines] fails
+#[derive(Clone, Copy, Debug)]
+pub struct MaxEmptyLinesFromIntError(pub u8);
+
+impl std::fmt::Display for MaxEmptyLinesFromIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "The maximum number of empty lines should be between {} and {}",
+            MaxEmptyLines::MIN,
+            MaxEmptyLines::MAX,
+        )
+    }
+}
+
 /// Error type returned when converting a u16 to a [LineWidth] fails
 #[derive(Clone, Copy, Debug)]
 pub struct LineWidthFromIntError(pub u16);
@@ -486,12 +615,15 @@ pub enum QuoteStyle {
     #[default]
     Double,
     Single,
+    /// Keeps the quote of each string as it's in the source. Where a quote is added, the double
+    /// quote is used.
+    Preserve,
 }
 
 impl QuoteStyle {
     pub fn as_char(&self) -> char {
         match self {
-            QuoteStyle::Double => '"',
+            QuoteStyle::Double | QuoteStyle::Preserve => '"',
             QuoteStyle::Single => '\'',
         }
     }
@@ -503,7 +635,7 @@ impl QuoteStyle {
     /// Returns the quote in HTML entity
     pub fn as_html_entity(&self) -> &str {
         match self {
-            QuoteStyle::Double => "&quot;",
+            QuoteStyle::Double | QuoteStyle::Preserve => "&quot;",
             QuoteStyle::Single => "&apos;",
         }
     }
@@ -511,14 +643,29 @@ impl QuoteStyle {
     /// Given the current quote, it returns the other one
     pub fn other(&self) -> Self {
         match self {
-            QuoteStyle::Double => QuoteStyle::Single,
+            QuoteStyle::Double | QuoteStyle::Preserve => QuoteStyle::Single,
             QuoteStyle::Single => QuoteStyle::Double,
         }
     }
 
+    /// Returns the quote to use for the string `literal`, enclosed in its quotes.
+    ///
+    /// [QuoteStyle::Preserve] returns the quote of `literal`.
+    pub fn resolve(&self, literal: &str) -> Self {
+        match self {
+            QuoteStyle::Preserve if literal.starts_with('\'') => QuoteStyle::Single,
+            QuoteStyle::Preserve => QuoteStyle::Double,
+            _ => *self,
+        }
+    }
+
     pub const fn is_double(&self) -> bool {
         matches!(self, Self::Double)
     }
+
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
 }
 
 impl FromStr for QuoteStyle {
@@ -528,6 +675,7 @@ impl FromStr for QuoteStyle {
         match s {
             "double" | "Double" => Ok(Self::Double),
             "single" | "Single" => Ok(Self::Single),
+            "preserve" | "Preserve" => Ok(Self::Preserve),
             // TODO: replace this error with a diagnostic
             _ => Err("Value not supported for QuoteStyle"),
         }
@@ -539,6 +687,7 @@ impl std::fmt::Display for QuoteStyle {
         match self {
             QuoteStyle::Double => std::write!(f, "Double Quotes"),
             QuoteStyle::Single => std::write!(f, "Single Quotes"),
+            QuoteStyle::Preserve => std::write!(f, "Preserved Quotes"),
         }
     }
 }
@@ -546,7 +695,7 @@ impl std::fmt::Display for QuoteStyle {
 impl From<QuoteStyle> for Quote {
     fn from(quote: QuoteStyle) -> Self {
         match quote {
-            QuoteStyle::Double => Self::Double,
+            QuoteStyle::Double | QuoteStyle::Preserve => Self::Double,
             QuoteStyle::Single => Self::Single,
         }
     }
@@ -668,6 +817,12 @@ pub trait FormatOptions {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     fn bracket_spacing(&self) -> BracketSpacing;
 
+    /// The maximum number of consecutive empty lines that are kept between the nodes
+    /// of a list. Defaults to 1.
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        MaxEmptyLines::default()
+    }
+
     /// Derives the print options from the these format options
     fn as_print_options(&self) -> PrinterOptions;
 }
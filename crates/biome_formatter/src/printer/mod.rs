@@ -117,7 +117,7 @@ impl<'a> Printer<'a> {
                             }
                             return Ok(());
                         }
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty(_) => {
                             self.state.measured_group_fits = false;
                         }
                     }
@@ -133,10 +133,13 @@ impl<'a> Printer<'a> {
                     self.print_str("\n");
                 }
 
-                // Print a second line break if this is an empty line
-                if line_mode == &LineMode::Empty && !self.state.has_empty_line {
-                    self.print_str("\n");
-                    self.state.has_empty_line = true;
+                // Print the line breaks of the empty lines that haven't been printed yet
+                if let LineMode::Empty(count) = *line_mode {
+                    let printed = self.state.empty_lines;
+                    for _ in printed..count {
+                        self.print_str("\n");
+                    }
+                    self.state.empty_lines = printed.max(count);
                 }
 
                 self.state.pending_space = false;
@@ -707,7 +710,7 @@ impl<'a> Printer<'a> {
         for char in content.chars() {
             self.print_char(char);
 
-            self.state.has_empty_line = false;
+            self.state.empty_lines = 0;
         }
     }
 
@@ -770,7 +773,8 @@ struct PrinterState<'a> {
     generated_line: usize,
     generated_column: usize,
     line_width: usize,
-    has_empty_line: bool,
+    /// The number of empty lines printed since the last content
+    empty_lines: u8,
     line_suffixes: LineSuffixes<'a>,
     verbatim_markers: Vec<TextRange>,
     group_modes: GroupModes,
@@ -1065,7 +1069,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                             self.state.pending_space = true;
                         }
                         LineMode::Soft => {}
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty(_) => {
                             // Even in flat mode, content that _directly_ contains a hard or empty
                             // line is considered to fit when a hard break is reached, since that
                             // break is always going to exist, regardless of the print mode.
@@ -1626,6 +1630,20 @@ two lines`,
         assert_eq!("a\n\nb", result.as_code())
     }
 
+    #[test]
+    fn it_prints_the_most_consecutive_empty_lines() {
+        let result = format(&format_args![
+            text("a"),
+            empty_line(),
+            empty_lines(3),
+            hard_line_break(),
+            empty_lines(2),
+            text("b"),
+        ]);
+
+        assert_eq!("a\n\n\n\nb", result.as_code())
+    }
+
     #[test]
     fn test_fill_breaks() {
         let mut state = FormatState::new(());
@@ -34,7 +34,7 @@ impl<'a> SpecTestFile<'a> {
     pub fn try_from_file(
         input_file: &'a str,
         root_path: &'a Path,
-        settings: Option<UpdateSettingsParams>,
+        configuration: Option<PartialConfiguration>,
     ) -> Option<SpecTestFile<'a>> {
         let mut console = EnvConsole::default();
         let app = App::with_console(&mut console);
@@ -47,20 +47,27 @@ impl<'a> SpecTestFile<'a> {
             spec_input_file.display()
         );
 
-        app.workspace
-            .register_project_folder(RegisterProjectFolderParams {
-                set_as_current_workspace: true,
-                path: None,
-            })
+        let project_key = app
+            .workspace
+            .register_project_folder(RegisterProjectFolderParams { path: None })
             .unwrap();
 
-        if let Some(settings) = settings {
-            app.workspace.update_settings(settings).unwrap();
+        if let Some(configuration) = configuration {
+            app.workspace
+                .update_settings(UpdateSettingsParams {
+                    project_key,
+                    configuration,
+                    vcs_base_path: None,
+                    gitignore_matches: vec![],
+                    workspace_directory: None,
+                })
+                .unwrap();
         }
         let mut input_file = BiomePath::new(file_path);
         let can_format = app
             .workspace
             .file_features(SupportsFeatureParams {
+                project_key,
                 path: input_file.clone(),
                 features: FeaturesBuilder::new().with_formatter().build(),
             })
@@ -1,9 +1,11 @@
 mod lint;
 pub mod options;
 mod registry;
+mod services;
 mod suppression_action;
 
 pub use crate::registry::visit_registry;
+pub use crate::services::schema::GraphqlSchema;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
@@ -11,9 +13,9 @@ use biome_analyze::{
 };
 use biome_diagnostics::{category, Error};
 use biome_graphql_syntax::GraphqlLanguage;
-use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax, SuppressionDiagnostic};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type GraphqlRuleAction = RuleAction<GraphqlLanguage>;
 
@@ -25,18 +27,20 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// Rules that rely on the project schema don't emit any signal when `schema` is `None`.
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<GraphqlLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, schema, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -50,6 +54,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -62,7 +67,7 @@ where
     ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
-        for comment in parse_suppression_comment(text) {
+        for comment in parse_suppression_comment_with(text, CommentSyntax::HASH) {
             let categories = match comment {
                 Ok(comment) => {
                     if comment.is_legacy {
@@ -98,7 +103,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -117,6 +122,7 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(schema);
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -166,6 +172,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,17 +2,37 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_deprecated_fields;
 pub mod no_duplicated_fields;
+pub mod no_invalid_argument_values;
+pub mod no_unknown_fields;
+pub mod no_unused_fragments;
 pub mod use_deprecated_reason;
+pub mod use_fragment_name_convention;
+pub mod use_max_query_depth;
 pub mod use_named_operation;
+pub mod use_operation_naming_convention;
+pub mod use_required_arguments;
+pub mod use_sorted_schema_fields;
+pub mod use_typename_on_abstract_types;
 
 declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_deprecated_fields :: NoDeprecatedFields ,
             self :: no_duplicated_fields :: NoDuplicatedFields ,
+            self :: no_invalid_argument_values :: NoInvalidArgumentValues ,
+            self :: no_unknown_fields :: NoUnknownFields ,
+            self :: no_unused_fragments :: NoUnusedFragments ,
             self :: use_deprecated_reason :: UseDeprecatedReason ,
+            self :: use_fragment_name_convention :: UseFragmentNameConvention ,
+            self :: use_max_query_depth :: UseMaxQueryDepth ,
             self :: use_named_operation :: UseNamedOperation ,
+            self :: use_operation_naming_convention :: UseOperationNamingConvention ,
+            self :: use_required_arguments :: UseRequiredArguments ,
+            self :: use_sorted_schema_fields :: UseSortedSchemaFields ,
+            self :: use_typename_on_abstract_types :: UseTypenameOnAbstractTypes ,
         ]
      }
 }
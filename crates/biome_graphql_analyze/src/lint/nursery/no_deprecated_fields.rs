@@ -0,0 +1,114 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, TextRange};
+
+use crate::services::schema::Schema;
+
+declare_lint_rule! {
+    /// Disallow selecting fields that are deprecated in the schema.
+    ///
+    /// A field marked with the `@deprecated` directive is scheduled for removal.
+    /// Operations that still select it will break once the schema drops it.
+    ///
+    /// This rule needs the schema of the project.
+    /// Set `graphql.linter.schema` to the path of the SDL file or of the introspection result that describes it:
+    /// the rule doesn't report anything when no schema is configured.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user: User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    ///   fullName: String @deprecated(reason: "Use `name` instead.")
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user {
+    ///     fullName
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoDeprecatedFields {
+        version: "next",
+        name: "noDeprecatedFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-deprecated")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    range: TextRange,
+    name: String,
+    reason: Option<String>,
+}
+
+impl Rule for NoDeprecatedFields {
+    type Query = Schema<GraphqlField>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let field = ctx.query();
+        let schema = ctx.schema()?;
+        let deprecation = schema.field_definition(field)?.deprecation.as_ref()?;
+        let name = field.name().ok()?;
+        Some(RuleState {
+            range: name.range(),
+            name: name.value_token().ok()?.text_trimmed().to_string(),
+            reason: deprecation.reason.clone(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            name,
+            reason,
+        } = state;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            *range,
+            markup! {
+                "The field "<Emphasis>{name}</Emphasis>" is deprecated."
+            },
+        );
+        let diagnostic = match reason {
+            Some(reason) => diagnostic.note(markup! {
+                "The schema gives the following reason: "{reason}
+            }),
+            None => diagnostic.note(markup! {
+                "The schema doesn't give a reason for the deprecation."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "Deprecated fields can be removed from the schema at any time."
+        }))
+    }
+}
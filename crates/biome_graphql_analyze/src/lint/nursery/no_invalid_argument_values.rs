@@ -0,0 +1,168 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlArgument, GraphqlField};
+use biome_rowan::AstNode;
+
+use crate::services::schema::{InvalidValue, Schema};
+
+declare_lint_rule! {
+    /// Disallow argument values that don't match the type of their argument in the schema.
+    ///
+    /// The server rejects an operation when the value of one of its arguments
+    /// can't be coerced to the type of the argument, for example a string given to an `Int`.
+    ///
+    /// This rule checks the values of the arguments of fields:
+    /// - scalar values must match the built-in scalars `Int`, `Float`, `String`, `Boolean`, and `ID`;
+    /// - enum values must be values of the enum type;
+    /// - `null` can't be given to a non-null type;
+    /// - object values must only have the fields of the input object type, and all its required fields.
+    ///
+    /// Variables aren't checked, since their value is only known when the operation is executed.
+    /// Custom scalars accept any value.
+    ///
+    /// Like the other rules that check documents against the schema,
+    /// it only runs when `graphql.linter.schema` is set.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   users(first: Int, filter: UserFilter): [User!]!
+    /// }
+    ///
+    /// input UserFilter {
+    ///   role: Role!
+    /// }
+    ///
+    /// enum Role {
+    ///   ADMIN
+    ///   MEMBER
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql
+    /// query {
+    ///   users(first: "ten") {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query {
+    ///   users(filter: { role: OWNER }) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query ($first: Int) {
+    ///   users(first: $first, filter: { role: ADMIN }) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidArgumentValues {
+        version: "next",
+        name: "noInvalidArgumentValues",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("values-of-correct-type")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    error: InvalidValue,
+    argument: String,
+    field: String,
+    /// The type of the argument
+    ty: String,
+}
+
+impl Rule for NoInvalidArgumentValues {
+    type Query = Schema<GraphqlArgument>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let argument = ctx.query();
+        let schema = ctx.schema()?;
+        // argument -> argument list -> arguments -> field
+        let field = GraphqlField::cast(argument.syntax().grand_parent()?.parent()?)?;
+        let definition = schema.field_definition(&field)?;
+        let name = argument.name().ok()?.value_token().ok()?;
+        let argument_definition = definition.argument(name.text_trimmed())?;
+
+        let error = schema
+            .check_value(&argument.value().ok()?, &argument_definition.ty)
+            .err()?;
+        Some(RuleState {
+            error,
+            argument: name.text_trimmed().to_string(),
+            field: field.name().ok()?.syntax().text_trimmed().to_string(),
+            ty: argument_definition.ty.to_string(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            error,
+            argument,
+            field,
+            ty,
+        } = state;
+        let diagnostic = match error {
+            InvalidValue::WrongType { range, expected } => {
+                let expected = expected.to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    *range,
+                    markup! {
+                        "This value doesn't match the type "<Emphasis>{expected}</Emphasis>"."
+                    },
+                )
+            }
+            InvalidValue::UnknownField {
+                range,
+                name,
+                input_type,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The input type "<Emphasis>{input_type}</Emphasis>" doesn't have a field "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+            InvalidValue::MissingField {
+                range,
+                name,
+                input_type,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This value is missing the required field "<Emphasis>{name}</Emphasis>" of the input type "<Emphasis>{input_type}</Emphasis>"."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "The argument "<Emphasis>{argument}</Emphasis>" of the field "<Emphasis>{field}</Emphasis>" has the type "<Emphasis>{ty}</Emphasis>", so the server rejects this value."
+        }))
+    }
+}
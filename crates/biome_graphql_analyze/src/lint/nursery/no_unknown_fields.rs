@@ -0,0 +1,131 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, TextRange};
+
+use crate::services::schema::{Schema, SchemaTypeKind};
+
+declare_lint_rule! {
+    /// Disallow selecting fields that don't exist in the schema.
+    ///
+    /// Selecting a field that isn't defined by the type of its selection set is an error
+    /// that the server reports when the operation is executed.
+    /// It's often caused by a typo, or by a field that was removed from the schema.
+    ///
+    /// `__typename` can be selected on any type, and `__schema` and `__type` on the query type.
+    /// The fields of a union type must be selected in inline fragments on its members.
+    ///
+    /// This rule needs the schema of the project, set in `graphql.linter.schema`.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: 1) {
+    ///     nmae
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: 1) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownFields {
+        version: "next",
+        name: "noUnknownFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    range: TextRange,
+    field: String,
+    parent_type: String,
+    parent_kind: SchemaTypeKind,
+}
+
+impl Rule for NoUnknownFields {
+    type Query = Schema<GraphqlField>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let field = ctx.query();
+        let schema = ctx.schema()?;
+        let parent_type = schema.field_parent_type(field)?;
+        let parent_kind = schema.get_type(parent_type)?.kind;
+        // Scalars and enums don't have fields to check the selection against
+        if !matches!(
+            parent_kind,
+            SchemaTypeKind::Object | SchemaTypeKind::Interface | SchemaTypeKind::Union
+        ) {
+            return None;
+        }
+
+        let name = field.name().ok()?;
+        let name_token = name.value_token().ok()?;
+        if schema.has_field(parent_type, name_token.text_trimmed()) {
+            return None;
+        }
+
+        Some(RuleState {
+            range: name.range(),
+            field: name_token.text_trimmed().to_string(),
+            parent_type: parent_type.to_string(),
+            parent_kind,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            field,
+            parent_type,
+            parent_kind,
+        } = state;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            *range,
+            markup! {
+                "The field "<Emphasis>{field}</Emphasis>" doesn't exist on the type "<Emphasis>{parent_type}</Emphasis>"."
+            },
+        );
+        let diagnostic = if *parent_kind == SchemaTypeKind::Union {
+            diagnostic.note(markup! {
+                "A union doesn't have fields. Select the fields of its members in inline fragments, such as "<Emphasis>"... on Member { "{field}" }"</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Check the spelling of the field, or remove it from the selection."
+            })
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,158 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, GraphqlFragmentSpread, GraphqlRoot, GraphqlSyntaxNode,
+};
+use biome_rowan::{AstNode, SyntaxNodeCast, TextRange, TokenText};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+declare_lint_rule! {
+    /// Disallow fragments that aren't used by any operation.
+    ///
+    /// Fragment definitions ship to the clients with the rest of the document,
+    /// even when no operation selects them.
+    ///
+    /// A fragment is used when an operation of the document spreads it,
+    /// either directly or through another fragment.
+    /// Documents that don't contain any operation are ignored:
+    /// their fragments are meant to be spread by other documents.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query GetUser {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    ///
+    /// fragment UserFields on User {
+    ///   name
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetUser {
+    ///   user {
+    ///     ...UserFields
+    ///   }
+    /// }
+    ///
+    /// fragment UserFields on User {
+    ///   name
+    ///   ...UserAvatar
+    /// }
+    ///
+    /// fragment UserAvatar on User {
+    ///   avatar
+    /// }
+    /// ```
+    ///
+    pub NoUnusedFragments {
+        version: "next",
+        name: "noUnusedFragments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-unused-fragments")],
+        source_kind: RuleSourceKind::SameLogic,
+        recommended: false,
+    }
+}
+
+pub struct UnusedFragment {
+    name: TokenText,
+    range: TextRange,
+}
+
+impl Rule for NoUnusedFragments {
+    type Query = Ast<GraphqlRoot>;
+    type State = UnusedFragment;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+
+        // The fragments defined in the document, with the fragments they spread
+        let mut fragments = FxHashMap::default();
+        // The fragments spread by the operations of the document
+        let mut used = Vec::new();
+        let mut has_operation = false;
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                    let Ok(name) = fragment.name().and_then(|name| name.value_token()) else {
+                        continue;
+                    };
+                    fragments.insert(
+                        name.token_text_trimmed(),
+                        (name, spreads(fragment.syntax())),
+                    );
+                }
+                AnyGraphqlDefinition::GraphqlOperationDefinition(_)
+                | AnyGraphqlDefinition::GraphqlSelectionSet(_) => {
+                    has_operation = true;
+                    used.extend(spreads(definition.syntax()));
+                }
+                _ => {}
+            }
+        }
+        if !has_operation {
+            return Box::default();
+        }
+
+        let mut reachable = FxHashSet::default();
+        while let Some(name) = used.pop() {
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+            if let Some((_, fragment_spreads)) = fragments.get(&name) {
+                used.extend(fragment_spreads.iter().cloned());
+            }
+        }
+
+        let mut unused: Vec<_> = fragments
+            .into_iter()
+            .filter(|(name, _)| !reachable.contains(name))
+            .map(|(name, (token, _))| UnusedFragment {
+                name,
+                range: token.text_trimmed_range(),
+            })
+            .collect();
+        unused.sort_unstable_by_key(|fragment| fragment.range.start());
+        unused.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnusedFragment { name, range } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The fragment "<Emphasis>{name.text()}</Emphasis>" isn't used by any operation."
+                },
+            )
+            .note(markup! {
+                "Unused fragments still ship to the clients, even though no operation selects them."
+            })
+            .note(markup! {
+                "Spread the fragment in an operation, or remove it."
+            }),
+        )
+    }
+}
+
+/// Returns the names of the fragments spread inside `node`
+fn spreads(node: &GraphqlSyntaxNode) -> Vec<TokenText> {
+    node.descendants()
+        .filter_map(|node| node.cast::<GraphqlFragmentSpread>())
+        .filter_map(|spread| spread.name().ok()?.value_token().ok())
+        .map(|name| name.token_text_trimmed())
+        .collect()
+}
@@ -0,0 +1,171 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_syntax::{GraphqlFragmentDefinition, GraphqlSyntaxToken};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a naming convention for GraphQL fragments.
+    ///
+    /// In large codebases, fragments are easier to find when their names are derived from the type
+    /// they select and from the file that defines them.
+    ///
+    /// The names of fragments must match a pattern, in which:
+    /// - `{TypeCondition}` stands for the type condition of the fragment, the `User` of `on User`;
+    /// - `{FileName}` stands for the name of the file, without its extensions;
+    /// - `*` stands for any sequence of characters, including an empty one.
+    ///
+    /// By default, the names of fragments must start with their type condition: `{TypeCondition}*`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment Fields on User {
+    ///   id
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// fragment UserFields on User {
+    ///   id
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the option described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "pattern": "{TypeCondition}Fields"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### pattern
+    ///
+    /// The pattern that the names of fragments must match. It defaults to `{TypeCondition}*`.
+    ///
+    /// With the option above, the fragments on `User` must be named `UserFields`.
+    /// With `"pattern": "{FileName}"`, the fragment of `UserFields.graphql` must be named `UserFields`.
+    ///
+    pub UseFragmentNameConvention {
+        version: "next",
+        name: "useFragmentNameConvention",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("match-document-filename")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    name: GraphqlSyntaxToken,
+    /// The pattern, where the placeholders are replaced by their value
+    pattern: String,
+}
+
+impl Rule for UseFragmentNameConvention {
+    type Query = Ast<GraphqlFragmentDefinition>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = FragmentNameConventionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?.value_token().ok()?;
+        let type_condition = node.type_condition().ok()?.ty().ok()?.value_token().ok()?;
+        let file_name = ctx
+            .file_path()
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.split('.').next())
+            .unwrap_or_default();
+
+        let pattern = ctx
+            .options()
+            .pattern
+            .replace("{TypeCondition}", type_condition.text_trimmed())
+            .replace("{FileName}", file_name);
+        if matches_pattern(name.text_trimmed(), &pattern) {
+            None
+        } else {
+            Some(RuleState { name, pattern })
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState { name, pattern } = state;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            name.text_trimmed_range(),
+            markup! {
+                "The name of this fragment doesn't match the pattern "<Emphasis>{pattern}</Emphasis>"."
+            },
+        );
+        let diagnostic = if pattern.contains('*') {
+            diagnostic.note(markup! {
+                "A "<Emphasis>"*"</Emphasis>" of the pattern stands for any sequence of characters."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Consider renaming it to "<Emphasis>{pattern}</Emphasis>"."
+            })
+        };
+        Some(diagnostic.note(markup! {
+            "Fragments named after their type and their file are easier to find in large codebases."
+        }))
+    }
+}
+
+/// Returns `true` if `name` matches `pattern`, in which `*` stands for any sequence of characters
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(rest) = parts.next().and_then(|prefix| name.strip_prefix(prefix)) else {
+        return false;
+    };
+    let parts: Vec<_> = parts.collect();
+    let Some((suffix, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    let mut rest = rest;
+    for part in middle {
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(suffix)
+}
+
+/// Options for the rule `useFragmentNameConvention`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FragmentNameConventionOptions {
+    /// The pattern that the names of fragments must match.
+    /// `{TypeCondition}` stands for the type condition of the fragment,
+    /// `{FileName}` for the name of the file, and `*` for any sequence of characters.
+    #[serde(default = "default_pattern")]
+    pub pattern: String,
+}
+
+fn default_pattern() -> String {
+    "{TypeCondition}*".to_string()
+}
+
+impl Default for FragmentNameConventionOptions {
+    fn default() -> Self {
+        Self {
+            pattern: default_pattern(),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::num::NonZeroU8;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlDefinitionList, GraphqlFragmentDefinition,
+    GraphqlOperationDefinition, GraphqlRoot, GraphqlSelectionSet,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Enforce a maximum depth for the selections of operations.
+    ///
+    /// Every level of nesting of an operation can multiply the number of objects that the server resolves.
+    /// Deep operations are expensive to execute and are often written by accident.
+    ///
+    /// The depth of an operation is the maximum number of fields nested in each other:
+    /// `{ user { name } }` has a depth of 2.
+    /// Inline fragments don't increase the depth,
+    /// and the fields of the fragments spread in the operation are counted
+    /// when the fragments are defined in the same document.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query GetThread {
+    ///   thread {
+    ///     posts {
+    ///       author {
+    ///         friends {
+    ///           posts {
+    ///             comments {
+    ///               author {
+    ///                 name
+    ///               }
+    ///             }
+    ///           }
+    ///         }
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetThread {
+    ///   thread {
+    ///     posts {
+    ///       title
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the options described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "maxDepth": 5
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### maxDepth
+    ///
+    /// The maximum depth allowed for an operation.
+    /// The allowed values range from 1 through 255. The default is 7.
+    ///
+    pub UseMaxQueryDepth {
+        version: "next",
+        name: "useMaxQueryDepth",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("selection-set-depth")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    /// An operation definition, or a query shorthand such as `{ user }`
+    pub AnyGraphqlOperation = GraphqlOperationDefinition | GraphqlSelectionSet
+}
+
+pub struct RuleState {
+    /// Range of the name of the operation, or of its type when it's anonymous
+    range: TextRange,
+    depth: usize,
+    /// Range of the name of the deepest field
+    deepest_field: TextRange,
+}
+
+impl Rule for UseMaxQueryDepth {
+    type Query = Ast<AnyGraphqlOperation>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = MaxQueryDepthOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (range, selection_set) = match ctx.query() {
+            AnyGraphqlOperation::GraphqlOperationDefinition(operation) => {
+                let range = match operation.name() {
+                    Some(name) => name.range(),
+                    None => operation.ty().ok()?.range(),
+                };
+                (range, operation.selection_set().ok()?)
+            }
+            AnyGraphqlOperation::GraphqlSelectionSet(selection_set) => {
+                // Only the selection sets of query shorthands are operations
+                if !GraphqlDefinitionList::can_cast(selection_set.syntax().parent()?.kind()) {
+                    return None;
+                }
+                let range = selection_set.l_curly_token().ok()?.text_trimmed_range();
+                (range, selection_set.clone())
+            }
+        };
+
+        let root = ctx.root();
+        let fragments = fragments(&root);
+        let (depth, deepest_field) =
+            selection_set_depth(&selection_set, &fragments, &mut FxHashSet::default())?;
+        if depth <= usize::from(ctx.options().max_depth.get()) {
+            return None;
+        }
+
+        Some(RuleState {
+            range,
+            depth,
+            deepest_field,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            depth,
+            deepest_field,
+        } = state;
+        let max_depth = ctx.options().max_depth.get();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This operation has a depth of "{depth}", which exceeds the maximum depth of "{max_depth}"."
+                },
+            )
+            .detail(*deepest_field, markup! { "This is the deepest field of the operation." })
+            .note(markup! {
+                "Deep operations are expensive to execute. Consider selecting fewer nested fields, or splitting the operation."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `useMaxQueryDepth`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct MaxQueryDepthOptions {
+    /// The maximum depth allowed for an operation.
+    pub max_depth: NonZeroU8,
+}
+
+impl Default for MaxQueryDepthOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: NonZeroU8::new(7).unwrap(),
+        }
+    }
+}
+
+/// Returns the fragments defined in `root`, by name
+fn fragments(root: &GraphqlRoot) -> FxHashMap<String, GraphqlFragmentDefinition> {
+    root.definitions()
+        .into_iter()
+        .filter_map(|definition| match definition {
+            AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                let name = fragment.name().ok()?.value_token().ok()?;
+                Some((name.text_trimmed().to_string(), fragment))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the depth of `selection_set`, with the range of the name of its deepest field.
+///
+/// `visiting` holds the fragments being spread, so that cyclic spreads terminate.
+fn selection_set_depth(
+    selection_set: &GraphqlSelectionSet,
+    fragments: &FxHashMap<String, GraphqlFragmentDefinition>,
+    visiting: &mut FxHashSet<String>,
+) -> Option<(usize, TextRange)> {
+    let mut deepest: Option<(usize, TextRange)> = None;
+    for selection in selection_set.selections() {
+        let depth = match selection {
+            AnyGraphqlSelection::GraphqlField(field) => {
+                let Ok(name) = field.name() else {
+                    continue;
+                };
+                let nested = field.selection_set().and_then(|selection_set| {
+                    selection_set_depth(&selection_set, fragments, visiting)
+                });
+                match nested {
+                    Some((depth, deepest_field)) => Some((depth + 1, deepest_field)),
+                    None => Some((1, name.range())),
+                }
+            }
+            AnyGraphqlSelection::GraphqlInlineFragment(fragment) => fragment
+                .selection_set()
+                .ok()
+                .and_then(|selection_set| selection_set_depth(&selection_set, fragments, visiting)),
+            AnyGraphqlSelection::GraphqlFragmentSpread(spread) => {
+                let Ok(name) = spread.name().and_then(|name| name.value_token()) else {
+                    continue;
+                };
+                let name = name.text_trimmed();
+                let Some(fragment) = fragments.get(name) else {
+                    continue;
+                };
+                if !visiting.insert(name.to_string()) {
+                    continue;
+                }
+                let depth = fragment.selection_set().ok().and_then(|selection_set| {
+                    selection_set_depth(&selection_set, fragments, visiting)
+                });
+                visiting.remove(name);
+                depth
+            }
+            AnyGraphqlSelection::GraphqlBogusSelection(_) => None,
+        };
+        if let Some((depth, field)) = depth {
+            if deepest.map_or(true, |(deepest_depth, _)| depth > deepest_depth) {
+                deepest = Some((depth, field));
+            }
+        }
+    }
+    deepest
+}
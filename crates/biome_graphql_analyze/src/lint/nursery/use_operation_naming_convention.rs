@@ -0,0 +1,265 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_factory::make;
+use biome_graphql_syntax::{GraphqlOperationDefinition, GraphqlSyntaxToken};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange};
+use biome_string_case::{Case, Cases};
+use serde::{Deserialize, Serialize};
+
+use crate::GraphqlRuleAction;
+
+declare_lint_rule! {
+    /// Enforce a naming convention for GraphQL operations.
+    ///
+    /// Consistent operation names make operations easier to find in the codebase,
+    /// in the logs of the server, and in the developer tools of GraphQL clients.
+    ///
+    /// This rule reports:
+    /// - anonymous operations;
+    /// - operation names that aren't in the configured case, _PascalCase_ by default;
+    /// - operation names that don't end with the type of the operation:
+    ///   `Query` for queries, `Mutation` for mutations, and `Subscription` for subscriptions.
+    ///
+    /// The code action appends the missing suffix to the name of the operation.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query getUserQuery {
+    ///   user
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// mutation UpdateUser {
+    ///   updateUser
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetUserQuery {
+    ///   user
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// subscription OnUserUpdatedSubscription {
+    ///   userUpdated
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the options described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "case": "camelCase",
+    ///         "requireSuffix": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### case
+    ///
+    /// The case that the names of operations must follow:
+    /// `"PascalCase"` (default) or `"camelCase"`.
+    ///
+    /// ### requireSuffix
+    ///
+    /// If `true` (default), the name of an operation must end with the type of the operation.
+    ///
+    pub UseOperationNamingConvention {
+        version: "next",
+        name: "useOperationNamingConvention",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("naming-convention")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub enum RuleState {
+    /// The operation doesn't have a name
+    Anonymous { range: TextRange },
+    /// The name of the operation isn't in the expected case
+    WrongCase { name: GraphqlSyntaxToken },
+    /// The name of the operation doesn't end with the expected suffix
+    MissingSuffix {
+        name: GraphqlSyntaxToken,
+        suffix: &'static str,
+    },
+}
+
+impl Rule for UseOperationNamingConvention {
+    type Query = Ast<GraphqlOperationDefinition>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = OperationNamingConventionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let operation_type = node.ty().ok()?;
+        let Some(name) = node.name() else {
+            return Some(RuleState::Anonymous {
+                range: operation_type.range(),
+            });
+        };
+        let name = name.value_token().ok()?;
+        let text = name.text_trimmed();
+
+        let expected_case = Case::from(options.case);
+        if !Cases::from(expected_case).contains(Case::identify(text, false)) {
+            return Some(RuleState::WrongCase { name });
+        }
+
+        if options.require_suffix {
+            let suffix = operation_suffix(operation_type.text().as_str())?;
+            if !text.ends_with(suffix) {
+                return Some(RuleState::MissingSuffix { name, suffix });
+            }
+        }
+
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let operation_type = ctx.query().ty().ok()?.text();
+        let diagnostic = match state {
+            RuleState::Anonymous { range } => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This "{operation_type}" doesn't have a name."
+                },
+            )
+            .note(markup! {
+                "Named operations are easier to find in the logs of the server and in the developer tools of GraphQL clients."
+            }),
+            RuleState::WrongCase { name } => {
+                let expected_case = Case::from(ctx.options().case);
+                let text = name.text_trimmed();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    name.text_trimmed_range(),
+                    markup! {
+                        "The name of this "{operation_type}" isn't in "<Emphasis>{expected_case.to_string()}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "Consider renaming it to "<Emphasis>{expected_case.convert(text)}</Emphasis>"."
+                })
+            }
+            RuleState::MissingSuffix { name, suffix } => RuleDiagnostic::new(
+                rule_category!(),
+                name.text_trimmed_range(),
+                markup! {
+                    "The name of this "{operation_type}" doesn't end with "<Emphasis>{suffix}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The suffix tells the type of the operation apart when it's referenced in the code."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<GraphqlRuleAction> {
+        let RuleState::MissingSuffix { name, suffix } = state else {
+            return None;
+        };
+        let new_name = format!("{}{suffix}", name.text_trimmed());
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(name.clone(), make::ident(&new_name));
+
+        Some(GraphqlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! {
+                "Rename the operation to "<Emphasis>{new_name}</Emphasis>"."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the suffix expected at the end of the name of operations of type `operation_type`
+fn operation_suffix(operation_type: &str) -> Option<&'static str> {
+    match operation_type {
+        "query" => Some("Query"),
+        "mutation" => Some("Mutation"),
+        "subscription" => Some("Subscription"),
+        _ => None,
+    }
+}
+
+/// Options for the rule `useOperationNamingConvention`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OperationNamingConventionOptions {
+    /// The case that the names of operations must follow.
+    #[serde(default)]
+    pub case: OperationNameCase,
+
+    /// If `true`, the name of an operation must end with the type of the operation,
+    /// such as `Query` for queries.
+    #[serde(default = "enabled")]
+    pub require_suffix: bool,
+}
+
+const fn enabled() -> bool {
+    true
+}
+
+impl Default for OperationNamingConventionOptions {
+    fn default() -> Self {
+        Self {
+            case: OperationNameCase::default(),
+            require_suffix: true,
+        }
+    }
+}
+
+/// Supported cases for operation names.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, Hash, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum OperationNameCase {
+    /// camelCase
+    #[serde(rename = "camelCase")]
+    Camel,
+
+    /// PascalCase
+    #[default]
+    #[serde(rename = "PascalCase")]
+    Pascal,
+}
+
+impl From<OperationNameCase> for Case {
+    fn from(case: OperationNameCase) -> Self {
+        match case {
+            OperationNameCase::Camel => Self::Camel,
+            OperationNameCase::Pascal => Self::Pascal,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+
+use crate::services::schema::Schema;
+
+declare_lint_rule! {
+    /// Require the arguments of fields that don't have a default value and can't be null.
+    ///
+    /// An argument with a non-null type and no default value must be given a value
+    /// whenever its field is selected. Otherwise, the server rejects the operation.
+    ///
+    /// The arguments with a default value, and the arguments that can be null, can be omitted.
+    ///
+    /// The rule doesn't report anything when `graphql.linter.schema` isn't set.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    ///   users(first: Int! = 10, after: String): [User!]!
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: 1) {
+    ///     name
+    ///   }
+    ///   users {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseRequiredArguments {
+        version: "next",
+        name: "useRequiredArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("provided-required-arguments")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    /// Range of the name of the field
+    range: TextRange,
+    field: String,
+    argument: String,
+    /// The type of the argument
+    ty: String,
+}
+
+impl Rule for UseRequiredArguments {
+    type Query = Schema<GraphqlField>;
+    type State = RuleState;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let field = ctx.query();
+        let Some(definition) = ctx
+            .schema()
+            .and_then(|schema| schema.field_definition(field))
+        else {
+            return Box::default();
+        };
+        let Ok(name) = field.name() else {
+            return Box::default();
+        };
+
+        let provided: Vec<_> = field
+            .arguments()
+            .into_iter()
+            .flat_map(|arguments| arguments.arguments().iter())
+            .filter_map(|argument| argument.name().ok()?.value_token().ok())
+            .collect();
+        definition
+            .arguments
+            .iter()
+            .filter(|argument| {
+                argument.is_required()
+                    && !provided
+                        .iter()
+                        .any(|name| name.text_trimmed() == argument.name)
+            })
+            .map(|argument| RuleState {
+                range: name.range(),
+                field: name.syntax().text_trimmed().to_string(),
+                argument: argument.name.clone(),
+                ty: argument.ty.to_string(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            field,
+            argument,
+            ty,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The required argument "<Emphasis>{argument}</Emphasis>" of the field "<Emphasis>{field}</Emphasis>" is missing."
+                },
+            )
+            .note(markup! {
+                "The argument has the type "<Emphasis>{ty}</Emphasis>" and no default value, so the server rejects the operation without it."
+            })
+            .note(markup! {
+                "Provide a value for "<Emphasis>{argument}</Emphasis>"."
+            }),
+        )
+    }
+}
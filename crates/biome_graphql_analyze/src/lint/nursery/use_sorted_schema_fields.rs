@@ -0,0 +1,285 @@
+use std::cmp::Ordering;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_factory::make;
+use biome_graphql_syntax::{
+    GraphqlEnumValueDefinition, GraphqlEnumValuesDefinition, GraphqlFieldDefinition,
+    GraphqlFieldsDefinition, GraphqlInputFieldsDefinition, GraphqlInputValueDefinition,
+    GraphqlNameBinding, GraphqlNameReference, GraphqlSyntaxNode, GraphqlSyntaxToken,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+use crate::GraphqlRuleAction;
+
+declare_lint_rule! {
+    /// Enforce sorting the fields of types, the fields of input types, and the values of enums.
+    ///
+    /// Sorted fields make the schema easier to navigate.
+    /// They also keep the schema deterministic:
+    /// two schemas with the same fields are printed the same,
+    /// and a new field doesn't conflict with the other changes to the type.
+    ///
+    /// The fields are sorted in natural order, in which numbers are compared by their value.
+    /// The fields of the extensions of a type are sorted on their own.
+    ///
+    /// The fix moves the fields with their description, their directives, and their comments.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type User {
+    ///   name: String
+    ///   id: ID!
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// enum Role {
+    ///   MEMBER
+    ///   ADMIN
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type User {
+    ///   email: String
+    ///   id: ID!
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// input UserFilter {
+    ///   name: String
+    ///   role: Role
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the option described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "groups": ["id", "*", "createdAt", "updatedAt"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### groups
+    ///
+    /// The names placed before or after the other names, in the given order.
+    /// `"*"` stands for the names that aren't in the list, which are sorted in natural order.
+    /// When the list doesn't contain `"*"`, the other names are placed after the names of the list.
+    ///
+    /// With the options above, `id` is the first field of the types,
+    /// and `createdAt` and `updatedAt` are their last fields:
+    ///
+    /// ```graphql
+    /// type User {
+    ///   id: ID!
+    ///   email: String
+    ///   name: String
+    ///   createdAt: String
+    ///   updatedAt: String
+    /// }
+    /// ```
+    ///
+    pub UseSortedSchemaFields {
+        version: "next",
+        name: "useSortedSchemaFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("alphabetize")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    /// The fields of a type or an input type, or the values of an enum
+    pub AnyGraphqlSchemaMembers = GraphqlFieldsDefinition | GraphqlInputFieldsDefinition | GraphqlEnumValuesDefinition
+}
+
+impl AnyGraphqlSchemaMembers {
+    /// Returns the members of the list with their name, or `None` if a name is missing
+    fn members(&self) -> Option<Vec<(GraphqlSyntaxToken, GraphqlSyntaxNode)>> {
+        match self {
+            Self::GraphqlFieldsDefinition(definition) => definition
+                .fields()
+                .iter()
+                .map(|field| Some((field.name().ok()?.value_token().ok()?, field.into_syntax())))
+                .collect(),
+            Self::GraphqlInputFieldsDefinition(definition) => definition
+                .fields()
+                .iter()
+                .map(|field| Some((field.name().ok()?.value_token().ok()?, field.into_syntax())))
+                .collect(),
+            Self::GraphqlEnumValuesDefinition(definition) => definition
+                .values()
+                .iter()
+                .map(|value| Some((value.value().ok()?.value_token().ok()?, value.into_syntax())))
+                .collect(),
+        }
+    }
+
+    /// Returns the same list, made of `members`
+    fn with_members(&self, members: impl ExactSizeIterator<Item = GraphqlSyntaxNode>) -> Self {
+        match self {
+            Self::GraphqlFieldsDefinition(definition) => {
+                let fields = members.map(GraphqlFieldDefinition::unwrap_cast);
+                definition
+                    .clone()
+                    .with_fields(make::graphql_field_definition_list(fields))
+                    .into()
+            }
+            Self::GraphqlInputFieldsDefinition(definition) => {
+                let fields = members.map(GraphqlInputValueDefinition::unwrap_cast);
+                definition
+                    .clone()
+                    .with_fields(make::graphql_input_field_list(fields))
+                    .into()
+            }
+            Self::GraphqlEnumValuesDefinition(definition) => {
+                let values = members.map(GraphqlEnumValueDefinition::unwrap_cast);
+                definition
+                    .clone()
+                    .with_values(make::graphql_enum_value_list(values))
+                    .into()
+            }
+        }
+    }
+
+    /// Returns how the members of the list are called in the diagnostics
+    const fn members_description(&self) -> &'static str {
+        match self {
+            Self::GraphqlFieldsDefinition(_) => "fields",
+            Self::GraphqlInputFieldsDefinition(_) => "input fields",
+            Self::GraphqlEnumValuesDefinition(_) => "values",
+        }
+    }
+
+    /// Returns the name of the type, or of the type extension, that declares the list
+    fn type_name(&self) -> Option<String> {
+        // fields definition -> type definition or extension
+        let definition = self.syntax().parent()?;
+        let name = definition.children().find(|child| {
+            GraphqlNameBinding::can_cast(child.kind())
+                || GraphqlNameReference::can_cast(child.kind())
+        })?;
+        Some(name.text_trimmed().to_string())
+    }
+}
+
+pub struct RuleState {
+    /// Name of the first member that must be placed before the member that precedes it
+    name: GraphqlSyntaxToken,
+    /// Name of the member that precedes it
+    previous: GraphqlSyntaxToken,
+}
+
+impl Rule for UseSortedSchemaFields {
+    type Query = Ast<AnyGraphqlSchemaMembers>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = SortedSchemaFieldsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let groups = &ctx.options().groups;
+        let members = ctx.query().members()?;
+        members.windows(2).find_map(|pair| {
+            let [(previous, _), (name, _)] = pair else {
+                return None;
+            };
+            let ordering = compare_names(groups, previous.text_trimmed(), name.text_trimmed());
+            (ordering == Ordering::Greater).then(|| RuleState {
+                name: name.clone(),
+                previous: previous.clone(),
+            })
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let list = ctx.query();
+        let members = list.members_description();
+        let type_name = list.type_name()?;
+        let name = state.name.text_trimmed();
+        let previous = state.previous.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.name.text_trimmed_range(),
+                markup! {
+                    "The "{members}" of "<Emphasis>{type_name}</Emphasis>" aren't sorted."
+                },
+            )
+            .note(markup! {
+                <Emphasis>{name}</Emphasis>" should be placed before "<Emphasis>{previous}</Emphasis>"."
+            })
+            .note(markup! {
+                "Sorted "{members}" make the schema easier to navigate, and its changes easier to review."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<GraphqlRuleAction> {
+        let list = ctx.query();
+        let groups = &ctx.options().groups;
+        let mut members = list.members()?;
+        // The sort is stable: members with the same name keep their order
+        members.sort_by(|(a, _), (b, _)| compare_names(groups, a.text_trimmed(), b.text_trimmed()));
+        let sorted_list = list.with_members(members.into_iter().map(|(_, member)| member.detach()));
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(list.clone(), sorted_list);
+        Some(GraphqlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! {
+                "Sort the "{list.members_description()}"."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// Compares the names `a` and `b` by their group, then in natural order.
+fn compare_names(groups: &[Box<str>], a: &str, b: &str) -> Ordering {
+    group_index(groups, a)
+        .cmp(&group_index(groups, b))
+        .then_with(|| natord::compare(a, b))
+}
+
+/// Returns the position of `name` in `groups`.
+///
+/// The names that aren't in `groups` are at the position of `"*"`, or after all the groups.
+fn group_index(groups: &[Box<str>], name: &str) -> usize {
+    groups
+        .iter()
+        .position(|group| group.as_ref() == name)
+        .or_else(|| groups.iter().position(|group| group.as_ref() == "*"))
+        .unwrap_or(groups.len())
+}
+
+/// Options for the rule `useSortedSchemaFields`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct SortedSchemaFieldsOptions {
+    /// The names placed before or after the other names, in the given order.
+    /// `"*"` stands for the names that aren't in the list.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub groups: Box<[Box<str>]>,
+}
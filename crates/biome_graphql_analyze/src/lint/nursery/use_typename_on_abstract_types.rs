@@ -0,0 +1,249 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlSelection, GraphqlField, GraphqlFragmentDefinition,
+    GraphqlInlineFragment, GraphqlRoot, GraphqlSelectionSet,
+};
+use biome_rowan::{AstNode, TextRange};
+
+use crate::services::schema::Schema;
+
+declare_lint_rule! {
+    /// Require selecting `__typename` on interfaces and unions.
+    ///
+    /// The objects returned for an interface or a union can be of several concrete types.
+    /// Many GraphQL clients read `__typename` to tell them apart,
+    /// for example to normalize the objects in their cache or to resolve inline fragments.
+    ///
+    /// This rule reports the selection sets that don't select `__typename`, when:
+    /// - the selection set is on an interface or a union of the schema;
+    /// - the selection set contains inline fragments with a type condition,
+    ///   and its type isn't known to be an object type of the schema.
+    ///
+    /// `__typename` counts when it's selected directly in the selection set,
+    /// or by a fragment of the same document spread in the selection set.
+    ///
+    /// This rule uses the schema of the project when `graphql.linter.schema` is set.
+    /// Without a schema, only the selection sets that contain inline fragments are checked.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   search(term: String!): [SearchResult!]!
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// type Post {
+    ///   title: String
+    /// }
+    ///
+    /// union SearchResult = User | Post
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   search(term: "biome") {
+    ///     ... on User {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   search(term: "biome") {
+    ///     __typename
+    ///     ... on User {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseTypenameOnAbstractTypes {
+        version: "next",
+        name: "useTypenameOnAbstractTypes",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("require-typename")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    range: TextRange,
+    /// The name of the abstract type of the selection set, when the schema knows it
+    abstract_type: Option<String>,
+}
+
+impl Rule for UseTypenameOnAbstractTypes {
+    type Query = Schema<GraphqlSelectionSet>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let selection_set = ctx.query();
+        let parent = selection_set.syntax().parent()?;
+        // The selection set enclosing an inline fragment selects the `__typename` of its objects
+        if GraphqlInlineFragment::can_cast(parent.kind()) {
+            return None;
+        }
+
+        let schema_type = ctx.schema().and_then(|schema| {
+            let name = schema.selection_set_type(selection_set)?;
+            Some((name, schema.get_type(name)?))
+        });
+        let abstract_type = match schema_type {
+            Some((name, schema_type)) if schema_type.is_abstract() => Some(name.to_string()),
+            // The objects of an object type are always of this type
+            Some(_) => return None,
+            None if has_typed_inline_fragment(selection_set) => None,
+            None => return None,
+        };
+
+        if selects_typename(selection_set, &root(selection_set)?) {
+            return None;
+        }
+
+        Some(RuleState {
+            range: selection_set_owner_range(selection_set)?,
+            abstract_type,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            abstract_type,
+        } = state;
+        let diagnostic = match abstract_type {
+            Some(abstract_type) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This selection on the abstract type "<Emphasis>{abstract_type}</Emphasis>" doesn't select "<Emphasis>"__typename"</Emphasis>"."
+                },
+            ),
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This selection contains inline fragments, but doesn't select "<Emphasis>"__typename"</Emphasis>"."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .note(markup! {
+                    "GraphQL clients read "<Emphasis>"__typename"</Emphasis>" to tell apart the concrete types of interfaces and unions, for example to normalize them in their cache."
+                })
+                .note(markup! {
+                    "Add "<Emphasis>"__typename"</Emphasis>" to the selection."
+                }),
+        )
+    }
+}
+
+/// Returns `true` if `selection_set` contains an inline fragment with a type condition
+fn has_typed_inline_fragment(selection_set: &GraphqlSelectionSet) -> bool {
+    selection_set.selections().into_iter().any(|selection| {
+        matches!(
+            selection,
+            AnyGraphqlSelection::GraphqlInlineFragment(fragment)
+                if fragment.type_condition().is_some()
+        )
+    })
+}
+
+/// Returns `true` if `selection_set` selects `__typename` without an alias,
+/// directly or through a fragment defined in `root`.
+fn selects_typename(selection_set: &GraphqlSelectionSet, root: &GraphqlRoot) -> bool {
+    selection_set
+        .selections()
+        .into_iter()
+        .any(|selection| match selection {
+            AnyGraphqlSelection::GraphqlField(field) => is_typename(&field),
+            AnyGraphqlSelection::GraphqlFragmentSpread(spread) => spread
+                .name()
+                .and_then(|name| name.value_token())
+                .ok()
+                .and_then(|name| find_fragment(root, name.text_trimmed()))
+                .and_then(|fragment| fragment.selection_set().ok())
+                .is_some_and(|fragment_selection_set| {
+                    fragment_selection_set
+                        .selections()
+                        .into_iter()
+                        .any(|selection| match selection {
+                            AnyGraphqlSelection::GraphqlField(field) => is_typename(&field),
+                            _ => false,
+                        })
+                }),
+            // `... @include(if: $foo) { __typename }` doesn't always select `__typename`
+            AnyGraphqlSelection::GraphqlInlineFragment(_)
+            | AnyGraphqlSelection::GraphqlBogusSelection(_) => false,
+        })
+}
+
+fn is_typename(field: &GraphqlField) -> bool {
+    field.alias().is_none()
+        && field
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|name| name.text_trimmed() == "__typename")
+}
+
+fn find_fragment(root: &GraphqlRoot, name: &str) -> Option<GraphqlFragmentDefinition> {
+    root.definitions()
+        .into_iter()
+        .find_map(|definition| match definition {
+            AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => fragment
+                .name()
+                .and_then(|fragment_name| fragment_name.value_token())
+                .is_ok_and(|fragment_name| fragment_name.text_trimmed() == name)
+                .then_some(fragment),
+            _ => None,
+        })
+}
+
+fn root(selection_set: &GraphqlSelectionSet) -> Option<GraphqlRoot> {
+    selection_set
+        .syntax()
+        .ancestors()
+        .find_map(GraphqlRoot::cast)
+}
+
+/// Returns the range of the node that owns `selection_set`, up to its selection set
+fn selection_set_owner_range(selection_set: &GraphqlSelectionSet) -> Option<TextRange> {
+    let parent = selection_set.syntax().parent()?;
+    if let Some(field) = GraphqlField::cast_ref(&parent) {
+        let start = match field.alias() {
+            Some(alias) => alias.range(),
+            None => field.name().ok()?.range(),
+        };
+        let end = match field.arguments() {
+            Some(arguments) => arguments.range(),
+            None => field.name().ok()?.range(),
+        };
+        return Some(start.cover(end));
+    }
+    if let Some(fragment) = GraphqlFragmentDefinition::cast_ref(&parent) {
+        let start = fragment.fragment_token().ok()?.text_trimmed_range();
+        let end = fragment.type_condition().ok()?.range();
+        return Some(start.cover(end));
+    }
+    Some(selection_set.l_curly_token().ok()?.text_trimmed_range())
+}
@@ -2,9 +2,24 @@
 
 use crate::lint;
 
+pub type NoDeprecatedFields =
+    <lint::nursery::no_deprecated_fields::NoDeprecatedFields as biome_analyze::Rule>::Options;
 pub type NoDuplicatedFields =
     <lint::nursery::no_duplicated_fields::NoDuplicatedFields as biome_analyze::Rule>::Options;
+pub type NoInvalidArgumentValues = < lint :: nursery :: no_invalid_argument_values :: NoInvalidArgumentValues as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownFields =
+    <lint::nursery::no_unknown_fields::NoUnknownFields as biome_analyze::Rule>::Options;
+pub type NoUnusedFragments =
+    <lint::nursery::no_unused_fragments::NoUnusedFragments as biome_analyze::Rule>::Options;
 pub type UseDeprecatedReason =
     <lint::nursery::use_deprecated_reason::UseDeprecatedReason as biome_analyze::Rule>::Options;
+pub type UseFragmentNameConvention = < lint :: nursery :: use_fragment_name_convention :: UseFragmentNameConvention as biome_analyze :: Rule > :: Options ;
+pub type UseMaxQueryDepth =
+    <lint::nursery::use_max_query_depth::UseMaxQueryDepth as biome_analyze::Rule>::Options;
 pub type UseNamedOperation =
     <lint::nursery::use_named_operation::UseNamedOperation as biome_analyze::Rule>::Options;
+pub type UseOperationNamingConvention = < lint :: nursery :: use_operation_naming_convention :: UseOperationNamingConvention as biome_analyze :: Rule > :: Options ;
+pub type UseRequiredArguments =
+    <lint::nursery::use_required_arguments::UseRequiredArguments as biome_analyze::Rule>::Options;
+pub type UseSortedSchemaFields = < lint :: nursery :: use_sorted_schema_fields :: UseSortedSchemaFields as biome_analyze :: Rule > :: Options ;
+pub type UseTypenameOnAbstractTypes = < lint :: nursery :: use_typename_on_abstract_types :: UseTypenameOnAbstractTypes as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,736 @@
+use biome_analyze::{
+    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
+    RuleKey, ServiceBag, SyntaxVisitor,
+};
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlPrimitiveType, AnyGraphqlType, AnyGraphqlTypeDefinition,
+    AnyGraphqlTypeExtension, AnyGraphqlValue, GraphqlDefinitionList, GraphqlDirectiveList,
+    GraphqlEnumValuesDefinition, GraphqlField, GraphqlFieldsDefinition, GraphqlFragmentDefinition,
+    GraphqlInlineFragment, GraphqlInputFieldsDefinition, GraphqlInputValueDefinition,
+    GraphqlLanguage, GraphqlObjectValue, GraphqlOperationDefinition, GraphqlOperationType,
+    GraphqlRoot, GraphqlRootOperationTypes, GraphqlSelectionSet, GraphqlSyntaxNode,
+    GraphqlUnionMemberTypes, T,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TextRange};
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::sync::Arc;
+
+mod introspection;
+
+/// A model of a GraphQL schema, built from its SDL definitions or from the result of an introspection query.
+///
+/// It only retains what rules need to know about the schema: the root operation types,
+/// the fields and arguments of object and interface types, the members of union types,
+/// the fields of input object types, and the values of enum types.
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    query_type: Option<String>,
+    mutation_type: Option<String>,
+    subscription_type: Option<String>,
+    types: FxHashMap<String, SchemaType>,
+    /// Whether the schema is an Apollo Federation subgraph, which links the federation specification
+    /// or, in Federation 1, declares entities with `@key`
+    federated: bool,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SchemaTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+#[derive(Debug)]
+pub struct SchemaType {
+    pub kind: SchemaTypeKind,
+    fields: FxHashMap<String, SchemaField>,
+    /// The members of a union type
+    members: Vec<String>,
+    /// The fields of an input object type, in their order of definition
+    input_fields: Vec<SchemaInputValue>,
+    /// The values of an enum type
+    values: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SchemaField {
+    /// The type returned by the field
+    pub ty: SchemaTypeRef,
+    /// The arguments of the field, in their order of definition
+    pub arguments: Vec<SchemaInputValue>,
+    /// Set when the field is marked with `@deprecated`
+    pub deprecation: Option<Deprecation>,
+}
+
+/// An argument of a field, or a field of an input object type
+#[derive(Debug)]
+pub struct SchemaInputValue {
+    pub name: String,
+    pub ty: SchemaTypeRef,
+    /// Whether the input has a default value
+    pub has_default: bool,
+}
+
+/// A reference to a type of the schema, wrapped by list and non-null types
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SchemaTypeRef {
+    Named(String),
+    List(Box<SchemaTypeRef>),
+    NonNull(Box<SchemaTypeRef>),
+}
+
+#[derive(Debug)]
+pub struct Deprecation {
+    /// The `reason` argument of the `@deprecated` directive, if any
+    pub reason: Option<String>,
+}
+
+/// The reason why a value can't be given to an input of the schema
+#[derive(Debug)]
+pub enum InvalidValue {
+    /// The value at `range` isn't of the `expected` type
+    WrongType {
+        range: TextRange,
+        expected: SchemaTypeRef,
+    },
+    /// The field `name`, at `range`, isn't a field of the input object type `input_type`
+    UnknownField {
+        range: TextRange,
+        name: String,
+        input_type: String,
+    },
+    /// The object value at `range` doesn't provide the required field `name` of the input object type `input_type`
+    MissingField {
+        range: TextRange,
+        name: String,
+        input_type: String,
+    },
+}
+
+impl GraphqlSchema {
+    /// Builds the schema from the type system definitions and extensions of `root`.
+    ///
+    /// Executable definitions, such as operations and fragments, are ignored.
+    pub fn from_root(root: &GraphqlRoot) -> Self {
+        let mut schema = Self::default();
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    schema.federated |= definition.directives().links_federation();
+                    if let Ok(types) = definition.root_operation_types() {
+                        schema.register_root_operation_types(&types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    schema.federated |= extension.directives().links_federation();
+                    if let Some(types) = extension.root_operation_types() {
+                        schema.register_root_operation_types(&types);
+                    }
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(definition) => {
+                    schema.register_type_definition(&definition);
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(extension) => {
+                    schema.register_type_extension(&extension);
+                }
+                _ => {}
+            }
+        }
+        schema
+    }
+
+    /// Returns the type with the given name.
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// Returns the field `field_name` of the object or interface type `type_name`.
+    pub fn get_field(&self, type_name: &str, field_name: &str) -> Option<&SchemaField> {
+        self.types.get(type_name)?.fields.get(field_name)
+    }
+
+    /// Returns `true` if the field `field_name` can be selected on the type `type_name`.
+    ///
+    /// `__typename` can be selected on any type, and `__schema` and `__type` on the query type.
+    /// The query type of a federated subgraph also has the `_entities` and `_service` fields,
+    /// which the subgraph library adds when it builds the schema.
+    pub fn has_field(&self, type_name: &str, field_name: &str) -> bool {
+        let is_query_type = || self.query_type.as_deref().unwrap_or("Query") == type_name;
+        match field_name {
+            "__typename" => true,
+            "__schema" | "__type" => is_query_type(),
+            _ if self.get_field(type_name, field_name).is_some() => true,
+            "_entities" | "_service" => self.federated && is_query_type(),
+            _ => false,
+        }
+    }
+
+    /// Returns the name of the root type of the given operation type.
+    ///
+    /// When the schema doesn't define the root operation types,
+    /// the default names `Query`, `Mutation`, and `Subscription` are used.
+    pub fn root_type(&self, operation_type: &GraphqlOperationType) -> Option<&str> {
+        let (explicit, default) = match operation_type.value_token().ok()?.kind() {
+            T![query] => (&self.query_type, "Query"),
+            T![mutation] => (&self.mutation_type, "Mutation"),
+            T![subscription] => (&self.subscription_type, "Subscription"),
+            _ => return None,
+        };
+        let name = explicit.as_deref().unwrap_or(default);
+        self.types
+            .get_key_value(name)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the name of the type that the fields of `selection_set` are selected on.
+    pub fn selection_set_type(&self, selection_set: &GraphqlSelectionSet) -> Option<&str> {
+        let parent = selection_set.syntax().parent()?;
+        if let Some(operation) = GraphqlOperationDefinition::cast_ref(&parent) {
+            return self.root_type(&operation.ty().ok()?);
+        }
+        if let Some(field) = GraphqlField::cast_ref(&parent) {
+            let parent_type = self.field_parent_type(&field)?;
+            let name = field.name().ok()?.value_token().ok()?;
+            let field = self.get_field(parent_type, name.text_trimmed())?;
+            return self.type_name(field.ty.named_type());
+        }
+        if let Some(fragment) = GraphqlInlineFragment::cast_ref(&parent) {
+            return match fragment.type_condition() {
+                Some(condition) => {
+                    let name = condition.ty().ok()?.value_token().ok()?;
+                    self.type_name(name.text_trimmed())
+                }
+                // `... @include(if: $foo) { field }` selects on the enclosing type
+                None => self.selection_set_type(&parent_selection_set(fragment.syntax())?),
+            };
+        }
+        if let Some(fragment) = GraphqlFragmentDefinition::cast_ref(&parent) {
+            let name = fragment
+                .type_condition()
+                .ok()?
+                .ty()
+                .ok()?
+                .value_token()
+                .ok()?;
+            return self.type_name(name.text_trimmed());
+        }
+        // Query shorthand: `{ field }`
+        if GraphqlDefinitionList::can_cast(parent.kind()) {
+            return self.type_name(self.query_type.as_deref().unwrap_or("Query"));
+        }
+        None
+    }
+
+    /// Returns the name of the type that `field` is selected on.
+    pub fn field_parent_type(&self, field: &GraphqlField) -> Option<&str> {
+        self.selection_set_type(&parent_selection_set(field.syntax())?)
+    }
+
+    /// Returns the schema definition of `field`.
+    pub fn field_definition(&self, field: &GraphqlField) -> Option<&SchemaField> {
+        let parent_type = self.field_parent_type(field)?;
+        let name = field.name().ok()?.value_token().ok()?;
+        self.get_field(parent_type, name.text_trimmed())
+    }
+
+    /// Checks that `value` can be given to an input of type `ty`.
+    ///
+    /// Variables are accepted, since their value is only known when the operation is executed.
+    pub fn check_value(
+        &self,
+        value: &AnyGraphqlValue,
+        ty: &SchemaTypeRef,
+    ) -> Result<(), InvalidValue> {
+        let wrong_type = || InvalidValue::WrongType {
+            range: value.range(),
+            expected: ty.clone(),
+        };
+        match (value, ty) {
+            (
+                AnyGraphqlValue::GraphqlVariableReference(_)
+                | AnyGraphqlValue::GraphqlBogusValue(_),
+                _,
+            ) => Ok(()),
+            (AnyGraphqlValue::GraphqlNullValue(_), SchemaTypeRef::NonNull(_)) => Err(wrong_type()),
+            (AnyGraphqlValue::GraphqlNullValue(_), _) => Ok(()),
+            (_, SchemaTypeRef::NonNull(ty)) => self.check_value(value, ty),
+            (AnyGraphqlValue::GraphqlListValue(list), SchemaTypeRef::List(item)) => list
+                .elements()
+                .iter()
+                .try_for_each(|element| self.check_value(&element, item)),
+            // A single value is coerced to a list of one item
+            (_, SchemaTypeRef::List(item)) => self.check_value(value, item),
+            (_, SchemaTypeRef::Named(name)) => match self.types.get(name) {
+                Some(schema_type) if schema_type.kind == SchemaTypeKind::InputObject => match value
+                {
+                    AnyGraphqlValue::GraphqlObjectValue(object) => {
+                        self.check_input_object(object, name, schema_type)
+                    }
+                    _ => Err(wrong_type()),
+                },
+                schema_type => {
+                    if is_named_type_value(value, name, schema_type) {
+                        Ok(())
+                    } else {
+                        Err(wrong_type())
+                    }
+                }
+            },
+        }
+    }
+
+    fn check_input_object(
+        &self,
+        object: &GraphqlObjectValue,
+        type_name: &str,
+        schema_type: &SchemaType,
+    ) -> Result<(), InvalidValue> {
+        let mut provided = Vec::new();
+        for member in object.members() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Ok(name_token) = name.value_token() else {
+                continue;
+            };
+            let name_text = name_token.text_trimmed();
+            match schema_type.input_field(name_text) {
+                Some(input_field) => self.check_value(&value, &input_field.ty)?,
+                None => {
+                    return Err(InvalidValue::UnknownField {
+                        range: name.range(),
+                        name: name_text.to_string(),
+                        input_type: type_name.to_string(),
+                    })
+                }
+            }
+            provided.push(name_token);
+        }
+        let missing = schema_type.input_fields.iter().find(|input_field| {
+            input_field.is_required()
+                && !provided
+                    .iter()
+                    .any(|name| name.text_trimmed() == input_field.name)
+        });
+        match missing {
+            Some(missing) => Err(InvalidValue::MissingField {
+                range: object.range(),
+                name: missing.name.clone(),
+                input_type: type_name.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn type_name(&self, name: &str) -> Option<&str> {
+        self.types
+            .get_key_value(name)
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn register_root_operation_types(&mut self, types: &GraphqlRootOperationTypes) {
+        for definition in types.root_operation_type() {
+            let (Ok(operation_type), Ok(named_type)) =
+                (definition.operation_type(), definition.named_type())
+            else {
+                continue;
+            };
+            let (Ok(operation_token), Ok(name)) =
+                (operation_type.value_token(), named_type.value_token())
+            else {
+                continue;
+            };
+            let name = Some(name.text_trimmed().to_string());
+            match operation_token.kind() {
+                T![query] => self.query_type = name,
+                T![mutation] => self.mutation_type = name,
+                T![subscription] => self.subscription_type = name,
+                _ => {}
+            }
+        }
+    }
+
+    fn register_type_definition(&mut self, definition: &AnyGraphqlTypeDefinition) {
+        let (name, kind) = match definition {
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::Scalar)
+            }
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::Object)
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::Interface)
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::Union)
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::Enum)
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(node) => {
+                (node.name().ok(), SchemaTypeKind::InputObject)
+            }
+        };
+        let Some(name) = name.and_then(|name| name.value_token().ok()) else {
+            return;
+        };
+        self.federated |= match definition {
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(node) => {
+                node.directives().declares_entity()
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(node) => {
+                node.directives().declares_entity()
+            }
+            _ => false,
+        };
+        let schema_type = self.register_type(name.text_trimmed(), kind);
+        match definition {
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(node) => {
+                schema_type.register_fields(node.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(node) => {
+                schema_type.register_fields(node.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(node) => {
+                schema_type.register_members(node.union_members());
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(node) => {
+                schema_type.register_values(node.enum_values());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(node) => {
+                schema_type.register_input_fields(node.input_fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(_) => {}
+        }
+    }
+
+    fn register_type_extension(&mut self, extension: &AnyGraphqlTypeExtension) {
+        match extension {
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(node) => {
+                self.federated |= node.directives().declares_entity();
+                if let Ok(name) = node.name().and_then(|name| name.value_token()) {
+                    self.register_type(name.text_trimmed(), SchemaTypeKind::Object)
+                        .register_fields(node.fields());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(node) => {
+                self.federated |= node.directives().declares_entity();
+                if let Ok(name) = node.name().and_then(|name| name.value_token()) {
+                    self.register_type(name.text_trimmed(), SchemaTypeKind::Interface)
+                        .register_fields(node.fields());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(node) => {
+                if let Ok(name) = node.name().and_then(|name| name.value_token()) {
+                    self.register_type(name.text_trimmed(), SchemaTypeKind::Union)
+                        .register_members(node.union_members());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(node) => {
+                if let Ok(name) = node.name().and_then(|name| name.value_token()) {
+                    self.register_type(name.text_trimmed(), SchemaTypeKind::Enum)
+                        .register_values(node.enum_values());
+                }
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(node) => {
+                if let Ok(name) = node.name().and_then(|name| name.value_token()) {
+                    self.register_type(name.text_trimmed(), SchemaTypeKind::InputObject)
+                        .register_input_fields(node.input_fields());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the type named by `name`, creating it if it doesn't exist yet.
+    fn register_type(&mut self, name: &str, kind: SchemaTypeKind) -> &mut SchemaType {
+        self.types
+            .entry(name.to_string())
+            .or_insert_with(|| SchemaType::new(kind))
+    }
+}
+
+impl SchemaType {
+    fn new(kind: SchemaTypeKind) -> Self {
+        Self {
+            kind,
+            fields: FxHashMap::default(),
+            members: Vec::new(),
+            input_fields: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Returns the fields of an object or interface type.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &SchemaField)> {
+        self.fields
+            .iter()
+            .map(|(name, field)| (name.as_str(), field))
+    }
+
+    /// Returns the members of a union type.
+    pub fn members(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(String::as_str)
+    }
+
+    /// Returns the field `name` of an input object type.
+    pub fn input_field(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.input_fields
+            .iter()
+            .find(|input_field| input_field.name == name)
+    }
+
+    /// Returns `true` if `value` is a value of an enum type.
+    ///
+    /// Any value is accepted when the schema doesn't define the values of the type.
+    pub fn has_value(&self, value: &str) -> bool {
+        self.values.is_empty() || self.values.iter().any(|known| known == value)
+    }
+
+    /// Whether the type is an interface or a union.
+    pub fn is_abstract(&self) -> bool {
+        matches!(self.kind, SchemaTypeKind::Interface | SchemaTypeKind::Union)
+    }
+
+    fn register_fields(&mut self, fields: Option<GraphqlFieldsDefinition>) {
+        let Some(fields) = fields else {
+            return;
+        };
+        for field in fields.fields() {
+            let (Ok(name), Ok(ty)) = (field.name(), field.ty()) else {
+                continue;
+            };
+            let (Ok(name), Some(ty)) = (name.value_token(), type_ref(&ty)) else {
+                continue;
+            };
+            let arguments = field
+                .arguments()
+                .map(|arguments| {
+                    arguments
+                        .arguments()
+                        .iter()
+                        .filter_map(input_value)
+                        .collect()
+                })
+                .unwrap_or_default();
+            self.fields.insert(
+                name.text_trimmed().to_string(),
+                SchemaField {
+                    ty,
+                    arguments,
+                    deprecation: deprecation(&field.directives()),
+                },
+            );
+        }
+    }
+
+    fn register_input_fields(&mut self, fields: Option<GraphqlInputFieldsDefinition>) {
+        let Some(fields) = fields else {
+            return;
+        };
+        self.input_fields
+            .extend(fields.fields().iter().filter_map(input_value));
+    }
+
+    fn register_values(&mut self, values: Option<GraphqlEnumValuesDefinition>) {
+        let Some(values) = values else {
+            return;
+        };
+        for value in values.values() {
+            if let Ok(value) = value.value().and_then(|value| value.value_token()) {
+                self.values.push(value.text_trimmed().to_string());
+            }
+        }
+    }
+
+    fn register_members(&mut self, members: Option<GraphqlUnionMemberTypes>) {
+        let Some(members) = members else {
+            return;
+        };
+        for member in members.members().iter().flatten() {
+            if let Ok(name) = member.value_token() {
+                self.members.push(name.text_trimmed().to_string());
+            }
+        }
+    }
+}
+
+impl SchemaField {
+    /// Returns the argument `name` of the field.
+    pub fn argument(&self, name: &str) -> Option<&SchemaInputValue> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+}
+
+impl SchemaInputValue {
+    /// Whether a value must be given to the input: it's non-null and doesn't have a default value.
+    pub fn is_required(&self) -> bool {
+        matches!(self.ty, SchemaTypeRef::NonNull(_)) && !self.has_default
+    }
+}
+
+impl SchemaTypeRef {
+    /// Returns the name of the type wrapped by list and non-null types.
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaTypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{name}"),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
+
+/// Returns the reference to the type `ty`.
+fn type_ref(ty: &AnyGraphqlType) -> Option<SchemaTypeRef> {
+    match ty {
+        AnyGraphqlType::AnyGraphqlPrimitiveType(primitive) => primitive_type_ref(primitive),
+        AnyGraphqlType::GraphqlNonNullType(non_null) => Some(SchemaTypeRef::NonNull(Box::new(
+            primitive_type_ref(&non_null.base().ok()?)?,
+        ))),
+        AnyGraphqlType::GraphqlBogusType(_) => None,
+    }
+}
+
+fn primitive_type_ref(primitive: &AnyGraphqlPrimitiveType) -> Option<SchemaTypeRef> {
+    match primitive {
+        AnyGraphqlPrimitiveType::GraphqlNameReference(name) => Some(SchemaTypeRef::Named(
+            name.value_token().ok()?.text_trimmed().to_string(),
+        )),
+        AnyGraphqlPrimitiveType::GraphqlListType(list) => Some(SchemaTypeRef::List(Box::new(
+            type_ref(&list.element().ok()?)?,
+        ))),
+    }
+}
+
+/// Returns `true` if `value` is a value of the type `name`, which isn't an input object type.
+fn is_named_type_value(
+    value: &AnyGraphqlValue,
+    name: &str,
+    schema_type: Option<&SchemaType>,
+) -> bool {
+    match name {
+        "Int" => matches!(value, AnyGraphqlValue::GraphqlIntValue(_)),
+        "Float" => matches!(
+            value,
+            AnyGraphqlValue::GraphqlIntValue(_) | AnyGraphqlValue::GraphqlFloatValue(_)
+        ),
+        "String" => matches!(value, AnyGraphqlValue::GraphqlStringValue(_)),
+        "Boolean" => matches!(value, AnyGraphqlValue::GraphqlBooleanValue(_)),
+        "ID" => matches!(
+            value,
+            AnyGraphqlValue::GraphqlIntValue(_) | AnyGraphqlValue::GraphqlStringValue(_)
+        ),
+        _ => match schema_type {
+            Some(schema_type) if schema_type.kind == SchemaTypeKind::Enum => match value {
+                AnyGraphqlValue::GraphqlEnumValue(value) => value
+                    .value()
+                    .and_then(|value| value.value_token())
+                    .is_ok_and(|value| schema_type.has_value(value.text_trimmed())),
+                _ => false,
+            },
+            // Custom scalars accept any value, and the values of unknown types can't be checked
+            _ => true,
+        },
+    }
+}
+
+fn input_value(definition: GraphqlInputValueDefinition) -> Option<SchemaInputValue> {
+    let name = definition.name().ok()?.value_token().ok()?;
+    Some(SchemaInputValue {
+        name: name.text_trimmed().to_string(),
+        ty: type_ref(&definition.ty().ok()?)?,
+        has_default: definition.default().is_some(),
+    })
+}
+
+/// Returns the deprecation carried by a `@deprecated` directive in `directives`.
+fn deprecation(directives: &GraphqlDirectiveList) -> Option<Deprecation> {
+    let directive = directives.find_by_name("deprecated")?;
+    let reason = match directive.find_argument_value("reason") {
+        Some(AnyGraphqlValue::GraphqlStringValue(value)) => value
+            .inner_string_text()
+            .ok()
+            .map(|reason| reason.text().trim().to_string()),
+        _ => None,
+    };
+    Some(Deprecation { reason })
+}
+
+/// Returns the selection set that contains the selection `node`.
+fn parent_selection_set(node: &GraphqlSyntaxNode) -> Option<GraphqlSelectionSet> {
+    // selection -> selection list -> selection set
+    node.grand_parent().and_then(GraphqlSelectionSet::cast)
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaServices {
+    pub(crate) schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl SchemaServices {
+    /// Returns the schema of the project, if one is configured.
+    pub(crate) fn schema(&self) -> Option<&GraphqlSchema> {
+        self.schema.as_deref()
+    }
+}
+
+impl FromServices for SchemaServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        let schema: &Option<Arc<GraphqlSchema>> = services.get_service().ok_or_else(|| {
+            MissingServicesDiagnostic::new(rule_key.rule_name(), &["GraphqlSchema"])
+        })?;
+
+        Ok(Self {
+            schema: schema.clone(),
+        })
+    }
+}
+
+impl Phase for SchemaServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the project schema** to match on [AstNode] types
+#[derive(Clone)]
+pub struct Schema<N>(pub N);
+
+impl<N> Queryable for Schema<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    type Input = GraphqlSyntaxNode;
+    type Output = N;
+
+    type Language = GraphqlLanguage;
+    type Services = SchemaServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<GraphqlLanguage>, _: &GraphqlRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
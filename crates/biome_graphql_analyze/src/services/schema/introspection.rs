@@ -0,0 +1,200 @@
+use super::{
+    Deprecation, GraphqlSchema, SchemaField, SchemaInputValue, SchemaType, SchemaTypeKind,
+    SchemaTypeRef,
+};
+use serde::Deserialize;
+
+impl GraphqlSchema {
+    /// Builds the schema from the result of an introspection query, serialized as JSON.
+    ///
+    /// Both the response of the server, `{ "data": { "__schema": ... } }`,
+    /// and its `data` member alone are accepted.
+    pub fn from_introspection(source: &str) -> serde_json::Result<Self> {
+        let (IntrospectionResult::Response { data } | IntrospectionResult::Data(data)) =
+            serde_json::from_str(source)?;
+        let IntrospectionSchema {
+            query_type,
+            mutation_type,
+            subscription_type,
+            types,
+        } = data.schema;
+        let mut schema = Self {
+            query_type: query_type.map(|ty| ty.name),
+            mutation_type: mutation_type.map(|ty| ty.name),
+            subscription_type: subscription_type.map(|ty| ty.name),
+            types: Default::default(),
+            // The fields added by federation are already part of the introspected types
+            federated: false,
+        };
+        for introspection_type in types {
+            let Some(kind) = introspection_type.kind.schema_type_kind() else {
+                continue;
+            };
+            let mut schema_type = SchemaType::new(kind);
+            for field in introspection_type.fields.into_iter().flatten() {
+                let Some(ty) = field.ty.schema_type_ref() else {
+                    continue;
+                };
+                schema_type.fields.insert(
+                    field.name,
+                    SchemaField {
+                        ty,
+                        arguments: field.args.into_iter().filter_map(input_value).collect(),
+                        deprecation: field.is_deprecated.then_some(Deprecation {
+                            reason: field.deprecation_reason,
+                        }),
+                    },
+                );
+            }
+            schema_type.input_fields = introspection_type
+                .input_fields
+                .into_iter()
+                .flatten()
+                .filter_map(input_value)
+                .collect();
+            schema_type.values = introspection_type
+                .enum_values
+                .into_iter()
+                .flatten()
+                .map(|value| value.name)
+                .collect();
+            // The possible types of an interface are its implementations, not members
+            if kind == SchemaTypeKind::Union {
+                schema_type.members = introspection_type
+                    .possible_types
+                    .into_iter()
+                    .flatten()
+                    .map(|ty| ty.name)
+                    .collect();
+            }
+            schema.types.insert(introspection_type.name, schema_type);
+        }
+        Ok(schema)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IntrospectionResult {
+    Response { data: IntrospectionData },
+    Data(IntrospectionData),
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    query_type: Option<IntrospectionNamedType>,
+    mutation_type: Option<IntrospectionNamedType>,
+    subscription_type: Option<IntrospectionNamedType>,
+    types: Vec<IntrospectionType>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionNamedType {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: IntrospectionTypeKind,
+    name: String,
+    #[serde(default)]
+    fields: Option<Vec<IntrospectionField>>,
+    #[serde(default)]
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    #[serde(default)]
+    enum_values: Option<Vec<IntrospectionNamedType>>,
+    #[serde(default)]
+    possible_types: Option<Vec<IntrospectionNamedType>>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum IntrospectionTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+impl IntrospectionTypeKind {
+    /// Returns the kind of a named type, or `None` for the list and non-null wrappers
+    fn schema_type_kind(self) -> Option<SchemaTypeKind> {
+        match self {
+            Self::Scalar => Some(SchemaTypeKind::Scalar),
+            Self::Object => Some(SchemaTypeKind::Object),
+            Self::Interface => Some(SchemaTypeKind::Interface),
+            Self::Union => Some(SchemaTypeKind::Union),
+            Self::Enum => Some(SchemaTypeKind::Enum),
+            Self::InputObject => Some(SchemaTypeKind::InputObject),
+            Self::List | Self::NonNull => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+    #[serde(default)]
+    default_value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeRef {
+    kind: IntrospectionTypeKind,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+impl IntrospectionTypeRef {
+    fn schema_type_ref(&self) -> Option<SchemaTypeRef> {
+        match self.kind {
+            IntrospectionTypeKind::List => Some(SchemaTypeRef::List(Box::new(
+                self.of_type.as_ref()?.schema_type_ref()?,
+            ))),
+            IntrospectionTypeKind::NonNull => Some(SchemaTypeRef::NonNull(Box::new(
+                self.of_type.as_ref()?.schema_type_ref()?,
+            ))),
+            _ => Some(SchemaTypeRef::Named(self.name.clone()?)),
+        }
+    }
+}
+
+fn input_value(input_value: IntrospectionInputValue) -> Option<SchemaInputValue> {
+    Some(SchemaInputValue {
+        ty: input_value.ty.schema_type_ref()?,
+        name: input_value.name,
+        has_default: input_value.default_value.is_some(),
+    })
+}
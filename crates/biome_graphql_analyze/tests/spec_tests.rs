@@ -1,6 +1,7 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_graphql_analyze::GraphqlSchema;
 use biome_graphql_parser::parse_graphql;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_rowan::AstNode;
@@ -10,6 +11,7 @@ use biome_test_utils::{
     write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{graphql,json,jsonc}", crate::run_test, "module"}
@@ -104,8 +106,9 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let schema = load_schema(input_file);
 
-    let (_, errors) = biome_graphql_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_graphql_analyze::analyze(&root, filter, &options, schema, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
@@ -154,6 +157,24 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Loads the schema used to analyze `input_file`.
+///
+/// The schema is read from the sibling file with the `graphqls` extension,
+/// or from `schema.graphqls` when the test doesn't have its own schema.
+/// Otherwise, it's read from the introspection result `schema.json`.
+fn load_schema(input_file: &Path) -> Option<Arc<GraphqlSchema>> {
+    let schema = match read_to_string(input_file.with_extension("graphqls"))
+        .or_else(|_| read_to_string(input_file.with_file_name("schema.graphqls")))
+    {
+        Ok(source) => GraphqlSchema::from_root(&parse_graphql(&source).tree()),
+        Err(_) => {
+            let source = read_to_string(input_file.with_file_name("schema.json")).ok()?;
+            GraphqlSchema::from_introspection(&source).expect("invalid introspection result")
+        }
+    };
+    Some(Arc::new(schema))
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
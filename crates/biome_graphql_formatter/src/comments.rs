@@ -7,7 +7,7 @@ use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_graphql_syntax::{GraphqlLanguage, TextLen};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax};
 
 pub type GraphqlComments = Comments<GraphqlLanguage>;
 
@@ -65,7 +65,7 @@ impl CommentStyle for GraphqlCommentStyle {
     type Language = GraphqlLanguage;
 
     fn is_suppression(text: &str) -> bool {
-        parse_suppression_comment(text)
+        parse_suppression_comment_with(text, CommentSyntax::HASH)
             .filter_map(Result::ok)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
@@ -3,7 +3,6 @@ use biome_configuration::{
 };
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_graphql_formatter::{context::GraphqlFormatOptions, GraphqlFormatLanguage};
-use biome_service::workspace::UpdateSettingsParams;
 use std::path::Path;
 
 mod language {
@@ -29,23 +28,19 @@ mod language {
 /// * `null` -> input: `tests/specs/null.graphql`, expected output: `tests/specs/null.graphql.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
-    let settings = UpdateSettingsParams {
-        configuration: PartialConfiguration {
-            graphql: Some(PartialGraphqlConfiguration {
-                formatter: Some(PartialGraphqlFormatter {
-                    enabled: Some(true),
-                    ..Default::default()
-                }),
+    let configuration = PartialConfiguration {
+        graphql: Some(PartialGraphqlConfiguration {
+            formatter: Some(PartialGraphqlFormatter {
+                enabled: Some(true),
                 ..Default::default()
             }),
             ..Default::default()
-        },
-        vcs_base_path: None,
-        gitignore_matches: vec![],
-        workspace_directory: None,
+        }),
+        ..Default::default()
     };
 
-    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path, Some(settings))
+    let Some(test_file) =
+        SpecTestFile::try_from_file(spec_input_file, root_path, Some(configuration))
     else {
         return;
     };
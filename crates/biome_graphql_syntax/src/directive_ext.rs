@@ -0,0 +1,69 @@
+use crate::{AnyGraphqlValue, GraphqlDirective, GraphqlDirectiveList};
+use biome_rowan::AstNodeList;
+
+/// The prefix of the URLs of the Apollo Federation specifications
+const FEDERATION_SPEC_URL: &str = "https://specs.apollo.dev/federation/";
+
+impl GraphqlDirectiveList {
+    /// Returns the first directive of the list with the given name, without its `@`
+    pub fn find_by_name(&self, name: &str) -> Option<GraphqlDirective> {
+        self.iter().find(|directive| directive.has_name(name))
+    }
+
+    /// Check if the list links the Apollo Federation specification
+    ///
+    /// Federated subgraphs link the specification from their schema definition or extension:
+    /// ```graphql
+    /// extend schema @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key"])
+    /// ```
+    pub fn links_federation(&self) -> bool {
+        self.iter().any(|directive| directive.is_federation_link())
+    }
+
+    /// Check if the list declares an entity of an Apollo Federation subgraph
+    ///
+    /// The subgraphs of Federation 1 don't link the specification, but declare their entities
+    /// with the `@key` directive:
+    /// ```graphql
+    /// type Product @key(fields: "upc") { upc: String! }
+    /// ```
+    pub fn declares_entity(&self) -> bool {
+        self.find_by_name("key").is_some()
+    }
+}
+
+impl GraphqlDirective {
+    /// Check if the directive has the given name, without its `@`
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name()
+            .and_then(|directive_name| directive_name.value_token())
+            .is_ok_and(|token| token.text_trimmed() == name)
+    }
+
+    /// Returns the value of the argument of the directive with the given name
+    ///
+    /// Example: the value of `fields` is `"id"` in `@key(fields: "id")`
+    pub fn find_argument_value(&self, name: &str) -> Option<AnyGraphqlValue> {
+        self.arguments()?.arguments().iter().find_map(|argument| {
+            let argument_name = argument.name().ok()?.value_token().ok()?;
+            if argument_name.text_trimmed() == name {
+                argument.value().ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Check if the directive is a `@link` to the Apollo Federation specification
+    pub fn is_federation_link(&self) -> bool {
+        if !self.has_name("link") {
+            return false;
+        }
+        match self.find_argument_value("url") {
+            Some(AnyGraphqlValue::GraphqlStringValue(url)) => url
+                .inner_string_text()
+                .is_ok_and(|url| url.text().starts_with(FEDERATION_SPEC_URL)),
+            _ => false,
+        }
+    }
+}
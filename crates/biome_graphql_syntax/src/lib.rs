@@ -4,6 +4,7 @@
 
 #[macro_use]
 mod generated;
+pub mod directive_ext;
 mod file_source;
 pub mod string_value_ext;
 mod syntax_node;
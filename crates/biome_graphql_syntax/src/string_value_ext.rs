@@ -1,4 +1,5 @@
 use crate::GraphqlStringValue;
+use biome_rowan::{SyntaxResult, TextRange, TextSize, TokenText};
 
 impl GraphqlStringValue {
     /// Check if the string is a block string
@@ -15,4 +16,21 @@ impl GraphqlStringValue {
         self.graphql_string_literal_token()
             .map_or(false, |token| token.text_trimmed().starts_with("\"\"\""))
     }
+
+    /// Returns the text of the string without its delimiters
+    ///
+    /// Escape sequences and the indentation of block strings are left untouched.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        let token = self.graphql_string_literal_token()?;
+        let text = token.token_text_trimmed();
+        let delimiter_len = TextSize::from(if self.is_block() { 3 } else { 1 });
+        // An unterminated string only has its opening delimiter
+        let end = if text.len() >= delimiter_len + delimiter_len {
+            text.len() - delimiter_len
+        } else {
+            text.len()
+        };
+        let range = TextRange::new(delimiter_len.min(end), end);
+        Ok(text.slice(range))
+    }
 }
@@ -0,0 +1,26 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_at_html_tags;
+pub mod no_positive_tabindex;
+pub mod use_alt_text;
+pub mod use_button_name;
+pub mod use_button_type;
+pub mod use_html_lang;
+pub mod use_valid_lang;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_at_html_tags :: NoAtHtmlTags ,
+            self :: no_positive_tabindex :: NoPositiveTabindex ,
+            self :: use_alt_text :: UseAltText ,
+            self :: use_button_name :: UseButtonName ,
+            self :: use_button_type :: UseButtonType ,
+            self :: use_html_lang :: UseHtmlLang ,
+            self :: use_valid_lang :: UseValidLang ,
+        ]
+     }
+}
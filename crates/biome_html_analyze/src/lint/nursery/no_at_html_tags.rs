@@ -0,0 +1,67 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_html_syntax::SvelteSpecialTag;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow the use of `{@html}` tags in Svelte templates
+    ///
+    /// The `{@html}` tag inserts its expression in the page as raw HTML, without escaping it.
+    /// When the expression contains content provided by the users, the page is exposed to
+    /// [cross-site scripting (XSS)](https://owasp.org/www-community/attacks/xss/) attacks.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```svelte,expect_diagnostic
+    /// {@html comment.content}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```svelte
+    /// <p>{comment.content}</p>
+    /// ```
+    pub NoAtHtmlTags {
+        version: "next",
+        name: "noAtHtmlTags",
+        language: "html",
+        sources: &[RuleSource::EslintSvelte("no-at-html-tags")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoAtHtmlTags {
+    type Query = Ast<SvelteSpecialTag>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let tag = ctx.query();
+        let name = tag.name().ok()?.value_token().ok()?;
+        (name.text_trimmed() == "html").then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let tag = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                tag.range(),
+                markup! {
+                    "Avoid the "<Emphasis>"{@html}"</Emphasis>" tag."
+                },
+            )
+            .note(markup! {
+                "The tag inserts its content as raw HTML, which exposes the page to cross-site scripting (XSS) attacks when the content comes from the users."
+            })
+            .note(markup! {
+                "Use an expression tag such as "<Emphasis>"{content}"</Emphasis>", which escapes its content, or sanitize the content before inserting it."
+            }),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_html_factory::make;
+use biome_html_syntax::{AnyHtmlTag, HtmlString};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Prevent the usage of positive integers on `tabindex` attribute.
+    ///
+    /// Avoid positive `tabindex` attribute values to synchronize the flow of the page with keyboard tab order.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <div tabindex="1">foo</div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input tabindex=5>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div tabindex="0">foo</div>
+    /// ```
+    ///
+    /// ```html
+    /// <div tabindex="-1">foo</div>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 2.4.3](https://www.w3.org/WAI/WCAG21/Understanding/focus-order)
+    ///
+    pub NoPositiveTabindex {
+        version: "next",
+        name: "noPositiveTabindex",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("tabindex-no-positive")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoPositiveTabindex {
+    type Query = Ast<AnyHtmlTag>;
+    type State = HtmlString;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let value = element
+            .find_attribute_by_name("tabindex")?
+            .initializer()?
+            .value()
+            .ok()?;
+        let text = value.inner_string_text().ok()?;
+
+        (!is_tabindex_valid(&text)).then_some(value)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range(),
+            markup!{"Avoid positive values for the "<Emphasis>"tabindex"</Emphasis>" attribute."}.to_owned(),
+        )
+        .note(
+            markup!{
+                "Elements with a positive "<Emphasis>"tabindex"</Emphasis>" override natural page content order. This causes elements without a positive tab index to come last when navigating using a keyboard."
+            }.to_owned(),
+        )
+        .note(
+            markup!{
+                "Use only 0 and -1 as "<Emphasis>"tabindex"</Emphasis>" values. Avoid using "<Emphasis>"tabindex"</Emphasis>" values greater than 0 and CSS properties that can change the order of focusable HTML elements."
+            }
+        );
+
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let new_value = make::html_string(make::html_string_literal("0"));
+        mutation.replace_node(state.clone(), new_value);
+
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Replace the "<Emphasis>"tabindex"</Emphasis>" attribute value with 0." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Verify that a tab index is not positive.
+fn is_tabindex_valid(number_like_string: &str) -> bool {
+    let number_string_result = number_like_string.trim().parse::<i32>();
+
+    match number_string_result {
+        Ok(number) => number <= 0,
+        Err(_) => true,
+    }
+}
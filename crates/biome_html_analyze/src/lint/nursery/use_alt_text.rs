@@ -0,0 +1,165 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::{fmt::Display, fmt::Formatter, markup};
+use biome_html_syntax::{AnyHtmlElement, AnyHtmlTag, HtmlElement};
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+    ///
+    /// This is a critical component of accessibility for screen reader users in order for them to understand the content's purpose on the page.
+    /// By default, this rule checks for alternative text on the following elements: `<img>`, `<area>`, `<input type="image">`, and `<object>`.
+    ///
+    /// An empty `alt` attribute is allowed, because it marks the image as decorative.
+    /// An `<object>` element with some content is also allowed, because its content is its text alternative.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="image.png">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="image" src="image.png">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="image.png" alt="image alt">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" alt="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-label="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-labelledby="someId">
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
+    ///
+    pub UseAltText {
+        version: "next",
+        name: "useAltText",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("alt-text")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub enum ValidatedElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl Display for ValidatedElement {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        match self {
+            ValidatedElement::Object => fmt.write_markup(markup!(<Emphasis>"title"</Emphasis>)),
+            _ => fmt.write_markup(markup!(<Emphasis>"alt"</Emphasis>)),
+        }
+    }
+}
+
+impl Rule for UseAltText {
+    type Query = Ast<AnyHtmlTag>;
+    type State = (ValidatedElement, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+
+        let validated_element = if element.has_name("object") {
+            if has_valid_label(element, "title") || has_accessible_content(element) {
+                return None;
+            }
+            ValidatedElement::Object
+        } else if element.has_name("img") {
+            ValidatedElement::Img
+        } else if element.has_name("area") {
+            ValidatedElement::Area
+        } else if element.has_name("input") && has_type_image_attribute(element) {
+            ValidatedElement::Input
+        } else {
+            return None;
+        };
+
+        let has_alt = !matches!(validated_element, ValidatedElement::Object)
+            && element.find_attribute_by_name("alt").is_some();
+        if has_alt
+            || has_valid_label(element, "aria-label")
+            || has_valid_label(element, "aria-labelledby")
+            || is_aria_hidden(element)
+        {
+            return None;
+        }
+
+        Some((validated_element, element.range()))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (validate_element, range) = state;
+        let message = markup!(
+            "Provide a text alternative through the "{{validate_element}}", "<Emphasis>"aria-label"</Emphasis>" or "<Emphasis>"aria-labelledby"</Emphasis>" attribute"
+        ).to_owned();
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Meaningful alternative text on elements helps users relying on screen readers to understand content's purpose within a page."
+            }).note(markup! { "If the content is decorative, redundant, or obscured, consider hiding it from assistive technologies with the "<Emphasis>"aria-hidden"</Emphasis>" attribute."}),
+        )
+    }
+}
+
+fn has_type_image_attribute(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("type")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("image"))
+}
+
+fn has_valid_label(element: &AnyHtmlTag, name_to_lookup: &str) -> bool {
+    element
+        .find_attribute_by_name(name_to_lookup)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+fn is_aria_hidden(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("aria-hidden")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"))
+}
+
+/// Returns `true` if the element has some content other than comments,
+/// such as `<object data="movie.mp4">A movie</object>`.
+fn has_accessible_content(element: &AnyHtmlTag) -> bool {
+    let AnyHtmlTag::HtmlOpeningElement(opening_element) = element else {
+        return false;
+    };
+    opening_element
+        .parent::<HtmlElement>()
+        .is_some_and(|element| {
+            element.children().into_iter().any(|child| match child {
+                AnyHtmlElement::HtmlComment(_) => false,
+                AnyHtmlElement::HtmlContent(content) => content
+                    .value_token()
+                    .is_ok_and(|token| !token.text_trimmed().trim().is_empty()),
+                _ => true,
+            })
+        })
+}
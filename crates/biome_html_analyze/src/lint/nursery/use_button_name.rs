@@ -0,0 +1,208 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_aria::AriaRoles;
+use biome_console::markup;
+use biome_html_syntax::{AnyHtmlElement, AnyHtmlTag, HtmlElement, HtmlElementList};
+use biome_rowan::{AstNode, AstNodeList, TextRange};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Enforce that buttons have an accessible name.
+    ///
+    /// The accessible name of a button is announced by the screen readers, so a button without
+    /// a name can't be told apart from the other buttons of the page.
+    /// The name is the text content of the button, or the value of its `aria-label`,
+    /// `aria-labelledby` or `title` attribute. The name of an `<input type="button">` can also be
+    /// its `value` attribute.
+    ///
+    /// The rule checks the elements that have the `button` role, either implicitly like
+    /// `<button>`, or explicitly like `<div role="button">`.
+    /// The content hidden with `aria-hidden="true"` isn't part of the name, and an image is part
+    /// of the name only when it has an `alt` text.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <button type="button"></button>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <button type="button"><span aria-hidden="true">×</span></button>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="button">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role="button"><img src="close.png"></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <button type="submit">Save</button>
+    /// ```
+    ///
+    /// ```html
+    /// <button type="button" aria-label="Close"><span aria-hidden="true">×</span></button>
+    /// ```
+    ///
+    /// ```html
+    /// <input type="button" value="Save">
+    /// ```
+    ///
+    /// ```html
+    /// <div role="button"><img src="close.png" alt="Close"></div>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub UseButtonName {
+        version: "next",
+        name: "useButtonName",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("control-has-associated-label")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+impl Rule for UseButtonName {
+    type Query = Ast<AnyHtmlTag>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !has_button_role(element) || is_aria_hidden(element) || has_name_attribute(element) {
+            return None;
+        }
+        if element.has_name("input") && has_non_empty_attribute(element, "value") {
+            return None;
+        }
+        let has_content = match element {
+            AnyHtmlTag::HtmlOpeningElement(opening_element) => opening_element
+                .parent::<HtmlElement>()
+                .is_some_and(|element| has_accessible_content(&element.children())),
+            AnyHtmlTag::HtmlSelfClosingElement(_) => false,
+        };
+        if has_content {
+            return None;
+        }
+        Some(element.range())
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state,
+                markup! {
+                    "Provide an accessible name for the button."
+                },
+            )
+            .note(markup! {
+                "The screen readers announce the name of a button, so a button without a name can't be told apart from the other buttons."
+            })
+            .note(markup! {
+                "Add some text content to the button, or name it with the "<Emphasis>"aria-label"</Emphasis>", "<Emphasis>"aria-labelledby"</Emphasis>" or "<Emphasis>"title"</Emphasis>" attribute."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the element has the `button` role.
+///
+/// An explicit role takes precedence over the implicit role of the element, which is read from
+/// the elements associated to the `button` role by `biome_aria`, such as `<button>` and
+/// `<input type="button">`.
+fn has_button_role(element: &AnyHtmlTag) -> bool {
+    let roles = AriaRoles;
+    let explicit_role = element
+        .find_attribute_by_name("role")
+        .and_then(|attribute| attribute.value())
+        .and_then(|value| {
+            let role = value.split_ascii_whitespace().next()?;
+            Some(role.to_ascii_lowercase_cow().into_owned())
+        })
+        .filter(|role| roles.get_role(role).is_some());
+    if let Some(role) = explicit_role {
+        return role == "button";
+    }
+
+    let Some(mut elements) = roles.get_elements_by_role("button") else {
+        return false;
+    };
+    elements.any(|(name, attributes)| {
+        element.has_name(name)
+            && attributes.iter().all(|(attribute, expected_value)| {
+                element
+                    .find_attribute_by_name(attribute)
+                    .and_then(|attribute| attribute.value())
+                    .is_some_and(|value| value.eq_ignore_ascii_case(expected_value))
+            })
+    })
+}
+
+/// Returns `true` if the children contain some text, or an element that has an accessible name.
+///
+/// The expressions and the blocks of the templates could render some text, so they're
+/// considered as accessible content.
+fn has_accessible_content(children: &HtmlElementList) -> bool {
+    children.iter().any(|child| match child {
+        AnyHtmlElement::HtmlComment(_) => false,
+        AnyHtmlElement::HtmlContent(content) => content
+            .value_token()
+            .is_ok_and(|token| !token.text_trimmed().trim().is_empty()),
+        AnyHtmlElement::HtmlElement(element) => {
+            element.opening_element().is_ok_and(|opening_element| {
+                let tag = AnyHtmlTag::from(opening_element);
+                !is_aria_hidden(&tag)
+                    && (has_name_attribute(&tag)
+                        || has_image_alt(&tag)
+                        || has_accessible_content(&element.children()))
+            })
+        }
+        AnyHtmlElement::HtmlSelfClosingElement(element) => {
+            let tag = AnyHtmlTag::from(element);
+            !is_aria_hidden(&tag) && (has_name_attribute(&tag) || has_image_alt(&tag))
+        }
+        AnyHtmlElement::HtmlBogusElement(_)
+        | AnyHtmlElement::HtmlSingleTextExpression(_)
+        | AnyHtmlElement::SvelteBlock(_)
+        | AnyHtmlElement::SvelteSpecialTag(_) => true,
+    })
+}
+
+/// Returns `true` if the element is named by its `aria-label`, `aria-labelledby` or `title`
+/// attribute
+fn has_name_attribute(element: &AnyHtmlTag) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|name| has_non_empty_attribute(element, name))
+}
+
+fn has_image_alt(element: &AnyHtmlTag) -> bool {
+    element.has_name("img") && has_non_empty_attribute(element, "alt")
+}
+
+fn has_non_empty_attribute(element: &AnyHtmlTag, name: &str) -> bool {
+    element
+        .find_attribute_by_name(name)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+fn is_aria_hidden(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("aria-hidden")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"))
+}
@@ -0,0 +1,109 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_html_syntax::AnyHtmlTag;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforces the usage of the attribute `type` for the element `button`
+    ///
+    /// The values of the attribute are compared case-insensitively, like the browsers do.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <button>Do something</button>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <button type="incorrectType">Do something</button>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <button type="button">Do something</button>
+    /// ```
+    pub UseButtonType {
+        version: "next",
+        name: "useButtonType",
+        language: "html",
+        sources: &[RuleSource::EslintReact("button-has-type")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+const ALLOWED_BUTTON_TYPES: [&str; 3] = ["submit", "button", "reset"];
+
+pub struct UseButtonTypeState {
+    range: TextRange,
+    missing_attribute: bool,
+}
+
+impl Rule for UseButtonType {
+    type Query = Ast<AnyHtmlTag>;
+    type State = UseButtonTypeState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !element.has_name("button") {
+            return None;
+        }
+        let Some(attribute) = element.find_attribute_by_name("type") else {
+            return Some(UseButtonTypeState {
+                range: element.range(),
+                missing_attribute: true,
+            });
+        };
+        let Some(value) = attribute
+            .initializer()
+            .and_then(|initializer| initializer.value().ok())
+        else {
+            return Some(UseButtonTypeState {
+                range: attribute.range(),
+                missing_attribute: false,
+            });
+        };
+        let text = value.inner_string_text().ok()?;
+        if ALLOWED_BUTTON_TYPES
+            .iter()
+            .any(|button_type| text.eq_ignore_ascii_case(button_type))
+        {
+            return None;
+        }
+        Some(UseButtonTypeState {
+            range: value.range(),
+            missing_attribute: false,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = if state.missing_attribute {
+            (markup! {
+                "Provide an explicit "<Emphasis>"type"</Emphasis>" attribute for the "<Emphasis>"button"</Emphasis>" element."
+            }).to_owned()
+        } else {
+            (markup!{
+                "Provide a valid "<Emphasis>"type"</Emphasis>" attribute for the "<Emphasis>"button"</Emphasis>" element."
+            }).to_owned()
+        };
+        Some(RuleDiagnostic::new(rule_category!(),
+            state.range,
+            message
+        )
+            .note(markup! {
+                "The default "<Emphasis>"type"</Emphasis>" of a button is "<Emphasis>"submit"</Emphasis>", which causes the submission of a form when placed inside a `form` element."
+            })
+            .note(
+            markup! {
+                "Allowed button types are: "<Emphasis>"submit"</Emphasis>", "<Emphasis>"button"</Emphasis>" or "<Emphasis>"reset"</Emphasis>""
+            }
+        ))
+    }
+}
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoAtHtmlTags =
+    <lint::nursery::no_at_html_tags::NoAtHtmlTags as biome_analyze::Rule>::Options;
+pub type NoPositiveTabindex =
+    <lint::nursery::no_positive_tabindex::NoPositiveTabindex as biome_analyze::Rule>::Options;
+pub type UseAltText = <lint::nursery::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
+pub type UseButtonName =
+    <lint::nursery::use_button_name::UseButtonName as biome_analyze::Rule>::Options;
+pub type UseButtonType =
+    <lint::nursery::use_button_type::UseButtonType as biome_analyze::Rule>::Options;
+pub type UseHtmlLang = <lint::nursery::use_html_lang::UseHtmlLang as biome_analyze::Rule>::Options;
+pub type UseValidLang =
+    <lint::nursery::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
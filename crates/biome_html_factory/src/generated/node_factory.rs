@@ -7,6 +7,37 @@ use biome_html_syntax::{
     HtmlSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
+pub fn astro_frontmatter_element(
+    l_fence_token: SyntaxToken,
+    r_fence_token: SyntaxToken,
+) -> AstroFrontmatterElementBuilder {
+    AstroFrontmatterElementBuilder {
+        l_fence_token,
+        r_fence_token,
+        content_token: None,
+    }
+}
+pub struct AstroFrontmatterElementBuilder {
+    l_fence_token: SyntaxToken,
+    r_fence_token: SyntaxToken,
+    content_token: Option<SyntaxToken>,
+}
+impl AstroFrontmatterElementBuilder {
+    pub fn with_content_token(mut self, content_token: SyntaxToken) -> Self {
+        self.content_token = Some(content_token);
+        self
+    }
+    pub fn build(self) -> AstroFrontmatterElement {
+        AstroFrontmatterElement::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::ASTRO_FRONTMATTER_ELEMENT,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.content_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.r_fence_token)),
+            ],
+        ))
+    }
+}
 pub fn html_attribute(name: HtmlName) -> HtmlAttributeBuilder {
     HtmlAttributeBuilder {
         name,
@@ -184,6 +215,7 @@ pub fn html_root(html: HtmlElementList, eof_token: SyntaxToken) -> HtmlRootBuild
         html,
         eof_token,
         bom_token: None,
+        frontmatter: None,
         directive: None,
     }
 }
@@ -191,6 +223,7 @@ pub struct HtmlRootBuilder {
     html: HtmlElementList,
     eof_token: SyntaxToken,
     bom_token: Option<SyntaxToken>,
+    frontmatter: Option<AstroFrontmatterElement>,
     directive: Option<HtmlDirective>,
 }
 impl HtmlRootBuilder {
@@ -198,6 +231,10 @@ impl HtmlRootBuilder {
         self.bom_token = Some(bom_token);
         self
     }
+    pub fn with_frontmatter(mut self, frontmatter: AstroFrontmatterElement) -> Self {
+        self.frontmatter = Some(frontmatter);
+        self
+    }
     pub fn with_directive(mut self, directive: HtmlDirective) -> Self {
         self.directive = Some(directive);
         self
@@ -207,6 +244,8 @@ impl HtmlRootBuilder {
             HtmlSyntaxKind::HTML_ROOT,
             [
                 self.bom_token.map(|token| SyntaxElement::Token(token)),
+                self.frontmatter
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
                 self.directive
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
                 Some(SyntaxElement::Node(self.html.into_syntax())),
@@ -254,12 +293,174 @@ impl HtmlSelfClosingElementBuilder {
         ))
     }
 }
+pub fn html_single_text_expression(
+    l_curly_token: SyntaxToken,
+    expression: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+) -> HtmlSingleTextExpression {
+    HtmlSingleTextExpression::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_SINGLE_TEXT_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(expression.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
 pub fn html_string(value_token: SyntaxToken) -> HtmlString {
     HtmlString::unwrap_cast(SyntaxNode::new_detached(
         HtmlSyntaxKind::HTML_STRING,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn html_text_expression(value_token: SyntaxToken) -> HtmlTextExpression {
+    HtmlTextExpression::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_TEXT_EXPRESSION,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn svelte_block(
+    opening: SvelteBlockOpening,
+    children: HtmlElementList,
+    clauses: SvelteBlockClauseList,
+    closing: SvelteBlockClosing,
+) -> SvelteBlock {
+    SvelteBlock::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_BLOCK,
+        [
+            Some(SyntaxElement::Node(opening.into_syntax())),
+            Some(SyntaxElement::Node(children.into_syntax())),
+            Some(SyntaxElement::Node(clauses.into_syntax())),
+            Some(SyntaxElement::Node(closing.into_syntax())),
+        ],
+    ))
+}
+pub fn svelte_block_clause(
+    sv_curly_colon_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+    children: HtmlElementList,
+) -> SvelteBlockClauseBuilder {
+    SvelteBlockClauseBuilder {
+        sv_curly_colon_token,
+        name,
+        r_curly_token,
+        children,
+        expression: None,
+    }
+}
+pub struct SvelteBlockClauseBuilder {
+    sv_curly_colon_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+    children: HtmlElementList,
+    expression: Option<HtmlTextExpression>,
+}
+impl SvelteBlockClauseBuilder {
+    pub fn with_expression(mut self, expression: HtmlTextExpression) -> Self {
+        self.expression = Some(expression);
+        self
+    }
+    pub fn build(self) -> SvelteBlockClause {
+        SvelteBlockClause::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_BLOCK_CLAUSE,
+            [
+                Some(SyntaxElement::Token(self.sv_curly_colon_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.expression
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_curly_token)),
+                Some(SyntaxElement::Node(self.children.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn svelte_block_closing(
+    sv_curly_slash_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+) -> SvelteBlockClosing {
+    SvelteBlockClosing::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_BLOCK_CLOSING,
+        [
+            Some(SyntaxElement::Token(sv_curly_slash_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn svelte_block_opening(
+    sv_curly_hash_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+) -> SvelteBlockOpeningBuilder {
+    SvelteBlockOpeningBuilder {
+        sv_curly_hash_token,
+        name,
+        r_curly_token,
+        expression: None,
+    }
+}
+pub struct SvelteBlockOpeningBuilder {
+    sv_curly_hash_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+    expression: Option<HtmlTextExpression>,
+}
+impl SvelteBlockOpeningBuilder {
+    pub fn with_expression(mut self, expression: HtmlTextExpression) -> Self {
+        self.expression = Some(expression);
+        self
+    }
+    pub fn build(self) -> SvelteBlockOpening {
+        SvelteBlockOpening::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_BLOCK_OPENING,
+            [
+                Some(SyntaxElement::Token(self.sv_curly_hash_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.expression
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_curly_token)),
+            ],
+        ))
+    }
+}
+pub fn svelte_special_tag(
+    sv_curly_at_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+) -> SvelteSpecialTagBuilder {
+    SvelteSpecialTagBuilder {
+        sv_curly_at_token,
+        name,
+        r_curly_token,
+        expression: None,
+    }
+}
+pub struct SvelteSpecialTagBuilder {
+    sv_curly_at_token: SyntaxToken,
+    name: HtmlName,
+    r_curly_token: SyntaxToken,
+    expression: Option<HtmlTextExpression>,
+}
+impl SvelteSpecialTagBuilder {
+    pub fn with_expression(mut self, expression: HtmlTextExpression) -> Self {
+        self.expression = Some(expression);
+        self
+    }
+    pub fn build(self) -> SvelteSpecialTag {
+        SvelteSpecialTag::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_SPECIAL_TAG,
+            [
+                Some(SyntaxElement::Token(self.sv_curly_at_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.expression
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_curly_token)),
+            ],
+        ))
+    }
+}
 pub fn html_attribute_list<I>(items: I) -> HtmlAttributeList
 where
     I: IntoIterator<Item = AnyHtmlAttribute>,
@@ -284,6 +485,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn svelte_block_clause_list<I>(items: I) -> SvelteBlockClauseList
+where
+    I: IntoIterator<Item = SvelteBlockClause>,
+    I::IntoIter: ExactSizeIterator,
+{
+    SvelteBlockClauseList::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_BLOCK_CLAUSE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn html_bogus<I>(slots: I) -> HtmlBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
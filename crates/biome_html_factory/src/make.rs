@@ -5,3 +5,13 @@ pub use crate::generated::node_factory::*;
 pub fn ident(text: &str) -> HtmlSyntaxToken {
     HtmlSyntaxToken::new_detached(HtmlSyntaxKind::IDENT, text, [], [])
 }
+
+/// Create a new string literal token with no attached trivia, such as `"value"`
+pub fn html_string_literal(text: &str) -> HtmlSyntaxToken {
+    HtmlSyntaxToken::new_detached(
+        HtmlSyntaxKind::HTML_STRING_LITERAL,
+        &format!("\"{text}\""),
+        [],
+        [],
+    )
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{AstroFrontmatterElement, AstroFrontmatterElementFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAstroFrontmatterElement;
+impl FormatNodeRule<AstroFrontmatterElement> for FormatAstroFrontmatterElement {
+    fn fmt_fields(
+        &self,
+        node: &AstroFrontmatterElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        let AstroFrontmatterElementFields {
+            l_fence_token,
+            content_token,
+            r_fence_token,
+        } = node.as_fields();
+
+        // The code of the frontmatter is formatted by the JavaScript formatter
+        write!(
+            f,
+            [
+                l_fence_token.format(),
+                content_token.format(),
+                r_fence_token.format()
+            ]
+        )
+    }
+}
@@ -9,7 +9,7 @@ use biome_formatter::{
 };
 use biome_html_syntax::HtmlLanguage;
 use biome_rowan::{SyntaxTriviaPieceComments, TextLen};
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax};
 
 use crate::context::HtmlFormatContext;
 
@@ -85,7 +85,7 @@ impl CommentStyle for HtmlCommentStyle {
     type Language = HtmlLanguage;
 
     fn is_suppression(text: &str) -> bool {
-        parse_suppression_comment(text)
+        parse_suppression_comment_with(text, CommentSyntax::MARKUP)
             .filter_map(Result::ok)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
@@ -1,5 +1,6 @@
-use std::{fmt, rc::Rc};
+use std::{fmt, num::NonZeroU8, rc::Rc, str::FromStr};
 
+use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::{
     printer::PrinterOptions, AttributePosition, BracketSpacing, CstFormatContext, FormatContext,
     FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, TransformSourceMap,
@@ -24,6 +25,18 @@ pub struct HtmlFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// Whether to hug the closing bracket of multiline HTML tags to the end of the last line, rather than being alone on the following line. Defaults to false.
+    bracket_same_line: BracketSameLine,
+
+    /// Whether the whitespace around and inside the elements is significant. Defaults to `css`.
+    whitespace_sensitivity: WhitespaceSensitivity,
+
+    /// The number of attributes above which the attributes of an element are printed on separate lines. Unlimited by default.
+    max_inline_attributes: MaxInlineAttributes,
+
+    /// Whether to remove the whitespace of the elements that only contain whitespace, like `<div> </div>`. Defaults to true.
+    collapse_empty_elements: CollapseEmptyElements,
 }
 
 impl HtmlFormatOptions {
@@ -58,6 +71,35 @@ impl HtmlFormatOptions {
         self
     }
 
+    pub fn with_bracket_same_line(mut self, bracket_same_line: BracketSameLine) -> Self {
+        self.bracket_same_line = bracket_same_line;
+        self
+    }
+
+    pub fn with_whitespace_sensitivity(
+        mut self,
+        whitespace_sensitivity: WhitespaceSensitivity,
+    ) -> Self {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+        self
+    }
+
+    pub fn with_max_inline_attributes(
+        mut self,
+        max_inline_attributes: MaxInlineAttributes,
+    ) -> Self {
+        self.max_inline_attributes = max_inline_attributes;
+        self
+    }
+
+    pub fn with_collapse_empty_elements(
+        mut self,
+        collapse_empty_elements: CollapseEmptyElements,
+    ) -> Self {
+        self.collapse_empty_elements = collapse_empty_elements;
+        self
+    }
+
     pub fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -78,6 +120,22 @@ impl HtmlFormatOptions {
         self.attribute_position
     }
 
+    pub fn bracket_same_line(&self) -> BracketSameLine {
+        self.bracket_same_line
+    }
+
+    pub fn whitespace_sensitivity(&self) -> WhitespaceSensitivity {
+        self.whitespace_sensitivity
+    }
+
+    pub fn max_inline_attributes(&self) -> MaxInlineAttributes {
+        self.max_inline_attributes
+    }
+
+    pub fn collapse_empty_elements(&self) -> CollapseEmptyElements {
+        self.collapse_empty_elements
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -97,6 +155,22 @@ impl HtmlFormatOptions {
     pub fn set_attribute_position(&mut self, attribute_position: AttributePosition) {
         self.attribute_position = attribute_position;
     }
+
+    pub fn set_bracket_same_line(&mut self, bracket_same_line: BracketSameLine) {
+        self.bracket_same_line = bracket_same_line;
+    }
+
+    pub fn set_whitespace_sensitivity(&mut self, whitespace_sensitivity: WhitespaceSensitivity) {
+        self.whitespace_sensitivity = whitespace_sensitivity;
+    }
+
+    pub fn set_max_inline_attributes(&mut self, max_inline_attributes: MaxInlineAttributes) {
+        self.max_inline_attributes = max_inline_attributes;
+    }
+
+    pub fn set_collapse_empty_elements(&mut self, collapse_empty_elements: CollapseEmptyElements) {
+        self.collapse_empty_elements = collapse_empty_elements;
+    }
 }
 
 impl fmt::Display for HtmlFormatOptions {
@@ -105,7 +179,15 @@ impl fmt::Display for HtmlFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
+        writeln!(f, "Whitespace sensitivity: {}", self.whitespace_sensitivity)?;
+        writeln!(f, "Max inline attributes: {}", self.max_inline_attributes)?;
+        writeln!(
+            f,
+            "Collapse empty elements: {}",
+            self.collapse_empty_elements.value()
+        )
     }
 }
 
@@ -185,3 +267,133 @@ impl CstFormatContext for HtmlFormatContext {
         &self.comments
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct BracketSameLine(bool);
+
+impl BracketSameLine {
+    /// Return the boolean value for this [BracketSameLine]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for BracketSameLine {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+/// Whether the whitespace around and inside the elements is significant, because it's rendered
+/// as a space by the browsers.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum WhitespaceSensitivity {
+    /// The whitespace is significant around and inside the elements that are inline by default,
+    /// such as `<span>` or `<a>`, and around the text and the expressions.
+    #[default]
+    Css,
+    /// The whitespace is significant around and inside all the elements.
+    Strict,
+    /// The whitespace is never significant, so the formatter can add or remove it.
+    Ignore,
+}
+
+impl WhitespaceSensitivity {
+    pub const fn is_css(&self) -> bool {
+        matches!(self, Self::Css)
+    }
+
+    pub const fn is_strict(&self) -> bool {
+        matches!(self, Self::Strict)
+    }
+
+    pub const fn is_ignore(&self) -> bool {
+        matches!(self, Self::Ignore)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for WhitespaceSensitivity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "css" | "Css" => Ok(Self::Css),
+            "strict" | "Strict" => Ok(Self::Strict),
+            "ignore" | "Ignore" => Ok(Self::Ignore),
+            _ => Err("Value not supported for WhitespaceSensitivity. Supported values are 'css', 'strict' and 'ignore'."),
+        }
+    }
+}
+
+impl fmt::Display for WhitespaceSensitivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Css => write!(f, "css"),
+            Self::Strict => write!(f, "strict"),
+            Self::Ignore => write!(f, "ignore"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MaxInlineAttributes(Option<NonZeroU8>);
+
+impl MaxInlineAttributes {
+    /// Return the maximum, or `None` if the number of attributes is unlimited
+    pub fn value(&self) -> Option<NonZeroU8> {
+        self.0
+    }
+
+    /// Returns `true` if an element with `count` attributes must print them on separate lines
+    pub fn is_exceeded_by(&self, count: usize) -> bool {
+        self.0.is_some_and(|max| count > usize::from(max.get()))
+    }
+}
+
+impl From<NonZeroU8> for MaxInlineAttributes {
+    fn from(value: NonZeroU8) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl fmt::Display for MaxInlineAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(max) => write!(f, "{max}"),
+            None => write!(f, "Unlimited"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CollapseEmptyElements(bool);
+
+impl Default for CollapseEmptyElements {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl CollapseEmptyElements {
+    /// Return the boolean value for this [CollapseEmptyElements]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for CollapseEmptyElements {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
@@ -4,6 +4,46 @@ use crate::{
     AsFormat, FormatBogusNodeRule, FormatNodeRule, HtmlFormatContext, HtmlFormatter, IntoFormat,
 };
 use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_html_syntax::AstroFrontmatterElement>
+    for crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::AstroFrontmatterElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::AstroFrontmatterElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AstroFrontmatterElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AstroFrontmatterElement,
+        crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AstroFrontmatterElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AstroFrontmatterElement,
+        crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement::default(),
+        )
+    }
+}
 impl FormatRule<biome_html_syntax::HtmlAttribute>
     for crate::html::auxiliary::attribute::FormatHtmlAttribute
 {
@@ -426,6 +466,48 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlSelfClosingElement
         )
     }
 }
+impl FormatRule<biome_html_syntax::HtmlSingleTextExpression>
+    for crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlSingleTextExpression,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlSingleTextExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlSingleTextExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlSingleTextExpression,
+        crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlSingleTextExpression {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlSingleTextExpression,
+        crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression::default(
+            ),
+        )
+    }
+}
 impl FormatRule<biome_html_syntax::HtmlString>
     for crate::html::auxiliary::string::FormatHtmlString
 {
@@ -462,6 +544,246 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlString {
         )
     }
 }
+impl FormatRule<biome_html_syntax::HtmlTextExpression>
+    for crate::html::auxiliary::text_expression::FormatHtmlTextExpression
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlTextExpression,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlTextExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlTextExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlTextExpression,
+        crate::html::auxiliary::text_expression::FormatHtmlTextExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::text_expression::FormatHtmlTextExpression::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlTextExpression {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlTextExpression,
+        crate::html::auxiliary::text_expression::FormatHtmlTextExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::text_expression::FormatHtmlTextExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteBlock>
+    for crate::svelte::auxiliary::block::FormatSvelteBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteBlock,
+        crate::svelte::auxiliary::block::FormatSvelteBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::block::FormatSvelteBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteBlock,
+        crate::svelte::auxiliary::block::FormatSvelteBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::block::FormatSvelteBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteBlockClause>
+    for crate::svelte::auxiliary::block_clause::FormatSvelteBlockClause
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteBlockClause,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteBlockClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteBlockClause,
+        crate::svelte::auxiliary::block_clause::FormatSvelteBlockClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_clause::FormatSvelteBlockClause::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClause {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteBlockClause,
+        crate::svelte::auxiliary::block_clause::FormatSvelteBlockClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_clause::FormatSvelteBlockClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteBlockClosing>
+    for crate::svelte::auxiliary::block_closing::FormatSvelteBlockClosing
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteBlockClosing,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteBlockClosing>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClosing {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteBlockClosing,
+        crate::svelte::auxiliary::block_closing::FormatSvelteBlockClosing,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_closing::FormatSvelteBlockClosing::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClosing {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteBlockClosing,
+        crate::svelte::auxiliary::block_closing::FormatSvelteBlockClosing,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_closing::FormatSvelteBlockClosing::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteBlockOpening>
+    for crate::svelte::auxiliary::block_opening::FormatSvelteBlockOpening
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteBlockOpening,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteBlockOpening>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockOpening {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteBlockOpening,
+        crate::svelte::auxiliary::block_opening::FormatSvelteBlockOpening,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_opening::FormatSvelteBlockOpening::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockOpening {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteBlockOpening,
+        crate::svelte::auxiliary::block_opening::FormatSvelteBlockOpening,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::block_opening::FormatSvelteBlockOpening::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteSpecialTag>
+    for crate::svelte::auxiliary::special_tag::FormatSvelteSpecialTag
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteSpecialTag,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteSpecialTag>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteSpecialTag {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteSpecialTag,
+        crate::svelte::auxiliary::special_tag::FormatSvelteSpecialTag,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::special_tag::FormatSvelteSpecialTag::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteSpecialTag {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteSpecialTag,
+        crate::svelte::auxiliary::special_tag::FormatSvelteSpecialTag,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::special_tag::FormatSvelteSpecialTag::default(),
+        )
+    }
+}
 impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttributeList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -516,6 +838,33 @@ impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlElementList {
         )
     }
 }
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClauseList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteBlockClauseList,
+        crate::svelte::lists::block_clause_list::FormatSvelteBlockClauseList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::lists::block_clause_list::FormatSvelteBlockClauseList::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteBlockClauseList {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteBlockClauseList,
+        crate::svelte::lists::block_clause_list::FormatSvelteBlockClauseList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::lists::block_clause_list::FormatSvelteBlockClauseList::default(),
+        )
+    }
+}
 impl FormatRule<biome_html_syntax::HtmlBogus> for crate::html::bogus::bogus::FormatHtmlBogus {
     type Context = HtmlFormatContext;
     #[inline(always)]
@@ -13,6 +13,9 @@ impl FormatRule<AnyHtmlElement> for FormatAnyHtmlElement {
             AnyHtmlElement::HtmlContent(node) => node.format().fmt(f),
             AnyHtmlElement::HtmlElement(node) => node.format().fmt(f),
             AnyHtmlElement::HtmlSelfClosingElement(node) => node.format().fmt(f),
+            AnyHtmlElement::HtmlSingleTextExpression(node) => node.format().fmt(f),
+            AnyHtmlElement::SvelteBlock(node) => node.format().fmt(f),
+            AnyHtmlElement::SvelteSpecialTag(node) => node.format().fmt(f),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::metadata::{has_trailing_whitespace, is_inline_element};
 use biome_formatter::write;
 use biome_html_syntax::{HtmlElement, HtmlElementFields};
 #[derive(Debug, Clone, Default)]
@@ -11,14 +12,47 @@ impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
             closing_element,
         } = node.as_fields();
 
-        write!(
-            f,
-            [
-                opening_element.format(),
-                children.format(),
-                closing_element.format(),
-            ]
-        )?;
+        write!(f, [opening_element.format(), children.format()])?;
+
+        // An element that only contains whitespace, like `<div> </div>`
+        if children.is_empty() {
+            let r_angle_token = node
+                .opening_element()
+                .and_then(|opening| opening.r_angle_token())
+                .ok();
+            let l_angle_token = node
+                .closing_element()
+                .and_then(|closing| closing.l_angle_token())
+                .ok();
+            let has_whitespace = r_angle_token.as_ref().is_some_and(has_trailing_whitespace)
+                || l_angle_token
+                    .as_ref()
+                    .is_some_and(|token| token.has_leading_whitespace_or_newline());
+
+            if has_whitespace {
+                if !f.options().collapse_empty_elements().value() {
+                    let has_newline = l_angle_token
+                        .as_ref()
+                        .is_some_and(|token| token.has_leading_newline())
+                        || r_angle_token.as_ref().is_some_and(|token| {
+                            token
+                                .trailing_trivia()
+                                .pieces()
+                                .any(|piece| piece.is_newline())
+                        });
+                    if has_newline {
+                        write!(f, [hard_line_break()])?;
+                    } else {
+                        write!(f, [space()])?;
+                    }
+                } else if is_inline_element(node, f.options().whitespace_sensitivity()) {
+                    // The whitespace of an inline element is rendered as a space
+                    write!(f, [space()])?;
+                }
+            }
+        }
+
+        write!(f, [closing_element.format()])?;
 
         Ok(())
     }
@@ -11,4 +11,6 @@ pub(crate) mod name;
 pub(crate) mod opening_element;
 pub(crate) mod root;
 pub(crate) mod self_closing_element;
+pub(crate) mod single_text_expression;
 pub(crate) mod string;
+pub(crate) mod text_expression;
@@ -8,6 +8,9 @@ impl FormatNodeRule<HtmlRoot> for FormatHtmlRoot {
         if let Some(bom) = node.bom_token() {
             bom.format().fmt(f)?;
         }
+        if let Some(frontmatter) = node.frontmatter() {
+            write!(f, [frontmatter.format(), hard_line_break()])?;
+        }
         if let Some(directive) = node.directive() {
             directive.format().fmt(f)?;
         }
@@ -11,7 +11,11 @@ impl FormatNodeRule<HtmlString> for FormatHtmlString {
         if let Ok(value) = value_token.as_ref() {
             let value_text = value.text().trim();
 
-            if !(value_text.starts_with('"') && value_text.ends_with('"')) {
+            // The expressions of the attributes of Svelte, like `class={name}`, aren't strings
+            let is_expression = value_text.starts_with('{') && value_text.ends_with('}');
+            let is_double_quoted = value_text.starts_with('"') && value_text.ends_with('"');
+
+            if !is_expression && !is_double_quoted {
                 let contains_double_quote = value_text.contains('"');
 
                 let range = if value_text.starts_with('\'')
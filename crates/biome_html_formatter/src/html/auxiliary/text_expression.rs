@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlTextExpression, HtmlTextExpressionFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlTextExpression;
+impl FormatNodeRule<HtmlTextExpression> for FormatHtmlTextExpression {
+    fn fmt_fields(&self, node: &HtmlTextExpression, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlTextExpressionFields { value_token } = node.as_fields();
+
+        // The expressions are JavaScript code that is kept as written
+        write!(f, [value_token.format()])
+    }
+}
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::{write, AttributePosition};
+use biome_formatter::{format_args, write, AttributePosition};
 use biome_html_syntax::HtmlAttributeList;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlAttributeList;
@@ -12,13 +12,32 @@ impl FormatRule<HtmlAttributeList> for FormatHtmlAttributeList {
             soft_line_break_or_space()
         };
 
-        write!(
-            f,
-            [&group(&soft_block_indent(&format_with(|f| {
-                f.join_with(&line_break)
-                    .entries(node.iter().formatted())
-                    .finish()
-            })))]
-        )
+        // The attributes are printed on separate lines when there are too many of them
+        let should_expand = f
+            .options()
+            .max_inline_attributes()
+            .is_exceeded_by(node.len());
+
+        let attributes = format_with(|f| {
+            f.join_with(&line_break)
+                .entries(node.iter().formatted())
+                .finish()
+        });
+
+        if f.options().bracket_same_line().value() {
+            // The closing bracket of the element follows the last attribute
+            write!(
+                f,
+                [
+                    &group(&indent(&format_args![soft_line_break(), attributes]))
+                        .should_expand(should_expand)
+                ]
+            )
+        } else {
+            write!(
+                f,
+                [&group(&soft_block_indent(&attributes)).should_expand(should_expand)]
+            )
+        }
     }
 }
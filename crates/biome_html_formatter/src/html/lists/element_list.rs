@@ -4,14 +4,19 @@ use std::cell::RefCell;
 
 use crate::{
     comments::HtmlComments,
+    context::WhitespaceSensitivity,
     prelude::*,
-    utils::children::{
-        html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator, HtmlSpace,
+    utils::{
+        children::{
+            html_split_children, is_meaningful_html_text, HtmlChild, HtmlChildrenIterator,
+            HtmlSpace,
+        },
+        metadata::{has_trailing_whitespace, is_inline, is_inline_element},
     },
 };
 use biome_formatter::{best_fitting, prelude::*, CstFormatContext};
 use biome_formatter::{format_args, write, VecBuffer};
-use biome_html_syntax::{AnyHtmlElement, HtmlElementList, HtmlRoot};
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlElementList, HtmlRoot};
 use tag::GroupMode;
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatHtmlElementList {
@@ -72,24 +77,57 @@ impl FormatHtmlElementList {
         let children_meta = self.children_meta(list, f.context().comments());
         let layout = self.layout(children_meta);
 
-        let multiline_layout = if children_meta.meaningful_text {
-            MultilineLayout::Fill
-        } else {
-            MultilineLayout::NoFill
-        };
-
         let is_root_parent = list
             .syntax()
             .parent()
             .is_some_and(|parent| HtmlRoot::can_cast(parent.kind()));
 
+        let sensitivity = f.options().whitespace_sensitivity();
+        let mut children = html_split_children(list.iter(), f.context().comments())?;
+
+        // The whitespace after the opening tag and before the closing tag of an inline element is
+        // significant, so no line break can be added where there's none.
+        let (hug_start, hug_end) = match list.parent::<HtmlElement>() {
+            Some(element) if is_inline_element(&element, sensitivity) => {
+                if has_whitespace_after_opening_tag(&element)
+                    && children.first().is_some_and(|child| !child.is_whitespace())
+                {
+                    children.insert(0, HtmlChild::Whitespace);
+                }
+                if has_whitespace_before_closing_tag(&element)
+                    && children.last().is_some_and(|child| !child.is_whitespace())
+                {
+                    children.push(HtmlChild::Whitespace);
+                }
+                (
+                    children
+                        .first()
+                        .is_some_and(|child| is_inline_child(child, sensitivity)),
+                    children
+                        .last()
+                        .is_some_and(|child| is_inline_child(child, sensitivity)),
+                )
+            }
+            _ => (false, false),
+        };
+
+        // The inline children are filled in the lines, because a line break between them would
+        // be rendered as a space
+        let multiline_layout = if children_meta.meaningful_text
+            || children.iter().any(|child| {
+                matches!(child, HtmlChild::NonText(_)) && is_inline_child(child, sensitivity)
+            }) {
+            MultilineLayout::Fill
+        } else {
+            MultilineLayout::NoFill
+        };
+
         let mut flat = FlatBuilder::new();
-        let mut multiline = MultilineBuilder::new(multiline_layout, is_root_parent);
+        let mut multiline =
+            MultilineBuilder::new(multiline_layout, is_root_parent, hug_start, hug_end);
 
         let mut force_multiline = layout.is_multiline();
 
-        let mut children = html_split_children(list.iter(), f.context().comments())?;
-
         // Trim trailing new lines
         if let Some(HtmlChild::EmptyLine | HtmlChild::Newline) = children.last() {
             children.pop();
@@ -114,6 +152,13 @@ impl FormatHtmlElementList {
                             Some(WordSeparator::BetweenWords)
                         }
 
+                        // A word followed by an inline element without any whitespace in between
+                        Some(HtmlChild::NonText(next_child))
+                            if is_inline(next_child, sensitivity) =>
+                        {
+                            Some(WordSeparator::Adjacent)
+                        }
+
                         // Last word or last word before an element without any whitespace in between
                         Some(HtmlChild::NonText(next_child)) => Some(WordSeparator::EndOfText {
                             is_soft_line_break: !matches!(
@@ -221,7 +266,15 @@ impl FormatHtmlElementList {
 
                 // Any child that isn't text
                 HtmlChild::NonText(non_text) => {
-                    let line_mode = match children_iter.peek() {
+                    let separator = match children_iter.peek() {
+                        // An inline element followed by a text without any whitespace in between
+                        Some(HtmlChild::Word(_))
+                            if is_inline(non_text, sensitivity)
+                                && !has_trailing_whitespace_after(non_text) =>
+                        {
+                            Some(ElementSeparator::Adjacent)
+                        }
+
                         Some(HtmlChild::Word(word)) => {
                             // Break if the current or next element is a self closing element
                             // ```javascript
@@ -235,14 +288,43 @@ impl FormatHtmlElementList {
                             if matches!(non_text, AnyHtmlElement::HtmlSelfClosingElement(_))
                                 && !word.is_single_character()
                             {
-                                Some(LineMode::Hard)
+                                Some(ElementSeparator::Line(LineMode::Hard))
+                            }
+                            // Keep the whitespace between an element and a text, like `<b>a</b> text`
+                            else if has_trailing_whitespace_after(non_text) {
+                                Some(ElementSeparator::Line(LineMode::SoftOrSpace))
+                            } else {
+                                Some(ElementSeparator::Line(LineMode::Soft))
+                            }
+                        }
+
+                        // Keep the whitespace between two inline elements, like `<b>a</b> <i>b</i>`,
+                        // and don't add any where there's none
+                        Some(HtmlChild::NonText(next_child))
+                            if is_inline(non_text, sensitivity)
+                                && is_inline(next_child, sensitivity) =>
+                        {
+                            if has_whitespace_between(non_text, next_child) {
+                                Some(ElementSeparator::Line(LineMode::SoftOrSpace))
+                            } else {
+                                Some(ElementSeparator::Adjacent)
+                            }
+                        }
+
+                        // Keep the whitespace between the expressions of a text, like `{first} {last}`
+                        Some(HtmlChild::NonText(next_child))
+                            if is_inline_expression(non_text)
+                                || is_inline_expression(next_child) =>
+                        {
+                            if has_whitespace_between(non_text, next_child) {
+                                Some(ElementSeparator::Line(LineMode::SoftOrSpace))
                             } else {
-                                Some(LineMode::Soft)
+                                Some(ElementSeparator::Line(LineMode::Soft))
                             }
                         }
 
                         // Add a hard line break if what comes after the element is not a text or is all whitespace
-                        Some(HtmlChild::NonText(_)) => Some(LineMode::Hard),
+                        Some(HtmlChild::NonText(_)) => Some(ElementSeparator::Line(LineMode::Hard)),
 
                         Some(HtmlChild::Newline | HtmlChild::Whitespace | HtmlChild::EmptyLine) => {
                             None
@@ -251,19 +333,11 @@ impl FormatHtmlElementList {
                         None => None,
                     };
 
-                    child_breaks = line_mode.map_or(false, |mode| mode.is_hard());
-
-                    let format_separator = line_mode.map(|mode| {
-                        format_with(move |f| f.write_element(FormatElement::Line(mode)))
-                    });
+                    child_breaks = separator.map_or(false, |separator| separator.will_break());
 
                     if force_multiline {
-                        if let Some(format_separator) = format_separator {
-                            multiline.write_with_separator(
-                                &non_text.format(),
-                                &format_separator,
-                                f,
-                            );
+                        if let Some(separator) = separator {
+                            multiline.write_with_separator(&non_text.format(), &separator, f);
                         } else {
                             // it's safe to write without a separator because None means that next element is a separator or end of the iterator
                             multiline.write_content(&non_text.format(), f);
@@ -272,10 +346,10 @@ impl FormatHtmlElementList {
                         let mut memoized = non_text.format().memoized();
 
                         force_multiline = memoized.inspect(f)?.will_break();
-                        flat.write(&format_args![memoized, format_separator], f);
+                        flat.write(&format_args![memoized, separator], f);
 
-                        if let Some(format_separator) = format_separator {
-                            multiline.write_with_separator(&memoized, &format_separator, f);
+                        if let Some(separator) = separator {
+                            multiline.write_with_separator(&memoized, &separator, f);
                         } else {
                             // it's safe to write without a separator because None means that next element is a separator or end of the iterator
                             multiline.write_content(&memoized, f);
@@ -353,7 +427,7 @@ impl FormatHtmlElementList {
             use AnyHtmlElement::*;
 
             match child {
-                HtmlElement(_) | HtmlSelfClosingElement(_) => meta.any_tag = true,
+                HtmlElement(_) | HtmlSelfClosingElement(_) | SvelteBlock(_) => meta.any_tag = true,
                 HtmlContent(text) => {
                     meta.meaningful_text = meta.meaningful_text
                         || text
@@ -368,6 +442,60 @@ impl FormatHtmlElementList {
     }
 }
 
+/// Returns `true` if `element` is an expression of the text that contains it, like `{name}` or
+/// `{@html content}` in Svelte.
+fn is_inline_expression(element: &AnyHtmlElement) -> bool {
+    matches!(
+        element,
+        AnyHtmlElement::HtmlSingleTextExpression(_) | AnyHtmlElement::SvelteSpecialTag(_)
+    )
+}
+
+/// Returns `true` if the whitespace around `child` is significant with the given `sensitivity`.
+fn is_inline_child(child: &HtmlChild, sensitivity: WhitespaceSensitivity) -> bool {
+    match child {
+        HtmlChild::Word(_) => !sensitivity.is_ignore(),
+        HtmlChild::NonText(element) => is_inline(element, sensitivity),
+        HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine => false,
+    }
+}
+
+/// Returns `true` if there's whitespace right after `element`, like `<b>a</b> text`.
+fn has_trailing_whitespace_after(element: &AnyHtmlElement) -> bool {
+    element
+        .syntax()
+        .last_token()
+        .is_some_and(|token| has_trailing_whitespace(&token))
+}
+
+/// Returns `true` if the children of `element` start with whitespace, like `<span> a</span>`.
+fn has_whitespace_after_opening_tag(element: &HtmlElement) -> bool {
+    element
+        .opening_element()
+        .and_then(|opening| opening.r_angle_token())
+        .is_ok_and(|token| has_trailing_whitespace(&token))
+}
+
+/// Returns `true` if the children of `element` end with whitespace, like `<span>a </span>`.
+fn has_whitespace_before_closing_tag(element: &HtmlElement) -> bool {
+    element
+        .closing_element()
+        .and_then(|closing| closing.l_angle_token())
+        .is_ok_and(|token| token.has_leading_whitespace_or_newline())
+}
+
+/// Returns `true` if there's whitespace between two consecutive elements.
+fn has_whitespace_between(element: &AnyHtmlElement, next: &AnyHtmlElement) -> bool {
+    element
+        .syntax()
+        .last_token()
+        .is_some_and(|token| token.has_trailing_whitespace())
+        || next
+            .syntax()
+            .first_token()
+            .is_some_and(|token| token.has_leading_whitespace_or_newline())
+}
+
 #[derive(Debug, Default, Copy, Clone)]
 pub enum HtmlChildListLayout {
     /// Prefers to format the children on a single line if possible.
@@ -428,6 +556,14 @@ enum WordSeparator {
     /// );
     /// ```
     EndOfText { is_soft_line_break: bool },
+
+    /// A word right before an inline element, without any whitespace in between. Nothing is
+    /// written, because a line break would be rendered as a space.
+    ///
+    /// ```html
+    /// <p>a<b>bold</b></p>
+    /// ```
+    Adjacent,
 }
 
 impl WordSeparator {
@@ -465,6 +601,37 @@ impl Format<HtmlFormatContext> for WordSeparator {
                     hard_line_break().fmt(f)
                 }
             }
+            WordSeparator::Adjacent => Ok(()),
+        }
+    }
+}
+
+/// The separator written after an element that isn't a text.
+#[derive(Copy, Clone, Debug)]
+enum ElementSeparator {
+    Line(LineMode),
+
+    /// An inline element right before a text or another inline element, without any whitespace
+    /// in between. Nothing is written, because a line break would be rendered as a space.
+    ///
+    /// ```html
+    /// <p><b>bold</b>text</p>
+    /// ```
+    Adjacent,
+}
+
+impl ElementSeparator {
+    /// Returns if formatting this separator will result in a child that expands
+    fn will_break(&self) -> bool {
+        matches!(self, ElementSeparator::Line(mode) if mode.is_hard())
+    }
+}
+
+impl Format<HtmlFormatContext> for ElementSeparator {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        match self {
+            ElementSeparator::Line(mode) => f.write_element(FormatElement::Line(*mode)),
+            ElementSeparator::Adjacent => Ok(()),
         }
     }
 }
@@ -492,14 +659,18 @@ enum MultilineLayout {
 struct MultilineBuilder {
     layout: MultilineLayout,
     is_root: bool,
+    hug_start: bool,
+    hug_end: bool,
     result: FormatResult<Vec<FormatElement>>,
 }
 
 impl MultilineBuilder {
-    fn new(layout: MultilineLayout, is_root: bool) -> Self {
+    fn new(layout: MultilineLayout, is_root: bool, hug_start: bool, hug_end: bool) -> Self {
         Self {
             layout,
             is_root,
+            hug_start,
+            hug_end,
             result: Ok(Vec::new()),
         }
     }
@@ -571,6 +742,8 @@ impl MultilineBuilder {
         Ok(FormatMultilineChildren {
             layout: self.layout,
             is_root: self.is_root,
+            hug_start: self.hug_start,
+            hug_end: self.hug_end,
             elements: RefCell::new(self.result?),
         })
     }
@@ -580,6 +753,12 @@ impl MultilineBuilder {
 pub(crate) struct FormatMultilineChildren {
     layout: MultilineLayout,
     is_root: bool,
+    /// Whether the children start right after the opening tag, because a line break would be
+    /// rendered as a space.
+    hug_start: bool,
+    /// Whether the closing tag follows the children right away, because a line break would be
+    /// rendered as a space.
+    hug_end: bool,
     elements: RefCell<Vec<FormatElement>>,
 }
 
@@ -632,7 +811,31 @@ impl Format<HtmlFormatContext> for FormatMultilineChildren {
         // that content that breaks shouldn't be considered flat and should be
         // expanded. This is in contrast to something like a concise array fill,
         // which _does_ allow breaks to fit and preserves density.
-        write!(f, [group(&block_indent(&format_inner))])
+        if self.hug_start || self.hug_end {
+            let format_start = format_with(|f| {
+                if self.hug_start {
+                    Ok(())
+                } else {
+                    write!(f, [hard_line_break()])
+                }
+            });
+            let format_end = format_with(|f| {
+                if self.hug_end {
+                    Ok(())
+                } else {
+                    write!(f, [hard_line_break()])
+                }
+            });
+            write!(
+                f,
+                [group(&format_args![
+                    indent(&format_args![format_start, format_inner]),
+                    format_end
+                ])]
+            )
+        } else {
+            write!(f, [group(&block_indent(&format_inner))])
+        }
     }
 }
 
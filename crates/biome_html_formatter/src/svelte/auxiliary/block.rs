@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteBlock, SvelteBlockFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteBlock;
+impl FormatNodeRule<SvelteBlock> for FormatSvelteBlock {
+    fn fmt_fields(&self, node: &SvelteBlock, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteBlockFields {
+            opening,
+            children,
+            clauses,
+            closing,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                opening.format(),
+                children.format(),
+                clauses.format(),
+                closing.format()
+            ]
+        )
+    }
+}
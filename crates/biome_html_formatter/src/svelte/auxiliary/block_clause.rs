@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteBlockClause, SvelteBlockClauseFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteBlockClause;
+impl FormatNodeRule<SvelteBlockClause> for FormatSvelteBlockClause {
+    fn fmt_fields(&self, node: &SvelteBlockClause, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteBlockClauseFields {
+            sv_curly_colon_token,
+            name,
+            expression,
+            r_curly_token,
+            children,
+        } = node.as_fields();
+
+        write!(f, [sv_curly_colon_token.format(), name.format()])?;
+        if let Some(expression) = expression {
+            write!(f, [space(), expression.format()])?;
+        }
+        write!(f, [r_curly_token.format(), children.format()])
+    }
+}
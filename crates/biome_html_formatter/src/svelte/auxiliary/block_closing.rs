@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteBlockClosing, SvelteBlockClosingFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteBlockClosing;
+impl FormatNodeRule<SvelteBlockClosing> for FormatSvelteBlockClosing {
+    fn fmt_fields(&self, node: &SvelteBlockClosing, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteBlockClosingFields {
+            sv_curly_slash_token,
+            name,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                sv_curly_slash_token.format(),
+                name.format(),
+                r_curly_token.format()
+            ]
+        )
+    }
+}
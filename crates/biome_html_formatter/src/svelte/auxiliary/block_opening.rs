@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteBlockOpening, SvelteBlockOpeningFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteBlockOpening;
+impl FormatNodeRule<SvelteBlockOpening> for FormatSvelteBlockOpening {
+    fn fmt_fields(&self, node: &SvelteBlockOpening, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteBlockOpeningFields {
+            sv_curly_hash_token,
+            name,
+            expression,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(f, [sv_curly_hash_token.format(), name.format()])?;
+        if let Some(expression) = expression {
+            write!(f, [space(), expression.format()])?;
+        }
+        write!(f, [r_curly_token.format()])
+    }
+}
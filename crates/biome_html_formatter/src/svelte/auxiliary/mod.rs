@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block;
+pub(crate) mod block_clause;
+pub(crate) mod block_closing;
+pub(crate) mod block_opening;
+pub(crate) mod special_tag;
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteSpecialTag, SvelteSpecialTagFields};
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteSpecialTag;
+impl FormatNodeRule<SvelteSpecialTag> for FormatSvelteSpecialTag {
+    fn fmt_fields(&self, node: &SvelteSpecialTag, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteSpecialTagFields {
+            sv_curly_at_token,
+            name,
+            expression,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(f, [sv_curly_at_token.format(), name.format()])?;
+        if let Some(expression) = expression {
+            write!(f, [space(), expression.format()])?;
+        }
+        write!(f, [r_curly_token.format()])
+    }
+}
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_html_syntax::SvelteBlockClauseList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteBlockClauseList;
+impl FormatRule<SvelteBlockClauseList> for FormatSvelteBlockClauseList {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &SvelteBlockClauseList, f: &mut HtmlFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
@@ -123,6 +123,14 @@ impl HtmlChild {
     pub(crate) const fn is_any_line(&self) -> bool {
         matches!(self, HtmlChild::EmptyLine | HtmlChild::Newline)
     }
+
+    /// Returns `true` if the child is a whitespace, a new line or an empty line
+    pub(crate) const fn is_whitespace(&self) -> bool {
+        matches!(
+            self,
+            HtmlChild::Whitespace | HtmlChild::Newline | HtmlChild::EmptyLine
+        )
+    }
 }
 
 /// Creates either a space using an expression child and a string literal,
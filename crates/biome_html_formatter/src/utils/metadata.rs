@@ -0,0 +1,152 @@
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlSyntaxToken};
+
+use crate::context::WhitespaceSensitivity;
+
+/// The elements that aren't displayed inline by the
+/// [default stylesheet of the browsers](https://html.spec.whatwg.org/multipage/rendering.html).
+///
+/// The other elements, including the custom elements and the components, are displayed inline.
+const NON_INLINE_ELEMENTS: &[&str] = &[
+    "address",
+    "area",
+    "article",
+    "aside",
+    "base",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "datalist",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "legend",
+    "li",
+    "link",
+    "listing",
+    "main",
+    "menu",
+    "meta",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "plaintext",
+    "pre",
+    "rp",
+    "script",
+    "search",
+    "section",
+    "source",
+    "style",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+    "xmp",
+];
+
+/// Returns `true` if the element called `name` is displayed inline by the browsers, like
+/// `<span>` or `<a>`.
+pub(crate) fn is_inline_element_name(name: &str) -> bool {
+    !NON_INLINE_ELEMENTS
+        .iter()
+        .any(|element| element.eq_ignore_ascii_case(name))
+}
+
+/// Returns `true` if the whitespace around `element` is significant with the given
+/// `sensitivity`, because it's rendered as a space.
+///
+/// The text is always inline, so this also applies to the whitespace between `element` and a text.
+pub(crate) fn is_inline(element: &AnyHtmlElement, sensitivity: WhitespaceSensitivity) -> bool {
+    match sensitivity {
+        WhitespaceSensitivity::Ignore => false,
+        WhitespaceSensitivity::Strict => matches!(
+            element,
+            AnyHtmlElement::HtmlElement(_)
+                | AnyHtmlElement::HtmlSelfClosingElement(_)
+                | AnyHtmlElement::HtmlContent(_)
+                | AnyHtmlElement::HtmlSingleTextExpression(_)
+                | AnyHtmlElement::SvelteSpecialTag(_)
+        ),
+        WhitespaceSensitivity::Css => match element {
+            AnyHtmlElement::HtmlElement(element) => element_name(element)
+                .is_some_and(|name| is_inline_element_name(name.text_trimmed())),
+            AnyHtmlElement::HtmlSelfClosingElement(element) => element
+                .name()
+                .and_then(|name| name.value_token())
+                .is_ok_and(|name| is_inline_element_name(name.text_trimmed())),
+            AnyHtmlElement::HtmlContent(_)
+            | AnyHtmlElement::HtmlSingleTextExpression(_)
+            | AnyHtmlElement::SvelteSpecialTag(_) => true,
+            AnyHtmlElement::HtmlBogusElement(_)
+            | AnyHtmlElement::HtmlComment(_)
+            | AnyHtmlElement::SvelteBlock(_) => false,
+        },
+    }
+}
+
+/// Returns `true` if the whitespace inside `element`, after its opening tag and before its
+/// closing tag, is significant with the given `sensitivity`.
+pub(crate) fn is_inline_element(element: &HtmlElement, sensitivity: WhitespaceSensitivity) -> bool {
+    match sensitivity {
+        WhitespaceSensitivity::Ignore => false,
+        WhitespaceSensitivity::Strict => true,
+        WhitespaceSensitivity::Css => {
+            element_name(element).is_some_and(|name| is_inline_element_name(name.text_trimmed()))
+        }
+    }
+}
+
+fn element_name(element: &HtmlElement) -> Option<HtmlSyntaxToken> {
+    element
+        .opening_element()
+        .ok()?
+        .name()
+        .ok()?
+        .value_token()
+        .ok()
+}
+
+/// Returns `true` if `token` is followed by whitespace or a line break
+pub(crate) fn has_trailing_whitespace(token: &HtmlSyntaxToken) -> bool {
+    token
+        .trailing_trivia()
+        .pieces()
+        .any(|piece| piece.is_whitespace() || piece.is_newline())
+}
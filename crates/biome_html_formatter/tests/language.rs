@@ -2,7 +2,7 @@ use biome_formatter_test::TestFormatLanguage;
 use biome_fs::BiomePath;
 use biome_html_formatter::context::HtmlFormatContext;
 use biome_html_formatter::HtmlFormatLanguage;
-use biome_html_parser::parse_html;
+use biome_html_parser::{parse_html, HtmlParserOptions};
 use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_parser::AnyParse;
 use biome_service::{
@@ -11,7 +11,6 @@ use biome_service::{
 };
 
 pub struct HtmlTestFormatLanguage {
-    #[allow(dead_code)]
     source_type: HtmlFileSource,
 }
 
@@ -27,7 +26,7 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
     type FormatLanguage = HtmlFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        parse_html(text).into()
+        parse_html(text, HtmlParserOptions::from(&self.source_type)).into()
     }
 
     fn to_format_language(
@@ -35,10 +34,11 @@ impl TestFormatLanguage for HtmlTestFormatLanguage {
         settings: &Settings,
         file_source: &DocumentFileSource,
     ) -> Self::FormatLanguage {
+        let language_settings = &settings.languages.html.formatter;
         let options = Self::ServiceLanguage::resolve_format_options(
             Some(&settings.formatter),
             Some(&settings.override_settings),
-            None,
+            Some(language_settings),
             &BiomePath::new(""),
             file_source,
         );
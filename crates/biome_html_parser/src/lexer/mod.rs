@@ -1,5 +1,6 @@
 mod tests;
 
+use crate::parser::HtmlParserOptions;
 use crate::token_source::{HtmlEmbededLanguage, HtmlLexContext};
 use biome_html_syntax::HtmlSyntaxKind::{
     DOCTYPE_KW, EOF, ERROR_TOKEN, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL, NEWLINE, TOMBSTONE,
@@ -33,6 +34,8 @@ pub(crate) struct HtmlLexer<'src> {
     after_newline: bool,
 
     unicode_bom_length: usize,
+
+    options: HtmlParserOptions,
 }
 
 impl<'src> HtmlLexer<'src> {
@@ -47,9 +50,14 @@ impl<'src> HtmlLexer<'src> {
             after_newline: false,
             current_flags: TokenFlags::empty(),
             unicode_bom_length: 0,
+            options: HtmlParserOptions::default(),
         }
     }
 
+    pub(crate) fn with_options(self, options: HtmlParserOptions) -> Self {
+        Self { options, ..self }
+    }
+
     /// Consume a token in the [HtmlLexContext::Regular] context.
     fn consume_token(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -60,6 +68,13 @@ impl<'src> HtmlLexer<'src> {
             b'=' => self.consume_byte(T![=]),
             b'!' => self.consume_byte(T![!]),
             b'\'' | b'"' => self.consume_string_literal(current),
+            // `<input {value} {...props}>`
+            b'{' if self.options.has_text_expressions() => {
+                self.consume_curly_expression();
+                HTML_LITERAL
+            }
+            // The frontmatter of an Astro component that starts with a byte order mark
+            b'-' if self.at_frontmatter_start() => self.consume_frontmatter_fence(),
             // TODO: differentiate between attribute names and identifiers
             _ if is_identifier_byte(current) || is_attribute_name_byte(current) => {
                 self.consume_identifier(current, false)
@@ -81,10 +96,172 @@ impl<'src> HtmlLexer<'src> {
         match current {
             b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
             b'<' => self.consume_l_angle(),
-            _ => self.consume_html_text(),
+            b'{' if self.options.svelte => self.consume_svelte_l_curly(),
+            b'{' if self.options.astro => self.consume_byte(T!['{']),
+            b'-' if self.at_frontmatter_start() => self.consume_frontmatter_fence(),
+            _ => {
+                if self.position == 0 {
+                    if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                        self.unicode_bom_length = bom_size;
+                        return bom;
+                    }
+                }
+                self.consume_html_text()
+            }
         }
     }
 
+    /// Consume a `{` that starts a Svelte expression, block or tag, like `{#if` or `{@html`.
+    fn consume_svelte_l_curly(&mut self) -> HtmlSyntaxKind {
+        self.assert_byte(b'{');
+
+        let kind = match self.byte_at(1) {
+            Some(b'#') => T!["{#"],
+            Some(b':') => T!["{:"],
+            Some(b'/') => T!["{/"],
+            Some(b'@') => T!["{@"],
+            _ => return self.consume_byte(T!['{']),
+        };
+        self.advance(2);
+        kind
+    }
+
+    /// Consume a token in the [HtmlLexContext::SvelteName] context.
+    fn consume_token_svelte_name(&mut self, current: u8) -> HtmlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'}' => self.consume_byte(T!['}']),
+            _ if current.is_ascii_alphabetic() => {
+                while self
+                    .current_byte()
+                    .is_some_and(|byte| byte.is_ascii_alphabetic())
+                {
+                    self.advance(1);
+                }
+                HTML_LITERAL
+            }
+            _ => self.consume_unexpected_character(),
+        }
+    }
+
+    /// Consume a token in the [HtmlLexContext::TextExpression] context.
+    ///
+    /// The expression is a single token that ends before the `}` that closes the tag. Its
+    /// trailing whitespace is left to the trivia of the `}`.
+    fn consume_token_text_expression(&mut self, current: u8) -> HtmlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'}' => self.consume_byte(T!['}']),
+            _ => {
+                let mut end = self.position;
+                let mut depth = 0usize;
+                while let Some(byte) = self.current_byte() {
+                    match byte {
+                        b'}' if depth == 0 => break,
+                        b'{' => {
+                            depth += 1;
+                            self.advance(1);
+                        }
+                        b'}' => {
+                            depth -= 1;
+                            self.advance(1);
+                        }
+                        b'\'' | b'"' | b'`' => self.consume_quoted_expression_text(byte),
+                        _ => self.advance_byte_or_char(byte),
+                    }
+                    if !matches!(byte, b'\n' | b'\r' | b'\t' | b' ') {
+                        end = self.position;
+                    }
+                }
+                self.position = end;
+                HTML_LITERAL
+            }
+        }
+    }
+
+    /// Consume a `{...}` expression, including its curly braces, like the value of the
+    /// attribute `class={active ? "active" : ""}` of a Svelte component.
+    fn consume_curly_expression(&mut self) {
+        self.assert_byte(b'{');
+
+        let mut depth = 0usize;
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'{' => {
+                    depth += 1;
+                    self.advance(1);
+                }
+                b'}' => {
+                    self.advance(1);
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                b'\'' | b'"' | b'`' => self.consume_quoted_expression_text(byte),
+                _ => self.advance_byte_or_char(byte),
+            }
+        }
+    }
+
+    /// Consume a string or a template literal of an expression, so that the curly braces it
+    /// contains don't close the expression.
+    fn consume_quoted_expression_text(&mut self, quote: u8) {
+        self.advance(1);
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\\' => {
+                    self.advance(1);
+                    if let Some(byte) = self.current_byte() {
+                        self.advance_byte_or_char(byte);
+                    }
+                }
+                _ if byte == quote => {
+                    self.advance(1);
+                    break;
+                }
+                _ => self.advance_byte_or_char(byte),
+            }
+        }
+    }
+
+    /// Consume a token in the [HtmlLexContext::AstroFrontmatter] context.
+    ///
+    /// The code is a single token that ends at the start of the line of the closing fence.
+    fn consume_token_astro_frontmatter(&mut self) -> HtmlSyntaxKind {
+        if self.at_frontmatter_fence() {
+            return self.consume_frontmatter_fence();
+        }
+        while let Some(byte) = self.current_byte() {
+            self.advance_byte_or_char(byte);
+            if byte == b'\n' && self.at_frontmatter_fence() {
+                break;
+            }
+        }
+        HTML_LITERAL
+    }
+
+    /// Returns `true` if the lexer is at the `---` fence that opens the frontmatter of an Astro
+    /// component, at the start of the file.
+    fn at_frontmatter_start(&self) -> bool {
+        self.options.astro
+            && self.position == self.unicode_bom_length
+            && self.source[self.position..].starts_with("---")
+    }
+
+    /// Returns `true` if the lexer is at the `---` fence that closes the frontmatter of an
+    /// Astro component, at the start of a line.
+    fn at_frontmatter_fence(&self) -> bool {
+        self.position > 0
+            && self.source.as_bytes()[self.position - 1] == b'\n'
+            && self.source[self.position..].starts_with("---")
+    }
+
+    fn consume_frontmatter_fence(&mut self) -> HtmlSyntaxKind {
+        self.advance(3);
+        T![---]
+    }
+
     /// Consume a token in the [HtmlLexContext::AttributeValue] context.
     fn consume_token_attribute_value(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -92,6 +269,10 @@ impl<'src> HtmlLexer<'src> {
             b'<' => self.consume_byte(T![<]),
             b'>' => self.consume_byte(T![>]),
             b'\'' | b'"' => self.consume_string_literal(current),
+            b'{' if self.options.has_text_expressions() => {
+                self.consume_curly_expression();
+                HTML_STRING_LITERAL
+            }
             _ => self.consume_unquoted_string_literal(),
         }
     }
@@ -421,6 +602,7 @@ impl<'src> HtmlLexer<'src> {
         while let Some(current) = self.current_byte() {
             match current {
                 b'<' => break,
+                b'{' if self.options.has_text_expressions() => break,
                 b'\n' | b'\r' => {
                     self.after_newline = true;
                     break;
@@ -479,6 +661,9 @@ impl<'src> Lexer<'src> for HtmlLexer<'src> {
                         self.consume_token_embedded_language(current, lang)
                     }
                     HtmlLexContext::Comment => self.consume_inside_comment(current),
+                    HtmlLexContext::SvelteName => self.consume_token_svelte_name(current),
+                    HtmlLexContext::TextExpression => self.consume_token_text_expression(current),
+                    HtmlLexContext::AstroFrontmatter => self.consume_token_astro_frontmatter(),
                 },
                 None => EOF,
             }
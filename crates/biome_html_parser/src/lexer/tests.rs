@@ -326,3 +326,36 @@ fn comment_full() {
         COMMENT_END: 3,
     }
 }
+
+#[test]
+fn svelte_name() {
+    assert_lex! {
+        HtmlLexContext::SvelteName,
+        "each }",
+        HTML_LITERAL: 4,
+        WHITESPACE: 1,
+        R_CURLY: 1,
+    }
+}
+
+#[test]
+fn text_expression() {
+    assert_lex! {
+        HtmlLexContext::TextExpression,
+        " a.b({ c: '}' }) }",
+        WHITESPACE: 1,
+        HTML_LITERAL: 15,
+        WHITESPACE: 1,
+        R_CURLY: 1,
+    }
+}
+
+#[test]
+fn astro_frontmatter() {
+    assert_lex! {
+        HtmlLexContext::AstroFrontmatter,
+        "\nconst a = 1;\n---",
+        HTML_LITERAL: 14,
+        FENCE: 3,
+    }
+}
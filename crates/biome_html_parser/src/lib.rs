@@ -3,6 +3,7 @@ mod parser;
 mod syntax;
 mod token_source;
 
+pub use crate::parser::HtmlParserOptions;
 use crate::parser::{HtmlLosslessTreeSink, HtmlParser};
 use crate::syntax::parse_root;
 use biome_html_syntax::{HtmlRoot, HtmlSyntaxNode};
@@ -11,9 +12,13 @@ use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 
 /// Parses the provided string as HTML program using the provided node cache.
-pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
+pub fn parse_html_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: HtmlParserOptions,
+) -> HtmlParse {
     tracing::debug_span!("Parsing phase").in_scope(move || {
-        let mut parser = HtmlParser::new(source);
+        let mut parser = HtmlParser::new(source, options);
 
         parse_root(&mut parser);
 
@@ -26,9 +31,9 @@ pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
         HtmlParse::new(green, diagnostics)
     })
 }
-pub fn parse_html(source: &str) -> HtmlParse {
+pub fn parse_html(source: &str, options: HtmlParserOptions) -> HtmlParse {
     let mut cache = NodeCache::default();
-    parse_html_with_cache(source, &mut cache)
+    parse_html_with_cache(source, &mut cache, options)
 }
 
 /// A utility struct for managing the result of a parser job
@@ -46,13 +51,13 @@ impl HtmlParse {
     /// The syntax node represented by this Parse result
     ///
     /// ```
-    /// # use biome_html_parser::parse_html;
+    /// # use biome_html_parser::{parse_html, HtmlParserOptions};
     /// # use biome_html_syntax::HtmlSyntaxKind;
     /// # use biome_rowan::{AstNode, AstNodeList, SyntaxError};
     ///
     /// # fn main() -> Result<(), SyntaxError> {
     /// use biome_html_syntax::HtmlSyntaxKind;
-    /// // let parse = parse_html(r#"<html></html>"#);
+    /// // let parse = parse_html(r#"<html></html>"#, HtmlParserOptions::default());
     ///
     /// // Get the root value
     /// // let root_value = parse.tree().html()?;
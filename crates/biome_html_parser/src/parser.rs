@@ -1,6 +1,6 @@
 use crate::token_source::HtmlTokenSource;
 use biome_html_factory::HtmlSyntaxFactory;
-use biome_html_syntax::{HtmlLanguage, HtmlSyntaxKind};
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage, HtmlSyntaxKind};
 use biome_parser::diagnostic::{merge_diagnostics, ParseDiagnostic};
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
@@ -15,11 +15,51 @@ pub(crate) struct HtmlParser<'source> {
     source: HtmlTokenSource<'source>,
 }
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct HtmlParserOptions {
+    /// Enables parsing of the Svelte template syntax: the `{expression}` tags, the `{#if}`,
+    /// `{#each}` or `{#await}` blocks, and the `{@html}` or `{@const}` tags.
+    /// Defaults to `false`.
+    pub svelte: bool,
+    /// Enables parsing of the template syntax of Astro: the `---` frontmatter at the start of
+    /// the file, and the `{expression}` tags.
+    /// Defaults to `false`.
+    pub astro: bool,
+}
+
+impl HtmlParserOptions {
+    /// Enables parsing of the Svelte template syntax.
+    pub fn with_svelte(mut self) -> Self {
+        self.svelte = true;
+        self
+    }
+
+    /// Enables parsing of the template syntax of Astro.
+    pub fn with_astro(mut self) -> Self {
+        self.astro = true;
+        self
+    }
+
+    /// Returns `true` if the markup can contain `{expression}` tags.
+    pub(crate) fn has_text_expressions(&self) -> bool {
+        self.svelte || self.astro
+    }
+}
+
+impl From<&HtmlFileSource> for HtmlParserOptions {
+    fn from(file_source: &HtmlFileSource) -> Self {
+        Self {
+            svelte: file_source.is_svelte(),
+            astro: file_source.is_astro(),
+        }
+    }
+}
+
 impl<'source> HtmlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: HtmlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, options),
         }
     }
 
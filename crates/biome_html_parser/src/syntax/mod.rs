@@ -28,12 +28,36 @@ pub(crate) fn parse_root(p: &mut HtmlParser) {
 
     p.eat(UNICODE_BOM);
 
+    parse_astro_frontmatter(p).ok();
     parse_doc_type(p).ok();
-    ElementList.parse_list(p);
+    ElementList::default().parse_list(p);
 
     m.complete(p, HTML_ROOT);
 }
 
+/// Parses the frontmatter of an Astro component. Its code is a single token, which is formatted
+/// by the JavaScript formatter.
+///
+/// ```astro
+/// ---
+/// const title = "Hello";
+/// ---
+/// ```
+fn parse_astro_frontmatter(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T![---]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_with_context(T![---], HtmlLexContext::AstroFrontmatter);
+    if p.at(HTML_LITERAL) {
+        p.bump_with_context(HTML_LITERAL, HtmlLexContext::AstroFrontmatter);
+    }
+    p.expect_with_context(T![---], HtmlLexContext::OutsideTag);
+
+    Present(m.complete(p, ASTRO_FRONTMATTER_ELEMENT))
+}
+
 fn parse_doc_type(p: &mut HtmlParser) -> ParsedSyntax {
     if !(p.at(T![<]) && p.nth_at(1, T![!])) {
         return Absent;
@@ -112,7 +136,7 @@ fn parse_element(p: &mut HtmlParser) -> ParsedSyntax {
         );
         let opening = m.complete(p, HTML_OPENING_ELEMENT);
         loop {
-            ElementList.parse_list(p);
+            ElementList::default().parse_list(p);
             if let Some(mut closing) =
                 parse_closing_element(p).or_add_diagnostic(p, expected_closing_tag)
             {
@@ -149,7 +173,19 @@ fn parse_closing_element(p: &mut HtmlParser) -> ParsedSyntax {
 }
 
 #[derive(Default)]
-struct ElementList;
+struct ElementList {
+    /// The list is the content of a Svelte block, which ends with a clause such as `{:else}`,
+    /// or with the closing tag of the block, such as `{/if}`.
+    in_svelte_block: bool,
+}
+
+impl ElementList {
+    fn svelte_block() -> Self {
+        Self {
+            in_svelte_block: true,
+        }
+    }
+}
 
 impl ParseNodeList for ElementList {
     type Kind = HtmlSyntaxKind;
@@ -160,6 +196,9 @@ impl ParseNodeList for ElementList {
         match p.cur() {
             T![<!--] => parse_comment(p),
             T![<] => parse_element(p),
+            T!['{'] => parse_single_text_expression(p),
+            T!["{#"] => parse_svelte_block(p),
+            T!["{@"] => parse_svelte_special_tag(p),
             HTML_LITERAL => {
                 let m = p.start();
                 p.bump_with_context(HTML_LITERAL, HtmlLexContext::OutsideTag);
@@ -173,7 +212,8 @@ impl ParseNodeList for ElementList {
         let at_l_angle0 = p.at(T![<]);
         let at_slash1 = p.nth_at(1, T![/]);
         let at_eof = p.at(EOF);
-        at_l_angle0 && at_slash1 || at_eof
+        let at_svelte_block_end = self.in_svelte_block && (p.at(T!["{:"]) || p.at(T!["{/"]));
+        at_l_angle0 && at_slash1 || at_eof || at_svelte_block_end
     }
 
     fn recover(
@@ -275,3 +315,135 @@ fn parse_comment(p: &mut HtmlParser) -> ParsedSyntax {
     p.expect(T![-->]);
     Present(m.complete(p, HTML_COMMENT))
 }
+
+/// Parses an expression between curly braces, such as `{count + 1}` in Svelte.
+fn parse_single_text_expression(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(T!['{'], HtmlLexContext::TextExpression);
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+    p.expect_with_context(T!['}'], HtmlLexContext::OutsideTag);
+    Present(m.complete(p, HTML_SINGLE_TEXT_EXPRESSION))
+}
+
+fn parse_text_expression(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(HTML_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(HTML_LITERAL, HtmlLexContext::TextExpression);
+    Present(m.complete(p, HTML_TEXT_EXPRESSION))
+}
+
+/// Parses the name of a Svelte block or tag, such as `if` in `{#if visible}`.
+fn parse_svelte_name(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(HTML_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(HTML_LITERAL, HtmlLexContext::TextExpression);
+    Present(m.complete(p, HTML_NAME))
+}
+
+/// Parses a Svelte block, such as `{#if visible}...{:else}...{/if}`.
+fn parse_svelte_block(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{#"]) {
+        return Absent;
+    }
+    let m = p.start();
+
+    let opening = p.start();
+    p.bump_with_context(T!["{#"], HtmlLexContext::SvelteName);
+    let block_name = p.cur_text().to_string();
+    parse_svelte_name(p).or_add_diagnostic(p, expected_block_name);
+    parse_text_expression(p).ok();
+    p.expect_with_context(T!['}'], HtmlLexContext::OutsideTag);
+    opening.complete(p, SVELTE_BLOCK_OPENING);
+
+    ElementList::svelte_block().parse_list(p);
+    SvelteBlockClauseList.parse_list(p);
+
+    if let Some(closing) =
+        parse_svelte_block_closing(p).or_add_diagnostic(p, expected_closing_block)
+    {
+        let closing_name = closing
+            .text(p)
+            .trim_start_matches("{/")
+            .trim_end_matches('}')
+            .trim();
+        if closing_name != block_name {
+            p.error(expected_matching_closing_block(p, closing.range(p)));
+        }
+    }
+
+    Present(m.complete(p, SVELTE_BLOCK))
+}
+
+#[derive(Default)]
+struct SvelteBlockClauseList;
+
+impl ParseNodeList for SvelteBlockClauseList {
+    type Kind = HtmlSyntaxKind;
+    type Parser<'source> = HtmlParser<'source>;
+    const LIST_KIND: Self::Kind = SVELTE_BLOCK_CLAUSE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_svelte_block_clause(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T!["{:"])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(HTML_BOGUS_ELEMENT, token_set![T!["{:"], T!["{/"]]),
+            expected_block_clause,
+        )
+    }
+}
+
+/// Parses a clause of a Svelte block, such as `{:else}` or `{:then value}`, with its content.
+fn parse_svelte_block_clause(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{:"]) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(T!["{:"], HtmlLexContext::SvelteName);
+    parse_svelte_name(p).or_add_diagnostic(p, expected_block_name);
+    parse_text_expression(p).ok();
+    p.expect_with_context(T!['}'], HtmlLexContext::OutsideTag);
+    ElementList::svelte_block().parse_list(p);
+    Present(m.complete(p, SVELTE_BLOCK_CLAUSE))
+}
+
+fn parse_svelte_block_closing(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{/"]) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(T!["{/"], HtmlLexContext::SvelteName);
+    parse_svelte_name(p).or_add_diagnostic(p, expected_block_name);
+    p.expect_with_context(T!['}'], HtmlLexContext::OutsideTag);
+    Present(m.complete(p, SVELTE_BLOCK_CLOSING))
+}
+
+/// Parses a Svelte tag, such as `{@html content}` or `{@const area = width * height}`.
+fn parse_svelte_special_tag(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{@"]) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump_with_context(T!["{@"], HtmlLexContext::SvelteName);
+    parse_svelte_name(p).or_add_diagnostic(p, expected_tag_name);
+    parse_text_expression(p).ok();
+    p.expect_with_context(T!['}'], HtmlLexContext::OutsideTag);
+    Present(m.complete(p, SVELTE_SPECIAL_TAG))
+}
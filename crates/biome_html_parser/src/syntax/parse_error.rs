@@ -56,3 +56,59 @@ pub(crate) fn void_element_should_not_have_closing_tag(
 ) -> ParseDiagnostic {
     ParseDiagnostic::new("Void elements should not have a closing tag.", range)
 }
+
+/// The parser was expecting an expression between curly braces.
+///
+/// ```svelte
+/// <p>{}</p>
+///     ^ expected expression
+/// ```
+pub(crate) fn expected_expression(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("expression", range, p).into_diagnostic(p)
+}
+
+/// The parser encountered a Svelte block or clause that doesn't have a name.
+///
+/// ```svelte
+/// {# visible}
+///    ^ expected block name
+/// ```
+pub(crate) fn expected_block_name(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("block name", range, p).into_diagnostic(p)
+}
+
+/// The parser encountered a Svelte tag that doesn't have a name.
+///
+/// ```svelte
+/// {@ content}
+///    ^ expected tag name
+/// ```
+pub(crate) fn expected_tag_name(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("tag name", range, p).into_diagnostic(p)
+}
+
+pub(crate) fn expected_block_clause(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("block clause", range, p).into_diagnostic(p)
+}
+
+/// The parser encountered a Svelte block that isn't closed.
+///
+/// ```svelte
+/// {#if visible}
+///   <p>Visible</p>
+/// ```
+pub(crate) fn expected_closing_block(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("closing block", range, p).into_diagnostic(p)
+}
+
+/// The name of the closing tag of a Svelte block doesn't match the name of the block.
+///
+/// ```svelte
+/// {#if visible}
+///   <p>Visible</p>
+/// {/each}
+/// ^^^^^^^ expected `{/if}`
+/// ```
+pub(crate) fn expected_matching_closing_block(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("matching closing block", range, p).into_diagnostic(p)
+}
@@ -1,4 +1,5 @@
 use crate::lexer::HtmlLexer;
+use crate::parser::HtmlParserOptions;
 use biome_html_syntax::HtmlSyntaxKind::EOF;
 use biome_html_syntax::{HtmlSyntaxKind, TextRange};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -35,6 +36,14 @@ pub(crate) enum HtmlLexContext {
     EmbeddedLanguage(HtmlEmbededLanguage),
     /// Comments are treated as text until the closing comment tag is encountered.
     Comment,
+    /// Lexes the name of a Svelte block or tag, such as `if` in `{#if visible}`.
+    SvelteName,
+    /// Lexes the expression of a Svelte tag or block as a single text token, up to the `}` that
+    /// closes it.
+    TextExpression,
+    /// Lexes the code of the frontmatter of an Astro component as a single text token, up to
+    /// the `---` fence that closes it.
+    AstroFrontmatter,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -60,13 +69,20 @@ impl LexContext for HtmlLexContext {
 
 impl<'source> HtmlTokenSource<'source> {
     /// Creates a new token source for the given string
-    pub fn from_str(source: &'source str) -> Self {
-        let lexer = HtmlLexer::from_str(source);
+    pub fn from_str(source: &'source str, options: HtmlParserOptions) -> Self {
+        let lexer = HtmlLexer::from_str(source).with_options(options);
 
         let buffered = BufferedLexer::new(lexer);
         let mut source = Self::new(buffered);
 
-        source.next_non_trivia_token(HtmlLexContext::Regular, true);
+        // The markup of a Svelte or an Astro component can start with some text or with an
+        // expression
+        let context = if options.has_text_expressions() {
+            HtmlLexContext::OutsideTag
+        } else {
+            HtmlLexContext::Regular
+        };
+        source.next_non_trivia_token(context, true);
         source
     }
 
@@ -1,7 +1,8 @@
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_diagnostics::{termcolor, DiagnosticExt, PrintDiagnostic};
-use biome_html_parser::parse_html;
+use biome_html_parser::{parse_html, HtmlParserOptions};
+use biome_html_syntax::HtmlFileSource;
 use biome_rowan::SyntaxKind;
 use biome_test_utils::has_bogus_nodes_or_empty_slots;
 use std::fmt::Write;
@@ -34,11 +35,16 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parsed = parse_html(&content);
+    let file_source = HtmlFileSource::try_from(test_case_path).unwrap_or_default();
+    let parsed = parse_html(&content, HtmlParserOptions::from(&file_source));
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```html\n{content}\n```\n\n").unwrap();
+    let extension = test_case_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("html");
+    writeln!(snapshot, "\n## Input\n\n```{extension}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
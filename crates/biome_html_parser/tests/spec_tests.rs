@@ -3,9 +3,9 @@
 mod spec_test;
 
 mod ok {
-    tests_macros::gen_tests! {"tests/html_specs/ok/**/*.html", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/html_specs/ok/**/*.{html,svelte,astro}", crate::spec_test::run, "ok"}
 }
 
 mod error {
-    tests_macros::gen_tests! {"tests/html_specs/error/**/*.html", crate::spec_test::run, "error"}
+    tests_macros::gen_tests! {"tests/html_specs/error/**/*.{html,svelte,astro}", crate::spec_test::run, "error"}
 }
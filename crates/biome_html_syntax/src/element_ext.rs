@@ -0,0 +1,85 @@
+use crate::{
+    HtmlAttribute, HtmlAttributeList, HtmlName, HtmlOpeningElement, HtmlSelfClosingElement,
+    HtmlString,
+};
+use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult, TextRange, TextSize, TokenText};
+
+declare_node_union! {
+    /// The start tag of an element, such as `<a href="/">` or `<img />`
+    pub AnyHtmlTag = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTag {
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        match self {
+            Self::HtmlOpeningElement(element) => element.name(),
+            Self::HtmlSelfClosingElement(element) => element.name(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            Self::HtmlOpeningElement(element) => element.attributes(),
+            Self::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    /// Returns `true` if the name of the element is `name`.
+    ///
+    /// The names of HTML elements are case-insensitive.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name()
+            .and_then(|element_name| element_name.value_token())
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the first attribute of the element named `name`
+    pub fn find_attribute_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.attributes().find_by_name(name)
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the first attribute named `name`.
+    ///
+    /// The names of HTML attributes are case-insensitive.
+    pub fn find_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.iter().find_map(|attribute| {
+            let attribute = attribute.as_html_attribute()?;
+            let token = attribute.name().ok()?.value_token().ok()?;
+            token
+                .text_trimmed()
+                .eq_ignore_ascii_case(name)
+                .then(|| attribute.clone())
+        })
+    }
+}
+
+impl HtmlAttribute {
+    /// Returns the value of the attribute without its quotes,
+    /// or `None` if the attribute has no value, such as `disabled`.
+    pub fn value(&self) -> Option<TokenText> {
+        self.initializer()?.value().ok()?.inner_string_text().ok()
+    }
+}
+
+impl HtmlString {
+    /// Returns the text of the string without its quotes.
+    ///
+    /// Unquoted attribute values, such as `button` in `type=button`, are returned as they are.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        let token = self.value_token()?;
+        let text = token.token_text_trimmed();
+        let Some(quote) = text.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+            return Ok(text);
+        };
+        let start = TextSize::of(quote);
+        // An unterminated string only has its opening quote
+        let end = if text.len() > start && text.ends_with(quote) {
+            text.len() - start
+        } else {
+            text.len()
+        };
+        Ok(text.slice(TextRange::new(start, end)))
+    }
+}
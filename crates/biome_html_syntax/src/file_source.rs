@@ -7,7 +7,6 @@ use std::{ffi::OsStr, path::Path};
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct HtmlFileSource {
-    #[allow(unused)]
     variant: HtmlVariant,
 }
 
@@ -19,6 +18,7 @@ enum HtmlVariant {
     #[default]
     Standard,
     Astro,
+    Svelte,
 }
 
 impl HtmlFileSource {
@@ -32,6 +32,23 @@ impl HtmlFileSource {
             variant: HtmlVariant::Astro,
         }
     }
+    pub fn svelte() -> Self {
+        Self {
+            variant: HtmlVariant::Svelte,
+        }
+    }
+
+    /// Returns `true` if the markup is the template of an Astro component, which starts with a
+    /// `---` frontmatter and contains `{...}` expressions.
+    pub const fn is_astro(&self) -> bool {
+        matches!(self.variant, HtmlVariant::Astro)
+    }
+
+    /// Returns `true` if the markup is the markup of a Svelte component, which contains `{...}`
+    /// expressions and `{#if}`, `{#each}` or `{@html}` blocks.
+    pub const fn is_svelte(&self) -> bool {
+        matches!(self.variant, HtmlVariant::Svelte)
+    }
 
     /// Try to return the HTML file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Path) -> Result<Self, FileSourceError> {
@@ -45,6 +62,7 @@ impl HtmlFileSource {
         match extension.as_encoded_bytes() {
             b"html" => Ok(Self::html()),
             b"astro" => Ok(Self::astro()),
+            b"svelte" => Ok(Self::svelte()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -62,6 +80,7 @@ impl HtmlFileSource {
         match language_id {
             "html" => Ok(Self::html()),
             "astro" => Ok(Self::astro()),
+            "svelte" => Ok(Self::svelte()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
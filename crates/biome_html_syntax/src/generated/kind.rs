@@ -21,6 +21,13 @@ pub enum HtmlSyntaxKind {
     MINUS,
     COMMENT_START,
     COMMENT_END,
+    L_CURLY,
+    R_CURLY,
+    SV_CURLY_HASH,
+    SV_CURLY_COLON,
+    SV_CURLY_SLASH,
+    SV_CURLY_AT,
+    FENCE,
     NULL_KW,
     TRUE_KW,
     FALSE_KW,
@@ -48,6 +55,15 @@ pub enum HtmlSyntaxKind {
     HTML_ATTRIBUTE_LIST,
     HTML_CONTENT,
     HTML_COMMENT,
+    HTML_SINGLE_TEXT_EXPRESSION,
+    HTML_TEXT_EXPRESSION,
+    ASTRO_FRONTMATTER_ELEMENT,
+    SVELTE_BLOCK,
+    SVELTE_BLOCK_OPENING,
+    SVELTE_BLOCK_CLAUSE_LIST,
+    SVELTE_BLOCK_CLAUSE,
+    SVELTE_BLOCK_CLOSING,
+    SVELTE_SPECIAL_TAG,
     HTML_BOGUS,
     HTML_BOGUS_ELEMENT,
     HTML_BOGUS_ATTRIBUTE,
@@ -58,7 +74,9 @@ use self::HtmlSyntaxKind::*;
 impl HtmlSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            L_ANGLE | R_ANGLE | SLASH | EQ | BANG | MINUS | COMMENT_START | COMMENT_END => true,
+            L_ANGLE | R_ANGLE | SLASH | EQ | BANG | MINUS | COMMENT_START | COMMENT_END
+            | L_CURLY | R_CURLY | SV_CURLY_HASH | SV_CURLY_COLON | SV_CURLY_SLASH | SV_CURLY_AT
+            | FENCE => true,
             _ => false,
         }
     }
@@ -70,7 +88,7 @@ impl HtmlSyntaxKind {
     }
     pub const fn is_list(self) -> bool {
         match self {
-            HTML_ELEMENT_LIST | HTML_ATTRIBUTE_LIST => true,
+            HTML_ELEMENT_LIST | HTML_ATTRIBUTE_LIST | SVELTE_BLOCK_CLAUSE_LIST => true,
             _ => false,
         }
     }
@@ -95,6 +113,13 @@ impl HtmlSyntaxKind {
             MINUS => "-",
             COMMENT_START => "<!--",
             COMMENT_END => "-->",
+            L_CURLY => "{",
+            R_CURLY => "}",
+            SV_CURLY_HASH => "{#",
+            SV_CURLY_COLON => "{:",
+            SV_CURLY_SLASH => "{/",
+            SV_CURLY_AT => "{@",
+            FENCE => "---",
             NULL_KW => "null",
             TRUE_KW => "true",
             FALSE_KW => "false",
@@ -108,4 +133,4 @@ impl HtmlSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: HtmlSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: HtmlSyntaxKind :: R_ANGLE } ; [/] => { $ crate :: HtmlSyntaxKind :: SLASH } ; [=] => { $ crate :: HtmlSyntaxKind :: EQ } ; [!] => { $ crate :: HtmlSyntaxKind :: BANG } ; [-] => { $ crate :: HtmlSyntaxKind :: MINUS } ; [<!--] => { $ crate :: HtmlSyntaxKind :: COMMENT_START } ; [-->] => { $ crate :: HtmlSyntaxKind :: COMMENT_END } ; [null] => { $ crate :: HtmlSyntaxKind :: NULL_KW } ; [true] => { $ crate :: HtmlSyntaxKind :: TRUE_KW } ; [false] => { $ crate :: HtmlSyntaxKind :: FALSE_KW } ; [doctype] => { $ crate :: HtmlSyntaxKind :: DOCTYPE_KW } ; [html] => { $ crate :: HtmlSyntaxKind :: HTML_KW } ; [ident] => { $ crate :: HtmlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: HtmlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: HtmlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: HtmlSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: HtmlSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: HtmlSyntaxKind :: R_ANGLE } ; [/] => { $ crate :: HtmlSyntaxKind :: SLASH } ; [=] => { $ crate :: HtmlSyntaxKind :: EQ } ; [!] => { $ crate :: HtmlSyntaxKind :: BANG } ; [-] => { $ crate :: HtmlSyntaxKind :: MINUS } ; [<!--] => { $ crate :: HtmlSyntaxKind :: COMMENT_START } ; [-->] => { $ crate :: HtmlSyntaxKind :: COMMENT_END } ; ['{'] => { $ crate :: HtmlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: HtmlSyntaxKind :: R_CURLY } ; ["{#"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_HASH } ; ["{:"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_COLON } ; ["{/"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_SLASH } ; ["{@"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_AT } ; [---] => { $ crate :: HtmlSyntaxKind :: FENCE } ; [null] => { $ crate :: HtmlSyntaxKind :: NULL_KW } ; [true] => { $ crate :: HtmlSyntaxKind :: TRUE_KW } ; [false] => { $ crate :: HtmlSyntaxKind :: FALSE_KW } ; [doctype] => { $ crate :: HtmlSyntaxKind :: DOCTYPE_KW } ; [html] => { $ crate :: HtmlSyntaxKind :: HTML_KW } ; [ident] => { $ crate :: HtmlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: HtmlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: HtmlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: HtmlSyntaxKind :: HASH } ; }
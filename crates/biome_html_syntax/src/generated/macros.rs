@@ -16,6 +16,10 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::HtmlSyntaxNode::kind(&node) {
+                $crate::HtmlSyntaxKind::ASTRO_FRONTMATTER_ELEMENT => {
+                    let $pattern = unsafe { $crate::AstroFrontmatterElement::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_ATTRIBUTE => {
                     let $pattern = unsafe { $crate::HtmlAttribute::new_unchecked(node) };
                     $body
@@ -61,10 +65,38 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlSelfClosingElement::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_SINGLE_TEXT_EXPRESSION => {
+                    let $pattern = unsafe { $crate::HtmlSingleTextExpression::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_STRING => {
                     let $pattern = unsafe { $crate::HtmlString::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_TEXT_EXPRESSION => {
+                    let $pattern = unsafe { $crate::HtmlTextExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_BLOCK_CLAUSE => {
+                    let $pattern = unsafe { $crate::SvelteBlockClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_BLOCK_CLOSING => {
+                    let $pattern = unsafe { $crate::SvelteBlockClosing::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_BLOCK_OPENING => {
+                    let $pattern = unsafe { $crate::SvelteBlockOpening::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_SPECIAL_TAG => {
+                    let $pattern = unsafe { $crate::SvelteSpecialTag::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_BOGUS => {
                     let $pattern = unsafe { $crate::HtmlBogus::new_unchecked(node) };
                     $body
@@ -85,6 +117,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlElementList::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::SVELTE_BLOCK_CLAUSE_LIST => {
+                    let $pattern = unsafe { $crate::SvelteBlockClauseList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
@@ -23,6 +23,51 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AstroFrontmatterElement {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstroFrontmatterElement {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> AstroFrontmatterElementFields {
+        AstroFrontmatterElementFields {
+            l_fence_token: self.l_fence_token(),
+            content_token: self.content_token(),
+            r_fence_token: self.r_fence_token(),
+        }
+    }
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for AstroFrontmatterElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct AstroFrontmatterElementFields {
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub content_token: Option<SyntaxToken>,
+    pub r_fence_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlAttribute {
     pub(crate) syntax: SyntaxNode,
 }
@@ -449,6 +494,7 @@ impl HtmlRoot {
     pub fn as_fields(&self) -> HtmlRootFields {
         HtmlRootFields {
             bom_token: self.bom_token(),
+            frontmatter: self.frontmatter(),
             directive: self.directive(),
             html: self.html(),
             eof_token: self.eof_token(),
@@ -457,14 +503,17 @@ impl HtmlRoot {
     pub fn bom_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, 0usize)
     }
-    pub fn directive(&self) -> Option<HtmlDirective> {
+    pub fn frontmatter(&self) -> Option<AstroFrontmatterElement> {
         support::node(&self.syntax, 1usize)
     }
+    pub fn directive(&self) -> Option<HtmlDirective> {
+        support::node(&self.syntax, 2usize)
+    }
     pub fn html(&self) -> HtmlElementList {
-        support::list(&self.syntax, 2usize)
+        support::list(&self.syntax, 3usize)
     }
     pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
+        support::required_token(&self.syntax, 4usize)
     }
 }
 impl Serialize for HtmlRoot {
@@ -478,6 +527,7 @@ impl Serialize for HtmlRoot {
 #[derive(Serialize)]
 pub struct HtmlRootFields {
     pub bom_token: Option<SyntaxToken>,
+    pub frontmatter: Option<AstroFrontmatterElement>,
     pub directive: Option<HtmlDirective>,
     pub html: HtmlElementList,
     pub eof_token: SyntaxResult<SyntaxToken>,
@@ -538,6 +588,51 @@ pub struct HtmlSelfClosingElementFields {
     pub r_angle_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlSingleTextExpression {
+    pub(crate) syntax: SyntaxNode,
+}
+impl HtmlSingleTextExpression {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> HtmlSingleTextExpressionFields {
+        HtmlSingleTextExpressionFields {
+            l_curly_token: self.l_curly_token(),
+            expression: self.expression(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn expression(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for HtmlSingleTextExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct HtmlSingleTextExpressionFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub expression: SyntaxResult<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlString {
     pub(crate) syntax: SyntaxNode,
 }
@@ -572,130 +667,485 @@ impl Serialize for HtmlString {
 pub struct HtmlStringFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyHtmlAttribute {
-    HtmlAttribute(HtmlAttribute),
-    HtmlBogusAttribute(HtmlBogusAttribute),
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlTextExpression {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AnyHtmlAttribute {
-    pub fn as_html_attribute(&self) -> Option<&HtmlAttribute> {
-        match &self {
-            AnyHtmlAttribute::HtmlAttribute(item) => Some(item),
-            _ => None,
-        }
+impl HtmlTextExpression {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_html_bogus_attribute(&self) -> Option<&HtmlBogusAttribute> {
-        match &self {
-            AnyHtmlAttribute::HtmlBogusAttribute(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> HtmlTextExpressionFields {
+        HtmlTextExpressionFields {
+            value_token: self.value_token(),
         }
     }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyHtmlElement {
-    HtmlBogusElement(HtmlBogusElement),
-    HtmlComment(HtmlComment),
-    HtmlContent(HtmlContent),
-    HtmlElement(HtmlElement),
-    HtmlSelfClosingElement(HtmlSelfClosingElement),
+impl Serialize for HtmlTextExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyHtmlElement {
-    pub fn as_html_bogus_element(&self) -> Option<&HtmlBogusElement> {
-        match &self {
-            AnyHtmlElement::HtmlBogusElement(item) => Some(item),
-            _ => None,
-        }
+#[derive(Serialize)]
+pub struct HtmlTextExpressionFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_html_comment(&self) -> Option<&HtmlComment> {
-        match &self {
-            AnyHtmlElement::HtmlComment(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> SvelteBlockFields {
+        SvelteBlockFields {
+            opening: self.opening(),
+            children: self.children(),
+            clauses: self.clauses(),
+            closing: self.closing(),
         }
     }
-    pub fn as_html_content(&self) -> Option<&HtmlContent> {
-        match &self {
-            AnyHtmlElement::HtmlContent(item) => Some(item),
-            _ => None,
-        }
+    pub fn opening(&self) -> SyntaxResult<SvelteBlockOpening> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_html_element(&self) -> Option<&HtmlElement> {
-        match &self {
-            AnyHtmlElement::HtmlElement(item) => Some(item),
-            _ => None,
-        }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 1usize)
     }
-    pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
-        match &self {
-            AnyHtmlElement::HtmlSelfClosingElement(item) => Some(item),
-            _ => None,
-        }
+    pub fn clauses(&self) -> SvelteBlockClauseList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn closing(&self) -> SyntaxResult<SvelteBlockClosing> {
+        support::required_node(&self.syntax, 3usize)
     }
 }
-impl AstNode for HtmlAttribute {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ATTRIBUTE
+impl Serialize for SvelteBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+}
+#[derive(Serialize)]
+pub struct SvelteBlockFields {
+    pub opening: SyntaxResult<SvelteBlockOpening>,
+    pub children: HtmlElementList,
+    pub clauses: SvelteBlockClauseList,
+    pub closing: SyntaxResult<SvelteBlockClosing>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteBlockClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteBlockClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteBlockClauseFields {
+        SvelteBlockClauseFields {
+            sv_curly_colon_token: self.sv_curly_colon_token(),
+            name: self.name(),
+            expression: self.expression(),
+            r_curly_token: self.r_curly_token(),
+            children: self.children(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn sv_curly_colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        support::required_node(&self.syntax, 1usize)
     }
-}
-impl std::fmt::Debug for HtmlAttribute {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlAttribute")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "initializer",
-                &support::DebugOptionalElement(self.initializer()),
-            )
-            .finish()
+    pub fn expression(&self) -> Option<HtmlTextExpression> {
+        support::node(&self.syntax, 2usize)
     }
-}
-impl From<HtmlAttribute> for SyntaxNode {
-    fn from(n: HtmlAttribute) -> SyntaxNode {
-        n.syntax
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 4usize)
     }
 }
-impl From<HtmlAttribute> for SyntaxElement {
-    fn from(n: HtmlAttribute) -> SyntaxElement {
-        n.syntax.into()
+impl Serialize for SvelteBlockClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl AstNode for HtmlAttributeInitializerClause {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE_INITIALIZER_CLAUSE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ATTRIBUTE_INITIALIZER_CLAUSE
+#[derive(Serialize)]
+pub struct SvelteBlockClauseFields {
+    pub sv_curly_colon_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<HtmlName>,
+    pub expression: Option<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub children: HtmlElementList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteBlockClosing {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteBlockClosing {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+    pub fn as_fields(&self) -> SvelteBlockClosingFields {
+        SvelteBlockClosingFields {
+            sv_curly_slash_token: self.sv_curly_slash_token(),
+            name: self.name(),
+            r_curly_token: self.r_curly_token(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn sv_curly_slash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-impl std::fmt::Debug for HtmlAttributeInitializerClause {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Serialize for SvelteBlockClosing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct SvelteBlockClosingFields {
+    pub sv_curly_slash_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<HtmlName>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteBlockOpening {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteBlockOpening {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteBlockOpeningFields {
+        SvelteBlockOpeningFields {
+            sv_curly_hash_token: self.sv_curly_hash_token(),
+            name: self.name(),
+            expression: self.expression(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn sv_curly_hash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn expression(&self) -> Option<HtmlTextExpression> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+impl Serialize for SvelteBlockOpening {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct SvelteBlockOpeningFields {
+    pub sv_curly_hash_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<HtmlName>,
+    pub expression: Option<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteSpecialTag {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteSpecialTag {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteSpecialTagFields {
+        SvelteSpecialTagFields {
+            sv_curly_at_token: self.sv_curly_at_token(),
+            name: self.name(),
+            expression: self.expression(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn sv_curly_at_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn expression(&self) -> Option<HtmlTextExpression> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+impl Serialize for SvelteSpecialTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct SvelteSpecialTagFields {
+    pub sv_curly_at_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<HtmlName>,
+    pub expression: Option<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyHtmlAttribute {
+    HtmlAttribute(HtmlAttribute),
+    HtmlBogusAttribute(HtmlBogusAttribute),
+}
+impl AnyHtmlAttribute {
+    pub fn as_html_attribute(&self) -> Option<&HtmlAttribute> {
+        match &self {
+            AnyHtmlAttribute::HtmlAttribute(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_bogus_attribute(&self) -> Option<&HtmlBogusAttribute> {
+        match &self {
+            AnyHtmlAttribute::HtmlBogusAttribute(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyHtmlElement {
+    HtmlBogusElement(HtmlBogusElement),
+    HtmlComment(HtmlComment),
+    HtmlContent(HtmlContent),
+    HtmlElement(HtmlElement),
+    HtmlSelfClosingElement(HtmlSelfClosingElement),
+    HtmlSingleTextExpression(HtmlSingleTextExpression),
+    SvelteBlock(SvelteBlock),
+    SvelteSpecialTag(SvelteSpecialTag),
+}
+impl AnyHtmlElement {
+    pub fn as_html_bogus_element(&self) -> Option<&HtmlBogusElement> {
+        match &self {
+            AnyHtmlElement::HtmlBogusElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_comment(&self) -> Option<&HtmlComment> {
+        match &self {
+            AnyHtmlElement::HtmlComment(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_content(&self) -> Option<&HtmlContent> {
+        match &self {
+            AnyHtmlElement::HtmlContent(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_element(&self) -> Option<&HtmlElement> {
+        match &self {
+            AnyHtmlElement::HtmlElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
+        match &self {
+            AnyHtmlElement::HtmlSelfClosingElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_single_text_expression(&self) -> Option<&HtmlSingleTextExpression> {
+        match &self {
+            AnyHtmlElement::HtmlSingleTextExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_svelte_block(&self) -> Option<&SvelteBlock> {
+        match &self {
+            AnyHtmlElement::SvelteBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_svelte_special_tag(&self) -> Option<&SvelteSpecialTag> {
+        match &self {
+            AnyHtmlElement::SvelteSpecialTag(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for AstroFrontmatterElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(ASTRO_FRONTMATTER_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASTRO_FRONTMATTER_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for AstroFrontmatterElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AstroFrontmatterElement")
+            .field(
+                "l_fence_token",
+                &support::DebugSyntaxResult(self.l_fence_token()),
+            )
+            .field(
+                "content_token",
+                &support::DebugOptionalElement(self.content_token()),
+            )
+            .field(
+                "r_fence_token",
+                &support::DebugSyntaxResult(self.r_fence_token()),
+            )
+            .finish()
+    }
+}
+impl From<AstroFrontmatterElement> for SyntaxNode {
+    fn from(n: AstroFrontmatterElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<AstroFrontmatterElement> for SyntaxElement {
+    fn from(n: AstroFrontmatterElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlAttribute {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ATTRIBUTE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlAttribute")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "initializer",
+                &support::DebugOptionalElement(self.initializer()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlAttribute> for SyntaxNode {
+    fn from(n: HtmlAttribute) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlAttribute> for SyntaxElement {
+    fn from(n: HtmlAttribute) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlAttributeInitializerClause {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE_INITIALIZER_CLAUSE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ATTRIBUTE_INITIALIZER_CLAUSE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlAttributeInitializerClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("HtmlAttributeInitializerClause")
             .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
             .field("value", &support::DebugSyntaxResult(self.value()))
@@ -834,30 +1284,378 @@ impl AstNode for HtmlContent {
 }
 impl std::fmt::Debug for HtmlContent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlContent")
+        f.debug_struct("HtmlContent")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlContent> for SyntaxNode {
+    fn from(n: HtmlContent) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlContent> for SyntaxElement {
+    fn from(n: HtmlContent) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlDirective {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_DIRECTIVE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_DIRECTIVE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlDirective")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .field(
+                "doctype_token",
+                &support::DebugSyntaxResult(self.doctype_token()),
+            )
+            .field(
+                "html_token",
+                &support::DebugOptionalElement(self.html_token()),
+            )
+            .field(
+                "quirk_token",
+                &support::DebugOptionalElement(self.quirk_token()),
+            )
+            .field(
+                "public_id_token",
+                &support::DebugOptionalElement(self.public_id_token()),
+            )
+            .field(
+                "system_id_token",
+                &support::DebugOptionalElement(self.system_id_token()),
+            )
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlDirective> for SyntaxNode {
+    fn from(n: HtmlDirective) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlDirective> for SyntaxElement {
+    fn from(n: HtmlDirective) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlElement")
+            .field(
+                "opening_element",
+                &support::DebugSyntaxResult(self.opening_element()),
+            )
+            .field("children", &self.children())
+            .field(
+                "closing_element",
+                &support::DebugSyntaxResult(self.closing_element()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlElement> for SyntaxNode {
+    fn from(n: HtmlElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlElement> for SyntaxElement {
+    fn from(n: HtmlElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_NAME as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlName")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlName> for SyntaxNode {
+    fn from(n: HtmlName) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlName> for SyntaxElement {
+    fn from(n: HtmlName) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlOpeningElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_OPENING_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_OPENING_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlOpeningElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlOpeningElement")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("attributes", &self.attributes())
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlOpeningElement> for SyntaxNode {
+    fn from(n: HtmlOpeningElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlOpeningElement> for SyntaxElement {
+    fn from(n: HtmlOpeningElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ROOT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlRoot")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field(
+                "frontmatter",
+                &support::DebugOptionalElement(self.frontmatter()),
+            )
+            .field(
+                "directive",
+                &support::DebugOptionalElement(self.directive()),
+            )
+            .field("html", &self.html())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<HtmlRoot> for SyntaxNode {
+    fn from(n: HtmlRoot) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlRoot> for SyntaxElement {
+    fn from(n: HtmlRoot) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlSelfClosingElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SELF_CLOSING_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_SELF_CLOSING_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlSelfClosingElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlSelfClosingElement")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("attributes", &self.attributes())
+            .field(
+                "slash_token",
+                &support::DebugOptionalElement(self.slash_token()),
+            )
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlSelfClosingElement> for SyntaxNode {
+    fn from(n: HtmlSelfClosingElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlSelfClosingElement> for SyntaxElement {
+    fn from(n: HtmlSelfClosingElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlSingleTextExpression {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SINGLE_TEXT_EXPRESSION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_SINGLE_TEXT_EXPRESSION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlSingleTextExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlSingleTextExpression")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("expression", &support::DebugSyntaxResult(self.expression()))
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlContent> for SyntaxNode {
-    fn from(n: HtmlContent) -> SyntaxNode {
+impl From<HtmlSingleTextExpression> for SyntaxNode {
+    fn from(n: HtmlSingleTextExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlContent> for SyntaxElement {
-    fn from(n: HtmlContent) -> SyntaxElement {
+impl From<HtmlSingleTextExpression> for SyntaxElement {
+    fn from(n: HtmlSingleTextExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlDirective {
+impl AstNode for HtmlString {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_DIRECTIVE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_STRING as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_DIRECTIVE
+        kind == HTML_STRING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -873,57 +1671,32 @@ impl AstNode for HtmlDirective {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlDirective {
+impl std::fmt::Debug for HtmlString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlDirective")
-            .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
-            )
-            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-            .field(
-                "doctype_token",
-                &support::DebugSyntaxResult(self.doctype_token()),
-            )
-            .field(
-                "html_token",
-                &support::DebugOptionalElement(self.html_token()),
-            )
-            .field(
-                "quirk_token",
-                &support::DebugOptionalElement(self.quirk_token()),
-            )
-            .field(
-                "public_id_token",
-                &support::DebugOptionalElement(self.public_id_token()),
-            )
-            .field(
-                "system_id_token",
-                &support::DebugOptionalElement(self.system_id_token()),
-            )
+        f.debug_struct("HtmlString")
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<HtmlDirective> for SyntaxNode {
-    fn from(n: HtmlDirective) -> SyntaxNode {
+impl From<HtmlString> for SyntaxNode {
+    fn from(n: HtmlString) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlDirective> for SyntaxElement {
-    fn from(n: HtmlDirective) -> SyntaxElement {
+impl From<HtmlString> for SyntaxElement {
+    fn from(n: HtmlString) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlElement {
+impl AstNode for HtmlTextExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_TEXT_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ELEMENT
+        kind == HTML_TEXT_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -939,37 +1712,32 @@ impl AstNode for HtmlElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlElement {
+impl std::fmt::Debug for HtmlTextExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlElement")
-            .field(
-                "opening_element",
-                &support::DebugSyntaxResult(self.opening_element()),
-            )
-            .field("children", &self.children())
+        f.debug_struct("HtmlTextExpression")
             .field(
-                "closing_element",
-                &support::DebugSyntaxResult(self.closing_element()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<HtmlElement> for SyntaxNode {
-    fn from(n: HtmlElement) -> SyntaxNode {
+impl From<HtmlTextExpression> for SyntaxNode {
+    fn from(n: HtmlTextExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlElement> for SyntaxElement {
-    fn from(n: HtmlElement) -> SyntaxElement {
+impl From<HtmlTextExpression> for SyntaxElement {
+    fn from(n: HtmlTextExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlName {
+impl AstNode for SvelteBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_NAME
+        kind == SVELTE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -985,32 +1753,32 @@ impl AstNode for HtmlName {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlName {
+impl std::fmt::Debug for SvelteBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlName")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("SvelteBlock")
+            .field("opening", &support::DebugSyntaxResult(self.opening()))
+            .field("children", &self.children())
+            .field("clauses", &self.clauses())
+            .field("closing", &support::DebugSyntaxResult(self.closing()))
             .finish()
     }
 }
-impl From<HtmlName> for SyntaxNode {
-    fn from(n: HtmlName) -> SyntaxNode {
+impl From<SvelteBlock> for SyntaxNode {
+    fn from(n: SvelteBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlName> for SyntaxElement {
-    fn from(n: HtmlName) -> SyntaxElement {
+impl From<SvelteBlock> for SyntaxElement {
+    fn from(n: SvelteBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlOpeningElement {
+impl AstNode for SvelteBlockClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_OPENING_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_BLOCK_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_OPENING_ELEMENT
+        kind == SVELTE_BLOCK_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1026,38 +1794,42 @@ impl AstNode for HtmlOpeningElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlOpeningElement {
+impl std::fmt::Debug for SvelteBlockClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlOpeningElement")
+        f.debug_struct("SvelteBlockClause")
             .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
+                "sv_curly_colon_token",
+                &support::DebugSyntaxResult(self.sv_curly_colon_token()),
             )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attributes", &self.attributes())
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "expression",
+                &support::DebugOptionalElement(self.expression()),
+            )
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
+            .field("children", &self.children())
             .finish()
     }
 }
-impl From<HtmlOpeningElement> for SyntaxNode {
-    fn from(n: HtmlOpeningElement) -> SyntaxNode {
+impl From<SvelteBlockClause> for SyntaxNode {
+    fn from(n: SvelteBlockClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlOpeningElement> for SyntaxElement {
-    fn from(n: HtmlOpeningElement) -> SyntaxElement {
+impl From<SvelteBlockClause> for SyntaxElement {
+    fn from(n: SvelteBlockClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlRoot {
+impl AstNode for SvelteBlockClosing {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ROOT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_BLOCK_CLOSING as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ROOT
+        kind == SVELTE_BLOCK_CLOSING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1073,38 +1845,37 @@ impl AstNode for HtmlRoot {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlRoot {
+impl std::fmt::Debug for SvelteBlockClosing {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlRoot")
+        f.debug_struct("SvelteBlockClosing")
             .field(
-                "bom_token",
-                &support::DebugOptionalElement(self.bom_token()),
+                "sv_curly_slash_token",
+                &support::DebugSyntaxResult(self.sv_curly_slash_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "directive",
-                &support::DebugOptionalElement(self.directive()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
-            .field("html", &self.html())
-            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
             .finish()
     }
 }
-impl From<HtmlRoot> for SyntaxNode {
-    fn from(n: HtmlRoot) -> SyntaxNode {
+impl From<SvelteBlockClosing> for SyntaxNode {
+    fn from(n: SvelteBlockClosing) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlRoot> for SyntaxElement {
-    fn from(n: HtmlRoot) -> SyntaxElement {
+impl From<SvelteBlockClosing> for SyntaxElement {
+    fn from(n: SvelteBlockClosing) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlSelfClosingElement {
+impl AstNode for SvelteBlockOpening {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SELF_CLOSING_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_BLOCK_OPENING as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_SELF_CLOSING_ELEMENT
+        kind == SVELTE_BLOCK_OPENING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1120,42 +1891,41 @@ impl AstNode for HtmlSelfClosingElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlSelfClosingElement {
+impl std::fmt::Debug for SvelteBlockOpening {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlSelfClosingElement")
+        f.debug_struct("SvelteBlockOpening")
             .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
+                "sv_curly_hash_token",
+                &support::DebugSyntaxResult(self.sv_curly_hash_token()),
             )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attributes", &self.attributes())
             .field(
-                "slash_token",
-                &support::DebugOptionalElement(self.slash_token()),
+                "expression",
+                &support::DebugOptionalElement(self.expression()),
             )
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlSelfClosingElement> for SyntaxNode {
-    fn from(n: HtmlSelfClosingElement) -> SyntaxNode {
+impl From<SvelteBlockOpening> for SyntaxNode {
+    fn from(n: SvelteBlockOpening) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlSelfClosingElement> for SyntaxElement {
-    fn from(n: HtmlSelfClosingElement) -> SyntaxElement {
+impl From<SvelteBlockOpening> for SyntaxElement {
+    fn from(n: SvelteBlockOpening) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlString {
+impl AstNode for SvelteSpecialTag {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_STRING as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_SPECIAL_TAG as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_STRING
+        kind == SVELTE_SPECIAL_TAG
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1171,23 +1941,32 @@ impl AstNode for HtmlString {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlString {
+impl std::fmt::Debug for SvelteSpecialTag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlString")
+        f.debug_struct("SvelteSpecialTag")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "sv_curly_at_token",
+                &support::DebugSyntaxResult(self.sv_curly_at_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "expression",
+                &support::DebugOptionalElement(self.expression()),
+            )
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlString> for SyntaxNode {
-    fn from(n: HtmlString) -> SyntaxNode {
+impl From<SvelteSpecialTag> for SyntaxNode {
+    fn from(n: SvelteSpecialTag) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlString> for SyntaxElement {
-    fn from(n: HtmlString) -> SyntaxElement {
+impl From<SvelteSpecialTag> for SyntaxElement {
+    fn from(n: SvelteSpecialTag) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -1278,13 +2057,31 @@ impl From<HtmlSelfClosingElement> for AnyHtmlElement {
         AnyHtmlElement::HtmlSelfClosingElement(node)
     }
 }
+impl From<HtmlSingleTextExpression> for AnyHtmlElement {
+    fn from(node: HtmlSingleTextExpression) -> AnyHtmlElement {
+        AnyHtmlElement::HtmlSingleTextExpression(node)
+    }
+}
+impl From<SvelteBlock> for AnyHtmlElement {
+    fn from(node: SvelteBlock) -> AnyHtmlElement {
+        AnyHtmlElement::SvelteBlock(node)
+    }
+}
+impl From<SvelteSpecialTag> for AnyHtmlElement {
+    fn from(node: SvelteSpecialTag) -> AnyHtmlElement {
+        AnyHtmlElement::SvelteSpecialTag(node)
+    }
+}
 impl AstNode for AnyHtmlElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = HtmlBogusElement::KIND_SET
         .union(HtmlComment::KIND_SET)
         .union(HtmlContent::KIND_SET)
         .union(HtmlElement::KIND_SET)
-        .union(HtmlSelfClosingElement::KIND_SET);
+        .union(HtmlSelfClosingElement::KIND_SET)
+        .union(HtmlSingleTextExpression::KIND_SET)
+        .union(SvelteBlock::KIND_SET)
+        .union(SvelteSpecialTag::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -1293,6 +2090,9 @@ impl AstNode for AnyHtmlElement {
                 | HTML_CONTENT
                 | HTML_ELEMENT
                 | HTML_SELF_CLOSING_ELEMENT
+                | HTML_SINGLE_TEXT_EXPRESSION
+                | SVELTE_BLOCK
+                | SVELTE_SPECIAL_TAG
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -1304,6 +2104,11 @@ impl AstNode for AnyHtmlElement {
             HTML_SELF_CLOSING_ELEMENT => {
                 AnyHtmlElement::HtmlSelfClosingElement(HtmlSelfClosingElement { syntax })
             }
+            HTML_SINGLE_TEXT_EXPRESSION => {
+                AnyHtmlElement::HtmlSingleTextExpression(HtmlSingleTextExpression { syntax })
+            }
+            SVELTE_BLOCK => AnyHtmlElement::SvelteBlock(SvelteBlock { syntax }),
+            SVELTE_SPECIAL_TAG => AnyHtmlElement::SvelteSpecialTag(SvelteSpecialTag { syntax }),
             _ => return None,
         };
         Some(res)
@@ -1315,6 +2120,9 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => &it.syntax,
             AnyHtmlElement::HtmlElement(it) => &it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => &it.syntax,
+            AnyHtmlElement::HtmlSingleTextExpression(it) => &it.syntax,
+            AnyHtmlElement::SvelteBlock(it) => &it.syntax,
+            AnyHtmlElement::SvelteSpecialTag(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -1324,6 +2132,9 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => it.syntax,
             AnyHtmlElement::HtmlElement(it) => it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.syntax,
+            AnyHtmlElement::HtmlSingleTextExpression(it) => it.syntax,
+            AnyHtmlElement::SvelteBlock(it) => it.syntax,
+            AnyHtmlElement::SvelteSpecialTag(it) => it.syntax,
         }
     }
 }
@@ -1335,6 +2146,9 @@ impl std::fmt::Debug for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlElement(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlSelfClosingElement(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::HtmlSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::SvelteBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::SvelteSpecialTag(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1346,6 +2160,9 @@ impl From<AnyHtmlElement> for SyntaxNode {
             AnyHtmlElement::HtmlContent(it) => it.into(),
             AnyHtmlElement::HtmlElement(it) => it.into(),
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.into(),
+            AnyHtmlElement::HtmlSingleTextExpression(it) => it.into(),
+            AnyHtmlElement::SvelteBlock(it) => it.into(),
+            AnyHtmlElement::SvelteSpecialTag(it) => it.into(),
         }
     }
 }
@@ -1365,6 +2182,11 @@ impl std::fmt::Display for AnyHtmlElement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AstroFrontmatterElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlAttribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1420,11 +2242,46 @@ impl std::fmt::Display for HtmlSelfClosingElement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlSingleTextExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlTextExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteBlockClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteBlockClosing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteBlockOpening {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteSpecialTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct HtmlBogus {
     syntax: SyntaxNode,
@@ -1757,6 +2614,88 @@ impl IntoIterator for HtmlElementList {
         self.iter()
     }
 }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct SvelteBlockClauseList {
+    syntax_list: SyntaxList,
+}
+impl SvelteBlockClauseList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for SvelteBlockClauseList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_BLOCK_CLAUSE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_BLOCK_CLAUSE_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<SvelteBlockClauseList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(SvelteBlockClauseList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for SvelteBlockClauseList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for SvelteBlockClauseList {
+    type Language = Language;
+    type Node = SvelteBlockClause;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for SvelteBlockClauseList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SvelteBlockClauseList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &SvelteBlockClauseList {
+    type Item = SvelteBlockClause;
+    type IntoIter = AstNodeListIterator<Language, SvelteBlockClause>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for SvelteBlockClauseList {
+    type Item = SvelteBlockClause;
+    type IntoIter = AstNodeListIterator<Language, SvelteBlockClause>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 #[derive(Clone)]
 pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
 impl Debug for DebugSyntaxElementChildren {
@@ -4,6 +4,8 @@ use biome_analyze::declare_assists_group;
 
 pub mod organize_imports;
 pub mod sort_jsx_props;
+pub mod use_explicit_type_annotation;
+pub mod use_template_literal;
 
 declare_assists_group! {
     pub Source {
@@ -11,6 +13,8 @@ declare_assists_group! {
         rules : [
             self :: organize_imports :: OrganizeImports ,
             self :: sort_jsx_props :: SortJsxProps ,
+            self :: use_explicit_type_annotation :: UseExplicitTypeAnnotation ,
+            self :: use_template_literal :: UseTemplateLiteral ,
         ]
      }
 }
@@ -0,0 +1,313 @@
+use std::borrow::Cow;
+
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Rule, RuleAction, SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{AnyFunctionLike, JsReturnStatement};
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBindingPattern, AnyJsFunction, AnyJsFunctionBody,
+    AnyJsStatement, AnyTsReturnType, AnyTsType, AnyTsVariableAnnotation, JsFileSource,
+    JsFormalParameter, JsFunctionBody, JsParameters, JsSyntaxNode, JsVariableDeclarator,
+    TsTypeAnnotation, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind, WalkEvent,
+};
+
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use crate::utils::inferred_type::InferredType;
+use crate::utils::is_node_equal;
+use crate::JsRuleAction;
+
+declare_source_rule! {
+    /// Adds an explicit type annotation to a variable, a parameter or the return of a function.
+    ///
+    /// The annotation is the type that TypeScript infers, so adding it doesn't change the type of
+    /// the declaration. It helps to enable a rule such as
+    /// [useExplicitType](https://biomejs.dev/linter/rules/use-explicit-type/) one declaration at
+    /// a time.
+    ///
+    /// The type of a variable or a parameter is inferred from its initializer, without the type
+    /// checker, in the same way as the rule
+    /// [noRedundantTypeAnnotations](https://biomejs.dev/linter/rules/no-redundant-type-annotations/).
+    /// The return type of a function is inferred from its `return` statements: they must all
+    /// return the same type, or no value at all, in which case the function returns `void`.
+    /// An `async` function returns a `Promise` of this type.
+    /// The generators aren't annotated.
+    ///
+    /// The literals of `const` declarations are annotated with their primitive type, such as
+    /// `number` for `const x = 5`, instead of the literal type `5` that TypeScript infers.
+    ///
+    /// ## Examples
+    ///
+    /// ```ts
+    /// let count = 0;
+    /// ```
+    ///
+    /// ```ts
+    /// function greet(name = "world") {
+    ///     return `Hello ${name}!`;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// const createCache = async () => new Map<string, number>();
+    /// ```
+    ///
+    pub UseExplicitTypeAnnotation {
+        version: "next",
+        name: "useExplicitTypeAnnotation",
+        language: "ts",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyDeclarationWithInferredType = AnyFunctionLike | JsFormalParameter | JsVariableDeclarator
+}
+
+impl Rule for UseExplicitTypeAnnotation {
+    type Query = Semantic<AnyDeclarationWithInferredType>;
+    type State = AnyTsType;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        match ctx.query() {
+            AnyDeclarationWithInferredType::AnyFunctionLike(function) => {
+                inferred_return_type(function, ctx)
+            }
+            AnyDeclarationWithInferredType::JsFormalParameter(parameter) => {
+                if parameter.type_annotation().is_some()
+                    || !matches!(
+                        parameter.binding().ok()?,
+                        AnyJsBindingPattern::AnyJsBinding(_)
+                    )
+                {
+                    return None;
+                }
+                let initializer = parameter.initializer()?.expression().ok()?;
+                InferredType::from_initializer(&initializer, ctx.model())?.to_ts_type()
+            }
+            AnyDeclarationWithInferredType::JsVariableDeclarator(declarator) => {
+                if declarator.variable_annotation().is_some()
+                    || !matches!(declarator.id().ok()?, AnyJsBindingPattern::AnyJsBinding(_))
+                {
+                    return None;
+                }
+                let initializer = declarator.initializer()?.expression().ok()?;
+                InferredType::from_initializer(&initializer, ctx.model())?.to_ts_type()
+            }
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, ty: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            AnyDeclarationWithInferredType::AnyFunctionLike(function) => {
+                let new_function = with_return_type(function.clone(), ty.clone())?;
+                mutation.replace_node(function.clone(), new_function);
+            }
+            AnyDeclarationWithInferredType::JsFormalParameter(parameter) => {
+                let binding = parameter.binding().ok()?;
+                let annotation = type_annotation_after(binding.syntax(), ty.clone())?;
+                let new_parameter = parameter
+                    .clone()
+                    .with_binding(binding.trim_trailing_trivia()?)
+                    .with_type_annotation(Some(annotation));
+                mutation.replace_node(parameter.clone(), new_parameter);
+            }
+            AnyDeclarationWithInferredType::JsVariableDeclarator(declarator) => {
+                let id = declarator.id().ok()?;
+                let annotation = type_annotation_after(id.syntax(), ty.clone())?;
+                let new_declarator = declarator
+                    .clone()
+                    .with_id(id.trim_trailing_trivia()?)
+                    .with_variable_annotation(Some(AnyTsVariableAnnotation::TsTypeAnnotation(
+                        annotation,
+                    )));
+                mutation.replace_node(declarator.clone(), new_declarator);
+            }
+        }
+        // `const x = 5` is annotated with `number` instead of the literal type `5`
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::MaybeIncorrect,
+            markup! { "Add the type annotation "<Emphasis>{ty.syntax().to_string()}</Emphasis>"." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the type of the values returned by `function`, or `None` if the returned values
+/// don't have the same inferred type.
+fn inferred_return_type(
+    function: &AnyFunctionLike,
+    ctx: &RuleContext<UseExplicitTypeAnnotation>,
+) -> Option<AnyTsType> {
+    if function.is_generator() || return_type_position(function)?.is_none() {
+        return None;
+    }
+    let returned_type = match function.body().ok()? {
+        AnyJsFunctionBody::AnyJsExpression(expression) => {
+            Some(InferredType::from_initializer(&expression, ctx.model())?.to_ts_type()?)
+        }
+        AnyJsFunctionBody::JsFunctionBody(body) => body_return_type(&body, ctx)?,
+    };
+    let returned_type =
+        returned_type.unwrap_or_else(|| make::ts_void_type(make::token(T![void])).into());
+    if !function.is_async() {
+        return Some(returned_type);
+    }
+    let type_arguments = make::ts_type_arguments(
+        make::token(T![<]),
+        make::ts_type_argument_list([returned_type], []),
+        make::token(T![>]),
+    );
+    Some(
+        make::ts_reference_type(make::js_reference_identifier(make::ident("Promise")).into())
+            .with_type_arguments(type_arguments)
+            .build()
+            .into(),
+    )
+}
+
+/// Returns the type returned by the `return` statements of `body`, `Some(None)` if they don't
+/// return any value, or `None` if the type can't be inferred.
+///
+/// When a value is returned, the body must end with a `return` or a `throw` statement, otherwise
+/// the function may also return `undefined` when the end of the body is reached. A body that
+/// only throws isn't annotated, because TypeScript infers `never` for some functions.
+fn body_return_type(
+    body: &JsFunctionBody,
+    ctx: &RuleContext<UseExplicitTypeAnnotation>,
+) -> Option<Option<AnyTsType>> {
+    let mut returned_type: Option<AnyTsType> = None;
+    let mut returns_nothing = false;
+    let mut iter = body.syntax().preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        // The nested functions have their own returns
+        if AnyJsControlFlowRoot::can_cast(node.kind()) {
+            iter.skip_subtree();
+            continue;
+        }
+        let Some(statement) = JsReturnStatement::cast(node) else {
+            continue;
+        };
+        let Some(argument) = statement.argument() else {
+            returns_nothing = true;
+            continue;
+        };
+        let ty = InferredType::from_initializer(&argument, ctx.model())?.to_ts_type()?;
+        match &returned_type {
+            Some(returned_type) if !is_node_equal(returned_type.syntax(), ty.syntax()) => {
+                return None
+            }
+            Some(_) => {}
+            None => returned_type = Some(ty),
+        }
+    }
+    let last_statement = body.statements().iter().last();
+    match returned_type {
+        Some(_) if returns_nothing => None,
+        Some(returned_type) => matches!(
+            last_statement,
+            Some(AnyJsStatement::JsReturnStatement(_) | AnyJsStatement::JsThrowStatement(_))
+        )
+        .then_some(Some(returned_type)),
+        None if !returns_nothing
+            && matches!(last_statement, Some(AnyJsStatement::JsThrowStatement(_))) =>
+        {
+            None
+        }
+        None => Some(None),
+    }
+}
+
+/// Returns the parameters after which the return type is written, `Some(None)` if the function
+/// already has a return type, or `None` if it can't have one without adding parentheses, such as
+/// `x => x`.
+fn return_type_position(function: &AnyFunctionLike) -> Option<Option<JsParameters>> {
+    let (parameters, return_type) = match function {
+        AnyFunctionLike::AnyJsFunction(AnyJsFunction::JsArrowFunctionExpression(arrow)) => {
+            match arrow.parameters().ok()? {
+                AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+                    (parameters, arrow.return_type_annotation())
+                }
+                AnyJsArrowFunctionParameters::AnyJsBinding(_) => return None,
+            }
+        }
+        AnyFunctionLike::AnyJsFunction(function) => (
+            function.parameters().ok()?.as_js_parameters()?.clone(),
+            function.return_type_annotation(),
+        ),
+        AnyFunctionLike::JsMethodClassMember(method) => {
+            (method.parameters().ok()?, method.return_type_annotation())
+        }
+        AnyFunctionLike::JsMethodObjectMember(method) => {
+            (method.parameters().ok()?, method.return_type_annotation())
+        }
+    };
+    Some(return_type.is_none().then_some(parameters))
+}
+
+/// Returns `function` with the return type `ty` written after its parameters.
+fn with_return_type(function: AnyFunctionLike, ty: AnyTsType) -> Option<AnyFunctionLike> {
+    let parameters = return_type_position(&function)??;
+    let annotation = make::ts_return_type_annotation(
+        make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyTsReturnType::AnyTsType(ty),
+    )
+    .with_trailing_trivia_pieces(parameters.syntax().last_trailing_trivia()?.pieces())?;
+    let parameters = parameters.trim_trailing_trivia()?;
+    Some(match function {
+        AnyFunctionLike::AnyJsFunction(function) => {
+            AnyFunctionLike::AnyJsFunction(match function {
+                AnyJsFunction::JsArrowFunctionExpression(arrow) => arrow
+                    .with_parameters(AnyJsArrowFunctionParameters::JsParameters(parameters))
+                    .with_return_type_annotation(Some(annotation))
+                    .into(),
+                AnyJsFunction::JsFunctionDeclaration(declaration) => declaration
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation))
+                    .into(),
+                AnyJsFunction::JsFunctionExportDefaultDeclaration(declaration) => declaration
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation))
+                    .into(),
+                AnyJsFunction::JsFunctionExpression(expression) => expression
+                    .with_parameters(parameters)
+                    .with_return_type_annotation(Some(annotation))
+                    .into(),
+            })
+        }
+        AnyFunctionLike::JsMethodClassMember(method) => method
+            .with_parameters(parameters)
+            .with_return_type_annotation(Some(annotation))
+            .into(),
+        AnyFunctionLike::JsMethodObjectMember(method) => method
+            .with_parameters(parameters)
+            .with_return_type_annotation(Some(annotation))
+            .into(),
+    })
+}
+
+/// Returns the annotation `: ty`, which takes the trailing trivia of `node`, after which it's
+/// written.
+fn type_annotation_after(node: &JsSyntaxNode, ty: AnyTsType) -> Option<TsTypeAnnotation> {
+    make::ts_type_annotation(
+        make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ty,
+    )
+    .with_trailing_trivia_pieces(node.last_trailing_trivia()?.pieces())
+}
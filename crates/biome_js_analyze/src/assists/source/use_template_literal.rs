@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsBinaryExpression};
+use biome_rowan::BatchMutationExt;
+
+use crate::utils::template::{
+    can_be_template_literal, is_in_concatenation, template_expression_from_binary_expression,
+};
+use crate::JsRuleAction;
+
+declare_source_rule! {
+    /// Converts a string concatenation to a template literal.
+    ///
+    /// The whole chain of `+` operators is converted, whatever its length, and the strings are
+    /// merged with the expressions they're concatenated with.
+    /// The escape sequences of the strings, such as `\n`, are kept as they're written, so a
+    /// message built over several lines keeps its line breaks where they were.
+    /// The numbers that are added before the first string stay together in a single
+    /// substitution, because they're added before being concatenated.
+    ///
+    /// Unlike the fix of the lint rule [useTemplate](https://biomejs.dev/linter/rules/use-template/),
+    /// the assist is available even when the rule is disabled.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// const message = "Hello " + user.name + ",\n" + "you have " + count + " new messages.";
+    /// ```
+    ///
+    /// ```js
+    /// const label = 1 + 2 + " items";
+    /// ```
+    ///
+    pub UseTemplateLiteral {
+        version: "next",
+        name: "useTemplateLiteral",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseTemplateLiteral {
+    type Query = Ast<JsBinaryExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_in_concatenation(node) {
+            return None;
+        }
+        can_be_template_literal(node)?.then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let template = template_expression_from_binary_expression(node)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsBinaryExpression(node.clone()),
+            AnyJsExpression::JsTemplateExpression(template),
+        );
+        // The objects are converted to strings with `toString()` instead of `valueOf()`
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::MaybeIncorrect,
+            markup! { "Convert the concatenation to a "<Emphasis>"template literal"</Emphasis>"." },
+            mutation,
+        ))
+    }
+}
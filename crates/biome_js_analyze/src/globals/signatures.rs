@@ -0,0 +1,227 @@
+//! Signatures of the global functions and methods of the web and Node.js runtimes, so the rules
+//! can know the types of their parameters and results without any declaration file.
+//!
+//! The signatures are a compact subset of the declarations of TypeScript's `lib.es5.d.ts`,
+//! `lib.es2015.core.d.ts` and `lib.dom.d.ts`, and of `@types/node`. They're generated by
+//! `cargo codegen-signatures`. The values parsed from a JSON text are `unknown` rather than `any`,
+//! because nothing is known about them until they're validated.
+
+mod generated;
+
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{global_identifier, AnyJsExpression, AnyJsMemberExpression};
+use biome_rowan::AstNode;
+
+/// Sorted arrays of the signatures, by path, and of the interfaces, by name
+pub use generated::{INTERFACES, SIGNATURES};
+
+/// The type of a parameter, a property or a result
+#[derive(Debug, Eq, PartialEq)]
+pub enum TypeRef {
+    /// A value that must be checked before it's used, like the result of `JSON.parse()`
+    Unknown,
+    /// A value that isn't checked by TypeScript, like the result of `require()`
+    Any,
+    Void,
+    Never,
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    Function,
+    /// An interface of the runtime, like `Response`
+    Interface(&'static str),
+    Array(&'static TypeRef),
+    Promise(&'static TypeRef),
+    Union(&'static [TypeRef]),
+}
+
+impl TypeRef {
+    /// Returns the type of the value resolved by a promise, or the type itself
+    pub fn awaited(&self) -> &Self {
+        match self {
+            Self::Promise(ty) => ty,
+            ty => ty,
+        }
+    }
+
+    /// Returns `true` if the type accepts `null` or `undefined`
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Self::Unknown | Self::Any | Self::Void | Self::Undefined | Self::Null => true,
+            Self::Union(types) => types.iter().any(Self::is_nullable),
+            _ => false,
+        }
+    }
+}
+
+/// A parameter of a signature, or a property of an interface
+#[derive(Debug, Eq, PartialEq)]
+pub struct Member {
+    pub name: &'static str,
+    pub ty: TypeRef,
+    pub optional: bool,
+}
+
+/// The signature of a global function, like `fetch`, or of a method, like
+/// `EventTarget.prototype.addEventListener`
+#[derive(Debug, Eq, PartialEq)]
+pub struct Signature {
+    /// The path of the function from the global object
+    pub path: &'static str,
+    pub parameters: &'static [Member],
+    pub returns: TypeRef,
+}
+
+impl Signature {
+    /// Returns the name of the function, like `addEventListener`
+    pub fn name(&self) -> &'static str {
+        self.path
+            .rsplit_once('.')
+            .map_or(self.path, |(_, name)| name)
+    }
+
+    /// Returns `true` if the function is a method of the instances of an interface
+    pub fn is_method(&self) -> bool {
+        self.path.contains(".prototype.")
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&'static Member> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+    }
+}
+
+/// An interface of the options of a function, or of the value returned by a function
+#[derive(Debug, Eq, PartialEq)]
+pub struct Interface {
+    pub name: &'static str,
+    pub properties: &'static [Member],
+}
+
+impl Interface {
+    pub fn property(&self, name: &str) -> Option<&'static Member> {
+        self.properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+}
+
+const fn required(name: &'static str, ty: TypeRef) -> Member {
+    Member {
+        name,
+        ty,
+        optional: false,
+    }
+}
+
+const fn optional(name: &'static str, ty: TypeRef) -> Member {
+    Member {
+        name,
+        ty,
+        optional: true,
+    }
+}
+
+const fn signature(
+    path: &'static str,
+    parameters: &'static [Member],
+    returns: TypeRef,
+) -> Signature {
+    Signature {
+        path,
+        parameters,
+        returns,
+    }
+}
+
+/// Returns the signature of the global function at `path`
+///
+/// ```
+/// use biome_js_analyze::globals::signatures::{global_signature, TypeRef};
+///
+/// let signature = global_signature("JSON.parse").unwrap();
+/// assert_eq!(signature.returns, TypeRef::Unknown);
+/// ```
+pub fn global_signature(path: &str) -> Option<&'static Signature> {
+    SIGNATURES
+        .binary_search_by(|signature| signature.path.cmp(path))
+        .ok()
+        .map(|index| &SIGNATURES[index])
+}
+
+/// Returns the signatures of the methods called `name`, whatever their interface
+///
+/// ```
+/// use biome_js_analyze::globals::signatures::method_signatures;
+///
+/// let mut signatures = method_signatures("addEventListener");
+/// assert_eq!(signatures.next().unwrap().path, "EventTarget.prototype.addEventListener");
+/// ```
+pub fn method_signatures(name: &str) -> impl Iterator<Item = &'static Signature> + '_ {
+    SIGNATURES
+        .iter()
+        .filter(move |signature| signature.is_method() && signature.name() == name)
+}
+
+/// Returns the interface called `name`
+///
+/// ```
+/// use biome_js_analyze::globals::signatures::interface;
+///
+/// let options = interface("AddEventListenerOptions").unwrap();
+/// assert!(options.property("passive").is_some());
+/// ```
+pub fn interface(name: &str) -> Option<&'static Interface> {
+    INTERFACES
+        .binary_search_by(|interface| interface.name.cmp(name))
+        .ok()
+        .map(|index| &INTERFACES[index])
+}
+
+/// Returns the signature of the global function called by `callee`, like `fetch`,
+/// `window.fetch` or `JSON.parse`.
+///
+/// Returns `None` if the callee is declared in the file, which shadows the global.
+pub fn callee_signature(
+    callee: &AnyJsExpression,
+    model: &SemanticModel,
+) -> Option<&'static Signature> {
+    let callee = callee.clone().omit_parentheses();
+    if let Some((reference, name)) = global_identifier(&callee) {
+        if model.binding(&reference).is_none() {
+            return global_signature(name.text());
+        }
+        return None;
+    }
+    let member = AnyJsMemberExpression::cast_ref(callee.syntax())?;
+    let object = member.object().ok()?.omit_parentheses();
+    let (reference, object_name) = global_identifier(&object)?;
+    if model.binding(&reference).is_some() {
+        return None;
+    }
+    let member_name = member.member_name()?;
+    global_signature(&format!("{}.{}", object_name.text(), member_name.text()))
+}
+
+#[test]
+fn test_order() {
+    for items in SIGNATURES.windows(2) {
+        assert!(
+            items[0].path < items[1].path,
+            "{} < {}",
+            items[0].path,
+            items[1].path
+        );
+    }
+    for items in INTERFACES.windows(2) {
+        assert!(
+            items[0].name < items[1].name,
+            "{} < {}",
+            items[0].name,
+            items[1].name
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use super::{optional, required, signature, Interface, Signature, TypeRef};
+pub const SIGNATURES: &[Signature] = &[
+    signature(
+        "AbortSignal.any",
+        &[required(
+            "signals",
+            TypeRef::Array(&TypeRef::Interface("AbortSignal")),
+        )],
+        TypeRef::Interface("AbortSignal"),
+    ),
+    signature(
+        "AbortSignal.timeout",
+        &[required("milliseconds", TypeRef::Number)],
+        TypeRef::Interface("AbortSignal"),
+    ),
+    signature(
+        "Buffer.from",
+        &[
+            required("value", TypeRef::Unknown),
+            optional("encoding", TypeRef::String),
+        ],
+        TypeRef::Interface("Buffer"),
+    ),
+    signature(
+        "Document.prototype.createElement",
+        &[
+            required("tagName", TypeRef::String),
+            optional("options", TypeRef::Interface("ElementCreationOptions")),
+        ],
+        TypeRef::Interface("HTMLElement"),
+    ),
+    signature(
+        "Document.prototype.getElementById",
+        &[required("elementId", TypeRef::String)],
+        TypeRef::Union(&[TypeRef::Interface("HTMLElement"), TypeRef::Null]),
+    ),
+    signature(
+        "Element.prototype.closest",
+        &[required("selectors", TypeRef::String)],
+        TypeRef::Union(&[TypeRef::Interface("Element"), TypeRef::Null]),
+    ),
+    signature(
+        "Element.prototype.getAttribute",
+        &[required("qualifiedName", TypeRef::String)],
+        TypeRef::Union(&[TypeRef::String, TypeRef::Null]),
+    ),
+    signature(
+        "Element.prototype.querySelector",
+        &[required("selectors", TypeRef::String)],
+        TypeRef::Union(&[TypeRef::Interface("Element"), TypeRef::Null]),
+    ),
+    signature(
+        "Element.prototype.querySelectorAll",
+        &[required("selectors", TypeRef::String)],
+        TypeRef::Interface("NodeListOf"),
+    ),
+    signature(
+        "EventTarget.prototype.addEventListener",
+        &[
+            required("type", TypeRef::String),
+            required(
+                "callback",
+                TypeRef::Union(&[
+                    TypeRef::Function,
+                    TypeRef::Interface("EventListenerObject"),
+                    TypeRef::Null,
+                ]),
+            ),
+            optional(
+                "options",
+                TypeRef::Union(&[
+                    TypeRef::Interface("AddEventListenerOptions"),
+                    TypeRef::Boolean,
+                ]),
+            ),
+        ],
+        TypeRef::Void,
+    ),
+    signature(
+        "EventTarget.prototype.dispatchEvent",
+        &[required("event", TypeRef::Interface("Event"))],
+        TypeRef::Boolean,
+    ),
+    signature(
+        "EventTarget.prototype.removeEventListener",
+        &[
+            required("type", TypeRef::String),
+            required(
+                "callback",
+                TypeRef::Union(&[
+                    TypeRef::Function,
+                    TypeRef::Interface("EventListenerObject"),
+                    TypeRef::Null,
+                ]),
+            ),
+            optional(
+                "options",
+                TypeRef::Union(&[TypeRef::Interface("EventListenerOptions"), TypeRef::Boolean]),
+            ),
+        ],
+        TypeRef::Void,
+    ),
+    signature(
+        "JSON.parse",
+        &[
+            required("text", TypeRef::String),
+            optional("reviver", TypeRef::Function),
+        ],
+        TypeRef::Unknown,
+    ),
+    signature(
+        "JSON.stringify",
+        &[
+            required("value", TypeRef::Any),
+            optional(
+                "replacer",
+                TypeRef::Union(&[
+                    TypeRef::Array(&TypeRef::Union(&[TypeRef::Number, TypeRef::String])),
+                    TypeRef::Null,
+                ]),
+            ),
+            optional("space", TypeRef::Union(&[TypeRef::String, TypeRef::Number])),
+        ],
+        TypeRef::String,
+    ),
+    signature(
+        "Number.parseInt",
+        &[
+            required("string", TypeRef::String),
+            optional("radix", TypeRef::Number),
+        ],
+        TypeRef::Number,
+    ),
+    signature(
+        "Response.json",
+        &[
+            required("data", TypeRef::Any),
+            optional("init", TypeRef::Interface("ResponseInit")),
+        ],
+        TypeRef::Interface("Response"),
+    ),
+    signature(
+        "Response.prototype.arrayBuffer",
+        &[],
+        TypeRef::Promise(&TypeRef::Interface("ArrayBuffer")),
+    ),
+    signature(
+        "Response.prototype.blob",
+        &[],
+        TypeRef::Promise(&TypeRef::Interface("Blob")),
+    ),
+    signature(
+        "Response.prototype.clone",
+        &[],
+        TypeRef::Interface("Response"),
+    ),
+    signature(
+        "Response.prototype.formData",
+        &[],
+        TypeRef::Promise(&TypeRef::Interface("FormData")),
+    ),
+    signature(
+        "Response.prototype.json",
+        &[],
+        TypeRef::Promise(&TypeRef::Unknown),
+    ),
+    signature(
+        "Response.prototype.text",
+        &[],
+        TypeRef::Promise(&TypeRef::String),
+    ),
+    signature(
+        "Storage.prototype.getItem",
+        &[required("key", TypeRef::String)],
+        TypeRef::Union(&[TypeRef::String, TypeRef::Null]),
+    ),
+    signature(
+        "Storage.prototype.removeItem",
+        &[required("key", TypeRef::String)],
+        TypeRef::Void,
+    ),
+    signature(
+        "Storage.prototype.setItem",
+        &[
+            required("key", TypeRef::String),
+            required("value", TypeRef::String),
+        ],
+        TypeRef::Void,
+    ),
+    signature(
+        "cancelAnimationFrame",
+        &[required("handle", TypeRef::Number)],
+        TypeRef::Void,
+    ),
+    signature(
+        "clearInterval",
+        &[optional(
+            "id",
+            TypeRef::Union(&[TypeRef::Number, TypeRef::Interface("Timeout")]),
+        )],
+        TypeRef::Void,
+    ),
+    signature(
+        "clearTimeout",
+        &[optional(
+            "id",
+            TypeRef::Union(&[TypeRef::Number, TypeRef::Interface("Timeout")]),
+        )],
+        TypeRef::Void,
+    ),
+    signature(
+        "fetch",
+        &[
+            required(
+                "input",
+                TypeRef::Union(&[
+                    TypeRef::Interface("Request"),
+                    TypeRef::String,
+                    TypeRef::Interface("URL"),
+                ]),
+            ),
+            optional("init", TypeRef::Interface("RequestInit")),
+        ],
+        TypeRef::Promise(&TypeRef::Interface("Response")),
+    ),
+    signature(
+        "parseInt",
+        &[
+            required("string", TypeRef::String),
+            optional("radix", TypeRef::Number),
+        ],
+        TypeRef::Number,
+    ),
+    signature("process.cwd", &[], TypeRef::String),
+    signature(
+        "process.exit",
+        &[optional("code", TypeRef::Number)],
+        TypeRef::Never,
+    ),
+    signature(
+        "process.nextTick",
+        &[required("callback", TypeRef::Function)],
+        TypeRef::Void,
+    ),
+    signature(
+        "queueMicrotask",
+        &[required("callback", TypeRef::Function)],
+        TypeRef::Void,
+    ),
+    signature(
+        "requestAnimationFrame",
+        &[required("callback", TypeRef::Function)],
+        TypeRef::Number,
+    ),
+    signature("require", &[required("id", TypeRef::String)], TypeRef::Any),
+    signature(
+        "setInterval",
+        &[
+            required(
+                "handler",
+                TypeRef::Union(&[TypeRef::String, TypeRef::Function]),
+            ),
+            optional("timeout", TypeRef::Number),
+        ],
+        TypeRef::Union(&[TypeRef::Number, TypeRef::Interface("Timeout")]),
+    ),
+    signature(
+        "setTimeout",
+        &[
+            required(
+                "handler",
+                TypeRef::Union(&[TypeRef::String, TypeRef::Function]),
+            ),
+            optional("timeout", TypeRef::Number),
+        ],
+        TypeRef::Union(&[TypeRef::Number, TypeRef::Interface("Timeout")]),
+    ),
+    signature(
+        "structuredClone",
+        &[
+            required("value", TypeRef::Any),
+            optional("options", TypeRef::Interface("StructuredSerializeOptions")),
+        ],
+        TypeRef::Any,
+    ),
+];
+pub const INTERFACES: &[Interface] = &[
+    Interface {
+        name: "AddEventListenerOptions",
+        properties: &[
+            optional("capture", TypeRef::Boolean),
+            optional("once", TypeRef::Boolean),
+            optional("passive", TypeRef::Boolean),
+            optional("signal", TypeRef::Interface("AbortSignal")),
+        ],
+    },
+    Interface {
+        name: "ElementCreationOptions",
+        properties: &[optional("is", TypeRef::String)],
+    },
+    Interface {
+        name: "EventListenerOptions",
+        properties: &[optional("capture", TypeRef::Boolean)],
+    },
+    Interface {
+        name: "RequestInit",
+        properties: &[
+            optional(
+                "body",
+                TypeRef::Union(&[
+                    TypeRef::Interface("ReadableStream"),
+                    TypeRef::Interface("Blob"),
+                    TypeRef::Interface("ArrayBufferView"),
+                    TypeRef::Interface("ArrayBuffer"),
+                    TypeRef::Interface("FormData"),
+                    TypeRef::Interface("URLSearchParams"),
+                    TypeRef::String,
+                    TypeRef::Null,
+                ]),
+            ),
+            optional("cache", TypeRef::String),
+            optional("credentials", TypeRef::String),
+            optional("headers", TypeRef::Unknown),
+            optional("integrity", TypeRef::String),
+            optional("keepalive", TypeRef::Boolean),
+            optional("method", TypeRef::String),
+            optional("mode", TypeRef::String),
+            optional("priority", TypeRef::String),
+            optional("redirect", TypeRef::String),
+            optional("referrer", TypeRef::String),
+            optional("referrerPolicy", TypeRef::String),
+            optional(
+                "signal",
+                TypeRef::Union(&[TypeRef::Interface("AbortSignal"), TypeRef::Null]),
+            ),
+            optional("window", TypeRef::Null),
+        ],
+    },
+    Interface {
+        name: "Response",
+        properties: &[
+            required(
+                "body",
+                TypeRef::Union(&[TypeRef::Interface("ReadableStream"), TypeRef::Null]),
+            ),
+            required("bodyUsed", TypeRef::Boolean),
+            required("headers", TypeRef::Interface("Headers")),
+            required("ok", TypeRef::Boolean),
+            required("redirected", TypeRef::Boolean),
+            required("status", TypeRef::Number),
+            required("statusText", TypeRef::String),
+            required("type", TypeRef::String),
+            required("url", TypeRef::String),
+        ],
+    },
+    Interface {
+        name: "ResponseInit",
+        properties: &[
+            optional("headers", TypeRef::Unknown),
+            optional("status", TypeRef::Number),
+            optional("statusText", TypeRef::String),
+        ],
+    },
+    Interface {
+        name: "StructuredSerializeOptions",
+        properties: &[optional(
+            "transfer",
+            TypeRef::Array(&TypeRef::Union(&[
+                TypeRef::Interface("OffscreenCanvas"),
+                TypeRef::Interface("ImageBitmap"),
+                TypeRef::Interface("MessagePort"),
+                TypeRef::Interface("MediaSourceHandle"),
+                TypeRef::Interface("ReadableStream"),
+                TypeRef::Interface("WritableStream"),
+                TypeRef::Interface("TransformStream"),
+                TypeRef::Interface("VideoFrame"),
+                TypeRef::Interface("ArrayBuffer"),
+            ])),
+        )],
+    },
+];
@@ -1,6 +1,8 @@
 #![warn(clippy::needless_pass_by_value)]
 
+use crate::module_graph::ModuleGraph;
 use crate::suppression_action::JsSuppressionAction;
+use biome_analyze::browserslist::BrowserTargets;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
     InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
@@ -10,7 +12,7 @@ use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Error as DiagnosticError};
 use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_project::PackageJson;
-use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax, SuppressionDiagnostic};
 use std::ops::Deref;
 use std::sync::{Arc, LazyLock};
 
@@ -18,12 +20,15 @@ pub mod assists;
 mod ast_utils;
 pub mod globals;
 pub mod lint;
+pub mod module_graph;
 pub mod options;
+mod playwright;
 mod react;
 mod registry;
 mod services;
 mod suppression_action;
 mod syntax;
+mod testing_library;
 pub mod utils;
 
 pub use crate::registry::visit_registry;
@@ -40,16 +45,22 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The `browser_targets` are the browsers targeted by the project; the default browsers are
+/// targeted when they are `None`. The `module_graph` links the modules of the project, when the
+/// whole project is analyzed.
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    manifest: Option<Arc<PackageJson>>,
+    browser_targets: Option<Arc<BrowserTargets>>,
+    module_graph: Option<Arc<ModuleGraph>>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -62,7 +73,7 @@ where
     ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
-        for comment in parse_suppression_comment(text) {
+        for comment in parse_suppression_comment_with(text, CommentSyntax::C_STYLE) {
             let categories = match comment {
                 Ok(comment) => {
                     if comment.is_legacy {
@@ -123,7 +134,9 @@ where
 
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
-    services.insert_service(Arc::new(manifest));
+    services.insert_service(manifest);
+    services.insert_service(browser_targets.unwrap_or_default());
+    services.insert_service(module_graph.unwrap_or_default());
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -138,13 +151,19 @@ where
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The `browser_targets` are the browsers targeted by the project; the default browsers are
+/// targeted when they are `None`. The `module_graph` links the modules of the project, when the
+/// whole project is analyzed.
+#[allow(clippy::too_many_arguments)]
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
-    manifest: Option<PackageJson>,
+    manifest: Option<Arc<PackageJson>>,
+    browser_targets: Option<Arc<BrowserTargets>>,
+    module_graph: Option<Arc<ModuleGraph>>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -158,13 +177,17 @@ where
         options,
         source_type,
         manifest,
+        browser_targets,
+        module_graph,
         emit_signal,
     )
 }
 
 #[cfg(test)]
 mod tests {
-    use biome_analyze::{AnalyzerOptions, Never, RuleCategoriesBuilder, RuleFilter};
+    use biome_analyze::{
+        AnalyzerConfiguration, AnalyzerOptions, Never, RuleCategoriesBuilder, RuleFilter,
+    };
     use biome_console::fmt::{Formatter, Termcolor};
     use biome_console::{markup, Markup};
     use biome_diagnostics::category;
@@ -174,6 +197,7 @@ mod tests {
     use biome_js_syntax::{JsFileSource, TextRange, TextSize};
     use biome_project::{Dependencies, PackageJson};
     use std::slice;
+    use std::sync::Arc;
 
     use crate::{analyze, AnalysisFilter, ControlFlow};
 
@@ -207,10 +231,12 @@ mod tests {
             },
             &options,
             JsFileSource::tsx(),
-            Some(PackageJson {
+            Some(Arc::new(PackageJson {
                 dependencies,
                 ..Default::default()
-            }),
+            })),
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -303,6 +329,8 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -364,6 +392,170 @@ mod tests {
         );
     }
 
+    #[test]
+    fn broad_suppressions() {
+        const SOURCE: &str = "
+            // biome-ignore lint: all the rules
+            a == b;
+            // biome-ignore lint/suspicious: whole group
+            a == b;
+            // biome-ignore lint/suspicious/noDoubleEquals: single rule
+            a == b;
+        ";
+
+        let parsed = parse(
+            SOURCE,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut broad_ranges: Vec<TextRange> = Vec::new();
+
+        let options = AnalyzerOptions {
+            configuration: AnalyzerConfiguration {
+                report_broad_suppressions: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            JsFileSource::js_module(),
+            None,
+            None,
+            None,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    if diag.category() == Some(category!("suppressions/tooBroad")) {
+                        broad_ranges.push(diag.get_span().unwrap());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+        assert_eq!(
+            broad_ranges.as_slice(),
+            &[
+                TextRange::new(TextSize::from(13), TextSize::from(48)),
+                TextRange::new(TextSize::from(81), TextSize::from(125)),
+            ]
+        );
+    }
+
+    /// Returns the ranges of the `suppressions/tooBroad` diagnostics of `source`
+    fn broad_suppression_ranges(source: &str, report_broad_suppressions: bool) -> Vec<TextRange> {
+        let parsed = parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut broad_ranges: Vec<TextRange> = Vec::new();
+
+        let options = AnalyzerOptions {
+            configuration: AnalyzerConfiguration {
+                report_broad_suppressions,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            JsFileSource::js_module(),
+            None,
+            None,
+            None,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    if diag.category() == Some(category!("suppressions/tooBroad")) {
+                        broad_ranges.push(diag.get_span().unwrap());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        broad_ranges
+    }
+
+    #[test]
+    fn broad_suppressions_of_groups() {
+        const SOURCE: &str = "
+            // biome-ignore lint/correctness: whole group
+            let a = b == c;
+            // biome-ignore lint/style lint/suspicious/noDoubleEquals: group and rule
+            a == b;
+            /* biome-ignore lint/suspicious: block comment */
+            a == b;
+            // biome-ignore lint/unknownGroup: unknown group
+            a == b;
+        ";
+
+        assert_eq!(
+            broad_suppression_ranges(SOURCE, true).as_slice(),
+            &[
+                TextRange::new(TextSize::from(13), TextSize::from(58)),
+                TextRange::new(TextSize::from(99), TextSize::from(172)),
+                TextRange::new(TextSize::from(205), TextSize::from(254)),
+            ]
+        );
+    }
+
+    #[test]
+    fn broad_suppressions_of_category() {
+        const SOURCE: &str = "
+            // biome-ignore lint: line comment
+            a == b;
+            /* biome-ignore lint: block comment */
+            a == b;
+            // biome-ignore format lint: with another category
+            a == b;
+            // biome-ignore format: not a lint suppression
+            a == b;
+        ";
+
+        assert_eq!(
+            broad_suppression_ranges(SOURCE, true).as_slice(),
+            &[
+                TextRange::new(TextSize::from(13), TextSize::from(47)),
+                TextRange::new(TextSize::from(80), TextSize::from(118)),
+                TextRange::new(TextSize::from(151), TextSize::from(201)),
+            ]
+        );
+    }
+
+    #[test]
+    fn specific_suppressions_are_not_broad() {
+        const SOURCE: &str = "
+            // biome-ignore lint/suspicious/noDoubleEquals: single rule
+            a == b;
+            // biome-ignore lint/suspicious/noDoubleEquals lint/style/useConst: several rules
+            let c = a == b;
+            // biome-ignore lint/suspicious/noDoubleEquals(a): rule instance
+            a == b;
+        ";
+
+        assert!(broad_suppression_ranges(SOURCE, true).is_empty());
+    }
+
+    #[test]
+    fn broad_suppressions_are_not_reported_by_default() {
+        const SOURCE: &str = "
+            // biome-ignore lint: all the rules
+            a == b;
+            // biome-ignore lint/suspicious: whole group
+            a == b;
+        ";
+
+        assert!(broad_suppression_ranges(SOURCE, false).is_empty());
+    }
+
     #[test]
     fn suppression_syntax() {
         const SOURCE: &str = "
@@ -389,6 +581,8 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
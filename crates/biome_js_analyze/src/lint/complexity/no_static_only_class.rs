@@ -2,12 +2,7 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_js_syntax::{
-    AnyJsClass, AnyJsClassMember, JsGetterClassMember, JsMethodClassMember, JsPropertyClassMember,
-    JsSetterClassMember, TsGetterSignatureClassMember, TsIndexSignatureClassMember,
-    TsInitializedPropertySignatureClassMember, TsMethodSignatureClassMember,
-    TsPropertySignatureClassMember, TsSetterSignatureClassMember,
-};
+use biome_js_syntax::AnyJsClass;
 use biome_rowan::{AstNode, AstNodeList};
 
 declare_lint_rule! {
@@ -102,33 +97,6 @@ declare_lint_rule! {
     }
 }
 
-trait HasStaticModifier {
-    fn has_static_modifier(&self) -> bool;
-}
-
-macro_rules! impl_has_static_modifiers {
-    ($type:ty) => {
-        impl HasStaticModifier for $type {
-            fn has_static_modifier(&self) -> bool {
-                self.modifiers()
-                    .iter()
-                    .any(|modifier| modifier.as_js_static_modifier().is_some())
-            }
-        }
-    };
-}
-
-impl_has_static_modifiers!(JsGetterClassMember);
-impl_has_static_modifiers!(JsSetterClassMember);
-impl_has_static_modifiers!(JsMethodClassMember);
-impl_has_static_modifiers!(JsPropertyClassMember);
-impl_has_static_modifiers!(TsGetterSignatureClassMember);
-impl_has_static_modifiers!(TsIndexSignatureClassMember);
-impl_has_static_modifiers!(TsInitializedPropertySignatureClassMember);
-impl_has_static_modifiers!(TsMethodSignatureClassMember);
-impl_has_static_modifiers!(TsPropertySignatureClassMember);
-impl_has_static_modifiers!(TsSetterSignatureClassMember);
-
 impl Rule for NoStaticOnlyClass {
     type Query = Ast<AnyJsClass>;
     type State = ();
@@ -153,32 +121,12 @@ impl Rule for NoStaticOnlyClass {
             return None;
         }
 
+        // Constructors aren't regarded as static, and static initialization blocks are (GH#4482)
         let all_members_static = class_declaration
             .members()
             .iter()
-            .filter_map(|member| match member {
-                AnyJsClassMember::JsBogusMember(_)
-                | AnyJsClassMember::JsMetavariable(_)
-                | AnyJsClassMember::JsEmptyClassMember(_) => None,
-                AnyJsClassMember::JsConstructorClassMember(_) => Some(false), // See GH#4482: Constructors are not regarded as static
-                AnyJsClassMember::TsConstructorSignatureClassMember(_) => Some(false), // See GH#4482: Constructors are not regarded as static
-                AnyJsClassMember::JsGetterClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::JsMethodClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::JsPropertyClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::JsSetterClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::JsStaticInitializationBlockClassMember(_) => Some(true), // See GH#4482: Static initialization blocks are regarded as static
-                AnyJsClassMember::TsGetterSignatureClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::TsIndexSignatureClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::TsInitializedPropertySignatureClassMember(m) => {
-                    Some(m.has_static_modifier())
-                }
-                AnyJsClassMember::TsMethodSignatureClassMember(m) => Some(m.has_static_modifier()),
-                AnyJsClassMember::TsPropertySignatureClassMember(m) => {
-                    Some(m.has_static_modifier())
-                }
-                AnyJsClassMember::TsSetterSignatureClassMember(m) => Some(m.has_static_modifier()),
-            })
-            .all(|is_static| is_static);
+            .filter(|member| member.member_kind().is_some())
+            .all(|member| member.is_static());
 
         if all_members_static {
             Some(())
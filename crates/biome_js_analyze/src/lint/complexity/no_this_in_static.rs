@@ -5,10 +5,10 @@ use biome_analyze::{
 use biome_console::markup;
 use biome_js_factory::make;
 use biome_js_syntax::{
-    AnyJsClass, AnyJsClassMember, AnyJsExpression, JsArrowFunctionExpression, JsSuperExpression,
-    JsSyntaxToken, JsThisExpression,
+    AnyJsClass, AnyJsClassMember, AnyJsClassMemberName, AnyJsExpression, JsArrowFunctionExpression,
+    JsSuperExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsThisExpression,
 };
-use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt, SyntaxResult};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxResult};
 
 use crate::{services::control_flow::AnyJsControlFlowRoot, JsRuleAction};
 
@@ -95,35 +95,8 @@ impl Rule for NoThisInStatic {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let this_super_expression = ctx.query();
-        let static_method = this_super_expression
-            .syntax()
-            .ancestors()
-            .find(|x| {
-                AnyJsControlFlowRoot::can_cast(x.kind())
-                    && !JsArrowFunctionExpression::can_cast(x.kind())
-            })
-            .and_then(AnyJsClassMember::cast)
-            .filter(|member| match member {
-                AnyJsClassMember::JsGetterClassMember(member) => member
-                    .modifiers()
-                    .iter()
-                    .any(|modifier| modifier.as_js_static_modifier().is_some()),
-                AnyJsClassMember::JsMethodClassMember(member) => member
-                    .modifiers()
-                    .iter()
-                    .any(|modifier| modifier.as_js_static_modifier().is_some()),
-                AnyJsClassMember::JsSetterClassMember(member) => member
-                    .modifiers()
-                    .iter()
-                    .any(|modifier| modifier.as_js_static_modifier().is_some()),
-                AnyJsClassMember::JsPropertyClassMember(member) => member
-                    .modifiers()
-                    .iter()
-                    .any(|modifier| modifier.as_js_static_modifier().is_some()),
-                AnyJsClassMember::JsStaticInitializationBlockClassMember(_) => true,
-                _ => false,
-            });
-        static_method.is_some().then_some(())
+        let member = enclosing_class_member(this_super_expression.syntax())?;
+        member.is_static().then_some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
@@ -179,6 +152,32 @@ impl Rule for NoThisInStatic {
     }
 }
 
+/// Returns the class member that binds `this` and `super` at `node`, if any.
+///
+/// Computed names and decorators of a member are evaluated outside of the member,
+/// and arrow functions don't bind `this` and `super`.
+fn enclosing_class_member(node: &JsSyntaxNode) -> Option<AnyJsClassMember> {
+    let mut child = node.clone();
+    for ancestor in node.ancestors().skip(1) {
+        if let Some(member) = AnyJsClassMember::cast_ref(&ancestor) {
+            let is_member_head = AnyJsClassMemberName::can_cast(child.kind())
+                || matches!(
+                    child.kind(),
+                    JsSyntaxKind::JS_METHOD_MODIFIER_LIST | JsSyntaxKind::JS_PROPERTY_MODIFIER_LIST
+                );
+            if !is_member_head {
+                return Some(member);
+            }
+        } else if AnyJsControlFlowRoot::can_cast(ancestor.kind())
+            && !JsArrowFunctionExpression::can_cast(ancestor.kind())
+        {
+            return None;
+        }
+        child = ancestor;
+    }
+    None
+}
+
 declare_node_union! {
     pub JsThisSuperExpression = JsSuperExpression | JsThisExpression
 }
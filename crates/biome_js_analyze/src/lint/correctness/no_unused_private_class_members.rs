@@ -9,8 +9,7 @@ use biome_js_syntax::{
     TsAccessibilityModifier, TsPropertyParameter,
 };
 use biome_rowan::{
-    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt,
-    SyntaxNodeOptionExt, TextRange,
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, SyntaxNodeOptionExt, TextRange,
 };
 use rustc_hash::FxHashSet;
 
@@ -170,30 +169,10 @@ fn get_all_declared_private_members(
 }
 
 fn get_constructor_params(class_declaration: &JsClassDeclaration) -> FxHashSet<AnyMember> {
-    let constructor_member = class_declaration
-        .members()
-        .iter()
-        .find_map(|member| match member {
-            AnyJsClassMember::JsConstructorClassMember(member) => Some(member),
-            _ => None,
-        });
-
-    if let Some(constructor_member) = constructor_member {
-        if let Ok(constructor_params) = constructor_member.parameters() {
-            return constructor_params
-                .parameters()
-                .iter()
-                .filter_map(|param| match param.ok()? {
-                    biome_js_syntax::AnyJsConstructorParameter::TsPropertyParameter(
-                        ts_property,
-                    ) => Some(ts_property.into()),
-                    _ => None,
-                })
-                .collect();
-        }
-    }
-
-    FxHashSet::default()
+    class_declaration
+        .constructor_parameter_properties()
+        .map(AnyMember::TsPropertyParameter)
+        .collect()
 }
 
 /// Check whether the provided `AnyJsName` is part of a potentially write-only assignment expression.
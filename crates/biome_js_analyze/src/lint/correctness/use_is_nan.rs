@@ -1,13 +1,18 @@
+use biome_analyze::browserslist::{BrowserTargets, Feature};
 use biome_analyze::context::RuleContext;
 use biome_analyze::{declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource};
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
 use biome_js_semantic::SemanticModel;
 use biome_js_syntax::{
-    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression,
-    JsBinaryExpression, JsBinaryOperator, JsCaseClause, JsSwitchStatement, TextRange, T,
+    binding_ext::AnyJsBindingDeclaration, global_identifier, AnyJsCallArgument, AnyJsExpression,
+    AnyJsLiteralExpression, AnyJsMemberExpression, JsBinaryExpression, JsBinaryOperator,
+    JsCallExpression, JsCaseClause, JsSwitchStatement, TextRange, T,
 };
 use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 use crate::{services::semantic::Semantic, JsRuleAction};
 
@@ -28,6 +33,9 @@ declare_lint_rule! {
     /// `Number.isNaN()` does not perform this coercion.
     /// Therefore, it is a more reliable way to test whether a value is `NaN`.
     ///
+    /// Constants initialized with `NaN` or `Number.NaN` are resolved through the semantic model,
+    /// so comparisons against such aliases are reported as well.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -48,6 +56,11 @@ declare_lint_rule! {
     /// Number.NaN == "abc"
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// const nan = Number.NaN;
+    /// value === nan
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -58,6 +71,38 @@ declare_lint_rule! {
     /// switch(foo) {}
     /// ```
     ///
+    /// ## Options
+    ///
+    /// ### enforceForIndexOf
+    ///
+    /// When `true`, the rule also reports `indexOf(NaN)` and `lastIndexOf(NaN)`:
+    /// these methods use strict equality and can never find `NaN`.
+    /// The calls on string literals and template literals are ignored, because the argument
+    /// of the string methods is converted to a string.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "enforceForIndexOf": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic,use_options
+    /// [1, 2, NaN].indexOf(NaN)
+    /// ```
+    ///
+    /// The fix of `indexOf(NaN)` uses `findIndex`. The fix of `lastIndexOf(NaN)` uses
+    /// `findLastIndex`, which requires ES2023: it's suggested only when all the targeted browsers
+    /// support it.
+    /// Note that `includes(NaN)` is allowed because it uses the [SameValueZero](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness#same-value-zero_equality) algorithm.
+    ///
+    /// ```js,use_options
+    /// [1, 2, NaN].includes(NaN)
+    /// ```
+    ///
     pub UseIsNan {
         version: "1.0.0",
         name: "useIsNan",
@@ -68,14 +113,28 @@ declare_lint_rule! {
     }
 }
 
+/// Options for the rule `useIsNan`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseIsNanOptions {
+    /// Whether `indexOf(NaN)` and `lastIndexOf(NaN)` are reported.
+    pub enforce_for_index_of: bool,
+}
+
 declare_node_union! {
-    pub UseIsNanQuery = JsBinaryExpression | JsCaseClause | JsSwitchStatement
+    pub UseIsNanQuery = JsBinaryExpression | JsCaseClause | JsSwitchStatement | JsCallExpression
 }
 
+/// Maximum number of `const` aliases followed when resolving `NaN`.
+const MAX_ALIAS_DEPTH: usize = 8;
+
 enum Message {
     BinaryExpression,
     CaseClause,
     SwitchCase,
+    IndexOf,
+    LastIndexOf,
 }
 
 pub struct RuleState {
@@ -88,7 +147,9 @@ impl Message {
         match self {
 			Self::BinaryExpression => "Use the Number.isNaN function to compare with NaN.",
 			Self::CaseClause => "'case NaN' can never match. Use Number.isNaN before the switch.",
-			Self::SwitchCase => "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch."
+			Self::SwitchCase => "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch.",
+			Self::IndexOf => "'indexOf(NaN)' can never find NaN. Use findIndex with Number.isNaN instead.",
+			Self::LastIndexOf => "'lastIndexOf(NaN)' can never find NaN. Use findLastIndex with Number.isNaN instead."
 		}
     }
 }
@@ -97,7 +158,7 @@ impl Rule for UseIsNan {
     type Query = Semantic<UseIsNanQuery>;
     type State = RuleState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = UseIsNanOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
@@ -135,6 +196,16 @@ impl Rule for UseIsNan {
                     });
                 }
             }
+            UseIsNanQuery::JsCallExpression(call_expr) => {
+                if !ctx.options().enforce_for_index_of {
+                    return None;
+                }
+                let message_id = index_of_nan(call_expr, model)?;
+                return Some(RuleState {
+                    message_id,
+                    range: call_expr.range(),
+                });
+            }
         }
         None
     }
@@ -147,7 +218,7 @@ impl Rule for UseIsNan {
         ))
     }
 
-    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
         let query = ctx.query();
         let model = ctx.model();
         let mut mutation = ctx.root().begin();
@@ -193,6 +264,47 @@ impl Rule for UseIsNan {
             }
             UseIsNanQuery::JsCaseClause(_) => None,
             UseIsNanQuery::JsSwitchStatement(_) => None,
+            UseIsNanQuery::JsCallExpression(call_expression) => {
+                let replacement = match state.message_id {
+                    Message::IndexOf => "findIndex",
+                    Message::LastIndexOf => {
+                        // `findLastIndex` requires ES2023
+                        let targets = ctx.get_service::<Arc<BrowserTargets>>()?;
+                        if !targets.supports(Feature::ArrayFindLast) {
+                            return None;
+                        }
+                        "findLastIndex"
+                    }
+                    _ => return None,
+                };
+                let callee = call_expression.callee().ok()?;
+                let member_expression = callee.as_js_static_member_expression()?;
+                // `fromIndex` has no equivalent in `findIndex`/`findLastIndex`
+                let [Some(AnyJsCallArgument::AnyJsExpression(nan)), None] = call_expression
+                    .arguments()
+                    .ok()?
+                    .get_arguments_by_index([0, 1])
+                else {
+                    return None;
+                };
+                let is_nan_expression = create_is_nan_expression(nan.clone())?;
+
+                mutation.replace_node(
+                    member_expression.member().ok()?,
+                    make::js_name(make::ident(replacement)).into(),
+                );
+                mutation.replace_node(nan, is_nan_expression);
+
+                Some(JsRuleAction::new(
+                    ActionCategory::QuickFix,
+                    ctx.metadata().applicability(),
+                    markup! {
+                        "Use "<Emphasis>{replacement}"(Number.isNaN)"</Emphasis>" instead."
+                    }
+                    .to_owned(),
+                    mutation,
+                ))
+            }
         }
     }
 }
@@ -286,10 +398,61 @@ fn get_literal(
     }
 }
 
-/// Checks whether an expression has `NaN`, `Number.NaN`, or `Number['NaN']`.
+/// Checks whether `call` is `indexOf(NaN)` or `lastIndexOf(NaN)` on a receiver that isn't a string literal.
+fn index_of_nan(call: &JsCallExpression, model: &SemanticModel) -> Option<Message> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    let callee = AnyJsMemberExpression::cast(callee.into_syntax())?;
+    let message_id = match callee.member_name()?.text() {
+        "indexOf" => Message::IndexOf,
+        "lastIndexOf" => Message::LastIndexOf,
+        _ => return None,
+    };
+    // `"NaN".indexOf(NaN)` converts `NaN` to a string, and finds it
+    if matches!(
+        callee.object().ok()?.omit_parentheses(),
+        AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+            _
+        )) | AnyJsExpression::JsTemplateExpression(_)
+    ) {
+        return None;
+    }
+    let [Some(AnyJsCallArgument::AnyJsExpression(first)), _, None] =
+        call.arguments().ok()?.get_arguments_by_index([0, 1, 2])
+    else {
+        return None;
+    };
+    has_nan(first, model).then_some(message_id)
+}
+
+/// Follows `const` bindings such as `const nan = Number.NaN` back to their initializer.
+fn resolve_const_alias(mut expr: AnyJsExpression, model: &SemanticModel) -> AnyJsExpression {
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let Some(initializer) = const_initializer(&expr, model) else {
+            break;
+        };
+        expr = initializer.omit_parentheses();
+    }
+    expr
+}
+
+fn const_initializer(expr: &AnyJsExpression, model: &SemanticModel) -> Option<AnyJsExpression> {
+    let reference = expr.as_js_identifier_expression()?.name().ok()?;
+    let declaration = model.binding(&reference)?.tree().declaration()?;
+    // Destructuring patterns are ignored: `declaration()` returns the pattern member for them.
+    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) = declaration else {
+        return None;
+    };
+    if !declarator.declaration()?.is_const() {
+        return None;
+    }
+    declarator.initializer()?.expression().ok()
+}
+
+/// Checks whether an expression has `NaN`, `Number.NaN`, or `Number['NaN']`,
+/// either directly or through a `const` alias.
 fn has_nan(expr: AnyJsExpression, model: &SemanticModel) -> bool {
     (|| {
-        let expr = expr.omit_parentheses();
+        let expr = resolve_const_alias(expr.omit_parentheses(), model);
         let reference = if let Some((reference, name)) = global_identifier(&expr) {
             if name.text() != "NaN" {
                 return None;
@@ -2,6 +2,7 @@
 
 use biome_analyze::declare_lint_group;
 
+pub mod no_await_in_loops;
 pub mod no_common_js;
 pub mod no_document_cookie;
 pub mod no_document_import_in_page;
@@ -9,35 +10,52 @@ pub mod no_duplicate_else_if;
 pub mod no_dynamic_namespace_import_access;
 pub mod no_enum;
 pub mod no_exported_imports;
+pub mod no_hardcoded_locale_strings;
 pub mod no_head_element;
 pub mod no_head_import_in_document;
 pub mod no_img_element;
 pub mod no_irregular_whitespace;
+pub mod no_mutation_of_function_parameters_deep;
 pub mod no_nested_ternary;
 pub mod no_octal_escape;
+pub mod no_outdated_browser_apis;
 pub mod no_process_env;
+pub mod no_redundant_type_annotations;
 pub mod no_restricted_imports;
 pub mod no_restricted_types;
 pub mod no_secrets;
+pub mod no_skipped_accessibility_in_tests;
 pub mod no_static_element_interactions;
 pub mod no_substr;
 pub mod no_template_curly_in_string;
+pub mod no_unchecked_json_parse;
 pub mod no_useless_escape_in_regex;
 pub mod no_useless_string_raw;
 pub mod no_useless_undefined;
+pub mod no_wildcard_exports_from_tests;
+pub mod use_accessor_pairs;
 pub mod use_adjacent_overload_signatures;
 pub mod use_aria_props_supported_by_role;
 pub mod use_at_index;
+pub mod use_canonical_order_of_modifiers;
 pub mod use_collapsed_if;
 pub mod use_component_export_only_modules;
 pub mod use_consistent_curly_braces;
 pub mod use_consistent_member_accessibility;
+pub mod use_consistent_response_handling;
+pub mod use_destructured_imports;
 pub mod use_explicit_type;
 pub mod use_google_font_display;
 pub mod use_google_font_preconnect;
 pub mod use_guard_for_in;
+pub mod use_immutable_exports;
 pub mod use_import_restrictions;
+pub mod use_minimal_bundle_imports;
+pub mod use_playwright_locator_best_practices;
+pub mod use_preferred_modules;
+pub mod use_safe_integer_checks;
 pub mod use_sorted_classes;
+pub mod use_static_class_blocks;
 pub mod use_strict_mode;
 pub mod use_trim_start_end;
 pub mod use_valid_autocomplete;
@@ -46,6 +64,7 @@ declare_lint_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_await_in_loops :: NoAwaitInLoops ,
             self :: no_common_js :: NoCommonJs ,
             self :: no_document_cookie :: NoDocumentCookie ,
             self :: no_document_import_in_page :: NoDocumentImportInPage ,
@@ -53,35 +72,52 @@ declare_lint_group! {
             self :: no_dynamic_namespace_import_access :: NoDynamicNamespaceImportAccess ,
             self :: no_enum :: NoEnum ,
             self :: no_exported_imports :: NoExportedImports ,
+            self :: no_hardcoded_locale_strings :: NoHardcodedLocaleStrings ,
             self :: no_head_element :: NoHeadElement ,
             self :: no_head_import_in_document :: NoHeadImportInDocument ,
             self :: no_img_element :: NoImgElement ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
+            self :: no_mutation_of_function_parameters_deep :: NoMutationOfFunctionParametersDeep ,
             self :: no_nested_ternary :: NoNestedTernary ,
             self :: no_octal_escape :: NoOctalEscape ,
+            self :: no_outdated_browser_apis :: NoOutdatedBrowserApis ,
             self :: no_process_env :: NoProcessEnv ,
+            self :: no_redundant_type_annotations :: NoRedundantTypeAnnotations ,
             self :: no_restricted_imports :: NoRestrictedImports ,
             self :: no_restricted_types :: NoRestrictedTypes ,
             self :: no_secrets :: NoSecrets ,
+            self :: no_skipped_accessibility_in_tests :: NoSkippedAccessibilityInTests ,
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
             self :: no_template_curly_in_string :: NoTemplateCurlyInString ,
+            self :: no_unchecked_json_parse :: NoUncheckedJsonParse ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_useless_string_raw :: NoUselessStringRaw ,
             self :: no_useless_undefined :: NoUselessUndefined ,
+            self :: no_wildcard_exports_from_tests :: NoWildcardExportsFromTests ,
+            self :: use_accessor_pairs :: UseAccessorPairs ,
             self :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures ,
             self :: use_aria_props_supported_by_role :: UseAriaPropsSupportedByRole ,
             self :: use_at_index :: UseAtIndex ,
+            self :: use_canonical_order_of_modifiers :: UseCanonicalOrderOfModifiers ,
             self :: use_collapsed_if :: UseCollapsedIf ,
             self :: use_component_export_only_modules :: UseComponentExportOnlyModules ,
             self :: use_consistent_curly_braces :: UseConsistentCurlyBraces ,
             self :: use_consistent_member_accessibility :: UseConsistentMemberAccessibility ,
+            self :: use_consistent_response_handling :: UseConsistentResponseHandling ,
+            self :: use_destructured_imports :: UseDestructuredImports ,
             self :: use_explicit_type :: UseExplicitType ,
             self :: use_google_font_display :: UseGoogleFontDisplay ,
             self :: use_google_font_preconnect :: UseGoogleFontPreconnect ,
             self :: use_guard_for_in :: UseGuardForIn ,
+            self :: use_immutable_exports :: UseImmutableExports ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_minimal_bundle_imports :: UseMinimalBundleImports ,
+            self :: use_playwright_locator_best_practices :: UsePlaywrightLocatorBestPractices ,
+            self :: use_preferred_modules :: UsePreferredModules ,
+            self :: use_safe_integer_checks :: UseSafeIntegerChecks ,
             self :: use_sorted_classes :: UseSortedClasses ,
+            self :: use_static_class_blocks :: UseStaticClassBlocks ,
             self :: use_strict_mode :: UseStrictMode ,
             self :: use_trim_start_end :: UseTrimStartEnd ,
             self :: use_valid_autocomplete :: UseValidAutocomplete ,
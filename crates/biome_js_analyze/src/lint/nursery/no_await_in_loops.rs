@@ -0,0 +1,387 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsCallArgument, AnyJsExpression, AnyJsForInOrOfInitializer,
+    AnyJsFormalParameter, AnyJsFunctionBody, AnyJsParameter, AnyJsStatement, JsAwaitExpression,
+    JsBreakStatement, JsContinueStatement, JsDoWhileStatement, JsForInStatement, JsForOfStatement,
+    JsForStatement, JsIdentifierAssignment, JsReturnStatement, JsSwitchStatement, JsSyntaxKind,
+    JsSyntaxNode, JsVariableDeclaration, JsWhileStatement, JsYieldExpression, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, BatchMutationExt, SyntaxNodeCast, TextRange, TriviaPieceKind,
+    WalkEvent,
+};
+
+use crate::{
+    services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic},
+    JsRuleAction,
+};
+
+declare_lint_rule! {
+    /// Disallow `await` inside loops whose iterations are independent.
+    ///
+    /// When a loop awaits a promise in each iteration, the iterations run one after the other.
+    /// If the iterations don't depend on each other, they can run concurrently,
+    /// for example with `Promise.all()`, which is usually faster.
+    ///
+    /// The rule considers that the iterations of a `for`, `for...in`, or `for...of` loop are independent when:
+    ///
+    /// - the body of the loop doesn't assign a variable that is declared outside of it,
+    ///   except the variables that are declared in the head of a `for...in` or `for...of` loop;
+    /// - the body of the loop doesn't declare a variable with `var`;
+    /// - the body of the loop doesn't exit the loop with `break`, `return`, or a labeled `continue`.
+    ///
+    /// The rule ignores `while` and `do...while` loops, and `for await...of` loops,
+    /// because their iterations are usually meant to run one after the other.
+    ///
+    /// For a `for...of` loop that declares its variable with `const` or `let`,
+    /// the rule provides a code action that runs the iterations concurrently with `Promise.all()`.
+    /// The code action is unsafe, because the iterations no longer run in order,
+    /// and the iterated value must be an array.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function fetchAll(urls) {
+    ///     for (const url of urls) {
+    ///         await fetch(url);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function deleteAll(ids) {
+    ///     for (let i = 0; i < ids.length; i++) {
+    ///         await remove(ids[i]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function fetchAll(urls) {
+    ///     await Promise.all(urls.map(async (url) => {
+    ///         await fetch(url);
+    ///     }));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function sum(urls) {
+    ///     let total = 0;
+    ///     for (const url of urls) {
+    ///         total += await fetchCount(url);
+    ///     }
+    ///     return total;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function findFirst(urls) {
+    ///     for (const url of urls) {
+    ///         if (await exists(url)) {
+    ///             return url;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoAwaitInLoops {
+        version: "next",
+        name: "noAwaitInLoops",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsIndependentLoop = JsForStatement | JsForInStatement | JsForOfStatement
+}
+
+impl AnyJsIndependentLoop {
+    fn body(&self) -> Option<AnyJsStatement> {
+        match self {
+            Self::JsForStatement(node) => node.body().ok(),
+            Self::JsForInStatement(node) => node.body().ok(),
+            Self::JsForOfStatement(node) => node.body().ok(),
+        }
+    }
+
+    /// Returns the range where the variables that are declared for each iteration are declared.
+    fn iteration_scope_range(&self, body: &AnyJsStatement) -> TextRange {
+        match self {
+            // The variables declared in the initializer are shared by all the iterations
+            Self::JsForStatement(_) => body.range(),
+            Self::JsForInStatement(_) | Self::JsForOfStatement(_) => self.range(),
+        }
+    }
+}
+
+pub struct AwaitInLoop {
+    /// The first `await` in the body of the loop
+    await_range: TextRange,
+    /// Whether the body uses `continue` to go to the next iteration.
+    has_continue: bool,
+}
+
+impl Rule for NoAwaitInLoops {
+    type Query = Semantic<AnyJsIndependentLoop>;
+    type State = AwaitInLoop;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        match node {
+            AnyJsIndependentLoop::JsForStatement(node) => {
+                // A loop without a condition only stops with a `break`
+                node.test()?;
+            }
+            AnyJsIndependentLoop::JsForOfStatement(node) => {
+                if node.await_token().is_some() {
+                    return None;
+                }
+            }
+            AnyJsIndependentLoop::JsForInStatement(_) => {}
+        }
+        let body = node.body()?;
+        let iteration_scope = node.iteration_scope_range(&body);
+        let mut await_range = None;
+        let mut has_continue = false;
+        let mut iter = body.syntax().preorder();
+        while let Some(event) = iter.next() {
+            let WalkEvent::Enter(descendant) = event else {
+                continue;
+            };
+            if AnyJsControlFlowRoot::can_cast(descendant.kind()) {
+                // The assignments in nested functions can also carry data between iterations
+                if has_outer_assignment(model, &descendant, iteration_scope) {
+                    return None;
+                }
+                iter.skip_subtree();
+            } else if let Some(await_expression) = descendant.clone().cast::<JsAwaitExpression>() {
+                // The `await` of a nested `for` loop is reported for the nested loop
+                if !is_nested_in(&descendant, &body, AnyJsIndependentLoop::can_cast) {
+                    await_range.get_or_insert(await_expression.range());
+                }
+            } else if let Some(assignment) = descendant.clone().cast::<JsIdentifierAssignment>() {
+                if !is_declared_in(model, &assignment, iteration_scope) {
+                    return None;
+                }
+            } else if let Some(declaration) = descendant.clone().cast::<JsVariableDeclaration>() {
+                if declaration.is_var() {
+                    return None;
+                }
+            } else if let Some(statement) = descendant.clone().cast::<JsBreakStatement>() {
+                if statement.label().is_some()
+                    || !is_nested_in(&descendant, &body, |kind| {
+                        is_loop(kind) || JsSwitchStatement::can_cast(kind)
+                    })
+                {
+                    return None;
+                }
+            } else if let Some(statement) = descendant.clone().cast::<JsContinueStatement>() {
+                if statement.label().is_some() {
+                    return None;
+                }
+                has_continue |= !is_nested_in(&descendant, &body, is_loop);
+            } else if JsReturnStatement::can_cast(descendant.kind())
+                || JsYieldExpression::can_cast(descendant.kind())
+            {
+                return None;
+            }
+        }
+        Some(AwaitInLoop {
+            await_range: await_range?,
+            has_continue,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.await_range,
+                markup! {
+                    "Avoid using "<Emphasis>"await"</Emphasis>" inside loops."
+                },
+            )
+            .note(markup! {
+                "Each iteration waits for the previous one to complete, although the iterations don't depend on each other."
+            })
+            .note(markup! {
+                "Run the iterations concurrently, for example with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if state.has_continue {
+            return None;
+        }
+        let AnyJsIndependentLoop::JsForOfStatement(node) = ctx.query() else {
+            return None;
+        };
+        let AnyJsForInOrOfInitializer::JsForVariableDeclaration(declaration) =
+            node.initializer().ok()?
+        else {
+            return None;
+        };
+        if !(declaration.is_const() || declaration.is_let()) || declaration.await_token().is_some()
+        {
+            return None;
+        }
+        let binding = declaration.declarator().ok()?.id().ok()?.trim_trivia()?;
+        let iterable = node.expression().ok()?;
+        // Avoid adding parentheses around the iterated value
+        if !matches!(
+            iterable,
+            AnyJsExpression::JsIdentifierExpression(_)
+                | AnyJsExpression::JsStaticMemberExpression(_)
+                | AnyJsExpression::JsComputedMemberExpression(_)
+                | AnyJsExpression::JsCallExpression(_)
+                | AnyJsExpression::JsArrayExpression(_)
+        ) {
+            return None;
+        }
+        let iterable = iterable.trim_trivia()?;
+        let body = match node.body().ok()? {
+            AnyJsStatement::JsBlockStatement(block) => make::js_function_body(
+                block.l_curly_token().ok()?,
+                make::js_directive_list([]),
+                block.statements(),
+                block.r_curly_token().ok()?,
+            ),
+            statement => make::js_function_body(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_directive_list([]),
+                make::js_statement_list([statement.trim_trivia()?]),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            ),
+        };
+
+        let callback = make::js_arrow_function_expression(
+            AnyJsArrowFunctionParameters::JsParameters(make::js_parameters(
+                make::token(T!['(']),
+                make::js_parameter_list(
+                    [AnyJsParameter::AnyJsFormalParameter(
+                        AnyJsFormalParameter::JsFormalParameter(
+                            make::js_formal_parameter(make::js_decorator_list([]), binding).build(),
+                        ),
+                    )],
+                    [],
+                ),
+                make::token(T![')']),
+            )),
+            make::token_decorated_with_space(T![=>]),
+            AnyJsFunctionBody::JsFunctionBody(body),
+        )
+        .with_async_token(
+            make::token(T![async]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )
+        .build();
+        let map_call = make::js_call_expression(
+            make::js_static_member_expression(
+                iterable,
+                make::token(T![.]),
+                make::js_name(make::ident("map")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(callback.into())],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let promise_all_call = make::js_call_expression(
+            make::js_static_member_expression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    "Promise",
+                )))
+                .into(),
+                make::token(T![.]),
+                make::js_name(make::ident("all")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(map_call.into())],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let statement = make::js_expression_statement(
+            make::js_await_expression(
+                make::token(T![await]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                promise_all_call.into(),
+            )
+            .into(),
+        )
+        .with_semicolon_token(make::token(T![;]))
+        .build();
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsStatement::from(node.clone()), statement.into());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! {
+                "Run the iterations concurrently with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }
+            .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `assignment` writes a variable declared in `range`.
+fn is_declared_in(
+    model: &SemanticModel,
+    assignment: &JsIdentifierAssignment,
+    range: TextRange,
+) -> bool {
+    // An unresolved variable is a global variable
+    model
+        .binding(assignment)
+        .is_some_and(|binding| range.contains_range(binding.syntax().text_trimmed_range()))
+}
+
+/// Returns `true` if `node` assigns a variable that isn't declared in `range`.
+fn has_outer_assignment(model: &SemanticModel, node: &JsSyntaxNode, range: TextRange) -> bool {
+    node.descendants()
+        .filter_map(JsIdentifierAssignment::cast)
+        .any(|assignment| !is_declared_in(model, &assignment, range))
+}
+
+/// Returns `true` if `node` is in a statement nested in `body` that matches `predicate`.
+fn is_nested_in(
+    node: &JsSyntaxNode,
+    body: &AnyJsStatement,
+    predicate: impl Fn(JsSyntaxKind) -> bool,
+) -> bool {
+    node.ancestors()
+        .take_while(|ancestor| ancestor != body.syntax())
+        .any(|ancestor| predicate(ancestor.kind()))
+}
+
+fn is_loop(kind: JsSyntaxKind) -> bool {
+    AnyJsIndependentLoop::can_cast(kind)
+        || JsWhileStatement::can_cast(kind)
+        || JsDoWhileStatement::can_cast(kind)
+}
@@ -1,10 +1,13 @@
-use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use biome_console::markup;
 use biome_js_semantic::ReferencesExtensions;
 use biome_js_syntax::{JsComputedMemberExpression, JsImportNamespaceClause};
-use biome_rowan::{AstNode, TextRange};
+use biome_rowan::{AstNode, BatchMutationExt, TextRange};
 
-use crate::services::semantic::Semantic;
+use crate::utils::namespace_import::to_named_imports;
+use crate::{services::semantic::Semantic, JsRuleAction};
 
 declare_lint_rule! {
     /// Disallow accessing namespace imports dynamically.
@@ -18,6 +21,10 @@ declare_lint_rule! {
     ///
     /// If you want to completely disallow namespace imports, consider using the [noNamespaceImport](https://biomejs.dev/linter/rules/no-namespace-import/) rule.
     ///
+    /// When the namespace is only used to access members with static names, such as `foo["bar"]`
+    /// or `foo.bar`, the rule provides a code action that replaces the namespace import with
+    /// named imports, and the accesses with references to these imports.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -61,6 +68,7 @@ declare_lint_rule! {
         name: "noDynamicNamespaceImportAccess",
         language: "js",
         recommended: false,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -90,6 +98,18 @@ impl Rule for NoDynamicNamespaceImportAccess {
 
         Some(diagnostic)
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        to_named_imports(ctx.query(), ctx.model(), &mut mutation)?;
+
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use named imports instead." }.to_owned(),
+            mutation,
+        ))
+    }
 }
 
 fn find_dynamic_namespace_import_accesses(
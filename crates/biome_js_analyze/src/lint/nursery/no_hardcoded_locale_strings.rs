@@ -0,0 +1,326 @@
+use crate::services::semantic::Semantic;
+use crate::utils::named_import;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsBinaryExpression, JsBinaryOperator,
+    JsCallArgumentList, JsCallArguments, JsCallExpression, JsConditionalExpression,
+    JsLogicalExpression, JsParenthesizedExpression, JsStringLiteralExpression, JsSyntaxNode,
+    JsTemplateExpression, JsxAttribute, JsxAttributeInitializerClause, JsxExpressionAttributeValue,
+    JsxExpressionChild, JsxString, JsxText,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow strings displayed to users that aren't translated.
+    ///
+    /// Applications that are translated into several languages extract the strings displayed to
+    /// users into translation catalogs, and display them through a translation function, such as
+    /// `t("welcome")`.
+    /// This rule helps to find the strings that are still hardcoded in a single language.
+    ///
+    /// The rule reports:
+    /// - the text of JSX elements;
+    /// - the strings passed to the JSX attributes listed in the `attributes` option, such as
+    ///   `title` or `placeholder`;
+    /// - the strings passed to the functions listed in the `functions` option, such as `alert()`.
+    ///
+    /// The strings are also reported when they are one of the branches of a conditional or logical
+    /// expression, or when they are concatenated.
+    /// The strings passed to a translation function, which are listed in the
+    /// `translationFunctions` option, aren't reported.
+    /// The functions are also recognized when they are imported under another name, such as
+    /// `import { t as translate } from "i18next"`.
+    ///
+    /// The strings that don't contain any letter, such as `"-"` or `"42"`, aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <h1>Welcome</h1>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input placeholder="Your name" />;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// confirm(`Delete ${count} files?`);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <h1>{t("welcome")}</h1>;
+    /// ```
+    ///
+    /// ```jsx
+    /// <input placeholder={i18n.t("name.placeholder")} type="text" />;
+    /// ```
+    ///
+    /// ```js
+    /// alert(t("saved"));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the options described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "translationFunctions": ["t", "i18n.t", "intl.formatMessage"],
+    ///         "attributes": ["alt", "aria-label", "placeholder", "title", "label"],
+    ///         "functions": ["alert", "confirm", "prompt", "toast.error"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### translationFunctions
+    ///
+    /// The functions that translate their arguments.
+    /// A function of an object is written with its path, such as `i18n.t`.
+    ///
+    /// Default: `["t", "i18n.t"]`
+    ///
+    /// ### attributes
+    ///
+    /// The JSX attributes whose values are displayed to users.
+    ///
+    /// Default: `["alt", "aria-label", "placeholder", "title"]`
+    ///
+    /// ### functions
+    ///
+    /// The functions that display their arguments to users.
+    /// A function of an object is written with its path, such as `toast.error`.
+    ///
+    /// Default: `["alert", "confirm", "prompt"]`
+    ///
+    pub NoHardcodedLocaleStrings {
+        version: "next",
+        name: "noHardcodedLocaleStrings",
+        language: "js",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyHardcodedString = JsxText | JsxString | JsStringLiteralExpression | JsTemplateExpression
+}
+
+/// Options for the rule `noHardcodedLocaleStrings`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoHardcodedLocaleStringsOptions {
+    /// The functions that translate their arguments, such as `t` or `i18n.t`.
+    pub translation_functions: Box<[Box<str>]>,
+    /// The JSX attributes whose values are displayed to users.
+    pub attributes: Box<[Box<str>]>,
+    /// The functions that display their arguments to users, such as `alert`.
+    pub functions: Box<[Box<str>]>,
+}
+
+impl Default for NoHardcodedLocaleStringsOptions {
+    fn default() -> Self {
+        Self {
+            translation_functions: ["t", "i18n.t"].map(Box::from).into(),
+            attributes: ["alt", "aria-label", "placeholder", "title"]
+                .map(Box::from)
+                .into(),
+            functions: ["alert", "confirm", "prompt"].map(Box::from).into(),
+        }
+    }
+}
+
+impl Rule for NoHardcodedLocaleStrings {
+    type Query = Semantic<AnyHardcodedString>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoHardcodedLocaleStringsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        match node {
+            AnyHardcodedString::JsxText(text) => {
+                let token = text.value_token().ok()?;
+                let value = token.text_trimmed();
+                if !has_letter(value) {
+                    return None;
+                }
+                // The text includes the whitespace around it, such as the line breaks
+                let start = token.text_trimmed_range().start()
+                    + TextSize::from((value.len() - value.trim_start().len()) as u32);
+                Some(TextRange::at(
+                    start,
+                    TextSize::from(value.trim().len() as u32),
+                ))
+            }
+            AnyHardcodedString::JsxString(string) => {
+                if !has_letter(string.inner_string_text().ok()?.text()) {
+                    return None;
+                }
+                let attribute = string
+                    .parent::<JsxAttributeInitializerClause>()?
+                    .parent::<JsxAttribute>()?;
+                is_displayed_attribute(&attribute, options).then(|| string.range())
+            }
+            AnyHardcodedString::JsStringLiteralExpression(string) => {
+                if !has_letter(string.inner_string_text().ok()?.text()) {
+                    return None;
+                }
+                is_displayed(string.syntax(), options, ctx.model()).then(|| string.range())
+            }
+            AnyHardcodedString::JsTemplateExpression(template) => {
+                if template.tag().is_some() {
+                    return None;
+                }
+                let has_text = template.elements().iter().any(|element| {
+                    matches!(
+                        element,
+                        AnyJsTemplateElement::JsTemplateChunkElement(chunk)
+                            if chunk
+                                .template_chunk_token()
+                                .is_ok_and(|token| has_letter(token.text_trimmed()))
+                    )
+                });
+                if !has_text {
+                    return None;
+                }
+                is_displayed(template.syntax(), options, ctx.model()).then(|| template.range())
+            }
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This string is displayed to users, but it isn't translated."
+                },
+            )
+            .note(markup! {
+                "Move the string into the translation catalogs, and display it with a translation function."
+            }),
+        )
+    }
+}
+
+fn has_letter(text: &str) -> bool {
+    text.chars().any(char::is_alphabetic)
+}
+
+fn is_displayed_attribute(
+    attribute: &JsxAttribute,
+    options: &NoHardcodedLocaleStringsOptions,
+) -> bool {
+    attribute
+        .name()
+        .ok()
+        .and_then(|name| name.as_jsx_name()?.value_token().ok())
+        .is_some_and(|name| {
+            options
+                .attributes
+                .iter()
+                .any(|attribute| attribute.as_ref() == name.text_trimmed())
+        })
+}
+
+/// Returns `true` if the value of the expression `node` is displayed to users, because it's
+/// rendered by JSX, or passed to one of the configured functions.
+fn is_displayed(
+    node: &JsSyntaxNode,
+    options: &NoHardcodedLocaleStringsOptions,
+    model: &SemanticModel,
+) -> bool {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if JsParenthesizedExpression::can_cast(parent.kind())
+            || JsLogicalExpression::can_cast(parent.kind())
+        {
+            node = parent;
+        } else if let Some(conditional) = JsConditionalExpression::cast_ref(&parent) {
+            // The test of a condition isn't displayed
+            if conditional.test().is_ok_and(|test| test.syntax() == &node) {
+                return false;
+            }
+            node = parent;
+        } else if let Some(binary) = JsBinaryExpression::cast_ref(&parent) {
+            if binary.operator() != Ok(JsBinaryOperator::Plus) {
+                return false;
+            }
+            node = parent;
+        } else if JsxExpressionChild::can_cast(parent.kind()) {
+            return true;
+        } else if let Some(value) = JsxExpressionAttributeValue::cast_ref(&parent) {
+            return value
+                .parent::<JsxAttributeInitializerClause>()
+                .and_then(|initializer| initializer.parent::<JsxAttribute>())
+                .is_some_and(|attribute| is_displayed_attribute(&attribute, options));
+        } else if JsCallArgumentList::can_cast(parent.kind()) {
+            let Some(call) = parent
+                .parent()
+                .and_then(JsCallArguments::cast)
+                .and_then(|arguments| arguments.parent::<JsCallExpression>())
+            else {
+                return false;
+            };
+            let Ok(callee) = call.callee() else {
+                return false;
+            };
+            // The strings passed to a translation function are the keys of the translations
+            return !options
+                .translation_functions
+                .iter()
+                .any(|path| is_function(&callee, path, model))
+                && options
+                    .functions
+                    .iter()
+                    .any(|path| is_function(&callee, path, model));
+        } else {
+            return false;
+        }
+    }
+    false
+}
+
+/// Returns `true` if `callee` refers to the function at `path`, such as `i18n.t`.
+///
+/// The identifiers that are imported under another name are resolved to their imported name.
+fn is_function(callee: &AnyJsExpression, path: &str, model: &SemanticModel) -> bool {
+    match callee.clone().omit_parentheses() {
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let Some((object_path, name)) = path.rsplit_once('.') else {
+                return false;
+            };
+            member
+                .member()
+                .ok()
+                .and_then(|member| member.as_js_name()?.value_token().ok())
+                .is_some_and(|member| member.text_trimmed() == name)
+                && member
+                    .object()
+                    .is_ok_and(|object| is_function(&object, object_path, model))
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let Ok(reference) = identifier.name() else {
+                return false;
+            };
+            reference
+                .value_token()
+                .is_ok_and(|name| name.text_trimmed() == path)
+                || named_import(&reference, model)
+                    .is_some_and(|(imported_name, _)| imported_name.text_trimmed() == path)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,309 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    global_identifier, AnyJsBindingPattern, AnyJsMemberExpression, JsCallExpression,
+    JsComputedMemberAssignment, JsIdentifierBinding, JsStaticMemberAssignment, JsSyntaxKind,
+    JsSyntaxNode, JsUnaryExpression, JsUnaryOperator, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, TextRange, WalkEvent};
+use rustc_hash::FxHashSet;
+
+declare_lint_rule! {
+    /// Disallow mutating the properties of `function` parameters.
+    ///
+    /// Objects and arrays are passed by reference: mutating a parameter also mutates the value
+    /// of the caller. This side effect is hard to notice when reading the call, and is often
+    /// unintended.
+    ///
+    /// This rule extends [noParameterAssign](https://biomejs.dev/linter/rules/no-parameter-assign),
+    /// which reports the reassignments of the parameters, to the mutations of their content:
+    /// - the assignments to a property, such as `param.prop = value` or `param[0]++`;
+    /// - the deletions of a property, such as `delete param.prop`;
+    /// - the calls to a method that mutates an array, a `Map` or a `Set`, such as
+    ///   `param.push(value)`;
+    /// - the calls to `Object.assign()`, `Object.defineProperty()`,
+    ///   `Object.defineProperties()` and `Object.setPrototypeOf()` with the parameter as target.
+    ///
+    /// The rule follows the variables that are initialized with a parameter, or with one of its
+    /// properties, such as `const options = param.options`, and reports their mutations too.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function addItem(list, item) {
+    ///     list.push(item);
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function enable(settings) {
+    ///     settings.flags.enabled = true;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function reset(state) {
+    ///     const current = state;
+    ///     delete current.value;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function addItem(list, item) {
+    ///     return [...list, item];
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function enable(settings) {
+    ///     return { ...settings, flags: { ...settings.flags, enabled: true } };
+    /// }
+    /// ```
+    ///
+    pub NoMutationOfFunctionParametersDeep {
+        version: "next",
+        name: "noMutationOfFunctionParametersDeep",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-param-reassign")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// The methods that mutate an array, a `Map` or a `Set`, sorted
+const MUTATING_METHODS: [&str; 13] = [
+    "add",
+    "clear",
+    "copyWithin",
+    "delete",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "set",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+/// The methods of `Object` that mutate their first argument
+const OBJECT_MUTATING_METHODS: [&str; 4] = [
+    "assign",
+    "defineProperties",
+    "defineProperty",
+    "setPrototypeOf",
+];
+
+pub struct ParameterMutation {
+    /// The range of the mutation
+    range: TextRange,
+    /// The variable that refers to the parameter, when the mutation goes through an alias
+    alias: Option<TextRange>,
+}
+
+impl Rule for NoMutationOfFunctionParametersDeep {
+    type Query = Semantic<JsIdentifierBinding>;
+    type State = ParameterMutation;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binding = ctx.query();
+        let model = ctx.model();
+        if !is_parameter(binding) {
+            return Box::default();
+        }
+
+        let mut mutations = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut bindings = vec![(binding.clone(), None)];
+        while let Some((binding, alias)) = bindings.pop() {
+            if !visited.insert(binding.range()) {
+                continue;
+            }
+            for reference in binding.all_reads(model) {
+                match usage_of(reference.syntax(), model) {
+                    Some(Usage::Mutation(range)) => {
+                        mutations.push(ParameterMutation { range, alias });
+                    }
+                    Some(Usage::Alias(pattern)) => {
+                        bindings.extend(pattern_bindings(&pattern).into_iter().map(|binding| {
+                            let range = binding.range();
+                            (binding, Some(range))
+                        }));
+                    }
+                    None => {}
+                }
+            }
+        }
+        mutations.sort_unstable_by_key(|mutation| mutation.range.start());
+        mutations.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let binding = ctx.query();
+        let name = binding.name_token().ok()?;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "Avoid mutating the "<Emphasis>"function parameter"</Emphasis>" "<Emphasis>{name.text_trimmed()}</Emphasis>"."
+            },
+        );
+        let diagnostic = match state.alias {
+            Some(alias) => diagnostic.detail(
+                alias,
+                markup! {
+                    "This variable refers to the parameter:"
+                },
+            ),
+            None => diagnostic,
+        };
+        Some(
+            diagnostic
+                .detail(
+                    binding.range(),
+                    markup! {
+                        "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    },
+                )
+                .note(markup! {
+                    "Mutating a parameter also mutates the value of the caller, which is an unexpected side effect."
+                })
+                .note(markup! {
+                    "Create a copy instead, for example with the spread syntax or "<Emphasis>"structuredClone()"</Emphasis>"."
+                }),
+        )
+    }
+}
+
+/// How a reference to a parameter, or to one of its aliases, is used
+enum Usage {
+    /// The reference, or one of its properties, is mutated
+    Mutation(TextRange),
+    /// The reference, or one of its properties, initializes the variables of the pattern
+    Alias(AnyJsBindingPattern),
+}
+
+/// Returns `true` if `binding` is a parameter, or is destructured from a parameter
+fn is_parameter(binding: &JsIdentifierBinding) -> bool {
+    binding.declaration().is_some_and(|declaration| {
+        declaration.is_parameter_like()
+            || declaration
+                .parent_binding_pattern_declaration()
+                .is_some_and(|declaration| declaration.is_parameter_like())
+    })
+}
+
+/// Returns how the reference `reference` is used
+fn usage_of(reference: &JsSyntaxNode, model: &SemanticModel) -> Option<Usage> {
+    // The reference is wrapped in a `JsIdentifierExpression`
+    let mut current = reference.parent()?;
+    let mut is_member = false;
+    loop {
+        let parent = current.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+            | JsSyntaxKind::TS_AS_EXPRESSION
+            | JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION
+            | JsSyntaxKind::TS_SATISFIES_EXPRESSION => {}
+            // `param.prop`, `param[index]`
+            JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+            | JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION => {
+                let member = AnyJsMemberExpression::unwrap_cast(parent.clone());
+                if member.object().ok()?.syntax() != &current {
+                    return None;
+                }
+                is_member = true;
+            }
+            // `param.prop = value`, `param[index]++`
+            JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT => {
+                let assignment = JsStaticMemberAssignment::unwrap_cast(parent.clone());
+                return (assignment.object().ok()?.syntax() == &current)
+                    .then(|| Usage::Mutation(parent.text_trimmed_range()));
+            }
+            JsSyntaxKind::JS_COMPUTED_MEMBER_ASSIGNMENT => {
+                let assignment = JsComputedMemberAssignment::unwrap_cast(parent.clone());
+                return (assignment.object().ok()?.syntax() == &current)
+                    .then(|| Usage::Mutation(parent.text_trimmed_range()));
+            }
+            // `delete param.prop`
+            JsSyntaxKind::JS_UNARY_EXPRESSION => {
+                let unary = JsUnaryExpression::unwrap_cast(parent.clone());
+                return (is_member && unary.operator().ok()? == JsUnaryOperator::Delete)
+                    .then(|| Usage::Mutation(parent.text_trimmed_range()));
+            }
+            // `param.push(value)`
+            JsSyntaxKind::JS_CALL_EXPRESSION => {
+                let method = AnyJsMemberExpression::cast(current)?.member_name()?;
+                return MUTATING_METHODS
+                    .binary_search(&method.text())
+                    .is_ok()
+                    .then(|| Usage::Mutation(parent.text_trimmed_range()));
+            }
+            // `Object.assign(param, value)`
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST => {
+                if current.prev_sibling().is_some() {
+                    return None;
+                }
+                let call = parent.grand_parent().and_then(JsCallExpression::cast)?;
+                return is_object_mutation(&call, model).then(|| Usage::Mutation(call.range()));
+            }
+            // `const alias = param`
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                let declarator = parent.parent().and_then(JsVariableDeclarator::cast)?;
+                return Some(Usage::Alias(declarator.id().ok()?));
+            }
+            _ => return None,
+        }
+        current = parent;
+    }
+}
+
+/// Returns `true` if `call` is a call to one of the methods of `Object` that mutate their first
+/// argument, such as `Object.assign()`
+fn is_object_mutation(call: &JsCallExpression, model: &SemanticModel) -> bool {
+    let Some(callee) = call
+        .callee()
+        .ok()
+        .and_then(|callee| AnyJsMemberExpression::cast(callee.omit_parentheses().into_syntax()))
+    else {
+        return false;
+    };
+    callee
+        .member_name()
+        .is_some_and(|method| OBJECT_MUTATING_METHODS.contains(&method.text()))
+        && callee
+            .object()
+            .ok()
+            .and_then(|object| global_identifier(&object.omit_parentheses()))
+            .is_some_and(|(reference, name)| {
+                name.text() == "Object" && model.binding(&reference).is_none()
+            })
+}
+
+/// Returns the variables of `pattern`, without the ones of the default values
+fn pattern_bindings(pattern: &AnyJsBindingPattern) -> Vec<JsIdentifierBinding> {
+    let mut bindings = Vec::new();
+    let mut iter = pattern.syntax().preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if node.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE {
+            iter.skip_subtree();
+        } else if let Some(binding) = JsIdentifierBinding::cast(node) {
+            bindings.push(binding);
+        }
+    }
+    bindings
+}
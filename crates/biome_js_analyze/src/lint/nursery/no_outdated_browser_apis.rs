@@ -0,0 +1,198 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    browserslist::{Browser, BrowserTargets, BrowserVersion, Feature},
+    context::RuleContext,
+    declare_lint_rule, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsMemberExpression, JsCallExpression,
+};
+use biome_rowan::{AstNode, TextRange};
+use std::sync::Arc;
+
+declare_lint_rule! {
+    /// Disallow the APIs that some of the targeted environments don't support.
+    ///
+    /// Calling a recent API, such as `Array.prototype.findLast()` or `structuredClone()`,
+    /// throws an error in the browsers and in the versions of Node.js that don't implement it yet.
+    ///
+    /// The targeted browsers are read from the closest `.browserslistrc` file, or from the
+    /// `browserslist` field of `package.json`.
+    /// The targeted version of Node.js is read from the `engines.node` field of `package.json`.
+    /// When the project configures neither of them, the recent versions of the major browsers are targeted.
+    ///
+    /// The rule checks the following APIs:
+    ///
+    /// - `Array.fromAsync()`
+    /// - `Array.prototype.at()` and `String.prototype.at()`
+    /// - `Array.prototype.findLast()` and `Array.prototype.findLastIndex()`
+    /// - `Array.prototype.toReversed()`, `Array.prototype.toSorted()` and `Array.prototype.toSpliced()`
+    /// - `Object.groupBy()` and `Map.groupBy()`
+    /// - `Object.hasOwn()`
+    /// - `Promise.any()` and `Promise.withResolvers()`
+    /// - `String.prototype.replaceAll()`
+    /// - `structuredClone()`
+    ///
+    /// The methods of the prototypes are recognized by their name, whatever the type of the object they are called on.
+    /// Only the calls are reported: checking whether an API exists, such as `if (Object.hasOwn)`, is allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const groups = Object.groupBy(items, (item) => item.kind);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const sorted = items.toSorted();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { promise, resolve } = Promise.withResolvers();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const last = items.findLast((item) => item.done);
+    /// ```
+    ///
+    /// ```js
+    /// const copy = structuredClone(value);
+    /// ```
+    ///
+    /// ```js
+    /// const groupBy = Object.groupBy ?? groupByPolyfill;
+    /// ```
+    ///
+    pub NoOutdatedBrowserApis {
+        version: "next",
+        name: "noOutdatedBrowserApis",
+        language: "js",
+        recommended: false,
+    }
+}
+
+pub struct UnsupportedApi {
+    /// The range of the name of the API
+    range: TextRange,
+    /// The name of the API, such as `Object.hasOwn()`
+    api: &'static str,
+    browser: Browser,
+    version: BrowserVersion,
+}
+
+impl Rule for NoOutdatedBrowserApis {
+    type Query = Semantic<AnyJsExpression>;
+    type State = UnsupportedApi;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let call = node.parent::<JsCallExpression>()?;
+        if call.is_optional() || call.callee().ok()?.syntax() != node.syntax() {
+            return None;
+        }
+
+        let (range, api, feature) = if let Some((reference, name)) = global_identifier(node) {
+            if name.text() != "structuredClone" || model.binding(&reference).is_some() {
+                return None;
+            }
+            (node.range(), "structuredClone()", Feature::StructuredClone)
+        } else {
+            let member_expression = AnyJsMemberExpression::cast_ref(node.syntax())?;
+            let member_name = member_expression.member_name()?;
+            let member_name = member_name.text();
+            let object = member_expression.object().ok()?;
+            let static_api = global_identifier(&object).and_then(|(reference, object_name)| {
+                if model.binding(&reference).is_some() {
+                    return None;
+                }
+                static_method(object_name.text(), member_name)
+            });
+            let (api, feature) = static_api.or_else(|| prototype_method(member_name))?;
+            (member_range(&member_expression)?, api, feature)
+        };
+
+        let targets = ctx.get_service::<Arc<BrowserTargets>>()?;
+        let (browser, version) = targets.first_unsupported(feature)?;
+        Some(UnsupportedApi {
+            range,
+            api,
+            browser,
+            version,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsupportedApi {
+            range,
+            api,
+            browser,
+            version,
+        } = state;
+        let target = format!("{browser} {version}");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    <Emphasis>{api}</Emphasis>" isn't supported by "<Emphasis>{target}</Emphasis>", which is targeted by the project."
+                },
+            )
+            .note(markup! {
+                "Calling this API throws an error in the environments that don't support it."
+            })
+            .note(markup! {
+                "Use an alternative, load a polyfill, or stop targeting the environments that don't support it."
+            }),
+        )
+    }
+}
+
+/// Returns the range of the member accessed by `member_expression`
+fn member_range(member_expression: &AnyJsMemberExpression) -> Option<TextRange> {
+    let range = match member_expression {
+        AnyJsMemberExpression::JsStaticMemberExpression(expression) => {
+            expression.member().ok()?.range()
+        }
+        AnyJsMemberExpression::JsComputedMemberExpression(expression) => {
+            expression.member().ok()?.range()
+        }
+    };
+    Some(range)
+}
+
+/// Returns the name and the feature of a static method of a global object, such as
+/// `Object.hasOwn()`
+fn static_method(object: &str, member: &str) -> Option<(&'static str, Feature)> {
+    let method = match (object, member) {
+        ("Array", "fromAsync") => ("Array.fromAsync()", Feature::ArrayFromAsync),
+        ("Map", "groupBy") => ("Map.groupBy()", Feature::ObjectGroupBy),
+        ("Object", "groupBy") => ("Object.groupBy()", Feature::ObjectGroupBy),
+        ("Object", "hasOwn") => ("Object.hasOwn()", Feature::ObjectHasOwn),
+        ("Promise", "any") => ("Promise.any()", Feature::PromiseAny),
+        ("Promise", "withResolvers") => ("Promise.withResolvers()", Feature::PromiseWithResolvers),
+        _ => return None,
+    };
+    Some(method)
+}
+
+/// Returns the name and the feature of a method of the prototypes, such as `findLast()`
+fn prototype_method(member: &str) -> Option<(&'static str, Feature)> {
+    let method = match member {
+        "at" => ("Array.prototype.at()", Feature::ArrayAt),
+        "findLast" => ("Array.prototype.findLast()", Feature::ArrayFindLast),
+        "findLastIndex" => ("Array.prototype.findLastIndex()", Feature::ArrayFindLast),
+        "replaceAll" => ("String.prototype.replaceAll()", Feature::StringReplaceAll),
+        "toReversed" => ("Array.prototype.toReversed()", Feature::ArrayChangeByCopy),
+        "toSorted" => ("Array.prototype.toSorted()", Feature::ArrayChangeByCopy),
+        "toSpliced" => ("Array.prototype.toSpliced()", Feature::ArrayChangeByCopy),
+        _ => return None,
+    };
+    Some(method)
+}
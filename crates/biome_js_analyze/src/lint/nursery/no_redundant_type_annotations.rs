@@ -0,0 +1,192 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyTsPropertyAnnotation, AnyTsVariableAnnotation, JsFormalParameter, JsInitializerClause,
+    JsPropertyClassMember, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator, TsTypeAnnotation,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+use crate::services::semantic::Semantic;
+use crate::utils::inferred_type::InferredType;
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Disallow type annotations that are identical to the type inferred from the initializer.
+    ///
+    /// TypeScript infers the type of a variable, a class property or a parameter from its
+    /// initializer, so annotating it with the same type only repeats it.
+    /// The rule reports the annotations of the primitive types initialized with a literal, such
+    /// as `let s: string = "a"`, and the annotations of the class instances initialized with
+    /// `new`, such as `const map: Map<string, number> = new Map<string, number>()`.
+    ///
+    /// The rule only relies on the initializer, without the type checker.
+    /// An instance of a class is only reported when its type arguments don't depend on the
+    /// arguments of the constructor: they're written explicitly, or the class is declared
+    /// without type parameters in the file, or it's a global class without type parameters,
+    /// such as `Date` or `URL`.
+    ///
+    /// Unlike [noInferrableTypes](https://biomejs.dev/linter/rules/no-inferrable-types), this
+    /// rule also reports the wide types of `const` declarations, such as `const x: number = 5`,
+    /// because the literal type that TypeScript infers is assignable to them.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const x: number = 5;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// let s: string = "a";
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const scores: Map<string, number> = new Map<string, number>();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Clock {
+    ///     start: Date = new Date();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// let value: number | undefined = 5;
+    /// const scores: Map<string, number> = new Map();
+    /// const pattern: RegExp = /a/;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### ignoreExported
+    ///
+    /// When `true`, the annotations of the exported variables, the members of the exported
+    /// classes and the parameters of the exported functions are ignored.
+    /// They're required to emit the declaration files with `isolatedDeclarations`.
+    ///
+    /// Default: `false`
+    ///
+    /// ```json,options
+    /// {
+    ///     "options": {
+    ///         "ignoreExported": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,use_options
+    /// export const timeout: number = 1000;
+    /// ```
+    ///
+    pub NoRedundantTypeAnnotations {
+        version: "next",
+        name: "noRedundantTypeAnnotations",
+        language: "ts",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `noRedundantTypeAnnotations`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoRedundantTypeAnnotationsOptions {
+    /// Whether the annotations of the exported declarations are ignored.
+    pub ignore_exported: bool,
+}
+
+impl Rule for NoRedundantTypeAnnotations {
+    type Query = Semantic<JsInitializerClause>;
+    type State = TsTypeAnnotation;
+    type Signals = Option<Self::State>;
+    type Options = NoRedundantTypeAnnotationsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let initializer = ctx.query();
+        let parent = initializer.syntax().parent()?;
+        let annotation = if let Some(parameter) = JsFormalParameter::cast_ref(&parent) {
+            parameter.type_annotation()?
+        } else if let Some(property) = JsPropertyClassMember::cast_ref(&parent) {
+            match property.property_annotation()? {
+                AnyTsPropertyAnnotation::TsTypeAnnotation(annotation) => annotation,
+                _ => return None,
+            }
+        } else if let Some(declarator) = JsVariableDeclarator::cast_ref(&parent) {
+            match declarator.variable_annotation()? {
+                AnyTsVariableAnnotation::TsTypeAnnotation(annotation) => annotation,
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+        if ctx.options().ignore_exported && is_exported(&parent) {
+            return None;
+        }
+        let inferred_type =
+            InferredType::from_initializer(&initializer.expression().ok()?, ctx.model())?;
+        inferred_type
+            .is_same_as(&annotation.ty().ok()?)
+            .then_some(annotation)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, annotation: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                annotation.range(),
+                markup! {
+                    "This type annotation is identical to the type inferred from the initializer."
+                },
+            )
+            .note(markup! {
+                "TypeScript infers the same type without the annotation."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, annotation: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let first_token = annotation.syntax().first_token()?;
+        let prev_token = first_token.prev_token()?;
+        let new_prev_token = prev_token.append_trivia_pieces(first_token.leading_trivia().pieces());
+        let last_token = annotation.syntax().last_token()?;
+        let next_token = last_token.next_token()?;
+        let new_next_token =
+            next_token.prepend_trivia_pieces(last_token.trailing_trivia().pieces());
+        mutation.replace_token_discard_trivia(prev_token, new_prev_token);
+        mutation.replace_token_discard_trivia(next_token, new_next_token);
+        mutation.remove_node(annotation.clone());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the type annotation." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `node` is part of an exported declaration, such as a parameter of an
+/// exported function or a property of an exported class.
+/// The declarations in the bodies of the functions aren't exported.
+fn is_exported(node: &JsSyntaxNode) -> bool {
+    for ancestor in node.ancestors() {
+        match ancestor.kind() {
+            JsSyntaxKind::JS_EXPORT
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => return true,
+            JsSyntaxKind::JS_FUNCTION_BODY
+            | JsSyntaxKind::JS_STATEMENT_LIST
+            | JsSyntaxKind::JS_MODULE_ITEM_LIST => return false,
+            _ => {}
+        }
+    }
+    false
+}
@@ -0,0 +1,212 @@
+use crate::services::semantic::Semantic;
+use crate::testing_library::{
+    destructured_query, is_queries_object, is_render_container, TestingLibraryQuery,
+};
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{AnyJsExpression, JsCallExpression};
+use biome_rowan::{AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow queries of Testing Library that bypass the accessibility tree.
+    ///
+    /// [Testing Library](https://testing-library.com) recommends querying elements the way users
+    /// find them: by their role, their label or their text.
+    /// Such queries fail when an element isn't accessible, so the tests also check the accessibility
+    /// of the page.
+    ///
+    /// This rule reports:
+    /// - the queries by test id, such as `getByTestId`, which rely on attributes that neither users
+    ///   nor assistive technologies can see;
+    /// - the calls to `querySelector` and `querySelectorAll` on the `container` returned by `render`,
+    ///   which couple the test to the structure of the DOM.
+    ///
+    /// The rule only reports the queries that come from a `@testing-library/*` package: the `screen`
+    /// object, and the results of `render` and `within`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { screen } from "@testing-library/react";
+    ///
+    /// screen.getByTestId("submit");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { render } from "@testing-library/react";
+    ///
+    /// const { container } = render(App());
+    /// container.querySelector("button");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { screen } from "@testing-library/react";
+    ///
+    /// screen.getByRole("button", { name: "Submit" });
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the option described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "strictness": "strict"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### strictness
+    ///
+    /// With `"recommended"`, the default, the rule reports the queries by test id and the queries
+    /// on the container.
+    ///
+    /// With `"strict"`, the rule also reports the other queries that aren't by role, such as
+    /// `getByText`, and suggests to use the matching query by role instead.
+    ///
+    pub NoSkippedAccessibilityInTests {
+        version: "next",
+        name: "noSkippedAccessibilityInTests",
+        language: "js",
+        sources: &[RuleSource::EslintTestingLibrary("no-container")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub enum RuleState {
+    /// A query that isn't by role
+    Query {
+        range: TextRange,
+        name: String,
+        by_role: String,
+        by_test_id: bool,
+    },
+    /// A call to `querySelector` or `querySelectorAll` on the container of a rendered component
+    ContainerQuery { range: TextRange, method: String },
+}
+
+impl Rule for NoSkippedAccessibilityInTests {
+    type Query = Semantic<JsCallExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = NoSkippedAccessibilityInTestsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let (name_token, query_name) = match node.callee().ok()?.omit_parentheses() {
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                let name_token = member.member().ok()?.as_js_name()?.value_token().ok()?;
+                let object = member.object().ok()?;
+                let name = name_token.text_trimmed();
+                if matches!(name, "querySelector" | "querySelectorAll") {
+                    return is_render_container(&object, model).then(|| {
+                        RuleState::ContainerQuery {
+                            range: member.range(),
+                            method: name.to_string(),
+                        }
+                    });
+                }
+                if !is_queries_object(&object, model) {
+                    return None;
+                }
+                let query_name = name_token.token_text_trimmed();
+                (name_token, query_name)
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let reference = identifier.name().ok()?;
+                let query_name = destructured_query(&reference, model)?;
+                (reference.value_token().ok()?, query_name)
+            }
+            _ => return None,
+        };
+
+        let query = TestingLibraryQuery::parse(query_name.text())?;
+        let by_test_id = query.is_by_test_id();
+        if query.is_by_role()
+            || (!by_test_id && ctx.options().strictness == QueryStrictness::Recommended)
+        {
+            return None;
+        }
+        Some(RuleState::Query {
+            range: name_token.text_trimmed_range(),
+            name: query_name.text().to_string(),
+            by_role: format!("{}Role", query.variant),
+            by_test_id,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            RuleState::Query {
+                range,
+                name,
+                by_role,
+                by_test_id,
+            } => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The query "<Emphasis>{name}</Emphasis>" doesn't rely on the accessibility tree."
+                    },
+                );
+                let diagnostic = if *by_test_id {
+                    diagnostic.note(markup! {
+                        "Test ids can't be seen by users, nor by assistive technologies."
+                    })
+                } else {
+                    diagnostic
+                };
+                diagnostic.note(markup! {
+                    "Prefer "<Emphasis>{by_role}</Emphasis>", which finds elements the way assistive technologies do, and fails when they aren't accessible."
+                })
+            }
+            RuleState::ContainerQuery { range, method } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid querying the container with "<Emphasis>{method}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Selectors couple the test to the structure of the DOM, and don't check that the elements are accessible."
+            })
+            .note(markup! {
+                "Prefer the queries of Testing Library, such as "<Emphasis>"screen.getByRole"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Options for the rule `noSkippedAccessibilityInTests`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoSkippedAccessibilityInTestsOptions {
+    /// The queries to report.
+    pub strictness: QueryStrictness,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum QueryStrictness {
+    /// Report the queries by test id and the queries on the container.
+    #[default]
+    Recommended,
+    /// Also report the queries that aren't by role.
+    Strict,
+}
@@ -0,0 +1,257 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsAssignmentPattern, AnyJsBindingPattern, AnyJsMemberExpression, JsAssignmentExpression,
+    JsCallExpression, JsIdentifierBinding, JsInitializerClause, JsSyntaxKind, JsSyntaxNode,
+    JsTryFinallyStatement, JsTryStatement, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+use crate::globals::callee_signature;
+use crate::globals::signatures::TypeRef;
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Disallow accessing the result of `JSON.parse()` without validating it.
+    ///
+    /// `JSON.parse()` returns whatever value is encoded in the text, so its result is `unknown`
+    /// until it's validated.
+    /// Accessing a property of the result, or destructuring it, assumes a shape that the text
+    /// may not have: the property may be missing, or the access may throw when the result is
+    /// `null`.
+    /// This matters most at the boundaries of an application, where the text comes from an API,
+    /// a file or the storage.
+    ///
+    /// The rule reports the results of `JSON.parse()` that are accessed or destructured
+    /// immediately, such as `JSON.parse(text).user`.
+    /// The results that are passed to a function, such as a type guard or a schema, are
+    /// considered validated.
+    /// The accesses inside the `try` block of a `try...catch` statement of the same function
+    /// aren't reported, because their errors are handled.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const name = JSON.parse(text).user.name;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { user } = JSON.parse(text);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const config = configSchema.parse(JSON.parse(text));
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     const { user } = JSON.parse(text);
+    /// } catch {
+    ///     console.error("Invalid user");
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "strict": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### strict
+    ///
+    /// When `true`, the variables initialized with the result of `JSON.parse()` are checked too.
+    /// The first access to their properties in the function is reported, unless the variable
+    /// is used in another way before it, such as being passed to a type guard.
+    ///
+    /// Default: `false`
+    ///
+    pub NoUncheckedJsonParse {
+        version: "next",
+        name: "noUncheckedJsonParse",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noUncheckedJsonParse`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUncheckedJsonParseOptions {
+    /// Whether the variables initialized with the result of `JSON.parse()` are checked too.
+    pub strict: bool,
+}
+
+pub struct UncheckedUse {
+    kind: UncheckedUseKind,
+    range: TextRange,
+}
+
+#[derive(Clone, Copy)]
+enum UncheckedUseKind {
+    Access,
+    Destructuring,
+}
+
+impl Rule for NoUncheckedJsonParse {
+    type Query = Semantic<JsCallExpression>;
+    type State = UncheckedUse;
+    type Signals = Option<Self::State>;
+    type Options = NoUncheckedJsonParseOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        // The parsed value is `unknown` until it's validated
+        let signature = callee_signature(&call.callee().ok()?, model)?;
+        if signature.returns != TypeRef::Unknown || is_in_try_block(call.syntax()) {
+            return None;
+        }
+
+        let (node, parent) = skip_type_assertions(call.syntax().clone())?;
+        if let Some(unchecked) = unchecked_use(&node, &parent) {
+            return Some(unchecked);
+        }
+        if ctx.options().strict {
+            let declarator = JsInitializerClause::cast(parent)?.parent::<JsVariableDeclarator>()?;
+            let id = declarator.id().ok()?;
+            let binding = id.as_any_js_binding()?.as_js_identifier_binding()?;
+            return first_unchecked_read(binding, model);
+        }
+        None
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let action = match state.kind {
+            UncheckedUseKind::Access => "accessed",
+            UncheckedUseKind::Destructuring => "destructured",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The result of "<Emphasis>"JSON.parse()"</Emphasis>" is "{action}" without being validated."
+                },
+            )
+            .note(markup! {
+                "The parsed value can have any shape, so its properties may be missing, and accessing them throws when the value is "<Emphasis>"null"</Emphasis>"."
+            })
+            .note(markup! {
+                "Validate the value with a type guard or a schema first, or handle the errors with a "<Emphasis>"try...catch"</Emphasis>" statement."
+            }),
+        )
+    }
+}
+
+/// Returns the node of the expression without its parentheses and type assertions, such as
+/// `JSON.parse(text) as User`, with its parent
+fn skip_type_assertions(mut node: JsSyntaxNode) -> Option<(JsSyntaxNode, JsSyntaxNode)> {
+    let mut parent = node.parent()?;
+    while matches!(
+        parent.kind(),
+        JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+            | JsSyntaxKind::TS_AS_EXPRESSION
+            | JsSyntaxKind::TS_SATISFIES_EXPRESSION
+            | JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION
+            | JsSyntaxKind::TS_TYPE_ASSERTION_EXPRESSION
+    ) {
+        node = parent;
+        parent = node.parent()?;
+    }
+    Some((node, parent))
+}
+
+/// Returns how the parsed value `node` is used by `parent`, if it accesses its properties
+fn unchecked_use(node: &JsSyntaxNode, parent: &JsSyntaxNode) -> Option<UncheckedUse> {
+    if let Some(member) = AnyJsMemberExpression::cast_ref(parent) {
+        return (member.object().ok()?.syntax() == node).then(|| UncheckedUse {
+            kind: UncheckedUseKind::Access,
+            range: member.range(),
+        });
+    }
+    if let Some(assignment) = JsAssignmentExpression::cast_ref(parent) {
+        let is_destructuring = matches!(
+            assignment.left().ok()?,
+            AnyJsAssignmentPattern::JsObjectAssignmentPattern(_)
+                | AnyJsAssignmentPattern::JsArrayAssignmentPattern(_)
+        );
+        return is_destructuring.then(|| UncheckedUse {
+            kind: UncheckedUseKind::Destructuring,
+            range: assignment.range(),
+        });
+    }
+    let declarator = JsInitializerClause::cast_ref(parent)?.parent::<JsVariableDeclarator>()?;
+    let is_destructuring = matches!(
+        declarator.id().ok()?,
+        AnyJsBindingPattern::JsObjectBindingPattern(_)
+            | AnyJsBindingPattern::JsArrayBindingPattern(_)
+    );
+    is_destructuring.then(|| UncheckedUse {
+        kind: UncheckedUseKind::Destructuring,
+        range: declarator.range(),
+    })
+}
+
+/// Returns the first read of `binding` that accesses the properties of the parsed value,
+/// unless the value is used in another way before it.
+///
+/// Only the reads of the function that declares the variable are considered, in the order of
+/// the source, because a nested function may be called at any time.
+fn first_unchecked_read(
+    binding: &JsIdentifierBinding,
+    model: &SemanticModel,
+) -> Option<UncheckedUse> {
+    if binding.all_writes(model).next().is_some() {
+        return None;
+    }
+    let function = control_flow_root(binding.syntax());
+    let mut reads: Vec<_> = binding
+        .all_reads(model)
+        .filter(|read| control_flow_root(read.syntax()) == function)
+        .collect();
+    reads.sort_by_key(|read| read.range_start());
+    for read in reads {
+        let (node, parent) = skip_type_assertions(read.syntax().parent()?)?;
+        let unchecked = unchecked_use(&node, &parent)?;
+        if !is_in_try_block(&node) {
+            return Some(unchecked);
+        }
+    }
+    None
+}
+
+fn control_flow_root(node: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+}
+
+/// Returns `true` if `node` is in the `try` block of a `try` statement of its function
+/// that has a `catch` clause
+fn is_in_try_block(node: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .take_while(|ancestor| !AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+        .any(|ancestor| {
+            ancestor.kind() == JsSyntaxKind::JS_BLOCK_STATEMENT
+                && ancestor.parent().is_some_and(|parent| {
+                    // A `try...finally` statement without `catch` clause doesn't handle the errors
+                    JsTryStatement::can_cast(parent.kind())
+                        || JsTryFinallyStatement::cast(parent)
+                            .is_some_and(|statement| statement.catch_clause().is_some())
+                })
+        })
+}
@@ -0,0 +1,283 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsImportClause, AnyJsImportLike, JsExportFromClause, JsExportNamedFromClause,
+};
+use biome_rowan::AstNode;
+use serde::{Deserialize, Serialize};
+
+use crate::module_graph::{absolute_path, is_reexport_source, ModuleGraph};
+use crate::utils::restricted_glob::{CandidatePath, RestrictedGlob};
+
+declare_lint_rule! {
+    /// Disallow the production code from importing the test files, and the entry points from exporting them.
+    ///
+    /// The test files, such as `*.test.ts` files or the files of the `__tests__` folders, are
+    /// usually excluded from the build, and their dependencies are development dependencies.
+    /// A production module that imports a test file breaks when the test files aren't shipped,
+    /// or pulls the test dependencies into the bundle.
+    /// An entry point of the package that re-exports a test file, for instance with
+    /// `export * from "./test-utils"`, adds the test utilities to the public API of the package.
+    ///
+    /// This rule reports:
+    /// - the imports, the `require()` calls and the `export ... from` statements of the
+    ///   production files whose relative specifiers resolve to a test file;
+    /// - the `export ... from` statements of the entry points that re-export a test file,
+    ///   directly or through the `export ... from` statements of other modules of the project.
+    ///
+    /// When the whole project is analyzed, the rule follows the re-exports of the modules of the
+    /// project, and resolves the specifiers without extension to the modules of the project.
+    /// The type-only imports and exports are ignored, because they are removed from the
+    /// compiled code.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { createUser } from "./__tests__/factories.js";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { server } = require("../mocks/server.test.js");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { createUser } from "./factories.js";
+    /// ```
+    ///
+    /// ```ts
+    /// import type { Fixture } from "./__tests__/fixtures.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the options described below.
+    /// The globs are matched against the paths relative to the folder of the project.
+    /// A glob that starts with `!` excludes the files that match it.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "testFiles": ["**/*.test.*", "**/testing/**"],
+    ///         "entryPoints": ["src/index.*", "src/server.*"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### testFiles
+    ///
+    /// The globs of the test files.
+    ///
+    /// Default: `["**/__tests__/**", "**/__mocks__/**", "**/test/**", "**/tests/**", "**/*.test.*", "**/*.spec.*"]`
+    ///
+    /// ### entryPoints
+    ///
+    /// The globs of the entry points of the project.
+    ///
+    /// Default: `["index.*", "src/index.*", "lib/index.*"]`
+    ///
+    pub NoWildcardExportsFromTests {
+        version: "next",
+        name: "noWildcardExportsFromTests",
+        language: "js",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noWildcardExportsFromTests`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoWildcardExportsFromTestsOptions {
+    /// The globs of the test files, relative to the folder of the project.
+    pub test_files: Box<[RestrictedGlob]>,
+    /// The globs of the entry points, relative to the folder of the project.
+    pub entry_points: Box<[RestrictedGlob]>,
+}
+
+impl Default for NoWildcardExportsFromTestsOptions {
+    fn default() -> Self {
+        Self {
+            test_files: [
+                "**/__tests__/**",
+                "**/__mocks__/**",
+                "**/test/**",
+                "**/tests/**",
+                "**/*.test.*",
+                "**/*.spec.*",
+            ]
+            .map(|glob| glob.parse().expect("the default globs are valid"))
+            .into(),
+            entry_points: ["index.*", "src/index.*", "lib/index.*"]
+                .map(|glob| glob.parse().expect("the default globs are valid"))
+                .into(),
+        }
+    }
+}
+
+pub enum TestFileLeak {
+    /// A production file imports a test file
+    Import { test_file: PathBuf },
+    /// An entry point re-exports a test file. The chain lists the re-exported modules, from the
+    /// module re-exported by the entry point to the test file.
+    Export { chain: Vec<PathBuf> },
+}
+
+impl Rule for NoWildcardExportsFromTests {
+    type Query = Ast<AnyJsImportLike>;
+    type State = TestFileLeak;
+    type Signals = Option<Self::State>;
+    type Options = Box<NoWildcardExportsFromTestsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_in_ts_module_declaration() || is_type_only(node) {
+            return None;
+        }
+        let specifier = node.inner_string_text()?;
+
+        // The graph is empty when the project isn't analyzed: the globs are then relative to
+        // the folder of the file
+        let graph = ctx.get_service::<Arc<ModuleGraph>>()?;
+        let path = absolute_path(ctx.file_path());
+        let root = if graph.root().as_os_str().is_empty() {
+            path.parent()?.to_path_buf()
+        } else {
+            graph.root().to_path_buf()
+        };
+        let classification = FileClassification {
+            root,
+            options: ctx.options(),
+        };
+        if classification.is_test_file(&path) {
+            return None;
+        }
+
+        let target = graph.resolve(&path, specifier.text())?;
+        let is_reexport =
+            matches!(node, AnyJsImportLike::JsModuleSource(source) if is_reexport_source(source));
+        if is_reexport && classification.is_entry_point(&path) {
+            let chain = if classification.is_test_file(&target) {
+                vec![target]
+            } else {
+                let mut chain =
+                    graph.find_reexport(&target, |module| classification.is_test_file(module))?;
+                chain.insert(0, target);
+                chain
+            };
+            return Some(TestFileLeak::Export {
+                chain: chain
+                    .iter()
+                    .map(|module| classification.relative_path(module).to_path_buf())
+                    .collect(),
+            });
+        }
+
+        classification
+            .is_test_file(&target)
+            .then(|| TestFileLeak::Import {
+                test_file: classification.relative_path(&target).to_path_buf(),
+            })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        match state {
+            TestFileLeak::Import { test_file } => {
+                let test_file = test_file.display().to_string();
+                Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        node.range(),
+                        markup! {
+                            "This production module imports the test file "<Emphasis>{test_file}</Emphasis>"."
+                        },
+                    )
+                    .note(markup! {
+                        "The test files are usually excluded from the build: the import can break, or pull the test dependencies into the bundle."
+                    })
+                    .note(markup! {
+                        "Move the code shared with the tests to a module that isn't a test file."
+                    }),
+                )
+            }
+            TestFileLeak::Export { chain } => {
+                let (test_file, modules) = chain.split_last()?;
+                let test_file = test_file.display().to_string();
+                let mut diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    node.range(),
+                    markup! {
+                        "This entry point exports the test file "<Emphasis>{test_file}</Emphasis>"."
+                    },
+                );
+                if !modules.is_empty() {
+                    let modules = modules
+                        .iter()
+                        .map(|module| module.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    diagnostic = diagnostic.note(markup! {
+                        "The test file is re-exported by "<Emphasis>{modules}</Emphasis>"."
+                    });
+                }
+                Some(
+                    diagnostic
+                        .note(markup! {
+                            "The test utilities become part of the public API of the package."
+                        })
+                        .note(markup! {
+                            "Remove the export, or export the test utilities from an entry point dedicated to the tests."
+                        }),
+                )
+            }
+        }
+    }
+}
+
+/// Returns `true` if `node` is a type-only import or export, such as `import type { A } from "a"`.
+fn is_type_only(node: &AnyJsImportLike) -> bool {
+    let AnyJsImportLike::JsModuleSource(source) = node else {
+        return false;
+    };
+    if let Some(clause) = source.parent::<AnyJsImportClause>() {
+        clause.type_token().is_some()
+    } else if let Some(clause) = source.parent::<JsExportFromClause>() {
+        clause.type_token().is_some()
+    } else if let Some(clause) = source.parent::<JsExportNamedFromClause>() {
+        clause.type_token().is_some()
+    } else {
+        false
+    }
+}
+
+/// Classifies the files of the project with the globs of the options
+struct FileClassification<'a> {
+    /// The folder the globs are relative to
+    root: PathBuf,
+    options: &'a NoWildcardExportsFromTestsOptions,
+}
+
+impl FileClassification<'_> {
+    fn relative_path<'p>(&self, path: &'p Path) -> &'p Path {
+        path.strip_prefix(&self.root).unwrap_or(path)
+    }
+
+    fn is_test_file(&self, path: &Path) -> bool {
+        let path = self.relative_path(path);
+        CandidatePath::new(&path).matches_with_exceptions(self.options.test_files.iter())
+    }
+
+    fn is_entry_point(&self, path: &Path) -> bool {
+        let path = self.relative_path(path);
+        CandidatePath::new(&path).matches_with_exceptions(self.options.entry_points.iter())
+    }
+}
@@ -0,0 +1,498 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    class_member_ext::AccessorKind, AnyJsBinding, AnyJsBindingPattern, AnyJsClass, AnyJsExpression,
+    AnyJsName, AnyJsObjectMember, ClassMemberName, JsAssignmentExpression, JsAssignmentOperator,
+    JsIdentifierBinding, JsIdentifierExpression, JsInitializerClause, JsObjectExpression,
+    JsPostUpdateExpression, JsPreUpdateExpression, JsStaticMemberAssignment,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, TextRange, WalkEvent,
+};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow reading properties that only have a setter, and assigning properties that only have a getter.
+    ///
+    /// A property that only has a setter always evaluates to `undefined` when it's read.
+    /// Assigning a property that only has a getter throws a `TypeError` in strict mode,
+    /// and is silently ignored otherwise.
+    ///
+    /// The rule checks the accessors of classes and object literals.
+    /// A property is considered used when it's accessed through `this` inside the class or the object literal,
+    /// or through the variable that the object literal initializes.
+    /// Properties that are also declared as regular properties or methods are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class Temperature {
+    ///     set celsius(value) {
+    ///         this.kelvin = value + 273.15;
+    ///     }
+    ///
+    ///     toString() {
+    ///         return `${this.celsius}°C`;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const counter = {
+    ///     count: 0,
+    ///     get next() {
+    ///         return this.count + 1;
+    ///     },
+    /// };
+    /// counter.next = 2;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class Temperature {
+    ///     get celsius() {
+    ///         return this.kelvin - 273.15;
+    ///     }
+    ///
+    ///     set celsius(value) {
+    ///         this.kelvin = value + 273.15;
+    ///     }
+    ///
+    ///     toString() {
+    ///         return `${this.celsius}°C`;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// const counter = {
+    ///     count: 0,
+    ///     get next() {
+    ///         return this.count + 1;
+    ///     },
+    /// };
+    /// counter.count = counter.next;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides the options described below.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "setWithoutGet": true,
+    ///         "getWithoutSet": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### setWithoutGet
+    ///
+    /// If `true` (default), the rule reports reads of properties that only have a setter.
+    ///
+    /// ### getWithoutSet
+    ///
+    /// If `true` (default), the rule reports assignments of properties that only have a getter.
+    ///
+    pub UseAccessorPairs {
+        version: "next",
+        name: "useAccessorPairs",
+        language: "js",
+        sources: &[RuleSource::Eslint("accessor-pairs")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyAccessorOwner = AnyJsClass | JsObjectExpression
+}
+
+pub struct UnpairedAccessorUse {
+    /// The kind of the only accessor of the property
+    kind: AccessorKind,
+    /// Range of the name of the accessor
+    accessor_range: TextRange,
+    /// Range of the member access that needs the missing accessor
+    access_range: TextRange,
+    name: ClassMemberName,
+}
+
+impl Rule for UseAccessorPairs {
+    type Query = Semantic<AnyAccessorOwner>;
+    type State = UnpairedAccessorUse;
+    type Signals = Box<[Self::State]>;
+    type Options = AccessorPairsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let accessors = match ctx.query() {
+            AnyAccessorOwner::AnyJsClass(class) => unpaired_class_accessors(class),
+            AnyAccessorOwner::JsObjectExpression(object) => {
+                unpaired_object_accessors(object, ctx.model())
+            }
+        };
+
+        let mut signals = Vec::new();
+        for accessor in accessors {
+            let reported_access = match accessor.kind {
+                AccessorKind::Setter if options.set_without_get => Access::is_read,
+                AccessorKind::Getter if options.get_without_set => Access::is_write,
+                _ => continue,
+            };
+            signals.extend(
+                accessor
+                    .accesses
+                    .into_iter()
+                    .filter(|(access, _)| reported_access(*access))
+                    .map(|(_, access_range)| UnpairedAccessorUse {
+                        kind: accessor.kind,
+                        accessor_range: accessor.range,
+                        access_range,
+                        name: accessor.name.clone(),
+                    }),
+            );
+        }
+        signals.sort_unstable_by_key(|signal| signal.access_range.start());
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnpairedAccessorUse {
+            kind,
+            accessor_range,
+            access_range,
+            name,
+        } = state;
+        let name = match name {
+            ClassMemberName::Private(name) => format!("#{}", name.text()),
+            ClassMemberName::Public(name) => name.text().to_string(),
+        };
+        let diagnostic = match kind {
+            AccessorKind::Setter => RuleDiagnostic::new(
+                rule_category!(),
+                *access_range,
+                markup! {
+                    "The property "<Emphasis>{name}</Emphasis>" is read, but it only has a setter."
+                },
+            )
+            .detail(*accessor_range, markup! { "The setter is declared here." })
+            .note(markup! {
+                "Reading a property that only has a setter always evaluates to "<Emphasis>"undefined"</Emphasis>"."
+            })
+            .note(markup! {
+                "Add a getter to the property, or remove the read."
+            }),
+            AccessorKind::Getter => RuleDiagnostic::new(
+                rule_category!(),
+                *access_range,
+                markup! {
+                    "The property "<Emphasis>{name}</Emphasis>" is assigned, but it only has a getter."
+                },
+            )
+            .detail(*accessor_range, markup! { "The getter is declared here." })
+            .note(markup! {
+                "Assigning a property that only has a getter throws a "<Emphasis>"TypeError"</Emphasis>" in strict mode, and is silently ignored otherwise."
+            })
+            .note(markup! {
+                "Add a setter to the property, or remove the assignment."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Options for the rule `useAccessorPairs`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct AccessorPairsOptions {
+    /// If `true`, reads of properties that only have a setter are reported.
+    pub set_without_get: bool,
+
+    /// If `true`, assignments of properties that only have a getter are reported.
+    pub get_without_set: bool,
+}
+
+impl Default for AccessorPairsOptions {
+    fn default() -> Self {
+        Self {
+            set_without_get: true,
+            get_without_set: true,
+        }
+    }
+}
+
+/// An accessor that doesn't have a counterpart, with the accesses to its property
+struct UnpairedAccessor {
+    kind: AccessorKind,
+    name: ClassMemberName,
+    range: TextRange,
+    accesses: Vec<(Access, TextRange)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Access {
+    /// `this.a`
+    Read,
+    /// `this.a = b`
+    Write,
+    /// `this.a += b`, `this.a++`
+    ReadWrite,
+}
+
+impl Access {
+    fn is_read(self) -> bool {
+        matches!(self, Self::Read | Self::ReadWrite)
+    }
+
+    fn is_write(self) -> bool {
+        matches!(self, Self::Write | Self::ReadWrite)
+    }
+}
+
+fn unpaired_class_accessors(class: &AnyJsClass) -> Vec<UnpairedAccessor> {
+    let members = class.members();
+    let mut visited = FxHashSet::default();
+    let mut result = Vec::new();
+    for member in members.iter() {
+        let Some(kind) = member.accessor_kind() else {
+            continue;
+        };
+        let (Some(name), Ok(Some(member_name))) = (member.member_name(), member.name()) else {
+            continue;
+        };
+        let is_static = member.is_static();
+        if !visited.insert((name.clone(), is_static)) {
+            continue;
+        }
+        let counterpart = match kind {
+            AccessorKind::Getter => AccessorKind::Setter,
+            AccessorKind::Setter => AccessorKind::Getter,
+        };
+        if members
+            .find_accessor(counterpart, &name, is_static)
+            .is_some()
+        {
+            continue;
+        }
+        // Ignore the properties that are also declared as fields or methods
+        let is_redeclared = members
+            .find_members_by_name(&name)
+            .any(|other| other.is_static() == is_static && other.accessor_kind().is_none());
+        if is_redeclared {
+            continue;
+        }
+        // `this` refers to the class in static members, and to the instance otherwise
+        let accesses = members
+            .iter()
+            .filter(|other| other.is_static() == is_static)
+            .flat_map(|other| this_accesses(other.syntax(), &name))
+            .collect();
+        result.push(UnpairedAccessor {
+            kind,
+            name,
+            range: member_name.range(),
+            accesses,
+        });
+    }
+    result
+}
+
+fn unpaired_object_accessors(
+    object: &JsObjectExpression,
+    model: &SemanticModel,
+) -> Vec<UnpairedAccessor> {
+    let members: Vec<_> = object
+        .members()
+        .iter()
+        .filter_map(Result::ok)
+        .filter_map(|member| {
+            let (kind, name) = match &member {
+                AnyJsObjectMember::JsGetterObjectMember(getter) => {
+                    (Some(AccessorKind::Getter), getter.name().ok()?)
+                }
+                AnyJsObjectMember::JsSetterObjectMember(setter) => {
+                    (Some(AccessorKind::Setter), setter.name().ok()?)
+                }
+                AnyJsObjectMember::JsMethodObjectMember(method) => (None, method.name().ok()?),
+                AnyJsObjectMember::JsPropertyObjectMember(property) => {
+                    (None, property.name().ok()?)
+                }
+                AnyJsObjectMember::JsShorthandPropertyObjectMember(property) => {
+                    let name = property.name().ok()?.value_token().ok()?;
+                    return Some((None, name.token_text_trimmed(), name.text_trimmed_range()));
+                }
+                AnyJsObjectMember::JsBogusMember(_) | AnyJsObjectMember::JsSpread(_) => {
+                    return None;
+                }
+            };
+            Some((kind, name.name()?, name.range()))
+        })
+        .collect();
+
+    // Accesses through the variable initialized with the object, such as `obj.a`
+    let variable_accesses: Vec<_> = object_variable(object)
+        .map(|binding| {
+            binding
+                .all_references(model)
+                .filter_map(|reference| {
+                    let expression = reference.syntax().parent()?;
+                    if !JsIdentifierExpression::can_cast(expression.kind()) {
+                        return None;
+                    }
+                    let access = expression.parent()?;
+                    let (_, member, access_kind) = member_access(&access)?;
+                    Some((member, access_kind, access.text_trimmed_range()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut visited = FxHashSet::default();
+    let mut result = Vec::new();
+    for (kind, name, range) in &members {
+        let Some(kind) = *kind else {
+            continue;
+        };
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        let has_counterpart = members
+            .iter()
+            .any(|(other_kind, other_name, _)| other_name == name && *other_kind != Some(kind));
+        if has_counterpart {
+            continue;
+        }
+        let name = ClassMemberName::Public(name.clone());
+        // `this` refers to the object in its methods and accessors
+        let mut accesses: Vec<_> = object
+            .members()
+            .iter()
+            .filter_map(Result::ok)
+            .filter(|member| binds_this(member.syntax().kind()))
+            .flat_map(|member| this_accesses(member.syntax(), &name))
+            .collect();
+        accesses.extend(
+            variable_accesses
+                .iter()
+                .filter(|(member, _, _)| is_member_named(member, &name))
+                .map(|(_, access, range)| (*access, *range)),
+        );
+        result.push(UnpairedAccessor {
+            kind,
+            name,
+            range: *range,
+            accesses,
+        });
+    }
+    result
+}
+
+/// Returns the accesses to the property `name` of `this` inside `root`.
+///
+/// Nested functions, classes, and methods of object literals are skipped,
+/// because they bind their own `this`.
+fn this_accesses(root: &JsSyntaxNode, name: &ClassMemberName) -> Vec<(Access, TextRange)> {
+    let mut accesses = Vec::new();
+    let mut iter = root.preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if &node != root && binds_this(node.kind()) {
+            iter.skip_subtree();
+            continue;
+        }
+        let Some((object, member, access)) = member_access(&node) else {
+            continue;
+        };
+        if matches!(object, AnyJsExpression::JsThisExpression(_)) && is_member_named(&member, name)
+        {
+            accesses.push((access, node.text_trimmed_range()));
+        }
+    }
+    accesses
+}
+
+/// Returns `true` if a node of kind `kind` introduces a new binding of `this`
+fn binds_this(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+    )
+}
+
+/// Returns the object, the member, and the kind of access of a static member access
+fn member_access(node: &JsSyntaxNode) -> Option<(AnyJsExpression, AnyJsName, Access)> {
+    if let Some(expression) = JsStaticMemberExpression::cast_ref(node) {
+        return Some((
+            expression.object().ok()?,
+            expression.member().ok()?,
+            Access::Read,
+        ));
+    }
+    let assignment = JsStaticMemberAssignment::cast_ref(node)?;
+    let parent = assignment.syntax().parent()?;
+    let access = if let Some(parent) = JsAssignmentExpression::cast_ref(&parent) {
+        if parent.operator().ok()? == JsAssignmentOperator::Assign {
+            Access::Write
+        } else {
+            Access::ReadWrite
+        }
+    } else if JsPreUpdateExpression::can_cast(parent.kind())
+        || JsPostUpdateExpression::can_cast(parent.kind())
+    {
+        Access::ReadWrite
+    } else {
+        // Destructuring assignments and `for...in`/`for...of` loops
+        Access::Write
+    };
+    Some((assignment.object().ok()?, assignment.member().ok()?, access))
+}
+
+fn is_member_named(member: &AnyJsName, name: &ClassMemberName) -> bool {
+    let Ok(token) = member.value_token() else {
+        return false;
+    };
+    let is_private = matches!(member, AnyJsName::JsPrivateName(_));
+    match name {
+        ClassMemberName::Private(name) => is_private && token.text_trimmed() == name.text(),
+        ClassMemberName::Public(name) => !is_private && token.text_trimmed() == name.text(),
+    }
+}
+
+/// Returns the binding of the variable initialized with `object`, such as `obj` in `const obj = {}`
+fn object_variable(object: &JsObjectExpression) -> Option<JsIdentifierBinding> {
+    let initializer = object
+        .syntax()
+        .parent()
+        .and_then(JsInitializerClause::cast)?;
+    let declarator = initializer.parent::<JsVariableDeclarator>()?;
+    match declarator.id().ok()? {
+        AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) => {
+            Some(binding)
+        }
+        _ => None,
+    }
+}
@@ -1,7 +1,9 @@
 use crate::JsRuleAction;
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
-    RuleSource, RuleSourceKind,
+    browserslist::{BrowserTargets, Feature},
+    context::RuleContext,
+    declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
 };
 use biome_console::{markup, MarkupBuf};
 use biome_js_factory::make::{self};
@@ -11,6 +13,7 @@ use biome_js_syntax::{
     JsStaticMemberExpression, JsUnaryExpression, T,
 };
 use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+use std::sync::Arc;
 
 declare_lint_rule! {
     /// Use `at()` instead of integer index access.
@@ -22,6 +25,9 @@ declare_lint_rule! {
     ///
     /// This rule enforces the usage of `at()` over index access, `charAt()`, and `slice()[0]` when `at()` is more convenient.
     ///
+    /// The rule doesn't report anything when some of the targeted browsers don't support `at()`.
+    /// The targeted browsers are read from the closest `.browserslistrc` file, or from the
+    /// `browserslist` field of `package.json`.
     ///
     /// ## Examples
     ///
@@ -131,6 +137,10 @@ impl Rule for UseAtIndex {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let exp = ctx.query();
+        let targets = ctx.get_service::<Arc<BrowserTargets>>()?;
+        if !targets.supports(Feature::ArrayAt) {
+            return None;
+        }
 
         let result: Option<UseAtIndexState> = match exp {
             // foo[a]
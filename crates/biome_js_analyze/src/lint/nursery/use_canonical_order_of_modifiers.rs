@@ -0,0 +1,190 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    sort_modifiers_by_precedence, JsGetterClassMember, JsLanguage, JsMethodClassMember,
+    JsPropertyClassMember, JsSetterClassMember, JsSyntaxNode, Modifier, TextRange,
+    TsGetterSignatureClassMember, TsIndexSignatureClassMember,
+    TsInitializedPropertySignatureClassMember, TsMethodSignatureClassMember, TsPropertyParameter,
+    TsPropertySignatureClassMember, TsSetterSignatureClassMember,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce a canonical order of the modifiers of the class members and the parameter properties.
+    ///
+    /// Most of the modifiers must be written in a specific order, but TypeScript accepts
+    /// `declare` anywhere among them, such as in `static declare` or `declare public`,
+    /// which makes the same member look different from one class to another.
+    /// This rule requires the order in which the formatter prints the modifiers:
+    ///
+    /// 1. the decorators;
+    /// 2. the accessibility: `private`, `protected` or `public`;
+    /// 3. `declare`;
+    /// 4. `static`;
+    /// 5. `abstract`;
+    /// 6. `override`;
+    /// 7. `readonly`;
+    /// 8. `accessor`.
+    ///
+    /// The rule and the formatter share the same ordering, so they never disagree.
+    /// The modifiers of the declarations, such as `export default abstract class`, only
+    /// accept a single order, so they aren't checked.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Config {
+    ///     static declare defaults: object;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Config {
+    ///     declare public value: number;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// class Config {
+    ///     declare static defaults: object;
+    ///     public declare value: number;
+    ///     protected static readonly sides = 0;
+    /// }
+    /// ```
+    ///
+    pub UseCanonicalOrderOfModifiers {
+        version: "next",
+        name: "useCanonicalOrderOfModifiers",
+        language: "ts",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyMemberWithModifiers =
+        JsGetterClassMember
+        | JsMethodClassMember
+        | JsPropertyClassMember
+        | JsSetterClassMember
+        | TsGetterSignatureClassMember
+        | TsIndexSignatureClassMember
+        | TsInitializedPropertySignatureClassMember
+        | TsMethodSignatureClassMember
+        | TsPropertyParameter
+        | TsPropertySignatureClassMember
+        | TsSetterSignatureClassMember
+}
+
+pub struct MisorderedModifiers {
+    /// The range of the modifier list
+    range: TextRange,
+    /// The modifiers that aren't at their position, along with the modifier that belongs there
+    moves: Box<[(JsSyntaxNode, JsSyntaxNode)]>,
+}
+
+impl Rule for UseCanonicalOrderOfModifiers {
+    type Query = Ast<AnyMemberWithModifiers>;
+    type State = MisorderedModifiers;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyMemberWithModifiers::JsGetterClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::JsMethodClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::JsPropertyClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::JsSetterClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsGetterSignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsIndexSignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsInitializedPropertySignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsMethodSignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsPropertyParameter(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsPropertySignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+            AnyMemberWithModifiers::TsSetterSignatureClassMember(member) => {
+                misordered_modifiers(&member.modifiers())
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The modifiers aren't in the canonical order."
+                },
+            )
+            .note(markup! {
+                "The accessibility comes first, followed by "<Emphasis>"declare"</Emphasis>", "<Emphasis>"static"</Emphasis>", "<Emphasis>"abstract"</Emphasis>", "<Emphasis>"override"</Emphasis>", "<Emphasis>"readonly"</Emphasis>" and "<Emphasis>"accessor"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        // Each position keeps its trivia, so the modifiers stay separated as they were
+        for (modifier, new_modifier) in &state.moves {
+            mutation.replace_element(modifier.clone().into(), new_modifier.clone().into());
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Sort the modifiers." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the modifiers of `list` that aren't at their position in the canonical order.
+fn misordered_modifiers<List, Node>(list: &List) -> Option<MisorderedModifiers>
+where
+    Node: AstNode<Language = JsLanguage>,
+    List: AstNodeList<Language = JsLanguage, Node = Node>,
+    Modifier: for<'a> From<&'a Node>,
+{
+    let moves = list
+        .iter()
+        .zip(sort_modifiers_by_precedence(list))
+        .filter(|(modifier, new_modifier)| modifier.syntax() != new_modifier.syntax())
+        .map(|(modifier, new_modifier)| {
+            (modifier.into_syntax(), new_modifier.into_syntax().detach())
+        })
+        .collect::<Box<[_]>>();
+    if moves.is_empty() {
+        return None;
+    }
+    Some(MisorderedModifiers {
+        range: list.syntax_list().node().text_trimmed_range(),
+        moves,
+    })
+}
@@ -0,0 +1,421 @@
+use crate::services::control_flow::{AnyJsControlFlowRoot, JsControlFlowGraph};
+use crate::ControlFlowGraph;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_control_flow::{
+    builder::{BlockId, ROOT_BLOCK_ID},
+    ExceptionHandlerKind, InstructionKind,
+};
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsExpression,
+    AnyJsFormalParameter, AnyJsFunction, AnyJsFunctionBody, AnyJsMemberExpression, AnyJsParameter,
+    JsAwaitExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
+    JsIdentifierExpression, JsInitializerClause, JsReferenceIdentifier, JsSyntaxNode,
+    JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstSeparatedList, NodeOrToken, TextRange, WalkEvent};
+use roaring::RoaringBitmap;
+
+declare_lint_rule! {
+    /// Require checking the status of the responses of `fetch()` before reading their body.
+    ///
+    /// Unlike most HTTP clients, `fetch()` doesn't reject its promise when the server responds
+    /// with an error status, such as 404 or 500.
+    /// Reading the body with `.json()` without checking `response.ok` or `response.status`
+    /// then fails with a confusing parsing error, or silently uses the error payload as if it
+    /// were the expected data.
+    ///
+    /// The rule follows the control flow of the function that fetches the response, and reports
+    /// the calls to `.json()` that can be reached without checking the status first.
+    /// The status is considered checked when the function reads `response.ok` or
+    /// `response.status`, or when it passes the response to another function, such as
+    /// `assertOk(response)`.
+    ///
+    /// The rule checks the responses assigned to a variable with `await fetch()`, the responses
+    /// received by the first callback of `fetch().then()`, and the body read directly with
+    /// `(await fetch()).json()`.
+    ///
+    /// Libraries such as [axios](https://axios-http.com/) reject their promise when the status
+    /// isn't successful, so their responses aren't reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function getUser(id) {
+    ///     const response = await fetch(`/users/${id}`);
+    ///     return await response.json();
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// fetch("/users").then((response) => response.json());
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function getUser(id) {
+    ///     const response = await fetch(`/users/${id}`);
+    ///     if (id === 0) {
+    ///         return null;
+    ///     }
+    ///     return response.json();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function getUser(id) {
+    ///     const response = await fetch(`/users/${id}`);
+    ///     if (!response.ok) {
+    ///         throw new Error(`Request failed with status ${response.status}`);
+    ///     }
+    ///     return await response.json();
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// fetch("/users").then((response) => (response.ok ? response.json() : []));
+    /// ```
+    ///
+    pub UseConsistentResponseHandling {
+        version: "next",
+        name: "useConsistentResponseHandling",
+        language: "js",
+        recommended: false,
+    }
+}
+
+impl Rule for UseConsistentResponseHandling {
+    type Query = ControlFlowGraph;
+    type State = UncheckedResponse;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let cfg = ctx.query();
+        let mut responses = Vec::new();
+
+        // An arrow function with an expression as body doesn't have any instruction
+        let expression_body = AnyJsFunction::cast_ref(&cfg.node)
+            .and_then(|function| function.body().ok())
+            .and_then(|body| match body {
+                AnyJsFunctionBody::AnyJsExpression(expression) => Some(expression),
+                AnyJsFunctionBody::JsFunctionBody(_) => None,
+            });
+        for (block_id, block) in cfg.block_id_iter() {
+            for (index, instruction) in block.instructions.iter().enumerate() {
+                let Some(node) = instruction.node.as_ref().and_then(NodeOrToken::as_node) else {
+                    continue;
+                };
+                responses.extend(unchecked_direct_reads(node));
+                // `const response = await fetch(url)`
+                if let Some((name, fetch)) = declared_response(node) {
+                    find_unchecked_reads(
+                        cfg,
+                        &name,
+                        node,
+                        fetch.range(),
+                        (block_id, index + 1),
+                        &mut responses,
+                    );
+                }
+            }
+        }
+        if let Some(body) = &expression_body {
+            responses.extend(unchecked_direct_reads(body.syntax()));
+        }
+
+        // `fetch(url).then((response) => response.json())`
+        if let Some((name, fetch)) = AnyJsFunction::cast_ref(&cfg.node)
+            .as_ref()
+            .and_then(fetch_callback_response)
+        {
+            if let Some(body) = expression_body {
+                let (reads, _) = check_instruction(body.syntax(), &name);
+                responses.extend(reads.into_iter().map(|body_read| UncheckedResponse {
+                    body_read,
+                    fetch: fetch.range(),
+                }));
+            } else {
+                find_unchecked_reads(
+                    cfg,
+                    &name,
+                    &cfg.node,
+                    fetch.range(),
+                    (ROOT_BLOCK_ID, 0),
+                    &mut responses,
+                );
+            }
+        }
+
+        responses.sort_by_key(|response| response.body_read.start());
+        responses.dedup_by_key(|response| response.body_read);
+        responses.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.body_read,
+                markup! {
+                    "The body of this response is read without checking its status."
+                },
+            )
+            .detail(
+                state.fetch,
+                markup! {
+                    "The response is fetched here."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"fetch()"</Emphasis>" doesn't reject when the server responds with an error status, such as 404 or 500: the body is then an error page or an error payload."
+            })
+            .note(markup! {
+                "Check "<Emphasis>"response.ok"</Emphasis>" or "<Emphasis>"response.status"</Emphasis>" before reading the body."
+            }),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct UncheckedResponse {
+    /// The range of the call that reads the body, such as `response.json()`
+    body_read: TextRange,
+    /// The range of the call to `fetch()` that returned the response
+    fetch: TextRange,
+}
+
+/// Returns the bodies read directly from the result of `fetch()` in `node`, such as
+/// `(await fetch(url)).json()`.
+fn unchecked_direct_reads(node: &JsSyntaxNode) -> impl Iterator<Item = UncheckedResponse> {
+    function_descendants(node)
+        .filter_map(JsCallExpression::cast)
+        .filter_map(|call| {
+            let object = body_read_object(&call)?;
+            let fetch = JsAwaitExpression::cast(object.omit_parentheses().into_syntax())?
+                .argument()
+                .ok()
+                .and_then(|argument| fetch_call(&argument))?;
+            Some(UncheckedResponse {
+                body_read: call.range(),
+                fetch: fetch.range(),
+            })
+        })
+}
+
+/// Follows the control flow from `start`, and collects the reads of the body of the response
+/// `name` that aren't preceded by a check of its status.
+///
+/// `declaration` is the node that assigns the response: the paths that reach it again, such as
+/// in a loop, assign a new response and are no longer followed.
+fn find_unchecked_reads(
+    cfg: &JsControlFlowGraph,
+    name: &str,
+    declaration: &JsSyntaxNode,
+    fetch: TextRange,
+    start: (BlockId, usize),
+    responses: &mut Vec<UncheckedResponse>,
+) {
+    let mut block_stack = vec![start];
+    let mut visited_blocks = RoaringBitmap::new();
+    'blocks: while let Some((block_id, start_index)) = block_stack.pop() {
+        let block = cfg.get(block_id);
+        for exception_handler in block.exception_handlers.iter() {
+            // Ignore finally handler: they are already in the Control Flow Graph.
+            if matches!(exception_handler.kind, ExceptionHandlerKind::Catch)
+                && visited_blocks.insert(exception_handler.target.index())
+            {
+                block_stack.push((exception_handler.target, 0));
+            }
+        }
+        for instruction in block.instructions.iter().skip(start_index) {
+            if let Some(node) = instruction.node.as_ref().and_then(NodeOrToken::as_node) {
+                if node == declaration {
+                    continue 'blocks;
+                }
+                let (reads, is_checked) = check_instruction(node, name);
+                responses.extend(
+                    reads
+                        .into_iter()
+                        .map(|body_read| UncheckedResponse { body_read, fetch }),
+                );
+                if is_checked {
+                    // The status is checked on this path
+                    continue 'blocks;
+                }
+            }
+            match instruction.kind {
+                InstructionKind::Statement => {}
+                InstructionKind::Jump {
+                    conditional,
+                    block: jump_block_id,
+                    ..
+                } => {
+                    // Avoid cycles and redundant checks.
+                    if visited_blocks.insert(jump_block_id.index()) {
+                        block_stack.push((jump_block_id, 0));
+                    }
+                    if !conditional {
+                        // The next instructions are unreachable.
+                        break;
+                    }
+                }
+                InstructionKind::Return => {
+                    // The next instructions are unreachable.
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the reads of the body of the response `name` in `node` that precede the first check
+/// of its status, and whether `node` checks the status.
+fn check_instruction(node: &JsSyntaxNode, name: &str) -> (Vec<TextRange>, bool) {
+    let mut reads = Vec::new();
+    for reference in function_descendants(node).filter_map(JsReferenceIdentifier::cast) {
+        if !reference
+            .value_token()
+            .is_ok_and(|token| token.text_trimmed() == name)
+        {
+            continue;
+        }
+        let Some(expression) = reference.parent::<JsIdentifierExpression>() else {
+            continue;
+        };
+        if let Some(member) = expression.parent::<AnyJsMemberExpression>() {
+            let Some(member_name) = member.member_name() else {
+                continue;
+            };
+            match member_name.text() {
+                "ok" | "status" => return (reads, true),
+                "json" => {
+                    if let Some(call) = member
+                        .parent::<JsCallExpression>()
+                        .filter(|call| body_read_object(call).is_some())
+                    {
+                        reads.push(call.range());
+                    }
+                }
+                _ => {}
+            }
+        } else if expression.parent::<JsCallArgumentList>().is_some() {
+            // The response is passed to a function that may check it
+            return (reads, true);
+        }
+    }
+    (reads, false)
+}
+
+/// Returns the response of `call` if it reads the body of a response as JSON, such as
+/// `response.json()`.
+fn body_read_object(call: &JsCallExpression) -> Option<AnyJsExpression> {
+    let callee = AnyJsMemberExpression::cast(call.callee().ok()?.into_syntax())?;
+    if callee.member_name()?.text() != "json" {
+        return None;
+    }
+    callee.object().ok()
+}
+
+/// Returns the call if `expression` calls `fetch()`, `window.fetch()` or `globalThis.fetch()`.
+fn fetch_call(expression: &AnyJsExpression) -> Option<JsCallExpression> {
+    let call = JsCallExpression::cast(expression.clone().omit_parentheses().into_syntax())?;
+    let is_fetch = match call.callee().ok()?.omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .ok()?
+            .value_token()
+            .is_ok_and(|name| name.text_trimmed() == "fetch"),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let object = member.object().ok()?.omit_parentheses();
+            let object = object.as_js_identifier_expression()?.name().ok()?;
+            let object = object.value_token().ok()?;
+            matches!(object.text_trimmed(), "window" | "globalThis" | "self")
+                && member
+                    .member()
+                    .ok()?
+                    .as_js_name()?
+                    .value_token()
+                    .is_ok_and(|name| name.text_trimmed() == "fetch")
+        }
+        _ => false,
+    };
+    is_fetch.then_some(call)
+}
+
+/// Returns the name of the variable and the call to `fetch()` if `node` is the initializer of a
+/// variable that is assigned a response, such as `const response = await fetch(url)`.
+fn declared_response(node: &JsSyntaxNode) -> Option<(String, JsCallExpression)> {
+    let initializer = AnyJsExpression::cast_ref(node)?;
+    let fetch = JsAwaitExpression::cast(initializer.clone().omit_parentheses().into_syntax())?
+        .argument()
+        .ok()
+        .and_then(|argument| fetch_call(&argument))?;
+    let declarator = initializer
+        .parent::<JsInitializerClause>()?
+        .parent::<JsVariableDeclarator>()?;
+    let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+        declarator.id().ok()?
+    else {
+        return None;
+    };
+    let name = binding.name_token().ok()?;
+    Some((name.text_trimmed().to_string(), fetch))
+}
+
+/// Returns the name of the first parameter and the call to `fetch()` if `function` is the first
+/// callback of `fetch(url).then()`.
+fn fetch_callback_response(function: &AnyJsFunction) -> Option<(String, JsCallExpression)> {
+    let arguments = function
+        .parent::<JsCallArgumentList>()
+        .filter(|arguments| {
+            arguments
+                .first()
+                .and_then(|argument| argument.ok())
+                .is_some_and(|argument| argument.syntax() == function.syntax())
+        })?
+        .parent::<JsCallArguments>()?;
+    let then = arguments.parent::<JsCallExpression>()?.callee().ok()?;
+    let then = AnyJsMemberExpression::cast(then.into_syntax())?;
+    if then.member_name()?.text() != "then" {
+        return None;
+    }
+    let fetch = fetch_call(&then.object().ok()?)?;
+    let binding = match function.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => binding,
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+            match parameters.items().first()?.ok()? {
+                AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                    parameter,
+                )) => match parameter.binding().ok()? {
+                    AnyJsBindingPattern::AnyJsBinding(binding) => binding,
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+    };
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    Some((name.text_trimmed().to_string(), fetch))
+}
+
+/// Returns `node` and its descendants, without the nested functions, which have their own
+/// control flow
+fn function_descendants(node: &JsSyntaxNode) -> impl Iterator<Item = JsSyntaxNode> {
+    let mut iter = node.preorder();
+    std::iter::from_fn(move || loop {
+        match iter.next()? {
+            WalkEvent::Enter(node) => {
+                if AnyJsControlFlowRoot::can_cast(node.kind()) {
+                    iter.skip_subtree();
+                } else {
+                    return Some(node);
+                }
+            }
+            WalkEvent::Leave(_) => {}
+        }
+    })
+}
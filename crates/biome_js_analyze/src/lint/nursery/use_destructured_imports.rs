@@ -0,0 +1,256 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    inner_string_text, AnyJsCombinedSpecifier, AnyJsImportClause, JsImport, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+use biome_string_case::Case;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::services::semantic::Semantic;
+use crate::utils::namespace_import::{to_named_imports, to_namespace_import};
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce importing the configured modules either with named imports or with a namespace import.
+    ///
+    /// Projects often agree on a single way to import some modules: the members of `lodash` are
+    /// imported by their name, such as `import { map } from "lodash"`, while React is always
+    /// imported as a namespace, such as `import * as React from "react"`.
+    /// This rule reports the imports of the modules listed in the `modules` option that don't
+    /// use the configured style.
+    /// The type-only imports are ignored.
+    ///
+    /// The fix converts the import and every reference to the imported variables in the file:
+    /// `React.useState` becomes `useState` when the members are imported by their name, and
+    /// `useState` becomes `React.useState` when the module is imported as a namespace.
+    /// It isn't available when an imported variable is used in another way than in an
+    /// expression, a type or a JSX element name, or when the new variables would conflict with
+    /// other variables of the file.
+    /// The fix is unsafe, because the functions that are called as members of the namespace
+    /// receive it as `this`.
+    ///
+    /// When the rule isn't configured, no import is reported.
+    ///
+    /// ## Examples
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "modules": {
+    ///             "lodash": {
+    ///                 "style": "named"
+    ///             },
+    ///             "react": {
+    ///                 "style": "namespace",
+    ///                 "namespace": "React"
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// With the configuration above, the following imports are reported:
+    ///
+    /// ```js
+    /// import * as _ from "lodash";
+    /// import { useState } from "react";
+    ///
+    /// const [items, setItems] = useState(_.map(data, toItem));
+    /// ```
+    ///
+    /// They are fixed to:
+    ///
+    /// ```js
+    /// import { map } from "lodash";
+    /// import * as React from "react";
+    ///
+    /// const [items, setItems] = React.useState(map(data, toItem));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### modules
+    ///
+    /// The modules whose imports are checked, mapped to the way they're imported.
+    /// Each module accepts the following fields:
+    ///
+    /// - `style`: `"named"` (default) imports the members of the module by their name, and
+    ///   `"namespace"` imports the module as a namespace.
+    /// - `namespace`: the name of the namespace that the fix imports.
+    ///   It defaults to the last segment of the module specifier in camel case, such as
+    ///   `reactDom` for `react-dom`.
+    ///
+    pub UseDestructuredImports {
+        version: "next",
+        name: "useDestructuredImports",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useDestructuredImports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseDestructuredImportsOptions {
+    /// The modules whose imports are checked, mapped to the way they're imported.
+    #[serde(skip_serializing_if = "FxHashMap::is_empty")]
+    pub modules: FxHashMap<Box<str>, ModuleImports>,
+}
+
+/// The way a module is imported.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ModuleImports {
+    /// Whether the members of the module are imported by their name or as a namespace.
+    pub style: ImportStyle,
+    /// The name of the namespace that the fix imports.
+    /// It defaults to the last segment of the module specifier in camel case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<Box<str>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum ImportStyle {
+    /// Import the members by their name, such as `import { map } from "lodash"`.
+    #[default]
+    Named,
+    /// Import the module as a namespace, such as `import * as React from "react"`.
+    Namespace,
+}
+
+pub struct UnexpectedImportStyle {
+    /// The range of the namespace specifier or of the named specifiers
+    range: TextRange,
+    /// The specifier of the module, without the quotes
+    module_name: Box<str>,
+    /// The configured style
+    style: ImportStyle,
+}
+
+impl Rule for UseDestructuredImports {
+    type Query = Semantic<JsImport>;
+    type State = UnexpectedImportStyle;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseDestructuredImportsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let clause = ctx.query().import_clause().ok()?;
+        if clause.type_token().is_some() {
+            return None;
+        }
+        let module_name_token = clause.source().ok()?.value_token().ok()?;
+        let module_name = inner_string_text(&module_name_token);
+        let style = ctx.options().modules.get(module_name.text())?.style;
+        let range = match (style, &clause) {
+            (ImportStyle::Named, AnyJsImportClause::JsImportNamespaceClause(clause)) => {
+                clause.namespace_specifier().ok()?.range()
+            }
+            (ImportStyle::Named, AnyJsImportClause::JsImportCombinedClause(clause)) => {
+                let AnyJsCombinedSpecifier::JsNamespaceImportSpecifier(specifier) =
+                    clause.specifier().ok()?
+                else {
+                    return None;
+                };
+                specifier.range()
+            }
+            (ImportStyle::Namespace, AnyJsImportClause::JsImportNamedClause(clause)) => {
+                let specifiers = clause.named_specifiers().ok()?;
+                if specifiers.specifiers().is_empty() {
+                    return None;
+                }
+                specifiers.range()
+            }
+            (ImportStyle::Namespace, AnyJsImportClause::JsImportCombinedClause(clause)) => {
+                let AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers) =
+                    clause.specifier().ok()?
+                else {
+                    return None;
+                };
+                if specifiers.specifiers().is_empty() {
+                    return None;
+                }
+                specifiers.range()
+            }
+            _ => return None,
+        };
+        Some(UnexpectedImportStyle {
+            range,
+            module_name: module_name.text().into(),
+            style,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let module_name = &*state.module_name;
+        let diagnostic = match state.style {
+            ImportStyle::Named => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The members of the module "<Emphasis>{module_name}</Emphasis>" should be imported by their name."
+                },
+            ),
+            ImportStyle::Namespace => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The module "<Emphasis>{module_name}</Emphasis>" should be imported as a namespace."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "The import style of the module is configured in the "<Emphasis>"modules"</Emphasis>" option of the rule."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let clause = ctx.query().import_clause().ok()?;
+        let mut mutation = ctx.root().begin();
+        let message = match state.style {
+            ImportStyle::Named => {
+                let AnyJsImportClause::JsImportNamespaceClause(clause) = clause else {
+                    return None;
+                };
+                to_named_imports(&clause, ctx.model(), &mut mutation)?;
+                markup! { "Import the used members by their name." }.to_owned()
+            }
+            ImportStyle::Namespace => {
+                let AnyJsImportClause::JsImportNamedClause(clause) = clause else {
+                    return None;
+                };
+                let module_imports = ctx.options().modules.get(&state.module_name)?;
+                let namespace = match module_imports.namespace.as_deref() {
+                    Some(namespace) => namespace.to_string(),
+                    None => default_namespace(&state.module_name),
+                };
+                to_namespace_import(&clause, &namespace, ctx.model(), &mut mutation)?;
+                markup! { "Import the module as the namespace "<Emphasis>{namespace}</Emphasis>"." }
+                    .to_owned()
+            }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns the last segment of `module_name` in camel case, such as `reactDom` for
+/// `@types/react-dom`.
+fn default_namespace(module_name: &str) -> String {
+    let segment = module_name.rsplit('/').next().unwrap_or(module_name);
+    Case::Camel.convert(segment)
+}
@@ -0,0 +1,227 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::{CanBeImportedExported, ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, JsAssignmentExpression, JsAssignmentOperator,
+    JsIdentifierBinding, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Disallow reassigning exported variables after the module is initialized.
+    ///
+    /// The variables declared with `let` or `var` are exported as live bindings: the modules that
+    /// import them see every reassignment.
+    /// A variable that is reassigned by a function, or by a callback, changes under the feet of
+    /// the modules that import it, long after they have read it.
+    /// This behavior is confusing, and often unintended.
+    ///
+    /// This rule reports the reassignments of the exported `let` and `var` variables that run
+    /// after the initialization of the module: the reassignments inside functions, methods,
+    /// getters, setters and constructors.
+    /// The reassignments at the top level of the module, which run while the module is
+    /// initialized, are allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export let user = null;
+    ///
+    /// export function login(name) {
+    ///     user = { name };
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let ready = false;
+    /// setTimeout(() => {
+    ///     ready = true;
+    /// });
+    /// export { ready };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// let mode = "production";
+    /// if (process.env.DEBUG) {
+    ///     mode = "development";
+    /// }
+    /// export { mode };
+    /// ```
+    ///
+    /// ```js
+    /// let user = null;
+    ///
+    /// export function login(name) {
+    ///     user = { name };
+    /// }
+    ///
+    /// export function currentUser() {
+    ///     return user;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowAccumulators": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### allowAccumulators
+    ///
+    /// When `true`, the rule allows the compound assignments, such as `count += 1`, and the
+    /// increments and decrements, such as `count++`.
+    /// These assignments accumulate a value, such as a counter, instead of replacing it.
+    ///
+    /// Default: `false`
+    ///
+    pub UseImmutableExports {
+        version: "next",
+        name: "useImmutableExports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-mutable-exports")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+impl Rule for UseImmutableExports {
+    type Query = Semantic<JsIdentifierBinding>;
+    type State = TextRange;
+    type Signals = Box<[Self::State]>;
+    type Options = UseImmutableExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binding = ctx.query();
+        let model = ctx.model();
+        if !is_mutable_variable(binding) || !is_live_export(binding, model) {
+            return Box::default();
+        }
+        let allow_accumulators = ctx.options().allow_accumulators;
+        binding
+            .all_writes(model)
+            .filter(|write| {
+                let write = write.syntax();
+                is_deferred(write) && !(allow_accumulators && is_accumulation(write))
+            })
+            .map(|write| write.syntax().text_trimmed_range())
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        let binding = ctx.query();
+        let name = binding.name_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The exported variable "<Emphasis>{name.text_trimmed()}</Emphasis>" is reassigned after the module is initialized."
+                },
+            )
+            .detail(
+                binding.range(),
+                markup! {
+                    "The variable is declared here:"
+                },
+            )
+            .note(markup! {
+                "The modules that import the variable see the new value, at any time, which makes their behavior hard to follow."
+            })
+            .note(markup! {
+                "Export a function that returns the current value instead."
+            }),
+        )
+    }
+}
+
+/// Options for the rule `useImmutableExports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseImmutableExportsOptions {
+    /// Allow the compound assignments and the increments, such as `count += 1` or `count++`.
+    pub allow_accumulators: bool,
+}
+
+/// Returns `true` if `binding` is declared with `let` or `var`
+fn is_mutable_variable(binding: &JsIdentifierBinding) -> bool {
+    let declarator = match binding.declaration() {
+        Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => declarator,
+        Some(declaration) => match declaration.parent_binding_pattern_declaration() {
+            Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => declarator,
+            _ => return false,
+        },
+        None => return false,
+    };
+    declarator
+        .declaration()
+        .is_some_and(|declaration| declaration.is_let() || declaration.is_var())
+}
+
+/// Returns `true` if `binding` is exported as a live binding.
+///
+/// `export default binding` exports the current value of the variable, and isn't a live binding.
+fn is_live_export(binding: &JsIdentifierBinding, model: &SemanticModel) -> bool {
+    if !binding.is_exported(model) {
+        return false;
+    }
+    binding
+        .declaration()
+        .is_some_and(|declaration| declaration.export().is_some())
+        || binding.all_reads(model).any(|read| {
+            read.syntax().parent().is_some_and(|parent| {
+                matches!(
+                    parent.kind(),
+                    JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+                        | JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER
+                )
+            })
+        })
+}
+
+/// Returns `true` if `write` runs after the initialization of the module, because it's inside a
+/// function, a method, a getter, a setter or a constructor
+fn is_deferred(write: &JsSyntaxNode) -> bool {
+    write
+        .ancestors()
+        .filter_map(AnyJsControlFlowRoot::cast)
+        .any(|root| {
+            !matches!(
+                root,
+                AnyJsControlFlowRoot::JsModule(_)
+                    | AnyJsControlFlowRoot::JsScript(_)
+                    | AnyJsControlFlowRoot::JsStaticInitializationBlockClassMember(_)
+                    | AnyJsControlFlowRoot::TsModuleDeclaration(_)
+            )
+        })
+}
+
+/// Returns `true` if `write` is a compound assignment, such as `count += 1`, or an increment or
+/// a decrement, such as `count++`
+fn is_accumulation(write: &JsSyntaxNode) -> bool {
+    let Some(parent) = write.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION | JsSyntaxKind::JS_POST_UPDATE_EXPRESSION => true,
+        JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION => JsAssignmentExpression::unwrap_cast(parent)
+            .operator()
+            .is_ok_and(|operator| operator != JsAssignmentOperator::Assign),
+        _ => false,
+    }
+}
@@ -0,0 +1,351 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    inner_string_text, AnyJsImportClause, AnyJsModuleItem, AnyJsModuleSource,
+    AnyJsNamedImportSpecifier, JsImport, JsImportNamedClause, JsModuleItemList, JsSyntaxToken, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxElement, TriviaPieceKind};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Disallow importing whole heavy libraries when their members can be imported from their own modules.
+    ///
+    /// Some libraries bundle all their members in their entry point.
+    /// Importing a single member from the entry point may add the whole library to the bundle,
+    /// unless the bundler is able to tree-shake it.
+    /// These libraries also provide a module for each member, such as `lodash/map` for the
+    /// function `map` of `lodash`.
+    ///
+    /// This rule reports the static imports of the entry point of the following libraries:
+    ///
+    /// - `lodash`, whose members are the default exports of `lodash/<member>`;
+    /// - `date-fns`, whose members are exported by `date-fns/<member>`;
+    /// - `rxjs/operators`, whose members are exported by the legacy modules `rxjs/operators/<member>`.
+    ///
+    /// Other libraries can be added with the `libraries` option.
+    /// The type-only imports are ignored, because they are removed from the bundle.
+    ///
+    /// The fix replaces the named imports with an import for each member.
+    /// The default and namespace imports aren't fixed, because the imported members are unknown.
+    /// The fix is unsafe, because the module of a member may not exist in the installed version of
+    /// the library.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { debounce, throttle as limit } from "lodash";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { format } from "date-fns";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import * as _ from "lodash";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import debounce from "lodash/debounce";
+    /// import { format } from "date-fns/format";
+    /// ```
+    ///
+    /// ```ts
+    /// import type { DebouncedFunc } from "lodash";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "libraries": {
+    ///             "lodash-es": {
+    ///                 "importStyle": "default"
+    ///             },
+    ///             "@mui/icons-material": {
+    ///                 "path": "@mui/icons-material/{name}",
+    ///                 "importStyle": "default"
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### libraries
+    ///
+    /// The libraries to report, in addition to the default ones, mapped to the way their members
+    /// are imported.
+    /// A library of the option replaces the default library with the same name.
+    /// Each library accepts the following fields:
+    ///
+    /// - `path`: the module of a member, where `{name}` is replaced with the name of the member.
+    ///   It defaults to `<library>/{name}`.
+    /// - `importStyle`: how a member is imported from its module.
+    ///   `"named"` (default) imports the member by its name, such as `import { format } from "date-fns/format"`,
+    ///   and `"default"` imports the default export of the module, such as `import map from "lodash/map"`.
+    ///
+    pub UseMinimalBundleImports {
+        version: "next",
+        name: "useMinimalBundleImports",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useMinimalBundleImports`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseMinimalBundleImportsOptions {
+    /// The libraries to report, in addition to the default ones, mapped to the way their members
+    /// are imported.
+    #[serde(skip_serializing_if = "FxHashMap::is_empty")]
+    pub libraries: FxHashMap<Box<str>, SubpathImports>,
+}
+
+/// The way the members of a library are imported from their own modules.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct SubpathImports {
+    /// The module of a member, where `{name}` is replaced with the name of the member.
+    /// It defaults to `<library>/{name}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Box<str>>,
+    /// How a member is imported from its module.
+    pub import_style: SubpathImportStyle,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum SubpathImportStyle {
+    /// Import the member by its name, such as `import { format } from "date-fns/format"`.
+    #[default]
+    Named,
+    /// Import the default export of the module, such as `import map from "lodash/map"`.
+    Default,
+}
+
+/// The libraries that are reported when the option `libraries` doesn't override them.
+const DEFAULT_LIBRARIES: &[(&str, SubpathImportStyle)] = &[
+    ("date-fns", SubpathImportStyle::Named),
+    ("lodash", SubpathImportStyle::Default),
+    ("rxjs/operators", SubpathImportStyle::Named),
+];
+
+pub struct WholeLibraryImport {
+    /// The string literal of the module specifier
+    module_name_token: JsSyntaxToken,
+    /// The name of the library
+    library: Box<str>,
+    /// The module of a member, where `{name}` is replaced with the name of the member
+    path: Box<str>,
+    import_style: SubpathImportStyle,
+}
+
+impl WholeLibraryImport {
+    fn member_module(&self, member: &str) -> String {
+        self.path.replace("{name}", member)
+    }
+}
+
+impl Rule for UseMinimalBundleImports {
+    type Query = Ast<JsImport>;
+    type State = WholeLibraryImport;
+    type Signals = Option<Self::State>;
+    type Options = Box<UseMinimalBundleImportsOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let clause = node.import_clause().ok()?;
+        if matches!(clause, AnyJsImportClause::JsImportBareClause(_))
+            || clause.type_token().is_some()
+        {
+            return None;
+        }
+        if let AnyJsImportClause::JsImportNamedClause(clause) = &clause {
+            // The type-only specifiers are removed from the bundle
+            let specifiers = clause.named_specifiers().ok()?.specifiers();
+            if specifiers
+                .iter()
+                .flatten()
+                .all(|specifier| specifier.type_token().is_some())
+            {
+                return None;
+            }
+        }
+        let module_name_token = clause.source().ok()?.value_token().ok()?;
+        let library = inner_string_text(&module_name_token);
+        let library = library.text();
+        let (path, import_style) = match ctx.options().libraries.get(library) {
+            Some(subpath_imports) => (subpath_imports.path.clone(), subpath_imports.import_style),
+            None => {
+                let (_, import_style) = DEFAULT_LIBRARIES
+                    .iter()
+                    .find(|(name, _)| *name == library)?;
+                (None, *import_style)
+            }
+        };
+        Some(WholeLibraryImport {
+            path: path.unwrap_or_else(|| format!("{library}/{{name}}").into()),
+            library: library.into(),
+            import_style,
+            module_name_token,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let library = &*state.library;
+        let member_module = state.member_module("<member>");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.module_name_token.text_trimmed_range(),
+                markup! {
+                    "The whole library "<Emphasis>{library}</Emphasis>" is imported."
+                },
+            )
+            .note(markup! {
+                "The entry point of the library may add all its members to the bundle, even the unused ones."
+            })
+            .note(markup! {
+                "Import each member from its own module "<Emphasis>{member_module}</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let AnyJsImportClause::JsImportNamedClause(clause) = node.import_clause().ok()? else {
+            return None;
+        };
+        let semicolon_token = node.semicolon_token().is_some().then(|| make::token(T![;]));
+        let mut new_imports = Vec::new();
+        for specifier in clause.named_specifiers().ok()?.specifiers() {
+            let new_clause = to_member_clause(&clause, &specifier.ok()?, state, ctx)?;
+            let new_import = if new_imports.is_empty() {
+                // The first import keeps the comments and the trailing trivia of the import
+                node.clone().with_import_clause(new_clause)
+            } else {
+                make::js_import(
+                    make::token(T![import])
+                        .with_leading_trivia([(TriviaPieceKind::Newline, "\n")])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    new_clause,
+                )
+                .build()
+                .with_semicolon_token(semicolon_token.clone())
+            };
+            new_imports.push(AnyJsModuleItem::from(new_import));
+        }
+        if new_imports.is_empty() {
+            return None;
+        }
+        // Move the trailing trivia of the import, such as a comment, after the last import
+        let trailing_trivia = node.syntax().last_trailing_trivia()?;
+        let first = new_imports.first_mut()?;
+        *first = first.clone().with_trailing_trivia_pieces([])?;
+        let last = new_imports.last_mut()?;
+        *last = last
+            .clone()
+            .append_trivia_pieces(trailing_trivia.pieces())?;
+
+        let module_item_list = node.parent::<JsModuleItemList>()?.into_syntax();
+        let slot = module_item_list
+            .slots()
+            .position(|slot| slot.into_node().as_ref() == Some(node.syntax()))?;
+        let new_module_item_list = module_item_list.clone().splice_slots(
+            slot..(slot + 1),
+            new_imports
+                .into_iter()
+                .map(|item| Some(SyntaxElement::Node(item.into_syntax()))),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(module_item_list.into(), new_module_item_list.into());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Import each member from its own module." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Converts the specifier `member as name` of `import { member as name } from "library"` to
+/// `import { member as name } from "library/member"` or `import name from "library/member"`.
+fn to_member_clause(
+    clause: &JsImportNamedClause,
+    specifier: &AnyJsNamedImportSpecifier,
+    state: &WholeLibraryImport,
+    ctx: &RuleContext<UseMinimalBundleImports>,
+) -> Option<AnyJsImportClause> {
+    // A type-only specifier can't be moved to the module of the member
+    if specifier.type_token().is_some() {
+        return None;
+    }
+    let member = match specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            inner_string_text(&specifier.name().ok()?.value().ok()?)
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+            specifier.imported_name()?.token_text_trimmed()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+    };
+    if member.text() == "default" {
+        return None;
+    }
+    let member_module = state.member_module(member.text());
+    let source = AnyJsModuleSource::from(make::js_module_source(
+        if ctx.as_preferred_quote().is_double() {
+            make::js_string_literal(&member_module)
+        } else {
+            make::js_string_literal_single_quotes(&member_module)
+        },
+    ));
+    let new_clause = match state.import_style {
+        SubpathImportStyle::Named => {
+            let named_specifiers = make::js_named_import_specifiers(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_named_import_specifier_list([specifier.clone().trim_trivia()?], []),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            );
+            let mut builder = make::js_import_named_clause(
+                named_specifiers,
+                make::token_decorated_with_space(T![from]),
+                source,
+            );
+            if let Some(assertion) = clause.assertion() {
+                builder = builder.with_assertion(assertion);
+            }
+            AnyJsImportClause::from(builder.build())
+        }
+        SubpathImportStyle::Default => {
+            let local_name = specifier.local_name()?.trim_trivia()?;
+            let mut builder = make::js_import_default_clause(
+                make::js_default_import_specifier(local_name),
+                make::token_decorated_with_space(T![from]),
+                source,
+            );
+            if let Some(assertion) = clause.assertion() {
+                builder = builder.with_assertion(assertion);
+            }
+            AnyJsImportClause::from(builder.build())
+        }
+    };
+    Some(new_clause)
+}
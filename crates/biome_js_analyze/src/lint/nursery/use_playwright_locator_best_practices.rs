@@ -0,0 +1,162 @@
+use crate::playwright::{is_async_assertion, is_playwright_module, is_promise_handled};
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExpression, JsCallExpression};
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforce the best practices of Playwright for locating elements and waiting for them.
+    ///
+    /// [Playwright](https://playwright.dev) recommends relying on locators and on auto-retrying
+    /// assertions, which wait until the page is ready. This rule reports:
+    ///
+    /// - the calls to `page.$()` and `page.$$()`, which return element handles.
+    ///   An element handle points to a single element of the DOM, and becomes stale when the page
+    ///   re-renders. Use `page.locator()` instead.
+    /// - the auto-retrying assertions that aren't awaited, such as `expect(locator).toBeVisible()`.
+    ///   These assertions return a promise: the test doesn't wait for them, and doesn't fail when
+    ///   they aren't met.
+    /// - the calls to `page.waitForTimeout()`, which make the tests slow and flaky.
+    ///   Wait for a condition instead, for example with an auto-retrying assertion.
+    ///
+    /// The rule only checks the files that import `@playwright/test`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { test } from "@playwright/test";
+    ///
+    /// test("submits the form", async ({ page }) => {
+    ///     const button = await page.$("button");
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { expect, test } from "@playwright/test";
+    ///
+    /// test("shows the dialog", async ({ page }) => {
+    ///     expect(page.getByRole("dialog")).toBeVisible();
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { test } from "@playwright/test";
+    ///
+    /// test("loads the page", async ({ page }) => {
+    ///     await page.waitForTimeout(1000);
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { expect, test } from "@playwright/test";
+    ///
+    /// test("shows the dialog", async ({ page }) => {
+    ///     await page.getByRole("button").click();
+    ///     await expect(page.getByRole("dialog")).toBeVisible();
+    /// });
+    /// ```
+    ///
+    pub UsePlaywrightLocatorBestPractices {
+        version: "next",
+        name: "usePlaywrightLocatorBestPractices",
+        language: "js",
+        sources: &[
+            RuleSource::EslintPlaywright("no-element-handle"),
+            RuleSource::EslintPlaywright("missing-playwright-await"),
+            RuleSource::EslintPlaywright("no-wait-for-timeout"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub enum RuleState {
+    /// A call to `page.$()` or `page.$$()`
+    ElementHandle { range: TextRange, method: String },
+    /// An auto-retrying assertion that isn't awaited
+    MissingAwait(TextRange),
+    /// A call to `page.waitForTimeout()`
+    WaitForTimeout(TextRange),
+}
+
+impl Rule for UsePlaywrightLocatorBestPractices {
+    type Query = Semantic<JsCallExpression>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if is_async_assertion(node, ctx.model()) {
+            return (!is_promise_handled(node)).then(|| RuleState::MissingAwait(node.range()));
+        }
+
+        let AnyJsExpression::JsStaticMemberExpression(callee) =
+            node.callee().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        let method = callee.member().ok()?.as_js_name()?.value_token().ok()?;
+        let state = match method.text_trimmed() {
+            "$" | "$$" => RuleState::ElementHandle {
+                range: callee.range(),
+                method: method.text_trimmed().to_string(),
+            },
+            "waitForTimeout" => RuleState::WaitForTimeout(callee.range()),
+            _ => return None,
+        };
+        is_playwright_module(&ctx.root()).then_some(state)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            RuleState::ElementHandle { range, method } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid "<Emphasis>{method}"()"</Emphasis>", which returns an element handle."
+                },
+            )
+            .note(markup! {
+                "An element handle points to a single element of the DOM, and becomes stale when the page re-renders. A locator finds the element each time it's used, and waits until the element is ready."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"locator()"</Emphasis>" instead."
+            }),
+            RuleState::MissingAwait(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This assertion isn't awaited."
+                },
+            )
+            .note(markup! {
+                "The assertion retries until it's met, and returns a promise. The test doesn't wait for the assertion when it isn't awaited, and doesn't fail when the assertion isn't met."
+            })
+            .note(markup! {
+                "Add "<Emphasis>"await"</Emphasis>" before the assertion."
+            }),
+            RuleState::WaitForTimeout(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid waiting for a fixed amount of time."
+                },
+            )
+            .note(markup! {
+                "Fixed timeouts make the tests slow when they're too long, and flaky when they're too short."
+            })
+            .note(markup! {
+                "Wait for a condition instead, for example with "<Emphasis>"await expect(locator).toBeVisible()"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
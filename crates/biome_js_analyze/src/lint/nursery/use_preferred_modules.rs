@@ -0,0 +1,272 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    inner_string_text, AnyJsImportClause, AnyJsImportLike, AnyJsModuleSource,
+    JsImportDefaultClause, JsImportNamespaceClause, JsModuleSource, JsSyntaxToken, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of the preferred modules over their alternatives.
+    ///
+    /// Projects often standardize on one module among several equivalent ones: `lodash-es` rather
+    /// than `lodash`, `node:fs/promises` rather than `fs`, or an in-house wrapper rather than the
+    /// library that it wraps.
+    /// This rule reports the imports, the re-exports, the calls to `require()` and the dynamic
+    /// imports of the modules listed in the `aliases` option.
+    ///
+    /// The fix replaces the module with its preferred alternative.
+    /// When the preferred module doesn't have a default export, the default import is also
+    /// replaced with a namespace import, according to the `defaultImport` option.
+    /// The default imports that are combined with other imports, such as
+    /// `import _, { map } from "lodash"`, aren't fixed in this case.
+    /// The fix is unsafe, because the preferred module may not have exactly the same API.
+    ///
+    /// Only the module specifiers that exactly match an alias are reported: an alias of `lodash`
+    /// doesn't apply to `lodash/map`.
+    ///
+    /// ## Examples
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "aliases": {
+    ///             "lodash": {
+    ///                 "replacement": "lodash-es",
+    ///                 "defaultImport": "namespace"
+    ///             },
+    ///             "fs": {
+    ///                 "replacement": "node:fs/promises"
+    ///             },
+    ///             "moment": {
+    ///                 "message": "moment is in maintenance mode."
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// With the configuration above, the following imports are reported:
+    ///
+    /// ```js
+    /// import _ from "lodash";
+    /// import { readFile } from "fs";
+    /// const moment = require("moment");
+    /// ```
+    ///
+    /// They are fixed to:
+    ///
+    /// ```js
+    /// import * as _ from "lodash-es";
+    /// import { readFile } from "node:fs/promises";
+    /// const moment = require("moment");
+    /// ```
+    ///
+    /// `moment` doesn't have any replacement: it's reported, but not fixed.
+    ///
+    /// ## Options
+    ///
+    /// ### aliases
+    ///
+    /// The modules to avoid, mapped to their preference.
+    /// Each preference accepts the following fields:
+    ///
+    /// - `replacement`: the module to use instead.
+    ///   When it's absent, the module is reported without any fix.
+    /// - `defaultImport`: how the default import of the module is imported from the
+    ///   replacement.
+    ///   `"default"` (default) keeps the default import, and `"namespace"` replaces it with a
+    ///   namespace import.
+    /// - `message`: an explanation displayed in the diagnostic.
+    ///
+    pub UsePreferredModules {
+        version: "next",
+        name: "usePreferredModules",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `usePreferredModules`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UsePreferredModulesOptions {
+    /// The modules to avoid, mapped to their preference.
+    #[serde(skip_serializing_if = "FxHashMap::is_empty")]
+    pub aliases: FxHashMap<Box<str>, ModulePreference>,
+}
+
+/// The preference for a module that should be avoided.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ModulePreference {
+    /// The module to use instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<Box<str>>,
+    /// How the default import of the module is imported from the replacement.
+    pub default_import: DefaultImportStyle,
+    /// An explanation displayed in the diagnostic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Box<str>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum DefaultImportStyle {
+    /// Keep the default import, such as `import _ from "lodash-es"`.
+    #[default]
+    Default,
+    /// Replace the default import with a namespace import, such as
+    /// `import * as _ from "lodash-es"`.
+    Namespace,
+}
+
+pub struct AvoidedModule {
+    /// The string literal of the module specifier
+    module_name_token: JsSyntaxToken,
+    /// The specifier of the module, without the quotes
+    module_name: Box<str>,
+}
+
+impl Rule for UsePreferredModules {
+    type Query = Ast<AnyJsImportLike>;
+    type State = AvoidedModule;
+    type Signals = Option<Self::State>;
+    type Options = Box<UsePreferredModulesOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_in_ts_module_declaration() {
+            return None;
+        }
+        let module_name_token = node.module_name_token()?;
+        let module_name = inner_string_text(&module_name_token);
+        if !ctx.options().aliases.contains_key(module_name.text()) {
+            return None;
+        }
+        Some(AvoidedModule {
+            module_name: module_name.text().into(),
+            module_name_token,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let preference = ctx.options().aliases.get(&state.module_name)?;
+        let module_name = &*state.module_name;
+        let range = state.module_name_token.text_trimmed_range();
+        let mut diagnostic = match preference.replacement.as_deref() {
+            Some(replacement) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Use the module "<Emphasis>{replacement}</Emphasis>" instead of "<Emphasis>{module_name}</Emphasis>"."
+                },
+            ),
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The module "<Emphasis>{module_name}</Emphasis>" should be avoided."
+                },
+            ),
+        };
+        if let Some(message) = preference.message.as_deref() {
+            diagnostic = diagnostic.note(markup! { {message} });
+        }
+        Some(diagnostic.note(markup! {
+            "The preferred modules are configured in the "<Emphasis>"aliases"</Emphasis>" option of the rule."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let preference = ctx.options().aliases.get(&state.module_name)?;
+        let replacement = preference.replacement.as_deref()?;
+        let new_module_name = if ctx.as_preferred_quote().is_double() {
+            make::js_string_literal(replacement)
+        } else {
+            make::js_string_literal_single_quotes(replacement)
+        };
+        let mut mutation = ctx.root().begin();
+        let clause = state
+            .module_name_token
+            .parent()
+            .and_then(JsModuleSource::cast)
+            .and_then(|source| source.parent::<AnyJsImportClause>());
+        match (preference.default_import, clause) {
+            (
+                DefaultImportStyle::Namespace,
+                Some(AnyJsImportClause::JsImportDefaultClause(default_clause)),
+            ) => {
+                let token = &state.module_name_token;
+                let new_module_name = new_module_name
+                    .with_leading_trivia_pieces(token.leading_trivia().pieces())
+                    .with_trailing_trivia_pieces(token.trailing_trivia().pieces());
+                let namespace_clause = to_namespace_clause(&default_clause, new_module_name)?;
+                mutation.replace_node(
+                    AnyJsImportClause::from(default_clause),
+                    AnyJsImportClause::from(namespace_clause),
+                );
+            }
+            // A namespace import can't be combined with named imports
+            (DefaultImportStyle::Namespace, Some(AnyJsImportClause::JsImportCombinedClause(_))) => {
+                return None
+            }
+            _ => {
+                mutation.replace_token_transfer_trivia(
+                    state.module_name_token.clone(),
+                    new_module_name,
+                );
+            }
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Use "<Emphasis>{replacement}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Converts `import name from "module"` to `import * as name from "replacement"`
+fn to_namespace_clause(
+    clause: &JsImportDefaultClause,
+    new_module_name: JsSyntaxToken,
+) -> Option<JsImportNamespaceClause> {
+    let local_name = clause.default_specifier().ok()?.local_name().ok()?;
+    let source = clause
+        .source()
+        .ok()?
+        .as_js_module_source()?
+        .clone()
+        .with_value_token(new_module_name);
+    let specifier = make::js_namespace_import_specifier(
+        make::token(T![*]),
+        make::token_decorated_with_space(T![as]),
+        local_name,
+    );
+    let mut builder = make::js_import_namespace_clause(
+        specifier,
+        clause.from_token().ok()?,
+        AnyJsModuleSource::JsModuleSource(source),
+    );
+    if let Some(type_token) = clause.type_token() {
+        builder = builder.with_type_token(type_token);
+    }
+    if let Some(assertion) = clause.assertion() {
+        builder = builder.with_assertion(assertion);
+    }
+    Some(builder.build())
+}
@@ -0,0 +1,185 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{
+    static_value::StaticValue, AnyJsCallArgument, AnyJsExpression, JsBinaryExpression,
+    JsBinaryOperator, JsCallExpression, JsUnaryOperator,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+
+use crate::globals::signatures::callee_signature;
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Disallow comparing and computing integers that exceed `Number.MAX_SAFE_INTEGER`.
+    ///
+    /// A number represents the integers exactly up to `Number.MAX_SAFE_INTEGER`, which is
+    /// `2^53 - 1`.
+    /// The larger integers are rounded to the closest number that can be represented, so
+    /// `9007199254740993 === 9007199254740992` is `true`, and `9007199254740992 + 1` is
+    /// `9007199254740992`.
+    /// The comparisons and the arithmetic on these integers silently return wrong results, which
+    /// often happens with the 64-bit identifiers returned by the databases and the APIs.
+    ///
+    /// The rule reports the integer literals larger than `Number.MAX_SAFE_INTEGER` that are
+    /// compared with `==`, `===`, `!=` or `!==`, or that are operands of an arithmetic operator.
+    /// It also reports the calls of `parseInt()` and `Number.parseInt()` on strings of digits that
+    /// exceed `Number.MAX_SAFE_INTEGER`.
+    /// Use a `BigInt` to represent these integers instead, such as `9007199254740993n` or
+    /// `BigInt("9007199254740993")`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// if (id === 9007199254740993) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const next = 18014398509481984 + 1;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const id = parseInt("12345678901234567890");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (id === 9007199254740993n) {}
+    /// ```
+    ///
+    /// ```js
+    /// const id = BigInt("12345678901234567890");
+    /// ```
+    ///
+    /// ```js
+    /// const max = Number.MAX_SAFE_INTEGER - 1;
+    /// ```
+    ///
+    pub UseSafeIntegerChecks {
+        version: "next",
+        name: "useSafeIntegerChecks",
+        language: "js",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyIntegerOperation = JsBinaryExpression | JsCallExpression
+}
+
+pub enum UnsafeInteger {
+    /// An integer literal that is compared or computed
+    Literal(TextRange),
+    /// A string of digits that is parsed into a number
+    ParsedString(TextRange),
+}
+
+impl Rule for UseSafeIntegerChecks {
+    type Query = Semantic<AnyIntegerOperation>;
+    type State = UnsafeInteger;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyIntegerOperation::JsBinaryExpression(binary) => {
+                let is_checked = matches!(
+                    binary.operator().ok()?,
+                    JsBinaryOperator::Equality
+                        | JsBinaryOperator::StrictEquality
+                        | JsBinaryOperator::Inequality
+                        | JsBinaryOperator::StrictInequality
+                        | JsBinaryOperator::Plus
+                        | JsBinaryOperator::Minus
+                        | JsBinaryOperator::Times
+                        | JsBinaryOperator::Divide
+                        | JsBinaryOperator::Remainder
+                        | JsBinaryOperator::Exponent
+                );
+                if !is_checked {
+                    return None;
+                }
+                [binary.left().ok()?, binary.right().ok()?]
+                    .into_iter()
+                    .find_map(unsafe_integer_literal)
+                    .map(UnsafeInteger::Literal)
+            }
+            AnyIntegerOperation::JsCallExpression(call) => {
+                let signature = callee_signature(&call.callee().ok()?, ctx.model())?;
+                if signature.name() != "parseInt" {
+                    return None;
+                }
+                let arguments = call.arguments().ok()?.args();
+                let mut arguments = arguments.iter();
+                let AnyJsCallArgument::AnyJsExpression(string) = arguments.next()?.ok()? else {
+                    return None;
+                };
+                // The digits of the other radixes aren't decimal
+                if let Some(radix) = arguments.next() {
+                    let AnyJsCallArgument::AnyJsExpression(radix) = radix.ok()? else {
+                        return None;
+                    };
+                    if radix.omit_parentheses().as_static_value()?.text() != "10" {
+                        return None;
+                    }
+                }
+                string
+                    .omit_parentheses()
+                    .as_static_value()
+                    .filter(|value| value.is_unsafe_integer())
+                    .map(|value| UnsafeInteger::ParsedString(value.range()))
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            UnsafeInteger::Literal(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This integer exceeds "<Emphasis>"Number.MAX_SAFE_INTEGER"</Emphasis>", so the result of this operation may be wrong."
+                },
+            )
+            .note(markup! {
+                "The integers larger than "<Emphasis>"2^53 - 1"</Emphasis>" are rounded to the closest number that can be represented."
+            })
+            .note(markup! {
+                "Use a "<Emphasis>"BigInt"</Emphasis>" literal instead, by adding the "<Emphasis>"n"</Emphasis>" suffix to the integer."
+            }),
+            UnsafeInteger::ParsedString(range) => RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This string is parsed into an integer that exceeds "<Emphasis>"Number.MAX_SAFE_INTEGER"</Emphasis>", so its last digits are lost."
+                },
+            )
+            .note(markup! {
+                "The integers larger than "<Emphasis>"2^53 - 1"</Emphasis>" are rounded to the closest number that can be represented."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"BigInt()"</Emphasis>" to parse the string instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the range of `expression` if it's an integer literal that exceeds
+/// `Number.MAX_SAFE_INTEGER`, such as `9007199254740993` or `-9007199254740993`
+fn unsafe_integer_literal(expression: AnyJsExpression) -> Option<TextRange> {
+    let mut expression = expression.omit_parentheses();
+    if let AnyJsExpression::JsUnaryExpression(unary) = &expression {
+        if matches!(
+            unary.operator().ok()?,
+            JsUnaryOperator::Minus | JsUnaryOperator::Plus
+        ) {
+            expression = unary.argument().ok()?.omit_parentheses();
+        }
+    }
+    let value = expression.as_static_value()?;
+    (matches!(value, StaticValue::Number(_)) && value.is_unsafe_integer())
+        .then(|| expression.range())
+}
@@ -0,0 +1,267 @@
+use std::iter;
+
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsClass, AnyJsClassMember, AnyJsExpression,
+    AnyJsStatement, JsAssignmentOperator, JsExport, JsExpressionStatement, JsReferenceIdentifier,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+
+use crate::JsRuleAction;
+
+declare_lint_rule! {
+    /// Enforce the use of static initialization blocks over static assignments after a class declaration.
+    ///
+    /// Assigning the static properties of a class right after its declaration
+    /// splits the initialization of the class in two places.
+    /// Since ES2022, a static initialization block runs the same code
+    /// when the class is defined, inside the body of the class.
+    ///
+    /// This rule reports the assignments to the members of a class that immediately follow its declaration.
+    ///
+    /// The fix moves the assignments into a static initialization block at the end of the class.
+    /// It isn't provided when the class has decorators,
+    /// or when the assigned values use `this`, `super`, `arguments`, `await` or `yield`,
+    /// because they don't have the same meaning in a static initialization block.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// class Config {}
+    /// Config.defaults = { debug: false };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Cache {
+    ///     static instances = new Map();
+    /// }
+    /// Cache.maxSize = 100;
+    /// Cache.clear = () => Cache.instances.clear();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// class Config {
+    ///     static {
+    ///         Config.defaults = { debug: false };
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// class Config {}
+    /// register(Config);
+    /// Config.defaults = { debug: false };
+    /// ```
+    ///
+    pub UseStaticClassBlocks {
+        version: "next",
+        name: "useStaticClassBlocks",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseStaticClassBlocks {
+    type Query = Ast<AnyJsClass>;
+    type State = Vec<JsExpressionStatement>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let class = ctx.query();
+        let item = class_item(class)?;
+        let name = class.id()?.as_js_identifier_binding()?.name_token().ok()?;
+        let assignments: Vec<_> = iter::successors(item.next_sibling(), JsSyntaxNode::next_sibling)
+            .map_while(JsExpressionStatement::cast)
+            .take_while(|statement| is_member_assignment(statement, name.text_trimmed()))
+            .collect();
+        (!assignments.is_empty()).then_some(assignments)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, assignments: &Self::State) -> Option<RuleDiagnostic> {
+        let class = ctx.query();
+        let id = class.id()?;
+        let name = id.as_js_identifier_binding()?.name_token().ok()?;
+        let range = assignments
+            .first()?
+            .range()
+            .cover(assignments.last()?.range());
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The members of "<Emphasis>{name.text_trimmed()}</Emphasis>" are assigned after its declaration."
+                },
+            )
+            .detail(id.range(), markup! { "The class is declared here." })
+            .note(markup! {
+                "Assign them in a "<Emphasis>"static"</Emphasis>" initialization block, so that the class is initialized in a single place."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, assignments: &Self::State) -> Option<JsRuleAction> {
+        let class = ctx.query();
+        // Decorators can replace the class after its static initialization blocks run
+        if !class.decorators().is_empty() || !assignments.iter().all(can_be_moved) {
+            return None;
+        }
+        let item = class_item(class)?;
+        let members = class.members();
+        let r_curly_token = class.r_curly_token().ok()?;
+
+        let class_indentation = item
+            .first_token()
+            .as_ref()
+            .and_then(line_indentation)
+            .unwrap_or_default();
+        let member_indentation = members
+            .iter()
+            .find_map(|member| {
+                member
+                    .syntax()
+                    .first_token()
+                    .as_ref()
+                    .and_then(line_indentation)
+            })
+            .unwrap_or_else(|| format!("{class_indentation}\t"));
+        let indent_unit = member_indentation
+            .strip_prefix(class_indentation.as_str())
+            .filter(|indent_unit| !indent_unit.is_empty())
+            .unwrap_or("\t");
+        let statement_indentation = format!("{member_indentation}{indent_unit}");
+
+        let statements = assignments
+            .iter()
+            .map(|assignment| {
+                let statement = assignment.syntax().clone();
+                let first_token = statement.first_token()?;
+                let new_first_token =
+                    first_token.with_leading_trivia(indentation_trivia(&statement_indentation));
+                AnyJsStatement::cast(
+                    statement.replace_child(first_token.into(), new_first_token.into())?,
+                )
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let static_block = make::js_static_initialization_block_class_member(
+            make::token(T![static])
+                .with_leading_trivia(indentation_trivia(&member_indentation))
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::token(T!['{']),
+            make::js_statement_list(statements),
+            make::token(T!['}']).with_leading_trivia(indentation_trivia(&member_indentation)),
+        );
+        let new_members = make::js_class_member_list(
+            members
+                .iter()
+                .chain([AnyJsClassMember::from(static_block)])
+                .collect::<Vec<_>>(),
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(members, new_members);
+        // The closing brace of the class must be on its own line, after the block
+        if line_indentation(&r_curly_token).is_none() {
+            let new_r_curly_token =
+                r_curly_token.with_leading_trivia(indentation_trivia(&class_indentation));
+            mutation.replace_token_discard_trivia(r_curly_token, new_r_curly_token);
+        }
+        for assignment in assignments {
+            mutation.remove_node(assignment.clone());
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Move the assignments into a "<Emphasis>"static"</Emphasis>" initialization block." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the statement or the module item that declares `class`.
+///
+/// Class expressions and ambient classes aren't declared by a statement.
+fn class_item(class: &AnyJsClass) -> Option<JsSyntaxNode> {
+    if matches!(class, AnyJsClass::JsClassExpression(_)) {
+        return None;
+    }
+    let item = class.syntax().ancestors().find(|node| {
+        node.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+            )
+        })
+    })?;
+    (&item == class.syntax() || JsExport::can_cast(item.kind())).then_some(item)
+}
+
+/// Returns `true` if `statement` is an assignment such as `Foo.bar = value` or `Foo["bar"] = value`.
+fn is_member_assignment(statement: &JsExpressionStatement, class_name: &str) -> bool {
+    let Ok(AnyJsExpression::JsAssignmentExpression(assignment)) = statement.expression() else {
+        return false;
+    };
+    if !matches!(assignment.operator(), Ok(JsAssignmentOperator::Assign)) {
+        return false;
+    }
+    let object = match assignment.left() {
+        Ok(AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsStaticMemberAssignment(
+            member,
+        ))) => member.object(),
+        Ok(AnyJsAssignmentPattern::AnyJsAssignment(
+            AnyJsAssignment::JsComputedMemberAssignment(member),
+        )) => member.object(),
+        _ => return false,
+    };
+    matches!(
+        object,
+        Ok(AnyJsExpression::JsIdentifierExpression(object))
+            if object.name().is_ok_and(|name| name.has_name(class_name))
+    )
+}
+
+/// Returns `true` if `statement` keeps its meaning in a static initialization block.
+fn can_be_moved(statement: &JsExpressionStatement) -> bool {
+    !statement.syntax().has_leading_comments()
+        && !statement.syntax().descendants().any(|node| {
+            matches!(
+                node.kind(),
+                JsSyntaxKind::JS_THIS_EXPRESSION
+                    | JsSyntaxKind::JS_SUPER_EXPRESSION
+                    | JsSyntaxKind::JS_AWAIT_EXPRESSION
+                    | JsSyntaxKind::JS_YIELD_EXPRESSION
+            ) || JsReferenceIdentifier::cast(node).is_some_and(|name| name.has_name("arguments"))
+        })
+}
+
+/// Returns the indentation of `token`, if it starts a line.
+fn line_indentation(token: &JsSyntaxToken) -> Option<String> {
+    let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+    let newline = pieces.iter().rposition(|piece| piece.is_newline())?;
+    Some(
+        pieces[newline + 1..]
+            .iter()
+            .filter(|piece| piece.is_whitespace())
+            .map(|piece| piece.text())
+            .collect(),
+    )
+}
+
+fn indentation_trivia(indentation: &str) -> [(TriviaPieceKind, &str); 2] {
+    [
+        (TriviaPieceKind::Newline, "\n"),
+        (TriviaPieceKind::Whitespace, indentation),
+    ]
+}
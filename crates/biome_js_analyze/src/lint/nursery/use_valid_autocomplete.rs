@@ -1,5 +1,6 @@
 use crate::services::aria::{Aria, AttributeValue};
 use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_aria::autofill::is_valid_autofill;
 use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::{JsxOpeningElement, JsxSelfClosingElement};
@@ -13,6 +14,18 @@ declare_lint_rule! {
     /// This allows for more detailed purpose definitions compared to the `type` attribute.
     /// Using these predefined values, user agents and assistive technologies can present input purposes to users in different ways.
     ///
+    /// The value of the attribute is either `on` or `off`, or the following tokens, in this order:
+    ///
+    /// 1. optionally, a section name that starts with `section-`, such as `section-login`;
+    /// 2. optionally, `shipping` or `billing`;
+    /// 3. a field name such as `street-address`, or a contact field name such as `email`,
+    ///    optionally preceded by a contact type such as `home` or `work`;
+    /// 4. optionally, `webauthn`.
+    ///
+    /// The rule checks the `input`, `select` and `textarea` elements, and the components listed
+    /// in the `inputComponents` option.
+    /// The `autocomplete` attribute of the `form` elements only accepts `on` and `off`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -21,11 +34,22 @@ declare_lint_rule! {
     /// <input type="text" autocomplete="incorrect" />
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// <input type="text" autocomplete="home url" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <form autocomplete="name"></form>
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```jsx
     /// <>
     ///   <input type="text" autocomplete="name" />
+    ///   <input type="text" autocomplete="section-blue shipping street-address" />
+    ///   <input type="tel" autocomplete="work tel" />
+    ///   <input type="password" autocomplete="current-password webauthn" />
     ///   <MyInput autocomplete="incorrect" />
     /// </>
     /// ```
@@ -41,6 +65,11 @@ declare_lint_rule! {
     /// }
     /// ```
     ///
+    /// ### inputComponents
+    ///
+    /// The custom components that render an `input` element, and whose `autocomplete` property
+    /// is checked like the `autocomplete` attribute of `input`.
+    ///
     /// ## Accessibility guidelines
     /// - [WCAG 1.3.5](https://www.w3.org/WAI/WCAG21/Understanding/identify-input-purpose)
     ///
@@ -61,80 +90,6 @@ declare_node_union! {
     pub UseValidAutocompleteQuery = JsxSelfClosingElement | JsxOpeningElement
 }
 
-// Sorted for binary search
-const VALID_AUTOCOMPLETE_VALUES: [&str; 55] = [
-    "additional-name",
-    "address-level1",
-    "address-level2",
-    "address-level3",
-    "address-level4",
-    "address-line1",
-    "address-line2",
-    "address-line3",
-    "bday",
-    "bday-day",
-    "bday-month",
-    "bday-year",
-    "cc-additional-name",
-    "cc-csc",
-    "cc-exp",
-    "cc-exp-month",
-    "cc-exp-year",
-    "cc-family-name",
-    "cc-given-name",
-    "cc-name",
-    "cc-number",
-    "cc-type",
-    "country",
-    "country-name",
-    "current-password",
-    "email",
-    "family-name",
-    "given-name",
-    "honorific-prefix",
-    "honorific-suffix",
-    "impp",
-    "language",
-    "name",
-    "new-password",
-    "nickname",
-    "off",
-    "on",
-    "one-time-code",
-    "organization",
-    "organization-title",
-    "photo",
-    "postal-code",
-    "sex",
-    "street-address",
-    "tel",
-    "tel-area-code",
-    "tel-country-code",
-    "tel-extension",
-    "tel-local",
-    "tel-national",
-    "transaction-amount",
-    "transaction-currency",
-    "url",
-    "username",
-    "webauthn",
-];
-
-// Sorted for binary search
-const BILLING_AND_SHIPPING_ADDRESS: &[&str; 11] = &[
-    "address-level1",
-    "address-level2",
-    "address-level3",
-    "address-level4",
-    "address-line1",
-    "address-line2",
-    "address-line3",
-    "country",
-    "country-name",
-    "postal-code",
-    "street-address",
-];
-
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields, default)]
@@ -150,68 +105,50 @@ impl Rule for UseValidAutocomplete {
     type Options = Box<UseValidAutocompleteOptions>;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
-        let options = ctx.options();
-        let input_components = &options.input_components;
-        match ctx.query() {
+        let (elem_name, attributes) = match ctx.query() {
             UseValidAutocompleteQuery::JsxOpeningElement(elem) => {
-                let elem_name = elem.name().ok()?.name_value_token()?;
-                let elem_name = elem_name.text_trimmed();
-                if !(elem_name == "input"
-                    || input_components.iter().any(|x| x.as_ref() == elem_name))
-                {
-                    return None;
-                }
-                let attributes = elem.attributes();
-                let autocomplete = attributes.find_by_name("autocomplete").ok()??;
-                let _initializer = autocomplete.initializer()?;
-                let extract_attrs = ctx.extract_attributes(&attributes)?;
-                let autocomplete_values = extract_attrs.get("autocomplete")?;
-                if autocomplete_values
-                    .first()
-                    .map_or(false, |v| matches!(v, AttributeValue::DynamicValue(_)))
-                {
-                    return None;
-                }
-
-                let autocomplete_values = ctx.convert_attribute_values(autocomplete_values.clone());
-
-                if autocomplete_values.first() == Some(&"none".to_string())
-                    || is_valid_autocomplete(&autocomplete_values)?
-                {
-                    return None;
-                }
-                Some(autocomplete.range())
+                (elem.name().ok()?.name_value_token()?, elem.attributes())
             }
             UseValidAutocompleteQuery::JsxSelfClosingElement(elem) => {
-                let elem_name = elem.name().ok()?.name_value_token()?;
-                let elem_name = elem_name.text_trimmed();
-                if !(elem_name == "input"
-                    || input_components.iter().any(|x| x.as_ref() == elem_name))
-                {
-                    return None;
-                }
-                let attributes = elem.attributes();
-                let autocomplete = attributes.find_by_name("autocomplete").ok()??;
-                let _initializer = autocomplete.initializer()?;
-                let extract_attrs = ctx.extract_attributes(&attributes)?;
-                let autocomplete_values = extract_attrs.get("autocomplete")?;
-                if autocomplete_values
-                    .first()
-                    .map_or(false, |v| matches!(v, AttributeValue::DynamicValue(_)))
-                {
-                    return None;
-                }
+                (elem.name().ok()?.name_value_token()?, elem.attributes())
+            }
+        };
+        let elem_name = elem_name.text_trimmed();
+        let is_form = elem_name == "form";
+        let input_components = &ctx.options().input_components;
+        if !(is_form
+            || matches!(elem_name, "input" | "select" | "textarea")
+            || input_components.iter().any(|x| x.as_ref() == elem_name))
+        {
+            return None;
+        }
+        let autocomplete = attributes.find_by_name("autocomplete").ok()??;
+        let _initializer = autocomplete.initializer()?;
+        let extract_attrs = ctx.extract_attributes(&attributes)?;
+        let autocomplete_values = extract_attrs.get("autocomplete")?;
+        if autocomplete_values
+            .first()
+            .map_or(false, |v| matches!(v, AttributeValue::DynamicValue(_)))
+        {
+            return None;
+        }
 
-                let autocomplete_values = ctx.convert_attribute_values(autocomplete_values.clone());
+        let autocomplete_values = ctx.convert_attribute_values(autocomplete_values.clone());
 
-                if autocomplete_values.first() == Some(&"none".to_string())
-                    || is_valid_autocomplete(&autocomplete_values)?
-                {
-                    return None;
-                }
-                Some(autocomplete.range())
+        let is_valid = match autocomplete_values.as_slice() {
+            [] => true,
+            // `none` isn't valid, but it's commonly used to disable the autofill
+            [value] if value == "none" => true,
+            // The autocomplete attribute of forms only turns the autofill on and off
+            [value] if is_form => {
+                value.eq_ignore_ascii_case("on") || value.eq_ignore_ascii_case("off")
             }
+            values => !is_form && is_valid_autofill(values),
+        };
+        if is_valid {
+            return None;
         }
+        Some(autocomplete.range())
     }
 
     fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
@@ -234,40 +171,3 @@ impl Rule for UseValidAutocomplete {
     )
     }
 }
-
-/// Checks if the autocomplete attribute values are valid
-fn is_valid_autocomplete(autocomplete_values: &[String]) -> Option<bool> {
-    let is_valid = match autocomplete_values.len() {
-        0 => true,
-        1 => {
-            let first = autocomplete_values.first()?.as_str();
-            first.is_empty()
-                | first.starts_with("section-")
-                | VALID_AUTOCOMPLETE_VALUES.binary_search(&first).is_ok()
-        }
-        _ => {
-            let first = autocomplete_values.first()?.as_str();
-            let second = autocomplete_values.get(1)?.as_str();
-            first.starts_with("section-")
-                || ["billing", "shipping"].contains(&first)
-                    && (BILLING_AND_SHIPPING_ADDRESS.binary_search(&second).is_ok()
-                        || VALID_AUTOCOMPLETE_VALUES.binary_search(&second).is_ok())
-                || autocomplete_values.iter().all(|val| {
-                    VALID_AUTOCOMPLETE_VALUES
-                        .binary_search(&val.as_str())
-                        .is_ok()
-                })
-        }
-    };
-    Some(is_valid)
-}
-
-#[test]
-fn test_order() {
-    for items in VALID_AUTOCOMPLETE_VALUES.windows(2) {
-        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
-    }
-    for items in BILLING_AND_SHIPPING_ADDRESS.windows(2) {
-        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
-    }
-}
@@ -1,7 +1,8 @@
 use crate::{services::semantic::Semantic, JsRuleAction};
 use biome_analyze::{
-    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
-    RuleSource,
+    browserslist::{BrowserTargets, Feature},
+    context::RuleContext,
+    declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
 use biome_js_factory::make::{self};
@@ -11,6 +12,7 @@ use biome_js_syntax::{
     TextRange, T,
 };
 use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+use std::sync::Arc;
 
 declare_lint_rule! {
     /// Disallow direct use of `Object.prototype` builtins.
@@ -23,6 +25,7 @@ declare_lint_rule! {
     /// To avoid subtle bugs like this, you should call these methods from `Object.prototype`.
     /// For example, `foo.isPrototypeOf(bar)` should be replaced with `Object.prototype.isPrototypeOf.call(foo, "bar")`
     /// As for the `hasOwn` method, `foo.hasOwn("bar")` should be replaced with `Object.hasOwn(foo, "bar")`.
+    /// `Object.hasOwn()` is recommended, and its fix is suggested, only when all the targeted browsers support it.
     ///
     /// ## Examples
     ///
@@ -113,7 +116,7 @@ impl Rule for NoPrototypeBuiltins {
         None
     }
 
-    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let diag = RuleDiagnostic::new(
             rule_category!(),
             state.text_range,
@@ -122,7 +125,7 @@ impl Rule for NoPrototypeBuiltins {
             },
         );
 
-        if state.prototype_builtins_method_name == "hasOwnProperty" {
+        if state.prototype_builtins_method_name == "hasOwnProperty" && supports_has_own(ctx) {
             Some(
                 diag.note(markup! {
                     "It's recommended using "<Emphasis>"Object.hasOwn()"</Emphasis>" instead of using "<Emphasis>"Object.hasOwnProperty()"</Emphasis>"."
@@ -139,7 +142,10 @@ impl Rule for NoPrototypeBuiltins {
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
         let node = ctx.query();
 
-        if node.is_optional() || state.prototype_builtins_method_name != "hasOwnProperty" {
+        if node.is_optional()
+            || state.prototype_builtins_method_name != "hasOwnProperty"
+            || !supports_has_own(ctx)
+        {
             return None;
         }
 
@@ -199,6 +205,12 @@ impl Rule for NoPrototypeBuiltins {
     }
 }
 
+/// Checks if all the targeted browsers support `Object.hasOwn()`
+fn supports_has_own(ctx: &RuleContext<NoPrototypeBuiltins>) -> bool {
+    ctx.get_service::<Arc<BrowserTargets>>()
+        .is_none_or(|targets| targets.supports(Feature::ObjectHasOwn))
+}
+
 /// Checks if the `Object.prototype` builtins called directly.
 fn is_prototype_builtins(token_text: &str) -> bool {
     matches!(
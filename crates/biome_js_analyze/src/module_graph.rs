@@ -0,0 +1,166 @@
+use biome_js_syntax::{AnyJsRoot, JsExportFromClause, JsExportNamedFromClause, JsModuleSource};
+use biome_rowan::AstNode;
+use std::collections::{BTreeMap, VecDeque};
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+
+/// The extensions tried, in this order, to resolve a specifier without extension
+const RESOLVED_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Returns `true` if `source` is the source of an `export ... from` statement.
+pub fn is_reexport_source(source: &JsModuleSource) -> bool {
+    source.parent::<JsExportFromClause>().is_some()
+        || source.parent::<JsExportNamedFromClause>().is_some()
+}
+
+/// The modules of a project, linked by their `export ... from` statements
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    /// The folder of the project. The paths of the modules are displayed relative to it.
+    root: PathBuf,
+    /// The specifiers re-exported by each module, by absolute path
+    modules: RwLock<BTreeMap<PathBuf, Vec<String>>>,
+}
+
+impl ModuleGraph {
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: absolute_path(root),
+            modules: RwLock::default(),
+        }
+    }
+
+    /// The folder of the project, empty when the project isn't analyzed
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Indexes the module `root`, stored at `path`.
+    ///
+    /// The module previously indexed for `path` is replaced.
+    pub fn insert(&self, path: &Path, root: &AnyJsRoot) {
+        let reexports = root
+            .syntax()
+            .descendants()
+            .filter_map(JsModuleSource::cast)
+            .filter(is_reexport_source)
+            .filter_map(|source| Some(source.inner_string_text().ok()?.to_string()))
+            .collect();
+        self.modules
+            .write()
+            .unwrap()
+            .insert(absolute_path(path), reexports);
+    }
+
+    /// Resolves the relative `specifier` imported by the module `path`.
+    ///
+    /// The specifiers without extension resolve to the indexed module with one of the extensions
+    /// of JavaScript and TypeScript files, or to the `index` module of the folder. The path of
+    /// the specifier is returned as is when it doesn't resolve to an indexed module.
+    ///
+    /// Returns `None` if `specifier` isn't relative, such as the specifiers of the packages.
+    pub fn resolve(&self, path: &Path, specifier: &str) -> Option<PathBuf> {
+        let modules = self.modules.read().unwrap();
+        resolve(&modules, path, specifier)
+    }
+
+    /// Returns the chain of `export ... from` statements that leads from the module `path` to
+    /// the first module accepted by `predicate`, excluding `path`.
+    ///
+    /// Returns `None` if no module re-exported by `path`, directly or not, is accepted.
+    pub fn find_reexport(
+        &self,
+        path: &Path,
+        predicate: impl Fn(&Path) -> bool,
+    ) -> Option<Vec<PathBuf>> {
+        let modules = self.modules.read().unwrap();
+        let start = absolute_path(path);
+        // The module from which each visited module is re-exported
+        let mut parents: BTreeMap<PathBuf, PathBuf> = BTreeMap::new();
+        let mut queue = VecDeque::from([start.clone()]);
+
+        while let Some(module) = queue.pop_front() {
+            let Some(reexports) = modules.get(&module) else {
+                continue;
+            };
+            for specifier in reexports {
+                let Some(target) = resolve(&modules, &module, specifier) else {
+                    continue;
+                };
+                if target == start || parents.contains_key(&target) {
+                    continue;
+                }
+                parents.insert(target.clone(), module.clone());
+                if predicate(&target) {
+                    let mut chain = vec![target];
+                    while let Some(parent) = parents.get(chain.last()?) {
+                        if *parent == start {
+                            break;
+                        }
+                        chain.push(parent.clone());
+                    }
+                    chain.reverse();
+                    return Some(chain);
+                }
+                queue.push_back(target);
+            }
+        }
+
+        None
+    }
+}
+
+fn resolve(
+    modules: &BTreeMap<PathBuf, Vec<String>>,
+    path: &Path,
+    specifier: &str,
+) -> Option<PathBuf> {
+    let is_relative = matches!(specifier, "." | "..")
+        || specifier.starts_with("./")
+        || specifier.starts_with("../");
+    if !is_relative {
+        return None;
+    }
+
+    let target = normalize_path(&absolute_path(path).parent()?.join(specifier));
+    if modules.contains_key(&target) {
+        return Some(target);
+    }
+    let file_name = target.file_name()?.to_str()?;
+    let candidates = RESOLVED_EXTENSIONS
+        .iter()
+        .map(|extension| target.with_file_name(format!("{file_name}.{extension}")))
+        .chain(
+            RESOLVED_EXTENSIONS
+                .iter()
+                .map(|extension| target.join(format!("index.{extension}"))),
+        );
+    for candidate in candidates {
+        if modules.contains_key(&candidate) {
+            return Some(candidate);
+        }
+    }
+
+    Some(target)
+}
+
+/// Returns the absolute path of `path`, relative to the working directory when `path` is
+/// relative
+pub(crate) fn absolute_path(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Removes the `.` and `..` components of `path`
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -14,6 +14,8 @@ pub type NoArrayIndexKey =
 pub type NoAssignInExpressions = < lint :: suspicious :: no_assign_in_expressions :: NoAssignInExpressions as biome_analyze :: Rule > :: Options ;
 pub type NoAsyncPromiseExecutor = < lint :: suspicious :: no_async_promise_executor :: NoAsyncPromiseExecutor as biome_analyze :: Rule > :: Options ;
 pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoAwaitInLoops =
+    <lint::nursery::no_await_in_loops::NoAwaitInLoops as biome_analyze::Rule>::Options;
 pub type NoBannedTypes =
     <lint::complexity::no_banned_types::NoBannedTypes as biome_analyze::Rule>::Options;
 pub type NoBarrelFile =
@@ -114,6 +116,7 @@ pub type NoGlobalIsFinite =
 pub type NoGlobalIsNan =
     <lint::suspicious::no_global_is_nan::NoGlobalIsNan as biome_analyze::Rule>::Options;
 pub type NoGlobalObjectCalls = < lint :: correctness :: no_global_object_calls :: NoGlobalObjectCalls as biome_analyze :: Rule > :: Options ;
+pub type NoHardcodedLocaleStrings = < lint :: nursery :: no_hardcoded_locale_strings :: NoHardcodedLocaleStrings as biome_analyze :: Rule > :: Options ;
 pub type NoHeadElement =
     <lint::nursery::no_head_element::NoHeadElement as biome_analyze::Rule>::Options;
 pub type NoHeadImportInDocument = < lint :: nursery :: no_head_import_in_document :: NoHeadImportInDocument as biome_analyze :: Rule > :: Options ;
@@ -146,6 +149,7 @@ pub type NoMisleadingInstantiator = < lint :: suspicious :: no_misleading_instan
 pub type NoMisplacedAssertion = < lint :: suspicious :: no_misplaced_assertion :: NoMisplacedAssertion as biome_analyze :: Rule > :: Options ;
 pub type NoMisrefactoredShorthandAssign = < lint :: suspicious :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign as biome_analyze :: Rule > :: Options ;
 pub type NoMultipleSpacesInRegularExpressionLiterals = < lint :: complexity :: no_multiple_spaces_in_regular_expression_literals :: NoMultipleSpacesInRegularExpressionLiterals as biome_analyze :: Rule > :: Options ;
+pub type NoMutationOfFunctionParametersDeep = < lint :: nursery :: no_mutation_of_function_parameters_deep :: NoMutationOfFunctionParametersDeep as biome_analyze :: Rule > :: Options ;
 pub type NoNamespace = <lint::style::no_namespace::NoNamespace as biome_analyze::Rule>::Options;
 pub type NoNamespaceImport =
     <lint::style::no_namespace_import::NoNamespaceImport as biome_analyze::Rule>::Options;
@@ -164,6 +168,7 @@ pub type NoNoninteractiveTabindex = < lint :: a11y :: no_noninteractive_tabindex
 pub type NoNonoctalDecimalEscape = < lint :: correctness :: no_nonoctal_decimal_escape :: NoNonoctalDecimalEscape as biome_analyze :: Rule > :: Options ;
 pub type NoOctalEscape =
     <lint::nursery::no_octal_escape::NoOctalEscape as biome_analyze::Rule>::Options;
+pub type NoOutdatedBrowserApis = < lint :: nursery :: no_outdated_browser_apis :: NoOutdatedBrowserApis as biome_analyze :: Rule > :: Options ;
 pub type NoParameterAssign =
     <lint::style::no_parameter_assign::NoParameterAssign as biome_analyze::Rule>::Options;
 pub type NoParameterProperties =
@@ -185,6 +190,7 @@ pub type NoRedundantAlt =
     <lint::a11y::no_redundant_alt::NoRedundantAlt as biome_analyze::Rule>::Options;
 pub type NoRedundantRoles =
     <lint::a11y::no_redundant_roles::NoRedundantRoles as biome_analyze::Rule>::Options;
+pub type NoRedundantTypeAnnotations = < lint :: nursery :: no_redundant_type_annotations :: NoRedundantTypeAnnotations as biome_analyze :: Rule > :: Options ;
 pub type NoRedundantUseStrict = < lint :: suspicious :: no_redundant_use_strict :: NoRedundantUseStrict as biome_analyze :: Rule > :: Options ;
 pub type NoRenderReturnValue = < lint :: correctness :: no_render_return_value :: NoRenderReturnValue as biome_analyze :: Rule > :: Options ;
 pub type NoRestrictedGlobals =
@@ -203,6 +209,7 @@ pub type NoSetterReturn =
 pub type NoShadowRestrictedNames = < lint :: suspicious :: no_shadow_restricted_names :: NoShadowRestrictedNames as biome_analyze :: Rule > :: Options ;
 pub type NoShoutyConstants =
     <lint::style::no_shouty_constants::NoShoutyConstants as biome_analyze::Rule>::Options;
+pub type NoSkippedAccessibilityInTests = < lint :: nursery :: no_skipped_accessibility_in_tests :: NoSkippedAccessibilityInTests as biome_analyze :: Rule > :: Options ;
 pub type NoSkippedTests =
     <lint::suspicious::no_skipped_tests::NoSkippedTests as biome_analyze::Rule>::Options;
 pub type NoSparseArray =
@@ -221,6 +228,8 @@ pub type NoThenProperty =
     <lint::suspicious::no_then_property::NoThenProperty as biome_analyze::Rule>::Options;
 pub type NoThisInStatic =
     <lint::complexity::no_this_in_static::NoThisInStatic as biome_analyze::Rule>::Options;
+pub type NoUncheckedJsonParse =
+    <lint::nursery::no_unchecked_json_parse::NoUncheckedJsonParse as biome_analyze::Rule>::Options;
 pub type NoUndeclaredDependencies = < lint :: correctness :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
 pub type NoUnnecessaryContinue = < lint :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
@@ -275,6 +284,7 @@ pub type NoVoid = <lint::complexity::no_void::NoVoid as biome_analyze::Rule>::Op
 pub type NoVoidElementsWithChildren = < lint :: correctness :: no_void_elements_with_children :: NoVoidElementsWithChildren as biome_analyze :: Rule > :: Options ;
 pub type NoVoidTypeReturn =
     <lint::correctness::no_void_type_return::NoVoidTypeReturn as biome_analyze::Rule>::Options;
+pub type NoWildcardExportsFromTests = < lint :: nursery :: no_wildcard_exports_from_tests :: NoWildcardExportsFromTests as biome_analyze :: Rule > :: Options ;
 pub type NoWith = <lint::complexity::no_with::NoWith as biome_analyze::Rule>::Options;
 pub type NoYodaExpression =
     <lint::style::no_yoda_expression::NoYodaExpression as biome_analyze::Rule>::Options;
@@ -282,6 +292,8 @@ pub type OrganizeImports =
     <assists::source::organize_imports::OrganizeImports as biome_analyze::Rule>::Options;
 pub type SortJsxProps =
     <assists::source::sort_jsx_props::SortJsxProps as biome_analyze::Rule>::Options;
+pub type UseAccessorPairs =
+    <lint::nursery::use_accessor_pairs::UseAccessorPairs as biome_analyze::Rule>::Options;
 pub type UseAdjacentOverloadSignatures = < lint :: nursery :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures as biome_analyze :: Rule > :: Options ;
 pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
 pub type UseAnchorContent =
@@ -302,6 +314,7 @@ pub type UseBlockStatements =
     <lint::style::use_block_statements::UseBlockStatements as biome_analyze::Rule>::Options;
 pub type UseButtonType =
     <lint::a11y::use_button_type::UseButtonType as biome_analyze::Rule>::Options;
+pub type UseCanonicalOrderOfModifiers = < lint :: nursery :: use_canonical_order_of_modifiers :: UseCanonicalOrderOfModifiers as biome_analyze :: Rule > :: Options ;
 pub type UseCollapsedElseIf =
     <lint::style::use_collapsed_else_if::UseCollapsedElseIf as biome_analyze::Rule>::Options;
 pub type UseCollapsedIf =
@@ -311,11 +324,13 @@ pub type UseConsistentArrayType = < lint :: style :: use_consistent_array_type :
 pub type UseConsistentBuiltinInstantiation = < lint :: style :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentCurlyBraces = < lint :: nursery :: use_consistent_curly_braces :: UseConsistentCurlyBraces as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentMemberAccessibility = < lint :: nursery :: use_consistent_member_accessibility :: UseConsistentMemberAccessibility as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentResponseHandling = < lint :: nursery :: use_consistent_response_handling :: UseConsistentResponseHandling as biome_analyze :: Rule > :: Options ;
 pub type UseConst = <lint::style::use_const::UseConst as biome_analyze::Rule>::Options;
 pub type UseDateNow = <lint::complexity::use_date_now::UseDateNow as biome_analyze::Rule>::Options;
 pub type UseDefaultParameterLast = < lint :: style :: use_default_parameter_last :: UseDefaultParameterLast as biome_analyze :: Rule > :: Options ;
 pub type UseDefaultSwitchClause = < lint :: style :: use_default_switch_clause :: UseDefaultSwitchClause as biome_analyze :: Rule > :: Options ;
 pub type UseDefaultSwitchClauseLast = < lint :: suspicious :: use_default_switch_clause_last :: UseDefaultSwitchClauseLast as biome_analyze :: Rule > :: Options ;
+pub type UseDestructuredImports = < lint :: nursery :: use_destructured_imports :: UseDestructuredImports as biome_analyze :: Rule > :: Options ;
 pub type UseEnumInitializers =
     <lint::style::use_enum_initializers::UseEnumInitializers as biome_analyze::Rule>::Options;
 pub type UseErrorMessage =
@@ -324,6 +339,7 @@ pub type UseExhaustiveDependencies = < lint :: correctness :: use_exhaustive_dep
 pub type UseExplicitLengthCheck = < lint :: style :: use_explicit_length_check :: UseExplicitLengthCheck as biome_analyze :: Rule > :: Options ;
 pub type UseExplicitType =
     <lint::nursery::use_explicit_type::UseExplicitType as biome_analyze::Rule>::Options;
+pub type UseExplicitTypeAnnotation = < assists :: source :: use_explicit_type_annotation :: UseExplicitTypeAnnotation as biome_analyze :: Rule > :: Options ;
 pub type UseExponentiationOperator = < lint :: style :: use_exponentiation_operator :: UseExponentiationOperator as biome_analyze :: Rule > :: Options ;
 pub type UseExportType =
     <lint::style::use_export_type::UseExportType as biome_analyze::Rule>::Options;
@@ -347,6 +363,8 @@ pub type UseHookAtTopLevel =
 pub type UseHtmlLang = <lint::a11y::use_html_lang::UseHtmlLang as biome_analyze::Rule>::Options;
 pub type UseIframeTitle =
     <lint::a11y::use_iframe_title::UseIframeTitle as biome_analyze::Rule>::Options;
+pub type UseImmutableExports =
+    <lint::nursery::use_immutable_exports::UseImmutableExports as biome_analyze::Rule>::Options;
 pub type UseImportExtensions =
     <lint::correctness::use_import_extensions::UseImportExtensions as biome_analyze::Rule>::Options;
 pub type UseImportRestrictions =
@@ -366,6 +384,7 @@ pub type UseLiteralKeys =
     <lint::complexity::use_literal_keys::UseLiteralKeys as biome_analyze::Rule>::Options;
 pub type UseMediaCaption =
     <lint::a11y::use_media_caption::UseMediaCaption as biome_analyze::Rule>::Options;
+pub type UseMinimalBundleImports = < lint :: nursery :: use_minimal_bundle_imports :: UseMinimalBundleImports as biome_analyze :: Rule > :: Options ;
 pub type UseNamespaceKeyword =
     <lint::suspicious::use_namespace_keyword::UseNamespaceKeyword as biome_analyze::Rule>::Options;
 pub type UseNamingConvention =
@@ -380,8 +399,13 @@ pub type UseNumericLiterals =
     <lint::style::use_numeric_literals::UseNumericLiterals as biome_analyze::Rule>::Options;
 pub type UseOptionalChain =
     <lint::complexity::use_optional_chain::UseOptionalChain as biome_analyze::Rule>::Options;
+pub type UsePlaywrightLocatorBestPractices = < lint :: nursery :: use_playwright_locator_best_practices :: UsePlaywrightLocatorBestPractices as biome_analyze :: Rule > :: Options ;
+pub type UsePreferredModules =
+    <lint::nursery::use_preferred_modules::UsePreferredModules as biome_analyze::Rule>::Options;
 pub type UseRegexLiterals =
     <lint::complexity::use_regex_literals::UseRegexLiterals as biome_analyze::Rule>::Options;
+pub type UseSafeIntegerChecks =
+    <lint::nursery::use_safe_integer_checks::UseSafeIntegerChecks as biome_analyze::Rule>::Options;
 pub type UseSelfClosingElements = < lint :: style :: use_self_closing_elements :: UseSelfClosingElements as biome_analyze :: Rule > :: Options ;
 pub type UseSemanticElements =
     <lint::a11y::use_semantic_elements::UseSemanticElements as biome_analyze::Rule>::Options;
@@ -397,9 +421,13 @@ pub type UseSingleCaseStatement = < lint :: style :: use_single_case_statement :
 pub type UseSingleVarDeclarator = < lint :: style :: use_single_var_declarator :: UseSingleVarDeclarator as biome_analyze :: Rule > :: Options ;
 pub type UseSortedClasses =
     <lint::nursery::use_sorted_classes::UseSortedClasses as biome_analyze::Rule>::Options;
+pub type UseStaticClassBlocks =
+    <lint::nursery::use_static_class_blocks::UseStaticClassBlocks as biome_analyze::Rule>::Options;
 pub type UseStrictMode =
     <lint::nursery::use_strict_mode::UseStrictMode as biome_analyze::Rule>::Options;
 pub type UseTemplate = <lint::style::use_template::UseTemplate as biome_analyze::Rule>::Options;
+pub type UseTemplateLiteral =
+    <assists::source::use_template_literal::UseTemplateLiteral as biome_analyze::Rule>::Options;
 pub type UseThrowNewError =
     <lint::style::use_throw_new_error::UseThrowNewError as biome_analyze::Rule>::Options;
 pub type UseThrowOnlyError =
@@ -0,0 +1,153 @@
+//! A series of AST utilities to work with [Playwright](https://playwright.dev) tests
+
+use crate::utils::named_import;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsRoot, JsCallExpression, JsReferenceIdentifier,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxToken,
+};
+use biome_rowan::AstNode;
+
+/// The package that provides the test runner of Playwright
+const PLAYWRIGHT_TEST_PACKAGE: &str = "@playwright/test";
+
+/// The matchers of Playwright that retry until they pass, and return a promise, sorted.
+///
+/// See <https://playwright.dev/docs/test-assertions#auto-retrying-assertions>
+const ASYNC_MATCHERS: [&str; 27] = [
+    "toBeAttached",
+    "toBeChecked",
+    "toBeDisabled",
+    "toBeEditable",
+    "toBeEmpty",
+    "toBeEnabled",
+    "toBeFocused",
+    "toBeHidden",
+    "toBeInViewport",
+    "toBeOK",
+    "toBeVisible",
+    "toContainText",
+    "toHaveAccessibleDescription",
+    "toHaveAccessibleName",
+    "toHaveAttribute",
+    "toHaveCSS",
+    "toHaveClass",
+    "toHaveCount",
+    "toHaveId",
+    "toHaveJSProperty",
+    "toHaveRole",
+    "toHaveScreenshot",
+    "toHaveText",
+    "toHaveTitle",
+    "toHaveURL",
+    "toHaveValue",
+    "toHaveValues",
+];
+
+/// Returns `true` if the module imports `@playwright/test`
+pub(crate) fn is_playwright_module(root: &AnyJsRoot) -> bool {
+    let Some(module) = root.as_js_module() else {
+        return false;
+    };
+    module.items().into_iter().any(|item| {
+        let AnyJsModuleItem::JsImport(import) = item else {
+            return false;
+        };
+        import
+            .source_text()
+            .is_ok_and(|source| source.text() == PLAYWRIGHT_TEST_PACKAGE)
+    })
+}
+
+/// Returns `true` if `call` is an assertion of Playwright that returns a promise.
+///
+/// That's the case of the auto-retrying matchers, such as `expect(locator).toBeVisible()`, and
+/// of all the matchers of `expect.poll(...)`.
+pub(crate) fn is_async_assertion(call: &JsCallExpression, model: &SemanticModel) -> bool {
+    let Ok(AnyJsExpression::JsStaticMemberExpression(matcher)) = call.callee() else {
+        return false;
+    };
+    let Some(matcher_name) = member_name(&matcher) else {
+        return false;
+    };
+    let Ok(mut object) = matcher.object() else {
+        return false;
+    };
+    // Skip the modifier of `expect(...).not.toBeVisible()`
+    if let AnyJsExpression::JsStaticMemberExpression(modifier) = &object {
+        if member_name(modifier).is_some_and(|name| name.text_trimmed() == "not") {
+            let Ok(modifier_object) = modifier.object() else {
+                return false;
+            };
+            object = modifier_object;
+        }
+    }
+    let Some(expect_call) = object.as_js_call_expression() else {
+        return false;
+    };
+    match expect_call.callee() {
+        Ok(AnyJsExpression::JsIdentifierExpression(expect)) => {
+            expect
+                .name()
+                .is_ok_and(|reference| is_playwright_import(&reference, model, "expect"))
+                && ASYNC_MATCHERS
+                    .binary_search(&matcher_name.text_trimmed())
+                    .is_ok()
+        }
+        // `expect.soft(...)` and `expect.poll(...)`
+        Ok(AnyJsExpression::JsStaticMemberExpression(member)) => {
+            let Some(modifier) = member_name(&member) else {
+                return false;
+            };
+            let is_expect = member.object().is_ok_and(|object| {
+                object
+                    .as_js_identifier_expression()
+                    .and_then(|expect| expect.name().ok())
+                    .is_some_and(|reference| is_playwright_import(&reference, model, "expect"))
+            });
+            is_expect
+                && match modifier.text_trimmed() {
+                    "poll" => true,
+                    "soft" => ASYNC_MATCHERS
+                        .binary_search(&matcher_name.text_trimmed())
+                        .is_ok(),
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the promise returned by `call` is handled: it's awaited, returned, or
+/// collected in an array, for example for `Promise.all()`.
+pub(crate) fn is_promise_handled(call: &JsCallExpression) -> bool {
+    call.syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_AWAIT_EXPRESSION
+                    | JsSyntaxKind::JS_RETURN_STATEMENT
+                    | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+                    | JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+            )
+        })
+}
+
+/// Returns `true` if `reference` refers to the export `name` of `@playwright/test`
+fn is_playwright_import(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+    name: &str,
+) -> bool {
+    named_import(reference, model).is_some_and(|(imported_name, source)| {
+        imported_name.text_trimmed() == name && source.text() == PLAYWRIGHT_TEST_PACKAGE
+    })
+}
+
+/// Returns the name of the member of `member`, such as `b` for `a.b`
+fn member_name(member: &JsStaticMemberExpression) -> Option<JsSyntaxToken> {
+    member.member().ok()?.as_js_name()?.value_token().ok()
+}
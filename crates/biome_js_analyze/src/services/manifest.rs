@@ -7,44 +7,43 @@ use biome_project::PackageJson;
 use biome_rowan::AstNode;
 use std::sync::Arc;
 
+/// Gives access to the `package.json` of the project that contains the analyzed file.
+///
+/// Besides the [Manifest] query, any rule can retrieve this service with
+/// `ctx.get_service::<Option<Arc<PackageJson>>>()`.
 #[derive(Debug, Clone)]
 pub struct ManifestServices {
-    pub(crate) manifest: Arc<Option<PackageJson>>,
+    pub(crate) manifest: Option<Arc<PackageJson>>,
 }
 
 impl ManifestServices {
+    /// The manifest, with its dependencies, `type`, `engines`, `browserslist`
+    /// and package manager.
+    pub(crate) fn manifest(&self) -> Option<&PackageJson> {
+        self.manifest.as_deref()
+    }
+
     pub(crate) fn name(&self) -> Option<&str> {
-        self.manifest
-            .as_ref()
-            .as_ref()
-            .and_then(|pkg| pkg.name.as_deref())
+        self.manifest().and_then(|pkg| pkg.name.as_deref())
     }
 
     pub(crate) fn is_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
+        self.manifest()
             .is_some_and(|pkg| pkg.dependencies.contains(specifier))
     }
 
     pub(crate) fn is_dev_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
+        self.manifest()
             .is_some_and(|pkg| pkg.dev_dependencies.contains(specifier))
     }
 
     pub(crate) fn is_peer_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
+        self.manifest()
             .is_some_and(|pkg| pkg.peer_dependencies.contains(specifier))
     }
 
     pub(crate) fn is_optional_dependency(&self, specifier: &str) -> bool {
-        self.manifest
-            .as_ref()
-            .as_ref()
+        self.manifest()
             .is_some_and(|pkg| pkg.optional_dependencies.contains(specifier))
     }
 }
@@ -54,7 +53,7 @@ impl FromServices for ManifestServices {
         rule_key: &RuleKey,
         services: &ServiceBag,
     ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
-        let manifest: &Arc<Option<PackageJson>> = services.get_service().ok_or_else(|| {
+        let manifest: &Option<Arc<PackageJson>> = services.get_service().ok_or_else(|| {
             MissingServicesDiagnostic::new(rule_key.rule_name(), &["PackageJson"])
         })?;
 
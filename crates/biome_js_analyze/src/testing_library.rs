@@ -0,0 +1,215 @@
+//! A series of AST utilities to work with the [Testing Library](https://testing-library.com) packages
+
+use crate::utils::named_import;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsExpression, JsIdentifierBinding,
+    JsReferenceIdentifier,
+};
+use biome_rowan::{AstNode, TokenText};
+
+/// The scope of the Testing Library packages, such as `@testing-library/react`
+const TESTING_LIBRARY_SCOPE: &str = "@testing-library/";
+
+/// The functions of Testing Library that return an object exposing the queries
+const QUERIES_FUNCTIONS: [&str; 2] = ["render", "within"];
+
+/// The kinds of queries of Testing Library, sorted by priority.
+///
+/// See <https://testing-library.com/docs/queries/about#priority>
+const QUERY_KINDS: [&str; 8] = [
+    "Role",
+    "LabelText",
+    "PlaceholderText",
+    "Text",
+    "DisplayValue",
+    "AltText",
+    "Title",
+    "TestId",
+];
+
+/// The variants of the queries of Testing Library.
+///
+/// The variants that match several elements come first, so that `getAllBy` isn't taken for `getBy`.
+const QUERY_VARIANTS: [&str; 6] = [
+    "getAllBy",
+    "queryAllBy",
+    "findAllBy",
+    "getBy",
+    "queryBy",
+    "findBy",
+];
+
+/// A query of Testing Library, such as `getByRole` or `findAllByTestId`
+pub(crate) struct TestingLibraryQuery<'a> {
+    /// The variant of the query, such as `getBy` or `findAllBy`
+    pub(crate) variant: &'a str,
+    /// The kind of the query, such as `Role` or `TestId`
+    pub(crate) kind: &'a str,
+}
+
+impl<'a> TestingLibraryQuery<'a> {
+    /// Parses the name of a query, such as `getByRole`
+    pub(crate) fn parse(name: &'a str) -> Option<Self> {
+        QUERY_VARIANTS.iter().find_map(|variant| {
+            let kind = name.strip_prefix(variant)?;
+            QUERY_KINDS
+                .contains(&kind)
+                .then_some(Self { variant, kind })
+        })
+    }
+
+    pub(crate) fn is_by_role(&self) -> bool {
+        self.kind == "Role"
+    }
+
+    pub(crate) fn is_by_test_id(&self) -> bool {
+        self.kind == "TestId"
+    }
+}
+
+/// Returns `true` if `expression` is an object that exposes the queries of Testing Library.
+///
+/// That's the case of:
+/// - the `screen` object;
+/// - the results of `render(...)` and `within(...)`;
+/// - the variables initialized with one of the previous expressions.
+pub(crate) fn is_queries_object(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let expression = expression.clone().omit_parentheses();
+    if let Some(reference) = expression
+        .as_js_identifier_expression()
+        .and_then(|identifier| identifier.name().ok())
+    {
+        if is_testing_library_import(&reference, model, "screen") {
+            return true;
+        }
+        return variable_initializer(&reference, model)
+            .is_some_and(|initializer| is_queries_call(&initializer, model, &QUERIES_FUNCTIONS));
+    }
+    is_queries_call(&expression, model, &QUERIES_FUNCTIONS)
+}
+
+/// Returns `true` if `expression` is the `container` of the result of `render(...)`.
+///
+/// The `container` can be accessed as a member of the result or destructured from it:
+///
+/// ```js
+/// render(<App />).container;
+/// const { container } = render(<App />);
+/// ```
+pub(crate) fn is_render_container(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .ok()
+            .and_then(|reference| destructured_property(&reference, model))
+            .is_some_and(|(name, initializer)| {
+                name.text() == "container" && is_render_result(&initializer, model)
+            }),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            member
+                .member()
+                .ok()
+                .and_then(|member| member.as_js_name()?.value_token().ok())
+                .is_some_and(|name| name.text_trimmed() == "container")
+                && member
+                    .object()
+                    .is_ok_and(|object| is_render_result(&object, model))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the name of the query that `reference` refers to, when the query was destructured from
+/// an object that exposes the queries of Testing Library:
+///
+/// ```js
+/// const { getByTestId } = render(<App />);
+/// ```
+pub(crate) fn destructured_query(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<TokenText> {
+    let (name, initializer) = destructured_property(reference, model)?;
+    is_queries_call(&initializer, model, &QUERIES_FUNCTIONS).then_some(name)
+}
+
+/// Returns `true` if `expression` is the result of `render(...)`, or a variable initialized with it
+fn is_render_result(expression: &AnyJsExpression, model: &SemanticModel) -> bool {
+    let expression = expression.clone().omit_parentheses();
+    match expression
+        .as_js_identifier_expression()
+        .and_then(|identifier| identifier.name().ok())
+    {
+        Some(reference) => variable_initializer(&reference, model)
+            .is_some_and(|initializer| is_queries_call(&initializer, model, &["render"])),
+        None => is_queries_call(&expression, model, &["render"]),
+    }
+}
+
+/// Returns `true` if `expression` calls one of the given functions of Testing Library
+fn is_queries_call(expression: &AnyJsExpression, model: &SemanticModel, names: &[&str]) -> bool {
+    let Some(call) = expression.as_js_call_expression() else {
+        return false;
+    };
+    let Some(AnyJsExpression::JsIdentifierExpression(callee)) =
+        call.callee().ok().map(AnyJsExpression::omit_parentheses)
+    else {
+        return false;
+    };
+    callee.name().is_ok_and(|reference| {
+        names
+            .iter()
+            .any(|name| is_testing_library_import(&reference, model, name))
+    })
+}
+
+/// Returns `true` if `reference` refers to the export `name` of a Testing Library package
+fn is_testing_library_import(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+    name: &str,
+) -> bool {
+    named_import(reference, model).is_some_and(|(imported_name, source)| {
+        imported_name.text_trimmed() == name && source.text().starts_with(TESTING_LIBRARY_SCOPE)
+    })
+}
+
+/// Returns the initializer of the variable that `reference` refers to
+fn variable_initializer(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<AnyJsExpression> {
+    let binding = model.binding(reference)?;
+    let ident = JsIdentifierBinding::cast_ref(binding.syntax())?;
+    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) = ident.declaration()? else {
+        return None;
+    };
+    declarator.initializer()?.expression().ok()
+}
+
+/// Returns the name of the property that `reference` was destructured from, along with the
+/// initializer of the destructured variable
+fn destructured_property(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<(TokenText, AnyJsExpression)> {
+    let binding = model.binding(reference)?;
+    let ident = JsIdentifierBinding::cast_ref(binding.syntax())?;
+    let declaration = ident.declaration()?;
+    let name = match &declaration {
+        AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_) => {
+            ident.name_token().ok()?.token_text_trimmed()
+        }
+        AnyJsBindingDeclaration::JsObjectBindingPatternProperty(property) => {
+            property.member().ok()?.name()?
+        }
+        _ => return None,
+    };
+    let AnyJsBindingDeclaration::JsVariableDeclarator(declarator) =
+        declaration.parent_binding_pattern_declaration()?
+    else {
+        return None;
+    };
+    Some((name, declarator.initializer()?.expression().ok()?))
+}
@@ -1,11 +1,18 @@
-use biome_js_syntax::{inner_string_text, AnyJsExpression, JsBinaryExpression, JsSyntaxNode};
-use biome_rowan::{AstNode, Direction, WalkEvent};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    inner_string_text, AnyJsExpression, AnyJsNamedImportSpecifier, JsBinaryExpression,
+    JsIdentifierBinding, JsImport, JsReferenceIdentifier, JsSyntaxNode, JsSyntaxToken,
+};
+use biome_rowan::{AstNode, Direction, TokenText, WalkEvent};
 use std::iter;
 
 pub mod batch;
+pub mod inferred_type;
+pub mod namespace_import;
 pub mod rename;
 pub mod restricted_glob;
 pub mod restricted_regex;
+pub mod template;
 #[cfg(test)]
 pub mod tests;
 
@@ -40,6 +47,33 @@ pub(crate) fn is_node_equal(a_node: &JsSyntaxNode, b_node: &JsSyntaxNode) -> boo
     true
 }
 
+/// Returns the name of the export that `reference` refers to, along with the source of its import.
+///
+/// For example, it returns `useState` and `react` for the references to `use` of
+/// `import { useState as use } from "react"`.
+pub(crate) fn named_import(
+    reference: &JsReferenceIdentifier,
+    model: &SemanticModel,
+) -> Option<(JsSyntaxToken, TokenText)> {
+    let binding = model.binding(reference)?;
+    let ident = JsIdentifierBinding::cast_ref(binding.syntax())?;
+    let import_specifier = ident.parent::<AnyJsNamedImportSpecifier>()?;
+    let imported_name = match &import_specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.name().ok()?.value().ok()?
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => ident.name_token().ok()?,
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+    };
+    let source = import_specifier
+        .syntax()
+        .ancestors()
+        .find_map(JsImport::cast)?
+        .source_text()
+        .ok()?;
+    Some((imported_name, source))
+}
+
 #[derive(Debug, PartialEq)]
 pub enum VariablePosition {
     Right,
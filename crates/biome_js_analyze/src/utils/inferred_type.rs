@@ -0,0 +1,210 @@
+//! Lightweight inference of the type of an initializer, such as `5` or `new Map<string, number>()`,
+//! for the cases where it doesn't require the type checker.
+
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyTsName, AnyTsType, JsNewExpression, JsSyntaxNode,
+    JsUnaryOperator, T,
+};
+use biome_rowan::{AstNode, Direction};
+use std::iter;
+
+use crate::utils::is_node_equal;
+
+/// The global classes that don't have type parameters
+const NON_GENERIC_GLOBAL_CLASSES: &[&str] = &[
+    "AbortController",
+    "Blob",
+    "Date",
+    "Error",
+    "EvalError",
+    "FormData",
+    "Headers",
+    "RangeError",
+    "ReferenceError",
+    "RegExp",
+    "Request",
+    "Response",
+    "SyntaxError",
+    "TextDecoder",
+    "TextEncoder",
+    "TypeError",
+    "URIError",
+    "URL",
+    "URLSearchParams",
+];
+
+/// The type that TypeScript infers for a variable, a property or a parameter from its
+/// initializer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum InferredType {
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    /// An instance of the class created by the expression, such as `new Map<string, number>()`
+    Instance(JsNewExpression),
+}
+
+impl InferredType {
+    /// Returns the type inferred from `initializer` without the type checker, or `None` if it
+    /// depends on other types.
+    ///
+    /// A literal is inferred as its primitive type. An instance of a class is only inferred when
+    /// its type arguments are known: they're either explicit, such as in
+    /// `new Map<string, number>()`, or the class doesn't have type parameters, such as a class
+    /// declared without type parameters in the file or a global class such as `Date`.
+    pub(crate) fn from_initializer(
+        initializer: &AnyJsExpression,
+        model: &SemanticModel,
+    ) -> Option<Self> {
+        match initializer.clone().omit_parentheses() {
+            AnyJsExpression::AnyJsLiteralExpression(literal) => match literal {
+                AnyJsLiteralExpression::JsBigintLiteralExpression(_) => Some(Self::BigInt),
+                AnyJsLiteralExpression::JsBooleanLiteralExpression(_) => Some(Self::Boolean),
+                AnyJsLiteralExpression::JsNumberLiteralExpression(_) => Some(Self::Number),
+                AnyJsLiteralExpression::JsStringLiteralExpression(_) => Some(Self::String),
+                AnyJsLiteralExpression::JsNullLiteralExpression(_)
+                | AnyJsLiteralExpression::JsRegexLiteralExpression(_) => None,
+            },
+            AnyJsExpression::JsTemplateExpression(template) => {
+                template.tag().is_none().then_some(Self::String)
+            }
+            AnyJsExpression::JsUnaryExpression(unary) => match unary.operator().ok()? {
+                JsUnaryOperator::LogicalNot => Some(Self::Boolean),
+                JsUnaryOperator::Typeof => Some(Self::String),
+                JsUnaryOperator::Plus | JsUnaryOperator::Minus => {
+                    match Self::from_initializer(&unary.argument().ok()?, model)? {
+                        Self::Number => Some(Self::Number),
+                        Self::BigInt if unary.operator().ok()? == JsUnaryOperator::Minus => {
+                            Some(Self::BigInt)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            AnyJsExpression::JsNewExpression(new_expression) => {
+                has_known_type_arguments(&new_expression, model)
+                    .then_some(Self::Instance(new_expression))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `ty` is the same type as the inferred type
+    pub(crate) fn is_same_as(&self, ty: &AnyTsType) -> bool {
+        match (self, ty.clone().omit_parentheses()) {
+            (Self::Boolean, AnyTsType::TsBooleanType(_))
+            | (Self::Number, AnyTsType::TsNumberType(_))
+            | (Self::BigInt, AnyTsType::TsBigintType(_))
+            | (Self::String, AnyTsType::TsStringType(_)) => true,
+            (Self::Instance(new_expression), AnyTsType::TsReferenceType(reference)) => {
+                let (Ok(callee), Ok(name)) = (new_expression.callee(), reference.name()) else {
+                    return false;
+                };
+                if !has_same_tokens(callee.syntax(), name.syntax()) {
+                    return false;
+                }
+                match (new_expression.type_arguments(), reference.type_arguments()) {
+                    (None, None) => true,
+                    (Some(arguments), Some(reference_arguments)) => {
+                        is_node_equal(arguments.syntax(), reference_arguments.syntax())
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the type written as an annotation, such as `number` or `Map<string, number>`.
+    ///
+    /// Returns `None` if the class of an instance can't be named in a type, such as
+    /// `new (getClass())()`.
+    pub(crate) fn to_ts_type(&self) -> Option<AnyTsType> {
+        Some(match self {
+            Self::Boolean => make::ts_boolean_type(make::token(T![boolean])).into(),
+            Self::Number => make::ts_number_type(make::token(T![number])).into(),
+            Self::BigInt => make::ts_bigint_type(make::token(T![bigint])).into(),
+            Self::String => make::ts_string_type(make::token(T![string])).into(),
+            Self::Instance(new_expression) => {
+                let name = ts_name_from_callee(&new_expression.callee().ok()?)?;
+                let reference = make::ts_reference_type(name);
+                match new_expression.type_arguments() {
+                    Some(type_arguments) => reference
+                        .with_type_arguments(type_arguments.trim_trivia()?)
+                        .build(),
+                    None => reference.build(),
+                }
+                .into()
+            }
+        })
+    }
+}
+
+/// Returns the name of the class called by `callee` as it's written in a type, such as `a.B`.
+fn ts_name_from_callee(callee: &AnyJsExpression) -> Option<AnyTsName> {
+    match callee {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let name = identifier.name().ok()?.value_token().ok()?;
+            Some(make::js_reference_identifier(make::ident(name.text_trimmed())).into())
+        }
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let left = ts_name_from_callee(&member.object().ok()?)?;
+            let right = member.member().ok()?.as_js_name()?.value_token().ok()?;
+            Some(
+                make::ts_qualified_name(
+                    left,
+                    make::token(T![.]),
+                    make::js_name(make::ident(right.text_trimmed())),
+                )
+                .into(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the type arguments of the instance created by `new_expression` don't
+/// depend on the arguments of the constructor.
+fn has_known_type_arguments(new_expression: &JsNewExpression, model: &SemanticModel) -> bool {
+    if new_expression.type_arguments().is_some() {
+        return true;
+    }
+    let Ok(AnyJsExpression::JsIdentifierExpression(callee)) = new_expression.callee() else {
+        return false;
+    };
+    let Ok(reference) = callee.name() else {
+        return false;
+    };
+    let Some(binding) = model.binding(&reference) else {
+        return reference
+            .value_token()
+            .is_ok_and(|token| NON_GENERIC_GLOBAL_CLASSES.contains(&token.text_trimmed()));
+    };
+    match binding.tree().declaration() {
+        Some(AnyJsBindingDeclaration::JsClassDeclaration(class)) => {
+            class.type_parameters().is_none()
+        }
+        Some(AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(class)) => {
+            class.type_parameters().is_none()
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if both nodes have the same tokens, regardless of their kind and their trivia,
+/// such as `a.B` in an expression and in a type.
+fn has_same_tokens(a: &JsSyntaxNode, b: &JsSyntaxNode) -> bool {
+    let mut a_tokens = a.descendants_tokens(Direction::Next);
+    let mut b_tokens = b.descendants_tokens(Direction::Next);
+    iter::from_fn(|| match (a_tokens.next(), b_tokens.next()) {
+        (None, None) => None,
+        (Some(a), Some(b)) => Some(a.text_trimmed() == b.text_trimmed()),
+        _ => Some(false),
+    })
+    .all(|is_same| is_same)
+}
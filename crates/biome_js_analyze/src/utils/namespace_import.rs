@@ -0,0 +1,246 @@
+//! Conversions between a namespace import, such as `import * as React from "react"`, and the
+//! named imports of the same module, such as `import { useState } from "react"`.
+
+use biome_js_factory::make;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    inner_string_text, AnyJsBinding, AnyJsExpression, AnyJsImportClause, AnyJsMemberExpression,
+    AnyJsNamedImportSpecifier, JsIdentifierExpression, JsImportNamedClause,
+    JsImportNamespaceClause, JsLanguage, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode,
+    JsxMemberName, JsxReferenceIdentifier, TsQualifiedName, T,
+};
+use biome_rowan::{AstNode, BatchMutation, TriviaPieceKind};
+use biome_unicode_table::is_js_ident;
+use rustc_hash::FxHashSet;
+
+/// Replaces the namespace import `clause` with the named imports of the accessed members, and
+/// the accesses of the members with the imported names.
+///
+/// Returns `None` if the namespace is used in another way than accessing one of its members
+/// with a static name, or if an imported name would conflict with another variable.
+pub(crate) fn to_named_imports(
+    clause: &JsImportNamespaceClause,
+    model: &SemanticModel,
+    mutation: &mut BatchMutation<JsLanguage>,
+) -> Option<()> {
+    let specifier = clause.namespace_specifier().ok()?;
+    let any_binding = specifier.local_name().ok()?;
+    let identifier = any_binding.as_js_identifier_binding()?;
+
+    // Every usage of the namespace must access a member with a static name,
+    // which is imported with the same name.
+    let mut accesses = Vec::new();
+    for reference in identifier.all_references(model) {
+        let (access, name) = member_access(reference.syntax())?;
+        if !is_js_ident(&name) || JsSyntaxKind::from_keyword(&name).is_some() {
+            return None;
+        }
+        // The imported name must not be shadowed where the member is accessed
+        if model
+            .scope(&access)
+            .ancestors()
+            .any(|scope| scope.get_binding(&name).is_some())
+        {
+            return None;
+        }
+        accesses.push((access, name));
+    }
+    // The imported names must not capture the references to global variables
+    let unresolved_names = unresolved_names(model);
+    let mut names = Vec::new();
+    for (_, name) in &accesses {
+        if unresolved_names.contains(name.as_str()) {
+            return None;
+        }
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    if names.is_empty() {
+        return None;
+    }
+
+    for (access, name) in accesses {
+        let new_node = match access.kind() {
+            JsSyntaxKind::TS_QUALIFIED_NAME => {
+                make::js_reference_identifier(make::ident(&name)).into_syntax()
+            }
+            JsSyntaxKind::JSX_MEMBER_NAME => {
+                make::jsx_reference_identifier(make::jsx_ident(&name)).into_syntax()
+            }
+            _ => make::js_identifier_expression(make::js_reference_identifier(make::ident(&name)))
+                .into_syntax(),
+        };
+        mutation.replace_element(access.into(), new_node.into());
+    }
+    let specifiers = names.iter().map(|name| {
+        AnyJsNamedImportSpecifier::from(
+            make::js_shorthand_named_import_specifier(AnyJsBinding::from(
+                make::js_identifier_binding(make::ident(name)),
+            ))
+            .build(),
+        )
+    });
+    let separators = (1..names.len())
+        .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+    let named_specifiers = make::js_named_import_specifiers(
+        make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_named_import_specifier_list(specifiers, separators),
+        make::token(T!['}'])
+            .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    );
+    let mut import_named_clause = make::js_import_named_clause(
+        named_specifiers,
+        clause.from_token().ok()?,
+        clause.source().ok()?,
+    );
+    if let Some(assertion) = clause.assertion() {
+        import_named_clause = import_named_clause.with_assertion(assertion);
+    }
+    mutation.replace_node(
+        AnyJsImportClause::from(clause.clone()),
+        import_named_clause.build().into(),
+    );
+    Some(())
+}
+
+/// Returns the access of a member of the namespace `reference`, such as `React.useState`,
+/// `React.FC` in a type or `<React.Fragment>`, and the name of the member.
+fn member_access(reference: &JsSyntaxNode) -> Option<(JsSyntaxNode, String)> {
+    let parent = reference.parent()?;
+    if let Some(name) = TsQualifiedName::cast_ref(&parent) {
+        if name.left().ok()?.syntax() != reference {
+            return None;
+        }
+        let member = name.right().ok()?.value_token().ok()?;
+        return Some((parent, member.text_trimmed().to_string()));
+    }
+    if let Some(name) = JsxMemberName::cast_ref(&parent) {
+        let member = name.member().ok()?.value_token().ok()?;
+        let member = member.text_trimmed();
+        // A lowercase name would be an intrinsic element, such as `<div>`
+        if !member.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return None;
+        }
+        return Some((parent, member.to_string()));
+    }
+    let object = JsIdentifierExpression::cast(parent)?;
+    let member = object.parent::<AnyJsMemberExpression>()?;
+    if member.object().ok()?.syntax() != object.syntax() {
+        return None;
+    }
+    let name = member.member_name()?.text().to_string();
+    Some((member.into_syntax(), name))
+}
+
+/// Replaces the named imports `clause` with the namespace import `* as namespace`, and the
+/// references of the imported variables with the accesses of the members of the namespace.
+///
+/// Returns `None` if an imported variable is used in another way than as an expression, a type
+/// or a JSX element name, or if `namespace` would conflict with another variable.
+pub(crate) fn to_namespace_import(
+    clause: &JsImportNamedClause,
+    namespace: &str,
+    model: &SemanticModel,
+    mutation: &mut BatchMutation<JsLanguage>,
+) -> Option<()> {
+    if !is_js_ident(namespace) || JsSyntaxKind::from_keyword(namespace).is_some() {
+        return None;
+    }
+    let is_bound = model.all_bindings().any(|binding| {
+        binding
+            .tree()
+            .name_token()
+            .is_ok_and(|token| token.text_trimmed() == namespace)
+    });
+    if is_bound || unresolved_names(model).contains(namespace) {
+        return None;
+    }
+
+    for specifier in clause.named_specifiers().ok()?.specifiers() {
+        let specifier = specifier.ok()?;
+        let member = match &specifier {
+            AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                inner_string_text(&specifier.name().ok()?.value().ok()?)
+            }
+            AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => {
+                specifier.imported_name()?.token_text_trimmed()
+            }
+            AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+        };
+        let member = member.text();
+        if !is_js_ident(member) {
+            return None;
+        }
+        let local_name = specifier.local_name()?;
+        let identifier = local_name.as_js_identifier_binding()?;
+        for reference in identifier.all_references(model) {
+            let reference = reference.syntax();
+            let new_node = if JsxReferenceIdentifier::can_cast(reference.kind()) {
+                make::jsx_member_name(
+                    make::jsx_reference_identifier(make::jsx_ident(namespace)).into(),
+                    make::token(T![.]),
+                    make::js_name(make::ident(member)),
+                )
+                .into_syntax()
+            } else {
+                JsReferenceIdentifier::cast_ref(reference)?;
+                let parent = reference.parent()?;
+                match parent.kind() {
+                    JsSyntaxKind::JS_IDENTIFIER_EXPRESSION => {
+                        let new_node = make::js_static_member_expression(
+                            AnyJsExpression::from(make::js_identifier_expression(
+                                make::js_reference_identifier(make::ident(namespace)),
+                            )),
+                            make::token(T![.]),
+                            make::js_name(make::ident(member)).into(),
+                        );
+                        mutation.replace_element(parent.into(), new_node.into_syntax().into());
+                        continue;
+                    }
+                    JsSyntaxKind::TS_REFERENCE_TYPE
+                    | JsSyntaxKind::TS_TYPEOF_TYPE
+                    | JsSyntaxKind::TS_QUALIFIED_NAME => make::ts_qualified_name(
+                        make::js_reference_identifier(make::ident(namespace)).into(),
+                        make::token(T![.]),
+                        make::js_name(make::ident(member)),
+                    )
+                    .into_syntax(),
+                    _ => return None,
+                }
+            };
+            mutation.replace_element(reference.clone().into(), new_node.into());
+        }
+    }
+
+    let namespace_specifier = make::js_namespace_import_specifier(
+        make::token(T![*]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::token(T![as]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyJsBinding::from(make::js_identifier_binding(
+            make::ident(namespace).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )),
+    );
+    let mut import_namespace_clause = make::js_import_namespace_clause(
+        namespace_specifier,
+        clause.from_token().ok()?,
+        clause.source().ok()?,
+    );
+    if let Some(assertion) = clause.assertion() {
+        import_namespace_clause = import_namespace_clause.with_assertion(assertion);
+    }
+    mutation.replace_node(
+        AnyJsImportClause::from(clause.clone()),
+        import_namespace_clause.build().into(),
+    );
+    Some(())
+}
+
+/// Returns the names of the references to global variables
+fn unresolved_names(model: &SemanticModel) -> FxHashSet<String> {
+    model
+        .all_unresolved_references()
+        .filter_map(|reference| reference.tree().value_token().ok())
+        .map(|token| token.text_trimmed().to_string())
+        .collect()
+}
@@ -4,10 +4,11 @@ use biome_diagnostics::{DiagnosticExt, Severity};
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::JsFileSource;
 use biome_test_utils::{
-    code_fix_to_string, create_analyzer_options, diagnostic_to_string, load_manifest,
-    parse_test_path, scripts_from_json,
+    code_fix_to_string, create_analyzer_options, diagnostic_to_string, load_browser_targets,
+    load_manifest, parse_test_path, scripts_from_json,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 // use this test check if your snippet produces the diagnostics you wish, without using a snapshot
@@ -70,10 +71,18 @@ fn analyze(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
-    let manifest = load_manifest(input_file, &mut diagnostics);
+    let manifest = load_manifest(input_file, &mut diagnostics).map(Arc::new);
+    let browser_targets = load_browser_targets(input_file);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        browser_targets,
+        None,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
@@ -89,7 +98,8 @@ fn analyze(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -7,10 +7,11 @@ use biome_project::PackageType;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
-    scripts_from_json, write_analyzer_snapshot, CheckActionType,
+    has_bogus_nodes_or_empty_slots, load_browser_targets, load_manifest, parse_test_path,
+    register_leak_checker, scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,cts,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
@@ -104,7 +105,8 @@ pub(crate) fn analyze_and_snap(
 ) -> usize {
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
-    let manifest = load_manifest(input_file, &mut diagnostics);
+    let manifest = load_manifest(input_file, &mut diagnostics).map(Arc::new);
+    let browser_targets = load_browser_targets(input_file);
 
     if let Some(manifest) = &manifest {
         if manifest.r#type == Some(PackageType::Commonjs) &&
@@ -120,8 +122,15 @@ pub(crate) fn analyze_and_snap(
     //
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        browser_targets,
+        None,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -177,7 +186,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -0,0 +1,10 @@
+class X0 {
+	static {}
+}
+
+class X1 {
+	static accessor foo = false;
+	static get bar() { return 0; }
+	static set bar(value) {}
+	static {}
+}
@@ -0,0 +1,17 @@
+class X0 {
+	static {}
+
+	constructor() {}
+}
+
+class X1 {
+	static accessor foo = false;
+
+	accessor bar = false;
+}
+
+class X2 {
+	static get foo() { return 0; }
+
+	set foo(value) {}
+}
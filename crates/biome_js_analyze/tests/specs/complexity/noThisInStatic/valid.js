@@ -2,4 +2,7 @@ function foo() { this }
 () => { this }
 class A { constructor() { this } }
 class A { foo() { this } }
-class A { static foo() { function foo() { this } } }
\ No newline at end of file
+class A { static foo() { function foo() { this } } }
+class A { foo() { class B { static [this.key]() {} } } }
+class A { foo() { class B { @decorator(this) static bar = 0 } } }
+class A { static foo = function () { this } }
\ No newline at end of file
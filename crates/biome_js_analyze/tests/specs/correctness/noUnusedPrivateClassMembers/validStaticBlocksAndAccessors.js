@@ -0,0 +1,16 @@
+class UsedInStaticBlock {
+	static #count = 0;
+
+	static {
+		UsedInStaticBlock.#count += 1;
+		console.log(UsedInStaticBlock.#count);
+	}
+}
+
+class UsedAccessor {
+	accessor #value = 0;
+
+	method() {
+		return this.#value;
+	}
+}
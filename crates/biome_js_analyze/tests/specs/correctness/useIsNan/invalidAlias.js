@@ -0,0 +1,7 @@
+const nan = NaN;
+const alias = nan;
+const numberNaN = Number.NaN;
+value === nan;
+value !== alias;
+switch (value) { case numberNaN: break; }
+arr.indexOf(numberNaN);
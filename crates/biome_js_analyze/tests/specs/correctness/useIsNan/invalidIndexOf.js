@@ -0,0 +1,6 @@
+arr.indexOf(NaN);
+arr.lastIndexOf(NaN);
+arr.indexOf(Number.NaN);
+arr.indexOf(NaN, 1);
+arr["indexOf"](NaN);
+(arr.lastIndexOf)(Number.NaN);
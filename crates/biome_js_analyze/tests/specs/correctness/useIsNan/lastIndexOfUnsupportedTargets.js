@@ -0,0 +1,2 @@
+arr.indexOf(NaN);
+arr.lastIndexOf(NaN);
@@ -50,3 +50,20 @@ switch(foo) { case 'Number.NaN': break }
 switch(foo) { case foo(Number.NaN): break }
 switch(foo) { case foo.Number.NaN: break }
 
+// indexOf / includes
+arr.includes(NaN);
+arr.includes(Number.NaN);
+arr.indexOf(foo);
+arr.indexOf(NaN, 0, extra);
+arr.findIndex(Number.isNaN);
+
+// enforceForIndexOf is disabled
+arr.indexOf(NaN);
+arr.lastIndexOf(Number.NaN);
+
+// aliases
+let mutable = NaN;
+value === mutable;
+const { NaN: destructured } = Number;
+value === destructured;
+function f(NaN) { return value === NaN; }
@@ -0,0 +1,7 @@
+// The argument of the string methods is converted to a string
+"NaN".indexOf(NaN);
+'NaN'.lastIndexOf(NaN);
+`NaN`.indexOf(Number.NaN);
+(`${prefix}NaN`).lastIndexOf(NaN);
+arr.includes(NaN);
+arr.indexOf(foo);
@@ -0,0 +1,73 @@
+async function forOf(urls) {
+	for (const url of urls) {
+		await fetch(url);
+	}
+}
+
+async function forOfWithLocals(users) {
+	for (const { id, name } of users) {
+		const profile = await fetchProfile(id);
+		console.log(name, profile);
+	}
+}
+
+async function forOfStatementBody(items) {
+	for (let item of items) await save(item);
+}
+
+async function forOfWithContinue(items) {
+	for (const item of items) {
+		if (!item) {
+			continue;
+		}
+		await save(item);
+	}
+}
+
+async function forOfWithNestedBreak(items) {
+	for (const item of items) {
+		switch (item.kind) {
+			case "a":
+				await save(item);
+				break;
+		}
+	}
+}
+
+async function forIn(object) {
+	for (const key in object) {
+		await save(key, object[key]);
+	}
+}
+
+async function forLoop(ids) {
+	for (let i = 0; i < ids.length; i++) {
+		await remove(ids[i]);
+	}
+}
+
+async function nestedLoops(groups) {
+	for (const group of groups) {
+		for (const item of group) {
+			await save(item);
+		}
+	}
+}
+
+async function iterableMember(object) {
+	for (const item of object.items) {
+		await save(item);
+	}
+}
+
+async function varLoop(items) {
+	for (var item of items) {
+		await save(item);
+	}
+}
+
+async function iterableNeedingParentheses(a, b) {
+	for (const item of a || b) {
+		await save(item);
+	}
+}
@@ -0,0 +1,114 @@
+async function concurrent(urls) {
+	await Promise.all(urls.map(async (url) => {
+		await fetch(url);
+	}));
+}
+
+async function accumulate(urls) {
+	let total = 0;
+	for (const url of urls) {
+		total += await fetchCount(url);
+	}
+	return total;
+}
+
+async function returnEarly(urls) {
+	for (const url of urls) {
+		if (await exists(url)) {
+			return url;
+		}
+	}
+}
+
+async function breakEarly(urls) {
+	for (const url of urls) {
+		if (await exists(url)) {
+			break;
+		}
+	}
+}
+
+async function retry() {
+	for (let attempt = 0; attempt < 3; attempt++) {
+		try {
+			return await request();
+		} catch {}
+	}
+}
+
+async function writeCounter(ids) {
+	for (let i = 0; i < ids.length; i++) {
+		await remove(ids[i]);
+		i++;
+	}
+}
+
+async function closureWrite(items) {
+	let last;
+	for (const item of items) {
+		await save(item, () => {
+			last = item;
+		});
+	}
+}
+
+async function varInBody(items) {
+	for (const item of items) {
+		var previous = await save(item, previous);
+	}
+}
+
+async function globalWrite(items) {
+	for (const item of items) {
+		current = await save(item);
+	}
+}
+
+async function forAwait(stream) {
+	for await (const chunk of stream) {
+		await save(chunk);
+	}
+}
+
+async function poll() {
+	for (;;) {
+		await sleep(1000);
+		check();
+	}
+}
+
+async function whileLoop(queue) {
+	while (queue.length > 0) {
+		await save(queue.pop());
+	}
+}
+
+async function nestedFunction(items) {
+	for (const item of items) {
+		items.forEach(async (other) => {
+			await save(item, other);
+		});
+	}
+}
+
+async function awaitInHead(source) {
+	for (const item of await source()) {
+		save(item);
+	}
+}
+
+async function labeled(groups) {
+	outer: for (const group of groups) {
+		for (const item of group) {
+			if (await exists(item)) {
+				continue outer;
+			}
+		}
+	}
+}
+
+async function* generator(items) {
+	for (const item of items) {
+		yield await save(item);
+	}
+}
@@ -0,0 +1,6 @@
+import * as foo from "foo";
+
+foo["bar"];
+foo.baz;
+foo["bar"]();
+const value = foo?.["qux"] + 1;
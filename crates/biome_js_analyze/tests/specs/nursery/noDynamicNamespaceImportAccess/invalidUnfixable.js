@@ -0,0 +1,26 @@
+import * as a from "a";
+import * as b from "b";
+import * as c from "c";
+import * as d from "d";
+import * as e from "e";
+
+// Dynamic key
+a["bar"];
+a[key];
+
+// Shadowed name
+b["bar"];
+function f(bar) {
+	return b["bar"];
+}
+
+// Global variable with the same name
+c["console"];
+console.log();
+
+// Reserved word
+d["default"];
+
+// Namespace used as a value
+e["bar"];
+use(e);
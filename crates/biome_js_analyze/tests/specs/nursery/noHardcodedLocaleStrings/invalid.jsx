@@ -0,0 +1,19 @@
+<h1>Welcome</h1>;
+
+<p>
+	Your order has been shipped.
+</p>;
+
+<input placeholder="Your name" />;
+<img alt="Company logo" src="logo.png" />;
+<button title={"Close the dialog"} />;
+<button aria-label={open ? "Close" : "Open"} />;
+
+<p>{"Loading"}</p>;
+<p>{loading && "Loading"}</p>;
+<p>{`Hello ${name}`}</p>;
+<p>{"Hello " + name}</p>;
+
+alert("The file was saved.");
+confirm(`Delete ${count} files?`);
+prompt(saved ? "Saved" : "Not saved");
@@ -0,0 +1,4 @@
+<input label="Search" placeholder="Search the docs" />;
+toast.error("Something went wrong");
+alert("Not reported anymore");
+intl.formatMessage("Not reported either");
@@ -0,0 +1,23 @@
+import { t as translate } from "i18next";
+
+<h1>{t("welcome")}</h1>;
+<h1>{translate("welcome")}</h1>;
+<input placeholder={i18n.t("name.placeholder")} type="text" />;
+<p>{t(loading ? "loading" : "ready")}</p>;
+
+// Strings without letters
+<p>-</p>;
+<p>{"42"}</p>;
+<span>{count} / {total}</span>;
+
+// Attributes that aren't displayed
+<div className="container" id="main" data-testid="root" />;
+<a href="https://biomejs.dev">{t("docs")}</a>;
+
+// Strings that aren't displayed
+const label = "Welcome";
+console.log("The file was saved.");
+<p>{state === "loading" ? t("loading") : null}</p>;
+<p>{styled`color: red;`}</p>;
+
+alert(t("saved"));
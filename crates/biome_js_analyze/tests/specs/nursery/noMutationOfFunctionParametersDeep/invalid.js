@@ -0,0 +1,41 @@
+function addItem(list, item) {
+    list.push(item);
+}
+
+function enable(settings) {
+    settings.flags.enabled = true;
+    settings["count"]++;
+}
+
+function reset(state) {
+    delete state.value;
+}
+
+function merge(target, source) {
+    Object.assign(target, source);
+}
+
+const sortInPlace = (values) => values.sort();
+
+const clear = map => map.clear();
+
+function update({ items }) {
+    items.pop();
+}
+
+function withAlias(state) {
+    const current = state;
+    current.value = 1;
+}
+
+function withNestedAlias(config) {
+    const { options } = config;
+    const flags = options.flags;
+    flags.debug = true;
+}
+
+class Store {
+    constructor(data) {
+        data.initialized = true;
+    }
+}
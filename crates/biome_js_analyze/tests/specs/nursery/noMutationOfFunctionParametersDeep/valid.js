@@ -0,0 +1,40 @@
+function addItem(list, item) {
+    return [...list, item];
+}
+
+function enable(settings) {
+    return { ...settings, flags: { ...settings.flags, enabled: true } };
+}
+
+function sortCopy(values) {
+    const sorted = values.slice();
+    sorted.sort();
+    return sorted;
+}
+
+function merge(target) {
+    return Object.assign({}, target);
+}
+
+function read(state) {
+    return state.value + state.items.length;
+}
+
+function setKey(key) {
+    const copy = {};
+    copy[key] = 1;
+    return copy;
+}
+
+function shadowed(Object, target) {
+    Object.assign(target, {});
+}
+
+function local() {
+    const list = [];
+    list.push(1);
+}
+
+function reassign(value) {
+    value = 1;
+}
@@ -0,0 +1,15 @@
+const last = items.findLast((item) => item.done);
+const index = items.findLastIndex((item) => item.done);
+const sorted = items.toSorted();
+const reversed = items["toReversed"]();
+const spliced = items.toSpliced(0, 1);
+const text = value.replaceAll("a", "b");
+const groups = Object.groupBy(items, (item) => item.kind);
+const map = Map.groupBy(items, (item) => item.kind);
+const hasName = Object.hasOwn(value, "name");
+const first = await Promise.any(promises);
+const { promise, resolve } = Promise.withResolvers();
+const array = await Array.fromAsync(iterable);
+const copy = structuredClone(value);
+const copy2 = globalThis.structuredClone(value);
+const hasName2 = window.Object.hasOwn(value, "name");
@@ -0,0 +1,3 @@
+const copy = structuredClone(value);
+const hasName = Object.hasOwn(value, "name");
+const text = value.replaceAll("a", "b");
@@ -0,0 +1,22 @@
+// The default targets support these APIs
+const last = items.findLast((item) => item.done);
+const text = value.replaceAll("a", "b");
+const hasName = Object.hasOwn(value, "name");
+const first = await Promise.any(promises);
+const copy = structuredClone(value);
+const element = items.at(-1);
+
+// The APIs aren't called
+const groupBy = Object.groupBy ?? groupByPolyfill;
+if (typeof Promise.withResolvers === "function") {}
+const sorted = items.toSorted?.();
+
+// The globals are shadowed
+function f(Object, structuredClone) {
+	Object.groupBy(items, (item) => item.kind);
+	structuredClone(value);
+}
+
+// Unrelated methods
+items.toSorting();
+Reflect.groupBy(items);
@@ -0,0 +1,13 @@
+export const timeout: number = 1000;
+
+export class Clock {
+	start: Date = new Date();
+
+	tick(step: number = 1) {
+		const next: number = 0;
+	}
+}
+
+export default function format(unit: string = "px") {}
+
+const local: number = 5;
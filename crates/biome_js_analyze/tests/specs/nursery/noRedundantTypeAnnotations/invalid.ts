@@ -0,0 +1,33 @@
+const count: number = 5;
+let name: string = "a";
+var enabled: boolean = true;
+const big: bigint = 10n;
+const negative: number = -1;
+const negativeBig: bigint = -1n;
+const label: string = `label`;
+const isEmpty: boolean = !name;
+const kind: string = typeof name;
+const wrapped: (number) = (5);
+
+class Point {
+	x: number = 0;
+	readonly origin: boolean = false;
+}
+
+function scale(factor: number = 1, unit: string = "px") {}
+
+const scores: Map<string, number> = new Map<string, number>();
+const ids: Set<string> = new Set<string>([]);
+const start: Date = new Date();
+const url: URL = new URL("https://biomejs.dev");
+
+class Service {}
+const service: Service = new Service();
+
+class Cache {
+	entries: Map<string, Service> = new Map<string, Service>();
+}
+
+export const timeout: number = 1000;
+
+const commented /* before */: /* type */ number /* after */ = 5;
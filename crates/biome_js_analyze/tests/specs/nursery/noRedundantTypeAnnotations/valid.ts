@@ -0,0 +1,23 @@
+const count = 5;
+let value: number | undefined = 5;
+let literal: 1 | 2 = 1;
+const pattern: RegExp = /a/;
+const nothing: null = null;
+const tagged: string = String.raw`a`;
+const negated: number = -value;
+
+const scores: Map<string, number> = new Map();
+const ids: ReadonlySet<string> = new Set<string>();
+const other: Map<string, string> = new Map<string, number>();
+
+class Box<T> {
+	constructor(readonly value?: T) {}
+}
+const box: Box<number> = new Box(1);
+
+class Base {}
+class Derived extends Base {}
+const base: Base = new Derived();
+
+import { Service } from "./service";
+const service: Service = new Service();
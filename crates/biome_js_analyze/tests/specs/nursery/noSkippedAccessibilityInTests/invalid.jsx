@@ -0,0 +1,21 @@
+import { render, screen, within } from "@testing-library/react";
+
+test("submits the form", async () => {
+	screen.getByTestId("submit");
+	screen.queryAllByTestId("row");
+	await screen.findByTestId("dialog");
+});
+
+test("renders the list", () => {
+	const { container, getByTestId } = render(<List />);
+	getByTestId("list");
+	container.querySelector("li");
+	container.querySelectorAll("li");
+});
+
+test("renders the menu", () => {
+	const view = render(<Menu />);
+	view.getAllByTestId("item");
+	view.container.querySelector(".menu");
+	within(view.getByRole("menu")).getByTestId("first");
+});
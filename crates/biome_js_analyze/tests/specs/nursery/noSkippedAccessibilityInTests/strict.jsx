@@ -0,0 +1,10 @@
+import { render, screen } from "@testing-library/react";
+
+test("renders the form", () => {
+	const { getByPlaceholderText } = render(<Form />);
+	screen.getByText("Welcome");
+	screen.queryAllByLabelText("Email");
+	getByPlaceholderText("Search");
+	screen.getByRole("button");
+	screen.getByTestId("submit");
+});
@@ -0,0 +1,22 @@
+import { render, screen, within } from "@testing-library/react";
+import { getByTestId } from "./helpers";
+
+test("submits the form", async () => {
+	screen.getByRole("button", { name: "Submit" });
+	screen.getByLabelText("Email");
+	await screen.findByText("Sent");
+});
+
+test("renders the list", () => {
+	const { getAllByRole } = render(<List />);
+	getAllByRole("listitem");
+	within(screen.getByRole("list")).getByText("First");
+});
+
+// These queries don't come from Testing Library
+getByTestId("list");
+document.querySelector("li");
+const container = document.body;
+container.querySelector("li");
+const page = { getByTestId() {} };
+page.getByTestId("list");
@@ -0,0 +1,28 @@
+const name = JSON.parse(text).user.name;
+const first = JSON.parse(text)[0];
+JSON.parse(text)?.save();
+window.JSON.parse(text).user;
+(JSON.parse(text)).user;
+
+const { user } = JSON.parse(text);
+const [head] = JSON.parse(text);
+let settings;
+({ settings } = JSON.parse(text));
+
+function load(text) {
+	try {
+		prepare();
+	} catch {
+		return JSON.parse(text).fallback;
+	}
+}
+
+try {
+	handle(() => JSON.parse(text).user);
+} catch {}
+
+try {
+	JSON.parse(text).user;
+} finally {
+	cleanup();
+}
@@ -0,0 +1,3 @@
+const user = (JSON.parse(text) as User).name;
+const { name } = JSON.parse(text) satisfies unknown;
+const id = JSON.parse(text)!.id;
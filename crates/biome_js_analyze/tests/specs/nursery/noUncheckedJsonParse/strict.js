@@ -0,0 +1,25 @@
+const data = JSON.parse(text);
+console.log(data.user);
+console.log(data.user.name);
+
+const response = JSON.parse(body);
+const { status } = response;
+
+function read(text) {
+	const value = JSON.parse(text);
+	return () => value.items;
+}
+
+const user = JSON.parse(text);
+if (isUser(user)) {
+	console.log(user.name);
+}
+
+const settings = JSON.parse(text);
+try {
+	apply(settings.theme);
+} catch {}
+
+let cache = JSON.parse(text);
+cache = {};
+cache.items;
@@ -0,0 +1,28 @@
+/* should not generate diagnostics */
+const config = configSchema.parse(JSON.parse(text));
+const data = JSON.parse(text);
+console.log(data.user);
+if (isUser(JSON.parse(text))) {
+}
+const values = Object.keys(JSON.parse(text));
+
+try {
+	const { user } = JSON.parse(text);
+	const name = JSON.parse(text).name;
+} catch {
+	console.error("Invalid user");
+}
+
+try {
+	load(JSON.parse(text).user);
+} catch {
+	report();
+} finally {
+	cleanup();
+}
+
+{
+	// Another JSON object
+	const JSON = { parse: (text) => ({ text }) };
+	JSON.parse(text).text;
+}
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+import JSON from "json5";
+
+const { user } = JSON.parse(text);
+const name = JSON.parse(text).user.name;
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+const { user } = YAML.parse(text);
+const name = JSON5.parse(text).user.name;
+const raw = JSON.rawJSON(text).rawJSON;
+const id = parse(text).id;
@@ -0,0 +1,4 @@
+export * from "./testing/utils.js";
+export * from "./testing/public/utils.js";
+import "./__tests__/helpers.js";
+import { server } from "./testing/server.js";
@@ -0,0 +1,3 @@
+import { createUser } from "./__tests__/factories.js";
+import { server } from "./mocks/server.spec.js";
+export * from "./__mocks__/api.js";
@@ -0,0 +1,4 @@
+export * from "./__tests__/helpers.js";
+export { render } from "./render.test.js";
+export * as mocks from "./__mocks__/api.js";
+export * from "./components.js";
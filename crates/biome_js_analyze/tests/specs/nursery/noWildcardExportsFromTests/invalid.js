@@ -0,0 +1,6 @@
+import { createUser } from "./__tests__/factories.js";
+import "./setup.test.js";
+import * as mocks from "./__mocks__/api.js";
+export { render } from "./test/render.js";
+const { server } = require("./mocks/server.spec.js");
+const fixtures = await import("./tests/fixtures.js");
@@ -0,0 +1,7 @@
+import { createUser } from "./factories.js";
+import { describe } from "vitest";
+import { latest } from "./contest.js";
+import data from "./testing-data.js";
+import "./styles.css";
+export * from "./utils.js";
+const { server } = require("./mocks/server.js");
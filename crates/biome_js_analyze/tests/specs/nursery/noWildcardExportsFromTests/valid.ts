@@ -0,0 +1,3 @@
+import type { Fixture } from "./__tests__/fixtures.js";
+export type { Server } from "./mocks/server.test.js";
+export type * from "./setup.test.js";
@@ -0,0 +1,14 @@
+class Counter {
+	get count() {
+		return this.total;
+	}
+
+	set label(value) {
+		this.text = value;
+	}
+
+	reset() {
+		this.count = 0;
+		return this.label;
+	}
+}
@@ -0,0 +1,61 @@
+class Temperature {
+	set celsius(value) {
+		this.kelvin = value + 273.15;
+	}
+
+	toString() {
+		return `${this.celsius}°C`;
+	}
+}
+
+class Counter {
+	get count() {
+		return this.total;
+	}
+
+	increment() {
+		this.count += 1;
+	}
+
+	reset() {
+		this.count = 0;
+	}
+}
+
+class Registry {
+	static set entry(value) {
+		Registry.entries.push(value);
+	}
+
+	static {
+		console.log(this.entry);
+	}
+}
+
+class Secret {
+	set #value(value) {
+		this.hash = hash(value);
+	}
+
+	reveal() {
+		return this.#value;
+	}
+}
+
+const counter = {
+	count: 0,
+	get next() {
+		return this.count + 1;
+	},
+	advance() {
+		this.next++;
+	},
+};
+counter.next = 2;
+
+let logger = {
+	set level(value) {
+		this.threshold = value;
+	},
+};
+console.log(logger.level);
@@ -0,0 +1,95 @@
+class Temperature {
+	get celsius() {
+		return this.kelvin - 273.15;
+	}
+
+	set celsius(value) {
+		this.kelvin = value + 273.15;
+	}
+
+	toString() {
+		return `${this.celsius}°C`;
+	}
+}
+
+// The accessors are never used through `this`
+class Settings {
+	set theme(value) {
+		this.current = value;
+	}
+
+	get version() {
+		return 1;
+	}
+}
+
+// `this` is rebound by regular functions, nested classes and object methods
+class Widget {
+	set size(value) {
+		this.width = value;
+	}
+
+	render() {
+		function draw() {
+			return this.size;
+		}
+		const child = {
+			get size() {
+				return 0;
+			},
+			measure() {
+				return this.size;
+			},
+		};
+		class Inner {
+			size = 1;
+			area() {
+				return this.size;
+			}
+		}
+	}
+}
+
+// Static and instance members are distinct properties
+class Pool {
+	static get size() {
+		return 10;
+	}
+
+	set size(value) {
+		this.capacity = value;
+	}
+
+	grow() {
+		Pool.size;
+		this.capacity = this.capacity * 2;
+	}
+}
+
+// Properties that are also declared as fields or methods
+class Model {
+	name = "";
+
+	set name(value) {}
+
+	describe() {
+		return this.name;
+	}
+}
+
+const counter = {
+	count: 0,
+	get next() {
+		return this.count + 1;
+	},
+};
+counter.count = counter.next;
+
+const config = {
+	set mode(value) {
+		this.current = value;
+	},
+	current: "dark",
+};
+config.mode = "light";
+other.mode;
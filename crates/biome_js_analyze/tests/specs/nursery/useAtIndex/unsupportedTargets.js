@@ -0,0 +1,3 @@
+const foo = array[array.length - 1];
+const bar = string.charAt(string.length - 5);
+const baz = array.slice(-1)[0];
@@ -0,0 +1,14 @@
+class Config {
+	static declare defaults: object;
+	readonly declare name: string;
+	declare public value: number;
+}
+
+abstract class Shape {
+	abstract declare sides: number;
+}
+
+class Documented {
+	/** The height */
+	static /* first */ declare height: number;
+}
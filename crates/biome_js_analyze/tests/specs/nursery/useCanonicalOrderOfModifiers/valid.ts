@@ -0,0 +1,22 @@
+abstract class Shape {
+	public abstract area(): number;
+	protected abstract override perimeter(): number;
+}
+
+class Config {
+	@observable
+	@tracked
+	private static readonly defaults = {};
+	declare value: number;
+	public static override readonly base = 1;
+	protected static accessor count = 0;
+	static readonly [key: string]: string;
+}
+
+class Point {
+	constructor(private readonly x: number, public override y: number) {}
+}
+
+interface Named {
+	readonly name: string;
+}
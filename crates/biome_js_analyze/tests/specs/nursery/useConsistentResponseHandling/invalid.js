@@ -0,0 +1,61 @@
+async function getUser(id) {
+	const response = await fetch(`/users/${id}`);
+	return await response.json();
+}
+
+async function getPosts() {
+	const response = await window.fetch("/posts");
+	const posts = await response.json();
+	return posts;
+}
+
+async function getComments(id) {
+	const response = await fetch(`/comments/${id}`);
+	if (id === 0) {
+		return null;
+	}
+	return response.json();
+}
+
+async function checkedInOneBranch(id) {
+	const response = await fetch(`/users/${id}`);
+	if (id > 0) {
+		if (!response.ok) {
+			throw new Error("Request failed");
+		}
+	}
+	return response.json();
+}
+
+async function readBeforeCheck() {
+	const response = await fetch("/users");
+	const users = await response.json();
+	if (!response.ok) {
+		throw new Error(users.message);
+	}
+	return users;
+}
+
+async function inLoop(ids) {
+	for (const id of ids) {
+		const response = await fetch(`/users/${id}`);
+		console.log(await response.json());
+	}
+}
+
+async function direct() {
+	return (await fetch("/users")).json();
+}
+
+fetch("/users").then((response) => response.json());
+
+fetch("/users").then(function (response) {
+	console.log(response.headers.get("content-type"));
+	return response.json();
+});
+
+const load = async () => {
+	const res = await globalThis.fetch("/settings");
+	const { theme } = await res.json();
+	return theme;
+};
@@ -0,0 +1,78 @@
+async function getUser(id) {
+	const response = await fetch(`/users/${id}`);
+	if (!response.ok) {
+		throw new Error(`Request failed with status ${response.status}`);
+	}
+	return await response.json();
+}
+
+async function getPosts() {
+	const response = await fetch("/posts");
+	if (response.status === 404) {
+		return [];
+	}
+	return response.json();
+}
+
+async function checkedInBothBranches(id) {
+	const response = await fetch(`/users/${id}`);
+	if (id > 0) {
+		if (!response.ok) {
+			throw new Error("Request failed");
+		}
+	} else if (response.status !== 200) {
+		return null;
+	}
+	return response.json();
+}
+
+async function withHelper() {
+	const response = await fetch("/users");
+	assertOk(response);
+	return response.json();
+}
+
+async function conditional() {
+	const response = await fetch("/users");
+	return response.ok ? response.json() : null;
+}
+
+async function notRead() {
+	const response = await fetch("/users");
+	return response.headers.get("content-type");
+}
+
+async function text() {
+	const response = await fetch("/users");
+	return response.text();
+}
+
+async function otherClient() {
+	const response = await axios.get("/users");
+	return response.data;
+}
+
+async function notAwaited() {
+	const promise = fetch("/users");
+	return promise;
+}
+
+fetch("/users").then((response) => (response.ok ? response.json() : []));
+
+fetch("/users").then(function (response) {
+	if (!response.ok) {
+		throw new Error("Request failed");
+	}
+	return response.json();
+});
+
+fetch("/users").then(handleResponse);
+
+async function nested() {
+	const response = await fetch("/users");
+	const read = () => response.json();
+	if (!response.ok) {
+		return null;
+	}
+	return read();
+}
@@ -0,0 +1,18 @@
+import * as _ from "lodash";
+import * as dateFns from "date-fns";
+import { useEffect, useState as useLocalState } from "react";
+import { createRoot } from "react-dom/client";
+
+const items = _.map(data, toItem);
+const sorted = _["sortBy"](items, "name");
+const date = dateFns.format(new Date(), "yyyy-MM-dd");
+
+function App() {
+	const [count, setCount] = useLocalState(0);
+	useEffect(() => {
+		document.title = `${count}`;
+	}, [count]);
+	return null;
+}
+
+createRoot(document.getElementById("root"));
@@ -0,0 +1,13 @@
+import * as _ from "lodash";
+import { Fragment, type FC, useState } from "react";
+
+type Mapper = _.ListIterator<string, number>;
+
+const App: FC = () => {
+	const [value] = useState<typeof Fragment | null>(null);
+	return (
+		<Fragment>
+			{_.map(items, String)}
+		</Fragment>
+	);
+};
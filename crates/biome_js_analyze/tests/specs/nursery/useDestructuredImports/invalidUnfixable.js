@@ -0,0 +1,17 @@
+// The namespace is used as a value
+import * as _ from "lodash";
+console.log(_);
+
+// The imported name conflicts with a variable
+import * as dateFns from "date-fns";
+const format = "yyyy-MM-dd";
+dateFns.format(new Date(), format);
+
+// The namespace conflicts with a variable
+import { useState } from "react";
+const React = {};
+useState(0);
+
+// The imported variable is exported
+import { createRoot } from "react-dom/client";
+export { createRoot };
@@ -0,0 +1,9 @@
+import { map, sortBy } from "lodash";
+import * as React from "react";
+import React2, * as ReactNamespace from "react";
+import type { FC } from "react";
+import * as other from "other";
+import { format } from "date-fns";
+
+map(items, toItem);
+React.useState(0);
@@ -0,0 +1,6 @@
+export let count = 0;
+export function increment() {
+	count++;
+	count += 2;
+	count = 0;
+}
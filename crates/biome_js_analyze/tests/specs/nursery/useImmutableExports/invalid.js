@@ -0,0 +1,30 @@
+export let user = null;
+
+export function login(name) {
+	user = { name };
+}
+
+let ready = false;
+setTimeout(() => {
+	ready = true;
+});
+export { ready };
+
+export var count = 0;
+export const increment = function () {
+	count += 1;
+	count++;
+};
+
+let current = "idle";
+export { current as status };
+export class Machine {
+	start() {
+		current = "running";
+	}
+}
+
+export let [first, second] = [1, 2];
+export function swap() {
+	[first, second] = [second, first];
+}
@@ -0,0 +1,46 @@
+// Reassigned while the module is initialized
+let mode = "production";
+if (globalThis.DEBUG) {
+	mode = "development";
+}
+export { mode };
+
+// Not exported
+let user = null;
+export function login(name) {
+	user = { name };
+}
+export function currentUser() {
+	return user;
+}
+
+// Not a live binding
+let value = 0;
+export default value;
+export function update() {
+	value = 1;
+}
+
+// Constant
+export const config = {};
+export function configure() {
+	config.enabled = true;
+}
+
+// Shadowed
+export let total = 0;
+export function sum(values) {
+	let total = 0;
+	for (const value of values) {
+		total += value;
+	}
+	return total;
+}
+
+// Static initialization block
+export let registry = null;
+class Registry {
+	static {
+		registry = new Registry();
+	}
+}
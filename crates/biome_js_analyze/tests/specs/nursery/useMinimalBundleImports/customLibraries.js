@@ -0,0 +1,3 @@
+import { map } from "lodash-es";
+import { AccessAlarm, Delete } from "@mui/icons-material";
+import { debounce } from "lodash";
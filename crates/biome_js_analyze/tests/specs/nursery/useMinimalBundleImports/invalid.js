@@ -0,0 +1,8 @@
+import { debounce, throttle as limit } from "lodash";
+import { format, parseISO } from "date-fns"; // dates
+import { map, filter } from "rxjs/operators"
+import { "isEmpty" as empty } from "lodash";
+import { default as lodash } from "lodash";
+import _ from "lodash";
+import * as fns from "date-fns";
+import _2, { pick } from "lodash";
@@ -0,0 +1 @@
+import { type DebouncedFunc, merge } from "lodash";
@@ -0,0 +1,7 @@
+import debounce from "lodash/debounce";
+import { format } from "date-fns/format";
+import { map } from "rxjs/operators/map";
+import { map as lodashMap } from "lodash-es";
+import "lodash";
+import {} from "lodash";
+export { debounce } from "lodash";
@@ -0,0 +1,2 @@
+import type { DebouncedFunc } from "lodash";
+import { type Locale } from "date-fns";
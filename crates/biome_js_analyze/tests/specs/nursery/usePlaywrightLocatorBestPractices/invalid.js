@@ -0,0 +1,11 @@
+import { expect, test } from "@playwright/test";
+
+test("submits the form", async ({ page }) => {
+	const button = await page.$("button");
+	const rows = await page.$$("tr");
+	await page.waitForTimeout(1000);
+	expect(page.getByRole("dialog")).toBeVisible();
+	expect(page.getByRole("alert")).not.toHaveText("Error");
+	expect.soft(page.getByRole("status")).toHaveCount(1);
+	expect.poll(() => rows.length).toBe(3);
+});
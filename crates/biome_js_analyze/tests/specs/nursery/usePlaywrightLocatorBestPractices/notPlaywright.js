@@ -0,0 +1,7 @@
+// The file doesn't import `@playwright/test`
+import { expect } from "./expect";
+
+const element = $(".item");
+const items = page.$$(".item");
+page.waitForTimeout(1000);
+expect(element).toBeVisible();
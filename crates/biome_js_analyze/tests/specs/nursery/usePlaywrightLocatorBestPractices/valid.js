@@ -0,0 +1,22 @@
+import { expect, test } from "@playwright/test";
+
+test("submits the form", async ({ page }) => {
+	await page.locator("button").click();
+	await page.getByRole("button").click();
+	await expect(page.getByRole("dialog")).toBeVisible();
+	await expect(page.getByRole("alert")).not.toHaveText("Error");
+	await expect.soft(page.getByRole("status")).toHaveCount(1);
+	await expect.poll(() => fetchCount()).toBe(3);
+	await Promise.all([
+		expect(page.getByRole("heading")).toBeVisible(),
+		expect(page.getByRole("main")).toBeVisible(),
+	]);
+	expect(await page.title()).toBe("Home");
+	expect([1, 2]).toHaveLength(2);
+});
+
+test("returns the assertion", ({ page }) => expect(page.getByRole("main")).toBeVisible());
+
+test("returns the assertion", ({ page }) => {
+	return expect(page.getByRole("main")).toBeVisible();
+});
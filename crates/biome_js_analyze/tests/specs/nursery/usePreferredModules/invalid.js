@@ -0,0 +1,7 @@
+import _ from "lodash";
+import lodash, { map } from "lodash";
+import { readFile } from "fs";
+import * as fs from "fs";
+export { filter } from "lodash";
+const moment = require("moment");
+const lazy = await import("lodash");
@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+import * as _ from "lodash-es";
+import map from "lodash/map";
+import { readFile } from "node:fs/promises";
+import dayjs from "dayjs";
+const path = require("path");
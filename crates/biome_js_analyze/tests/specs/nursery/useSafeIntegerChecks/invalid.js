@@ -0,0 +1,9 @@
+if (id === 9007199254740993) {}
+if (9007199254740992 != id) {}
+const next = 18014398509481984 + 1;
+const previous = id - (-9007199254740993);
+const hex = id * 0x20000000000001;
+const separated = id % 9_007_199_254_740_993;
+const parsed = parseInt("12345678901234567890");
+const parsedWithRadix = Number.parseInt("-12345678901234567890", 10);
+const parsedFromWindow = window.parseInt(`90071992547409930`);
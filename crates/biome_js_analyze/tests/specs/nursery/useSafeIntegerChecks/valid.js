@@ -0,0 +1,15 @@
+if (id === 9007199254740993n) {}
+if (id === 9007199254740991) {}
+const max = Number.MAX_SAFE_INTEGER - 1;
+const float = id * 1e21;
+const fraction = id + 12345678901234567890.5;
+const compared = id > 9007199254740993;
+const string = id === "9007199254740993";
+const parsed = BigInt("12345678901234567890");
+const small = parseInt("9007199254740991");
+const hexadecimal = parseInt("12345678901234567890", 16);
+const text = parseInt("12345678901234567890px");
+
+function shadowed(parseInt) {
+	return parseInt("12345678901234567890");
+}
@@ -0,0 +1,8 @@
+import { parseInt } from "./numbers.js";
+
+const id = parseInt("12345678901234567890");
+
+{
+	const Number = { parseInt: (value) => BigInt(value) };
+	const big = Number.parseInt("12345678901234567890");
+}
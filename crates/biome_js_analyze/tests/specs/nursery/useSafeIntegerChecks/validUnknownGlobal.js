@@ -0,0 +1,3 @@
+const id = parseBigInt("12345678901234567890");
+const big = BigNumber.parseInt("12345678901234567890");
+const parsed = window.parseBigInt("12345678901234567890");
@@ -0,0 +1,26 @@
+class Foo {
+    static a = 1;
+}
+Foo.b = 2;
+Foo["c"] = 3;
+
+export class Bar {}
+Bar.baz = () => 1;
+
+export default class Baz { static a = 1; }
+Baz.qux = "qux";
+
+function create() {
+  class Local {
+    method() {}
+  }
+  Local.instance = new Local();
+  return Local;
+}
+
+class WithThis {}
+WithThis.self = this;
+
+@decorator
+class Decorated {}
+Decorated.value = 1;
@@ -0,0 +1,20 @@
+class Foo {
+    static b = 2;
+    static {
+        Foo.c = 3;
+    }
+}
+
+class Bar {}
+register(Bar);
+Bar.baz = 1;
+
+class Baz {}
+Baz.qux += 1;
+Other.qux = 1;
+
+const Qux = class {};
+Qux.value = 1;
+
+class Quux {}
+Quux.prototype.method = function () {};
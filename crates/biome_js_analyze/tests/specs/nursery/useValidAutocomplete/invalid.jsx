@@ -5,4 +5,10 @@
 	<input type="text" autocomplete="home url" />
 	<Bar autocomplete="baz"></Bar>
 	<Input type="text" autocomplete="baz" />
+	<input type="text" autocomplete="name email" />
+	<input type="text" autocomplete="webauthn" />
+	<input type="text" autocomplete="shipping section-blue name" />
+	<textarea autocomplete="foo"></textarea>
+	<select autocomplete="email home"></select>
+	<form autocomplete="name"></form>
 </>
@@ -13,6 +13,11 @@
 	<input type="text" autocomplete={autocompl || "name"} />
 	<input type="text" autocomplete={autocompl || "foo"} />
 	<input type={isEmail ? "email" : "text"} autocomplete="none" />
+	<input type="text" autocomplete="NAME" />
+	<input type="tel" autocomplete="mobile tel-local-prefix" />
+	<input type="password" autocomplete="section-login current-password webauthn" />
+	<textarea autocomplete="street-address"></textarea>
+	<form autocomplete="off"></form>
 	<Foo autocomplete="bar"></Foo>
 	<Input type="text" autocomplete="name" />
 	<Input type="text" autocomplete="baz" />
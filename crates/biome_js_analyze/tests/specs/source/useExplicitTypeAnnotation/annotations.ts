@@ -0,0 +1,49 @@
+let count = 0;
+const message = `Hello ${name}!`;
+var enabled = !disabled;
+const scores = new Map<string, number>();
+const start = new Date();
+
+function greet(name = "world") {
+	return `Hello ${name}!`;
+}
+
+function log() {
+	console.log("log");
+}
+
+function earlyReturn(flag: boolean) {
+	if (flag) {
+		return;
+	}
+	console.log("flag");
+}
+
+function nested() {
+	const inner = () => "inner";
+	return 1;
+}
+
+const arrow = () => 1n;
+
+const createCache = async () => new Map<string, number>();
+
+async function load() {
+	return true;
+}
+
+class Counter {
+	increment(step = 1) {
+		return -1;
+	}
+}
+
+const object = {
+	method() {
+		return "method";
+	},
+};
+
+export default function () {
+	return 1;
+}
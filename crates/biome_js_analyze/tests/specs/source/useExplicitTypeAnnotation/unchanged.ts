@@ -0,0 +1,35 @@
+let annotated: number = 1;
+let unknown = getValue();
+const { a, b } = { a: 1, b: 2 };
+let nothing = null;
+
+function annotatedReturn(): number {
+	return 1;
+}
+
+function mixed(flag: boolean) {
+	if (flag) {
+		return 1;
+	}
+	return "one";
+}
+
+function fallsThrough(flag: boolean) {
+	if (flag) {
+		return 1;
+	}
+}
+
+function* generator() {
+	return 1;
+}
+
+const single = x => 1;
+
+function withAnnotatedParameter(value: string = "a") {
+	return value;
+}
+
+const fail = () => {
+	throw new Error();
+};
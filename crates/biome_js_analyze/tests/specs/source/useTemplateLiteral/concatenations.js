@@ -0,0 +1,11 @@
+const message = "Hello " + user.name + ",\n" + "you have " + count + " new messages.";
+const label = 1 + 2 + " items";
+const nested = "(" + (a + "-" + b) + ")";
+const quotes = 'It\'s ' + name + " \"quoted\"";
+const backticks = "`" + code + "`";
+const dollar = "$" + "{" + value;
+const template = `${greeting}, ` + name + "!";
+const multiline = "first line\n" +
+	"second line\n" +
+	last;
+const tagged = html`<b>` + "</b>";
@@ -0,0 +1,3 @@
+const octal = "\1" + value;
+const numbers = 1 + 2;
+const strings = "a" + "b";
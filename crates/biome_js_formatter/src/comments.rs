@@ -18,7 +18,10 @@ use biome_js_syntax::{
     JsVariableDeclarator, JsWhileStatement, TsInterfaceDeclaration, TsMappedType,
 };
 use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment_with, CommentSyntax};
+use reflow::{is_reflowable_comment, FormatReflowedComment};
+
+mod reflow;
 
 pub type JsComments = Comments<JsLanguage>;
 
@@ -33,6 +36,10 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
         comment: &SourceComment<JsLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
+        if f.options().comment_reflow().is_wrap() && is_reflowable_comment(comment) {
+            return write!(f, [FormatReflowedComment(comment)]);
+        }
+
         if is_alignable_comment(comment.piece()) {
             let mut source_offset = comment.piece().text_range().start();
 
@@ -77,7 +84,7 @@ impl CommentStyle for JsCommentStyle {
     type Language = JsLanguage;
 
     fn is_suppression(text: &str) -> bool {
-        parse_suppression_comment(text)
+        parse_suppression_comment_with(text, CommentSyntax::C_STYLE)
             .filter_map(Result::ok)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
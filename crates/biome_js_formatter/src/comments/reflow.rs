@@ -0,0 +1,291 @@
+//! Wrapping of the comments that exceed the line width, enabled by the option `commentReflow`.
+//!
+//! Only the lines that exceed the line width are wrapped: the short lines are never joined
+//! together. The lines that look preformatted, such as the code blocks and the tables, are
+//! printed as they are in the source.
+
+use crate::prelude::*;
+use biome_formatter::comments::{is_alignable_comment, CommentKind, CommentStyle, SourceComment};
+use biome_formatter::{format_args, write};
+use biome_js_syntax::JsLanguage;
+use biome_rowan::TextSize;
+use std::ops::Range;
+
+use super::JsCommentStyle;
+
+/// The prefixes of the comments that are directives for a tool, and are never wrapped.
+const DIRECTIVE_PREFIXES: [&str; 17] = [
+    "!",
+    "#",
+    "/",
+    "@",
+    "biome-ignore",
+    "c8 ",
+    "eslint",
+    "global ",
+    "globals ",
+    "istanbul ",
+    "jscs:",
+    "jshint",
+    "prettier-ignore",
+    "stylelint-",
+    "tslint:",
+    "v8 ",
+    "webpack",
+];
+
+/// Returns `true` if the lines of `comment` that exceed the line width can be wrapped.
+///
+/// The line comments and the block comments whose lines start with `*` are wrapped when they
+/// start on their own line, whether they are leading, trailing or dangling comments: all of them
+/// are formatted by [FormatJsLeadingComment](super::FormatJsLeadingComment). A line of an
+/// end-of-line comment that wraps would become a comment of the next line.
+pub(super) fn is_reflowable_comment(comment: &SourceComment<JsLanguage>) -> bool {
+    let piece = comment.piece();
+    let text = piece.text();
+
+    if !is_own_line_comment(comment)
+        || JsCommentStyle::is_suppression(text)
+        || text.contains("@license")
+        || text.contains("@preserve")
+    {
+        return false;
+    }
+
+    match comment.kind() {
+        CommentKind::Line => text
+            .strip_prefix("//")
+            .is_some_and(|content| !is_directive(content.trim_start())),
+        CommentKind::Block | CommentKind::InlineBlock => {
+            is_alignable_comment(piece)
+                && text.lines().next().is_some_and(|first_line| {
+                    !is_directive(first_line.trim_start_matches(['/', '*']).trim_start())
+                })
+        }
+    }
+}
+
+fn is_own_line_comment(comment: &SourceComment<JsLanguage>) -> bool {
+    if comment.lines_before() > 0 {
+        return true;
+    }
+
+    // The comments at the start of the file have no line break before them
+    let piece = comment.piece();
+    let token = piece.as_piece().token();
+    token.prev_token().is_none() && piece.text_range().end() <= token.text_trimmed_range().start()
+}
+
+fn is_directive(content: &str) -> bool {
+    DIRECTIVE_PREFIXES
+        .iter()
+        .any(|prefix| content.starts_with(prefix))
+}
+
+/// Formats a comment accepted by [is_reflowable_comment], and wraps its lines that exceed the
+/// line width.
+pub(super) struct FormatReflowedComment<'a>(pub(super) &'a SourceComment<JsLanguage>);
+
+impl Format<JsFormatContext> for FormatReflowedComment<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let piece = self.0.piece();
+        let comment_text = piece.text();
+        let position = piece.text_range().start();
+
+        if self.0.kind().is_line() {
+            return write!(
+                f,
+                [FormatCommentLine {
+                    text: comment_text.trim_end(),
+                    position,
+                    marker_len: 2,
+                    continuation_marker: "//",
+                }]
+            );
+        }
+
+        let mut lines = comment_text.split('\n');
+        let mut line_position = position;
+        let mut is_preformatted = false;
+
+        // SAFETY: Safe, `is_reflowable_comment` only accepts multiline block comments
+        let first_line = lines.next().unwrap();
+        let marker_len = 2 + first_line[2..]
+            .bytes()
+            .take_while(|byte| *byte == b'*')
+            .count();
+        write!(
+            f,
+            [FormatCommentLine {
+                text: first_line.trim_end(),
+                position: line_position,
+                marker_len,
+                continuation_marker: " *",
+            }]
+        )?;
+        line_position += TextSize::from(first_line.len() as u32 + 1);
+
+        for line in lines {
+            let trimmed = line.trim();
+            let trimmed_position =
+                line_position + TextSize::from((line.len() - line.trim_start().len()) as u32);
+            line_position += TextSize::from(line.len() as u32 + 1);
+
+            write!(f, [hard_line_break(), text(" ")])?;
+
+            // The content of the code blocks and of the examples of JSDoc is code
+            let content = trimmed[1..].trim_start();
+            if content.starts_with("```") || content.starts_with("~~~") {
+                is_preformatted = !is_preformatted;
+                write!(f, [dynamic_text(trimmed, trimmed_position)])?;
+                continue;
+            }
+            if content.starts_with('@') {
+                is_preformatted = content.starts_with("@example");
+            }
+
+            if is_preformatted || trimmed.ends_with("*/") {
+                write!(f, [dynamic_text(trimmed, trimmed_position)])?;
+            } else {
+                write!(
+                    f,
+                    [FormatCommentLine {
+                        text: trimmed,
+                        position: trimmed_position,
+                        marker_len: 1,
+                        continuation_marker: " *",
+                    }]
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A line of a comment, whose words wrap when the line exceeds the line width.
+struct FormatCommentLine<'a> {
+    /// The text of the line, without its indentation
+    text: &'a str,
+    /// The position of the line in the source
+    position: TextSize,
+    /// The length of the comment marker that starts the line, such as `//` or `*`
+    marker_len: usize,
+    /// The marker printed at the start of the lines that wrap
+    continuation_marker: &'static str,
+}
+
+impl Format<JsFormatContext> for FormatCommentLine<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let after_marker = &self.text[self.marker_len..];
+        let content = after_marker.trim_start();
+        let gap = &after_marker[..after_marker.len() - content.len()];
+        let content_start = self.marker_len + gap.len();
+
+        let mut words = split_words(content);
+        let list_marker_len = list_marker_len(content);
+        if list_marker_len > 0 && words.len() > 1 {
+            // The marker of a list item never ends a line
+            words[1].start = words[0].start;
+            words.remove(0);
+        }
+
+        if words.len() < 2 || is_preformatted_line(gap, content) {
+            return write!(f, [dynamic_text(self.text, self.position)]);
+        }
+
+        // The wrapped lines of a list item are aligned with the text of the item
+        let continuation = std::format!("{}{gap}{:list_marker_len$}", self.continuation_marker, "");
+        let position_of =
+            |index: usize| self.position + TextSize::from((content_start + index) as u32);
+
+        let mut fill = f.fill();
+        fill.entry(
+            &soft_line_break_or_space(),
+            &dynamic_text(&self.text[..content_start + words[0].end], self.position),
+        );
+
+        for pair in words.windows(2) {
+            let (previous, word) = (&pair[0], &pair[1]);
+            let separator = format_with(|f| {
+                write!(
+                    f,
+                    [
+                        if_group_fits_on_line(&dynamic_text(
+                            &content[previous.end..word.start],
+                            position_of(previous.end)
+                        )),
+                        if_group_breaks(&format_args![
+                            soft_line_break(),
+                            dynamic_text(&continuation, self.position)
+                        ])
+                    ]
+                )
+            });
+            fill.entry(
+                &separator,
+                &dynamic_text(&content[word.clone()], position_of(word.start)),
+            );
+        }
+
+        fill.finish()
+    }
+}
+
+/// Returns the ranges of the words of `content`. The code spans are never split.
+fn split_words(content: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut word_start = None;
+    let mut is_in_code_span = false;
+
+    for (index, byte) in content.bytes().enumerate() {
+        match byte {
+            b' ' | b'\t' if !is_in_code_span => {
+                if let Some(start) = word_start.take() {
+                    words.push(start..index);
+                }
+            }
+            _ => {
+                if byte == b'`' {
+                    is_in_code_span = !is_in_code_span;
+                }
+                word_start.get_or_insert(index);
+            }
+        }
+    }
+
+    if let Some(start) = word_start {
+        words.push(start..content.len());
+    }
+
+    words
+}
+
+/// Returns the length of the marker of the list item `content`, including the space that
+/// follows it, or `0` if `content` isn't a list item.
+fn list_marker_len(content: &str) -> usize {
+    let bytes = content.as_bytes();
+
+    if matches!(bytes, [b'-' | b'*' | b'+', b' ', ..]) {
+        return 2;
+    }
+
+    let digits = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if digits > 0 && matches!(bytes.get(digits..digits + 2), Some([b'.' | b')', b' '])) {
+        digits + 2
+    } else {
+        0
+    }
+}
+
+/// Returns `true` if the line looks preformatted, such as an indented code block or a table.
+fn is_preformatted_line(gap: &str, content: &str) -> bool {
+    gap.len() > 4
+        || gap.contains('\t')
+        || ["```", "~~~", "|", ">"]
+            .iter()
+            .any(|prefix| content.starts_with(prefix))
+}
@@ -5,11 +5,13 @@ use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::printer::PrinterOptions;
 use biome_formatter::{
     AttributePosition, BracketSpacing, CstFormatContext, FormatContext, FormatElement,
-    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteStyle, TransformSourceMap,
+    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines, QuoteStyle,
+    TransformSourceMap,
 };
 use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage};
 use std::fmt;
 use std::fmt::Debug;
+use std::num::NonZeroU8;
 use std::rc::Rc;
 use std::str::FromStr;
 pub use trailing_commas::TrailingCommas;
@@ -143,6 +145,9 @@ pub struct JsFormatOptions {
     /// What's the max width of a line. Defaults to 80.
     line_width: LineWidth,
 
+    /// The maximum number of consecutive empty lines that are kept. Defaults to 1.
+    max_empty_lines: MaxEmptyLines,
+
     /// The style for quotes. Defaults to double.
     quote_style: QuoteStyle,
 
@@ -172,6 +177,39 @@ pub struct JsFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// The maximum number of attributes of a JSX element printed on a single line. Unlimited by default.
+    jsx_max_inline_attributes: JsxMaxInlineAttributes,
+
+    /// Where the decorators of class members and parameters are printed. Defaults to "preserve".
+    decorator_placement: DecoratorPlacement,
+
+    /// Whether the decorators of the parameters break the list of parameters. Defaults to "auto".
+    parameter_decorators: ParameterDecorators,
+
+    /// Whether the objects that are expanded in the input stay expanded. Defaults to "preserve".
+    object_wrap: ObjectWrap,
+
+    /// Where the operators of the binary and logical expressions are printed when the expressions are broken. Defaults to "after".
+    operator_linebreak: OperatorLinebreak,
+
+    /// How the chains of nested conditional expressions are printed when they don't fit on a single line. Defaults to "indent".
+    ternary_style: TernaryStyle,
+
+    /// How the lists of named imports and exports are wrapped. Defaults to "auto".
+    import_export_wrap: ImportExportWrap,
+
+    /// The maximum number of named imports or exports printed on a single line. Unlimited by default.
+    max_inline_specifiers: MaxInlineSpecifiers,
+
+    /// Whether the comments that exceed the line width are wrapped. Defaults to "preserve".
+    comment_reflow: CommentReflow,
+
+    /// Whether the lines of JSX children can break at the whitespace between a text and a tag. Defaults to "break".
+    jsx_children_whitespace: JsxChildrenWhitespace,
+
+    /// Whether the JSX elements that have more than one attribute print each attribute on its own line. Defaults to false.
+    single_attribute_per_line: SingleAttributePerLine,
 }
 
 impl JsFormatOptions {
@@ -182,6 +220,7 @@ impl JsFormatOptions {
             indent_width: IndentWidth::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            max_empty_lines: MaxEmptyLines::default(),
             quote_style: QuoteStyle::default(),
             jsx_quote_style: QuoteStyle::default(),
             quote_properties: QuoteProperties::default(),
@@ -191,6 +230,17 @@ impl JsFormatOptions {
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
+            jsx_max_inline_attributes: JsxMaxInlineAttributes::default(),
+            decorator_placement: DecoratorPlacement::default(),
+            parameter_decorators: ParameterDecorators::default(),
+            object_wrap: ObjectWrap::default(),
+            operator_linebreak: OperatorLinebreak::default(),
+            ternary_style: TernaryStyle::default(),
+            import_export_wrap: ImportExportWrap::default(),
+            max_inline_specifiers: MaxInlineSpecifiers::default(),
+            comment_reflow: CommentReflow::default(),
+            jsx_children_whitespace: JsxChildrenWhitespace::default(),
+            single_attribute_per_line: SingleAttributePerLine::default(),
         }
     }
 
@@ -229,6 +279,11 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
         self.quote_style = quote_style;
         self
@@ -259,6 +314,73 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_jsx_max_inline_attributes(
+        mut self,
+        jsx_max_inline_attributes: JsxMaxInlineAttributes,
+    ) -> Self {
+        self.jsx_max_inline_attributes = jsx_max_inline_attributes;
+        self
+    }
+
+    pub fn with_decorator_placement(mut self, decorator_placement: DecoratorPlacement) -> Self {
+        self.decorator_placement = decorator_placement;
+        self
+    }
+
+    pub fn with_parameter_decorators(mut self, parameter_decorators: ParameterDecorators) -> Self {
+        self.parameter_decorators = parameter_decorators;
+        self
+    }
+
+    pub fn with_object_wrap(mut self, object_wrap: ObjectWrap) -> Self {
+        self.object_wrap = object_wrap;
+        self
+    }
+
+    pub fn with_operator_linebreak(mut self, operator_linebreak: OperatorLinebreak) -> Self {
+        self.operator_linebreak = operator_linebreak;
+        self
+    }
+
+    pub fn with_ternary_style(mut self, ternary_style: TernaryStyle) -> Self {
+        self.ternary_style = ternary_style;
+        self
+    }
+
+    pub fn with_import_export_wrap(mut self, import_export_wrap: ImportExportWrap) -> Self {
+        self.import_export_wrap = import_export_wrap;
+        self
+    }
+
+    pub fn with_max_inline_specifiers(
+        mut self,
+        max_inline_specifiers: MaxInlineSpecifiers,
+    ) -> Self {
+        self.max_inline_specifiers = max_inline_specifiers;
+        self
+    }
+
+    pub fn with_comment_reflow(mut self, comment_reflow: CommentReflow) -> Self {
+        self.comment_reflow = comment_reflow;
+        self
+    }
+
+    pub fn with_jsx_children_whitespace(
+        mut self,
+        jsx_children_whitespace: JsxChildrenWhitespace,
+    ) -> Self {
+        self.jsx_children_whitespace = jsx_children_whitespace;
+        self
+    }
+
+    pub fn with_single_attribute_per_line(
+        mut self,
+        single_attribute_per_line: SingleAttributePerLine,
+    ) -> Self {
+        self.single_attribute_per_line = single_attribute_per_line;
+        self
+    }
+
     pub fn set_arrow_parentheses(&mut self, arrow_parentheses: ArrowParentheses) {
         self.arrow_parentheses = arrow_parentheses;
     }
@@ -287,6 +409,10 @@ impl JsFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
         self.quote_style = quote_style;
     }
@@ -306,6 +432,56 @@ impl JsFormatOptions {
         self.attribute_position = attribute_position;
     }
 
+    pub fn set_jsx_max_inline_attributes(
+        &mut self,
+        jsx_max_inline_attributes: JsxMaxInlineAttributes,
+    ) {
+        self.jsx_max_inline_attributes = jsx_max_inline_attributes;
+    }
+
+    pub fn set_decorator_placement(&mut self, decorator_placement: DecoratorPlacement) {
+        self.decorator_placement = decorator_placement;
+    }
+
+    pub fn set_parameter_decorators(&mut self, parameter_decorators: ParameterDecorators) {
+        self.parameter_decorators = parameter_decorators;
+    }
+
+    pub fn set_object_wrap(&mut self, object_wrap: ObjectWrap) {
+        self.object_wrap = object_wrap;
+    }
+
+    pub fn set_operator_linebreak(&mut self, operator_linebreak: OperatorLinebreak) {
+        self.operator_linebreak = operator_linebreak;
+    }
+
+    pub fn set_ternary_style(&mut self, ternary_style: TernaryStyle) {
+        self.ternary_style = ternary_style;
+    }
+
+    pub fn set_import_export_wrap(&mut self, import_export_wrap: ImportExportWrap) {
+        self.import_export_wrap = import_export_wrap;
+    }
+
+    pub fn set_max_inline_specifiers(&mut self, max_inline_specifiers: MaxInlineSpecifiers) {
+        self.max_inline_specifiers = max_inline_specifiers;
+    }
+
+    pub fn set_comment_reflow(&mut self, comment_reflow: CommentReflow) {
+        self.comment_reflow = comment_reflow;
+    }
+
+    pub fn set_jsx_children_whitespace(&mut self, jsx_children_whitespace: JsxChildrenWhitespace) {
+        self.jsx_children_whitespace = jsx_children_whitespace;
+    }
+
+    pub fn set_single_attribute_per_line(
+        &mut self,
+        single_attribute_per_line: SingleAttributePerLine,
+    ) {
+        self.single_attribute_per_line = single_attribute_per_line;
+    }
+
     pub fn set_semicolons(&mut self, semicolons: Semicolons) {
         self.semicolons = semicolons;
     }
@@ -353,6 +529,56 @@ impl JsFormatOptions {
     pub fn attribute_position(&self) -> AttributePosition {
         self.attribute_position
     }
+
+    pub fn jsx_max_inline_attributes(&self) -> JsxMaxInlineAttributes {
+        self.jsx_max_inline_attributes
+    }
+
+    pub fn decorator_placement(&self) -> DecoratorPlacement {
+        self.decorator_placement
+    }
+
+    pub fn parameter_decorators(&self) -> ParameterDecorators {
+        self.parameter_decorators
+    }
+
+    pub fn object_wrap(&self) -> ObjectWrap {
+        self.object_wrap
+    }
+
+    pub fn operator_linebreak(&self) -> OperatorLinebreak {
+        self.operator_linebreak
+    }
+
+    pub fn ternary_style(&self) -> TernaryStyle {
+        self.ternary_style
+    }
+
+    pub fn import_export_wrap(&self) -> ImportExportWrap {
+        self.import_export_wrap
+    }
+
+    pub fn max_inline_specifiers(&self) -> MaxInlineSpecifiers {
+        self.max_inline_specifiers
+    }
+
+    pub fn comment_reflow(&self) -> CommentReflow {
+        self.comment_reflow
+    }
+
+    pub fn jsx_children_whitespace(&self) -> JsxChildrenWhitespace {
+        self.jsx_children_whitespace
+    }
+
+    pub fn single_attribute_per_line(&self) -> SingleAttributePerLine {
+        self.single_attribute_per_line
+    }
+
+    /// Returns `true` if a list of `count` named imports or exports must print each of them
+    /// on its own line, even if they fit in the line width
+    pub fn should_expand_specifiers(&self, count: usize) -> bool {
+        self.import_export_wrap.is_per_line() || self.max_inline_specifiers.is_exceeded_by(count)
+    }
 }
 
 impl FormatOptions for JsFormatOptions {
@@ -380,6 +606,10 @@ impl FormatOptions for JsFormatOptions {
         self.bracket_spacing
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::from(self)
     }
@@ -399,7 +629,31 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(
+            f,
+            "JSX max inline attributes: {}",
+            self.jsx_max_inline_attributes
+        )?;
+        writeln!(f, "Decorator placement: {}", self.decorator_placement)?;
+        writeln!(f, "Parameter decorators: {}", self.parameter_decorators)?;
+        writeln!(f, "Object wrap: {}", self.object_wrap)?;
+        writeln!(f, "Operator linebreak: {}", self.operator_linebreak)?;
+        writeln!(f, "Ternary style: {}", self.ternary_style)?;
+        writeln!(f, "Import export wrap: {}", self.import_export_wrap)?;
+        writeln!(f, "Max inline specifiers: {}", self.max_inline_specifiers)?;
+        writeln!(f, "Comment reflow: {}", self.comment_reflow)?;
+        writeln!(
+            f,
+            "JSX children whitespace: {}",
+            self.jsx_children_whitespace
+        )?;
+        writeln!(
+            f,
+            "Single attribute per line: {}",
+            self.single_attribute_per_line.value()
+        )?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines.value())
     }
 }
 
@@ -544,3 +798,512 @@ impl From<bool> for BracketSameLine {
         Self(value)
     }
 }
+
+/// Whether the JSX elements that have more than one attribute print each attribute on its own
+/// line, even if the element fits in the line width.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct SingleAttributePerLine(bool);
+
+impl SingleAttributePerLine {
+    /// Return the boolean value for this [SingleAttributePerLine]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+
+    /// Returns `true` if an element with `count` attributes must print them on separate lines
+    pub fn is_exceeded_by(&self, count: usize) -> bool {
+        self.0 && count > 1
+    }
+}
+
+impl From<bool> for SingleAttributePerLine {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+/// The maximum number of attributes of a JSX element printed on a single line.
+///
+/// The attributes of the elements that have more attributes are each printed on their own line.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct JsxMaxInlineAttributes(Option<NonZeroU8>);
+
+impl JsxMaxInlineAttributes {
+    /// Return the maximum, or `None` if the number of attributes is unlimited
+    pub fn value(&self) -> Option<NonZeroU8> {
+        self.0
+    }
+
+    /// Returns `true` if an element with `count` attributes must print them on separate lines
+    pub fn is_exceeded_by(&self, count: usize) -> bool {
+        self.0.is_some_and(|max| count > usize::from(max.get()))
+    }
+}
+
+impl From<NonZeroU8> for JsxMaxInlineAttributes {
+    fn from(value: NonZeroU8) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl fmt::Display for JsxMaxInlineAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(max) => write!(f, "{max}"),
+            None => write!(f, "Unlimited"),
+        }
+    }
+}
+
+/// Where the decorators of class members and parameters are printed.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DecoratorPlacement {
+    /// The decorators are printed on the line of their member when they are on the same line in the input,
+    /// and on their own line otherwise.
+    #[default]
+    Preserve,
+    /// A single decorator is printed on the line of its member when it fits in the line width,
+    /// even if it's on its own line in the input.
+    SameLine,
+    /// The decorators are always printed on their own line, above their member.
+    Above,
+}
+
+impl DecoratorPlacement {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_same_line(&self) -> bool {
+        matches!(self, Self::SameLine)
+    }
+
+    pub const fn is_above(&self) -> bool {
+        matches!(self, Self::Above)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for DecoratorPlacement {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "same-line" | "SameLine" => Ok(Self::SameLine),
+            "above" | "Above" => Ok(Self::Above),
+            _ => Err("Value not supported for DecoratorPlacement. Supported values are 'preserve', 'same-line' and 'above'."),
+        }
+    }
+}
+
+impl fmt::Display for DecoratorPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecoratorPlacement::Preserve => write!(f, "Preserve"),
+            DecoratorPlacement::SameLine => write!(f, "Same line"),
+            DecoratorPlacement::Above => write!(f, "Above"),
+        }
+    }
+}
+
+/// Whether the decorators of the parameters break the list of parameters.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ParameterDecorators {
+    /// The decorators of the parameters are printed like the decorators of the class members,
+    /// following `decoratorPlacement`. The parameters break when a decorator is printed on its own line.
+    #[default]
+    Auto,
+    /// The decorators are printed on the line of their parameter, and the parameters only break
+    /// when they don't fit in the line width.
+    Inline,
+    /// The decorators are printed on the line of their parameter, and the parameters of a function
+    /// that has a decorated parameter are always printed on their own line.
+    Break,
+}
+
+impl ParameterDecorators {
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub const fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline)
+    }
+
+    pub const fn is_break(&self) -> bool {
+        matches!(self, Self::Break)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ParameterDecorators {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" | "Auto" => Ok(Self::Auto),
+            "inline" | "Inline" => Ok(Self::Inline),
+            "break" | "Break" => Ok(Self::Break),
+            _ => Err("Value not supported for ParameterDecorators. Supported values are 'auto', 'inline' and 'break'."),
+        }
+    }
+}
+
+impl fmt::Display for ParameterDecorators {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterDecorators::Auto => write!(f, "Auto"),
+            ParameterDecorators::Inline => write!(f, "Inline"),
+            ParameterDecorators::Break => write!(f, "Break"),
+        }
+    }
+}
+
+/// Whether the objects that are expanded in the input stay expanded.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ObjectWrap {
+    /// The objects that have a line break between the `{` and their first member are printed
+    /// on multiple lines, even if they fit in the line width.
+    #[default]
+    Preserve,
+    /// The objects are printed on a single line when they fit in the line width.
+    Collapse,
+}
+
+impl ObjectWrap {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_collapse(&self) -> bool {
+        matches!(self, Self::Collapse)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ObjectWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "collapse" | "Collapse" => Ok(Self::Collapse),
+            _ => Err("Value not supported for ObjectWrap. Supported values are 'preserve' and 'collapse'."),
+        }
+    }
+}
+
+impl fmt::Display for ObjectWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectWrap::Preserve => write!(f, "Preserve"),
+            ObjectWrap::Collapse => write!(f, "Collapse"),
+        }
+    }
+}
+
+/// Where the operators of the broken binary and logical expressions are printed.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum OperatorLinebreak {
+    /// The operator is printed at the end of the line, before the line break.
+    #[default]
+    After,
+    /// The operator is printed at the start of the next line, after the line break.
+    Before,
+}
+
+impl OperatorLinebreak {
+    pub const fn is_after(&self) -> bool {
+        matches!(self, Self::After)
+    }
+
+    pub const fn is_before(&self) -> bool {
+        matches!(self, Self::Before)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for OperatorLinebreak {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "after" | "After" => Ok(Self::After),
+            "before" | "Before" => Ok(Self::Before),
+            _ => Err("Value not supported for OperatorLinebreak. Supported values are 'after' and 'before'."),
+        }
+    }
+}
+
+impl fmt::Display for OperatorLinebreak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperatorLinebreak::After => write!(f, "After"),
+            OperatorLinebreak::Before => write!(f, "Before"),
+        }
+    }
+}
+
+/// How the chains of nested conditional expressions are printed.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TernaryStyle {
+    /// Each nested conditional expression is indented one level more than its parent.
+    #[default]
+    Indent,
+    /// The conditional expressions nested in the alternates are printed at the same level, with
+    /// each test on the same line as its consequent, like the cases of a `switch`.
+    Case,
+}
+
+impl TernaryStyle {
+    pub const fn is_indent(&self) -> bool {
+        matches!(self, Self::Indent)
+    }
+
+    pub const fn is_case(&self) -> bool {
+        matches!(self, Self::Case)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for TernaryStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "indent" | "Indent" => Ok(Self::Indent),
+            "case" | "Case" => Ok(Self::Case),
+            _ => Err(
+                "Value not supported for TernaryStyle. Supported values are 'indent' and 'case'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for TernaryStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TernaryStyle::Indent => write!(f, "Indent"),
+            TernaryStyle::Case => write!(f, "Case"),
+        }
+    }
+}
+
+/// How the lists of named imports and exports are wrapped.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ImportExportWrap {
+    /// The specifiers are printed on a single line when they fit in the line width,
+    /// unless there are more than `maxInlineSpecifiers` of them.
+    #[default]
+    Auto,
+    /// Each specifier is printed on its own line, even if there's a single one.
+    PerLine,
+}
+
+impl ImportExportWrap {
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub const fn is_per_line(&self) -> bool {
+        matches!(self, Self::PerLine)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ImportExportWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" | "Auto" => Ok(Self::Auto),
+            "per-line" | "PerLine" => Ok(Self::PerLine),
+            _ => Err("Value not supported for ImportExportWrap. Supported values are 'auto' and 'per-line'."),
+        }
+    }
+}
+
+impl fmt::Display for ImportExportWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportExportWrap::Auto => write!(f, "Auto"),
+            ImportExportWrap::PerLine => write!(f, "Per line"),
+        }
+    }
+}
+
+/// The maximum number of named imports or exports printed on a single line.
+///
+/// The lists that have more specifiers print each of them on its own line.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct MaxInlineSpecifiers(Option<NonZeroU8>);
+
+impl MaxInlineSpecifiers {
+    /// Return the maximum, or `None` if the number of specifiers is unlimited
+    pub fn value(&self) -> Option<NonZeroU8> {
+        self.0
+    }
+
+    /// Returns `true` if a list with `count` specifiers must print them on separate lines
+    pub fn is_exceeded_by(&self, count: usize) -> bool {
+        self.0.is_some_and(|max| count > usize::from(max.get()))
+    }
+}
+
+impl From<NonZeroU8> for MaxInlineSpecifiers {
+    fn from(value: NonZeroU8) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl fmt::Display for MaxInlineSpecifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(max) => write!(f, "{max}"),
+            None => write!(f, "Unlimited"),
+        }
+    }
+}
+
+/// Whether the comments that exceed the line width are wrapped.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum CommentReflow {
+    /// The comments are printed as they are in the source.
+    #[default]
+    Preserve,
+    /// The lines of the comments that exceed the line width are wrapped. The comments that are
+    /// directives, such as suppression comments, are printed as they are in the source.
+    Wrap,
+}
+
+impl CommentReflow {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_wrap(&self) -> bool {
+        matches!(self, Self::Wrap)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for CommentReflow {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "wrap" | "Wrap" => Ok(Self::Wrap),
+            _ => Err("Value not supported for CommentReflow. Supported values are 'preserve' and 'wrap'."),
+        }
+    }
+}
+
+impl fmt::Display for CommentReflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentReflow::Preserve => write!(f, "Preserve"),
+            CommentReflow::Wrap => write!(f, "Wrap"),
+        }
+    }
+}
+
+/// Whether the lines of JSX children can break at the whitespace between a text and a tag.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum JsxChildrenWhitespace {
+    /// A line can break at the whitespace between a text and a tag. The formatter inserts
+    /// `{" "}` at the end of the line to keep the whitespace.
+    #[default]
+    Break,
+    /// The text stays on the line of the tags that it's separated from by a whitespace. The
+    /// lines only break between the words of the text, so the formatter never inserts `{" "}`.
+    Glue,
+}
+
+impl JsxChildrenWhitespace {
+    pub const fn is_break(&self) -> bool {
+        matches!(self, Self::Break)
+    }
+
+    pub const fn is_glue(&self) -> bool {
+        matches!(self, Self::Glue)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for JsxChildrenWhitespace {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "break" | "Break" => Ok(Self::Break),
+            "glue" | "Glue" => Ok(Self::Glue),
+            _ => Err("Value not supported for JsxChildrenWhitespace. Supported values are 'break' and 'glue'."),
+        }
+    }
+}
+
+impl fmt::Display for JsxChildrenWhitespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsxChildrenWhitespace::Break => write!(f, "Break"),
+            JsxChildrenWhitespace::Glue => write!(f, "Glue"),
+        }
+    }
+}
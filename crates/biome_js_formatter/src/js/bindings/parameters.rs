@@ -94,13 +94,13 @@ impl Format<JsFormatContext> for FormatAnyJsParameters {
                     write!(f, [format_removed(&l_paren_token)])?;
                 }
 
-                write!(
-                    f,
-                    [soft_block_indent(&FormatJsAnyParameterList::with_layout(
-                        &list,
-                        ParameterLayout::Default,
-                    ))]
-                )?;
+                let format_list =
+                    FormatJsAnyParameterList::with_layout(&list, ParameterLayout::Default);
+                if has_any_decorated_parameter && f.options().parameter_decorators().is_break() {
+                    write!(f, [block_indent(&format_list)])?;
+                } else {
+                    write!(f, [soft_block_indent(&format_list)])?;
+                }
 
                 if !parentheses_not_needed {
                     write!(f, [r_paren_token.format()])?;
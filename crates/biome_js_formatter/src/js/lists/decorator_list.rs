@@ -72,7 +72,9 @@ impl FormatRule<JsDecoratorList> for FormatJsDecoratorList {
             );
 
             if is_parameter_decorators {
-                let should_expand = should_expand_decorators(node);
+                // With "inline" and "break", the decorators stay on the line of their parameter
+                let should_expand = f.options().parameter_decorators().is_auto()
+                    && should_expand_decorators(node, f.options().decorator_placement());
 
                 if should_expand {
                     write!(f, [expand_parent()])?;
@@ -33,7 +33,10 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
             Some(AstSeparatedElement {
                 node: Ok(node),
                 trailing_separator: Ok(separator),
-            }) if specifiers.len() == 1 && !f.comments().has_comments(node.syntax()) => {
+            }) if specifiers.len() == 1
+                && !f.comments().has_comments(node.syntax())
+                && !f.options().should_expand_specifiers(1) =>
+            {
                 write!(
                     f,
                     [
@@ -49,7 +52,9 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
                 write!(f, [maybe_space(should_insert_space_around_brackets)])?;
             }
             _ => {
-                if specifiers.syntax().has_leading_newline() {
+                if specifiers.syntax().has_leading_newline()
+                    || f.options().should_expand_specifiers(specifiers.len())
+                {
                     write!(f, [block_indent(&specifiers.format()),])?;
                 } else {
                     write!(
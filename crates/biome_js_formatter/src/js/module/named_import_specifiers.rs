@@ -22,6 +22,8 @@ impl FormatNodeRule<JsNamedImportSpecifiers> for FormatJsNamedImportSpecifiers {
                 f,
                 [format_dangling_comments(node.syntax()).with_soft_block_indent()]
             )?;
+        } else if f.options().should_expand_specifiers(specifiers.len()) {
+            write!(f, [block_indent(&specifiers.format())])?;
         } else {
             let should_insert_space_around_brackets = f.options().bracket_spacing().value();
             write!(
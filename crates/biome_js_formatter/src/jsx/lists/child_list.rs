@@ -172,6 +172,16 @@ impl FormatJsxChildList {
                     // ```
                     else if last.is_none() {
                         multiline.write_with_separator(&JsxRawSpace, &hard_line_break(), f);
+                    }
+                    // The text stays on the line of the tag, and the whitespace is never replaced by `{" "}`
+                    //
+                    // ```
+                    // <div>
+                    //   some text <b>bold</b> more text
+                    // </div>
+                    // ```
+                    else if f.options().jsx_children_whitespace().is_glue() {
+                        multiline.write_glue(&space(), f);
                     } else {
                         multiline.write_separator(&JsxSpace, f);
                     }
@@ -573,6 +583,8 @@ enum MultilineLayout {
 struct MultilineBuilder {
     layout: MultilineLayout,
     result: FormatResult<Vec<FormatElement>>,
+    /// Whether the next content is written in the entry of the previous content
+    is_glued: bool,
 }
 
 impl MultilineBuilder {
@@ -580,6 +592,7 @@ impl MultilineBuilder {
         Self {
             layout,
             result: Ok(Vec::new()),
+            is_glued: false,
         }
     }
 
@@ -604,6 +617,15 @@ impl MultilineBuilder {
         self.write(content, Some(separator), f);
     }
 
+    /// Writes `glue` in the entry of the previous content, and writes the next content in the same
+    /// entry, so that the fill never breaks the line between them
+    fn write_glue(&mut self, glue: &dyn Format<JsFormatContext>, f: &mut JsFormatter) {
+        self.is_glued = true;
+        self.write(glue, None, f);
+        // `write` resets the flag, the next content joins the same entry
+        self.is_glued = true;
+    }
+
     fn write(
         &mut self,
         content: &dyn Format<JsFormatContext>,
@@ -612,13 +634,23 @@ impl MultilineBuilder {
     ) {
         let result = std::mem::replace(&mut self.result, Ok(Vec::new()));
 
-        self.result = result.and_then(|elements| {
+        self.result = result.and_then(|mut elements| {
+            // Reopen the entry of the previous content
+            let is_reopened = self.is_glued
+                && matches!(self.layout, MultilineLayout::Fill)
+                && matches!(elements.last(), Some(FormatElement::Tag(Tag::EndEntry)));
+            if is_reopened {
+                elements.pop();
+            }
+
             let elements = {
                 let mut buffer = VecBuffer::new_with_vec(f.state_mut(), elements);
                 match self.layout {
                     MultilineLayout::Fill => {
                         // Make sure that the separator and content only ever write a single element
-                        buffer.write_element(FormatElement::Tag(Tag::StartEntry))?;
+                        if !is_reopened {
+                            buffer.write_element(FormatElement::Tag(Tag::StartEntry))?;
+                        }
                         write!(buffer, [content])?;
                         buffer.write_element(FormatElement::Tag(Tag::EndEntry))?;
 
@@ -639,7 +671,8 @@ impl MultilineBuilder {
                 buffer.into_vec()
             };
             Ok(elements)
-        })
+        });
+        self.is_glued = false;
     }
 
     fn finish(self) -> FormatResult<FormatMultilineChildren> {
@@ -100,10 +100,14 @@ impl Format<JsFormatContext> for AnyJsxOpeningElement {
                 let has_multiline_string_attribute = attributes
                     .iter()
                     .any(|attribute| is_multiline_string_literal_attribute(&attribute));
-                write![
-                    f,
-                    [group(&format_inner).should_expand(has_multiline_string_attribute)]
-                ]
+                let should_expand = has_multiline_string_attribute
+                    || f.options()
+                        .jsx_max_inline_attributes()
+                        .is_exceeded_by(attributes.len())
+                    || f.options()
+                        .single_attribute_per_line()
+                        .is_exceeded_by(attributes.len());
+                write!(f, [group(&format_inner).should_expand(should_expand)])
             }
         }
     }
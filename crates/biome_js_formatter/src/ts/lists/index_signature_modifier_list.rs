@@ -1,7 +1,6 @@
 use crate::prelude::*;
-use crate::utils::sort_modifiers_by_precedence;
 
-use biome_js_syntax::TsIndexSignatureModifierList;
+use biome_js_syntax::{sort_modifiers_by_precedence, TsIndexSignatureModifierList};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatTsIndexSignatureModifierList;
@@ -1,6 +1,5 @@
 use crate::prelude::*;
-use crate::utils::sort_modifiers_by_precedence;
-use biome_js_syntax::TsPropertyParameterModifierList;
+use biome_js_syntax::{sort_modifiers_by_precedence, TsPropertyParameterModifierList};
 
 #[derive(Debug, Clone, Default)]
 pub struct FormatTsPropertyParameterModifierList;
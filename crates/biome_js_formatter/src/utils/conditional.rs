@@ -64,6 +64,9 @@ impl FormatRule<AnyJsConditional> for FormatJsAnyConditionalRule {
         let indent_style = f.options().indent_style();
         let layout = self.layout(conditional, f.context().options().source_type());
         let jsx_chain = layout.jsx_chain().unwrap_or(self.jsx_chain);
+        let is_case_style = f.options().ternary_style().is_case()
+            && jsx_chain.is_no_chain()
+            && is_alternate_chain(conditional)?;
 
         let format_consequent_and_alternate = format_with(|f| {
             write!(
@@ -117,6 +120,67 @@ impl FormatRule<AnyJsConditional> for FormatJsAnyConditionalRule {
             write!(f, [alternate])
         });
 
+        // Formats the chain of alternates as cases, with each test on the same line as its consequent
+        // and all the `:` aligned:
+        //
+        // ```javascript
+        // const animal = pet.canBark() ? "dog"
+        //     : pet.canMeow() ? "cat"
+        //     : "bunny";
+        // ```
+        let format_case_consequent_and_alternate = format_with(|f| {
+            let is_consequent_nested = consequent.syntax().kind() == syntax.kind();
+            let format_consequent = format_with(|f| {
+                write!(
+                    f,
+                    [
+                        soft_line_break_or_space(),
+                        conditional.question_mark_token().format(),
+                        space()
+                    ]
+                )?;
+
+                let consequent = format_with(|f| {
+                    if indent_style.is_space() {
+                        write!(f, [align(2, &consequent)])
+                    } else {
+                        write!(f, [indent(&consequent)])
+                    }
+                });
+                if is_consequent_nested {
+                    write!(
+                        f,
+                        [
+                            if_group_fits_on_line(&text("(")),
+                            consequent,
+                            if_group_fits_on_line(&text(")"))
+                        ]
+                    )
+                } else {
+                    write!(f, [consequent])
+                }
+            });
+            // The consequent stays on the line of the test when it fits
+            write!(f, [group(&format_consequent)])?;
+
+            write!(
+                f,
+                [
+                    soft_line_break_or_space(),
+                    conditional.colon_token().format(),
+                    space()
+                ]
+            )?;
+            if alternate.syntax().kind() == syntax.kind() {
+                // The nested alternate continues the chain at the same level
+                write!(f, [alternate])
+            } else if indent_style.is_space() {
+                write!(f, [align(2, &alternate)])
+            } else {
+                write!(f, [indent(&alternate)])
+            }
+        });
+
         let format_tail_with_indent = format_with(|f: &mut JsFormatter| {
             match conditional {
                 AnyJsConditional::JsConditionalExpression(conditional) if jsx_chain.is_chain() => {
@@ -134,6 +198,7 @@ impl FormatRule<AnyJsConditional> for FormatJsAnyConditionalRule {
                         ]
                     )
                 }
+                _ if is_case_style => format_case_consequent_and_alternate.fmt(f),
                 _ => {
                     // Add an extra level of indent to nested consequences.
                     if layout.is_nested_consequent() {
@@ -460,6 +525,30 @@ impl FormatJsAnyConditionalRule {
     }
 }
 
+/// Returns `true` if `conditional` is part of a chain of conditionals nested in the alternates,
+/// starting from a conditional that isn't nested in another conditional.
+fn is_alternate_chain(conditional: &AnyJsConditional) -> FormatResult<bool> {
+    let mut node = conditional.syntax().clone();
+    let is_alternate_nested = conditional.alternate()?.syntax().kind() == node.kind();
+    let is_nested = node
+        .parent()
+        .is_some_and(|parent| parent.kind() == node.kind());
+    if !is_alternate_nested && !is_nested {
+        return Ok(false);
+    }
+    while let Some(parent) = node.parent() {
+        if parent.kind() != node.kind() {
+            break;
+        }
+        let parent = AnyJsConditional::unwrap_cast(parent);
+        if !parent.is_alternate(&node) {
+            return Ok(false);
+        }
+        node = parent.into_syntax();
+    }
+    Ok(true)
+}
+
 /// Formats the test conditional of a conditional expression.
 struct FormatConditionalTest<'a> {
     conditional: &'a AnyJsConditional,
@@ -1,9 +1,9 @@
+use crate::context::DecoratorPlacement;
 use crate::prelude::*;
-use crate::utils::sort_modifiers_by_precedence;
 use crate::{AsFormat, IntoFormat};
 use biome_formatter::{format_args, write};
 use biome_js_syntax::JsSyntaxKind::JS_DECORATOR;
-use biome_js_syntax::{JsLanguage, Modifier};
+use biome_js_syntax::{sort_modifiers_by_precedence, JsLanguage, JsSyntaxNode, Modifier};
 use biome_rowan::{AstNode, AstNodeList, NodeOrToken};
 
 pub(crate) struct FormatModifiers<List> {
@@ -24,7 +24,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
         let modifiers = sort_modifiers_by_precedence(&self.list);
-        let should_expand = should_expand_decorators(&self.list);
+        let should_expand = should_expand_decorators(&self.list, f.options().decorator_placement());
 
         // Returning early here is important, because otherwise this node
         // returns a group that always has a soft line break, which causes
@@ -90,8 +90,47 @@ where
     }
 }
 
-/// This function expands decorators enclosing a group if there is a newline between decorators or after the last decorator.
-pub(crate) fn should_expand_decorators<List, Node>(list: &List) -> bool
+/// Returns `true` if the decorators of `list` must be printed on their own line.
+///
+/// With [DecoratorPlacement::Preserve], this is the case if there is a newline between decorators or after the last decorator.
+/// With [DecoratorPlacement::SameLine], a single decorator isn't expanded, unless a comment follows it.
+/// With [DecoratorPlacement::Above], the decorators are always expanded.
+pub(crate) fn should_expand_decorators<List, Node>(
+    list: &List,
+    placement: DecoratorPlacement,
+) -> bool
+where
+    Node: AstNode<Language = JsLanguage>,
+    List: AstNodeList<Language = JsLanguage, Node = Node>,
+{
+    let decorators: Vec<_> = list
+        .iter()
+        .filter(|node| node.syntax().kind() == JS_DECORATOR)
+        .collect();
+    match (placement, decorators.as_slice()) {
+        (DecoratorPlacement::Above, decorators) => !decorators.is_empty(),
+        (DecoratorPlacement::SameLine, [decorator]) if !has_comments_after(decorator.syntax()) => {
+            false
+        }
+        _ => has_newline_around_decorators(list),
+    }
+}
+
+/// Returns `true` if a comment follows `decorator`, before the next modifier or the member.
+///
+/// The comment would be moved to the line of the decorator, or after the member,
+/// if they were printed on the same line.
+fn has_comments_after(decorator: &JsSyntaxNode) -> bool {
+    decorator.last_token().map_or(false, |token| {
+        token.has_trailing_comments()
+            || token
+                .next_token()
+                .map_or(false, |next| next.has_leading_comments())
+    })
+}
+
+/// Returns `true` if there is a newline between decorators or after the last decorator.
+fn has_newline_around_decorators<List, Node>(list: &List) -> bool
 where
     Node: AstNode<Language = JsLanguage>,
     List: AstNodeList<Language = JsLanguage, Node = Node>,
@@ -179,7 +179,7 @@ pub(crate) struct JsxRawSpace;
 impl Format<JsFormatContext> for JsxRawSpace {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
         let jsx_space = match f.options().quote_style() {
-            QuoteStyle::Double => r#"{" "}"#,
+            QuoteStyle::Double | QuoteStyle::Preserve => r#"{" "}"#,
             QuoteStyle::Single => "{' '}",
         };
 
@@ -25,11 +25,9 @@ pub(crate) use assignment_like::{
     with_assignment_layout, AnyJsAssignmentLike, AssignmentLikeLayout,
 };
 use biome_formatter::{format_args, write, Buffer};
-use biome_js_syntax::{
-    AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause, JsLanguage, Modifier,
-};
+use biome_js_syntax::{AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause};
 use biome_js_syntax::{JsSyntaxKind, JsSyntaxToken};
-use biome_rowan::{AstNode, AstNodeList};
+use biome_rowan::AstNode;
 use biome_text_size::TextSize;
 pub(crate) use conditional::{AnyJsConditional, ConditionalJsxChain};
 pub(crate) use object_like::JsObjectLike;
@@ -197,20 +195,6 @@ impl Format<JsFormatContext> for FormatStatementBody<'_> {
     }
 }
 
-/// This function consumes a list of modifiers and applies a predictable sorting.
-pub(crate) fn sort_modifiers_by_precedence<List, Node>(list: &List) -> Vec<Node>
-where
-    Node: AstNode<Language = JsLanguage>,
-    List: AstNodeList<Language = JsLanguage, Node = Node>,
-    Modifier: for<'a> From<&'a Node>,
-{
-    let mut nodes_and_modifiers = list.iter().collect::<Vec<Node>>();
-
-    nodes_and_modifiers.sort_unstable_by_key(|node| Modifier::from(node));
-
-    nodes_and_modifiers
-}
-
 pub(crate) type FormatStatementSemicolon<'a> = FormatOptionalSemicolon<'a>;
 
 /// Formats a semicolon in a position where it is optional (not needed to maintain syntactical correctness).
@@ -138,8 +138,12 @@ impl FormatLiteralStringToken<'_> {
     /// (' content """ dont\'t ')
     /// ```
     /// Like this, we reduced the number of escaped quotes.
+    ///
+    /// With [QuoteStyle::Preserve], the quote of the string is kept, regardless of its content.
     fn compute_string_information(&self, chosen_quote: QuoteStyle) -> StringInformation {
         let literal = self.token().text_trimmed();
+        let is_quote_preserved = chosen_quote.is_preserve();
+        let chosen_quote = chosen_quote.resolve(literal);
         let alternate_quote = chosen_quote.other();
         let chosen_quote_byte = chosen_quote.as_byte();
         let alternate_quote_byte = alternate_quote.as_byte();
@@ -174,7 +178,7 @@ impl FormatLiteralStringToken<'_> {
         );
 
         StringInformation {
-            preferred_quote: if chosen_quote_count > alternate_quote_count {
+            preferred_quote: if !is_quote_preserved && chosen_quote_count > alternate_quote_count {
                 alternate_quote
             } else {
                 chosen_quote
@@ -0,0 +1,28 @@
+// This line comment is long enough to exceed the line width of eighty characters, so it wraps.
+// Short comments stay unchanged.
+
+/**
+ * Computes the sum of the numbers, and this sentence is long enough to exceed the line width.
+ *
+ * - a list item that is long enough to exceed the line width, so its text wraps after the marker
+ * 1. an ordered list item that is long enough to exceed the line width, so that it wraps as well
+ * The code spans such as `const someVeryLongIdentifierName = anotherVeryLongIdentifierName` never split.
+ *
+ * ```js
+ * const result = sum([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21]);
+ * ```
+ *
+ * @param numbers the numbers to sum, which can be any finite numbers including the negative ones
+ * @example
+ * sum([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24]);
+ */
+function sum(numbers) {
+	// biome-ignore lint/suspicious/noExplicitAny: this suppression comment is long and it is never wrapped
+	const total = 0; // a trailing comment that is long enough to exceed the line width stays on its line
+	// eslint-disable-next-line no-unused-vars -- the directive comments are never wrapped either, whatever their length
+	//     an indented code block in a comment that is long enough to exceed the line width stays as is
+	// | a table | that is long enough | to exceed the line width | stays unchanged | as well |
+	return numbers.reduce((total, number) => total + number, total);
+}
+
+/* A block comment without stars on its lines is long enough to exceed the line width, and stays. */
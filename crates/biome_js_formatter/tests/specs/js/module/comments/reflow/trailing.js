@@ -0,0 +1,20 @@
+const config = {
+	name: "config",
+	// A trailing comment on its own line at the end of an object is long enough to wrap.
+};
+
+const options = {
+	strict: true,
+	/**
+	 * A trailing block comment at the end of an object is long enough to exceed the line width.
+	 */
+};
+
+function noop() {
+	// A dangling comment in an empty block is long enough to exceed the line width of the file.
+}
+
+// See https://example.com/a/very/long/path/to/the/documentation/of/the/comment/reflow/option for the details.
+
+// Les commentaires écrits en français contiennent des caractères accentués, comme é, è et à, qui comptent une fois.
+// The wide characters such as 日本語 and 中文 count twice in the width, so this comment wraps earlier.
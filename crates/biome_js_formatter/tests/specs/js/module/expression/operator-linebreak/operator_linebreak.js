@@ -0,0 +1,21 @@
+const total = firstValueOfTheSeries + secondValueOfTheSeries + thirdValueOfTheSeries + fourth;
+
+if (isEnabled && hasPermission && userPreferences.showNotifications && notifications.length > 0) {
+	notify();
+}
+
+const isValid = (value !== undefined && value !== null) || defaultValueOfTheSetting === "enabled";
+
+const isInstance = someVeryLongVariableNameToBreakTheLine instanceof SomeVeryLongClassName;
+
+const isKey = someVeryLongPropertyNameToBreakTheLineOfTheExpression in someVeryLongObjectName;
+
+const options = userOptions || {
+	name: "default",
+};
+
+const short = a && b;
+
+const withComment =
+	firstCondition && // the first condition
+	secondCondition;
@@ -0,0 +1,9 @@
+const animalName = pet.canSqueak() ? "mouse" : pet.canBark() ? "dog" : pet.canMeow() ? "cat" : "probably a bunny";
+
+const short = isEnabled ? "on" : "off";
+
+const message = isError ? getErrorMessageForTheCurrentUser(currentUser, errorCode) : isWarning ? getWarningMessage(currentUser) : defaultMessageForEverybodyElse;
+
+const size = isLarge ? (isWide ? "large and wide" : "large and narrow") : isMedium ? "medium" : "small and probably something else";
+
+const value = (isFirst ? someLongFirstValueExpression : someLongSecondValueExpression) ? resultWhenTheTestIsTruthy : resultWhenTheTestIsFalsy;
@@ -0,0 +1,11 @@
+import { a } from "a";
+import { b, c } from "bc";
+import d, { e, f, g } from "defg";
+import { h, i, j, k } from "hijk";
+import {} from "empty";
+
+export { a };
+export { b, c };
+export { h, i, j, k };
+export { m } from "m";
+export { n, o, p, q } from "nopq";
@@ -0,0 +1,44 @@
+import { a } from "a";
+
+
+
+import { b } from "b";
+const first = 1;
+
+
+const second = 2;
+function run() {
+	start();
+
+
+
+
+	// Keeps the empty lines before the comment
+	stop();
+}
+
+class Counter {
+	count = 0;
+
+
+
+	increment() {
+		this.count++;
+	}
+
+
+	decrement() {
+		this.count--;
+	}
+}
+
+const object = {
+	a: 1,
+
+
+
+
+	b: 2,
+
+	c: 3,
+};
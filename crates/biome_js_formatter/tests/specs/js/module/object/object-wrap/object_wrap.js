@@ -0,0 +1,22 @@
+const user = {
+	name: "John",
+	age: 42,
+};
+
+const nested = {
+	point: {
+		x: 1, y: 2 },
+};
+
+const withComment = {
+	// The name
+	name: "John",
+};
+
+call({
+	a: 1,
+});
+
+const long = {
+	firstName: "John", lastName: "Smith", email: "john.smith@example.com", age: 42,
+};
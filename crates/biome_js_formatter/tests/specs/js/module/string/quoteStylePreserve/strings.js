@@ -0,0 +1,16 @@
+"use strict";
+'use client';
+
+import a from './a.js';
+import b from "./b.js";
+
+const double = "double";
+const single = 'single';
+const doubleWithSingle = "it's";
+const singleWithDouble = 'say "hi"';
+const escapedSingle = 'it\'s';
+const escapedDouble = "say \"hi\"";
+const mostlyDouble = 'say "hi" to "them"';
+const unnecessaryEscape = 'a \d b';
+
+const object = { "a": 1, 'b': 2, 'c-d': 3, "e-f": 4 };
@@ -0,0 +1,21 @@
+const paragraph = (
+	<p>
+		Read the <a href="/docs/getting-started">getting started guide</a> before you install the <Badge>beta</Badge> release of the design system.
+	</p>
+);
+
+const button = (
+	<Button>
+		Save the changes of the current document <Icon name="save" /> and close the editor window
+	</Button>
+);
+
+const leading = <div> <b>a very long text content that exceeds the line width of eighty chars</b></div>;
+
+const trailing = <div>some text that is long enough to exceed the line width of 80 <b>x</b> </div>;
+
+const link = (
+	<p>
+		Our design system documents each of its components, see the detailed <a href="/components">component reference</a> for more.
+	</p>
+);
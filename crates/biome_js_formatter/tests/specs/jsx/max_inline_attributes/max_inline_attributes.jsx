@@ -0,0 +1,23 @@
+import React from "react";
+
+const Component = () => (
+  <div>
+    <div data-a="1" data-b="2">
+      Lorem ipsum dolor sit amet, consectetur adipiscing elit.
+    </div>
+
+    <div data-a="1" data-b="2" data-c="3">
+      Lorem ipsum dolor sit amet, consectetur adipiscing elit.
+    </div>
+
+    <img src="/images/foo.png" />
+
+    <img src="/images/foo.png" alt="bar" />
+
+    <img src="/images/foo.png" alt="bar" loading="lazy" />
+
+    <Foo {...props} key={id} />
+
+    <Foo {...props} key={id} onClick={handleClick} />
+  </div>
+);
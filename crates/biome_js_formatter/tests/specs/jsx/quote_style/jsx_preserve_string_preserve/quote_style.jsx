@@ -0,0 +1,3 @@
+<div className='single' id="double" title={'expression'} data-text="it's">
+	{"text"} {'text'}
+</div>;
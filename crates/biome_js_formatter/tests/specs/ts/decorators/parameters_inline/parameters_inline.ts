@@ -0,0 +1,14 @@
+class UsersController {
+	constructor(
+		@Inject(TOKEN)
+		private readonly service: Service,
+	) {}
+
+	constructor(@InjectRepository(User) private readonly users: Repository<User>, @Inject(CACHE) cache: Cache) {}
+
+	findOne(@Param("id") id: string) {}
+
+	update(@Param("id") id: string, @Body() body: UpdateUserDto) {}
+
+	method(param) {}
+}
@@ -0,0 +1,26 @@
+class AppComponent {
+	@Input()
+	name: string;
+
+	@Output() change = new EventEmitter<string>();
+
+	@HostListener("window:resize", ["$event"])
+	onResize(event) {}
+
+	@Input()
+	@Required()
+	value: string;
+
+	@Input() // the name of the user
+	user: string;
+
+	@ViewChild("aVeryLongReferenceNameThatDoesNotFit", { static: true })
+	element: ElementRef<HTMLDivElement>;
+
+	constructor(
+		@Inject(TOKEN)
+		private readonly service: Service,
+	) {}
+
+	method(@Param() param) {}
+}
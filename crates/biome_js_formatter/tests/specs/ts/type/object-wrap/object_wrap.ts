@@ -0,0 +1,8 @@
+type Point = {
+	x: number;
+	y: number;
+};
+
+function move(point: {
+	x: number;
+}) {}
@@ -0,0 +1,319 @@
+use crate::{
+    expr_ext::ClassMemberName, modifier_ext::Modifier, AnyJsClassMember, AnyJsConstructorParameter,
+    JsClassDeclaration, JsClassMemberList, JsConstructorClassMember, TsPropertyParameter,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+use enumflags2::BitFlags;
+
+/// The kind of an accessor, see [AnyJsClassMember::accessor_kind].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessorKind {
+    Getter,
+    Setter,
+}
+
+/// The kind of a class member, see [AnyJsClassMember::member_kind].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClassMemberKind {
+    /// `constructor() {}`
+    Constructor,
+    /// `get a() {}`
+    Getter,
+    /// `[key: string]: number`
+    IndexSignature,
+    /// `a() {}`
+    Method,
+    /// `a = 0`
+    Property,
+    /// `set a(value) {}`
+    Setter,
+    /// `static {}`
+    StaticInitializationBlock,
+}
+
+impl AnyJsClassMember {
+    /// Returns the kind of the member, or `None` for empty and bogus members.
+    ///
+    /// TypeScript signatures have the kind of their implementation.
+    pub fn member_kind(&self) -> Option<ClassMemberKind> {
+        let kind = match self {
+            Self::JsConstructorClassMember(_) | Self::TsConstructorSignatureClassMember(_) => {
+                ClassMemberKind::Constructor
+            }
+            Self::JsGetterClassMember(_) | Self::TsGetterSignatureClassMember(_) => {
+                ClassMemberKind::Getter
+            }
+            Self::TsIndexSignatureClassMember(_) => ClassMemberKind::IndexSignature,
+            Self::JsMethodClassMember(_) | Self::TsMethodSignatureClassMember(_) => {
+                ClassMemberKind::Method
+            }
+            Self::JsPropertyClassMember(_)
+            | Self::TsInitializedPropertySignatureClassMember(_)
+            | Self::TsPropertySignatureClassMember(_) => ClassMemberKind::Property,
+            Self::JsSetterClassMember(_) | Self::TsSetterSignatureClassMember(_) => {
+                ClassMemberKind::Setter
+            }
+            Self::JsStaticInitializationBlockClassMember(_) => {
+                ClassMemberKind::StaticInitializationBlock
+            }
+            Self::JsBogusMember(_) | Self::JsEmptyClassMember(_) | Self::JsMetavariable(_) => {
+                return None;
+            }
+        };
+        Some(kind)
+    }
+
+    /// Returns the name of the member if it's statically known.
+    ///
+    /// Computed names are only resolved when they are literals or constant templates.
+    pub fn member_name(&self) -> Option<ClassMemberName> {
+        self.name().ok()??.name()
+    }
+
+    /// Returns `true` if the member belongs to the class itself rather than to its instances.
+    ///
+    /// Static initialization blocks are considered static.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse_module, JsParserOptions};
+    /// use biome_js_syntax::JsClassMemberList;
+    /// use biome_rowan::{AstNode, AstNodeList};
+    ///
+    /// let parsed = parse_module(
+    ///     "class A { static a = 1; b() {} static get c() {} static {} constructor() {} }",
+    ///     JsParserOptions::default(),
+    /// );
+    /// let members = parsed.syntax().descendants().find_map(JsClassMemberList::cast).unwrap();
+    /// let statics: Vec<_> = members.iter().map(|member| member.is_static()).collect();
+    ///
+    /// assert_eq!(statics, [true, false, true, true, false]);
+    /// ```
+    pub fn is_static(&self) -> bool {
+        let modifiers: BitFlags<Modifier> = match self {
+            Self::JsGetterClassMember(member) => (&member.modifiers()).into(),
+            Self::JsMethodClassMember(member) => (&member.modifiers()).into(),
+            Self::JsPropertyClassMember(member) => (&member.modifiers()).into(),
+            Self::JsSetterClassMember(member) => (&member.modifiers()).into(),
+            Self::TsGetterSignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::TsIndexSignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::TsInitializedPropertySignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::TsMethodSignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::TsPropertySignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::TsSetterSignatureClassMember(member) => (&member.modifiers()).into(),
+            Self::JsStaticInitializationBlockClassMember(_) => return true,
+            Self::JsBogusMember(_)
+            | Self::JsConstructorClassMember(_)
+            | Self::JsEmptyClassMember(_)
+            | Self::JsMetavariable(_)
+            | Self::TsConstructorSignatureClassMember(_) => return false,
+        };
+        modifiers.contains(Modifier::Static)
+    }
+
+    /// Returns the kind of accessor of the member, or `None` if it isn't an accessor.
+    pub fn accessor_kind(&self) -> Option<AccessorKind> {
+        match self {
+            Self::JsGetterClassMember(_) | Self::TsGetterSignatureClassMember(_) => {
+                Some(AccessorKind::Getter)
+            }
+            Self::JsSetterClassMember(_) | Self::TsSetterSignatureClassMember(_) => {
+                Some(AccessorKind::Setter)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl JsClassMemberList {
+    /// Returns the members named `name`, in declaration order.
+    ///
+    /// Members with a name that isn't statically known are never returned.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse_module, JsParserOptions};
+    /// use biome_js_syntax::JsClassMemberList;
+    /// use biome_rowan::{AstNode, AstNodeList};
+    ///
+    /// let parsed = parse_module(
+    ///     "class A { get a() {} set a(v) {} #a = 1; ['a']() {} b() {} [c]() {} }",
+    ///     JsParserOptions::default(),
+    /// );
+    /// let members = parsed.syntax().descendants().find_map(JsClassMemberList::cast).unwrap();
+    ///
+    /// let public_a = members.first().unwrap().member_name().unwrap();
+    /// assert_eq!(members.find_members_by_name(&public_a).count(), 3);
+    ///
+    /// let private_a = members.iter().nth(2).unwrap().member_name().unwrap();
+    /// assert_eq!(members.find_members_by_name(&private_a).count(), 1);
+    /// ```
+    pub fn find_members_by_name<'a>(
+        &self,
+        name: &'a ClassMemberName,
+    ) -> impl Iterator<Item = AnyJsClassMember> + 'a {
+        self.iter()
+            .filter(move |member| member.member_name().as_ref() == Some(name))
+    }
+
+    /// Returns the first accessor of kind `kind` named `name`.
+    ///
+    /// `is_static` selects between the accessors of the class and the accessors of its instances.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse_module, JsParserOptions};
+    /// use biome_js_syntax::{class_member_ext::AccessorKind, JsClassMemberList};
+    /// use biome_rowan::{AstNode, AstNodeList};
+    ///
+    /// let parsed = parse_module(
+    ///     "class A { get a() {} static set a(v) {} set #b(v) {} }",
+    ///     JsParserOptions::default(),
+    /// );
+    /// let members = parsed.syntax().descendants().find_map(JsClassMemberList::cast).unwrap();
+    /// let a = members.first().unwrap().member_name().unwrap();
+    /// let b = members.last().unwrap().member_name().unwrap();
+    ///
+    /// assert!(members.find_accessor(AccessorKind::Getter, &a, false).is_some());
+    /// assert!(members.find_accessor(AccessorKind::Setter, &a, false).is_none());
+    /// assert!(members.find_accessor(AccessorKind::Setter, &a, true).is_some());
+    /// assert!(members.find_accessor(AccessorKind::Setter, &b, false).is_some());
+    /// ```
+    pub fn find_accessor(
+        &self,
+        kind: AccessorKind,
+        name: &ClassMemberName,
+        is_static: bool,
+    ) -> Option<AnyJsClassMember> {
+        self.find_members_by_name(name)
+            .find(|member| member.accessor_kind() == Some(kind) && member.is_static() == is_static)
+    }
+}
+
+impl JsClassDeclaration {
+    /// Find and return the first member named `name_to_lookup`.
+    ///
+    /// The names of private members must include the leading `#`.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse_module, JsParserOptions};
+    /// use biome_js_syntax::JsClassDeclaration;
+    /// use biome_rowan::AstNode;
+    ///
+    /// let parsed = parse_module("class A { a = 0; #b() {} }", JsParserOptions::default());
+    /// let class = parsed.syntax().descendants().find_map(JsClassDeclaration::cast).unwrap();
+    ///
+    /// assert!(class.find_member_by_name("a").is_some());
+    /// assert!(class.find_member_by_name("#b").is_some());
+    /// assert!(class.find_member_by_name("b").is_none());
+    /// ```
+    pub fn find_member_by_name(&self, name_to_lookup: &str) -> Option<AnyJsClassMember> {
+        self.members().iter().find(|member| {
+            member
+                .member_name()
+                .is_some_and(|name| has_name(&name, name_to_lookup))
+        })
+    }
+
+    /// Returns the members of kind `kind`, in declaration order.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse, JsParserOptions};
+    /// use biome_js_syntax::{class_member_ext::ClassMemberKind, JsClassDeclaration, JsFileSource};
+    /// use biome_rowan::AstNode;
+    ///
+    /// let parsed = parse(
+    ///     "class A { constructor() {} a() {} b = 0; d(): void; static {} }",
+    ///     JsFileSource::ts(),
+    ///     JsParserOptions::default(),
+    /// );
+    /// let class = parsed.syntax().descendants().find_map(JsClassDeclaration::cast).unwrap();
+    ///
+    /// assert_eq!(class.find_members_by_kind(ClassMemberKind::Method).count(), 2);
+    /// assert_eq!(class.find_members_by_kind(ClassMemberKind::Property).count(), 1);
+    /// ```
+    pub fn find_members_by_kind(
+        &self,
+        kind: ClassMemberKind,
+    ) -> impl Iterator<Item = AnyJsClassMember> {
+        self.members()
+            .iter()
+            .filter(move |member| member.member_kind() == Some(kind))
+    }
+
+    /// Returns the constructor of the class, if it has one.
+    ///
+    /// Constructor signatures of overloads are skipped.
+    pub fn constructor(&self) -> Option<JsConstructorClassMember> {
+        self.members().iter().find_map(|member| match member {
+            AnyJsClassMember::JsConstructorClassMember(constructor) => Some(constructor),
+            _ => None,
+        })
+    }
+
+    /// Returns the parameters of the constructor that are also properties of the class,
+    /// such as `a` in `constructor(private a: number) {}`.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse, JsParserOptions};
+    /// use biome_js_syntax::{JsClassDeclaration, JsFileSource};
+    /// use biome_rowan::AstNode;
+    ///
+    /// let parsed = parse(
+    ///     "class A { constructor(); constructor(private a: number, b, readonly c = 0) {} }",
+    ///     JsFileSource::ts(),
+    ///     JsParserOptions::default(),
+    /// );
+    /// let class = parsed.syntax().descendants().find_map(JsClassDeclaration::cast).unwrap();
+    /// let properties: Vec<_> = class
+    ///     .constructor_parameter_properties()
+    ///     .map(|parameter| parameter.syntax().text_trimmed().to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(properties, ["private a: number", "readonly c = 0"]);
+    /// ```
+    pub fn constructor_parameter_properties(&self) -> impl Iterator<Item = TsPropertyParameter> {
+        self.constructor()
+            .and_then(|constructor| constructor.parameters().ok())
+            .into_iter()
+            .flat_map(|parameters| parameters.parameters().iter())
+            .filter_map(|parameter| match parameter.ok()? {
+                AnyJsConstructorParameter::TsPropertyParameter(parameter) => Some(parameter),
+                _ => None,
+            })
+    }
+
+    /// Returns the names of the interfaces listed in the `implements` clause of the class.
+    ///
+    /// Qualified names are returned in full, such as `ns.Comparable`.
+    ///
+    /// ```
+    /// use biome_js_parser::{parse, JsParserOptions};
+    /// use biome_js_syntax::{JsClassDeclaration, JsFileSource};
+    /// use biome_rowan::AstNode;
+    ///
+    /// let parsed = parse(
+    ///     "class A implements Iterable<number>, ns.Comparable {}",
+    ///     JsFileSource::ts(),
+    ///     JsParserOptions::default(),
+    /// );
+    /// let class = parsed.syntax().descendants().find_map(JsClassDeclaration::cast).unwrap();
+    ///
+    /// assert_eq!(class.implemented_interface_names(), ["Iterable", "ns.Comparable"]);
+    /// ```
+    pub fn implemented_interface_names(&self) -> Vec<String> {
+        let Some(implements_clause) = self.implements_clause() else {
+            return Vec::new();
+        };
+        implements_clause
+            .types()
+            .iter()
+            .filter_map(|ty| Some(ty.ok()?.name().ok()?.syntax().text_trimmed().to_string()))
+            .collect()
+    }
+}
+
+/// Returns `true` if `name` is written `text` in the source code
+fn has_name(name: &ClassMemberName, text: &str) -> bool {
+    match name {
+        ClassMemberName::Private(name) => text.strip_prefix('#') == Some(name.text()),
+        ClassMemberName::Public(name) => name.text() == text,
+    }
+}
@@ -7,6 +7,7 @@ mod generated;
 pub mod assign_ext;
 pub mod binary_like_expression;
 pub mod binding_ext;
+pub mod class_member_ext;
 pub mod declaration_ext;
 pub mod directive_ext;
 pub mod export_ext;
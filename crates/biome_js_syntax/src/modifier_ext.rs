@@ -1,14 +1,16 @@
+use biome_rowan::{AstNode, AstNodeList};
 use enumflags2::BitFlags;
 
 use crate::{
     AnyJsMethodModifier, AnyJsPropertyModifier, AnyTsIndexSignatureModifier,
     AnyTsMethodSignatureModifier, AnyTsPropertyParameterModifier, AnyTsPropertySignatureModifier,
-    AnyTsTypeParameterModifier, JsMethodModifierList, JsPropertyModifierList, JsSyntaxKind,
-    TsAccessibilityModifier, TsIndexSignatureModifierList, TsMethodSignatureModifierList,
-    TsPropertySignatureModifierList,
+    AnyTsTypeParameterModifier, JsLanguage, JsMethodModifierList, JsPropertyModifierList,
+    JsSyntaxKind, TsAccessibilityModifier, TsIndexSignatureModifierList,
+    TsMethodSignatureModifierList, TsPropertySignatureModifierList,
 };
 
 /// Helpful data structure to make the order of modifiers predictable inside the formatter
+/// and the linter
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[enumflags2::bitflags]
 #[repr(u16)]
@@ -159,6 +161,22 @@ impl From<&TsAccessibilityModifier> for Modifier {
     }
 }
 
+/// Returns the modifiers of `list` sorted by their precedence in [Modifier].
+///
+/// The formatter prints the modifiers in this order, and the rule `useCanonicalOrderOfModifiers`
+/// reports the lists that aren't in this order. The sort is stable, so the decorators keep their
+/// relative order.
+pub fn sort_modifiers_by_precedence<List, Node>(list: &List) -> Vec<Node>
+where
+    Node: AstNode<Language = JsLanguage>,
+    List: AstNodeList<Language = JsLanguage, Node = Node>,
+    Modifier: for<'a> From<&'a Node>,
+{
+    let mut modifiers = list.iter().collect::<Vec<Node>>();
+    modifiers.sort_by_key(|node| Modifier::from(node));
+    modifiers
+}
+
 impl From<&JsMethodModifierList> for enumflags2::BitFlags<Modifier> {
     fn from(value: &JsMethodModifierList) -> Self {
         value
@@ -1,7 +1,13 @@
+use std::num::IntErrorKind;
+
 use biome_rowan::TextRange;
 
+use crate::numbers::split_into_radix_and_number;
 use crate::{JsSyntaxKind, JsSyntaxToken};
 
+/// The value of `Number.MAX_SAFE_INTEGER`, the largest integer that a number represents exactly
+pub const MAX_SAFE_INTEGER: u128 = (1 << 53) - 1;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 /// static values defined in JavaScript's expressions
 pub enum StaticValue {
@@ -152,4 +158,49 @@ impl StaticValue {
     pub fn is_null_or_undefined(&self) -> bool {
         matches!(self, StaticValue::Null(_) | StaticValue::Undefined(_))
     }
+
+    /// Return `true` if the value is an integer greater than `Number.MAX_SAFE_INTEGER`, that a
+    /// number can't represent exactly. The value is either
+    /// 1. An integer literal, such as `9007199254740993` or `0x20000000000001`
+    /// 2. A string of decimal digits, such as `"9007199254740993"`
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_js_syntax::static_value::StaticValue;
+    /// use biome_js_factory::make;
+    ///
+    /// let number = make::js_number_literal("9007199254740993");
+    /// assert!(StaticValue::Number(number).is_unsafe_integer());
+    ///
+    /// let string = make::js_string_literal("9007199254740991");
+    /// assert!(!StaticValue::String(string).is_unsafe_integer());
+    /// ```
+    pub fn is_unsafe_integer(&self) -> bool {
+        match self {
+            StaticValue::Number(token) => {
+                let (radix, digits) = split_into_radix_and_number(token.text_trimmed());
+                // The fractions and the exponents are floating-point literals
+                if radix == 10 && digits.contains(['.', 'e', 'E']) {
+                    return false;
+                }
+                exceeds_max_safe_integer(&digits, radix)
+            }
+            StaticValue::String(_) => {
+                let text = self.text().trim();
+                let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+                !digits.is_empty()
+                    && digits.bytes().all(|byte| byte.is_ascii_digit())
+                    && exceeds_max_safe_integer(digits, 10)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn exceeds_max_safe_integer(digits: &str, radix: u8) -> bool {
+    match u128::from_str_radix(digits, radix.into()) {
+        Ok(value) => value > MAX_SAFE_INTEGER,
+        Err(error) => *error.kind() == IntErrorKind::PosOverflow,
+    }
 }
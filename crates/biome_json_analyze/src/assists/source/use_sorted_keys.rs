@@ -4,16 +4,43 @@ use biome_analyze::{
     SourceActionKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Applicability;
 use biome_json_factory::make::{json_member_list, token};
 use biome_json_syntax::{JsonMember, JsonMemberList, T};
-use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+use biome_rowan::{AstNodeExt, AstSeparatedList, BatchMutationExt, TokenText};
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
 
 declare_source_rule! {
     /// Sorts the keys of a JSON object in natural order
+    ///
+    /// To sort the keys of some files only, enable the assist for these files with `overrides`.
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "pinnedKeys": ["name", "version"],
+    ///         "sortOrder": "lexicographic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### pinnedKeys
+    ///
+    /// The keys that are placed before the other keys, in the given order.
+    /// The other keys are sorted after them.
+    ///
+    /// ### sortOrder
+    ///
+    /// The order of the keys: `"natural"` (default) or `"lexicographic"`.
+    /// In natural order, the numbers in the keys are compared by their value, so `item2` comes before `item10`.
+    /// In lexicographic order, the keys are compared character by character, so `item10` comes before `item2`.
+    ///
     pub UseSortedKeys {
         version: "1.9.0",
         name: "useSortedKeys",
@@ -21,47 +48,52 @@ declare_source_rule! {
     }
 }
 
-#[derive(Eq, PartialEq)]
-pub struct MemberKey {
-    node: JsonMember,
+/// Options for the assist `useSortedKeys`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct UseSortedKeysOptions {
+    /// The keys that are placed before the other keys, in the given order.
+    pub pinned_keys: Box<[Box<str>]>,
+    /// The order of the keys.
+    pub sort_order: SortOrder,
 }
 
-impl Ord for MemberKey {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Sort keys using natural ordering
-        natord::compare(
-            &self.node.name().unwrap().text(),
-            &other.node.name().unwrap().text(),
-        )
-    }
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum SortOrder {
+    /// The numbers in the keys are compared by their value, such as `item2` before `item10`.
+    #[default]
+    Natural,
+    /// The keys are compared character by character, such as `item10` before `item2`.
+    Lexicographic,
+}
+
+pub struct MemberKey {
+    node: JsonMember,
+    name: TokenText,
+    /// The position of the key in the pinned keys
+    pinned_position: Option<usize>,
 }
 
-impl PartialOrd for MemberKey {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl MemberKey {
+    fn cmp(&self, other: &Self, sort_order: SortOrder) -> Ordering {
+        match (self.pinned_position, other.pinned_position) {
+            (Some(position), Some(other_position)) => position.cmp(&other_position),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => match sort_order {
+                SortOrder::Natural => natord::compare(self.name.text(), other.name.text()),
+                SortOrder::Lexicographic => self.name.text().cmp(other.name.text()),
+            },
+        }
     }
 }
 
-pub struct Members(pub BTreeSet<MemberKey>);
+pub struct Members(pub Vec<MemberKey>);
 
 impl Members {
-    /// Returns true if the nodes in the group are already sorted in the file
-    fn is_sorted(&self) -> bool {
-        // The imports are sorted if the text position of each node in the `BTreeMap`
-        // (sorted in natural order) is higher than the previous item in
-        // the sequence
-        let mut iter = self
-            .0
-            .iter()
-            .map(|node| node.node.syntax().text_range().start());
-        let mut previous_start = iter.next().unwrap_or_default();
-        iter.all(|start| {
-            let is_sorted = previous_start < start;
-            previous_start = start;
-            is_sorted
-        })
-    }
-
     fn to_sorted_node(&self) -> JsonMemberList {
         let items = self.0.iter().map(|key| key.node.clone().detach());
 
@@ -83,30 +115,44 @@ impl Rule for UseSortedKeys {
     type Query = Ast<JsonMemberList>;
     type State = Members;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = UseSortedKeysOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
+        let options = ctx.options();
 
         if node.is_empty() {
             return None;
         }
 
-        let state = node
+        let mut members = node
             .iter()
-            .filter_map(|node| {
+            .map(|node| {
                 let node = node.ok()?;
-                Some(MemberKey { node })
+                let name = node.name().ok()?.inner_string_text().ok()?;
+                let pinned_position = options
+                    .pinned_keys
+                    .iter()
+                    .position(|key| key.as_ref() == name.text());
+                Some(MemberKey {
+                    node,
+                    name,
+                    pinned_position,
+                })
             })
-            .collect::<BTreeSet<_>>();
+            .collect::<Option<Vec<_>>>()?;
 
-        let state = Members(state);
-
-        if !state.is_sorted() {
-            Some(state)
-        } else {
-            None
+        // The keys are already sorted if each key comes after the previous one
+        let is_sorted = members
+            .windows(2)
+            .all(|pair| pair[0].cmp(&pair[1], options.sort_order) != Ordering::Greater);
+        if is_sorted {
+            return None;
         }
+
+        // The sort is stable, so the members with the same key keep their order
+        members.sort_by(|member, other| member.cmp(other, options.sort_order));
+        Some(Members(members))
     }
 
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
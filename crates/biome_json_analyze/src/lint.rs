@@ -1,4 +1,5 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+pub mod nursery;
 pub mod suspicious;
-::biome_analyze::declare_category! { pub Lint { kind : Lint , groups : [self :: suspicious :: Suspicious ,] } }
+::biome_analyze::declare_category! { pub Lint { kind : Lint , groups : [self :: nursery :: Nursery , self :: suspicious :: Suspicious ,] } }
@@ -0,0 +1,28 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_conflicting_compiler_options;
+pub mod no_deprecated_compiler_options;
+pub mod no_duplicate_dependencies;
+pub mod no_invalid_semver_range;
+pub mod use_exports_types_condition;
+pub mod use_sorted_dependencies;
+pub mod use_sorted_package_json_fields;
+pub mod use_strict_companion_options;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_conflicting_compiler_options :: NoConflictingCompilerOptions ,
+            self :: no_deprecated_compiler_options :: NoDeprecatedCompilerOptions ,
+            self :: no_duplicate_dependencies :: NoDuplicateDependencies ,
+            self :: no_invalid_semver_range :: NoInvalidSemverRange ,
+            self :: use_exports_types_condition :: UseExportsTypesCondition ,
+            self :: use_sorted_dependencies :: UseSortedDependencies ,
+            self :: use_sorted_package_json_fields :: UseSortedPackageJsonFields ,
+            self :: use_strict_companion_options :: UseStrictCompanionOptions ,
+        ]
+     }
+}
@@ -0,0 +1,248 @@
+use crate::utils::{find_member, find_object_member, is_tsconfig, root_object};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonObjectValue, JsonRoot, TextRange};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Disallow compiler options of `tsconfig.json` that contradict each other.
+    ///
+    /// TypeScript refuses to compile a project whose compiler options contradict each other,
+    /// but the error is only reported when the compiler runs.
+    /// The rule reports the following combinations:
+    ///
+    /// - `moduleResolution` set to `bundler` with a `module` that isn't `preserve` or an ECMAScript module, such as `commonjs`;
+    /// - `moduleResolution` set to `node16` or `nodenext` with a different `module`;
+    /// - `noEmit` and `emitDeclarationOnly` both enabled;
+    /// - `emitDeclarationOnly` enabled without `declaration` or `composite`.
+    ///
+    /// The rule only checks the options declared in the same file.
+    /// An option that is inherited with `extends` isn't taken into account.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "module": "commonjs",
+    ///     "moduleResolution": "bundler"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "noEmit": true,
+    ///     "emitDeclarationOnly": true,
+    ///     "declaration": true
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "module": "esnext",
+    ///     "moduleResolution": "bundler"
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoConflictingCompilerOptions {
+        version: "next",
+        name: "noConflictingCompilerOptions",
+        language: "json",
+        recommended: false,
+    }
+}
+
+pub enum CompilerOptionsConflict {
+    /// `moduleResolution` is `bundler`, and `module` isn't `preserve` or an ECMAScript module
+    BundlerResolution {
+        module_resolution: TextRange,
+        module: TextRange,
+    },
+    /// `moduleResolution` is `node16` or `nodenext`, and `module` is different
+    NodeResolution {
+        module_resolution: TextRange,
+        module: TextRange,
+    },
+    /// `noEmit` and `emitDeclarationOnly` are both enabled
+    NoEmitWithEmitDeclarationOnly {
+        no_emit: TextRange,
+        emit_declaration_only: TextRange,
+    },
+    /// `emitDeclarationOnly` is enabled without `declaration` or `composite`
+    EmitDeclarationOnlyWithoutDeclaration { emit_declaration_only: TextRange },
+}
+
+impl Rule for NoConflictingCompilerOptions {
+    type Query = Ast<JsonRoot>;
+    type State = CompilerOptionsConflict;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(options) =
+            root_object(ctx.query()).and_then(|root| find_object_member(&root, "compilerOptions"))
+        else {
+            return Box::default();
+        };
+        let mut signals = Vec::new();
+
+        if let (Some((module_resolution, resolution)), Some((module, module_kind))) = (
+            string_option(&options, "moduleResolution"),
+            string_option(&options, "module"),
+        ) {
+            match resolution.as_str() {
+                "bundler" if !is_ecmascript_module(&module_kind) => {
+                    signals.push(CompilerOptionsConflict::BundlerResolution {
+                        module_resolution: module_resolution.range(),
+                        module: module.range(),
+                    });
+                }
+                "node16" | "nodenext" if resolution != module_kind => {
+                    signals.push(CompilerOptionsConflict::NodeResolution {
+                        module_resolution: module_resolution.range(),
+                        module: module.range(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(emit_declaration_only) = enabled_option(&options, "emitDeclarationOnly") {
+            if let Some(no_emit) = enabled_option(&options, "noEmit") {
+                signals.push(CompilerOptionsConflict::NoEmitWithEmitDeclarationOnly {
+                    no_emit: no_emit.range(),
+                    emit_declaration_only: emit_declaration_only.range(),
+                });
+            }
+            if enabled_option(&options, "declaration").is_none()
+                && enabled_option(&options, "composite").is_none()
+            {
+                signals.push(
+                    CompilerOptionsConflict::EmitDeclarationOnlyWithoutDeclaration {
+                        emit_declaration_only: emit_declaration_only.range(),
+                    },
+                );
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            CompilerOptionsConflict::BundlerResolution {
+                module_resolution,
+                module,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                module_resolution,
+                markup! {
+                    "The module resolution "<Emphasis>"bundler"</Emphasis>" can't be used with this "<Emphasis>"module"</Emphasis>"."
+                },
+            )
+            .detail(
+                module,
+                markup! {
+                    "The "<Emphasis>"module"</Emphasis>" is set here."
+                },
+            )
+            .note(markup! {
+                "Set "<Emphasis>"module"</Emphasis>" to "<Emphasis>"preserve"</Emphasis>" or to an ECMAScript module, such as "<Emphasis>"esnext"</Emphasis>"."
+            }),
+            CompilerOptionsConflict::NodeResolution {
+                module_resolution,
+                module,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                module_resolution,
+                markup! {
+                    "The module resolution and the "<Emphasis>"module"</Emphasis>" don't match."
+                },
+            )
+            .detail(
+                module,
+                markup! {
+                    "The "<Emphasis>"module"</Emphasis>" is set here."
+                },
+            )
+            .note(markup! {
+                "When "<Emphasis>"moduleResolution"</Emphasis>" is "<Emphasis>"node16"</Emphasis>" or "<Emphasis>"nodenext"</Emphasis>", "<Emphasis>"module"</Emphasis>" must have the same value."
+            }),
+            CompilerOptionsConflict::NoEmitWithEmitDeclarationOnly {
+                no_emit,
+                emit_declaration_only,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                emit_declaration_only,
+                markup! {
+                    "The options "<Emphasis>"emitDeclarationOnly"</Emphasis>" and "<Emphasis>"noEmit"</Emphasis>" can't be both enabled."
+                },
+            )
+            .detail(
+                no_emit,
+                markup! {
+                    <Emphasis>"noEmit"</Emphasis>" is enabled here."
+                },
+            )
+            .note(markup! {
+                "Remove one of the options."
+            }),
+            CompilerOptionsConflict::EmitDeclarationOnlyWithoutDeclaration {
+                emit_declaration_only,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                emit_declaration_only,
+                markup! {
+                    "The option "<Emphasis>"emitDeclarationOnly"</Emphasis>" requires "<Emphasis>"declaration"</Emphasis>" or "<Emphasis>"composite"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Enable "<Emphasis>"declaration"</Emphasis>" to emit the declaration files."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "TypeScript refuses to compile a project whose compiler options contradict each other."
+        }))
+    }
+}
+
+/// Returns the value of the option `name`, and its lowercase text if it's a string
+fn string_option(options: &JsonObjectValue, name: &str) -> Option<(AnyJsonValue, String)> {
+    let value = find_member(options, name)?.value().ok()?;
+    let mut text = value
+        .as_json_string_value()?
+        .inner_string_text()
+        .ok()?
+        .text()
+        .to_string();
+    text.make_ascii_lowercase();
+    Some((value, text))
+}
+
+/// Returns the member of the option `name` if it's set to `true`
+fn enabled_option(options: &JsonObjectValue, name: &str) -> Option<JsonMember> {
+    let member = find_member(options, name)?;
+    let value = member.value().ok()?;
+    let value = value.as_json_boolean_value()?;
+    value
+        .value_token()
+        .is_ok_and(|token| token.text_trimmed() == "true")
+        .then_some(member)
+}
+
+/// Returns `true` if `module` is `preserve` or an ECMAScript module, such as `es2015` or `esnext`
+fn is_ecmascript_module(module: &str) -> bool {
+    module == "preserve" || module == "esnext" || module.starts_with("es20") || module == "es6"
+}
@@ -0,0 +1,162 @@
+use crate::utils::{find_object_member, is_tsconfig, root_object};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMemberName, JsonRoot, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Disallow the deprecated compiler options of `tsconfig.json`.
+    ///
+    /// TypeScript 5.0 deprecated several compiler options, and TypeScript 5.5 stopped supporting them:
+    /// a project that still sets them fails to compile unless `ignoreDeprecations` is set to `"5.0"`.
+    /// Most of them have a replacement, that the diagnostic suggests.
+    ///
+    /// The rule checks the files named `tsconfig.json` and `jsconfig.json`,
+    /// and their variants such as `tsconfig.build.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "importsNotUsedAsValues": "remove"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "target": "ES3"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "verbatimModuleSyntax": true,
+    ///     "target": "ES2022"
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoDeprecatedCompilerOptions {
+        version: "next",
+        name: "noDeprecatedCompilerOptions",
+        language: "json",
+        recommended: false,
+    }
+}
+
+/// The deprecated compiler options, and their replacement
+const DEPRECATED_OPTIONS: [(&str, Option<&str>); 9] = [
+    ("charset", None),
+    ("importsNotUsedAsValues", Some("verbatimModuleSyntax")),
+    ("keyofStringsOnly", None),
+    ("noImplicitUseStrict", None),
+    ("noStrictGenericChecks", None),
+    ("out", Some("outFile")),
+    ("preserveValueImports", Some("verbatimModuleSyntax")),
+    ("suppressExcessPropertyErrors", None),
+    ("suppressImplicitAnyIndexErrors", None),
+];
+
+pub enum DeprecatedCompilerOption {
+    /// A deprecated option, such as `importsNotUsedAsValues`
+    Option {
+        name: JsonMemberName,
+        replacement: Option<&'static str>,
+    },
+    /// The deprecated value `ES3` of `target`
+    TargetEs3(TextRange),
+}
+
+impl Rule for NoDeprecatedCompilerOptions {
+    type Query = Ast<JsonRoot>;
+    type State = DeprecatedCompilerOption;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(compiler_options) =
+            root_object(ctx.query()).and_then(|root| find_object_member(&root, "compilerOptions"))
+        else {
+            return Box::default();
+        };
+        compiler_options
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| {
+                let name = member.name().ok()?;
+                let text = name.inner_string_text().ok()?;
+                if text.text() == "target" {
+                    let value = member.value().ok()?;
+                    let value = value.as_json_string_value()?;
+                    return value
+                        .inner_string_text()
+                        .ok()?
+                        .text()
+                        .eq_ignore_ascii_case("es3")
+                        .then(|| DeprecatedCompilerOption::TargetEs3(value.range()));
+                }
+                let (_, replacement) = DEPRECATED_OPTIONS
+                    .iter()
+                    .find(|(option, _)| *option == text.text())?;
+                Some(DeprecatedCompilerOption::Option {
+                    name,
+                    replacement: *replacement,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        match state {
+            DeprecatedCompilerOption::Option { name, replacement } => {
+                let option = name.inner_string_text().ok()?;
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    name.range(),
+                    markup! {
+                        "The compiler option "<Emphasis>{option.text()}</Emphasis>" is deprecated."
+                    },
+                )
+                .note(markup! {
+                    "TypeScript 5.5 and later versions don't support this option anymore."
+                });
+                Some(match replacement {
+                    Some(replacement) => diagnostic.note(markup! {
+                        "Use "<Emphasis>{replacement}</Emphasis>" instead."
+                    }),
+                    None => diagnostic.note(markup! {
+                        "Remove the option."
+                    }),
+                })
+            }
+            DeprecatedCompilerOption::TargetEs3(range) => Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The target "<Emphasis>"ES3"</Emphasis>" is deprecated."
+                    },
+                )
+                .note(markup! {
+                    "TypeScript 5.5 and later versions don't support this target anymore."
+                })
+                .note(markup! {
+                    "Use "<Emphasis>"ES5"</Emphasis>" or a later target instead."
+                }),
+            ),
+        }
+    }
+}
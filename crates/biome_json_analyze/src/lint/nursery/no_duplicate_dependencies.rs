@@ -0,0 +1,129 @@
+use crate::utils::{find_member, find_object_member, is_package_json, root_object};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMemberName, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Disallow dependencies that are declared in `dependencies` and in another dependency field of `package.json`.
+    ///
+    /// A package that is declared in `dependencies` is always installed,
+    /// so declaring it again in `devDependencies` or `optionalDependencies` has no effect,
+    /// and the two version ranges can drift apart.
+    ///
+    /// The dependencies declared in `dependencies` and in `peerDependencies` are allowed,
+    /// because it's how a package provides a default version of one of its peer dependencies.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "lodash": "^4.17.21"
+    ///   },
+    ///   "devDependencies": {
+    ///     "lodash": "^4.17.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "lodash": "^4.17.21"
+    ///   },
+    ///   "devDependencies": {
+    ///     "typescript": "^5.6.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoDuplicateDependencies {
+        version: "next",
+        name: "noDuplicateDependencies",
+        language: "json",
+        recommended: false,
+    }
+}
+
+/// The fields of `package.json` whose dependencies must not be declared in `dependencies`
+const CHECKED_FIELDS: [&str; 2] = ["devDependencies", "optionalDependencies"];
+
+pub struct DuplicateDependency {
+    /// The name of the dependency in `devDependencies` or `optionalDependencies`
+    duplicate: JsonMemberName,
+    /// The name of the dependency in `dependencies`
+    original: JsonMemberName,
+    field: &'static str,
+}
+
+impl Rule for NoDuplicateDependencies {
+    type Query = Ast<JsonRoot>;
+    type State = DuplicateDependency;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(manifest) = root_object(ctx.query()) else {
+            return Box::default();
+        };
+        let Some(dependencies) = find_object_member(&manifest, "dependencies") else {
+            return Box::default();
+        };
+        CHECKED_FIELDS
+            .iter()
+            .filter_map(|field| Some((*field, find_object_member(&manifest, field)?)))
+            .flat_map(|(field, object)| {
+                object
+                    .json_member_list()
+                    .iter()
+                    .flatten()
+                    .map(move |member| (field, member))
+            })
+            .filter_map(|(field, member)| {
+                let duplicate = member.name().ok()?;
+                let name = duplicate.inner_string_text().ok()?;
+                let original = find_member(&dependencies, name.text())?.name().ok()?;
+                Some(DuplicateDependency {
+                    duplicate,
+                    original,
+                    field,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicateDependency {
+            duplicate,
+            original,
+            field,
+        } = state;
+        let name = duplicate.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                duplicate.range(),
+                markup! {
+                    "The dependency "<Emphasis>{name.text()}</Emphasis>" is declared in "<Emphasis>"dependencies"</Emphasis>" and in "<Emphasis>{field}</Emphasis>"."
+                },
+            )
+            .detail(
+                original.range(),
+                markup! {
+                    "The dependency is already declared here."
+                },
+            )
+            .note(markup! {
+                "The dependencies of "<Emphasis>"dependencies"</Emphasis>" are always installed. Remove one of the declarations."
+            }),
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use crate::utils::{find_object_member, is_package_json, root_object, DEPENDENCY_FIELDS};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonRoot, JsonStringValue};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Disallow invalid version ranges in `package.json`.
+    ///
+    /// npm fails to install a package when the version range of one of its dependencies isn't valid.
+    /// The rule checks the version ranges of the fields `dependencies`, `devDependencies`,
+    /// `peerDependencies`, `optionalDependencies` and `engines` of the files named `package.json`.
+    ///
+    /// The values that aren't version ranges, such as URLs, paths, Git repositories,
+    /// aliases (`npm:`), workspace protocols (`workspace:`) and tags (`latest`), are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "lodash": "^4.17.x.1"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "engines": {
+    ///     "node": ">=18 <"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "lodash": "^4.17.21",
+    ///     "react": "16.x || >=18.0.0-rc.0",
+    ///     "typescript": "latest"
+    ///   },
+    ///   "engines": {
+    ///     "node": ">=18"
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidSemverRange {
+        version: "next",
+        name: "noInvalidSemverRange",
+        language: "json",
+        recommended: false,
+    }
+}
+
+impl Rule for NoInvalidSemverRange {
+    type Query = Ast<JsonRoot>;
+    type State = JsonStringValue;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(manifest) = root_object(ctx.query()) else {
+            return Box::default();
+        };
+        DEPENDENCY_FIELDS
+            .iter()
+            .chain(&["engines"])
+            .filter_map(|field| find_object_member(&manifest, field))
+            .flat_map(|object| object.json_member_list().iter().flatten())
+            .filter_map(|member| {
+                let value = member.value().ok()?;
+                let value = value.as_json_string_value()?;
+                let text = value.inner_string_text().ok()?;
+                let text = text.text();
+                (is_version_range_like(text) && !is_valid_range(text)).then(|| value.clone())
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let range = node.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The version range "<Emphasis>{range.text()}</Emphasis>" is invalid."
+                },
+            )
+            .note(markup! {
+                "npm can't install a dependency whose version range is invalid."
+            })
+            .note(markup! {
+                "See "<Hyperlink href="https://docs.npmjs.com/cli/configuring-npm/package-json#dependencies">"the npm documentation"</Hyperlink>" for the syntax of the version ranges."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `value` looks like a version range and not like
+/// a tag, a URL, a path, or a protocol such as `npm:` or `workspace:`.
+fn is_version_range_like(value: &str) -> bool {
+    let value = value.trim();
+    if value.contains([':', '/']) {
+        return false;
+    }
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_digit() || "^~<>=*".contains(first) => true,
+        Some('v' | 'x' | 'X') => chars
+            .next()
+            .map_or(true, |second| second.is_ascii_digit() || second == '.'),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `range` is a valid range of the [node-semver grammar](https://github.com/npm/node-semver#range-grammar)
+fn is_valid_range(range: &str) -> bool {
+    range.split("||").all(|set| {
+        let set = set.trim();
+        if set.is_empty() {
+            // An empty set matches any version
+            return true;
+        }
+        if let Some((from, to)) = set.split_once(" - ") {
+            return is_valid_partial(from.trim()) && is_valid_partial(to.trim());
+        }
+        is_valid_simple_list(set)
+    })
+}
+
+/// Returns `true` if `set` is a whitespace separated list of comparators,
+/// such as `>=1.2.0 <2`.
+fn is_valid_simple_list(set: &str) -> bool {
+    let mut tokens = set.split_whitespace();
+    while let Some(token) = tokens.next() {
+        let operator_len = token
+            .find(|c: char| !matches!(c, '<' | '>' | '=' | '~' | '^'))
+            .unwrap_or(token.len());
+        let (operator, partial) = token.split_at(operator_len);
+        if !matches!(
+            operator,
+            "" | "<" | ">" | "<=" | ">=" | "=" | "~" | "~>" | "^"
+        ) {
+            return false;
+        }
+        // A space is allowed between the operator and the version
+        let partial = if partial.is_empty() && !operator.is_empty() {
+            let Some(next) = tokens.next() else {
+                return false;
+            };
+            next
+        } else {
+            partial
+        };
+        if !is_valid_partial(partial) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns `true` if `partial` is a version that can omit its minor and patch numbers,
+/// such as `1`, `1.x` or `1.2.3-beta.1`.
+fn is_valid_partial(partial: &str) -> bool {
+    let partial = partial.strip_prefix(['v', '=']).unwrap_or(partial);
+    let (version, qualifier) = match partial.find(['-', '+']) {
+        Some(index) => partial.split_at(index),
+        None => (partial, ""),
+    };
+    let components: Vec<_> = version.split('.').collect();
+    if components.len() > 3 || !components.iter().all(|component| is_valid_xr(component)) {
+        return false;
+    }
+    qualifier.is_empty() || (components.len() == 3 && is_valid_qualifier(qualifier))
+}
+
+/// Returns `true` if `component` is a wildcard or a number without leading zeros
+fn is_valid_xr(component: &str) -> bool {
+    matches!(component, "x" | "X" | "*") || is_valid_number(component)
+}
+
+fn is_valid_number(value: &str) -> bool {
+    !value.is_empty()
+        && value.bytes().all(|byte| byte.is_ascii_digit())
+        && (value == "0" || !value.starts_with('0'))
+}
+
+/// Returns `true` if `qualifier` is a valid pre-release and/or build metadata,
+/// such as `-beta.1+build.5`.
+fn is_valid_qualifier(qualifier: &str) -> bool {
+    let (pre_release, build) = match qualifier.split_once('+') {
+        Some((pre_release, build)) => (pre_release, Some(build)),
+        None => (qualifier, None),
+    };
+    let is_valid_pre_release = match pre_release.strip_prefix('-') {
+        Some(pre_release) => pre_release.split('.').all(|part| {
+            is_valid_identifier(part)
+                && (!part.bytes().all(|byte| byte.is_ascii_digit()) || is_valid_number(part))
+        }),
+        None => pre_release.is_empty(),
+    };
+    is_valid_pre_release && build.map_or(true, |build| build.split('.').all(is_valid_identifier))
+}
+
+fn is_valid_identifier(part: &str) -> bool {
+    !part.is_empty()
+        && part
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+}
@@ -0,0 +1,185 @@
+use crate::utils::{find_member, is_package_json, root_object};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonMemberName, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_lint_rule! {
+    /// Require the `types` condition of the `exports` of `package.json` to be consistent with the `types` field.
+    ///
+    /// When a package declares `exports`, TypeScript resolves its types with the conditions of `exports`
+    /// and ignores the `types` and `typings` fields.
+    /// A package that declares the `types` field without a `types` condition
+    /// has no types for the projects that use the `node16` or `bundler` module resolutions.
+    ///
+    /// The conditions of `exports` are matched in order, so the `types` condition must also
+    /// be the first condition of its object. Otherwise, TypeScript can resolve a JavaScript file
+    /// of a previous condition instead of the declaration file.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "types": "./index.d.ts",
+    ///   "exports": {
+    ///     ".": "./index.js"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "exports": {
+    ///     ".": {
+    ///       "import": "./index.mjs",
+    ///       "types": "./index.d.ts"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///   "types": "./index.d.ts",
+    ///   "exports": {
+    ///     ".": {
+    ///       "types": "./index.d.ts",
+    ///       "import": "./index.mjs"
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseExportsTypesCondition {
+        version: "next",
+        name: "useExportsTypesCondition",
+        language: "json",
+        recommended: false,
+    }
+}
+
+pub enum ExportsTypesIssue {
+    /// The `types` or `typings` field is declared, but `exports` has no `types` condition
+    Missing(JsonMemberName),
+    /// The `types` condition isn't the first condition of its object
+    Misplaced(JsonMemberName),
+}
+
+impl Rule for UseExportsTypesCondition {
+    type Query = Ast<JsonRoot>;
+    type State = ExportsTypesIssue;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(manifest) = root_object(ctx.query()) else {
+            return Box::default();
+        };
+        let Some(exports) =
+            find_member(&manifest, "exports").and_then(|member| member.value().ok())
+        else {
+            return Box::default();
+        };
+        let mut types_conditions = Vec::new();
+        collect_types_conditions(&exports, &mut types_conditions);
+        let has_types_condition = !types_conditions.is_empty();
+
+        let mut signals: Vec<_> = types_conditions
+            .into_iter()
+            .filter(|(_, is_first)| !is_first)
+            .map(|(name, _)| ExportsTypesIssue::Misplaced(name))
+            .collect();
+        if exports.as_json_object_value().is_some() && !has_types_condition {
+            let types_field = find_member(&manifest, "types")
+                .or_else(|| find_member(&manifest, "typings"))
+                .and_then(|member| member.name().ok());
+            if let Some(types_field) = types_field {
+                signals.push(ExportsTypesIssue::Missing(types_field));
+            }
+        }
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        match state {
+            ExportsTypesIssue::Missing(name) => {
+                let field = name.inner_string_text().ok()?;
+                Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        name.range(),
+                        markup! {
+                            "The "<Emphasis>{field.text()}</Emphasis>" field is declared, but "<Emphasis>"exports"</Emphasis>" has no "<Emphasis>"types"</Emphasis>" condition."
+                        },
+                    )
+                    .note(markup! {
+                        "TypeScript ignores the "<Emphasis>{field.text()}</Emphasis>" field when "<Emphasis>"exports"</Emphasis>" is declared and the module resolution is "<Emphasis>"node16"</Emphasis>" or "<Emphasis>"bundler"</Emphasis>"."
+                    })
+                    .note(markup! {
+                        "Add a "<Emphasis>"types"</Emphasis>" condition to the entries of "<Emphasis>"exports"</Emphasis>"."
+                    }),
+                )
+            }
+            ExportsTypesIssue::Misplaced(name) => Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    name.range(),
+                    markup! {
+                        "The "<Emphasis>"types"</Emphasis>" condition must be the first condition of its object."
+                    },
+                )
+                .note(markup! {
+                    "The conditions are matched in order, so the conditions before "<Emphasis>"types"</Emphasis>" can resolve a file that isn't a declaration file."
+                }),
+            ),
+        }
+    }
+}
+
+/// Returns `true` if `object` is an object of conditions, such as `{ "import": "./index.mjs" }`,
+/// and not an object of subpaths, such as `{ ".": "./index.js" }`.
+fn is_conditions_object(object: &JsonObjectValue) -> bool {
+    object.json_member_list().iter().flatten().any(|member| {
+        member
+            .name()
+            .and_then(|name| name.inner_string_text())
+            .is_ok_and(|name| !name.text().starts_with('.'))
+    })
+}
+
+/// Collects the `types` conditions of `value`, and whether they are the first condition of their object
+fn collect_types_conditions(value: &AnyJsonValue, conditions: &mut Vec<(JsonMemberName, bool)>) {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => {
+            let is_conditions = is_conditions_object(object);
+            for (index, member) in object.json_member_list().iter().flatten().enumerate() {
+                if is_conditions {
+                    if let Ok(name) = member.name() {
+                        if name
+                            .inner_string_text()
+                            .is_ok_and(|text| text.text() == "types")
+                        {
+                            conditions.push((name, index == 0));
+                        }
+                    }
+                }
+                if let Ok(value) = member.value() {
+                    collect_types_conditions(&value, conditions);
+                }
+            }
+        }
+        AnyJsonValue::JsonArrayValue(array) => {
+            for element in array.elements().iter().flatten() {
+                collect_types_conditions(&element, conditions);
+            }
+        }
+        _ => {}
+    }
+}
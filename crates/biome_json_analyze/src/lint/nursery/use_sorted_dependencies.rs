@@ -0,0 +1,126 @@
+use crate::utils::{find_object_member, is_package_json, root_object, DEPENDENCY_FIELDS};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_json_factory::make::{json_member_list, token};
+use biome_json_syntax::{JsonMember, JsonObjectValue, JsonRoot, T};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Require the dependencies of `package.json` to be sorted alphabetically.
+    ///
+    /// npm sorts the dependencies when it adds or removes one of them.
+    /// A dependency that is added by hand out of order moves with the next `npm install`,
+    /// which adds unrelated changes to the diff of `package.json`.
+    ///
+    /// The rule checks the fields `dependencies`, `devDependencies`, `peerDependencies` and
+    /// `optionalDependencies` of the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "react": "^18.0.0",
+    ///     "lodash": "^4.17.21"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///   "dependencies": {
+    ///     "lodash": "^4.17.21",
+    ///     "react": "^18.0.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseSortedDependencies {
+        version: "next",
+        name: "useSortedDependencies",
+        language: "json",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseSortedDependencies {
+    type Query = Ast<JsonRoot>;
+    type State = JsonMember;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Box::default();
+        }
+        let Some(manifest) = root_object(ctx.query()) else {
+            return Box::default();
+        };
+        DEPENDENCY_FIELDS
+            .iter()
+            .filter_map(|field| {
+                let dependencies = find_object_member(&manifest, field)?;
+                let members = dependency_members(&dependencies);
+                let is_sorted = members
+                    .windows(2)
+                    .all(|pair| dependency_name(&pair[0]) <= dependency_name(&pair[1]));
+                (!is_sorted).then(|| dependencies.parent::<JsonMember>())?
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, node: &Self::State) -> Option<RuleDiagnostic> {
+        let name = node.name().ok()?;
+        let field = name.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The dependencies of "<Emphasis>{field.text()}</Emphasis>" aren't sorted alphabetically."
+                },
+            )
+            .note(markup! {
+                "npm sorts the dependencies when it installs a package, so the unsorted dependencies are moved by the next installation."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, node: &Self::State) -> Option<JsonRuleAction> {
+        let dependencies = node.value().ok()?.as_json_object_value()?.clone();
+        let list = dependencies.json_member_list();
+        let mut members = dependency_members(&dependencies);
+        members.sort_by_key(dependency_name);
+        let separators = (1..members.len()).map(|_| token(T![,]));
+        let sorted_list = json_member_list(
+            members.into_iter().map(|member| member.detach()),
+            separators,
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(list, sorted_list);
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Sort the dependencies." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn dependency_members(dependencies: &JsonObjectValue) -> Vec<JsonMember> {
+    dependencies.json_member_list().iter().flatten().collect()
+}
+
+fn dependency_name(member: &JsonMember) -> Option<String> {
+    let name = member.name().ok()?.inner_string_text().ok()?;
+    Some(name.text().to_string())
+}
@@ -0,0 +1,178 @@
+use crate::utils::{is_package_json, root_object};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_json_factory::make::{json_member_list, token};
+use biome_json_syntax::{JsonMember, JsonMemberName, JsonRoot, T};
+use biome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Require the fields of `package.json` to follow the conventional order.
+    ///
+    /// npm and most of the tools that edit `package.json` keep its fields in a conventional order:
+    /// the metadata of the package comes first, followed by its entry points, its scripts and its dependencies.
+    /// Following this order makes the manifests of a project easier to read and to compare.
+    ///
+    /// The rule only checks the relative order of the fields it knows.
+    /// The other fields, such as the configurations of the tools, can be placed anywhere.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=package.json
+    /// {
+    ///   "version": "1.0.0",
+    ///   "name": "my-package"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=package.json
+    /// {
+    ///   "name": "my-package",
+    ///   "version": "1.0.0",
+    ///   "prettier": {},
+    ///   "dependencies": {}
+    /// }
+    /// ```
+    ///
+    pub UseSortedPackageJsonFields {
+        version: "next",
+        name: "useSortedPackageJsonFields",
+        language: "json",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// The known fields of `package.json`, in their conventional order
+const FIELDS_ORDER: [&str; 40] = [
+    "name",
+    "version",
+    "description",
+    "keywords",
+    "homepage",
+    "bugs",
+    "license",
+    "author",
+    "contributors",
+    "funding",
+    "files",
+    "type",
+    "exports",
+    "imports",
+    "main",
+    "module",
+    "browser",
+    "types",
+    "typings",
+    "bin",
+    "man",
+    "directories",
+    "repository",
+    "scripts",
+    "config",
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "bundleDependencies",
+    "bundledDependencies",
+    "optionalDependencies",
+    "overrides",
+    "engines",
+    "os",
+    "cpu",
+    "private",
+    "publishConfig",
+    "workspaces",
+    "packageManager",
+];
+
+impl Rule for UseSortedPackageJsonFields {
+    type Query = Ast<JsonRoot>;
+    type State = JsonMemberName;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return None;
+        }
+        let manifest = root_object(ctx.query())?;
+        let members: Vec<_> = manifest.json_member_list().iter().flatten().collect();
+        let sorted_members = sort_known_fields(&members);
+        // Report the first field that isn't at its place
+        members
+            .iter()
+            .zip(&sorted_members)
+            .find(|(member, sorted_member)| member != sorted_member)
+            .and_then(|(member, _)| member.name().ok())
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let field = name.inner_string_text().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The field "<Emphasis>{field.text()}</Emphasis>" isn't in the conventional order of the fields of "<Emphasis>"package.json"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Following the conventional order makes the manifests easier to read and to compare."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsonRuleAction> {
+        let manifest = root_object(ctx.query())?;
+        let list = manifest.json_member_list();
+        let members: Vec<_> = list.iter().flatten().collect();
+        let sorted_members = sort_known_fields(&members);
+        let separators = (1..sorted_members.len()).map(|_| token(T![,]));
+        let sorted_list = json_member_list(
+            sorted_members.into_iter().map(|member| member.detach()),
+            separators,
+        );
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(list, sorted_list);
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Sort the fields." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the position of `member` in the conventional order, if it's a known field
+fn field_order(member: &JsonMember) -> Option<usize> {
+    let name = member.name().ok()?.inner_string_text().ok()?;
+    FIELDS_ORDER.iter().position(|field| *field == name.text())
+}
+
+/// Sorts the known fields of `members`.
+/// The unknown fields keep their positions, and the known fields are moved between them.
+fn sort_known_fields(members: &[JsonMember]) -> Vec<JsonMember> {
+    let mut known_fields: Vec<_> = members
+        .iter()
+        .filter_map(|member| Some((field_order(member)?, member.clone())))
+        .collect();
+    // The sort is stable, so duplicated fields keep their relative order
+    known_fields.sort_by_key(|(order, _)| *order);
+    let mut known_fields = known_fields.into_iter().map(|(_, member)| member);
+    members
+        .iter()
+        .map(|member| match field_order(member) {
+            Some(_) => known_fields.next().unwrap_or_else(|| member.clone()),
+            None => member.clone(),
+        })
+        .collect()
+}
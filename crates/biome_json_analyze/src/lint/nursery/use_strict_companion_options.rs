@@ -0,0 +1,129 @@
+use crate::utils::{find_member, find_object_member, is_tsconfig, root_object};
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::{JsonMemberName, JsonRoot};
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Require the compiler options that complement `strict` in `tsconfig.json`.
+    ///
+    /// The option `strict` enables a family of type checks, but some useful checks aren't part of it.
+    /// When `strict` is enabled, the rule requires the following options to be set:
+    ///
+    /// - `noUncheckedIndexedAccess`
+    /// - `noImplicitOverride`
+    /// - `noImplicitReturns`
+    /// - `noFallthroughCasesInSwitch`
+    ///
+    /// An option that is explicitly disabled is considered as set.
+    /// The rule ignores the files that use `extends`, because the options can be inherited from the extended configuration.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "strict": true
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,file=tsconfig.json
+    /// {
+    ///   "compilerOptions": {
+    ///     "strict": true,
+    ///     "noUncheckedIndexedAccess": true,
+    ///     "noImplicitOverride": true,
+    ///     "noImplicitReturns": true,
+    ///     "noFallthroughCasesInSwitch": false
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseStrictCompanionOptions {
+        version: "next",
+        name: "useStrictCompanionOptions",
+        language: "json",
+        recommended: false,
+    }
+}
+
+/// The compiler options that complement `strict`
+const COMPANION_OPTIONS: [&str; 4] = [
+    "noUncheckedIndexedAccess",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noFallthroughCasesInSwitch",
+];
+
+pub struct MissingCompanionOptions {
+    /// The name of the option `strict`
+    strict: JsonMemberName,
+    missing_options: Box<[&'static str]>,
+}
+
+impl Rule for UseStrictCompanionOptions {
+    type Query = Ast<JsonRoot>;
+    type State = MissingCompanionOptions;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return None;
+        }
+        let root = root_object(ctx.query())?;
+        if find_member(&root, "extends").is_some() {
+            return None;
+        }
+        let compiler_options = find_object_member(&root, "compilerOptions")?;
+        let strict = find_member(&compiler_options, "strict")?;
+        let value = strict.value().ok()?;
+        if value
+            .as_json_boolean_value()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            != "true"
+        {
+            return None;
+        }
+        let missing_options: Box<[_]> = COMPANION_OPTIONS
+            .into_iter()
+            .filter(|option| find_member(&compiler_options, option).is_none())
+            .collect();
+        if missing_options.is_empty() {
+            return None;
+        }
+        Some(MissingCompanionOptions {
+            strict: strict.name().ok()?,
+            missing_options,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let MissingCompanionOptions {
+            strict,
+            missing_options,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                strict.range(),
+                markup! {
+                    "Some compiler options that complement "<Emphasis>"strict"</Emphasis>" aren't set."
+                },
+            )
+            .footer_list(
+                markup! {
+                    "These options enable checks that "<Emphasis>"strict"</Emphasis>" doesn't include. Set the following options:"
+                },
+                missing_options,
+            ),
+        )
+    }
+}
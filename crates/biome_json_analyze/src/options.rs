@@ -3,6 +3,16 @@
 use crate::assists;
 use crate::lint;
 
+pub type NoConflictingCompilerOptions = < lint :: nursery :: no_conflicting_compiler_options :: NoConflictingCompilerOptions as biome_analyze :: Rule > :: Options ;
+pub type NoDeprecatedCompilerOptions = < lint :: nursery :: no_deprecated_compiler_options :: NoDeprecatedCompilerOptions as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateDependencies = < lint :: nursery :: no_duplicate_dependencies :: NoDuplicateDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidSemverRange =
+    <lint::nursery::no_invalid_semver_range::NoInvalidSemverRange as biome_analyze::Rule>::Options;
+pub type UseExportsTypesCondition = < lint :: nursery :: use_exports_types_condition :: UseExportsTypesCondition as biome_analyze :: Rule > :: Options ;
+pub type UseSortedDependencies =
+    <lint::nursery::use_sorted_dependencies::UseSortedDependencies as biome_analyze::Rule>::Options;
 pub type UseSortedKeys =
     <assists::source::use_sorted_keys::UseSortedKeys as biome_analyze::Rule>::Options;
+pub type UseSortedPackageJsonFields = < lint :: nursery :: use_sorted_package_json_fields :: UseSortedPackageJsonFields as biome_analyze :: Rule > :: Options ;
+pub type UseStrictCompanionOptions = < lint :: nursery :: use_strict_companion_options :: UseStrictCompanionOptions as biome_analyze :: Rule > :: Options ;
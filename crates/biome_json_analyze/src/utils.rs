@@ -1,5 +1,70 @@
-use biome_json_syntax::{JsonMember, JsonMemberList, JsonMemberName, JsonObjectValue};
-use biome_rowan::AstNode;
+use biome_json_syntax::{JsonMember, JsonMemberList, JsonMemberName, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList};
+use std::path::Path;
+
+/// The fields of `package.json` that map the dependencies to their version range
+pub(crate) const DEPENDENCY_FIELDS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// A JSON file whose structure is known by the analyzer
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum WellKnownFile {
+    /// The manifest of an npm package, `package.json`
+    PackageJson,
+    /// The configuration of a TypeScript or JavaScript project, such as `tsconfig.json`,
+    /// `tsconfig.build.json` or `jsconfig.json`
+    TsConfig,
+}
+
+impl WellKnownFile {
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        if file_name == "package.json" {
+            return Some(Self::PackageJson);
+        }
+        let stem = file_name.strip_suffix(".json")?;
+        let base = stem.split_once('.').map_or(stem, |(base, _)| base);
+        matches!(base, "tsconfig" | "jsconfig").then_some(Self::TsConfig)
+    }
+}
+
+/// Returns `true` if `path` is the manifest of an npm package
+pub(crate) fn is_package_json(path: &Path) -> bool {
+    WellKnownFile::from_path(path) == Some(WellKnownFile::PackageJson)
+}
+
+/// Returns `true` if `path` is the configuration of a TypeScript or JavaScript project
+pub(crate) fn is_tsconfig(path: &Path) -> bool {
+    WellKnownFile::from_path(path) == Some(WellKnownFile::TsConfig)
+}
+
+/// Returns the top-level object of a well-known file
+pub(crate) fn root_object(root: &JsonRoot) -> Option<JsonObjectValue> {
+    root.value().ok()?.as_json_object_value().cloned()
+}
+
+/// Returns the member of `object` named `name`
+pub(crate) fn find_member(object: &JsonObjectValue, name: &str) -> Option<JsonMember> {
+    object.json_member_list().iter().flatten().find(|member| {
+        member
+            .name()
+            .and_then(|name| name.inner_string_text())
+            .is_ok_and(|text| text.text() == name)
+    })
+}
+
+/// Returns the object value of the member of `object` named `name`
+pub(crate) fn find_object_member(object: &JsonObjectValue, name: &str) -> Option<JsonObjectValue> {
+    find_member(object, name)?
+        .value()
+        .ok()?
+        .as_json_object_value()
+        .cloned()
+}
 
 /// Matches a JSON member name node against a path
 pub fn matches_path(optional_node: Option<&JsonMemberName>, path: &[&str]) -> bool {
@@ -5,7 +5,7 @@ use biome_formatter::separated::TrailingSeparator;
 use biome_formatter::{prelude::*, AttributePosition, BracketSpacing, IndentWidth};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
-    TransformSourceMap,
+    MaxEmptyLines, TransformSourceMap,
 };
 use biome_json_syntax::JsonLanguage;
 use std::default::Default;
@@ -64,6 +64,7 @@ pub struct JsonFormatOptions {
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
+    max_empty_lines: MaxEmptyLines,
     attribute_position: AttributePosition,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     trailing_commas: TrailingCommas,
@@ -131,6 +132,11 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_trailing_commas(mut self, trailing_commas: TrailingCommas) -> Self {
         self.trailing_commas = trailing_commas;
         self
@@ -152,6 +158,10 @@ impl JsonFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_trailing_commas(&mut self, trailing_commas: TrailingCommas) {
         self.trailing_commas = trailing_commas;
     }
@@ -189,6 +199,10 @@ impl FormatOptions for JsonFormatOptions {
         BracketSpacing::default()
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::from(self)
     }
@@ -200,6 +214,7 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.value())?;
-        writeln!(f, "Trailing commas: {}", self.trailing_commas)
+        writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines.value())
     }
 }
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use biome_formatter::token::string::{normalize_string, Quote};
-use biome_json_syntax::JsonSyntaxToken;
+use biome_json_syntax::{JsonSyntaxKind, JsonSyntaxToken};
 use std::borrow::Cow;
 
 pub(crate) fn format_string_token(token: &JsonSyntaxToken) -> CleanedStringLiteralText {
@@ -13,16 +13,27 @@ pub(crate) struct CleanedStringLiteralText<'token> {
 
 impl Format<JsonFormatContext> for CleanedStringLiteralText<'_> {
     fn fmt(&self, f: &mut Formatter<JsonFormatContext>) -> FormatResult<()> {
+        // The unquoted keys of JSON5 are printed as-is
+        if self.token.kind() == JsonSyntaxKind::IDENT {
+            return self.token.format().fmt(f);
+        }
+
         let content = self.token.text_trimmed();
         let raw_content = &content[1..content.len() - 1];
+        // JSON5 allows single-quoted strings: keep their quotes to preserve the dialect
+        let quote = if content.starts_with('\'') {
+            Quote::Single
+        } else {
+            Quote::Double
+        };
 
-        let text = match normalize_string(raw_content, Quote::Double, false) {
+        let text = match normalize_string(raw_content, quote, false) {
             Cow::Borrowed(_) => Cow::Borrowed(content),
             Cow::Owned(raw_content) => Cow::Owned(std::format!(
                 "{}{}{}",
-                Quote::Double.as_char(),
+                quote.as_char(),
                 raw_content,
-                Quote::Double.as_char()
+                quote.as_char()
             )),
         };
 
@@ -7,6 +7,16 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let value_token = node.value_token()?;
+        // The hexadecimal numbers, `Infinity` and `NaN` of JSON5 are printed as-is
+        let unsigned = value_token.text_trimmed().trim_start_matches(['+', '-']);
+        let is_json5_number = matches!(unsigned, "Infinity" | "NaN")
+            || unsigned.starts_with("0x")
+            || unsigned.starts_with("0X");
+        if is_json5_number {
+            value_token.format().fmt(f)
+        } else {
+            format_number_token(&value_token).fmt(f)
+        }
     }
 }
@@ -12,7 +12,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Default)]
 pub struct JsonTestFormatLanguage {
-    _source_type: JsonFileSource,
+    source_type: JsonFileSource,
+}
+
+impl JsonTestFormatLanguage {
+    pub fn new(source_type: JsonFileSource) -> Self {
+        JsonTestFormatLanguage { source_type }
+    }
 }
 
 impl TestFormatLanguage for JsonTestFormatLanguage {
@@ -21,7 +27,11 @@ impl TestFormatLanguage for JsonTestFormatLanguage {
     type FormatLanguage = JsonFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        parse_json(text, JsonParserOptions::default().with_allow_comments()).into()
+        parse_json(
+            text,
+            JsonParserOptions::from(&self.source_type).with_allow_comments(),
+        )
+        .into()
     }
 
     fn to_format_language(
@@ -3,6 +3,7 @@ use std::{env, path::Path};
 use biome_formatter::{IndentStyle, IndentWidth};
 use biome_formatter_test::test_prettier_snapshot::{PrettierSnapshot, PrettierTestFile};
 use biome_json_formatter::{context::JsonFormatOptions, JsonFormatLanguage};
+use biome_json_syntax::JsonFileSource;
 
 mod language;
 
@@ -20,7 +21,7 @@ fn test_snapshot(input: &'static str, _: &str, _: &str, _: &str) {
     let options = JsonFormatOptions::default()
         .with_indent_style(IndentStyle::Space)
         .with_indent_width(IndentWidth::default());
-    let language = language::JsonTestFormatLanguage::default();
+    let language = language::JsonTestFormatLanguage::new(JsonFileSource::json());
     let snapshot = PrettierSnapshot::new(test_file, language, JsonFormatLanguage::new(options));
 
     snapshot.test()
@@ -2,6 +2,7 @@ use biome_formatter_test::check_reformat::CheckReformat;
 use biome_json_formatter::format_node;
 use biome_json_formatter::{context::JsonFormatOptions, JsonFormatLanguage};
 use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::JsonFileSource;
 
 mod language {
     include!("language.rs");
@@ -23,7 +24,7 @@ fn quick_test() {
         .unwrap();
 
     let root = &parse.syntax();
-    let language = language::JsonTestFormatLanguage::default();
+    let language = language::JsonTestFormatLanguage::new(JsonFileSource::json());
 
     let check_reformat = CheckReformat::new(
         root,
@@ -139,6 +139,8 @@ impl<'src> Lexer<'src> {
                     b'\r' | b'\n' => {
                         break;
                     }
+                    // JSON5 also allows the vertical tab and the form feed
+                    0x0b | 0x0c if self.options.allow_json5 => self.advance(1),
                     _ => {
                         let start = self.text_position();
                         self.advance(1);
@@ -310,6 +312,7 @@ impl<'src> Lexer<'src> {
             IDT | DOL => self.lex_identifier(current),
             COM => self.eat_byte(T![,]),
             MIN | DIG | ZER => self.lex_number(current),
+            PLS | PRD if self.options.allow_json5 => self.lex_number(current),
             COL => self.eat_byte(T![:]),
             BTO => self.eat_byte(T!['[']),
             BTC => self.eat_byte(T![']']),
@@ -358,10 +361,16 @@ impl<'src> Lexer<'src> {
 
         let start = self.text_position();
 
-        if current == b'-' {
+        if matches!(current, b'-' | b'+') {
             self.advance(1);
         }
 
+        if self.options.allow_json5 {
+            if let Some(kind) = self.lex_json5_number() {
+                return kind;
+            }
+        }
+
         let mut state = LexNumberState::FirstDigit;
 
         loop {
@@ -403,6 +412,16 @@ impl<'src> Lexer<'src> {
                         {
                             LexNumberState::FractionalPart
                         }
+                        // JSON5 allows a leading or a trailing decimal point: `.5` and `5.`
+                        LexNumberState::FirstDigit
+                            if self.options.allow_json5
+                                && matches!(self.current_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            LexNumberState::FractionalPart
+                        }
+                        LexNumberState::IntegerPart if self.options.allow_json5 => {
+                            LexNumberState::FractionalPart
+                        }
                         LexNumberState::IntegerPart => LexNumberState::Invalid {
                             position: self.text_position(),
                             reason: InvalidNumberReason::MissingFraction,
@@ -452,10 +471,12 @@ impl<'src> Lexer<'src> {
             | LexNumberState::FractionalPart
             | LexNumberState::Exponent => JSON_NUMBER_LITERAL,
             LexNumberState::FirstDigit => {
-                let err = ParseDiagnostic::new(
-                    "Minus must be followed by a digit",
-                    start..self.text_position(),
-                );
+                let message = match current {
+                    b'+' => "Plus must be followed by a digit",
+                    b'.' => "The decimal point must be followed by a digit",
+                    _ => "Minus must be followed by a digit",
+                };
+                let err = ParseDiagnostic::new(message, start..self.text_position());
                 self.diagnostics.push(err);
                 ERROR_TOKEN
             }
@@ -499,7 +520,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.allow_json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -540,6 +561,17 @@ impl<'src> Lexer<'src> {
                             self.advance(1);
                         }
 
+                        Some(_) if self.options.allow_json5 => {
+                            match (self.lex_json5_escape(escape_start), state) {
+                                (Ok(_), _) => {}
+                                (Err(err), LexStringState::InString) => {
+                                    self.diagnostics.push(err);
+                                    state = LexStringState::InvalidEscapeSequence;
+                                }
+                                (Err(_), _) => {}
+                            }
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
@@ -584,7 +616,12 @@ impl<'src> Lexer<'src> {
                 // * quotation mark: (U+0022),
                 // * reverse solidus (U+005C),
                 // * and the **control characters U+0000 to U+001F** <- This
-                ERR | WHS if matches!(state, LexStringState::InString) && chr <= 0x1f => {
+                // JSON5 allows them
+                ERR | WHS
+                    if matches!(state, LexStringState::InString)
+                        && chr <= 0x1f
+                        && !self.options.allow_json5 =>
+                {
                     self.diagnostics.push(
                         ParseDiagnostic::new(
 
@@ -677,11 +714,92 @@ impl<'src> Lexer<'src> {
         Ok(())
     }
 
+    /// Lexes the escape sequences that JSON5 allows in addition to the ones of JSON.
+    /// Assumes that the lexer is positioned after the `\`.
+    ///
+    /// JSON5 allows `\xXX`, `\0`, a backslash followed by a line terminator,
+    /// and any other character that isn't a decimal digit.
+    fn lex_json5_escape(&mut self, escape_start: TextSize) -> Result<(), ParseDiagnostic> {
+        self.assert_at_char_boundary();
+
+        match self.current_byte() {
+            Some(b'x') => {
+                self.advance(1);
+                for _ in 0..2 {
+                    match self.current_byte() {
+                        Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                        _ => {
+                            return Err(ParseDiagnostic::new(
+                                "Invalid hexadecimal escape sequence",
+                                escape_start..self.text_position(),
+                            )
+                            .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F` for '/'."));
+                        }
+                    }
+                }
+            }
+            Some(b'0') if !matches!(self.peek_byte(), Some(b'0'..=b'9')) => self.advance(1),
+            Some(b'0'..=b'9') => {
+                self.advance(1);
+                return Err(ParseDiagnostic::new(
+                    "Invalid escape sequence",
+                    escape_start..self.text_position(),
+                )
+                .with_hint("JSON5 doesn't allow decimal escape sequences, except `\\0` when it isn't followed by a digit."));
+            }
+            // A line continuation
+            Some(b'\r') if self.peek_byte() == Some(b'\n') => self.advance(2),
+            Some(byte) => self.advance_byte_or_char(byte),
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Lexes the numbers of JSON5 that have no equivalent in JSON:
+    /// `Infinity`, `NaN` and the hexadecimal numbers such as `0xFF`.
+    /// Assumes that the lexer is positioned after the sign of the number, if any.
+    ///
+    /// Returns `None` if the number isn't one of them.
+    fn lex_json5_number(&mut self) -> Option<JsonSyntaxKind> {
+        let start = self.text_position();
+        let rest = &self.source[self.position..];
+
+        if let Some(keyword) = ["Infinity", "NaN"]
+            .into_iter()
+            .find(|keyword| rest.starts_with(keyword))
+        {
+            self.advance(keyword.len());
+            return Some(JSON_NUMBER_LITERAL);
+        }
+
+        if rest.starts_with("0x") || rest.starts_with("0X") {
+            self.advance(2);
+            let digits_start = self.position;
+            while matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit()) {
+                self.advance(1);
+            }
+            if self.position == digits_start {
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Missing hexadecimal digits", start..self.text_position())
+                        .with_hint(
+                            "A hexadecimal number must have at least one digit, e.g. `0xFF`.",
+                        ),
+                );
+                return Some(ERROR_TOKEN);
+            }
+            return Some(JSON_NUMBER_LITERAL);
+        }
+
+        None
+    }
+
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
         self.assert_at_char_boundary();
 
+        let start = self.position;
         let mut keyword = KeywordMatcher::from_byte(first);
 
         self.advance_byte_or_char(first);
@@ -712,6 +830,11 @@ impl<'src> Lexer<'src> {
             KeywordMatcher::Null => NULL_KW,
             KeywordMatcher::True => TRUE_KW,
             KeywordMatcher::False => FALSE_KW,
+            _ if self.options.allow_json5
+                && matches!(&self.source[start..self.position], "Infinity" | "NaN") =>
+            {
+                JSON_NUMBER_LITERAL
+            }
             _ => IDENT,
         }
     }
@@ -16,6 +16,12 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Allow the syntax of [JSON5](https://spec.json5.org/):
+    /// unquoted keys, single-quoted strings, hexadecimal numbers, `Infinity` and `NaN`.
+    ///
+    /// JSON5 also allows comments and trailing commas,
+    /// but they are controlled by `allow_comments` and `allow_trailing_commas`.
+    pub allow_json5: bool,
 }
 
 impl JsonParserOptions {
@@ -28,16 +34,25 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Allows the syntax of JSON5, and its comments and trailing commas.
+    pub fn with_allow_json5(mut self) -> Self {
+        self.allow_json5 = true;
+        self.with_allow_comments().with_allow_trailing_commas()
+    }
 }
 
 impl From<&JsonFileSource> for JsonParserOptions {
     fn from(file_source: &JsonFileSource) -> Self {
-        let options = Self::default();
+        let mut options = Self::default();
         if file_source.allow_comments() {
-            options.with_allow_comments();
+            options = options.with_allow_comments();
         }
         if file_source.allow_trailing_commas() {
-            options.with_allow_trailing_commas();
+            options = options.with_allow_trailing_commas();
+        }
+        if file_source.allow_json5() {
+            options = options.with_allow_json5();
         }
         options
     }
@@ -75,7 +75,12 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
 
         IDENT => {
             let m = p.start();
-            p.error(p.err_builder("String values must be double quoted.", p.cur_range()));
+            let message = if p.options().allow_json5 {
+                "String values must be quoted."
+            } else {
+                "String values must be double quoted."
+            };
+            p.error(p.err_builder(message, p.cur_range()));
             p.bump(IDENT);
             Present(m.complete(p, JSON_BOGUS_VALUE))
         }
@@ -289,11 +294,24 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
             p.bump(JSON_STRING_LITERAL);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
+        // JSON5 allows unquoted keys, including the keywords, `Infinity` and `NaN`
+        IDENT | T![null] | T![true] | T![false] if p.options().allow_json5 => {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
+        JSON_NUMBER_LITERAL
+            if p.options().allow_json5 && matches!(p.cur_text(), "Infinity" | "NaN") =>
+        {
+            let m = p.start();
+            p.bump_remap(IDENT);
+            Present(m.complete(p, JSON_MEMBER_NAME))
+        }
         IDENT | T![null] | T![true] | T![false] => {
             let m = p.start();
             p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
             p.bump_remap(IDENT);
-            Present(m.complete(p, JSON_MEMBER_NAME))
+            Present(m.complete(p, JSON_BOGUS))
         }
         _ => Absent,
     }
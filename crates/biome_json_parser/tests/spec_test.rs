@@ -35,10 +35,14 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parse_config = JsonParserOptions {
+    let mut parse_config = JsonParserOptions {
         allow_comments: test_directory.contains("allow_comments"),
         allow_trailing_commas: test_directory.contains("allow_trailing_commas"),
+        allow_json5: false,
     };
+    if test_directory.contains("allow_json5") {
+        parse_config = parse_config.with_allow_json5();
+    }
     let parsed = parse_json(&content, parse_config);
     let formatted_ast = format!("{:#?}", parsed.tree());
 
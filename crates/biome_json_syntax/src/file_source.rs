@@ -13,6 +13,7 @@ use std::{
 pub struct JsonFileSource {
     allow_trailing_commas: bool,
     allow_comments: bool,
+    allow_json5: bool,
 }
 
 impl JsonFileSource {
@@ -143,6 +144,7 @@ impl JsonFileSource {
         Self {
             allow_comments: false,
             allow_trailing_commas: false,
+            allow_json5: false,
         }
     }
 
@@ -150,6 +152,7 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: false,
+            allow_json5: false,
         }
     }
 
@@ -157,6 +160,16 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: true,
+            allow_json5: false,
+        }
+    }
+
+    /// JSON5 is a superset of JSON that also allows comments and trailing commas.
+    pub fn json5() -> Self {
+        Self {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            allow_json5: true,
         }
     }
 
@@ -178,6 +191,15 @@ impl JsonFileSource {
         self.allow_comments
     }
 
+    pub fn with_allow_json5(mut self) -> Self {
+        self.allow_json5 = true;
+        self
+    }
+
+    pub fn allow_json5(&self) -> bool {
+        self.allow_json5
+    }
+
     pub fn is_well_known_json_file(file_name: &OsStr) -> bool {
         Self::WELL_KNOWN_JSON_FILES
             .binary_search(&file_name.as_encoded_bytes())
@@ -245,6 +267,8 @@ impl JsonFileSource {
             | b"sublime-workspace"
             | b"sublime_metrics"
             | b"sublime_session" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            // https://spec.json5.org/#file-extension
+            b"json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -262,6 +286,7 @@ impl JsonFileSource {
         match language_id {
             "json" => Ok(Self::json()),
             "jsonc" | "snippets" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            "json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -10,8 +10,8 @@ use biome_fs::BiomePath;
 use biome_rowan::{TextRange, TextSize};
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams, PullActionsParams,
-    SupportsFeatureParams,
+    FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams, ProjectKey,
+    PullActionsParams, SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -42,8 +42,10 @@ pub(crate) fn code_actions(
 ) -> Result<Option<CodeActionResponse>, LspError> {
     let url = params.text_document.uri.clone();
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
 
     let file_features = &session.workspace.file_features(SupportsFeatureParams {
+        project_key,
         path: biome_path,
         features: FeaturesBuilder::new()
             .with_linter()
@@ -78,11 +80,13 @@ pub(crate) fn code_actions(
 
     let url = params.text_document.uri.clone();
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
     let doc = session.document(&url)?;
     let position_encoding = session.position_encoding();
 
     let diagnostics = params.context.diagnostics;
     let content = session.workspace.get_file_content(GetFileContentParams {
+        project_key,
         path: biome_path.clone(),
     })?;
     let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
@@ -113,6 +117,7 @@ pub(crate) fn code_actions(
 
     debug!("Cursor range {:?}", &cursor_range);
     let result = match session.workspace.pull_actions(PullActionsParams {
+        project_key,
         path: biome_path.clone(),
         range: Some(cursor_range),
         // TODO: compute skip and only based on configuration
@@ -139,6 +144,7 @@ pub(crate) fn code_actions(
         fix_all(
             session,
             &url,
+            project_key,
             biome_path.clone(),
             &doc.line_index,
             &diagnostics,
@@ -227,6 +233,7 @@ pub(crate) fn code_actions(
 fn fix_all(
     session: &Session,
     url: &lsp::Url,
+    project_key: ProjectKey,
     biome_path: BiomePath,
     line_index: &LineIndex,
     diagnostics: &[lsp::Diagnostic],
@@ -235,11 +242,13 @@ fn fix_all(
     let should_format = session
         .workspace
         .file_features(SupportsFeatureParams {
+            project_key,
             path: biome_path.clone(),
             features: FeaturesBuilder::new().with_formatter().build(),
         })?
         .supports_format();
     let fixed = session.workspace.fix_file(FixFileParams {
+        project_key,
         path: biome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
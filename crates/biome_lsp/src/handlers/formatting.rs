@@ -23,10 +23,12 @@ pub(crate) fn format(
 ) -> Result<Option<Vec<TextEdit>>, LspError> {
     let url = params.text_document.uri;
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
 
     let doc = session.document(&url)?;
 
     let file_features = session.workspace.file_features(SupportsFeatureParams {
+        project_key,
         path: biome_path.clone(),
         features: FeaturesBuilder::new().with_formatter().build(),
     })?;
@@ -34,30 +36,17 @@ pub(crate) fn format(
     if file_features.supports_format() {
         debug!("Formatting...");
         let printed = session.workspace.format_file(FormatFileParams {
+            project_key,
             path: biome_path.clone(),
         })?;
 
-        let mut output = printed.into_code();
-        let input = session.workspace.get_file_content(GetFileContentParams {
-            path: biome_path.clone(),
-        })?;
+        let output = printed.into_code();
         if output.is_empty() {
             return Ok(None);
         }
-        match biome_path.extension().map(OsStr::as_encoded_bytes) {
-            Some(b"astro") => {
-                output = AstroFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some(b"vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
-            Some(b"svelte") => {
-                output = SvelteFileHandler::output(input.as_str(), output.as_str());
-            }
-            _ => {}
-        }
 
         let content = session.workspace.get_file_content(GetFileContentParams {
+            project_key,
             path: biome_path.clone(),
         })?;
         let indels =
@@ -78,8 +67,10 @@ pub(crate) fn format_range(
 ) -> Result<Option<Vec<TextEdit>>, LspError> {
     let url = params.text_document.uri;
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
 
     let file_features = session.workspace.file_features(SupportsFeatureParams {
+        project_key,
         path: biome_path.clone(),
         features: FeaturesBuilder::new().with_formatter().build(),
     })?;
@@ -96,6 +87,7 @@ pub(crate) fn format_range(
                 )
             })?;
         let content = session.workspace.get_file_content(GetFileContentParams {
+            project_key,
             path: biome_path.clone(),
         })?;
         let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
@@ -118,11 +110,13 @@ pub(crate) fn format_range(
         };
 
         let formatted = session.workspace.format_range(FormatRangeParams {
+            project_key,
             path: biome_path.clone(),
             range: format_range,
         })?;
 
         let content = session.workspace.get_file_content(GetFileContentParams {
+            project_key,
             path: biome_path.clone(),
         })?;
         let indels =
@@ -151,7 +145,10 @@ pub(crate) fn format_on_type(
 
     let biome_path = session.file_path(&url)?;
 
+    let project_key = session.project_for_path(&biome_path)?;
+
     let file_features = session.workspace.file_features(SupportsFeatureParams {
+        project_key,
         path: biome_path.clone(),
         features: FeaturesBuilder::new().with_formatter().build(),
     })?;
@@ -164,11 +161,13 @@ pub(crate) fn format_on_type(
             .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
 
         let formatted = session.workspace.format_on_type(FormatOnTypeParams {
+            project_key,
             path: biome_path.clone(),
             offset,
         })?;
 
         let content = session.workspace.get_file_content(GetFileContentParams {
+            project_key,
             path: biome_path.clone(),
         })?;
 
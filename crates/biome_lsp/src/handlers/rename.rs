@@ -1,10 +1,12 @@
 use std::collections::HashMap;
+use std::fs;
 
 use crate::converters::from_proto;
+use crate::converters::line_index::LineIndex;
 use crate::diagnostics::LspError;
 use crate::{session::Session, utils};
-use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use anyhow::{anyhow, Context, Result};
+use tower_lsp::lsp_types::{RenameParams, Url, WorkspaceEdit};
 use tracing::trace;
 
 #[tracing::instrument(level = "debug", skip(session), err)]
@@ -14,6 +16,7 @@ pub(crate) fn rename(
 ) -> Result<Option<WorkspaceEdit>, LspError> {
     let url = params.text_document_position.text_document.uri;
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
 
     trace!("Renaming...");
 
@@ -34,6 +37,7 @@ pub(crate) fn rename(
     let result = session
         .workspace
         .rename(biome_service::workspace::RenameParams {
+            project_key,
             path: biome_path,
             symbol_at: cursor_range,
             new_name: params.new_name,
@@ -44,6 +48,23 @@ pub(crate) fn rename(
         url,
         utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?,
     );
+    // A CSS custom property or a CSS class is also renamed in the other files of the project
+    for file in result.other_files {
+        let url = Url::from_file_path(file.path.as_path())
+            .map_err(|()| anyhow!("failed to convert {} to a URL", file.path.display()))?;
+        let line_index = match session.document(&url) {
+            Ok(document) => document.line_index,
+            Err(_) => {
+                let content = fs::read_to_string(file.path.as_path())
+                    .with_context(|| format!("failed to read {}", file.path.display()))?;
+                LineIndex::new(&content)
+            }
+        };
+        changes.insert(
+            url,
+            utils::text_edit(&line_index, file.indels, position_encoding, None)?,
+        );
+    }
 
     let workspace_edit = WorkspaceEdit {
         changes: Some(changes),
@@ -26,9 +26,12 @@ pub(crate) async fn did_open(
     let language_hint = DocumentFileSource::from_language_id(&params.text_document.language_id);
 
     let biome_path = session.file_path(&url)?;
+
+    let project_key = session.project_for_path(&biome_path)?;
     let doc = Document::new(version, &content);
 
     session.workspace.open_file(OpenFileParams {
+        project_key,
         path: biome_path,
         version,
         content,
@@ -55,7 +58,10 @@ pub(crate) async fn did_change(
 
     let biome_path = session.file_path(&url)?;
 
+    let project_key = session.project_for_path(&biome_path)?;
+
     let old_text = session.workspace.get_file_content(GetFileContentParams {
+        project_key,
         path: biome_path.clone(),
     })?;
     tracing::trace!("old document: {:?}", old_text);
@@ -72,6 +78,7 @@ pub(crate) async fn did_change(
     session.insert_document(url.clone(), Document::new(version, &text));
 
     session.workspace.change_file(ChangeFileParams {
+        project_key,
         path: biome_path,
         version,
         content: text,
@@ -92,10 +99,12 @@ pub(crate) async fn did_close(
 ) -> Result<()> {
     let url = params.text_document.uri;
     let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
 
-    session
-        .workspace
-        .close_file(CloseFileParams { path: biome_path })?;
+    session.workspace.close_file(CloseFileParams {
+        project_key,
+        path: biome_path,
+    })?;
 
     session.remove_document(&url);
 
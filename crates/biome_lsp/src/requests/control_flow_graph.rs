@@ -0,0 +1,48 @@
+use crate::converters::from_proto;
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::GetControlFlowGraphParams;
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{Position, TextDocumentIdentifier};
+use tracing::info;
+
+pub const CONTROL_FLOW_GRAPH_REQUEST: &str = "biome/controlFlowGraph";
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlFlowGraphPayload {
+    pub text_document: TextDocumentIdentifier,
+    /// The graph is built for the function that contains this position
+    pub position: Position,
+}
+
+/// Returns the control flow graph of the function under the cursor, in the
+/// Mermaid format
+pub(crate) fn control_flow_graph(
+    session: &Session,
+    payload: ControlFlowGraphPayload,
+) -> Result<String, LspError> {
+    info!("Showing control flow graph");
+    let url = payload.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let project_key = session.project_for_path(&biome_path)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor = from_proto::offset(&doc.line_index, payload.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {url}",
+                payload.position
+            )
+        })?;
+
+    let graph = session
+        .workspace
+        .get_control_flow_graph(GetControlFlowGraphParams {
+            project_key,
+            path: biome_path,
+            cursor,
+        })?;
+    Ok(graph)
+}
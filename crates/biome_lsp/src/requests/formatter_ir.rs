@@ -0,0 +1,25 @@
+use crate::session::Session;
+use anyhow::Result;
+use biome_service::workspace::GetFormatterIRParams;
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{TextDocumentIdentifier, Url};
+use tracing::info;
+
+pub const FORMATTER_IR_REQUEST: &str = "biome/formatterIr";
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FormatterIrPayload {
+    pub text_document: TextDocumentIdentifier,
+}
+
+pub(crate) fn formatter_ir(session: &Session, url: &Url) -> Result<String> {
+    info!("Showing formatter IR");
+    let biome_path = session.file_path(url)?;
+    let project_key = session.project_for_path(&biome_path)?;
+    let ir = session.workspace.get_formatter_ir(GetFormatterIRParams {
+        project_key,
+        path: biome_path,
+    })?;
+    Ok(ir)
+}
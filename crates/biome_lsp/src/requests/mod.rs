@@ -1 +1,3 @@
+pub(crate) mod control_flow_graph;
+pub(crate) mod formatter_ir;
 pub(crate) mod syntax_tree;
@@ -5,7 +5,11 @@ use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::{TextDocumentIdentifier, Url};
 use tracing::info;
 
-pub const SYNTAX_TREE_REQUEST: &str = "biome_lsp/syntaxTree";
+pub const SYNTAX_TREE_REQUEST: &str = "biome/syntaxTree";
+
+/// Name of the request before it was moved to the `biome/` namespace.
+/// It's still registered so that older editor extensions keep working.
+pub const LEGACY_SYNTAX_TREE_REQUEST: &str = "biome_lsp/syntaxTree";
 
 #[derive(Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -16,8 +20,10 @@ pub struct SyntaxTreePayload {
 pub(crate) fn syntax_tree(session: &Session, url: &Url) -> Result<String> {
     info!("Showing syntax tree");
     let biome_path = session.file_path(url)?;
-    let syntax_tree = session
-        .workspace
-        .get_syntax_tree(GetSyntaxTreeParams { path: biome_path })?;
+    let project_key = session.project_for_path(&biome_path)?;
+    let syntax_tree = session.workspace.get_syntax_tree(GetSyntaxTreeParams {
+        project_key,
+        path: biome_path,
+    })?;
     Ok(syntax_tree.ast)
 }
@@ -1,6 +1,10 @@
 use crate::capabilities::server_capabilities;
 use crate::diagnostics::{handle_lsp_error, LspError};
-use crate::requests::syntax_tree::{SyntaxTreePayload, SYNTAX_TREE_REQUEST};
+use crate::requests::control_flow_graph::{ControlFlowGraphPayload, CONTROL_FLOW_GRAPH_REQUEST};
+use crate::requests::formatter_ir::{FormatterIrPayload, FORMATTER_IR_REQUEST};
+use crate::requests::syntax_tree::{
+    SyntaxTreePayload, LEGACY_SYNTAX_TREE_REQUEST, SYNTAX_TREE_REQUEST,
+};
 use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
 };
@@ -69,6 +73,31 @@ impl LSPServer {
         requests::syntax_tree::syntax_tree(&self.session, &url).map_err(into_lsp_error)
     }
 
+    async fn control_flow_graph_request(
+        &self,
+        params: ControlFlowGraphPayload,
+    ) -> LspResult<String> {
+        trace!(
+            "Calling method: {}\n with params: {:?}",
+            CONTROL_FLOW_GRAPH_REQUEST,
+            &params
+        );
+
+        requests::control_flow_graph::control_flow_graph(&self.session, params)
+            .map_err(into_lsp_error)
+    }
+
+    async fn formatter_ir_request(&self, params: FormatterIrPayload) -> LspResult<String> {
+        trace!(
+            "Calling method: {}\n with params: {:?}",
+            FORMATTER_IR_REQUEST,
+            &params
+        );
+
+        let url = params.text_document.uri;
+        requests::formatter_ir::formatter_ir(&self.session, &url).map_err(into_lsp_error)
+    }
+
     #[tracing::instrument(skip(self), name = "biome/rage", level = "debug")]
     async fn rage(&self, params: RageParams) -> LspResult<RageResult> {
         let mut entries = vec![
@@ -289,11 +318,11 @@ impl LanguageServer for LSPServer {
 
         info!("Attempting to load the configuration from 'biome.json' file");
 
-        futures::join!(
-            self.session.load_extension_settings(),
-            self.session.load_workspace_settings(),
-            self.session.load_manifest()
-        );
+        futures::join!(self.session.load_extension_settings(), async {
+            self.session.load_workspace_settings().await;
+            // The manifest belongs to the project registered by the settings
+            self.session.load_manifest().await;
+        });
 
         let msg = format!("Server initialized with PID: {}", std::process::id());
         self.session
@@ -378,6 +407,7 @@ impl LanguageServer for LSPServer {
     async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
         for removed in &params.event.removed {
             if let Ok(project_path) = self.session.file_path(&removed.uri) {
+                self.session.remove_project(&project_path);
                 let result = self
                     .session
                     .workspace
@@ -401,16 +431,20 @@ impl LanguageServer for LSPServer {
                     .workspace
                     .register_project_folder(RegisterProjectFolderParams {
                         path: Some(project_path.to_path_buf()),
-                        set_as_current_workspace: true,
                     })
                     .map_err(into_lsp_error);
 
-                if let Err(err) = result {
-                    error!("Failed to add project to the workspace: {}", err);
-                    self.session
-                        .client
-                        .log_message(MessageType::ERROR, err)
-                        .await;
+                match result {
+                    Ok(project_key) => self
+                        .session
+                        .insert_project(project_path.to_path_buf(), project_key),
+                    Err(err) => {
+                        error!("Failed to add project to the workspace: {}", err);
+                        self.session
+                            .client
+                            .log_message(MessageType::ERROR, err)
+                            .await;
+                    }
                 }
             }
         }
@@ -609,6 +643,12 @@ impl ServerFactory {
         });
 
         builder = builder.custom_method(SYNTAX_TREE_REQUEST, LSPServer::syntax_tree_request);
+        builder = builder.custom_method(LEGACY_SYNTAX_TREE_REQUEST, LSPServer::syntax_tree_request);
+        builder = builder.custom_method(
+            CONTROL_FLOW_GRAPH_REQUEST,
+            LSPServer::control_flow_graph_request,
+        );
+        builder = builder.custom_method(FORMATTER_IR_REQUEST, LSPServer::formatter_ir_request);
 
         // "shutdown" is not part of the Workspace API
         builder = builder.custom_method("biome/shutdown", |server: &LSPServer, (): ()| {
@@ -639,7 +679,9 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, rename_token);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, metrics);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -14,10 +14,10 @@ use biome_fs::{BiomePath, FileSystem};
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, PullDiagnosticsParams, RegisterProjectFolderParams,
-    SetManifestForProjectParams, SupportsFeatureParams,
+    FeaturesBuilder, GetFileContentParams, ProjectKey, PullDiagnosticsParams,
+    RegisterProjectFolderParams, SetManifestForProjectParams, SupportsFeatureParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::Workspace;
@@ -27,7 +27,7 @@ use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
 use std::sync::Arc;
@@ -83,6 +83,9 @@ pub(crate) struct Session {
 
     pub(crate) config_path: Option<PathBuf>,
     pub(crate) manifest_path: Option<PathBuf>,
+
+    /// The projects registered in the workspace, with their folder, in the order of registration
+    projects: RwLock<Vec<(PathBuf, ProjectKey)>>,
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -182,6 +185,7 @@ impl Session {
             config_path: None,
             manifest_path: None,
             notified_broken_configuration: AtomicBool::new(false),
+            projects: RwLock::default(),
         }
     }
 
@@ -297,6 +301,37 @@ impl Session {
         Ok(BiomePath::new(path_to_file))
     }
 
+    /// Records the project registered in the workspace for the folder `path`
+    pub(crate) fn insert_project(&self, path: PathBuf, project_key: ProjectKey) {
+        let mut projects = self.projects.write().unwrap();
+        projects.retain(|(folder, _)| *folder != path);
+        projects.push((path, project_key));
+    }
+
+    /// Forgets the project registered for the folder `path`
+    pub(crate) fn remove_project(&self, path: &Path) {
+        self.projects
+            .write()
+            .unwrap()
+            .retain(|(folder, _)| folder != path);
+    }
+
+    /// Returns the key of the project that contains the file `path`, which is the project with
+    /// the deepest folder among the ancestors of `path`.
+    ///
+    /// The files outside of all the folders, such as unsaved files, belong to the first
+    /// registered project.
+    pub(crate) fn project_for_path(&self, path: &Path) -> Result<ProjectKey, WorkspaceError> {
+        let projects = self.projects.read().unwrap();
+        projects
+            .iter()
+            .filter(|(folder, _)| path.starts_with(folder))
+            .max_by_key(|(folder, _)| folder.components().count())
+            .or_else(|| projects.first())
+            .map(|(_, project_key)| *project_key)
+            .ok_or_else(WorkspaceError::project_not_found)
+    }
+
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
@@ -310,7 +345,9 @@ impl Session {
                     .show_message(MessageType::WARNING, "The configuration file has errors. Biome will report only parsing errors until the configuration is fixed.")
                     .await;
         }
+        let project_key = self.project_for_path(&biome_path)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
+            project_key,
             features: FeaturesBuilder::new()
                 .with_linter()
                 .with_assists()
@@ -340,6 +377,7 @@ impl Session {
                 }
             }
             let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
+                project_key,
                 path: biome_path.clone(),
                 categories: categories.build(),
                 max_diagnostics: u64::MAX,
@@ -349,12 +387,12 @@ impl Session {
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
             let content = self.workspace.get_file_content(GetFileContentParams {
+                project_key,
                 path: biome_path.clone(),
             })?;
             let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
                 Some(b"vue") => VueFileHandler::start(content.as_str()),
                 Some(b"astro") => AstroFileHandler::start(content.as_str()),
-                Some(b"svelte") => SvelteFileHandler::start(content.as_str()),
                 _ => None,
             };
 
@@ -543,31 +581,28 @@ impl Session {
 
                     match result {
                         Ok((vcs_base_path, gitignore_matches)) => {
-                            let register_result =
+                            let project_path =
                                 if let ConfigurationPathHint::FromWorkspace(path) = &base_path {
-                                    // We don't need the key
-                                    self.workspace
-                                        .register_project_folder(RegisterProjectFolderParams {
-                                            path: Some(path.clone()),
-                                            // This is naive, but we don't know if the user has a file already open or not, so we register every project as the current one.
-                                            // The correct one is actually set when the LSP calls `textDocument/didOpen`
-                                            set_as_current_workspace: true,
-                                        })
-                                        .err()
+                                    Some(path.clone())
                                 } else {
-                                    self.workspace
-                                        .register_project_folder(RegisterProjectFolderParams {
-                                            path: fs.working_directory(),
-                                            set_as_current_workspace: true,
-                                        })
-                                        .err()
+                                    fs.working_directory()
                                 };
-                            if let Some(error) = register_result {
-                                error!("Failed to register the project folder: {}", error);
-                                self.client.log_message(MessageType::ERROR, &error).await;
-                                return ConfigurationStatus::Error;
-                            }
+                            let register_result = self.workspace.register_project_folder(
+                                RegisterProjectFolderParams {
+                                    path: project_path.clone(),
+                                },
+                            );
+                            let project_key = match register_result {
+                                Ok(project_key) => project_key,
+                                Err(error) => {
+                                    error!("Failed to register the project folder: {}", error);
+                                    self.client.log_message(MessageType::ERROR, &error).await;
+                                    return ConfigurationStatus::Error;
+                                }
+                            };
+                            self.insert_project(project_path.unwrap_or_default(), project_key);
                             let result = self.workspace.update_settings(UpdateSettingsParams {
+                                project_key,
                                 workspace_directory: fs.working_directory(),
                                 configuration,
                                 vcs_base_path,
@@ -607,6 +642,13 @@ impl Session {
             .map(PathBuf::from)
             .or(self.base_path());
         if let Some(base_path) = base_path {
+            let project_key = match self.project_for_path(&base_path) {
+                Ok(project_key) => project_key,
+                Err(err) => {
+                    error!("{}", err);
+                    return;
+                }
+            };
             let result = self.fs.auto_search(&base_path, &["package.json"], false);
             match result {
                 Ok(result) => {
@@ -615,6 +657,7 @@ impl Session {
                         let result =
                             self.workspace
                                 .set_manifest_for_project(SetManifestForProjectParams {
+                                    project_key,
                                     manifest_path: biome_path.clone(),
                                     content: result.content,
                                     version: 0,
@@ -7,7 +7,9 @@ use biome_lsp::LSPServer;
 use biome_lsp::ServerFactory;
 use biome_lsp::WorkspaceSettings;
 use biome_service::workspace::GetSyntaxTreeResult;
-use biome_service::workspace::{GetFileContentParams, GetSyntaxTreeParams};
+use biome_service::workspace::{
+    GetFileContentParams, GetSyntaxTreeParams, ProjectKey, RegisterProjectFolderParams,
+};
 use biome_service::DynRef;
 use futures::channel::mpsc::{channel, Sender};
 use futures::Sink;
@@ -16,7 +18,7 @@ use futures::Stream;
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, json, to_value};
 use std::any::type_name;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -341,6 +343,17 @@ impl Server {
         .await
     }
 
+    /// Registers a project through the `biome/register_project_folder` request, and returns its key
+    async fn register_project(&mut self) -> Result<ProjectKey> {
+        self.request(
+            "biome/register_project_folder",
+            "register_project_folder",
+            RegisterProjectFolderParams { path: None },
+        )
+        .await?
+        .context("biome/register_project_folder returned None")
+    }
+
     /// Basic implementation of the `biome/shutdown` request for tests
     async fn biome_shutdown(&mut self) -> Result<()> {
         self.request::<_, ()>("biome/shutdown", "_biome_shutdown", ())
@@ -491,11 +504,13 @@ async fn document_lifecycle() -> Result<()> {
         )
         .await?;
 
+    let project_key = server.register_project().await?;
     let res: GetSyntaxTreeResult = server
         .request(
             "biome/get_syntax_tree",
             "get_syntax_tree",
             GetSyntaxTreeParams {
+                project_key,
                 path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
             },
         )
@@ -627,6 +642,109 @@ async fn document_no_extension() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn debug_requests() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("debugger;\n").await?;
+
+    let syntax_tree: String = server
+        .request(
+            "biome/syntaxTree",
+            "syntax_tree",
+            json!({ "textDocument": { "uri": url!("document.js") } }),
+        )
+        .await?
+        .context("syntaxTree returned None")?;
+    assert_eq!(
+        syntax_tree,
+        r#"JsModule {
+    bom_token: missing (optional),
+    interpreter_token: missing (optional),
+    directives: JsDirectiveList [],
+    items: JsModuleItemList [
+        JsDebuggerStatement {
+            debugger_token: DEBUGGER_KW@0..8 "debugger" [] [],
+            semicolon_token: SEMICOLON@8..9 ";" [] [],
+        },
+    ],
+    eof_token: EOF@9..10 "" [Newline("\n")] [],
+}"#
+    );
+
+    let formatter_ir: String = server
+        .request(
+            "biome/formatterIr",
+            "formatter_ir",
+            json!({ "textDocument": { "uri": url!("document.js") } }),
+        )
+        .await?
+        .context("formatterIr returned None")?;
+    assert_eq!(formatter_ir, r#"["debugger;", hard_line_break]"#);
+
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("function test () { return; }\nconst value = 1;\n"),
+            }],
+        )
+        .await?;
+
+    let control_flow_graph: String = server
+        .request(
+            "biome/controlFlowGraph",
+            "control_flow_graph",
+            json!({
+                "textDocument": { "uri": url!("document.js") },
+                "position": { "line": 0, "character": 20 }
+            }),
+        )
+        .await?
+        .context("controlFlowGraph returned None")?;
+    assert_eq!(
+        control_flow_graph,
+        "flowchart TB
+    block_0[\"<b>block_0</b><br/>Return(JS_RETURN_STATEMENT 19..26)<br/>Return\"]\n\n"
+    );
+
+    // Outside of a function, the graph is the one of the module
+    let control_flow_graph: String = server
+        .request(
+            "biome/controlFlowGraph",
+            "control_flow_graph",
+            json!({
+                "textDocument": { "uri": url!("document.js") },
+                "position": { "line": 1, "character": 6 }
+            }),
+        )
+        .await?
+        .context("controlFlowGraph returned None")?;
+    assert_eq!(
+        control_flow_graph,
+        "flowchart TB
+    block_0[\"<b>block_0</b><br/>Statement(JS_NUMBER_LITERAL_EXPRESSION 43..44)<br/>Return\"]\n\n"
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_diagnostics() -> Result<()> {
     let factory = ServerFactory::default();
@@ -2080,11 +2198,13 @@ isSpreadAssignment;
         )
         .await?;
 
+    let project_key = server.register_project().await?;
     let actual: String = server
         .request(
             "biome/get_file_content",
             "get_file_content",
             GetFileContentParams {
+                project_key,
                 path: BiomePath::new(url!("document.js").to_file_path().unwrap()),
             },
         )
@@ -7,10 +7,10 @@ use biome_markdown_syntax::{
     MarkdownSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
-pub fn md_bullet_list_item(md_bullet_list: MdBulletList) -> MdBulletListItem {
-    MdBulletListItem::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+pub fn md_bullet_list_block(items: MdListItemList) -> MdBulletListBlock {
+    MdBulletListBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET_LIST_BLOCK,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
     ))
 }
 pub fn md_document(value: MdBlockList, eof_token: SyntaxToken) -> MdDocumentBuilder {
@@ -41,11 +41,43 @@ impl MdDocumentBuilder {
         ))
     }
 }
-pub fn md_fenced_code_block(md_textual: MdTextual) -> MdFencedCodeBlock {
-    MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
-    ))
+pub fn md_fenced_code_block(
+    l_fence_token: SyntaxToken,
+    content: MdLineList,
+) -> MdFencedCodeBlockBuilder {
+    MdFencedCodeBlockBuilder {
+        l_fence_token,
+        content,
+        info_token: None,
+        r_fence_token: None,
+    }
+}
+pub struct MdFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    content: MdLineList,
+    info_token: Option<SyntaxToken>,
+    r_fence_token: Option<SyntaxToken>,
+}
+impl MdFencedCodeBlockBuilder {
+    pub fn with_info_token(mut self, info_token: SyntaxToken) -> Self {
+        self.info_token = Some(info_token);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
+        self
+    }
+    pub fn build(self) -> MdFencedCodeBlock {
+        MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
 }
 pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
     MdHardLine::unwrap_cast(SyntaxNode::new_detached(
@@ -53,190 +85,194 @@ pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_hash(hash_token: SyntaxToken) -> MdHash {
-    MdHash::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HASH,
-        [Some(SyntaxElement::Token(hash_token))],
-    ))
-}
-pub fn md_header(before: MdHashList, after: MdHashList) -> MdHeaderBuilder {
+pub fn md_header(before_token: SyntaxToken, content: MdInlineItemList) -> MdHeaderBuilder {
     MdHeaderBuilder {
-        before,
-        after,
-        md_paragraph: None,
+        before_token,
+        content,
+        after_token: None,
     }
 }
 pub struct MdHeaderBuilder {
-    before: MdHashList,
-    after: MdHashList,
-    md_paragraph: Option<MdParagraph>,
+    before_token: SyntaxToken,
+    content: MdInlineItemList,
+    after_token: Option<SyntaxToken>,
 }
 impl MdHeaderBuilder {
-    pub fn with_md_paragraph(mut self, md_paragraph: MdParagraph) -> Self {
-        self.md_paragraph = Some(md_paragraph);
+    pub fn with_after_token(mut self, after_token: SyntaxToken) -> Self {
+        self.after_token = Some(after_token);
         self
     }
     pub fn build(self) -> MdHeader {
         MdHeader::unwrap_cast(SyntaxNode::new_detached(
             MarkdownSyntaxKind::MD_HEADER,
             [
-                Some(SyntaxElement::Node(self.before.into_syntax())),
-                self.md_paragraph
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Node(self.after.into_syntax())),
+                Some(SyntaxElement::Token(self.before_token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.after_token.map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
 }
-pub fn md_html_block(md_textual: MdTextual) -> MdHtmlBlock {
+pub fn md_html_block(content: MdLineList) -> MdHtmlBlock {
     MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_HTML_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_indent(value_token: SyntaxToken) -> MdIndent {
-    MdIndent::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT,
-        [Some(SyntaxElement::Token(value_token))],
-    ))
-}
-pub fn md_indent_code_block(md_textual: MdTextual) -> MdIndentCodeBlock {
+pub fn md_indent_code_block(content: MdLineList) -> MdIndentCodeBlock {
     MdIndentCodeBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_inline_code(md_textual: MdTextual) -> MdInlineCode {
+pub fn md_inline_code(value_token: SyntaxToken) -> MdInlineCode {
     MdInlineCode::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INLINE_CODE,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_inline_emphasis(md_textual: MdTextual) -> MdInlineEmphasis {
+pub fn md_inline_emphasis(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineEmphasis {
     MdInlineEmphasis::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INLINE_EMPHASIS,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
     ))
 }
-pub fn md_inline_image(alt: MdTextual, src: MdTextual) -> MdInlineImageBuilder {
-    MdInlineImageBuilder {
-        alt,
-        src,
-        title: None,
-    }
+pub fn md_inline_strong(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineStrong {
+    MdInlineStrong::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_STRONG,
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
+    ))
 }
-pub struct MdInlineImageBuilder {
-    alt: MdTextual,
-    src: MdTextual,
-    title: Option<MdTextual>,
+pub fn md_line(value_token: SyntaxToken) -> MdLine {
+    MdLine::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
 }
-impl MdInlineImageBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
-        self
-    }
-    pub fn build(self) -> MdInlineImage {
-        MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_IMAGE,
-            [
-                Some(SyntaxElement::Node(self.alt.into_syntax())),
-                Some(SyntaxElement::Node(self.src.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
+pub fn md_list_item(marker_token: SyntaxToken, content: MdBlockList) -> MdListItem {
+    MdListItem::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LIST_ITEM,
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
+    ))
+}
+pub fn md_mdx_esm_block(content: MdLineList) -> MdMdxEsmBlock {
+    MdMdxEsmBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_MDX_ESM_BLOCK,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_mdx_flow_block(content: MdLineList) -> MdMdxFlowBlock {
+    MdMdxFlowBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_MDX_FLOW_BLOCK,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_ordered_list_block(items: MdListItemList) -> MdOrderedListBlock {
+    MdOrderedListBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST_BLOCK,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn md_paragraph(content: MdInlineItemList) -> MdParagraph {
+    MdParagraph::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_PARAGRAPH,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
+}
+pub fn md_quote(content: MdLineList) -> MdQuote {
+    MdQuote::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_QUOTE,
+        [Some(SyntaxElement::Node(content.into_syntax()))],
+    ))
 }
-pub fn md_inline_link(label: MdTextual, url: MdTextual) -> MdInlineLinkBuilder {
-    MdInlineLinkBuilder {
-        label,
-        url,
-        title: None,
+pub fn md_setext_header(content: MdInlineItemList, underline_token: SyntaxToken) -> MdSetextHeader {
+    MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_SETEXT_HEADER,
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
+    ))
+}
+pub fn md_table(header: MdTableRow, delimiter: MdTableRow, rows: MdTableRowList) -> MdTable {
+    MdTable::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE,
+        [
+            Some(SyntaxElement::Node(header.into_syntax())),
+            Some(SyntaxElement::Node(delimiter.into_syntax())),
+            Some(SyntaxElement::Node(rows.into_syntax())),
+        ],
+    ))
+}
+pub fn md_table_cell(content: MdInlineItemList) -> MdTableCellBuilder {
+    MdTableCellBuilder {
+        content,
+        l_pipe_token: None,
     }
 }
-pub struct MdInlineLinkBuilder {
-    label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+pub struct MdTableCellBuilder {
+    content: MdInlineItemList,
+    l_pipe_token: Option<SyntaxToken>,
 }
-impl MdInlineLinkBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
+impl MdTableCellBuilder {
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
         self
     }
-    pub fn build(self) -> MdInlineLink {
-        MdInlineLink::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_LINK,
+    pub fn build(self) -> MdTableCell {
+        MdTableCell::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_CELL,
             [
-                Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
             ],
         ))
     }
 }
-pub fn md_link_block(label: MdTextual, url: MdTextual) -> MdLinkBlockBuilder {
-    MdLinkBlockBuilder {
-        label,
-        url,
-        title: None,
+pub fn md_table_row(cells: MdTableCellList) -> MdTableRowBuilder {
+    MdTableRowBuilder {
+        cells,
+        r_pipe_token: None,
     }
 }
-pub struct MdLinkBlockBuilder {
-    label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+pub struct MdTableRowBuilder {
+    cells: MdTableCellList,
+    r_pipe_token: Option<SyntaxToken>,
 }
-impl MdLinkBlockBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
-        self.title = Some(title);
+impl MdTableRowBuilder {
+    pub fn with_r_pipe_token(mut self, r_pipe_token: SyntaxToken) -> Self {
+        self.r_pipe_token = Some(r_pipe_token);
         self
     }
-    pub fn build(self) -> MdLinkBlock {
-        MdLinkBlock::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_LINK_BLOCK,
+    pub fn build(self) -> MdTableRow {
+        MdTableRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_ROW,
             [
-                Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
-                self.title
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+                self.r_pipe_token.map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
 }
-pub fn md_order_list_item(md_bullet_list: MdBulletList) -> MdOrderListItem {
-    MdOrderListItem::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
-    ))
-}
-pub fn md_paragraph(md_paragraph_item_list: MdParagraphItemList) -> MdParagraph {
-    MdParagraph::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_PARAGRAPH,
-        [Some(SyntaxElement::Node(
-            md_paragraph_item_list.into_syntax(),
-        ))],
-    ))
-}
-pub fn md_quote(any_md_block: AnyMdBlock) -> MdQuote {
-    MdQuote::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_QUOTE,
-        [Some(SyntaxElement::Node(any_md_block.into_syntax()))],
-    ))
-}
-pub fn md_setext_header(md_paragraph: MdParagraph) -> MdSetextHeader {
-    MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_SETEXT_HEADER,
-        [Some(SyntaxElement::Node(md_paragraph.into_syntax()))],
-    ))
-}
-pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
-    MdSoftBreak::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_SOFT_BREAK,
-        [Some(SyntaxElement::Token(value_token))],
-    ))
-}
 pub fn md_textual(value_token: SyntaxToken) -> MdTextual {
     MdTextual::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_TEXTUAL,
@@ -261,49 +297,61 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_bullet_list<I>(items: I) -> MdBulletList
+pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = AnyMdInline>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdBulletList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET_LIST,
+    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_hash_list<I>(items: I) -> MdHashList
+pub fn md_line_list<I>(items: I) -> MdLineList
 where
-    I: IntoIterator<Item = MdHash>,
+    I: IntoIterator<Item = MdLine>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdHashList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HASH_LIST,
+    MdLineList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_order_list<I>(items: I) -> MdOrderList
+pub fn md_list_item_list<I>(items: I) -> MdListItemList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = MdListItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdOrderList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST,
+    MdListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LIST_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_paragraph_item_list<I>(items: I) -> MdParagraphItemList
+pub fn md_table_cell_list<I>(items: I) -> MdTableCellList
 where
-    I: IntoIterator<Item = AnyMdInline>,
+    I: IntoIterator<Item = MdTableCell>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdTableCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn md_table_row_list<I>(items: I) -> MdTableRowList
+where
+    I: IntoIterator<Item = MdTableRow>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdParagraphItemList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_PARAGRAPH_ITEM_LIST,
+    MdTableRowList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_ROW_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
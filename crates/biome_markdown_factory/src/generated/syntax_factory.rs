@@ -15,12 +15,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
-            MD_BULLET_LIST_ITEM => {
+            MD_BULLET_LIST_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdListItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -28,11 +28,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_BULLET_LIST_ITEM.to_bogus(),
+                        MD_BULLET_LIST_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_BULLET_LIST_ITEM, children)
+                slots.into_node(MD_BULLET_LIST_BLOCK, children)
             }
             MD_DOCUMENT => {
                 let mut elements = (&children).into_iter();
@@ -69,10 +69,31 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_FENCE_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_LINE_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLineList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -105,42 +126,26 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HARD_LINE, children)
             }
-            MD_HASH => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [#] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(MD_HASH.to_bogus(), children.into_iter().map(Some));
-                }
-                slots.into_node(MD_HASH, children)
-            }
             MD_HEADER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdHashList::can_cast(element.kind()) {
+                    if element.kind() == MD_HASH_SEQUENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHashList::can_cast(element.kind()) {
+                    if element.kind() == MD_HASH_SEQUENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -159,7 +164,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -173,31 +178,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == MD_INDENT_CHUNK_LITERAL {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_INDENT, children)
-            }
             MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -216,7 +202,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_INLINE_CODE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -232,10 +218,24 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_INLINE_EMPHASIS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [*] | T![_]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [*] | T![_]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -249,26 +249,26 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_INLINE_EMPHASIS, children)
             }
-            MD_INLINE_IMAGE => {
+            MD_INLINE_STRONG => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [**] | T![__]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [**] | T![__]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -276,32 +276,41 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE.to_bogus(),
+                        MD_INLINE_STRONG.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE, children)
+                slots.into_node(MD_INLINE_STRONG, children)
             }
-            MD_INLINE_LINK => {
+            MD_LINE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_LINE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_LINE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_LINE, children)
+            }
+            MD_LIST_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == MD_LIST_MARKER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -309,32 +318,37 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_LINK.to_bogus(),
+                        MD_LIST_ITEM.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_LINK, children)
+                slots.into_node(MD_LIST_ITEM, children)
             }
-            MD_LINK_BLOCK => {
+            MD_MDX_ESM_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_MDX_ESM_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(MD_MDX_ESM_BLOCK, children)
+            }
+            MD_MDX_FLOW_BLOCK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -342,18 +356,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_LINK_BLOCK.to_bogus(),
+                        MD_MDX_FLOW_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_LINK_BLOCK, children)
+                slots.into_node(MD_MDX_FLOW_BLOCK, children)
             }
-            MD_ORDER_LIST_ITEM => {
+            MD_ORDERED_LIST_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdListItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -361,18 +375,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        MD_ORDERED_LIST_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_ORDER_LIST_ITEM, children)
+                slots.into_node(MD_ORDERED_LIST_BLOCK, children)
             }
             MD_PARAGRAPH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraphItemList::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -391,7 +405,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyMdBlock::can_cast(element.kind()) {
+                    if MdLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -404,10 +418,17 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_SETEXT_HEADER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_SETEXT_UNDERLINE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -421,12 +442,49 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TABLE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_SOFT_BREAK_LITERAL {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRowList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_TABLE, children)
+            }
+            MD_TABLE_CELL => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -434,11 +492,37 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TABLE_CELL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TABLE_CELL, children)
+            }
+            MD_TABLE_ROW => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if MdTableCellList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_TABLE_ROW.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_TABLE_ROW, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -479,12 +563,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.into_node(MD_THEMATIC_BREAK_BLOCK, children)
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
-            MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
-            MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
-            MD_PARAGRAPH_ITEM_LIST => {
+            MD_INLINE_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
             }
+            MD_LINE_LIST => Self::make_node_list_syntax(kind, children, MdLine::can_cast),
+            MD_LIST_ITEM_LIST => Self::make_node_list_syntax(kind, children, MdListItem::can_cast),
+            MD_TABLE_CELL_LIST => {
+                Self::make_node_list_syntax(kind, children, MdTableCell::can_cast)
+            }
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
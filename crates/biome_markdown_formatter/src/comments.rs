@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use biome_formatter::comments::{CommentKind, CommentStyle, Comments, SourceComment};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_rowan::SyntaxTriviaPieceComments;
+
+pub type MarkdownComments = Comments<MarkdownLanguage>;
+
+#[derive(Default)]
+pub struct FormatMarkdownLeadingComment;
+
+impl FormatRule<SourceComment<MarkdownLanguage>> for FormatMarkdownLeadingComment {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<MarkdownLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct MarkdownCommentStyle;
+
+impl CommentStyle for MarkdownCommentStyle {
+    type Language = MarkdownLanguage;
+
+    fn is_suppression(_text: &str) -> bool {
+        // The HTML comments of Markdown are blocks or inline items, not trivia
+        false
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Block
+    }
+}
@@ -0,0 +1,166 @@
+use crate::MarkdownCommentStyle;
+use biome_formatter::{prelude::*, AttributePosition, BracketSpacing, IndentWidth};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownComments};
+use biome_markdown_syntax::{MarkdownFileSource, MarkdownLanguage};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_style: IndentStyle,
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    attribute_position: AttributePosition,
+    bracket_spacing: BracketSpacing,
+    _file_source: MarkdownFileSource,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new(file_source: MarkdownFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            indent_style: IndentStyle::default(),
+            indent_width: IndentWidth::default(),
+            line_ending: LineEnding::default(),
+            line_width: LineWidth::default(),
+            attribute_position: AttributePosition::default(),
+            bracket_spacing: BracketSpacing::default(),
+        }
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_bracket_spacing(mut self, bracket_spacing: BracketSpacing) -> Self {
+        self.bracket_spacing = bracket_spacing;
+        self
+    }
+
+    pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
+        self.indent_style = indent_style;
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_bracket_spacing(&mut self, bracket_spacing: BracketSpacing) {
+        self.bracket_spacing = bracket_spacing;
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn attribute_position(&self) -> AttributePosition {
+        self.attribute_position
+    }
+
+    fn bracket_spacing(&self) -> BracketSpacing {
+        self.bracket_spacing
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style())?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult};
+use biome_markdown_syntax::{map_syntax_node, MarkdownSyntaxNode};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FormatMarkdownSyntaxNode;
+
+impl FormatRule<MarkdownSyntaxNode> for FormatMarkdownSyntaxNode {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MarkdownSyntaxNode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        map_syntax_node!(node.clone(), node => node.format().fmt(f))
+    }
+}
+
+impl AsFormat<MarkdownFormatContext> for MarkdownSyntaxNode {
+    type Format<'a> = FormatRefWithRule<'a, MarkdownSyntaxNode, FormatMarkdownSyntaxNode>;
+
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, FormatMarkdownSyntaxNode)
+    }
+}
+
+impl IntoFormat<MarkdownFormatContext> for MarkdownSyntaxNode {
+    type Format = FormatOwnedWithRule<MarkdownSyntaxNode, FormatMarkdownSyntaxNode>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatMarkdownSyntaxNode)
+    }
+}
@@ -0,0 +1,1216 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, MarkdownFormatContext,
+    MarkdownFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_markdown_syntax::MdBulletListBlock>
+    for crate::md::auxiliary::bullet_list_block::FormatMdBulletListBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdBulletListBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdBulletListBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletListBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBulletListBlock,
+        crate::md::auxiliary::bullet_list_block::FormatMdBulletListBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::bullet_list_block::FormatMdBulletListBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBulletListBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBulletListBlock,
+        crate::md::auxiliary::bullet_list_block::FormatMdBulletListBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::bullet_list_block::FormatMdBulletListBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdDocument>
+    for crate::md::auxiliary::document::FormatMdDocument
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdDocument,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdDocument,
+        crate::md::auxiliary::document::FormatMdDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::document::FormatMdDocument::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdDocument {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdDocument,
+        crate::md::auxiliary::document::FormatMdDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::document::FormatMdDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdFencedCodeBlock>
+    for crate::md::auxiliary::fenced_code_block::FormatMdFencedCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdFencedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdFencedCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdFencedCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdFencedCodeBlock,
+        crate::md::auxiliary::fenced_code_block::FormatMdFencedCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::fenced_code_block::FormatMdFencedCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdFencedCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdFencedCodeBlock,
+        crate::md::auxiliary::fenced_code_block::FormatMdFencedCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::fenced_code_block::FormatMdFencedCodeBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHardLine>
+    for crate::md::auxiliary::hard_line::FormatMdHardLine
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHardLine,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHardLine>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHardLine {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHardLine,
+        crate::md::auxiliary::hard_line::FormatMdHardLine,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::hard_line::FormatMdHardLine::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHardLine {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHardLine,
+        crate::md::auxiliary::hard_line::FormatMdHardLine,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::hard_line::FormatMdHardLine::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHeader> for crate::md::auxiliary::header::FormatMdHeader {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHeader,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHeader>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHeader {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHeader,
+        crate::md::auxiliary::header::FormatMdHeader,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::header::FormatMdHeader::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHeader {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHeader,
+        crate::md::auxiliary::header::FormatMdHeader,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::header::FormatMdHeader::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdHtmlBlock>
+    for crate::md::auxiliary::html_block::FormatMdHtmlBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdHtmlBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdHtmlBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHtmlBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdHtmlBlock,
+        crate::md::auxiliary::html_block::FormatMdHtmlBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::html_block::FormatMdHtmlBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdHtmlBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdHtmlBlock,
+        crate::md::auxiliary::html_block::FormatMdHtmlBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::html_block::FormatMdHtmlBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdIndentCodeBlock>
+    for crate::md::auxiliary::indent_code_block::FormatMdIndentCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdIndentCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdIndentCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdIndentCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdIndentCodeBlock,
+        crate::md::auxiliary::indent_code_block::FormatMdIndentCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::indent_code_block::FormatMdIndentCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdIndentCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdIndentCodeBlock,
+        crate::md::auxiliary::indent_code_block::FormatMdIndentCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::indent_code_block::FormatMdIndentCodeBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineCode>
+    for crate::md::auxiliary::inline_code::FormatMdInlineCode
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineCode,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineCode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineCode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineCode,
+        crate::md::auxiliary::inline_code::FormatMdInlineCode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::inline_code::FormatMdInlineCode::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineCode {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineCode,
+        crate::md::auxiliary::inline_code::FormatMdInlineCode,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::inline_code::FormatMdInlineCode::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineEmphasis>
+    for crate::md::auxiliary::inline_emphasis::FormatMdInlineEmphasis
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineEmphasis,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineEmphasis>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineEmphasis {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineEmphasis,
+        crate::md::auxiliary::inline_emphasis::FormatMdInlineEmphasis,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::inline_emphasis::FormatMdInlineEmphasis::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineEmphasis {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineEmphasis,
+        crate::md::auxiliary::inline_emphasis::FormatMdInlineEmphasis,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::inline_emphasis::FormatMdInlineEmphasis::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdInlineStrong>
+    for crate::md::auxiliary::inline_strong::FormatMdInlineStrong
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdInlineStrong,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdInlineStrong>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineStrong {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineStrong,
+        crate::md::auxiliary::inline_strong::FormatMdInlineStrong,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::inline_strong::FormatMdInlineStrong::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineStrong {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineStrong,
+        crate::md::auxiliary::inline_strong::FormatMdInlineStrong,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::inline_strong::FormatMdInlineStrong::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdLine> for crate::md::auxiliary::line::FormatMdLine {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdLine,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdLine>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLine {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdLine,
+        crate::md::auxiliary::line::FormatMdLine,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::auxiliary::line::FormatMdLine::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLine {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdLine,
+        crate::md::auxiliary::line::FormatMdLine,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::auxiliary::line::FormatMdLine::default())
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdListItem>
+    for crate::md::auxiliary::list_item::FormatMdListItem
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdListItem,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdListItem>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdListItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdListItem,
+        crate::md::auxiliary::list_item::FormatMdListItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::list_item::FormatMdListItem::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdListItem {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdListItem,
+        crate::md::auxiliary::list_item::FormatMdListItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::list_item::FormatMdListItem::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdMdxEsmBlock>
+    for crate::md::auxiliary::mdx_esm_block::FormatMdMdxEsmBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdMdxEsmBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdMdxEsmBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdMdxEsmBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdMdxEsmBlock,
+        crate::md::auxiliary::mdx_esm_block::FormatMdMdxEsmBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::mdx_esm_block::FormatMdMdxEsmBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdMdxEsmBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdMdxEsmBlock,
+        crate::md::auxiliary::mdx_esm_block::FormatMdMdxEsmBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::mdx_esm_block::FormatMdMdxEsmBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdMdxFlowBlock>
+    for crate::md::auxiliary::mdx_flow_block::FormatMdMdxFlowBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdMdxFlowBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdMdxFlowBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdMdxFlowBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdMdxFlowBlock,
+        crate::md::auxiliary::mdx_flow_block::FormatMdMdxFlowBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::mdx_flow_block::FormatMdMdxFlowBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdMdxFlowBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdMdxFlowBlock,
+        crate::md::auxiliary::mdx_flow_block::FormatMdMdxFlowBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::mdx_flow_block::FormatMdMdxFlowBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdOrderedListBlock>
+    for crate::md::auxiliary::ordered_list_block::FormatMdOrderedListBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdOrderedListBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdOrderedListBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderedListBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdOrderedListBlock,
+        crate::md::auxiliary::ordered_list_block::FormatMdOrderedListBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::ordered_list_block::FormatMdOrderedListBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdOrderedListBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdOrderedListBlock,
+        crate::md::auxiliary::ordered_list_block::FormatMdOrderedListBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::ordered_list_block::FormatMdOrderedListBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdParagraph>
+    for crate::md::auxiliary::paragraph::FormatMdParagraph
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdParagraph,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdParagraph>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdParagraph {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdParagraph,
+        crate::md::auxiliary::paragraph::FormatMdParagraph,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::paragraph::FormatMdParagraph::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdParagraph {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdParagraph,
+        crate::md::auxiliary::paragraph::FormatMdParagraph,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::paragraph::FormatMdParagraph::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdQuote> for crate::md::auxiliary::quote::FormatMdQuote {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdQuote,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdQuote>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuote {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdQuote,
+        crate::md::auxiliary::quote::FormatMdQuote,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::auxiliary::quote::FormatMdQuote::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdQuote {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdQuote,
+        crate::md::auxiliary::quote::FormatMdQuote,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::auxiliary::quote::FormatMdQuote::default())
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdSetextHeader>
+    for crate::md::auxiliary::setext_header::FormatMdSetextHeader
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdSetextHeader,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdSetextHeader>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdSetextHeader {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdSetextHeader,
+        crate::md::auxiliary::setext_header::FormatMdSetextHeader,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::setext_header::FormatMdSetextHeader::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdSetextHeader {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdSetextHeader,
+        crate::md::auxiliary::setext_header::FormatMdSetextHeader,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::setext_header::FormatMdSetextHeader::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTable> for crate::md::auxiliary::table::FormatMdTable {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTable,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTable,
+        crate::md::auxiliary::table::FormatMdTable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::auxiliary::table::FormatMdTable::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTable {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTable,
+        crate::md::auxiliary::table::FormatMdTable,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::auxiliary::table::FormatMdTable::default())
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTableCell>
+    for crate::md::auxiliary::table_cell::FormatMdTableCell
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTableCell,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTableCell>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCell {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableCell,
+        crate::md::auxiliary::table_cell::FormatMdTableCell,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::table_cell::FormatMdTableCell::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCell {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableCell,
+        crate::md::auxiliary::table_cell::FormatMdTableCell,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::table_cell::FormatMdTableCell::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTableRow>
+    for crate::md::auxiliary::table_row::FormatMdTableRow
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTableRow,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTableRow>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRow {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableRow,
+        crate::md::auxiliary::table_row::FormatMdTableRow,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::table_row::FormatMdTableRow::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRow {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableRow,
+        crate::md::auxiliary::table_row::FormatMdTableRow,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::table_row::FormatMdTableRow::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdTextual>
+    for crate::md::auxiliary::textual::FormatMdTextual
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdTextual,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdTextual>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTextual {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTextual,
+        crate::md::auxiliary::textual::FormatMdTextual,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::textual::FormatMdTextual::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTextual {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTextual,
+        crate::md::auxiliary::textual::FormatMdTextual,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::textual::FormatMdTextual::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdThematicBreakBlock>
+    for crate::md::auxiliary::thematic_break_block::FormatMdThematicBreakBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdThematicBreakBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdThematicBreakBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdThematicBreakBlock,
+        crate::md::auxiliary::thematic_break_block::FormatMdThematicBreakBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::auxiliary::thematic_break_block::FormatMdThematicBreakBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdThematicBreakBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdThematicBreakBlock,
+        crate::md::auxiliary::thematic_break_block::FormatMdThematicBreakBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::auxiliary::thematic_break_block::FormatMdThematicBreakBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBlockList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBlockList,
+        crate::md::lists::block_list::FormatMdBlockList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::block_list::FormatMdBlockList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBlockList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdBlockList,
+        crate::md::lists::block_list::FormatMdBlockList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::block_list::FormatMdBlockList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdInlineItemList,
+        crate::md::lists::inline_item_list::FormatMdInlineItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::inline_item_list::FormatMdInlineItemList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdInlineItemList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdInlineItemList,
+        crate::md::lists::inline_item_list::FormatMdInlineItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::inline_item_list::FormatMdInlineItemList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLineList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdLineList,
+        crate::md::lists::line_list::FormatMdLineList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::line_list::FormatMdLineList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdLineList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdLineList,
+        crate::md::lists::line_list::FormatMdLineList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::line_list::FormatMdLineList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdListItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdListItemList,
+        crate::md::lists::list_item_list::FormatMdListItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::list_item_list::FormatMdListItemList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdListItemList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdListItemList,
+        crate::md::lists::list_item_list::FormatMdListItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::list_item_list::FormatMdListItemList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCellList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableCellList,
+        crate::md::lists::table_cell_list::FormatMdTableCellList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::table_cell_list::FormatMdTableCellList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableCellList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableCellList,
+        crate::md::lists::table_cell_list::FormatMdTableCellList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::table_cell_list::FormatMdTableCellList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRowList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdTableRowList,
+        crate::md::lists::table_row_list::FormatMdTableRowList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::lists::table_row_list::FormatMdTableRowList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdTableRowList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdTableRowList,
+        crate::md::lists::table_row_list::FormatMdTableRowList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::lists::table_row_list::FormatMdTableRowList::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdBogus> for crate::md::bogus::bogus::FormatMdBogus {
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdBogus,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_markdown_syntax::MdBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdBogus,
+        crate::md::bogus::bogus::FormatMdBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::bogus::bogus::FormatMdBogus::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdBogus {
+    type Format =
+        FormatOwnedWithRule<biome_markdown_syntax::MdBogus, crate::md::bogus::bogus::FormatMdBogus>;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::bogus::bogus::FormatMdBogus::default())
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdBlock,
+        crate::md::any::block::FormatAnyMdBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::any::block::FormatAnyMdBlock::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdBlock,
+        crate::md::any::block::FormatAnyMdBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::any::block::FormatAnyMdBlock::default())
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdCodeBlock,
+        crate::md::any::code_block::FormatAnyMdCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::any::code_block::FormatAnyMdCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdCodeBlock,
+        crate::md::any::code_block::FormatAnyMdCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::any::code_block::FormatAnyMdCodeBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdContainerBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdContainerBlock,
+        crate::md::any::container_block::FormatAnyMdContainerBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::any::container_block::FormatAnyMdContainerBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdContainerBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdContainerBlock,
+        crate::md::any::container_block::FormatAnyMdContainerBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::any::container_block::FormatAnyMdContainerBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdInline {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdInline,
+        crate::md::any::inline::FormatAnyMdInline,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::md::any::inline::FormatAnyMdInline::default())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdInline {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdInline,
+        crate::md::any::inline::FormatAnyMdInline,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::md::any::inline::FormatAnyMdInline::default())
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdLeafBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMdLeafBlock,
+        crate::md::any::leaf_block::FormatAnyMdLeafBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::md::any::leaf_block::FormatAnyMdLeafBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMdLeafBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMdLeafBlock,
+        crate::md::any::leaf_block::FormatAnyMdLeafBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::md::any::leaf_block::FormatAnyMdLeafBlock::default(),
+        )
+    }
+}
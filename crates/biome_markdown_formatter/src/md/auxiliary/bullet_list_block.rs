@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBulletListBlock, MdBulletListBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletListBlock;
+
+impl FormatNodeRule<MdBulletListBlock> for FormatMdBulletListBlock {
+    fn fmt_fields(&self, node: &MdBulletListBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletListBlockFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use crate::utils::lines::{FormatLineBreaks, FormatLines, LinePrefix};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdFencedCodeBlock, MdFencedCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence_token,
+            info_token,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+        let l_fence_token = l_fence_token?;
+
+        write!(f, [l_fence_token.format(), info_token.format()])?;
+        if let Some(first_line) = content.first() {
+            // The lines lose the indentation of the opening fence
+            // https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+            let base = LinePrefix::of(&l_fence_token).column();
+            write!(
+                f,
+                [
+                    FormatLineBreaks(get_lines_before(first_line.syntax())),
+                    FormatLines::new(&content, base)
+                ]
+            )?;
+        }
+        if let Some(r_fence_token) = r_fence_token {
+            write!(
+                f,
+                [
+                    FormatLineBreaks(get_lines_before_token(&r_fence_token)),
+                    r_fence_token.format()
+                ]
+            )?;
+        }
+        Ok(())
+    }
+}
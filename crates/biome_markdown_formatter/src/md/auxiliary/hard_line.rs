@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::MdHardLine;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatInlineContent::new(node.syntax(), InlineLayout::SingleLine).fmt(f)
+    }
+}
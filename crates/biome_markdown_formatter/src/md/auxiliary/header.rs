@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHeader, MdHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHeader;
+
+impl FormatNodeRule<MdHeader> for FormatMdHeader {
+    fn fmt_fields(&self, node: &MdHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHeaderFields {
+            before_token,
+            content,
+            after_token,
+        } = node.as_fields();
+
+        write!(f, [before_token.format()])?;
+        if !content.is_empty() {
+            write!(f, [space(), content.format()])?;
+        }
+        if let Some(after_token) = after_token {
+            // The closing sequence is removed, unless the content ends with `#`, which would
+            // become the closing sequence.
+            let ends_with_hashes = content
+                .syntax()
+                .last_token()
+                .is_some_and(|token| token.text_trimmed().bytes().all(|byte| byte == b'#'));
+            if ends_with_hashes {
+                write!(f, [space(), after_token.format()])?;
+            } else {
+                write!(f, [format_removed(&after_token)])?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdIndentCodeBlock, MdIndentCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { content } = node.as_fields();
+
+        write!(f, [content.format()])
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::MdInlineCode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatInlineContent::new(node.syntax(), InlineLayout::SingleLine).fmt(f)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::MdInlineEmphasis;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineEmphasis;
+
+impl FormatNodeRule<MdInlineEmphasis> for FormatMdInlineEmphasis {
+    fn fmt_fields(&self, node: &MdInlineEmphasis, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatInlineContent::new(node.syntax(), InlineLayout::SingleLine).fmt(f)
+    }
+}
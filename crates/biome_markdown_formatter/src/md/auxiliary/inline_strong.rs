@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::MdInlineStrong;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineStrong;
+
+impl FormatNodeRule<MdInlineStrong> for FormatMdInlineStrong {
+    fn fmt_fields(&self, node: &MdInlineStrong, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatInlineContent::new(node.syntax(), InlineLayout::SingleLine).fmt(f)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdLine, MdLineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLine;
+
+impl FormatNodeRule<MdLine> for FormatMdLine {
+    fn fmt_fields(&self, node: &MdLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdLineFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
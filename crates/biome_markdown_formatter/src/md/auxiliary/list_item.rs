@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use biome_formatter::{write, FormatRuleWithOptions};
+use biome_markdown_syntax::{MdListItem, MdListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdListItem {
+    /// The marker that replaces the marker of the item
+    marker: Option<String>,
+}
+
+impl FormatRuleWithOptions<MdListItem> for FormatMdListItem {
+    type Options = Option<String>;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.marker = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdListItem> for FormatMdListItem {
+    fn fmt_fields(&self, node: &MdListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdListItemFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+        let marker_token = marker_token?;
+
+        let marker = match &self.marker {
+            Some(marker) if marker != marker_token.text_trimmed() => {
+                write!(
+                    f,
+                    [format_replaced(
+                        &marker_token,
+                        &dynamic_text(marker, marker_token.text_trimmed_range().start())
+                    )]
+                )?;
+                marker.as_str()
+            }
+            _ => {
+                write!(f, [marker_token.format()])?;
+                marker_token.text_trimmed()
+            }
+        };
+        if content.is_empty() {
+            return Ok(());
+        }
+
+        // The content is aligned with the first character after the marker and its space
+        let width = u8::try_from(marker.len() + 1).unwrap_or(u8::MAX);
+        write!(f, [space(), align(width, &content.format())])
+    }
+}
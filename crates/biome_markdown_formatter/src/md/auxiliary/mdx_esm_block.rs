@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdMdxEsmBlock, MdMdxEsmBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdMdxEsmBlock;
+
+impl FormatNodeRule<MdMdxEsmBlock> for FormatMdMdxEsmBlock {
+    fn fmt_fields(&self, node: &MdMdxEsmBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdMdxEsmBlockFields { content } = node.as_fields();
+
+        write!(f, [content.format()])
+    }
+}
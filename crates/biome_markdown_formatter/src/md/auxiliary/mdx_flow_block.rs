@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdMdxFlowBlock, MdMdxFlowBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdMdxFlowBlock;
+
+impl FormatNodeRule<MdMdxFlowBlock> for FormatMdMdxFlowBlock {
+    fn fmt_fields(&self, node: &MdMdxFlowBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdMdxFlowBlockFields { content } = node.as_fields();
+
+        write!(f, [content.format()])
+    }
+}
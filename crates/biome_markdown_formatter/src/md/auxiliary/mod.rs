@@ -0,0 +1,25 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod bullet_list_block;
+pub(crate) mod document;
+pub(crate) mod fenced_code_block;
+pub(crate) mod hard_line;
+pub(crate) mod header;
+pub(crate) mod html_block;
+pub(crate) mod indent_code_block;
+pub(crate) mod inline_code;
+pub(crate) mod inline_emphasis;
+pub(crate) mod inline_strong;
+pub(crate) mod line;
+pub(crate) mod list_item;
+pub(crate) mod mdx_esm_block;
+pub(crate) mod mdx_flow_block;
+pub(crate) mod ordered_list_block;
+pub(crate) mod paragraph;
+pub(crate) mod quote;
+pub(crate) mod setext_header;
+pub(crate) mod table;
+pub(crate) mod table_cell;
+pub(crate) mod table_row;
+pub(crate) mod textual;
+pub(crate) mod thematic_break_block;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderedListBlock, MdOrderedListBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedListBlock;
+
+impl FormatNodeRule<MdOrderedListBlock> for FormatMdOrderedListBlock {
+    fn fmt_fields(&self, node: &MdOrderedListBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderedListBlockFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
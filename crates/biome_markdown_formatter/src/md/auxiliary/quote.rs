@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_markdown_syntax::{MdQuote, MdQuoteFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuote;
+
+impl FormatNodeRule<MdQuote> for FormatMdQuote {
+    fn fmt_fields(&self, node: &MdQuote, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteFields { content } = node.as_fields();
+
+        // The lines of a quote keep their content, without their indentation
+        f.join_with(hard_line_break())
+            .entries(content.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdSetextHeader, MdSetextHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+        let underline_token = underline_token?;
+
+        // An ATX heading can't have a hard line break
+        let has_hard_line = content
+            .syntax()
+            .descendants_tokens(biome_rowan::Direction::Next)
+            .any(|token| token.kind() == MarkdownSyntaxKind::MD_HARD_LINE_LITERAL);
+        if has_hard_line {
+            return write!(
+                f,
+                [
+                    FormatInlineContent::new(content.syntax(), InlineLayout::Fill),
+                    hard_line_break(),
+                    underline_token.format()
+                ]
+            );
+        }
+
+        let sequence = if underline_token.text_trimmed().starts_with('=') {
+            "#"
+        } else {
+            "##"
+        };
+        write!(
+            f,
+            [
+                format_removed(&underline_token),
+                text(sequence),
+                space(),
+                FormatInlineContent::new(content.syntax(), InlineLayout::SingleLine)
+            ]
+        )
+    }
+}
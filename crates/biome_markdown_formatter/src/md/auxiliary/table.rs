@@ -0,0 +1,221 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTable, MdTableCell, MdTableFields, MdTableRow};
+use biome_rowan::TextSize;
+use std::iter;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+        let header = header?;
+        let delimiter = delimiter?;
+
+        let rows = iter::once(header)
+            .chain(rows.iter())
+            .map(|row| {
+                let cells = row
+                    .cells()
+                    .iter()
+                    .map(|cell| {
+                        FormatInlineContent::new(cell.content().syntax(), InlineLayout::SingleLine)
+                    })
+                    .collect::<Vec<_>>();
+                (row, cells)
+            })
+            .collect::<Vec<_>>();
+
+        let mut columns = delimiter
+            .cells()
+            .iter()
+            .map(|cell| TableColumn {
+                alignment: ColumnAlignment::from_delimiter(&cell),
+                width: 3,
+            })
+            .collect::<Vec<_>>();
+        for (_, cells) in &rows {
+            for (index, cell) in cells.iter().enumerate() {
+                match columns.get_mut(index) {
+                    Some(column) => column.width = column.width.max(cell.width()),
+                    None => columns.push(TableColumn {
+                        alignment: ColumnAlignment::None,
+                        width: cell.width().max(3),
+                    }),
+                }
+            }
+        }
+
+        for (index, (row, cells)) in rows.iter().enumerate() {
+            if index > 0 {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(
+                f,
+                [FormatTableRow {
+                    row,
+                    cells: TableCells::Content(cells),
+                    columns: &columns
+                }]
+            )?;
+            if index == 0 {
+                write!(
+                    f,
+                    [
+                        hard_line_break(),
+                        FormatTableRow {
+                            row: &delimiter,
+                            cells: TableCells::Delimiter,
+                            columns: &columns
+                        }
+                    ]
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ColumnAlignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl ColumnAlignment {
+    /// Returns the alignment of the cells of a column from its delimiter, such as `:---`.
+    fn from_delimiter(cell: &MdTableCell) -> Self {
+        let text = cell.content().syntax().text_trimmed().to_string();
+        match (text.starts_with(':'), text.ends_with(':') && text.len() > 1) {
+            (true, true) => Self::Center,
+            (true, false) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+
+    /// Returns the delimiter of a column with the width `width`.
+    fn delimiter(self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => std::format!(":{}", "-".repeat(width - 1)),
+            Self::Center => std::format!(":{}:", "-".repeat(width - 2)),
+            Self::Right => std::format!("{}:", "-".repeat(width - 1)),
+        }
+    }
+}
+
+struct TableColumn {
+    alignment: ColumnAlignment,
+    /// The width of the widest cell of the column, at least 3 to fit the delimiter
+    width: usize,
+}
+
+enum TableCells<'a> {
+    Content(&'a [FormatInlineContent]),
+    Delimiter,
+}
+
+/// Formats a row of a table with its cells padded to the width of their column. The missing
+/// cells are added.
+struct FormatTableRow<'a> {
+    row: &'a MdTableRow,
+    cells: TableCells<'a>,
+    columns: &'a [TableColumn],
+}
+
+impl Format<MarkdownFormatContext> for FormatTableRow<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The cells are formatted token by token, and they can't have comments
+        for node in self.row.syntax().descendants() {
+            f.comments().mark_suppression_checked(&node);
+        }
+
+        let mut cells = self.row.cells().iter();
+        for (index, column) in self.columns.iter().enumerate() {
+            let Some(cell) = cells.next() else {
+                let position = self.row.syntax().text_trimmed_range().end();
+                write!(
+                    f,
+                    [
+                        text("|"),
+                        space(),
+                        FormatPadding(column.width, position),
+                        space()
+                    ]
+                )?;
+                continue;
+            };
+
+            match cell.l_pipe_token() {
+                Some(l_pipe_token) => write!(f, [l_pipe_token.format()])?,
+                None => write!(f, [text("|")])?,
+            }
+            write!(f, [space()])?;
+
+            let position = cell.syntax().text_trimmed_range().start();
+            match self.cells {
+                TableCells::Content(contents) => {
+                    let content = &contents[index];
+                    let padding = column.width - content.width();
+                    let (before, after) = match column.alignment {
+                        ColumnAlignment::None | ColumnAlignment::Left => (0, padding),
+                        ColumnAlignment::Center => (padding / 2, padding - padding / 2),
+                        ColumnAlignment::Right => (padding, 0),
+                    };
+                    write!(
+                        f,
+                        [
+                            FormatPadding(before, position),
+                            content,
+                            FormatPadding(after, position)
+                        ]
+                    )?;
+                }
+                TableCells::Delimiter => {
+                    let delimiter = column.alignment.delimiter(column.width);
+                    let mut tokens = cell
+                        .content()
+                        .syntax()
+                        .descendants_tokens(biome_rowan::Direction::Next);
+                    match tokens.next() {
+                        Some(token) => write!(
+                            f,
+                            [format_replaced(&token, &dynamic_text(&delimiter, position))]
+                        )?,
+                        None => write!(f, [dynamic_text(&delimiter, position)])?,
+                    }
+                    for token in tokens {
+                        write!(f, [format_removed(&token)])?;
+                    }
+                }
+            }
+            write!(f, [space()])?;
+        }
+
+        match self.row.r_pipe_token() {
+            Some(r_pipe_token) => write!(f, [r_pipe_token.format()]),
+            None => write!(f, [text("|")]),
+        }
+    }
+}
+
+/// Formats `width` spaces
+struct FormatPadding(usize, TextSize);
+
+impl Format<MarkdownFormatContext> for FormatPadding {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if self.0 == 0 {
+            return Ok(());
+        }
+        write!(f, [dynamic_text(&" ".repeat(self.0), self.1)])
+    }
+}
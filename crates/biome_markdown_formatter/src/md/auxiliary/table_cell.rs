@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTableCell, MdTableCellFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCell;
+
+impl FormatNodeRule<MdTableCell> for FormatMdTableCell {
+    fn fmt_fields(&self, node: &MdTableCell, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableCellFields {
+            l_pipe_token,
+            content,
+        } = node.as_fields();
+
+        match l_pipe_token {
+            Some(l_pipe_token) => write!(f, [l_pipe_token.format()])?,
+            None => write!(f, [text("|")])?,
+        }
+        write!(f, [space(), content.format()])
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTableRow, MdTableRowFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow;
+
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableRowFields {
+            cells,
+            r_pipe_token,
+        } = node.as_fields();
+
+        write!(f, [cells.format(), space()])?;
+        match r_pipe_token {
+            Some(r_pipe_token) => write!(f, [r_pipe_token.format()]),
+            None => write!(f, [text("|")]),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::MdTextual;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTextual;
+
+impl FormatNodeRule<MdTextual> for FormatMdTextual {
+    fn fmt_fields(&self, node: &MdTextual, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatInlineContent::new(node.syntax(), InlineLayout::SingleLine).fmt(f)
+    }
+}
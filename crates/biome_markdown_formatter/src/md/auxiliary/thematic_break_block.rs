@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MdListItem, MdParagraph, MdThematicBreakBlock, MdThematicBreakBlockFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let syntax = node.syntax();
+        let is_in_list_item = syntax
+            .grand_parent()
+            .is_some_and(|node| MdListItem::can_cast(node.kind()));
+        let thematic_break = if is_in_list_item && syntax.prev_sibling().is_none() {
+            // `- ---` and `* ***` are thematic breaks, not list items
+            "___"
+        } else if is_in_list_item
+            && syntax
+                .prev_sibling()
+                .is_some_and(|node| MdParagraph::can_cast(node.kind()))
+            && get_lines_before(syntax) <= 1
+        {
+            // `---` after a paragraph is the underline of a heading
+            "***"
+        } else {
+            "---"
+        };
+
+        if value_token.text_trimmed() == thematic_break {
+            write!(f, [value_token.format()])
+        } else {
+            write!(f, [format_replaced(&value_token, &text(thematic_break))])
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdBlockList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The blank lines between the blocks of a list item make the list loose
+        // https://spec.commonmark.org/0.31.2/#loose
+        let is_in_list_item = node
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == MarkdownSyntaxKind::MD_LIST_ITEM);
+
+        for (index, block) in node.iter().enumerate() {
+            if index > 0 {
+                if is_in_list_item && get_lines_before(block.syntax()) <= 1 {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [empty_line()])?;
+                }
+            }
+            write!(f, [block.format()])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use crate::utils::inline::{FormatInlineContent, InlineLayout};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MdInlineItemList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItemList;
+
+impl FormatRule<MdInlineItemList> for FormatMdInlineItemList {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MdInlineItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_prose = node
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == MarkdownSyntaxKind::MD_PARAGRAPH);
+        let layout = if is_prose {
+            InlineLayout::Fill
+        } else {
+            InlineLayout::SingleLine
+        };
+
+        FormatInlineContent::new(node.syntax(), layout).fmt(f)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use crate::utils::lines::{container_indent, FormatLines};
+use biome_markdown_syntax::MdLineList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLineList;
+
+impl FormatRule<MdLineList> for FormatMdLineList {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MdLineList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        FormatLines::new(node, container_indent(node.syntax())).fmt(f)
+    }
+}
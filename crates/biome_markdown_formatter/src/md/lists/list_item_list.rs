@@ -0,0 +1,84 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBulletListBlock, MdListItemList, MdOrderedListBlock};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdListItemList;
+
+impl FormatRule<MdListItemList> for FormatMdListItemList {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MdListItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let mut markers = list_markers(node).into_iter();
+        // A blank line between two items makes the list loose, then a blank line separates
+        // all its items
+        // https://spec.commonmark.org/0.31.2/#loose
+        let is_loose = node
+            .iter()
+            .skip(1)
+            .any(|item| get_lines_before(item.syntax()) > 1);
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                if is_loose {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            write!(f, [item.format().with_options(markers.next())])?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the markers of the items of a list.
+///
+/// The items of a bullet list use `-`, or `*` when the list follows a list that uses `-`,
+/// so that the two lists stay apart.
+/// The numbers of an ordered list start at the number of its first item, and increment unless
+/// its first two items have the same number.
+fn list_markers(list: &MdListItemList) -> Vec<String> {
+    let Some(parent) = list.syntax().parent() else {
+        return Vec::new();
+    };
+    if let Some(bullet_list) = MdBulletListBlock::cast_ref(&parent) {
+        let marker = bullet_marker(&bullet_list);
+        return list.iter().map(|_| marker.to_string()).collect();
+    }
+    if !MdOrderedListBlock::can_cast(parent.kind()) {
+        return Vec::new();
+    }
+
+    let numbers = list
+        .iter()
+        .take(2)
+        .filter_map(|item| item.marker_token().ok())
+        .map(|marker| {
+            let text = marker.text_trimmed();
+            let (number, delimiter) = text.split_at(text.len() - 1);
+            (number.parse::<u64>().unwrap_or(1), delimiter.to_string())
+        })
+        .collect::<Vec<_>>();
+    let Some((start, delimiter)) = numbers.first() else {
+        return Vec::new();
+    };
+    let increment = match numbers.get(1) {
+        Some((second, _)) if second == start => 0,
+        _ => 1,
+    };
+    (0..list.len() as u64)
+        .map(|index| std::format!("{}{delimiter}", start + index * increment))
+        .collect()
+}
+
+fn bullet_marker(list: &MdBulletListBlock) -> &'static str {
+    let previous = list
+        .syntax()
+        .prev_sibling()
+        .and_then(MdBulletListBlock::cast);
+    match previous {
+        Some(previous) if bullet_marker(&previous) == "-" => "*",
+        _ => "-",
+    }
+}
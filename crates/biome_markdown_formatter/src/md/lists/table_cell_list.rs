@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdTableCellList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCellList;
+
+impl FormatRule<MdTableCellList> for FormatMdTableCellList {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(&self, node: &MdTableCellList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.join_with(space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
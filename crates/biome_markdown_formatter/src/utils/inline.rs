@@ -0,0 +1,277 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::MarkdownSyntaxKind::{
+    DOUBLE_STAR, DOUBLE_UNDERSCORE, MD_HARD_LINE_LITERAL, MD_INLINE_CODE_LITERAL,
+    MD_INLINE_EMPHASIS, MD_INLINE_STRONG, STAR, UNDERSCORE,
+};
+use biome_markdown_syntax::{MarkdownSyntaxNode, MarkdownSyntaxToken};
+use biome_rowan::Direction;
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthStr;
+
+/// How the words of an inline content are laid out
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum InlineLayout {
+    /// The words fill the lines up to the line width, like the prose of a paragraph.
+    Fill,
+    /// The words are on a single line, like the content of a heading or of a table cell.
+    SingleLine,
+}
+
+/// Formats the inline items of `node`: normalizes the delimiters of the emphasis to `_`, and
+/// the delimiters of the strong emphasis to `**`.
+pub(crate) struct FormatInlineContent {
+    node: MarkdownSyntaxNode,
+    words: Vec<InlineWord>,
+    layout: InlineLayout,
+}
+
+impl FormatInlineContent {
+    pub(crate) fn new(node: &MarkdownSyntaxNode, layout: InlineLayout) -> Self {
+        Self {
+            node: node.clone(),
+            words: inline_words(node),
+            layout,
+        }
+    }
+
+    /// Returns the width of the content when it's on a single line.
+    pub(crate) fn width(&self) -> usize {
+        let words_width: usize = self
+            .words
+            .iter()
+            .flat_map(|word| &word.pieces)
+            .map(|(_, text)| text.width())
+            .sum();
+        words_width + self.words.len().saturating_sub(1)
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineContent {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The inline nodes are formatted token by token, and they can't have comments
+        for node in self.node.descendants() {
+            f.comments().mark_suppression_checked(&node);
+        }
+
+        match self.layout {
+            InlineLayout::Fill => {
+                let mut fill = f.fill();
+                let mut after_hard_line = false;
+                for (index, word) in self.words.iter().enumerate() {
+                    let is_last = index + 1 == self.words.len();
+                    let separator = format_with(|f| {
+                        if after_hard_line {
+                            write!(f, [hard_line_break()])
+                        } else if word.starts_block() {
+                            // A line break would turn the word into a list marker, or into
+                            // another block
+                            write!(f, [space()])
+                        } else {
+                            write!(f, [soft_line_break_or_space()])
+                        }
+                    });
+                    fill.entry(&separator, &FormatInlineWord { word, is_last });
+                    after_hard_line = word.hard_line.is_some();
+                }
+                fill.finish()
+            }
+            InlineLayout::SingleLine => {
+                for (index, word) in self.words.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, [space()])?;
+                    }
+                    write!(
+                        f,
+                        [FormatInlineWord {
+                            word,
+                            is_last: true
+                        }]
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The tokens of an inline content that aren't separated by whitespace, with their
+/// normalized text
+#[derive(Default)]
+struct InlineWord {
+    pieces: Vec<(MarkdownSyntaxToken, Cow<'static, str>)>,
+    /// The hard line break that ends the word
+    hard_line: Option<MarkdownSyntaxToken>,
+}
+
+impl InlineWord {
+    fn text(&self) -> String {
+        self.pieces.iter().map(|(_, text)| text.as_ref()).collect()
+    }
+
+    /// Returns `true` if the word starts a block when it starts a line, such as `-`, `1.`
+    /// or `>`.
+    fn starts_block(&self) -> bool {
+        let text = self.text();
+        let mut chars = text.chars();
+        match chars.next() {
+            Some('>') => true,
+            Some('<') => chars
+                .next()
+                .is_some_and(|char| char.is_ascii_alphabetic() || matches!(char, '/' | '!' | '?')),
+            Some('`' | '~') => text.starts_with("```") || text.starts_with("~~~"),
+            Some('0'..='9') => {
+                let digits = text.trim_end_matches(['.', ')']);
+                text.len() - digits.len() == 1
+                    && digits.len() <= 9
+                    && digits.bytes().all(|byte| byte.is_ascii_digit())
+            }
+            Some(_) => text
+                .chars()
+                .all(|char| matches!(char, '-' | '+' | '*' | '_' | '=' | '#' | '|' | ':')),
+            None => false,
+        }
+    }
+}
+
+struct FormatInlineWord<'a> {
+    word: &'a InlineWord,
+    is_last: bool,
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineWord<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        for (token, text) in &self.word.pieces {
+            if token.text_trimmed() == text {
+                write!(f, [token.format()])?;
+            } else {
+                write!(
+                    f,
+                    [format_replaced(
+                        token,
+                        &dynamic_text(text, token.text_trimmed_range().start())
+                    )]
+                )?;
+            }
+        }
+        if let Some(hard_line) = &self.word.hard_line {
+            if hard_line.text_trimmed() == "\\" {
+                // The backslash is a literal backslash when it ends the content
+                write!(f, [hard_line.format()])?;
+            } else if self.is_last {
+                write!(f, [format_removed(hard_line)])?;
+            } else {
+                write!(f, [format_replaced(hard_line, &text("  "))])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn inline_words(node: &MarkdownSyntaxNode) -> Vec<InlineWord> {
+    let mut words = Vec::new();
+    let mut current = InlineWord::default();
+    let mut previous: Option<MarkdownSyntaxToken> = None;
+    for token in node.descendants_tokens(Direction::Next) {
+        if token.kind() == MD_HARD_LINE_LITERAL {
+            current.hard_line = Some(token.clone());
+            words.push(std::mem::take(&mut current));
+            previous = Some(token);
+            continue;
+        }
+
+        let is_separated = previous.as_ref().is_some_and(|previous| {
+            previous.has_trailing_whitespace() || token.has_leading_whitespace_or_newline()
+        });
+        if is_separated && !current.pieces.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+
+        let text = normalized_text(&token);
+        current.pieces.push((token.clone(), text));
+        previous = Some(token);
+    }
+    if !current.pieces.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Returns the text of an inline token once formatted.
+fn normalized_text(token: &MarkdownSyntaxToken) -> Cow<'static, str> {
+    let text = token.text_trimmed();
+    match token.kind() {
+        // A code span is on a single line once its line endings become spaces
+        // https://spec.commonmark.org/0.31.2/#code-spans
+        MD_INLINE_CODE_LITERAL if text.contains(['\n', '\r']) => {
+            Cow::Owned(text.replace("\r\n", " ").replace(['\n', '\r'], " "))
+        }
+        STAR | UNDERSCORE | DOUBLE_STAR | DOUBLE_UNDERSCORE => token
+            .parent()
+            .and_then(|parent| normalized_delimiter(&parent))
+            .map_or_else(|| Cow::Owned(text.to_string()), Cow::Borrowed),
+        _ => Cow::Owned(text.to_string()),
+    }
+}
+
+/// Returns the delimiter of an emphasis, `_`, or of a strong emphasis, `**`, when it can
+/// replace the delimiters of `node` without changing the emphasis.
+///
+/// The `_` delimiters can't be inside a word, and the delimiters can't touch other
+/// delimiters.
+/// https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis
+fn normalized_delimiter(node: &MarkdownSyntaxNode) -> Option<&'static str> {
+    let delimiter = match node.kind() {
+        MD_INLINE_EMPHASIS => "_",
+        MD_INLINE_STRONG => "**",
+        _ => return None,
+    };
+    let opening = node.first_token()?;
+    let closing = node.last_token()?;
+    if opening.text_trimmed() == delimiter
+        || !matches!(
+            opening.kind(),
+            STAR | UNDERSCORE | DOUBLE_STAR | DOUBLE_UNDERSCORE
+        )
+        || opening.kind() != closing.kind()
+    {
+        return None;
+    }
+
+    let is_delimiter = |char: Option<char>| matches!(char, Some('*' | '_'));
+    if is_delimiter(char_after(&opening)) || is_delimiter(char_before(&closing)) {
+        return None;
+    }
+    let is_boundary = |char: Option<char>| match char {
+        None => true,
+        Some('*' | '_') => false,
+        Some(char) => delimiter == "**" || char.is_whitespace() || char.is_ascii_punctuation(),
+    };
+    (is_boundary(char_before(&opening)) && is_boundary(char_after(&closing))).then_some(delimiter)
+}
+
+/// Returns the character before `token`, or a space when whitespace precedes it.
+fn char_before(token: &MarkdownSyntaxToken) -> Option<char> {
+    if token.has_leading_whitespace_or_newline() {
+        return Some(' ');
+    }
+    let previous = token.prev_token()?;
+    if previous.has_trailing_whitespace() {
+        Some(' ')
+    } else {
+        previous.text_trimmed().chars().next_back()
+    }
+}
+
+/// Returns the character after `token`, or a space when whitespace follows it.
+fn char_after(token: &MarkdownSyntaxToken) -> Option<char> {
+    if token.has_trailing_whitespace() {
+        return Some(' ');
+    }
+    let next = token.next_token()?;
+    if next.has_leading_whitespace_or_newline() {
+        Some(' ')
+    } else {
+        next.text_trimmed().chars().next()
+    }
+}
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxNode, MarkdownSyntaxToken, MdLineList, MdListItem};
+
+/// The text between the start of the line of a token and the token
+pub(crate) struct LinePrefix {
+    text: String,
+}
+
+impl LinePrefix {
+    pub(crate) fn of(token: &MarkdownSyntaxToken) -> Self {
+        let mut pieces = Vec::new();
+        let mut current = Some(token.clone());
+        let mut is_first = true;
+        while let Some(token) = current {
+            let text = token.text();
+            let end = if is_first {
+                usize::from(token.text_trimmed_range().start() - token.text_range().start())
+            } else {
+                text.len()
+            };
+            let text = &text[..end];
+            if let Some(line_start) = text.rfind(['\n', '\r']) {
+                pieces.push(text[line_start + 1..].to_string());
+                break;
+            }
+            pieces.push(text.to_string());
+            current = token.prev_token();
+            is_first = false;
+        }
+        let text = pieces
+            .into_iter()
+            .rev()
+            .collect::<String>()
+            .replace('\u{feff}', "");
+        Self { text }
+    }
+
+    /// Returns the column of the token. A tab advances to the next multiple of 4.
+    pub(crate) fn column(&self) -> usize {
+        column_after(0, &self.text)
+    }
+
+    /// Returns the indentation of the token relative to the column `base`.
+    ///
+    /// The indentation keeps its tabs when the token starts its line.
+    pub(crate) fn indentation_from(&self, base: usize) -> String {
+        if !self.text.chars().all(|char| matches!(char, ' ' | '\t')) {
+            return " ".repeat(self.column().saturating_sub(base));
+        }
+
+        let mut column = 0;
+        for (index, char) in self.text.char_indices() {
+            if column >= base {
+                return " ".repeat(column - base) + &self.text[index..];
+            }
+            column = column_after(column, &self.text[index..index + char.len_utf8()]);
+        }
+        " ".repeat(column.saturating_sub(base))
+    }
+}
+
+fn column_after(column: usize, text: &str) -> usize {
+    text.chars().fold(column, |column, char| {
+        if char == '\t' {
+            column + 4 - column % 4
+        } else {
+            column + 1
+        }
+    })
+}
+
+/// Returns the column where the content of the container of `node` starts: the column of the
+/// content of its list item, or 0.
+pub(crate) fn container_indent(node: &MarkdownSyntaxNode) -> usize {
+    node.ancestors()
+        .find_map(MdListItem::cast)
+        .map_or(0, |item| list_item_content_indent(&item))
+}
+
+/// Returns the column where the content of a list item starts.
+///
+/// https://spec.commonmark.org/0.31.2/#list-items
+pub(crate) fn list_item_content_indent(item: &MdListItem) -> usize {
+    let Ok(marker) = item.marker_token() else {
+        return 0;
+    };
+    let marker_end = LinePrefix::of(&marker).column() + marker.text_trimmed().len();
+    match item.content().syntax().first_token() {
+        Some(first) if get_lines_before_token(&first) == 0 => {
+            let column = LinePrefix::of(&first).column();
+            if column - marker_end > 4 {
+                marker_end + 1
+            } else {
+                column
+            }
+        }
+        _ => marker_end + 1,
+    }
+}
+
+/// Formats `lines` line breaks between two lines of a block that keeps its lines, such as a
+/// code block.
+pub(crate) struct FormatLineBreaks(pub(crate) usize);
+
+impl Format<MarkdownFormatContext> for FormatLineBreaks {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if self.0 > 1 {
+            // The printer doesn't print consecutive empty lines, and the indentation of the
+            // blank lines would be trailing whitespace
+            let blank_lines = format_with(|f| {
+                write!(f, [hard_line_break()])?;
+                for _ in 1..self.0 {
+                    write!(f, [text("\n")])?;
+                }
+                Ok(())
+            });
+            write!(f, [dedent_to_root(&blank_lines)])?;
+        }
+        write!(f, [hard_line_break()])
+    }
+}
+
+/// Formats the lines of a code block, or of an HTML block, and keeps their indentation
+/// relative to the column `base`, and their blank lines.
+pub(crate) struct FormatLines<'a> {
+    lines: &'a MdLineList,
+    base: usize,
+}
+
+impl<'a> FormatLines<'a> {
+    pub(crate) fn new(lines: &'a MdLineList, base: usize) -> Self {
+        Self { lines, base }
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatLines<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        for (index, line) in self.lines.iter().enumerate() {
+            f.comments().mark_suppression_checked(line.syntax());
+            let token = line.value_token()?;
+            if index > 0 {
+                write!(f, [FormatLineBreaks(get_lines_before_token(&token))])?;
+            }
+            let indentation = LinePrefix::of(&token).indentation_from(self.base);
+            write!(
+                f,
+                [
+                    dynamic_text(&indentation, token.text_trimmed_range().start()),
+                    token.format()
+                ]
+            )?;
+        }
+        Ok(())
+    }
+}
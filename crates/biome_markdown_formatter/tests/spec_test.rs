@@ -0,0 +1,60 @@
+use biome_configuration::{
+    PartialConfiguration, PartialMarkdownConfiguration, PartialMarkdownFormatter,
+};
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_markdown_formatter::{context::MarkdownFormatOptions, MarkdownFormatLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_markdown_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.md` and the output file name is `{spec_name}.md.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `markdown/null` -> input: `tests/specs/markdown/null.md`, expected output: `tests/specs/markdown/null.md.snap`
+/// * `null` -> input: `tests/specs/null.md`, expected output: `tests/specs/null.md.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+    let configuration = PartialConfiguration {
+        markdown: Some(PartialMarkdownConfiguration {
+            formatter: Some(PartialMarkdownFormatter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+        }),
+        ..Default::default()
+    };
+
+    let Some(test_file) =
+        SpecTestFile::try_from_file(spec_input_file, root_path, Some(configuration))
+    else {
+        return;
+    };
+
+    let source_type: MarkdownFileSource = test_file.input_file().as_path().try_into().unwrap();
+    let options = MarkdownFormatOptions::new(source_type);
+    let language = language::MarkdownTestFormatLanguage::new(source_type);
+
+    let snapshot = SpecSnapshot::new(
+        test_file,
+        test_directory,
+        language,
+        MarkdownFormatLanguage::new(options),
+    );
+
+    snapshot.test()
+}
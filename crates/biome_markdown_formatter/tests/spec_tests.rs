@@ -0,0 +1,8 @@
+mod quick_test;
+mod spec_test;
+
+mod formatter {
+    mod markdown_module {
+        tests_macros::gen_tests! {"tests/specs/markdown/**/*.{md,mdx}", crate::spec_test::run, ""}
+    }
+}
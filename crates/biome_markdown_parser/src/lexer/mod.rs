@@ -1,21 +1,28 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lossless Markdown lexer which yields SyntaxKind tokens used by the biome-markdown parser.
+//!
+//! Markdown is line oriented: the same characters start a block at the beginning of a line,
+//! such as `- item`, and are regular text elsewhere. The lexer only recognises the block markers
+//! at the start of a block, that is at the start of a line or after a list marker.
 
 #[rustfmt::skip]
 mod tests;
 
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::T;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
 };
 use biome_rowan::{SyntaxKind, TextSize};
-use biome_unicode_table::{lookup_byte, Dispatch::*};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
     #[default]
     Regular,
+    /// Lexes the rest of a line as a single [MD_LINE_LITERAL], such as a line of code.
+    /// The whitespace around the line is trivia.
+    Line,
 }
 
 impl LexContext for MarkdownLexContext {
@@ -28,9 +35,9 @@ impl LexContext for MarkdownLexContext {
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[allow(dead_code)]
     Regular,
-    // UnicodeRange,
+    /// See [MarkdownLexContext::Line]
+    Line,
 }
 
 /// An extremely fast, lookup table based, lossless Markdown lexer
@@ -90,12 +97,15 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = self.text_position();
         self.current_flags = TokenFlags::empty();
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current),
+            Some(current) => match context {
+                MarkdownLexContext::Regular => self.consume_token(current),
+                MarkdownLexContext::Line => self.consume_line(current),
+            },
             None => EOF,
         };
 
@@ -177,11 +187,30 @@ impl<'src> MarkdownLexer<'src> {
     }
 
     pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
-        let dispatched = lookup_byte(current);
-        match dispatched {
-            WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
-            _ => self.consume_textual(),
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b' ' | b'\t' => self.consume_whitespace_or_hard_line(),
+            0xEF if self.position == 0 && self.source.starts_with('\u{feff}') => {
+                self.advance(3);
+                self.unicode_bom_length = 3;
+                UNICODE_BOM
+            }
+            _ if self.is_at_block_start() => self.consume_block_token(current),
+            _ => self.consume_inline_token(current),
+        }
+    }
+
+    /// Lexes the rest of the line as a [MD_LINE_LITERAL], without the surrounding whitespace.
+    fn consume_line(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b' ' | b'\t' => self.consume_whitespace(),
+            _ => {
+                let end = self.line_end();
+                let trimmed = self.source[self.position..end].trim_end_matches([' ', '\t']);
+                self.advance(trimmed.len());
+                MD_LINE_LITERAL
+            }
         }
     }
 
@@ -189,12 +218,6 @@ impl<'src> MarkdownLexer<'src> {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[allow(dead_code)]
-    fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
     /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
     #[inline]
     fn byte_at(&self, offset: usize) -> Option<u8> {
@@ -210,16 +233,45 @@ impl<'src> MarkdownLexer<'src> {
         self.byte_at(1)
     }
 
-    /// Consume one newline or all whitespace until a non-whitespace or a newline is found.
-    ///
-    /// ## Safety
-    /// Must be called at a valid UT8 char boundary
-    fn consume_newline_or_whitespace(&mut self) -> MarkdownSyntaxKind {
-        match self.current_byte() {
-            Some(b'\n' | b'\r') => self.consume_newline(),
-            Some(b' ') => self.consume_whitespace(),
-            Some(b'\t') => self.consume_tab(),
-            _ => self.consume_textual(),
+    /// Returns the position of the line break that ends the current line,
+    /// or the length of the source on the last line.
+    fn line_end(&self) -> usize {
+        self.source.as_bytes()[self.position..]
+            .iter()
+            .position(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(self.source.len(), |offset| self.position + offset)
+    }
+
+    /// Returns the rest of the current line without the trailing whitespace.
+    fn rest_of_line(&self) -> &'src str {
+        self.source[self.position..self.line_end()].trim_end_matches([' ', '\t'])
+    }
+
+    /// Returns `true` if the lexer is at the start of a block: at the start of a line,
+    /// or after the marker of a list item, such as `- ` or `1. `.
+    fn is_at_block_start(&self) -> bool {
+        let bytes = &self.source.as_bytes()[..self.position];
+        let mut end = bytes.len();
+        loop {
+            let before_whitespace = end;
+            while end > 0 && matches!(bytes[end - 1], b' ' | b'\t') {
+                end -= 1;
+            }
+            if end == self.unicode_bom_length || matches!(bytes[end - 1], b'\n' | b'\r') {
+                return true;
+            }
+            if end == before_whitespace {
+                return false;
+            }
+            let word_start = bytes[..end]
+                .iter()
+                .rposition(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+                .map_or(self.unicode_bom_length, |position| position + 1)
+                .max(self.unicode_bom_length);
+            if !is_list_marker(&bytes[word_start..end]) {
+                return false;
+            }
+            end = word_start;
         }
     }
 
@@ -247,53 +299,280 @@ impl<'src> MarkdownLexer<'src> {
         NEWLINE
     }
 
-    /// Consumes all whitespace until a non-whitespace or a newline is found.
+    /// Consumes all the spaces and tabs until a non-whitespace or a newline is found.
     ///
     /// ## Safety
     /// Must be called at a valid UT8 char boundary
     fn consume_whitespace(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
-        while let Some(b' ') = self.current_byte() {
+        while let Some(b' ' | b'\t') = self.current_byte() {
             self.advance(1);
         }
 
         WHITESPACE
     }
 
-    fn consume_tab(&mut self) -> MarkdownSyntaxKind {
-        self.assert_at_char_boundary();
-
-        if matches!(self.current_byte(), Some(b'\t')) {
-            self.advance(1)
+    /// Consumes the whitespace, or a hard line break when two spaces or more end a line of text
+    /// that is followed by another line.
+    ///
+    /// https://spec.commonmark.org/0.31.2/#hard-line-breaks
+    fn consume_whitespace_or_hard_line(&mut self) -> MarkdownSyntaxKind {
+        let start = self.position;
+        let ends_text = !self.is_at_line_start();
+        self.consume_whitespace();
+        let spaces = &self.source[start..self.position];
+        if ends_text
+            && spaces.len() >= 2
+            && !spaces.contains('\t')
+            && matches!(self.current_byte(), Some(b'\n' | b'\r'))
+            && self.is_followed_by_text_line()
+        {
+            MD_HARD_LINE_LITERAL
+        } else {
+            WHITESPACE
         }
-        TAB
     }
 
-    fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
-        self.assert_at_char_boundary();
+    /// Returns `true` if only whitespace precedes the lexer on the current line.
+    fn is_at_line_start(&self) -> bool {
+        self.source.as_bytes()[..self.position]
+            .iter()
+            .rev()
+            .find(|byte| !matches!(byte, b' ' | b'\t'))
+            .is_none_or(|byte| matches!(byte, b'\n' | b'\r'))
+    }
+
+    /// Returns `true` if the line after the current one has some text.
+    fn is_followed_by_text_line(&self) -> bool {
+        let rest = &self.source[self.line_end()..];
+        let rest = rest
+            .strip_prefix("\r\n")
+            .or_else(|| rest.strip_prefix(['\n', '\r']))
+            .unwrap_or(rest);
+        rest.lines()
+            .next()
+            .is_some_and(|line| !line.trim_matches([' ', '\t']).is_empty())
+    }
+
+    /// Lexes the tokens that can only start a block, or falls back to the inline tokens.
+    fn consume_block_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'-' | b'*' | b'_' | b'+' => {
+                if let Some(kind) = self.consume_thematic_break_literal(current) {
+                    kind
+                } else if current != b'_' && self.is_followed_by_whitespace(1) {
+                    self.advance(1);
+                    MD_LIST_MARKER_LITERAL
+                } else {
+                    self.consume_inline_token(current)
+                }
+            }
+            b'0'..=b'9' => {
+                let digits = self.source.as_bytes()[self.position..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+                if digits <= 9
+                    && matches!(self.byte_at(digits), Some(b'.' | b')'))
+                    && self.is_followed_by_whitespace(digits + 1)
+                {
+                    self.advance(digits + 1);
+                    MD_LIST_MARKER_LITERAL
+                } else {
+                    self.consume_inline_token(current)
+                }
+            }
+            b'#' => {
+                let hashes = self.source.as_bytes()[self.position..]
+                    .iter()
+                    .take_while(|byte| **byte == b'#')
+                    .count();
+                if hashes <= 6 && self.is_followed_by_whitespace(hashes) {
+                    self.advance(hashes);
+                    MD_HASH_SEQUENCE_LITERAL
+                } else {
+                    self.consume_inline_token(current)
+                }
+            }
+            b'`' | b'~' => {
+                let fence = self.source.as_bytes()[self.position..]
+                    .iter()
+                    .take_while(|byte| **byte == current)
+                    .count();
+                let info = &self.source[self.position + fence..self.line_end()];
+                // The info string of a backtick fence can't contain backticks
+                if fence >= 3 && (current == b'~' || !info.contains('`')) {
+                    self.advance(fence);
+                    MD_FENCE_LITERAL
+                } else {
+                    self.consume_inline_token(current)
+                }
+            }
+            b'=' => {
+                let rest = self.rest_of_line();
+                if rest.bytes().all(|byte| byte == b'=') {
+                    self.advance(rest.len());
+                    MD_SETEXT_UNDERLINE_LITERAL
+                } else {
+                    self.consume_inline_token(current)
+                }
+            }
+            b'>' => self.consume_byte(T![>]),
+            b'<' => self.consume_byte(T![<]),
+            _ => self.consume_inline_token(current),
+        }
+    }
 
-        let start_char = match self.current_byte() {
-            Some(b'-') => b'-',
-            Some(b'*') => b'*',
-            Some(b'_') => b'_',
-            _ => return self.consume_textual(),
-        };
+    /// Returns `true` if the byte at `offset` is a whitespace, or if the line ends at `offset`.
+    fn is_followed_by_whitespace(&self, offset: usize) -> bool {
+        matches!(
+            self.byte_at(offset),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
+    }
 
+    /// Lexes a thematic break: a line of three `-`, `*` or `_` or more, that can be separated
+    /// by whitespace.
+    ///
+    /// https://spec.commonmark.org/0.31.2/#thematic-breaks
+    fn consume_thematic_break_literal(&mut self, current: u8) -> Option<MarkdownSyntaxKind> {
+        if current == b'+' {
+            return None;
+        }
+        let rest = self.rest_of_line();
         let mut count = 0;
-        loop {
-            self.consume_whitespace();
-            if matches!(self.current_byte(), Some(ch) if ch == start_char) {
-                self.advance(1);
-                count += 1;
-            } else {
-                break;
+        for byte in rest.bytes() {
+            match byte {
+                b' ' | b'\t' => {}
+                _ if byte == current => count += 1,
+                _ => return None,
+            }
+        }
+        if count < 3 {
+            return None;
+        }
+        self.advance(rest.len());
+        Some(MD_THEMATIC_BREAK_LITERAL)
+    }
+
+    /// Lexes the tokens of the text of a paragraph, a header or a table cell.
+    fn consume_inline_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'*' => self.consume_delimiter_run(current),
+            b'_' if !self.is_intraword_underscore() => self.consume_delimiter_run(current),
+            b'`' => self.consume_inline_code(),
+            b'|' => self.consume_byte(T![|]),
+            b'\\' if matches!(self.peek_byte(), Some(b'\n' | b'\r')) => {
+                self.consume_byte(MD_HARD_LINE_LITERAL)
             }
+            _ => self.consume_textual(),
+        }
+    }
+
+    /// Lexes a run of `*` or `_` that can delimit an emphasis, or a strong emphasis.
+    /// The longer runs, such as `***`, are regular text.
+    fn consume_delimiter_run(&mut self, current: u8) -> MarkdownSyntaxKind {
+        let run = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| **byte == current)
+            .count();
+        self.advance(run);
+        match (current, run) {
+            (b'*', 1) => T![*],
+            (b'*', 2) => T![**],
+            (b'_', 1) => T![_],
+            (b'_', 2) => T![__],
+            _ => MD_TEXTUAL_LITERAL,
         }
-        // until next newline or eof
-        if matches!(self.current_byte(), Some(b'\n' | b'\r') | None) && count >= 3 {
-            return MD_THEMATIC_BREAK_LITERAL;
+    }
+
+    /// Returns `true` if the run of underscores at the current position is surrounded by
+    /// alphanumeric characters, such as in `snake_case`. It can't delimit an emphasis.
+    fn is_intraword_underscore(&self) -> bool {
+        let before = self.source[..self.position].chars().next_back();
+        let after = self.source[self.position..]
+            .trim_start_matches('_')
+            .chars()
+            .next();
+        before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric)
+    }
+
+    /// Lexes a code span, from a run of backticks to the next run of the same length.
+    /// A run of backticks without a closing run is regular text.
+    ///
+    /// https://spec.commonmark.org/0.31.2/#code-spans
+    fn consume_inline_code(&mut self) -> MarkdownSyntaxKind {
+        let bytes = self.source.as_bytes();
+        let run = bytes[self.position..]
+            .iter()
+            .take_while(|byte| **byte == b'`')
+            .count();
+        let mut offset = self.position + run;
+        while offset < bytes.len() {
+            match bytes[offset] {
+                b'`' => {
+                    let closing = bytes[offset..]
+                        .iter()
+                        .take_while(|byte| **byte == b'`')
+                        .count();
+                    if closing == run {
+                        self.position = offset + closing;
+                        return MD_INLINE_CODE_LITERAL;
+                    }
+                    offset += closing;
+                }
+                b'\n' | b'\r' => {
+                    // A code span can't contain a blank line
+                    let next_line = bytes[offset + 1..]
+                        .iter()
+                        .find(|byte| !matches!(byte, b' ' | b'\t'));
+                    if next_line.is_none_or(|byte| matches!(byte, b'\n' | b'\r')) {
+                        break;
+                    }
+                    offset += 1;
+                }
+                _ => offset += 1,
+            }
         }
-        ERROR_TOKEN
+        self.advance(run);
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Lexes a word: the characters until a whitespace or the start of another inline token.
+    fn consume_textual(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+        let start = self.position;
+
+        while let Some(current) = self.current_byte() {
+            match current {
+                b' ' | b'\t' | b'\n' | b'\r' | b'*' | b'`' | b'|' => break,
+                b'_' => {
+                    if !self.is_intraword_underscore() {
+                        break;
+                    }
+                    while self.current_byte() == Some(b'_') {
+                        self.advance(1);
+                    }
+                }
+                b'\\' => match self.peek_byte() {
+                    Some(b'\n' | b'\r') => break,
+                    // https://spec.commonmark.org/0.31.2/#backslash-escapes
+                    Some(escaped) if escaped.is_ascii_punctuation() => self.advance(2),
+                    _ => self.advance(1),
+                },
+                _ => {
+                    let char = self.current_char_unchecked();
+                    self.advance(char.len_utf8());
+                }
+            }
+        }
+
+        if self.position == start {
+            let char = self.current_char_unchecked();
+            self.advance(char.len_utf8());
+        }
+
+        MD_TEXTUAL_LITERAL
     }
 
     /// Get the UTF8 char which starts at the current byte
@@ -352,18 +631,7 @@ impl<'src> MarkdownLexer<'src> {
         self.position >= self.source.len()
     }
 
-    #[inline]
-    fn consume_textual(&mut self) -> MarkdownSyntaxKind {
-        self.assert_at_char_boundary();
-
-        let char = self.current_char_unchecked();
-        self.advance(char.len_utf8());
-
-        MD_TEXTUAL_LITERAL
-    }
-
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
     fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
@@ -378,12 +646,12 @@ impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
         let re_lexed_kind = match self.current_byte() {
             Some(current) => match context {
                 MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                MarkdownReLexContext::Line => self.consume_line(current),
             },
             None => EOF,
         };
 
-        if self.current() == re_lexed_kind {
+        if self.current() == re_lexed_kind && self.position == old_position {
             // Didn't re-lex anything. Return existing token again
             self.position = old_position;
         } else {
@@ -407,3 +675,16 @@ impl<'src> LexerWithCheckpoint<'src> for MarkdownLexer<'src> {
         }
     }
 }
+
+/// Returns `true` if `word` is the marker of a list item, such as `-`, `*`, `+`, `1.` or `1)`.
+///
+/// https://spec.commonmark.org/0.31.2/#list-items
+fn is_list_marker(word: &[u8]) -> bool {
+    match word {
+        [b'-' | b'*' | b'+'] => true,
+        [digits @ .., b'.' | b')'] => {
+            (1..=9).contains(&digits.len()) && digits.iter().all(u8::is_ascii_digit)
+        }
+        _ => false,
+    }
+}
@@ -109,8 +109,10 @@ fn empty() {
 #[test]
 fn textual() {
     assert_lex! {
-        "+",
-       MD_TEXTUAL_LITERAL:1,
+        "Lorem ipsum",
+        MD_TEXTUAL_LITERAL:5,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:5,
     }
 }
 
@@ -125,18 +127,21 @@ fn new_line() {
 }
 
 #[test]
-fn tab() {
+fn whitespace() {
     assert_lex! {
-        "\t",
-        TAB:1,
+        " \t ",
+        WHITESPACE:3,
     }
 }
 
 #[test]
-fn whitespace() {
+fn unicode_bom() {
     assert_lex! {
-        " ",
+        "\u{feff}# a",
+        UNICODE_BOM:3,
+        MD_HASH_SEQUENCE_LITERAL:1,
         WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
     }
 }
 
@@ -159,6 +164,174 @@ _ _ _ _  _ "#,
         NEWLINE:1,
         MD_THEMATIC_BREAK_LITERAL:7,
         NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:11,
+        MD_THEMATIC_BREAK_LITERAL:10,
+        WHITESPACE:1,
+    }
+}
+
+#[test]
+fn hash_sequence() {
+    assert_lex! {
+        "## a #b",
+        MD_HASH_SEQUENCE_LITERAL:2,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:2,
+    }
+}
+
+#[test]
+fn not_hash_sequence() {
+    assert_lex! {
+        "#a\n####### a",
+        MD_TEXTUAL_LITERAL:2,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:7,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn list_markers() {
+    assert_lex! {
+        "- a\n  1. - b\n+\n10) c",
+        MD_LIST_MARKER_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        WHITESPACE:2,
+        MD_LIST_MARKER_LITERAL:2,
+        WHITESPACE:1,
+        MD_LIST_MARKER_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_LIST_MARKER_LITERAL:1,
+        NEWLINE:1,
+        MD_LIST_MARKER_LITERAL:3,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn list_markers_in_text() {
+    assert_lex! {
+        "a - b 1. c",
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:2,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn fence() {
+    assert_lex! {
+        "```js\n~~~~",
+        MD_FENCE_LITERAL:3,
+        MD_TEXTUAL_LITERAL:2,
+        NEWLINE:1,
+        MD_FENCE_LITERAL:4,
+    }
+}
+
+#[test]
+fn setext_underline() {
+    assert_lex! {
+        "a\n=== ",
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_SETEXT_UNDERLINE_LITERAL:3,
+        WHITESPACE:1,
+    }
+}
+
+#[test]
+fn emphasis_delimiters() {
+    assert_lex! {
+        "***a** _b_ snake_case",
+        MD_TEXTUAL_LITERAL:3,
+        MD_TEXTUAL_LITERAL:1,
+        DOUBLE_STAR:2,
+        WHITESPACE:1,
+        UNDERSCORE:1,
+        MD_TEXTUAL_LITERAL:1,
+        UNDERSCORE:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:10,
+    }
+}
+
+#[test]
+fn inline_code() {
+    assert_lex! {
+        "`a` ``b ` c`` `d",
+        MD_INLINE_CODE_LITERAL:3,
+        WHITESPACE:1,
+        MD_INLINE_CODE_LITERAL:9,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn escapes() {
+    assert_lex! {
+        r"\*a\_",
+        MD_TEXTUAL_LITERAL:5,
+    }
+}
+
+#[test]
+fn hard_line() {
+    assert_lex! {
+        "a  \nb\\\nc  \n",
+        MD_TEXTUAL_LITERAL:1,
+        MD_HARD_LINE_LITERAL:2,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_HARD_LINE_LITERAL:1,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:2,
+        NEWLINE:1,
+    }
+}
+
+#[test]
+fn block_quote_and_html() {
+    assert_lex! {
+        "> a\n<div>",
+        R_ANGLE:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        L_ANGLE:1,
+        MD_TEXTUAL_LITERAL:4,
+    }
+}
+
+#[test]
+fn table() {
+    assert_lex! {
+        "| a |\n|:-|",
+        PIPE:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        WHITESPACE:1,
+        PIPE:1,
+        NEWLINE:1,
+        PIPE:1,
+        MD_TEXTUAL_LITERAL:2,
+        PIPE:1,
     }
 }
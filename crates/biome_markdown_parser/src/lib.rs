@@ -1,10 +1,12 @@
 use biome_markdown_factory::MarkdownSyntaxFactory;
 use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument};
-use biome_parser::{prelude::ParseDiagnostic, tree_sink::LosslessTreeSink};
+use biome_parser::{prelude::ParseDiagnostic, tree_sink::LosslessTreeSink, AnyParse};
 use biome_rowan::{AstNode, NodeCache};
 use parser::MarkdownParser;
 use syntax::parse_document;
 
+pub use parser::MarkdownParserOptions;
+
 mod lexer;
 mod parser;
 mod syntax;
@@ -13,14 +15,18 @@ mod token_source;
 pub(crate) type MarkdownLosslessTreeSink<'source> =
     LosslessTreeSink<'source, MarkdownLanguage, MarkdownSyntaxFactory>;
 
-pub fn parse_markdown(source: &str) -> MarkdownParse {
+pub fn parse_markdown(source: &str, options: MarkdownParserOptions) -> MarkdownParse {
     let mut cache = NodeCache::default();
-    parse_markdown_with_cache(source, &mut cache)
+    parse_markdown_with_cache(source, &mut cache, options)
 }
 
-pub fn parse_markdown_with_cache(source: &str, cache: &mut NodeCache) -> MarkdownParse {
+pub fn parse_markdown_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: MarkdownParserOptions,
+) -> MarkdownParse {
     tracing::debug_span!("Parsing phase").in_scope(move || {
-        let mut parser = MarkdownParser::new(source);
+        let mut parser = MarkdownParser::new(source, options);
 
         parse_document(&mut parser);
 
@@ -75,3 +81,15 @@ impl MarkdownParse {
         MdDocument::unwrap_cast(self.syntax())
     }
 }
+
+impl From<MarkdownParse> for AnyParse {
+    fn from(parse: MarkdownParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+        Self::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        )
+    }
+}
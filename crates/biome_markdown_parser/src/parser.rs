@@ -1,25 +1,57 @@
-use biome_markdown_syntax::MarkdownSyntaxKind;
+use biome_markdown_syntax::MarkdownFileSource;
+use biome_markdown_syntax::MarkdownSyntaxKind::{self, *};
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
 use biome_parser::ParserContext;
 use biome_parser::{diagnostic::merge_diagnostics, ParserContextCheckpoint};
 
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
 use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct MarkdownParserOptions {
+    /// Enables parsing of the MDX syntax: the ESM statements and the JSX blocks.
+    /// Defaults to `false`.
+    pub mdx: bool,
+}
+
+impl MarkdownParserOptions {
+    /// Enables parsing of the MDX syntax.
+    pub fn allow_mdx(mut self) -> Self {
+        self.mdx = true;
+        self
+    }
+}
+
+impl From<&MarkdownFileSource> for MarkdownParserOptions {
+    fn from(file_source: &MarkdownFileSource) -> Self {
+        let mut options = Self::default();
+        if file_source.is_mdx() {
+            options = options.allow_mdx();
+        }
+        options
+    }
+}
+
 pub(crate) struct MarkdownParser<'source> {
     context: ParserContext<MarkdownSyntaxKind>,
     source: MarkdownTokenSource<'source>,
+    options: MarkdownParserOptions,
 }
 
 impl<'source> MarkdownParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: MarkdownParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
             source: MarkdownTokenSource::from_str(source),
+            options,
         }
     }
-    #[allow(dead_code)]
+
+    pub fn options(&self) -> &MarkdownParserOptions {
+        &self.options
+    }
     pub fn checkpoint(&self) -> MarkdownParserCheckpoint {
         MarkdownParserCheckpoint {
             context: self.context.checkpoint(),
@@ -27,11 +59,6 @@ impl<'source> MarkdownParser<'source> {
         }
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
-    }
-
-    #[allow(dead_code)]
     pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
         let MarkdownParserCheckpoint { context, source } = checkpoint;
 
@@ -39,6 +66,118 @@ impl<'source> MarkdownParser<'source> {
         self.source.rewind(source);
     }
 
+    /// Re-lexes the current token in the given context
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.source.re_lex(context)
+    }
+
+    /// Bumps the current line, and lexes the next token as a line too.
+    /// See [MarkdownLexContext::Line].
+    pub fn bump_line(&mut self) {
+        self.bump_with_context(MD_LINE_LITERAL, MarkdownLexContext::Line);
+    }
+
+    /// Returns the text of the current line before the current token, without the Unicode BOM.
+    fn line_before_current(&self) -> &str {
+        let text = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        let line_start = text[..start]
+            .rfind(['\n', '\r'])
+            .map_or(0, |index| index + 1);
+        let line = &text[line_start..start];
+        line.strip_prefix('\u{feff}').unwrap_or(line)
+    }
+
+    /// Returns `true` if only whitespace precedes the current token on its line.
+    pub fn is_at_line_start(&self) -> bool {
+        self.line_before_current()
+            .bytes()
+            .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Returns the column of the current token. A tab advances to the next multiple of 4.
+    ///
+    /// https://spec.commonmark.org/0.31.2/#tabs
+    pub fn column(&self) -> usize {
+        self.line_before_current().chars().fold(0, |column, char| {
+            if char == '\t' {
+                column + 4 - column % 4
+            } else {
+                column + 1
+            }
+        })
+    }
+
+    /// Returns `true` if a blank line separates the current token from the previous one.
+    pub fn has_preceding_blank_line(&self) -> bool {
+        let text = self.source.text();
+        let mut before = &text[..usize::from(self.cur_range().start())];
+        let mut line_breaks = 0;
+        loop {
+            before = before.trim_end_matches([' ', '\t']);
+            let Some(rest) = before
+                .strip_suffix("\r\n")
+                .or_else(|| before.strip_suffix(['\n', '\r']))
+            else {
+                return false;
+            };
+            line_breaks += 1;
+            if line_breaks == 2 {
+                return true;
+            }
+            before = rest;
+        }
+    }
+
+    /// Returns the rest of the current line after the current token, without the line break.
+    pub fn rest_of_line(&self) -> &str {
+        let text = self.source.text();
+        let rest = &text[usize::from(self.cur_range().end())..];
+        let end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+        &rest[..end]
+    }
+
+    /// Returns the current line from the current token, without the line break.
+    pub fn current_line(&self) -> &str {
+        let text = self.source.text();
+        let rest = &text[usize::from(self.cur_range().start())..];
+        let end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+        &rest[..end]
+    }
+
+    /// Returns the line that follows the current line, if any.
+    pub fn next_line(&self) -> Option<&str> {
+        let text = self.source.text();
+        let rest = &text[usize::from(self.cur_range().start())..];
+        let end = rest.find(['\n', '\r'])?;
+        let rest = &rest[end..];
+        let rest = rest
+            .strip_prefix("\r\n")
+            .or_else(|| rest.strip_prefix(['\n', '\r']))?;
+        rest.lines().next()
+    }
+
+    /// Returns the character that precedes the current token, if any.
+    pub fn char_before_current(&self) -> Option<char> {
+        self.source.text()[..usize::from(self.cur_range().start())]
+            .chars()
+            .next_back()
+    }
+
+    /// Returns the character that follows the current token, if any.
+    pub fn char_after_current(&self) -> Option<char> {
+        self.source.text()[usize::from(self.cur_range().end())..]
+            .chars()
+            .next()
+    }
+
+    /// Returns `true` if the rest of the current line after the current token is blank.
+    pub fn is_at_line_end(&self) -> bool {
+        self.rest_of_line()
+            .bytes()
+            .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -76,7 +215,6 @@ impl<'source> Parser for MarkdownParser<'source> {
     }
 }
 
-#[allow(dead_code)]
 pub struct MarkdownParserCheckpoint {
     pub(super) context: ParserContextCheckpoint,
     pub(super) source: MarkdownTokenSourceCheckpoint,
@@ -1,56 +1,101 @@
+pub mod code_block;
+pub mod header;
+pub mod html_block;
+pub mod inline;
+pub mod list;
+pub mod mdx;
+pub mod paragraph;
+pub mod quote;
+pub mod table;
 pub mod thematic_break_block;
 
 use biome_markdown_syntax::{kind::MarkdownSyntaxKind::*, T};
 use biome_parser::{
-    prelude::ParsedSyntax::{self, *},
+    prelude::{CompletedMarker, ParsedSyntax},
     Parser,
 };
-use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
+use code_block::{parse_fenced_code_block, parse_indent_code_block};
+use header::parse_header;
+use html_block::{at_html_block, parse_html_block};
+use list::{at_list_item_interrupting_paragraph, parse_list};
+use mdx::{at_mdx_esm_block, at_mdx_flow_block, parse_mdx_esm_block, parse_mdx_flow_block};
+use paragraph::parse_paragraph;
+use quote::parse_quote;
+use table::{at_table, parse_table};
+use thematic_break_block::parse_thematic_break_block;
 
 use crate::MarkdownParser;
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
     let m = p.start();
-    let _ = parse_block_list(p);
+    p.eat(UNICODE_BOM);
+    parse_block_list(p, 0);
+    p.expect(T![EOF]);
     m.complete(p, MD_DOCUMENT);
 }
 
-pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
+/// Parses the blocks of a container whose content starts at the column `indent`.
+///
+/// The list ends with the container, when a line is indented less than the content.
+pub(crate) fn parse_block_list(p: &mut MarkdownParser, indent: usize) -> CompletedMarker {
     let m = p.start();
 
-    while !p.at(T![EOF]) {
-        parse_any_block(p);
+    while !p.at(T![EOF]) && !at_container_end(p, indent) {
+        let _ = parse_any_block(p, indent);
     }
-    Present(m.complete(p, MD_BLOCK_LIST))
+    m.complete(p, MD_BLOCK_LIST)
 }
 
-pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
-    if at_indent_code_block(p) {
-        parse_indent_code_block(p);
-    } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
-            }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
-        }
-    }
+/// Returns `true` if the current line is indented less than the content of the container.
+fn at_container_end(p: &MarkdownParser, indent: usize) -> bool {
+    p.is_at_line_start() && p.column() < indent
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
+pub(crate) fn parse_any_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if at_indent_code_block(p, indent) {
+        return parse_indent_code_block(p, indent);
+    }
+    if at_mdx_esm_block(p, indent) {
+        return parse_mdx_esm_block(p);
+    }
+    if at_mdx_flow_block(p) {
+        return parse_mdx_flow_block(p, indent);
+    }
+    match p.cur() {
+        MD_THEMATIC_BREAK_LITERAL => parse_thematic_break_block(p),
+        MD_HASH_SEQUENCE_LITERAL => parse_header(p),
+        MD_FENCE_LITERAL => parse_fenced_code_block(p, indent),
+        T![>] => parse_quote(p, indent),
+        MD_LIST_MARKER_LITERAL => parse_list(p, indent),
+        T![<] if at_html_block(p, false) => parse_html_block(p, indent),
+        _ if at_table(p) => parse_table(p, indent),
+        _ => parse_paragraph(p, indent),
+    }
 }
 
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns `true` if the current line is indented by four spaces or more from the content of
+/// the container.
+pub(crate) fn at_indent_code_block(p: &MarkdownParser, indent: usize) -> bool {
+    p.is_at_line_start() && p.column() >= indent + 4
 }
 
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns `true` if the current line starts a block that ends the paragraph or the table
+/// that precedes it, such as a header or a list item.
+///
+/// https://spec.commonmark.org/0.31.2/#paragraph-continuation-text
+pub(crate) fn at_block_interrupt(p: &MarkdownParser, indent: usize) -> bool {
+    if !p.is_at_line_start() || at_indent_code_block(p, indent) {
+        return false;
+    }
+    match p.cur() {
+        MD_THEMATIC_BREAK_LITERAL | MD_HASH_SEQUENCE_LITERAL | MD_FENCE_LITERAL | T![>] => true,
+        MD_LIST_MARKER_LITERAL => {
+            // The item of an enclosing list ends the paragraph
+            p.column() < indent || at_list_item_interrupting_paragraph(p)
+        }
+        T![<] => at_html_block(p, true),
+        _ => false,
+    }
 }
 
 /// Attempt to parse some input with the given parsing function. If parsing
@@ -0,0 +1,82 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Parses the lines indented by four spaces or more from the content of the container.
+/// The blank lines between them belong to the code.
+///
+/// https://spec.commonmark.org/0.31.2/#indented-code-blocks
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    let m = p.start();
+    let content = p.start();
+
+    p.re_lex(MarkdownReLexContext::Line);
+    loop {
+        let line = p.start();
+        p.bump_line();
+        line.complete(p, MD_LINE);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+        if p.column() < indent + 4 {
+            p.re_lex(MarkdownReLexContext::Regular);
+            break;
+        }
+    }
+    content.complete(p, MD_LINE_LIST);
+
+    Present(m.complete(p, MD_INDENT_CODE_BLOCK))
+}
+
+/// Parses a fenced code block. The code ends with a closing fence, with the container,
+/// or with the document.
+///
+/// https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !p.at(MD_FENCE_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    let fence = p.cur_text();
+    let fence_char = fence.as_bytes()[0];
+    let fence_len = fence.len();
+
+    p.bump_with_context(MD_FENCE_LITERAL, MarkdownLexContext::Line);
+    if p.at(MD_LINE_LITERAL) && !p.has_preceding_line_break() {
+        p.bump_line();
+    }
+
+    let content = p.start();
+    let mut closed = false;
+    while !p.at(T![EOF]) {
+        let column = p.column();
+        if column < indent {
+            break;
+        }
+        let text = p.cur_text();
+        if column < indent + 4
+            && text.len() >= fence_len
+            && text.bytes().all(|byte| byte == fence_char)
+        {
+            closed = true;
+            break;
+        }
+        let line = p.start();
+        p.bump_line();
+        line.complete(p, MD_LINE);
+    }
+    content.complete(p, MD_LINE_LIST);
+
+    if closed {
+        p.bump_remap(MD_FENCE_LITERAL);
+    } else if !p.at(T![EOF]) {
+        p.re_lex(MarkdownReLexContext::Regular);
+    }
+
+    Present(m.complete(p, MD_FENCED_CODE_BLOCK))
+}
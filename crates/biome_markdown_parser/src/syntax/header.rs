@@ -0,0 +1,40 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{parse_inline_item_list, InlineContext};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+pub(crate) fn at_header(p: &mut MarkdownParser) -> bool {
+    p.at(MD_HASH_SEQUENCE_LITERAL)
+}
+
+/// Parses an ATX header, such as `## Title`, with its optional closing sequence.
+///
+/// https://spec.commonmark.org/0.31.2/#atx-headings
+pub(crate) fn parse_header(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_header(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    p.bump(MD_HASH_SEQUENCE_LITERAL);
+    parse_inline_item_list(p, InlineContext::Header);
+    if at_closing_sequence(p) {
+        p.bump_remap(MD_HASH_SEQUENCE_LITERAL);
+    }
+
+    Present(m.complete(p, MD_HEADER))
+}
+
+/// Returns `true` if the current token is the closing sequence of `#` of a header,
+/// such as in `## Title ##`.
+pub(crate) fn at_closing_sequence(p: &MarkdownParser) -> bool {
+    let text = p.cur_text();
+    !p.has_preceding_line_break()
+        && !text.is_empty()
+        && text.bytes().all(|byte| byte == b'#')
+        && matches!(p.char_before_current(), Some(' ' | '\t'))
+        && p.is_at_line_end()
+}
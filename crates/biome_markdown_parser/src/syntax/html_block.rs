@@ -0,0 +1,201 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// The names of the HTML elements whose block ends with a blank line, and that can end a
+/// paragraph.
+///
+/// https://spec.commonmark.org/0.31.2/#html-blocks
+const BLOCK_ELEMENTS: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// The names of the HTML elements whose block ends with their closing tag, even after a
+/// blank line.
+const RAW_ELEMENTS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// How an HTML block ends
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum HtmlBlockEnd {
+    /// The block ends with the line that contains the text
+    Text(&'static str),
+    /// The block ends with the line that contains the closing tag of a raw element
+    RawElement,
+    /// The block ends before a blank line
+    BlankLine,
+}
+
+/// Returns `true` if the current line starts an HTML block. When the block would `interrupt`
+/// a paragraph, only the comments, the declarations and the known block elements start it.
+///
+/// MDX documents don't have HTML blocks: their tags are JSX.
+pub(crate) fn at_html_block(p: &MarkdownParser, interrupting: bool) -> bool {
+    !p.options().mdx && p.at(T![<]) && html_block_end(p.current_line(), interrupting).is_some()
+}
+
+/// Returns how the HTML block that starts the line ends, or `None` if it doesn't start a block.
+fn html_block_end(line: &str, interrupting: bool) -> Option<HtmlBlockEnd> {
+    let rest = line.strip_prefix('<')?;
+    if rest.starts_with("!--") {
+        return Some(HtmlBlockEnd::Text("-->"));
+    }
+    if rest.starts_with("![CDATA[") {
+        return Some(HtmlBlockEnd::Text("]]>"));
+    }
+    if rest.starts_with('?') {
+        return Some(HtmlBlockEnd::Text("?>"));
+    }
+    if rest
+        .strip_prefix('!')
+        .is_some_and(|rest| rest.starts_with(|char: char| char.is_ascii_alphabetic()))
+    {
+        return Some(HtmlBlockEnd::Text(">"));
+    }
+    let closing = rest.starts_with('/');
+    let rest = rest.trim_start_matches('/');
+    let name_len = rest
+        .find(|char: char| !char.is_ascii_alphanumeric() && char != '-')
+        .unwrap_or(rest.len());
+    let name = &rest[..name_len];
+    if !name.starts_with(|char: char| char.is_ascii_alphabetic()) {
+        return None;
+    }
+    // The name must end the tag, or be followed by an attribute
+    let after_name = &rest[name_len..];
+    if !(after_name.is_empty()
+        || after_name.starts_with([' ', '\t', '>'])
+        || after_name.starts_with("/>"))
+    {
+        return None;
+    }
+    if !closing
+        && RAW_ELEMENTS
+            .iter()
+            .any(|element| element.eq_ignore_ascii_case(name))
+    {
+        return Some(HtmlBlockEnd::RawElement);
+    }
+    if BLOCK_ELEMENTS
+        .iter()
+        .any(|element| element.eq_ignore_ascii_case(name))
+        || !interrupting
+    {
+        return Some(HtmlBlockEnd::BlankLine);
+    }
+    None
+}
+
+/// Returns `true` if `line` contains the closing tag of a raw element, such as `</script>`.
+fn contains_raw_element_end(line: &str) -> bool {
+    line.match_indices("</").any(|(index, _)| {
+        let rest = &line[index + 2..];
+        RAW_ELEMENTS.iter().any(|element| {
+            rest.get(..element.len())
+                .is_some_and(|name| name.eq_ignore_ascii_case(element))
+                && rest[element.len()..].starts_with('>')
+        })
+    })
+}
+
+/// Parses an HTML block. Its lines are kept as written.
+///
+/// https://spec.commonmark.org/0.31.2/#html-blocks
+pub(crate) fn parse_html_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    let Some(end) = html_block_end(p.current_line(), false) else {
+        return Absent;
+    };
+    let m = p.start();
+    let content = p.start();
+
+    p.re_lex(MarkdownReLexContext::Line);
+    loop {
+        let text = p.cur_text();
+        let is_last_line = match end {
+            HtmlBlockEnd::Text(end) => text.contains(end),
+            HtmlBlockEnd::RawElement => contains_raw_element_end(text),
+            HtmlBlockEnd::BlankLine => false,
+        };
+        let line = p.start();
+        p.bump_line();
+        line.complete(p, MD_LINE);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+        if is_last_line
+            || p.column() < indent
+            || end == HtmlBlockEnd::BlankLine && p.has_preceding_blank_line()
+        {
+            p.re_lex(MarkdownReLexContext::Regular);
+            break;
+        }
+    }
+    content.complete(p, MD_LINE_LIST);
+
+    Present(m.complete(p, MD_HTML_BLOCK))
+}
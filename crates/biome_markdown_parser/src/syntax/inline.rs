@@ -0,0 +1,136 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::header::at_closing_sequence;
+use crate::syntax::paragraph::at_setext_underline;
+use crate::syntax::table::at_table;
+use crate::syntax::{at_block_interrupt, try_parse};
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::{prelude::CompletedMarker, Parser};
+use biome_rowan::TextSize;
+
+/// Where the text is, which decides where it ends.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum InlineContext {
+    /// The text of a paragraph spans several lines, until a blank line or another block.
+    /// `start` is the position of the first token of the paragraph.
+    Paragraph { indent: usize, start: TextSize },
+    /// The text of a header ends with its line, or with the closing sequence of `#`.
+    Header,
+    /// The text of a table cell ends with a pipe, or with the line of the row.
+    /// `start` is the position of the first token of the row.
+    TableCell { start: TextSize },
+}
+
+pub(crate) fn parse_inline_item_list(
+    p: &mut MarkdownParser,
+    context: InlineContext,
+) -> CompletedMarker {
+    let m = p.start();
+    parse_inline_items(p, context, None);
+    m.complete(p, MD_INLINE_ITEM_LIST)
+}
+
+/// Parses the inline items until the end of the text, or until the delimiter that closes an
+/// emphasis. Returns `true` if the closing delimiter was found.
+fn parse_inline_items(
+    p: &mut MarkdownParser,
+    context: InlineContext,
+    closing: Option<MarkdownSyntaxKind>,
+) -> bool {
+    loop {
+        if at_inline_end(p, context) {
+            return false;
+        }
+        if closing.is_some_and(|closing| p.at(closing)) && at_closing_delimiter(p) {
+            return true;
+        }
+        parse_inline_item(p, context);
+    }
+}
+
+fn at_inline_end(p: &MarkdownParser, context: InlineContext) -> bool {
+    if p.at(T![EOF]) {
+        return true;
+    }
+    match context {
+        InlineContext::Paragraph { indent, start } => {
+            p.has_preceding_line_break()
+                && p.cur_range().start() != start
+                && (p.has_preceding_blank_line()
+                    || at_setext_underline(p, indent)
+                    || at_block_interrupt(p, indent)
+                    || at_table(p))
+        }
+        InlineContext::Header => p.has_preceding_line_break() || at_closing_sequence(p),
+        InlineContext::TableCell { start } => {
+            p.at(T![|]) || p.has_preceding_line_break() && p.cur_range().start() != start
+        }
+    }
+}
+
+fn parse_inline_item(p: &mut MarkdownParser, context: InlineContext) {
+    match p.cur() {
+        MD_INLINE_CODE_LITERAL => {
+            let m = p.start();
+            p.bump(MD_INLINE_CODE_LITERAL);
+            m.complete(p, MD_INLINE_CODE);
+        }
+        MD_HARD_LINE_LITERAL => {
+            let m = p.start();
+            p.bump(MD_HARD_LINE_LITERAL);
+            m.complete(p, MD_HARD_LINE);
+        }
+        T![*] | T![_] | T![**] | T![__] if at_opening_delimiter(p) => {
+            if parse_emphasis(p, context).is_err() {
+                parse_textual(p);
+            }
+        }
+        _ => parse_textual(p),
+    }
+}
+
+/// Parses any token as text, such as a word or a delimiter that doesn't delimit an emphasis.
+fn parse_textual(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.bump_remap(MD_TEXTUAL_LITERAL);
+    m.complete(p, MD_TEXTUAL);
+}
+
+/// Parses an emphasis, such as `_text_`, or a strong emphasis, such as `**text**`.
+/// Fails if the delimiter isn't closed in the same text.
+///
+/// https://spec.commonmark.org/0.31.2/#emphasis-and-strong-emphasis
+fn parse_emphasis(p: &mut MarkdownParser, context: InlineContext) -> Result<CompletedMarker, ()> {
+    let delimiter = p.cur();
+    let kind = if matches!(delimiter, T![*] | T![_]) {
+        MD_INLINE_EMPHASIS
+    } else {
+        MD_INLINE_STRONG
+    };
+    try_parse(p, |p| {
+        let m = p.start();
+        p.bump(delimiter);
+        let content = p.start();
+        let content_start = p.cur_range().start();
+        let closed = parse_inline_items(p, context, Some(delimiter));
+        if !closed || p.cur_range().start() == content_start {
+            content.abandon(p);
+            m.abandon(p);
+            return Err(());
+        }
+        content.complete(p, MD_INLINE_ITEM_LIST);
+        p.bump(delimiter);
+        Ok(m.complete(p, kind))
+    })
+}
+
+/// Returns `true` if the current delimiter can open an emphasis: a whitespace doesn't follow it.
+fn at_opening_delimiter(p: &MarkdownParser) -> bool {
+    p.char_after_current()
+        .is_some_and(|char| !char.is_whitespace())
+}
+
+/// Returns `true` if the current delimiter can close an emphasis: a whitespace doesn't precede it.
+fn at_closing_delimiter(p: &MarkdownParser) -> bool {
+    p.char_before_current()
+        .is_some_and(|char| !char.is_whitespace())
+}
@@ -0,0 +1,93 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::parse_block_list;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Parses the consecutive items of a bullet list, such as `- item`, or of an ordered list,
+/// such as `1. item`. The items of a list have the same bullet, or the same delimiter after
+/// their number.
+///
+/// https://spec.commonmark.org/0.31.2/#lists
+pub(crate) fn parse_list(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !p.at(MD_LIST_MARKER_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    let items = p.start();
+
+    let marker_kind = ListMarkerKind::from_marker(p.cur_text());
+    loop {
+        let content_indent = parse_list_item(p);
+        let column = p.column();
+        let is_sibling = p.at(MD_LIST_MARKER_LITERAL)
+            && p.is_at_line_start()
+            && column >= indent
+            && column < content_indent
+            && ListMarkerKind::from_marker(p.cur_text()) == marker_kind;
+        if !is_sibling {
+            break;
+        }
+    }
+    items.complete(p, MD_LIST_ITEM_LIST);
+
+    let kind = match marker_kind {
+        ListMarkerKind::Bullet(_) => MD_BULLET_LIST_BLOCK,
+        ListMarkerKind::Ordered(_) => MD_ORDERED_LIST_BLOCK,
+    };
+    Present(m.complete(p, kind))
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ListMarkerKind {
+    /// The bullet of the items: `-`, `*` or `+`
+    Bullet(u8),
+    /// The delimiter after the number of the items: `.` or `)`
+    Ordered(u8),
+}
+
+impl ListMarkerKind {
+    fn from_marker(marker: &str) -> Self {
+        match marker.as_bytes() {
+            [bullet] => Self::Bullet(*bullet),
+            [.., delimiter] => Self::Ordered(*delimiter),
+            [] => unreachable!("A list marker isn't empty"),
+        }
+    }
+}
+
+/// Parses a list item, and returns the column of its content.
+fn parse_list_item(p: &mut MarkdownParser) -> usize {
+    let m = p.start();
+    let marker_end = p.column() + p.cur_text().len();
+
+    p.bump(MD_LIST_MARKER_LITERAL);
+
+    // The content of an item starts one space after the marker when the item starts with a
+    // blank line, or with an indented code block.
+    // https://spec.commonmark.org/0.31.2/#list-items
+    let content_indent = if p.has_preceding_line_break() || p.at(T![EOF]) {
+        marker_end + 1
+    } else {
+        let column = p.column();
+        if column - marker_end > 4 {
+            marker_end + 1
+        } else {
+            column
+        }
+    };
+    parse_block_list(p, content_indent);
+
+    m.complete(p, MD_LIST_ITEM);
+    content_indent
+}
+
+/// Returns `true` if the current list marker can start a list that ends a paragraph:
+/// a bullet list, or an ordered list that starts with 1, with some content.
+pub(crate) fn at_list_item_interrupting_paragraph(p: &MarkdownParser) -> bool {
+    let marker = p.cur_text();
+    !p.is_at_line_end()
+        && (marker.len() == 1 || matches!(marker.trim_start_matches('0'), "1." | "1)"))
+}
@@ -0,0 +1,81 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Returns `true` if the current line starts the ESM statements of an MDX document, such as
+/// `import { Chart } from "./chart.js"`. They're only allowed at the top level of the document.
+///
+/// https://mdxjs.com/docs/what-is-mdx/#esm
+pub(crate) fn at_mdx_esm_block(p: &MarkdownParser, indent: usize) -> bool {
+    if !p.options().mdx || indent > 0 || p.column() > 0 || !p.is_at_line_start() {
+        return false;
+    }
+    let line = p.current_line();
+    ["import", "export"].iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .is_some_and(|rest| rest.starts_with([' ', '\t', '{', '*']))
+    })
+}
+
+/// Returns `true` if the current line starts a JSX element or an expression of an MDX
+/// document, such as `<Chart data={data} />` or `{/* comment */}`, that isn't followed by text
+/// on its line.
+///
+/// https://mdxjs.com/docs/what-is-mdx/#jsx
+pub(crate) fn at_mdx_flow_block(p: &MarkdownParser) -> bool {
+    if !p.options().mdx {
+        return false;
+    }
+    let line = p.current_line().trim_end();
+    let is_element = line.strip_prefix('<').is_some_and(|rest| {
+        rest.starts_with(|char: char| char.is_ascii_alphabetic() || matches!(char, '>' | '/'))
+    });
+    if is_element {
+        // The tag either ends the line, or continues on the next lines
+        return line.ends_with(['>', '}']) || !line.contains('>');
+    }
+    line.starts_with('{') && line.ends_with('}')
+}
+
+/// Parses the ESM statements of an MDX document, until a blank line. Their lines are kept as
+/// written.
+pub(crate) fn parse_mdx_esm_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    parse_mdx_lines(p, 0, MD_MDX_ESM_BLOCK)
+}
+
+/// Parses a JSX element or an expression of an MDX document, until a blank line. Their lines
+/// are kept as written.
+pub(crate) fn parse_mdx_flow_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    parse_mdx_lines(p, indent, MD_MDX_FLOW_BLOCK)
+}
+
+fn parse_mdx_lines(
+    p: &mut MarkdownParser,
+    indent: usize,
+    kind: MarkdownSyntaxKind,
+) -> ParsedSyntax {
+    let m = p.start();
+    let content = p.start();
+
+    p.re_lex(MarkdownReLexContext::Line);
+    loop {
+        let line = p.start();
+        p.bump_line();
+        line.complete(p, MD_LINE);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+        if p.column() < indent || p.has_preceding_blank_line() {
+            p.re_lex(MarkdownReLexContext::Regular);
+            break;
+        }
+    }
+    content.complete(p, MD_LINE_LIST);
+
+    Present(m.complete(p, kind))
+}
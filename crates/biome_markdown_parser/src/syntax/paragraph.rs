@@ -0,0 +1,38 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::{parse_inline_item_list, InlineContext};
+use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Parses a paragraph, or a setext header when the paragraph is underlined with `=` or `-`.
+///
+/// https://spec.commonmark.org/0.31.2/#paragraphs
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    let m = p.start();
+
+    let start = p.cur_range().start();
+    parse_inline_item_list(p, InlineContext::Paragraph { indent, start });
+
+    if !p.has_preceding_blank_line() && at_setext_underline(p, indent) {
+        p.bump_remap(MD_SETEXT_UNDERLINE_LITERAL);
+        return Present(m.complete(p, MD_SETEXT_HEADER));
+    }
+
+    Present(m.complete(p, MD_PARAGRAPH))
+}
+
+/// Returns `true` if the current line is the underline of a setext header: a line of `=` or
+/// a line of `-`.
+///
+/// https://spec.commonmark.org/0.31.2/#setext-headings
+pub(crate) fn at_setext_underline(p: &MarkdownParser, indent: usize) -> bool {
+    let text = p.cur_text();
+    p.has_preceding_line_break()
+        && p.is_at_line_start()
+        && (indent..indent + 4).contains(&p.column())
+        && (!text.is_empty() && text.bytes().all(|byte| byte == b'=')
+            || !text.is_empty() && text.bytes().all(|byte| byte == b'-'))
+        && p.is_at_line_end()
+}
@@ -0,0 +1,49 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::at_block_interrupt;
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+
+/// Parses a block quote. Its lines are kept as written.
+///
+/// The quote continues with the lines that start with `>`, and with the lines of text that
+/// follow it, until a blank line or another block.
+///
+/// https://spec.commonmark.org/0.31.2/#block-quotes
+pub(crate) fn parse_quote(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !p.at(T![>]) {
+        return Absent;
+    }
+    let m = p.start();
+    let content = p.start();
+
+    p.re_lex(MarkdownReLexContext::Line);
+    loop {
+        let line = p.start();
+        p.bump_line();
+        line.complete(p, MD_LINE);
+
+        if p.at(T![EOF]) {
+            break;
+        }
+        if p.has_preceding_blank_line() || p.column() < indent {
+            p.re_lex(MarkdownReLexContext::Regular);
+            break;
+        }
+        if p.cur_text().starts_with('>') {
+            continue;
+        }
+        // A lazy continuation line of the quoted paragraph
+        p.re_lex(MarkdownReLexContext::Regular);
+        if at_block_interrupt(p, indent) {
+            break;
+        }
+        p.re_lex(MarkdownReLexContext::Line);
+    }
+    content.complete(p, MD_LINE_LIST);
+
+    Present(m.complete(p, MD_QUOTE))
+}
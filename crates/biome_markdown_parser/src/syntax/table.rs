@@ -0,0 +1,114 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::at_block_interrupt;
+use crate::syntax::inline::{parse_inline_item_list, InlineContext};
+use biome_markdown_syntax::{MarkdownSyntaxKind::*, T};
+use biome_parser::{
+    prelude::ParsedSyntax::{self, *},
+    Parser,
+};
+use biome_rowan::TextSize;
+
+/// Returns `true` if the current line is the header row of a table: a row with pipes,
+/// followed by a delimiter row with the same number of cells.
+///
+/// https://github.github.com/gfm/#tables-extension-
+pub(crate) fn at_table(p: &MarkdownParser) -> bool {
+    if !p.is_at_line_start() {
+        return false;
+    }
+    let header = p.current_line();
+    let Some(delimiter) = p.next_line() else {
+        return false;
+    };
+    let header_cells = split_cells(header);
+    let delimiter_cells = split_cells(delimiter);
+    header.contains('|')
+        && header_cells.len() == delimiter_cells.len()
+        && delimiter_cells.iter().all(|cell| is_delimiter_cell(cell))
+}
+
+/// Splits a row into its cells, without the pipes at the start and the end of the row.
+fn split_cells(row: &str) -> Vec<&str> {
+    let row = row.trim_matches([' ', '\t']);
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = match row.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => row,
+    };
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, char) in row.char_indices() {
+        match char {
+            '\\' => escaped = !escaped,
+            '|' if !escaped => {
+                cells.push(&row[start..index]);
+                start = index + 1;
+            }
+            _ => escaped = false,
+        }
+    }
+    cells.push(&row[start..]);
+    cells
+}
+
+/// Returns `true` if `cell` is a cell of the delimiter row, such as `---`, `:--`, `--:` or `:-:`.
+fn is_delimiter_cell(cell: &str) -> bool {
+    let cell = cell.trim_matches([' ', '\t']);
+    let cell = cell.strip_prefix(':').unwrap_or(cell);
+    let cell = cell.strip_suffix(':').unwrap_or(cell);
+    !cell.is_empty() && cell.bytes().all(|byte| byte == b'-')
+}
+
+/// Parses a table: a header row, a delimiter row, and the rows until a blank line or
+/// another block.
+pub(crate) fn parse_table(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_table(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    parse_table_row(p);
+    parse_table_row(p);
+
+    let rows = p.start();
+    while !p.at(T![EOF])
+        && !p.has_preceding_blank_line()
+        && p.column() >= indent
+        && !at_block_interrupt(p, indent)
+    {
+        parse_table_row(p);
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    Present(m.complete(p, MD_TABLE))
+}
+
+fn parse_table_row(p: &mut MarkdownParser) {
+    let m = p.start();
+    let cells = p.start();
+
+    let start = p.cur_range().start();
+    while !at_closing_pipe(p, start) {
+        let cell = p.start();
+        p.eat(T![|]);
+        parse_inline_item_list(p, InlineContext::TableCell { start });
+        cell.complete(p, MD_TABLE_CELL);
+        if !p.at(T![|]) || p.has_preceding_line_break() {
+            break;
+        }
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+    if at_closing_pipe(p, start) {
+        p.bump(T![|]);
+    }
+
+    m.complete(p, MD_TABLE_ROW);
+}
+
+/// Returns `true` if the current token is the pipe that ends the row that starts at `start`.
+fn at_closing_pipe(p: &MarkdownParser, start: TextSize) -> bool {
+    p.at(T![|])
+        && p.is_at_line_end()
+        && (!p.has_preceding_line_break() || p.cur_range().start() == start)
+}
@@ -14,7 +14,6 @@ pub(crate) struct MarkdownTokenSource<'source> {
     pub(super) trivia_list: Vec<Trivia>,
 }
 
-#[allow(dead_code)]
 pub(crate) type MarkdownTokenSourceCheckpoint = TokenSourceCheckpoint<MarkdownSyntaxKind>;
 
 impl<'source> MarkdownTokenSource<'source> {
@@ -63,36 +62,11 @@ impl<'source> MarkdownTokenSource<'source> {
         }
     }
 
-    /// Returns the number of whitespace characters before the current token until the first new line.
-    /// tab will be counted as 4 spaces https://spec.commonmark.org/0.31.2/#tabs
-    /// whitespace will be counted as 1 space
-    pub fn before_whitespace_count(&self) -> usize {
-        let last_trivia: Vec<&Trivia> = self
-            .trivia_list
-            .iter()
-            .rev()
-            .take_while(|item| {
-                // get before whitespace and tab collect
-                matches!(
-                    item.kind(),
-                    TriviaPieceKind::Whitespace | TriviaPieceKind::Skipped
-                )
-            })
-            .collect();
-        last_trivia.iter().fold(0, |count, b| match b.kind() {
-            TriviaPieceKind::Skipped => count + 4,
-            TriviaPieceKind::Whitespace => count + u32::from(b.len()) as usize,
-            _ => count,
-        })
-    }
-
-    #[allow(dead_code)]
     pub fn re_lex(&mut self, mode: MarkdownReLexContext) -> MarkdownSyntaxKind {
         self.lexer.re_lex(mode)
     }
 
     /// Creates a checkpoint to which it can later return using [Self::rewind].
-    #[allow(dead_code)]
     pub fn checkpoint(&self) -> MarkdownTokenSourceCheckpoint {
         MarkdownTokenSourceCheckpoint {
             trivia_len: self.trivia_list.len() as u32,
@@ -101,7 +75,6 @@ impl<'source> MarkdownTokenSource<'source> {
     }
 
     /// Restores the token source to a previous state
-    #[allow(dead_code)]
     pub fn rewind(&mut self, checkpoint: MarkdownTokenSourceCheckpoint) {
         assert!(self.trivia_list.len() >= checkpoint.trivia_len as usize);
         self.trivia_list.truncate(checkpoint.trivia_len as usize);
@@ -3,7 +3,7 @@ use biome_console::markup;
 use biome_diagnostics::display::PrintDiagnostic;
 use biome_diagnostics::termcolor;
 use biome_diagnostics::DiagnosticExt;
-use biome_markdown_parser::parse_markdown;
+use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
 use biome_rowan::SyntaxKind;
 use biome_test_utils::has_bogus_nodes_or_empty_slots;
 use std::fmt::Write;
@@ -36,7 +36,15 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parsed = parse_markdown(&content);
+    let options = if test_case_path
+        .extension()
+        .is_some_and(|extension| extension == "mdx")
+    {
+        MarkdownParserOptions::default().allow_mdx()
+    } else {
+        MarkdownParserOptions::default()
+    };
+    let parsed = parse_markdown(&content, options);
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
@@ -133,7 +141,7 @@ pub fn quick_test() {
 your test code
 "#;
 
-    let root = parse_markdown(code);
+    let root = parse_markdown(code, MarkdownParserOptions::default());
     let syntax = root.syntax();
     dbg!(&syntax, root.diagnostics(), root.has_errors());
     if has_bogus_nodes_or_empty_slots(&syntax) {
@@ -0,0 +1,80 @@
+use biome_rowan::FileSourceError;
+use biome_string_case::StrLikeExtension;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct MarkdownFileSource {
+    variant: MarkdownVariant,
+}
+
+/// The flavor of Markdown contained in the file.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+enum MarkdownVariant {
+    #[default]
+    Standard,
+    /// [MDX](https://mdxjs.com/docs/what-is-mdx/), which embeds ESM statements and JSX in the
+    /// document
+    Mdx,
+}
+
+impl MarkdownFileSource {
+    pub fn markdown() -> Self {
+        Self {
+            variant: MarkdownVariant::Standard,
+        }
+    }
+
+    pub fn mdx() -> Self {
+        Self {
+            variant: MarkdownVariant::Mdx,
+        }
+    }
+
+    pub const fn is_mdx(&self) -> bool {
+        matches!(self.variant, MarkdownVariant::Mdx)
+    }
+
+    /// Try to return the Markdown file source corresponding to this file extension
+    pub fn try_from_extension(extension: &OsStr) -> Result<Self, FileSourceError> {
+        // We assume the file extension is normalized to lowercase
+        match extension.as_encoded_bytes() {
+            // https://github.com/github-linguist/linguist/blob/main/lib/linguist/languages.yml
+            b"md" | b"markdown" => Ok(Self::markdown()),
+            b"mdx" => Ok(Self::mdx()),
+            _ => Err(FileSourceError::UnknownExtension),
+        }
+    }
+
+    /// Try to return the Markdown file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "markdown" => Ok(Self::markdown()),
+            "mdx" => Ok(Self::mdx()),
+            _ => Err(FileSourceError::UnknownLanguageId),
+        }
+    }
+}
+
+impl TryFrom<&Path> for MarkdownFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let Some(extension) = path.extension() else {
+            return Err(FileSourceError::MissingFileExtension);
+        };
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        Self::try_from_extension(&extension.to_ascii_lowercase_cow())
+    }
+}
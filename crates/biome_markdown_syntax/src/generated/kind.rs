@@ -15,61 +15,55 @@ pub enum MarkdownSyntaxKind {
     UNICODE_BOM,
     L_ANGLE,
     R_ANGLE,
-    L_PAREN,
-    R_PAREN,
-    L_BRACK,
-    R_BRACK,
-    SLASH,
-    EQ,
-    BANG,
-    MINUS,
+    PIPE,
     STAR,
-    BACKTICK,
-    TILDE,
-    WHITESPACE3,
+    DOUBLE_STAR,
     UNDERSCORE,
-    HASH,
+    DOUBLE_UNDERSCORE,
     NULL_KW,
     MD_HARD_LINE_LITERAL,
-    MD_SOFT_BREAK_LITERAL,
     MD_TEXTUAL_LITERAL,
-    MD_STRING_LITERAL,
-    MD_INDENT_CHUNK_LITERAL,
+    MD_INLINE_CODE_LITERAL,
+    MD_HASH_SEQUENCE_LITERAL,
+    MD_SETEXT_UNDERLINE_LITERAL,
     MD_THEMATIC_BREAK_LITERAL,
-    MD_ERROR_LITERAL,
+    MD_FENCE_LITERAL,
+    MD_LINE_LITERAL,
+    MD_LIST_MARKER_LITERAL,
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
-    TAB,
     BOGUS,
     MD_BOGUS,
     MD_DOCUMENT,
     MD_BLOCK_LIST,
-    MD_HASH_LIST,
-    MD_HASH,
+    MD_THEMATIC_BREAK_BLOCK,
     MD_HEADER,
+    MD_SETEXT_HEADER,
     MD_INDENT_CODE_BLOCK,
     MD_FENCED_CODE_BLOCK,
     MD_HTML_BLOCK,
-    MD_LINK_BLOCK,
-    MD_QUOTE,
-    MD_ORDER_LIST_ITEM,
-    MD_BULLET_LIST_ITEM,
-    MD_BULLET_LIST,
-    MD_ORDER_LIST,
+    MD_MDX_ESM_BLOCK,
+    MD_MDX_FLOW_BLOCK,
+    MD_TABLE,
+    MD_TABLE_ROW_LIST,
+    MD_TABLE_ROW,
+    MD_TABLE_CELL_LIST,
+    MD_TABLE_CELL,
     MD_PARAGRAPH,
-    MD_PARAGRAPH_ITEM_LIST,
+    MD_LINE,
+    MD_LINE_LIST,
+    MD_QUOTE,
+    MD_BULLET_LIST_BLOCK,
+    MD_ORDERED_LIST_BLOCK,
+    MD_LIST_ITEM_LIST,
+    MD_LIST_ITEM,
+    MD_INLINE_ITEM_LIST,
     MD_INLINE_CODE,
     MD_INLINE_EMPHASIS,
-    MD_INLINE_LINK,
-    MD_INLINE_IMAGE,
+    MD_INLINE_STRONG,
     MD_HARD_LINE,
-    MD_SOFT_BREAK,
     MD_TEXTUAL,
-    MD_SETEXT_HEADER,
-    MD_STRING,
-    MD_INDENT,
-    MD_THEMATIC_BREAK_BLOCK,
     #[doc(hidden)]
     __LAST,
 }
@@ -77,30 +71,28 @@ use self::MarkdownSyntaxKind::*;
 impl MarkdownSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            L_ANGLE | R_ANGLE | L_PAREN | R_PAREN | L_BRACK | R_BRACK | SLASH | EQ | BANG
-            | MINUS | STAR | BACKTICK | TILDE | WHITESPACE3 | UNDERSCORE | HASH => true,
+            L_ANGLE | R_ANGLE | PIPE | STAR | DOUBLE_STAR | UNDERSCORE | DOUBLE_UNDERSCORE => true,
             _ => false,
         }
     }
     pub const fn is_literal(self) -> bool {
         match self {
             MD_HARD_LINE_LITERAL
-            | MD_SOFT_BREAK_LITERAL
             | MD_TEXTUAL_LITERAL
-            | MD_STRING_LITERAL
-            | MD_INDENT_CHUNK_LITERAL
+            | MD_INLINE_CODE_LITERAL
+            | MD_HASH_SEQUENCE_LITERAL
+            | MD_SETEXT_UNDERLINE_LITERAL
             | MD_THEMATIC_BREAK_LITERAL
-            | MD_ERROR_LITERAL => true,
+            | MD_FENCE_LITERAL
+            | MD_LINE_LITERAL
+            | MD_LIST_MARKER_LITERAL => true,
             _ => false,
         }
     }
     pub const fn is_list(self) -> bool {
         match self {
-            MD_BLOCK_LIST
-            | MD_HASH_LIST
-            | MD_BULLET_LIST
-            | MD_ORDER_LIST
-            | MD_PARAGRAPH_ITEM_LIST => true,
+            MD_BLOCK_LIST | MD_TABLE_ROW_LIST | MD_TABLE_CELL_LIST | MD_LINE_LIST
+            | MD_LIST_ITEM_LIST | MD_INLINE_ITEM_LIST => true,
             _ => false,
         }
     }
@@ -115,20 +107,11 @@ impl MarkdownSyntaxKind {
         let tok = match self {
             L_ANGLE => "<",
             R_ANGLE => ">",
-            L_PAREN => "(",
-            R_PAREN => ")",
-            L_BRACK => "[",
-            R_BRACK => "]",
-            SLASH => "/",
-            EQ => "=",
-            BANG => "!",
-            MINUS => "-",
+            PIPE => "|",
             STAR => "*",
-            BACKTICK => "`",
-            TILDE => "~",
-            WHITESPACE3 => "   ",
+            DOUBLE_STAR => "**",
             UNDERSCORE => "_",
-            HASH => "#",
+            DOUBLE_UNDERSCORE => "__",
             NULL_KW => "null",
             _ => return None,
         };
@@ -137,4 +120,4 @@ impl MarkdownSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; [   ] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; [_] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; [|] => { $ crate :: MarkdownSyntaxKind :: PIPE } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; [_] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; [__] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
@@ -16,8 +16,8 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::MarkdownSyntaxNode::kind(&node) {
-                $crate::MarkdownSyntaxKind::MD_BULLET_LIST_ITEM => {
-                    let $pattern = unsafe { $crate::MdBulletListItem::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_BULLET_LIST_BLOCK => {
+                    let $pattern = unsafe { $crate::MdBulletListBlock::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_DOCUMENT => {
@@ -32,10 +32,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHardLine::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_HASH => {
-                    let $pattern = unsafe { $crate::MdHash::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_HEADER => {
                     let $pattern = unsafe { $crate::MdHeader::new_unchecked(node) };
                     $body
@@ -44,10 +40,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHtmlBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENT => {
-                    let $pattern = unsafe { $crate::MdIndent::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK => {
                     let $pattern = unsafe { $crate::MdIndentCodeBlock::new_unchecked(node) };
                     $body
@@ -60,20 +52,28 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineEmphasis::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE => {
-                    let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_STRONG => {
+                    let $pattern = unsafe { $crate::MdInlineStrong::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_LINE => {
+                    let $pattern = unsafe { $crate::MdLine::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_LIST_ITEM => {
+                    let $pattern = unsafe { $crate::MdListItem::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_LINK => {
-                    let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_MDX_ESM_BLOCK => {
+                    let $pattern = unsafe { $crate::MdMdxEsmBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_LINK_BLOCK => {
-                    let $pattern = unsafe { $crate::MdLinkBlock::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_MDX_FLOW_BLOCK => {
+                    let $pattern = unsafe { $crate::MdMdxFlowBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_ORDER_LIST_ITEM => {
-                    let $pattern = unsafe { $crate::MdOrderListItem::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_ORDERED_LIST_BLOCK => {
+                    let $pattern = unsafe { $crate::MdOrderedListBlock::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_PARAGRAPH => {
@@ -88,8 +88,16 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdSetextHeader::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_SOFT_BREAK => {
-                    let $pattern = unsafe { $crate::MdSoftBreak::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE => {
+                    let $pattern = unsafe { $crate::MdTable::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL => {
+                    let $pattern = unsafe { $crate::MdTableCell::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW => {
+                    let $pattern = unsafe { $crate::MdTableRow::new_unchecked(node) };
                     $body
                 }
                 $crate::MarkdownSyntaxKind::MD_TEXTUAL => {
@@ -108,20 +116,24 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdBlockList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_BULLET_LIST => {
-                    let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_INLINE_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MdInlineItemList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_LINE_LIST => {
+                    let $pattern = unsafe { $crate::MdLineList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_HASH_LIST => {
-                    let $pattern = unsafe { $crate::MdHashList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_LIST_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MdListItemList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_ORDER_LIST => {
-                    let $pattern = unsafe { $crate::MdOrderList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL_LIST => {
+                    let $pattern = unsafe { $crate::MdTableCellList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_PARAGRAPH_ITEM_LIST => {
-                    let $pattern = unsafe { $crate::MdParagraphItemList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW_LIST => {
+                    let $pattern = unsafe { $crate::MdTableRowList::new_unchecked(node) };
                     $body
                 }
                 _ => unreachable!(),
@@ -24,10 +24,10 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdBulletListItem {
+pub struct MdBulletListBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdBulletListItem {
+impl MdBulletListBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -37,16 +37,16 @@ impl MdBulletListItem {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdBulletListItemFields {
-        MdBulletListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+    pub fn as_fields(&self) -> MdBulletListBlockFields {
+        MdBulletListBlockFields {
+            items: self.items(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
+    pub fn items(&self) -> MdListItemList {
         support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdBulletListItem {
+impl Serialize for MdBulletListBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -55,8 +55,8 @@ impl Serialize for MdBulletListItem {
     }
 }
 #[derive(Serialize)]
-pub struct MdBulletListItemFields {
-    pub md_bullet_list: MdBulletList,
+pub struct MdBulletListBlockFields {
+    pub items: MdListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdDocument {
@@ -119,11 +119,23 @@ impl MdFencedCodeBlock {
     }
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
-            md_textual: self.md_textual(),
+            l_fence_token: self.l_fence_token(),
+            info_token: self.info_token(),
+            content: self.content(),
+            r_fence_token: self.r_fence_token(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn info_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn content(&self) -> MdLineList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -136,7 +148,10 @@ impl Serialize for MdFencedCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub info_token: Option<SyntaxToken>,
+    pub content: MdLineList,
+    pub r_fence_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -174,10 +189,10 @@ pub struct MdHardLineFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdHash {
+pub struct MdHeader {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdHash {
+impl MdHeader {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -187,16 +202,24 @@ impl MdHash {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdHashFields {
-        MdHashFields {
-            hash_token: self.hash_token(),
+    pub fn as_fields(&self) -> MdHeaderFields {
+        MdHeaderFields {
+            before_token: self.before_token(),
+            content: self.content(),
+            after_token: self.after_token(),
         }
     }
-    pub fn hash_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn before_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn after_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 2usize)
+    }
 }
-impl Serialize for MdHash {
+impl Serialize for MdHeader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -205,14 +228,16 @@ impl Serialize for MdHash {
     }
 }
 #[derive(Serialize)]
-pub struct MdHashFields {
-    pub hash_token: SyntaxResult<SyntaxToken>,
+pub struct MdHeaderFields {
+    pub before_token: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub after_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdHeader {
+pub struct MdHtmlBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdHeader {
+impl MdHtmlBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -222,24 +247,16 @@ impl MdHeader {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdHeaderFields {
-        MdHeaderFields {
-            before: self.before(),
-            md_paragraph: self.md_paragraph(),
-            after: self.after(),
+    pub fn as_fields(&self) -> MdHtmlBlockFields {
+        MdHtmlBlockFields {
+            content: self.content(),
         }
     }
-    pub fn before(&self) -> MdHashList {
+    pub fn content(&self) -> MdLineList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn md_paragraph(&self) -> Option<MdParagraph> {
-        support::node(&self.syntax, 1usize)
-    }
-    pub fn after(&self) -> MdHashList {
-        support::list(&self.syntax, 2usize)
-    }
 }
-impl Serialize for MdHeader {
+impl Serialize for MdHtmlBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -248,16 +265,14 @@ impl Serialize for MdHeader {
     }
 }
 #[derive(Serialize)]
-pub struct MdHeaderFields {
-    pub before: MdHashList,
-    pub md_paragraph: Option<MdParagraph>,
-    pub after: MdHashList,
+pub struct MdHtmlBlockFields {
+    pub content: MdLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdHtmlBlock {
+pub struct MdIndentCodeBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdHtmlBlock {
+impl MdIndentCodeBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -267,16 +282,16 @@ impl MdHtmlBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdHtmlBlockFields {
-        MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+    pub fn as_fields(&self) -> MdIndentCodeBlockFields {
+        MdIndentCodeBlockFields {
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdHtmlBlock {
+impl Serialize for MdIndentCodeBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -285,14 +300,14 @@ impl Serialize for MdHtmlBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+pub struct MdIndentCodeBlockFields {
+    pub content: MdLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
+pub struct MdInlineCode {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdIndent {
+impl MdInlineCode {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -302,8 +317,8 @@ impl MdIndent {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
+    pub fn as_fields(&self) -> MdInlineCodeFields {
+        MdInlineCodeFields {
             value_token: self.value_token(),
         }
     }
@@ -311,7 +326,7 @@ impl MdIndent {
         support::required_token(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdIndent {
+impl Serialize for MdInlineCode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -320,14 +335,14 @@ impl Serialize for MdIndent {
     }
 }
 #[derive(Serialize)]
-pub struct MdIndentFields {
+pub struct MdInlineCodeFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndentCodeBlock {
+pub struct MdInlineEmphasis {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdIndentCodeBlock {
+impl MdInlineEmphasis {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -337,16 +352,24 @@ impl MdIndentCodeBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdIndentCodeBlockFields {
-        MdIndentCodeBlockFields {
-            md_textual: self.md_textual(),
+    pub fn as_fields(&self) -> MdInlineEmphasisFields {
+        MdInlineEmphasisFields {
+            l_fence: self.l_fence(),
+            content: self.content(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdIndentCodeBlock {
+impl Serialize for MdInlineEmphasis {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -355,14 +378,16 @@ impl Serialize for MdIndentCodeBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdIndentCodeBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+pub struct MdInlineEmphasisFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineCode {
+pub struct MdInlineStrong {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineCode {
+impl MdInlineStrong {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -372,16 +397,24 @@ impl MdInlineCode {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineCodeFields {
-        MdInlineCodeFields {
-            md_textual: self.md_textual(),
+    pub fn as_fields(&self) -> MdInlineStrongFields {
+        MdInlineStrongFields {
+            l_fence: self.l_fence(),
+            content: self.content(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineCode {
+impl Serialize for MdInlineStrong {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -390,14 +423,16 @@ impl Serialize for MdInlineCode {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineCodeFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+pub struct MdInlineStrongFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineEmphasis {
+pub struct MdLine {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineEmphasis {
+impl MdLine {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -407,16 +442,16 @@ impl MdInlineEmphasis {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineEmphasisFields {
-        MdInlineEmphasisFields {
-            md_textual: self.md_textual(),
+    pub fn as_fields(&self) -> MdLineFields {
+        MdLineFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdInlineEmphasis {
+impl Serialize for MdLine {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -425,14 +460,14 @@ impl Serialize for MdInlineEmphasis {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineEmphasisFields {
-    pub md_textual: SyntaxResult<MdTextual>,
+pub struct MdLineFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImage {
+pub struct MdListItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImage {
+impl MdListItem {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -442,24 +477,20 @@ impl MdInlineImage {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageFields {
-        MdInlineImageFields {
-            alt: self.alt(),
-            src: self.src(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdListItemFields {
+        MdListItemFields {
+            marker_token: self.marker_token(),
+            content: self.content(),
         }
     }
-    pub fn alt(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn src(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
-impl Serialize for MdInlineImage {
+impl Serialize for MdListItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -468,16 +499,15 @@ impl Serialize for MdInlineImage {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageFields {
-    pub alt: SyntaxResult<MdTextual>,
-    pub src: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+pub struct MdListItemFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineLink {
+pub struct MdMdxEsmBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineLink {
+impl MdMdxEsmBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -487,24 +517,16 @@ impl MdInlineLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineLinkFields {
-        MdInlineLinkFields {
-            label: self.label(),
-            url: self.url(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdMdxEsmBlockFields {
+        MdMdxEsmBlockFields {
+            content: self.content(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn content(&self) -> MdLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdInlineLink {
+impl Serialize for MdMdxEsmBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -513,16 +535,14 @@ impl Serialize for MdInlineLink {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineLinkFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+pub struct MdMdxEsmBlockFields {
+    pub content: MdLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdLinkBlock {
+pub struct MdMdxFlowBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdLinkBlock {
+impl MdMdxFlowBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -532,24 +552,16 @@ impl MdLinkBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdLinkBlockFields {
-        MdLinkBlockFields {
-            label: self.label(),
-            url: self.url(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdMdxFlowBlockFields {
+        MdMdxFlowBlockFields {
+            content: self.content(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn content(&self) -> MdLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdLinkBlock {
+impl Serialize for MdMdxFlowBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -558,16 +570,14 @@ impl Serialize for MdLinkBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdLinkBlockFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+pub struct MdMdxFlowBlockFields {
+    pub content: MdLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdOrderListItem {
+pub struct MdOrderedListBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdOrderListItem {
+impl MdOrderedListBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -577,16 +587,16 @@ impl MdOrderListItem {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdOrderListItemFields {
-        MdOrderListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+    pub fn as_fields(&self) -> MdOrderedListBlockFields {
+        MdOrderedListBlockFields {
+            items: self.items(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
+    pub fn items(&self) -> MdListItemList {
         support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdOrderListItem {
+impl Serialize for MdOrderedListBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -595,8 +605,8 @@ impl Serialize for MdOrderListItem {
     }
 }
 #[derive(Serialize)]
-pub struct MdOrderListItemFields {
-    pub md_bullet_list: MdBulletList,
+pub struct MdOrderedListBlockFields {
+    pub items: MdListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdParagraph {
@@ -614,10 +624,10 @@ impl MdParagraph {
     }
     pub fn as_fields(&self) -> MdParagraphFields {
         MdParagraphFields {
-            md_paragraph_item_list: self.md_paragraph_item_list(),
+            content: self.content(),
         }
     }
-    pub fn md_paragraph_item_list(&self) -> MdParagraphItemList {
+    pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -631,7 +641,7 @@ impl Serialize for MdParagraph {
 }
 #[derive(Serialize)]
 pub struct MdParagraphFields {
-    pub md_paragraph_item_list: MdParagraphItemList,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -649,11 +659,11 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            content: self.content(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdQuote {
@@ -666,7 +676,7 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub content: MdLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdSetextHeader {
@@ -684,11 +694,15 @@ impl MdSetextHeader {
     }
     pub fn as_fields(&self) -> MdSetextHeaderFields {
         MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+            content: self.content(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdSetextHeader {
@@ -701,13 +715,14 @@ impl Serialize for MdSetextHeader {
 }
 #[derive(Serialize)]
 pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+    pub content: MdInlineItemList,
+    pub underline_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSoftBreak {
+pub struct MdTable {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSoftBreak {
+impl MdTable {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -717,16 +732,24 @@ impl MdSoftBreak {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSoftBreakFields {
-        MdSoftBreakFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdSoftBreak {
+impl Serialize for MdTable {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -735,8 +758,90 @@ impl Serialize for MdSoftBreak {
     }
 }
 #[derive(Serialize)]
-pub struct MdSoftBreakFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            l_pipe_token: self.l_pipe_token(),
+            content: self.content(),
+        }
+    }
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub content: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            cells: self.cells(),
+            r_pipe_token: self.r_pipe_token(),
+        }
+    }
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn r_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub cells: MdTableCellList,
+    pub r_pipe_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdTextual {
@@ -809,190 +914,183 @@ pub struct MdThematicBreakBlockFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCodeBlock {
+pub enum AnyMdBlock {
+    AnyMdContainerBlock(AnyMdContainerBlock),
+    AnyMdLeafBlock(AnyMdLeafBlock),
+}
+impl AnyMdBlock {
+    pub fn as_any_md_container_block(&self) -> Option<&AnyMdContainerBlock> {
+        match &self {
+            AnyMdBlock::AnyMdContainerBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_md_leaf_block(&self) -> Option<&AnyMdLeafBlock> {
+        match &self {
+            AnyMdBlock::AnyMdLeafBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdCodeBlock {
     MdFencedCodeBlock(MdFencedCodeBlock),
     MdIndentCodeBlock(MdIndentCodeBlock),
 }
-impl AnyCodeBlock {
+impl AnyMdCodeBlock {
     pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
         match &self {
-            AnyCodeBlock::MdFencedCodeBlock(item) => Some(item),
+            AnyMdCodeBlock::MdFencedCodeBlock(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_indent_code_block(&self) -> Option<&MdIndentCodeBlock> {
         match &self {
-            AnyCodeBlock::MdIndentCodeBlock(item) => Some(item),
+            AnyMdCodeBlock::MdIndentCodeBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyContainerBlock {
-    MdBulletListItem(MdBulletListItem),
-    MdOrderListItem(MdOrderListItem),
+pub enum AnyMdContainerBlock {
+    MdBulletListBlock(MdBulletListBlock),
+    MdOrderedListBlock(MdOrderedListBlock),
     MdQuote(MdQuote),
 }
-impl AnyContainerBlock {
-    pub fn as_md_bullet_list_item(&self) -> Option<&MdBulletListItem> {
+impl AnyMdContainerBlock {
+    pub fn as_md_bullet_list_block(&self) -> Option<&MdBulletListBlock> {
         match &self {
-            AnyContainerBlock::MdBulletListItem(item) => Some(item),
+            AnyMdContainerBlock::MdBulletListBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_order_list_item(&self) -> Option<&MdOrderListItem> {
+    pub fn as_md_ordered_list_block(&self) -> Option<&MdOrderedListBlock> {
         match &self {
-            AnyContainerBlock::MdOrderListItem(item) => Some(item),
+            AnyMdContainerBlock::MdOrderedListBlock(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_quote(&self) -> Option<&MdQuote> {
         match &self {
-            AnyContainerBlock::MdQuote(item) => Some(item),
+            AnyMdContainerBlock::MdQuote(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyLeafBlock {
-    AnyCodeBlock(AnyCodeBlock),
-    MdHeader(MdHeader),
-    MdHtmlBlock(MdHtmlBlock),
-    MdLinkBlock(MdLinkBlock),
-    MdParagraph(MdParagraph),
-    MdSetextHeader(MdSetextHeader),
-    MdThematicBreakBlock(MdThematicBreakBlock),
+pub enum AnyMdInline {
+    MdHardLine(MdHardLine),
+    MdInlineCode(MdInlineCode),
+    MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineStrong(MdInlineStrong),
+    MdTextual(MdTextual),
 }
-impl AnyLeafBlock {
-    pub fn as_any_code_block(&self) -> Option<&AnyCodeBlock> {
-        match &self {
-            AnyLeafBlock::AnyCodeBlock(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_md_header(&self) -> Option<&MdHeader> {
-        match &self {
-            AnyLeafBlock::MdHeader(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+impl AnyMdInline {
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
         match &self {
-            AnyLeafBlock::MdHtmlBlock(item) => Some(item),
+            AnyMdInline::MdHardLine(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
+    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
         match &self {
-            AnyLeafBlock::MdLinkBlock(item) => Some(item),
+            AnyMdInline::MdInlineCode(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
+    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
         match &self {
-            AnyLeafBlock::MdParagraph(item) => Some(item),
+            AnyMdInline::MdInlineEmphasis(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+    pub fn as_md_inline_strong(&self) -> Option<&MdInlineStrong> {
         match &self {
-            AnyLeafBlock::MdSetextHeader(item) => Some(item),
+            AnyMdInline::MdInlineStrong(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+    pub fn as_md_textual(&self) -> Option<&MdTextual> {
         match &self {
-            AnyLeafBlock::MdThematicBreakBlock(item) => Some(item),
+            AnyMdInline::MdTextual(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdBlock {
-    AnyContainerBlock(AnyContainerBlock),
-    AnyLeafBlock(AnyLeafBlock),
+pub enum AnyMdLeafBlock {
+    AnyMdCodeBlock(AnyMdCodeBlock),
+    MdHeader(MdHeader),
+    MdHtmlBlock(MdHtmlBlock),
+    MdMdxEsmBlock(MdMdxEsmBlock),
+    MdMdxFlowBlock(MdMdxFlowBlock),
+    MdParagraph(MdParagraph),
+    MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
+    MdThematicBreakBlock(MdThematicBreakBlock),
 }
-impl AnyMdBlock {
-    pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
-        match &self {
-            AnyMdBlock::AnyContainerBlock(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_any_leaf_block(&self) -> Option<&AnyLeafBlock> {
+impl AnyMdLeafBlock {
+    pub fn as_any_md_code_block(&self) -> Option<&AnyMdCodeBlock> {
         match &self {
-            AnyMdBlock::AnyLeafBlock(item) => Some(item),
+            AnyMdLeafBlock::AnyMdCodeBlock(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdInline {
-    MdHardLine(MdHardLine),
-    MdHtmlBlock(MdHtmlBlock),
-    MdInlineCode(MdInlineCode),
-    MdInlineEmphasis(MdInlineEmphasis),
-    MdInlineImage(MdInlineImage),
-    MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
-    MdTextual(MdTextual),
-}
-impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+    pub fn as_md_header(&self) -> Option<&MdHeader> {
         match &self {
-            AnyMdInline::MdHardLine(item) => Some(item),
+            AnyMdLeafBlock::MdHeader(item) => Some(item),
             _ => None,
         }
     }
     pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
         match &self {
-            AnyMdInline::MdHtmlBlock(item) => Some(item),
+            AnyMdLeafBlock::MdHtmlBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
+    pub fn as_md_mdx_esm_block(&self) -> Option<&MdMdxEsmBlock> {
         match &self {
-            AnyMdInline::MdInlineCode(item) => Some(item),
+            AnyMdLeafBlock::MdMdxEsmBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
+    pub fn as_md_mdx_flow_block(&self) -> Option<&MdMdxFlowBlock> {
         match &self {
-            AnyMdInline::MdInlineEmphasis(item) => Some(item),
+            AnyMdLeafBlock::MdMdxFlowBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
+    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
         match &self {
-            AnyMdInline::MdInlineImage(item) => Some(item),
+            AnyMdLeafBlock::MdParagraph(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
         match &self {
-            AnyMdInline::MdInlineLink(item) => Some(item),
+            AnyMdLeafBlock::MdSetextHeader(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
+    pub fn as_md_table(&self) -> Option<&MdTable> {
         match &self {
-            AnyMdInline::MdSoftBreak(item) => Some(item),
+            AnyMdLeafBlock::MdTable(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_textual(&self) -> Option<&MdTextual> {
+    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
         match &self {
-            AnyMdInline::MdTextual(item) => Some(item),
+            AnyMdLeafBlock::MdThematicBreakBlock(item) => Some(item),
             _ => None,
         }
     }
 }
-impl AstNode for MdBulletListItem {
+impl AstNode for MdBulletListBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET_LIST_ITEM
+        kind == MD_BULLET_LIST_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1008,20 +1106,20 @@ impl AstNode for MdBulletListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdBulletListItem {
+impl std::fmt::Debug for MdBulletListBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdBulletListItem")
-            .field("md_bullet_list", &self.md_bullet_list())
+        f.debug_struct("MdBulletListBlock")
+            .field("items", &self.items())
             .finish()
     }
 }
-impl From<MdBulletListItem> for SyntaxNode {
-    fn from(n: MdBulletListItem) -> SyntaxNode {
+impl From<MdBulletListBlock> for SyntaxNode {
+    fn from(n: MdBulletListBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdBulletListItem> for SyntaxElement {
-    fn from(n: MdBulletListItem) -> SyntaxElement {
+impl From<MdBulletListBlock> for SyntaxElement {
+    fn from(n: MdBulletListBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -1092,10 +1190,22 @@ impl AstNode for MdFencedCodeBlock {
 impl std::fmt::Debug for MdFencedCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdFencedCodeBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
-            .finish()
-    }
-}
+            .field(
+                "l_fence_token",
+                &support::DebugSyntaxResult(self.l_fence_token()),
+            )
+            .field(
+                "info_token",
+                &support::DebugOptionalElement(self.info_token()),
+            )
+            .field("content", &self.content())
+            .field(
+                "r_fence_token",
+                &support::DebugOptionalElement(self.r_fence_token()),
+            )
+            .finish()
+    }
+}
 impl From<MdFencedCodeBlock> for SyntaxNode {
     fn from(n: MdFencedCodeBlock) -> SyntaxNode {
         n.syntax
@@ -1147,12 +1257,12 @@ impl From<MdHardLine> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdHash {
+impl AstNode for MdHeader {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HEADER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH
+        kind == MD_HEADER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1168,29 +1278,37 @@ impl AstNode for MdHash {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHash {
+impl std::fmt::Debug for MdHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdHash")
-            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+        f.debug_struct("MdHeader")
+            .field(
+                "before_token",
+                &support::DebugSyntaxResult(self.before_token()),
+            )
+            .field("content", &self.content())
+            .field(
+                "after_token",
+                &support::DebugOptionalElement(self.after_token()),
+            )
             .finish()
     }
 }
-impl From<MdHash> for SyntaxNode {
-    fn from(n: MdHash) -> SyntaxNode {
+impl From<MdHeader> for SyntaxNode {
+    fn from(n: MdHeader) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdHash> for SyntaxElement {
-    fn from(n: MdHash) -> SyntaxElement {
+impl From<MdHeader> for SyntaxElement {
+    fn from(n: MdHeader) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdHeader {
+impl AstNode for MdHtmlBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HEADER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HEADER
+        kind == MD_HTML_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1206,34 +1324,29 @@ impl AstNode for MdHeader {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHeader {
+impl std::fmt::Debug for MdHtmlBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdHeader")
-            .field("before", &self.before())
-            .field(
-                "md_paragraph",
-                &support::DebugOptionalElement(self.md_paragraph()),
-            )
-            .field("after", &self.after())
+        f.debug_struct("MdHtmlBlock")
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdHeader> for SyntaxNode {
-    fn from(n: MdHeader) -> SyntaxNode {
+impl From<MdHtmlBlock> for SyntaxNode {
+    fn from(n: MdHtmlBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdHeader> for SyntaxElement {
-    fn from(n: MdHeader) -> SyntaxElement {
+impl From<MdHtmlBlock> for SyntaxElement {
+    fn from(n: MdHtmlBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdHtmlBlock {
+impl AstNode for MdIndentCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HTML_BLOCK
+        kind == MD_INDENT_CODE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1249,29 +1362,29 @@ impl AstNode for MdHtmlBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHtmlBlock {
+impl std::fmt::Debug for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdHtmlBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+        f.debug_struct("MdIndentCodeBlock")
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdHtmlBlock> for SyntaxNode {
-    fn from(n: MdHtmlBlock) -> SyntaxNode {
+impl From<MdIndentCodeBlock> for SyntaxNode {
+    fn from(n: MdIndentCodeBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdHtmlBlock> for SyntaxElement {
-    fn from(n: MdHtmlBlock) -> SyntaxElement {
+impl From<MdIndentCodeBlock> for SyntaxElement {
+    fn from(n: MdIndentCodeBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
+impl AstNode for MdInlineCode {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
+        kind == MD_INLINE_CODE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1287,9 +1400,9 @@ impl AstNode for MdIndent {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndent {
+impl std::fmt::Debug for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdIndent")
+        f.debug_struct("MdInlineCode")
             .field(
                 "value_token",
                 &support::DebugSyntaxResult(self.value_token()),
@@ -1297,22 +1410,22 @@ impl std::fmt::Debug for MdIndent {
             .finish()
     }
 }
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> SyntaxNode {
+impl From<MdInlineCode> for SyntaxNode {
+    fn from(n: MdInlineCode) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> SyntaxElement {
+impl From<MdInlineCode> for SyntaxElement {
+    fn from(n: MdInlineCode) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentCodeBlock {
+impl AstNode for MdInlineEmphasis {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT_CODE_BLOCK
+        kind == MD_INLINE_EMPHASIS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1328,29 +1441,31 @@ impl AstNode for MdIndentCodeBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndentCodeBlock {
+impl std::fmt::Debug for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdIndentCodeBlock")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+        f.debug_struct("MdInlineEmphasis")
+            .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+            .field("content", &self.content())
+            .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
             .finish()
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxNode {
-    fn from(n: MdIndentCodeBlock) -> SyntaxNode {
+impl From<MdInlineEmphasis> for SyntaxNode {
+    fn from(n: MdInlineEmphasis) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxElement {
-    fn from(n: MdIndentCodeBlock) -> SyntaxElement {
+impl From<MdInlineEmphasis> for SyntaxElement {
+    fn from(n: MdInlineEmphasis) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineCode {
+impl AstNode for MdInlineStrong {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_STRONG as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_CODE
+        kind == MD_INLINE_STRONG
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1366,29 +1481,31 @@ impl AstNode for MdInlineCode {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineCode {
+impl std::fmt::Debug for MdInlineStrong {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdInlineCode")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+        f.debug_struct("MdInlineStrong")
+            .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+            .field("content", &self.content())
+            .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
             .finish()
     }
 }
-impl From<MdInlineCode> for SyntaxNode {
-    fn from(n: MdInlineCode) -> SyntaxNode {
+impl From<MdInlineStrong> for SyntaxNode {
+    fn from(n: MdInlineStrong) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdInlineCode> for SyntaxElement {
-    fn from(n: MdInlineCode) -> SyntaxElement {
+impl From<MdInlineStrong> for SyntaxElement {
+    fn from(n: MdInlineStrong) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineEmphasis {
+impl AstNode for MdLine {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_EMPHASIS
+        kind == MD_LINE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1404,29 +1521,32 @@ impl AstNode for MdInlineEmphasis {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineEmphasis {
+impl std::fmt::Debug for MdLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdInlineEmphasis")
-            .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+        f.debug_struct("MdLine")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<MdInlineEmphasis> for SyntaxNode {
-    fn from(n: MdInlineEmphasis) -> SyntaxNode {
+impl From<MdLine> for SyntaxNode {
+    fn from(n: MdLine) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdInlineEmphasis> for SyntaxElement {
-    fn from(n: MdInlineEmphasis) -> SyntaxElement {
+impl From<MdLine> for SyntaxElement {
+    fn from(n: MdLine) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImage {
+impl AstNode for MdListItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LIST_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE
+        kind == MD_LIST_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1442,31 +1562,33 @@ impl AstNode for MdInlineImage {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImage {
+impl std::fmt::Debug for MdListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdInlineImage")
-            .field("alt", &support::DebugSyntaxResult(self.alt()))
-            .field("src", &support::DebugSyntaxResult(self.src()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+        f.debug_struct("MdListItem")
+            .field(
+                "marker_token",
+                &support::DebugSyntaxResult(self.marker_token()),
+            )
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdInlineImage> for SyntaxNode {
-    fn from(n: MdInlineImage) -> SyntaxNode {
+impl From<MdListItem> for SyntaxNode {
+    fn from(n: MdListItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdInlineImage> for SyntaxElement {
-    fn from(n: MdInlineImage) -> SyntaxElement {
+impl From<MdListItem> for SyntaxElement {
+    fn from(n: MdListItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineLink {
+impl AstNode for MdMdxEsmBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_MDX_ESM_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_LINK
+        kind == MD_MDX_ESM_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1482,31 +1604,29 @@ impl AstNode for MdInlineLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineLink {
+impl std::fmt::Debug for MdMdxEsmBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdInlineLink")
-            .field("label", &support::DebugSyntaxResult(self.label()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+        f.debug_struct("MdMdxEsmBlock")
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdInlineLink> for SyntaxNode {
-    fn from(n: MdInlineLink) -> SyntaxNode {
+impl From<MdMdxEsmBlock> for SyntaxNode {
+    fn from(n: MdMdxEsmBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdInlineLink> for SyntaxElement {
-    fn from(n: MdInlineLink) -> SyntaxElement {
+impl From<MdMdxEsmBlock> for SyntaxElement {
+    fn from(n: MdMdxEsmBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdLinkBlock {
+impl AstNode for MdMdxFlowBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_MDX_FLOW_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_LINK_BLOCK
+        kind == MD_MDX_FLOW_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1522,31 +1642,29 @@ impl AstNode for MdLinkBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdLinkBlock {
+impl std::fmt::Debug for MdMdxFlowBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdLinkBlock")
-            .field("label", &support::DebugSyntaxResult(self.label()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("title", &support::DebugOptionalElement(self.title()))
+        f.debug_struct("MdMdxFlowBlock")
+            .field("content", &self.content())
             .finish()
     }
 }
-impl From<MdLinkBlock> for SyntaxNode {
-    fn from(n: MdLinkBlock) -> SyntaxNode {
+impl From<MdMdxFlowBlock> for SyntaxNode {
+    fn from(n: MdMdxFlowBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdLinkBlock> for SyntaxElement {
-    fn from(n: MdLinkBlock) -> SyntaxElement {
+impl From<MdMdxFlowBlock> for SyntaxElement {
+    fn from(n: MdMdxFlowBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdOrderListItem {
+impl AstNode for MdOrderedListBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST_ITEM
+        kind == MD_ORDERED_LIST_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1562,20 +1680,20 @@ impl AstNode for MdOrderListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdOrderListItem {
+impl std::fmt::Debug for MdOrderedListBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdOrderListItem")
-            .field("md_bullet_list", &self.md_bullet_list())
+        f.debug_struct("MdOrderedListBlock")
+            .field("items", &self.items())
             .finish()
     }
 }
-impl From<MdOrderListItem> for SyntaxNode {
-    fn from(n: MdOrderListItem) -> SyntaxNode {
+impl From<MdOrderedListBlock> for SyntaxNode {
+    fn from(n: MdOrderedListBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdOrderListItem> for SyntaxElement {
-    fn from(n: MdOrderListItem) -> SyntaxElement {
+impl From<MdOrderedListBlock> for SyntaxElement {
+    fn from(n: MdOrderedListBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -1603,7 +1721,7 @@ impl AstNode for MdParagraph {
 impl std::fmt::Debug for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdParagraph")
-            .field("md_paragraph_item_list", &self.md_paragraph_item_list())
+            .field("content", &self.content())
             .finish()
     }
 }
@@ -1641,10 +1759,7 @@ impl AstNode for MdQuote {
 impl std::fmt::Debug for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdQuote")
-            .field(
-                "any_md_block",
-                &support::DebugSyntaxResult(self.any_md_block()),
-            )
+            .field("content", &self.content())
             .finish()
     }
 }
@@ -1682,9 +1797,10 @@ impl AstNode for MdSetextHeader {
 impl std::fmt::Debug for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MdSetextHeader")
+            .field("content", &self.content())
             .field(
-                "md_paragraph",
-                &support::DebugSyntaxResult(self.md_paragraph()),
+                "underline_token",
+                &support::DebugSyntaxResult(self.underline_token()),
             )
             .finish()
     }
@@ -1699,12 +1815,12 @@ impl From<MdSetextHeader> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdSoftBreak {
+impl AstNode for MdTable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SOFT_BREAK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SOFT_BREAK
+        kind == MD_TABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1720,23 +1836,106 @@ impl AstNode for MdSoftBreak {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSoftBreak {
+impl std::fmt::Debug for MdTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MdSoftBreak")
+        f.debug_struct("MdTable")
+            .field("header", &support::DebugSyntaxResult(self.header()))
+            .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+            .field("rows", &self.rows())
+            .finish()
+    }
+}
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableCell {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_CELL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdTableCell")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_pipe_token",
+                &support::DebugOptionalElement(self.l_pipe_token()),
+            )
+            .field("content", &self.content())
+            .finish()
+    }
+}
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableRow {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdTableRow")
+            .field("cells", &self.cells())
+            .field(
+                "r_pipe_token",
+                &support::DebugOptionalElement(self.r_pipe_token()),
             )
             .finish()
     }
 }
-impl From<MdSoftBreak> for SyntaxNode {
-    fn from(n: MdSoftBreak) -> SyntaxNode {
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<MdSoftBreak> for SyntaxElement {
-    fn from(n: MdSoftBreak) -> SyntaxElement {
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -1822,316 +2021,198 @@ impl From<MdThematicBreakBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl From<MdFencedCodeBlock> for AnyCodeBlock {
-    fn from(node: MdFencedCodeBlock) -> AnyCodeBlock {
-        AnyCodeBlock::MdFencedCodeBlock(node)
-    }
-}
-impl From<MdIndentCodeBlock> for AnyCodeBlock {
-    fn from(node: MdIndentCodeBlock) -> AnyCodeBlock {
-        AnyCodeBlock::MdIndentCodeBlock(node)
-    }
-}
-impl AstNode for AnyCodeBlock {
+impl AstNode for AnyMdBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
+        AnyMdContainerBlock::KIND_SET.union(AnyMdLeafBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_FENCED_CODE_BLOCK | MD_INDENT_CODE_BLOCK)
+        match kind {
+            k if AnyMdContainerBlock::can_cast(k) => true,
+            k if AnyMdLeafBlock::can_cast(k) => true,
+            _ => false,
+        }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_FENCED_CODE_BLOCK => AnyCodeBlock::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
-            MD_INDENT_CODE_BLOCK => AnyCodeBlock::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
-            _ => return None,
+        let syntax = match AnyMdContainerBlock::try_cast(syntax) {
+            Ok(any_md_container_block) => {
+                return Some(AnyMdBlock::AnyMdContainerBlock(any_md_container_block));
+            }
+            Err(syntax) => syntax,
         };
-        Some(res)
+        if let Some(any_md_leaf_block) = AnyMdLeafBlock::cast(syntax) {
+            return Some(AnyMdBlock::AnyMdLeafBlock(any_md_leaf_block));
+        }
+        None
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => &it.syntax,
-            AnyCodeBlock::MdIndentCodeBlock(it) => &it.syntax,
+            AnyMdBlock::AnyMdContainerBlock(it) => it.syntax(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.syntax,
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.syntax,
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into_syntax(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into_syntax(),
         }
     }
 }
-impl std::fmt::Debug for AnyCodeBlock {
+impl std::fmt::Debug for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyCodeBlock::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyCodeBlock::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdBlock::AnyMdContainerBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdBlock::AnyMdLeafBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxNode {
-    fn from(n: AnyCodeBlock) -> SyntaxNode {
+impl From<AnyMdBlock> for SyntaxNode {
+    fn from(n: AnyMdBlock) -> SyntaxNode {
         match n {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.into(),
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.into(),
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxElement {
-    fn from(n: AnyCodeBlock) -> SyntaxElement {
+impl From<AnyMdBlock> for SyntaxElement {
+    fn from(n: AnyMdBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdBulletListItem> for AnyContainerBlock {
-    fn from(node: MdBulletListItem) -> AnyContainerBlock {
-        AnyContainerBlock::MdBulletListItem(node)
-    }
-}
-impl From<MdOrderListItem> for AnyContainerBlock {
-    fn from(node: MdOrderListItem) -> AnyContainerBlock {
-        AnyContainerBlock::MdOrderListItem(node)
+impl From<MdFencedCodeBlock> for AnyMdCodeBlock {
+    fn from(node: MdFencedCodeBlock) -> AnyMdCodeBlock {
+        AnyMdCodeBlock::MdFencedCodeBlock(node)
     }
 }
-impl From<MdQuote> for AnyContainerBlock {
-    fn from(node: MdQuote) -> AnyContainerBlock {
-        AnyContainerBlock::MdQuote(node)
+impl From<MdIndentCodeBlock> for AnyMdCodeBlock {
+    fn from(node: MdIndentCodeBlock) -> AnyMdCodeBlock {
+        AnyMdCodeBlock::MdIndentCodeBlock(node)
     }
 }
-impl AstNode for AnyContainerBlock {
+impl AstNode for AnyMdCodeBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
-        .union(MdOrderListItem::KIND_SET)
-        .union(MdQuote::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> =
+        MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_BULLET_LIST_ITEM | MD_ORDER_LIST_ITEM | MD_QUOTE)
+        matches!(kind, MD_FENCED_CODE_BLOCK | MD_INDENT_CODE_BLOCK)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_BULLET_LIST_ITEM => AnyContainerBlock::MdBulletListItem(MdBulletListItem { syntax }),
-            MD_ORDER_LIST_ITEM => AnyContainerBlock::MdOrderListItem(MdOrderListItem { syntax }),
-            MD_QUOTE => AnyContainerBlock::MdQuote(MdQuote { syntax }),
+            MD_FENCED_CODE_BLOCK => AnyMdCodeBlock::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
+            MD_INDENT_CODE_BLOCK => AnyMdCodeBlock::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => &it.syntax,
-            AnyContainerBlock::MdOrderListItem(it) => &it.syntax,
-            AnyContainerBlock::MdQuote(it) => &it.syntax,
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => &it.syntax,
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => it.syntax,
-            AnyContainerBlock::MdOrderListItem(it) => it.syntax,
-            AnyContainerBlock::MdQuote(it) => it.syntax,
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.syntax,
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyContainerBlock {
+impl std::fmt::Debug for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyContainerBlock::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
-            AnyContainerBlock::MdOrderListItem(it) => std::fmt::Debug::fmt(it, f),
-            AnyContainerBlock::MdQuote(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxNode {
-    fn from(n: AnyContainerBlock) -> SyntaxNode {
+impl From<AnyMdCodeBlock> for SyntaxNode {
+    fn from(n: AnyMdCodeBlock) -> SyntaxNode {
         match n {
-            AnyContainerBlock::MdBulletListItem(it) => it.into(),
-            AnyContainerBlock::MdOrderListItem(it) => it.into(),
-            AnyContainerBlock::MdQuote(it) => it.into(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxElement {
-    fn from(n: AnyContainerBlock) -> SyntaxElement {
+impl From<AnyMdCodeBlock> for SyntaxElement {
+    fn from(n: AnyMdCodeBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdHeader> for AnyLeafBlock {
-    fn from(node: MdHeader) -> AnyLeafBlock {
-        AnyLeafBlock::MdHeader(node)
-    }
-}
-impl From<MdHtmlBlock> for AnyLeafBlock {
-    fn from(node: MdHtmlBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdHtmlBlock(node)
-    }
-}
-impl From<MdLinkBlock> for AnyLeafBlock {
-    fn from(node: MdLinkBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdLinkBlock(node)
-    }
-}
-impl From<MdParagraph> for AnyLeafBlock {
-    fn from(node: MdParagraph) -> AnyLeafBlock {
-        AnyLeafBlock::MdParagraph(node)
+impl From<MdBulletListBlock> for AnyMdContainerBlock {
+    fn from(node: MdBulletListBlock) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdBulletListBlock(node)
     }
 }
-impl From<MdSetextHeader> for AnyLeafBlock {
-    fn from(node: MdSetextHeader) -> AnyLeafBlock {
-        AnyLeafBlock::MdSetextHeader(node)
+impl From<MdOrderedListBlock> for AnyMdContainerBlock {
+    fn from(node: MdOrderedListBlock) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdOrderedListBlock(node)
     }
 }
-impl From<MdThematicBreakBlock> for AnyLeafBlock {
-    fn from(node: MdThematicBreakBlock) -> AnyLeafBlock {
-        AnyLeafBlock::MdThematicBreakBlock(node)
+impl From<MdQuote> for AnyMdContainerBlock {
+    fn from(node: MdQuote) -> AnyMdContainerBlock {
+        AnyMdContainerBlock::MdQuote(node)
     }
 }
-impl AstNode for AnyLeafBlock {
+impl AstNode for AnyMdContainerBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCodeBlock::KIND_SET
-        .union(MdHeader::KIND_SET)
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdLinkBlock::KIND_SET)
-        .union(MdParagraph::KIND_SET)
-        .union(MdSetextHeader::KIND_SET)
-        .union(MdThematicBreakBlock::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = MdBulletListBlock::KIND_SET
+        .union(MdOrderedListBlock::KIND_SET)
+        .union(MdQuote::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            MD_HEADER
-            | MD_HTML_BLOCK
-            | MD_LINK_BLOCK
-            | MD_PARAGRAPH
-            | MD_SETEXT_HEADER
-            | MD_THEMATIC_BREAK_BLOCK => true,
-            k if AnyCodeBlock::can_cast(k) => true,
-            _ => false,
-        }
+        matches!(
+            kind,
+            MD_BULLET_LIST_BLOCK | MD_ORDERED_LIST_BLOCK | MD_QUOTE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_HEADER => AnyLeafBlock::MdHeader(MdHeader { syntax }),
-            MD_HTML_BLOCK => AnyLeafBlock::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_LINK_BLOCK => AnyLeafBlock::MdLinkBlock(MdLinkBlock { syntax }),
-            MD_PARAGRAPH => AnyLeafBlock::MdParagraph(MdParagraph { syntax }),
-            MD_SETEXT_HEADER => AnyLeafBlock::MdSetextHeader(MdSetextHeader { syntax }),
-            MD_THEMATIC_BREAK_BLOCK => {
-                AnyLeafBlock::MdThematicBreakBlock(MdThematicBreakBlock { syntax })
+            MD_BULLET_LIST_BLOCK => {
+                AnyMdContainerBlock::MdBulletListBlock(MdBulletListBlock { syntax })
             }
-            _ => {
-                if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
-                    return Some(AnyLeafBlock::AnyCodeBlock(any_code_block));
-                }
-                return None;
+            MD_ORDERED_LIST_BLOCK => {
+                AnyMdContainerBlock::MdOrderedListBlock(MdOrderedListBlock { syntax })
             }
+            MD_QUOTE => AnyMdContainerBlock::MdQuote(MdQuote { syntax }),
+            _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyLeafBlock::MdHeader(it) => &it.syntax,
-            AnyLeafBlock::MdHtmlBlock(it) => &it.syntax,
-            AnyLeafBlock::MdLinkBlock(it) => &it.syntax,
-            AnyLeafBlock::MdParagraph(it) => &it.syntax,
-            AnyLeafBlock::MdSetextHeader(it) => &it.syntax,
-            AnyLeafBlock::MdThematicBreakBlock(it) => &it.syntax,
-            AnyLeafBlock::AnyCodeBlock(it) => it.syntax(),
+            AnyMdContainerBlock::MdBulletListBlock(it) => &it.syntax,
+            AnyMdContainerBlock::MdOrderedListBlock(it) => &it.syntax,
+            AnyMdContainerBlock::MdQuote(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyLeafBlock::MdHeader(it) => it.syntax,
-            AnyLeafBlock::MdHtmlBlock(it) => it.syntax,
-            AnyLeafBlock::MdLinkBlock(it) => it.syntax,
-            AnyLeafBlock::MdParagraph(it) => it.syntax,
-            AnyLeafBlock::MdSetextHeader(it) => it.syntax,
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.syntax,
-            AnyLeafBlock::AnyCodeBlock(it) => it.into_syntax(),
+            AnyMdContainerBlock::MdBulletListBlock(it) => it.syntax,
+            AnyMdContainerBlock::MdOrderedListBlock(it) => it.syntax,
+            AnyMdContainerBlock::MdQuote(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyLeafBlock {
+impl std::fmt::Debug for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyLeafBlock::AnyCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdHeader(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
-            AnyLeafBlock::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdBulletListBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdOrderedListBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdContainerBlock::MdQuote(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyLeafBlock> for SyntaxNode {
-    fn from(n: AnyLeafBlock) -> SyntaxNode {
+impl From<AnyMdContainerBlock> for SyntaxNode {
+    fn from(n: AnyMdContainerBlock) -> SyntaxNode {
         match n {
-            AnyLeafBlock::AnyCodeBlock(it) => it.into(),
-            AnyLeafBlock::MdHeader(it) => it.into(),
-            AnyLeafBlock::MdHtmlBlock(it) => it.into(),
-            AnyLeafBlock::MdLinkBlock(it) => it.into(),
-            AnyLeafBlock::MdParagraph(it) => it.into(),
-            AnyLeafBlock::MdSetextHeader(it) => it.into(),
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.into(),
+            AnyMdContainerBlock::MdBulletListBlock(it) => it.into(),
+            AnyMdContainerBlock::MdOrderedListBlock(it) => it.into(),
+            AnyMdContainerBlock::MdQuote(it) => it.into(),
         }
     }
 }
-impl From<AnyLeafBlock> for SyntaxElement {
-    fn from(n: AnyLeafBlock) -> SyntaxElement {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl AstNode for AnyMdBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            k if AnyContainerBlock::can_cast(k) => true,
-            k if AnyLeafBlock::can_cast(k) => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(AnyMdBlock::AnyContainerBlock(any_container_block));
-            }
-            Err(syntax) => syntax,
-        };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(AnyMdBlock::AnyLeafBlock(any_leaf_block));
-        }
-        None
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            AnyMdBlock::AnyContainerBlock(it) => it.syntax(),
-            AnyMdBlock::AnyLeafBlock(it) => it.syntax(),
-        }
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        match self {
-            AnyMdBlock::AnyContainerBlock(it) => it.into_syntax(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into_syntax(),
-        }
-    }
-}
-impl std::fmt::Debug for AnyMdBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AnyMdBlock::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdBlock::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
-    }
-}
-impl From<AnyMdBlock> for SyntaxNode {
-    fn from(n: AnyMdBlock) -> SyntaxNode {
-        match n {
-            AnyMdBlock::AnyContainerBlock(it) => it.into(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into(),
-        }
-    }
-}
-impl From<AnyMdBlock> for SyntaxElement {
-    fn from(n: AnyMdBlock) -> SyntaxElement {
+impl From<AnyMdContainerBlock> for SyntaxElement {
+    fn from(n: AnyMdContainerBlock) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
@@ -2141,11 +2222,6 @@ impl From<MdHardLine> for AnyMdInline {
         AnyMdInline::MdHardLine(node)
     }
 }
-impl From<MdHtmlBlock> for AnyMdInline {
-    fn from(node: MdHtmlBlock) -> AnyMdInline {
-        AnyMdInline::MdHtmlBlock(node)
-    }
-}
 impl From<MdInlineCode> for AnyMdInline {
     fn from(node: MdInlineCode) -> AnyMdInline {
         AnyMdInline::MdInlineCode(node)
@@ -2156,19 +2232,9 @@ impl From<MdInlineEmphasis> for AnyMdInline {
         AnyMdInline::MdInlineEmphasis(node)
     }
 }
-impl From<MdInlineImage> for AnyMdInline {
-    fn from(node: MdInlineImage) -> AnyMdInline {
-        AnyMdInline::MdInlineImage(node)
-    }
-}
-impl From<MdInlineLink> for AnyMdInline {
-    fn from(node: MdInlineLink) -> AnyMdInline {
-        AnyMdInline::MdInlineLink(node)
-    }
-}
-impl From<MdSoftBreak> for AnyMdInline {
-    fn from(node: MdSoftBreak) -> AnyMdInline {
-        AnyMdInline::MdSoftBreak(node)
+impl From<MdInlineStrong> for AnyMdInline {
+    fn from(node: MdInlineStrong) -> AnyMdInline {
+        AnyMdInline::MdInlineStrong(node)
     }
 }
 impl From<MdTextual> for AnyMdInline {
@@ -2179,35 +2245,22 @@ impl From<MdTextual> for AnyMdInline {
 impl AstNode for AnyMdInline {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = MdHardLine::KIND_SET
-        .union(MdHtmlBlock::KIND_SET)
         .union(MdInlineCode::KIND_SET)
         .union(MdInlineEmphasis::KIND_SET)
-        .union(MdInlineImage::KIND_SET)
-        .union(MdInlineLink::KIND_SET)
-        .union(MdSoftBreak::KIND_SET)
+        .union(MdInlineStrong::KIND_SET)
         .union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            MD_HARD_LINE
-                | MD_HTML_BLOCK
-                | MD_INLINE_CODE
-                | MD_INLINE_EMPHASIS
-                | MD_INLINE_IMAGE
-                | MD_INLINE_LINK
-                | MD_SOFT_BREAK
-                | MD_TEXTUAL
+            MD_HARD_LINE | MD_INLINE_CODE | MD_INLINE_EMPHASIS | MD_INLINE_STRONG | MD_TEXTUAL
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             MD_HARD_LINE => AnyMdInline::MdHardLine(MdHardLine { syntax }),
-            MD_HTML_BLOCK => AnyMdInline::MdHtmlBlock(MdHtmlBlock { syntax }),
             MD_INLINE_CODE => AnyMdInline::MdInlineCode(MdInlineCode { syntax }),
             MD_INLINE_EMPHASIS => AnyMdInline::MdInlineEmphasis(MdInlineEmphasis { syntax }),
-            MD_INLINE_IMAGE => AnyMdInline::MdInlineImage(MdInlineImage { syntax }),
-            MD_INLINE_LINK => AnyMdInline::MdInlineLink(MdInlineLink { syntax }),
-            MD_SOFT_BREAK => AnyMdInline::MdSoftBreak(MdSoftBreak { syntax }),
+            MD_INLINE_STRONG => AnyMdInline::MdInlineStrong(MdInlineStrong { syntax }),
             MD_TEXTUAL => AnyMdInline::MdTextual(MdTextual { syntax }),
             _ => return None,
         };
@@ -2216,24 +2269,18 @@ impl AstNode for AnyMdInline {
     fn syntax(&self) -> &SyntaxNode {
         match self {
             AnyMdInline::MdHardLine(it) => &it.syntax,
-            AnyMdInline::MdHtmlBlock(it) => &it.syntax,
             AnyMdInline::MdInlineCode(it) => &it.syntax,
             AnyMdInline::MdInlineEmphasis(it) => &it.syntax,
-            AnyMdInline::MdInlineImage(it) => &it.syntax,
-            AnyMdInline::MdInlineLink(it) => &it.syntax,
-            AnyMdInline::MdSoftBreak(it) => &it.syntax,
+            AnyMdInline::MdInlineStrong(it) => &it.syntax,
             AnyMdInline::MdTextual(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyMdInline::MdHardLine(it) => it.syntax,
-            AnyMdInline::MdHtmlBlock(it) => it.syntax,
             AnyMdInline::MdInlineCode(it) => it.syntax,
             AnyMdInline::MdInlineEmphasis(it) => it.syntax,
-            AnyMdInline::MdInlineImage(it) => it.syntax,
-            AnyMdInline::MdInlineLink(it) => it.syntax,
-            AnyMdInline::MdSoftBreak(it) => it.syntax,
+            AnyMdInline::MdInlineStrong(it) => it.syntax,
             AnyMdInline::MdTextual(it) => it.syntax,
         }
     }
@@ -2242,12 +2289,9 @@ impl std::fmt::Debug for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AnyMdInline::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            AnyMdInline::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdInline::MdInlineStrong(it) => std::fmt::Debug::fmt(it, f),
             AnyMdInline::MdTextual(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -2256,12 +2300,9 @@ impl From<AnyMdInline> for SyntaxNode {
     fn from(n: AnyMdInline) -> SyntaxNode {
         match n {
             AnyMdInline::MdHardLine(it) => it.into(),
-            AnyMdInline::MdHtmlBlock(it) => it.into(),
             AnyMdInline::MdInlineCode(it) => it.into(),
             AnyMdInline::MdInlineEmphasis(it) => it.into(),
-            AnyMdInline::MdInlineImage(it) => it.into(),
-            AnyMdInline::MdInlineLink(it) => it.into(),
-            AnyMdInline::MdSoftBreak(it) => it.into(),
+            AnyMdInline::MdInlineStrong(it) => it.into(),
             AnyMdInline::MdTextual(it) => it.into(),
         }
     }
@@ -2272,22 +2313,166 @@ impl From<AnyMdInline> for SyntaxElement {
         node.into()
     }
 }
-impl std::fmt::Display for AnyCodeBlock {
+impl From<MdHeader> for AnyMdLeafBlock {
+    fn from(node: MdHeader) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdHeader(node)
+    }
+}
+impl From<MdHtmlBlock> for AnyMdLeafBlock {
+    fn from(node: MdHtmlBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdHtmlBlock(node)
+    }
+}
+impl From<MdMdxEsmBlock> for AnyMdLeafBlock {
+    fn from(node: MdMdxEsmBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdMdxEsmBlock(node)
+    }
+}
+impl From<MdMdxFlowBlock> for AnyMdLeafBlock {
+    fn from(node: MdMdxFlowBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdMdxFlowBlock(node)
+    }
+}
+impl From<MdParagraph> for AnyMdLeafBlock {
+    fn from(node: MdParagraph) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdParagraph(node)
+    }
+}
+impl From<MdSetextHeader> for AnyMdLeafBlock {
+    fn from(node: MdSetextHeader) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdSetextHeader(node)
+    }
+}
+impl From<MdTable> for AnyMdLeafBlock {
+    fn from(node: MdTable) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdTable(node)
+    }
+}
+impl From<MdThematicBreakBlock> for AnyMdLeafBlock {
+    fn from(node: MdThematicBreakBlock) -> AnyMdLeafBlock {
+        AnyMdLeafBlock::MdThematicBreakBlock(node)
+    }
+}
+impl AstNode for AnyMdLeafBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = AnyMdCodeBlock::KIND_SET
+        .union(MdHeader::KIND_SET)
+        .union(MdHtmlBlock::KIND_SET)
+        .union(MdMdxEsmBlock::KIND_SET)
+        .union(MdMdxFlowBlock::KIND_SET)
+        .union(MdParagraph::KIND_SET)
+        .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
+        .union(MdThematicBreakBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            MD_HEADER
+            | MD_HTML_BLOCK
+            | MD_MDX_ESM_BLOCK
+            | MD_MDX_FLOW_BLOCK
+            | MD_PARAGRAPH
+            | MD_SETEXT_HEADER
+            | MD_TABLE
+            | MD_THEMATIC_BREAK_BLOCK => true,
+            k if AnyMdCodeBlock::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            MD_HEADER => AnyMdLeafBlock::MdHeader(MdHeader { syntax }),
+            MD_HTML_BLOCK => AnyMdLeafBlock::MdHtmlBlock(MdHtmlBlock { syntax }),
+            MD_MDX_ESM_BLOCK => AnyMdLeafBlock::MdMdxEsmBlock(MdMdxEsmBlock { syntax }),
+            MD_MDX_FLOW_BLOCK => AnyMdLeafBlock::MdMdxFlowBlock(MdMdxFlowBlock { syntax }),
+            MD_PARAGRAPH => AnyMdLeafBlock::MdParagraph(MdParagraph { syntax }),
+            MD_SETEXT_HEADER => AnyMdLeafBlock::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => AnyMdLeafBlock::MdTable(MdTable { syntax }),
+            MD_THEMATIC_BREAK_BLOCK => {
+                AnyMdLeafBlock::MdThematicBreakBlock(MdThematicBreakBlock { syntax })
+            }
+            _ => {
+                if let Some(any_md_code_block) = AnyMdCodeBlock::cast(syntax) {
+                    return Some(AnyMdLeafBlock::AnyMdCodeBlock(any_md_code_block));
+                }
+                return None;
+            }
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyMdLeafBlock::MdHeader(it) => &it.syntax,
+            AnyMdLeafBlock::MdHtmlBlock(it) => &it.syntax,
+            AnyMdLeafBlock::MdMdxEsmBlock(it) => &it.syntax,
+            AnyMdLeafBlock::MdMdxFlowBlock(it) => &it.syntax,
+            AnyMdLeafBlock::MdParagraph(it) => &it.syntax,
+            AnyMdLeafBlock::MdSetextHeader(it) => &it.syntax,
+            AnyMdLeafBlock::MdTable(it) => &it.syntax,
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => &it.syntax,
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.syntax(),
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyMdLeafBlock::MdHeader(it) => it.syntax,
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.syntax,
+            AnyMdLeafBlock::MdMdxEsmBlock(it) => it.syntax,
+            AnyMdLeafBlock::MdMdxFlowBlock(it) => it.syntax,
+            AnyMdLeafBlock::MdParagraph(it) => it.syntax,
+            AnyMdLeafBlock::MdSetextHeader(it) => it.syntax,
+            AnyMdLeafBlock::MdTable(it) => it.syntax,
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.syntax,
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into_syntax(),
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+        match self {
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdHeader(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdMdxEsmBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdMdxFlowBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdTable(it) => std::fmt::Debug::fmt(it, f),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdLeafBlock> for SyntaxNode {
+    fn from(n: AnyMdLeafBlock) -> SyntaxNode {
+        match n {
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdHeader(it) => it.into(),
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.into(),
+            AnyMdLeafBlock::MdMdxEsmBlock(it) => it.into(),
+            AnyMdLeafBlock::MdMdxFlowBlock(it) => it.into(),
+            AnyMdLeafBlock::MdParagraph(it) => it.into(),
+            AnyMdLeafBlock::MdSetextHeader(it) => it.into(),
+            AnyMdLeafBlock::MdTable(it) => it.into(),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.into(),
+        }
     }
 }
-impl std::fmt::Display for AnyContainerBlock {
+impl From<AnyMdLeafBlock> for SyntaxElement {
+    fn from(n: AnyMdLeafBlock) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyLeafBlock {
+impl std::fmt::Display for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdBlock {
+impl std::fmt::Display for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2297,7 +2482,12 @@ impl std::fmt::Display for AnyMdInline {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdBulletListItem {
+impl std::fmt::Display for AnyMdLeafBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdBulletListBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2317,57 +2507,57 @@ impl std::fmt::Display for MdHardLine {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHash {
+impl std::fmt::Display for MdHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHeader {
+impl std::fmt::Display for MdHtmlBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdHtmlBlock {
+impl std::fmt::Display for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
+impl std::fmt::Display for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentCodeBlock {
+impl std::fmt::Display for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineCode {
+impl std::fmt::Display for MdInlineStrong {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineEmphasis {
+impl std::fmt::Display for MdLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImage {
+impl std::fmt::Display for MdListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineLink {
+impl std::fmt::Display for MdMdxEsmBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdLinkBlock {
+impl std::fmt::Display for MdMdxFlowBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdOrderListItem {
+impl std::fmt::Display for MdOrderedListBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2387,7 +2577,17 @@ impl std::fmt::Display for MdSetextHeader {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSoftBreak {
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -2541,10 +2741,10 @@ impl IntoIterator for MdBlockList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdBulletList {
+pub struct MdInlineItemList {
     syntax_list: SyntaxList,
 }
-impl MdBulletList {
+impl MdInlineItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -2557,16 +2757,16 @@ impl MdBulletList {
         }
     }
 }
-impl AstNode for MdBulletList {
+impl AstNode for MdInlineItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET_LIST
+        kind == MD_INLINE_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<MdBulletList> {
+    fn cast(syntax: SyntaxNode) -> Option<MdInlineItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(MdBulletList {
+            Some(MdInlineItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -2580,7 +2780,7 @@ impl AstNode for MdBulletList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdBulletList {
+impl Serialize for MdInlineItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -2592,9 +2792,9 @@ impl Serialize for MdBulletList {
         seq.end()
     }
 }
-impl AstNodeList for MdBulletList {
+impl AstNodeList for MdInlineItemList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = AnyMdInline;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2602,31 +2802,31 @@ impl AstNodeList for MdBulletList {
         self.syntax_list
     }
 }
-impl Debug for MdBulletList {
+impl Debug for MdInlineItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdBulletList ")?;
+        f.write_str("MdInlineItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdBulletList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdBulletList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdHashList {
+pub struct MdLineList {
     syntax_list: SyntaxList,
 }
-impl MdHashList {
+impl MdLineList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -2639,16 +2839,16 @@ impl MdHashList {
         }
     }
 }
-impl AstNode for MdHashList {
+impl AstNode for MdLineList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH_LIST
+        kind == MD_LINE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<MdHashList> {
+    fn cast(syntax: SyntaxNode) -> Option<MdLineList> {
         if Self::can_cast(syntax.kind()) {
-            Some(MdHashList {
+            Some(MdLineList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -2662,7 +2862,7 @@ impl AstNode for MdHashList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdHashList {
+impl Serialize for MdLineList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -2674,9 +2874,9 @@ impl Serialize for MdHashList {
         seq.end()
     }
 }
-impl AstNodeList for MdHashList {
+impl AstNodeList for MdLineList {
     type Language = Language;
-    type Node = MdHash;
+    type Node = MdLine;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2684,31 +2884,31 @@ impl AstNodeList for MdHashList {
         self.syntax_list
     }
 }
-impl Debug for MdHashList {
+impl Debug for MdLineList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdHashList ")?;
+        f.write_str("MdLineList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for &MdLineList {
+    type Item = MdLine;
+    type IntoIter = AstNodeListIterator<Language, MdLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for MdLineList {
+    type Item = MdLine;
+    type IntoIter = AstNodeListIterator<Language, MdLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdOrderList {
+pub struct MdListItemList {
     syntax_list: SyntaxList,
 }
-impl MdOrderList {
+impl MdListItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -2721,16 +2921,16 @@ impl MdOrderList {
         }
     }
 }
-impl AstNode for MdOrderList {
+impl AstNode for MdListItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LIST_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST
+        kind == MD_LIST_ITEM_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<MdOrderList> {
+    fn cast(syntax: SyntaxNode) -> Option<MdListItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(MdOrderList {
+            Some(MdListItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -2744,7 +2944,7 @@ impl AstNode for MdOrderList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdOrderList {
+impl Serialize for MdListItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -2756,9 +2956,9 @@ impl Serialize for MdOrderList {
         seq.end()
     }
 }
-impl AstNodeList for MdOrderList {
+impl AstNodeList for MdListItemList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdListItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2766,31 +2966,31 @@ impl AstNodeList for MdOrderList {
         self.syntax_list
     }
 }
-impl Debug for MdOrderList {
+impl Debug for MdListItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdOrderList ")?;
+        f.write_str("MdListItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdListItemList {
+    type Item = MdListItem;
+    type IntoIter = AstNodeListIterator<Language, MdListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdListItemList {
+    type Item = MdListItem;
+    type IntoIter = AstNodeListIterator<Language, MdListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdParagraphItemList {
+pub struct MdTableCellList {
     syntax_list: SyntaxList,
 }
-impl MdParagraphItemList {
+impl MdTableCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -2803,16 +3003,16 @@ impl MdParagraphItemList {
         }
     }
 }
-impl AstNode for MdParagraphItemList {
+impl AstNode for MdTableCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_PARAGRAPH_ITEM_LIST
+        kind == MD_TABLE_CELL_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<MdParagraphItemList> {
+    fn cast(syntax: SyntaxNode) -> Option<MdTableCellList> {
         if Self::can_cast(syntax.kind()) {
-            Some(MdParagraphItemList {
+            Some(MdTableCellList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -2826,7 +3026,7 @@ impl AstNode for MdParagraphItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdParagraphItemList {
+impl Serialize for MdTableCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -2838,9 +3038,9 @@ impl Serialize for MdParagraphItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdParagraphItemList {
+impl AstNodeList for MdTableCellList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdTableCell;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -2848,22 +3048,104 @@ impl AstNodeList for MdParagraphItemList {
         self.syntax_list
     }
 }
-impl Debug for MdParagraphItemList {
+impl Debug for MdTableCellList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdParagraphItemList ")?;
+        f.write_str("MdTableCellList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdParagraphItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdParagraphItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdTableRowList {
+    syntax_list: SyntaxList,
+}
+impl MdTableRowList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdTableRowList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MdTableRowList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MdTableRowList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdTableRowList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdTableRowList {
+    type Language = Language;
+    type Node = MdTableRow;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdTableRowList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdTableRowList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
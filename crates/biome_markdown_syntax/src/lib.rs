@@ -1,9 +1,11 @@
 #[macro_use]
 mod generated;
+pub mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::{RawSyntaxKind, SyntaxKind, TriviaPieceKind};
+pub use file_source::MarkdownFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for MarkdownSyntaxKind {
@@ -35,7 +37,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_root(&self) -> bool {
-        todo!()
+        matches!(self, MarkdownSyntaxKind::MD_DOCUMENT)
     }
 
     fn is_list(&self) -> bool {
@@ -45,7 +47,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     fn is_trivia(self) -> bool {
         matches!(
             self,
-            MarkdownSyntaxKind::NEWLINE | MarkdownSyntaxKind::WHITESPACE | MarkdownSyntaxKind::TAB
+            MarkdownSyntaxKind::NEWLINE | MarkdownSyntaxKind::WHITESPACE
         )
     }
 
@@ -62,7 +64,6 @@ impl TryFrom<MarkdownSyntaxKind> for TriviaPieceKind {
             match value {
                 MarkdownSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
                 MarkdownSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
-                MarkdownSyntaxKind::TAB => Ok(TriviaPieceKind::Skipped),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
@@ -1,8 +1,8 @@
 use crate::{LanguageRoot, Manifest};
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_deserialize::{
-    Deserializable, DeserializableTypes, DeserializableValue, DeserializationDiagnostic,
-    DeserializationVisitor, Deserialized, Text,
+    Deserializable, DeserializableType, DeserializableTypes, DeserializableValue,
+    DeserializationDiagnostic, DeserializationVisitor, Deserialized, Text,
 };
 use biome_json_syntax::JsonLanguage;
 use biome_text_size::TextRange;
@@ -19,6 +19,15 @@ pub struct PackageJson {
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
     pub r#type: Option<PackageType>,
+    /// The queries of the `browserslist` field, which selects the browsers targeted by the project
+    pub browserslist: Option<Vec<String>>,
+    /// The versions of the runtimes supported by the project, such as `node` or `bun`
+    pub engines: Engines,
+    /// The package manager of the project.
+    ///
+    /// It's read from the `packageManager` field. When the field is absent, the package manager is
+    /// detected from the lockfile next to the manifest, see [PackageManager::from_lockfiles].
+    pub package_manager: Option<PackageManager>,
 }
 
 impl Manifest for PackageJson {
@@ -46,6 +55,21 @@ impl Dependencies {
     }
 }
 
+/// The `engines` field, which maps the runtimes to the range of their supported versions.
+#[derive(Debug, Default, Clone, biome_deserialize_macros::Deserializable)]
+pub struct Engines(FxHashMap<String, String>);
+
+impl Engines {
+    /// Returns the range of the supported versions of `engine`, such as `>=18` for `node`.
+    pub fn get(&self, engine: &str) -> Option<&str> {
+        self.0.get(engine).map(String::as_str)
+    }
+
+    pub fn add(&mut self, engine: impl Into<String>, range: impl Into<String>) {
+        self.0.insert(engine.into(), range.into());
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Version {
     SemVer(node_semver::Version),
@@ -136,6 +160,33 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 "type" => {
                     result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "engines" => {
+                    if let Some(engines) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.engines = engines;
+                    }
+                }
+                "packageManager" => {
+                    // The field is the name of the package manager followed by its version,
+                    // such as `pnpm@9.12.1`. The unknown package managers are ignored.
+                    result.package_manager = Text::deserialize(&value, &key_text, diagnostics)
+                        .and_then(|text| PackageManager::from_specifier(text.text()));
+                }
+                "browserslist" => {
+                    // The field is a query, or a list of queries.
+                    // The configurations per environment aren't supported.
+                    result.browserslist = match value.visitable_type() {
+                        Some(DeserializableType::Str) => {
+                            Text::deserialize(&value, &key_text, diagnostics)
+                                .map(|query| vec![query.text().to_string()])
+                        }
+                        Some(DeserializableType::Array) => {
+                            Deserializable::deserialize(&value, &key_text, diagnostics)
+                        }
+                        _ => None,
+                    };
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -176,3 +227,48 @@ impl PackageType {
         matches!(self, Self::Module)
     }
 }
+
+/// The package managers of Node.js projects
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PackageManager {
+    Npm,
+    Yarn,
+    Pnpm,
+    Bun,
+}
+
+impl PackageManager {
+    /// The lockfiles written by the package managers, in the order in which they're looked up
+    pub const LOCKFILES: &'static [(&'static str, Self)] = &[
+        ("package-lock.json", Self::Npm),
+        ("npm-shrinkwrap.json", Self::Npm),
+        ("yarn.lock", Self::Yarn),
+        ("pnpm-lock.yaml", Self::Pnpm),
+        ("bun.lock", Self::Bun),
+        ("bun.lockb", Self::Bun),
+    ];
+
+    /// Returns the package manager of the `packageManager` field, such as `yarn@4.5.0`.
+    pub fn from_specifier(specifier: &str) -> Option<Self> {
+        let name = specifier
+            .split_once('@')
+            .map_or(specifier, |(name, _)| name);
+        match name {
+            "npm" => Some(Self::Npm),
+            "yarn" => Some(Self::Yarn),
+            "pnpm" => Some(Self::Pnpm),
+            "bun" => Some(Self::Bun),
+            _ => None,
+        }
+    }
+
+    /// Returns the package manager of the first lockfile for which `exists` returns `true`.
+    ///
+    /// `exists` checks whether a lockfile is in the directory of the manifest.
+    pub fn from_lockfiles(exists: impl Fn(&str) -> bool) -> Option<Self> {
+        Self::LOCKFILES
+            .iter()
+            .find(|(lockfile, _)| exists(lockfile))
+            .map(|(_, package_manager)| *package_manager)
+    }
+}
@@ -7,6 +7,7 @@ use std::path::Path;
 
 mod manifest {
     tests_macros::gen_tests! {"tests/manifest/invalid/*.{json}", crate::run_invalid_manifests, "module"}
+    tests_macros::gen_tests! {"tests/manifest/valid/*.{json}", crate::run_valid_manifests, "module"}
 }
 
 mod tsconfig {
@@ -68,6 +69,46 @@ fn run_invalid_manifests(input: &'static str, _: &str, _: &str, _: &str) {
     });
 }
 
+fn run_valid_manifests(input: &'static str, _: &str, _: &str, _: &str) {
+    let input_file = Path::new(input);
+    let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+
+    let mut project = NodeJsProject::default();
+    match input_file.extension().map(OsStr::as_encoded_bytes) {
+        Some(b"json") => {
+            let parsed = parse_json(input_code.as_str(), JsonParserOptions::default());
+            project.deserialize_manifest(&parsed.tree());
+        }
+        _ => {
+            panic!("Extension not supported");
+        }
+    };
+
+    let result = project.analyze();
+
+    assert!(
+        !project.has_errors() && result.diagnostics.is_empty(),
+        "The file {input} should not have diagnostics, but it has some./"
+    );
+
+    let mut snapshot_result = String::new();
+
+    snapshot_result.push_str("## Input\n\n");
+    snapshot_result.push_str(&input_code);
+    snapshot_result.push_str("\n\n");
+    snapshot_result.push_str("## Data structure\n\n");
+    snapshot_result.push_str(&format!("{:#?}", project.manifest));
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot_result, file_name);
+    });
+}
+
 fn run_invalid_tsconfig(input: &'static str, _: &str, _: &str, _: &str) {
     let input_file = Path::new(input);
     let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
@@ -26,10 +26,13 @@ pub use mutation::{AstNodeExt, AstNodeListExt, AstSeparatedListExt};
 /// whether the corresponding [RawSyntaxKind] value is contained in the set
 ///
 /// This is similar to the `TokenSet` struct in `biome_js_parser`, with the
-/// bitfield here being twice as large as it needs to cover all nodes as well
-/// as all token kinds
+/// bitfield here being large enough to cover all nodes as well as all token
+/// kinds of the largest grammar
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct SyntaxKindSet<L: Language>([u128; 4], PhantomData<L>);
+pub struct SyntaxKindSet<L: Language>([u128; SYNTAX_KIND_SET_LEN], PhantomData<L>);
+
+/// The number of `u128` words of a [SyntaxKindSet]
+const SYNTAX_KIND_SET_LEN: usize = 5;
 
 impl<L> SyntaxKindSet<L>
 where
@@ -48,7 +51,7 @@ where
     /// ```compile_fail
     /// # use biome_rowan::{SyntaxKindSet, RawSyntaxKind, raw_language::RawLanguage};
     /// const EXAMPLE: SyntaxKindSet<RawLanguage> =
-    ///     SyntaxKindSet::<RawLanguage>::from_raw(RawSyntaxKind(512));
+    ///     SyntaxKindSet::<RawLanguage>::from_raw(RawSyntaxKind(640));
     /// # println!("{EXAMPLE:?}"); // The constant must be used to be evaluated
     /// ```
     pub const fn from_raw(kind: RawSyntaxKind) -> Self {
@@ -58,7 +61,7 @@ where
         let shift = kind % u128::BITS as u16;
         let mask = 1 << shift;
 
-        let mut bits = [0; 4];
+        let mut bits = [0; SYNTAX_KIND_SET_LEN];
         bits[index] = mask;
 
         Self(bits, PhantomData)
@@ -66,15 +69,15 @@ where
 
     /// Returns the union of the two sets `self` and `other`
     pub const fn union(self, other: Self) -> Self {
-        Self(
-            [
-                self.0[0] | other.0[0],
-                self.0[1] | other.0[1],
-                self.0[2] | other.0[2],
-                self.0[3] | other.0[3],
-            ],
-            PhantomData,
-        )
+        let mut bits = [0; SYNTAX_KIND_SET_LEN];
+
+        let mut index = 0;
+        while index < SYNTAX_KIND_SET_LEN {
+            bits[index] = self.0[index] | other.0[index];
+            index += 1;
+        }
+
+        Self(bits, PhantomData)
     }
 
     /// Returns true if `kind` is contained in this set
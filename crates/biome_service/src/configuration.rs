@@ -5,8 +5,8 @@ use biome_analyze::AnalyzerRules;
 use biome_configuration::diagnostics::{CantLoadExtendFile, EditorConfigDiagnostic};
 use biome_configuration::VERSION;
 use biome_configuration::{
-    push_to_analyzer_rules, BiomeDiagnostic, ConfigurationPathHint, ConfigurationPayload,
-    PartialConfiguration,
+    push_to_analyzer_assists, push_to_analyzer_rules, BiomeDiagnostic, ConfigurationPathHint,
+    ConfigurationPayload, PartialConfiguration,
 };
 use biome_console::markup;
 use biome_css_analyze::METADATA as css_lint_metadata;
@@ -15,10 +15,13 @@ use biome_deserialize::{Deserialized, Merge};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
 use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
 use biome_graphql_analyze::METADATA as graphql_lint_metadata;
+use biome_html_analyze::METADATA as html_lint_metadata;
 use biome_js_analyze::METADATA as js_lint_metadata;
 use biome_json_analyze::METADATA as json_lint_metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_toml_analyze::METADATA as toml_lint_metadata;
+use biome_yaml_analyze::METADATA as yaml_lint_metadata;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::io::ErrorKind;
@@ -374,9 +377,20 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Path) -> AnalyzerRules {
         push_to_analyzer_rules(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, html_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, yaml_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, toml_lint_metadata.deref(), &mut analyzer_rules);
     }
 
-    overrides.override_analyzer_rules(path, analyzer_rules)
+    let mut analyzer_rules = overrides.override_analyzer_rules(path, analyzer_rules);
+
+    // The overrides are already merged in the assists
+    if let Some(actions) = settings.as_assists_rules(path) {
+        push_to_analyzer_assists(&actions, js_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_assists(&actions, json_lint_metadata.deref(), &mut analyzer_rules);
+    }
+
+    analyzer_rules
 }
 
 pub trait PartialConfigurationExt {
@@ -29,6 +29,8 @@ pub enum WorkspaceError {
     DirtyWorkspace(DirtyWorkspace),
     /// The file does not exist in the [crate::Workspace]
     NotFound(NotFound),
+    /// The project is not registered in the [crate::Workspace]
+    ProjectNotFound(ProjectNotFound),
     /// A file is not supported. It contains the language and path of the file
     /// Use this error if Biome is trying to process a file that Biome can't understand
     SourceFileNotSupported(SourceFileNotSupported),
@@ -77,6 +79,10 @@ impl WorkspaceError {
         Self::NotFound(NotFound)
     }
 
+    pub fn project_not_found() -> Self {
+        Self::ProjectNotFound(ProjectNotFound)
+    }
+
     pub fn file_too_large(path: String, size: usize, limit: usize) -> Self {
         Self::FileTooLarge(FileTooLarge { path, size, limit })
     }
@@ -198,6 +204,14 @@ pub struct ReportNotSerializable {
 )]
 pub struct NotFound;
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "internalError/fs",
+    message = "The project is not registered in the workspace.",
+    tags(INTERNAL)
+)]
+pub struct ProjectNotFound;
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
     category = "format",
@@ -539,7 +553,7 @@ impl Advices for ProtectedFileAdvice {
 mod test {
     use crate::diagnostics::{
         CantReadDirectory, CantReadFile, DirtyWorkspace, FileIgnored, FileTooLarge, NotFound,
-        SourceFileNotSupported,
+        ProjectNotFound, SourceFileNotSupported,
     };
     use crate::file_handlers::DocumentFileSource;
     use crate::{TransportError, WorkspaceError};
@@ -613,6 +627,14 @@ mod test {
         )
     }
 
+    #[test]
+    fn project_not_found() {
+        snap_diagnostic(
+            "project_not_found",
+            WorkspaceError::ProjectNotFound(ProjectNotFound).into(),
+        )
+    }
+
     #[test]
     fn source_file_not_supported() {
         let path = BiomePath::new("not_supported.toml");
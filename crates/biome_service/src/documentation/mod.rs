@@ -1,8 +1,11 @@
 use biome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleMetadata};
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_syntax::YamlLanguage;
 use std::{collections::BTreeMap, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -39,6 +42,9 @@ impl LintRulesVisitor {
             rules_metadata: BTreeMap::new(),
         };
 
+        biome_toml_analyze::visit_registry(&mut visitor);
+        biome_yaml_analyze::visit_registry(&mut visitor);
+        biome_html_analyze::visit_registry(&mut visitor);
         biome_graphql_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
@@ -114,3 +120,51 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
         }
     }
 }
+
+impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+    }
+
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+}
+
+impl RegistryVisitor<YamlLanguage> for LintRulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+    }
+
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+}
+
+impl RegistryVisitor<TomlLanguage> for LintRulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+    }
+
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! The structured events of the event log.
+//!
+//! The events are emitted as [tracing] events with the target [EVENT_TARGET]. The CLI writes them
+//! to the file passed to `--log-file`, and the daemon writes them next to its logs, in the files
+//! prefixed by `server.events`. With `--log-format=jsonl`, each event is a JSON object on its own
+//! line, with the following fields:
+//!
+//! - `timestamp`: the number of milliseconds since the Unix epoch;
+//! - `source`: `"cli"` or `"daemon"`;
+//! - `thread`: the name of the thread that emitted the event, when it has one;
+//! - `event`: the name of the event, followed by the fields of the event.
+//!
+//! | Event                | Fields                                                                             |
+//! |----------------------|------------------------------------------------------------------------------------|
+//! | `runStarted`         | `command`                                                                          |
+//! | `runFinished`        | `command`, `durationMs`, `changed`, `unchanged`, `skipped`, `errors`, `warnings`   |
+//! | `fileStarted`        | `path`                                                                             |
+//! | `fileFinished`       | `path`, `durationMs`, `status`                                                     |
+//! | `diagnosticsEmitted` | `path`, `errors`, `warnings`, `total`                                              |
+//! | `fixesApplied`       | `path`, `fixes`, `skippedFixes`                                                    |
+//! | `panicCaught`        | `path`, `message`                                                                  |
+//!
+//! The `status` of `fileFinished` is one of `"changed"`, `"unchanged"`, `"ignored"`, `"protected"`,
+//! `"skipped"` and `"panicked"`.
+//!
+//! The events and their fields are part of the public interface of Biome: new events and new
+//! fields can be added, but the existing ones aren't renamed or removed.
+
+use std::path::Path;
+use std::time::Duration;
+
+/// The target of the tracing events that belong to the event log
+pub const EVENT_TARGET: &str = "biome::event";
+
+/// Emits the `runStarted` event, when a command starts processing the files
+pub fn run_started(command: &str) {
+    tracing::info!(target: EVENT_TARGET, event = "runStarted", command);
+}
+
+/// The counters of a run, reported by the `runFinished` event
+#[derive(Debug, Default)]
+pub struct RunSummary {
+    pub changed: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub errors: u32,
+    pub warnings: u32,
+}
+
+/// Emits the `runFinished` event, when a command processed all the files
+pub fn run_finished(command: &str, duration: Duration, summary: &RunSummary) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "runFinished",
+        command,
+        "durationMs" = duration_ms(duration),
+        changed = summary.changed,
+        unchanged = summary.unchanged,
+        skipped = summary.skipped,
+        errors = summary.errors,
+        warnings = summary.warnings,
+    );
+}
+
+/// Emits the `fileStarted` event, when the processing of a file starts
+pub fn file_started(path: &Path) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "fileStarted",
+        path = %path.display(),
+    );
+}
+
+/// Emits the `fileFinished` event, when the processing of a file finishes
+pub fn file_finished(path: &Path, duration: Duration, status: &str) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "fileFinished",
+        path = %path.display(),
+        "durationMs" = duration_ms(duration),
+        status,
+    );
+}
+
+/// Emits the `diagnosticsEmitted` event, when the diagnostics of a file are reported
+pub fn diagnostics_emitted(path: &str, errors: usize, warnings: usize, total: usize) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "diagnosticsEmitted",
+        path,
+        errors,
+        warnings,
+        total,
+    );
+}
+
+/// Emits the `fixesApplied` event, when the fixes of the analyzer are applied to a file
+pub fn fixes_applied(path: &Path, fixes: usize, skipped_fixes: u32) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "fixesApplied",
+        path = %path.display(),
+        fixes,
+        "skippedFixes" = skipped_fixes,
+    );
+}
+
+/// Emits the `panicCaught` event, when the processing of a file panics
+pub fn panic_caught(path: &Path, message: &str) {
+    tracing::info!(
+        target: EVENT_TARGET,
+        event = "panicCaught",
+        path = %path.display(),
+        message,
+    );
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
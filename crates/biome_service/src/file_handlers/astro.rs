@@ -1,5 +1,5 @@
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    html, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities,
 };
@@ -10,6 +10,7 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_html_syntax::HtmlFileSource;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
@@ -65,6 +66,23 @@ impl AstroFileHandler {
             input.to_string()
         }
     }
+
+    /// Formats the template and the `<style>` blocks of an Astro component around its
+    /// frontmatter, whose formatted content is `frontmatter`, and returns the whole formatted
+    /// component.
+    ///
+    /// The template, including its expressions such as `{title}`, is formatted with the HTML
+    /// formatter. The `<style>` blocks are formatted with the CSS formatter. The component is
+    /// left untouched when its template contains syntax errors.
+    pub(crate) fn format_blocks(
+        biome_path: &BiomePath,
+        content: &str,
+        frontmatter: Printed,
+        settings: &WorkspaceSettingsHandle,
+    ) -> Result<Printed, WorkspaceError> {
+        let code = AstroFileHandler::output(content, frontmatter.as_code());
+        html::format_component_markup(biome_path, code, HtmlFileSource::astro(), settings)
+    }
 }
 
 impl ExtensionHandler for AstroFileHandler {
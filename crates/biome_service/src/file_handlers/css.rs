@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, SearchCapabilities,
+    diagnostic_severity, is_diagnostic_error, load_browser_targets, AnalyzerVisitorBuilder,
+    CodeActionsParams, ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult,
+    SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -13,7 +14,7 @@ use crate::settings::{
 };
 use crate::workspace::{
     CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
-    OrganizeImportsResult, PullActionsResult,
+    OrganizeImportsResult, PullActionsResult, RenameResult, RenamedFile,
 };
 use crate::WorkspaceError;
 use biome_analyze::options::PreferredQuote;
@@ -22,19 +23,30 @@ use biome_analyze::{
     RuleCategoriesBuilder, RuleCategory, RuleError,
 };
 use biome_css_analyze::analyze;
+use biome_css_analyze::custom_properties::CustomPropertyIndex;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
+use biome_css_syntax::{
+    CssClassSelector, CssCustomIdentifier, CssDashedIdentifier, CssFileSource, CssLanguage,
+    CssRoot, CssSyntaxNode, CssSyntaxToken,
+};
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
-    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
+    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines, Printed,
+    QuoteStyle,
 };
 use biome_fs::BiomePath;
+use biome_js_analyze::utils::rename::RenameError;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{AnyJsxAttributeValue, JsFileSource, JsSyntaxNode, JsxAttribute};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, Direction, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use biome_text_edit::TextEdit;
+use ignore::WalkBuilder;
 use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -42,6 +54,7 @@ use tracing::{debug_span, error, info, trace_span};
 pub struct CssFormatterSettings {
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub quote_style: Option<QuoteStyle>,
@@ -88,6 +101,10 @@ impl ServiceLanguage for CssLanguage {
             .and_then(|l| l.line_width)
             .or(global.and_then(|g| g.line_width))
             .unwrap_or_default();
+        let max_empty_lines = language
+            .and_then(|l| l.max_empty_lines)
+            .or(global.and_then(|g| g.max_empty_lines))
+            .unwrap_or_default();
         let indent_width = language
             .and_then(|l| l.indent_width)
             .or(global.and_then(|g| g.indent_width))
@@ -106,6 +123,7 @@ impl ServiceLanguage for CssLanguage {
         .with_indent_style(indent_style)
         .with_indent_width(indent_width)
         .with_line_width(line_width)
+        .with_max_empty_lines(max_empty_lines)
         .with_line_ending(line_ending)
         .with_quote_style(language.and_then(|l| l.quote_style).unwrap_or_default());
         if let Some(overrides) = overrides {
@@ -117,7 +135,7 @@ impl ServiceLanguage for CssLanguage {
 
     fn resolve_analyzer_options(
         global: Option<&Settings>,
-        _linter: Option<&LinterSettings>,
+        linter: Option<&LinterSettings>,
         _overrides: Option<&OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
         file_path: &BiomePath,
@@ -147,6 +165,8 @@ impl ServiceLanguage for CssLanguage {
             globals: Vec::new(),
             preferred_quote,
             jsx_runtime: None,
+            report_broad_suppressions: linter
+                .is_some_and(|linter| linter.report_broad_suppressions),
         };
 
         AnalyzerOptions {
@@ -171,7 +191,7 @@ impl ExtensionHandler for CssFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
-                rename: None,
+                rename: Some(rename),
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
@@ -187,7 +207,7 @@ impl ExtensionHandler for CssFileHandler {
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     settings: Option<&Settings>,
     cache: &mut NodeCache,
@@ -198,8 +218,14 @@ fn parse(
             .unwrap_or_default(),
         css_modules: settings
             .and_then(|s| s.languages.css.parser.css_modules)
-            .unwrap_or_default(),
+            .unwrap_or_else(|| CssFileSource::is_css_module_path(biome_path)),
         grit_metavariables: false,
+        scss: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_scss()),
+        less: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_less()),
     };
     if let Some(settings) = settings {
         options = settings
@@ -308,7 +334,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting CSS file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
@@ -350,52 +376,60 @@ fn lint(params: LintParams) -> LintResults {
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
 
+            let browser_targets =
+                load_browser_targets(params.path.as_path(), params.manifest.as_deref());
+            let custom_properties = params.workspace.custom_properties();
+            // The CSS embedded in the templates of a JavaScript file isn't indexed
+            let is_stylesheet = DocumentFileSource::from_path(params.path.as_path())
+                .to_css_file_source()
+                .is_some();
+            if let Some(custom_properties) = custom_properties.as_ref().filter(|_| is_stylesheet) {
+                // The index is refreshed with the content of the document, which can be edited
+                custom_properties.insert(params.path.as_path(), &tree);
+            }
+
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) = analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                browser_targets,
+                custom_properties,
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic_severity(&diagnostic, rules.as_deref());
+
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -427,7 +461,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         range,
         workspace,
         path,
-        manifest: _,
+        manifest,
         language,
         only,
         skip,
@@ -462,21 +496,30 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 range,
             };
 
+            let browser_targets = load_browser_targets(path.as_path(), manifest.as_deref());
+
             info!("CSS runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
+            analyze(
+                &tree,
+                filter,
+                &analyzer_options,
+                browser_targets,
+                workspace.custom_properties(),
+                |signal| {
+                    actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                        CodeAction {
+                            category: item.category.clone(),
+                            rule_name: item
+                                .rule_name
+                                .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                            suggestion: item.suggestion,
+                        }
+                    }));
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             PullActionsResult { actions }
         })
@@ -519,49 +562,59 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<CssLanguage>(params.biome_path, &params.document_file_source);
+    let browser_targets =
+        load_browser_targets(params.biome_path.as_path(), params.manifest.as_deref());
+    let custom_properties = params.workspace.custom_properties();
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            browser_targets.clone(),
+            custom_properties.clone(),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: to implement
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: to implement
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -616,7 +669,6 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
 #[cfg(test)]
 mod test {
     use super::*;
-    use biome_css_syntax::CssFileSource;
 
     #[test]
     fn inherit_global_format_settings() {
@@ -637,3 +689,303 @@ mod test {
         );
     }
 }
+
+/// Indexes the custom properties defined by the stylesheets of the project folder `root`.
+///
+/// The hidden folders, the `node_modules` folders, and the files ignored by the VCS or by the
+/// configuration aren't scanned.
+pub(crate) fn scan_custom_properties(root: &Path, settings: &Settings) -> CustomPropertyIndex {
+    let index = CustomPropertyIndex::new(root.to_path_buf());
+    for path in project_files(root, settings) {
+        let Ok(file_source) = CssFileSource::try_from(path.as_path()) else {
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let parse =
+            biome_css_parser::parse_css(&content, project_parser_options(settings, file_source));
+        index.insert(&path, &parse.tree());
+    }
+    index
+}
+
+/// Returns the files of the project folder `root`, in the order of their paths.
+///
+/// The hidden folders, the `node_modules` folders, and the files ignored by the VCS or by the
+/// configuration are skipped.
+fn project_files<'a>(root: &Path, settings: &'a Settings) -> impl Iterator<Item = PathBuf> + 'a {
+    let walker = (!root.as_os_str().is_empty()).then(|| {
+        WalkBuilder::new(root)
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .sort_by_file_name(|left, right| left.cmp(right))
+            .build()
+    });
+    walker
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+        })
+        .map(|entry| entry.into_path())
+        .filter(|path| !settings.files.ignored_files.matches_path(path))
+}
+
+/// Returns the options of the parser for a stylesheet of the project that isn't open
+fn project_parser_options(settings: &Settings, file_source: CssFileSource) -> CssParserOptions {
+    CssParserOptions {
+        allow_wrong_line_comments: settings
+            .languages
+            .css
+            .parser
+            .allow_wrong_line_comments
+            .unwrap_or_default(),
+        css_modules: false,
+        grit_metavariables: false,
+        scss: file_source.is_scss(),
+        less: file_source.is_less(),
+    }
+}
+
+/// A CSS custom property or a CSS class, which can be renamed across the files of a project
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum CssSymbol {
+    /// A custom property, such as `--color-primary`
+    CustomProperty(String),
+    /// A class, such as `button` for `.button`
+    Class(String),
+}
+
+impl CssSymbol {
+    /// Returns the symbol named `name`: a custom property such as `--color-primary`, or a class
+    /// such as `.button`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name.strip_prefix('.') {
+            Some(class) => is_valid_class(class).then(|| Self::Class(class.to_string())),
+            None => is_valid_custom_property(name).then(|| Self::CustomProperty(name.to_string())),
+        }
+    }
+
+    /// Returns the symbol at `offset` in the stylesheet `parse`, and the range of its name.
+    pub(crate) fn at_offset(parse: &AnyParse, offset: TextSize) -> Option<(Self, TextRange)> {
+        parse
+            .syntax::<CssLanguage>()
+            .token_at_offset(offset)
+            .find_map(|token| Some((Self::of_token(&token)?, token.text_trimmed_range())))
+    }
+
+    /// Returns the symbol named by `token`, if it's the name of a custom property or the name
+    /// of a class selector.
+    fn of_token(token: &CssSyntaxToken) -> Option<Self> {
+        let parent = token.parent()?;
+        if CssDashedIdentifier::can_cast(parent.kind()) {
+            Some(Self::CustomProperty(token.text_trimmed().to_string()))
+        } else if CssCustomIdentifier::can_cast(parent.kind())
+            && parent
+                .parent()
+                .is_some_and(|grand_parent| CssClassSelector::can_cast(grand_parent.kind()))
+        {
+            Some(Self::Class(token.text_trimmed().to_string()))
+        } else {
+            None
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::CustomProperty(name) | Self::Class(name) => name,
+        }
+    }
+
+    /// Returns the name written in place of the symbol once renamed to `new_name`, or `None` if
+    /// `new_name` isn't a valid name for the symbol.
+    ///
+    /// The new name of a class can start with a `.`.
+    pub(crate) fn replacement_name<'a>(&self, new_name: &'a str) -> Option<&'a str> {
+        match self {
+            Self::CustomProperty(_) => is_valid_custom_property(new_name).then_some(new_name),
+            Self::Class(_) => {
+                let new_name = new_name.strip_prefix('.').unwrap_or(new_name);
+                is_valid_class(new_name).then_some(new_name)
+            }
+        }
+    }
+}
+
+fn is_name_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || matches!(char, '-' | '_') || !char.is_ascii()
+}
+
+fn is_valid_custom_property(name: &str) -> bool {
+    name.strip_prefix("--")
+        .is_some_and(|name| !name.is_empty() && name.chars().all(is_name_char))
+}
+
+fn is_valid_class(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|char: char| char.is_ascii_digit())
+        && name.chars().all(is_name_char)
+}
+
+/// Returns the ranges of the names of `symbol` in the stylesheet `root`
+fn stylesheet_occurrences(root: &CssSyntaxNode, symbol: &CssSymbol) -> Vec<TextRange> {
+    root.descendants_tokens(Direction::Next)
+        .filter(|token| {
+            token.text_trimmed() == symbol.name()
+                && CssSymbol::of_token(token).as_ref() == Some(symbol)
+        })
+        .map(|token| token.text_trimmed_range())
+        .collect()
+}
+
+/// Returns the ranges of `class` in the `class` and `className` attributes of the JSX elements
+/// of `root`
+fn jsx_class_occurrences(root: &JsSyntaxNode, class: &str) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    for attribute in root.descendants().filter_map(JsxAttribute::cast) {
+        let Some(name) = attribute
+            .name()
+            .ok()
+            .and_then(|name| name.as_jsx_name()?.value_token().ok())
+        else {
+            continue;
+        };
+        if !matches!(name.text_trimmed(), "class" | "className") {
+            continue;
+        }
+        let Some(AnyJsxAttributeValue::JsxString(value)) = attribute
+            .initializer()
+            .and_then(|initializer| initializer.value().ok())
+        else {
+            continue;
+        };
+        let Ok(value) = value.value_token() else {
+            continue;
+        };
+        // The quotes and the whitespace separate the classes
+        let mut start = value.text_trimmed_range().start();
+        for word in value
+            .text_trimmed()
+            .split(|char: char| matches!(char, '"' | '\'') || char.is_ascii_whitespace())
+        {
+            let len = TextSize::of(word);
+            if word == class {
+                ranges.push(TextRange::at(start, len));
+            }
+            start += len + TextSize::from(1);
+        }
+    }
+    ranges
+}
+
+/// Returns the edit of `content` that replaces the text of `ranges`, in order, with `new_text`
+fn replace_ranges(content: &str, ranges: &[TextRange], new_text: &str) -> TextEdit {
+    let mut builder = TextEdit::builder();
+    let mut last_end = 0;
+    for range in ranges {
+        let start = usize::from(range.start());
+        let end = usize::from(range.end());
+        if start > last_end {
+            builder.equal(&content[last_end..start]);
+        }
+        builder.replace(&content[start..end], new_text);
+        last_end = end;
+    }
+    if last_end < content.len() {
+        builder.equal(&content[last_end..]);
+    }
+    builder.finish()
+}
+
+/// Renames the custom property or the class at `symbol_at` in the stylesheet.
+///
+/// The other files of the project are renamed by [rename_in_project].
+fn rename(
+    _biome_path: &BiomePath,
+    parse: AnyParse,
+    symbol_at: TextSize,
+    new_name: String,
+) -> Result<RenameResult, WorkspaceError> {
+    let Some((symbol, range)) = CssSymbol::at_offset(&parse, symbol_at) else {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(new_name),
+        ));
+    };
+    let Some(replacement) = symbol.replacement_name(&new_name) else {
+        return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            original_name: symbol.name().to_string(),
+            original_range: range,
+            new_name,
+        }));
+    };
+
+    let root = parse.syntax::<CssLanguage>();
+    let ranges = stylesheet_occurrences(&root, &symbol);
+    Ok(RenameResult {
+        range: ranges
+            .iter()
+            .fold(range, |range, other| range.cover(*other)),
+        indels: replace_ranges(&root.to_string(), &ranges, replacement),
+        other_files: Vec::new(),
+    })
+}
+
+/// Renames `symbol` to `new_name` in the files of the project folder `root`, other than
+/// `except`: in the stylesheets, and, for a class, in the `class` and `className` attributes
+/// of the JSX elements.
+///
+/// `read_file` returns the content of a file of the project. Returns the edits of the files
+/// that use the symbol, or nothing if `new_name` isn't a valid name for the symbol.
+pub(crate) fn rename_in_project(
+    root: &Path,
+    settings: &Settings,
+    symbol: &CssSymbol,
+    new_name: &str,
+    except: Option<&Path>,
+    read_file: impl Fn(&Path) -> Option<String>,
+) -> Vec<RenamedFile> {
+    let Some(replacement) = symbol.replacement_name(new_name) else {
+        return Vec::new();
+    };
+    let except = except.and_then(|path| std::path::absolute(path).ok());
+
+    let mut files = Vec::new();
+    for path in project_files(root, settings) {
+        if except.as_deref() == Some(path.as_path()) {
+            continue;
+        }
+        let (ranges, content) = if let Ok(file_source) = CssFileSource::try_from(path.as_path()) {
+            let Some(content) = read_file(&path).filter(|content| content.contains(symbol.name()))
+            else {
+                continue;
+            };
+            let parse = biome_css_parser::parse_css(
+                &content,
+                project_parser_options(settings, file_source),
+            );
+            (stylesheet_occurrences(&parse.syntax(), symbol), content)
+        } else if let (CssSymbol::Class(class), Ok(file_source)) =
+            (symbol, JsFileSource::try_from(path.as_path()))
+        {
+            let Some(content) = read_file(&path).filter(|content| content.contains(class.as_str()))
+            else {
+                continue;
+            };
+            let parse = biome_js_parser::parse(&content, file_source, JsParserOptions::default());
+            (jsx_class_occurrences(&parse.syntax(), class), content)
+        } else {
+            continue;
+        };
+
+        if !ranges.is_empty() {
+            files.push(RenamedFile {
+                path: BiomePath::new(path),
+                indels: replace_ranges(&content, &ranges, replacement),
+            });
+        }
+    }
+    files
+}
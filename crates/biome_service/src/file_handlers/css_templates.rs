@@ -0,0 +1,430 @@
+//! Support for CSS embedded in JavaScript tagged templates, as written with the CSS-in-JS
+//! libraries such as styled-components and Emotion:
+//!
+//! ```js
+//! const Button = styled.button`
+//!   color: ${(props) => props.color};
+//!   padding: 4px;
+//! `;
+//! ```
+//!
+//! The templates of `styled.x`, `styled(Component)` and `css` contain the declarations of a
+//! rule, and the templates of `createGlobalStyle` contain a stylesheet. Their content is parsed
+//! with the CSS parser, where each `${...}` substitution is replaced by a placeholder, then it's
+//! analyzed by the CSS rules and formatted by the CSS formatter. The ranges of the diagnostics are
+//! mapped back to the host file.
+
+use super::{css, javascript, DocumentFileSource, LintParams, LintResults};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::WorkspaceError;
+use biome_css_formatter::format_node;
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{FormatOptions, IndentStyle, Printed};
+use biome_fs::BiomePath;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsTemplateElement, JsLanguage, JsTemplateExpression, TextRange,
+    TextSize,
+};
+use biome_rowan::AstNode;
+
+/// The rule that wraps the declarations of a template, so they can be parsed as a stylesheet
+const RULE_PREFIX: &str = "a{";
+const RULE_SUFFIX: &str = "}";
+
+/// What the content of a template is
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum CssTemplateKind {
+    /// The declarations of a rule, in the templates of `styled.x` and `css`
+    Declarations,
+    /// A stylesheet, in the templates of `createGlobalStyle`
+    Stylesheet,
+}
+
+/// Where a substitution appears in the CSS, which decides the placeholder that replaces it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SubstitutionPosition {
+    /// In place of a declaration or a rule, like a mixin `${truncate};`
+    Statement,
+    /// In place of the name of a property, like `${property}: 0;`
+    Property,
+    /// In a value or a selector, like `color: ${color};` or `${Icon}:hover {}`
+    Value,
+}
+
+/// A CSS template embedded in a JavaScript file
+struct CssTemplate {
+    kind: CssTemplateKind,
+    /// The range of the content of the template, between its backticks
+    range: TextRange,
+    /// The text around the substitutions. There's one more chunk than substitutions.
+    chunks: Vec<String>,
+    /// The text of the `${...}` substitutions
+    substitutions: Vec<String>,
+}
+
+/// The CSS source of a template
+struct CssSource {
+    text: String,
+    /// The placeholder of each substitution
+    placeholders: Vec<Placeholder>,
+}
+
+struct Placeholder {
+    /// The range of the placeholder in the CSS source
+    range: TextRange,
+    position: SubstitutionPosition,
+    /// The text of the template replaced by the placeholder: the substitution, and the
+    /// semicolon that follows a statement
+    replaced: String,
+}
+
+impl CssTemplate {
+    fn from_template(template: &JsTemplateExpression) -> Option<Self> {
+        let kind = template_kind(template)?;
+        let start = template.l_tick_token().ok()?.text_trimmed_range().end();
+        let end = template.r_tick_token().ok()?.text_trimmed_range().start();
+        let mut chunks = vec![String::new()];
+        let mut substitutions = Vec::new();
+        for element in template.elements() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let token = chunk.template_chunk_token().ok()?;
+                    chunks.last_mut()?.push_str(token.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    substitutions.push(element.syntax().text_trimmed().to_string());
+                    chunks.push(String::new());
+                }
+            }
+        }
+        Some(Self {
+            kind,
+            range: TextRange::new(start, end),
+            chunks,
+            substitutions,
+        })
+    }
+
+    fn is_blank(&self) -> bool {
+        self.substitutions.is_empty() && self.chunks.iter().all(|chunk| chunk.trim().is_empty())
+    }
+
+    /// The length of the text that precedes the content of the template in its CSS source
+    fn prefix_len(&self) -> TextSize {
+        match self.kind {
+            CssTemplateKind::Declarations => TextSize::of(RULE_PREFIX),
+            CssTemplateKind::Stylesheet => TextSize::from(0),
+        }
+    }
+
+    /// Returns the CSS source of the template, where each substitution is replaced by the
+    /// placeholder returned by `placeholder` for its index, its position and its text
+    fn source(
+        &self,
+        placeholder: impl Fn(usize, SubstitutionPosition, &str) -> String,
+    ) -> CssSource {
+        let mut text = String::new();
+        if self.kind == CssTemplateKind::Declarations {
+            text.push_str(RULE_PREFIX);
+        }
+        let mut placeholders = Vec::with_capacity(self.substitutions.len());
+        let mut chunk = self.chunks[0].as_str();
+        for (index, substitution) in self.substitutions.iter().enumerate() {
+            text.push_str(chunk);
+            chunk = &self.chunks[index + 1];
+            let position = substitution_position(&text, chunk);
+            let mut replaced = substitution.clone();
+            // A semicolon alone isn't valid CSS, so it's replaced with the statement
+            if position == SubstitutionPosition::Statement && chunk.starts_with(';') {
+                replaced.push(';');
+                chunk = &chunk[1..];
+            }
+            let start = TextSize::of(text.as_str());
+            text.push_str(&placeholder(index, position, &replaced));
+            placeholders.push(Placeholder {
+                range: TextRange::new(start, TextSize::of(text.as_str())),
+                position,
+                replaced,
+            });
+        }
+        text.push_str(chunk);
+        if self.kind == CssTemplateKind::Declarations {
+            text.push_str(RULE_SUFFIX);
+        }
+        CssSource { text, placeholders }
+    }
+}
+
+/// Returns where a substitution appears from the CSS that precedes it and the text that
+/// follows it
+fn substitution_position(before: &str, after: &str) -> SubstitutionPosition {
+    let before = before.trim_end();
+    let starts_statement =
+        before.is_empty() || before.ends_with(['{', ';', '}']) || before.ends_with("*/");
+    if !starts_statement {
+        return SubstitutionPosition::Value;
+    }
+    // A substitution that ends its line is a statement, like a mixin without a semicolon
+    if after
+        .trim_start_matches([' ', '\t'])
+        .starts_with(['\n', '\r'])
+    {
+        return SubstitutionPosition::Statement;
+    }
+    let after = after.trim_start();
+    match after.find(['{', ';', '}']).map(|index| &after[index..]) {
+        // The selector of a rule
+        Some(rest) if rest.starts_with('{') => SubstitutionPosition::Value,
+        _ if after.starts_with(':') => SubstitutionPosition::Property,
+        _ => SubstitutionPosition::Statement,
+    }
+}
+
+/// Returns a placeholder with the same length as the substitution, so the offsets of the rest
+/// of the template are preserved
+fn lint_placeholder(_: usize, position: SubstitutionPosition, substitution: &str) -> String {
+    let len = substitution.len();
+    match position {
+        SubstitutionPosition::Statement => format!("/*{}*/", "_".repeat(len.saturating_sub(4))),
+        SubstitutionPosition::Property => format!("--{}", "_".repeat(len.saturating_sub(2))),
+        SubstitutionPosition::Value => "_".repeat(len),
+    }
+}
+
+/// Returns a placeholder that's unique in the template, so it can be replaced by the
+/// substitution in the formatted CSS
+fn format_placeholder(index: usize, position: SubstitutionPosition, _: &str) -> String {
+    match position {
+        SubstitutionPosition::Statement => format!("/* biome-placeholder-{index} */"),
+        SubstitutionPosition::Property => format!("--biome-placeholder-{index}"),
+        SubstitutionPosition::Value => format!("biome-placeholder-{index}"),
+    }
+}
+
+fn template_kind(template: &JsTemplateExpression) -> Option<CssTemplateKind> {
+    let tag = template.tag()?;
+    if let Some(name) = identifier_name(&tag) {
+        return match name.as_str() {
+            "css" => Some(CssTemplateKind::Declarations),
+            "createGlobalStyle" => Some(CssTemplateKind::Stylesheet),
+            _ => None,
+        };
+    }
+    is_styled_component(&tag).then_some(CssTemplateKind::Declarations)
+}
+
+/// Returns `true` if `expression` creates a styled component, like `styled.div`,
+/// `styled(Button)` or `styled.div.attrs({ role: "button" })`
+fn is_styled_component(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsStaticMemberExpression(member) => member
+            .object()
+            .is_ok_and(|object| identifier_name(&object).is_some_and(|name| name == "styled")),
+        AnyJsExpression::JsCallExpression(call) => match call.callee() {
+            Ok(AnyJsExpression::JsStaticMemberExpression(member)) => {
+                let is_option = member.member().is_ok_and(|name| {
+                    matches!(
+                        name.syntax().text_trimmed().to_string().as_str(),
+                        "attrs" | "withConfig"
+                    )
+                });
+                is_option
+                    && member
+                        .object()
+                        .is_ok_and(|object| is_styled_component(&object))
+            }
+            Ok(callee) => identifier_name(&callee).is_some_and(|name| name == "styled"),
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
+
+fn identifier_name(expression: &AnyJsExpression) -> Option<String> {
+    let AnyJsExpression::JsIdentifierExpression(identifier) = expression else {
+        return None;
+    };
+    let token = identifier.name().ok()?.value_token().ok()?;
+    Some(token.text_trimmed().to_string())
+}
+
+/// Returns the CSS templates of `root`, in the order of the document
+fn css_templates(root: &AnyJsRoot) -> impl Iterator<Item = CssTemplate> {
+    root.syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+        .filter_map(|template| CssTemplate::from_template(&template))
+}
+
+/// Analyzes the CSS templates of `root` with the CSS rules.
+///
+/// Templates with syntax errors are skipped, and the diagnostics of the placeholders of the
+/// substitutions are dropped. The ranges of the diagnostics are relative to the host file.
+pub(crate) fn lint(params: &LintParams, root: &AnyJsRoot) -> LintResults {
+    let mut results = LintResults {
+        errors: 0,
+        diagnostics: Vec::new(),
+        skipped_diagnostics: 0,
+    };
+    for template in css_templates(root) {
+        if template.is_blank() {
+            continue;
+        }
+        let source = template.source(lint_placeholder);
+        let parse = parse_css(&source.text, CssParserOptions::default());
+        if parse.has_errors() {
+            continue;
+        }
+        let template_results = css::lint(LintParams {
+            parse: parse.into(),
+            workspace: params.workspace,
+            language: CssFileSource::css().into(),
+            max_diagnostics: params
+                .max_diagnostics
+                .saturating_sub(results.diagnostics.len() as u32),
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: None,
+        });
+
+        let content = TextRange::at(template.prefix_len(), template.range.len());
+        let offset = template.range.start() - template.prefix_len();
+        let mut errors = template_results.errors;
+        for diagnostic in template_results.diagnostics {
+            let is_in_template = diagnostic.location().span.is_some_and(|span| {
+                content.contains_range(span)
+                    && source.placeholders.iter().all(|placeholder| {
+                        span.end() <= placeholder.range.start()
+                            || placeholder.range.end() <= span.start()
+                    })
+            });
+            if is_in_template {
+                results.diagnostics.push(diagnostic.with_offset(offset));
+            } else if diagnostic.severity() >= Severity::Error {
+                errors = errors.saturating_sub(1);
+            }
+        }
+        results.errors += errors;
+        results.skipped_diagnostics += template_results.skipped_diagnostics;
+    }
+    results
+}
+
+/// Formats the CSS templates of a JavaScript file that was just formatted.
+///
+/// Templates with syntax errors are left untouched. The formatted CSS is indented one level
+/// deeper than the line of the template.
+pub(crate) fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    printed: Printed,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let Some(workspace_settings) = settings
+        .settings()
+        .filter(|settings| !settings.css_formatter_disabled())
+    else {
+        return Ok(printed);
+    };
+
+    let file_source = document_file_source.to_js_file_source().unwrap_or_default();
+    let options = javascript::parser_options(biome_path, Some(workspace_settings));
+    let parse = biome_js_parser::parse(printed.as_code(), file_source, options);
+    if parse.has_errors() {
+        return Ok(printed);
+    }
+
+    let js_options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    // The declarations are indented by the rule that wraps them, with the indentation of the
+    // JavaScript file
+    let css_options = settings
+        .format_options::<CssLanguage>(biome_path, &CssFileSource::css().into())
+        .with_indent_style(js_options.indent_style())
+        .with_indent_width(js_options.indent_width());
+    let indent_unit = match js_options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(js_options.indent_width().value() as usize),
+    };
+    let line_ending = js_options.line_ending().as_str();
+
+    let code = printed.as_code();
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = TextSize::from(0);
+    for template in css_templates(&parse.tree()) {
+        if template.is_blank() {
+            continue;
+        }
+        let source = template.source(format_placeholder);
+        let stylesheet = parse_css(&source.text, CssParserOptions::default());
+        if stylesheet.has_errors() {
+            continue;
+        }
+        let formatted = format_node(css_options.clone(), &stylesheet.syntax())?
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        let Some(formatted) = restore_substitutions(&source, formatted.as_code()) else {
+            continue;
+        };
+        let lines: Vec<_> = formatted.trim_end().lines().collect();
+        let (lines, line_indentation) = match template.kind {
+            CssTemplateKind::Declarations => {
+                // Remove the lines of the rule that wraps the declarations
+                let [first, inner @ .., last] = lines.as_slice() else {
+                    continue;
+                };
+                if first.trim_end() != "a {" || *last != "}" || inner.is_empty() {
+                    continue;
+                }
+                (inner, "")
+            }
+            CssTemplateKind::Stylesheet => (lines.as_slice(), indent_unit.as_str()),
+        };
+
+        let start = usize::from(template.range.start());
+        let line_start = code[..start].rfind('\n').map_or(0, |index| index + 1);
+        let indentation: String = code[line_start..start]
+            .chars()
+            .take_while(|c| matches!(c, ' ' | '\t'))
+            .collect();
+
+        output.push_str(&code[usize::from(last_end)..start]);
+        output.push_str(line_ending);
+        for line in lines {
+            if !line.is_empty() {
+                output.push_str(&indentation);
+                output.push_str(line_indentation);
+                output.push_str(line);
+            }
+            output.push_str(line_ending);
+        }
+        output.push_str(&indentation);
+        last_end = template.range.end();
+    }
+
+    if last_end == TextSize::from(0) {
+        return Ok(printed);
+    }
+    output.push_str(&code[usize::from(last_end)..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+/// Replaces the placeholders of the formatted CSS by the substitutions of the template.
+///
+/// Returns `None` if the formatter didn't keep every placeholder exactly once.
+fn restore_substitutions(source: &CssSource, formatted: &str) -> Option<String> {
+    let mut formatted = formatted.to_string();
+    // The placeholders with the highest indices go first, so `biome-placeholder-1` doesn't
+    // match the start of `biome-placeholder-10`
+    for (index, placeholder) in source.placeholders.iter().enumerate().rev() {
+        let text = format_placeholder(index, placeholder.position, &placeholder.replaced);
+        if formatted.matches(&text).count() != 1 {
+            return None;
+        }
+        formatted = formatted.replacen(&text, &placeholder.replaced, 1);
+    }
+    Some(formatted)
+}
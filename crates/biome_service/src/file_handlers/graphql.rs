@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
+    diagnostic_severity, is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams,
+    DocumentFileSource, ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult,
+    SearchCapabilities,
 };
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
@@ -24,15 +25,19 @@ use biome_formatter::{
     QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_graphql_analyze::analyze;
+use biome_graphql_analyze::{analyze, GraphqlSchema};
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
-use biome_graphql_parser::parse_graphql_with_cache;
+use biome_graphql_parser::{parse_graphql, parse_graphql_with_cache};
 use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
+use dashmap::DashMap;
 use std::borrow::Cow;
-use tracing::{debug_span, error, info, trace_span};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::SystemTime;
+use tracing::{debug_span, error, info, trace_span, warn};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -64,16 +69,84 @@ impl Default for GraphqlFormatterSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GraphqlLinterSettings {
     pub enabled: Option<bool>,
+    /// Path to the SDL file, or to the introspection result, of the project schema
+    pub schema: Option<PathBuf>,
 }
 
 impl Default for GraphqlLinterSettings {
     fn default() -> Self {
         Self {
             enabled: Some(false),
+            schema: None,
         }
     }
 }
 
+/// A schema loaded from the disk, along with the modification time of its file
+type LoadedSchema = (Option<SystemTime>, Arc<GraphqlSchema>);
+
+static SCHEMAS: LazyLock<DashMap<PathBuf, LoadedSchema>> = LazyLock::new(DashMap::default);
+
+/// Returns the schema configured in `graphql.linter.schema`.
+///
+/// The schema is parsed once and reloaded only when its file changes.
+fn load_schema(workspace: &WorkspaceSettingsHandle) -> Option<Arc<GraphqlSchema>> {
+    let path = workspace
+        .settings()?
+        .languages
+        .graphql
+        .linter
+        .schema
+        .as_deref()?;
+    let modified = std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    if let Some(cached) = SCHEMAS.get(path) {
+        if modified.is_some() && cached.0 == modified {
+            return Some(cached.1.clone());
+        }
+    }
+    let schema = Arc::new(parse_schema(path)?);
+    SCHEMAS.insert(path.to_path_buf(), (modified, schema.clone()));
+    Some(schema)
+}
+
+fn parse_schema(path: &Path) -> Option<GraphqlSchema> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => {
+            warn!(
+                "Could not read the GraphQL schema {}: {error}",
+                path.display()
+            );
+            return None;
+        }
+    };
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        return match GraphqlSchema::from_introspection(&content) {
+            Ok(schema) => Some(schema),
+            Err(error) => {
+                warn!(
+                    "The GraphQL introspection result {} is invalid: {error}",
+                    path.display()
+                );
+                None
+            }
+        };
+    }
+    let parsed = parse_graphql(&content);
+    if parsed.has_errors() {
+        warn!(
+            "The GraphQL schema {} contains syntax errors",
+            path.display()
+        );
+    }
+    Some(GraphqlSchema::from_root(&parsed.tree()))
+}
+
 impl ServiceLanguage for GraphqlLanguage {
     type FormatterSettings = GraphqlFormatterSettings;
     type LinterSettings = GraphqlLinterSettings;
@@ -136,14 +209,18 @@ impl ServiceLanguage for GraphqlLanguage {
 
     fn resolve_analyzer_options(
         _global: Option<&Settings>,
-        _linter: Option<&LinterSettings>,
+        linter: Option<&LinterSettings>,
         _overrides: Option<&OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
         path: &BiomePath,
         _file_source: &DocumentFileSource,
     ) -> AnalyzerOptions {
         AnalyzerOptions {
-            configuration: AnalyzerConfiguration::default(),
+            configuration: AnalyzerConfiguration {
+                report_broad_suppressions: linter
+                    .is_some_and(|linter| linter.report_broad_suppressions),
+                ..Default::default()
+            },
             file_path: path.to_path_buf(),
         }
     }
@@ -288,12 +365,13 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
             let workspace_settings = &params.workspace;
             let analyzer_options = workspace_settings
                 .analyzer_options::<GraphqlLanguage>(params.path, &params.language);
+            let schema = load_schema(workspace_settings);
             let tree = params.parse.tree();
 
             let has_only_filter = !params.only.is_empty();
@@ -331,51 +409,41 @@ fn lint(params: LintParams) -> LintResults {
                 .count();
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) =
+                analyze(&tree, filter, &analyzer_options, schema, |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if ignores_suppression_comment
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic_severity(&diagnostic, rules.as_deref());
+
+                        if severity >= Severity::Error {
+                            errors += 1;
                         }
 
-                        let error = diagnostic.with_severity(severity);
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                });
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -417,6 +485,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             };
 
             let analyzer_options = workspace.analyzer_options::<GraphqlLanguage>(path, &language);
+            let schema = load_schema(workspace);
             let mut actions = Vec::new();
             let (enabled_rules, disabled_rules) =
                 AnalyzerVisitorBuilder::new(params.workspace.settings())
@@ -438,7 +507,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
             info!("GraphQL runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
+            analyze(&tree, filter, &analyzer_options, schema, |signal| {
                 actions.extend(signal.actions().into_code_action_iter().map(|item| {
                     CodeAction {
                         category: item.category.clone(),
@@ -494,8 +563,9 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<GraphqlLanguage>(params.biome_path, &params.document_file_source);
+    let schema = load_schema(&params.workspace);
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+        let (action, _) = analyze(&tree, filter, &analyzer_options, schema.clone(), |signal| {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
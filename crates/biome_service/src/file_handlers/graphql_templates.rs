@@ -0,0 +1,202 @@
+//! Support for GraphQL documents embedded in JavaScript tagged templates:
+//!
+//! ```js
+//! const query = gql`
+//!   query { user { name } }
+//! `;
+//! ```
+//!
+//! The content of the templates is parsed with the GraphQL parser, analyzed by the GraphQL rules
+//! and formatted by the GraphQL formatter. The ranges of the diagnostics are mapped back to the
+//! host file.
+
+use super::{graphql, javascript, DocumentFileSource, LintParams, LintResults};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::WorkspaceError;
+use biome_formatter::{FormatOptions, IndentStyle, Printed};
+use biome_fs::BiomePath;
+use biome_graphql_formatter::format_node;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsTemplateElement, JsLanguage, JsTemplateExpression, TextRange,
+    TextSize,
+};
+use biome_rowan::AstNode;
+
+/// The names of the tags that mark a template as a GraphQL document
+const GRAPHQL_TAGS: [&str; 2] = ["gql", "graphql"];
+
+/// A GraphQL document embedded in a template
+struct GraphqlTemplate {
+    /// The GraphQL source, where each substitution is replaced by whitespace of the same length
+    text: String,
+    /// The range of the content of the template, between its backticks
+    range: TextRange,
+    /// Whether the template contains `${...}` substitutions
+    has_substitutions: bool,
+}
+
+impl GraphqlTemplate {
+    fn from_template(template: &JsTemplateExpression) -> Option<Self> {
+        let start = template.l_tick_token().ok()?.text_trimmed_range().end();
+        let end = template.r_tick_token().ok()?.text_trimmed_range().start();
+        let mut text = String::new();
+        let mut has_substitutions = false;
+        for element in template.elements() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    text.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    has_substitutions = true;
+                    // Keep the line breaks and the offsets of the rest of the document
+                    for c in element.syntax().text_trimmed().to_string().chars() {
+                        if matches!(c, '\n' | '\r') {
+                            text.push(c);
+                        } else {
+                            text.extend(std::iter::repeat(' ').take(c.len_utf8()));
+                        }
+                    }
+                }
+            }
+        }
+        Some(Self {
+            text,
+            range: TextRange::new(start, end),
+            has_substitutions,
+        })
+    }
+}
+
+/// Returns the templates tagged with `gql` or `graphql`, in the order of the document
+fn graphql_templates(root: &AnyJsRoot) -> impl Iterator<Item = GraphqlTemplate> {
+    root.syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+        .filter(is_graphql_tagged)
+        .filter_map(|template| GraphqlTemplate::from_template(&template))
+}
+
+fn is_graphql_tagged(template: &JsTemplateExpression) -> bool {
+    let Some(AnyJsExpression::JsIdentifierExpression(tag)) = template.tag() else {
+        return false;
+    };
+    tag.name()
+        .and_then(|name| name.value_token())
+        .is_ok_and(|token| GRAPHQL_TAGS.contains(&token.text_trimmed()))
+}
+
+/// Analyzes the GraphQL templates of `root` with the GraphQL rules.
+///
+/// The ranges of the diagnostics are relative to the host file.
+pub(crate) fn lint(params: &LintParams, root: &AnyJsRoot) -> LintResults {
+    let mut results = LintResults {
+        errors: 0,
+        diagnostics: Vec::new(),
+        skipped_diagnostics: 0,
+    };
+    for template in graphql_templates(root) {
+        if template.text.trim().is_empty() {
+            continue;
+        }
+        let template_results = graphql::lint(LintParams {
+            parse: parse_graphql(&template.text).into(),
+            workspace: params.workspace,
+            language: GraphqlFileSource::graphql().into(),
+            max_diagnostics: params
+                .max_diagnostics
+                .saturating_sub(results.diagnostics.len() as u32),
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: None,
+        });
+        results.errors += template_results.errors;
+        results.skipped_diagnostics += template_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            template_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(template.range.start())),
+        );
+    }
+    results
+}
+
+/// Formats the GraphQL templates of a JavaScript file that was just formatted.
+///
+/// Templates that contain substitutions or syntax errors are left untouched. The formatted
+/// document is indented one level deeper than the line of the template.
+pub(crate) fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    printed: Printed,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let Some(workspace_settings) = settings
+        .settings()
+        .filter(|settings| !settings.graphql_formatter_disabled())
+    else {
+        return Ok(printed);
+    };
+
+    let file_source = document_file_source.to_js_file_source().unwrap_or_default();
+    let options = javascript::parser_options(biome_path, Some(workspace_settings));
+    let parse = biome_js_parser::parse(printed.as_code(), file_source, options);
+    if parse.has_errors() {
+        return Ok(printed);
+    }
+
+    let js_options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let graphql_options = settings
+        .format_options::<GraphqlLanguage>(biome_path, &GraphqlFileSource::graphql().into());
+    let indent_unit = match js_options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(js_options.indent_width().value() as usize),
+    };
+    let line_ending = js_options.line_ending().as_str();
+
+    let code = printed.as_code();
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = TextSize::from(0);
+    for template in graphql_templates(&parse.tree()) {
+        if template.has_substitutions || template.text.trim().is_empty() {
+            continue;
+        }
+        let document = parse_graphql(&template.text);
+        if document.has_errors() {
+            continue;
+        }
+        let formatted = format_node(graphql_options.clone(), &document.syntax())?
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+
+        let start = usize::from(template.range.start());
+        let line_start = code[..start].rfind('\n').map_or(0, |index| index + 1);
+        let indentation: String = code[line_start..start]
+            .chars()
+            .take_while(|c| matches!(c, ' ' | '\t'))
+            .collect();
+
+        output.push_str(&code[usize::from(last_end)..start]);
+        output.push_str(line_ending);
+        for line in formatted.as_code().lines() {
+            if !line.is_empty() {
+                output.push_str(&indentation);
+                output.push_str(&indent_unit);
+                output.push_str(line);
+            }
+            output.push_str(line_ending);
+        }
+        output.push_str(&indentation);
+        last_end = template.range.end();
+    }
+
+    if last_end == TextSize::from(0) {
+        return Ok(printed);
+    }
+    output.push_str(&code[usize::from(last_end)..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
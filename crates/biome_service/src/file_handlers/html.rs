@@ -1,21 +1,38 @@
-use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
-use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
+    RuleCategoriesBuilder, RuleCategory, RuleError,
+};
+use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
+use biome_formatter::{
+    AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+};
 use biome_fs::BiomePath;
-use biome_html_formatter::{format_node, HtmlFormatOptions};
-use biome_html_parser::parse_html_with_cache;
-use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_html_analyze::analyze;
+use biome_html_formatter::{
+    context::{BracketSameLine, CollapseEmptyElements, MaxInlineAttributes, WhitespaceSensitivity},
+    format_node, HtmlFormatOptions,
+};
+use biome_html_parser::{parse_html, parse_html_with_cache, HtmlParserOptions};
+use biome_html_syntax::{AnyHtmlElement, HtmlFileSource, HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache, TextRange};
+use std::borrow::Cow;
+use tracing::{debug_span, error, info, trace_span};
 
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
-    workspace::GetSyntaxTreeResult,
+    workspace::{
+        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    },
     WorkspaceError,
 };
 
+use super::vue::{element_name, format_style};
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    diagnostic_severity, is_diagnostic_error, AnalyzerCapabilities, AnalyzerVisitorBuilder,
+    Capabilities, CodeActionsParams, DebugCapabilities, DocumentFileSource, ExtensionHandler,
+    FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult, ParserCapabilities,
+    SearchCapabilities,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -25,17 +42,27 @@ pub struct HtmlFormatterSettings {
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
+    pub attribute_position: Option<AttributePosition>,
+    pub bracket_same_line: Option<BracketSameLine>,
+    pub whitespace_sensitivity: Option<WhitespaceSensitivity>,
+    pub max_inline_attributes: Option<MaxInlineAttributes>,
+    pub collapse_empty_elements: Option<CollapseEmptyElements>,
     pub enabled: Option<bool>,
 }
 
 impl Default for HtmlFormatterSettings {
     fn default() -> Self {
         Self {
-            enabled: Some(false),
+            enabled: Some(true),
             indent_style: Default::default(),
             indent_width: Default::default(),
             line_ending: Default::default(),
             line_width: Default::default(),
+            attribute_position: Default::default(),
+            bracket_same_line: Default::default(),
+            whitespace_sensitivity: Default::default(),
+            max_inline_attributes: Default::default(),
+            collapse_empty_elements: Default::default(),
         }
     }
 }
@@ -78,12 +105,33 @@ impl ServiceLanguage for HtmlLanguage {
             .and_then(|l| l.line_ending)
             .or(global.and_then(|g| g.line_ending))
             .unwrap_or_default();
+        let attribute_position = language
+            .and_then(|l| l.attribute_position)
+            .or(global.and_then(|g| g.attribute_position))
+            .unwrap_or_default();
+        let bracket_same_line = language
+            .and_then(|l| l.bracket_same_line)
+            .unwrap_or_default();
+        let whitespace_sensitivity = language
+            .and_then(|l| l.whitespace_sensitivity)
+            .unwrap_or_default();
+        let max_inline_attributes = language
+            .and_then(|l| l.max_inline_attributes)
+            .unwrap_or_default();
+        let collapse_empty_elements = language
+            .and_then(|l| l.collapse_empty_elements)
+            .unwrap_or_default();
 
         let options = HtmlFormatOptions::new(file_source.to_html_file_source().unwrap_or_default())
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
-            .with_line_ending(line_ending);
+            .with_line_ending(line_ending)
+            .with_attribute_position(attribute_position)
+            .with_bracket_same_line(bracket_same_line)
+            .with_whitespace_sensitivity(whitespace_sensitivity)
+            .with_max_inline_attributes(max_inline_attributes)
+            .with_collapse_empty_elements(collapse_empty_elements);
         if let Some(overrides) = overrides {
             overrides.to_override_html_format_options(path, options)
         } else {
@@ -93,14 +141,18 @@ impl ServiceLanguage for HtmlLanguage {
 
     fn resolve_analyzer_options(
         _global: Option<&crate::settings::Settings>,
-        _linter: Option<&crate::settings::LinterSettings>,
+        linter: Option<&crate::settings::LinterSettings>,
         _overrides: Option<&crate::settings::OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
         path: &biome_fs::BiomePath,
         _file_source: &super::DocumentFileSource,
     ) -> AnalyzerOptions {
         AnalyzerOptions {
-            configuration: AnalyzerConfiguration::default(),
+            configuration: AnalyzerConfiguration {
+                report_broad_suppressions: linter
+                    .is_some_and(|linter| linter.report_broad_suppressions),
+                ..Default::default()
+            },
             file_path: path.to_path_buf(),
         }
     }
@@ -119,10 +171,10 @@ impl ExtensionHandler for HtmlFileHandler {
                 debug_formatter_ir: Some(debug_formatter_ir),
             },
             analyzer: AnalyzerCapabilities {
-                lint: None,
-                code_actions: None,
+                lint: Some(lint),
+                code_actions: Some(code_actions),
                 rename: None,
-                fix_all: None,
+                fix_all: Some(fix_all),
                 organize_imports: None,
             },
             formatter: FormatterCapabilities {
@@ -142,7 +194,11 @@ fn parse(
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let parse = parse_html_with_cache(text, cache);
+    let options = file_source
+        .to_html_file_source()
+        .map(|file_source| HtmlParserOptions::from(&file_source))
+        .unwrap_or_default();
+    let parse = parse_html_with_cache(text, cache, options);
 
     ParseResult {
         any_parse: parse.into(),
@@ -193,3 +249,378 @@ fn format(
         Err(error) => Err(WorkspaceError::FormatError(error.into())),
     }
 }
+
+/// Formats the markup and the `<style>` blocks of a component, such as a Svelte file, whose
+/// script was already formatted, and returns the whole formatted component.
+///
+/// The markup between the `<script>` and `<style>` blocks is formatted with the HTML
+/// formatter, and the `<style>` blocks with the CSS formatter. The `<script>` blocks and the
+/// frontmatter of Astro components are kept as they are. The component is left untouched when
+/// its markup contains syntax errors.
+pub(super) fn format_component_markup(
+    biome_path: &BiomePath,
+    code: String,
+    file_source: HtmlFileSource,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let parse = parse_html(&code, HtmlParserOptions::from(&file_source));
+    if parse.has_errors() {
+        return Ok(Printed::new(code, None, Vec::new(), Vec::new()));
+    }
+
+    let mut edits = Vec::new();
+    // The range of the markup between the `<script>` and `<style>` blocks
+    let mut markup: Option<TextRange> = None;
+    for element in parse.tree().html() {
+        let name = match &element {
+            AnyHtmlElement::HtmlElement(element) => element_name(element),
+            _ => None,
+        };
+        match (name.as_deref(), &element) {
+            (Some("script"), _) => {
+                edits.extend(format_markup(
+                    biome_path,
+                    &code,
+                    markup.take(),
+                    file_source,
+                    settings,
+                )?);
+            }
+            (Some("style"), AnyHtmlElement::HtmlElement(element)) => {
+                edits.extend(format_markup(
+                    biome_path,
+                    &code,
+                    markup.take(),
+                    file_source,
+                    settings,
+                )?);
+                edits.extend(format_style(biome_path, element, settings)?);
+            }
+            _ => {
+                let range = element.syntax().text_trimmed_range();
+                markup = Some(markup.map_or(range, |markup| markup.cover(range)));
+            }
+        }
+    }
+    edits.extend(format_markup(
+        biome_path,
+        &code,
+        markup,
+        file_source,
+        settings,
+    )?);
+
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = 0;
+    for (range, replacement) in edits {
+        output.push_str(&code[last_end..usize::from(range.start())]);
+        output.push_str(&replacement);
+        last_end = usize::from(range.end());
+    }
+    output.push_str(&code[last_end..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+/// Returns the formatted markup in `range` of `code`, with the range it replaces.
+fn format_markup(
+    biome_path: &BiomePath,
+    code: &str,
+    range: Option<TextRange>,
+    file_source: HtmlFileSource,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Option<(TextRange, String)>, WorkspaceError> {
+    let Some(range) = range else {
+        return Ok(None);
+    };
+    let parse = parse_html(&code[range], HtmlParserOptions::from(&file_source));
+    if parse.has_errors() {
+        return Ok(None);
+    }
+    let options =
+        settings.format_options::<HtmlLanguage>(biome_path, &DocumentFileSource::Html(file_source));
+    let formatted = format_node(options, &parse.syntax())?
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    Ok(Some((range, formatted.as_code().trim_end().to_string())))
+}
+
+pub(crate) fn lint(params: LintParams) -> LintResults {
+    debug_span!("Linting HTML file", path =? params.path, language =? params.language).in_scope(
+        move || {
+            let workspace_settings = &params.workspace;
+            let analyzer_options =
+                workspace_settings.analyzer_options::<HtmlLanguage>(params.path, &params.language);
+            let tree = params.parse.tree();
+
+            let has_only_filter = !params.only.is_empty();
+            let rules = params
+                .workspace
+                .settings()
+                .as_ref()
+                .and_then(|settings| settings.as_linter_rules(params.path.as_path()));
+
+            let (enabled_rules, disabled_rules) =
+                AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_syntax_rules()
+                    .with_linter_rules(&params.only, &params.skip, params.path.as_path())
+                    .with_assists_rules(&params.only, &params.skip, params.path.as_path())
+                    .finish();
+            let mut diagnostics = params.parse.into_diagnostics();
+
+            let filter = AnalysisFilter {
+                categories: params.categories,
+                enabled_rules: Some(enabled_rules.as_slice()),
+                disabled_rules: &disabled_rules,
+                range: None,
+            };
+
+            // Do not report unused suppression comment diagnostics if:
+            // - it is a syntax-only analyzer pass, or
+            // - if a single rule is run.
+            let ignores_suppression_comment =
+                !filter.categories.contains(RuleCategory::Lint) || has_only_filter;
+
+            let mut diagnostic_count = diagnostics.len() as u32;
+            let mut errors = diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            info!("Analyze file {}", params.path.display());
+            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
+                if let Some(mut diagnostic) = signal.diagnostic() {
+                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                    if ignores_suppression_comment
+                        && diagnostic.category() == Some(category!("suppressions/unused"))
+                    {
+                        return ControlFlow::<Never>::Continue(());
+                    }
+
+                    diagnostic_count += 1;
+
+                    // We do now check if the severity of the diagnostics should be changed.
+                    // The configuration allows to change the severity of the diagnostics emitted by rules.
+                    let severity = diagnostic_severity(&diagnostic, rules.as_deref());
+
+                    if severity >= Severity::Error {
+                        errors += 1;
+                    }
+
+                    if diagnostic_count <= params.max_diagnostics {
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diagnostic.with_severity(severity);
+
+                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            });
+
+            diagnostics.extend(
+                analyze_diagnostics
+                    .into_iter()
+                    .map(biome_diagnostics::serde::Diagnostic::new)
+                    .collect::<Vec<_>>(),
+            );
+            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+
+            LintResults {
+                diagnostics,
+                errors,
+                skipped_diagnostics,
+            }
+        },
+    )
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        workspace,
+        path,
+        manifest: _,
+        language,
+        only,
+        skip,
+    } = params;
+    debug_span!("Code actions HTML", range =? range, path =? path).in_scope(move || {
+        let tree = parse.tree();
+        trace_span!("Parsed file", tree =? tree).in_scope(move || {
+            let Some(_) = language.to_html_file_source() else {
+                error!("Could not determine the file source of the file");
+                return PullActionsResult {
+                    actions: Vec::new(),
+                };
+            };
+
+            let analyzer_options = workspace.analyzer_options::<HtmlLanguage>(path, &language);
+            let mut actions = Vec::new();
+            let (enabled_rules, disabled_rules) =
+                AnalyzerVisitorBuilder::new(params.workspace.settings())
+                    .with_syntax_rules()
+                    .with_linter_rules(&only, &skip, params.path.as_path())
+                    .with_assists_rules(&only, &skip, params.path.as_path())
+                    .finish();
+
+            let filter = AnalysisFilter {
+                categories: RuleCategoriesBuilder::default()
+                    .with_syntax()
+                    .with_lint()
+                    .with_action()
+                    .build(),
+                enabled_rules: Some(enabled_rules.as_slice()),
+                disabled_rules: &disabled_rules,
+                range,
+            };
+
+            info!("HTML runs the analyzer");
+
+            analyze(&tree, filter, &analyzer_options, |signal| {
+                actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                    CodeAction {
+                        category: item.category.clone(),
+                        rule_name: item
+                            .rule_name
+                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                        suggestion: item.suggestion,
+                    }
+                }));
+
+                ControlFlow::<Never>::Continue(())
+            });
+
+            PullActionsResult { actions }
+        })
+    })
+}
+
+/// If applies all the safe fixes to the given syntax tree.
+pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let mut tree: HtmlRoot = params.parse.tree();
+    let Some(settings) = params.workspace.settings() else {
+        return Ok(FixFileResult {
+            actions: Vec::new(),
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: tree.syntax().to_string(),
+        });
+    };
+
+    // Compute final rules (taking `overrides` into account)
+    let rules = settings.as_linter_rules(params.biome_path.as_path());
+
+    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_syntax_rules()
+        .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
+        .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
+        .finish();
+
+    let filter = AnalysisFilter {
+        categories: RuleCategoriesBuilder::default()
+            .with_syntax()
+            .with_lint()
+            .build(),
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+    let analyzer_options = params
+        .workspace
+        .analyzer_options::<HtmlLanguage>(params.biome_path, &params.document_file_source);
+    loop {
+        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
+            let current_diagnostic = signal.diagnostic();
+
+            if let Some(diagnostic) = current_diagnostic.as_ref() {
+                if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                    errors += 1;
+                }
+            }
+
+            for action in signal.actions() {
+                match params.fix_file_mode {
+                    FixFileMode::ApplySuppressions => {
+                        if action.is_suppression() {
+                            return ControlFlow::Break(action);
+                        }
+                    }
+                    FixFileMode::SafeFixes => {
+                        // suppression actions should not be part of the fixes (safe or suggested)
+                        if action.is_suppression() {
+                            continue;
+                        }
+                        if action.applicability == Applicability::MaybeIncorrect {
+                            skipped_suggested_fixes += 1;
+                        }
+                        if action.applicability == Applicability::Always {
+                            errors = errors.saturating_sub(1);
+                            return ControlFlow::Break(action);
+                        }
+                    }
+                    FixFileMode::SafeAndUnsafeFixes => {
+                        if action.is_suppression() {
+                            continue;
+                        }
+                        if matches!(
+                            action.applicability,
+                            Applicability::Always | Applicability::MaybeIncorrect
+                        ) {
+                            errors = errors.saturating_sub(1);
+                            return ControlFlow::Break(action);
+                        }
+                    }
+                }
+            }
+
+            ControlFlow::Continue(())
+        });
+
+        match action {
+            Some(action) => {
+                if let (root, Some((range, _))) =
+                    action.mutation.commit_with_text_range_and_edit(true)
+                {
+                    tree = match HtmlRoot::cast(root) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(WorkspaceError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: action.rule_name.map(|(group, rule)| {
+                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
+                                    }),
+                                },
+                            ));
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: action
+                            .rule_name
+                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        range,
+                    });
+                }
+            }
+            None => {
+                let code = tree.syntax().to_string();
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                });
+            }
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use super::{
-    search, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
+    css_templates, graphql_templates, load_javascript_targets, search, sql_templates,
+    AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
+use crate::file_handlers::{diagnostic_severity, is_diagnostic_error, FixAllParams};
 use crate::settings::{LinterSettings, OverrideSettings, Settings};
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
@@ -28,26 +29,35 @@ use biome_configuration::javascript::JsxRuntime;
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
     AttributePosition, BracketSpacing, FormatError, IndentStyle, IndentWidth, LineEnding,
-    LineWidth, Printed, QuoteStyle,
+    LineWidth, MaxEmptyLines, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
+use biome_js_analyze::lint::nursery::no_wildcard_exports_from_tests::NoWildcardExportsFromTests;
+use biome_js_analyze::module_graph::ModuleGraph;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use biome_js_analyze::{analyze, analyze_with_inspect_matcher, ControlFlowGraph};
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, JsFormatOptions, QuoteProperties, Semicolons,
+    ArrowParentheses, BracketSameLine, CommentReflow, DecoratorPlacement, ImportExportWrap,
+    JsFormatOptions, JsxChildrenWhitespace, JsxMaxInlineAttributes, MaxInlineSpecifiers,
+    ObjectWrap, OperatorLinebreak, ParameterDecorators, QuoteProperties, Semicolons,
+    SingleAttributePerLine, TernaryStyle,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
 use biome_js_semantic::{semantic_model, SemanticModelOptions};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    AnyJsRoot, EmbeddingKind, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize,
+    TokenAtOffset,
 };
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
+use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::path::Path;
+use std::sync::Arc;
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -63,10 +73,22 @@ pub struct JsFormatterSettings {
     pub bracket_same_line: Option<BracketSameLine>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub enabled: Option<bool>,
     pub attribute_position: Option<AttributePosition>,
+    pub jsx_max_inline_attributes: Option<JsxMaxInlineAttributes>,
+    pub decorator_placement: Option<DecoratorPlacement>,
+    pub parameter_decorators: Option<ParameterDecorators>,
+    pub object_wrap: Option<ObjectWrap>,
+    pub operator_linebreak: Option<OperatorLinebreak>,
+    pub ternary_style: Option<TernaryStyle>,
+    pub import_export_wrap: Option<ImportExportWrap>,
+    pub max_inline_specifiers: Option<MaxInlineSpecifiers>,
+    pub comment_reflow: Option<CommentReflow>,
+    pub jsx_children_whitespace: Option<JsxChildrenWhitespace>,
+    pub single_attribute_per_line: Option<SingleAttributePerLine>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -90,11 +112,16 @@ pub struct JsOrganizeImportsSettings {}
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsEnvironmentSettings {
     pub jsx_runtime: JsxRuntime,
+    /// The browserslist queries of the targeted environments, when they are configured
+    pub target: Option<Vec<String>>,
 }
 
 impl From<JsxRuntime> for JsEnvironmentSettings {
     fn from(jsx_runtime: JsxRuntime) -> Self {
-        Self { jsx_runtime }
+        Self {
+            jsx_runtime,
+            target: None,
+        }
     }
 }
 
@@ -141,6 +168,12 @@ impl ServiceLanguage for JsLanguage {
                 .or(global.and_then(|g| g.line_width))
                 .unwrap_or_default(),
         )
+        .with_max_empty_lines(
+            language
+                .and_then(|l| l.max_empty_lines)
+                .or(global.and_then(|g| g.max_empty_lines))
+                .unwrap_or_default(),
+        )
         .with_line_ending(
             language
                 .and_then(|l| l.line_ending)
@@ -177,6 +210,49 @@ impl ServiceLanguage for JsLanguage {
                 .and_then(|l| l.attribute_position)
                 .or(global.and_then(|g| g.attribute_position))
                 .unwrap_or_default(),
+        )
+        .with_jsx_max_inline_attributes(
+            language
+                .and_then(|l| l.jsx_max_inline_attributes)
+                .unwrap_or_default(),
+        )
+        .with_decorator_placement(
+            language
+                .and_then(|l| l.decorator_placement)
+                .unwrap_or_default(),
+        )
+        .with_parameter_decorators(
+            language
+                .and_then(|l| l.parameter_decorators)
+                .unwrap_or_default(),
+        )
+        .with_object_wrap(language.and_then(|l| l.object_wrap).unwrap_or_default())
+        .with_operator_linebreak(
+            language
+                .and_then(|l| l.operator_linebreak)
+                .unwrap_or_default(),
+        )
+        .with_ternary_style(language.and_then(|l| l.ternary_style).unwrap_or_default())
+        .with_import_export_wrap(
+            language
+                .and_then(|l| l.import_export_wrap)
+                .unwrap_or_default(),
+        )
+        .with_max_inline_specifiers(
+            language
+                .and_then(|l| l.max_inline_specifiers)
+                .unwrap_or_default(),
+        )
+        .with_comment_reflow(language.and_then(|l| l.comment_reflow).unwrap_or_default())
+        .with_jsx_children_whitespace(
+            language
+                .and_then(|l| l.jsx_children_whitespace)
+                .unwrap_or_default(),
+        )
+        .with_single_attribute_per_line(
+            language
+                .and_then(|l| l.single_attribute_per_line)
+                .unwrap_or_default(),
         );
 
         if let Some(overrides) = overrides {
@@ -188,7 +264,7 @@ impl ServiceLanguage for JsLanguage {
 
     fn resolve_analyzer_options(
         global: Option<&Settings>,
-        _linter: Option<&LinterSettings>,
+        linter: Option<&LinterSettings>,
         overrides: Option<&OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
         path: &BiomePath,
@@ -275,6 +351,8 @@ impl ServiceLanguage for JsLanguage {
             globals,
             preferred_quote,
             jsx_runtime,
+            report_broad_suppressions: linter
+                .is_some_and(|linter| linter.report_broad_suppressions),
         };
 
         AnalyzerOptions {
@@ -315,14 +393,12 @@ impl ExtensionHandler for JsFileHandler {
     }
 }
 
-fn parse(
+/// Resolves the options of the parser for the given file
+pub(crate) fn parser_options(
     biome_path: &BiomePath,
-    file_source: DocumentFileSource,
-    text: &str,
     settings: Option<&Settings>,
-    cache: &mut NodeCache,
-) -> ParseResult {
-    let mut options = JsParserOptions {
+) -> JsParserOptions {
+    let options = JsParserOptions {
         grit_metavariables: false,
         parse_class_parameter_decorators: settings.is_some_and(|settings| {
             settings
@@ -332,12 +408,22 @@ fn parse(
                 .parse_class_parameter_decorators
         }),
     };
-    if let Some(settings) = settings {
-        options = settings
+    match settings {
+        Some(settings) => settings
             .override_settings
-            .to_override_js_parser_options(biome_path, options);
+            .to_override_js_parser_options(biome_path, options),
+        None => options,
     }
+}
 
+fn parse(
+    biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let options = parser_options(biome_path, settings);
     let file_source = file_source.to_js_file_source().unwrap_or_default();
     let parse = biome_js_parser::parse_js_with_cache(text, file_source, options, cache);
     ParseResult {
@@ -393,6 +479,8 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        None,
+        None,
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -456,6 +544,28 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             let ignores_suppression_comment =
                 !filter.categories.contains(RuleCategory::Lint) || !params.only.is_empty();
 
+            // GraphQL documents embedded in `gql` and `graphql` tagged templates, and CSS
+            // embedded in `styled`, `css` and `createGlobalStyle` tagged templates
+            let mut embedded = LintResults {
+                errors: 0,
+                diagnostics: Vec::new(),
+                skipped_diagnostics: 0,
+            };
+            if let Some(settings) = params.workspace.settings() {
+                let mut templates = Vec::new();
+                if !settings.graphql_linter_disabled() {
+                    templates.push(graphql_templates::lint(&params, &tree));
+                }
+                if !settings.css_linter_disabled() {
+                    templates.push(css_templates::lint(&params, &tree));
+                }
+                for results in templates {
+                    embedded.errors += results.errors;
+                    embedded.diagnostics.extend(results.diagnostics);
+                    embedded.skipped_diagnostics += results.skipped_diagnostics;
+                }
+            }
+
             let mut diagnostics = params.parse.into_diagnostics();
             let mut diagnostic_count = diagnostics.len() as u32;
             let mut errors = diagnostics
@@ -463,6 +573,17 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
 
+            let browser_targets = load_javascript_targets(
+                params.path,
+                params.workspace.settings(),
+                params.manifest.as_deref(),
+            );
+            let module_graph = module_graph(params.workspace, &filter);
+            if let Some(module_graph) = &module_graph {
+                // The graph is refreshed with the content of the document, which can be edited
+                module_graph.insert(params.path.as_path(), &tree);
+            }
+
             info!("Analyze file {}", params.path.display());
             let (_, analyze_diagnostics) = analyze(
                 &tree,
@@ -470,6 +591,8 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 analyzer_options,
                 file_source,
                 params.manifest,
+                browser_targets,
+                module_graph,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -482,18 +605,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
 
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
-                        let severity = diagnostic
-                            .category()
-                            .filter(|category| category.name().starts_with("lint/"))
-                            .map_or_else(
-                                || diagnostic.severity(),
-                                |category| {
-                                    rules
-                                        .as_ref()
-                                        .and_then(|rules| rules.get_severity_from_code(category))
-                                        .unwrap_or(Severity::Warning)
-                                },
-                            );
+                        let severity = diagnostic_severity(&diagnostic, rules.as_deref());
 
                         if severity >= Severity::Error {
                             errors += 1;
@@ -523,11 +635,12 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                     .collect::<Vec<_>>(),
             );
             let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+            diagnostics.extend(embedded.diagnostics);
 
             LintResults {
                 diagnostics,
-                errors,
-                skipped_diagnostics,
+                errors: errors + embedded.errors,
+                skipped_diagnostics: skipped_diagnostics + embedded.skipped_diagnostics,
             }
         })
 }
@@ -574,6 +687,10 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 };
             };
 
+            let browser_targets =
+                load_javascript_targets(path, workspace.settings(), manifest.as_deref());
+            let module_graph = module_graph(workspace, &filter);
+
             trace!("Javascript runs the analyzer");
             analyze(
                 &tree,
@@ -581,6 +698,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                browser_targets,
+                module_graph,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -643,6 +762,12 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let analyzer_options = params
         .workspace
         .analyzer_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+    let browser_targets = load_javascript_targets(
+        params.biome_path,
+        Some(settings),
+        params.manifest.as_deref(),
+    );
+    let module_graph = module_graph(&params.workspace, &filter);
     loop {
         let (action, _) = analyze(
             &tree,
@@ -650,6 +775,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            browser_targets.clone(),
+            module_graph.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -762,7 +889,13 @@ pub(crate) fn format(
     info!("Format file {}", biome_path.display());
     let formatted = format_node(options, &tree)?;
     match formatted.print() {
-        Ok(printed) => Ok(printed),
+        Ok(printed) => {
+            let printed =
+                graphql_templates::format(biome_path, document_file_source, printed, &settings)?;
+            let printed =
+                css_templates::format(biome_path, document_file_source, printed, &settings)?;
+            sql_templates::format(biome_path, document_file_source, printed, &settings)
+        }
         Err(error) => {
             error!("The file {} couldn't be formatted", biome_path.display());
             Err(WorkspaceError::FormatError(error.into()))
@@ -852,7 +985,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.as_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        other_files: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -879,6 +1016,8 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        None,
+        None,
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -914,3 +1053,52 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         })
     }
 }
+
+/// Returns the module graph of the project, when the analysis runs a rule that needs it.
+///
+/// The graph isn't built when no such rule runs, because it requires parsing all the modules of
+/// the project.
+fn module_graph(
+    workspace: &WorkspaceSettingsHandle,
+    filter: &AnalysisFilter,
+) -> Option<Arc<ModuleGraph>> {
+    if filter.match_rule::<NoWildcardExportsFromTests>() {
+        workspace.module_graph()
+    } else {
+        None
+    }
+}
+
+/// Builds the module graph of the JavaScript and TypeScript files of the project folder `root`.
+///
+/// The hidden folders, the `node_modules` folders, and the files ignored by the VCS or by the
+/// configuration aren't scanned.
+pub(crate) fn scan_modules(root: &Path, settings: &Settings) -> ModuleGraph {
+    let graph = ModuleGraph::new(root);
+    if root.as_os_str().is_empty() {
+        return graph;
+    }
+
+    let walker = WalkBuilder::new(root)
+        .filter_entry(|entry| entry.file_name() != "node_modules")
+        .build();
+    for entry in walker.flatten() {
+        let path = entry.path();
+        let Ok(file_source) = JsFileSource::try_from(path) else {
+            continue;
+        };
+        // The scripts of the Astro, Vue and Svelte files can't be parsed without their template
+        if *file_source.as_embedding_kind() != EmbeddingKind::None
+            || settings.files.ignored_files.matches_path(path)
+        {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let options = parser_options(&BiomePath::new(path), Some(settings));
+        let parse = biome_js_parser::parse(&content, file_source, options);
+        graph.insert(path, &parse.tree());
+    }
+    graph
+}
@@ -1,9 +1,10 @@
 use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::path::Path;
 
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, ParseResult, SearchCapabilities,
+    diagnostic_severity, is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams,
+    DocumentFileSource, ExtensionHandler, ParseResult, SearchCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -25,10 +26,13 @@ use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
     RuleCategoriesBuilder, RuleCategory, RuleError,
 };
+use biome_configuration::json::JsonFileParser;
 use biome_configuration::PartialConfiguration;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
-use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_formatter::{
+    FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines, Printed,
+};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
 use biome_json_analyze::analyze;
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
@@ -38,6 +42,7 @@ use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use indexmap::IndexMap;
 use tracing::{debug_span, error, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -45,6 +50,7 @@ use tracing::{debug_span, error, trace, trace_span};
 pub struct JsonFormatterSettings {
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub trailing_commas: Option<TrailingCommas>,
@@ -56,6 +62,29 @@ pub struct JsonFormatterSettings {
 pub struct JsonParserSettings {
     pub allow_comments: Option<bool>,
     pub allow_trailing_commas: Option<bool>,
+    pub allow_json5: Option<bool>,
+    /// The files registered in `json.parser.wellKnownFiles`, keyed by their file name
+    pub well_known_files: IndexMap<String, JsonFileParser>,
+}
+
+impl JsonParserSettings {
+    /// Returns the file source of the file, if its name is registered as a well-known file
+    pub fn well_known_file_source(&self, path: &Path) -> Option<JsonFileSource> {
+        let file_name = path.file_name()?.to_str()?;
+        self.well_known_files.get(file_name).map(|file| {
+            let mut file_source = JsonFileSource::json();
+            if file.allow_comments {
+                file_source = file_source.with_allow_comments();
+            }
+            if file.allow_trailing_commas {
+                file_source = file_source.with_allow_trailing_commas();
+            }
+            if file.allow_json5 {
+                file_source = JsonFileSource::json5();
+            }
+            file_source
+        })
+    }
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -91,6 +120,10 @@ impl ServiceLanguage for JsonLanguage {
             .and_then(|l| l.line_width)
             .or(global.and_then(|g| g.line_width))
             .unwrap_or_default();
+        let max_empty_lines = language
+            .and_then(|l| l.max_empty_lines)
+            .or(global.and_then(|g| g.max_empty_lines))
+            .unwrap_or_default();
         let indent_width = language
             .and_then(|l| l.indent_width)
             .or(global.and_then(|g| g.indent_width))
@@ -116,6 +149,7 @@ impl ServiceLanguage for JsonLanguage {
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
+            .with_max_empty_lines(max_empty_lines)
             .with_trailing_commas(trailing_commas);
 
         if let Some(overrides) = overrides {
@@ -127,7 +161,7 @@ impl ServiceLanguage for JsonLanguage {
 
     fn resolve_analyzer_options(
         global: Option<&Settings>,
-        _linter: Option<&LinterSettings>,
+        linter: Option<&LinterSettings>,
         _overrides: Option<&OverrideSettings>,
         _language: Option<&Self::LinterSettings>,
         path: &BiomePath,
@@ -140,6 +174,8 @@ impl ServiceLanguage for JsonLanguage {
             globals: vec![],
             preferred_quote: PreferredQuote::Double,
             jsx_runtime: Default::default(),
+            report_broad_suppressions: linter
+                .is_some_and(|linter| linter.report_broad_suppressions),
         };
         AnalyzerOptions {
             configuration,
@@ -191,8 +227,13 @@ fn parse(
     } else {
         let parser = settings.map(|s| &s.languages.json.parser);
         let overrides = settings.map(|s| &s.override_settings);
-        let optional_json_file_source = file_source.to_json_file_source();
-        let options = JsonParserOptions {
+        let well_known_file_source = parser.and_then(|p| p.well_known_file_source(biome_path));
+        // The options of a file registered in `json.parser.wellKnownFiles` take precedence
+        // over the parser options of all the JSON files
+        let parser = parser.filter(|_| well_known_file_source.is_none());
+        let optional_json_file_source =
+            well_known_file_source.or_else(|| file_source.to_json_file_source());
+        let mut options = JsonParserOptions {
             allow_comments: parser.and_then(|p| p.allow_comments).map_or_else(
                 || optional_json_file_source.map_or(false, |x| x.allow_comments()),
                 |value| value,
@@ -201,7 +242,15 @@ fn parse(
                 || optional_json_file_source.map_or(false, |x| x.allow_trailing_commas()),
                 |value| value,
             ),
+            allow_json5: false,
         };
+        let allow_json5 = parser.and_then(|p| p.allow_json5).map_or_else(
+            || optional_json_file_source.map_or(false, |x| x.allow_json5()),
+            |value| value,
+        );
+        if allow_json5 {
+            options = options.with_allow_json5();
+        }
         if let Some(overrides) = overrides {
             overrides.to_override_json_parser_options(biome_path, options)
         } else {
@@ -395,18 +444,7 @@ fn lint(params: LintParams) -> LintResults {
 
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
-                        let severity = diagnostic
-                            .category()
-                            .filter(|category| category.name().starts_with("lint/"))
-                            .map_or_else(
-                                || diagnostic.severity(),
-                                |category| {
-                                    rules
-                                        .as_ref()
-                                        .and_then(|rules| rules.get_severity_from_code(category))
-                                        .unwrap_or(Severity::Warning)
-                                },
-                            );
+                        let severity = diagnostic_severity(&diagnostic, rules.as_deref());
 
                         if severity <= Severity::Error {
                             errors += 1;
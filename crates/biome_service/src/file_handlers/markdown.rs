@@ -0,0 +1,294 @@
+use crate::{
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult},
+    WorkspaceError,
+};
+use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_markdown_formatter::{context::MarkdownFormatOptions, format_node};
+use biome_markdown_parser::{parse_markdown, parse_markdown_with_cache, MarkdownParserOptions};
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache};
+use tracing::debug_span;
+
+use super::{
+    markdown_code_blocks, mdx_blocks, AnalyzerCapabilities, Capabilities, CodeActionsParams,
+    DebugCapabilities, DocumentFileSource, ExtensionHandler, FixAllParams, FormatterCapabilities,
+    LintParams, LintResults, ParseResult, ParserCapabilities, SearchCapabilities,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub indent_style: Option<IndentStyle>,
+    pub enabled: Option<bool>,
+}
+
+impl Default for MarkdownFormatterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: Some(true),
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+        }
+    }
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.markdown
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &biome_fs::BiomePath,
+        file_source: &super::DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let line_width = language
+            .and_then(|l| l.line_width)
+            .or(global.and_then(|g| g.line_width))
+            .unwrap_or_default();
+        let indent_style = language
+            .and_then(|l| l.indent_style)
+            .or(global.and_then(|g| g.indent_style))
+            .unwrap_or_default();
+        let indent_width = language
+            .and_then(|l| l.indent_width)
+            .or(global.and_then(|g| g.indent_width))
+            .unwrap_or_default();
+
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+
+        let options =
+            MarkdownFormatOptions::new(file_source.to_markdown_file_source().unwrap_or_default())
+                .with_indent_style(indent_style)
+                .with_indent_width(indent_width)
+                .with_line_width(line_width)
+                .with_line_ending(line_ending);
+        if let Some(overrides) = overrides {
+            overrides.to_override_markdown_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&crate::settings::Settings>,
+        _linter: Option<&crate::settings::LinterSettings>,
+        _overrides: Option<&crate::settings::OverrideSettings>,
+        _language: Option<&Self::LinterSettings>,
+        path: &biome_fs::BiomePath,
+        _file_source: &super::DocumentFileSource,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions {
+            configuration: AnalyzerConfiguration::default(),
+            file_path: path.to_path_buf(),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                rename: None,
+                fix_all: Some(fix_all),
+                organize_imports: None,
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities { search: None },
+        }
+    }
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: Option<&Settings>,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let options =
+        MarkdownParserOptions::from(&file_source.to_markdown_file_source().unwrap_or_default());
+    let parse = parse_markdown_with_cache(text, cache, options);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    tracing::debug!("Format with the following options: \n{}", options);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    match formatted.print() {
+        Ok(printed) => {
+            let printed =
+                markdown_code_blocks::format(biome_path, document_file_source, printed, &settings)?;
+            if document_file_source
+                .to_markdown_file_source()
+                .is_some_and(|file_source| file_source.is_mdx())
+            {
+                mdx_blocks::format(biome_path, document_file_source, printed, &settings)
+            } else {
+                Ok(printed)
+            }
+        }
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Lints the JavaScript code blocks of the document, and the ESM and JSX blocks of an MDX
+/// document
+fn lint(params: LintParams) -> LintResults {
+    debug_span!("Linting Markdown file", path =? params.path, language =? params.language).in_scope(
+        move || {
+            let tree: MdDocument = params.parse.tree();
+            let linter_disabled = params
+                .workspace
+                .settings()
+                .is_none_or(|settings| settings.javascript_linter_disabled());
+            let mut results = if linter_disabled {
+                LintResults {
+                    errors: 0,
+                    diagnostics: Vec::new(),
+                    skipped_diagnostics: 0,
+                }
+            } else {
+                let mut results = markdown_code_blocks::lint(&params, &tree);
+                if params
+                    .language
+                    .to_markdown_file_source()
+                    .is_some_and(|file_source| file_source.is_mdx())
+                {
+                    let mdx_results = mdx_blocks::lint(&params, &tree);
+                    results.errors += mdx_results.errors;
+                    results.skipped_diagnostics += mdx_results.skipped_diagnostics;
+                    results.diagnostics.extend(mdx_results.diagnostics);
+                }
+                results
+            };
+
+            let mut diagnostics = params.parse.into_diagnostics();
+            results.errors += diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity() <= Severity::Error)
+                .count();
+            diagnostics.append(&mut results.diagnostics);
+            results.diagnostics = diagnostics;
+            results
+        },
+    )
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let tree: MdDocument = params.parse.tree();
+    markdown_code_blocks::code_actions(params, &tree)
+}
+
+/// Applies the fixes of the JavaScript code blocks of the document
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let tree: MdDocument = params.parse.tree();
+    let biome_path = params.biome_path;
+    let document_file_source = params.document_file_source;
+    let should_format = params.should_format;
+    let settings = params.workspace.clone();
+    let linter_disabled = settings
+        .settings()
+        .is_none_or(|settings| settings.javascript_linter_disabled());
+    let mut result = if linter_disabled {
+        FixFileResult {
+            actions: Vec::new(),
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: tree.syntax().to_string(),
+        }
+    } else {
+        markdown_code_blocks::fix_all(params, &tree)?
+    };
+
+    if should_format {
+        let options = MarkdownParserOptions::from(
+            &document_file_source
+                .to_markdown_file_source()
+                .unwrap_or_default(),
+        );
+        let parse = parse_markdown(&result.code, options);
+        result.code =
+            format(biome_path, &document_file_source, parse.into(), settings)?.into_code();
+    }
+    Ok(result)
+}
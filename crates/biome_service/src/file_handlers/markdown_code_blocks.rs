@@ -0,0 +1,306 @@
+//! Support for the JavaScript and TypeScript code blocks of Markdown documents:
+//!
+//! ````md
+//! ```ts
+//! const answer: number = 42;
+//! ```
+//! ````
+//!
+//! The content of the fenced code blocks whose info string names JavaScript, JSX or TypeScript
+//! is parsed with the JavaScript parser, analyzed by the JavaScript rules and formatted by the
+//! JavaScript formatter. The ranges of the diagnostics and of the code actions are mapped back
+//! to the Markdown document.
+
+use super::{
+    javascript, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, LintResults,
+};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::workspace::{FixAction, FixFileResult, PullActionsResult};
+use crate::WorkspaceError;
+use biome_formatter::{FormatOptions, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_js_formatter::format_node;
+use biome_js_syntax::{JsFileSource, JsLanguage, TextRange, TextSize};
+use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
+use biome_markdown_syntax::{MarkdownLanguage, MdDocument, MdFencedCodeBlock};
+use biome_rowan::{AstNode, AstNodeList};
+use biome_string_case::StrLikeExtension;
+use biome_text_edit::TextEdit;
+
+/// A JavaScript or TypeScript code block of a Markdown document
+struct JsCodeBlock {
+    /// The code between the fences, starting with the line break that ends the opening fence.
+    /// The lines keep the indentation of the container of the block, such as a list item.
+    text: String,
+    /// The range of the code in the document
+    range: TextRange,
+    file_source: JsFileSource,
+}
+
+impl JsCodeBlock {
+    fn from_block(block: &MdFencedCodeBlock) -> Option<Self> {
+        let info = block.info_token()?;
+        let language = info.text_trimmed().split_whitespace().next()?;
+        let file_source = js_file_source(language)?;
+        let content = block.content();
+        if content.is_empty() {
+            return None;
+        }
+        Some(Self {
+            text: content.syntax().to_string(),
+            range: content.syntax().text_range(),
+            file_source,
+        })
+    }
+
+    fn document_file_source(&self) -> DocumentFileSource {
+        self.file_source.into()
+    }
+}
+
+/// Returns the file source of the language named by the info string of a code block, such as
+/// `js` or `typescript`.
+fn js_file_source(language: &str) -> Option<JsFileSource> {
+    match language.to_ascii_lowercase_cow().as_ref() {
+        "js" | "javascript" | "jsx" | "mjs" => Some(JsFileSource::jsx()),
+        "cjs" => Some(JsFileSource::js_script()),
+        "ts" | "typescript" => Some(JsFileSource::ts()),
+        "mts" | "cts" => Some(JsFileSource::ts_restricted()),
+        "tsx" => Some(JsFileSource::tsx()),
+        _ => None,
+    }
+}
+
+/// Returns the JavaScript and TypeScript code blocks of `root`, in the order of the document
+fn js_code_blocks(root: &MdDocument) -> impl Iterator<Item = JsCodeBlock> {
+    root.syntax()
+        .descendants()
+        .filter_map(MdFencedCodeBlock::cast)
+        .filter_map(|block| JsCodeBlock::from_block(&block))
+}
+
+/// Analyzes the code blocks of `root` with the JavaScript rules.
+///
+/// The ranges of the diagnostics are relative to the Markdown document.
+pub(crate) fn lint(params: &LintParams, root: &MdDocument) -> LintResults {
+    let mut results = LintResults {
+        errors: 0,
+        diagnostics: Vec::new(),
+        skipped_diagnostics: 0,
+    };
+    let parser_options = javascript::parser_options(params.path, params.workspace.settings());
+    for block in js_code_blocks(root) {
+        let parse = biome_js_parser::parse(&block.text, block.file_source, parser_options.clone());
+        let block_results = javascript::lint(LintParams {
+            parse: parse.into(),
+            workspace: params.workspace,
+            language: block.document_file_source(),
+            max_diagnostics: params
+                .max_diagnostics
+                .saturating_sub(results.diagnostics.len() as u32),
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: params.manifest.clone(),
+        });
+        results.errors += block_results.errors;
+        results.skipped_diagnostics += block_results.skipped_diagnostics;
+        results.diagnostics.extend(
+            block_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(block.range.start())),
+        );
+    }
+    results
+}
+
+/// Returns the code actions of the code blocks of `root` that intersect the range of `params`.
+///
+/// The edits of the actions apply to the whole Markdown document.
+pub(crate) fn code_actions(params: CodeActionsParams, root: &MdDocument) -> PullActionsResult {
+    let document = root.syntax().to_string();
+    let parser_options = javascript::parser_options(params.path, params.workspace.settings());
+    let mut actions = Vec::new();
+    for block in js_code_blocks(root) {
+        let range = match params.range {
+            Some(range) => match range.intersect(block.range) {
+                Some(range) => Some(range - block.range.start()),
+                None => continue,
+            },
+            None => None,
+        };
+        let parse = biome_js_parser::parse(&block.text, block.file_source, parser_options.clone());
+        let block_actions = javascript::code_actions(CodeActionsParams {
+            parse: parse.into(),
+            range,
+            workspace: params.workspace,
+            path: params.path,
+            manifest: params.manifest.clone(),
+            language: block.document_file_source(),
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+        });
+        actions.extend(block_actions.actions.into_iter().map(|mut action| {
+            let offset = block.range.start();
+            action.suggestion.span += offset;
+            for label in &mut action.suggestion.labels {
+                *label += offset;
+            }
+            action.suggestion.suggestion =
+                document_edit(&document, &block, &action.suggestion.suggestion);
+            action
+        }));
+    }
+    PullActionsResult { actions }
+}
+
+/// Returns the edit of the Markdown document that applies `edit` to the code of `block`
+fn document_edit(document: &str, block: &JsCodeBlock, edit: &TextEdit) -> TextEdit {
+    let mut builder = TextEdit::builder();
+    let start = usize::from(block.range.start());
+    let end = usize::from(block.range.end());
+    if start > 0 {
+        builder.equal(&document[..start]);
+    }
+    builder.with_unicode_words_diff(&block.text, &edit.new_string(&block.text));
+    if end < document.len() {
+        builder.equal(&document[end..]);
+    }
+    builder.finish()
+}
+
+/// Applies the fixes of the JavaScript rules to the code blocks of `root`.
+pub(crate) fn fix_all(
+    params: FixAllParams,
+    root: &MdDocument,
+) -> Result<FixFileResult, WorkspaceError> {
+    let document = root.syntax().to_string();
+    let parser_options = javascript::parser_options(params.biome_path, params.workspace.settings());
+    let mut code = String::with_capacity(document.len());
+    let mut actions = Vec::new();
+    let mut errors = 0;
+    let mut skipped_suggested_fixes = 0;
+    let mut last_end = 0;
+    for block in js_code_blocks(root) {
+        let parse = biome_js_parser::parse(&block.text, block.file_source, parser_options.clone());
+        let block_result = javascript::fix_all(FixAllParams {
+            parse: parse.into(),
+            fix_file_mode: params.fix_file_mode,
+            workspace: params.workspace.clone(),
+            // The code blocks are formatted with the document
+            should_format: false,
+            biome_path: params.biome_path,
+            manifest: params.manifest.clone(),
+            document_file_source: block.document_file_source(),
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            rule_categories: params.rule_categories,
+        })?;
+
+        code.push_str(&document[last_end..usize::from(block.range.start())]);
+        let offset = TextSize::from(code.len() as u32);
+        code.push_str(&block_result.code);
+        actions.extend(block_result.actions.into_iter().map(|action| FixAction {
+            range: action.range + offset,
+            ..action
+        }));
+        errors += block_result.errors;
+        skipped_suggested_fixes += block_result.skipped_suggested_fixes;
+        last_end = usize::from(block.range.end());
+    }
+    code.push_str(&document[last_end..]);
+
+    Ok(FixFileResult {
+        code,
+        actions,
+        errors,
+        skipped_suggested_fixes,
+    })
+}
+
+/// Formats the code blocks of a Markdown document that was just formatted.
+///
+/// The code blocks that contain syntax errors are left untouched. The formatted code keeps the
+/// indentation of the opening fence.
+pub(crate) fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    printed: Printed,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let Some(workspace_settings) = settings
+        .settings()
+        .filter(|settings| !settings.javascript_formatter_disabled())
+    else {
+        return Ok(printed);
+    };
+
+    let code = printed.as_code();
+    let file_source = document_file_source
+        .to_markdown_file_source()
+        .unwrap_or_default();
+    let document = parse_markdown(code, MarkdownParserOptions::from(&file_source));
+    let markdown_options =
+        settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+    let line_ending = markdown_options.line_ending().as_str();
+    let parser_options = javascript::parser_options(biome_path, Some(workspace_settings));
+
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = 0;
+    for block in js_code_blocks(&document.tree()) {
+        let start = usize::from(block.range.start());
+        let line_start = code[..start].rfind('\n').map_or(0, |index| index + 1);
+        let indentation: String = code[line_start..start]
+            .chars()
+            .take_while(|c| matches!(c, ' ' | '\t'))
+            .collect();
+
+        // The first line is the end of the line of the opening fence
+        let source = block
+            .text
+            .lines()
+            .skip(1)
+            .map(|line| {
+                line.strip_prefix(indentation.as_str())
+                    .unwrap_or_else(|| line.trim_start())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let parse = biome_js_parser::parse(&source, block.file_source, parser_options.clone());
+        if parse.has_errors() {
+            continue;
+        }
+
+        let js_options =
+            settings.format_options::<JsLanguage>(biome_path, &block.document_file_source());
+        let line_width = js_options
+            .line_width()
+            .value()
+            .saturating_sub(indentation.len() as u16);
+        let js_options = match LineWidth::try_from(line_width) {
+            Ok(line_width) => js_options.with_line_width(line_width),
+            Err(_) => js_options,
+        };
+        let formatted = format_node(js_options, &parse.syntax())?
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+
+        output.push_str(&code[last_end..start]);
+        for line in formatted.as_code().lines() {
+            output.push_str(line_ending);
+            if !line.is_empty() {
+                output.push_str(&indentation);
+                output.push_str(line);
+            }
+        }
+        last_end = usize::from(block.range.end());
+    }
+
+    if last_end == 0 {
+        return Ok(printed);
+    }
+    output.push_str(&code[last_end..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
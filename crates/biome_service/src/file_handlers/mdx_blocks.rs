@@ -0,0 +1,262 @@
+//! Support for the ESM statements and the JSX blocks of MDX documents:
+//!
+//! ```mdx
+//! import { Chart } from "./chart.js";
+//!
+//! # Sales
+//!
+//! <Chart data={sales} />
+//! ```
+//!
+//! The blocks are analyzed together by the JavaScript rules, as a single JSX module where each
+//! JSX block is wrapped in a fragment, so the imports used by the JSX aren't reported as unused.
+//! The blocks that contain syntax errors, such as a JSX element whose children are Markdown, are
+//! skipped. The ranges of the diagnostics are mapped back to the MDX document.
+//!
+//! Each block is formatted by the JavaScript formatter. The JSX blocks that contain text aren't
+//! formatted, because their text is Markdown.
+
+use super::{javascript, DocumentFileSource, LintParams, LintResults};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::WorkspaceError;
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{FormatOptions, IndentStyle, Printed};
+use biome_fs::BiomePath;
+use biome_js_formatter::format_node;
+use biome_js_parser::{JsParserOptions, Parse};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, JsLanguage, JsxText, TextRange, TextSize};
+use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
+use biome_markdown_syntax::{MarkdownSyntaxNode, MdDocument, MdMdxEsmBlock, MdMdxFlowBlock};
+use biome_rowan::AstNode;
+
+/// The fragment that wraps a JSX block, so its elements and expressions form an expression
+const FRAGMENT_PREFIX: &str = "<>";
+const FRAGMENT_SUFFIX: &str = "</>";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MdxBlockKind {
+    /// The `import` and `export` statements
+    Esm,
+    /// The JSX elements and the expressions
+    Flow,
+}
+
+/// An ESM block or a JSX block of an MDX document
+struct MdxBlock {
+    kind: MdxBlockKind,
+    text: String,
+    /// The range of the block in the document
+    range: TextRange,
+}
+
+impl MdxBlock {
+    fn from_node(node: &MarkdownSyntaxNode) -> Option<Self> {
+        let (kind, content) = if let Some(block) = MdMdxEsmBlock::cast_ref(node) {
+            (MdxBlockKind::Esm, block.content())
+        } else {
+            let block = MdMdxFlowBlock::cast_ref(node)?;
+            (MdxBlockKind::Flow, block.content())
+        };
+        Some(Self {
+            kind,
+            text: content.syntax().text_trimmed().to_string(),
+            range: content.syntax().text_trimmed_range(),
+        })
+    }
+
+    /// The length of the text that precedes the block in its JavaScript source
+    fn prefix_len(&self) -> TextSize {
+        match self.kind {
+            MdxBlockKind::Esm => TextSize::from(0),
+            MdxBlockKind::Flow => TextSize::of(FRAGMENT_PREFIX),
+        }
+    }
+
+    /// Returns the JavaScript source of the block
+    fn source(&self) -> String {
+        match self.kind {
+            MdxBlockKind::Esm => self.text.clone(),
+            MdxBlockKind::Flow => format!("{FRAGMENT_PREFIX}{}{FRAGMENT_SUFFIX}", self.text),
+        }
+    }
+
+    fn parse(&self, options: JsParserOptions) -> Parse<AnyJsRoot> {
+        biome_js_parser::parse(&self.source(), JsFileSource::jsx(), options)
+    }
+}
+
+/// Returns the ESM blocks and the JSX blocks of `root`, in the order of the document
+fn mdx_blocks(root: &MdDocument) -> impl Iterator<Item = MdxBlock> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| MdxBlock::from_node(&node))
+}
+
+/// A block in the JavaScript module of the document
+struct ModuleSegment {
+    /// The range of the block in the module
+    range: TextRange,
+    /// The start of the block in the document
+    document_start: TextSize,
+}
+
+/// Analyzes the ESM blocks and the JSX blocks of `root` with the JavaScript rules.
+///
+/// The ranges of the diagnostics are relative to the MDX document. The diagnostics of the
+/// fragments that wrap the JSX blocks are dropped.
+pub(crate) fn lint(params: &LintParams, root: &MdDocument) -> LintResults {
+    let parser_options = javascript::parser_options(params.path, params.workspace.settings());
+    let mut module = String::new();
+    let mut segments = Vec::new();
+    for block in mdx_blocks(root) {
+        if block.parse(parser_options.clone()).has_errors() {
+            continue;
+        }
+        // The semicolons separate the JSX blocks from the previous statements
+        if block.kind == MdxBlockKind::Flow {
+            module.push(';');
+        }
+        let start = TextSize::of(module.as_str()) + block.prefix_len();
+        module.push_str(&block.source());
+        module.push_str(if block.kind == MdxBlockKind::Flow {
+            ";\n"
+        } else {
+            "\n"
+        });
+        segments.push(ModuleSegment {
+            range: TextRange::at(start, block.range.len()),
+            document_start: block.range.start(),
+        });
+    }
+    if segments.is_empty() {
+        return LintResults {
+            errors: 0,
+            diagnostics: Vec::new(),
+            skipped_diagnostics: 0,
+        };
+    }
+
+    let parse = biome_js_parser::parse(&module, JsFileSource::jsx(), parser_options);
+    let module_results = javascript::lint(LintParams {
+        parse: parse.into(),
+        workspace: params.workspace,
+        language: JsFileSource::jsx().into(),
+        max_diagnostics: params.max_diagnostics,
+        path: params.path,
+        only: params.only.clone(),
+        skip: params.skip.clone(),
+        categories: params.categories,
+        manifest: params.manifest.clone(),
+    });
+
+    let mut results = LintResults {
+        errors: module_results.errors,
+        diagnostics: Vec::new(),
+        skipped_diagnostics: module_results.skipped_diagnostics,
+    };
+    for diagnostic in module_results.diagnostics {
+        let span = diagnostic.location().span.and_then(|span| {
+            let segment = segments
+                .iter()
+                .find(|segment| segment.range.contains_range(span))?;
+            Some(span - segment.range.start() + segment.document_start)
+        });
+        match span {
+            Some(span) => results.diagnostics.push(diagnostic.with_span(span)),
+            None if diagnostic.severity() >= Severity::Error => {
+                results.errors = results.errors.saturating_sub(1);
+            }
+            None => {}
+        }
+    }
+    results
+}
+
+/// Formats the ESM blocks and the JSX blocks of an MDX document that was just formatted.
+///
+/// The blocks that contain syntax errors are left untouched, and so are the JSX blocks that
+/// contain text and the blocks nested in a container, such as a list item.
+pub(crate) fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    printed: Printed,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let Some(workspace_settings) = settings
+        .settings()
+        .filter(|settings| !settings.javascript_formatter_disabled())
+    else {
+        return Ok(printed);
+    };
+
+    let code = printed.as_code();
+    let file_source = document_file_source
+        .to_markdown_file_source()
+        .unwrap_or_default();
+    let document = parse_markdown(code, MarkdownParserOptions::from(&file_source));
+    let parser_options = javascript::parser_options(biome_path, Some(workspace_settings));
+    let js_options = settings.format_options::<JsLanguage>(biome_path, &JsFileSource::jsx().into());
+    let indent_unit = match js_options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(js_options.indent_width().value() as usize),
+    };
+    let line_ending = js_options.line_ending().as_str();
+
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = 0;
+    for block in mdx_blocks(&document.tree()) {
+        let start = usize::from(block.range.start());
+        let is_nested = code[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|char| !matches!(char, '\n' | '\r'));
+        if is_nested {
+            continue;
+        }
+        let parse = block.parse(parser_options.clone());
+        let has_text = parse
+            .syntax()
+            .descendants()
+            .filter_map(JsxText::cast)
+            .any(|text| !text.syntax().text_trimmed().to_string().trim().is_empty());
+        if parse.has_errors() || has_text {
+            continue;
+        }
+        let formatted = format_node(js_options.clone(), &parse.syntax())?
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        let formatted = formatted.as_code().trim_end();
+        let lines: Vec<_> = match block.kind {
+            MdxBlockKind::Esm => formatted.lines().map(str::to_string).collect(),
+            MdxBlockKind::Flow => {
+                let Some(children) = formatted
+                    .trim_end_matches(';')
+                    .strip_prefix(FRAGMENT_PREFIX)
+                    .and_then(|formatted| formatted.strip_suffix(FRAGMENT_SUFFIX))
+                else {
+                    continue;
+                };
+                // The children are indented by the fragment when they don't fit on its line
+                children
+                    .trim_matches(['\n', '\r'])
+                    .lines()
+                    .map(|line| {
+                        line.strip_prefix(indent_unit.as_str())
+                            .unwrap_or(line)
+                            .to_string()
+                    })
+                    .collect()
+            }
+        };
+
+        output.push_str(&code[last_end..start]);
+        output.push_str(&lines.join(line_ending));
+        last_end = usize::from(block.range.end());
+    }
+
+    if last_end == 0 {
+        return Ok(printed);
+    }
+    output.push_str(&code[last_end..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
@@ -14,6 +14,7 @@ use crate::{
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     WorkspaceError,
 };
+use biome_analyze::browserslist::BrowserTargets;
 use biome_analyze::{
     AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategories,
     RuleCategory, RuleFilter, RuleGroup,
@@ -29,35 +30,54 @@ use biome_fs::BiomePath;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
-use biome_html_syntax::HtmlFileSource;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{
     EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
 };
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
 use biome_parser::AnyParse;
 use biome_project::PackageJson;
 use biome_rowan::{FileSourceError, NodeCache};
 use biome_string_case::StrLikeExtension;
+use biome_toml_syntax::{TomlFileSource, TomlLanguage};
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
+use dashmap::DashMap;
 
+pub(crate) use css::{rename_in_project, scan_custom_properties, CssSymbol};
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
+pub(crate) use javascript::scan_modules;
 pub use javascript::JsFormatterSettings;
+use markdown::MarkdownFileHandler;
 use std::borrow::Cow;
 use std::ffi::OsStr;
-use std::path::Path;
-use tracing::instrument;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::SystemTime;
+use toml::TomlFileHandler;
+use tracing::{instrument, warn};
+use yaml::YamlFileHandler;
 
 mod astro;
 mod css;
+mod css_templates;
 mod graphql;
+mod graphql_templates;
 mod grit;
 mod html;
 mod javascript;
 mod json;
+mod markdown;
+mod markdown_code_blocks;
+mod mdx_blocks;
+mod sql_templates;
 mod svelte;
+mod toml;
 mod unknown;
 mod vue;
+mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -70,6 +90,9 @@ pub enum DocumentFileSource {
     Graphql(GraphqlFileSource),
     Html(HtmlFileSource),
     Grit(GritFileSource),
+    Yaml(YamlFileSource),
+    Toml(TomlFileSource),
+    Markdown(MarkdownFileSource),
     #[default]
     Unknown,
 }
@@ -110,6 +133,24 @@ impl From<GritFileSource> for DocumentFileSource {
     }
 }
 
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl From<TomlFileSource> for DocumentFileSource {
+    fn from(value: TomlFileSource) -> Self {
+        Self::Toml(value)
+    }
+}
+
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
 impl From<&Path> for DocumentFileSource {
     fn from(path: &Path) -> Self {
         Self::from_path(path)
@@ -131,6 +172,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName)
     }
@@ -155,6 +202,15 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         #[cfg(feature = "experimental-html")]
         if let Ok(file_source) = HtmlFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
@@ -187,6 +243,15 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         #[cfg(feature = "experimental-html")]
         if let Ok(file_source) = HtmlFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
@@ -296,6 +361,22 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_html_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Html(_))
+    }
+
+    pub const fn is_yaml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Yaml(_))
+    }
+
+    pub const fn is_toml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Toml(_))
+    }
+
+    pub const fn is_markdown_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Markdown(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -338,6 +419,27 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            DocumentFileSource::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
+    pub fn to_toml_file_source(&self) -> Option<TomlFileSource> {
+        match self {
+            DocumentFileSource::Toml(toml) => Some(*toml),
+            _ => None,
+        }
+    }
+
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            DocumentFileSource::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
     pub fn can_parse(path: &Path, content: &str) -> bool {
         let file_source = DocumentFileSource::from(path);
         match file_source {
@@ -349,7 +451,10 @@ impl DocumentFileSource {
             },
             DocumentFileSource::Css(_)
             | DocumentFileSource::Graphql(_)
-            | DocumentFileSource::Json(_) => true,
+            | DocumentFileSource::Json(_)
+            | DocumentFileSource::Yaml(_)
+            | DocumentFileSource::Toml(_)
+            | DocumentFileSource::Markdown(_) => true,
             DocumentFileSource::Html(_) => cfg!(feature = "experimental-html"),
             DocumentFileSource::Grit(_) => cfg!(feature = "experimental-grit"),
             DocumentFileSource::Unknown => false,
@@ -375,7 +480,9 @@ impl biome_console::fmt::Display for DocumentFileSource {
                 }
             }
             DocumentFileSource::Json(json) => {
-                if json.allow_comments() {
+                if json.allow_json5() {
+                    fmt.write_markup(markup! { "JSON5" })
+                } else if json.allow_comments() {
                     fmt.write_markup(markup! { "JSONC" })
                 } else {
                     fmt.write_markup(markup! { "JSON" })
@@ -385,6 +492,9 @@ impl biome_console::fmt::Display for DocumentFileSource {
             DocumentFileSource::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
             DocumentFileSource::Html(_) => fmt.write_markup(markup! { "HTML" }),
             DocumentFileSource::Grit(_) => fmt.write_markup(markup! { "Grit" }),
+            DocumentFileSource::Yaml(_) => fmt.write_markup(markup! { "YAML" }),
+            DocumentFileSource::Toml(_) => fmt.write_markup(markup! { "TOML" }),
+            DocumentFileSource::Markdown(_) => fmt.write_markup(markup! { "Markdown" }),
             DocumentFileSource::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -393,11 +503,11 @@ impl biome_console::fmt::Display for DocumentFileSource {
 pub struct FixAllParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) fix_file_mode: FixFileMode,
-    pub(crate) workspace: WorkspaceSettingsHandle<'a>,
+    pub(crate) workspace: WorkspaceSettingsHandle,
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
-    pub(crate) manifest: Option<PackageJson>,
+    pub(crate) manifest: Option<Arc<PackageJson>>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -451,14 +561,14 @@ pub struct DebugCapabilities {
 #[derive(Debug)]
 pub(crate) struct LintParams<'a> {
     pub(crate) parse: AnyParse,
-    pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
+    pub(crate) workspace: &'a WorkspaceSettingsHandle,
     pub(crate) language: DocumentFileSource,
     pub(crate) max_diagnostics: u32,
     pub(crate) path: &'a BiomePath,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
-    pub(crate) manifest: Option<PackageJson>,
+    pub(crate) manifest: Option<Arc<PackageJson>>,
 }
 
 pub(crate) struct LintResults {
@@ -470,9 +580,9 @@ pub(crate) struct LintResults {
 pub(crate) struct CodeActionsParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) range: Option<TextRange>,
-    pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
+    pub(crate) workspace: &'a WorkspaceSettingsHandle,
     pub(crate) path: &'a BiomePath,
-    pub(crate) manifest: Option<PackageJson>,
+    pub(crate) manifest: Option<Arc<PackageJson>>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
@@ -564,6 +674,9 @@ pub(crate) struct Features {
     graphql: GraphqlFileHandler,
     html: HtmlFileHandler,
     grit: GritFileHandler,
+    yaml: YamlFileHandler,
+    toml: TomlFileHandler,
+    markdown: MarkdownFileHandler,
 }
 
 impl Features {
@@ -578,6 +691,9 @@ impl Features {
             graphql: GraphqlFileHandler {},
             html: HtmlFileHandler {},
             grit: GritFileHandler {},
+            yaml: YamlFileHandler {},
+            toml: TomlFileHandler {},
+            markdown: MarkdownFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -600,31 +716,127 @@ impl Features {
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
+            DocumentFileSource::Toml(_) => self.toml.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
     }
 }
 
-/// Checks whether a diagnostic coming from the analyzer is an [error](Severity::Error)
+/// Returns the severity of a diagnostic coming from the analyzer.
 ///
-/// The function checks the diagnostic against the current configured rules.
-pub(crate) fn is_diagnostic_error(
+/// The severity of a lint diagnostic is the one chosen by the rule, if any, or the one
+/// configured for the rule.
+pub(crate) fn diagnostic_severity(
     diagnostic: &'_ AnalyzerDiagnostic,
     rules: Option<&'_ Rules>,
-) -> bool {
-    let severity = diagnostic
+) -> Severity {
+    diagnostic
         .category()
         .filter(|category| category.name().starts_with("lint/"))
         .map_or_else(
             || diagnostic.severity(),
             |category| {
-                rules
-                    .and_then(|rules| rules.get_severity_from_code(category))
+                diagnostic
+                    .rule_severity()
+                    .or_else(|| rules.and_then(|rules| rules.get_severity_from_code(category)))
                     .unwrap_or(Severity::Warning)
             },
-        );
+        )
+}
 
-    severity >= Severity::Error
+/// Checks whether a diagnostic coming from the analyzer is an [error](Severity::Error)
+///
+/// The function checks the diagnostic against the current configured rules.
+pub(crate) fn is_diagnostic_error(
+    diagnostic: &'_ AnalyzerDiagnostic,
+    rules: Option<&'_ Rules>,
+) -> bool {
+    diagnostic_severity(diagnostic, rules) >= Severity::Error
+}
+
+/// Browser targets loaded from a `.browserslistrc` file, along with the modification time of
+/// the file
+type LoadedBrowserTargets = (Option<SystemTime>, Arc<BrowserTargets>);
+
+static BROWSERSLISTRCS: LazyLock<DashMap<PathBuf, LoadedBrowserTargets>> =
+    LazyLock::new(DashMap::default);
+
+/// Returns the environments targeted by the JavaScript file at `path`.
+///
+/// The queries of the `javascript.target` setting take precedence over the files of the project.
+/// Otherwise, in addition to the [browser targets](load_browser_targets), the version of Node.js
+/// in the `engines` field of the `package.json` manifest is targeted. When the project doesn't
+/// configure browserslist, Node.js is the only target.
+pub(crate) fn load_javascript_targets(
+    path: &BiomePath,
+    settings: Option<&Settings>,
+    manifest: Option<&PackageJson>,
+) -> Option<Arc<BrowserTargets>> {
+    let configured = settings.and_then(|settings| {
+        settings
+            .override_settings
+            .override_js_target(path, &settings.languages.javascript.environment.target)
+    });
+    if let Some(queries) = configured {
+        return Some(Arc::new(BrowserTargets::from_queries(
+            queries.iter().map(String::as_str),
+        )));
+    }
+
+    let targets = load_browser_targets(path.as_path(), manifest);
+    let Some(node) = manifest.and_then(|manifest| manifest.engines.get("node")) else {
+        return targets;
+    };
+    let Some(targets) = targets else {
+        return BrowserTargets::from_node_engine(node).map(Arc::new);
+    };
+    let mut with_node = targets.as_ref().clone();
+    match with_node.include_node_engine(node) {
+        Some(()) => Some(Arc::new(with_node)),
+        None => Some(targets),
+    }
+}
+
+/// Returns the browsers targeted by the file at `path`.
+///
+/// The targets are read from the closest `.browserslistrc` file, or from the `browserslist`
+/// field of the `package.json` manifest.
+/// The `.browserslistrc` files are parsed once and reloaded only when they change.
+pub(crate) fn load_browser_targets(
+    path: &Path,
+    manifest: Option<&PackageJson>,
+) -> Option<Arc<BrowserTargets>> {
+    let browserslistrc = path
+        .ancestors()
+        .skip(1)
+        .map(|directory| directory.join(".browserslistrc"))
+        .find(|browserslistrc| browserslistrc.is_file());
+    let Some(browserslistrc) = browserslistrc else {
+        let queries = manifest?.browserslist.as_ref()?;
+        return Some(Arc::new(BrowserTargets::from_queries(
+            queries.iter().map(String::as_str),
+        )));
+    };
+    let modified = std::fs::metadata(&browserslistrc)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    if let Some(cached) = BROWSERSLISTRCS.get(&browserslistrc) {
+        if modified.is_some() && cached.0 == modified {
+            return Some(cached.1.clone());
+        }
+    }
+    let content = match std::fs::read_to_string(&browserslistrc) {
+        Ok(content) => content,
+        Err(error) => {
+            warn!("Could not read {}: {error}", browserslistrc.display());
+            return None;
+        }
+    };
+    let targets = Arc::new(BrowserTargets::from_browserslistrc(&content));
+    BROWSERSLISTRCS.insert(browserslistrc, (modified, targets.clone()));
+    Some(targets)
 }
 
 /// Parse the "lang" attribute from the opening tag of the "\<script\>" block in Svelte or Vue files.
@@ -821,6 +1033,63 @@ impl<'a> RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'a> {
     }
 }
 
+impl<'a> RegistryVisitor<HtmlLanguage> for SyntaxVisitor<'a> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
+impl<'a> RegistryVisitor<YamlLanguage> for SyntaxVisitor<'a> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
+impl<'a> RegistryVisitor<TomlLanguage> for SyntaxVisitor<'a> {
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -1008,6 +1277,96 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for LintVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for LintVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = HtmlLanguage>>(&mut self) {
+        for selector in self.only {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+
+        for selector in self.skip {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>()
+    }
+}
+
+impl<'a, 'b> RegistryVisitor<YamlLanguage> for LintVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = YamlLanguage>>(&mut self) {
+        for selector in self.only {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+
+        for selector in self.skip {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>()
+    }
+}
+
+impl<'a, 'b> RegistryVisitor<TomlLanguage> for LintVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = TomlLanguage>>(&mut self) {
+        for selector in self.only {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+
+        for selector in self.skip {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>()
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: Option<&'b Settings>,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1146,6 +1505,54 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for AssistsVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
+impl<'a, 'b> RegistryVisitor<YamlLanguage> for AssistsVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
+impl<'a, 'b> RegistryVisitor<TomlLanguage> for AssistsVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a, 'b> {
     syntax: Option<SyntaxVisitor<'a>>,
     lint: Option<LintVisitor<'a, 'b>>,
@@ -1199,6 +1606,9 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut syntax);
             biome_json_analyze::visit_registry(&mut syntax);
             biome_graphql_analyze::visit_registry(&mut syntax);
+            biome_html_analyze::visit_registry(&mut syntax);
+            biome_yaml_analyze::visit_registry(&mut syntax);
+            biome_toml_analyze::visit_registry(&mut syntax);
             enabled_rules.extend(syntax.enabled_rules);
         }
 
@@ -1207,6 +1617,9 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut lint);
             biome_json_analyze::visit_registry(&mut lint);
             biome_graphql_analyze::visit_registry(&mut lint);
+            biome_html_analyze::visit_registry(&mut lint);
+            biome_yaml_analyze::visit_registry(&mut lint);
+            biome_toml_analyze::visit_registry(&mut lint);
             let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
             enabled_rules.extend(linter_enabled_rules);
             disabled_rules.extend(linter_disabled_rules);
@@ -1217,6 +1630,9 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut assists);
             biome_json_analyze::visit_registry(&mut assists);
             biome_graphql_analyze::visit_registry(&mut assists);
+            biome_html_analyze::visit_registry(&mut assists);
+            biome_yaml_analyze::visit_registry(&mut assists);
+            biome_toml_analyze::visit_registry(&mut assists);
             let (assists_enabled_rules, assists_disabled_rules) = assists.finish();
             enabled_rules.extend(assists_enabled_rules);
             disabled_rules.extend(assists_disabled_rules);
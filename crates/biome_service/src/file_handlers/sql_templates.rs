@@ -0,0 +1,637 @@
+//! Support for SQL queries embedded in JavaScript templates, tagged with `sql` or preceded by a
+//! `/* sql */` comment:
+//!
+//! ```js
+//! const users = await sql`select id, name from users where team_id = ${teamId}`;
+//! const query = /* sql */ `select count(*) from posts`;
+//! ```
+//!
+//! The queries are formatted by a minimal SQL formatter: the keywords are uppercased, each
+//! clause starts a line, the conditions joined by `AND` and `OR` start an indented line, and
+//! the subqueries are indented. Each `${...}` substitution is replaced by a placeholder, which
+//! is formatted like a name.
+
+use super::{javascript, DocumentFileSource};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::WorkspaceError;
+use biome_formatter::{FormatOptions, IndentStyle, Printed};
+use biome_fs::BiomePath;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsTemplateElement, JsLanguage, JsSyntaxToken,
+    JsTemplateExpression, TextRange, TextSize,
+};
+use biome_rowan::AstNode;
+use biome_string_case::StrLikeExtension;
+
+/// The keywords that are uppercased, in alphabetical order. The words that are often used as
+/// names, such as `key` or `name`, aren't keywords.
+const KEYWORDS: [&str; 66] = [
+    "ALL",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CONFLICT",
+    "CROSS",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LATERAL",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "MATERIALIZED",
+    "NATURAL",
+    "NOT",
+    "NOTHING",
+    "NULL",
+    "NULLS",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "RECURSIVE",
+    "RETURNING",
+    "RIGHT",
+    "ROWS",
+    "SELECT",
+    "SET",
+    "SOME",
+    "THEN",
+    "TRUE",
+    "UNION",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+    "WITH",
+];
+
+/// The clauses that start a line. The longest clause that matches is used.
+const CLAUSES: [&[&str]; 29] = [
+    &["CROSS", "JOIN"],
+    &["DELETE", "FROM"],
+    &["EXCEPT"],
+    &["FROM"],
+    &["FULL", "JOIN"],
+    &["FULL", "OUTER", "JOIN"],
+    &["GROUP", "BY"],
+    &["HAVING"],
+    &["INNER", "JOIN"],
+    &["INSERT", "INTO"],
+    &["INTERSECT"],
+    &["JOIN"],
+    &["LEFT", "JOIN"],
+    &["LEFT", "OUTER", "JOIN"],
+    &["LIMIT"],
+    &["OFFSET"],
+    &["ON", "CONFLICT"],
+    &["ORDER", "BY"],
+    &["RETURNING"],
+    &["RIGHT", "JOIN"],
+    &["RIGHT", "OUTER", "JOIN"],
+    &["SELECT"],
+    &["SET"],
+    &["UNION"],
+    &["UNION", "ALL"],
+    &["UPDATE"],
+    &["VALUES"],
+    &["WHERE"],
+    &["WITH"],
+];
+
+/// A SQL template embedded in a JavaScript file
+struct SqlTemplate {
+    /// The range of the content of the template, between its backticks
+    range: TextRange,
+    /// The text around the substitutions. There's one more chunk than substitutions.
+    chunks: Vec<String>,
+    /// The text of the `${...}` substitutions
+    substitutions: Vec<String>,
+}
+
+impl SqlTemplate {
+    fn from_template(template: &JsTemplateExpression) -> Option<Self> {
+        let l_tick = template.l_tick_token().ok()?;
+        let is_sql = match template.tag() {
+            Some(tag) => is_sql_tag(&tag),
+            None => has_sql_comment(&l_tick),
+        };
+        if !is_sql {
+            return None;
+        }
+        let start = l_tick.text_trimmed_range().end();
+        let end = template.r_tick_token().ok()?.text_trimmed_range().start();
+        let mut chunks = vec![String::new()];
+        let mut substitutions = Vec::new();
+        for element in template.elements() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let token = chunk.template_chunk_token().ok()?;
+                    // The escape sequences would be mistaken for SQL
+                    if token.text_trimmed().contains('\\') {
+                        return None;
+                    }
+                    chunks.last_mut()?.push_str(token.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    substitutions.push(element.syntax().text_trimmed().to_string());
+                    chunks.push(String::new());
+                }
+            }
+        }
+        Some(Self {
+            range: TextRange::new(start, end),
+            chunks,
+            substitutions,
+        })
+    }
+
+    fn is_blank(&self) -> bool {
+        self.substitutions.is_empty() && self.chunks.iter().all(|chunk| chunk.trim().is_empty())
+    }
+
+    /// Returns the SQL source of the template, where each substitution is replaced by a
+    /// placeholder
+    fn source(&self) -> String {
+        let mut text = self.chunks[0].clone();
+        for (index, chunk) in self.chunks[1..].iter().enumerate() {
+            text.push_str(&placeholder(index));
+            text.push_str(chunk);
+        }
+        text
+    }
+
+    /// Replaces the placeholders of the formatted query by the substitutions of the template.
+    ///
+    /// Returns `None` if the formatter didn't keep every placeholder exactly once.
+    fn restore_substitutions(&self, formatted: &str) -> Option<String> {
+        let mut formatted = formatted.to_string();
+        // The placeholders with the highest indices go first, so `biome_placeholder_1` doesn't
+        // match the start of `biome_placeholder_10`
+        for (index, substitution) in self.substitutions.iter().enumerate().rev() {
+            let text = placeholder(index);
+            if formatted.matches(&text).count() != 1 {
+                return None;
+            }
+            formatted = formatted.replacen(&text, substitution, 1);
+        }
+        Some(formatted)
+    }
+}
+
+fn placeholder(index: usize) -> String {
+    format!("biome_placeholder_{index}")
+}
+
+/// Returns `true` if `tag` is `sql`, or a member called `sql` like `Prisma.sql`
+fn is_sql_tag(tag: &AnyJsExpression) -> bool {
+    match tag {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|token| token.text_trimmed() == "sql"),
+        AnyJsExpression::JsStaticMemberExpression(member) => member
+            .member()
+            .is_ok_and(|member| member.syntax().text_trimmed() == "sql"),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the template that starts with `l_tick` is directly preceded by a
+/// `/* sql */` comment
+fn has_sql_comment(l_tick: &JsSyntaxToken) -> bool {
+    // A comment on the same line as the previous token is part of its trailing trivia
+    let last_comment = l_tick
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| piece.is_comments())
+        .last()
+        .or_else(|| {
+            l_tick
+                .prev_token()?
+                .trailing_trivia()
+                .pieces()
+                .filter(|piece| piece.is_comments())
+                .last()
+        });
+    last_comment.is_some_and(|comment| {
+        comment
+            .text()
+            .strip_prefix("/*")
+            .and_then(|text| text.strip_suffix("*/"))
+            .is_some_and(|text| text.trim().to_ascii_lowercase_cow() == "sql")
+    })
+}
+
+/// Returns the SQL templates of `root`, in the order of the document
+fn sql_templates(root: &AnyJsRoot) -> impl Iterator<Item = SqlTemplate> {
+    root.syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+        .filter_map(|template| SqlTemplate::from_template(&template))
+}
+
+/// Formats the SQL templates of `printed`, the formatted JavaScript file.
+///
+/// The templates whose query can't be tokenized are left as they are, and so are the templates
+/// nested in the substitutions of another SQL template.
+pub(crate) fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    printed: Printed,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let file_source = document_file_source.to_js_file_source().unwrap_or_default();
+    let options = javascript::parser_options(biome_path, settings.settings());
+    let parse = biome_js_parser::parse(printed.as_code(), file_source, options);
+    if parse.has_errors() {
+        return Ok(printed);
+    }
+
+    let js_options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let indent_unit = match js_options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(js_options.indent_width().value() as usize),
+    };
+    let line_ending = js_options.line_ending().as_str();
+
+    let code = printed.as_code();
+    let mut output = String::with_capacity(code.len());
+    let mut last_end = TextSize::from(0);
+    for template in sql_templates(&parse.tree()) {
+        if template.range.start() < last_end || template.is_blank() {
+            continue;
+        }
+        let Some(lines) = format_sql(&template.source(), &indent_unit) else {
+            continue;
+        };
+        let Some(formatted) = template.restore_substitutions(&lines.join("\n")) else {
+            continue;
+        };
+
+        let start = usize::from(template.range.start());
+        let line_start = code[..start].rfind('\n').map_or(0, |index| index + 1);
+        let indentation: String = code[line_start..start]
+            .chars()
+            .take_while(|c| matches!(c, ' ' | '\t'))
+            .collect();
+
+        output.push_str(&code[usize::from(last_end)..start]);
+        output.push_str(line_ending);
+        for line in formatted.lines() {
+            output.push_str(&indentation);
+            output.push_str(&indent_unit);
+            output.push_str(line);
+            output.push_str(line_ending);
+        }
+        output.push_str(&indentation);
+        last_end = template.range.end();
+    }
+
+    if last_end == TextSize::from(0) {
+        return Ok(printed);
+    }
+    output.push_str(&code[usize::from(last_end)..]);
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SqlTokenKind {
+    /// A keyword or a name, like `select` or `users`
+    Word,
+    /// A string, a quoted name, a number or a parameter, like `'admin'`, `"User"` or `$1`
+    Literal,
+    Operator,
+    LeftParen,
+    RightParen,
+    Comma,
+    Dot,
+    Semicolon,
+    LineComment,
+    BlockComment,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SqlToken<'a> {
+    kind: SqlTokenKind,
+    text: &'a str,
+}
+
+/// Splits `source` into tokens. Returns `None` if a string or a comment isn't terminated.
+fn tokenize(source: &str) -> Option<Vec<SqlToken<'_>>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let start = index;
+        let byte = bytes[index];
+        let rest = &source[index..];
+        let kind = match byte {
+            b' ' | b'\t' | b'\n' | b'\r' => {
+                index += 1;
+                continue;
+            }
+            b'-' if rest.starts_with("--") => {
+                index += rest.find('\n').unwrap_or(rest.len());
+                SqlTokenKind::LineComment
+            }
+            b'/' if rest.starts_with("/*") => {
+                index += rest[2..].find("*/")? + 4;
+                SqlTokenKind::BlockComment
+            }
+            b'\'' | b'"' => {
+                // A quote is escaped by doubling it
+                index += 1;
+                loop {
+                    index += source[index..].find(byte as char)? + 1;
+                    if bytes.get(index) != Some(&byte) {
+                        break;
+                    }
+                    index += 1;
+                }
+                SqlTokenKind::Literal
+            }
+            b'$' => {
+                let tag_len = word_len(&rest[1..]);
+                let tag = &rest[1..1 + tag_len];
+                if rest[1 + tag_len..].starts_with('$')
+                    && !tag.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    // A dollar-quoted string, like `$$text$$` or `$body$text$body$`
+                    let delimiter = &rest[..tag_len + 2];
+                    index += delimiter.len();
+                    index += source[index..].find(delimiter)? + delimiter.len();
+                } else {
+                    // A positional parameter, like `$1`
+                    index += 1 + tag_len;
+                }
+                SqlTokenKind::Literal
+            }
+            b':' if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                // A named parameter, like `:id`
+                index += 1 + word_len(&rest[1..]);
+                SqlTokenKind::Literal
+            }
+            b'0'..=b'9' => {
+                index += rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+                    .unwrap_or(rest.len());
+                SqlTokenKind::Literal
+            }
+            b'?' => {
+                index += 1;
+                SqlTokenKind::Literal
+            }
+            b'(' | b')' | b',' | b'.' | b';' => {
+                index += 1;
+                match byte {
+                    b'(' => SqlTokenKind::LeftParen,
+                    b')' => SqlTokenKind::RightParen,
+                    b',' => SqlTokenKind::Comma,
+                    b'.' => SqlTokenKind::Dot,
+                    _ => SqlTokenKind::Semicolon,
+                }
+            }
+            _ if byte.is_ascii_alphabetic() || byte == b'_' || !byte.is_ascii() => {
+                index += word_len(rest).max(rest.chars().next()?.len_utf8());
+                SqlTokenKind::Word
+            }
+            _ => {
+                index += rest
+                    .find(|c: char| !"+-*/<>=!|&%^~:@#".contains(c))
+                    .unwrap_or(rest.len())
+                    .max(1);
+                SqlTokenKind::Operator
+            }
+        };
+        tokens.push(SqlToken {
+            kind,
+            text: &source[start..index],
+        });
+    }
+    Some(tokens)
+}
+
+fn word_len(text: &str) -> usize {
+    text.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(text.len())
+}
+
+/// Returns the keyword of the token at `index` in uppercase, if it's a keyword that isn't part
+/// of a qualified name like `posts.order`
+fn keyword(tokens: &[SqlToken], index: usize) -> Option<&'static str> {
+    let token = tokens.get(index)?;
+    let is_qualified = index
+        .checked_sub(1)
+        .is_some_and(|previous| tokens[previous].kind == SqlTokenKind::Dot)
+        || tokens
+            .get(index + 1)
+            .is_some_and(|next| next.kind == SqlTokenKind::Dot);
+    if token.kind != SqlTokenKind::Word || is_qualified {
+        return None;
+    }
+    let upper = token.text.to_ascii_uppercase();
+    KEYWORDS
+        .binary_search(&upper.as_str())
+        .ok()
+        .map(|position| KEYWORDS[position])
+}
+
+/// Returns the number of keywords of the clause that starts at `index`
+fn clause_len(tokens: &[SqlToken], index: usize) -> Option<usize> {
+    CLAUSES
+        .iter()
+        .filter(|clause| {
+            clause
+                .iter()
+                .enumerate()
+                .all(|(offset, word)| keyword(tokens, index + offset) == Some(*word))
+        })
+        .map(|clause| clause.len())
+        .max()
+}
+
+/// Returns `true` if a space separates the token at `index` from the previous token of the
+/// same line
+fn has_space_before(tokens: &[SqlToken], index: usize) -> bool {
+    let Some(previous) = index.checked_sub(1) else {
+        return false;
+    };
+    match (tokens[previous].kind, tokens[index].kind) {
+        (
+            _,
+            SqlTokenKind::Comma
+            | SqlTokenKind::RightParen
+            | SqlTokenKind::Dot
+            | SqlTokenKind::Semicolon,
+        )
+        | (SqlTokenKind::LeftParen | SqlTokenKind::Dot, _) => false,
+        // A function call, like `count(*)`, unlike `IN (...)` and `INTO users (...)`
+        (SqlTokenKind::Word, SqlTokenKind::LeftParen) => {
+            keyword(tokens, previous).is_some()
+                || previous
+                    .checked_sub(1)
+                    .is_some_and(|before| keyword(tokens, before) == Some("INTO"))
+        }
+        // A cast, like `id::text`
+        (_, SqlTokenKind::Operator) if tokens[index].text == "::" => false,
+        (SqlTokenKind::Operator, _) => tokens[previous].text != "::" && !is_sign(tokens, previous),
+        _ => true,
+    }
+}
+
+/// Returns `true` if the operator at `index` is the sign of a number, like the `-` of `-1`
+fn is_sign(tokens: &[SqlToken], index: usize) -> bool {
+    matches!(tokens[index].text, "-" | "+")
+        && index.checked_sub(1).is_none_or(|previous| {
+            matches!(
+                tokens[previous].kind,
+                SqlTokenKind::Operator | SqlTokenKind::LeftParen | SqlTokenKind::Comma
+            ) || keyword(tokens, previous).is_some()
+        })
+}
+
+/// The lines of a formatted query
+struct SqlLines<'a> {
+    indent_unit: &'a str,
+    lines: Vec<String>,
+    line: String,
+}
+
+impl SqlLines<'_> {
+    /// Starts a line indented by `level`, unless the current line is empty
+    fn start_line(&mut self, level: usize) {
+        if !self.line.trim_start().is_empty() {
+            self.lines.push(std::mem::take(&mut self.line));
+        }
+        self.line = self.indent_unit.repeat(level);
+    }
+
+    fn push(&mut self, text: &str, space: bool) {
+        if space && !self.line.trim_start().is_empty() {
+            self.line.push(' ');
+        }
+        self.line.push_str(text);
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if !self.line.trim_start().is_empty() {
+            self.lines.push(self.line);
+        }
+        self.lines
+    }
+}
+
+/// Formats a SQL query into lines indented with `indent_unit`. Returns `None` if the query
+/// can't be tokenized.
+fn format_sql(source: &str, indent_unit: &str) -> Option<Vec<String>> {
+    let tokens = tokenize(source)?;
+    let mut lines = SqlLines {
+        indent_unit,
+        lines: Vec::new(),
+        line: String::new(),
+    };
+    // For each open parenthesis, whether it contains a subquery, like `(SELECT ...)`
+    let mut parens: Vec<bool> = Vec::new();
+    // The indentation of the clauses
+    let mut level = 0;
+    let mut in_between = false;
+    // The number of open `CASE` expressions, whose conditions stay on their line
+    let mut cases = 0_usize;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index];
+        // The clauses and the conditions are laid out at the top level and in the subqueries,
+        // not in the arguments of a function like `extract(year from created_at)`
+        let is_block = parens.last().is_none_or(|is_subquery| *is_subquery);
+        // The `UPDATE` of `ON CONFLICT DO UPDATE` continues the line
+        let is_after_do = index
+            .checked_sub(1)
+            .is_some_and(|previous| keyword(&tokens, previous) == Some("DO"));
+        if let Some(len) = clause_len(&tokens, index).filter(|_| is_block && !is_after_do) {
+            let words: Vec<_> = (index..index + len)
+                .filter_map(|index| keyword(&tokens, index))
+                .collect();
+            lines.start_line(level);
+            lines.push(&words.join(" "), false);
+            index += len;
+            continue;
+        }
+
+        let space = has_space_before(&tokens, index);
+        match (keyword(&tokens, index), token.kind) {
+            (Some("BETWEEN"), _) => {
+                in_between = true;
+                lines.push("BETWEEN", space);
+            }
+            // The `AND` of `BETWEEN 1 AND 10`
+            (Some("AND"), _) if in_between => {
+                in_between = false;
+                lines.push("AND", space);
+            }
+            (Some("CASE"), _) => {
+                cases += 1;
+                lines.push("CASE", space);
+            }
+            (Some("END"), _) => {
+                cases = cases.saturating_sub(1);
+                lines.push("END", space);
+            }
+            (Some(operator @ ("AND" | "OR")), _) if is_block && cases == 0 => {
+                lines.start_line(level + 1);
+                lines.push(operator, false);
+            }
+            (Some(keyword), _) => lines.push(keyword, space),
+            (None, SqlTokenKind::LeftParen) => {
+                let is_subquery = matches!(keyword(&tokens, index + 1), Some("SELECT" | "WITH"));
+                lines.push("(", space);
+                if is_subquery {
+                    level += 1;
+                }
+                parens.push(is_subquery);
+            }
+            (None, SqlTokenKind::RightParen) => {
+                if parens.pop() == Some(true) {
+                    level -= 1;
+                    lines.start_line(level);
+                }
+                lines.push(")", space);
+            }
+            (None, SqlTokenKind::Semicolon | SqlTokenKind::LineComment) => {
+                lines.push(token.text, space);
+                lines.start_line(level);
+            }
+            (None, _) => lines.push(token.text, space),
+        }
+        index += 1;
+    }
+    Some(lines.finish())
+}
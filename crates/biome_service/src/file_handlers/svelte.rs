@@ -1,5 +1,5 @@
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    html, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
     ParserCapabilities,
 };
@@ -10,6 +10,8 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
+use biome_html_parser::{parse_html, HtmlParserOptions};
+use biome_html_syntax::HtmlFileSource;
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
@@ -77,7 +79,63 @@ impl SvelteFileHandler {
                         .with_embedding_kind(EmbeddingKind::Svelte),
                 )
             })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+            .map_or(JsFileSource::svelte(), |fs| fs)
+    }
+
+    /// Formats the markup and the `<style>` blocks of a Svelte file around its `<script>` block,
+    /// whose formatted content is `script`, and returns the whole formatted file.
+    ///
+    /// The markup, including the blocks such as `{#if}` or `{#each}` and the tags such as
+    /// `{@html}`, is formatted with the HTML formatter. The `<style>` blocks are formatted with
+    /// the CSS formatter. The file is left untouched when its markup contains syntax errors.
+    pub(crate) fn format_blocks(
+        biome_path: &BiomePath,
+        content: &str,
+        script: Printed,
+        settings: &WorkspaceSettingsHandle,
+    ) -> Result<Printed, WorkspaceError> {
+        let code = SvelteFileHandler::output(content, script.as_code());
+        html::format_component_markup(biome_path, code, HtmlFileSource::svelte(), settings)
+    }
+
+    /// Analyzes the `<script>` block of a Svelte file, parsed in `params`, and its markup.
+    ///
+    /// The ranges of the diagnostics are relative to the whole file. The markup is only
+    /// analyzed when it doesn't contain syntax errors.
+    pub(crate) fn lint_file(params: LintParams, content: &str) -> LintResults {
+        let markup_params = LintParams {
+            parse: parse_html(content, HtmlParserOptions::default().with_svelte()).into(),
+            workspace: params.workspace,
+            language: DocumentFileSource::Html(HtmlFileSource::svelte()),
+            max_diagnostics: params.max_diagnostics,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: params.categories,
+            manifest: params.manifest.clone(),
+        };
+        let mut results = lint(params);
+        if let Some(start) = Self::start(content) {
+            results.diagnostics = results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(TextSize::from(start)))
+                .collect();
+        }
+        if markup_params.parse.has_errors() {
+            return results;
+        }
+
+        let markup_results = html::lint(LintParams {
+            max_diagnostics: markup_params
+                .max_diagnostics
+                .saturating_sub(results.diagnostics.len() as u32),
+            ..markup_params
+        });
+        results.errors += markup_results.errors;
+        results.skipped_diagnostics += markup_results.skipped_diagnostics;
+        results.diagnostics.extend(markup_results.diagnostics);
+        results
     }
 }
 
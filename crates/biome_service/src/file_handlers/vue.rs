@@ -8,12 +8,16 @@ use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
-use biome_formatter::Printed;
+use biome_css_parser::CssParserOptions;
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_formatter::{FormatOptions, Printed};
 use biome_fs::BiomePath;
+use biome_html_parser::{parse_html, HtmlParserOptions};
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, AstNodeList, NodeCache};
 use regex::{Match, Regex};
 use std::sync::LazyLock;
 use tracing::debug;
@@ -77,7 +81,51 @@ impl VueFileHandler {
                         .with_embedding_kind(EmbeddingKind::Vue),
                 )
             })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+            .map_or(JsFileSource::vue(), |fs| fs)
+    }
+
+    /// Formats the blocks of a Vue file around its `<script>` block, whose formatted content is
+    /// `script`, and returns the whole formatted file.
+    ///
+    /// The top-level `<template>` block is parsed with the HTML parser, which accepts the Vue
+    /// directives such as `v-if`, `:prop` or `@event`, and formatted with the HTML formatter.
+    /// The `<style>` blocks are formatted with the CSS formatter. The blocks written in another
+    /// language, such as `<template lang="pug">`, and the blocks that contain syntax errors are
+    /// left untouched.
+    pub(crate) fn format_blocks(
+        biome_path: &BiomePath,
+        content: &str,
+        script: Printed,
+        settings: &WorkspaceSettingsHandle,
+    ) -> Result<Printed, WorkspaceError> {
+        let code = VueFileHandler::output(content, script.as_code());
+        let parse = parse_html(&code, HtmlParserOptions::default());
+        if parse.has_errors() {
+            return Ok(Printed::new(code, None, Vec::new(), Vec::new()));
+        }
+
+        let mut edits = Vec::new();
+        for element in parse.tree().html() {
+            let AnyHtmlElement::HtmlElement(element) = element else {
+                continue;
+            };
+            let edit = match element_name(&element).as_deref() {
+                Some("template") => format_template(biome_path, &element, settings)?,
+                Some("style") => format_style(biome_path, &element, settings)?,
+                _ => None,
+            };
+            edits.extend(edit);
+        }
+
+        let mut output = String::with_capacity(code.len());
+        let mut last_end = 0;
+        for (range, replacement) in edits {
+            output.push_str(&code[last_end..usize::from(range.start())]);
+            output.push_str(&replacement);
+            last_end = usize::from(range.end());
+        }
+        output.push_str(&code[last_end..]);
+        Ok(Printed::new(output, None, Vec::new(), Vec::new()))
     }
 }
 
@@ -138,6 +186,123 @@ fn format(
     javascript::format(biome_path, document_file_source, parse, settings)
 }
 
+/// Returns the formatted `<template>` block, with the range it replaces.
+fn format_template(
+    biome_path: &BiomePath,
+    element: &HtmlElement,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Option<(TextRange, String)>, WorkspaceError> {
+    if settings
+        .settings()
+        .is_some_and(|settings| settings.html_formatter_disabled())
+    {
+        return Ok(None);
+    }
+    if lang_attribute(element).is_some_and(|lang| lang != "html") {
+        return Ok(None);
+    }
+    let range = element.syntax().text_trimmed_range();
+    let parse = parse_html(
+        &element.syntax().text_trimmed().to_string(),
+        HtmlParserOptions::default(),
+    );
+    if parse.has_errors() {
+        return Ok(None);
+    }
+    let options = settings.format_options::<HtmlLanguage>(
+        biome_path,
+        &DocumentFileSource::Html(HtmlFileSource::html()),
+    );
+    let formatted = biome_html_formatter::format_node(options, &parse.syntax())?
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    let formatted = formatted.as_code().trim_end();
+    Ok(Some((range, formatted.to_string())))
+}
+
+/// Returns the formatted content of a `<style>` block, with the range it replaces.
+pub(super) fn format_style(
+    biome_path: &BiomePath,
+    element: &HtmlElement,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<Option<(TextRange, String)>, WorkspaceError> {
+    if settings
+        .settings()
+        .is_some_and(|settings| settings.css_formatter_disabled())
+    {
+        return Ok(None);
+    }
+    let file_source = match lang_attribute(element).as_deref() {
+        None | Some("css") => CssFileSource::css(),
+        Some("scss") => CssFileSource::scss(),
+        Some("less") => CssFileSource::less(),
+        Some(_) => return Ok(None),
+    };
+    let children = element.children();
+    let range = children.syntax().text_range();
+    let text = children.syntax().text().to_string();
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let options = CssParserOptions {
+        allow_wrong_line_comments: settings
+            .settings()
+            .and_then(|settings| settings.languages.css.parser.allow_wrong_line_comments)
+            .unwrap_or_default(),
+        // `<style module>` blocks are CSS modules
+        css_modules: has_attribute(element, "module"),
+        grit_metavariables: false,
+        scss: file_source.is_scss(),
+        less: file_source.is_less(),
+    };
+    let parse = biome_css_parser::parse_css(&text, options);
+    if parse.has_errors() {
+        return Ok(None);
+    }
+    let options =
+        settings.format_options::<CssLanguage>(biome_path, &DocumentFileSource::Css(file_source));
+    let line_ending = options.line_ending().as_str();
+    let formatted = biome_css_formatter::format_node(options, &parse.syntax())?
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    Ok(Some((
+        range,
+        format!("{line_ending}{}", formatted.as_code()),
+    )))
+}
+
+/// Returns the name of `element`, such as `template`
+pub(super) fn element_name(element: &HtmlElement) -> Option<String> {
+    let name = element.opening_element().ok()?.name().ok()?;
+    Some(name.value_token().ok()?.text_trimmed().to_string())
+}
+
+/// Returns the value of the `lang` attribute of `element`, such as `scss`
+fn lang_attribute(element: &HtmlElement) -> Option<String> {
+    let opening = element.opening_element().ok()?;
+    opening.attributes().iter().find_map(|attribute| {
+        let attribute = attribute.as_html_attribute()?;
+        if attribute.name().ok()?.value_token().ok()?.text_trimmed() != "lang" {
+            return None;
+        }
+        let value = attribute.initializer()?.value().ok()?.value_token().ok()?;
+        Some(value.text_trimmed().trim_matches(['"', '\'']).to_string())
+    })
+}
+
+/// Returns `true` if `element` has the attribute `name`
+fn has_attribute(element: &HtmlElement, name: &str) -> bool {
+    element.opening_element().is_ok_and(|opening| {
+        opening.attributes().iter().any(|attribute| {
+            attribute
+                .as_html_attribute()
+                .and_then(|attribute| attribute.name().ok()?.value_token().ok())
+                .is_some_and(|token| token.text_trimmed() == name)
+        })
+    })
+}
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
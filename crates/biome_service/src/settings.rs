@@ -1,3 +1,5 @@
+use crate::file_handlers::{scan_custom_properties, scan_modules};
+use crate::workspace::incremental::{Dependencies, Input, Inputs, Query};
 use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
 use crate::{Matcher, WorkspaceError};
 use biome_analyze::{AnalyzerOptions, AnalyzerRules};
@@ -10,8 +12,11 @@ use biome_configuration::{
     JavascriptConfiguration, LinterConfiguration, OverrideAssistsConfiguration,
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
-    PartialGraphqlConfiguration, PartialJavascriptConfiguration, PartialJsonConfiguration,
+    PartialGraphqlConfiguration, PartialHtmlConfiguration, PartialJavascriptConfiguration,
+    PartialJsonConfiguration, PartialMarkdownConfiguration, PartialTomlConfiguration,
+    PartialYamlConfiguration,
 };
+use biome_css_analyze::custom_properties::CustomPropertyIndex;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
@@ -19,6 +24,7 @@ use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
 use biome_formatter::{
     AttributePosition, BracketSpacing, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    MaxEmptyLines,
 };
 use biome_fs::BiomePath;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
@@ -27,106 +33,198 @@ use biome_grit_formatter::context::GritFormatOptions;
 use biome_grit_syntax::GritLanguage;
 use biome_html_formatter::HtmlFormatOptions;
 use biome_html_syntax::HtmlLanguage;
+use biome_js_analyze::module_graph::ModuleGraph;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
 use biome_project::{NodeJsProject, PackageJson};
+use biome_toml_formatter::context::TomlFormatOptions;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
 use std::borrow::Cow;
+use std::convert::Infallible;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
-use std::sync::RwLockWriteGuard;
-use std::{
-    num::NonZeroU64,
-    sync::{RwLock, RwLockReadGuard},
-};
+use std::sync::{Arc, RwLockWriteGuard};
+use std::{num::NonZeroU64, sync::RwLock};
 use tracing::trace;
 
 #[derive(Debug, Default)]
 /// The information tracked for each project
+///
+/// Each project has its own locks, so that the requests of a project don't wait for
+/// the updates of the other projects.
 pub struct ProjectData {
     /// The root path of the project. This path should be **absolute**.
     path: BiomePath,
-    /// The settings of the project, usually inferred from the configuration file e.g. `biome.json`.
-    settings: Settings,
-    /// Information relative to the current project
-    project: Option<NodeJsProject>,
+    /// The settings of the project, usually inferred from the configuration file e.g. `biome.json`,
+    /// and the parameters from which they were resolved, if any.
+    ///
+    /// The settings are never mutated: an update replaces them, so that the requests that
+    /// are using the previous settings don't block the update, and aren't blocked by it.
+    /// The settings and their source are behind the same lock, so that they are always
+    /// replaced together.
+    settings: RwLock<(Arc<Settings>, Option<Arc<SettingsSource>>)>,
+    /// The manifest of the project, parsed once and shared by the requests of the project
+    manifest: RwLock<Option<Arc<PackageJson>>>,
+    /// The custom properties defined by the stylesheets of the project, indexed the first time
+    /// a stylesheet is linted, and indexed again after the settings change
+    custom_properties: Query<ProjectKey, Arc<CustomPropertyIndex>>,
+    /// The module graph of the project, built the first time a rule that needs it runs, and
+    /// built again after the settings change
+    module_graph: Query<ProjectKey, Arc<ModuleGraph>>,
 }
 
-#[derive(Debug, Default)]
-/// Type that manages different projects inside the workspace.
-pub struct WorkspaceSettings {
-    /// The data of the projects
-    data: WorkspaceData<ProjectData>,
-    /// The ID of the current project.
-    current_project: ProjectKey,
+/// The parameters from which the settings of a project are resolved
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct SettingsSource {
+    pub(crate) configuration: PartialConfiguration,
+    pub(crate) workspace_directory: Option<PathBuf>,
+    pub(crate) vcs_base_path: Option<PathBuf>,
+    pub(crate) gitignore_matches: Vec<String>,
 }
 
-impl WorkspaceSettings {
-    pub fn get_current_project_key(&self) -> ProjectKey {
-        self.current_project
+impl ProjectData {
+    /// Returns the root path of the project
+    pub fn path(&self) -> &BiomePath {
+        &self.path
     }
 
-    pub fn get_current_project_data_mut(&mut self) -> &mut ProjectData {
-        self.data
-            .get_mut(self.current_project)
-            .expect("You must have at least one workspace.")
+    /// Returns the current settings of the project
+    pub fn settings(&self) -> Arc<Settings> {
+        self.settings.read().unwrap().0.clone()
     }
 
-    /// Retrieves the settings of the current workspace folder
-    pub fn get_current_settings(&self) -> Option<&Settings> {
-        trace!("Current key {:?}", self.current_project);
-        let data = self.data.get(self.current_project);
-        if let Some(data) = data {
-            Some(&data.settings)
-        } else {
-            None
-        }
+    /// Replaces the settings of the project
+    pub fn set_settings(&self, settings: Settings) {
+        *self.settings.write().unwrap() = (Arc::new(settings), None);
     }
 
-    pub fn get_current_manifest(&self) -> Option<&PackageJson> {
-        let data = self.data.get(self.current_project);
-        if let Some(data) = data {
-            data.project.as_ref().map(|project| &project.manifest)
-        } else {
-            None
-        }
+    /// Returns `true` if the settings of the project were resolved from `source`
+    pub(crate) fn is_resolved_from(&self, source: &SettingsSource) -> bool {
+        self.settings
+            .read()
+            .unwrap()
+            .1
+            .as_deref()
+            .is_some_and(|resolved_from| resolved_from == source)
     }
 
-    /// Retrieves a mutable reference of the settings of the current project
-    pub fn get_current_settings_mut(&mut self) -> &mut Settings {
-        &mut self
-            .data
-            .get_mut(self.current_project)
-            .expect("You must have at least one workspace.")
-            .settings
+    /// Resolves the settings of the project from `source`, and replaces them
+    pub(crate) fn resolve_settings(&self, source: SettingsSource) -> Result<(), WorkspaceError> {
+        let mut settings = Settings::default();
+        settings.merge_with_configuration(
+            source.configuration.clone(),
+            source.workspace_directory.clone(),
+            source.vcs_base_path.clone(),
+            source.gitignore_matches.as_slice(),
+        )?;
+        *self.settings.write().unwrap() = (Arc::new(settings), Some(Arc::new(source)));
+        Ok(())
+    }
+
+    /// Returns the manifest of the project, if any
+    pub fn manifest(&self) -> Option<Arc<PackageJson>> {
+        self.manifest.read().unwrap().clone()
     }
 
-    /// Register the current project using its unique key
-    pub fn register_current_project(&mut self, key: ProjectKey) {
-        self.current_project = key;
+    /// Replaces the manifest of the project
+    pub fn set_manifest(&self, project: NodeJsProject) {
+        let _ = self
+            .manifest
+            .write()
+            .unwrap()
+            .insert(Arc::new(project.manifest));
     }
 
-    /// Insert a new project using its folder. Use [WorkspaceSettings::get_current_settings_mut] to retrieve
-    /// a mutable reference to its [Settings] and manipulate them.
+    /// Returns the index of the custom properties defined by the stylesheets of the project,
+    /// registered with `project_key`.
+    ///
+    /// The stylesheets of the project folder are scanned the first time the index is requested,
+    /// and the first time it's requested after the settings change.
+    pub(crate) fn custom_properties(
+        &self,
+        project_key: ProjectKey,
+        inputs: &Inputs,
+    ) -> Arc<CustomPropertyIndex> {
+        let mut dependencies = Dependencies::new(inputs);
+        let Ok((index, _)) = self.custom_properties.get(
+            project_key,
+            &mut dependencies,
+            |project_key, dependencies| {
+                // The ignored files are read from the settings
+                dependencies.read(Input::Settings(*project_key));
+                Ok::<_, Infallible>(Arc::new(scan_custom_properties(
+                    self.path.as_path(),
+                    &self.settings(),
+                )))
+            },
+        );
+        index
+    }
+
+    /// Returns the module graph of the project, registered with `project_key`.
+    ///
+    /// The modules of the project folder are scanned the first time the graph is requested,
+    /// and the first time it's requested after the settings change.
+    pub(crate) fn module_graph(
+        &self,
+        project_key: ProjectKey,
+        inputs: &Inputs,
+    ) -> Arc<ModuleGraph> {
+        let mut dependencies = Dependencies::new(inputs);
+        let Ok((graph, _)) = self.module_graph.get(
+            project_key,
+            &mut dependencies,
+            |project_key, dependencies| {
+                // The ignored files and the parser options are read from the settings
+                dependencies.read(Input::Settings(*project_key));
+                Ok::<_, Infallible>(Arc::new(scan_modules(
+                    self.path.as_path(),
+                    &self.settings(),
+                )))
+            },
+        );
+        graph
+    }
+}
+
+#[derive(Debug, Default)]
+/// Type that manages different projects inside the workspace.
+///
+/// It only holds the list of projects: their settings are behind their own locks.
+pub struct WorkspaceSettings {
+    /// The data of the projects
+    data: WorkspaceData<Arc<ProjectData>>,
+}
+
+impl WorkspaceSettings {
+    /// Retrieves the data of the project registered with `key`
+    pub fn get_project_data(&self, key: ProjectKey) -> Option<Arc<ProjectData>> {
+        self.data.get(key).cloned()
+    }
+
+    /// Insert a new project using its folder. Use [WorkspaceSettings::get_project_data] to retrieve
+    /// its [ProjectData] and replace its [Settings].
     pub fn insert_project(&mut self, workspace_path: impl Into<PathBuf>) -> ProjectKey {
         let path = BiomePath::new(workspace_path.into());
         trace!("Insert workspace folder: {:?}", path);
-        self.data.insert(ProjectData {
+        self.data.insert(Arc::new(ProjectData {
             path,
-            settings: Settings::default(),
-            project: None,
-        })
-    }
-
-    pub fn insert_manifest(&mut self, manifest: NodeJsProject) {
-        let project_data = self.get_current_project_data_mut();
-        let _ = project_data.project.insert(manifest);
+            settings: RwLock::default(),
+            manifest: RwLock::default(),
+            custom_properties: Query::default(),
+            module_graph: Query::default(),
+        }))
     }
 
     /// Remove a project using its folder.
@@ -149,38 +247,23 @@ impl WorkspaceSettings {
         }
     }
 
-    /// Checks if the current path belongs to a registered project.
-    ///
-    /// If there's a match, and the match **isn't** the current project, it returns the new key.
-    pub fn path_belongs_to_current_workspace(&self, path: &BiomePath) -> Option<ProjectKey> {
-        if self.data.is_empty() {
-            return None;
-        }
-        trace!("Current key: {:?}", self.current_project);
-        let iter = self.data.iter();
-        for (key, path_to_settings) in iter {
-            trace!(
-                "Workspace path {:?}, file path {:?}",
-                path_to_settings.path,
-                path
-            );
-            trace!("Iter key: {:?}", key);
-            if key == self.current_project {
-                continue;
-            }
-            if path.strip_prefix(path_to_settings.path.as_path()).is_ok() {
-                trace!("Update workspace to {:?}", key);
-                return Some(key);
-            }
-        }
-        None
+    /// Retrieves the key of the project registered with the folder `workspace_path`
+    pub fn get_project_key(&self, workspace_path: &Path) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .find(|(_, project)| project.path.as_path() == workspace_path)
+            .map(|(key, _)| key)
     }
 
-    /// Checks if the current path belongs to a registered project.
+    /// Retrieves the key of the project whose folder contains `path`.
     ///
-    /// If there's a match, and the match **isn't** the current project, the function will mark the match as the current project.
-    pub fn set_current_project(&mut self, new_key: ProjectKey) {
-        self.current_project = new_key;
+    /// When the folders of several projects contain it, the innermost project is returned.
+    pub fn get_project_key_for_path(&self, path: &Path) -> Option<ProjectKey> {
+        self.data
+            .iter()
+            .filter(|(_, project)| path.starts_with(project.path.as_path()))
+            .max_by_key(|(_, project)| project.path.as_path().components().count())
+            .map(|(key, _)| key)
     }
 }
 
@@ -266,7 +349,30 @@ impl Settings {
         }
         // graphql settings
         if let Some(graphql) = configuration.graphql {
-            self.languages.graphql = graphql.into()
+            self.languages.graphql = graphql.into();
+            // the schema path is relative to the configuration file
+            if let (Some(schema), Some(working_directory)) = (
+                self.languages.graphql.linter.schema.as_mut(),
+                working_directory.as_ref(),
+            ) {
+                *schema = working_directory.join(&schema);
+            }
+        }
+        // html settings
+        if let Some(html) = configuration.html {
+            self.languages.html = html.into()
+        }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into()
+        }
+        // toml settings
+        if let Some(toml) = configuration.toml {
+            self.languages.toml = toml.into()
+        }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into()
         }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
@@ -301,6 +407,36 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for GraphQL embedded in JavaScript files
+    pub fn graphql_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for HTML files
+    pub fn html_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.html.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for YAML files
+    pub fn yaml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.yaml.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for TOML files
+    pub fn toml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.toml.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for Markdown files
+    pub fn markdown_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.markdown.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Whether the linter is disabled for CSS files
     pub fn javascript_linter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.linter.enabled.as_ref();
@@ -320,6 +456,24 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the linter is disabled for GraphQL embedded in JavaScript files
+    pub fn graphql_linter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.linter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the linter is disabled for YAML files
+    pub fn yaml_linter_disabled(&self) -> bool {
+        let enabled = self.languages.yaml.linter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the linter is disabled for TOML files
+    pub fn toml_linter_disabled(&self) -> bool {
+        let enabled = self.languages.toml.linter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -413,6 +567,7 @@ pub struct FormatSettings {
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     pub attribute_position: Option<AttributePosition>,
     pub bracket_spacing: Option<BracketSpacing>,
     /// List of ignore paths/files
@@ -430,6 +585,7 @@ impl Default for FormatSettings {
             indent_width: Some(IndentWidth::default()),
             line_ending: Some(LineEnding::default()),
             line_width: Some(LineWidth::default()),
+            max_empty_lines: Some(MaxEmptyLines::default()),
             attribute_position: Some(AttributePosition::default()),
             bracket_spacing: Some(BracketSpacing::default()),
             ignored_files: Matcher::empty(),
@@ -450,6 +606,7 @@ pub struct OverrideFormatSettings {
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     pub bracket_spacing: Option<BracketSpacing>,
     pub attribute_position: Option<AttributePosition>,
 }
@@ -468,6 +625,9 @@ pub struct LinterSettings {
 
     /// List of included paths/files to match
     pub included_files: Matcher,
+
+    /// Whether the suppression comments that disable all the rules or a group are reported
+    pub report_broad_suppressions: bool,
 }
 
 impl Default for LinterSettings {
@@ -477,6 +637,7 @@ impl Default for LinterSettings {
             rules: Some(biome_configuration::analyzer::linter::Rules::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            report_broad_suppressions: false,
         }
     }
 }
@@ -567,6 +728,9 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
+    pub toml: LanguageSettings<TomlLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
 }
 
 impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
@@ -583,8 +747,24 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
         language_setting.formatter.bracket_same_line = Some(formatter.bracket_same_line.into());
         language_setting.formatter.enabled = Some(formatter.enabled);
         language_setting.formatter.line_width = formatter.line_width;
+        language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
         language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
         language_setting.formatter.attribute_position = formatter.attribute_position;
+        language_setting.formatter.jsx_max_inline_attributes =
+            formatter.jsx_max_inline_attributes.map(Into::into);
+        language_setting.formatter.decorator_placement = Some(formatter.decorator_placement);
+        language_setting.formatter.parameter_decorators = Some(formatter.parameter_decorators);
+        language_setting.formatter.object_wrap = Some(formatter.object_wrap);
+        language_setting.formatter.operator_linebreak = Some(formatter.operator_linebreak);
+        language_setting.formatter.ternary_style = Some(formatter.ternary_style);
+        language_setting.formatter.import_export_wrap = Some(formatter.import_export_wrap);
+        language_setting.formatter.max_inline_specifiers =
+            formatter.max_inline_specifiers.map(Into::into);
+        language_setting.formatter.comment_reflow = Some(formatter.comment_reflow);
+        language_setting.formatter.jsx_children_whitespace =
+            Some(formatter.jsx_children_whitespace);
+        language_setting.formatter.single_attribute_per_line =
+            Some(formatter.single_attribute_per_line.into());
         language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
         language_setting.parser.parse_class_parameter_decorators =
@@ -593,6 +773,10 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
 
         language_setting.globals = Some(javascript.globals.into_index_set());
         language_setting.environment = javascript.jsx_runtime.into();
+        if !javascript.target.is_empty() {
+            language_setting.environment.target =
+                Some(javascript.target.into_index_set().into_iter().collect());
+        }
         language_setting.linter.enabled = Some(javascript.linter.enabled);
 
         language_setting
@@ -606,11 +790,14 @@ impl From<PartialJsonConfiguration> for LanguageSettings<JsonLanguage> {
         if let Some(parser) = json.parser {
             language_setting.parser.allow_comments = parser.allow_comments;
             language_setting.parser.allow_trailing_commas = parser.allow_trailing_commas;
+            language_setting.parser.allow_json5 = parser.allow_json5;
+            language_setting.parser.well_known_files = parser.well_known_files.unwrap_or_default();
         }
         if let Some(formatter) = json.formatter {
             language_setting.formatter.trailing_commas = formatter.trailing_commas;
             language_setting.formatter.enabled = formatter.enabled;
             language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
             language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
             language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
         }
@@ -635,6 +822,7 @@ impl From<PartialCssConfiguration> for LanguageSettings<CssLanguage> {
             language_setting.formatter.indent_width = formatter.indent_width;
             language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
             language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
             language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.quote_style = formatter.quote_style;
         }
@@ -664,6 +852,86 @@ impl From<PartialGraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
         if let Some(linter) = graphql.linter {
             // TODO: change RHS to `linter.enabled` when graphql linting is enabled by default
             language_setting.linter.enabled = Some(linter.enabled.unwrap_or_default());
+            language_setting.linter.schema = linter.schema.map(PathBuf::from);
+        }
+
+        language_setting
+    }
+}
+
+impl From<PartialHtmlConfiguration> for LanguageSettings<HtmlLanguage> {
+    fn from(html: PartialHtmlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = html.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_style = formatter.indent_style;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+            language_setting.formatter.bracket_same_line =
+                formatter.bracket_same_line.map(Into::into);
+            language_setting.formatter.attribute_position = formatter
+                .single_attribute_per_line
+                .map(to_html_attribute_position);
+            language_setting.formatter.whitespace_sensitivity = formatter.whitespace_sensitivity;
+            language_setting.formatter.max_inline_attributes =
+                formatter.max_inline_attributes.map(Into::into);
+            language_setting.formatter.collapse_empty_elements =
+                formatter.collapse_empty_elements.map(Into::into);
+        }
+
+        language_setting
+    }
+}
+
+impl From<PartialYamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: PartialYamlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+        }
+        if let Some(linter) = yaml.linter {
+            language_setting.linter.enabled = linter.enabled;
+        }
+
+        language_setting
+    }
+}
+
+impl From<PartialTomlConfiguration> for LanguageSettings<TomlLanguage> {
+    fn from(toml: PartialTomlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<TomlLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = toml.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_style = formatter.indent_style;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
+        }
+        if let Some(linter) = toml.linter {
+            language_setting.linter.enabled = linter.enabled;
+        }
+
+        language_setting
+    }
+}
+
+impl From<PartialMarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: PartialMarkdownConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter.enabled = formatter.enabled;
+            language_setting.formatter.indent_style = formatter.indent_style;
+            language_setting.formatter.indent_width = formatter.indent_width;
+            language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
         }
 
         language_setting
@@ -801,32 +1069,52 @@ fn to_file_settings(
     })
 }
 
-/// Handle object holding a temporary lock on the workspace settings until
+/// Handle object holding the settings of a project until
 /// the deferred language-specific options resolution is called
-#[derive(Debug)]
-pub struct WorkspaceSettingsHandle<'a> {
-    inner: RwLockReadGuard<'a, WorkspaceSettings>,
+///
+/// It doesn't hold any lock: the settings can be updated while the handle is alive,
+/// and the handle keeps the settings that were current when it was created.
+#[derive(Clone, Debug)]
+pub struct WorkspaceSettingsHandle {
+    settings: Option<Arc<Settings>>,
+    project: Option<(ProjectKey, Arc<ProjectData>)>,
+    /// The inputs of the workspace server, which the memoized data of the project depend on
+    inputs: Arc<Inputs>,
 }
 
-impl<'a> WorkspaceSettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
+impl WorkspaceSettingsHandle {
+    pub(crate) fn new(
+        project_key: ProjectKey,
+        project: Arc<ProjectData>,
+        inputs: Arc<Inputs>,
+    ) -> Self {
         Self {
-            inner: settings.read().unwrap(),
+            settings: Some(project.settings()),
+            project: Some((project_key, project)),
+            inputs,
         }
     }
 
     pub(crate) fn settings(&self) -> Option<&Settings> {
-        self.inner.get_current_settings()
+        self.settings.as_deref()
+    }
+
+    /// Returns the index of the custom properties of the project, if any
+    pub(crate) fn custom_properties(&self) -> Option<Arc<CustomPropertyIndex>> {
+        self.project
+            .as_ref()
+            .map(|(project_key, project)| project.custom_properties(*project_key, &self.inputs))
     }
-}
 
-impl<'a> AsRef<WorkspaceSettings> for WorkspaceSettingsHandle<'a> {
-    fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+    /// Returns the module graph of the project, if any
+    pub(crate) fn module_graph(&self) -> Option<Arc<ModuleGraph>> {
+        self.project
+            .as_ref()
+            .map(|(project_key, project)| project.module_graph(*project_key, &self.inputs))
     }
 }
 
-impl<'a> WorkspaceSettingsHandle<'a> {
+impl WorkspaceSettingsHandle {
     /// Resolve the formatting context for the given language
     pub(crate) fn format_options<L>(
         &self,
@@ -836,7 +1124,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.settings();
         let formatter = settings.map(|s| &s.formatter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -853,7 +1141,7 @@ impl<'a> WorkspaceSettingsHandle<'a> {
     where
         L: ServiceLanguage,
     {
-        let settings = self.inner.get_current_settings();
+        let settings = self.settings();
         let linter = settings.map(|s| &s.linter);
         let overrides = settings.map(|s| &s.override_settings);
         let editor_settings = settings
@@ -950,6 +1238,30 @@ impl OverrideSettings {
             .unwrap_or_default()
     }
 
+    /// Returns the browserslist queries of the environments targeted by the JavaScript file at
+    /// `path`, when they are configured
+    pub fn override_js_target(
+        &self,
+        path: &BiomePath,
+        base_setting: &Option<Vec<String>>,
+    ) -> Option<Vec<String>> {
+        self.patterns
+            .iter()
+            // Reverse the traversal as only the last override takes effect
+            .rev()
+            .find_map(|pattern| {
+                if pattern.languages.javascript.environment.target.is_some()
+                    && pattern.include.matches_path(path)
+                    && !pattern.exclude.matches_path(path)
+                {
+                    pattern.languages.javascript.environment.target.clone()
+                } else {
+                    None
+                }
+            })
+            .or_else(|| base_setting.clone())
+    }
+
     pub fn override_jsx_runtime(&self, path: &BiomePath, base_setting: JsxRuntime) -> JsxRuntime {
         self.patterns
             .iter()
@@ -1033,6 +1345,45 @@ impl OverrideSettings {
         options
     }
 
+    pub fn to_override_yaml_format_options(
+        &self,
+        path: &Path,
+        mut options: YamlFormatOptions,
+    ) -> YamlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_yaml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_toml_format_options(
+        &self,
+        path: &Path,
+        mut options: TomlFormatOptions,
+    ) -> TomlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_toml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_markdown_format_options(
+        &self,
+        path: &Path,
+        mut options: MarkdownFormatOptions,
+    ) -> MarkdownFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                pattern.apply_overrides_to_markdown_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Path,
@@ -1102,6 +1453,21 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_yaml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_toml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1186,6 +1552,9 @@ pub struct OverrideSettingPattern {
     pub(crate) cached_grit_format_options: RwLock<Option<GritFormatOptions>>,
     pub(crate) cached_graphql_format_options: RwLock<Option<GraphqlFormatOptions>>,
     pub(crate) cached_html_format_options: RwLock<Option<HtmlFormatOptions>>,
+    pub(crate) cached_yaml_format_options: RwLock<Option<YamlFormatOptions>>,
+    pub(crate) cached_toml_format_options: RwLock<Option<TomlFormatOptions>>,
+    pub(crate) cached_markdown_format_options: RwLock<Option<MarkdownFormatOptions>>,
     pub(crate) cached_js_parser_options: RwLock<Option<JsParserOptions>>,
     pub(crate) _cached_json_parser_options: RwLock<Option<JsonParserOptions>>,
     pub(crate) cached_css_parser_options: RwLock<Option<CssParserOptions>>,
@@ -1213,6 +1582,9 @@ impl OverrideSettingPattern {
         if let Some(line_width) = js_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = js_formatter.max_empty_lines.or(formatter.max_empty_lines) {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(quote_style) = js_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
@@ -1243,6 +1615,39 @@ impl OverrideSettingPattern {
         {
             options.set_attribute_position(attribute_position);
         }
+        if let Some(jsx_max_inline_attributes) = js_formatter.jsx_max_inline_attributes {
+            options.set_jsx_max_inline_attributes(jsx_max_inline_attributes);
+        }
+        if let Some(decorator_placement) = js_formatter.decorator_placement {
+            options.set_decorator_placement(decorator_placement);
+        }
+        if let Some(parameter_decorators) = js_formatter.parameter_decorators {
+            options.set_parameter_decorators(parameter_decorators);
+        }
+        if let Some(object_wrap) = js_formatter.object_wrap {
+            options.set_object_wrap(object_wrap);
+        }
+        if let Some(operator_linebreak) = js_formatter.operator_linebreak {
+            options.set_operator_linebreak(operator_linebreak);
+        }
+        if let Some(ternary_style) = js_formatter.ternary_style {
+            options.set_ternary_style(ternary_style);
+        }
+        if let Some(import_export_wrap) = js_formatter.import_export_wrap {
+            options.set_import_export_wrap(import_export_wrap);
+        }
+        if let Some(max_inline_specifiers) = js_formatter.max_inline_specifiers {
+            options.set_max_inline_specifiers(max_inline_specifiers);
+        }
+        if let Some(comment_reflow) = js_formatter.comment_reflow {
+            options.set_comment_reflow(comment_reflow);
+        }
+        if let Some(jsx_children_whitespace) = js_formatter.jsx_children_whitespace {
+            options.set_jsx_children_whitespace(jsx_children_whitespace);
+        }
+        if let Some(single_attribute_per_line) = js_formatter.single_attribute_per_line {
+            options.set_single_attribute_per_line(single_attribute_per_line);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_js_format_options.write() {
             let options = options.clone();
@@ -1273,6 +1678,10 @@ impl OverrideSettingPattern {
         if let Some(line_width) = json_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = json_formatter.max_empty_lines.or(formatter.max_empty_lines)
+        {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(trailing_commas) = json_formatter.trailing_commas {
             options.set_trailing_commas(trailing_commas);
         }
@@ -1306,6 +1715,9 @@ impl OverrideSettingPattern {
         if let Some(line_width) = css_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = css_formatter.max_empty_lines.or(formatter.max_empty_lines) {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(quote_style) = css_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
@@ -1408,6 +1820,24 @@ impl OverrideSettingPattern {
         if let Some(line_width) = html_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(attribute_position) = html_formatter
+            .attribute_position
+            .or(formatter.attribute_position)
+        {
+            options.set_attribute_position(attribute_position);
+        }
+        if let Some(bracket_same_line) = html_formatter.bracket_same_line {
+            options.set_bracket_same_line(bracket_same_line);
+        }
+        if let Some(whitespace_sensitivity) = html_formatter.whitespace_sensitivity {
+            options.set_whitespace_sensitivity(whitespace_sensitivity);
+        }
+        if let Some(max_inline_attributes) = html_formatter.max_inline_attributes {
+            options.set_max_inline_attributes(max_inline_attributes);
+        }
+        if let Some(collapse_empty_elements) = html_formatter.collapse_empty_elements {
+            options.set_collapse_empty_elements(collapse_empty_elements);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_html_format_options.write() {
             let options = options.clone();
@@ -1415,6 +1845,93 @@ impl OverrideSettingPattern {
         }
     }
 
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_yaml_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_yaml_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
+    fn apply_overrides_to_toml_format_options(&self, options: &mut TomlFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_toml_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let toml_formatter = &self.languages.toml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_style) = toml_formatter.indent_style.or(formatter.indent_style) {
+            options.set_indent_style(indent_style);
+        }
+        if let Some(indent_width) = toml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = toml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = toml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_toml_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        if let Ok(readonly_cache) = self.cached_markdown_format_options.read() {
+            if let Some(cached_options) = readonly_cache.as_ref() {
+                *options = cached_options.clone();
+                return;
+            }
+        }
+
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_style) = markdown_formatter.indent_style.or(formatter.indent_style) {
+            options.set_indent_style(indent_style);
+        }
+        if let Some(indent_width) = markdown_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = markdown_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+
+        if let Ok(mut writeonly_cache) = self.cached_markdown_format_options.write() {
+            let options = options.clone();
+            let _ = writeonly_cache.insert(options);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         if let Ok(readonly_cache) = self.cached_js_parser_options.read() {
             if let Some(cached_options) = readonly_cache.as_ref() {
@@ -1443,6 +1960,11 @@ impl OverrideSettingPattern {
         if let Some(allow_trailing_commas) = json_parser.allow_trailing_commas {
             options.allow_trailing_commas = allow_trailing_commas;
         }
+        match json_parser.allow_json5 {
+            Some(true) => *options = options.with_allow_json5(),
+            Some(false) => options.allow_json5 = false,
+            None => {}
+        }
     }
 
     fn apply_overrides_to_css_parser_options(&self, options: &mut CssParserOptions) {
@@ -1565,6 +2087,7 @@ pub fn to_override_settings(
                 indent_width: formatter.indent_width,
                 line_ending: formatter.line_ending,
                 line_width: formatter.line_width,
+                max_empty_lines: formatter.max_empty_lines,
                 bracket_spacing: formatter.bracket_spacing,
                 attribute_position: formatter.attribute_position,
             })
@@ -1587,6 +2110,7 @@ pub fn to_override_settings(
         let json = pattern.json.take().unwrap_or_default();
         let css = pattern.css.take().unwrap_or_default();
         let graphql = pattern.graphql.take().unwrap_or_default();
+        let html = pattern.html.take().unwrap_or_default();
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
 
@@ -1594,6 +2118,7 @@ pub fn to_override_settings(
         languages.css = to_css_language_settings(css, &current_settings.languages.css);
         languages.graphql =
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
+        languages.html = to_html_language_settings(html, &current_settings.languages.html);
 
         let pattern_setting = OverrideSettingPattern {
             include: to_matcher(working_directory.clone(), pattern.include.as_ref())?,
@@ -1626,8 +2151,23 @@ fn to_javascript_language_settings(
     language_setting.formatter.arrow_parentheses = formatter.arrow_parentheses;
     language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
     language_setting.formatter.bracket_same_line = formatter.bracket_same_line.map(Into::into);
+    language_setting.formatter.jsx_max_inline_attributes =
+        formatter.jsx_max_inline_attributes.map(Into::into);
+    language_setting.formatter.decorator_placement = formatter.decorator_placement;
+    language_setting.formatter.parameter_decorators = formatter.parameter_decorators;
+    language_setting.formatter.object_wrap = formatter.object_wrap;
+    language_setting.formatter.operator_linebreak = formatter.operator_linebreak;
+    language_setting.formatter.ternary_style = formatter.ternary_style;
+    language_setting.formatter.import_export_wrap = formatter.import_export_wrap;
+    language_setting.formatter.max_inline_specifiers =
+        formatter.max_inline_specifiers.map(Into::into);
+    language_setting.formatter.comment_reflow = formatter.comment_reflow;
+    language_setting.formatter.jsx_children_whitespace = formatter.jsx_children_whitespace;
+    language_setting.formatter.single_attribute_per_line =
+        formatter.single_attribute_per_line.map(Into::into);
     language_setting.formatter.enabled = formatter.enabled;
     language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
     language_setting.formatter.line_ending = formatter.line_ending;
     language_setting.formatter.indent_width = formatter
         .indent_width
@@ -1650,6 +2190,10 @@ fn to_javascript_language_settings(
         .jsx_runtime
         .unwrap_or(parent_settings.environment.jsx_runtime);
 
+    language_setting.environment.target = conf
+        .target
+        .map(|target| target.into_index_set().into_iter().collect());
+
     language_setting
 }
 
@@ -1662,6 +2206,7 @@ fn to_json_language_settings(
 
     language_setting.formatter.enabled = formatter.enabled;
     language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
     language_setting.formatter.line_ending = formatter.line_ending;
     language_setting.formatter.indent_width = formatter
         .indent_width
@@ -1678,6 +2223,8 @@ fn to_json_language_settings(
         .allow_trailing_commas
         .or(parent_parser.allow_trailing_commas);
 
+    language_setting.parser.allow_json5 = parser.allow_json5.or(parent_parser.allow_json5);
+
     language_setting
 }
 
@@ -1690,6 +2237,7 @@ fn to_css_language_settings(
 
     language_setting.formatter.enabled = formatter.enabled;
     language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.max_empty_lines = formatter.max_empty_lines;
     language_setting.formatter.line_ending = formatter.line_ending;
     language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
     language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
@@ -1723,6 +2271,40 @@ fn to_graphql_language_settings(
     language_setting
 }
 
+fn to_html_language_settings(
+    mut conf: PartialHtmlConfiguration,
+    _parent_settings: &LanguageSettings<HtmlLanguage>,
+) -> LanguageSettings<HtmlLanguage> {
+    let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter.enabled = formatter.enabled;
+    language_setting.formatter.line_width = formatter.line_width;
+    language_setting.formatter.line_ending = formatter.line_ending;
+    language_setting.formatter.indent_width = formatter.indent_width;
+    language_setting.formatter.indent_style = formatter.indent_style;
+    language_setting.formatter.bracket_same_line = formatter.bracket_same_line.map(Into::into);
+    language_setting.formatter.attribute_position = formatter
+        .single_attribute_per_line
+        .map(to_html_attribute_position);
+    language_setting.formatter.whitespace_sensitivity = formatter.whitespace_sensitivity;
+    language_setting.formatter.max_inline_attributes =
+        formatter.max_inline_attributes.map(Into::into);
+    language_setting.formatter.collapse_empty_elements =
+        formatter.collapse_empty_elements.map(Into::into);
+
+    language_setting
+}
+
+/// `singleAttributePerLine` is the HTML spelling of the `multiline` attribute position
+fn to_html_attribute_position(single_attribute_per_line: bool) -> AttributePosition {
+    if single_attribute_per_line {
+        AttributePosition::Multiline
+    } else {
+        AttributePosition::Auto
+    }
+}
+
 pub fn to_format_settings(
     working_directory: Option<PathBuf>,
     conf: FormatterConfiguration,
@@ -1736,6 +2318,7 @@ pub fn to_format_settings(
         indent_width: Some(indent_width),
         line_ending: Some(conf.line_ending),
         line_width: Some(conf.line_width),
+        max_empty_lines: Some(conf.max_empty_lines),
         format_with_errors: conf.format_with_errors,
         attribute_position: Some(conf.attribute_position),
         bracket_spacing: Some(conf.bracket_spacing),
@@ -1765,6 +2348,7 @@ impl TryFrom<OverrideFormatterConfiguration> for FormatSettings {
             indent_width: Some(indent_width),
             line_ending: conf.line_ending,
             line_width: conf.line_width,
+            max_empty_lines: conf.max_empty_lines,
             attribute_position: Some(AttributePosition::default()),
             bracket_spacing: Some(BracketSpacing::default()),
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
@@ -1783,6 +2367,7 @@ pub fn to_linter_settings(
         rules: Some(conf.rules),
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory.clone(), Some(&conf.include))?,
+        report_broad_suppressions: conf.report_broad_suppressions,
     })
 }
 
@@ -1795,6 +2380,7 @@ impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
             rules: conf.rules,
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
+            report_broad_suppressions: false,
         })
     }
 }
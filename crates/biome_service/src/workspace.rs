@@ -46,6 +46,9 @@
 //! - [WorkspaceError::NotFound]: This error is returned when an operation is being
 //!     run on a path that doesn't correspond to any open document: either the
 //!     document has been closed or the client didn't open it in the first place
+//! - [WorkspaceError::ProjectNotFound]: This error is returned when an operation
+//!     is being run on a project key that doesn't correspond to any project
+//!     registered with [Workspace::register_project_folder]
 //! - [WorkspaceError::SourceFileNotSupported]: This error is returned when an
 //!     operation could not be completed because the language associated with the
 //!     document does not implement the required capability: for instance trying to
@@ -72,17 +75,21 @@ use enumflags2::{bitflags, BitFlags};
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use slotmap::{new_key_type, DenseSlotMap};
 use smallvec::SmallVec;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::{debug, instrument};
 
 mod client;
+pub(crate) mod incremental;
+mod metrics;
 mod server;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SupportsFeatureParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub features: FeatureName,
 }
@@ -186,6 +193,14 @@ impl FileFeaturesResult {
                 !settings.formatter().enabled || settings.json_formatter_disabled()
             } else if file_source.is_css_like() {
                 !settings.formatter().enabled || settings.css_formatter_disabled()
+            } else if file_source.is_html_like() {
+                !settings.formatter().enabled || settings.html_formatter_disabled()
+            } else if file_source.is_yaml_like() {
+                !settings.formatter().enabled || settings.yaml_formatter_disabled()
+            } else if file_source.is_toml_like() {
+                !settings.formatter().enabled || settings.toml_formatter_disabled()
+            } else if file_source.is_markdown_like() {
+                !settings.formatter().enabled || settings.markdown_formatter_disabled()
             } else {
                 !settings.formatter().enabled
             };
@@ -203,6 +218,13 @@ impl FileFeaturesResult {
                 !settings.linter().enabled || settings.json_linter_disabled()
             } else if file_source.is_css_like() {
                 !settings.linter().enabled || settings.css_linter_disabled()
+            } else if file_source.is_yaml_like() {
+                !settings.linter().enabled || settings.yaml_linter_disabled()
+            } else if file_source.is_toml_like() {
+                !settings.linter().enabled || settings.toml_linter_disabled()
+            } else if file_source.is_markdown_like() {
+                // Only the JavaScript code blocks of Markdown files are linted
+                !settings.linter().enabled || settings.javascript_linter_disabled()
             } else {
                 !settings.linter().enabled
             }
@@ -506,6 +528,8 @@ impl FeaturesBuilder {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub configuration: PartialConfiguration,
     // @ematipico TODO: have a better data structure for this
     pub vcs_base_path: Option<PathBuf>,
@@ -527,6 +551,8 @@ pub struct ProjectFeaturesResult {}
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OpenFileParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub content: String,
     pub version: i32,
@@ -535,6 +561,8 @@ pub struct OpenFileParams {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SetManifestForProjectParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub manifest_path: BiomePath,
     pub content: String,
     pub version: i32,
@@ -543,6 +571,7 @@ pub struct SetManifestForProjectParams {
 impl From<(BiomePath, String)> for SetManifestForProjectParams {
     fn from((manifest_path, content): (BiomePath, String)) -> Self {
         Self {
+            project_key: ProjectKey::default(),
             manifest_path,
             content,
             version: 0,
@@ -553,6 +582,8 @@ impl From<(BiomePath, String)> for SetManifestForProjectParams {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetSyntaxTreeParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
@@ -566,6 +597,8 @@ pub struct GetSyntaxTreeResult {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetControlFlowGraphParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub cursor: TextSize,
 }
@@ -573,18 +606,24 @@ pub struct GetControlFlowGraphParams {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetFormatterIRParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetFileContentParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChangeFileParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub content: String,
     pub version: i32,
@@ -593,12 +632,16 @@ pub struct ChangeFileParams {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CloseFileParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PullDiagnosticsParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
@@ -617,6 +660,8 @@ pub struct PullDiagnosticsResult {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PullActionsParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub range: Option<TextRange>,
     pub only: Vec<RuleSelector>,
@@ -640,12 +685,16 @@ pub struct CodeAction {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FormatFileParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FormatRangeParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub range: TextRange,
 }
@@ -653,6 +702,8 @@ pub struct FormatRangeParams {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FormatOnTypeParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub offset: TextSize,
 }
@@ -672,6 +723,8 @@ pub enum FixFileMode {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FixFileParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
@@ -707,6 +760,8 @@ pub struct FixAction {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RenameParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub symbol_at: TextSize,
     pub new_name: String,
@@ -719,6 +774,35 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// The edits of the other files of the project, when the symbol is used across the project,
+    /// such as a CSS custom property or a CSS class
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_files: Vec<RenamedFile>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenamedFile {
+    pub path: BiomePath,
+    /// List of text edit operations to apply on the content of the file
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenameTokenParams {
+    pub project_key: ProjectKey,
+    /// The name of the token, such as `--color-primary` for a CSS custom property, or `.button`
+    /// for a CSS class
+    pub name: String,
+    pub new_name: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenameTokenResult {
+    /// The edits of the files of the project that use the token
+    pub files: Vec<RenamedFile>,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -750,9 +834,53 @@ pub enum RageEntry {
     Markup(MarkupBuf),
 }
 
+#[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricsParams {}
+
+/// The metrics collected by the server since it started
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MetricsResult {
+    /// The number of requests served, by method
+    pub requests: BTreeMap<String, u64>,
+    /// The lookups in the cache of syntax trees
+    pub parse_cache: CacheMetrics,
+    /// The files that took the longest to process, from the slowest
+    pub slowest_files: Vec<FileMetrics>,
+    pub memory: MemoryMetrics,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    /// The ratio of hits among the lookups, or `None` if there was no lookup
+    pub hit_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FileMetrics {
+    pub path: String,
+    /// The method of the slowest request on the file
+    pub method: String,
+    pub duration_ms: f64,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MemoryMetrics {
+    /// The resident set size of the server process. It's only available on Linux.
+    pub resident_bytes: Option<u64>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OrganizeImportsParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
 }
 
@@ -798,6 +926,8 @@ pub struct ParsePatternResult {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SearchPatternParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub path: BiomePath,
     pub pattern: PatternId,
 }
@@ -846,6 +976,8 @@ impl From<&str> for PatternId {
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IsPathIgnoredParams {
+    #[serde(default)]
+    pub project_key: ProjectKey,
     pub biome_path: BiomePath,
     pub features: FeatureName,
 }
@@ -855,7 +987,6 @@ pub struct IsPathIgnoredParams {
 #[serde(rename_all = "camelCase")]
 pub struct RegisterProjectFolderParams {
     pub path: Option<PathBuf>,
-    pub set_as_current_workspace: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -958,9 +1089,18 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Return the edits of the files of the project after renaming a CSS custom
+    /// property or a CSS class
+    fn rename_token(&self, params: RenameTokenParams) -> Result<RenameTokenResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
+    /// Returns the metrics collected by the server since it started.
+    ///
+    /// The metrics never leave the machine: they're only returned to the client that asks.
+    fn metrics(&self, params: MetricsParams) -> Result<MetricsResult, WorkspaceError>;
+
     /// Parses a pattern to be used in follow-up [`Self::search_pattern`] requests.
     ///
     /// Clients should call [`Self::drop_pattern()`] when they no need longer need it.
@@ -1008,18 +1148,25 @@ where
 /// automatically on drop
 pub struct FileGuard<'app, W: Workspace + ?Sized> {
     workspace: &'app W,
+    project_key: ProjectKey,
     path: BiomePath,
 }
 
 impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
     pub fn open(workspace: &'app W, params: OpenFileParams) -> Result<Self, WorkspaceError> {
+        let project_key = params.project_key;
         let path = params.path.clone();
         workspace.open_file(params)?;
-        Ok(Self { workspace, path })
+        Ok(Self {
+            workspace,
+            project_key,
+            path,
+        })
     }
 
     pub fn get_syntax_tree(&self) -> Result<GetSyntaxTreeResult, WorkspaceError> {
         self.workspace.get_syntax_tree(GetSyntaxTreeParams {
+            project_key: self.project_key,
             path: self.path.clone(),
         })
     }
@@ -1027,6 +1174,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
     pub fn get_control_flow_graph(&self, cursor: TextSize) -> Result<String, WorkspaceError> {
         self.workspace
             .get_control_flow_graph(GetControlFlowGraphParams {
+                project_key: self.project_key,
                 path: self.path.clone(),
                 cursor,
             })
@@ -1034,6 +1182,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
     pub fn change_file(&self, version: i32, content: String) -> Result<(), WorkspaceError> {
         self.workspace.change_file(ChangeFileParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             version,
             content,
@@ -1042,6 +1191,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
     pub fn get_file_content(&self) -> Result<String, WorkspaceError> {
         self.workspace.get_file_content(GetFileContentParams {
+            project_key: self.project_key,
             path: self.path.clone(),
         })
     }
@@ -1054,6 +1204,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         skip: Vec<RuleSelector>,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             categories,
             max_diagnostics: max_diagnostics.into(),
@@ -1069,6 +1220,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         skip: Vec<RuleSelector>,
     ) -> Result<PullActionsResult, WorkspaceError> {
         self.workspace.pull_actions(PullActionsParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             range,
             only,
@@ -1078,12 +1230,14 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
     pub fn format_file(&self) -> Result<Printed, WorkspaceError> {
         self.workspace.format_file(FormatFileParams {
+            project_key: self.project_key,
             path: self.path.clone(),
         })
     }
 
     pub fn format_range(&self, range: TextRange) -> Result<Printed, WorkspaceError> {
         self.workspace.format_range(FormatRangeParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             range,
         })
@@ -1091,6 +1245,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
     pub fn format_on_type(&self, offset: TextSize) -> Result<Printed, WorkspaceError> {
         self.workspace.format_on_type(FormatOnTypeParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             offset,
         })
@@ -1105,6 +1260,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         skip: Vec<RuleSelector>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             fix_file_mode,
             should_format,
@@ -1116,12 +1272,14 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
 
     pub fn organize_imports(&self) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.workspace.organize_imports(OrganizeImportsParams {
+            project_key: self.project_key,
             path: self.path.clone(),
         })
     }
 
     pub fn search_pattern(&self, pattern: &PatternId) -> Result<SearchResults, WorkspaceError> {
         self.workspace.search_pattern(SearchPatternParams {
+            project_key: self.project_key,
             path: self.path.clone(),
             pattern: pattern.clone(),
         })
@@ -1132,6 +1290,7 @@ impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
     fn drop(&mut self) {
         self.workspace
             .close_file(CloseFileParams {
+                project_key: self.project_key,
                 path: self.path.clone(),
             })
             // `close_file` can only error if the file was already closed, in
@@ -1154,6 +1313,10 @@ fn test_order() {
 }
 
 new_key_type! {
+    /// The key of a project, returned by [Workspace::register_project_folder]
+    ///
+    /// The requests that omit it apply to the innermost project whose folder contains
+    /// the path of the request.
     pub struct ProjectKey;
 }
 
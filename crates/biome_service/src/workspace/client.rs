@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectKey, RageParams, RageResult, RegisterProjectFolderParams,
-    ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
+    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, MetricsParams, MetricsResult,
+    OrganizeImportsParams, OrganizeImportsResult, ProjectKey, RageParams, RageResult,
+    RegisterProjectFolderParams, ServerInfo, SetManifestForProjectParams,
+    UnregisterProjectFolderParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -16,8 +17,9 @@ use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams,
-    SearchResults, SupportsFeatureParams, UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, RenameTokenParams,
+    RenameTokenResult, SearchPatternParams, SearchResults, SupportsFeatureParams,
+    UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -200,10 +202,18 @@ where
         self.request("biome/rename", params)
     }
 
+    fn rename_token(&self, params: RenameTokenParams) -> Result<RenameTokenResult, WorkspaceError> {
+        self.request("biome/rename_token", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
 
+    fn metrics(&self, params: MetricsParams) -> Result<MetricsResult, WorkspaceError> {
+        self.request("biome/metrics", params)
+    }
+
     fn parse_pattern(
         &self,
         params: super::ParsePatternParams,
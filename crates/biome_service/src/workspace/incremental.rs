@@ -0,0 +1,298 @@
+//! Memoization of the computations of the workspace server.
+//!
+//! The server tracks the inputs of its computations, such as the content of the documents and
+//! the settings of the projects, along with the revision at which each of them last changed.
+//! A [Query] memoizes its results with the inputs that the computation read, and the revision
+//! of each of them at the time. A result is reused as long as none of these inputs changed, so
+//! editing a document only recomputes the results that read this document, and updating the
+//! settings only recomputes the results that read them.
+//!
+//! A computation that reads the result of another query also depends on the inputs of this
+//! result, so the dependencies are tracked across the queries.
+
+use super::ProjectKey;
+use biome_fs::BiomePath;
+use dashmap::DashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A point in the history of the inputs. Each change of an input creates a new revision.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Revision(u64);
+
+/// An input of the computations of the workspace server
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Input {
+    /// The content and the file source of a document
+    Document(BiomePath),
+    /// The settings of a project. They change when the settings of the project are updated.
+    Settings(ProjectKey),
+}
+
+/// The revisions at which the inputs last changed
+#[derive(Debug, Default)]
+pub(crate) struct Inputs {
+    /// The last revision
+    revision: AtomicU64,
+    changed_at: DashMap<Input, Revision>,
+}
+
+impl Inputs {
+    /// Records a change of `input`, which invalidates the results that read it.
+    ///
+    /// It must be called after the input is updated, so that a computation that reads the
+    /// previous revision of the input can't be reused.
+    pub(crate) fn changed(&self, input: Input) {
+        let revision = Revision(self.revision.fetch_add(1, Ordering::AcqRel) + 1);
+        self.changed_at.insert(input, revision);
+    }
+
+    /// Stops tracking `input`, for example when its document is closed.
+    ///
+    /// The input is then back to the initial revision, so the results that read it while it was
+    /// tracked are invalidated, and the server doesn't keep an entry for each document it ever
+    /// opened.
+    pub(crate) fn removed(&self, input: &Input) {
+        self.changed_at.remove(input);
+    }
+
+    /// Returns the revision at which `input` last changed
+    fn changed_at(&self, input: &Input) -> Revision {
+        self.changed_at
+            .get(input)
+            .map_or(Revision::default(), |revision| *revision)
+    }
+}
+
+/// The inputs read by a computation
+#[derive(Debug)]
+pub(crate) struct Dependencies<'a> {
+    inputs: &'a Inputs,
+    read: Vec<(Input, Revision)>,
+}
+
+impl<'a> Dependencies<'a> {
+    /// Starts tracking the inputs read by a computation
+    pub(crate) fn new(inputs: &'a Inputs) -> Self {
+        Self {
+            inputs,
+            read: Vec::new(),
+        }
+    }
+
+    /// Records that the computation reads `input`.
+    ///
+    /// It must be called before the input is read.
+    pub(crate) fn read(&mut self, input: Input) {
+        let revision = self.inputs.changed_at(&input);
+        if !self.read.iter().any(|(read, _)| *read == input) {
+            self.read.push((input, revision));
+        }
+    }
+
+    fn extend(&mut self, dependencies: &[(Input, Revision)]) {
+        for (input, revision) in dependencies {
+            if !self.read.iter().any(|(read, _)| read == input) {
+                self.read.push((input.clone(), *revision));
+            }
+        }
+    }
+}
+
+/// A result along with the inputs that its computation read
+#[derive(Debug)]
+struct Memo<V> {
+    value: V,
+    dependencies: Vec<(Input, Revision)>,
+}
+
+impl<V> Memo<V> {
+    /// Returns `true` if none of the inputs changed since the result was computed
+    fn is_up_to_date(&self, inputs: &Inputs) -> bool {
+        self.dependencies
+            .iter()
+            .all(|(input, revision)| inputs.changed_at(input) == *revision)
+    }
+}
+
+/// The memoized results of a computation, by key
+#[derive(Debug)]
+pub(crate) struct Query<K: Eq + Hash, V> {
+    memos: DashMap<K, Memo<V>>,
+}
+
+impl<K: Eq + Hash, V> Default for Query<K, V> {
+    fn default() -> Self {
+        Self {
+            memos: DashMap::default(),
+        }
+    }
+}
+
+/// Whether a result was reused or computed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Lookup {
+    Reused,
+    Computed,
+}
+
+impl<K: Eq + Hash, V: Clone> Query<K, V> {
+    /// Returns the result for `key`, which is computed by `compute` unless the memoized result
+    /// is up to date.
+    ///
+    /// The inputs that the result depends on are added to `dependencies`, whether the result is
+    /// computed or reused. No lock is held during the computation, so `compute` can read the
+    /// results of this query for other keys. The same result can be computed concurrently by
+    /// two requests, in which case the last computed result is kept.
+    pub(crate) fn get<E>(
+        &self,
+        key: K,
+        dependencies: &mut Dependencies,
+        compute: impl FnOnce(&K, &mut Dependencies) -> Result<V, E>,
+    ) -> Result<(V, Lookup), E> {
+        let inputs = dependencies.inputs;
+        if let Some(memo) = self.memos.get(&key) {
+            if memo.is_up_to_date(inputs) {
+                dependencies.extend(&memo.dependencies);
+                return Ok((memo.value.clone(), Lookup::Reused));
+            }
+        }
+        let mut read = Dependencies::new(inputs);
+        let value = compute(&key, &mut read)?;
+        dependencies.extend(&read.read);
+        self.memos.insert(
+            key,
+            Memo {
+                value: value.clone(),
+                dependencies: read.read,
+            },
+        );
+        Ok((value, Lookup::Computed))
+    }
+
+    /// Drops the result for `key`, for example when its document is closed
+    pub(crate) fn remove(&self, key: &K) {
+        self.memos.remove(key);
+    }
+
+    /// Drops the results whose key doesn't satisfy `keep`, for example the results of a closed
+    /// document or of a project whose settings changed
+    pub(crate) fn retain(&self, mut keep: impl FnMut(&K) -> bool) {
+        self.memos.retain(|key, _| keep(key));
+    }
+
+    /// Returns the number of memoized results
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.memos.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Dependencies, Input, Inputs, Lookup, Query};
+    use biome_fs::BiomePath;
+    use std::convert::Infallible;
+
+    fn document(path: &str) -> Input {
+        Input::Document(BiomePath::new(path))
+    }
+
+    fn get_length(
+        query: &Query<&'static str, usize>,
+        inputs: &Inputs,
+        key: &'static str,
+    ) -> Lookup {
+        let mut dependencies = Dependencies::new(inputs);
+        let Ok((_, lookup)) = query.get(key, &mut dependencies, |key, dependencies| {
+            dependencies.read(document(key));
+            Ok::<_, Infallible>(key.len())
+        });
+        lookup
+    }
+
+    #[test]
+    fn reuses_the_results_until_their_inputs_change() {
+        let inputs = Inputs::default();
+        let query = Query::default();
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Computed);
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Reused);
+
+        // A change of another input doesn't invalidate the result
+        inputs.changed(document("b.js"));
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Reused);
+
+        inputs.changed(document("a.js"));
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Computed);
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Reused);
+    }
+
+    #[test]
+    fn tracks_the_dependencies_across_the_queries() {
+        let inputs = Inputs::default();
+        let lengths = Query::default();
+        let totals: Query<(), usize> = Query::default();
+        let get_total = || {
+            let mut dependencies = Dependencies::new(&inputs);
+            let Ok((_, lookup)) = totals.get((), &mut dependencies, |_, dependencies| {
+                let Ok((length, _)) = lengths.get("a.js", dependencies, |key, dependencies| {
+                    dependencies.read(document(key));
+                    Ok::<_, Infallible>(key.len())
+                });
+                Ok::<_, Infallible>(length)
+            });
+            lookup
+        };
+        assert_eq!(get_total(), Lookup::Computed);
+        assert_eq!(get_total(), Lookup::Reused);
+
+        inputs.changed(document("a.js"));
+        assert_eq!(get_total(), Lookup::Computed);
+    }
+
+    #[test]
+    fn computes_the_results_that_read_the_same_query() {
+        let inputs = Inputs::default();
+        let query: Query<usize, usize> = Query::default();
+        fn sum(query: &Query<usize, usize>, inputs: &Inputs, key: usize) -> usize {
+            let mut dependencies = Dependencies::new(inputs);
+            let Ok((value, _)) = query.get(key, &mut dependencies, |key, _| {
+                // The computation reads the results of the query for the previous keys
+                Ok::<_, Infallible>(match *key {
+                    0 => 0,
+                    key => key + sum(query, inputs, key - 1),
+                })
+            });
+            value
+        }
+        assert_eq!(sum(&query, &inputs, 100), 5050);
+        assert_eq!(query.len(), 101);
+    }
+
+    #[test]
+    fn invalidates_the_results_of_the_removed_inputs() {
+        let inputs = Inputs::default();
+        let query = Query::default();
+        inputs.changed(document("a.js"));
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Computed);
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Reused);
+
+        inputs.removed(&document("a.js"));
+        assert!(inputs.changed_at.is_empty());
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Computed);
+    }
+
+    #[test]
+    fn drops_the_evicted_results() {
+        let inputs = Inputs::default();
+        let query = Query::default();
+        get_length(&query, &inputs, "a.js");
+        get_length(&query, &inputs, "b.js");
+        assert_eq!(query.len(), 2);
+
+        query.retain(|key| *key != "a.js");
+        assert_eq!(query.len(), 1);
+        assert_eq!(get_length(&query, &inputs, "a.js"), Lookup::Computed);
+        assert_eq!(get_length(&query, &inputs, "b.js"), Lookup::Reused);
+    }
+}
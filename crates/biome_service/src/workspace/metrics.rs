@@ -0,0 +1,141 @@
+//! Metrics collected by the workspace servers of the process.
+//!
+//! The metrics stay in memory: they're only returned by [Workspace::metrics](super::Workspace::metrics),
+//! for example when running `biome daemon status --metrics`.
+
+use super::{CacheMetrics, FileMetrics, MemoryMetrics, MetricsResult};
+use biome_fs::BiomePath;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// The number of files reported by [MetricsResult::slowest_files]
+const SLOWEST_FILES_LIMIT: usize = 10;
+
+/// The metrics are shared by all the workspaces of the process, so that the daemon reports the
+/// requests of every client, including the clients that have disconnected.
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::default);
+
+#[derive(Default)]
+struct Metrics {
+    /// The number of requests, by method
+    requests: Mutex<BTreeMap<&'static str, u64>>,
+    parse_cache_hits: AtomicU64,
+    parse_cache_misses: AtomicU64,
+    /// The slowest requests on a file, sorted from the slowest. There's one entry per file.
+    slowest_files: Mutex<Vec<(BiomePath, &'static str, Duration)>>,
+}
+
+/// Records a request when it's dropped, along with the time spent on the file
+pub(crate) struct RequestTimer<'a> {
+    method: &'static str,
+    path: Option<&'a BiomePath>,
+    start: Instant,
+}
+
+impl Drop for RequestTimer<'_> {
+    fn drop(&mut self) {
+        let duration = self.start.elapsed();
+        *METRICS
+            .requests
+            .lock()
+            .unwrap()
+            .entry(self.method)
+            .or_default() += 1;
+
+        let Some(path) = self.path else {
+            return;
+        };
+        let mut slowest_files = METRICS.slowest_files.lock().unwrap();
+        if let Some(index) = slowest_files.iter().position(|(file, ..)| file == path) {
+            if slowest_files[index].2 >= duration {
+                return;
+            }
+            slowest_files.remove(index);
+        } else if slowest_files.len() == SLOWEST_FILES_LIMIT
+            && slowest_files
+                .last()
+                .is_some_and(|(.., slowest)| *slowest >= duration)
+        {
+            return;
+        }
+        let index = slowest_files.partition_point(|(.., slowest)| *slowest >= duration);
+        slowest_files.insert(index, (path.clone(), self.method, duration));
+        slowest_files.truncate(SLOWEST_FILES_LIMIT);
+    }
+}
+
+/// Starts timing a request of the given method, on the given file
+pub(crate) fn request<'a>(method: &'static str, path: Option<&'a BiomePath>) -> RequestTimer<'a> {
+    RequestTimer {
+        method,
+        path,
+        start: Instant::now(),
+    }
+}
+
+/// Records a lookup in the cache of syntax trees
+pub(crate) fn parse_cache_lookup(hit: bool) {
+    let counter = if hit {
+        &METRICS.parse_cache_hits
+    } else {
+        &METRICS.parse_cache_misses
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns a snapshot of the metrics
+pub(crate) fn snapshot() -> MetricsResult {
+    let requests = METRICS
+        .requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(method, count)| ((*method).to_string(), *count))
+        .collect();
+    let hits = METRICS.parse_cache_hits.load(Ordering::Relaxed);
+    let misses = METRICS.parse_cache_misses.load(Ordering::Relaxed);
+    let slowest_files = METRICS
+        .slowest_files
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(path, method, duration)| FileMetrics {
+            path: path.to_path_buf().display().to_string(),
+            method: (*method).to_string(),
+            duration_ms: duration.as_secs_f64() * 1000.0,
+        })
+        .collect();
+
+    MetricsResult {
+        requests,
+        parse_cache: CacheMetrics {
+            hits,
+            misses,
+            hit_rate: if hits + misses == 0 {
+                None
+            } else {
+                Some(hits as f64 / (hits + misses) as f64)
+            },
+        },
+        slowest_files,
+        memory: MemoryMetrics {
+            resident_bytes: resident_bytes(),
+        },
+    }
+}
+
+/// Returns the resident set size of the process. It's only available on Linux.
+fn resident_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
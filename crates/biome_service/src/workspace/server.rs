@@ -4,20 +4,26 @@ use super::{
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ParsePatternParams,
     ParsePatternResult, PatternId, ProjectKey, PullActionsParams, PullActionsResult,
     PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
-    SearchPatternParams, SearchResults, SetManifestForProjectParams, SupportsFeatureParams,
-    UnregisterProjectFolderParams, UpdateSettingsParams,
+    RenameTokenParams, RenameTokenResult, RenamedFile, SearchPatternParams, SearchResults,
+    SetManifestForProjectParams, SupportsFeatureParams, UnregisterProjectFolderParams,
+    UpdateSettingsParams,
 };
 use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    rename_in_project, AstroFileHandler, Capabilities, CodeActionsParams, CssSymbol,
+    DocumentFileSource, FixAllParams, LintParams, ParseResult, SvelteFileHandler, VueFileHandler,
 };
-use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
+use crate::settings::{
+    ProjectData, Settings, SettingsSource, WorkspaceSettings, WorkspaceSettingsHandleMut,
+};
+use crate::workspace::incremental::{Dependencies, Input, Inputs, Lookup, Query};
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    metrics, FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, MetricsParams,
+    MetricsResult, OrganizeImportsParams, OrganizeImportsResult, RageEntry, RageParams, RageResult,
+    ServerInfo,
 };
 use crate::{
-    file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
+    events, file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
 use biome_diagnostics::{
@@ -26,32 +32,40 @@ use biome_diagnostics::{
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::GritQuery;
-use biome_js_syntax::ModuleKind;
+use biome_js_analyze::utils::rename::RenameError;
+use biome_js_syntax::{ModuleKind, TextRange};
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
-use biome_project::{NodeJsProject, PackageJson, PackageType, Project};
+use biome_project::{NodeJsProject, PackageManager, PackageType, Project};
 use biome_rowan::NodeCache;
-use dashmap::{mapref::entry::Entry, DashMap};
+use dashmap::DashMap;
 use indexmap::IndexSet;
+use slotmap::Key;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
     features: Features,
-    /// global settings object for this workspace
+    /// The projects of this workspace.
+    ///
+    /// The lock is only held to look up, register, or unregister a project:
+    /// the settings of each project are behind their own lock.
     settings: RwLock<WorkspaceSettings>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<BiomePath, Document>,
-    /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
-    syntax: DashMap<BiomePath, AnyParse>,
-    /// The current focused project
-    current_project_path: RwLock<Option<BiomePath>>,
+    /// The revisions of the inputs of the memoized computations
+    inputs: Arc<Inputs>,
+    /// Stores the result of the parser (syntax tree + diagnostics) for a given URL, in a given project
+    syntax: Query<(ProjectKey, BiomePath), AnyParse>,
+    /// Stores the features supported by a file, according to the settings
+    file_features: Query<(ProjectKey, BiomePath, FeatureName), FileFeaturesResult>,
     /// Stores the document sources used across the workspace
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
@@ -87,16 +101,84 @@ impl WorkspaceServer {
             features: Features::new(),
             settings: RwLock::default(),
             documents: DashMap::default(),
-            syntax: DashMap::default(),
-            current_project_path: RwLock::default(),
+            inputs: Arc::default(),
+            syntax: Query::default(),
+            file_features: Query::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
         }
     }
 
-    /// Provides a reference to the current settings
-    fn workspace(&self) -> WorkspaceSettingsHandle {
-        WorkspaceSettingsHandle::new(&self.settings)
+    /// Returns `project_key`, or the key of the project that contains `path` when the
+    /// request doesn't have a project key
+    ///
+    /// ## Errors
+    ///
+    /// - If the request doesn't have a project key, and no registered project contains `path`.
+    fn resolve_project_key(
+        &self,
+        project_key: ProjectKey,
+        path: &Path,
+    ) -> Result<ProjectKey, WorkspaceError> {
+        if !project_key.is_null() {
+            return Ok(project_key);
+        }
+        self.settings
+            .read()
+            .unwrap()
+            .get_project_key_for_path(path)
+            .ok_or_else(WorkspaceError::project_not_found)
+    }
+
+    /// Provides the data of the project registered with `project_key`
+    ///
+    /// ## Errors
+    ///
+    /// - If no project is registered with `project_key`. Usually, you'll have to call [Workspace::register_project_folder] to get a key.
+    fn project_data(&self, project_key: ProjectKey) -> Result<Arc<ProjectData>, WorkspaceError> {
+        self.settings
+            .read()
+            .unwrap()
+            .get_project_data(project_key)
+            .ok_or_else(WorkspaceError::project_not_found)
+    }
+
+    /// Provides the settings of the project registered with `project_key`
+    fn workspace(
+        &self,
+        project_key: ProjectKey,
+    ) -> Result<WorkspaceSettingsHandle, WorkspaceError> {
+        let project_data = self.project_data(project_key)?;
+        Ok(WorkspaceSettingsHandle::new(
+            project_key,
+            project_data,
+            self.inputs.clone(),
+        ))
+    }
+
+    /// Renames a CSS custom property or a CSS class in the files of the project, other
+    /// than `except`.
+    ///
+    /// The content of the open documents is renamed instead of their content on disk.
+    fn rename_in_project(
+        &self,
+        project_key: ProjectKey,
+        symbol: &CssSymbol,
+        new_name: &str,
+        except: Option<&BiomePath>,
+    ) -> Result<Vec<RenamedFile>, WorkspaceError> {
+        let project = self.project_data(project_key)?;
+        Ok(rename_in_project(
+            project.path(),
+            &project.settings(),
+            symbol,
+            new_name,
+            except.map(|path| path.as_path()),
+            |path| match self.documents.get(&BiomePath::new(path)) {
+                Some(document) => Some(document.content.clone()),
+                None => fs::read_to_string(path).ok(),
+            },
+        ))
     }
 
     fn workspaces_mut(&self) -> WorkspaceSettingsHandleMut {
@@ -104,32 +186,48 @@ impl WorkspaceServer {
     }
 
     /// Get the supported capabilities for a given file path
-    fn get_file_capabilities(&self, path: &BiomePath) -> Capabilities {
-        let language = self.get_file_source(path);
+    fn get_file_capabilities(&self, project_key: ProjectKey, path: &BiomePath) -> Capabilities {
+        let language = self.get_file_source(project_key, path);
 
         debug!("File capabilities: {:?} {:?}", &language, &path);
         self.features.get_capabilities(path, language)
     }
 
     /// Retrieves the supported language of a file
-    fn get_file_source(&self, path: &BiomePath) -> DocumentFileSource {
+    fn get_file_source(&self, project_key: ProjectKey, path: &BiomePath) -> DocumentFileSource {
         self.documents
             .get(path)
             .map(|doc| doc.file_source_index)
             .and_then(|index| self.get_source(index))
-            .unwrap_or(DocumentFileSource::from_path(path))
+            .unwrap_or_else(|| self.file_source_from_path(project_key, path))
+    }
+
+    /// Retrieves the language of a file from its path, including the files registered
+    /// in `json.parser.wellKnownFiles` of its project
+    fn file_source_from_path(&self, project_key: ProjectKey, path: &Path) -> DocumentFileSource {
+        let settings = self
+            .project_data(project_key)
+            .ok()
+            .map(|project| project.settings());
+        settings
+            .as_deref()
+            .and_then(|settings| settings.languages.json.parser.well_known_file_source(path))
+            .map_or_else(|| DocumentFileSource::from_path(path), Into::into)
     }
 
     /// Return an error factory function for unsupported features at a given path
     fn build_capability_error<'a>(
         &'a self,
+        project_key: ProjectKey,
         path: &'a BiomePath,
         // feature_name: &'a str,
     ) -> impl FnOnce() -> WorkspaceError + 'a {
         move || {
-            let file_source = self.get_file_source(path);
+            let file_source = self.get_file_source(project_key, path);
 
-            let language = DocumentFileSource::from_path(path).or(file_source);
+            let language = self
+                .file_source_from_path(project_key, path)
+                .or(file_source);
             WorkspaceError::source_file_not_supported(
                 language,
                 path.display().to_string(),
@@ -140,17 +238,6 @@ impl WorkspaceServer {
         }
     }
 
-    /// Returns the current project. The information of this project depend on path set by [WorkspaceServer::set_current_project]
-    ///
-    /// ## Errors
-    ///
-    /// - If no document is found in the workspace. Usually, you'll have to call [WorkspaceServer::set_manifest_for_project] to store said document.
-    #[tracing::instrument(level = "trace", skip(self))]
-    fn get_current_manifest(&self) -> Result<Option<PackageJson>, WorkspaceError> {
-        let workspace = self.workspace();
-        Ok(workspace.as_ref().get_current_manifest().cloned())
-    }
-
     #[tracing::instrument(level = "trace", skip(self), fields(return))]
     fn get_source(&self, index: usize) -> Option<DocumentFileSource> {
         let file_sources = self.file_sources.read().unwrap();
@@ -164,17 +251,6 @@ impl WorkspaceServer {
         index
     }
 
-    /// Retrieves the current project path
-    fn get_current_project_path(&self) -> Option<BiomePath> {
-        self.current_project_path.read().unwrap().as_ref().cloned()
-    }
-
-    /// Updates the current project path
-    fn set_current_project_path(&self, path: BiomePath) {
-        let mut current_project_path = self.current_project_path.write().unwrap();
-        let _ = current_project_path.insert(path);
-    }
-
     /// Register a new project in the current workspace
     fn register_project(&self, path: PathBuf) -> ProjectKey {
         let mut workspace = self.workspaces_mut();
@@ -182,10 +258,8 @@ impl WorkspaceServer {
         workspace_mut.insert_project(path.clone())
     }
 
-    /// Updates the manifest for the current project. Given the manifest path, the function will try to parse the manifest and update the current project.
-    fn register_manifest_for_project(&self, manifest_path: BiomePath) {
-        let mut workspace = self.workspaces_mut();
-        let workspace_mut = workspace.as_mut();
+    /// Updates the manifest of a project. Given the manifest path, the function will try to parse the manifest and update the project.
+    fn register_manifest_for_project(&self, project_data: &ProjectData, manifest_path: BiomePath) {
         let mut document = self.documents.get_mut(&manifest_path);
         if let Some(document) = document.as_deref_mut() {
             let parsed = parse_json_with_cache(
@@ -196,35 +270,39 @@ impl WorkspaceServer {
 
             let mut node_js_project = NodeJsProject::default();
             node_js_project.deserialize_manifest(&parsed.tree());
-            workspace_mut.insert_manifest(node_js_project);
+            // The lockfiles are looked up once, when the manifest is registered
+            if node_js_project.manifest.package_manager.is_none() {
+                if let Some(directory) = manifest_path.parent() {
+                    node_js_project.manifest.package_manager =
+                        PackageManager::from_lockfiles(|lockfile| {
+                            directory.join(lockfile).is_file()
+                        });
+                }
+            }
+            project_data.set_manifest(node_js_project);
         }
     }
 
-    /// Sets the current project of the current workspace
-    fn set_current_project(&self, project_key: ProjectKey) {
-        let mut workspace = self.workspaces_mut();
-        let workspace_mut = workspace.as_mut();
-        workspace_mut.set_current_project(project_key);
-    }
-
-    /// Checks whether, if the current path belongs to the current project.
-    ///
-    /// If there's a match, and the match **isn't** the current project, it returns the new key.
-    fn path_belongs_to_current_workspace(&self, path: &BiomePath) -> Option<ProjectKey> {
-        let workspace = self.workspace();
-        workspace.as_ref().path_belongs_to_current_workspace(path)
-    }
-
     /// Get the parser result for a given file
     ///
     /// Returns and error if no file exists in the workspace with this path or
     /// if the language associated with the file has no parser capability
-    fn get_parse(&self, biome_path: BiomePath) -> Result<AnyParse, WorkspaceError> {
-        match self.syntax.entry(biome_path) {
-            Entry::Occupied(entry) => Ok(entry.get().clone()),
-            Entry::Vacant(entry) => {
-                let biome_path = entry.key();
-                let capabilities = self.get_file_capabilities(biome_path);
+    fn get_parse(
+        &self,
+        project_key: ProjectKey,
+        biome_path: BiomePath,
+    ) -> Result<AnyParse, WorkspaceError> {
+        let workspace = self.workspace(project_key)?;
+        let mut dependencies = Dependencies::new(&self.inputs);
+        let (parse, lookup) = self.syntax.get(
+            (project_key, biome_path),
+            &mut dependencies,
+            |(project_key, biome_path), dependencies| {
+                let project_key = *project_key;
+                // The parser options are read from the settings
+                dependencies.read(Input::Document(biome_path.clone()));
+                dependencies.read(Input::Settings(project_key));
+                let capabilities = self.get_file_capabilities(project_key, biome_path);
 
                 let mut document = self
                     .documents
@@ -234,10 +312,9 @@ impl WorkspaceServer {
                 let parse = capabilities
                     .parser
                     .parse
-                    .ok_or_else(self.build_capability_error(biome_path))?;
+                    .ok_or_else(self.build_capability_error(project_key, biome_path))?;
 
                 let size_limit = {
-                    let workspace = self.workspace();
                     let settings = workspace.settings();
                     let limit =
                         settings.map_or(DEFAULT_FILE_SIZE_LIMIT.get(), |s| s.files.max_size.get());
@@ -254,7 +331,6 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let workspace = self.workspace();
                 let Some(file_source) = self.get_source(document.file_source_index) else {
                     return Err(WorkspaceError::not_found());
                 };
@@ -273,39 +349,36 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
-                Ok(entry.insert(any_parse).clone())
-            }
-        }
+                Ok(any_parse)
+            },
+        )?;
+        metrics::parse_cache_lookup(lookup == Lookup::Reused);
+        Ok(parse)
     }
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     /// or in the feature `ignore`/`include`
-    fn is_ignored(&self, path: &Path, features: FeatureName) -> bool {
+    fn is_ignored(&self, settings: &Settings, path: &Path, features: FeatureName) -> bool {
         let file_name = path.file_name().and_then(|s| s.to_str());
         let ignored_by_features = {
             let mut ignored = false;
 
             for feature in features.iter() {
                 // a path is ignored if it's ignored by all features
-                ignored &= self.is_ignored_by_feature_config(path, feature)
+                ignored &= Self::is_ignored_by_feature_config(settings, path, feature)
             }
             ignored
         };
         // Never ignore Biome's config file regardless `include`/`ignore`
         (file_name != Some(ConfigName::biome_json()) || file_name != Some(ConfigName::biome_jsonc())) &&
             // Apply top-level `include`/`ignore`
-            (self.is_ignored_by_top_level_config(path) ||
+            (Self::is_ignored_by_top_level_config(settings, path) ||
                 // Apply feature-level `include`/`ignore`
                 ignored_by_features)
     }
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
-    fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.workspace();
-        let settings = settings.settings();
-        let Some(settings) = settings else {
-            return false;
-        };
+    fn is_ignored_by_top_level_config(settings: &Settings, path: &Path) -> bool {
         let is_included = settings.files.included_files.is_empty()
             || is_dir(path)
             || settings.files.included_files.matches_path(path);
@@ -327,12 +400,11 @@ impl WorkspaceServer {
     }
 
     /// Check whether a file is ignored in the feature `ignore`/`include`
-    fn is_ignored_by_feature_config(&self, path: &Path, feature: FeatureKind) -> bool {
-        let settings = self.workspace();
-        let settings = settings.settings();
-        let Some(settings) = settings else {
-            return false;
-        };
+    fn is_ignored_by_feature_config(
+        settings: &Settings,
+        path: &Path,
+        feature: FeatureKind,
+    ) -> bool {
         let (feature_included_files, feature_ignored_files) = match feature {
             FeatureKind::Format => {
                 let formatter = &settings.formatter;
@@ -369,77 +441,106 @@ impl Workspace for WorkspaceServer {
         &self,
         params: SupportsFeatureParams,
     ) -> Result<FileFeaturesResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
-        let language = DocumentFileSource::from_path(&params.path);
-        let path = params.path.as_path();
-        let settings = self.workspace();
-        let settings = settings.settings();
-        let mut file_features = FileFeaturesResult::new();
-
-        let file_name = path.file_name().and_then(|s| s.to_str());
-        file_features = file_features.with_capabilities(&capabilities);
-        let Some(settings) = settings else {
-            return Ok(file_features);
-        };
-        file_features = file_features.with_settings_and_language(settings, &language, path);
-
-        if settings.files.ignore_unknown
-            && language == DocumentFileSource::Unknown
-            && self.get_file_source(&params.path) == DocumentFileSource::Unknown
-        {
-            file_features.ignore_not_supported();
-        } else if file_name == Some(ConfigName::biome_json())
-            || file_name == Some(ConfigName::biome_jsonc())
-        {
-            // Never ignore Biome's config file
-        } else if self.is_ignored_by_top_level_config(path) {
-            file_features.set_ignored_for_all_features();
-        } else {
-            for feature in params.features.iter() {
-                if self.is_ignored_by_feature_config(path, feature) {
-                    file_features.ignored(feature);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let key = (project_key, params.path, params.features);
+        let mut dependencies = Dependencies::new(&self.inputs);
+        let (file_features, _) = self.file_features.get(
+            key,
+            &mut dependencies,
+            |(project_key, biome_path, features), dependencies| {
+                let project_key = *project_key;
+                // The file source of an opened document takes precedence over its extension
+                dependencies.read(Input::Document(biome_path.clone()));
+                dependencies.read(Input::Settings(project_key));
+
+                let capabilities = self.get_file_capabilities(project_key, biome_path);
+                let language = self.file_source_from_path(project_key, biome_path);
+                let path = biome_path.as_path();
+                let settings = self.project_data(project_key)?.settings();
+                let mut file_features = FileFeaturesResult::new();
+
+                let file_name = path.file_name().and_then(|s| s.to_str());
+                file_features = file_features.with_capabilities(&capabilities);
+                file_features =
+                    file_features.with_settings_and_language(&settings, &language, path);
+
+                if settings.files.ignore_unknown
+                    && language == DocumentFileSource::Unknown
+                    && self.get_file_source(project_key, biome_path) == DocumentFileSource::Unknown
+                {
+                    file_features.ignore_not_supported();
+                } else if file_name == Some(ConfigName::biome_json())
+                    || file_name == Some(ConfigName::biome_jsonc())
+                {
+                    // Never ignore Biome's config file
+                } else if Self::is_ignored_by_top_level_config(&settings, path) {
+                    file_features.set_ignored_for_all_features();
+                } else {
+                    for feature in features.iter() {
+                        if Self::is_ignored_by_feature_config(&settings, path, feature) {
+                            file_features.ignored(feature);
+                        }
+                    }
+                }
+                // If the file is not ignored by at least one feature, then check that the file is not protected.
+                //
+                // Protected files must be ignored.
+                if !file_features.is_not_processed() && FileFeaturesResult::is_protected_file(path)
+                {
+                    file_features.set_protected_for_all_features();
                 }
-            }
-        }
-        // If the file is not ignored by at least one feature, then check that the file is not protected.
-        //
-        // Protected files must be ignored.
-        if !file_features.is_not_processed() && FileFeaturesResult::is_protected_file(path) {
-            file_features.set_protected_for_all_features();
-        }
 
+                Ok::<_, WorkspaceError>(file_features)
+            },
+        )?;
         Ok(file_features)
     }
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
-        Ok(self.is_ignored(params.biome_path.as_path(), params.features))
+        let project_key = self.resolve_project_key(params.project_key, &params.biome_path)?;
+        let settings = self.project_data(project_key)?.settings();
+        Ok(self.is_ignored(&settings, params.biome_path.as_path(), params.features))
     }
-    /// Update the global settings for this workspace
+    /// Update the settings of a project
+    ///
+    /// The new settings are computed without holding any lock, then they replace
+    /// the settings of the project. The requests in progress keep the previous settings,
+    /// so the update doesn't wait for them, and the next requests don't wait for the update.
     ///
     /// ## Panics
     /// This function may panic if the internal settings mutex has been poisoned
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "trace", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
-        let mut workspace = self.workspaces_mut();
-        workspace
-            .as_mut()
-            .get_current_settings_mut()
-            .merge_with_configuration(
-                params.configuration,
-                params.workspace_directory,
-                params.vcs_base_path,
-                params.gitignore_matches.as_slice(),
-            )?;
+        // The request without a project key applies to the project of its workspace directory
+        let workspace_directory = params.workspace_directory.clone().unwrap_or_default();
+        let project_key = self.resolve_project_key(params.project_key, &workspace_directory)?;
+        let project_data = self.project_data(project_key)?;
+        let source = SettingsSource {
+            configuration: params.configuration,
+            workspace_directory: params.workspace_directory,
+            vcs_base_path: params.vcs_base_path,
+            gitignore_matches: params.gitignore_matches,
+        };
+        // The results that depend on the settings stay valid when they're resolved from the same parameters
+        if project_data.is_resolved_from(&source) {
+            return Ok(());
+        }
+        project_data.resolve_settings(source)?;
+        self.inputs.changed(Input::Settings(project_key));
+        self.file_features.retain(|(key, _, _)| *key != project_key);
 
         Ok(())
     }
     /// Add a new file to the workspace
     #[tracing::instrument(level = "trace", skip(self))]
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError> {
+        let _timer = metrics::request("open_file", None);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let project_data = self.project_data(project_key)?;
         let mut source = params
             .document_file_source
-            .unwrap_or(DocumentFileSource::from_path(&params.path));
-        let manifest = self.get_current_manifest()?;
+            .unwrap_or_else(|| self.file_source_from_path(project_key, &params.path));
+        let manifest = project_data.manifest();
 
         if let DocumentFileSource::Js(js) = &mut source {
             if let Some(manifest) = manifest {
@@ -450,7 +551,6 @@ impl Workspace for WorkspaceServer {
         }
 
         let index = self.set_source(source);
-        self.syntax.remove(&params.path);
         self.documents.insert(
             params.path.clone(),
             Document {
@@ -460,9 +560,7 @@ impl Workspace for WorkspaceServer {
                 file_source_index: index,
             },
         );
-        if let Some(project_key) = self.path_belongs_to_current_workspace(&params.path) {
-            self.set_current_project(project_key);
-        }
+        self.inputs.changed(Input::Document(params.path.clone()));
 
         Ok(())
     }
@@ -470,8 +568,9 @@ impl Workspace for WorkspaceServer {
         &self,
         params: SetManifestForProjectParams,
     ) -> Result<(), WorkspaceError> {
+        let project_key = self.resolve_project_key(params.project_key, &params.manifest_path)?;
+        let project_data = self.project_data(project_key)?;
         let index = self.set_source(JsonFileSource::json().into());
-        self.syntax.remove(&params.manifest_path);
         self.documents.insert(
             params.manifest_path.clone(),
             Document {
@@ -481,7 +580,9 @@ impl Workspace for WorkspaceServer {
                 node_cache: NodeCache::default(),
             },
         );
-        self.register_manifest_for_project(params.manifest_path);
+        self.inputs
+            .changed(Input::Document(params.manifest_path.clone()));
+        self.register_manifest_for_project(&project_data, params.manifest_path);
         Ok(())
     }
 
@@ -489,30 +590,14 @@ impl Workspace for WorkspaceServer {
         &self,
         params: RegisterProjectFolderParams,
     ) -> Result<ProjectKey, WorkspaceError> {
-        let current_project_path = self.get_current_project_path();
-        debug!(
-            "Compare the current project with the new one {:?} {:?} {:?}",
-            current_project_path.as_deref(),
-            params.path.as_ref(),
-            current_project_path.as_deref() != params.path.as_ref()
-        );
-
-        let is_new_path = match (current_project_path.as_deref(), params.path.as_ref()) {
-            (Some(current_project_path), Some(params_path)) => current_project_path != params_path,
-            _ => true,
-        };
-
-        if is_new_path {
-            let path = params.path.unwrap_or_default();
-            let key = self.register_project(path.clone());
-            if params.set_as_current_workspace {
-                self.set_current_project(key);
-                self.set_current_project_path(BiomePath::new(path));
+        // The clients that share the daemon, such as the editor and the CLI, share the project of a folder
+        if let Some(path) = params.path.as_deref() {
+            if let Some(key) = self.settings.read().unwrap().get_project_key(path) {
+                return Ok(key);
             }
-            Ok(key)
-        } else {
-            Ok(self.workspace().as_ref().get_current_project_key())
         }
+
+        Ok(self.register_project(params.path.unwrap_or_default()))
     }
 
     fn unregister_project_folder(
@@ -520,7 +605,12 @@ impl Workspace for WorkspaceServer {
         params: UnregisterProjectFolderParams,
     ) -> Result<(), WorkspaceError> {
         let mut workspace = self.workspaces_mut();
-        workspace.as_mut().remove_project(params.path.as_path());
+        let workspace = workspace.as_mut();
+        workspace.remove_project(params.path.as_path());
+        self.syntax
+            .retain(|(key, _)| workspace.get_project_data(*key).is_some());
+        self.file_features
+            .retain(|(key, _, _)| workspace.get_project_data(*key).is_some());
         Ok(())
     }
 
@@ -528,14 +618,15 @@ impl Workspace for WorkspaceServer {
         &self,
         params: GetSyntaxTreeParams,
     ) -> Result<GetSyntaxTreeResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let debug_syntax_tree = capabilities
             .debug
             .debug_syntax_tree
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
         // The feature name here can be any feature, in theory
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
         let printed = debug_syntax_tree(&params.path, parse);
 
         Ok(printed)
@@ -545,34 +636,36 @@ impl Workspace for WorkspaceServer {
         &self,
         params: GetControlFlowGraphParams,
     ) -> Result<String, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let debug_control_flow = capabilities
             .debug
             .debug_control_flow
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
         let printed = debug_control_flow(parse, params.cursor);
 
         Ok(printed)
     }
 
     fn get_formatter_ir(&self, params: GetFormatterIRParams) -> Result<String, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let debug_formatter_ir = capabilities
             .debug
             .debug_formatter_ir
-            .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
+        let workspace = self.workspace(project_key)?;
         let settings = workspace.settings();
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
 
         if let Some(settings) = settings {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
         }
-        let document_file_source = self.get_file_source(&params.path);
+        let document_file_source = self.get_file_source(project_key, &params.path);
 
         debug_formatter_ir(&params.path, &document_file_source, parse, workspace)
     }
@@ -587,6 +680,7 @@ impl Workspace for WorkspaceServer {
 
     /// Change the content of an open file
     fn change_file(&self, params: ChangeFileParams) -> Result<(), WorkspaceError> {
+        let _timer = metrics::request("change_file", None);
         {
             let mut document = self
                 .documents
@@ -598,17 +692,21 @@ impl Workspace for WorkspaceServer {
             document.content = params.content;
         }
 
-        self.syntax.remove(&params.path);
+        self.inputs.changed(Input::Document(params.path));
         Ok(())
     }
 
     /// Remove a file from the workspace
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
+        let _timer = metrics::request("close_file", None);
         self.documents
             .remove(&params.path)
             .ok_or_else(WorkspaceError::not_found)?;
 
-        self.syntax.remove(&params.path);
+        self.inputs.removed(&Input::Document(params.path.clone()));
+        self.syntax.retain(|(_, path)| *path != params.path);
+        self.file_features
+            .retain(|(_, path, _)| *path != params.path);
         Ok(())
     }
 
@@ -618,38 +716,60 @@ impl Workspace for WorkspaceServer {
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
-        let parse = self.get_parse(params.path.clone())?;
-        let manifest = self.get_current_manifest()?;
-        let (diagnostics, errors, skipped_diagnostics) =
-            if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
-                info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
-                    let results = lint(LintParams {
-                        parse,
-                        workspace: &self.workspace(),
-                        max_diagnostics: params.max_diagnostics as u32,
-                        path: &params.path,
-                        only: params.only,
-                        skip: params.skip,
-                        language: self.get_file_source(&params.path),
-                        categories: params.categories,
-                        manifest,
-                    });
-
-                    (
-                        results.diagnostics,
-                        results.errors,
-                        results.skipped_diagnostics,
-                    )
-                })
+        let _timer = metrics::request("pull_diagnostics", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
+        let manifest = self.project_data(project_key)?.manifest();
+        let workspace = self.workspace(project_key)?;
+        let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) = self
+            .get_file_capabilities(project_key, &params.path)
+            .analyzer
+            .lint
+        {
+            let language = self.get_file_source(project_key, &params.path);
+            let is_svelte = language
+                .to_js_file_source()
+                .is_some_and(|file_source| file_source.as_embedding_kind().is_svelte());
+            let content = if is_svelte {
+                Some(self.get_file_content(GetFileContentParams {
+                    project_key,
+                    path: params.path.clone(),
+                })?)
             } else {
-                let parse_diagnostics = parse.into_diagnostics();
-                let errors = parse_diagnostics
-                    .iter()
-                    .filter(|diag| diag.severity() <= Severity::Error)
-                    .count();
-
-                (parse_diagnostics, errors, 0)
+                None
             };
+            info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
+                let lint_params = LintParams {
+                    parse,
+                    workspace: &workspace,
+                    max_diagnostics: params.max_diagnostics as u32,
+                    path: &params.path,
+                    only: params.only,
+                    skip: params.skip,
+                    language,
+                    categories: params.categories,
+                    manifest,
+                };
+                let results = match content {
+                    Some(content) => SvelteFileHandler::lint_file(lint_params, &content),
+                    None => lint(lint_params),
+                };
+
+                (
+                    results.diagnostics,
+                    results.errors,
+                    results.skipped_diagnostics,
+                )
+            })
+        } else {
+            let parse_diagnostics = parse.into_diagnostics();
+            let errors = parse_diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            (parse_diagnostics, errors, 0)
+        };
 
         info!("Pulled {:?} diagnostic(s)", diagnostics.len());
         Ok(PullDiagnosticsResult {
@@ -669,16 +789,18 @@ impl Workspace for WorkspaceServer {
     /// position within a file
     #[tracing::instrument(level = "trace", skip(self))]
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("pull_actions", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let code_actions = capabilities
             .analyzer
             .code_actions
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
-        let parse = self.get_parse(params.path.clone())?;
-        let workspace = self.workspace();
-        let manifest = self.get_current_manifest()?;
-        let language = self.get_file_source(&params.path);
+        let parse = self.get_parse(project_key, params.path.clone())?;
+        let workspace = self.workspace(project_key)?;
+        let manifest = self.project_data(project_key)?.manifest();
+        let language = self.get_file_source(project_key, &params.path);
         Ok(code_actions(CodeActionsParams {
             parse,
             range: params.range,
@@ -694,40 +816,67 @@ impl Workspace for WorkspaceServer {
     /// Runs the given file through the formatter using the provided options
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("format_file", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let format = capabilities
             .formatter
             .format
-            .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
+        let workspace = self.workspace(project_key)?;
         let settings = workspace.settings();
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
 
         if let Some(settings) = settings {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
         }
-        let document_file_source = self.get_file_source(&params.path);
+        let document_file_source = self.get_file_source(project_key, &params.path);
+        let embedding_kind = document_file_source
+            .to_js_file_source()
+            .map(|file_source| *file_source.as_embedding_kind())
+            .unwrap_or_default();
+        if embedding_kind.is_vue() || embedding_kind.is_svelte() || embedding_kind.is_astro() {
+            let content = self.get_file_content(GetFileContentParams {
+                project_key,
+                path: params.path.clone(),
+            })?;
+            let script = format(
+                &params.path,
+                &document_file_source,
+                parse,
+                workspace.clone(),
+            )?;
+            return if embedding_kind.is_vue() {
+                VueFileHandler::format_blocks(&params.path, &content, script, &workspace)
+            } else if embedding_kind.is_svelte() {
+                SvelteFileHandler::format_blocks(&params.path, &content, script, &workspace)
+            } else {
+                AstroFileHandler::format_blocks(&params.path, &content, script, &workspace)
+            };
+        }
         format(&params.path, &document_file_source, parse, workspace)
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("format_range", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let format_range = capabilities
             .formatter
             .format_range
-            .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
+        let workspace = self.workspace(project_key)?;
         let settings = workspace.settings();
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
 
         if let Some(settings) = settings {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
         }
-        let document_file_source = self.get_file_source(&params.path);
+        let document_file_source = self.get_file_source(project_key, &params.path);
         format_range(
             &params.path,
             &document_file_source,
@@ -738,21 +887,23 @@ impl Workspace for WorkspaceServer {
     }
 
     fn format_on_type(&self, params: FormatOnTypeParams) -> Result<Printed, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("format_on_type", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let format_on_type = capabilities
             .formatter
             .format_on_type
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
-        let workspace = self.workspace();
+        let workspace = self.workspace(project_key)?;
         let settings = workspace.settings();
-        let parse = self.get_parse(params.path.clone())?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
         if let Some(settings) = settings {
             if !settings.formatter().format_with_errors && parse.has_errors() {
                 return Err(WorkspaceError::format_with_errors_disabled());
             }
         }
-        let document_file_source = self.get_file_source(&params.path);
+        let document_file_source = self.get_file_source(project_key, &params.path);
 
         format_on_type(
             &params.path,
@@ -764,22 +915,24 @@ impl Workspace for WorkspaceServer {
     }
 
     fn fix_file(&self, params: super::FixFileParams) -> Result<FixFileResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("fix_file", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
 
         let fix_all = capabilities
             .analyzer
             .fix_all
-            .ok_or_else(self.build_capability_error(&params.path))?;
-        let parse = self.get_parse(params.path.clone())?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
 
-        let manifest = self.get_current_manifest()?;
-        let language = self.get_file_source(&params.path);
-        fix_all(FixAllParams {
+        let manifest = self.project_data(project_key)?.manifest();
+        let language = self.get_file_source(project_key, &params.path);
+        let result = fix_all(FixAllParams {
             parse,
             // rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
             // filter,
-            workspace: self.workspace(),
+            workspace: self.workspace(project_key)?,
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
@@ -787,22 +940,71 @@ impl Workspace for WorkspaceServer {
             only: params.only,
             skip: params.skip,
             rule_categories: params.rule_categories,
-        })
+        })?;
+
+        if !result.actions.is_empty() {
+            events::fixes_applied(
+                &params.path,
+                result.actions.len(),
+                result.skipped_suggested_fixes,
+            );
+        }
+
+        Ok(result)
     }
 
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let _timer = metrics::request("rename", Some(&params.path));
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let rename = capabilities
             .analyzer
             .rename
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
-        let parse = self.get_parse(params.path.clone())?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
+        let css_symbol = match self.get_file_source(project_key, &params.path) {
+            DocumentFileSource::Css(_) => CssSymbol::at_offset(&parse, params.symbol_at),
+            _ => None,
+        };
+        let mut result = rename(
+            &params.path,
+            parse,
+            params.symbol_at,
+            params.new_name.clone(),
+        )?;
+        if let Some((symbol, _)) = css_symbol {
+            result.other_files =
+                self.rename_in_project(project_key, &symbol, &params.new_name, Some(&params.path))?;
+        }
 
         Ok(result)
     }
 
+    fn rename_token(&self, params: RenameTokenParams) -> Result<RenameTokenResult, WorkspaceError> {
+        let _timer = metrics::request("rename_token", None);
+        let Some(symbol) = CssSymbol::from_name(&params.name) else {
+            return Err(WorkspaceError::RenameError(
+                RenameError::CannotFindDeclaration(params.name),
+            ));
+        };
+        if symbol.replacement_name(&params.new_name).is_none() {
+            return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+                original_name: params.name,
+                original_range: TextRange::default(),
+                new_name: params.new_name,
+            }));
+        }
+
+        Ok(RenameTokenResult {
+            files: self.rename_in_project(params.project_key, &symbol, &params.new_name, None)?,
+        })
+    }
+
+    fn metrics(&self, _: MetricsParams) -> Result<MetricsResult, WorkspaceError> {
+        Ok(metrics::snapshot())
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -828,21 +1030,22 @@ impl Workspace for WorkspaceServer {
     }
 
     fn search_pattern(&self, params: SearchPatternParams) -> Result<SearchResults, WorkspaceError> {
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
         let Some(query) = self.patterns.get(&params.pattern) else {
             return Err(WorkspaceError::SearchError(SearchError::InvalidPattern(
                 InvalidPattern,
             )));
         };
 
-        let capabilities = self.get_file_capabilities(&params.path);
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let search = capabilities
             .search
             .search
-            .ok_or_else(self.build_capability_error(&params.path))?;
-        let workspace = self.workspace();
-        let parse = self.get_parse(params.path.clone())?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
+        let workspace = self.workspace(project_key)?;
+        let parse = self.get_parse(project_key, params.path.clone())?;
 
-        let document_file_source = self.get_file_source(&params.path);
+        let document_file_source = self.get_file_source(project_key, &params.path);
         let matches = search(
             &params.path,
             &document_file_source,
@@ -870,13 +1073,14 @@ impl Workspace for WorkspaceServer {
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
-        let capabilities = self.get_file_capabilities(&params.path);
+        let project_key = self.resolve_project_key(params.project_key, &params.path)?;
+        let capabilities = self.get_file_capabilities(project_key, &params.path);
         let organize_imports = capabilities
             .analyzer
             .organize_imports
-            .ok_or_else(self.build_capability_error(&params.path))?;
+            .ok_or_else(self.build_capability_error(project_key, &params.path))?;
 
-        let parse = self.get_parse(params.path)?;
+        let parse = self.get_parse(project_key, params.path)?;
         let result = organize_imports(parse)?;
 
         Ok(result)
@@ -0,0 +1,152 @@
+//! Applies the fixes of all the enabled rules together over the fixture projects
+//! inside `tests/fix_conflicts`, as `biome check --write --unsafe` does.
+//!
+//! Each directory of `tests/fix_conflicts` is a project. Its `biome.json` is used
+//! to configure the workspace; when it's missing, all the rules are enabled.
+//! A fixture passes when:
+//! - applying the fixes doesn't panic, nor fails;
+//! - the fix loop terminates within [FIX_TIMEOUT];
+//! - the fixed code doesn't receive any further fix, meaning that the fixes converged.
+
+use biome_analyze::RuleCategoriesBuilder;
+use biome_configuration::PartialConfiguration;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::print_diagnostic_to_string;
+use biome_fs::BiomePath;
+use biome_json_parser::JsonParserOptions;
+use biome_service::workspace::{
+    server, FileGuard, FixFileMode, FixFileResult, OpenFileParams, RegisterProjectFolderParams,
+    UpdateSettingsParams,
+};
+use biome_service::WorkspaceError;
+use std::ffi::OsStr;
+use std::fs::read_to_string;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+tests_macros::gen_tests! {"tests/fix_conflicts/**/*.{cjs,js,jsx,mjs,ts,tsx}", crate::run_fix_conflicts, "module"}
+
+/// Configuration used by the projects that don't have a `biome.json`
+const ALL_RULES_CONFIGURATION: &str = r#"{ "linter": { "rules": { "all": true } } }"#;
+
+/// Maximum time given to the fix loop before considering that it doesn't terminate
+const FIX_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn run_fix_conflicts(input: &'static str, _: &str, _: &str, _: &str) {
+    let input_file = Path::new(input);
+    let configuration = load_configuration(input_file);
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+
+    // Fixes are applied on a separate thread, so a fix loop that never reaches
+    // a fix-point is reported as a failure instead of hanging the test suite.
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = fix_file(input, configuration.clone(), input_code).and_then(|first_pass| {
+            let second_pass = fix_file(input, configuration, first_pass.code.clone())?;
+            Ok((first_pass, second_pass))
+        });
+        // The receiver is gone when the test already timed out
+        let _ = sender.send(result);
+    });
+
+    let (first_pass, second_pass) = match receiver.recv_timeout(FIX_TIMEOUT) {
+        Ok(Ok(passes)) => passes,
+        Ok(Err(error)) => panic!("failed to apply the fixes to {input_file:?}: {error}"),
+        Err(mpsc::RecvTimeoutError::Timeout) => panic!(
+            "the fixes applied to {input_file:?} didn't converge within {FIX_TIMEOUT:?}, \
+            some rules probably revert each other's fixes"
+        ),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            panic!("applying the fixes to {input_file:?} panicked")
+        }
+    };
+
+    let rules = second_pass
+        .actions
+        .iter()
+        .filter_map(|action| action.rule_name.as_ref())
+        .map(|(group, rule)| format!("{group}/{rule}"))
+        .collect::<Vec<_>>();
+    assert!(
+        rules.is_empty(),
+        "the fixes applied to {input_file:?} didn't reach a fix-point, \
+        these rules still change the fixed code: {rules:?}\n\n{}",
+        first_pass.code,
+    );
+    assert_eq!(
+        first_pass.code, second_pass.code,
+        "the code of {input_file:?} changed after the fixes converged"
+    );
+}
+
+/// Reads the `biome.json` of the fixture project that contains `input_file`
+fn load_configuration(input_file: &Path) -> PartialConfiguration {
+    let project_dir = input_file
+        .ancestors()
+        .find(|ancestor| {
+            ancestor.parent().and_then(Path::file_name) == Some(OsStr::new("fix_conflicts"))
+        })
+        .unwrap_or_else(|| panic!("{input_file:?} isn't inside a fixture project"));
+    let configuration = read_to_string(project_dir.join("biome.json"))
+        .unwrap_or_else(|_| ALL_RULES_CONFIGURATION.to_string());
+    let deserialized = deserialize_from_json_str::<PartialConfiguration>(
+        &configuration,
+        JsonParserOptions::default(),
+        "",
+    );
+    assert!(
+        !deserialized.has_errors(),
+        "the configuration of {project_dir:?} is invalid:\n{}",
+        deserialized
+            .diagnostics()
+            .iter()
+            .map(print_diagnostic_to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+    deserialized.into_deserialized().unwrap_or_default()
+}
+
+/// Applies the safe and unsafe fixes of the enabled rules to `content`,
+/// the way `biome check --write --unsafe` does
+fn fix_file(
+    input: &'static str,
+    configuration: PartialConfiguration,
+    content: String,
+) -> Result<FixFileResult, WorkspaceError> {
+    let workspace = server();
+    let project_key =
+        workspace.register_project_folder(RegisterProjectFolderParams { path: None })?;
+    workspace.update_settings(UpdateSettingsParams {
+        project_key,
+        configuration,
+        vcs_base_path: None,
+        gitignore_matches: Vec::new(),
+        workspace_directory: None,
+    })?;
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            project_key,
+            path: BiomePath::new(input),
+            content,
+            version: 0,
+            document_file_source: None,
+        },
+    )?;
+    file.fix_file(
+        FixFileMode::SafeAndUnsafeFixes,
+        false,
+        RuleCategoriesBuilder::default()
+            .with_syntax()
+            .with_lint()
+            .with_action()
+            .build(),
+        Vec::new(),
+        Vec::new(),
+    )
+}
@@ -0,0 +1,19 @@
+// Rules such as useOptionalChain, noUselessTernary, useExponentiationOperator,
+// noExtraBooleanCast and useNumberNamespace change nested expressions.
+import type { Foo } from "./foo";
+import { Bar } from "./bar";
+
+let enabled = !!(foo && foo.bar && foo.bar.baz) ? true : false;
+let count = parseInt("42") + Math.pow(2, 3);
+let label = "count: " + count + " of " + "total";
+let value: Bar = bar ?? null;
+
+if (!!enabled) {
+	if (count) {
+		label = label + "!";
+	}
+} else {
+	label = label;
+}
+
+export const result: Foo = { enabled, count, label, value };
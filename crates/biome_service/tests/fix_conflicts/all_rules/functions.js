@@ -0,0 +1,23 @@
+// Rules such as useArrowFunction, noUselessThisAlias, useTemplate and
+// noUselessStringConcat rewrite overlapping parts of these functions.
+var self = this;
+var greeting = function (name) {
+	return "Hello " + name + "!" + "";
+};
+
+var values = [1, 2, 3].map(function (value) {
+	if (value > 1) {
+		return value ** 2;
+	} else {
+		return Math.pow(value, 2);
+	}
+});
+
+function wrapper() {
+	const that = this;
+	return function () {
+		return that.value;
+	};
+}
+
+export { greeting, values, wrapper, self };
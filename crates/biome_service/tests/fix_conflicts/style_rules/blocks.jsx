@@ -0,0 +1,11 @@
+// Rules such as useBlockStatements, noUselessElse, useCollapsedElseIf and
+// noUselessFragments rewrite the same statements and JSX elements.
+function Component({ items, empty }) {
+	if (empty) return <><span>Nothing</span></>;
+	else {
+		if (items.length === 1) return <>{items[0]}</>;
+		else return <ul>{items.map((item) => <li key={item}>{item}</li>)}</ul>;
+	}
+}
+
+export default Component;
@@ -2,23 +2,33 @@
 mod test {
     use biome_analyze::RuleCategories;
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
+    use biome_configuration::json::{JsonFileParser, PartialJsonParser};
+    use biome_configuration::{
+        PartialConfiguration, PartialFormatterConfiguration, PartialGraphqlConfiguration,
+        PartialGraphqlFormatter, PartialGraphqlLinter, PartialHtmlConfiguration,
+        PartialHtmlFormatter, PartialJsonConfiguration,
+    };
+    use biome_deserialize::json::deserialize_from_json_str;
+    use biome_diagnostics::{print_diagnostic_to_string, Diagnostic, Error, Severity};
+    use biome_formatter::IndentStyle;
     use biome_fs::BiomePath;
-    use biome_js_syntax::{JsFileSource, TextSize};
+    use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+    use biome_json_parser::JsonParserOptions;
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, FileGuard, FixFileMode, FormatFileParams, MetricsParams, OpenFileParams,
+        ProjectKey, RegisterProjectFolderParams, RenameParams, RenameTokenParams,
+        UnregisterProjectFolderParams, UpdateSettingsParams,
     };
     use biome_service::Workspace;
-    fn create_server() -> Box<dyn Workspace> {
+    use indexmap::IndexMap;
+    fn create_server() -> (Box<dyn Workspace>, ProjectKey) {
         let workspace = server();
-        workspace
-            .register_project_folder(RegisterProjectFolderParams {
-                set_as_current_workspace: true,
-                path: None,
-            })
+        let project_key = workspace
+            .register_project_folder(RegisterProjectFolderParams { path: None })
             .unwrap();
 
-        workspace
+        (workspace, project_key)
     }
 
     #[test]
@@ -27,10 +37,11 @@ mod test {
         const GRAPH: &str = "flowchart TB
     block_0[\"<b>block_0</b><br/>Return(JS_RETURN_STATEMENT 19..26)<br/>Return\"]\n\n";
 
-        let workspace = create_server();
+        let (workspace, project_key) = create_server();
         let file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("file.js"),
                 content: SOURCE.into(),
                 version: 0,
@@ -46,11 +57,12 @@ mod test {
 
     #[test]
     fn recognize_typescript_definition_file() {
-        let workspace = create_server();
+        let (workspace, project_key) = create_server();
 
         let file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("file.d.ts"),
                 // the following code snippet can be correctly parsed in .d.ts file but not in .ts file
                 content: "export const foo: number".into(),
@@ -65,12 +77,13 @@ mod test {
 
     #[test]
     fn correctly_handle_json_files() {
-        let workspace = create_server();
+        let (workspace, project_key) = create_server();
 
         // ".json" file
         let json_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("a.json"),
                 content: r#"{"a": 42}"#.into(),
                 version: 0,
@@ -84,6 +97,7 @@ mod test {
         let json_file_with_comments = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("b.json"),
                 content: r#"{"a": 42}//comment"#.into(),
                 version: 0,
@@ -97,6 +111,7 @@ mod test {
         let json_file_with_trailing_commas = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("c.json"),
                 content: r#"{"a": 42,}"#.into(),
                 version: 0,
@@ -110,6 +125,7 @@ mod test {
         let jsonc_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("d.jsonc"),
                 content: r#"{"a": 42}//comment"#.into(),
                 version: 0,
@@ -123,6 +139,7 @@ mod test {
         let jsonc_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("e.jsonc"),
                 content: r#"{"a": 42,}"#.into(),
                 version: 0,
@@ -136,6 +153,7 @@ mod test {
         let well_known_json_with_comments_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new(".eslintrc.json"),
                 content: r#"{"a": 42}//comment"#.into(),
                 version: 0,
@@ -149,6 +167,7 @@ mod test {
         let well_known_json_with_comments_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("project/.vscode/settings.json"),
                 content: r#"{"a": 42}//comment"#.into(),
                 version: 0,
@@ -162,6 +181,7 @@ mod test {
         let well_known_json_with_comments_file_with_trailing_commas = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("dir/.eslintrc.json"),
                 content: r#"{"a": 42,}"#.into(),
                 version: 0,
@@ -177,6 +197,7 @@ mod test {
         let well_known_json_with_comments_and_trailing_commas_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("tsconfig.json"),
                 content: r#"{"a": 42,}//comment"#.into(),
                 version: 0,
@@ -189,13 +210,75 @@ mod test {
             .is_ok());
     }
 
+    #[test]
+    fn correctly_handle_well_known_files_of_the_configuration() {
+        let (workspace, project_key) = create_server();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    json: Some(PartialJsonConfiguration {
+                        parser: Some(PartialJsonParser {
+                            well_known_files: Some(IndexMap::from([
+                                (
+                                    ".eslintrc".to_string(),
+                                    JsonFileParser {
+                                        allow_comments: true,
+                                        allow_trailing_commas: true,
+                                        ..Default::default()
+                                    },
+                                ),
+                                (".jshintrc".to_string(), JsonFileParser::default()),
+                            ])),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        // registered file without extension allows the configured syntax
+        let registered_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("dir/.eslintrc"),
+                content: r#"{"a": 42,}//comment"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert!(registered_file.format_file().is_ok());
+
+        // registered file overrides the options of the built-in well-known file
+        let registered_file_without_comments = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new(".jshintrc"),
+                content: r#"{"a": 42}//comment"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert!(registered_file_without_comments.format_file().is_err());
+    }
+
     #[test]
     fn correctly_parses_graphql_files() {
-        let workspace = create_server();
+        let (workspace, project_key) = create_server();
 
         let graphql_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("file.graphql"),
                 content: r#"type Query {
   me: User
@@ -220,11 +303,12 @@ type User {
 
     #[test]
     fn correctly_pulls_lint_diagnostics() {
-        let workspace = create_server();
+        let (workspace, project_key) = create_server();
 
         let graphql_file = FileGuard::open(
             workspace.as_ref(),
             OpenFileParams {
+                project_key,
                 path: BiomePath::new("file.graphql"),
                 content: r#"query {
   member @deprecated(abc: 123)
@@ -248,4 +332,1425 @@ type User {
         let diagnostics = result.unwrap().diagnostics;
         assert_eq!(diagnostics.len(), 1)
     }
+
+    #[test]
+    fn reports_custom_properties_duplicated_in_other_stylesheets() {
+        let directory =
+            std::env::temp_dir().join(format!("biome_custom_properties_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("tokens.css"),
+            "@layer tokens {\n  :root {\n    --color-primary: #0052cc;\n    --spacing: 4px;\n  }\n}\n",
+        )
+        .unwrap();
+
+        let workspace = server();
+        let project_key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(directory.clone()),
+            })
+            .unwrap();
+
+        // `--spacing` has another value, and the second rule is in another layer
+        const SOURCE: &str = "@layer tokens {
+  :root {
+    --color-primary:   #0052cc;
+    --spacing: 8px;
+  }
+}
+:root {
+  --color-primary: #0052cc;
+}
+";
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new(directory.join("button.css")),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(
+                RuleCategories::all(),
+                10,
+                vec![RuleSelector::Rule(
+                    RuleGroup::Nursery,
+                    "noDuplicateCustomProperties",
+                )],
+                vec![],
+            )
+            .unwrap()
+            .diagnostics;
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("--color-primary").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(15)))
+        );
+    }
+
+    #[test]
+    fn reports_test_files_reexported_through_other_modules() {
+        let directory =
+            std::env::temp_dir().join(format!("biome_module_graph_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("src/fixtures/__tests__")).unwrap();
+        std::fs::write(
+            directory.join("src/public.ts"),
+            "export * from \"./utils\";\nexport * from \"./fixtures\";\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("src/utils.ts"),
+            "export const sum = (a: number, b: number) => a + b;\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("src/fixtures/index.ts"),
+            "export { createUser } from \"./__tests__/factories\";\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("src/fixtures/__tests__/factories.ts"),
+            "export const createUser = () => ({ name: \"Ada\" });\n",
+        )
+        .unwrap();
+
+        let workspace = server();
+        let project_key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(directory.clone()),
+            })
+            .unwrap();
+
+        const SOURCE: &str = "export * from \"./utils\";\nexport * from \"./public\";\n";
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new(directory.join("src/index.ts")),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(
+                RuleCategories::all(),
+                10,
+                vec![RuleSelector::Rule(
+                    RuleGroup::Nursery,
+                    "noWildcardExportsFromTests",
+                )],
+                vec![],
+            )
+            .unwrap()
+            .diagnostics;
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("\"./public\"").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(10)))
+        );
+        let printed = print_diagnostic_to_string(&Error::from(diagnostics[0].clone()));
+        assert!(printed.contains("src/fixtures/__tests__/factories.ts"));
+        assert!(printed.contains("src/public.ts, src/fixtures/index.ts"));
+    }
+
+    #[test]
+    fn renames_css_tokens_across_the_project() {
+        let directory =
+            std::env::temp_dir().join(format!("biome_rename_token_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("tokens.css"),
+            ":root {\n  --color-primary: #0052cc;\n  --color-primary-dark: #003d99;\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("App.jsx"),
+            "export const App = () => <button className=\"button large\" class='button'>OK</button>;\n",
+        )
+        .unwrap();
+
+        let workspace = server();
+        let project_key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(directory.clone()),
+            })
+            .unwrap();
+
+        const SOURCE: &str = ".button {\n  color: var(--color-primary);\n}\n.button-large .button:hover {\n  color: var(--color-primary-dark);\n}\n";
+        let path = BiomePath::new(directory.join("button.css"));
+        std::fs::write(path.as_path(), SOURCE).unwrap();
+        let _file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: path.clone(),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let rename = workspace
+            .rename(RenameParams {
+                project_key,
+                path: path.clone(),
+                symbol_at: TextSize::from(SOURCE.find("--color-primary").unwrap() as u32 + 2),
+                new_name: "--color-brand".into(),
+            })
+            .unwrap();
+        let renamed_token = workspace.rename_token(RenameTokenParams {
+            project_key,
+            name: ".button".into(),
+            new_name: "btn".into(),
+        });
+        let invalid_name = workspace.rename_token(RenameTokenParams {
+            project_key,
+            name: ".button".into(),
+            new_name: "1st".into(),
+        });
+        let read = |name: &str| std::fs::read_to_string(directory.join(name)).unwrap();
+        let tokens = read("tokens.css");
+        let app = read("App.jsx");
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            rename.indels.new_string(SOURCE),
+            ".button {\n  color: var(--color-brand);\n}\n.button-large .button:hover {\n  color: var(--color-primary-dark);\n}\n"
+        );
+        assert_eq!(rename.other_files.len(), 1);
+        assert_eq!(
+            rename.other_files[0].indels.new_string(&tokens),
+            ":root {\n  --color-brand: #0052cc;\n  --color-primary-dark: #003d99;\n}\n"
+        );
+
+        let files = renamed_token.unwrap().files;
+        assert_eq!(files.len(), 2);
+        assert_eq!(
+            files[0].indels.new_string(&app),
+            "export const App = () => <button className=\"btn large\" class='btn'>OK</button>;\n"
+        );
+        assert_eq!(
+            files[1].indels.new_string(SOURCE),
+            ".btn {\n  color: var(--color-primary);\n}\n.button-large .btn:hover {\n  color: var(--color-primary-dark);\n}\n"
+        );
+        assert!(invalid_name.is_err());
+    }
+
+    #[test]
+    fn serves_requests_while_the_settings_are_updated() {
+        let (workspace, project_key) = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                content: "if (a) { b }".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let update_settings = |indent_style| {
+            workspace.update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    formatter: Some(PartialFormatterConfiguration {
+                        indent_style: Some(indent_style),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+        };
+
+        // The CLI updates the settings while the editor formats the file
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for index in 0..100 {
+                    let indent_style = if index % 2 == 0 {
+                        IndentStyle::Space
+                    } else {
+                        IndentStyle::Tab
+                    };
+                    update_settings(indent_style).unwrap();
+                }
+            });
+            for _ in 0..100 {
+                let printed = file.format_file().unwrap();
+                assert!(matches!(
+                    printed.as_code(),
+                    "if (a) {\n\tb;\n}\n" | "if (a) {\n  b;\n}\n"
+                ));
+            }
+        });
+
+        update_settings(IndentStyle::Space).unwrap();
+        assert_eq!(file.format_file().unwrap().as_code(), "if (a) {\n  b;\n}\n");
+    }
+
+    fn register_project_with_indent_style(
+        workspace: &dyn Workspace,
+        path: &str,
+        indent_style: IndentStyle,
+    ) -> ProjectKey {
+        let project_key = workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: Some(path.into()),
+            })
+            .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    formatter: Some(PartialFormatterConfiguration {
+                        indent_style: Some(indent_style),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+        project_key
+    }
+
+    #[test]
+    fn serves_the_requests_of_each_project_with_its_settings() {
+        let workspace = server();
+        let register_project = |path: &str, indent_style| {
+            register_project_with_indent_style(workspace.as_ref(), path, indent_style)
+        };
+        let spaces = register_project("/spaces", IndentStyle::Space);
+        let tabs = register_project("/tabs", IndentStyle::Tab);
+        let open_file = |project_key, path: &str| {
+            FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    project_key,
+                    path: BiomePath::new(path),
+                    content: "if (a) { b }".into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap()
+        };
+        let spaces_file = open_file(spaces, "/spaces/file.js");
+        let tabs_file = open_file(tabs, "/tabs/file.js");
+
+        // The editor and the CLI send requests for different projects
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    let printed = tabs_file.format_file().unwrap();
+                    assert_eq!(printed.as_code(), "if (a) {\n\tb;\n}\n");
+                }
+            });
+            for _ in 0..100 {
+                let printed = spaces_file.format_file().unwrap();
+                assert_eq!(printed.as_code(), "if (a) {\n  b;\n}\n");
+            }
+        });
+    }
+
+    #[test]
+    fn parses_the_documents_of_each_project_with_its_settings() {
+        let workspace = server();
+        let register_project = |path: &str, allow_comments| {
+            let project_key = workspace
+                .register_project_folder(RegisterProjectFolderParams {
+                    path: Some(path.into()),
+                })
+                .unwrap();
+            workspace
+                .update_settings(UpdateSettingsParams {
+                    project_key,
+                    configuration: PartialConfiguration {
+                        json: Some(PartialJsonConfiguration {
+                            parser: Some(PartialJsonParser {
+                                allow_comments: Some(allow_comments),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    vcs_base_path: None,
+                    gitignore_matches: Vec::new(),
+                    workspace_directory: None,
+                })
+                .unwrap();
+            project_key
+        };
+        let with_comments = register_project("/with-comments", true);
+        let without_comments = register_project("/without-comments", false);
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key: with_comments,
+                path: BiomePath::new("comments.json"),
+                content: r#"{ "a": 42 } // comment"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert!(file.format_file().is_ok());
+
+        // The same document, requested for another project, is parsed with the settings of this project
+        let result = workspace.format_file(FormatFileParams {
+            project_key: without_comments,
+            path: BiomePath::new("comments.json"),
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolves_the_project_of_the_requests_without_project_key() {
+        let workspace = server();
+        register_project_with_indent_style(workspace.as_ref(), "/spaces", IndentStyle::Space);
+        register_project_with_indent_style(workspace.as_ref(), "/tabs", IndentStyle::Tab);
+        let open_file = |path: &str| {
+            FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    project_key: ProjectKey::default(),
+                    path: BiomePath::new(path),
+                    content: "if (a) { b }".into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap()
+        };
+
+        // Each request applies to the project that contains its file, whatever file was opened last
+        let spaces_file = open_file("/spaces/file.js");
+        let tabs_file = open_file("/tabs/file.js");
+        assert_eq!(
+            spaces_file.format_file().unwrap().as_code(),
+            "if (a) {\n  b;\n}\n"
+        );
+        assert_eq!(
+            tabs_file.format_file().unwrap().as_code(),
+            "if (a) {\n\tb;\n}\n"
+        );
+
+        let result = workspace.open_file(OpenFileParams {
+            project_key: ProjectKey::default(),
+            path: BiomePath::new("/outside/file.js"),
+            content: "if (a) { b }".into(),
+            version: 0,
+            document_file_source: None,
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reports_the_unknown_projects() {
+        let (workspace, project_key) = create_server();
+        workspace
+            .unregister_project_folder(UnregisterProjectFolderParams {
+                path: BiomePath::new(""),
+            })
+            .unwrap();
+        let result = workspace.open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new("file.js"),
+            content: "statement()".into(),
+            version: 0,
+            document_file_source: None,
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn collects_metrics() {
+        let (workspace, project_key) = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("metrics.js"),
+                content: "statement()".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        file.format_file().unwrap();
+        file.format_file().unwrap();
+
+        // The metrics are shared with the other tests of the process
+        let metrics = workspace.metrics(MetricsParams {}).unwrap();
+        assert!(metrics.requests["format_file"] >= 2);
+        assert!(metrics.parse_cache.hits >= 1);
+        assert!(metrics.parse_cache.misses >= 1);
+        assert!(metrics.parse_cache.hit_rate.is_some());
+    }
+
+    #[test]
+    fn parses_the_documents_again_when_the_settings_change() {
+        let (workspace, project_key) = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("comments.json"),
+                content: r#"{ "a": 42 } // comment"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert!(file.format_file().is_err());
+
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    json: Some(PartialJsonConfiguration {
+                        parser: Some(PartialJsonParser {
+                            allow_comments: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+        assert!(file.format_file().is_ok());
+    }
+
+    #[test]
+    fn parses_the_documents_again_when_they_change() {
+        let (workspace, project_key) = create_server();
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("changed.json"),
+                content: r#"{ "a": 42 }"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert!(file.format_file().is_ok());
+
+        file.change_file(1, r#"{ "a": 42 } // comment"#.into())
+            .unwrap();
+        assert!(file.format_file().is_err());
+
+        file.change_file(2, r#"{ "a": 43 }"#.into()).unwrap();
+        assert_eq!(file.format_file().unwrap().as_code(), "{ \"a\": 43 }\n");
+    }
+
+    #[test]
+    fn parses_the_documents_again_when_they_are_opened_again() {
+        let (workspace, project_key) = create_server();
+        let open_file = |content: &str| {
+            FileGuard::open(
+                workspace.as_ref(),
+                OpenFileParams {
+                    project_key,
+                    path: BiomePath::new("reopened.json"),
+                    content: content.into(),
+                    version: 0,
+                    document_file_source: None,
+                },
+            )
+            .unwrap()
+        };
+        let file = open_file(r#"{ "a": 42 }"#);
+        assert!(file.format_file().is_ok());
+        drop(file);
+
+        // The document is opened again with the same version
+        let file = open_file(r#"{ "a": 42 } // comment"#);
+        assert!(file.format_file().is_err());
+    }
+
+    fn enable_graphql(workspace: &dyn Workspace, project_key: ProjectKey) {
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    graphql: Some(PartialGraphqlConfiguration {
+                        formatter: Some(PartialGraphqlFormatter {
+                            enabled: Some(true),
+                            ..Default::default()
+                        }),
+                        linter: Some(PartialGraphqlLinter {
+                            enabled: Some(true),
+                            ..Default::default()
+                        }),
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn formats_graphql_templates() {
+        let (workspace, project_key) = create_server();
+        enable_graphql(workspace.as_ref(), project_key);
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                content: r#"const query = gql`query User{user{name}}`;
+function useUser() {
+	return useQuery(gql`{ user { ...${fragment} } }`);
+}
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        // Templates with substitutions are left untouched
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"const query = gql`
+	query User {
+		user {
+			name
+		}
+	}
+`;
+function useUser() {
+	return useQuery(gql`{ user { ...${fragment} } }`);
+}
+"#
+        );
+    }
+
+    #[test]
+    fn does_not_format_graphql_templates_by_default() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                content: "const query = gql`query User{user{name}}`;\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            "const query = gql`query User{user{name}}`;\n"
+        );
+    }
+
+    #[test]
+    fn pulls_diagnostics_of_graphql_templates() {
+        const SOURCE: &str = "const query = gql`
+  query {
+    member @deprecated(abc: 123)
+  }
+`;
+";
+        let (workspace, project_key) = create_server();
+        enable_graphql(workspace.as_ref(), project_key);
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.ts"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(
+                RuleCategories::all(),
+                10,
+                vec![RuleSelector::Rule(
+                    RuleGroup::Nursery,
+                    "useDeprecatedReason",
+                )],
+                vec![],
+            )
+            .unwrap()
+            .diagnostics;
+
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("@deprecated").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(21)))
+        );
+    }
+
+    #[test]
+    fn formats_css_templates() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                content: r#"const Button = styled.button`color:${(props) => props.color};
+  ${truncate}
+  ${ellipsis};
+  &:hover{padding:4px ${padding}}
+`;
+const Title = styled(Heading).attrs({ level: 1 })`${property}:0;MARGIN:0 auto`;
+const GlobalStyle = createGlobalStyle`body{margin:0}`;
+const unknown = html`<b>1</b>`;
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"const Button = styled.button`
+	color: ${(props) => props.color};
+	${truncate}
+	${ellipsis};
+	&:hover {
+		padding: 4px ${padding};
+	}
+`;
+const Title = styled(Heading).attrs({ level: 1 })`
+	${property}: 0;
+	MARGIN: 0 auto;
+`;
+const GlobalStyle = createGlobalStyle`
+	body {
+		margin: 0;
+	}
+`;
+const unknown = html`<b>1</b>`;
+"#
+        );
+    }
+
+    #[test]
+    fn pulls_diagnostics_of_css_templates() {
+        const SOURCE: &str = "const Box = styled.div`
+  width: 10pixels;
+  height: ${height}px;
+`;
+";
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.jsx"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(
+                RuleCategories::all(),
+                10,
+                vec![RuleSelector::Rule(RuleGroup::Correctness, "noUnknownUnit")],
+                vec![],
+            )
+            .unwrap()
+            .diagnostics;
+
+        // The placeholder of the substitution isn't reported
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("pixels").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(6)))
+        );
+    }
+
+    #[test]
+    fn formats_sql_templates() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                content: r#"async function getPosts(teamId, since) {
+  return await sql`select p.id, count(*) as likes from posts p left join likes l on l.post_id = p.id
+    where p.team_id = ${teamId} and p.created_at between ${since} and now() or p.pinned
+    group by p.id order by likes desc limit 10`;
+}
+const query = /* sql */ `select name from users where id in (select user_id from admins where level > -1)`;
+await Prisma.sql`insert into users (name, "createdAt") values ('O''Brien', now()::date) -- the date
+on conflict (name) do update set count = case when users.count > 0 and users.active then 1 else 0 end`;
+const text = `select 1`;
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        let formatted = file.format_file().unwrap();
+        assert_eq!(
+            formatted.as_code(),
+            r#"async function getPosts(teamId, since) {
+	return await sql`
+		SELECT p.id, count(*) AS likes
+		FROM posts p
+		LEFT JOIN likes l ON l.post_id = p.id
+		WHERE p.team_id = ${teamId}
+			AND p.created_at BETWEEN ${since} AND now()
+			OR p.pinned
+		GROUP BY p.id
+		ORDER BY likes DESC
+		LIMIT 10
+	`;
+}
+const query = /* sql */ `
+	SELECT name
+	FROM users
+	WHERE id IN (
+		SELECT user_id
+		FROM admins
+		WHERE level > -1
+	)
+`;
+await Prisma.sql`
+	INSERT INTO users (name, "createdAt")
+	VALUES ('O''Brien', now()::date) -- the date
+	ON CONFLICT (name) DO UPDATE
+	SET count = CASE WHEN users.count > 0 AND users.active THEN 1 ELSE 0 END
+`;
+const text = `select 1`;
+"#
+        );
+
+        // The formatted queries are stable
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("formatted.js"),
+                content: formatted.as_code().into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(file.format_file().unwrap().as_code(), formatted.as_code());
+    }
+
+    #[test]
+    fn pulls_diagnostics_with_the_severity_chosen_by_the_rule() {
+        const CONFIGURATION: &str = r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noUnsupportedCssFeatures": {
+          "level": "warn",
+          "options": { "properties": "error", "values": "info" }
+        }
+      }
+    }
+  }
+}"#;
+        let (workspace, project_key) = create_server();
+        let configuration = deserialize_from_json_str::<PartialConfiguration>(
+            CONFIGURATION,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.css"),
+                content: "h1 { text-wrap: balance; }\n.grid { grid-template-columns: subgrid; }\n"
+                    .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let result = file
+            .pull_diagnostics(RuleCategories::all(), 10, vec![], vec![])
+            .unwrap();
+
+        let severities: Vec<_> = result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.severity())
+            .collect();
+        assert_eq!(severities, [Severity::Error, Severity::Information]);
+        assert_eq!(result.errors, 1);
+    }
+
+    #[test]
+    fn formats_vue_blocks() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.vue"),
+                content: r#"<template>
+<div   v-if="visible" :class="classes"   @click="toggle">{{ message }}</div>
+</template>
+
+<script setup lang="ts">
+const   message :string="Hello"
+</script>
+
+<style scoped>
+.message{color:red}
+</style>
+
+<style lang="scss">
+$color : blue;
+.title{ color:$color }
+</style>
+
+<style lang="stylus">
+.title
+  color red
+</style>
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        // The blocks written in other languages are left untouched
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"<template>
+	<div v-if="visible" :class="classes" @click="toggle">{{ message }}</div>
+</template>
+
+<script setup lang="ts">
+const message: string = "Hello";
+</script>
+
+<style scoped>
+.message {
+	color: red;
+}
+</style>
+
+<style lang="scss">
+$color: blue;
+.title {
+	color: $color;
+}
+</style>
+
+<style lang="stylus">
+.title
+  color red
+</style>
+"#
+        );
+    }
+
+    #[test]
+    fn does_not_format_vue_templates_when_the_html_formatter_is_disabled() {
+        let (workspace, project_key) = create_server();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration: PartialConfiguration {
+                    html: Some(PartialHtmlConfiguration {
+                        formatter: Some(PartialHtmlFormatter {
+                            enabled: Some(false),
+                            ..Default::default()
+                        }),
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.vue"),
+                content: r#"<template>
+<div   v-if="visible"   @click="toggle">{{ message }}</div>
+</template>
+
+<script setup>
+const   message="Hello"
+</script>
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"<template>
+<div   v-if="visible"   @click="toggle">{{ message }}</div>
+</template>
+
+<script setup>
+const message = "Hello";
+</script>
+"#
+        );
+    }
+
+    #[test]
+    fn formats_svelte_markup() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.svelte"),
+                content: r#"<script lang="ts">
+let   items :string[]=[]
+</script>
+
+{#if items.length}
+<ul>
+{#each items as item}
+<li   class={item}>{ item }</li>
+{/each}
+</ul>
+{:else}
+<p>Empty</p>
+{/if}
+
+<style>
+li{color:red}
+</style>
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"<script lang="ts">
+let items: string[] = [];
+</script>
+
+{#if items.length}
+	<ul>
+		{#each items as item}
+			<li class={item}>{item}</li>
+		{/each}
+	</ul>
+{:else}
+	<p>Empty</p>
+{/if}
+
+<style>
+li {
+	color: red;
+}
+</style>
+"#
+        );
+    }
+
+    #[test]
+    fn formats_astro_components() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.astro"),
+                content: r#"---
+import Card from "../components/Card.astro";
+const   title :string="Hello"
+---
+<main><h1   class="title">{title}</h1>
+<Card   title={title}/></main>
+
+<script>
+console.log(  "untouched"  )
+</script>
+
+<style>
+.title{color:red}
+</style>
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"---
+import Card from "../components/Card.astro";
+const title: string = "Hello";
+---
+<main>
+	<h1 class="title">{title}</h1>
+	<Card title={title} />
+</main>
+
+<script>
+console.log(  "untouched"  )
+</script>
+
+<style>
+.title {
+	color: red;
+}
+</style>
+"#
+        );
+    }
+
+    #[test]
+    fn pulls_diagnostics_of_svelte_markup() {
+        const CONFIGURATION: &str = r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noAtHtmlTags": "error"
+      }
+    }
+  }
+}"#;
+        const SOURCE: &str = r#"<script>
+debugger;
+</script>
+
+<p>{@html content}</p>
+"#;
+        let (workspace, project_key) = create_server();
+        let configuration = deserialize_from_json_str::<PartialConfiguration>(
+            CONFIGURATION,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                project_key,
+                configuration,
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.svelte"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(RuleCategories::all(), 10, vec![], vec![])
+            .unwrap()
+            .diagnostics;
+
+        // The ranges are relative to the whole file
+        let spans: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.location().span)
+            .collect();
+        let debugger = TextSize::from(SOURCE.find("debugger").unwrap() as u32);
+        let at_html = TextSize::from(SOURCE.find("{@html").unwrap() as u32);
+        assert_eq!(
+            spans,
+            [
+                Some(TextRange::at(debugger, TextSize::from(9))),
+                Some(TextRange::at(at_html, TextSize::from(15))),
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_markdown_code_blocks() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.md"),
+                content: r#"# Example
+
+```ts
+const   answer :number=42
+```
+
+- A list item:
+
+  ```js
+  statement ( ) ;
+  ```
+
+```js
+statement (
+```
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        // Code blocks with syntax errors are left untouched
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"# Example
+
+```ts
+const answer: number = 42;
+```
+
+- A list item:
+
+  ```js
+  statement();
+  ```
+
+```js
+statement (
+```
+"#
+        );
+    }
+
+    #[test]
+    fn pulls_diagnostics_of_markdown_code_blocks() {
+        const SOURCE: &str = "# Example
+
+- A list item:
+
+  ```js
+  debugger;
+  ```
+";
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.md"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(RuleCategories::all(), 10, vec![], vec![])
+            .unwrap()
+            .diagnostics;
+
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("debugger").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(9)))
+        );
+    }
+
+    #[test]
+    fn fixes_markdown_code_blocks() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.md"),
+                content: "Some text.\n\n```ts\nvar foo: string = \"\";\n```\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let result = file
+            .fix_file(
+                FixFileMode::SafeFixes,
+                false,
+                RuleCategories::all(),
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        assert_eq!(result.code, "Some text.\n\n```ts\nvar foo = \"\";\n```\n");
+        assert_eq!(result.actions.len(), 1);
+    }
+
+    #[test]
+    fn formats_mdx_blocks() {
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.mdx"),
+                content: r#"import {Chart} from './chart.js'
+export const meta = {title:'Sales'}
+
+Sales
+=====
+
+<Chart data={[1,2,3]} title={meta.title}/>
+
+<Note>
+  Some *Markdown*   text.
+</Note>
+"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        // The JSX blocks that contain Markdown are left untouched
+        assert_eq!(
+            file.format_file().unwrap().as_code(),
+            r#"import { Chart } from "./chart.js";
+export const meta = { title: "Sales" };
+
+# Sales
+
+<Chart data={[1, 2, 3]} title={meta.title} />
+
+<Note>
+  Some *Markdown*   text.
+</Note>
+"#
+        );
+    }
+
+    #[test]
+    fn pulls_diagnostics_of_mdx_blocks() {
+        const SOURCE: &str = r#"import { Chart, Table } from "./charts.js";
+
+# Sales
+
+<Chart data={sales} />
+"#;
+        let (workspace, project_key) = create_server();
+
+        let file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                project_key,
+                path: BiomePath::new("file.mdx"),
+                content: SOURCE.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let diagnostics = file
+            .pull_diagnostics(
+                RuleCategories::all(),
+                10,
+                vec![RuleSelector::Rule(
+                    RuleGroup::Correctness,
+                    "noUnusedImports",
+                )],
+                vec![],
+            )
+            .unwrap()
+            .diagnostics;
+
+        // `Chart` is used by the JSX block
+        assert_eq!(diagnostics.len(), 1);
+        let start = TextSize::from(SOURCE.find("Table").unwrap() as u32);
+        assert_eq!(
+            diagnostics[0].location().span,
+            Some(TextRange::at(start, TextSize::from(5)))
+        );
+    }
 }
@@ -27,34 +27,95 @@ pub struct Suppression<'a> {
     pub is_legacy: bool,
 }
 
+/// The delimiters of the comments that can contain a suppression in a given language.
+///
+/// Each language registers the comment syntax it supports, so a suppression is only
+/// recognized in the comments that the language can actually contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentSyntax {
+    /// The prefixes of the line comments, such as `//` or `#`
+    pub line: &'static [&'static str],
+    /// The opening and closing delimiters of the block comments, such as `/*` and `*/`
+    pub block: &'static [(&'static str, &'static str)],
+}
+
+impl CommentSyntax {
+    /// `// comment` and `/* comment */`, used by JavaScript, JSON and CSS
+    pub const C_STYLE: Self = Self {
+        line: &["//"],
+        block: &[("/*", "*/")],
+    };
+
+    /// `# comment`, used by GraphQL
+    pub const HASH: Self = Self {
+        line: &["#"],
+        block: &[],
+    };
+
+    /// `<!-- comment -->`, used by HTML and Markdown
+    pub const MARKUP: Self = Self {
+        line: &[],
+        block: &[("<!--", "-->")],
+    };
+
+    /// The comment syntaxes of all the supported languages
+    pub const ANY: Self = Self {
+        line: &["//", "#"],
+        block: &[("/*", "*/"), ("<!--", "-->")],
+    };
+
+    /// Strips the delimiters from `comment`, returning its content and whether
+    /// it's a block comment. Returns [None] if `comment` doesn't use this syntax.
+    fn strip_delimiters<'a>(&self, comment: &'a str) -> Option<(&'a str, bool)> {
+        for (open, close) in self.block {
+            if let Some(content) = comment.strip_prefix(open) {
+                let content = content
+                    .strip_suffix(close)
+                    // Unclosed block comments may end with part of the closing delimiter
+                    .or_else(|| content.strip_suffix(|c| close.contains(c)))
+                    .unwrap_or(content);
+                return Some((content, true));
+            }
+        }
+
+        self.line
+            .iter()
+            .find_map(|prefix| comment.strip_prefix(prefix))
+            .map(|content| (content, false))
+    }
+}
+
+/// Parses the suppressions of a comment written in any of the supported comment syntaxes.
+///
+/// Prefer [parse_suppression_comment_with] with the [CommentSyntax] of the language
+/// the comment comes from.
 pub fn parse_suppression_comment(
     base: &str,
 ) -> impl Iterator<Item = Result<Suppression, SuppressionDiagnostic>> {
-    let (head, mut comment) = if base.starts_with('#') {
-        base.split_at(1)
-    } else if base.starts_with("<!--") {
-        base.split_at(4)
-    } else {
-        base.split_at(2)
-    };
+    parse_suppression_comment_with(base, CommentSyntax::ANY)
+}
 
-    let is_block_comment = match head {
-        "//" => false,
-        "/*" => {
-            comment = comment
-                .strip_suffix("*/")
-                .or_else(|| comment.strip_suffix(['*', '/']))
-                .unwrap_or(comment);
-            true
-        }
-        "#" => false,
-        "<!--" => {
-            comment = comment.strip_suffix("-->").unwrap_or(comment);
-            true
-        }
-        token => panic!("comment with unknown opening token {token:?}, from {comment}"),
-    };
+/// Parses the suppressions of a comment written in the given [CommentSyntax].
+///
+/// Comments that don't use the syntax don't contain any suppression.
+pub fn parse_suppression_comment_with(
+    base: &str,
+    syntax: CommentSyntax,
+) -> impl Iterator<Item = Result<Suppression, SuppressionDiagnostic>> {
+    syntax
+        .strip_delimiters(base)
+        .into_iter()
+        .flat_map(move |(comment, is_block_comment)| {
+            parse_comment_lines(base, comment, is_block_comment)
+        })
+}
 
+/// Parses the suppression lines of `comment`, the content of `base` without its delimiters
+fn parse_comment_lines<'a>(
+    base: &'a str,
+    comment: &'a str,
+    is_block_comment: bool,
+) -> impl Iterator<Item = Result<Suppression<'a>, SuppressionDiagnostic>> {
     comment.lines().filter_map(move |line| {
         // Eat start of line whitespace
         let mut line = line.trim_start();
@@ -517,7 +578,9 @@ mod tests_biome_ignore {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{
+        parse_suppression_comment, parse_suppression_comment_with, CommentSyntax, Suppression,
+    };
 
     #[test]
     fn parse_simple_suppression() {
@@ -732,4 +795,69 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_markup_suppression() {
+        assert_eq!(
+            parse_suppression_comment_with(
+                "<!-- biome-ignore lint/a11y/useAltText: explanation -->",
+                CommentSyntax::MARKUP
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/a11y/useAltText"), None)],
+                reason: "explanation",
+                is_legacy: false
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment_with(
+                "<!--
+                  biome-ignore format: explanation
+                -->",
+                CommentSyntax::MARKUP
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "explanation",
+                is_legacy: false
+            })],
+        );
+    }
+
+    #[test]
+    fn ignore_comments_of_other_syntaxes() {
+        assert_eq!(
+            parse_suppression_comment_with(
+                "// biome-ignore format: explanation",
+                CommentSyntax::MARKUP
+            )
+            .collect::<Vec<_>>(),
+            vec![],
+        );
+
+        assert_eq!(
+            parse_suppression_comment_with(
+                "<!-- biome-ignore format: explanation -->",
+                CommentSyntax::C_STYLE
+            )
+            .collect::<Vec<_>>(),
+            vec![],
+        );
+
+        assert_eq!(
+            parse_suppression_comment_with(
+                "# biome-ignore format: explanation",
+                CommentSyntax::HASH
+            )
+            .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("format"), None)],
+                reason: "explanation",
+                is_legacy: false
+            })],
+        );
+    }
 }
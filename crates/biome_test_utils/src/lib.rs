@@ -1,3 +1,4 @@
+use biome_analyze::browserslist::BrowserTargets;
 use biome_analyze::options::{JsxRuntime, PreferredQuote};
 use biome_analyze::{AnalyzerAction, AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
 use biome_configuration::PartialConfiguration;
@@ -15,7 +16,7 @@ use similar::TextDiff;
 use std::ffi::{c_int, OsStr};
 use std::fmt::Write;
 use std::path::Path;
-use std::sync::Once;
+use std::sync::{Arc, Once};
 
 pub fn scripts_from_json(extension: &OsStr, input_code: &str) -> Option<Vec<String>> {
     if extension == "json" || extension == "jsonc" {
@@ -43,6 +44,7 @@ pub fn create_analyzer_options(
         globals: vec![],
         preferred_quote: PreferredQuote::Double,
         jsx_runtime: Some(JsxRuntime::Transparent),
+        report_broad_suppressions: false,
     };
     let options_file = input_file.with_extension("options.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -74,7 +76,7 @@ pub fn create_analyzer_options(
                 .and_then(|js| js.formatter.as_ref())
                 .and_then(|f| {
                     f.quote_style.map(|quote_style| {
-                        if quote_style.is_double() {
+                        if quote_style.is_double() || quote_style.is_preserve() {
                             PreferredQuote::Double
                         } else {
                             PreferredQuote::Single
@@ -93,6 +95,11 @@ pub fn create_analyzer_options(
                 ReactClassic => Some(JsxRuntime::ReactClassic),
                 Transparent => Some(JsxRuntime::Transparent),
             };
+            analyzer_configuration.report_broad_suppressions = configuration
+                .linter
+                .as_ref()
+                .and_then(|linter| linter.report_broad_suppressions)
+                .unwrap_or_default();
             analyzer_configuration.globals = configuration
                 .javascript
                 .as_ref()
@@ -145,6 +152,15 @@ pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option
     None
 }
 
+/// Loads the browsers targeted by `input_file`.
+///
+/// The targets are read from the sibling file with the `browserslistrc` extension.
+/// Otherwise, the default browsers are targeted.
+pub fn load_browser_targets(input_file: &Path) -> Option<Arc<BrowserTargets>> {
+    let content = std::fs::read_to_string(input_file.with_extension("browserslistrc")).ok()?;
+    Some(Arc::new(BrowserTargets::from_browserslistrc(&content)))
+}
+
 pub fn diagnostic_to_string(name: &str, source: &str, diag: Error) -> String {
     let error = diag.with_file_path(name).with_file_source_code(source);
     let text = markup_to_string(biome_console::markup! {
@@ -0,0 +1,115 @@
+use crate::utils::key_path;
+use crate::TomlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_source_rule, ActionCategory, Ast, Rule, RuleAction,
+    SourceActionKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt,
+};
+use biome_toml_factory::make::{toml_inline_table_entry_list, toml_key_value_list};
+use biome_toml_syntax::{
+    AnyTomlKeyValue, TomlArrayOfTables, TomlInlineTable, TomlKeyValue, TomlTable,
+};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+declare_source_rule! {
+    /// Sorts the keys of a TOML table in natural order
+    ///
+    /// The keys of the tables, the arrays of tables and the inline tables are sorted.
+    /// The comments above a key are moved with it.
+    ///
+    /// To sort the keys of some files only, enable the assist for these files with `overrides`.
+    ///
+    pub UseSortedTableKeys {
+        version: "next",
+        name: "useSortedTableKeys",
+        language: "toml",
+    }
+}
+
+declare_node_union! {
+    pub AnyTomlTable = TomlTable | TomlArrayOfTables | TomlInlineTable
+}
+
+impl Rule for UseSortedTableKeys {
+    type Query = Ast<AnyTomlTable>;
+    type State = Box<[TomlKeyValue]>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let entries = match ctx.query() {
+            AnyTomlTable::TomlTable(table) => collect_entries(table.entries().iter())?,
+            AnyTomlTable::TomlArrayOfTables(table) => collect_entries(table.entries().iter())?,
+            AnyTomlTable::TomlInlineTable(table) => {
+                table.entries().iter().collect::<Result<Vec<_>, _>>().ok()?
+            }
+        };
+
+        let mut entries = entries
+            .into_iter()
+            .map(|entry| Some((key_path(&entry.key().ok()?)?.join("."), entry)))
+            .collect::<Option<Vec<_>>>()?;
+
+        // The keys are already sorted if each key comes after the previous one
+        let is_sorted = entries
+            .windows(2)
+            .all(|pair| natord::compare(&pair[0].0, &pair[1].0) != Ordering::Greater);
+        if is_sorted {
+            return None;
+        }
+
+        // The sort is stable, so the entries with the same key keep their order
+        entries.sort_by(|(name, _), (other_name, _)| natord::compare(name, other_name));
+        Some(entries.into_iter().map(|(_, entry)| entry).collect())
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<TomlRuleAction> {
+        let entries = state.iter().map(|entry| entry.clone().detach());
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            AnyTomlTable::TomlTable(table) => {
+                let sorted = toml_key_value_list(entries.map(AnyTomlKeyValue::TomlKeyValue));
+                mutation.replace_node(table.entries(), sorted);
+            }
+            AnyTomlTable::TomlArrayOfTables(table) => {
+                let sorted = toml_key_value_list(entries.map(AnyTomlKeyValue::TomlKeyValue));
+                mutation.replace_node(table.entries(), sorted);
+            }
+            AnyTomlTable::TomlInlineTable(table) => {
+                // The separators are kept in place, with their spacing. The space before
+                // the closing brace belongs to the last entry, so it moves to the new last entry.
+                let list = table.entries();
+                let separators = list.separators().collect::<Result<Vec<_>, _>>().ok()?;
+                let last_trivia = list.syntax().last_token()?.trailing_trivia().pieces();
+                let mut entries = entries
+                    .map(|entry| entry.trim_trailing_trivia())
+                    .collect::<Option<Vec<_>>>()?;
+                let last = entries.pop()?.append_trivia_pieces(last_trivia)?;
+                entries.push(last);
+                let sorted = toml_inline_table_entry_list(entries, separators);
+                mutation.replace_node(list, sorted);
+            }
+        }
+
+        Some(RuleAction::new(
+            rule_action_category!(),
+            Applicability::Always,
+            markup! {
+                "The keys of the current table can be sorted."
+            },
+            mutation,
+        ))
+    }
+}
+
+/// Returns the key-value pairs of a table, or `None` if the table contains bogus entries.
+fn collect_entries(entries: impl Iterator<Item = AnyTomlKeyValue>) -> Option<Vec<TomlKeyValue>> {
+    entries
+        .map(|entry| entry.as_toml_key_value().cloned())
+        .collect()
+}
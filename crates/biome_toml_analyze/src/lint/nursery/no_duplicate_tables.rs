@@ -0,0 +1,136 @@
+use crate::utils::key_path;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_rowan::{AstNode, TextRange};
+use biome_toml_syntax::{AnyTomlRootItem, TomlKey, TomlRoot};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow defining the same table more than once.
+    ///
+    /// A table can be defined only once in a TOML document, and TOML parsers reject the documents
+    /// that define it again. An array of tables can't reuse the name of a table either.
+    ///
+    /// A quoted key and a bare key with the same content are the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```toml,expect_diagnostic
+    /// [dependencies]
+    /// serde = "1.0"
+    ///
+    /// [dependencies]
+    /// tracing = "0.1"
+    /// ```
+    ///
+    /// ```toml,expect_diagnostic
+    /// [bin]
+    /// name = "biome"
+    ///
+    /// [[bin]]
+    /// name = "xtask"
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```toml
+    /// [[bin]]
+    /// name = "biome"
+    /// [bin.metadata]
+    /// path = "src/main.rs"
+    ///
+    /// [[bin]]
+    /// name = "xtask"
+    /// [bin.metadata]
+    /// path = "xtask/main.rs"
+    /// ```
+    pub NoDuplicateTables {
+        version: "next",
+        name: "noDuplicateTables",
+        language: "toml",
+        recommended: true,
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum TableKind {
+    Table,
+    ArrayOfTables,
+}
+
+pub struct DuplicateTable {
+    /// The key of the header that defines the table again.
+    key: TomlKey,
+    /// The range of the first definition of the table.
+    first_definition: TextRange,
+}
+
+impl Rule for NoDuplicateTables {
+    type Query = Ast<TomlRoot>;
+    type State = DuplicateTable;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut definitions = FxHashMap::<Vec<String>, (TableKind, TextRange)>::default();
+        let mut duplicates = Vec::new();
+        for item in ctx.query().items() {
+            let (key, kind) = match item {
+                AnyTomlRootItem::TomlTable(table) => (table.key(), TableKind::Table),
+                AnyTomlRootItem::TomlArrayOfTables(array) => {
+                    (array.key(), TableKind::ArrayOfTables)
+                }
+                AnyTomlRootItem::TomlKeyValue(_) | AnyTomlRootItem::TomlBogus(_) => continue,
+            };
+            let Ok(key) = key else {
+                continue;
+            };
+            let Some(path) = key_path(&key) else {
+                continue;
+            };
+
+            match (kind, definitions.get(&path)) {
+                // Each header of an array of tables adds a new table to the array
+                (TableKind::ArrayOfTables, None | Some((TableKind::ArrayOfTables, _))) => {
+                    // The sub-tables of the previous table of the array can be defined again
+                    definitions.retain(|defined, _| !defined.starts_with(&path));
+                    definitions.insert(path, (kind, key.range()));
+                }
+                (TableKind::Table, None) => {
+                    definitions.insert(path, (kind, key.range()));
+                }
+                (_, Some((_, first_definition))) => {
+                    duplicates.push(DuplicateTable {
+                        first_definition: *first_definition,
+                        key,
+                    });
+                }
+            }
+        }
+        duplicates.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = key_path(&state.key)?.join(".");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.key.range(),
+                markup! {
+                    "The table "<Emphasis>{name}</Emphasis>" is defined more than once."
+                },
+            )
+            .detail(
+                state.first_definition,
+                markup! {
+                    "The table is first defined here."
+                },
+            )
+            .note(markup! {
+                "TOML parsers reject the documents that define the same table twice."
+            }),
+        )
+    }
+}
@@ -0,0 +1,9 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::assists;
+use crate::lint;
+
+pub type NoDuplicateTables =
+    <lint::nursery::no_duplicate_tables::NoDuplicateTables as biome_analyze::Rule>::Options;
+pub type UseSortedTableKeys =
+    <assists::source::use_sorted_table_keys::UseSortedTableKeys as biome_analyze::Rule>::Options;
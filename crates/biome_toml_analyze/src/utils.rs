@@ -0,0 +1,26 @@
+use biome_rowan::AstSeparatedList;
+use biome_toml_syntax::{AnyTomlKeyPart, TomlKey};
+
+/// Returns the parts of `key`, without the quotes of the quoted parts.
+///
+/// The key `a."b".'c'` returns `["a", "b", "c"]`.
+pub(crate) fn key_path(key: &TomlKey) -> Option<Vec<String>> {
+    key.parts()
+        .iter()
+        .map(|part| {
+            let token = match part.ok()? {
+                AnyTomlKeyPart::TomlBareKey(key) => key.value_token().ok()?,
+                AnyTomlKeyPart::TomlQuotedKey(key) => key.value_token().ok()?,
+            };
+            let text = token.text_trimmed();
+            let name = ['"', '\'']
+                .into_iter()
+                .find_map(|quote| {
+                    text.strip_prefix(quote)
+                        .and_then(|text| text.strip_suffix(quote))
+                })
+                .unwrap_or(text);
+            Some(name.to_string())
+        })
+        .collect()
+}
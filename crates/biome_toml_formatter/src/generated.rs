@@ -0,0 +1,839 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, TomlFormatContext, TomlFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_toml_syntax::TomlArray> for crate::toml::auxiliary::array::FormatTomlArray {
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_toml_syntax::TomlArray, f: &mut TomlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlArray>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlArray {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlArray,
+        crate::toml::auxiliary::array::FormatTomlArray,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::array::FormatTomlArray::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlArray {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlArray,
+        crate::toml::auxiliary::array::FormatTomlArray,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::array::FormatTomlArray::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlArrayOfTables>
+    for crate::toml::auxiliary::array_of_tables::FormatTomlArrayOfTables
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlArrayOfTables,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlArrayOfTables>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlArrayOfTables {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlArrayOfTables,
+        crate::toml::auxiliary::array_of_tables::FormatTomlArrayOfTables,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::array_of_tables::FormatTomlArrayOfTables::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlArrayOfTables {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlArrayOfTables,
+        crate::toml::auxiliary::array_of_tables::FormatTomlArrayOfTables,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::array_of_tables::FormatTomlArrayOfTables::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlBareKey>
+    for crate::toml::auxiliary::bare_key::FormatTomlBareKey
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlBareKey,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlBareKey>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlBareKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlBareKey,
+        crate::toml::auxiliary::bare_key::FormatTomlBareKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::bare_key::FormatTomlBareKey::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlBareKey {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlBareKey,
+        crate::toml::auxiliary::bare_key::FormatTomlBareKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::bare_key::FormatTomlBareKey::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlBooleanValue>
+    for crate::toml::value::boolean_value::FormatTomlBooleanValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlBooleanValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlBooleanValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlBooleanValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlBooleanValue,
+        crate::toml::value::boolean_value::FormatTomlBooleanValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::value::boolean_value::FormatTomlBooleanValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlBooleanValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlBooleanValue,
+        crate::toml::value::boolean_value::FormatTomlBooleanValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::value::boolean_value::FormatTomlBooleanValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlDateTimeValue>
+    for crate::toml::value::date_time_value::FormatTomlDateTimeValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlDateTimeValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlDateTimeValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlDateTimeValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlDateTimeValue,
+        crate::toml::value::date_time_value::FormatTomlDateTimeValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::value::date_time_value::FormatTomlDateTimeValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlDateTimeValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlDateTimeValue,
+        crate::toml::value::date_time_value::FormatTomlDateTimeValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::value::date_time_value::FormatTomlDateTimeValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlFloatValue>
+    for crate::toml::value::float_value::FormatTomlFloatValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlFloatValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlFloatValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlFloatValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlFloatValue,
+        crate::toml::value::float_value::FormatTomlFloatValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::value::float_value::FormatTomlFloatValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlFloatValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlFloatValue,
+        crate::toml::value::float_value::FormatTomlFloatValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::value::float_value::FormatTomlFloatValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlInlineTable>
+    for crate::toml::auxiliary::inline_table::FormatTomlInlineTable
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlInlineTable,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlInlineTable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlInlineTable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlInlineTable,
+        crate::toml::auxiliary::inline_table::FormatTomlInlineTable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::inline_table::FormatTomlInlineTable::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlInlineTable {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlInlineTable,
+        crate::toml::auxiliary::inline_table::FormatTomlInlineTable,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::inline_table::FormatTomlInlineTable::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlIntegerValue>
+    for crate::toml::value::integer_value::FormatTomlIntegerValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlIntegerValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlIntegerValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlIntegerValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlIntegerValue,
+        crate::toml::value::integer_value::FormatTomlIntegerValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::value::integer_value::FormatTomlIntegerValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlIntegerValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlIntegerValue,
+        crate::toml::value::integer_value::FormatTomlIntegerValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::value::integer_value::FormatTomlIntegerValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlKey> for crate::toml::auxiliary::key::FormatTomlKey {
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_toml_syntax::TomlKey, f: &mut TomlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlKey>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlKey,
+        crate::toml::auxiliary::key::FormatTomlKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::toml::auxiliary::key::FormatTomlKey::default())
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlKey {
+    type Format =
+        FormatOwnedWithRule<biome_toml_syntax::TomlKey, crate::toml::auxiliary::key::FormatTomlKey>;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::toml::auxiliary::key::FormatTomlKey::default())
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlKeyValue>
+    for crate::toml::auxiliary::key_value::FormatTomlKeyValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlKeyValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlKeyValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlKeyValue,
+        crate::toml::auxiliary::key_value::FormatTomlKeyValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::key_value::FormatTomlKeyValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlKeyValue,
+        crate::toml::auxiliary::key_value::FormatTomlKeyValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::key_value::FormatTomlKeyValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlQuotedKey>
+    for crate::toml::auxiliary::quoted_key::FormatTomlQuotedKey
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlQuotedKey,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlQuotedKey>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlQuotedKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlQuotedKey,
+        crate::toml::auxiliary::quoted_key::FormatTomlQuotedKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::quoted_key::FormatTomlQuotedKey::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlQuotedKey {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlQuotedKey,
+        crate::toml::auxiliary::quoted_key::FormatTomlQuotedKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::quoted_key::FormatTomlQuotedKey::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlRoot> for crate::toml::auxiliary::root::FormatTomlRoot {
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_toml_syntax::TomlRoot, f: &mut TomlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlRoot,
+        crate::toml::auxiliary::root::FormatTomlRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::root::FormatTomlRoot::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlRoot {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlRoot,
+        crate::toml::auxiliary::root::FormatTomlRoot,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::root::FormatTomlRoot::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlStringValue>
+    for crate::toml::value::string_value::FormatTomlStringValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlStringValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlStringValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlStringValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlStringValue,
+        crate::toml::value::string_value::FormatTomlStringValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::value::string_value::FormatTomlStringValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlStringValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlStringValue,
+        crate::toml::value::string_value::FormatTomlStringValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::value::string_value::FormatTomlStringValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlTable> for crate::toml::auxiliary::table::FormatTomlTable {
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_toml_syntax::TomlTable, f: &mut TomlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_toml_syntax::TomlTable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlTable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlTable,
+        crate::toml::auxiliary::table::FormatTomlTable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::auxiliary::table::FormatTomlTable::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlTable {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlTable,
+        crate::toml::auxiliary::table::FormatTomlTable,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::auxiliary::table::FormatTomlTable::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlArrayElementList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlArrayElementList,
+        crate::toml::lists::array_element_list::FormatTomlArrayElementList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::lists::array_element_list::FormatTomlArrayElementList::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlArrayElementList {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlArrayElementList,
+        crate::toml::lists::array_element_list::FormatTomlArrayElementList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::lists::array_element_list::FormatTomlArrayElementList::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlInlineTableEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlInlineTableEntryList,
+        crate::toml::lists::inline_table_entry_list::FormatTomlInlineTableEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::lists::inline_table_entry_list::FormatTomlInlineTableEntryList::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlInlineTableEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlInlineTableEntryList,
+        crate::toml::lists::inline_table_entry_list::FormatTomlInlineTableEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::lists::inline_table_entry_list::FormatTomlInlineTableEntryList::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyPartList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlKeyPartList,
+        crate::toml::lists::key_part_list::FormatTomlKeyPartList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::lists::key_part_list::FormatTomlKeyPartList::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyPartList {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlKeyPartList,
+        crate::toml::lists::key_part_list::FormatTomlKeyPartList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::lists::key_part_list::FormatTomlKeyPartList::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlKeyValueList,
+        crate::toml::lists::key_value_list::FormatTomlKeyValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::lists::key_value_list::FormatTomlKeyValueList::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlKeyValueList {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlKeyValueList,
+        crate::toml::lists::key_value_list::FormatTomlKeyValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::lists::key_value_list::FormatTomlKeyValueList::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlRootItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlRootItemList,
+        crate::toml::lists::root_item_list::FormatTomlRootItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::lists::root_item_list::FormatTomlRootItemList::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlRootItemList {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlRootItemList,
+        crate::toml::lists::root_item_list::FormatTomlRootItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::lists::root_item_list::FormatTomlRootItemList::default(),
+        )
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlBogus> for crate::toml::bogus::bogus::FormatTomlBogus {
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_toml_syntax::TomlBogus, f: &mut TomlFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_toml_syntax::TomlBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlBogus,
+        crate::toml::bogus::bogus::FormatTomlBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::toml::bogus::bogus::FormatTomlBogus::default())
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlBogus {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlBogus,
+        crate::toml::bogus::bogus::FormatTomlBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::toml::bogus::bogus::FormatTomlBogus::default())
+    }
+}
+impl FormatRule<biome_toml_syntax::TomlBogusValue>
+    for crate::toml::bogus::bogus_value::FormatTomlBogusValue
+{
+    type Context = TomlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_toml_syntax::TomlBogusValue,
+        f: &mut TomlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_toml_syntax::TomlBogusValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::TomlBogusValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::TomlBogusValue,
+        crate::toml::bogus::bogus_value::FormatTomlBogusValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::bogus::bogus_value::FormatTomlBogusValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::TomlBogusValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::TomlBogusValue,
+        crate::toml::bogus::bogus_value::FormatTomlBogusValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::bogus::bogus_value::FormatTomlBogusValue::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlKeyPart {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::AnyTomlKeyPart,
+        crate::toml::any::key_part::FormatAnyTomlKeyPart,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::any::key_part::FormatAnyTomlKeyPart::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlKeyPart {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::AnyTomlKeyPart,
+        crate::toml::any::key_part::FormatAnyTomlKeyPart,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::any::key_part::FormatAnyTomlKeyPart::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlKeyValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::AnyTomlKeyValue,
+        crate::toml::any::key_value::FormatAnyTomlKeyValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::any::key_value::FormatAnyTomlKeyValue::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlKeyValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::AnyTomlKeyValue,
+        crate::toml::any::key_value::FormatAnyTomlKeyValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::any::key_value::FormatAnyTomlKeyValue::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlRootItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::AnyTomlRootItem,
+        crate::toml::any::root_item::FormatAnyTomlRootItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::toml::any::root_item::FormatAnyTomlRootItem::default(),
+        )
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlRootItem {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::AnyTomlRootItem,
+        crate::toml::any::root_item::FormatAnyTomlRootItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::toml::any::root_item::FormatAnyTomlRootItem::default(),
+        )
+    }
+}
+impl AsFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_toml_syntax::AnyTomlValue,
+        crate::toml::any::value::FormatAnyTomlValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::toml::any::value::FormatAnyTomlValue::default())
+    }
+}
+impl IntoFormat<TomlFormatContext> for biome_toml_syntax::AnyTomlValue {
+    type Format = FormatOwnedWithRule<
+        biome_toml_syntax::AnyTomlValue,
+        crate::toml::any::value::FormatAnyTomlValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::toml::any::value::FormatAnyTomlValue::default())
+    }
+}
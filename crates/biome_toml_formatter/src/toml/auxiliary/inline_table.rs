@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlInlineTable, TomlInlineTableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlInlineTable;
+
+impl FormatNodeRule<TomlInlineTable> for FormatTomlInlineTable {
+    fn fmt_fields(&self, node: &TomlInlineTable, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlInlineTableFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        // An inline table must stay on a single line, so its entries are never broken
+        if entries.is_empty() {
+            write!(f, [l_curly_token.format(), r_curly_token.format()])
+        } else {
+            write!(
+                f,
+                [
+                    l_curly_token.format(),
+                    space(),
+                    entries.format(),
+                    space(),
+                    r_curly_token.format()
+                ]
+            )
+        }
+    }
+}
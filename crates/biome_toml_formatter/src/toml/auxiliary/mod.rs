@@ -0,0 +1,11 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array;
+pub(crate) mod array_of_tables;
+pub(crate) mod bare_key;
+pub(crate) mod inline_table;
+pub(crate) mod key;
+pub(crate) mod key_value;
+pub(crate) mod quoted_key;
+pub(crate) mod root;
+pub(crate) mod table;
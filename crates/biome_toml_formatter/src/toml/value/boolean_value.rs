@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlBooleanValue, TomlBooleanValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlBooleanValue;
+
+impl FormatNodeRule<TomlBooleanValue> for FormatTomlBooleanValue {
+    fn fmt_fields(&self, node: &TomlBooleanValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlBooleanValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlDateTimeValue, TomlDateTimeValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlDateTimeValue;
+
+impl FormatNodeRule<TomlDateTimeValue> for FormatTomlDateTimeValue {
+    fn fmt_fields(&self, node: &TomlDateTimeValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlDateTimeValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlFloatValue, TomlFloatValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlFloatValue;
+
+impl FormatNodeRule<TomlFloatValue> for FormatTomlFloatValue {
+    fn fmt_fields(&self, node: &TomlFloatValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlFloatValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
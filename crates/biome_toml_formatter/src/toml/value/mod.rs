@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod boolean_value;
+pub(crate) mod date_time_value;
+pub(crate) mod float_value;
+pub(crate) mod integer_value;
+pub(crate) mod string_value;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlStringValue, TomlStringValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlStringValue;
+
+impl FormatNodeRule<TomlStringValue> for FormatTomlStringValue {
+    fn fmt_fields(&self, node: &TomlStringValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlStringValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,57 @@
+use biome_configuration::{PartialConfiguration, PartialTomlConfiguration, PartialTomlFormatter};
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_toml_formatter::{context::TomlFormatOptions, TomlFormatLanguage};
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_toml_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.toml` and the output file name is `{spec_name}.toml.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `toml/null` -> input: `tests/specs/toml/null.toml`, expected output: `tests/specs/toml/null.toml.snap`
+/// * `null` -> input: `tests/specs/null.toml`, expected output: `tests/specs/null.toml.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+    let configuration = PartialConfiguration {
+        toml: Some(PartialTomlConfiguration {
+            formatter: Some(PartialTomlFormatter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let Some(test_file) =
+        SpecTestFile::try_from_file(spec_input_file, root_path, Some(configuration))
+    else {
+        return;
+    };
+
+    let options = TomlFormatOptions::default();
+    let language = language::TomlTestFormatLanguage::default();
+
+    let snapshot = SpecSnapshot::new(
+        test_file,
+        test_directory,
+        language,
+        TomlFormatLanguage::new(options),
+    );
+
+    snapshot.test()
+}
@@ -0,0 +1,636 @@
+//! A lossless TOML lexer which yields SyntaxKind tokens used by the biome TOML parser.
+//!
+//! TOML lexes the same characters differently depending on their position: `true` or `1979-05-27`
+//! are keys on the left of a `=`, and values on its right. The lexer keeps track of this position
+//! by itself, because it only depends on the tokens that precede it.
+#[rustfmt::skip]
+mod tests;
+
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
+use biome_toml_syntax::{TextLen, TextSize, TomlSyntaxKind, TomlSyntaxKind::*, T};
+use std::iter::FusedIterator;
+
+pub struct Token {
+    kind: TomlSyntaxKind,
+    range: biome_rowan::TextRange,
+}
+
+impl Token {
+    #[allow(dead_code)]
+    pub fn kind(&self) -> TomlSyntaxKind {
+        self.kind
+    }
+
+    #[allow(dead_code)]
+    pub fn range(&self) -> biome_rowan::TextRange {
+        self.range
+    }
+}
+
+/// A bracket that contains the next token
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Bracket {
+    /// `[1, 2]`
+    Array,
+    /// `{ a = 1 }`
+    InlineTable,
+}
+
+#[derive(Debug)]
+pub(crate) struct TomlLexer<'src> {
+    /// Source text
+    source: &'src str,
+
+    /// The start byte position in the source text of the next token.
+    position: usize,
+
+    /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
+    unicode_bom_length: usize,
+
+    /// Byte offset of the current token from the start of the source
+    /// The range of the current token can be computed by
+    /// `self.position - self.current_start`.
+    current_start: TextSize,
+
+    /// The kind of the current token
+    current_kind: TomlSyntaxKind,
+
+    /// Flags for the current token
+    current_flags: TokenFlags,
+
+    diagnostics: Vec<ParseDiagnostic>,
+
+    /// `true` if the next token is on the right of a `=`, or is an element of an array
+    expects_value: bool,
+
+    /// The arrays and the inline tables that contain the next token
+    brackets: Vec<Bracket>,
+
+    /// `true` if no token other than trivia precedes the next token on its line
+    at_line_start: bool,
+
+    /// `true` if the lexer is inside the header of an array of tables, `[[a]]`
+    in_array_of_tables_header: bool,
+}
+
+impl<'src> Lexer<'src> for TomlLexer<'src> {
+    const NEWLINE: Self::Kind = NEWLINE;
+    const WHITESPACE: Self::Kind = WHITESPACE;
+
+    type Kind = TomlSyntaxKind;
+    type LexContext = ();
+    type ReLexContext = ();
+
+    fn source(&self) -> &'src str {
+        self.source
+    }
+
+    fn current(&self) -> Self::Kind {
+        self.current_kind
+    }
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn current_start(&self) -> TextSize {
+        self.current_start
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: ParseDiagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn has_unicode_escape(&self) -> bool {
+        self.current_flags().has_unicode_escape()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.current_flags().has_preceding_line_break()
+    }
+
+    fn consume_newline_or_whitespaces(&mut self) -> Self::Kind {
+        if self.consume_newline() {
+            self.current_flags
+                .set(TokenFlags::PRECEDING_LINE_BREAK, true);
+            NEWLINE
+        } else {
+            self.consume_whitespaces();
+            WHITESPACE
+        }
+    }
+
+    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+        self.current_start = self.text_position();
+        self.current_flags = TokenFlags::empty();
+
+        let kind = match self.current_byte() {
+            Some(current) => self.consume_token(current),
+            None => EOF,
+        };
+
+        self.current_kind = kind;
+        self.track_token(kind);
+
+        if !kind.is_trivia() {
+            self.current_flags
+                .set(TokenFlags::PRECEDING_LINE_BREAK, false);
+        }
+
+        kind
+    }
+
+    #[inline]
+    fn advance_char_unchecked(&mut self) {
+        let c = self.current_char_unchecked();
+        self.position += c.len_utf8();
+    }
+
+    /// Advances the current position by `n` bytes.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    fn rewind(&mut self, _checkpoint: LexerCheckpoint<Self::Kind>) {
+        unimplemented!("TOML lexer doesn't support rewinding");
+    }
+}
+
+impl<'src> LexerWithCheckpoint<'src> for TomlLexer<'src> {
+    fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
+        LexerCheckpoint {
+            position: TextSize::from(self.position as u32),
+            current_start: self.current_start,
+            current_flags: self.current_flags,
+            current_kind: self.current_kind,
+            after_line_break: self.has_preceding_line_break(),
+            unicode_bom_length: self.unicode_bom_length,
+            diagnostics_pos: self.diagnostics.len() as u32,
+        }
+    }
+}
+
+impl<'src> TomlLexer<'src> {
+    /// Make a new lexer from a str, this is safe because strs are valid utf8
+    pub fn from_str(source: &'src str) -> Self {
+        Self {
+            source,
+            current_kind: TOMBSTONE,
+            current_start: TextSize::from(0),
+            current_flags: TokenFlags::empty(),
+            position: 0,
+            diagnostics: vec![],
+            unicode_bom_length: 0,
+            expects_value: false,
+            brackets: Vec::new(),
+            at_line_start: true,
+            in_array_of_tables_header: false,
+        }
+    }
+
+    /// Bumps the current byte and creates a lexed token of the passed in kind
+    fn consume_byte(&mut self, tok: TomlSyntaxKind) -> TomlSyntaxKind {
+        self.advance(1);
+        tok
+    }
+
+    /// Lexes the next token
+    ///
+    /// Guaranteed to not be at the end of the file
+    fn consume_token(&mut self, current: u8) -> TomlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'#' => self.consume_comment(),
+            b'=' => self.consume_byte(T![=]),
+            b',' => self.consume_byte(T![,]),
+            b'{' => self.consume_byte(T!['{']),
+            b'}' => self.consume_byte(T!['}']),
+            b'[' => self.consume_l_brack(),
+            b']' => self.consume_r_brack(),
+            b'"' | b'\'' => self.consume_string(current),
+            _ if self.expects_value => self.consume_value(current),
+            b'.' => self.consume_byte(T![.]),
+            _ if is_bare_key_char(current) => self.consume_bare_key(),
+            _ if self.position == 0 => {
+                if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                    self.unicode_bom_length = bom_size;
+                    return bom;
+                }
+                self.consume_unexpected_character()
+            }
+            _ => self.consume_unexpected_character(),
+        }
+    }
+
+    /// Lexes a `[`, or the `[[` that opens the header of an array of tables
+    fn consume_l_brack(&mut self) -> TomlSyntaxKind {
+        self.assert_byte(b'[');
+        if self.brackets.is_empty()
+            && !self.expects_value
+            && self.at_line_start
+            && self.peek_byte() == Some(b'[')
+        {
+            self.in_array_of_tables_header = true;
+            self.advance(2);
+            T!["[["]
+        } else {
+            self.consume_byte(T!['['])
+        }
+    }
+
+    /// Lexes a `]`, or the `]]` that closes the header of an array of tables
+    fn consume_r_brack(&mut self) -> TomlSyntaxKind {
+        self.assert_byte(b']');
+        if self.in_array_of_tables_header
+            && self.brackets.is_empty()
+            && self.peek_byte() == Some(b']')
+        {
+            self.in_array_of_tables_header = false;
+            self.advance(2);
+            T!["]]"]
+        } else {
+            self.consume_byte(T![']'])
+        }
+    }
+
+    /// Keeps track of the tokens that determine how the next tokens are lexed
+    fn track_token(&mut self, kind: TomlSyntaxKind) {
+        match kind {
+            NEWLINE => {
+                self.at_line_start = true;
+                self.in_array_of_tables_header = false;
+                // Only arrays can span several lines
+                if self.brackets.is_empty() {
+                    self.expects_value = false;
+                }
+                return;
+            }
+            WHITESPACE | COMMENT | UNICODE_BOM | EOF => return,
+            T![=] => self.expects_value = true,
+            T!['['] if self.expects_value => self.brackets.push(Bracket::Array),
+            T!['{'] => {
+                self.brackets.push(Bracket::InlineTable);
+                self.expects_value = false;
+            }
+            T![']'] | T!['}'] if !self.brackets.is_empty() => {
+                self.brackets.pop();
+                self.expects_value = false;
+            }
+            T![,] => self.expects_value = self.brackets.last() == Some(&Bracket::Array),
+            _ => self.expects_value = false,
+        }
+        self.at_line_start = false;
+    }
+
+    /// Lexes a bare key, made of ASCII letters, ASCII digits, underscores and dashes
+    fn consume_bare_key(&mut self) -> TomlSyntaxKind {
+        while let Some(byte) = self.current_byte() {
+            if is_bare_key_char(byte) {
+                self.advance(1);
+            } else {
+                break;
+            }
+        }
+        IDENT
+    }
+
+    /// Lexes a boolean, a number, or a date-time
+    fn consume_value(&mut self, current: u8) -> TomlSyntaxKind {
+        if !is_value_char(current) {
+            return self.consume_unexpected_character();
+        }
+
+        let start = self.position;
+        self.consume_value_chars();
+
+        // A date and a time can be separated by a space: `1979-05-27 07:32:00`
+        if is_date(&self.source[start..self.position])
+            && self.current_byte() == Some(b' ')
+            && is_time_start(&self.source.as_bytes()[self.position + 1..])
+        {
+            self.advance(1);
+            self.consume_value_chars();
+        }
+
+        let text = &self.source[start..self.position];
+        match text {
+            "true" => T![true],
+            "false" => T![false],
+            _ if is_date_time(text) => TOML_DATE_TIME_LITERAL,
+            _ if is_integer(text) => TOML_INTEGER_LITERAL,
+            _ if is_float(text) => TOML_FLOAT_LITERAL,
+            _ => {
+                let diagnostic = ParseDiagnostic::new(
+                    format!("Invalid value `{text}`"),
+                    self.current_start..self.text_position(),
+                )
+                .with_hint("Strings must be enclosed in quotes.");
+                self.push_diagnostic(diagnostic);
+                ERROR_TOKEN
+            }
+        }
+    }
+
+    fn consume_value_chars(&mut self) {
+        while let Some(byte) = self.current_byte() {
+            if is_value_char(byte) {
+                self.advance(1);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Lexes a basic string, `"a"`, a literal string, `'a'`, or their multi-line forms,
+    /// `"""a"""` and `'''a'''`
+    fn consume_string(&mut self, quote: u8) -> TomlSyntaxKind {
+        self.assert_byte(quote);
+        let start = self.text_position();
+        let is_multiline = self.peek_byte() == Some(quote) && self.byte_at(2) == Some(quote);
+
+        if is_multiline {
+            self.advance(3);
+        } else {
+            self.advance(1);
+        }
+
+        let is_basic = quote == b'"';
+        loop {
+            match self.current_byte() {
+                Some(byte) if byte == quote => {
+                    if !is_multiline {
+                        self.advance(1);
+                        return TOML_STRING_LITERAL;
+                    }
+                    if self.peek_byte() == Some(quote) && self.byte_at(2) == Some(quote) {
+                        self.advance(3);
+                        // Up to two quotes can precede the closing delimiter: `"""a""""`
+                        for _ in 0..2 {
+                            if self.current_byte() == Some(quote) {
+                                self.advance(1);
+                            }
+                        }
+                        return TOML_STRING_LITERAL;
+                    }
+                    self.advance(1);
+                }
+                Some(b'\\') if is_basic => self.consume_escape_sequence(is_multiline),
+                Some(b'\n' | b'\r') if !is_multiline => break,
+                Some(byte) => self.advance_byte_or_char(byte),
+                None => break,
+            }
+        }
+
+        let diagnostic = ParseDiagnostic::new("Missing closing quote", start..self.text_position());
+        let diagnostic = if self.is_eof() {
+            diagnostic.with_detail(
+                self.source.text_len()..self.source.text_len(),
+                "file ends here",
+            )
+        } else {
+            diagnostic.with_hint("Use a multi-line string for the strings that span several lines.")
+        };
+        self.push_diagnostic(diagnostic);
+
+        TOML_STRING_LITERAL
+    }
+
+    /// Lexes an escape sequence of a basic string. Assumes that the lexer is positioned at the
+    /// `\` token.
+    fn consume_escape_sequence(&mut self, is_multiline: bool) {
+        self.assert_byte(b'\\');
+        let escape_start = self.text_position();
+        self.advance(1);
+
+        match self.current_byte() {
+            Some(b'b' | b't' | b'n' | b'f' | b'r' | b'"' | b'\\') => self.advance(1),
+            Some(b'u') => self.consume_unicode_escape(escape_start, 4),
+            Some(b'U') => self.consume_unicode_escape(escape_start, 8),
+            // A line ending backslash trims the whitespaces up to the next non-whitespace
+            Some(b' ' | b'\t' | b'\n' | b'\r') if is_multiline => {}
+            Some(byte) => {
+                self.advance_byte_or_char(byte);
+                let diagnostic = ParseDiagnostic::new(
+                    "Invalid escape sequence",
+                    escape_start..self.text_position(),
+                )
+                .with_alternatives(
+                    "Expected one of the following values",
+                    &[
+                        r"\b",
+                        r"\t",
+                        r"\n",
+                        r"\f",
+                        r"\r",
+                        r#"\""#,
+                        r"\\",
+                        r"\uXXXX where X is a hexadecimal number",
+                        r"\UXXXXXXXX where X is a hexadecimal number",
+                    ],
+                );
+                self.push_diagnostic(diagnostic);
+            }
+            None => {}
+        }
+    }
+
+    /// Lexes a `\uXXXX` or a `\UXXXXXXXX` escape sequence. Assumes that the lexer is positioned
+    /// at the `u` or `U` token.
+    fn consume_unicode_escape(&mut self, escape_start: TextSize, digits: usize) {
+        self.advance(1);
+
+        for _ in 0..digits {
+            match self.current_byte() {
+                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                _ => {
+                    let diagnostic = ParseDiagnostic::new(
+                        "Invalid unicode sequence",
+                        escape_start..self.text_position(),
+                    )
+                    .with_hint(if digits == 4 {
+                        "A `\\u` escape sequence must consist of 4 hexadecimal numbers: `\\uXXXX`, e.g. `\\u002F` for `/`."
+                    } else {
+                        "A `\\U` escape sequence must consist of 8 hexadecimal numbers: `\\UXXXXXXXX`, e.g. `\\U0000002F` for `/`."
+                    });
+                    self.push_diagnostic(diagnostic);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn consume_comment(&mut self) -> TomlSyntaxKind {
+        self.assert_byte(b'#');
+
+        self.advance(1);
+
+        while let Some(chr) = self.current_byte() {
+            match chr {
+                b'\n' | b'\r' => return COMMENT,
+                chr => self.advance_byte_or_char(chr),
+            }
+        }
+        COMMENT
+    }
+
+    fn consume_unexpected_character(&mut self) -> TomlSyntaxKind {
+        self.assert_current_char_boundary();
+
+        let char = self.current_char_unchecked();
+        let diagnostic = ParseDiagnostic::new(
+            format!("Unexpected character `{char}`"),
+            self.text_position()..self.text_position() + char.text_len(),
+        );
+        self.push_diagnostic(diagnostic);
+        self.advance(char.len_utf8());
+
+        ERROR_TOKEN
+    }
+}
+
+impl Iterator for TomlLexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = self.next_token(());
+        if kind == EOF {
+            None
+        } else {
+            Some(Token {
+                kind,
+                range: self.current_range(),
+            })
+        }
+    }
+}
+
+impl FusedIterator for TomlLexer<'_> {}
+
+fn is_bare_key_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-')
+}
+
+/// Returns `true` for the characters of booleans, numbers, and date-times
+fn is_value_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-' | b'+' | b'.' | b':')
+}
+
+/// Returns `true` if the text is a full date: `1979-05-27`
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() == 10
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| matches!(index, 4 | 7) || byte.is_ascii_digit())
+}
+
+/// Returns `true` if the bytes start with the hours and the minutes of a time: `07:32`
+fn is_time_start(bytes: &[u8]) -> bool {
+    matches!(bytes, [h1, h2, b':', m1, m2, ..] if [h1, h2, m1, m2].iter().all(|byte| byte.is_ascii_digit()))
+}
+
+/// Returns `true` if the text is an offset date-time, a local date-time, a local date, or a
+/// local time
+fn is_date_time(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    if bytes.len() >= 10 && is_date(&text[..10]) {
+        return match bytes.get(10) {
+            None => true,
+            Some(b'T' | b't' | b' ') => is_time(&text[11..]),
+            Some(_) => false,
+        };
+    }
+    is_time(text)
+}
+
+/// Returns `true` if the text is a time, with an optional fraction and an optional offset:
+/// `07:32:00.999-07:00`
+fn is_time(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    if bytes.len() < 8 || !is_time_start(bytes) || bytes[5] != b':' {
+        return false;
+    }
+    if !bytes[6].is_ascii_digit() || !bytes[7].is_ascii_digit() {
+        return false;
+    }
+    let mut rest = &bytes[8..];
+    if let [b'.', fraction @ ..] = rest {
+        let digits = fraction
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+    match rest {
+        [] | [b'Z' | b'z'] => true,
+        [b'+' | b'-', offset @ ..] => is_time_start(offset) && offset.len() == 5,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the text is a decimal, hexadecimal, octal, or binary integer
+fn is_integer(text: &str) -> bool {
+    if let Some(digits) = text.strip_prefix("0x") {
+        return is_digits(digits, |byte| byte.is_ascii_hexdigit());
+    }
+    if let Some(digits) = text.strip_prefix("0o") {
+        return is_digits(digits, |byte| matches!(byte, b'0'..=b'7'));
+    }
+    if let Some(digits) = text.strip_prefix("0b") {
+        return is_digits(digits, |byte| matches!(byte, b'0' | b'1'));
+    }
+    is_decimal_integer(text.strip_prefix(['+', '-']).unwrap_or(text))
+}
+
+/// Returns `true` if the text is a decimal integer without a sign and without leading zeros
+fn is_decimal_integer(text: &str) -> bool {
+    text == "0" || (!text.starts_with('0') && is_digits(text, |byte| byte.is_ascii_digit()))
+}
+
+/// Returns `true` if the text is a float: `3.14`, `5e+22`, `-inf`, or `nan`
+fn is_float(text: &str) -> bool {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    if matches!(unsigned, "inf" | "nan") {
+        return true;
+    }
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    if fraction.is_none() && exponent.is_none() {
+        return false;
+    }
+    is_decimal_integer(integer)
+        && fraction.is_none_or(|fraction| is_digits(fraction, |byte| byte.is_ascii_digit()))
+        && exponent.is_none_or(|exponent| {
+            let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            is_digits(exponent, |byte| byte.is_ascii_digit())
+        })
+}
+
+/// Returns `true` if the text is made of digits, where each underscore is surrounded by digits
+fn is_digits(text: &str, is_digit: impl Fn(u8) -> bool) -> bool {
+    let bytes = text.as_bytes();
+    !bytes.is_empty()
+        && bytes.iter().all(|byte| *byte == b'_' || is_digit(*byte))
+        && bytes.first().is_some_and(|byte| is_digit(*byte))
+        && bytes.last().is_some_and(|byte| is_digit(*byte))
+        && !text.contains("__")
+}
@@ -0,0 +1,381 @@
+#![cfg(test)]
+#![allow(unused_mut, unused_variables, unused_assignments)]
+
+use super::{TextSize, TomlLexer};
+use biome_parser::lexer::Lexer;
+use biome_toml_syntax::TomlSyntaxKind::{self, EOF};
+use quickcheck_macros::quickcheck;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// Assert the result of lexing a piece of source code,
+// and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
+macro_rules! assert_lex {
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = TomlLexer::from_str($src);
+        let mut idx = 0;
+        let mut tok_idx = TextSize::default();
+
+        let mut new_str = String::with_capacity($src.len());
+        let mut tokens = vec![];
+
+        while lexer.next_token(()) != EOF {
+            tokens.push((lexer.current(), lexer.current_range()));
+        }
+
+        $(
+            assert_eq!(
+                tokens[idx].0,
+                TomlSyntaxKind::$kind,
+                "expected token kind {}, but found {:?}",
+                stringify!($kind),
+                tokens[idx].0,
+            );
+
+            assert_eq!(
+                tokens[idx].1.len(),
+                TextSize::from($len),
+                "expected token length of {}, but found {:?} for token {:?}",
+                $len,
+                tokens[idx].1.len(),
+                tokens[idx].0,
+            );
+
+            new_str.push_str(&$src[tokens[idx].1]);
+            tok_idx += tokens[idx].1.len();
+
+            idx += 1;
+        )*
+
+        if idx < tokens.len() {
+            panic!(
+                "expected {} tokens but lexer returned {}, first unexpected token is '{:?}'",
+                idx,
+                tokens.len(),
+                tokens[idx].0
+            );
+        } else {
+            assert_eq!(idx, tokens.len());
+        }
+
+        assert_eq!($src, new_str, "Failed to reconstruct input");
+    }};
+}
+
+// This is for testing if the lexer is truly lossless
+// It parses random strings and puts them back together with the produced tokens and compares
+#[quickcheck]
+fn losslessness(string: String) -> bool {
+    // using an mpsc channel allows us to spawn a thread and spawn the lexer there, then if
+    // it takes more than 2 seconds we panic because it is 100% infinite recursion
+    let cloned = string.clone();
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let mut lexer = TomlLexer::from_str(&cloned);
+        let mut tokens = vec![];
+
+        while lexer.next_token(()) != EOF {
+            tokens.push(lexer.current_range());
+        }
+
+        sender
+            .send(tokens)
+            .expect("Could not send tokens to receiver");
+    });
+    let token_ranges = receiver
+        .recv_timeout(Duration::from_secs(2))
+        .unwrap_or_else(|_| panic!("Lexer is infinitely recursing with this code: ->{string}<-"));
+
+    let mut new_str = String::with_capacity(string.len());
+    let mut idx = TextSize::from(0);
+
+    for range in token_ranges {
+        new_str.push_str(&string[range]);
+        idx += range.len();
+    }
+
+    string == new_str
+}
+
+#[test]
+fn empty() {
+    assert_lex! {
+        "",
+    }
+}
+
+#[test]
+fn key_value() {
+    assert_lex! {
+        r#"name = "biome""#,
+        IDENT:4,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:7
+    }
+}
+
+#[test]
+fn dotted_key() {
+    assert_lex! {
+        r#"a.'b'."c" = 1"#,
+        IDENT:1,
+        DOT:1,
+        TOML_STRING_LITERAL:3,
+        DOT:1,
+        TOML_STRING_LITERAL:3,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:1
+    }
+}
+
+#[test]
+fn keys_that_look_like_values() {
+    assert_lex! {
+        "true = 1\n1234 = false",
+        IDENT:4,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:1,
+        NEWLINE:1,
+        IDENT:4,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        FALSE_KW:5
+    }
+}
+
+#[test]
+fn table_headers() {
+    assert_lex! {
+        "[a]\n[[b]]",
+        L_BRACK:1,
+        IDENT:1,
+        R_BRACK:1,
+        NEWLINE:1,
+        L_DOUBLE_BRACK:2,
+        IDENT:1,
+        R_DOUBLE_BRACK:2
+    }
+}
+
+#[test]
+fn nested_arrays() {
+    assert_lex! {
+        "a = [[1], [2]]",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        L_BRACK:1,
+        TOML_INTEGER_LITERAL:1,
+        R_BRACK:1,
+        COMMA:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        TOML_INTEGER_LITERAL:1,
+        R_BRACK:1,
+        R_BRACK:1
+    }
+}
+
+#[test]
+fn multiline_array() {
+    assert_lex! {
+        "a = [\n  true,\n]\nb = 1",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        NEWLINE:1,
+        WHITESPACE:2,
+        TRUE_KW:4,
+        COMMA:1,
+        NEWLINE:1,
+        R_BRACK:1,
+        NEWLINE:1,
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:1
+    }
+}
+
+#[test]
+fn inline_table() {
+    assert_lex! {
+        "a = { true = false }",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        L_CURLY:1,
+        WHITESPACE:1,
+        IDENT:4,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        FALSE_KW:5,
+        WHITESPACE:1,
+        R_CURLY:1
+    }
+}
+
+#[test]
+fn numbers() {
+    assert_lex! {
+        "a = +1_000",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:6
+    }
+
+    assert_lex! {
+        "a = 0xDEAD_beef",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:11
+    }
+
+    assert_lex! {
+        "a = -3.14e-2",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_FLOAT_LITERAL:8
+    }
+
+    assert_lex! {
+        "a = -inf",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_FLOAT_LITERAL:4
+    }
+}
+
+#[test]
+fn invalid_numbers() {
+    assert_lex! {
+        "a = 01",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        ERROR_TOKEN:2
+    }
+
+    assert_lex! {
+        "a = 1__0",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        ERROR_TOKEN:4
+    }
+}
+
+#[test]
+fn date_times() {
+    assert_lex! {
+        "a = 1979-05-27T07:32:00Z",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:20
+    }
+
+    assert_lex! {
+        "a = 1979-05-27 00:32:00.999999-07:00",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:32
+    }
+
+    assert_lex! {
+        "a = 1979-05-27 # date",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:10,
+        WHITESPACE:1,
+        COMMENT:6
+    }
+
+    assert_lex! {
+        "a = 07:32:00",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_DATE_TIME_LITERAL:8
+    }
+}
+
+#[test]
+fn multiline_strings() {
+    assert_lex! {
+        "a = \"\"\"\nline \"quoted\"\n\"\"\"",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:21
+    }
+
+    assert_lex! {
+        "a = '''C:\\path'''",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:13
+    }
+}
+
+#[test]
+fn unquoted_string() {
+    assert_lex! {
+        "a = biome",
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        ERROR_TOKEN:5
+    }
+}
+
+#[test]
+fn comment() {
+    assert_lex! {
+        "# comment\na = 1 # trailing",
+        COMMENT:9,
+        NEWLINE:1,
+        IDENT:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:1,
+        WHITESPACE:1,
+        COMMENT:10
+    }
+}
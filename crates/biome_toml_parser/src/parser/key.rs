@@ -0,0 +1,50 @@
+use super::parse_error::{expected_key, multiline_string_key};
+use super::TomlParser;
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_toml_syntax::TomlSyntaxKind::*;
+use biome_toml_syntax::T;
+
+pub(crate) fn is_at_key(p: &mut TomlParser) -> bool {
+    p.at(IDENT) || p.at(TOML_STRING_LITERAL)
+}
+
+/// Parses a key, whose parts are separated by dots: `a.b."c"`
+pub(crate) fn parse_key(p: &mut TomlParser) -> ParsedSyntax {
+    if !is_at_key(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let parts = p.start();
+    loop {
+        parse_key_part(p).or_add_diagnostic(p, expected_key);
+        if !p.eat(T![.]) {
+            break;
+        }
+    }
+    parts.complete(p, TOML_KEY_PART_LIST);
+
+    Present(m.complete(p, TOML_KEY))
+}
+
+/// Parses a bare key, `a`, or a quoted key, `"a"`
+fn parse_key_part(p: &mut TomlParser) -> ParsedSyntax {
+    match p.cur() {
+        IDENT => {
+            let m = p.start();
+            p.bump(IDENT);
+            Present(m.complete(p, TOML_BARE_KEY))
+        }
+        TOML_STRING_LITERAL => {
+            if matches!(p.cur_text().get(..3), Some("\"\"\"" | "'''")) {
+                let diagnostic = multiline_string_key(p, p.cur_range());
+                p.error(diagnostic);
+            }
+            let m = p.start();
+            p.bump(TOML_STRING_LITERAL);
+            Present(m.complete(p, TOML_QUOTED_KEY))
+        }
+        _ => Absent,
+    }
+}
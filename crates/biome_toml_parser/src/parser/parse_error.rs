@@ -0,0 +1,33 @@
+use crate::parser::TomlParser;
+use biome_parser::diagnostic::{expected_any, expected_node, ParseDiagnostic};
+use biome_parser::Parser;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_key(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key", range, p)
+}
+
+pub(crate) fn expected_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("value", range, p)
+}
+
+pub(crate) fn expected_key_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key-value pair", range, p)
+}
+
+pub(crate) fn expected_key_value_or_table(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["key-value pair", "table header"], range, p)
+}
+
+pub(crate) fn expected_line_break(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "Expected a line break before this content, but found none.",
+        range,
+    )
+    .with_hint("Each key-value pair and each table header must be on its own line.")
+}
+
+pub(crate) fn multiline_string_key(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("A multi-line string can't be used as a key.", range)
+        .with_hint("Use a single-line string instead.")
+}
@@ -0,0 +1,167 @@
+//! The items of a TOML document: key-value pairs, tables, and arrays of tables.
+//!
+//! Each item must be on its own line.
+use super::key::{is_at_key, parse_key};
+use super::parse_error::{
+    expected_key, expected_key_value, expected_key_value_or_table, expected_line_break,
+    expected_value,
+};
+use super::value::parse_value;
+use super::TomlParser;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_rowan::TextRange;
+use biome_toml_syntax::TomlSyntaxKind::{self, *};
+use biome_toml_syntax::T;
+
+pub(crate) struct RootItemList;
+
+impl ParseNodeList for RootItemList {
+    type Kind = TomlSyntaxKind;
+    type Parser<'source> = TomlParser<'source>;
+
+    const LIST_KIND: Self::Kind = TOML_ROOT_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !p.has_preceding_line_break() {
+            return parse_bogus_line(p, expected_line_break);
+        }
+        match p.cur() {
+            T!['['] => parse_table(p),
+            T!["[["] => parse_array_of_tables(p),
+            _ if is_at_key(p) => parse_key_value(p),
+            _ => parse_bogus_line(p, expected_key_value_or_table),
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(EOF)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(TOML_BOGUS, token_set![T!['['], T!["[["]])
+                .enable_recovery_on_line_break(),
+            expected_key_value_or_table,
+        )
+    }
+}
+
+/// The key-value pairs that follow a table header, up to the next table header
+struct KeyValueList;
+
+impl ParseNodeList for KeyValueList {
+    type Kind = TomlSyntaxKind;
+    type Parser<'source> = TomlParser<'source>;
+
+    const LIST_KIND: Self::Kind = TOML_KEY_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !p.has_preceding_line_break() {
+            return parse_bogus_line(p, expected_line_break);
+        }
+        if is_at_key(p) {
+            parse_key_value(p)
+        } else {
+            parse_bogus_line(p, expected_key_value)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['[']) || p.at(T!["[["]) || p.at(EOF)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(TOML_BOGUS, token_set![T!['['], T!["[["]])
+                .enable_recovery_on_line_break(),
+            expected_key_value,
+        )
+    }
+}
+
+/// Parses a table: `[package]`, followed by its key-value pairs
+fn parse_table(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['[']);
+    parse_key(p).or_add_diagnostic(p, expected_key);
+    p.expect(T![']']);
+    KeyValueList.parse_list(p);
+
+    Present(m.complete(p, TOML_TABLE))
+}
+
+/// Parses an array of tables: `[[bin]]`, followed by its key-value pairs
+fn parse_array_of_tables(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!["[["]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!["[["]);
+    parse_key(p).or_add_diagnostic(p, expected_key);
+    p.expect(T!["]]"]);
+    KeyValueList.parse_list(p);
+
+    Present(m.complete(p, TOML_ARRAY_OF_TABLES))
+}
+
+/// Parses a key-value pair: `name = "biome"`
+pub(crate) fn parse_key_value(p: &mut TomlParser) -> ParsedSyntax {
+    if !is_at_key(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_key(p).ok();
+    if p.expect(T![=]) {
+        // The value must be on the same line as its key
+        if p.has_preceding_line_break() {
+            let diagnostic = expected_value(p, p.cur_range());
+            p.error(diagnostic);
+        } else {
+            parse_value(p).or_add_diagnostic(p, expected_value);
+        }
+    }
+
+    Present(m.complete(p, TOML_KEY_VALUE))
+}
+
+/// Skips the tokens up to the end of the line, and wraps them in a bogus node
+fn parse_bogus_line(
+    p: &mut TomlParser,
+    error: impl FnOnce(&TomlParser, TextRange) -> ParseDiagnostic,
+) -> ParsedSyntax {
+    if p.at(EOF) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let start = p.cur_range().start();
+    let mut end = p.cur_range().end();
+    p.bump_any();
+    while !p.has_preceding_line_break() && !p.at(EOF) {
+        end = p.cur_range().end();
+        p.bump_any();
+    }
+    let diagnostic = error(p, TextRange::new(start, end));
+    p.error(diagnostic);
+
+    Present(m.complete(p, TOML_BOGUS))
+}
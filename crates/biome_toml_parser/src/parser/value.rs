@@ -0,0 +1,125 @@
+use super::parse_error::{expected_key_value, expected_value};
+use super::table::parse_key_value;
+use super::TomlParser;
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_toml_syntax::TomlSyntaxKind::{self, *};
+use biome_toml_syntax::T;
+
+/// Parses any value: a string, a number, a boolean, a date-time, an array, or an inline table
+pub(crate) fn parse_value(p: &mut TomlParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        T!['['] => return parse_array(p),
+        T!['{'] => return parse_inline_table(p),
+        TOML_STRING_LITERAL => TOML_STRING_VALUE,
+        TOML_INTEGER_LITERAL => TOML_INTEGER_VALUE,
+        TOML_FLOAT_LITERAL => TOML_FLOAT_VALUE,
+        TOML_DATE_TIME_LITERAL => TOML_DATE_TIME_VALUE,
+        T![true] | T![false] => TOML_BOOLEAN_VALUE,
+        _ => return Absent,
+    };
+
+    let m = p.start();
+    p.bump_any();
+    Present(m.complete(p, kind))
+}
+
+/// Parses an array: `[1, 2, 3]`
+fn parse_array(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['[']);
+    ArrayElementList.parse_list(p);
+    p.expect(T![']']);
+
+    Present(m.complete(p, TOML_ARRAY))
+}
+
+struct ArrayElementList;
+
+impl ParseSeparatedList for ArrayElementList {
+    type Kind = TomlSyntaxKind;
+    type Parser<'source> = TomlParser<'source>;
+
+    const LIST_KIND: Self::Kind = TOML_ARRAY_ELEMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(TOML_BOGUS_VALUE, token_set![T![,], T![']']]),
+            expected_value,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses an inline table: `{ name = "biome", version = "1.0.0" }`
+fn parse_inline_table(p: &mut TomlParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+    InlineTableEntryList.parse_list(p);
+    p.expect(T!['}']);
+
+    Present(m.complete(p, TOML_INLINE_TABLE))
+}
+
+struct InlineTableEntryList;
+
+impl ParseSeparatedList for InlineTableEntryList {
+    type Kind = TomlSyntaxKind;
+    type Parser<'source> = TomlParser<'source>;
+
+    const LIST_KIND: Self::Kind = TOML_INLINE_TABLE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_key_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(TOML_BOGUS, token_set![T![,], T!['}']]),
+            expected_key_value,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
@@ -0,0 +1,111 @@
+use crate::lexer::TomlLexer;
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::BufferedLexer;
+use biome_parser::prelude::TokenSource;
+use biome_parser::token_source::{TokenSourceWithBufferedLexer, Trivia};
+use biome_rowan::TriviaPieceKind;
+use biome_toml_syntax::TomlSyntaxKind::{EOF, UNICODE_BOM};
+use biome_toml_syntax::{TextRange, TomlSyntaxKind};
+
+pub(crate) struct TomlTokenSource<'source> {
+    lexer: BufferedLexer<TomlSyntaxKind, TomlLexer<'source>>,
+    trivia_list: Vec<Trivia>,
+
+    /// `true` if the current token is the first token of its line
+    preceding_line_break: bool,
+}
+
+impl<'source> TomlTokenSource<'source> {
+    pub(crate) fn new(lexer: BufferedLexer<TomlSyntaxKind, TomlLexer<'source>>) -> Self {
+        Self {
+            lexer,
+            trivia_list: Vec::new(),
+            preceding_line_break: true,
+        }
+    }
+
+    pub fn from_str(source: &'source str) -> Self {
+        let lexer = TomlLexer::from_str(source);
+        let lexer = BufferedLexer::new(lexer);
+
+        let mut source = TomlTokenSource::new(lexer);
+
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        let mut trailing = !first_token;
+        self.preceding_line_break = first_token || self.current() == UNICODE_BOM;
+
+        loop {
+            let kind = self.lexer.next_token(());
+
+            let trivia_kind = TriviaPieceKind::try_from(kind);
+
+            match trivia_kind {
+                Err(_) => {
+                    // Not trivia
+                    break;
+                }
+                Ok(trivia_kind) => {
+                    if trivia_kind.is_newline() {
+                        trailing = false;
+                        self.preceding_line_break = true;
+                    }
+
+                    self.trivia_list
+                        .push(Trivia::new(trivia_kind, self.current_range(), trailing));
+                }
+            }
+        }
+    }
+}
+
+impl<'source> TokenSource for TomlTokenSource<'source> {
+    type Kind = TomlSyntaxKind;
+
+    fn current(&self) -> Self::Kind {
+        self.lexer.current()
+    }
+
+    fn current_range(&self) -> TextRange {
+        self.lexer.current_range()
+    }
+
+    fn text(&self) -> &str {
+        self.lexer.source()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.preceding_line_break
+    }
+
+    fn bump(&mut self) {
+        if self.current() != EOF {
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn skip_as_trivia(&mut self) {
+        if self.current() != EOF {
+            self.trivia_list.push(Trivia::new(
+                TriviaPieceKind::Skipped,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia_list, self.lexer.finish())
+    }
+}
+
+impl<'source> TokenSourceWithBufferedLexer<TomlLexer<'source>> for TomlTokenSource<'source> {
+    fn lexer(&mut self) -> &mut BufferedLexer<TomlSyntaxKind, TomlLexer<'source>> {
+        &mut self.lexer
+    }
+}
@@ -0,0 +1,13 @@
+#![allow(non_snake_case)]
+
+mod spec_test;
+
+mod ok {
+    //! Tests that must pass according to the TOML specification
+    tests_macros::gen_tests! {"tests/toml_test_suite/ok/*.toml", crate::spec_test::run, "ok"}
+}
+
+mod err {
+    //! Tests that must fail according to the TOML specification
+    tests_macros::gen_tests! {"tests/toml_test_suite/err/*.toml", crate::spec_test::run, "error"}
+}
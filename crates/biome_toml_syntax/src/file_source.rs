@@ -0,0 +1,94 @@
+use biome_rowan::FileSourceError;
+use biome_string_case::StrLikeExtension;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct TomlFileSource {
+    // ?? Options
+}
+
+impl TomlFileSource {
+    // Well-known TOML files without the `.toml` extension
+    // This list should be SORTED!
+    // Source: https://github.com/github-linguist/linguist/blob/main/lib/linguist/languages.yml
+    // Note: we shouldn't include machine generated files, such as `Cargo.lock` or `poetry.lock`
+    const WELL_KNOWN_TOML_FILES: &'static [&'static str] = &["Pipfile"];
+
+    pub fn toml() -> Self {
+        Self {
+            // ?? Options
+        }
+    }
+
+    pub fn is_well_known_toml_file(file_name: &str) -> bool {
+        Self::WELL_KNOWN_TOML_FILES
+            .binary_search(&file_name)
+            .is_ok()
+    }
+
+    /// Try to return the TOML file source corresponding to this file name from well-known files
+    pub fn try_from_well_known(path: &Path) -> Result<Self, FileSourceError> {
+        let file_name = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or(FileSourceError::MissingFileName)?;
+        if Self::is_well_known_toml_file(file_name) {
+            return Ok(Self::toml());
+        }
+        Err(FileSourceError::UnknownFileName)
+    }
+
+    /// Try to return the TOML file source corresponding to this file extension
+    pub fn try_from_extension(extension: &OsStr) -> Result<Self, FileSourceError> {
+        // We assume the file extension is normalized to lowercase
+        match extension.as_encoded_bytes() {
+            // https://toml.io/en/v1.0.0#filename-extension
+            b"toml" => Ok(Self::toml()),
+            _ => Err(FileSourceError::UnknownExtension),
+        }
+    }
+
+    /// Try to return the TOML file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// The language ID `toml` is registered by the most used TOML extensions of VS Code,
+    /// such as [Even Better TOML]
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    /// [Even Better TOML]: https://marketplace.visualstudio.com/items?itemName=tamasfe.even-better-toml
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "toml" => Ok(Self::toml()),
+            _ => Err(FileSourceError::UnknownLanguageId),
+        }
+    }
+}
+
+impl TryFrom<&Path> for TomlFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        if let Ok(file_source) = Self::try_from_well_known(path) {
+            return Ok(file_source);
+        }
+
+        let Some(extension) = path.extension() else {
+            return Err(FileSourceError::MissingFileExtension);
+        };
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        Self::try_from_extension(&extension.to_ascii_lowercase_cow())
+    }
+}
+
+#[test]
+fn test_order() {
+    for items in TomlFileSource::WELL_KNOWN_TOML_FILES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+}
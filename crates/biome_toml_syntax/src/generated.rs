@@ -0,0 +1,11 @@
+#[rustfmt::skip]
+pub(super) mod nodes;
+#[rustfmt::skip]
+pub(super) mod nodes_mut;
+#[rustfmt::skip]
+pub mod macros;
+#[macro_use]
+pub mod kind;
+
+pub use kind::*;
+pub use nodes::*;
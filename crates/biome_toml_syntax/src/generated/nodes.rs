@@ -0,0 +1,2283 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::match_like_matches_macro)]
+use crate::{
+    macros::map_syntax_node,
+    TomlLanguage as Language, TomlSyntaxElement as SyntaxElement,
+    TomlSyntaxElementChildren as SyntaxElementChildren,
+    TomlSyntaxKind::{self as SyntaxKind, *},
+    TomlSyntaxList as SyntaxList, TomlSyntaxNode as SyntaxNode, TomlSyntaxToken as SyntaxToken,
+};
+use biome_rowan::{support, AstNode, RawSyntaxKind, SyntaxKindSet, SyntaxResult};
+#[allow(unused)]
+use biome_rowan::{
+    AstNodeList, AstNodeListIterator, AstNodeSlotMap, AstSeparatedList,
+    AstSeparatedListNodesIterator,
+};
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+#[doc = r" Sentinel value indicating a missing element in a dynamic node, where"]
+#[doc = r" the slots are not statically known."]
+#[allow(dead_code)]
+pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlArray {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlArray {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlArrayFields {
+        TomlArrayFields {
+            l_brack_token: self.l_brack_token(),
+            elements: self.elements(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn elements(&self) -> TomlArrayElementList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlArrayFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub elements: TomlArrayElementList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlArrayOfTables {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlArrayOfTables {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlArrayOfTablesFields {
+        TomlArrayOfTablesFields {
+            l_double_brack_token: self.l_double_brack_token(),
+            key: self.key(),
+            r_double_brack_token: self.r_double_brack_token(),
+            entries: self.entries(),
+        }
+    }
+    pub fn l_double_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_double_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn entries(&self) -> TomlKeyValueList {
+        support::list(&self.syntax, 3usize)
+    }
+}
+impl Serialize for TomlArrayOfTables {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlArrayOfTablesFields {
+    pub l_double_brack_token: SyntaxResult<SyntaxToken>,
+    pub key: SyntaxResult<TomlKey>,
+    pub r_double_brack_token: SyntaxResult<SyntaxToken>,
+    pub entries: TomlKeyValueList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlBareKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlBareKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlBareKeyFields {
+        TomlBareKeyFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlBareKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlBareKeyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlBooleanValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlBooleanValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlBooleanValueFields {
+        TomlBooleanValueFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlBooleanValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlBooleanValueFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlDateTimeValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlDateTimeValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlDateTimeValueFields {
+        TomlDateTimeValueFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlDateTimeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlDateTimeValueFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlFloatValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlFloatValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlFloatValueFields {
+        TomlFloatValueFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlFloatValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlFloatValueFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlInlineTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlInlineTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlInlineTableFields {
+        TomlInlineTableFields {
+            l_curly_token: self.l_curly_token(),
+            entries: self.entries(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn entries(&self) -> TomlInlineTableEntryList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlInlineTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlInlineTableFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub entries: TomlInlineTableEntryList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlIntegerValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlIntegerValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlIntegerValueFields {
+        TomlIntegerValueFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlIntegerValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlIntegerValueFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlKeyFields {
+        TomlKeyFields {
+            parts: self.parts(),
+        }
+    }
+    pub fn parts(&self) -> TomlKeyPartList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlKeyFields {
+    pub parts: TomlKeyPartList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlKeyValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlKeyValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlKeyValueFields {
+        TomlKeyValueFields {
+            key: self.key(),
+            eq_token: self.eq_token(),
+            value: self.value(),
+        }
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> SyntaxResult<AnyTomlValue> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlKeyValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlKeyValueFields {
+    pub key: SyntaxResult<TomlKey>,
+    pub eq_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyTomlValue>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlQuotedKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlQuotedKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlQuotedKeyFields {
+        TomlQuotedKeyFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlQuotedKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlQuotedKeyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlRoot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlRoot {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlRootFields {
+        TomlRootFields {
+            bom_token: self.bom_token(),
+            items: self.items(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn bom_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn items(&self) -> TomlRootItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for TomlRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlRootFields {
+    pub bom_token: Option<SyntaxToken>,
+    pub items: TomlRootItemList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlStringValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlStringValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlStringValueFields {
+        TomlStringValueFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for TomlStringValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlStringValueFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TomlTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TomlTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TomlTableFields {
+        TomlTableFields {
+            l_brack_token: self.l_brack_token(),
+            key: self.key(),
+            r_brack_token: self.r_brack_token(),
+            entries: self.entries(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn key(&self) -> SyntaxResult<TomlKey> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn entries(&self) -> TomlKeyValueList {
+        support::list(&self.syntax, 3usize)
+    }
+}
+impl Serialize for TomlTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct TomlTableFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub key: SyntaxResult<TomlKey>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub entries: TomlKeyValueList,
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlKeyPart {
+    TomlBareKey(TomlBareKey),
+    TomlQuotedKey(TomlQuotedKey),
+}
+impl AnyTomlKeyPart {
+    pub fn as_toml_bare_key(&self) -> Option<&TomlBareKey> {
+        match &self {
+            AnyTomlKeyPart::TomlBareKey(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_quoted_key(&self) -> Option<&TomlQuotedKey> {
+        match &self {
+            AnyTomlKeyPart::TomlQuotedKey(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlKeyValue {
+    TomlBogus(TomlBogus),
+    TomlKeyValue(TomlKeyValue),
+}
+impl AnyTomlKeyValue {
+    pub fn as_toml_bogus(&self) -> Option<&TomlBogus> {
+        match &self {
+            AnyTomlKeyValue::TomlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_key_value(&self) -> Option<&TomlKeyValue> {
+        match &self {
+            AnyTomlKeyValue::TomlKeyValue(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlRootItem {
+    TomlArrayOfTables(TomlArrayOfTables),
+    TomlBogus(TomlBogus),
+    TomlKeyValue(TomlKeyValue),
+    TomlTable(TomlTable),
+}
+impl AnyTomlRootItem {
+    pub fn as_toml_array_of_tables(&self) -> Option<&TomlArrayOfTables> {
+        match &self {
+            AnyTomlRootItem::TomlArrayOfTables(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_bogus(&self) -> Option<&TomlBogus> {
+        match &self {
+            AnyTomlRootItem::TomlBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_key_value(&self) -> Option<&TomlKeyValue> {
+        match &self {
+            AnyTomlRootItem::TomlKeyValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_table(&self) -> Option<&TomlTable> {
+        match &self {
+            AnyTomlRootItem::TomlTable(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyTomlValue {
+    TomlArray(TomlArray),
+    TomlBogusValue(TomlBogusValue),
+    TomlBooleanValue(TomlBooleanValue),
+    TomlDateTimeValue(TomlDateTimeValue),
+    TomlFloatValue(TomlFloatValue),
+    TomlInlineTable(TomlInlineTable),
+    TomlIntegerValue(TomlIntegerValue),
+    TomlStringValue(TomlStringValue),
+}
+impl AnyTomlValue {
+    pub fn as_toml_array(&self) -> Option<&TomlArray> {
+        match &self {
+            AnyTomlValue::TomlArray(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_bogus_value(&self) -> Option<&TomlBogusValue> {
+        match &self {
+            AnyTomlValue::TomlBogusValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_boolean_value(&self) -> Option<&TomlBooleanValue> {
+        match &self {
+            AnyTomlValue::TomlBooleanValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_date_time_value(&self) -> Option<&TomlDateTimeValue> {
+        match &self {
+            AnyTomlValue::TomlDateTimeValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_float_value(&self) -> Option<&TomlFloatValue> {
+        match &self {
+            AnyTomlValue::TomlFloatValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_inline_table(&self) -> Option<&TomlInlineTable> {
+        match &self {
+            AnyTomlValue::TomlInlineTable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_integer_value(&self) -> Option<&TomlIntegerValue> {
+        match &self {
+            AnyTomlValue::TomlIntegerValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_toml_string_value(&self) -> Option<&TomlStringValue> {
+        match &self {
+            AnyTomlValue::TomlStringValue(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for TomlArray {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlArray")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("elements", &self.elements())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlArray> for SyntaxNode {
+    fn from(n: TomlArray) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlArray> for SyntaxElement {
+    fn from(n: TomlArray) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlArrayOfTables {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY_OF_TABLES as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY_OF_TABLES
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlArrayOfTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlArrayOfTables")
+            .field(
+                "l_double_brack_token",
+                &support::DebugSyntaxResult(self.l_double_brack_token()),
+            )
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "r_double_brack_token",
+                &support::DebugSyntaxResult(self.r_double_brack_token()),
+            )
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+impl From<TomlArrayOfTables> for SyntaxNode {
+    fn from(n: TomlArrayOfTables) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlArrayOfTables> for SyntaxElement {
+    fn from(n: TomlArrayOfTables) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlBareKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BARE_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BARE_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBareKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBareKey")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlBareKey> for SyntaxNode {
+    fn from(n: TomlBareKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBareKey> for SyntaxElement {
+    fn from(n: TomlBareKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlBooleanValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOOLEAN_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOOLEAN_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBooleanValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBooleanValue")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlBooleanValue> for SyntaxNode {
+    fn from(n: TomlBooleanValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBooleanValue> for SyntaxElement {
+    fn from(n: TomlBooleanValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlDateTimeValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_DATE_TIME_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_DATE_TIME_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlDateTimeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlDateTimeValue")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlDateTimeValue> for SyntaxNode {
+    fn from(n: TomlDateTimeValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlDateTimeValue> for SyntaxElement {
+    fn from(n: TomlDateTimeValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlFloatValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_FLOAT_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_FLOAT_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlFloatValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlFloatValue")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlFloatValue> for SyntaxNode {
+    fn from(n: TomlFloatValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlFloatValue> for SyntaxElement {
+    fn from(n: TomlFloatValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlInlineTable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_INLINE_TABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_INLINE_TABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlInlineTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlInlineTable")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("entries", &self.entries())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlInlineTable> for SyntaxNode {
+    fn from(n: TomlInlineTable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlInlineTable> for SyntaxElement {
+    fn from(n: TomlInlineTable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlIntegerValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_INTEGER_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_INTEGER_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlIntegerValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlIntegerValue")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlIntegerValue> for SyntaxNode {
+    fn from(n: TomlIntegerValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlIntegerValue> for SyntaxElement {
+    fn from(n: TomlIntegerValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlKey")
+            .field("parts", &self.parts())
+            .finish()
+    }
+}
+impl From<TomlKey> for SyntaxNode {
+    fn from(n: TomlKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlKey> for SyntaxElement {
+    fn from(n: TomlKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlKeyValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlKeyValue")
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<TomlKeyValue> for SyntaxNode {
+    fn from(n: TomlKeyValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlKeyValue> for SyntaxElement {
+    fn from(n: TomlKeyValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlQuotedKey {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_QUOTED_KEY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_QUOTED_KEY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlQuotedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlQuotedKey")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlQuotedKey> for SyntaxNode {
+    fn from(n: TomlQuotedKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlQuotedKey> for SyntaxElement {
+    fn from(n: TomlQuotedKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ROOT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlRoot")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field("items", &self.items())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<TomlRoot> for SyntaxNode {
+    fn from(n: TomlRoot) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlRoot> for SyntaxElement {
+    fn from(n: TomlRoot) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlStringValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_STRING_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_STRING_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlStringValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlStringValue")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<TomlStringValue> for SyntaxNode {
+    fn from(n: TomlStringValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlStringValue> for SyntaxElement {
+    fn from(n: TomlStringValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for TomlTable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_TABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_TABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlTable")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+impl From<TomlTable> for SyntaxNode {
+    fn from(n: TomlTable) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlTable> for SyntaxElement {
+    fn from(n: TomlTable) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl From<TomlBareKey> for AnyTomlKeyPart {
+    fn from(node: TomlBareKey) -> AnyTomlKeyPart {
+        AnyTomlKeyPart::TomlBareKey(node)
+    }
+}
+impl From<TomlQuotedKey> for AnyTomlKeyPart {
+    fn from(node: TomlQuotedKey) -> AnyTomlKeyPart {
+        AnyTomlKeyPart::TomlQuotedKey(node)
+    }
+}
+impl AstNode for AnyTomlKeyPart {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlBareKey::KIND_SET.union(TomlQuotedKey::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOML_BARE_KEY | TOML_QUOTED_KEY)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_BARE_KEY => AnyTomlKeyPart::TomlBareKey(TomlBareKey { syntax }),
+            TOML_QUOTED_KEY => AnyTomlKeyPart::TomlQuotedKey(TomlQuotedKey { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => &it.syntax,
+            AnyTomlKeyPart::TomlQuotedKey(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => it.syntax,
+            AnyTomlKeyPart::TomlQuotedKey(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlKeyPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlKeyPart::TomlBareKey(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlKeyPart::TomlQuotedKey(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlKeyPart> for SyntaxNode {
+    fn from(n: AnyTomlKeyPart) -> SyntaxNode {
+        match n {
+            AnyTomlKeyPart::TomlBareKey(it) => it.into(),
+            AnyTomlKeyPart::TomlQuotedKey(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlKeyPart> for SyntaxElement {
+    fn from(n: AnyTomlKeyPart) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<TomlBogus> for AnyTomlKeyValue {
+    fn from(node: TomlBogus) -> AnyTomlKeyValue {
+        AnyTomlKeyValue::TomlBogus(node)
+    }
+}
+impl From<TomlKeyValue> for AnyTomlKeyValue {
+    fn from(node: TomlKeyValue) -> AnyTomlKeyValue {
+        AnyTomlKeyValue::TomlKeyValue(node)
+    }
+}
+impl AstNode for AnyTomlKeyValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlBogus::KIND_SET.union(TomlKeyValue::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOML_BOGUS | TOML_KEY_VALUE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_BOGUS => AnyTomlKeyValue::TomlBogus(TomlBogus { syntax }),
+            TOML_KEY_VALUE => AnyTomlKeyValue::TomlKeyValue(TomlKeyValue { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlKeyValue::TomlBogus(it) => &it.syntax,
+            AnyTomlKeyValue::TomlKeyValue(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlKeyValue::TomlBogus(it) => it.syntax,
+            AnyTomlKeyValue::TomlKeyValue(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlKeyValue::TomlBogus(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlKeyValue::TomlKeyValue(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlKeyValue> for SyntaxNode {
+    fn from(n: AnyTomlKeyValue) -> SyntaxNode {
+        match n {
+            AnyTomlKeyValue::TomlBogus(it) => it.into(),
+            AnyTomlKeyValue::TomlKeyValue(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlKeyValue> for SyntaxElement {
+    fn from(n: AnyTomlKeyValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<TomlArrayOfTables> for AnyTomlRootItem {
+    fn from(node: TomlArrayOfTables) -> AnyTomlRootItem {
+        AnyTomlRootItem::TomlArrayOfTables(node)
+    }
+}
+impl From<TomlBogus> for AnyTomlRootItem {
+    fn from(node: TomlBogus) -> AnyTomlRootItem {
+        AnyTomlRootItem::TomlBogus(node)
+    }
+}
+impl From<TomlKeyValue> for AnyTomlRootItem {
+    fn from(node: TomlKeyValue) -> AnyTomlRootItem {
+        AnyTomlRootItem::TomlKeyValue(node)
+    }
+}
+impl From<TomlTable> for AnyTomlRootItem {
+    fn from(node: TomlTable) -> AnyTomlRootItem {
+        AnyTomlRootItem::TomlTable(node)
+    }
+}
+impl AstNode for AnyTomlRootItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlArrayOfTables::KIND_SET
+        .union(TomlBogus::KIND_SET)
+        .union(TomlKeyValue::KIND_SET)
+        .union(TomlTable::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            TOML_ARRAY_OF_TABLES | TOML_BOGUS | TOML_KEY_VALUE | TOML_TABLE
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_ARRAY_OF_TABLES => {
+                AnyTomlRootItem::TomlArrayOfTables(TomlArrayOfTables { syntax })
+            }
+            TOML_BOGUS => AnyTomlRootItem::TomlBogus(TomlBogus { syntax }),
+            TOML_KEY_VALUE => AnyTomlRootItem::TomlKeyValue(TomlKeyValue { syntax }),
+            TOML_TABLE => AnyTomlRootItem::TomlTable(TomlTable { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlRootItem::TomlArrayOfTables(it) => &it.syntax,
+            AnyTomlRootItem::TomlBogus(it) => &it.syntax,
+            AnyTomlRootItem::TomlKeyValue(it) => &it.syntax,
+            AnyTomlRootItem::TomlTable(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlRootItem::TomlArrayOfTables(it) => it.syntax,
+            AnyTomlRootItem::TomlBogus(it) => it.syntax,
+            AnyTomlRootItem::TomlKeyValue(it) => it.syntax,
+            AnyTomlRootItem::TomlTable(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlRootItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlRootItem::TomlArrayOfTables(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlRootItem::TomlBogus(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlRootItem::TomlKeyValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlRootItem::TomlTable(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlRootItem> for SyntaxNode {
+    fn from(n: AnyTomlRootItem) -> SyntaxNode {
+        match n {
+            AnyTomlRootItem::TomlArrayOfTables(it) => it.into(),
+            AnyTomlRootItem::TomlBogus(it) => it.into(),
+            AnyTomlRootItem::TomlKeyValue(it) => it.into(),
+            AnyTomlRootItem::TomlTable(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlRootItem> for SyntaxElement {
+    fn from(n: AnyTomlRootItem) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<TomlArray> for AnyTomlValue {
+    fn from(node: TomlArray) -> AnyTomlValue {
+        AnyTomlValue::TomlArray(node)
+    }
+}
+impl From<TomlBogusValue> for AnyTomlValue {
+    fn from(node: TomlBogusValue) -> AnyTomlValue {
+        AnyTomlValue::TomlBogusValue(node)
+    }
+}
+impl From<TomlBooleanValue> for AnyTomlValue {
+    fn from(node: TomlBooleanValue) -> AnyTomlValue {
+        AnyTomlValue::TomlBooleanValue(node)
+    }
+}
+impl From<TomlDateTimeValue> for AnyTomlValue {
+    fn from(node: TomlDateTimeValue) -> AnyTomlValue {
+        AnyTomlValue::TomlDateTimeValue(node)
+    }
+}
+impl From<TomlFloatValue> for AnyTomlValue {
+    fn from(node: TomlFloatValue) -> AnyTomlValue {
+        AnyTomlValue::TomlFloatValue(node)
+    }
+}
+impl From<TomlInlineTable> for AnyTomlValue {
+    fn from(node: TomlInlineTable) -> AnyTomlValue {
+        AnyTomlValue::TomlInlineTable(node)
+    }
+}
+impl From<TomlIntegerValue> for AnyTomlValue {
+    fn from(node: TomlIntegerValue) -> AnyTomlValue {
+        AnyTomlValue::TomlIntegerValue(node)
+    }
+}
+impl From<TomlStringValue> for AnyTomlValue {
+    fn from(node: TomlStringValue) -> AnyTomlValue {
+        AnyTomlValue::TomlStringValue(node)
+    }
+}
+impl AstNode for AnyTomlValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = TomlArray::KIND_SET
+        .union(TomlBogusValue::KIND_SET)
+        .union(TomlBooleanValue::KIND_SET)
+        .union(TomlDateTimeValue::KIND_SET)
+        .union(TomlFloatValue::KIND_SET)
+        .union(TomlInlineTable::KIND_SET)
+        .union(TomlIntegerValue::KIND_SET)
+        .union(TomlStringValue::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            TOML_ARRAY
+                | TOML_BOGUS_VALUE
+                | TOML_BOOLEAN_VALUE
+                | TOML_DATE_TIME_VALUE
+                | TOML_FLOAT_VALUE
+                | TOML_INLINE_TABLE
+                | TOML_INTEGER_VALUE
+                | TOML_STRING_VALUE
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            TOML_ARRAY => AnyTomlValue::TomlArray(TomlArray { syntax }),
+            TOML_BOGUS_VALUE => AnyTomlValue::TomlBogusValue(TomlBogusValue { syntax }),
+            TOML_BOOLEAN_VALUE => AnyTomlValue::TomlBooleanValue(TomlBooleanValue { syntax }),
+            TOML_DATE_TIME_VALUE => AnyTomlValue::TomlDateTimeValue(TomlDateTimeValue { syntax }),
+            TOML_FLOAT_VALUE => AnyTomlValue::TomlFloatValue(TomlFloatValue { syntax }),
+            TOML_INLINE_TABLE => AnyTomlValue::TomlInlineTable(TomlInlineTable { syntax }),
+            TOML_INTEGER_VALUE => AnyTomlValue::TomlIntegerValue(TomlIntegerValue { syntax }),
+            TOML_STRING_VALUE => AnyTomlValue::TomlStringValue(TomlStringValue { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyTomlValue::TomlArray(it) => &it.syntax,
+            AnyTomlValue::TomlBogusValue(it) => &it.syntax,
+            AnyTomlValue::TomlBooleanValue(it) => &it.syntax,
+            AnyTomlValue::TomlDateTimeValue(it) => &it.syntax,
+            AnyTomlValue::TomlFloatValue(it) => &it.syntax,
+            AnyTomlValue::TomlInlineTable(it) => &it.syntax,
+            AnyTomlValue::TomlIntegerValue(it) => &it.syntax,
+            AnyTomlValue::TomlStringValue(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyTomlValue::TomlArray(it) => it.syntax,
+            AnyTomlValue::TomlBogusValue(it) => it.syntax,
+            AnyTomlValue::TomlBooleanValue(it) => it.syntax,
+            AnyTomlValue::TomlDateTimeValue(it) => it.syntax,
+            AnyTomlValue::TomlFloatValue(it) => it.syntax,
+            AnyTomlValue::TomlInlineTable(it) => it.syntax,
+            AnyTomlValue::TomlIntegerValue(it) => it.syntax,
+            AnyTomlValue::TomlStringValue(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyTomlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyTomlValue::TomlArray(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlBogusValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlBooleanValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlDateTimeValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlFloatValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlInlineTable(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlIntegerValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyTomlValue::TomlStringValue(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyTomlValue> for SyntaxNode {
+    fn from(n: AnyTomlValue) -> SyntaxNode {
+        match n {
+            AnyTomlValue::TomlArray(it) => it.into(),
+            AnyTomlValue::TomlBogusValue(it) => it.into(),
+            AnyTomlValue::TomlBooleanValue(it) => it.into(),
+            AnyTomlValue::TomlDateTimeValue(it) => it.into(),
+            AnyTomlValue::TomlFloatValue(it) => it.into(),
+            AnyTomlValue::TomlInlineTable(it) => it.into(),
+            AnyTomlValue::TomlIntegerValue(it) => it.into(),
+            AnyTomlValue::TomlStringValue(it) => it.into(),
+        }
+    }
+}
+impl From<AnyTomlValue> for SyntaxElement {
+    fn from(n: AnyTomlValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyTomlKeyPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyTomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyTomlRootItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyTomlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlArrayOfTables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlBareKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlBooleanValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlDateTimeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlFloatValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlInlineTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlIntegerValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlKeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlQuotedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlStringValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TomlTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct TomlBogus {
+    syntax: SyntaxNode,
+}
+impl TomlBogus {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for TomlBogus {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOGUS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOGUS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBogus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBogus")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<TomlBogus> for SyntaxNode {
+    fn from(n: TomlBogus) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBogus> for SyntaxElement {
+    fn from(n: TomlBogus) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct TomlBogusValue {
+    syntax: SyntaxNode,
+}
+impl TomlBogusValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for TomlBogusValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_BOGUS_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_BOGUS_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for TomlBogusValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TomlBogusValue")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<TomlBogusValue> for SyntaxNode {
+    fn from(n: TomlBogusValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<TomlBogusValue> for SyntaxElement {
+    fn from(n: TomlBogusValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlArrayElementList {
+    syntax_list: SyntaxList,
+}
+impl TomlArrayElementList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlArrayElementList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ARRAY_ELEMENT_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ARRAY_ELEMENT_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlArrayElementList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlArrayElementList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlArrayElementList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlArrayElementList {
+    type Language = Language;
+    type Node = AnyTomlValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlArrayElementList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlArrayElementList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlArrayElementList {
+    type Item = SyntaxResult<AnyTomlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlArrayElementList {
+    type Item = SyntaxResult<AnyTomlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlInlineTableEntryList {
+    syntax_list: SyntaxList,
+}
+impl TomlInlineTableEntryList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlInlineTableEntryList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_INLINE_TABLE_ENTRY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_INLINE_TABLE_ENTRY_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlInlineTableEntryList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlInlineTableEntryList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlInlineTableEntryList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlInlineTableEntryList {
+    type Language = Language;
+    type Node = TomlKeyValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlInlineTableEntryList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlInlineTableEntryList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlInlineTableEntryList {
+    type Item = SyntaxResult<TomlKeyValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, TomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlInlineTableEntryList {
+    type Item = SyntaxResult<TomlKeyValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, TomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlKeyPartList {
+    syntax_list: SyntaxList,
+}
+impl TomlKeyPartList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlKeyPartList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY_PART_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY_PART_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlKeyPartList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlKeyPartList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlKeyPartList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for TomlKeyPartList {
+    type Language = Language;
+    type Node = AnyTomlKeyPart;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlKeyPartList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlKeyPartList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for TomlKeyPartList {
+    type Item = SyntaxResult<AnyTomlKeyPart>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlKeyPart>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &TomlKeyPartList {
+    type Item = SyntaxResult<AnyTomlKeyPart>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyTomlKeyPart>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlKeyValueList {
+    syntax_list: SyntaxList,
+}
+impl TomlKeyValueList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlKeyValueList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_KEY_VALUE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_KEY_VALUE_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlKeyValueList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlKeyValueList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlKeyValueList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for TomlKeyValueList {
+    type Language = Language;
+    type Node = AnyTomlKeyValue;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlKeyValueList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlKeyValueList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &TomlKeyValueList {
+    type Item = AnyTomlKeyValue;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for TomlKeyValueList {
+    type Item = AnyTomlKeyValue;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlKeyValue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TomlRootItemList {
+    syntax_list: SyntaxList,
+}
+impl TomlRootItemList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TomlRootItemList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TOML_ROOT_ITEM_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TOML_ROOT_ITEM_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TomlRootItemList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TomlRootItemList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for TomlRootItemList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for TomlRootItemList {
+    type Language = Language;
+    type Node = AnyTomlRootItem;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TomlRootItemList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TomlRootItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &TomlRootItemList {
+    type Item = AnyTomlRootItem;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlRootItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for TomlRootItemList {
+    type Item = AnyTomlRootItem;
+    type IntoIter = AstNodeListIterator<Language, AnyTomlRootItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone)]
+pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
+impl Debug for DebugSyntaxElementChildren {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.clone().0.map(DebugSyntaxElement))
+            .finish()
+    }
+}
+struct DebugSyntaxElement(SyntaxElement);
+impl Debug for DebugSyntaxElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            SyntaxElement::Node(node) => {
+                map_syntax_node ! (node . clone () , node => std :: fmt :: Debug :: fmt (& node , f))
+            }
+            SyntaxElement::Token(token) => Debug::fmt(token, f),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+
+pub mod no_duplicate_keys;
+
+declare_lint_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_duplicate_keys :: NoDuplicateKeys ,
+        ]
+     }
+}
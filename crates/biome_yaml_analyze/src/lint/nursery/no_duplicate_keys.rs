@@ -0,0 +1,129 @@
+use biome_analyze::{context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, SyntaxResult, TextRange,
+};
+use biome_yaml_syntax::{YamlIdentifier, YamlObject, YamlObjectInline, YamlObjectMember};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow duplicate keys in YAML mappings.
+    ///
+    /// The keys of a mapping must be unique. Most YAML parsers silently keep the value of the
+    /// last key and drop the previous ones, while others reject the whole file.
+    ///
+    /// A quoted key and a plain key with the same content are the same key.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// name: build
+    /// name: test
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// { image: node, "image": rust }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// build:
+    ///   name: build
+    /// test:
+    ///   name: test
+    /// ```
+    pub NoDuplicateKeys {
+        version: "next",
+        name: "noDuplicateKeys",
+        language: "yaml",
+        recommended: true,
+    }
+}
+
+declare_node_union! {
+    pub AnyYamlMapping = YamlObject | YamlObjectInline
+}
+
+impl AnyYamlMapping {
+    fn members(&self) -> Vec<YamlObjectMember> {
+        match self {
+            Self::YamlObject(object) => object.members().iter().collect(),
+            Self::YamlObjectInline(object) => object.members().iter().flatten().collect(),
+        }
+    }
+}
+
+pub struct DuplicateKey {
+    /// The key that declares the same name again.
+    key: YamlIdentifier,
+    /// The range of the first declaration of the key.
+    first_declaration: TextRange,
+}
+
+impl Rule for NoDuplicateKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = DuplicateKey;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut first_declarations = FxHashMap::<String, TextRange>::default();
+        let mut duplicates = Vec::new();
+        for member in ctx.query().members() {
+            let Ok(key) = member.key() else {
+                continue;
+            };
+            let Ok(name) = key_name(&key) else {
+                continue;
+            };
+            if let Some(first_declaration) = first_declarations.get(&name) {
+                duplicates.push(DuplicateKey {
+                    first_declaration: *first_declaration,
+                    key,
+                });
+            } else {
+                first_declarations.insert(name, key.range());
+            }
+        }
+        duplicates.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = key_name(&state.key).ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.key.range(),
+                markup! {
+                    "The key "<Emphasis>{name}</Emphasis>" is declared more than once."
+                },
+            )
+            .detail(
+                state.first_declaration,
+                markup! {
+                    "The key is first declared here."
+                },
+            )
+            .note(markup! {
+                "Most YAML parsers only keep the value of the last declaration, and some reject the file."
+            }),
+        )
+    }
+}
+
+/// Returns the name of `key`, without the quotes of a quoted key.
+fn key_name(key: &YamlIdentifier) -> SyntaxResult<String> {
+    let token = key.value_token()?;
+    let text = token.text_trimmed();
+    let name = ['"', '\'']
+        .into_iter()
+        .find_map(|quote| {
+            text.strip_prefix(quote)
+                .and_then(|text| text.strip_suffix(quote))
+        })
+        .unwrap_or(text);
+    Ok(name.to_string())
+}
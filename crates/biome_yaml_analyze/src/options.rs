@@ -0,0 +1,6 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoDuplicateKeys =
+    <lint::nursery::no_duplicate_keys::NoDuplicateKeys as biome_analyze::Rule>::Options;
@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::RegistryVisitor;
+use biome_yaml_syntax::YamlLanguage;
+pub fn visit_registry<V: RegistryVisitor<YamlLanguage>>(registry: &mut V) {
+    registry.record_category::<crate::lint::Lint>();
+}
@@ -27,40 +27,88 @@ pub fn yaml_array_inline(
         ],
     ))
 }
-pub fn yaml_array_item(minus_token: SyntaxToken, item: AnyYamlValue) -> YamlArrayItem {
-    YamlArrayItem::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_ARRAY_ITEM,
-        [
-            Some(SyntaxElement::Token(minus_token)),
-            Some(SyntaxElement::Node(item.into_syntax())),
-        ],
-    ))
+pub fn yaml_array_item(minus_token: SyntaxToken) -> YamlArrayItemBuilder {
+    YamlArrayItemBuilder {
+        minus_token,
+        item: None,
+    }
 }
-pub fn yaml_block_folded(r_angle_token: SyntaxToken, value: YamlBlockValue) -> YamlBlockFolded {
-    YamlBlockFolded::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_BLOCK_FOLDED,
-        [
-            Some(SyntaxElement::Token(r_angle_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
-        ],
-    ))
+pub struct YamlArrayItemBuilder {
+    minus_token: SyntaxToken,
+    item: Option<AnyYamlValue>,
+}
+impl YamlArrayItemBuilder {
+    pub fn with_item(mut self, item: AnyYamlValue) -> Self {
+        self.item = Some(item);
+        self
+    }
+    pub fn build(self) -> YamlArrayItem {
+        YamlArrayItem::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_ARRAY_ITEM,
+            [
+                Some(SyntaxElement::Token(self.minus_token)),
+                self.item
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_block_folded(r_angle_token: SyntaxToken) -> YamlBlockFoldedBuilder {
+    YamlBlockFoldedBuilder {
+        r_angle_token,
+        content: None,
+    }
+}
+pub struct YamlBlockFoldedBuilder {
+    r_angle_token: SyntaxToken,
+    content: Option<YamlBlockValue>,
+}
+impl YamlBlockFoldedBuilder {
+    pub fn with_content(mut self, content: YamlBlockValue) -> Self {
+        self.content = Some(content);
+        self
+    }
+    pub fn build(self) -> YamlBlockFolded {
+        YamlBlockFolded::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_FOLDED,
+            [
+                Some(SyntaxElement::Token(self.r_angle_token)),
+                self.content
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
 }
-pub fn yaml_block_literal(
+pub fn yaml_block_literal(bitwise_or_token: SyntaxToken) -> YamlBlockLiteralBuilder {
+    YamlBlockLiteralBuilder {
+        bitwise_or_token,
+        content: None,
+    }
+}
+pub struct YamlBlockLiteralBuilder {
     bitwise_or_token: SyntaxToken,
-    value: YamlBlockValue,
-) -> YamlBlockLiteral {
-    YamlBlockLiteral::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_BLOCK_LITERAL,
-        [
-            Some(SyntaxElement::Token(bitwise_or_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
-        ],
-    ))
+    content: Option<YamlBlockValue>,
 }
-pub fn yaml_block_value(yaml_block_value_token: SyntaxToken) -> YamlBlockValue {
+impl YamlBlockLiteralBuilder {
+    pub fn with_content(mut self, content: YamlBlockValue) -> Self {
+        self.content = Some(content);
+        self
+    }
+    pub fn build(self) -> YamlBlockLiteral {
+        YamlBlockLiteral::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_LITERAL,
+            [
+                Some(SyntaxElement::Token(self.bitwise_or_token)),
+                self.content
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_block_value(value_token: SyntaxToken) -> YamlBlockValue {
     YamlBlockValue::unwrap_cast(SyntaxNode::new_detached(
         YamlSyntaxKind::YAML_BLOCK_VALUE,
-        [Some(SyntaxElement::Token(yaml_block_value_token))],
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
 pub fn yaml_boolean_value(value_token: SyntaxToken) -> YamlBooleanValue {
@@ -69,16 +117,16 @@ pub fn yaml_boolean_value(value_token: SyntaxToken) -> YamlBooleanValue {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn yaml_document(body: AnyYamlValue) -> YamlDocumentBuilder {
+pub fn yaml_document() -> YamlDocumentBuilder {
     YamlDocumentBuilder {
-        body,
         dashdashdash_token: None,
+        body: None,
         dotdotdot_token: None,
     }
 }
 pub struct YamlDocumentBuilder {
-    body: AnyYamlValue,
     dashdashdash_token: Option<SyntaxToken>,
+    body: Option<AnyYamlValue>,
     dotdotdot_token: Option<SyntaxToken>,
 }
 impl YamlDocumentBuilder {
@@ -86,6 +134,10 @@ impl YamlDocumentBuilder {
         self.dashdashdash_token = Some(dashdashdash_token);
         self
     }
+    pub fn with_body(mut self, body: AnyYamlValue) -> Self {
+        self.body = Some(body);
+        self
+    }
     pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
         self.dotdotdot_token = Some(dotdotdot_token);
         self
@@ -96,7 +148,8 @@ impl YamlDocumentBuilder {
             [
                 self.dashdashdash_token
                     .map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.body.into_syntax())),
+                self.body
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
                 self.dotdotdot_token
                     .map(|token| SyntaxElement::Token(token)),
             ],
@@ -127,20 +180,52 @@ pub fn yaml_object(members: YamlObjectMemberList) -> YamlObject {
         [Some(SyntaxElement::Node(members.into_syntax()))],
     ))
 }
-pub fn yaml_object_member(
-    key: YamlIdentifier,
-    colon_token: SyntaxToken,
-    value: AnyYamlValue,
-) -> YamlObjectMember {
-    YamlObjectMember::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_OBJECT_MEMBER,
+pub fn yaml_object_inline(
+    l_curly_token: SyntaxToken,
+    members: YamlObjectInlineMemberList,
+    r_curly_token: SyntaxToken,
+) -> YamlObjectInline {
+    YamlObjectInline::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_OBJECT_INLINE,
         [
-            Some(SyntaxElement::Node(key.into_syntax())),
-            Some(SyntaxElement::Token(colon_token)),
-            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
         ],
     ))
 }
+pub fn yaml_object_member(
+    key: YamlIdentifier,
+    colon_token: SyntaxToken,
+) -> YamlObjectMemberBuilder {
+    YamlObjectMemberBuilder {
+        key,
+        colon_token,
+        value: None,
+    }
+}
+pub struct YamlObjectMemberBuilder {
+    key: YamlIdentifier,
+    colon_token: SyntaxToken,
+    value: Option<AnyYamlValue>,
+}
+impl YamlObjectMemberBuilder {
+    pub fn with_value(mut self, value: AnyYamlValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlObjectMember {
+        YamlObjectMember::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_OBJECT_MEMBER,
+            [
+                Some(SyntaxElement::Node(self.key.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn yaml_root(documents: YamlDocumentList, eof_token: SyntaxToken) -> YamlRootBuilder {
     YamlRootBuilder {
         documents,
@@ -177,7 +262,7 @@ pub fn yaml_string_value(value_token: SyntaxToken) -> YamlStringValue {
 }
 pub fn yaml_array_inline_list<I, S>(items: I, separators: S) -> YamlArrayInlineList
 where
-    I: IntoIterator<Item = AnyYamlScalar>,
+    I: IntoIterator<Item = AnyYamlValue>,
     I::IntoIter: ExactSizeIterator,
     S: IntoIterator<Item = YamlSyntaxToken>,
     S::IntoIter: ExactSizeIterator,
@@ -220,6 +305,27 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn yaml_object_inline_member_list<I, S>(items: I, separators: S) -> YamlObjectInlineMemberList
+where
+    I: IntoIterator<Item = YamlObjectMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = YamlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    YamlObjectInlineMemberList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_OBJECT_INLINE_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
 pub fn yaml_object_member_list<I>(items: I) -> YamlObjectMemberList
 where
     I: IntoIterator<Item = YamlObjectMember>,
@@ -294,6 +294,39 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 }
                 slots.into_node(YAML_OBJECT, children)
             }
+            YAML_OBJECT_INLINE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['{'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if YamlObjectInlineMemberList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['}'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        YAML_OBJECT_INLINE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(YAML_OBJECT_INLINE, children)
+            }
             YAML_OBJECT_MEMBER => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
@@ -382,7 +415,7 @@ impl SyntaxFactory for YamlSyntaxFactory {
             YAML_ARRAY_INLINE_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
-                AnyYamlScalar::can_cast,
+                AnyYamlValue::can_cast,
                 T ! [,],
                 true,
             ),
@@ -392,6 +425,13 @@ impl SyntaxFactory for YamlSyntaxFactory {
             YAML_DOCUMENT_LIST => {
                 Self::make_node_list_syntax(kind, children, YamlDocument::can_cast)
             }
+            YAML_OBJECT_INLINE_MEMBER_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                YamlObjectMember::can_cast,
+                T ! [,],
+                true,
+            ),
             YAML_OBJECT_MEMBER_LIST => {
                 Self::make_node_list_syntax(kind, children, YamlObjectMember::can_cast)
             }
@@ -0,0 +1,875 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, YamlFormatContext, YamlFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_yaml_syntax::YamlArray> for crate::yaml::auxiliary::array::FormatYamlArray {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlArray, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlArray>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlArray {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlArray,
+        crate::yaml::auxiliary::array::FormatYamlArray,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::array::FormatYamlArray::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlArray {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlArray,
+        crate::yaml::auxiliary::array::FormatYamlArray,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::array::FormatYamlArray::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlArrayInline>
+    for crate::yaml::auxiliary::array_inline::FormatYamlArrayInline
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlArrayInline,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlArrayInline>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayInline {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlArrayInline,
+        crate::yaml::auxiliary::array_inline::FormatYamlArrayInline,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::array_inline::FormatYamlArrayInline::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayInline {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlArrayInline,
+        crate::yaml::auxiliary::array_inline::FormatYamlArrayInline,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::array_inline::FormatYamlArrayInline::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlArrayItem>
+    for crate::yaml::auxiliary::array_item::FormatYamlArrayItem
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlArrayItem,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlArrayItem>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlArrayItem,
+        crate::yaml::auxiliary::array_item::FormatYamlArrayItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::array_item::FormatYamlArrayItem::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayItem {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlArrayItem,
+        crate::yaml::auxiliary::array_item::FormatYamlArrayItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::array_item::FormatYamlArrayItem::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockFolded>
+    for crate::yaml::auxiliary::block_folded::FormatYamlBlockFolded
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockFolded,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockFolded>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockFolded {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockFolded,
+        crate::yaml::auxiliary::block_folded::FormatYamlBlockFolded,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_folded::FormatYamlBlockFolded::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockFolded {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockFolded,
+        crate::yaml::auxiliary::block_folded::FormatYamlBlockFolded,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_folded::FormatYamlBlockFolded::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockLiteral>
+    for crate::yaml::auxiliary::block_literal::FormatYamlBlockLiteral
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockLiteral,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockLiteral>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockLiteral {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockLiteral,
+        crate::yaml::auxiliary::block_literal::FormatYamlBlockLiteral,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_literal::FormatYamlBlockLiteral::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockLiteral {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockLiteral,
+        crate::yaml::auxiliary::block_literal::FormatYamlBlockLiteral,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_literal::FormatYamlBlockLiteral::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockValue>
+    for crate::yaml::value::block_value::FormatYamlBlockValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockValue,
+        crate::yaml::value::block_value::FormatYamlBlockValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::value::block_value::FormatYamlBlockValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockValue,
+        crate::yaml::value::block_value::FormatYamlBlockValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::value::block_value::FormatYamlBlockValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBooleanValue>
+    for crate::yaml::value::boolean_value::FormatYamlBooleanValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBooleanValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBooleanValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBooleanValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBooleanValue,
+        crate::yaml::value::boolean_value::FormatYamlBooleanValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::value::boolean_value::FormatYamlBooleanValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBooleanValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBooleanValue,
+        crate::yaml::value::boolean_value::FormatYamlBooleanValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::value::boolean_value::FormatYamlBooleanValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDocument>
+    for crate::yaml::auxiliary::document::FormatYamlDocument
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDocument,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlIdentifier>
+    for crate::yaml::auxiliary::identifier::FormatYamlIdentifier
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlIdentifier,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlIdentifier,
+        crate::yaml::auxiliary::identifier::FormatYamlIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::identifier::FormatYamlIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlIdentifier,
+        crate::yaml::auxiliary::identifier::FormatYamlIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::identifier::FormatYamlIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlNullValue>
+    for crate::yaml::value::null_value::FormatYamlNullValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlNullValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlNullValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlNullValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlNullValue,
+        crate::yaml::value::null_value::FormatYamlNullValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::value::null_value::FormatYamlNullValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlNullValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlNullValue,
+        crate::yaml::value::null_value::FormatYamlNullValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::value::null_value::FormatYamlNullValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlNumberValue>
+    for crate::yaml::value::number_value::FormatYamlNumberValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlNumberValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlNumberValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlNumberValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlNumberValue,
+        crate::yaml::value::number_value::FormatYamlNumberValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::value::number_value::FormatYamlNumberValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlNumberValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlNumberValue,
+        crate::yaml::value::number_value::FormatYamlNumberValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::value::number_value::FormatYamlNumberValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlObject>
+    for crate::yaml::auxiliary::object::FormatYamlObject
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlObject, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlObject>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlObject {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlObject,
+        crate::yaml::auxiliary::object::FormatYamlObject,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::object::FormatYamlObject::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlObject {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlObject,
+        crate::yaml::auxiliary::object::FormatYamlObject,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::object::FormatYamlObject::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlObjectInline>
+    for crate::yaml::auxiliary::object_inline::FormatYamlObjectInline
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlObjectInline,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlObjectInline>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectInline {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlObjectInline,
+        crate::yaml::auxiliary::object_inline::FormatYamlObjectInline,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::object_inline::FormatYamlObjectInline::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectInline {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlObjectInline,
+        crate::yaml::auxiliary::object_inline::FormatYamlObjectInline,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::object_inline::FormatYamlObjectInline::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlObjectMember>
+    for crate::yaml::auxiliary::object_member::FormatYamlObjectMember
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlObjectMember,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlObjectMember>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectMember {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlObjectMember,
+        crate::yaml::auxiliary::object_member::FormatYamlObjectMember,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::object_member::FormatYamlObjectMember::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectMember {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlObjectMember,
+        crate::yaml::auxiliary::object_member::FormatYamlObjectMember,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::object_member::FormatYamlObjectMember::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlRoot> for crate::yaml::auxiliary::root::FormatYamlRoot {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlStringValue>
+    for crate::yaml::value::string_value::FormatYamlStringValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlStringValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlStringValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlStringValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlStringValue,
+        crate::yaml::value::string_value::FormatYamlStringValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::value::string_value::FormatYamlStringValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlStringValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlStringValue,
+        crate::yaml::value::string_value::FormatYamlStringValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::value::string_value::FormatYamlStringValue::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayInlineList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlArrayInlineList,
+        crate::yaml::lists::array_inline_list::FormatYamlArrayInlineList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::array_inline_list::FormatYamlArrayInlineList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayInlineList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlArrayInlineList,
+        crate::yaml::lists::array_inline_list::FormatYamlArrayInlineList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::array_inline_list::FormatYamlArrayInlineList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlArrayItemList,
+        crate::yaml::lists::array_item_list::FormatYamlArrayItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::array_item_list::FormatYamlArrayItemList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlArrayItemList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlArrayItemList,
+        crate::yaml::lists::array_item_list::FormatYamlArrayItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::array_item_list::FormatYamlArrayItemList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectInlineMemberList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlObjectInlineMemberList,
+        crate::yaml::lists::object_inline_member_list::FormatYamlObjectInlineMemberList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: yaml :: lists :: object_inline_member_list :: FormatYamlObjectInlineMemberList :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectInlineMemberList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlObjectInlineMemberList,
+        crate::yaml::lists::object_inline_member_list::FormatYamlObjectInlineMemberList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: yaml :: lists :: object_inline_member_list :: FormatYamlObjectInlineMemberList :: default ())
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectMemberList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlObjectMemberList,
+        crate::yaml::lists::object_member_list::FormatYamlObjectMemberList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::object_member_list::FormatYamlObjectMemberList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlObjectMemberList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlObjectMemberList,
+        crate::yaml::lists::object_member_list::FormatYamlObjectMemberList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::object_member_list::FormatYamlObjectMemberList::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogus> for crate::yaml::bogus::bogus::FormatYamlBogus {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlBogus, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogusValue>
+    for crate::yaml::bogus::bogus_value::FormatYamlBogusValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBogusValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogusValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogusValue,
+        crate::yaml::bogus::bogus_value::FormatYamlBogusValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_value::FormatYamlBogusValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogusValue,
+        crate::yaml::bogus::bogus_value::FormatYamlBogusValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_value::FormatYamlBogusValue::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlScalar,
+        crate::yaml::any::scalar::FormatAnyYamlScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::scalar::FormatAnyYamlScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlScalar,
+        crate::yaml::any::scalar::FormatAnyYamlScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::scalar::FormatAnyYamlScalar::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlValue,
+        crate::yaml::any::value::FormatAnyYamlValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::yaml::any::value::FormatAnyYamlValue::default())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlValue,
+        crate::yaml::any::value::FormatAnyYamlValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::yaml::any::value::FormatAnyYamlValue::default())
+    }
+}
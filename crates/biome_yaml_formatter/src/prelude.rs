@@ -0,0 +1,12 @@
+//! This module provides important and useful traits to help to format tokens and nodes
+//! when implementing the [crate::FormatNodeRule] trait.
+
+#[allow(unused_imports)]
+pub(crate) use crate::{
+    AsFormat, FormatNodeRule, FormattedIterExt as _, IntoFormat, YamlFormatContext, YamlFormatter,
+};
+pub(crate) use biome_formatter::prelude::*;
+#[allow(unused_imports)]
+pub(crate) use biome_rowan::{
+    AstNode as _, AstNodeList as _, AstNodeSlotMap as _, AstSeparatedList as _,
+};
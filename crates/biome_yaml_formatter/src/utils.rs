@@ -0,0 +1,93 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_rowan::{TextLen, TextSize};
+use biome_yaml_syntax::YamlSyntaxToken;
+
+/// Formats a scalar token that can span several lines, such as a multiline plain scalar or
+/// a quoted scalar.
+///
+/// The continuation lines of the scalar are trimmed and indented one level deeper than the
+/// node that contains the scalar, so that they remain part of the scalar wherever it's moved:
+///
+/// ```yaml
+/// key: a plain scalar
+///      on two lines
+/// ```
+///
+/// becomes
+///
+/// ```yaml
+/// key: a plain scalar
+///   on two lines
+/// ```
+pub(crate) fn format_scalar_token(token: &YamlSyntaxToken) -> FormatScalarToken {
+    FormatScalarToken { token }
+}
+
+pub(crate) struct FormatScalarToken<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl Format<YamlFormatContext> for FormatScalarToken<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+        if !text.contains(['\n', '\r']) {
+            return write!(f, [self.token.format()]);
+        }
+
+        let start = self.token.text_trimmed_range().start();
+        let content = format_with(|f| {
+            if let Some((first_line, position)) = text_lines(text, start).next() {
+                write!(f, [dynamic_text(first_line.trim_end(), position)])?;
+            }
+
+            let continuation = format_with(|f| {
+                let mut empty_lines = 0;
+                for (line, position) in text_lines(text, start).skip(1) {
+                    let content = line.trim_start();
+                    if content.trim_end().is_empty() {
+                        empty_lines += 1;
+                        continue;
+                    }
+
+                    write_line_breaks(f, empty_lines)?;
+                    empty_lines = 0;
+
+                    let position = position + (line.text_len() - content.text_len());
+                    write!(f, [dynamic_text(content.trim_end(), position)])?;
+                }
+                Ok(())
+            });
+
+            write!(f, [indent(&continuation)])
+        });
+
+        write!(f, [format_replaced(self.token, &content)])
+    }
+}
+
+/// Writes a line break followed by `empty_lines` empty lines.
+///
+/// The empty lines inside a scalar are part of its value, so all of them are kept, unlike the
+/// empty lines between two nodes that are collapsed into a single one.
+pub(crate) fn write_line_breaks(f: &mut YamlFormatter, empty_lines: usize) -> FormatResult<()> {
+    if empty_lines > 0 {
+        // The printer merges consecutive line breaks, the newlines have to be printed as text
+        for _ in 0..=empty_lines {
+            write!(f, [text("\n")])?;
+        }
+    }
+
+    write!(f, [hard_line_break()])
+}
+
+/// Returns the lines of `text` with the position of their start in the source, `start` being
+/// the position of `text`. The line terminators aren't part of the lines.
+pub(crate) fn text_lines(text: &str, start: TextSize) -> impl Iterator<Item = (&str, TextSize)> {
+    let mut position = start;
+    text.split('\n').map(move |line| {
+        let line_position = position;
+        position += line.text_len() + TextSize::from(1);
+        (line.strip_suffix('\r').unwrap_or(line), line_position)
+    })
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockFolded, YamlBlockFoldedFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockFolded;
+
+impl FormatNodeRule<YamlBlockFolded> for FormatYamlBlockFolded {
+    fn fmt_fields(&self, node: &YamlBlockFolded, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockFoldedFields {
+            r_angle_token,
+            content,
+        } = node.as_fields();
+
+        write!(f, [r_angle_token.format(), content.format()])
+    }
+}
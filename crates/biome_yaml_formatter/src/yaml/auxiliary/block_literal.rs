@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockLiteral, YamlBlockLiteralFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockLiteral;
+
+impl FormatNodeRule<YamlBlockLiteral> for FormatYamlBlockLiteral {
+    fn fmt_fields(&self, node: &YamlBlockLiteral, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockLiteralFields {
+            bitwise_or_token,
+            content,
+        } = node.as_fields();
+
+        write!(f, [bitwise_or_token.format(), content.format()])
+    }
+}
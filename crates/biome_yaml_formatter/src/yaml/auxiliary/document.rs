@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{AnyYamlValue, YamlDocument, YamlDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            dashdashdash_token,
+            body,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        if let Some(dashdashdash_token) = dashdashdash_token {
+            write!(f, [dashdashdash_token.format()])?;
+
+            if let Some(body) = &body {
+                // Block collections can't start on the line of the document marker
+                if matches!(
+                    body,
+                    AnyYamlValue::YamlObject(_) | AnyYamlValue::YamlArray(_)
+                ) {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [space()])?;
+                }
+            }
+        }
+
+        write!(f, [body.format()])?;
+
+        if let Some(dotdotdot_token) = dotdotdot_token {
+            write!(f, [hard_line_break(), dotdotdot_token.format()])?;
+        }
+
+        Ok(())
+    }
+}
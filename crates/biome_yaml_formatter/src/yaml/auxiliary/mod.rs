@@ -0,0 +1,13 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array;
+pub(crate) mod array_inline;
+pub(crate) mod array_item;
+pub(crate) mod block_folded;
+pub(crate) mod block_literal;
+pub(crate) mod document;
+pub(crate) mod identifier;
+pub(crate) mod object;
+pub(crate) mod object_inline;
+pub(crate) mod object_member;
+pub(crate) mod root;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlObject, YamlObjectFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObject;
+
+impl FormatNodeRule<YamlObject> for FormatYamlObject {
+    fn fmt_fields(&self, node: &YamlObject, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlObjectFields { members } = node.as_fields();
+
+        write!(f, [members.format()])
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use biome_formatter::{write, FormatOptions};
+use biome_yaml_syntax::{YamlObjectInline, YamlObjectInlineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectInline;
+
+impl FormatNodeRule<YamlObjectInline> for FormatYamlObjectInline {
+    fn fmt_fields(&self, node: &YamlObjectInline, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlObjectInlineFields {
+            l_curly_token,
+            members,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(f, [l_curly_token.format()])?;
+
+        if members.is_empty() {
+            write!(
+                f,
+                [format_dangling_comments(node.syntax()).with_block_indent()]
+            )?;
+        } else {
+            let should_insert_space_around_brackets = f.options().bracket_spacing().value();
+            write!(
+                f,
+                [group(&soft_block_indent_with_maybe_space(
+                    &members.format(),
+                    should_insert_space_around_brackets
+                ))]
+            )?;
+        }
+
+        write!(f, [line_suffix_boundary(), r_curly_token.format()])
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlObjectInline,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Handled as part of `fmt_fields`
+        Ok(())
+    }
+}
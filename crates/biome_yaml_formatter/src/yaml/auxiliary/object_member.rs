@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{AnyYamlValue, YamlObjectMember, YamlObjectMemberFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectMember;
+
+impl FormatNodeRule<YamlObjectMember> for FormatYamlObjectMember {
+    fn fmt_fields(&self, node: &YamlObjectMember, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlObjectMemberFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        match value {
+            // Block collections start on the next line, one level deeper than the key
+            Some(value @ (AnyYamlValue::YamlObject(_) | AnyYamlValue::YamlArray(_))) => {
+                write!(
+                    f,
+                    [indent(&format_args![hard_line_break(), value.format()])]
+                )
+            }
+            Some(value) => write!(f, [space(), value.format()]),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlRoot, YamlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlRoot;
+
+impl FormatNodeRule<YamlRoot> for FormatYamlRoot {
+    fn fmt_fields(&self, node: &YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlRootFields {
+            bom_token,
+            documents,
+            eof_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                bom_token.format(),
+                documents.format(),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+}
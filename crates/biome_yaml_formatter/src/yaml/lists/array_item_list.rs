@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_yaml_syntax::YamlArrayItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlArrayItemList;
+
+impl FormatRule<YamlArrayItemList> for FormatYamlArrayItemList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlArrayItemList, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for item in node {
+            join.entry(item.syntax(), &format_or_verbatim(item.format()));
+        }
+
+        join.finish()
+    }
+}
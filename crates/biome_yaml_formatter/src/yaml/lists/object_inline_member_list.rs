@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use biome_formatter::separated::TrailingSeparator;
+use biome_yaml_syntax::YamlObjectInlineMemberList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlObjectInlineMemberList;
+
+impl FormatRule<YamlObjectInlineMemberList> for FormatYamlObjectInlineMemberList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlObjectInlineMemberList, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_soft_line();
+
+        for (element, formatted) in node
+            .elements()
+            .zip(node.format_separated(",", TrailingSeparator::Omit))
+        {
+            join.entry(element.node()?.syntax(), &formatted);
+        }
+
+        join.finish()
+    }
+}
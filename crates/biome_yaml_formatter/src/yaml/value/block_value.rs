@@ -0,0 +1,71 @@
+use crate::prelude::*;
+use crate::utils::{text_lines, write_line_breaks};
+use biome_formatter::write;
+use biome_rowan::TextSize;
+use biome_yaml_syntax::{YamlArrayItem, YamlBlockValue, YamlBlockValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockValue;
+
+impl FormatNodeRule<YamlBlockValue> for FormatYamlBlockValue {
+    fn fmt_fields(&self, node: &YamlBlockValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockValueFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let text = value_token.text_trimmed();
+        let start = value_token.text_trimmed_range().start();
+        let mut lines = text_lines(text, start);
+        let Some((header, header_position)) = lines.next() else {
+            return write!(f, [value_token.format()]);
+        };
+
+        // An indentation indicator, such as in `|2`, fixes the indentation of the content,
+        // it can't be changed.
+        if header.bytes().any(|byte| byte.is_ascii_digit()) {
+            return write!(f, [value_token.format()]);
+        }
+
+        let lines: Vec<(&str, TextSize)> = lines.collect();
+        let content_indent = lines
+            .iter()
+            .filter(|(line, _)| !line.trim().is_empty())
+            .map(|(line, _)| line.len() - line.trim_start_matches(' ').len())
+            .min()
+            .unwrap_or(0);
+
+        let content = format_with(|f| {
+            write!(f, [dynamic_text(header.trim_end(), header_position)])?;
+
+            let body = format_with(|f| {
+                let mut empty_lines = 0;
+                for (line, position) in &lines {
+                    if line.trim().is_empty() {
+                        empty_lines += 1;
+                        continue;
+                    }
+
+                    write_line_breaks(f, empty_lines)?;
+                    empty_lines = 0;
+
+                    let content_position = *position + TextSize::from(content_indent as u32);
+                    write!(f, [dynamic_text(&line[content_indent..], content_position)])?;
+                }
+                Ok(())
+            });
+
+            // The items of a sequence are already aligned after their `- `
+            let is_array_item = node
+                .syntax()
+                .ancestors()
+                .nth(2)
+                .is_some_and(|ancestor| YamlArrayItem::can_cast(ancestor.kind()));
+            if is_array_item {
+                write!(f, [body])
+            } else {
+                write!(f, [indent(&body)])
+            }
+        });
+
+        write!(f, [format_replaced(&value_token, &content)])
+    }
+}
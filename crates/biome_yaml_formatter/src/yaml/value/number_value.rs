@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlNumberValue, YamlNumberValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlNumberValue;
+
+impl FormatNodeRule<YamlNumberValue> for FormatYamlNumberValue {
+    fn fmt_fields(&self, node: &YamlNumberValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlNumberValueFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use crate::utils::format_scalar_token;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlStringValue, YamlStringValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlStringValue;
+
+impl FormatNodeRule<YamlStringValue> for FormatYamlStringValue {
+    fn fmt_fields(&self, node: &YamlStringValue, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlStringValueFields { value_token } = node.as_fields();
+
+        write!(f, [format_scalar_token(&value_token?)])
+    }
+}
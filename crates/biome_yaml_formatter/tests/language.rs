@@ -0,0 +1,44 @@
+use biome_formatter_test::TestFormatLanguage;
+use biome_fs::BiomePath;
+use biome_parser::AnyParse;
+use biome_service::{
+    settings::{ServiceLanguage, Settings},
+    workspace::DocumentFileSource,
+};
+use biome_yaml_formatter::context::YamlFormatContext;
+use biome_yaml_formatter::YamlFormatLanguage;
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
+
+#[derive(Default)]
+pub struct YamlTestFormatLanguage {
+    _source_type: YamlFileSource,
+}
+
+impl TestFormatLanguage for YamlTestFormatLanguage {
+    type ServiceLanguage = YamlLanguage;
+    type Context = YamlFormatContext;
+    type FormatLanguage = YamlFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_yaml(text);
+
+        AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
+    }
+
+    fn to_format_language(
+        &self,
+        settings: &Settings,
+        file_source: &DocumentFileSource,
+    ) -> Self::FormatLanguage {
+        let language_settings = &settings.languages.yaml.formatter;
+        let options = Self::ServiceLanguage::resolve_format_options(
+            Some(&settings.formatter),
+            Some(&settings.override_settings),
+            Some(language_settings),
+            &BiomePath::new(""),
+            file_source,
+        );
+        YamlFormatLanguage::new(options)
+    }
+}
@@ -0,0 +1,42 @@
+use biome_formatter::LineWidth;
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_formatter::{format_node, YamlFormatLanguage};
+use biome_yaml_parser::parse_yaml;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+key:   value
+list:
+    -   a
+    -   b
+"#;
+    let parse = parse_yaml(src);
+    println!("{:#?}", parse);
+
+    let options = YamlFormatOptions::default().with_line_width(LineWidth::try_from(80).unwrap());
+    let doc = format_node(options.clone(), &parse.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    let root = &parse.syntax();
+    let language = language::YamlTestFormatLanguage::default();
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        root,
+        result.as_code(),
+        "quick_test",
+        &language,
+        YamlFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
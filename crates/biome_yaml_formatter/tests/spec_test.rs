@@ -0,0 +1,57 @@
+use biome_configuration::{PartialConfiguration, PartialYamlConfiguration, PartialYamlFormatter};
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_yaml_formatter::{context::YamlFormatOptions, YamlFormatLanguage};
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_yaml_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.yaml` and the output file name is `{spec_name}.yaml.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `yaml/null` -> input: `tests/specs/yaml/null.yaml`, expected output: `tests/specs/yaml/null.yaml.snap`
+/// * `null` -> input: `tests/specs/null.yaml`, expected output: `tests/specs/null.yaml.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+    let configuration = PartialConfiguration {
+        yaml: Some(PartialYamlConfiguration {
+            formatter: Some(PartialYamlFormatter {
+                enabled: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let Some(test_file) =
+        SpecTestFile::try_from_file(spec_input_file, root_path, Some(configuration))
+    else {
+        return;
+    };
+
+    let options = YamlFormatOptions::default();
+    let language = language::YamlTestFormatLanguage::default();
+
+    let snapshot = SpecSnapshot::new(
+        test_file,
+        test_directory,
+        language,
+        YamlFormatLanguage::new(options),
+    );
+
+    snapshot.test()
+}
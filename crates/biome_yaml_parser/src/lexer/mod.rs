@@ -5,7 +5,7 @@ use biome_parser::{
     lexer::{LexContext, Lexer, TokenFlags},
 };
 use biome_rowan::{TextRange, TextSize};
-use biome_yaml_syntax::{YamlSyntaxKind, T};
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxKind::UNICODE_BOM, T};
 
 #[rustfmt::skip]
 mod tests;
@@ -55,6 +55,21 @@ pub(crate) struct YamlLexer<'src> {
     diagnostics: Vec<ParseDiagnostic>,
 
     context: YamlLexContext,
+
+    /// The kind of the last token that isn't trivia
+    last_non_trivia_kind: YamlSyntaxKind,
+
+    /// The byte position of the start of the current line
+    line_start: usize,
+
+    /// The column of the last key or the last `-`, which is the parent of the value that follows
+    parent_column: usize,
+
+    /// The number of flow collections, `[...]` or `{...}`, that contain the next token
+    flow_depth: usize,
+
+    /// The column of the parent of a block scalar whose content is lexed next
+    block_scalar_parent_column: Option<usize>,
 }
 
 impl<'source> YamlLexer<'source> {
@@ -71,6 +86,11 @@ impl<'source> YamlLexer<'source> {
             current_flags: TokenFlags::empty(),
             diagnostics: vec![],
             context: YamlLexContext::Regular,
+            last_non_trivia_kind: YamlSyntaxKind::EOF,
+            line_start: 0,
+            parent_column: 0,
+            flow_depth: 0,
+            block_scalar_parent_column: None,
         }
     }
 
@@ -83,15 +103,46 @@ impl<'source> YamlLexer<'source> {
     }
 
     fn is_next_char_whitespace(&self) -> bool {
-        matches!(self.peek_next_char(), Some(b' ' | b'\n'))
+        matches!(
+            self.peek_next_char(),
+            Some(b' ' | b'\t' | b'\n' | b'\r') | None
+        )
+    }
+
+    /// Returns the column of the current position
+    fn column(&self) -> usize {
+        self.position - self.line_start
+    }
+
+    /// Returns `true` if the source at the current position starts with the given document marker,
+    /// `---` or `...`, at the start of a line
+    fn is_at_document_marker(&self, marker: &str) -> bool {
+        self.position == self.line_start
+            && self.source[self.position..].starts_with(marker)
+            && matches!(
+                self.source.as_bytes().get(self.position + marker.len()),
+                Some(b' ' | b'\t' | b'\n' | b'\r') | None
+            )
+    }
+
+    /// Returns `true` if the lexer is at the position of a value that can be a block scalar,
+    /// after a `:` or a `-`
+    fn is_at_block_scalar_indicator(&self) -> bool {
+        self.flow_depth == 0
+            && matches!(
+                self.last_non_trivia_kind,
+                T![:] | T![-] | T![---] | YamlSyntaxKind::EOF
+            )
     }
 
     /// Consumes and returns the next token, if any
     fn consume_token(&mut self) -> Option<Token> {
         let start = self.text_position();
         let char = self.current_char()?;
+        self.current_start = start;
         let kind = self.consume_token_in_context(char, self.context);
         self.current_kind = kind;
+        self.track_token(kind);
         let end = self.text_position();
         Some(Token {
             kind,
@@ -107,15 +158,29 @@ impl<'source> YamlLexer<'source> {
 
         let start = self.text_position();
 
+        if let Some(parent_column) = self.block_scalar_parent_column.take() {
+            if let Some(kind) = self.consume_block_value(parent_column) {
+                return kind;
+            }
+        }
+
         let kind = match current {
             b'#' => self.consume_comment(),
             b'-' => {
-                if self.is_next_char_whitespace() {
+                if self.is_at_document_marker("---") {
+                    self.advance(3);
+                    T![---]
+                } else if self.is_next_char_whitespace() {
+                    self.parent_column = self.column();
                     self.consume_byte(T![-])
                 } else {
                     self.consume_identifer_or_value()
                 }
             }
+            b'.' if self.is_at_document_marker("...") => {
+                self.advance(3);
+                T![...]
+            }
             b':' => {
                 if self.is_next_char_whitespace() {
                     self.context = YamlLexContext::AfterIdent;
@@ -124,33 +189,56 @@ impl<'source> YamlLexer<'source> {
                     self.consume_identifer_or_value()
                 }
             }
+            b'|' | b'>' if self.is_at_block_scalar_indicator() => {
+                self.block_scalar_parent_column = Some(self.parent_column);
+                if current == b'|' {
+                    self.consume_byte(T![|])
+                } else {
+                    self.consume_byte(T![>])
+                }
+            }
             b'\'' | b'"' => self.consume_string_literal(current),
-            b' ' => self.consume_newline_or_whitespaces(),
-            b'\n' => {
+            b' ' | b'\t' => self.consume_newline_or_whitespaces(),
+            b'\n' | b'\r' => {
                 if self.context == YamlLexContext::AfterIdent {
                     self.context = YamlLexContext::Regular;
                 }
                 self.consume_newline_or_whitespaces()
             }
-            b'[' => self.consume_array_inline_start(),
+            b'[' => self.consume_flow_start(T!['[']),
+            b'{' => self.consume_flow_start(T!['{']),
             b',' => {
-                if self.context == YamlLexContext::AfterInlineArray {
+                if self.flow_depth > 0 {
+                    self.context = YamlLexContext::Regular;
                     self.consume_byte(T![,])
                 } else {
                     self.consume_identifer_or_value()
                 }
             }
             b']' => {
-                if self.context == YamlLexContext::AfterInlineArray {
-                    self.consume_array_inline_end()
+                if self.flow_depth > 0 {
+                    self.consume_flow_end(T![']'])
                 } else {
                     self.consume_identifer_or_value()
                 }
             }
+            b'}' => {
+                if self.flow_depth > 0 {
+                    self.consume_flow_end(T!['}'])
+                } else {
+                    self.consume_identifer_or_value()
+                }
+            }
+            _ if self.position == 0 => {
+                if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                    self.unicode_bom_length = bom_size;
+                    return bom;
+                }
+                self.consume_identifer_or_value()
+            }
             _ => match context {
                 YamlLexContext::Regular => self.consume_identifer_or_value(),
                 YamlLexContext::AfterIdent => self.consume_value(),
-                YamlLexContext::AfterInlineArray => self.consume_value(),
             },
         };
 
@@ -206,26 +294,30 @@ impl<'source> YamlLexer<'source> {
                 }
             }
         }
-        YamlSyntaxKind::YAML_STRING_VALUE
+
+        if self.context == YamlLexContext::Regular && self.is_followed_by_mapping_colon() {
+            YamlSyntaxKind::YAML_IDENTIFIER
+        } else {
+            YamlSyntaxKind::YAML_STRING_VALUE
+        }
+    }
+
+    /// Returns `true` if the current position is followed by optional whitespaces and a `:`
+    /// that separates a key from its value
+    fn is_followed_by_mapping_colon(&self) -> bool {
+        let rest = self.source[self.position..].trim_start_matches([' ', '\t']);
+        let mut bytes = rest.bytes();
+        bytes.next() == Some(b':')
+            && matches!(bytes.next(), Some(b' ' | b'\t' | b'\n' | b'\r') | None)
     }
 
     /// Consume a line up to the colon or the end of the line
     fn consume_identifer_or_value(&mut self) -> YamlSyntaxKind {
         let start = self.position;
-        let mut is_ident = false;
-        while let Some(c) = self.current_char() {
-            if c == b'\n' {
-                break;
-            }
-            if c == b':' && self.is_next_char_whitespace() {
-                is_ident = true;
-                break;
-            }
-            self.advance(1);
-        }
-        if is_ident {
+        if self.consume_plain_scalar(true) {
             YamlSyntaxKind::YAML_IDENTIFIER
         } else {
+            self.consume_plain_scalar_continuation();
             let value = &self.source[start..self.position];
             interpret_value(value)
         }
@@ -233,32 +325,168 @@ impl<'source> YamlLexer<'source> {
 
     fn consume_value(&mut self) -> YamlSyntaxKind {
         let start = self.position;
-        while let Some(c) = self.current_char() {
-            if c == b'\n' {
-                break;
+        self.consume_plain_scalar(false);
+        self.consume_plain_scalar_continuation();
+        let value = &self.source[start..self.position];
+        interpret_value(value)
+    }
+
+    /// Consumes the lines that continue a plain scalar of a block collection: the following
+    /// lines that are more indented than the parent of the scalar, and that aren't comments.
+    ///
+    /// ```yaml
+    /// key: a plain scalar
+    ///   on two lines
+    /// ```
+    fn consume_plain_scalar_continuation(&mut self) {
+        if self.flow_depth > 0 {
+            return;
+        }
+
+        let bytes = self.source.as_bytes();
+        loop {
+            let mut line_end = memchr_newline(bytes, self.position);
+            if !self.source[self.position..line_end].trim().is_empty() {
+                // A comment ends the scalar
+                return;
             }
-            if self.context == YamlLexContext::AfterInlineArray && (c == b',' || c == b']') {
-                break;
+
+            let content_start = loop {
+                if line_end == bytes.len() {
+                    return;
+                }
+                let line_start = line_end + newline_len(bytes, line_end);
+                let next_line_end = memchr_newline(bytes, line_start);
+                let line = &self.source[line_start..next_line_end];
+                let content = line.trim_start_matches(' ');
+                if content.trim().is_empty() {
+                    line_end = next_line_end;
+                    continue;
+                }
+                if line.len() - content.len() <= self.parent_column || content.starts_with('#') {
+                    return;
+                }
+                break next_line_end - content.len();
+            };
+
+            self.position = content_start;
+            self.consume_plain_scalar(false);
+        }
+    }
+
+    /// Consumes a plain scalar up to the end of the line, a comment, or the end of the flow
+    /// collection that contains it. The trailing whitespaces aren't consumed.
+    ///
+    /// Returns `true` if the scalar is a key, followed by a `:`, and `allow_key` is `true`.
+    fn consume_plain_scalar(&mut self, allow_key: bool) -> bool {
+        let mut end = self.position;
+        let mut is_key = false;
+        while let Some(c) = self.current_char() {
+            match c {
+                b'\n' | b'\r' => break,
+                b':' if allow_key && self.is_next_char_whitespace() => {
+                    is_key = true;
+                    break;
+                }
+                b'#' if matches!(self.source.as_bytes()[self.position - 1], b' ' | b'\t') => {
+                    break;
+                }
+                b',' | b']' | b'}' if self.flow_depth > 0 => break,
+                _ => {}
             }
             self.advance(1);
+            if !matches!(c, b' ' | b'\t') {
+                end = self.position;
+            }
         }
-        let value = &self.source[start..self.position];
-        interpret_value(value)
+        self.position = end;
+        is_key
     }
 
-    fn consume_array_inline_start(&mut self) -> YamlSyntaxKind {
-        self.assert_byte(b'[');
-        self.advance(1);
-        self.context = YamlLexContext::AfterInlineArray;
-        YamlSyntaxKind::L_BRACK
+    fn consume_flow_start(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.flow_depth += 1;
+        self.context = YamlLexContext::Regular;
+        self.consume_byte(kind)
     }
 
-    fn consume_array_inline_end(&mut self) -> YamlSyntaxKind {
-        self.assert_byte(b']');
-        self.advance(1);
+    fn consume_flow_end(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.flow_depth -= 1;
         self.context = YamlLexContext::Regular;
-        YamlSyntaxKind::R_BRACK
+        self.consume_byte(kind)
+    }
+
+    /// Consumes the content of a block scalar: the rest of the line of its indicator, and the
+    /// following lines that are more indented than the parent of the block scalar.
+    /// The empty lines that end the block scalar aren't consumed.
+    ///
+    /// Returns [None] if the block scalar is empty.
+    fn consume_block_value(&mut self, parent_column: usize) -> Option<YamlSyntaxKind> {
+        let bytes = self.source.as_bytes();
+        let start = self.position;
+        let mut end = memchr_newline(bytes, start);
+        let mut line_end = end;
+        while line_end < bytes.len() {
+            let line_start = line_end + newline_len(bytes, line_end);
+            let next_line_end = memchr_newline(bytes, line_start);
+            let line = &self.source[line_start..next_line_end];
+            let content = line.trim_start_matches(' ');
+            if content.trim().is_empty() {
+                // Empty lines belong to the block scalar if some content follows them
+                line_end = next_line_end;
+                continue;
+            }
+            if line.len() - content.len() <= parent_column {
+                break;
+            }
+            end = next_line_end;
+            line_end = next_line_end;
+        }
+
+        if end == start {
+            return None;
+        }
+        self.position = end;
+        Some(YamlSyntaxKind::YAML_BLOCK_VALUE)
     }
+
+    /// Keeps track of the tokens that determine how the next tokens are lexed
+    fn track_token(&mut self, kind: YamlSyntaxKind) {
+        match kind {
+            YamlSyntaxKind::NEWLINE => {
+                self.line_start = self.position;
+            }
+            YamlSyntaxKind::WHITESPACE | YamlSyntaxKind::COMMENT => {}
+            YamlSyntaxKind::YAML_IDENTIFIER => {
+                self.parent_column = usize::from(self.current_start) - self.line_start;
+                self.last_non_trivia_kind = kind;
+            }
+            _ => {
+                // Block scalars, multiline plain scalars and quoted scalars can span several lines
+                let start = usize::from(self.current_start);
+                if let Some(index) = self.source[start..self.position].rfind(['\n', '\r']) {
+                    self.line_start = start + index + 1;
+                }
+                self.last_non_trivia_kind = kind;
+            }
+        }
+    }
+}
+
+/// Returns the length of the line break at the given position
+fn newline_len(bytes: &[u8], position: usize) -> usize {
+    if bytes[position] == b'\r' && bytes.get(position + 1) == Some(&b'\n') {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the position of the next line break at or after `from`, or the end of the source
+fn memchr_newline(bytes: &[u8], from: usize) -> usize {
+    bytes[from..]
+        .iter()
+        .position(|byte| matches!(byte, b'\n' | b'\r'))
+        .map_or(bytes.len(), |index| from + index)
 }
 
 fn interpret_value(value: &str) -> YamlSyntaxKind {
@@ -304,10 +532,15 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_start
     }
 
-    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
         self.current_start = TextSize::from(self.position as u32);
         self.current_flags = TokenFlags::empty();
-        self.consume_token_in_context(self.current_char().unwrap_or(b'\0'), context)
+        // The lexer keeps track of its context by itself, because it depends on the previous tokens
+        let kind =
+            self.consume_token_in_context(self.current_char().unwrap_or(b'\0'), self.context);
+        self.current_kind = kind;
+        self.track_token(kind);
+        kind
     }
 
     fn has_preceding_line_break(&self) -> bool {
@@ -395,14 +628,6 @@ pub enum YamlLexContext {
     /// #       ^ invalid syntax, mapping values not allowed here
     /// ```
     AfterIdent,
-    /// The lexer has lexed an inline array and is expecting a value or the end of the array to come next.
-    ///
-    /// In this context, commas are allowed to separate values. Normally, commas are simply treated as part of a value.
-    /// A newline is not allowed in this context. The newline must come after the array has been closed with `]`
-    /// ```yaml
-    /// foo: [1, 2, 3]
-    /// ```
-    AfterInlineArray,
 }
 
 impl LexContext for YamlLexContext {
@@ -263,3 +263,116 @@ fn lex_array_inline_invalid() {
         YAML_STRING_VALUE:2,
     );
 }
+
+#[test]
+fn lex_array_inline_nested() {
+    assert_lex!(
+        "[[1], 2]",
+        L_BRACK:1,
+        L_BRACK:1,
+        YAML_NUMBER_VALUE:1,
+        R_BRACK:1,
+        COMMA:1,
+        WHITESPACE:1,
+        YAML_NUMBER_VALUE:1,
+        R_BRACK:1,
+    );
+}
+
+#[test]
+fn lex_object_inline() {
+    assert_lex!(
+        "{a: 1, b: c}",
+        L_CURLY:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_NUMBER_VALUE:1,
+        COMMA:1,
+        WHITESPACE:1,
+        YAML_IDENTIFIER:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:1,
+        R_CURLY:1,
+    );
+}
+
+#[test]
+fn lex_quoted_key() {
+    assert_lex!(
+        "\"foo bar\": baz",
+        YAML_IDENTIFIER:9,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:3,
+    );
+}
+
+#[test]
+fn lex_value_with_comment() {
+    assert_lex!(
+        "key: value # comment",
+        YAML_IDENTIFIER:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:5,
+        WHITESPACE:1,
+        COMMENT:9,
+    );
+}
+
+#[test]
+fn lex_value_with_hash() {
+    assert_lex!(
+        "key: C#",
+        YAML_IDENTIFIER:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:2,
+    );
+}
+
+#[test]
+fn lex_documents() {
+    assert_lex!(
+        "---\nfoo\n...",
+        DOC_START:3,
+        NEWLINE:1,
+        YAML_STRING_VALUE:3,
+        NEWLINE:1,
+        DOC_END:3,
+    );
+}
+
+#[test]
+fn lex_block_literal() {
+    assert_lex!(
+        "foo: |\n  bar\n\n  baz\nqux: 1",
+        YAML_IDENTIFIER:3,
+        COLON:1,
+        WHITESPACE:1,
+        PIPE:1,
+        YAML_BLOCK_VALUE:13,
+        NEWLINE:1,
+        YAML_IDENTIFIER:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_NUMBER_VALUE:1,
+    );
+}
+
+#[test]
+fn lex_block_folded_in_list() {
+    assert_lex!(
+        "- >-\n  foo\n- bar",
+        DASH:1,
+        WHITESPACE:1,
+        R_ANGLE:1,
+        YAML_BLOCK_VALUE:7,
+        NEWLINE:1,
+        DASH:1,
+        WHITESPACE:1,
+        YAML_STRING_VALUE:3,
+    );
+}
@@ -0,0 +1,127 @@
+//! Block collections, whose structure is defined by the indentation of their content.
+//!
+//! The entries of a block collection start at the same column. The content of an entry is on
+//! the line of the entry, or on the following lines whose column is greater than the column
+//! of the entry.
+use super::parse_error::unexpected_content;
+use super::value::{parse_any_value, parse_identifier, parse_inline_value};
+use super::YamlParser;
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_rowan::TextRange;
+use biome_yaml_syntax::YamlSyntaxKind::*;
+use biome_yaml_syntax::T;
+
+/// Parses a block mapping:
+///
+/// ```yaml
+/// key: value
+/// other: value
+/// ```
+pub(crate) fn parse_block_mapping(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(YAML_IDENTIFIER) {
+        return Absent;
+    }
+
+    let column = p.cur_column();
+    let m = p.start();
+    let members = p.start();
+    parse_block_mapping_member(p, column);
+    while p.at(YAML_IDENTIFIER) && p.has_preceding_line_break() && p.cur_column() == column {
+        parse_block_mapping_member(p, column);
+    }
+    members.complete(p, YAML_OBJECT_MEMBER_LIST);
+
+    Present(m.complete(p, YAML_OBJECT))
+}
+
+fn parse_block_mapping_member(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let m = p.start();
+
+    parse_identifier(p).ok();
+    p.expect(T![:]);
+
+    let value = if !p.has_preceding_line_break() {
+        parse_inline_value(p)
+    } else if p.at(T![-]) && p.cur_column() >= column {
+        // A sequence can have the same indentation as the key that contains it
+        parse_block_sequence(p)
+    } else if !p.at(EOF) && p.cur_column() > column {
+        parse_any_value(p)
+    } else {
+        Absent
+    };
+    recover_block_content(p, value, column);
+
+    m.complete(p, YAML_OBJECT_MEMBER)
+}
+
+/// Parses a block sequence:
+///
+/// ```yaml
+/// - value
+/// - other
+/// ```
+pub(crate) fn parse_block_sequence(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T![-]) {
+        return Absent;
+    }
+
+    let column = p.cur_column();
+    let m = p.start();
+    let items = p.start();
+    parse_block_sequence_item(p, column);
+    while p.at(T![-]) && p.has_preceding_line_break() && p.cur_column() == column {
+        parse_block_sequence_item(p, column);
+    }
+    items.complete(p, YAML_ARRAY_ITEM_LIST);
+
+    Present(m.complete(p, YAML_ARRAY))
+}
+
+fn parse_block_sequence_item(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let m = p.start();
+
+    p.bump(T![-]);
+
+    // The value can start on the line of the `-`, such as `- key: value` or `- - value`
+    let value = if !p.has_preceding_line_break() || (!p.at(EOF) && p.cur_column() > column) {
+        parse_any_value(p)
+    } else {
+        Absent
+    };
+    recover_block_content(p, value, column);
+
+    m.complete(p, YAML_ARRAY_ITEM)
+}
+
+/// Wraps the content that follows the value of an entry in a bogus value, with the value, when
+/// the content belongs to the entry but isn't part of its value.
+///
+/// ```yaml
+/// key: value
+///   unexpected: content
+/// ```
+fn recover_block_content(p: &mut YamlParser, value: ParsedSyntax, column: usize) {
+    if is_at_block_end(p, column) {
+        return;
+    }
+
+    let bogus = match value {
+        Present(value) => value.precede(p),
+        Absent => p.start(),
+    };
+    let start = p.cur_range().start();
+    let mut end = start;
+    while !is_at_block_end(p, column) {
+        end = p.cur_range().end();
+        p.bump_any();
+    }
+    p.error(unexpected_content(p, TextRange::new(start, end)));
+    bogus.complete(p, YAML_BOGUS_VALUE);
+}
+
+/// Returns `true` if the current token doesn't belong to the entry at the given column
+fn is_at_block_end(p: &YamlParser, column: usize) -> bool {
+    p.at(EOF) || (p.has_preceding_line_break() && p.cur_column() <= column)
+}
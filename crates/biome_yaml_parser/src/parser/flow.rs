@@ -0,0 +1,113 @@
+//! Flow collections, which are enclosed in brackets or braces, such as `[a, b]` or `{ a: b }`.
+//!
+//! The indentation of their content doesn't matter.
+use super::parse_error::{expected_mapping_entry, expected_value};
+use super::value::{parse_identifier, parse_scalar};
+use super::YamlParser;
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_rowan::TextRange;
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+
+const FLOW_ENTRY_END_SET: TokenSet<YamlSyntaxKind> = token_set![T![,], T![']'], T!['}'], EOF];
+
+/// Parses a value of a flow collection: a scalar or a flow collection
+pub(crate) fn parse_flow_value(p: &mut YamlParser) -> ParsedSyntax {
+    match p.cur() {
+        T!['['] => parse_flow_sequence(p),
+        T!['{'] => parse_flow_mapping(p),
+        _ => parse_scalar(p),
+    }
+}
+
+/// Parses a flow sequence: `[a, b]`
+fn parse_flow_sequence(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T!['[']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['[']);
+
+    let items = p.start();
+    while !p.at(T![']']) && !p.at(EOF) {
+        if parse_flow_value(p).is_absent() {
+            let bogus = p.start();
+            let range = skip_flow_entry(p);
+            p.error(expected_value(p, range));
+            bogus.complete(p, YAML_BOGUS_VALUE);
+        }
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+    items.complete(p, YAML_ARRAY_INLINE_LIST);
+
+    p.expect(T![']']);
+
+    Present(m.complete(p, YAML_ARRAY_INLINE))
+}
+
+/// Parses a flow mapping: `{ a: b }`
+///
+/// The whole mapping is bogus when one of its entries isn't a key-value pair.
+fn parse_flow_mapping(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+
+    let members = p.start();
+    let mut is_valid = true;
+    while !p.at(T!['}']) && !p.at(EOF) {
+        if p.at(YAML_IDENTIFIER) {
+            parse_flow_mapping_member(p);
+        } else {
+            is_valid = false;
+            let range = skip_flow_entry(p);
+            p.error(expected_mapping_entry(p, range));
+        }
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+    members.complete(p, YAML_OBJECT_INLINE_MEMBER_LIST);
+
+    p.expect(T!['}']);
+
+    let kind = if is_valid {
+        YAML_OBJECT_INLINE
+    } else {
+        YAML_BOGUS_VALUE
+    };
+    Present(m.complete(p, kind))
+}
+
+fn parse_flow_mapping_member(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    parse_identifier(p).ok();
+    p.expect(T![:]);
+    parse_flow_value(p).ok();
+
+    m.complete(p, YAML_OBJECT_MEMBER)
+}
+
+/// Skips the tokens up to the end of the current entry of a flow collection, and returns their
+/// range
+fn skip_flow_entry(p: &mut YamlParser) -> TextRange {
+    let start = p.cur_range().start();
+    let mut end = start;
+    while !p.at_ts(FLOW_ENTRY_END_SET) {
+        end = p.cur_range().end();
+        p.bump_any();
+    }
+    if start == end {
+        p.cur_range()
+    } else {
+        TextRange::new(start, end)
+    }
+}
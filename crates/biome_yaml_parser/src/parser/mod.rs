@@ -0,0 +1,120 @@
+mod block;
+mod flow;
+mod parse_error;
+mod value;
+
+use crate::token_source::YamlTokenSource;
+use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::event::Event;
+use biome_parser::prelude::{ParsedSyntax::*, *};
+use biome_parser::token_source::Trivia;
+use biome_parser::ParserContext;
+use biome_rowan::TextRange;
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::T;
+use parse_error::unexpected_content;
+use value::parse_any_value;
+
+pub(crate) struct YamlParser<'source> {
+    context: ParserContext<YamlSyntaxKind>,
+    source: YamlTokenSource<'source>,
+}
+
+impl<'source> YamlParser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            context: ParserContext::default(),
+            source: YamlTokenSource::from_str(source),
+        }
+    }
+
+    /// Returns the column of the current token
+    pub fn cur_column(&self) -> usize {
+        self.source.current_column()
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        Vec<Event<YamlSyntaxKind>>,
+        Vec<ParseDiagnostic>,
+        Vec<Trivia>,
+    ) {
+        let (trivia, lexer_diagnostics) = self.source.finish();
+        let (events, parse_diagnostics) = self.context.finish();
+
+        let diagnostics = merge_diagnostics(lexer_diagnostics, parse_diagnostics);
+
+        (events, diagnostics, trivia)
+    }
+}
+
+impl<'source> Parser for YamlParser<'source> {
+    type Kind = YamlSyntaxKind;
+    type Source = YamlTokenSource<'source>;
+
+    fn context(&self) -> &ParserContext<Self::Kind> {
+        &self.context
+    }
+
+    fn context_mut(&mut self) -> &mut ParserContext<Self::Kind> {
+        &mut self.context
+    }
+
+    fn source(&self) -> &Self::Source {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+pub(crate) fn parse_root(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(UNICODE_BOM);
+
+    let documents = p.start();
+    while !p.at(EOF) {
+        parse_document(p);
+    }
+    documents.complete(p, YAML_DOCUMENT_LIST);
+
+    p.expect(EOF);
+
+    m.complete(p, YAML_ROOT)
+}
+
+/// Parses a document, which ends at the end of the file, at the next `---`, or at a `...`
+fn parse_document(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(T![---]);
+
+    let body = parse_any_value(p);
+    if !is_at_document_end(p) {
+        // The content that doesn't belong to the body, such as a scalar after a mapping, makes
+        // the whole body invalid
+        let bogus = match body {
+            Present(body) => body.precede(p),
+            Absent => p.start(),
+        };
+        let start = p.cur_range().start();
+        let mut end = start;
+        while !is_at_document_end(p) {
+            end = p.cur_range().end();
+            p.bump_any();
+        }
+        p.error(unexpected_content(p, TextRange::new(start, end)));
+        bogus.complete(p, YAML_BOGUS_VALUE);
+    }
+
+    p.eat(T![...]);
+
+    m.complete(p, YAML_DOCUMENT)
+}
+
+fn is_at_document_end(p: &YamlParser) -> bool {
+    p.at(EOF) || p.at(T![---]) || p.at(T![...])
+}
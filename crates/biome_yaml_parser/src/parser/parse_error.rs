@@ -0,0 +1,20 @@
+use crate::parser::YamlParser;
+use biome_parser::diagnostic::{expected_node, ParseDiagnostic};
+use biome_parser::Parser;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_value(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("value", range, p)
+}
+
+pub(crate) fn expected_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key-value pair", range, p)
+}
+
+pub(crate) fn unexpected_content(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "Unexpected content, it doesn't belong to the value that precedes it.",
+        range,
+    )
+    .with_hint("Check the indentation of the content.")
+}
@@ -0,0 +1,67 @@
+use super::block::{parse_block_mapping, parse_block_sequence};
+use super::flow::parse_flow_value;
+use super::YamlParser;
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_yaml_syntax::YamlSyntaxKind::*;
+use biome_yaml_syntax::T;
+
+/// Parses any value: a block collection, a block scalar, a flow collection or a scalar
+pub(crate) fn parse_any_value(p: &mut YamlParser) -> ParsedSyntax {
+    match p.cur() {
+        T![-] => parse_block_sequence(p),
+        YAML_IDENTIFIER => parse_block_mapping(p),
+        _ => parse_inline_value(p),
+    }
+}
+
+/// Parses a value that can start on the line of its key: a block scalar, a flow collection or
+/// a scalar
+pub(crate) fn parse_inline_value(p: &mut YamlParser) -> ParsedSyntax {
+    match p.cur() {
+        T![|] | T![>] => parse_block_scalar(p),
+        _ => parse_flow_value(p),
+    }
+}
+
+pub(crate) fn parse_scalar(p: &mut YamlParser) -> ParsedSyntax {
+    let kind = p.cur();
+    if !matches!(
+        kind,
+        YAML_STRING_VALUE | YAML_NUMBER_VALUE | YAML_BOOLEAN_VALUE | YAML_NULL_VALUE
+    ) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(kind);
+    Present(m.complete(p, kind))
+}
+
+pub(crate) fn parse_identifier(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(YAML_IDENTIFIER) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(YAML_IDENTIFIER);
+    Present(m.complete(p, YAML_IDENTIFIER))
+}
+
+/// Parses a literal block scalar, `|`, or a folded block scalar, `>`
+fn parse_block_scalar(p: &mut YamlParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        T![|] => YAML_BLOCK_LITERAL,
+        T![>] => YAML_BLOCK_FOLDED,
+        _ => return Absent,
+    };
+
+    let m = p.start();
+    p.bump_any();
+    if p.at(YAML_BLOCK_VALUE) {
+        let content = p.start();
+        p.bump(YAML_BLOCK_VALUE);
+        content.complete(p, YAML_BLOCK_VALUE);
+    }
+    Present(m.complete(p, kind))
+}
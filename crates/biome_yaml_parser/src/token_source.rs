@@ -0,0 +1,125 @@
+use crate::lexer::{YamlLexContext, YamlLexer};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::BufferedLexer;
+use biome_parser::prelude::TokenSource;
+use biome_parser::token_source::{TokenSourceWithBufferedLexer, Trivia};
+use biome_rowan::TriviaPieceKind;
+use biome_yaml_syntax::YamlSyntaxKind::{EOF, UNICODE_BOM};
+use biome_yaml_syntax::{TextRange, YamlSyntaxKind};
+
+pub(crate) struct YamlTokenSource<'source> {
+    lexer: BufferedLexer<YamlSyntaxKind, YamlLexer<'source>>,
+    trivia_list: Vec<Trivia>,
+
+    /// `true` if the current token is the first token of its line
+    preceding_line_break: bool,
+}
+
+impl<'source> YamlTokenSource<'source> {
+    pub(crate) fn new(lexer: BufferedLexer<YamlSyntaxKind, YamlLexer<'source>>) -> Self {
+        Self {
+            lexer,
+            trivia_list: Vec::new(),
+            preceding_line_break: true,
+        }
+    }
+
+    pub fn from_str(source: &'source str) -> Self {
+        let lexer = YamlLexer::from_str(source);
+        let lexer = BufferedLexer::new(lexer);
+
+        let mut source = YamlTokenSource::new(lexer);
+
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        let mut trailing = !first_token;
+        self.preceding_line_break = first_token || self.current() == UNICODE_BOM;
+
+        loop {
+            let kind = self.lexer.next_token(YamlLexContext::Regular);
+
+            let trivia_kind = TriviaPieceKind::try_from(kind);
+
+            match trivia_kind {
+                Err(_) => {
+                    // Not trivia
+                    break;
+                }
+                Ok(trivia_kind) => {
+                    if trivia_kind.is_newline() {
+                        trailing = false;
+                        self.preceding_line_break = true;
+                    }
+
+                    self.trivia_list
+                        .push(Trivia::new(trivia_kind, self.current_range(), trailing));
+                }
+            }
+        }
+    }
+
+    /// Returns the column of the current token.
+    ///
+    /// The columns are the block indentation of YAML: they decide which mapping or sequence
+    /// contains the token.
+    pub fn current_column(&self) -> usize {
+        let text = self.text();
+        let start = usize::from(self.current_range().start());
+        let line_start = text[..start].rfind(['\n', '\r']).map_or_else(
+            || text.len() - text.trim_start_matches('\u{feff}').len(),
+            |index| index + 1,
+        );
+        start - line_start
+    }
+}
+
+impl<'source> TokenSource for YamlTokenSource<'source> {
+    type Kind = YamlSyntaxKind;
+
+    fn current(&self) -> Self::Kind {
+        self.lexer.current()
+    }
+
+    fn current_range(&self) -> TextRange {
+        self.lexer.current_range()
+    }
+
+    fn text(&self) -> &str {
+        self.lexer.source()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.preceding_line_break
+    }
+
+    fn bump(&mut self) {
+        if self.current() != EOF {
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn skip_as_trivia(&mut self) {
+        if self.current() != EOF {
+            self.trivia_list.push(Trivia::new(
+                TriviaPieceKind::Skipped,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia_list, self.lexer.finish())
+    }
+}
+
+impl<'source> TokenSourceWithBufferedLexer<YamlLexer<'source>> for YamlTokenSource<'source> {
+    fn lexer(&mut self) -> &mut BufferedLexer<YamlSyntaxKind, YamlLexer<'source>> {
+        &mut self.lexer
+    }
+}
@@ -52,6 +52,8 @@ pub enum YamlSyntaxKind {
     YAML_OBJECT,
     YAML_OBJECT_MEMBER,
     YAML_OBJECT_MEMBER_LIST,
+    YAML_OBJECT_INLINE,
+    YAML_OBJECT_INLINE_MEMBER_LIST,
     YAML_ARRAY,
     YAML_ARRAY_ITEM,
     YAML_ARRAY_ITEM_LIST,
@@ -84,6 +86,7 @@ impl YamlSyntaxKind {
             YAML_DOCUMENT_LIST
             | YAML_ARRAY_INLINE_LIST
             | YAML_OBJECT_MEMBER_LIST
+            | YAML_OBJECT_INLINE_MEMBER_LIST
             | YAML_ARRAY_ITEM_LIST => true,
             _ => false,
         }
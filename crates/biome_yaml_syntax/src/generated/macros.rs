@@ -64,6 +64,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlObject::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_OBJECT_INLINE => {
+                    let $pattern = unsafe { $crate::YamlObjectInline::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_OBJECT_MEMBER => {
                     let $pattern = unsafe { $crate::YamlObjectMember::new_unchecked(node) };
                     $body
@@ -96,6 +100,11 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlDocumentList::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_OBJECT_INLINE_MEMBER_LIST => {
+                    let $pattern =
+                        unsafe { $crate::YamlObjectInlineMemberList::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_OBJECT_MEMBER_LIST => {
                     let $pattern = unsafe { $crate::YamlObjectMemberList::new_unchecked(node) };
                     $body
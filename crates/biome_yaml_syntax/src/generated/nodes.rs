@@ -125,8 +125,8 @@ impl YamlArrayItem {
     pub fn minus_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn item(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn item(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlArrayItem {
@@ -140,7 +140,7 @@ impl Serialize for YamlArrayItem {
 #[derive(Serialize)]
 pub struct YamlArrayItemFields {
     pub minus_token: SyntaxResult<SyntaxToken>,
-    pub item: SyntaxResult<AnyYamlValue>,
+    pub item: Option<AnyYamlValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockFolded {
@@ -159,14 +159,14 @@ impl YamlBlockFolded {
     pub fn as_fields(&self) -> YamlBlockFoldedFields {
         YamlBlockFoldedFields {
             r_angle_token: self.r_angle_token(),
-            value: self.value(),
+            content: self.content(),
         }
     }
     pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<YamlBlockValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn content(&self) -> Option<YamlBlockValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlBlockFolded {
@@ -180,7 +180,7 @@ impl Serialize for YamlBlockFolded {
 #[derive(Serialize)]
 pub struct YamlBlockFoldedFields {
     pub r_angle_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<YamlBlockValue>,
+    pub content: Option<YamlBlockValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockLiteral {
@@ -199,14 +199,14 @@ impl YamlBlockLiteral {
     pub fn as_fields(&self) -> YamlBlockLiteralFields {
         YamlBlockLiteralFields {
             bitwise_or_token: self.bitwise_or_token(),
-            value: self.value(),
+            content: self.content(),
         }
     }
     pub fn bitwise_or_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<YamlBlockValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn content(&self) -> Option<YamlBlockValue> {
+        support::node(&self.syntax, 1usize)
     }
 }
 impl Serialize for YamlBlockLiteral {
@@ -220,7 +220,7 @@ impl Serialize for YamlBlockLiteral {
 #[derive(Serialize)]
 pub struct YamlBlockLiteralFields {
     pub bitwise_or_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<YamlBlockValue>,
+    pub content: Option<YamlBlockValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockValue {
@@ -238,10 +238,10 @@ impl YamlBlockValue {
     }
     pub fn as_fields(&self) -> YamlBlockValueFields {
         YamlBlockValueFields {
-            yaml_block_value_token: self.yaml_block_value_token(),
+            value_token: self.value_token(),
         }
     }
-    pub fn yaml_block_value_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
 }
@@ -255,7 +255,7 @@ impl Serialize for YamlBlockValue {
 }
 #[derive(Serialize)]
 pub struct YamlBlockValueFields {
-    pub yaml_block_value_token: SyntaxResult<SyntaxToken>,
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBooleanValue {
@@ -316,8 +316,8 @@ impl YamlDocument {
     pub fn dashdashdash_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, 0usize)
     }
-    pub fn body(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn body(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 1usize)
     }
     pub fn dotdotdot_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, 2usize)
@@ -334,7 +334,7 @@ impl Serialize for YamlDocument {
 #[derive(Serialize)]
 pub struct YamlDocumentFields {
     pub dashdashdash_token: Option<SyntaxToken>,
-    pub body: SyntaxResult<AnyYamlValue>,
+    pub body: Option<AnyYamlValue>,
     pub dotdotdot_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -478,6 +478,51 @@ pub struct YamlObjectFields {
     pub members: YamlObjectMemberList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlObjectInline {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlObjectInline {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlObjectInlineFields {
+        YamlObjectInlineFields {
+            l_curly_token: self.l_curly_token(),
+            members: self.members(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn members(&self) -> YamlObjectInlineMemberList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+impl Serialize for YamlObjectInline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct YamlObjectInlineFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub members: YamlObjectInlineMemberList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlObjectMember {
     pub(crate) syntax: SyntaxNode,
 }
@@ -504,8 +549,8 @@ impl YamlObjectMember {
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn value(&self) -> SyntaxResult<AnyYamlValue> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn value(&self) -> Option<AnyYamlValue> {
+        support::node(&self.syntax, 2usize)
     }
 }
 impl Serialize for YamlObjectMember {
@@ -520,7 +565,7 @@ impl Serialize for YamlObjectMember {
 pub struct YamlObjectMemberFields {
     pub key: SyntaxResult<YamlIdentifier>,
     pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<AnyYamlValue>,
+    pub value: Option<AnyYamlValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlRoot {
@@ -640,8 +685,11 @@ pub enum AnyYamlValue {
     AnyYamlScalar(AnyYamlScalar),
     YamlArray(YamlArray),
     YamlArrayInline(YamlArrayInline),
+    YamlBlockFolded(YamlBlockFolded),
+    YamlBlockLiteral(YamlBlockLiteral),
     YamlBogusValue(YamlBogusValue),
     YamlObject(YamlObject),
+    YamlObjectInline(YamlObjectInline),
 }
 impl AnyYamlValue {
     pub fn as_any_yaml_scalar(&self) -> Option<&AnyYamlScalar> {
@@ -662,6 +710,18 @@ impl AnyYamlValue {
             _ => None,
         }
     }
+    pub fn as_yaml_block_folded(&self) -> Option<&YamlBlockFolded> {
+        match &self {
+            AnyYamlValue::YamlBlockFolded(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_block_literal(&self) -> Option<&YamlBlockLiteral> {
+        match &self {
+            AnyYamlValue::YamlBlockLiteral(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_bogus_value(&self) -> Option<&YamlBogusValue> {
         match &self {
             AnyYamlValue::YamlBogusValue(item) => Some(item),
@@ -674,6 +734,12 @@ impl AnyYamlValue {
             _ => None,
         }
     }
+    pub fn as_yaml_object_inline(&self) -> Option<&YamlObjectInline> {
+        match &self {
+            AnyYamlValue::YamlObjectInline(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 impl AstNode for YamlArray {
     type Language = Language;
@@ -787,7 +853,7 @@ impl std::fmt::Debug for YamlArrayItem {
                 "minus_token",
                 &support::DebugSyntaxResult(self.minus_token()),
             )
-            .field("item", &support::DebugSyntaxResult(self.item()))
+            .field("item", &support::DebugOptionalElement(self.item()))
             .finish()
     }
 }
@@ -829,7 +895,7 @@ impl std::fmt::Debug for YamlBlockFolded {
                 "r_angle_token",
                 &support::DebugSyntaxResult(self.r_angle_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("content", &support::DebugOptionalElement(self.content()))
             .finish()
     }
 }
@@ -871,7 +937,7 @@ impl std::fmt::Debug for YamlBlockLiteral {
                 "bitwise_or_token",
                 &support::DebugSyntaxResult(self.bitwise_or_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("content", &support::DebugOptionalElement(self.content()))
             .finish()
     }
 }
@@ -910,8 +976,8 @@ impl std::fmt::Debug for YamlBlockValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("YamlBlockValue")
             .field(
-                "yaml_block_value_token",
-                &support::DebugSyntaxResult(self.yaml_block_value_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
@@ -995,7 +1061,7 @@ impl std::fmt::Debug for YamlDocument {
                 "dashdashdash_token",
                 &support::DebugOptionalElement(self.dashdashdash_token()),
             )
-            .field("body", &support::DebugSyntaxResult(self.body()))
+            .field("body", &support::DebugOptionalElement(self.body()))
             .field(
                 "dotdotdot_token",
                 &support::DebugOptionalElement(self.dotdotdot_token()),
@@ -1174,6 +1240,52 @@ impl From<YamlObject> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for YamlObjectInline {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_OBJECT_INLINE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_OBJECT_INLINE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlObjectInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlObjectInline")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("members", &self.members())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlObjectInline> for SyntaxNode {
+    fn from(n: YamlObjectInline) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlObjectInline> for SyntaxElement {
+    fn from(n: YamlObjectInline) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
 impl AstNode for YamlObjectMember {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -1203,7 +1315,7 @@ impl std::fmt::Debug for YamlObjectMember {
                 "colon_token",
                 &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &support::DebugOptionalElement(self.value()))
             .finish()
     }
 }
@@ -1396,6 +1508,16 @@ impl From<YamlArrayInline> for AnyYamlValue {
         AnyYamlValue::YamlArrayInline(node)
     }
 }
+impl From<YamlBlockFolded> for AnyYamlValue {
+    fn from(node: YamlBlockFolded) -> AnyYamlValue {
+        AnyYamlValue::YamlBlockFolded(node)
+    }
+}
+impl From<YamlBlockLiteral> for AnyYamlValue {
+    fn from(node: YamlBlockLiteral) -> AnyYamlValue {
+        AnyYamlValue::YamlBlockLiteral(node)
+    }
+}
 impl From<YamlBogusValue> for AnyYamlValue {
     fn from(node: YamlBogusValue) -> AnyYamlValue {
         AnyYamlValue::YamlBogusValue(node)
@@ -1406,16 +1528,25 @@ impl From<YamlObject> for AnyYamlValue {
         AnyYamlValue::YamlObject(node)
     }
 }
+impl From<YamlObjectInline> for AnyYamlValue {
+    fn from(node: YamlObjectInline) -> AnyYamlValue {
+        AnyYamlValue::YamlObjectInline(node)
+    }
+}
 impl AstNode for AnyYamlValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyYamlScalar::KIND_SET
         .union(YamlArray::KIND_SET)
         .union(YamlArrayInline::KIND_SET)
+        .union(YamlBlockFolded::KIND_SET)
+        .union(YamlBlockLiteral::KIND_SET)
         .union(YamlBogusValue::KIND_SET)
-        .union(YamlObject::KIND_SET);
+        .union(YamlObject::KIND_SET)
+        .union(YamlObjectInline::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            YAML_ARRAY | YAML_ARRAY_INLINE | YAML_BOGUS_VALUE | YAML_OBJECT => true,
+            YAML_ARRAY | YAML_ARRAY_INLINE | YAML_BLOCK_FOLDED | YAML_BLOCK_LITERAL
+            | YAML_BOGUS_VALUE | YAML_OBJECT | YAML_OBJECT_INLINE => true,
             k if AnyYamlScalar::can_cast(k) => true,
             _ => false,
         }
@@ -1424,8 +1555,11 @@ impl AstNode for AnyYamlValue {
         let res = match syntax.kind() {
             YAML_ARRAY => AnyYamlValue::YamlArray(YamlArray { syntax }),
             YAML_ARRAY_INLINE => AnyYamlValue::YamlArrayInline(YamlArrayInline { syntax }),
+            YAML_BLOCK_FOLDED => AnyYamlValue::YamlBlockFolded(YamlBlockFolded { syntax }),
+            YAML_BLOCK_LITERAL => AnyYamlValue::YamlBlockLiteral(YamlBlockLiteral { syntax }),
             YAML_BOGUS_VALUE => AnyYamlValue::YamlBogusValue(YamlBogusValue { syntax }),
             YAML_OBJECT => AnyYamlValue::YamlObject(YamlObject { syntax }),
+            YAML_OBJECT_INLINE => AnyYamlValue::YamlObjectInline(YamlObjectInline { syntax }),
             _ => {
                 if let Some(any_yaml_scalar) = AnyYamlScalar::cast(syntax) {
                     return Some(AnyYamlValue::AnyYamlScalar(any_yaml_scalar));
@@ -1439,8 +1573,11 @@ impl AstNode for AnyYamlValue {
         match self {
             AnyYamlValue::YamlArray(it) => &it.syntax,
             AnyYamlValue::YamlArrayInline(it) => &it.syntax,
+            AnyYamlValue::YamlBlockFolded(it) => &it.syntax,
+            AnyYamlValue::YamlBlockLiteral(it) => &it.syntax,
             AnyYamlValue::YamlBogusValue(it) => &it.syntax,
             AnyYamlValue::YamlObject(it) => &it.syntax,
+            AnyYamlValue::YamlObjectInline(it) => &it.syntax,
             AnyYamlValue::AnyYamlScalar(it) => it.syntax(),
         }
     }
@@ -1448,8 +1585,11 @@ impl AstNode for AnyYamlValue {
         match self {
             AnyYamlValue::YamlArray(it) => it.syntax,
             AnyYamlValue::YamlArrayInline(it) => it.syntax,
+            AnyYamlValue::YamlBlockFolded(it) => it.syntax,
+            AnyYamlValue::YamlBlockLiteral(it) => it.syntax,
             AnyYamlValue::YamlBogusValue(it) => it.syntax,
             AnyYamlValue::YamlObject(it) => it.syntax,
+            AnyYamlValue::YamlObjectInline(it) => it.syntax,
             AnyYamlValue::AnyYamlScalar(it) => it.into_syntax(),
         }
     }
@@ -1460,8 +1600,11 @@ impl std::fmt::Debug for AnyYamlValue {
             AnyYamlValue::AnyYamlScalar(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlArray(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlArrayInline(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlBlockFolded(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlBlockLiteral(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlBogusValue(it) => std::fmt::Debug::fmt(it, f),
             AnyYamlValue::YamlObject(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlValue::YamlObjectInline(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1471,8 +1614,11 @@ impl From<AnyYamlValue> for SyntaxNode {
             AnyYamlValue::AnyYamlScalar(it) => it.into(),
             AnyYamlValue::YamlArray(it) => it.into(),
             AnyYamlValue::YamlArrayInline(it) => it.into(),
+            AnyYamlValue::YamlBlockFolded(it) => it.into(),
+            AnyYamlValue::YamlBlockLiteral(it) => it.into(),
             AnyYamlValue::YamlBogusValue(it) => it.into(),
             AnyYamlValue::YamlObject(it) => it.into(),
+            AnyYamlValue::YamlObjectInline(it) => it.into(),
         }
     }
 }
@@ -1552,6 +1698,11 @@ impl std::fmt::Display for YamlObject {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for YamlObjectInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for YamlObjectMember {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1733,7 +1884,7 @@ impl Serialize for YamlArrayInlineList {
 }
 impl AstSeparatedList for YamlArrayInlineList {
     type Language = Language;
-    type Node = AnyYamlScalar;
+    type Node = AnyYamlValue;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -1748,15 +1899,15 @@ impl Debug for YamlArrayInlineList {
     }
 }
 impl IntoIterator for YamlArrayInlineList {
-    type Item = SyntaxResult<AnyYamlScalar>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlScalar>;
+    type Item = SyntaxResult<AnyYamlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 impl IntoIterator for &YamlArrayInlineList {
-    type Item = SyntaxResult<AnyYamlScalar>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlScalar>;
+    type Item = SyntaxResult<AnyYamlValue>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlValue>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -1926,6 +2077,88 @@ impl IntoIterator for YamlDocumentList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlObjectInlineMemberList {
+    syntax_list: SyntaxList,
+}
+impl YamlObjectInlineMemberList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlObjectInlineMemberList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_OBJECT_INLINE_MEMBER_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_OBJECT_INLINE_MEMBER_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlObjectInlineMemberList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlObjectInlineMemberList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for YamlObjectInlineMemberList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for YamlObjectInlineMemberList {
+    type Language = Language;
+    type Node = YamlObjectMember;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for YamlObjectInlineMemberList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlObjectInlineMemberList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for YamlObjectInlineMemberList {
+    type Item = SyntaxResult<YamlObjectMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, YamlObjectMember>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &YamlObjectInlineMemberList {
+    type Item = SyntaxResult<YamlObjectMember>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, YamlObjectMember>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct YamlObjectMemberList {
     syntax_list: SyntaxList,
 }
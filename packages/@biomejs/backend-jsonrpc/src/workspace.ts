@@ -3,6 +3,7 @@ import type { Transport } from "./transport";
 export interface SupportsFeatureParams {
 	features: FeatureName;
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export type FeatureName = FeatureKind[];
 export interface BiomePath {
@@ -42,6 +43,7 @@ export interface FileFeaturesResult {
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
 	gitignore_matches: string[];
+	project_key?: ProjectKey;
 	vcs_base_path?: string;
 	workspace_directory?: string;
 }
@@ -77,6 +79,10 @@ export interface PartialConfiguration {
 	 * Specific configuration for the GraphQL language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * Specific configuration for the JavaScript language
 	 */
@@ -89,6 +95,10 @@ export interface PartialConfiguration {
 	 * The configuration for the linter
 	 */
 	linter?: PartialLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: PartialMarkdownConfiguration;
 	/**
 	 * The configuration of the import sorting
 	 */
@@ -97,10 +107,18 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Specific configuration for the TOML language
+	 */
+	toml?: PartialTomlConfiguration;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: PartialVcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: PartialYamlConfiguration;
 }
 export interface PartialAssistsConfiguration {
 	/**
@@ -208,6 +226,10 @@ export interface PartialFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of consecutive empty lines that are kept between the statements, the class members and the object members. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
 	/**
 	 * Use any `.editorconfig` files to configure the formatter. Configuration in `biome.json` will override `.editorconfig` configuration. Default: false.
 	 */
@@ -223,6 +245,15 @@ export interface PartialGraphqlConfiguration {
 	formatter?: PartialGraphqlFormatter;
 	linter?: PartialGraphqlLinter;
 }
+/**
+ * Options applied to HTML files
+ */
+export interface PartialHtmlConfiguration {
+	/**
+	 * HTML formatter options
+	 */
+	formatter?: PartialHtmlFormatter;
+}
 /**
  * A set of options applied to the JavaScript files
  */
@@ -254,6 +285,12 @@ If defined here, they should not emit diagnostics.
 	 * Parsing options
 	 */
 	parser?: PartialJavascriptParser;
+	/**
+	* The environments targeted by the JavaScript files, written as browserslist queries, such as `chrome >= 100` or `node >= 18`.
+
+When it's defined, it takes precedence over the `.browserslistrc` files, and over the `browserslist` and `engines` fields of the `package.json` manifest. 
+	 */
+	target?: StringSet;
 }
 /**
  * Options applied to JSON files
@@ -289,11 +326,24 @@ export interface PartialLinterConfiguration {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * Whether the suppression comments that disable all the lint rules, such as `// biome-ignore lint: <explanation>`, or all the rules of a group, such as `// biome-ignore lint/suspicious: <explanation>`, are reported. `false` by default
+	 */
+	reportBroadSuppressions?: boolean;
 	/**
 	 * List of rules
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface PartialMarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: PartialMarkdownFormatter;
+}
 export interface PartialOrganizeImports {
 	/**
 	 * Enables the organization of imports
@@ -309,6 +359,19 @@ export interface PartialOrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+/**
+ * Options applied to TOML files
+ */
+export interface PartialTomlConfiguration {
+	/**
+	 * TOML formatter options
+	 */
+	formatter?: PartialTomlFormatter;
+	/**
+	 * TOML linter options
+	 */
+	linter?: PartialTomlLinter;
+}
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -336,6 +399,19 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface PartialYamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: PartialYamlFormatter;
+	/**
+	 * YAML linter options
+	 */
+	linter?: PartialYamlLinter;
+}
 export interface Actions {
 	source?: Source;
 }
@@ -373,7 +449,11 @@ export interface PartialCssFormatter {
 	 */
 	lineWidth?: LineWidth;
 	/**
-	 * The type of quotes used in CSS code. Defaults to double.
+	 * The maximum number of consecutive empty lines that are kept in CSS (and its super languages) files. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
+	/**
+	 * The type of quotes used in CSS code. Defaults to double. `preserve` keeps the quotes of each string.
 	 */
 	quoteStyle?: QuoteStyle;
 }
@@ -395,7 +475,7 @@ export interface PartialCssParser {
 	 */
 	allowWrongLineComments?: boolean;
 	/**
-	 * Enables parsing of CSS Modules specific features.
+	 * Enables parsing of CSS Modules specific features. When it isn't set, it's enabled in the files whose name ends with `.module.css`.
 	 */
 	cssModules?: boolean;
 }
@@ -410,6 +490,12 @@ export type LineEnding = "lf" | "crlf" | "cr";
 The allowed range of values is 1..=320 
 	 */
 export type LineWidth = number;
+/**
+	* Validated value for the `max_empty_lines` formatter options: the maximum number of consecutive empty lines that are kept between the statements and the members.
+
+The allowed range of values is 0..=20 
+	 */
+export type MaxEmptyLines = number;
 /**
  * Options that changes how the GraphQL formatter behaves
  */
@@ -451,6 +537,57 @@ export interface PartialGraphqlLinter {
 	 * Control the formatter for GraphQL files.
 	 */
 	enabled?: boolean;
+	/**
+	* Path to the file that describes the GraphQL schema of the project.
+
+It's either an SDL file, or a JSON file with the result of an introspection query. Rules that check documents against the schema are only run when it's set. 
+	 */
+	schema?: string;
+}
+/**
+ * Options that changes how the HTML formatter behaves
+ */
+export interface PartialHtmlFormatter {
+	/**
+	 * Whether to hug the closing bracket of the HTML tags whose attributes don't fit in the line width to the end of the last attribute, rather than being alone on the following line. Defaults to false.
+	 */
+	bracketSameLine?: boolean;
+	/**
+	 * Whether the elements that only contain whitespace, like `<div> </div>`, are collapsed. When it's false, the whitespace is kept as a space or as a line break. Defaults to true.
+	 */
+	collapseEmptyElements?: boolean;
+	/**
+	 * Control the formatter for HTML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to HTML files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to HTML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to HTML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to HTML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of attributes of an HTML element printed on a single line. The attributes of the elements that have more attributes are each printed on their own line, even if they fit in the line width. Unlimited by default.
+	 */
+	maxInlineAttributes?: number;
+	/**
+	 * Whether the HTML elements that have more than one attribute print each attribute on its own line, even if the element fits in the line width. Defaults to false.
+	 */
+	singleAttributePerLine?: boolean;
+	/**
+	 * Whether the whitespace around and inside the elements is significant: "css" follows the default CSS display of the elements, so only the whitespace around and inside the inline elements, such as `<span>`, is significant; "strict" treats the whitespace around and inside all the elements as significant; "ignore" treats no whitespace as significant. Defaults to "css".
+	 */
+	whitespaceSensitivity?: WhitespaceSensitivity;
 }
 /**
  * Linter options specific to the JavaScript linter
@@ -481,10 +618,22 @@ export interface PartialJavascriptFormatter {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: BracketSpacing;
+	/**
+	 * Whether the comments that exceed the line width are wrapped: "preserve" prints the comments as they are in the source, and "wrap" wraps the lines of the `//` comments and of the block comments whose lines start with `*`, when they start on their own line. The leading, trailing and dangling comments are all wrapped, but a comment that follows code on the same line is never wrapped. The directive comments, such as suppression comments, are never wrapped. Defaults to "preserve".
+	 */
+	commentReflow?: CommentReflow;
+	/**
+	 * Where the decorators of class members and parameters are printed. Defaults to "preserve".
+	 */
+	decoratorPlacement?: DecoratorPlacement;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * How the lists of named imports and exports are wrapped: "auto" prints them on a single line when they fit in the line width, and "per-line" prints each specifier on its own line. Defaults to "auto".
+	 */
+	importExportWrap?: ImportExportWrap;
 	/**
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
@@ -498,7 +647,15 @@ export interface PartialJavascriptFormatter {
 	 */
 	indentWidth?: IndentWidth;
 	/**
-	 * The type of quotes used in JSX. Defaults to double.
+	 * Whether the lines of JSX children can break at the whitespace between a text and a tag: "break" breaks the line there when it exceeds the line width, and inserts `{" "}` at the end of the line to keep the whitespace, and "glue" keeps the text on the line of the tag, and only breaks the lines between the words of the text. Defaults to "break".
+	 */
+	jsxChildrenWhitespace?: JsxChildrenWhitespace;
+	/**
+	 * The maximum number of attributes of a JSX element printed on a single line. The attributes of the elements that have more attributes are each printed on their own line, even if they fit in the line width. Unlimited by default.
+	 */
+	jsxMaxInlineAttributes?: number;
+	/**
+	 * The type of quotes used in JSX. Defaults to double. `preserve` keeps the quotes of each string.
 	 */
 	jsxQuoteStyle?: QuoteStyle;
 	/**
@@ -509,18 +666,46 @@ export interface PartialJavascriptFormatter {
 	 * What's the max width of a line applied to JavaScript (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of consecutive empty lines that are kept in JavaScript (and its super languages) files. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
+	/**
+	 * The maximum number of named imports or exports printed on a single line. The lists that have more specifiers print each of them on its own line, even if they fit in the line width. Unlimited by default.
+	 */
+	maxInlineSpecifiers?: number;
+	/**
+	 * Whether the objects that have a line break between the `{` and their first member stay expanded, even if they fit in the line width. Defaults to "preserve".
+	 */
+	objectWrap?: ObjectWrap;
+	/**
+	 * Whether the operators of the binary and logical expressions are printed at the end of the line or at the start of the next line, when the expressions don't fit in the line width. Defaults to "after".
+	 */
+	operatorLinebreak?: OperatorLinebreak;
+	/**
+	 * Whether the decorators of the parameters break the list of parameters: "auto" follows `decoratorPlacement`, "inline" keeps the decorators on the line of their parameter and only breaks the parameters when they don't fit in the line width, and "break" prints each parameter of a function that has a decorated parameter on its own line. Defaults to "auto".
+	 */
+	parameterDecorators?: ParameterDecorators;
 	/**
 	 * When properties in objects are quoted. Defaults to asNeeded.
 	 */
 	quoteProperties?: QuoteProperties;
 	/**
-	 * The type of quotes used in JavaScript code. Defaults to double.
+	 * The type of quotes used in JavaScript code. Defaults to double. `preserve` keeps the quotes of each string.
 	 */
 	quoteStyle?: QuoteStyle;
 	/**
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	 * Whether the JSX elements that have more than one attribute print each attribute on its own line, even if the element fits in the line width. Defaults to false.
+	 */
+	singleAttributePerLine?: boolean;
+	/**
+	 * How the chains of nested conditional expressions are printed when they don't fit on a single line: "indent" indents each nested conditional, and "case" prints each test on the same line as its consequent. Defaults to "indent".
+	 */
+	ternaryStyle?: TernaryStyle;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
@@ -593,6 +778,10 @@ export interface PartialJsonFormatter {
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of consecutive empty lines that are kept in JSON (and its super languages) files. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
 	 */
@@ -615,10 +804,20 @@ export interface PartialJsonParser {
 	 * Allow parsing comments in `.json` files
 	 */
 	allowComments?: boolean;
+	/**
+	 * Allow parsing the JSON5 syntax in `.json` files, such as unquoted keys and single-quoted strings. It also allows comments and trailing commas.
+	 */
+	allowJson5?: boolean;
 	/**
 	 * Allow parsing trailing commas in `.json` files
 	 */
 	allowTrailingCommas?: boolean;
+	/**
+	* Additional files that are parsed as JSON, mapped to the parser options they accept, such as `{ ".eslintrc": { "allowComments": true } }`.
+
+The names are matched against the file name, and they take precedence over the built-in list of well-known files, such as `tsconfig.json`. 
+	 */
+	wellKnownFiles?: {};
 }
 export interface Rules {
 	a11y?: A11y;
@@ -638,6 +837,31 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface PartialMarkdownFormatter {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to Markdown files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to Markdown files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Css language
@@ -651,6 +875,10 @@ export interface OverridePattern {
 	 * Specific configuration for the Graphql language
 	 */
 	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the Html language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * A list of Unix shell style patterns. The formatter will ignore files/folders that will match these patterns.
 	 */
@@ -676,7 +904,73 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+/**
+ * Options that changes how the TOML formatter behaves
+ */
+export interface PartialTomlFormatter {
+	/**
+	 * Control the formatter for TOML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to TOML files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to TOML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to TOML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to TOML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
+/**
+ * Options that changes how the TOML linter behaves
+ */
+export interface PartialTomlLinter {
+	/**
+	 * Control the linter for TOML files.
+	 */
+	enabled?: boolean;
+}
 export type VcsClientKind = "git";
+/**
+	* Options that changes how the YAML formatter behaves
+
+There's no option for the indent style: YAML doesn't allow tabs in the indentation. 
+	 */
+export interface PartialYamlFormatter {
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
+/**
+ * Options that changes how the YAML linter behaves
+ */
+export interface PartialYamlLinter {
+	/**
+	 * Control the linter for YAML files.
+	 */
+	enabled?: boolean;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -684,16 +978,64 @@ export interface Source {
 	/**
 	 * Enforce props sorting in JSX elements.
 	 */
-	sortJsxProps?: RuleAssistConfiguration;
+	sortJsxProps?: RuleAssistConfiguration_for_Null;
+	/**
+	 * Adds an explicit type annotation to a variable, a parameter or the return of a function.
+	 */
+	useExplicitTypeAnnotation?: RuleAssistConfiguration_for_Null;
 	/**
 	 * Sorts the keys of a JSON object in natural order
 	 */
-	useSortedKeys?: RuleAssistConfiguration;
+	useSortedKeys?: RuleAssistConfiguration_for_UseSortedKeysOptions;
+	/**
+	 * Sorts the keys of a TOML table in natural order
+	 */
+	useSortedTableKeys?: RuleAssistConfiguration_for_Null;
+	/**
+	 * Converts a string concatenation to a template literal.
+	 */
+	useTemplateLiteral?: RuleAssistConfiguration_for_Null;
 }
-export type QuoteStyle = "double" | "single";
+export type QuoteStyle = "double" | "single" | "preserve";
+/**
+ * Whether the whitespace around and inside the elements is significant, because it's rendered as a space by the browsers.
+ */
+export type WhitespaceSensitivity = "css" | "strict" | "ignore";
 export type ArrowParentheses = "always" | "asNeeded";
+/**
+ * Whether the comments that exceed the line width are wrapped.
+ */
+export type CommentReflow = "preserve" | "wrap";
+/**
+ * Where the decorators of class members and parameters are printed.
+ */
+export type DecoratorPlacement = "preserve" | "sameLine" | "above";
+/**
+ * How the lists of named imports and exports are wrapped.
+ */
+export type ImportExportWrap = "auto" | "perLine";
+/**
+ * Whether the lines of JSX children can break at the whitespace between a text and a tag.
+ */
+export type JsxChildrenWhitespace = "break" | "glue";
+/**
+ * Whether the objects that are expanded in the input stay expanded.
+ */
+export type ObjectWrap = "preserve" | "collapse";
+/**
+ * Where the operators of the broken binary and logical expressions are printed.
+ */
+export type OperatorLinebreak = "after" | "before";
+/**
+ * Whether the decorators of the parameters break the list of parameters.
+ */
+export type ParameterDecorators = "auto" | "inline" | "break";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
+/**
+ * How the chains of nested conditional expressions are printed.
+ */
+export type TernaryStyle = "indent" | "case";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
@@ -1046,7 +1388,7 @@ export interface Correctness {
 	 */
 	noInvalidConstructorSuper?: RuleConfiguration_for_Null;
 	/**
-	 * Disallow non-standard direction values for linear gradient functions.
+	 * Disallow non-standard direction values for linear gradient functions, and invalid color stops in gradient functions.
 	 */
 	noInvalidDirectionInLinearGradient?: RuleConfiguration_for_Null;
 	/**
@@ -1200,7 +1542,7 @@ export interface Correctness {
 	/**
 	 * Require calls to isNaN() when checking for NaN.
 	 */
-	useIsNan?: RuleFixConfiguration_for_Null;
+	useIsNan?: RuleFixConfiguration_for_UseIsNanOptions;
 	/**
 	 * Disallow missing key props in iterators/collection literals.
 	 */
@@ -1222,10 +1564,30 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * Disallow the use of {@html} tags in Svelte templates
+	 */
+	noAtHtmlTags?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow await inside loops whose iterations are independent.
+	 */
+	noAwaitInLoops?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow use of CommonJs module system in favor of ESM style imports.
 	 */
 	noCommonJs?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow compiler options of tsconfig.json that contradict each other.
+	 */
+	noConflictingCompilerOptions?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the deprecated compiler options of tsconfig.json.
+	 */
+	noDeprecatedCompilerOptions?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting fields that are deprecated in the schema.
+	 */
+	noDeprecatedFields?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow a lower specificity selector from coming after a higher specificity selector.
 	 */
@@ -1242,14 +1604,26 @@ export interface Nursery {
 	 * Disallow duplicate custom properties within declaration blocks.
 	 */
 	noDuplicateCustomProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow dependencies that are declared in dependencies and in another dependency field of package.json.
+	 */
+	noDuplicateDependencies?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate conditions in if-else-if chains
 	 */
 	noDuplicateElseIf?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow duplicate keys in YAML mappings.
+	 */
+	noDuplicateKeys?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate properties within declaration blocks.
 	 */
 	noDuplicateProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow defining the same table more than once.
+	 */
+	noDuplicateTables?: RuleConfiguration_for_Null;
 	/**
 	 * No duplicated fields in GraphQL operations.
 	 */
@@ -1257,7 +1631,7 @@ export interface Nursery {
 	/**
 	 * Disallow accessing namespace imports dynamically.
 	 */
-	noDynamicNamespaceImportAccess?: RuleConfiguration_for_Null;
+	noDynamicNamespaceImportAccess?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow TypeScript enum.
 	 */
@@ -1266,6 +1640,14 @@ export interface Nursery {
 	 * Disallow exporting an imported variable.
 	 */
 	noExportedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the selectors of a CSS module that aren't scoped to the module.
+	 */
+	noGlobalLeakage?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow strings displayed to users that aren't translated.
+	 */
+	noHardcodedLocaleStrings?: RuleConfiguration_for_NoHardcodedLocaleStringsOptions;
 	/**
 	 * Prevent usage of \<head> element in a Next.js project.
 	 */
@@ -1278,14 +1660,30 @@ export interface Nursery {
 	 * Prevent usage of \<img> element in a Next.js project.
 	 */
 	noImgElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow argument values that don't match the type of their argument in the schema.
+	 */
+	noInvalidArgumentValues?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid version ranges in package.json.
+	 */
+	noInvalidSemverRange?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows the use of irregular whitespace characters.
 	 */
 	noIrregularWhitespace?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow text colors that don't contrast enough with the background color.
+	 */
+	noLowContrastColors?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow missing var function for css variables.
 	 */
 	noMissingVarFunction?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow mutating the properties of function parameters.
+	 */
+	noMutationOfFunctionParametersDeep?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow nested ternary expressions.
 	 */
@@ -1294,10 +1692,26 @@ export interface Nursery {
 	 * Disallow octal escape sequences in string literals
 	 */
 	noOctalEscape?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the APIs that some of the targeted environments don't support.
+	 */
+	noOutdatedBrowserApis?: RuleConfiguration_for_Null;
+	/**
+	 * Prevent the usage of positive integers on tabindex attribute.
+	 */
+	noPositiveTabindex?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow the use of process.env.
 	 */
 	noProcessEnv?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow longhand properties that can be merged into a preceding shorthand property.
+	 */
+	noRedundantLonghand?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow type annotations that are identical to the type inferred from the initializer.
+	 */
+	noRedundantTypeAnnotations?: RuleFixConfiguration_for_NoRedundantTypeAnnotationsOptions;
 	/**
 	 * Disallow specified modules when loaded by import or require.
 	 */
@@ -1310,6 +1724,10 @@ export interface Nursery {
 	 * Disallow usage of sensitive data such as API keys and tokens.
 	 */
 	noSecrets?: RuleConfiguration_for_NoSecretsOptions;
+	/**
+	 * Disallow queries of Testing Library that bypass the accessibility tree.
+	 */
+	noSkippedAccessibilityInTests?: RuleConfiguration_for_NoSkippedAccessibilityInTestsOptions;
 	/**
 	 * Enforce that static, visible elements (such as \<div>) that have click handlers use the valid role attribute.
 	 */
@@ -1322,6 +1740,18 @@ export interface Nursery {
 	 * Disallow template literal placeholder syntax in regular strings.
 	 */
 	noTemplateCurlyInString?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow accessing the result of JSON.parse() without validating it.
+	 */
+	noUncheckedJsonParse?: RuleConfiguration_for_NoUncheckedJsonParseOptions;
+	/**
+	 * Disallow composing the classes that aren't defined in a CSS module.
+	 */
+	noUndefinedComposes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting fields that don't exist in the schema.
+	 */
+	noUnknownFields?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown pseudo-class selectors.
 	 */
@@ -1334,6 +1764,18 @@ export interface Nursery {
 	 * Disallow unknown type selectors.
 	 */
 	noUnknownTypeSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the CSS features that some of the targeted browsers don't support.
+	 */
+	noUnsupportedCssFeatures?: RuleConfiguration_for_NoUnsupportedCssFeaturesOptions;
+	/**
+	 * Disallow custom properties that are never used.
+	 */
+	noUnusedCustomProperties?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow fragments that aren't used by any operation.
+	 */
+	noUnusedFragments?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -1350,14 +1792,30 @@ export interface Nursery {
 	 * Disallow use of @value rule in css modules.
 	 */
 	noValueAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow vendor prefixes that aren't needed by the targeted browsers.
+	 */
+	noVendorPrefix?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow the production code from importing the test files, and the entry points from exporting them.
+	 */
+	noWildcardExportsFromTests?: RuleConfiguration_for_NoWildcardExportsFromTestsOptions;
 	/**
 	 * It enables the recommended rules for this group
 	 */
 	recommended?: boolean;
+	/**
+	 * Disallow reading properties that only have a setter, and assigning properties that only have a getter.
+	 */
+	useAccessorPairs?: RuleConfiguration_for_AccessorPairsOptions;
 	/**
 	 * Disallow the use of overload signatures that are not next to each other.
 	 */
 	useAdjacentOverloadSignatures?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+	 */
+	useAltText?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce that ARIA properties are valid for the roles that are supported by the element.
 	 */
@@ -1366,6 +1824,18 @@ export interface Nursery {
 	 * Use at() instead of integer index access.
 	 */
 	useAtIndex?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce that buttons have an accessible name.
+	 */
+	useButtonName?: RuleConfiguration_for_Null;
+	/**
+	 * Enforces the usage of the attribute type for the element button
+	 */
+	useButtonType?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce a canonical order of the modifiers of the class members and the parameter properties.
+	 */
+	useCanonicalOrderOfModifiers?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce using single if instead of nested if clauses.
 	 */
@@ -1374,6 +1844,10 @@ export interface Nursery {
 	 * Enforce declaring components only within modules that export React Components exclusively.
 	 */
 	useComponentExportOnlyModules?: RuleConfiguration_for_UseComponentExportOnlyModulesOptions;
+	/**
+	 * Enforce a single notation for the colors.
+	 */
+	useConsistentColorFormat?: RuleFixConfiguration_for_UseConsistentColorFormatOptions;
 	/**
 	 * This rule enforces consistent use of curly braces inside JSX attributes and JSX children.
 	 */
@@ -1382,14 +1856,34 @@ export interface Nursery {
 	 * Require consistent accessibility modifiers on class properties and methods.
 	 */
 	useConsistentMemberAccessibility?: RuleConfiguration_for_ConsistentMemberAccessibilityOptions;
+	/**
+	 * Require checking the status of the responses of fetch() before reading their body.
+	 */
+	useConsistentResponseHandling?: RuleConfiguration_for_Null;
+	/**
+	 * Prefer CSS custom properties over Sass variables for the values that can be themed.
+	 */
+	useCssVariablesOverSassVariables?: RuleFixConfiguration_for_Null;
 	/**
 	 * Require specifying the reason argument when using @deprecated directive
 	 */
 	useDeprecatedReason?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce importing the configured modules either with named imports or with a namespace import.
+	 */
+	useDestructuredImports?: RuleFixConfiguration_for_UseDestructuredImportsOptions;
 	/**
 	 * Require explicit return types on functions and class methods.
 	 */
 	useExplicitType?: RuleConfiguration_for_Null;
+	/**
+	 * Require the types condition of the exports of package.json to be consistent with the types field.
+	 */
+	useExportsTypesCondition?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce a naming convention for GraphQL fragments.
+	 */
+	useFragmentNameConvention?: RuleConfiguration_for_FragmentNameConventionOptions;
 	/**
 	 * Enforces the use of a recommended display strategy with Google Fonts.
 	 */
@@ -1402,18 +1896,90 @@ export interface Nursery {
 	 * Require for-in loops to include an if statement.
 	 */
 	useGuardForIn?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that html element has lang attribute.
+	 */
+	useHtmlLang?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow reassigning exported variables after the module is initialized.
+	 */
+	useImmutableExports?: RuleConfiguration_for_UseImmutableExportsOptions;
 	/**
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of logical properties and values.
+	 */
+	useLogicalProperties?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce a maximum nesting depth for the rules.
+	 */
+	useMaxNestingDepth?: RuleConfiguration_for_UseMaxNestingDepthOptions;
+	/**
+	 * Enforce a maximum depth for the selections of operations.
+	 */
+	useMaxQueryDepth?: RuleConfiguration_for_MaxQueryDepthOptions;
+	/**
+	 * Enforce a maximum specificity for the selectors.
+	 */
+	useMaxSpecificity?: RuleConfiguration_for_UseMaxSpecificityOptions;
+	/**
+	 * Disallow importing whole heavy libraries when their members can be imported from their own modules.
+	 */
+	useMinimalBundleImports?: RuleFixConfiguration_for_UseMinimalBundleImportsOptions;
 	/**
 	 * Enforce specifying the name of GraphQL operations.
 	 */
 	useNamedOperation?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce a naming convention for GraphQL operations.
+	 */
+	useOperationNamingConvention?: RuleFixConfiguration_for_OperationNamingConventionOptions;
+	/**
+	 * Enforce the best practices of Playwright for locating elements and waiting for them.
+	 */
+	usePlaywrightLocatorBestPractices?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of the preferred modules over their alternatives.
+	 */
+	usePreferredModules?: RuleFixConfiguration_for_UsePreferredModulesOptions;
+	/**
+	 * Require the arguments of fields that don't have a default value and can't be null.
+	 */
+	useRequiredArguments?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow comparing and computing integers that exceed Number.MAX_SAFE_INTEGER.
+	 */
+	useSafeIntegerChecks?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of shorthand properties when all their longhand properties are declared.
+	 */
+	useShorthandProperties?: RuleFixConfiguration_for_Null;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleFixConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Require the dependencies of package.json to be sorted alphabetically.
+	 */
+	useSortedDependencies?: RuleFixConfiguration_for_Null;
+	/**
+	 * Require the fields of package.json to follow the conventional order.
+	 */
+	useSortedPackageJsonFields?: RuleFixConfiguration_for_Null;
+	/**
+	 * Enforce sorting the fields of types, the fields of input types, and the values of enums.
+	 */
+	useSortedSchemaFields?: RuleFixConfiguration_for_SortedSchemaFieldsOptions;
+	/**
+	 * Enforce the use of static initialization blocks over static assignments after a class declaration.
+	 */
+	useStaticClassBlocks?: RuleFixConfiguration_for_Null;
+	/**
+	 * Require the compiler options that complement strict in tsconfig.json.
+	 */
+	useStrictCompanionOptions?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce the use of the directive "use strict" in script files.
 	 */
@@ -1422,10 +1988,18 @@ export interface Nursery {
 	 * Enforce the use of String.trimStart() and String.trimEnd() over String.trimLeft() and String.trimRight().
 	 */
 	useTrimStartEnd?: RuleFixConfiguration_for_Null;
+	/**
+	 * Require selecting __typename on interfaces and unions.
+	 */
+	useTypenameOnAbstractTypes?: RuleConfiguration_for_Null;
 	/**
 	 * Use valid values for the autocomplete attribute on input elements.
 	 */
 	useValidAutocomplete?: RuleConfiguration_for_UseValidAutocompleteOptions;
+	/**
+	 * Ensure that the attribute passed to the lang attribute is a correct ISO language and/or country.
+	 */
+	useValidLang?: RuleConfiguration_for_Null;
 }
 /**
  * A list of rules that belong to this group
@@ -2013,6 +2587,10 @@ export interface OverrideFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * The maximum number of consecutive empty lines that are kept. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
 }
 export interface OverrideLinterConfiguration {
 	/**
@@ -2030,7 +2608,12 @@ export interface OverrideOrganizeImportsConfiguration {
 	 */
 	enabled?: boolean;
 }
-export type RuleAssistConfiguration = "on" | "off";
+export type RuleAssistConfiguration_for_Null =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_Null;
+export type RuleAssistConfiguration_for_UseSortedKeysOptions =
+	| RuleAssistPlainConfiguration
+	| RuleAssistWithOptions_for_UseSortedKeysOptions;
 export type RuleFixConfiguration_for_Null =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_Null;
@@ -2058,6 +2641,15 @@ export type RuleConfiguration_for_DeprecatedHooksOptions =
 export type RuleFixConfiguration_for_UseImportExtensionsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseImportExtensionsOptions;
+export type RuleFixConfiguration_for_UseIsNanOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseIsNanOptions;
+export type RuleConfiguration_for_NoHardcodedLocaleStringsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHardcodedLocaleStringsOptions;
+export type RuleFixConfiguration_for_NoRedundantTypeAnnotationsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoRedundantTypeAnnotationsOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
@@ -2067,15 +2659,63 @@ export type RuleFixConfiguration_for_NoRestrictedTypesOptions =
 export type RuleConfiguration_for_NoSecretsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoSecretsOptions;
+export type RuleConfiguration_for_NoSkippedAccessibilityInTestsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoSkippedAccessibilityInTestsOptions;
+export type RuleConfiguration_for_NoUncheckedJsonParseOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUncheckedJsonParseOptions;
+export type RuleConfiguration_for_NoUnsupportedCssFeaturesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnsupportedCssFeaturesOptions;
+export type RuleConfiguration_for_NoWildcardExportsFromTestsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoWildcardExportsFromTestsOptions;
+export type RuleConfiguration_for_AccessorPairsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_AccessorPairsOptions;
 export type RuleConfiguration_for_UseComponentExportOnlyModulesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseComponentExportOnlyModulesOptions;
+export type RuleFixConfiguration_for_UseConsistentColorFormatOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseConsistentColorFormatOptions;
 export type RuleConfiguration_for_ConsistentMemberAccessibilityOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_ConsistentMemberAccessibilityOptions;
+export type RuleFixConfiguration_for_UseDestructuredImportsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseDestructuredImportsOptions;
+export type RuleConfiguration_for_FragmentNameConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_FragmentNameConventionOptions;
+export type RuleConfiguration_for_UseImmutableExportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseImmutableExportsOptions;
+export type RuleConfiguration_for_UseMaxNestingDepthOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseMaxNestingDepthOptions;
+export type RuleConfiguration_for_MaxQueryDepthOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_MaxQueryDepthOptions;
+export type RuleConfiguration_for_UseMaxSpecificityOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseMaxSpecificityOptions;
+export type RuleFixConfiguration_for_UseMinimalBundleImportsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UseMinimalBundleImportsOptions;
+export type RuleFixConfiguration_for_OperationNamingConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_OperationNamingConventionOptions;
+export type RuleFixConfiguration_for_UsePreferredModulesOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_UsePreferredModulesOptions;
 export type RuleFixConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UtilityClassSortingOptions;
+export type RuleFixConfiguration_for_SortedSchemaFieldsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_SortedSchemaFieldsOptions;
 export type RuleConfiguration_for_UseValidAutocompleteOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseValidAutocompleteOptions;
@@ -2100,12 +2740,173 @@ export type RuleFixConfiguration_for_NoConsoleOptions =
 export type RuleFixConfiguration_for_NoDoubleEqualsOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NoDoubleEqualsOptions;
+export type RuleAssistPlainConfiguration = "on" | "off";
+export interface RuleAssistWithOptions_for_Null {
+	/**
+	 * Whether the assist is enabled
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: null;
+}
+export interface RuleAssistWithOptions_for_UseSortedKeysOptions {
+	/**
+	 * Whether the assist is enabled
+	 */
+	level: RuleAssistPlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedKeysOptions;
+}
 export type RulePlainConfiguration = "warn" | "error" | "info" | "off";
 export interface RuleWithFixOptions_for_Null {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: null;
+}
+export interface RuleWithFixOptions_for_AllowDomainOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: AllowDomainOptions;
+}
+export interface RuleWithOptions_for_NoLabelWithoutControlOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoLabelWithoutControlOptions;
+}
+export interface RuleWithOptions_for_Null {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: null;
+}
+export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ValidAriaRoleOptions;
+}
+export interface RuleWithOptions_for_ComplexityOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ComplexityOptions;
+}
+export interface RuleWithOptions_for_UseExhaustiveDependenciesOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseExhaustiveDependenciesOptions;
+}
+export interface RuleWithOptions_for_DeprecatedHooksOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: DeprecatedHooksOptions;
+}
+export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseImportExtensionsOptions;
+}
+export interface RuleWithFixOptions_for_UseIsNanOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseIsNanOptions;
+}
+export interface RuleWithOptions_for_NoHardcodedLocaleStringsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoHardcodedLocaleStringsOptions;
+}
+export interface RuleWithFixOptions_for_NoRedundantTypeAnnotationsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
 	 */
-	fix?: FixKind;
+	options: NoRedundantTypeAnnotationsOptions;
+}
+export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2113,9 +2914,9 @@ export interface RuleWithFixOptions_for_Null {
 	/**
 	 * Rule's options
 	 */
-	options: null;
+	options: RestrictedImportsOptions;
 }
-export interface RuleWithFixOptions_for_AllowDomainOptions {
+export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -2127,9 +2928,9 @@ export interface RuleWithFixOptions_for_AllowDomainOptions {
 	/**
 	 * Rule's options
 	 */
-	options: AllowDomainOptions;
+	options: NoRestrictedTypesOptions;
 }
-export interface RuleWithOptions_for_NoLabelWithoutControlOptions {
+export interface RuleWithOptions_for_NoSecretsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2137,9 +2938,9 @@ export interface RuleWithOptions_for_NoLabelWithoutControlOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoLabelWithoutControlOptions;
+	options: NoSecretsOptions;
 }
-export interface RuleWithOptions_for_Null {
+export interface RuleWithOptions_for_NoSkippedAccessibilityInTestsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2147,13 +2948,19 @@ export interface RuleWithOptions_for_Null {
 	/**
 	 * Rule's options
 	 */
-	options: null;
+	options: NoSkippedAccessibilityInTestsOptions;
 }
-export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
+export interface RuleWithOptions_for_NoUncheckedJsonParseOptions {
 	/**
-	 * The kind of the code actions emitted by the rule
+	 * The severity of the emitted diagnostics by the rule
 	 */
-	fix?: FixKind;
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUncheckedJsonParseOptions;
+}
+export interface RuleWithOptions_for_NoUnsupportedCssFeaturesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2161,9 +2968,9 @@ export interface RuleWithFixOptions_for_ValidAriaRoleOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ValidAriaRoleOptions;
+	options: NoUnsupportedCssFeaturesOptions;
 }
-export interface RuleWithOptions_for_ComplexityOptions {
+export interface RuleWithOptions_for_NoWildcardExportsFromTestsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2171,9 +2978,9 @@ export interface RuleWithOptions_for_ComplexityOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ComplexityOptions;
+	options: NoWildcardExportsFromTestsOptions;
 }
-export interface RuleWithOptions_for_UseExhaustiveDependenciesOptions {
+export interface RuleWithOptions_for_AccessorPairsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2181,9 +2988,9 @@ export interface RuleWithOptions_for_UseExhaustiveDependenciesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseExhaustiveDependenciesOptions;
+	options: AccessorPairsOptions;
 }
-export interface RuleWithOptions_for_DeprecatedHooksOptions {
+export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2191,9 +2998,9 @@ export interface RuleWithOptions_for_DeprecatedHooksOptions {
 	/**
 	 * Rule's options
 	 */
-	options: DeprecatedHooksOptions;
+	options: UseComponentExportOnlyModulesOptions;
 }
-export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
+export interface RuleWithFixOptions_for_UseConsistentColorFormatOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -2205,9 +3012,9 @@ export interface RuleWithFixOptions_for_UseImportExtensionsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseImportExtensionsOptions;
+	options: UseConsistentColorFormatOptions;
 }
-export interface RuleWithOptions_for_RestrictedImportsOptions {
+export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2215,9 +3022,9 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedImportsOptions;
+	options: ConsistentMemberAccessibilityOptions;
 }
-export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
+export interface RuleWithFixOptions_for_UseDestructuredImportsOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -2229,9 +3036,9 @@ export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoRestrictedTypesOptions;
+	options: UseDestructuredImportsOptions;
 }
-export interface RuleWithOptions_for_NoSecretsOptions {
+export interface RuleWithOptions_for_FragmentNameConventionOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2239,9 +3046,9 @@ export interface RuleWithOptions_for_NoSecretsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoSecretsOptions;
+	options: FragmentNameConventionOptions;
 }
-export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
+export interface RuleWithOptions_for_UseImmutableExportsOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2249,9 +3056,9 @@ export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UseComponentExportOnlyModulesOptions;
+	options: UseImmutableExportsOptions;
 }
-export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
+export interface RuleWithOptions_for_UseMaxNestingDepthOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
 	 */
@@ -2259,7 +3066,69 @@ export interface RuleWithOptions_for_ConsistentMemberAccessibilityOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ConsistentMemberAccessibilityOptions;
+	options: UseMaxNestingDepthOptions;
+}
+export interface RuleWithOptions_for_MaxQueryDepthOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: MaxQueryDepthOptions;
+}
+export interface RuleWithOptions_for_UseMaxSpecificityOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseMaxSpecificityOptions;
+}
+export interface RuleWithFixOptions_for_UseMinimalBundleImportsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseMinimalBundleImportsOptions;
+}
+export interface RuleWithFixOptions_for_OperationNamingConventionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: OperationNamingConventionOptions;
+}
+export interface RuleWithFixOptions_for_UsePreferredModulesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UsePreferredModulesOptions;
 }
 export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	/**
@@ -2275,6 +3144,20 @@ export interface RuleWithFixOptions_for_UtilityClassSortingOptions {
 	 */
 	options: UtilityClassSortingOptions;
 }
+export interface RuleWithFixOptions_for_SortedSchemaFieldsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: SortedSchemaFieldsOptions;
+}
 export interface RuleWithOptions_for_UseValidAutocompleteOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2375,6 +3258,19 @@ export interface RuleWithFixOptions_for_NoDoubleEqualsOptions {
 	 */
 	options: NoDoubleEqualsOptions;
 }
+/**
+ * Options for the assist `useSortedKeys`.
+ */
+export interface UseSortedKeysOptions {
+	/**
+	 * The keys that are placed before the other keys, in the given order.
+	 */
+	pinnedKeys?: string[];
+	/**
+	 * The order of the keys.
+	 */
+	sortOrder?: SortOrder;
+}
 /**
  * Used to identify the kind of code action emitted by a rule
  */
@@ -2439,6 +3335,41 @@ export interface UseImportExtensionsOptions {
 	 */
 	suggestedExtensions?: {};
 }
+/**
+ * Options for the rule `useIsNan`.
+ */
+export interface UseIsNanOptions {
+	/**
+	 * Whether `indexOf(NaN)` and `lastIndexOf(NaN)` are reported.
+	 */
+	enforceForIndexOf?: boolean;
+}
+/**
+ * Options for the rule `noHardcodedLocaleStrings`.
+ */
+export interface NoHardcodedLocaleStringsOptions {
+	/**
+	 * The JSX attributes whose values are displayed to users.
+	 */
+	attributes?: string[];
+	/**
+	 * The functions that display their arguments to users, such as `alert`.
+	 */
+	functions?: string[];
+	/**
+	 * The functions that translate their arguments, such as `t` or `i18n.t`.
+	 */
+	translationFunctions?: string[];
+}
+/**
+ * Options for the rule `noRedundantTypeAnnotations`.
+ */
+export interface NoRedundantTypeAnnotationsOptions {
+	/**
+	 * Whether the annotations of the exported declarations are ignored.
+	 */
+	ignoreExported?: boolean;
+}
 /**
  * Options for the rule `noRestrictedImports`.
  */
@@ -2457,6 +3388,67 @@ export interface NoSecretsOptions {
 	 */
 	entropyThreshold?: number;
 }
+/**
+ * Options for the rule `noSkippedAccessibilityInTests`.
+ */
+export interface NoSkippedAccessibilityInTestsOptions {
+	/**
+	 * The queries to report.
+	 */
+	strictness?: QueryStrictness;
+}
+/**
+ * Options for the rule `noUncheckedJsonParse`.
+ */
+export interface NoUncheckedJsonParseOptions {
+	/**
+	 * Whether the variables initialized with the result of `JSON.parse()` are checked too.
+	 */
+	strict?: boolean;
+}
+/**
+ * Options for the rule `noUnsupportedCssFeatures`.
+ */
+export interface NoUnsupportedCssFeaturesOptions {
+	/**
+	 * The severity of the unsupported at-rules, such as `@container`. The severity configured for the rule applies by default.
+	 */
+	atRules?: FeatureSeverity;
+	/**
+	 * The severity of the unsupported properties, such as `text-wrap`. The severity configured for the rule applies by default.
+	 */
+	properties?: FeatureSeverity;
+	/**
+	 * The severity of the unsupported values, such as `display: contents`. The severity configured for the rule applies by default.
+	 */
+	values?: FeatureSeverity;
+}
+/**
+ * Options for the rule `noWildcardExportsFromTests`.
+ */
+export interface NoWildcardExportsFromTestsOptions {
+	/**
+	 * The globs of the entry points, relative to the folder of the project.
+	 */
+	entryPoints?: Regex[];
+	/**
+	 * The globs of the test files, relative to the folder of the project.
+	 */
+	testFiles?: Regex[];
+}
+/**
+ * Options for the rule `useAccessorPairs`.
+ */
+export interface AccessorPairsOptions {
+	/**
+	 * If `true`, assignments of properties that only have a getter are reported.
+	 */
+	getWithoutSet?: boolean;
+	/**
+	 * If `true`, reads of properties that only have a setter are reported.
+	 */
+	setWithoutGet?: boolean;
+}
 export interface UseComponentExportOnlyModulesOptions {
 	/**
 	 * Allows the export of constants. This option is for environments that support it, such as [Vite](https://vitejs.dev/)
@@ -2467,9 +3459,103 @@ export interface UseComponentExportOnlyModulesOptions {
 	 */
 	allowExportNames: string[];
 }
+/**
+ * Options for the rule `useConsistentColorFormat`.
+ */
+export interface UseConsistentColorFormatOptions {
+	/**
+	 * The notation of the colors.
+	 */
+	format?: ColorFormat;
+}
 export interface ConsistentMemberAccessibilityOptions {
 	accessibility?: Accessibility;
 }
+/**
+ * Options for the rule `useDestructuredImports`.
+ */
+export interface UseDestructuredImportsOptions {
+	/**
+	 * The modules whose imports are checked, mapped to the way they're imported.
+	 */
+	modules: {};
+}
+/**
+ * Options for the rule `useFragmentNameConvention`.
+ */
+export interface FragmentNameConventionOptions {
+	/**
+	 * The pattern that the names of fragments must match. `{TypeCondition}` stands for the type condition of the fragment, `{FileName}` for the name of the file, and `*` for any sequence of characters.
+	 */
+	pattern?: string;
+}
+/**
+ * Options for the rule `useImmutableExports`.
+ */
+export interface UseImmutableExportsOptions {
+	/**
+	 * Allow the compound assignments and the increments, such as `count += 1` or `count++`.
+	 */
+	allowAccumulators?: boolean;
+}
+/**
+ * Options for the rule `useMaxNestingDepth`.
+ */
+export interface UseMaxNestingDepthOptions {
+	/**
+	 * The maximum number of rules in which a rule can be nested.
+	 */
+	max?: number;
+}
+/**
+ * Options for the rule `useMaxQueryDepth`.
+ */
+export interface MaxQueryDepthOptions {
+	/**
+	 * The maximum depth allowed for an operation.
+	 */
+	maxDepth?: number;
+}
+/**
+ * Options for the rule `useMaxSpecificity`.
+ */
+export interface UseMaxSpecificityOptions {
+	/**
+	 * The maximum specificity of the selectors.
+	 */
+	max?: MaxSpecificity;
+}
+/**
+ * Options for the rule `useMinimalBundleImports`.
+ */
+export interface UseMinimalBundleImportsOptions {
+	/**
+	 * The libraries to report, in addition to the default ones, mapped to the way their members are imported.
+	 */
+	libraries: {};
+}
+/**
+ * Options for the rule `useOperationNamingConvention`.
+ */
+export interface OperationNamingConventionOptions {
+	/**
+	 * The case that the names of operations must follow.
+	 */
+	case?: OperationNameCase;
+	/**
+	 * If `true`, the name of an operation must end with the type of the operation, such as `Query` for queries.
+	 */
+	requireSuffix?: boolean;
+}
+/**
+ * Options for the rule `usePreferredModules`.
+ */
+export interface UsePreferredModulesOptions {
+	/**
+	 * The modules to avoid, mapped to their preference.
+	 */
+	aliases: {};
+}
 export interface UtilityClassSortingOptions {
 	/**
 	 * Additional attributes that will be sorted.
@@ -2480,6 +3566,15 @@ export interface UtilityClassSortingOptions {
 	 */
 	functions?: string[];
 }
+/**
+ * Options for the rule `useSortedSchemaFields`.
+ */
+export interface SortedSchemaFieldsOptions {
+	/**
+	 * The names placed before or after the other names, in the given order. `"*"` stands for the names that aren't in the list.
+	 */
+	groups: string[];
+}
 export interface UseValidAutocompleteOptions {
 	/**
 	 * `input` like custom components that should be checked.
@@ -2563,6 +3658,7 @@ If `false`, no such exception will be made.
 	 */
 	ignoreNull: boolean;
 }
+export type SortOrder = "natural" | "lexicographic";
 export interface Hook {
 	/**
 	* The "position" of the closure function, starting from zero.
@@ -2589,10 +3685,37 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	 */
 	stableResult?: StableHookResult;
 }
+export type QueryStrictness = "recommended" | "strict";
+/**
+ * The severity of the diagnostics of a group of features
+ */
+export type FeatureSeverity = "info" | "warn" | "error" | "off";
+export type Regex = string;
+export type ColorFormat = "hex" | "rgb" | "hsl" | "oklch";
 export type Accessibility = "noPublic" | "explicit" | "none";
+/**
+ * A specificity, as the number of ID selectors, class selectors and type selectors.
+ */
+export interface MaxSpecificity {
+	/**
+	 * The number of class selectors, attribute selectors and pseudo-classes.
+	 */
+	classes?: number;
+	/**
+	 * The number of ID selectors.
+	 */
+	ids?: number;
+	/**
+	 * The number of type selectors and pseudo-elements.
+	 */
+	types?: number;
+}
+/**
+ * Supported cases for operation names.
+ */
+export type OperationNameCase = "camelCase" | "PascalCase";
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
-export type Regex = string;
 export interface Convention {
 	/**
 	 * String cases to enforce
@@ -2689,18 +3812,19 @@ export type RestrictedModifier =
 	| "static";
 export interface RegisterProjectFolderParams {
 	path?: string;
-	setAsCurrentWorkspace: boolean;
 }
 export type ProjectKey = string;
 export interface SetManifestForProjectParams {
 	content: string;
 	manifest_path: BiomePath;
+	project_key?: ProjectKey;
 	version: number;
 }
 export interface OpenFileParams {
 	content: string;
 	document_file_source?: DocumentFileSource;
 	path: BiomePath;
+	project_key?: ProjectKey;
 	version: number;
 }
 export type DocumentFileSource =
@@ -2710,7 +3834,10 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Yaml: YamlFileSource }
+	| { Toml: TomlFileSource }
+	| { Markdown: MarkdownFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -2723,6 +3850,7 @@ export interface JsFileSource {
 }
 export interface JsonFileSource {
 	allow_comments: boolean;
+	allow_json5: boolean;
 	allow_trailing_commas: boolean;
 }
 export interface CssFileSource {
@@ -2737,6 +3865,11 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export interface YamlFileSource {}
+export interface TomlFileSource {}
+export interface MarkdownFileSource {
+	variant: MarkdownVariant;
+}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "JavaScript"
@@ -2755,25 +3888,32 @@ export type LanguageVersion = "ES2022" | "ESNext";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Biome aims to be compatible with the latest Recommendation level standards of plain CSS, and supports the SCSS syntax of Sass and the Less syntax. 
 	 */
-export type CssVariant = "Standard";
+export type CssVariant = "Standard" | "Scss" | "Less";
 /**
  * The style of GraphQL contained in the file.
  */
 export type GraphqlVariant = "Standard";
-export type HtmlVariant = "Standard" | "Astro";
+export type HtmlVariant = "Standard" | "Astro" | "Svelte";
 export type GritVariant = "Standard";
+/**
+ * The flavor of Markdown contained in the file.
+ */
+export type MarkdownVariant = "Standard" | "Mdx";
 export interface ChangeFileParams {
 	content: string;
 	path: BiomePath;
+	project_key?: ProjectKey;
 	version: number;
 }
 export interface CloseFileParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface GetSyntaxTreeParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface GetSyntaxTreeResult {
 	ast: string;
@@ -2781,26 +3921,31 @@ export interface GetSyntaxTreeResult {
 }
 export interface OrganizeImportsParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface OrganizeImportsResult {
 	code: string;
 }
 export interface GetFileContentParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface GetControlFlowGraphParams {
 	cursor: TextSize;
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export type TextSize = number;
 export interface GetFormatterIRParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
 	only: RuleCode[];
 	path: BiomePath;
+	project_key?: ProjectKey;
 	skip: RuleCode[];
 }
 export type RuleCategories = RuleCategory[];
@@ -2952,43 +4097,65 @@ export type Category =
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
 	| "lint/nursery/colorNoInvalidHex"
+	| "lint/nursery/noAtHtmlTags"
+	| "lint/nursery/noAwaitInLoops"
 	| "lint/nursery/noColorInvalidHex"
 	| "lint/nursery/noCommonJs"
+	| "lint/nursery/noConflictingCompilerOptions"
 	| "lint/nursery/noConsole"
+	| "lint/nursery/noDeprecatedCompilerOptions"
+	| "lint/nursery/noDeprecatedFields"
 	| "lint/nursery/noDescendingSpecificity"
 	| "lint/nursery/noDocumentCookie"
 	| "lint/nursery/noDocumentImportInPage"
 	| "lint/nursery/noDoneCallback"
 	| "lint/nursery/noDuplicateAtImportRules"
 	| "lint/nursery/noDuplicateCustomProperties"
+	| "lint/nursery/noDuplicateDependencies"
 	| "lint/nursery/noDuplicateElseIf"
+	| "lint/nursery/noDuplicateKeys"
 	| "lint/nursery/noDuplicateProperties"
+	| "lint/nursery/noDuplicateTables"
 	| "lint/nursery/noDuplicatedFields"
 	| "lint/nursery/noDynamicNamespaceImportAccess"
 	| "lint/nursery/noEnum"
 	| "lint/nursery/noExportedImports"
+	| "lint/nursery/noGlobalLeakage"
+	| "lint/nursery/noHardcodedLocaleStrings"
 	| "lint/nursery/noHeadElement"
 	| "lint/nursery/noHeadImportInDocument"
 	| "lint/nursery/noImgElement"
 	| "lint/nursery/noImportantInKeyframe"
+	| "lint/nursery/noInvalidArgumentValues"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidGridAreas"
 	| "lint/nursery/noInvalidPositionAtImportRule"
+	| "lint/nursery/noInvalidSemverRange"
 	| "lint/nursery/noIrregularWhitespace"
+	| "lint/nursery/noLowContrastColors"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
 	| "lint/nursery/noMissingVarFunction"
+	| "lint/nursery/noMutationOfFunctionParametersDeep"
 	| "lint/nursery/noNestedTernary"
 	| "lint/nursery/noOctalEscape"
+	| "lint/nursery/noOutdatedBrowserApis"
+	| "lint/nursery/noPositiveTabindex"
 	| "lint/nursery/noProcessEnv"
 	| "lint/nursery/noReactSpecificProps"
+	| "lint/nursery/noRedundantLonghand"
+	| "lint/nursery/noRedundantTypeAnnotations"
 	| "lint/nursery/noRestrictedImports"
 	| "lint/nursery/noRestrictedTypes"
 	| "lint/nursery/noSecrets"
 	| "lint/nursery/noShorthandPropertyOverrides"
+	| "lint/nursery/noSkippedAccessibilityInTests"
 	| "lint/nursery/noStaticElementInteractions"
 	| "lint/nursery/noSubstr"
 	| "lint/nursery/noTemplateCurlyInString"
+	| "lint/nursery/noUncheckedJsonParse"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUndefinedComposes"
+	| "lint/nursery/noUnknownFields"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
@@ -2999,32 +4166,68 @@ export type Category =
 	| "lint/nursery/noUnknownTypeSelector"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnsupportedCssFeatures"
+	| "lint/nursery/noUnusedCustomProperties"
+	| "lint/nursery/noUnusedFragments"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noUselessStringRaw"
 	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/noValueAtRule"
+	| "lint/nursery/noVendorPrefix"
+	| "lint/nursery/noWildcardExportsFromTests"
+	| "lint/nursery/useAccessorPairs"
 	| "lint/nursery/useAdjacentOverloadSignatures"
+	| "lint/nursery/useAltText"
 	| "lint/nursery/useAriaPropsSupportedByRole"
 	| "lint/nursery/useAtIndex"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useButtonName"
+	| "lint/nursery/useButtonType"
+	| "lint/nursery/useCanonicalOrderOfModifiers"
 	| "lint/nursery/useCollapsedIf"
 	| "lint/nursery/useComponentExportOnlyModules"
+	| "lint/nursery/useConsistentColorFormat"
 	| "lint/nursery/useConsistentCurlyBraces"
 	| "lint/nursery/useConsistentMemberAccessibility"
+	| "lint/nursery/useConsistentResponseHandling"
+	| "lint/nursery/useCssVariablesOverSassVariables"
 	| "lint/nursery/useDeprecatedReason"
+	| "lint/nursery/useDestructuredImports"
 	| "lint/nursery/useExplicitFunctionReturnType"
 	| "lint/nursery/useExplicitType"
+	| "lint/nursery/useExportsTypesCondition"
+	| "lint/nursery/useFragmentNameConvention"
 	| "lint/nursery/useGoogleFontDisplay"
 	| "lint/nursery/useGoogleFontPreconnect"
 	| "lint/nursery/useGuardForIn"
+	| "lint/nursery/useHtmlLang"
+	| "lint/nursery/useImmutableExports"
 	| "lint/nursery/useImportRestrictions"
 	| "lint/nursery/useJsxCurlyBraceConvention"
+	| "lint/nursery/useLogicalProperties"
+	| "lint/nursery/useMaxNestingDepth"
+	| "lint/nursery/useMaxQueryDepth"
+	| "lint/nursery/useMaxSpecificity"
+	| "lint/nursery/useMinimalBundleImports"
 	| "lint/nursery/useNamedOperation"
+	| "lint/nursery/useOperationNamingConvention"
+	| "lint/nursery/usePlaywrightLocatorBestPractices"
+	| "lint/nursery/usePreferredModules"
+	| "lint/nursery/useRequiredArguments"
+	| "lint/nursery/useSafeIntegerChecks"
+	| "lint/nursery/useShorthandProperties"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedDependencies"
+	| "lint/nursery/useSortedPackageJsonFields"
+	| "lint/nursery/useSortedSchemaFields"
+	| "lint/nursery/useStaticClassBlocks"
+	| "lint/nursery/useStrictCompanionOptions"
 	| "lint/nursery/useStrictMode"
 	| "lint/nursery/useTrimStartEnd"
+	| "lint/nursery/useTypenameOnAbstractTypes"
 	| "lint/nursery/useValidAutocomplete"
+	| "lint/nursery/useValidLang"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noBarrelFile"
 	| "lint/performance/noDelete"
@@ -3150,7 +4353,10 @@ export type Category =
 	| "lint/suspicious/useNamespaceKeyword"
 	| "lint/suspicious/useNumberToFixedDigitsArgument"
 	| "lint/suspicious/useValidTypeof"
+	| "assists/source/useExplicitTypeAnnotation"
 	| "assists/source/useSortedKeys"
+	| "assists/source/useSortedTableKeys"
+	| "assists/source/useTemplateLiteral"
 	| "syntax/correctness/noTypeOnlyImportAttributes"
 	| "syntax/correctness/noSuperWithoutExtends"
 	| "syntax/correctness/noInitializerWithDefinite"
@@ -3167,6 +4373,7 @@ export type Category =
 	| "deserialize"
 	| "project"
 	| "search"
+	| "maxDiagnostics"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -3189,6 +4396,7 @@ export type Category =
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/tooBroad"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -3285,6 +4493,7 @@ export interface BacktraceSymbol {
 export interface PullActionsParams {
 	only: RuleCode[];
 	path: BiomePath;
+	project_key?: ProjectKey;
 	range?: TextRange;
 	skip: RuleCode[];
 }
@@ -3341,6 +4550,7 @@ export type SourceActionKind =
 export type Applicability = "Always" | "MaybeIncorrect";
 export interface FormatFileParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface Printed {
 	code: string;
@@ -3363,16 +4573,19 @@ export interface SourceMarker {
 }
 export interface FormatRangeParams {
 	path: BiomePath;
+	project_key?: ProjectKey;
 	range: TextRange;
 }
 export interface FormatOnTypeParams {
 	offset: TextSize;
 	path: BiomePath;
+	project_key?: ProjectKey;
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	only: RuleCode[];
 	path: BiomePath;
+	project_key?: ProjectKey;
 	rule_categories: RuleCategories;
 	should_format: boolean;
 	skip: RuleCode[];
@@ -3415,6 +4628,7 @@ export interface FixAction {
 export interface RenameParams {
 	new_name: string;
 	path: BiomePath;
+	project_key?: ProjectKey;
 	symbol_at: TextSize;
 }
 export interface RenameResult {
@@ -3422,11 +4636,22 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * The edits of the other files of the project, when the symbol is used across the project, such as a CSS custom property or a CSS class
+	 */
+	other_files: RenamedFile[];
 	/**
 	 * Range of source code modified by this rename operation
 	 */
 	range: TextRange;
 }
+export interface RenamedFile {
+	/**
+	 * List of text edit operations to apply on the content of the file
+	 */
+	indels: TextEdit;
+	path: BiomePath;
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<FileFeaturesResult>;
@@ -14,10 +14,11 @@ describe("Workspace API", () => {
 		);
 
 		const workspace = await createWorkspaceWithBinary(command);
-		workspace.registerProjectFolder({
+		const projectKey = await workspace.registerProjectFolder({
 			setAsCurrentWorkspace: true,
 		});
 		await workspace.openFile({
+			project_key: projectKey,
 			path: {
 				path: "test.js",
 				was_written: false,
@@ -28,6 +29,7 @@ describe("Workspace API", () => {
 		});
 
 		const printed = await workspace.formatFile({
+			project_key: projectKey,
 			path: {
 				path: "test.js",
 				was_written: false,
@@ -38,6 +40,7 @@ describe("Workspace API", () => {
 		expect(printed.code).toBe("statement();\n");
 
 		await workspace.closeFile({
+			project_key: projectKey,
 			path: {
 				path: "test.js",
 				was_written: false,
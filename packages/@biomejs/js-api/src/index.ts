@@ -3,6 +3,7 @@ import type {
 	Diagnostic,
 	FixFileMode,
 	PartialConfiguration,
+	ProjectKey,
 	Workspace,
 } from "@biomejs/wasm-nodejs";
 import { Distribution, type WasmModule, loadModule, wrapError } from "./wasm";
@@ -92,6 +93,7 @@ export class Biome {
 	private constructor(
 		private readonly module: WasmModule,
 		private readonly workspace: Workspace,
+		private projectKey: ProjectKey,
 	) {}
 
 	/**
@@ -100,9 +102,8 @@ export class Biome {
 	static async create(options: BiomeCreate): Promise<Biome> {
 		const module = await loadModule(options.distribution);
 		const workspace = new module.Workspace();
-		const biome = new Biome(module, workspace);
-		biome.registerProjectFolder();
-		return biome;
+		const projectKey = workspace.registerProjectFolder({});
+		return new Biome(module, workspace, projectKey);
 	}
 
 	/**
@@ -125,6 +126,7 @@ export class Biome {
 	applyConfiguration(configuration: Configuration): void {
 		try {
 			this.workspace.updateSettings({
+				project_key: this.projectKey,
 				configuration,
 				gitignore_matches: [],
 				workspace_directory: "./",
@@ -134,12 +136,13 @@ export class Biome {
 		}
 	}
 
+	/**
+	 * Registers a new project. The configuration applied and the content handled afterwards
+	 * belong to this project.
+	 */
 	registerProjectFolder(): void;
 	registerProjectFolder(path?: string): void {
-		this.workspace.registerProjectFolder({
-			path,
-			setAsCurrentWorkspace: true,
-		});
+		this.projectKey = this.workspace.registerProjectFolder({ path });
 	}
 
 	private tryCatchWrapper<T>(func: () => T): T {
@@ -163,6 +166,7 @@ export class Biome {
 			};
 
 			this.workspace.openFile({
+				project_key: this.projectKey,
 				content,
 				version: 0,
 				path: biomePath,
@@ -172,6 +176,7 @@ export class Biome {
 				return func(biomePath);
 			} finally {
 				this.workspace.closeFile({
+					project_key: this.projectKey,
 					path: biomePath,
 				});
 			}
@@ -198,6 +203,7 @@ export class Biome {
 			let code = content;
 
 			const { diagnostics } = this.workspace.pullDiagnostics({
+				project_key: this.projectKey,
 				path,
 				categories: ["Syntax"],
 				max_diagnostics: Number.MAX_SAFE_INTEGER,
@@ -211,12 +217,14 @@ export class Biome {
 			if (!hasErrors) {
 				if (options.range) {
 					const result = this.workspace.formatRange({
+						project_key: this.projectKey,
 						path,
 						range: options.range,
 					});
 					code = result.code;
 				} else {
 					const result = this.workspace.formatFile({
+						project_key: this.projectKey,
 						path,
 					});
 					code = result.code;
@@ -224,6 +232,7 @@ export class Biome {
 
 				if (isFormatContentDebug(options)) {
 					const ir = this.workspace.getFormatterIr({
+						project_key: this.projectKey,
 						path,
 					});
 
@@ -257,6 +266,7 @@ export class Biome {
 					let code = content;
 
 					const result = this.workspace.fixFile({
+						project_key: this.projectKey,
 						path,
 						fix_file_mode: fixFileMode,
 						should_format: false,
@@ -273,6 +283,7 @@ export class Biome {
 
 		return this.withFile(filePath, maybeFixedContent, (path) => {
 			const { diagnostics } = this.workspace.pullDiagnostics({
+				project_key: this.projectKey,
 				path,
 				categories: ["Syntax", "Lint"],
 				max_diagnostics: Number.MAX_SAFE_INTEGER,
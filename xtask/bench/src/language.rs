@@ -195,6 +195,8 @@ impl Analyze {
                     &options,
                     JsFileSource::default(),
                     None,
+                    None,
+                    None,
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
@@ -211,7 +213,7 @@ impl Analyze {
                     ..AnalysisFilter::default()
                 };
                 let options = AnalyzerOptions::default();
-                biome_css_analyze::analyze(root, filter, &options, |event| {
+                biome_css_analyze::analyze(root, filter, &options, None, None, |event| {
                     black_box(event.diagnostic());
                     black_box(event.actions());
                     ControlFlow::<Never>::Continue(())
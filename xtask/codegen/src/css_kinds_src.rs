@@ -40,6 +40,8 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("==", "EQ2"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -241,6 +243,19 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "value",
         "as",
         "composes",
+        // tailwind at rules
+        "tailwind",
+        "apply",
+        "screen",
+        "config",
+        // scss at rules
+        "mixin",
+        "include",
+        "if",
+        "else",
+        "each",
+        // less
+        "when",
         //
         "font-face",
         // Don't add to the end of this list, add new keywords above the "HERE"
@@ -478,6 +493,44 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_VALUE_AT_RULE_DECLARATION_CLAUSE",
         "CSS_VALUE_AT_RULE_GENERIC_PROPERTY",
         "CSS_VALUE_AT_RULE_GENERIC_VALUE",
+        "CSS_TAILWIND_AT_RULE",
+        "CSS_APPLY_AT_RULE",
+        "CSS_APPLY_CLASS_LIST",
+        "CSS_SCREEN_AT_RULE",
+        "CSS_CONFIG_AT_RULE",
+        // SCSS
+        "SCSS_IDENTIFIER",
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_EXPRESSION",
+        "SCSS_EXPRESSION_ITEM_LIST",
+        "SCSS_INTERPOLATION",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT_VALUE",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_KEYWORD_ARGUMENT",
+        "SCSS_IF_AT_RULE",
+        "SCSS_ELSE_CLAUSE",
+        "SCSS_EACH_AT_RULE",
+        "SCSS_EACH_BINDING_LIST",
+        // Less
+        "LESS_IDENTIFIER",
+        "LESS_DECLARATION",
+        "LESS_MIXIN_DECLARATION",
+        "LESS_MIXIN_PARAMETERS",
+        "LESS_MIXIN_PARAMETER_LIST",
+        "LESS_MIXIN_PARAMETER",
+        "LESS_NAMED_PARAMETER",
+        "LESS_MIXIN_GUARD",
+        "LESS_GUARD_BINARY_CONDITION",
+        "LESS_GUARD_NOT_CONDITION",
+        "LESS_MIXIN_CALL",
         "CSS_UNKNOWN_BLOCK_AT_RULE",
         "CSS_UNKNOWN_VALUE_AT_RULE",
         "CSS_UNKNOWN_AT_RULE_COMPONENT_LIST",
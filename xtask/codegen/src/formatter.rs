@@ -556,9 +556,16 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
+    Less,
     Grit,
     Graphql,
     Html,
+    Astro,
+    Svelte,
+    Yaml,
+    Toml,
+    Md,
 }
 
 impl NodeDialect {
@@ -569,9 +576,16 @@ impl NodeDialect {
             NodeDialect::Jsx,
             NodeDialect::Json,
             NodeDialect::Css,
+            NodeDialect::Scss,
+            NodeDialect::Less,
             NodeDialect::Grit,
             NodeDialect::Graphql,
             NodeDialect::Html,
+            NodeDialect::Astro,
+            NodeDialect::Svelte,
+            NodeDialect::Yaml,
+            NodeDialect::Toml,
+            NodeDialect::Md,
         ]
     }
 
@@ -586,9 +600,16 @@ impl NodeDialect {
             NodeDialect::Jsx => "jsx",
             NodeDialect::Json => "json",
             NodeDialect::Css => "css",
+            NodeDialect::Scss => "scss",
+            NodeDialect::Less => "less",
             NodeDialect::Grit => "grit",
             NodeDialect::Graphql => "graphql",
             NodeDialect::Html => "html",
+            NodeDialect::Astro => "astro",
+            NodeDialect::Svelte => "svelte",
+            NodeDialect::Yaml => "yaml",
+            NodeDialect::Toml => "toml",
+            NodeDialect::Md => "md",
         }
     }
 
@@ -599,9 +620,16 @@ impl NodeDialect {
             "Ts" => NodeDialect::Ts,
             "Json" => NodeDialect::Json,
             "Css" => NodeDialect::Css,
+            "Scss" => NodeDialect::Scss,
+            "Less" => NodeDialect::Less,
             "Grit" => NodeDialect::Grit,
             "Graphql" => NodeDialect::Graphql,
             "Html" => NodeDialect::Html,
+            "Astro" => NodeDialect::Astro,
+            "Svelte" => NodeDialect::Svelte,
+            "Yaml" => NodeDialect::Yaml,
+            "Toml" => NodeDialect::Toml,
+            "Md" => NodeDialect::Md,
             _ => {
                 eprintln!("missing prefix {name}");
                 NodeDialect::Js
@@ -824,8 +852,16 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
-            LanguageKind::Yaml => NodeConcept::Auxiliary,
+            LanguageKind::Yaml => match name {
+                _ if name.ends_with("Value") => NodeConcept::Value,
+                _ => NodeConcept::Auxiliary,
+            },
+
+            LanguageKind::Toml => match name {
+                "KeyValue" => NodeConcept::Auxiliary,
+                _ if name.ends_with("Value") => NodeConcept::Value,
+                _ => NodeConcept::Auxiliary,
+            },
         }
     }
 }
@@ -891,7 +927,8 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatter",
             LanguageKind::Html => "HtmlFormatter",
             LanguageKind::Yaml => "YamlFormatter",
-            LanguageKind::Markdown => "DemoFormatter",
+            LanguageKind::Toml => "TomlFormatter",
+            LanguageKind::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -906,7 +943,8 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatContext",
             LanguageKind::Html => "HtmlFormatContext",
             LanguageKind::Yaml => "YamlFormatContext",
-            LanguageKind::Markdown => "DemoFormatterContext",
+            LanguageKind::Toml => "TomlFormatContext",
+            LanguageKind::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
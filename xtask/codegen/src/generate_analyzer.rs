@@ -12,6 +12,9 @@ pub fn generate_analyzer() -> Result<()> {
     generate_json_analyzer()?;
     generate_css_analyzer()?;
     generate_graphql_analyzer()?;
+    generate_html_analyzer()?;
+    generate_yaml_analyzer()?;
+    generate_toml_analyzer()?;
     Ok(())
 }
 
@@ -59,6 +62,34 @@ fn generate_graphql_analyzer() -> Result<()> {
     update_graphql_registry_builder(analyzers)
 }
 
+fn generate_html_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_html_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_html_registry_builder(analyzers)
+}
+
+fn generate_yaml_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_yaml_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_yaml_registry_builder(analyzers)
+}
+
+fn generate_toml_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_toml_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, &base_path)?;
+
+    generate_options(&base_path)?;
+    update_toml_registry_builder(analyzers, assists)
+}
+
 fn generate_options(base_path: &Path) -> Result<()> {
     let mut rules_options = BTreeMap::new();
     let mut crates = vec![];
@@ -350,6 +381,69 @@ fn update_graphql_registry_builder(analyzers: BTreeMap<&'static str, TokenStream
     Ok(())
 }
 
+fn update_html_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_html_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_html_syntax::HtmlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<HtmlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_yaml_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_yaml_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_yaml_syntax::YamlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<YamlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_toml_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
+    let path = project_root().join("crates/biome_toml_analyze/src/registry.rs");
+
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_toml_syntax::TomlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<TomlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
 /// Returns file paths of the given directory.
 fn list_entry_paths(dir: &Path) -> Result<impl Iterator<Item = PathBuf>> {
     Ok(fs2::read_dir(dir)
@@ -3,9 +3,12 @@ use biome_analyze::{
 };
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_string_case::Case;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_syntax::YamlLanguage;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
 use quote::quote;
@@ -95,6 +98,63 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
+impl RegistryVisitor<YamlLanguage> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
+impl RegistryVisitor<TomlLanguage> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
 // ======= ASSISTS ======
 #[derive(Default)]
 struct AssistsRulesVisitor {
@@ -176,6 +236,63 @@ impl RegistryVisitor<GraphqlLanguage> for AssistsRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for AssistsRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
+impl RegistryVisitor<YamlLanguage> for AssistsRulesVisitor {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
+impl RegistryVisitor<TomlLanguage> for AssistsRulesVisitor {
+    fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
 pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let linter_config_root = project_root().join("crates/biome_configuration/src/analyzer/linter");
     let assists_config_root =
@@ -192,6 +309,12 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     biome_css_analyze::visit_registry(&mut assists_visitor);
     biome_graphql_analyze::visit_registry(&mut lint_visitor);
     biome_graphql_analyze::visit_registry(&mut assists_visitor);
+    biome_html_analyze::visit_registry(&mut lint_visitor);
+    biome_html_analyze::visit_registry(&mut assists_visitor);
+    biome_yaml_analyze::visit_registry(&mut lint_visitor);
+    biome_yaml_analyze::visit_registry(&mut assists_visitor);
+    biome_toml_analyze::visit_registry(&mut lint_visitor);
+    biome_toml_analyze::visit_registry(&mut assists_visitor);
 
     // let LintRulesVisitor { groups } = lint_visitor;
 
@@ -294,8 +417,8 @@ fn generate_for_groups(
                             .#group_idents
                             .as_ref()
                             .and_then(|group| group.get_rule_configuration(rule_name))
-                            .filter(|conf| !matches!(conf, RuleAssistConfiguration::Off))
-                            .map(|conf| conf.into())
+                            .filter(|(level, _)| !matches!(level, RuleAssistPlainConfiguration::Off))
+                            .map(|(level, _)| level.into())
                     )*
                 }
             }
@@ -343,8 +466,8 @@ fn generate_for_groups(
 
     let use_rule_configuration = if kind == RuleCategory::Action {
         quote! {
-            use crate::analyzer::RuleAssistConfiguration;
-            use biome_analyze::RuleFilter;
+            use crate::analyzer::{RuleAssistConfiguration, RuleAssistPlainConfiguration};
+            use biome_analyze::{options::RuleOptions, RuleFilter};
         }
     } else {
         quote! {
@@ -607,10 +730,22 @@ fn generate_for_groups(
                 use biome_analyze::{AnalyzerRules, MetadataRegistry};
 
                 pub fn push_to_analyzer_assists(
-                    _rules: &Actions,
-                    _metadata: &MetadataRegistry,
-                    _analyzer_rules: &mut AnalyzerRules,
-                ) {}
+                    rules: &Actions,
+                    metadata: &MetadataRegistry,
+                    analyzer_rules: &mut AnalyzerRules,
+                ) {
+                    #(
+                        if let Some(rules) = rules.#group_idents.as_ref() {
+                            for rule_name in #group_pascal_idents::GROUP_RULES {
+                                if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
+                                    if let Some(rule_key) = metadata.find_rule(#group_strings, rule_name) {
+                                        analyzer_rules.push_rule(rule_key, rule_options);
+                                    }
+                                }
+                            }
+                        }
+                    )*
+                }
             }
         }
         RuleCategory::Syntax | RuleCategory::Transformation => unimplemented!(),
@@ -703,10 +838,10 @@ fn generate_group_struct(
         let rule_identifier = quote::format_ident!("{}", Case::Snake.convert(rule));
         let rule_config_type = quote::format_ident!(
             "{}",
-            if metadata.fix_kind != FixKind::None {
-                "RuleFixConfiguration"
-            } else if kind == RuleCategory::Action {
+            if kind == RuleCategory::Action {
                 "RuleAssistConfiguration"
+            } else if metadata.fix_kind != FixKind::None {
+                "RuleFixConfiguration"
             } else {
                 "RuleConfiguration"
             }
@@ -734,20 +869,25 @@ fn generate_group_struct(
             "graphql" => quote! {
                 biome_graphql_analyze::options::#rule_name
             },
+            "html" => quote! {
+                biome_html_analyze::options::#rule_name
+            },
             "json" => quote! {
                 biome_json_analyze::options::#rule_name
             },
+            "toml" => quote! {
+                biome_toml_analyze::options::#rule_name
+            },
             "ts" | "js" | "jsx" | "tsx" => quote! {
                 biome_js_analyze::options::#rule_name
             },
+            "yaml" => quote! {
+                biome_yaml_analyze::options::#rule_name
+            },
             _ => panic!("Language not supported"),
         };
-        let rule_option = if kind == RuleCategory::Action {
-            quote! { Option<#rule_config_type> }
-        } else {
-            quote! {
-                Option<#rule_config_type<#rule_option_type>>
-            }
+        let rule_option = quote! {
+            Option<#rule_config_type<#rule_option_type>>
         };
         schema_lines_rules.push(quote! {
             #[doc = #summary]
@@ -776,22 +916,16 @@ fn generate_group_struct(
             }
         });
 
-        if kind == RuleCategory::Action {
-            get_rule_configuration_line.push(quote! {
-                #rule => self.#rule_identifier.as_ref().copied()
-            });
-        } else {
-            get_rule_configuration_line.push(quote! {
-                #rule => self.#rule_identifier.as_ref().map(|conf| (conf.level(), conf.get_options()))
-            });
-        }
+        get_rule_configuration_line.push(quote! {
+            #rule => self.#rule_identifier.as_ref().map(|conf| (conf.level(), conf.get_options()))
+        });
     }
 
     let group_pascal_ident = Ident::new(&to_capitalized(group), Span::call_site());
 
     let get_configuration_function = if kind == RuleCategory::Action {
         quote! {
-            pub(crate) fn get_rule_configuration(&self, rule_name: &str) -> Option<RuleAssistConfiguration> {
+            pub(crate) fn get_rule_configuration(&self, rule_name: &str) -> Option<(RuleAssistPlainConfiguration, Option<RuleOptions>)> {
                 match rule_name {
                     #( #get_rule_configuration_line ),*,
                     _ => None
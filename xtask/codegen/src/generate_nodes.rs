@@ -973,9 +973,9 @@ pub(crate) fn token_kind_to_code(name: &str, language_kind: LanguageKind) -> Tok
         let token: TokenStream = token.parse().unwrap();
         quote! { T![#token] }
     } else {
-        // $ is valid syntax in rust and it's part of macros,
+        // $ is valid syntax in rust and it's part of macros, and the brackets must be balanced,
         // so we need to decorate the tokens with quotes
-        if matches!(name, "$=" | "$_") {
+        if matches!(name, "$=" | "$_" | "[[" | "]]" | "{#" | "{:" | "{/" | "{@") {
             let token = Literal::string(name);
             quote! { T![#token] }
         } else {
@@ -0,0 +1,615 @@
+//! Generates the signatures of the global functions of the web and Node.js runtimes, used by the
+//! rules of `biome_js_analyze` that need the types of their parameters and results.
+//!
+//! The signatures are extracted from the declarations of TypeScript's libraries, and from the
+//! `ADJUSTMENTS`, which are merged after them.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsDeclarationClause, AnyJsParameter, AnyTsName,
+    AnyTsReturnType, AnyTsType, AnyTsTypeMember, AnyTsVariableAnnotation, JsFileSource,
+    JsParameters, TsDeclareStatement, TsInterfaceDeclaration, TsMethodSignatureTypeMember,
+    TsPropertySignatureTypeMember, TsReferenceType, TsReturnTypeAnnotation, TsTypeAliasDeclaration,
+    TsTypeMemberList, TsTypeParameters,
+};
+use biome_rowan::AstNode;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use xtask::{bail, project_root, Mode, Result};
+use xtask_codegen::update;
+
+/// The version of TypeScript whose libraries are used
+const TYPESCRIPT_VERSION: &str = "5.6.3";
+
+/// The libraries of TypeScript, in the order they're merged
+const LIBRARIES: &[&str] = &["lib.es5.d.ts", "lib.es2015.core.d.ts", "lib.dom.d.ts"];
+
+/// The declarations of Node.js, which aren't part of TypeScript's libraries, and the adjustments
+/// of the declarations of the libraries.
+///
+/// They're merged after the libraries, so their functions and methods replace the ones of the
+/// libraries.
+const ADJUSTMENTS: &str = r#"
+// The values parsed from a JSON text must be validated before they're used
+interface JSON {
+    parse(text: string, reviver?: (this: any, key: string, value: any) => any): unknown;
+}
+interface Body {
+    json(): Promise<unknown>;
+}
+
+// The timers are numbers in the browsers, and `Timeout` objects in Node.js
+declare function clearInterval(id?: number | Timeout): void;
+declare function clearTimeout(id?: number | Timeout): void;
+declare function setInterval(handler: TimerHandler, timeout?: number): number | Timeout;
+declare function setTimeout(handler: TimerHandler, timeout?: number): number | Timeout;
+
+// A subset of `@types/node`
+declare var Buffer: {
+    from(value: unknown, encoding?: string): Buffer;
+};
+declare var process: {
+    cwd(): string;
+    exit(code?: number): never;
+    nextTick(callback: Function): void;
+};
+declare function require(id: string): any;
+"#;
+
+/// The paths of the functions whose signatures are generated
+const SIGNATURES: &[&str] = &[
+    "AbortSignal.any",
+    "AbortSignal.timeout",
+    "Buffer.from",
+    "Document.prototype.createElement",
+    "Document.prototype.getElementById",
+    "Element.prototype.closest",
+    "Element.prototype.getAttribute",
+    "Element.prototype.querySelector",
+    "Element.prototype.querySelectorAll",
+    "EventTarget.prototype.addEventListener",
+    "EventTarget.prototype.dispatchEvent",
+    "EventTarget.prototype.removeEventListener",
+    "JSON.parse",
+    "JSON.stringify",
+    "Number.parseInt",
+    "Response.json",
+    "Response.prototype.arrayBuffer",
+    "Response.prototype.blob",
+    "Response.prototype.clone",
+    "Response.prototype.formData",
+    "Response.prototype.json",
+    "Response.prototype.text",
+    "Storage.prototype.getItem",
+    "Storage.prototype.removeItem",
+    "Storage.prototype.setItem",
+    "cancelAnimationFrame",
+    "clearInterval",
+    "clearTimeout",
+    "fetch",
+    "parseInt",
+    "process.cwd",
+    "process.exit",
+    "process.nextTick",
+    "queueMicrotask",
+    "requestAnimationFrame",
+    "require",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+];
+
+/// The names of the interfaces whose properties are generated
+const INTERFACES: &[&str] = &[
+    "AddEventListenerOptions",
+    "ElementCreationOptions",
+    "EventListenerOptions",
+    "RequestInit",
+    "Response",
+    "ResponseInit",
+    "StructuredSerializeOptions",
+];
+
+/// The maximum depth of the type aliases that are resolved
+const MAX_ALIAS_DEPTH: usize = 16;
+
+pub(crate) fn generate_signatures(mode: Mode) -> Result<()> {
+    let mut declarations = Declarations::default();
+    for library in LIBRARIES {
+        declarations.add(library, &cached_or_fetch(library)?)?;
+    }
+    declarations.add("adjustments", ADJUSTMENTS)?;
+
+    let mut signatures = SIGNATURES.to_vec();
+    signatures.sort_unstable();
+    let signatures = signatures
+        .into_iter()
+        .map(|path| {
+            let function = declarations
+                .function(path)
+                .with_context(|| format!("the function `{path}` isn't declared"))?;
+            Ok(declarations.signature(path, &function))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut interfaces = INTERFACES.to_vec();
+    interfaces.sort_unstable();
+    let interfaces = interfaces
+        .into_iter()
+        .map(|name| {
+            if !declarations.interfaces.contains_key(name) {
+                bail!("the interface `{name}` isn't declared");
+            }
+            let name_literal = Literal::string(name);
+            let properties = declarations.properties(name).into_values();
+            Ok(quote! {
+                Interface {
+                    name: #name_literal,
+                    properties: &[#(#properties),*],
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let tokens = quote! {
+        use super::{optional, required, signature, Interface, Signature, TypeRef};
+
+        pub const SIGNATURES: &[Signature] = &[
+            #(#signatures),*
+        ];
+
+        pub const INTERFACES: &[Interface] = &[
+            #(#interfaces),*
+        ];
+    };
+
+    let path = project_root().join("crates/biome_js_analyze/src/globals/signatures/generated.rs");
+    update(&path, &xtask::reformat(tokens)?, &mode)?;
+
+    Ok(())
+}
+
+/// Retrieves a library from the cache, or from the registry if the cache is missing.
+fn cached_or_fetch(library: &str) -> Result<String> {
+    let path = PathBuf::from(format!("target/typescript-{TYPESCRIPT_VERSION}/{library}"));
+    let path = project_root().join(path);
+    if let Ok(text) = std::fs::read_to_string(&path) {
+        println!("Loaded `{library}` from cache ({})", path.display());
+        return Ok(text);
+    }
+
+    let url = format!("https://unpkg.com/typescript@{TYPESCRIPT_VERSION}/lib/{library}");
+    let text = ureq::get(&url).call()?.into_string()?;
+    println!("Loaded `{library}` from `unpkg.com`");
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, &text)?;
+    println!("Saved `{library}` to cache ({})", path.display());
+
+    Ok(text)
+}
+
+/// The top-level declarations of the libraries, by name
+#[derive(Default)]
+struct Declarations {
+    /// The declarations of each interface, merged in the order they're declared
+    interfaces: HashMap<String, Vec<TsInterfaceDeclaration>>,
+    aliases: HashMap<String, TsTypeAliasDeclaration>,
+    /// The types of the global variables, like `JSON` or `AbortSignal`
+    variables: HashMap<String, AnyTsType>,
+    /// The overloads of each global function
+    functions: HashMap<String, Vec<Function>>,
+}
+
+/// A function, or a method of an interface
+#[derive(Clone)]
+struct Function {
+    type_parameters: Option<TsTypeParameters>,
+    parameters: JsParameters,
+    return_type: Option<TsReturnTypeAnnotation>,
+}
+
+impl From<TsMethodSignatureTypeMember> for Function {
+    fn from(method: TsMethodSignatureTypeMember) -> Self {
+        Self {
+            type_parameters: method.type_parameters(),
+            // The parameters of the parsed declarations are never missing
+            parameters: method.parameters().expect("parameters of a method"),
+            return_type: method.return_type_annotation(),
+        }
+    }
+}
+
+impl Declarations {
+    fn add(&mut self, name: &str, text: &str) -> Result<()> {
+        let parsed = parse(text, JsFileSource::d_ts(), JsParserOptions::default());
+        if parsed.has_errors() {
+            bail!("`{name}` has syntax errors");
+        }
+        let root = parsed.tree();
+        let Some(module) = root.as_js_module() else {
+            bail!("`{name}` isn't a module");
+        };
+
+        // The functions declared by a source replace the ones of the previous sources
+        let mut functions = HashSet::new();
+        for item in module.items() {
+            let declaration = match TsDeclareStatement::cast_ref(item.syntax()) {
+                Some(statement) => statement.declaration().ok(),
+                None => AnyJsDeclarationClause::cast_ref(item.syntax()),
+            };
+            match declaration {
+                Some(AnyJsDeclarationClause::TsInterfaceDeclaration(interface)) => {
+                    let name = interface.id()?.syntax().text_trimmed().to_string();
+                    self.interfaces.entry(name).or_default().push(interface);
+                }
+                Some(AnyJsDeclarationClause::TsTypeAliasDeclaration(alias)) => {
+                    let name = alias
+                        .binding_identifier()?
+                        .syntax()
+                        .text_trimmed()
+                        .to_string();
+                    self.aliases.insert(name, alias);
+                }
+                Some(AnyJsDeclarationClause::TsDeclareFunctionDeclaration(function)) => {
+                    let name = function.id()?.syntax().text_trimmed().to_string();
+                    let function = Function {
+                        type_parameters: function.type_parameters(),
+                        parameters: function.parameters()?,
+                        return_type: function.return_type_annotation(),
+                    };
+                    let overloads = self.functions.entry(name.clone()).or_default();
+                    if functions.insert(name) {
+                        overloads.clear();
+                    }
+                    overloads.push(function);
+                }
+                Some(AnyJsDeclarationClause::JsVariableDeclarationClause(clause)) => {
+                    for declarator in clause.declaration()?.declarators().iter() {
+                        let declarator = declarator?;
+                        let Some(AnyTsVariableAnnotation::TsTypeAnnotation(annotation)) =
+                            declarator.variable_annotation()
+                        else {
+                            continue;
+                        };
+                        let name = declarator.id()?.syntax().text_trimmed().to_string();
+                        self.variables.insert(name, annotation.ty()?);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the function at `path`, which is the last overload of the function, the most
+    /// general one
+    fn function(&self, path: &str) -> Option<Function> {
+        if let Some((interface, method)) = path.split_once(".prototype.") {
+            return self.method(interface, method);
+        }
+        if let Some((variable, method)) = path.split_once('.') {
+            return match self.variables.get(variable)?.clone().omit_parentheses() {
+                AnyTsType::TsObjectType(object) => last_method(object.members(), method),
+                AnyTsType::TsReferenceType(reference) => self.method(&name(&reference)?, method),
+                _ => None,
+            };
+        }
+        self.functions.get(path)?.last().cloned()
+    }
+
+    /// Returns the last overload of the method of an interface, or of the interfaces it extends
+    fn method(&self, interface: &str, method: &str) -> Option<Function> {
+        let declarations = self.interfaces.get(interface)?;
+        declarations
+            .iter()
+            .filter_map(|declaration| last_method(declaration.members(), method))
+            .last()
+            .or_else(|| {
+                self.extended(declarations)
+                    .find_map(|extended| self.method(&extended, method))
+            })
+    }
+
+    /// Returns the properties of an interface and of the interfaces it extends, by name
+    fn properties(&self, interface: &str) -> BTreeMap<String, TokenStream> {
+        let Some(declarations) = self.interfaces.get(interface) else {
+            return BTreeMap::new();
+        };
+        let mut properties = BTreeMap::new();
+        for extended in self.extended(declarations) {
+            properties.extend(self.properties(&extended));
+        }
+        for member in declarations
+            .iter()
+            .flat_map(|declaration| declaration.members().iter())
+        {
+            if let Some(property) = TsPropertySignatureTypeMember::cast_ref(member.syntax()) {
+                if let Some(name) = property.name().ok().and_then(|name| name.name()) {
+                    let ty = property
+                        .type_annotation()
+                        .and_then(|annotation| annotation.ty().ok())
+                        .map_or(TypeRef::Any, |ty| self.type_ref(&ty, None, 0));
+                    let member =
+                        member_tokens(name.text(), ty, property.optional_token().is_some());
+                    properties.insert(name.to_string(), member);
+                }
+            }
+        }
+        properties
+    }
+
+    /// Returns the names of the interfaces extended by the declarations of an interface
+    fn extended<'a>(
+        &'a self,
+        declarations: &'a [TsInterfaceDeclaration],
+    ) -> impl Iterator<Item = String> + 'a {
+        declarations
+            .iter()
+            .filter_map(|declaration| declaration.extends_clause())
+            .flat_map(|clause| clause.types().iter())
+            .filter_map(|ty| name(&ty.ok()?))
+    }
+
+    fn signature(&self, path: &str, function: &Function) -> TokenStream {
+        let type_parameters = function.type_parameters.as_ref();
+        let parameters = function.parameters.items().iter().filter_map(|parameter| {
+            // The rest parameters and the `this` parameters aren't part of the signatures
+            let AnyJsParameter::AnyJsFormalParameter(parameter) = parameter.ok()? else {
+                return None;
+            };
+            let parameter = parameter.as_js_formal_parameter()?;
+            let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+                parameter.binding().ok()?
+            else {
+                return None;
+            };
+            let name = binding.name_token().ok()?;
+            let ty = parameter
+                .type_annotation()
+                .and_then(|annotation| annotation.ty().ok())
+                .map_or(TypeRef::Any, |ty| self.type_ref(&ty, type_parameters, 0));
+            Some(member_tokens(
+                name.text_trimmed(),
+                ty,
+                parameter.question_mark_token().is_some(),
+            ))
+        });
+        let returns = match function
+            .return_type
+            .as_ref()
+            .and_then(|annotation| annotation.ty().ok())
+        {
+            Some(AnyTsReturnType::AnyTsType(ty)) => self.type_ref(&ty, type_parameters, 0),
+            Some(AnyTsReturnType::TsPredicateReturnType(_)) => TypeRef::Boolean,
+            Some(AnyTsReturnType::TsAssertsReturnType(_)) => TypeRef::Void,
+            None => TypeRef::Any,
+        };
+        let path = Literal::string(path);
+        quote! {
+            signature(#path, &[#(#parameters),*], #returns)
+        }
+    }
+
+    /// Returns the type referenced by `ty`. The type parameters are replaced by their default
+    /// types, or by their constraints.
+    fn type_ref(
+        &self,
+        ty: &AnyTsType,
+        type_parameters: Option<&TsTypeParameters>,
+        depth: usize,
+    ) -> TypeRef {
+        match ty.clone().omit_parentheses() {
+            AnyTsType::TsAnyType(_) => TypeRef::Any,
+            AnyTsType::TsUnknownType(_) => TypeRef::Unknown,
+            AnyTsType::TsVoidType(_) => TypeRef::Void,
+            AnyTsType::TsNeverType(_) => TypeRef::Never,
+            AnyTsType::TsUndefinedType(_) => TypeRef::Undefined,
+            AnyTsType::TsNullLiteralType(_) => TypeRef::Null,
+            AnyTsType::TsBooleanType(_) | AnyTsType::TsBooleanLiteralType(_) => TypeRef::Boolean,
+            AnyTsType::TsNumberType(_) | AnyTsType::TsNumberLiteralType(_) => TypeRef::Number,
+            AnyTsType::TsStringType(_)
+            | AnyTsType::TsStringLiteralType(_)
+            | AnyTsType::TsTemplateLiteralType(_) => TypeRef::String,
+            AnyTsType::TsFunctionType(_) => TypeRef::Function,
+            AnyTsType::TsArrayType(array) => array.element_type().map_or(TypeRef::Unknown, |ty| {
+                TypeRef::Array(Box::new(self.type_ref(&ty, type_parameters, depth)))
+            }),
+            AnyTsType::TsUnionType(union) => TypeRef::union(
+                union
+                    .types()
+                    .iter()
+                    .filter_map(Result::ok)
+                    .map(|ty| self.type_ref(&ty, type_parameters, depth)),
+            ),
+            AnyTsType::TsReferenceType(reference) => {
+                self.reference(&reference, type_parameters, depth)
+            }
+            // The object types, the tuples and the computed types aren't described
+            _ => TypeRef::Unknown,
+        }
+    }
+
+    fn reference(
+        &self,
+        reference: &TsReferenceType,
+        type_parameters: Option<&TsTypeParameters>,
+        depth: usize,
+    ) -> TypeRef {
+        let Some(name) = name(reference) else {
+            return TypeRef::Unknown;
+        };
+        if depth > MAX_ALIAS_DEPTH {
+            return TypeRef::Unknown;
+        }
+
+        let type_parameter = type_parameters.and_then(|parameters| {
+            parameters.items().iter().find_map(|parameter| {
+                let parameter = parameter.ok()?;
+                let parameter_name = parameter.name().ok()?.ident_token().ok()?;
+                (parameter_name.text_trimmed() == name).then_some(parameter)
+            })
+        });
+        if let Some(parameter) = type_parameter {
+            let ty = match (parameter.default(), parameter.constraint()) {
+                (Some(default), _) => default.ty().ok(),
+                (None, Some(constraint)) => constraint.ty().ok(),
+                (None, None) => None,
+            };
+            // The type parameters of the default types aren't in scope
+            return ty.map_or(TypeRef::Unknown, |ty| self.type_ref(&ty, None, depth + 1));
+        }
+
+        let argument = || {
+            let arguments = reference.type_arguments()?;
+            let argument = arguments.ts_type_argument_list().iter().next()?.ok()?;
+            Some(self.type_ref(&argument, type_parameters, depth))
+        };
+        match name.as_str() {
+            "Array" | "ReadonlyArray" => {
+                return TypeRef::Array(Box::new(argument().unwrap_or(TypeRef::Any)));
+            }
+            "Promise" | "PromiseLike" => {
+                return TypeRef::Promise(Box::new(argument().unwrap_or(TypeRef::Any)));
+            }
+            "Function" => return TypeRef::Function,
+            _ => {}
+        }
+
+        if let Some(alias) = self.aliases.get(&name) {
+            // The generic aliases, like `Record`, are computed types
+            if alias.type_parameters().is_some() {
+                return TypeRef::Unknown;
+            }
+            return alias
+                .ty()
+                .map_or(TypeRef::Unknown, |ty| self.type_ref(&ty, None, depth + 1));
+        }
+        // The interfaces that are only called, like `EventListener`, are functions
+        let is_callable = self.interfaces.get(&name).is_some_and(|declarations| {
+            declarations.iter().all(|declaration| {
+                declaration.extends_clause().is_none()
+                    && declaration.members().iter().all(|member| {
+                        matches!(member, AnyTsTypeMember::TsCallSignatureTypeMember(_))
+                    })
+            })
+        });
+        if is_callable {
+            TypeRef::Function
+        } else {
+            TypeRef::Interface(name)
+        }
+    }
+}
+
+fn last_method(members: TsTypeMemberList, name: &str) -> Option<Function> {
+    members
+        .iter()
+        .filter_map(|member| TsMethodSignatureTypeMember::cast(member.into_syntax()))
+        .filter(|method| {
+            method
+                .name()
+                .ok()
+                .and_then(|member_name| member_name.name())
+                .is_some_and(|member_name| member_name.text() == name)
+        })
+        .last()
+        .map(Function::from)
+}
+
+/// Returns the name of a reference, like `AbortSignal`. The qualified names aren't resolved.
+fn name(reference: &TsReferenceType) -> Option<String> {
+    match reference.name().ok()? {
+        AnyTsName::JsReferenceIdentifier(identifier) => {
+            Some(identifier.value_token().ok()?.text_trimmed().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn member_tokens(name: &str, ty: TypeRef, optional: bool) -> TokenStream {
+    let name = Literal::string(name);
+    if optional {
+        quote! { optional(#name, #ty) }
+    } else {
+        quote! { required(#name, #ty) }
+    }
+}
+
+/// The type of a parameter, a property or a result, see `TypeRef` in `biome_js_analyze`
+#[derive(Clone, Eq, PartialEq)]
+enum TypeRef {
+    Unknown,
+    Any,
+    Void,
+    Never,
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    String,
+    Function,
+    Interface(String),
+    Array(Box<TypeRef>),
+    Promise(Box<TypeRef>),
+    Union(Vec<TypeRef>),
+}
+
+impl TypeRef {
+    /// Returns the union of `types`, without the nested unions and the duplicated types
+    fn union(types: impl IntoIterator<Item = Self>) -> Self {
+        let mut variants: Vec<Self> = Vec::new();
+        for ty in types {
+            let nested = match ty {
+                Self::Union(nested) => nested,
+                ty => vec![ty],
+            };
+            for ty in nested {
+                if !variants.contains(&ty) {
+                    variants.push(ty);
+                }
+            }
+        }
+        // `any` and `unknown` absorb the other types
+        if variants.contains(&Self::Any) {
+            Self::Any
+        } else if variants.contains(&Self::Unknown) {
+            Self::Unknown
+        } else if variants.len() == 1 {
+            variants.remove(0)
+        } else {
+            Self::Union(variants)
+        }
+    }
+}
+
+impl quote::ToTokens for TypeRef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Unknown => quote! { TypeRef::Unknown },
+            Self::Any => quote! { TypeRef::Any },
+            Self::Void => quote! { TypeRef::Void },
+            Self::Never => quote! { TypeRef::Never },
+            Self::Undefined => quote! { TypeRef::Undefined },
+            Self::Null => quote! { TypeRef::Null },
+            Self::Boolean => quote! { TypeRef::Boolean },
+            Self::Number => quote! { TypeRef::Number },
+            Self::String => quote! { TypeRef::String },
+            Self::Function => quote! { TypeRef::Function },
+            Self::Interface(name) => {
+                let name = Literal::string(name);
+                quote! { TypeRef::Interface(#name) }
+            }
+            Self::Array(ty) => quote! { TypeRef::Array(&#ty) },
+            Self::Promise(ty) => quote! { TypeRef::Promise(&#ty) },
+            Self::Union(types) => quote! { TypeRef::Union(&[#(#types),*]) },
+        });
+    }
+}
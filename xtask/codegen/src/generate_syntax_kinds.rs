@@ -14,7 +14,10 @@ pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) ->
         if "{}[]()`".contains(token) {
             let c = token.chars().next().unwrap();
             quote! { #c }
-        } else if matches!(*token, "$=" | "$_") {
+        } else if matches!(
+            *token,
+            "$=" | "$_" | "[[" | "]]" | "{#" | "{:" | "{/" | "{@"
+        ) {
             let token = Literal::string(token);
             quote! { #token }
         } else {
@@ -197,6 +200,19 @@ pub fn generate_syntax_kinds(grammar: KindsSrc, language_kind: LanguageKind) ->
                 }
             }
         }
+        LanguageKind::Toml => {
+            quote! {
+                pub const fn to_string(&self) -> Option<&'static str> {
+                    let tok = match self {
+                        #(#punctuation => #punctuation_strings,)*
+                        #(#full_keywords => #all_keyword_to_strings,)*
+                        TOML_STRING_LITERAL => "string literal",
+                        _ => return None,
+                    };
+                    Some(tok)
+                }
+            }
+        }
     };
 
     let ast = quote! {
@@ -10,6 +10,13 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         ("-", "MINUS"),
         ("<!--", "COMMENT_START"),
         ("-->", "COMMENT_END"),
+        ("{", "L_CURLY"),
+        ("}", "R_CURLY"),
+        ("{#", "SV_CURLY_HASH"),
+        ("{:", "SV_CURLY_COLON"),
+        ("{/", "SV_CURLY_SLASH"),
+        ("{@", "SV_CURLY_AT"),
+        ("---", "FENCE"),
     ],
     keywords: &["null", "true", "false", "doctype", "html"],
     literals: &["HTML_STRING_LITERAL", "HTML_LITERAL"],
@@ -36,6 +43,17 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_ATTRIBUTE_LIST",
         "HTML_CONTENT",
         "HTML_COMMENT",
+        "HTML_SINGLE_TEXT_EXPRESSION",
+        "HTML_TEXT_EXPRESSION",
+        // Astro
+        "ASTRO_FRONTMATTER_ELEMENT",
+        // Svelte
+        "SVELTE_BLOCK",
+        "SVELTE_BLOCK_OPENING",
+        "SVELTE_BLOCK_CLAUSE_LIST",
+        "SVELTE_BLOCK_CLAUSE",
+        "SVELTE_BLOCK_CLOSING",
+        "SVELTE_SPECIAL_TAG",
         // Bogus nodes
         "HTML_BOGUS",
         "HTML_BOGUS_ELEMENT",
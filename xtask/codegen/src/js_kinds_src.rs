@@ -684,12 +684,19 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
-                    ("$", LanguageKind::Graphql) => "dollar",
+                    ("$", LanguageKind::Graphql | LanguageKind::Css) => "dollar",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     ("---", LanguageKind::Yaml) => "dashdashdash",
+                    ("[[", LanguageKind::Toml) => "l_double_brack",
+                    ("]]", LanguageKind::Toml) => "r_double_brack",
                     ("<!--", LanguageKind::Html) => "comment_start",
                     ("-->", LanguageKind::Html) => "comment_end",
+                    ("{#", LanguageKind::Html) => "sv_curly_hash",
+                    ("{:", LanguageKind::Html) => "sv_curly_colon",
+                    ("{/", LanguageKind::Html) => "sv_curly_slash",
+                    ("{@", LanguageKind::Html) => "sv_curly_at",
+                    ("---", LanguageKind::Html) => "fence",
                     _ => name,
                 };
 
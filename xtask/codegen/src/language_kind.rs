@@ -8,20 +8,24 @@ use crate::js_kinds_src::JS_KINDS_SRC;
 use crate::json_kinds_src::JSON_KINDS_SRC;
 use crate::kind_src::KindsSrc;
 use crate::markdown_kinds_src::MARKDOWN_KINDS_SRC;
+use crate::toml_kinds_src::TOML_KINDS_SRC;
 use crate::yaml_kinds_src::YAML_KINDS_SRC;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 
-pub const LANGUAGE_PREFIXES: [&str; 10] = [
+pub const LANGUAGE_PREFIXES: [&str; 13] = [
     "js_",
     "ts_",
     "jsx_",
     "tsx_",
     "css_",
+    "scss_",
+    "less_",
     "json_",
     "grit_",
     "html_",
     "yaml_",
+    "toml_",
     "markdown_",
 ];
 
@@ -34,6 +38,7 @@ pub enum LanguageKind {
     Grit,
     Html,
     Yaml,
+    Toml,
     Markdown,
 }
 
@@ -47,12 +52,13 @@ impl std::fmt::Display for LanguageKind {
             LanguageKind::Grit => write!(f, "grit"),
             LanguageKind::Html => write!(f, "html"),
             LanguageKind::Yaml => write!(f, "yaml"),
+            LanguageKind::Toml => write!(f, "toml"),
             LanguageKind::Markdown => write!(f, "markdown"),
         }
     }
 }
 
-pub const ALL_LANGUAGE_KIND: [LanguageKind; 8] = [
+pub const ALL_LANGUAGE_KIND: [LanguageKind; 9] = [
     LanguageKind::Js,
     LanguageKind::Css,
     LanguageKind::Json,
@@ -60,6 +66,7 @@ pub const ALL_LANGUAGE_KIND: [LanguageKind; 8] = [
     LanguageKind::Grit,
     LanguageKind::Html,
     LanguageKind::Yaml,
+    LanguageKind::Toml,
     LanguageKind::Markdown,
 ];
 
@@ -75,9 +82,10 @@ impl FromStr for LanguageKind {
             "grit" => Ok(LanguageKind::Grit),
             "html" => Ok(LanguageKind::Html),
             "yaml" => Ok(LanguageKind::Yaml),
+            "toml" => Ok(LanguageKind::Toml),
             "markdown" => Ok(LanguageKind::Markdown),
             _ => Err(format!(
-                "Language {kind} not supported, please use: `js`, `css`, `json`, `grit`, `graphql`, `html`, `yaml` or `markdown`"
+                "Language {kind} not supported, please use: `js`, `css`, `json`, `grit`, `graphql`, `html`, `yaml`, `toml` or `markdown`"
             )),
         }
     }
@@ -113,7 +121,7 @@ macro_rules! define_language_kind_functions {
 }
 
 impl LanguageKind {
-    define_language_kind_functions!([Js, Css, Json, Graphql, Grit, Html, Yaml, Markdown]);
+    define_language_kind_functions!([Js, Css, Json, Graphql, Grit, Html, Yaml, Toml, Markdown]);
 
     pub(crate) fn syntax_crate_ident(&self) -> Ident {
         Ident::new(self.syntax_crate_name().as_str(), Span::call_site())
@@ -144,6 +152,7 @@ impl LanguageKind {
             LanguageKind::Grit => GRIT_KINDS_SRC,
             LanguageKind::Html => HTML_KINDS_SRC,
             LanguageKind::Yaml => YAML_KINDS_SRC,
+            LanguageKind::Toml => TOML_KINDS_SRC,
             LanguageKind::Markdown => MARKDOWN_KINDS_SRC,
         }
     }
@@ -157,6 +166,7 @@ impl LanguageKind {
             LanguageKind::Grit => include_str!("../gritql.ungram"),
             LanguageKind::Html => include_str!("../html.ungram"),
             LanguageKind::Yaml => include_str!("../yaml.ungram"),
+            LanguageKind::Toml => include_str!("../toml.ungram"),
             LanguageKind::Markdown => include_str!("../markdown.ungram"),
         }
     }
@@ -17,6 +17,7 @@ mod grit_kinds_src;
 mod js_kinds_src;
 mod json_kinds_src;
 mod markdown_kinds_src;
+mod toml_kinds_src;
 mod yaml_kinds_src;
 
 mod generate_crate;
@@ -102,6 +103,9 @@ pub enum TaskCommand {
     /// It updates the file that contains licenses
     #[bpaf(command)]
     License,
+    /// Generates the signatures of the runtime globals from the TypeScript libraries
+    #[bpaf(command)]
+    Signatures,
     /// Transforms ungram files into AST
     #[bpaf(command)]
     Grammar(Vec<String>),
@@ -8,6 +8,8 @@ mod generate_license;
 mod generate_migrate_eslint;
 #[cfg(feature = "schema")]
 mod generate_schema;
+#[cfg(feature = "signatures")]
+mod generate_signatures;
 mod promote_rule;
 use xtask::{project_root, pushd, Result};
 
@@ -21,6 +23,8 @@ use crate::generate_license::generate_license;
 use crate::generate_migrate_eslint::generate_migrate_eslint;
 #[cfg(feature = "schema")]
 use crate::generate_schema::generate_configuration_schema;
+#[cfg(feature = "signatures")]
+use crate::generate_signatures::generate_signatures;
 use crate::promote_rule::promote_rule;
 
 use xtask::Mode::Overwrite;
@@ -60,6 +64,10 @@ fn main() -> Result<()> {
             #[cfg(feature = "license")]
             generate_license(Overwrite)?;
         }
+        TaskCommand::Signatures => {
+            #[cfg(feature = "signatures")]
+            generate_signatures(Overwrite)?;
+        }
         TaskCommand::Grammar(language_list) => {
             generate_ast(Overwrite, language_list)?;
         }
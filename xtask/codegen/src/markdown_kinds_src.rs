@@ -5,32 +5,25 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
     punct: &[
         ("<", "L_ANGLE"),
         (">", "R_ANGLE"),
-        ("(", "L_PAREN"),
-        (")", "R_PAREN"),
-        ("[", "L_BRACK"),
-        ("]", "R_BRACK"),
-        ("/", "SLASH"),
-        ("=", "EQ"),
-        ("!", "BANG"),
-        ("-", "MINUS"),
+        ("|", "PIPE"),
         ("*", "STAR"),
-        ("`", "BACKTICK"),
-        ("~", "TILDE"),
-        ("   ", "WHITESPACE3"),
+        ("**", "DOUBLE_STAR"),
         ("_", "UNDERSCORE"),
-        ("#", "HASH"),
+        ("__", "DOUBLE_UNDERSCORE"),
     ],
     keywords: &["null"],
     literals: &[
         "MD_HARD_LINE_LITERAL",
-        "MD_SOFT_BREAK_LITERAL",
         "MD_TEXTUAL_LITERAL",
-        "MD_STRING_LITERAL",
-        "MD_INDENT_CHUNK_LITERAL",
+        "MD_INLINE_CODE_LITERAL",
+        "MD_HASH_SEQUENCE_LITERAL",
+        "MD_SETEXT_UNDERLINE_LITERAL",
         "MD_THEMATIC_BREAK_LITERAL",
-        "MD_ERROR_LITERAL",
+        "MD_FENCE_LITERAL",
+        "MD_LINE_LITERAL",
+        "MD_LIST_MARKER_LITERAL",
     ],
-    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "TAB"],
+    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE"],
     nodes: &[
         // Bogus nodes
         "BOGUS",
@@ -38,30 +31,32 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         // node
         "MD_DOCUMENT",
         "MD_BLOCK_LIST",
-        "MD_HASH_LIST",
-        "MD_HASH",
+        "MD_THEMATIC_BREAK_BLOCK",
         "MD_HEADER",
+        "MD_SETEXT_HEADER",
         "MD_INDENT_CODE_BLOCK",
         "MD_FENCED_CODE_BLOCK",
         "MD_HTML_BLOCK",
-        "MD_LINK_BLOCK",
-        "MD_QUOTE",
-        "MD_ORDER_LIST_ITEM",
-        "MD_BULLET_LIST_ITEM",
-        "MD_BULLET_LIST",
-        "MD_ORDER_LIST",
+        "MD_MDX_ESM_BLOCK",
+        "MD_MDX_FLOW_BLOCK",
+        "MD_TABLE",
+        "MD_TABLE_ROW_LIST",
+        "MD_TABLE_ROW",
+        "MD_TABLE_CELL_LIST",
+        "MD_TABLE_CELL",
         "MD_PARAGRAPH",
-        "MD_PARAGRAPH_ITEM_LIST",
+        "MD_LINE",
+        "MD_LINE_LIST",
+        "MD_QUOTE",
+        "MD_BULLET_LIST_BLOCK",
+        "MD_ORDERED_LIST_BLOCK",
+        "MD_LIST_ITEM_LIST",
+        "MD_LIST_ITEM",
+        "MD_INLINE_ITEM_LIST",
         "MD_INLINE_CODE",
         "MD_INLINE_EMPHASIS",
-        "MD_INLINE_LINK",
-        "MD_INLINE_IMAGE",
+        "MD_INLINE_STRONG",
         "MD_HARD_LINE",
-        "MD_SOFT_BREAK",
         "MD_TEXTUAL",
-        "MD_SETEXT_HEADER",
-        "MD_STRING",
-        "MD_INDENT",
-        "MD_THEMATIC_BREAK_BLOCK",
     ],
 };
@@ -18,6 +18,9 @@ const KNOWN_PATHS: &[&str] = &[
     "crates/biome_css_analyze",
     "crates/biome_json_analyze",
     "crates/biome_graphql_analyze",
+    "crates/biome_html_analyze",
+    "crates/biome_yaml_analyze",
+    "crates/biome_toml_analyze",
 ];
 pub fn promote_rule(rule_name: &str, new_group: &str) {
     let current_dir = env::current_dir().ok().unwrap();
@@ -0,0 +1,47 @@
+use crate::kind_src::KindsSrc;
+
+pub const TOML_KINDS_SRC: KindsSrc = KindsSrc {
+    punct: &[
+        ("=", "EQ"),
+        (".", "DOT"),
+        (",", "COMMA"),
+        ("{", "L_CURLY"),
+        ("}", "R_CURLY"),
+        ("[", "L_BRACK"),
+        ("]", "R_BRACK"),
+        ("[[", "L_DOUBLE_BRACK"),
+        ("]]", "R_DOUBLE_BRACK"),
+    ],
+    keywords: &["true", "false"],
+    literals: &[
+        "TOML_STRING_LITERAL",
+        "TOML_INTEGER_LITERAL",
+        "TOML_FLOAT_LITERAL",
+        "TOML_DATE_TIME_LITERAL",
+    ],
+    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "IDENT", "COMMENT"],
+    nodes: &[
+        "TOML_ROOT",
+        "TOML_ROOT_ITEM_LIST",
+        "TOML_TABLE",
+        "TOML_ARRAY_OF_TABLES",
+        "TOML_KEY_VALUE_LIST",
+        "TOML_KEY_VALUE",
+        "TOML_KEY",
+        "TOML_KEY_PART_LIST",
+        "TOML_BARE_KEY",
+        "TOML_QUOTED_KEY",
+        "TOML_STRING_VALUE",
+        "TOML_INTEGER_VALUE",
+        "TOML_FLOAT_VALUE",
+        "TOML_BOOLEAN_VALUE",
+        "TOML_DATE_TIME_VALUE",
+        "TOML_ARRAY",
+        "TOML_ARRAY_ELEMENT_LIST",
+        "TOML_INLINE_TABLE",
+        "TOML_INLINE_TABLE_ENTRY_LIST",
+        // Bogus nodes
+        "TOML_BOGUS",
+        "TOML_BOGUS_VALUE",
+    ],
+};
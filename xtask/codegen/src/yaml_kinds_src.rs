@@ -42,6 +42,8 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         "YAML_OBJECT",
         "YAML_OBJECT_MEMBER",
         "YAML_OBJECT_MEMBER_LIST",
+        "YAML_OBJECT_INLINE",
+        "YAML_OBJECT_INLINE_MEMBER_LIST",
         "YAML_ARRAY",
         "YAML_ARRAY_ITEM",
         "YAML_ARRAY_ITEM_LIST",
@@ -12,12 +12,15 @@ use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage};
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
-use biome_service::settings::WorkspaceSettings;
+use biome_service::settings::Settings;
 use biome_service::workspace::DocumentFileSource;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_syntax::YamlLanguage;
 use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
 use std::collections::BTreeMap;
 use std::fmt::Write;
@@ -107,11 +110,62 @@ pub fn check_rules() -> anyhow::Result<()> {
         }
     }
 
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
+    impl RegistryVisitor<YamlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
+    impl RegistryVisitor<TomlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = TomlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
     biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
+    biome_yaml_analyze::visit_registry(&mut visitor);
+    biome_toml_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -127,10 +181,22 @@ struct CodeBlockTest {
     tag: String,
     expect_diagnostic: bool,
     ignore: bool,
+    /// Whether the code block is a CSS module, such as `button.module.css`
+    css_modules: bool,
+    /// The name of the file of the code block, such as `package.json`
+    file_name: Option<String>,
 }
 
 impl CodeBlockTest {
     fn document_file_source(&self) -> DocumentFileSource {
+        // The support of HTML files is behind a feature flag in the workspace
+        if self.tag == "html" {
+            return DocumentFileSource::Html(HtmlFileSource::html());
+        }
+        // The code blocks of the rules of the Svelte templates only contain markup
+        if self.tag == "svelte" {
+            return DocumentFileSource::Html(HtmlFileSource::svelte());
+        }
         DocumentFileSource::from_extension(&self.tag)
     }
 }
@@ -150,6 +216,8 @@ impl FromStr for CodeBlockTest {
             tag: String::new(),
             expect_diagnostic: false,
             ignore: false,
+            css_modules: false,
+            file_name: None,
         };
 
         for token in tokens {
@@ -157,6 +225,10 @@ impl FromStr for CodeBlockTest {
                 // Other attributes
                 "expect_diagnostic" => test.expect_diagnostic = true,
                 "ignore" => test.ignore = true,
+                "css_modules" => test.css_modules = true,
+                _ if token.starts_with("file=") => {
+                    test.file_name = Some(token["file=".len()..].to_string())
+                }
                 // Regard as language tags, last one wins
                 _ => test.tag = token.to_string(),
             }
@@ -175,7 +247,13 @@ fn assert_lint(
     test: &CodeBlockTest,
     code: &str,
 ) -> anyhow::Result<()> {
-    let file_path = format!("code-block.{}", test.tag);
+    let file_path = if let Some(file_name) = &test.file_name {
+        file_name.clone()
+    } else if test.css_modules {
+        format!("code-block.module.{}", test.tag)
+    } else {
+        format!("code-block.{}", test.tag)
+    };
 
     let mut diagnostic_count = 0;
     let mut all_diagnostics = vec![];
@@ -220,9 +298,7 @@ fn assert_lint(
         return Ok(());
     }
 
-    let mut settings = WorkspaceSettings::default();
-    let key = settings.insert_project(PathBuf::new());
-    settings.register_current_project(key);
+    let settings = Settings::default();
     match test.document_file_source() {
         DocumentFileSource::Js(file_source) => {
             // Temporary support for astro, svelte and vue code blocks
@@ -265,10 +341,70 @@ fn assert_lint(
                     },
                     file_path: PathBuf::from(&file_path),
                 };
-                biome_js_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
+                biome_js_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    file_source,
+                    None,
+                    None,
+                    None,
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_severity_from_rule_code(category).expect(
+                                "If you see this error, it means you need to run cargo codegen-configuration",
+                            );
+
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(&file_path)
+                                .with_file_source_code(code);
+                            let res = write_diagnostic(code, error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    },
+                );
+            }
+        }
+        DocumentFileSource::Json(file_source) => {
+            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = AnalyzerOptions {
+                    file_path: PathBuf::from(&file_path),
+                    ..Default::default()
+                };
+                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                        let severity = settings.get_severity_from_rule_code(category).expect(
                                 "If you see this error, it means you need to run cargo codegen-configuration",
                             );
 
@@ -295,8 +431,16 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Json(file_source) => {
-            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
+        DocumentFileSource::Css(file_source) => {
+            let parse = biome_css_parser::parse_css(
+                code,
+                CssParserOptions {
+                    css_modules: test.css_modules,
+                    scss: file_source.is_scss(),
+                    less: file_source.is_less(),
+                    ..CssParserOptions::default()
+                },
+            );
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -316,10 +460,10 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
+                biome_css_analyze::analyze(&root, filter, &options, None, None, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                        let severity = settings.get_severity_from_rule_code(category).expect(
                                 "If you see this error, it means you need to run cargo codegen-configuration",
                             );
 
@@ -346,8 +490,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Css(..) => {
-            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+        DocumentFileSource::Graphql(..) => {
+            let parse = biome_graphql_parser::parse_graphql(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -367,12 +511,12 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_css_analyze::analyze(&root, filter, &options, |signal| {
+                biome_graphql_analyze::analyze(&root, filter, &options, None, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
-                                "If you see this error, it means you need to run cargo codegen-configuration",
-                            );
+                        let severity = settings.get_severity_from_rule_code(category).expect(
+                            "If you see this error, it means you need to run cargo codegen-configuration",
+                        );
 
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -397,8 +541,113 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Graphql(..) => {
-            let parse = biome_graphql_parser::parse_graphql(code);
+        DocumentFileSource::Html(file_source) => {
+            let parse = biome_html_parser::parse_html(
+                code,
+                biome_html_parser::HtmlParserOptions::from(&file_source),
+            );
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = AnalyzerOptions {
+                    file_path: PathBuf::from(&file_path),
+                    ..Default::default()
+                };
+                biome_html_analyze::analyze(&root, filter, &options, |signal| {
+                    if let Some(mut diag) = signal.diagnostic() {
+                        let category = diag.category().expect("linter diagnostic has no code");
+                        let severity = settings.get_severity_from_rule_code(category).expect(
+                            "If you see this error, it means you need to run cargo codegen-configuration",
+                        );
+
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diag = diag.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diag
+                            .with_severity(severity)
+                            .with_file_path(&file_path)
+                            .with_file_source_code(code);
+                        let res = write_diagnostic(code, error);
+
+                        // Abort the analysis on error
+                        if let Err(err) = res {
+                            eprintln!("Error: {err}");
+                            return ControlFlow::Break(err);
+                        }
+                    }
+
+                    ControlFlow::Continue(())
+                });
+            }
+        }
+        DocumentFileSource::Yaml(..) => {
+            let parse = biome_yaml_parser::parse_yaml(code);
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = AnalyzerOptions {
+                    file_path: PathBuf::from(&file_path),
+                    ..Default::default()
+                };
+                biome_yaml_analyze::analyze(&root, filter, &options, |signal| {
+                    if let Some(mut diag) = signal.diagnostic() {
+                        let category = diag.category().expect("linter diagnostic has no code");
+                        let severity = settings.get_severity_from_rule_code(category).expect(
+                            "If you see this error, it means you need to run cargo codegen-configuration",
+                        );
+
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diag = diag.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diag
+                            .with_severity(severity)
+                            .with_file_path(&file_path)
+                            .with_file_source_code(code);
+                        let res = write_diagnostic(code, error);
+
+                        // Abort the analysis on error
+                        if let Err(err) = res {
+                            eprintln!("Error: {err}");
+                            return ControlFlow::Break(err);
+                        }
+                    }
+
+                    ControlFlow::Continue(())
+                });
+            }
+        }
+        DocumentFileSource::Toml(..) => {
+            let parse = biome_toml_parser::parse_toml(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -418,10 +667,10 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
+                biome_toml_analyze::analyze(&root, filter, &options, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
-                        let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                        let severity = settings.get_severity_from_rule_code(category).expect(
                             "If you see this error, it means you need to run cargo codegen-configuration",
                         );
 
@@ -448,7 +697,6 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests